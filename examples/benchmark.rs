@@ -596,5 +596,35 @@ fn main() {
         1_000_000.0 / avg_us
     );
 
+    // Repeated identical query text, with and without the plan cache
+    let repeated_sql = "SELECT u.name, COUNT(o.id), SUM(o.amount) FROM users u \
+                        JOIN orders o ON o.user_id = u.id \
+                        WHERE u.id = 42 AND o.status IN ('pending', 'shipped') \
+                        GROUP BY u.name";
+    for (label, cache_size) in [
+        (
+            "Repeated query (cached)",
+            oxibase::executor::DEFAULT_CACHE_SIZE,
+        ),
+        ("Repeated query (no cache)", 0),
+    ] {
+        db.execute(&format!("SET plan_cache_size = {}", cache_size), ())
+            .unwrap();
+        let mut total = std::time::Duration::ZERO;
+        for _ in 0..ITERATIONS {
+            let start = Instant::now();
+            let rows = db.query(repeated_sql, ()).unwrap();
+            for _ in rows {}
+            total += start.elapsed();
+        }
+        let avg_us = total.as_micros() as f64 / ITERATIONS as f64;
+        println!(
+            "{:<25} | {:>12.1} | {:>12.0}",
+            label,
+            avg_us,
+            1_000_000.0 / avg_us
+        );
+    }
+
     println!("============================================================");
 }
@@ -0,0 +1,67 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rough load throughput with per-row index maintenance vs a bulk load
+//!
+//! Run with: cargo run --release --example bulk_load
+
+use oxibase::{ApiTransaction, Database};
+use std::time::Instant;
+
+const ROW_COUNT: i64 = 200_000;
+
+fn load(name: &str, begin: impl Fn(&Database) -> ApiTransaction) {
+    let db = Database::open(&format!("memory://bulk_load_{}", name)).unwrap();
+    db.execute(
+        "CREATE TABLE events (id INTEGER PRIMARY KEY, name TEXT, kind INTEGER, ts INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute("CREATE UNIQUE INDEX idx_events_name ON events(name)", ())
+        .unwrap();
+    db.execute("CREATE INDEX idx_events_kind ON events(kind)", ())
+        .unwrap();
+    db.execute(
+        "CREATE UNIQUE INDEX idx_events_kind_ts ON events(kind, ts)",
+        (),
+    )
+    .unwrap();
+
+    let start = Instant::now();
+    let mut tx = begin(&db);
+    for id in 0..ROW_COUNT {
+        tx.execute(
+            "INSERT INTO events VALUES ($1, $2, $3, $4)",
+            (id, format!("key-{}", id), id % 16, id),
+        )
+        .unwrap();
+    }
+    let inserted = start.elapsed();
+    tx.commit().unwrap();
+    let total = start.elapsed();
+
+    println!(
+        "{:<8} insert {:>8.1} ms, commit {:>8.1} ms, {:>10.0} rows/s",
+        name,
+        inserted.as_secs_f64() * 1000.0,
+        (total - inserted).as_secs_f64() * 1000.0,
+        ROW_COUNT as f64 / total.as_secs_f64()
+    );
+}
+
+fn main() {
+    println!("Loading {} rows into a table with 3 indexes\n", ROW_COUNT);
+    load("per_row", |db| db.begin().unwrap());
+    load("bulk", |db| db.begin_bulk().unwrap());
+}
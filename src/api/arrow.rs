@@ -0,0 +1,163 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Apache Arrow export
+//!
+//! Streams query results as Arrow [`RecordBatch`]es so they can be handed to
+//! Arrow-based tools (Polars, DataFusion, ...) without going through rows.
+//!
+//! Oxibase values map to Arrow types as described in `core::arrow`.
+//! Column types are inferred from the values in the first batch. A column
+//! mixing integers and floats becomes `Float64`; columns that are entirely
+//! NULL or that mix unrelated types become `Utf8`.
+
+use std::sync::Arc;
+
+use arrow_array::{RecordBatch, RecordBatchReader};
+use arrow_schema::{ArrowError, Field, Schema, SchemaRef};
+
+use crate::api::{Database, Params, ResultRow, Rows};
+use crate::core::arrow::{arrow_field, values_to_array};
+use crate::core::{DataType, Result, Value};
+
+/// Default number of rows per record batch
+pub const DEFAULT_ARROW_BATCH_SIZE: usize = 8192;
+
+impl Database {
+    /// Execute a query and stream the result as Arrow record batches
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let reader = db.query_arrow("SELECT id, name FROM users", ())?;
+    /// let schema = reader.schema();
+    /// for batch in reader {
+    ///     let batch = batch?;
+    ///     println!("{} rows", batch.num_rows());
+    /// }
+    /// ```
+    pub fn query_arrow<P: Params>(&self, sql: &str, params: P) -> Result<ArrowBatches> {
+        ArrowBatches::new(self.query(sql, params)?, DEFAULT_ARROW_BATCH_SIZE)
+    }
+}
+
+/// Streaming reader producing Arrow record batches from a query result
+///
+/// Implements [`RecordBatchReader`], so it can be passed directly to
+/// consumers of the Arrow C stream interface and similar APIs.
+pub struct ArrowBatches {
+    rows: Rows,
+    schema: SchemaRef,
+    batch_size: usize,
+    /// First batch, read ahead to infer the schema
+    pending: Option<Vec<ResultRow>>,
+    /// No more rows to read from the result set
+    done: bool,
+    /// At least one batch has been returned
+    emitted: bool,
+}
+
+impl ArrowBatches {
+    /// Wrap a result set, reading the first batch to infer the schema
+    pub fn new(mut rows: Rows, batch_size: usize) -> Result<Self> {
+        let batch_size = batch_size.max(1);
+        let first = read_rows(&mut rows, batch_size)?;
+        let done = first.len() < batch_size;
+        let schema = Arc::new(infer_schema(rows.columns(), &first));
+        Ok(Self {
+            rows,
+            schema,
+            batch_size,
+            pending: Some(first),
+            done,
+            emitted: false,
+        })
+    }
+
+    fn build_batch(&self, rows: &[ResultRow]) -> std::result::Result<RecordBatch, ArrowError> {
+        let columns = self
+            .schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| {
+                values_to_array(field, rows.len(), rows.iter().map(|row| row.get_value(idx)))
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        RecordBatch::try_new(Arc::clone(&self.schema), columns)
+    }
+}
+
+impl Iterator for ArrowBatches {
+    type Item = std::result::Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rows = match self.pending.take() {
+            Some(rows) => rows,
+            None if self.done => return None,
+            None => match read_rows(&mut self.rows, self.batch_size) {
+                Ok(rows) => {
+                    self.done = rows.len() < self.batch_size;
+                    rows
+                }
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(ArrowError::ExternalError(Box::new(err))));
+                }
+            },
+        };
+        // An empty result still yields a single empty batch
+        if rows.is_empty() && self.emitted {
+            return None;
+        }
+        self.emitted = true;
+        Some(self.build_batch(&rows))
+    }
+}
+
+impl RecordBatchReader for ArrowBatches {
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+}
+
+fn read_rows(rows: &mut Rows, limit: usize) -> Result<Vec<ResultRow>> {
+    rows.by_ref().take(limit).collect()
+}
+
+/// Infer an Arrow schema from the column names and a sample of rows
+fn infer_schema(columns: &[String], rows: &[ResultRow]) -> Schema {
+    let fields: Vec<Field> = columns
+        .iter()
+        .enumerate()
+        .map(|(idx, name)| {
+            let mut inferred: Option<DataType> = None;
+            for value in rows.iter().filter_map(|row| row.get_value(idx)) {
+                let ty = match value {
+                    Value::Null(_) => continue,
+                    other => other.data_type(),
+                };
+                inferred = Some(match inferred {
+                    None => ty,
+                    Some(prev) if prev == ty => prev,
+                    Some(prev) if prev.is_numeric() && ty.is_numeric() => DataType::Float,
+                    // Unrelated types fall back to their text form
+                    Some(_) => DataType::Text,
+                });
+            }
+            arrow_field(name, inferred.unwrap_or(DataType::Text))
+        })
+        .collect();
+    Schema::new(fields)
+}
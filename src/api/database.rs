@@ -52,11 +52,11 @@ use crate::executor::context::ExecutionContextBuilder;
 use crate::executor::scheduler::JobScheduler;
 use crate::executor::Executor;
 use crate::storage::mvcc::engine::MVCCEngine;
-use crate::storage::traits::Engine;
-use crate::storage::{Config, SyncMode};
+use crate::storage::traits::{Engine, QueryResult};
+use crate::storage::{parse_byte_size, parse_duration_ms, Config, SyncMode};
 
 use super::params::{NamedParams, Params};
-use super::rows::{FromRow, Rows};
+use super::rows::{FromRow, RowCounts, Rows};
 use super::statement::Statement;
 use super::transaction::Transaction;
 
@@ -69,10 +69,20 @@ static DATABASE_REGISTRY: std::sync::LazyLock<
     RwLock<HashMap<String, std::sync::Weak<DatabaseInner>>>,
 > = std::sync::LazyLock::new(|| RwLock::new(HashMap::new()));
 
+/// Factory creating a storage engine for a custom DSN scheme
+///
+/// The factory receives the part of the DSN after `scheme://` and returns
+/// an engine that is ready to use (already opened).
+pub type EngineFactory = Arc<dyn Fn(&str) -> Result<Arc<dyn Engine>> + Send + Sync>;
+
+/// Custom storage engines registered by scheme
+static ENGINE_FACTORIES: std::sync::LazyLock<RwLock<HashMap<String, EngineFactory>>> =
+    std::sync::LazyLock::new(|| RwLock::new(HashMap::new()));
+
 /// Inner database state (shared between Database instances with same DSN)
 struct DatabaseInner {
-    engine: Arc<MVCCEngine>,
-    executor: Mutex<Executor>,
+    engine: Arc<dyn Engine>,
+    executor: Arc<Mutex<Executor>>,
     dsn: String,
     scheduler_shutdown: Option<Arc<std::sync::atomic::AtomicBool>>,
 }
@@ -84,10 +94,19 @@ impl Drop for DatabaseInner {
             shutdown.store(true, std::sync::atomic::Ordering::Relaxed);
         }
         // Close the engine when the last reference is dropped
-        let _ = self.engine.close_engine();
+        let _ = close_engine(&self.engine);
     }
 }
 
+/// Close an engine, and the MVCC engine it exposes if it wraps one
+fn close_engine(engine: &Arc<dyn Engine>) -> Result<()> {
+    let closed = engine.close_engine();
+    if let Some(mvcc) = engine.as_mvcc() {
+        mvcc.close_engine()?;
+    }
+    closed
+}
+
 /// Database represents a Stoolap database connection
 ///
 /// This is the main entry point for using Stoolap. It wraps the storage engine
@@ -175,9 +194,9 @@ impl Database {
         let (scheme, path) = Self::parse_dsn(dsn)?;
 
         // Create the engine based on scheme
-        let engine = match scheme.as_str() {
+        let engine: Arc<dyn Engine> = match scheme.as_str() {
             MEMORY_SCHEME => {
-                let engine = MVCCEngine::in_memory();
+                let engine = MVCCEngine::new(Self::parse_memory_config(&path));
                 engine.open_engine()?;
                 Arc::new(engine)
             }
@@ -185,15 +204,19 @@ impl Database {
                 // Parse optional query parameters
                 let (_clean_path, config) = Self::parse_file_config(&path)?;
 
-                let engine = MVCCEngine::new(config);
+                let engine = Arc::new(MVCCEngine::new(config));
                 engine.open_engine()?;
-                Arc::new(engine)
+                engine.start_auto_checkpoint()?;
+                engine
             }
             _ => {
-                return Err(Error::parse(format!(
-                    "Unsupported scheme '{}'. Use 'memory://' or 'file://path'",
-                    scheme
-                )));
+                let factory = ENGINE_FACTORIES
+                    .read()
+                    .map_err(|_| Error::LockAcquisitionFailed("engine factories".to_string()))?
+                    .get(&scheme)
+                    .cloned()
+                    .ok_or_else(|| Self::unsupported_scheme(&scheme))?;
+                factory(&path)?
             }
         };
 
@@ -212,7 +235,7 @@ impl Database {
 
         let inner = Arc::new(DatabaseInner {
             engine,
-            executor: Mutex::new(executor),
+            executor: Arc::new(Mutex::new(executor)),
             dsn: dsn.to_string(),
             scheduler_shutdown,
         });
@@ -232,7 +255,7 @@ impl Database {
         // Create engine directly without registry (each in_memory call is unique)
         let engine = MVCCEngine::in_memory();
         engine.open_engine()?;
-        let engine = Arc::new(engine);
+        let engine: Arc<dyn Engine> = Arc::new(engine);
 
         let function_registry = crate::functions::registry::global_registry();
         let executor =
@@ -240,7 +263,7 @@ impl Database {
 
         let inner = Arc::new(DatabaseInner {
             engine,
-            executor: Mutex::new(executor),
+            executor: Arc::new(Mutex::new(executor)),
             dsn: "memory://".to_string(),
             scheduler_shutdown: None,
         });
@@ -261,10 +284,13 @@ impl Database {
         match scheme.as_str() {
             MEMORY_SCHEME | FILE_SCHEME => {}
             _ => {
-                return Err(Error::parse(format!(
-                    "Unsupported scheme '{}'. Use 'memory://' or 'file://path'",
-                    scheme
-                )));
+                let registered = ENGINE_FACTORIES
+                    .read()
+                    .map_err(|_| Error::LockAcquisitionFailed("engine factories".to_string()))?
+                    .contains_key(&scheme);
+                if !registered {
+                    return Err(Self::unsupported_scheme(&scheme));
+                }
             }
         }
 
@@ -284,6 +310,59 @@ impl Database {
         Ok((scheme, path))
     }
 
+    fn unsupported_scheme(scheme: &str) -> Error {
+        Error::parse(format!(
+            "Unsupported scheme '{}'. Use 'memory://', 'file://path' or register a custom engine",
+            scheme
+        ))
+    }
+
+    /// Register a custom storage engine for a DSN scheme
+    ///
+    /// After registration, `Database::open("scheme://...")` calls `factory`
+    /// with the part of the DSN after `scheme://` and runs the SQL executor
+    /// on top of the returned engine. Registering a scheme again replaces the
+    /// previous factory; databases that are already open are not affected.
+    ///
+    /// The engine must implement the [`Engine`] contract: transactions come
+    /// from [`Engine::begin_transaction`], and every table access goes through
+    /// the returned [`Transaction`](crate::storage::traits::Transaction) and
+    /// its [`Table`](crate::storage::traits::Table)s, whose scans produce
+    /// rows consumed as [`QueryResult`](crate::storage::traits::QueryResult)s.
+    /// DDL (CREATE/DROP TABLE, views, schemas) goes through the catalog
+    /// methods of [`Engine`]; an engine that keeps their default
+    /// implementations rejects those statements with `NotSupported`.
+    ///
+    /// The built-in `memory` and `file` schemes cannot be overridden.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// Database::register_engine("myengine", |path| {
+    ///     Ok(Arc::new(MyEngine::open(path)?) as Arc<dyn Engine>)
+    /// })?;
+    ///
+    /// let db = Database::open("myengine://some/location")?;
+    /// ```
+    pub fn register_engine<F>(scheme: &str, factory: F) -> Result<()>
+    where
+        F: Fn(&str) -> Result<Arc<dyn Engine>> + Send + Sync + 'static,
+    {
+        let scheme = scheme.to_lowercase();
+        if scheme == MEMORY_SCHEME || scheme == FILE_SCHEME {
+            return Err(Error::invalid_argument(format!(
+                "cannot override built-in scheme '{}'",
+                scheme
+            )));
+        }
+
+        ENGINE_FACTORIES
+            .write()
+            .map_err(|_| Error::LockAcquisitionFailed("engine factories".to_string()))?
+            .insert(scheme, Arc::new(factory));
+        Ok(())
+    }
+
     /// Parse file:// config from query parameters
     fn parse_file_config(path: &str) -> Result<(String, Config)> {
         let (clean_path, query) = if let Some(idx) = path.find('?') {
@@ -335,10 +414,16 @@ impl Database {
                             config.persistence.wal_buffer_size = bytes;
                         }
                     }
-                    // WAL max size in bytes: wal_max_size=67108864
-                    "wal_max_size" => {
-                        if let Ok(bytes) = value.parse::<usize>() {
-                            config.persistence.wal_max_size = bytes;
+                    // WAL segment size in bytes or with a unit: wal_max_size=64MB
+                    "wal_max_size" | "wal_segment_size" => {
+                        if let Some(bytes) = parse_byte_size(value) {
+                            config.persistence.wal_max_size = bytes as usize;
+                        }
+                    }
+                    // WAL size that triggers a checkpoint: checkpoint_wal_size=256MB
+                    "checkpoint_wal_size" => {
+                        if let Some(bytes) = parse_byte_size(value) {
+                            config.persistence.checkpoint_wal_size = bytes as usize;
                         }
                     }
                     // Commit batch size: commit_batch_size=100
@@ -376,7 +461,10 @@ impl Database {
                             config.persistence.compression_threshold = bytes;
                         }
                     }
-                    _ => {} // Ignore unknown parameters
+                    _ => {
+                        // Auto-vacuum, retention, memory and script-worker settings; ignore unknown parameters
+                        Self::apply_engine_param(&mut config, key, value);
+                    }
                 }
             }
         }
@@ -384,6 +472,76 @@ impl Database {
         Ok((clean_path, config))
     }
 
+    /// Parse memory:// config from query parameters
+    ///
+    /// Only the auto-vacuum, version-retention, memory-limit and script-worker parameters apply to in-memory databases.
+    fn parse_memory_config(path: &str) -> Config {
+        let mut config = Config::default();
+
+        if let Some(idx) = path.find('?') {
+            for param in path[idx + 1..].split('&') {
+                let mut parts = param.splitn(2, '=');
+                let key = parts.next().unwrap_or("");
+                let value = parts.next().unwrap_or("");
+                Self::apply_engine_param(&mut config, key, value);
+            }
+        }
+
+        config
+    }
+
+    /// Apply an auto-vacuum, version-retention, memory-limit or script-worker
+    /// query parameter, ignoring any other key
+    fn apply_engine_param(config: &mut Config, key: &str, value: &str) {
+        match key {
+            // Memory limit in bytes or with a unit: max_memory=512MB
+            "max_memory" => {
+                if let Some(bytes) = parse_byte_size(value) {
+                    config.max_memory = bytes;
+                }
+            }
+            // Enable auto-vacuum: autovacuum=on|off
+            "autovacuum" => {
+                config.auto_vacuum.enabled =
+                    matches!(value.to_lowercase().as_str(), "on" | "true" | "1" | "yes");
+            }
+            // Check interval in ms or with a unit: autovacuum_interval=30s
+            "autovacuum_interval_ms" | "autovacuum_interval" => {
+                if let Some(ms) = parse_duration_ms(value) {
+                    config.auto_vacuum.interval_ms = ms;
+                }
+            }
+            // Dead-version ratio that triggers a vacuum: autovacuum_threshold=0.2
+            "autovacuum_threshold" => {
+                if let Ok(ratio) = value.parse::<f64>() {
+                    if (0.0..=1.0).contains(&ratio) {
+                        config.auto_vacuum.threshold = ratio;
+                    }
+                }
+            }
+            // Minimum dead versions per table: autovacuum_min_dead_versions=50
+            "autovacuum_min_dead_versions" => {
+                if let Ok(count) = value.parse::<usize>() {
+                    config.auto_vacuum.min_dead_versions = count;
+                }
+            }
+            // Keep old versions for AS OF queries: version_retention=1h
+            "version_retention_ms" | "version_retention" => {
+                if let Some(ms) = parse_duration_ms(value) {
+                    config.version_retention_ms = ms;
+                }
+            }
+            // Process-wide cap on concurrent scripting runtimes: script_workers=4
+            #[cfg(feature = "runtime-pool")]
+            "script_workers" => {
+                if let Ok(workers) = value.parse::<usize>() {
+                    crate::functions::backends::pool::global_pool().set_limit(workers);
+                }
+            }
+            _ => {}
+        }
+    }
+
     #[inline]
     fn truncate_sql(sql: &str) -> &str {
         if sql.len() > 1024 {
@@ -431,6 +589,31 @@ impl Database {
     /// )?;
     /// ```
     pub fn execute<P: Params>(&self, sql: &str, params: P) -> Result<i64> {
+        Ok(self.execute_result(sql, params)?.rows_affected())
+    }
+
+    /// Execute a SQL statement, returning both its affected and changed row counts
+    ///
+    /// For UPDATE, `rows_affected` counts every row matched by the WHERE
+    /// clause and `rows_changed` only the rows whose values changed. Other
+    /// statements report the same number for both.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let counts = db.execute_counts("UPDATE users SET name = name WHERE id = $1", (1,))?;
+    /// assert_eq!(counts.rows_affected, 1);
+    /// assert_eq!(counts.rows_changed, 0);
+    /// ```
+    pub fn execute_counts<P: Params>(&self, sql: &str, params: P) -> Result<RowCounts> {
+        let result = self.execute_result(sql, params)?;
+        Ok(RowCounts {
+            rows_affected: result.rows_affected(),
+            rows_changed: result.rows_changed(),
+        })
+    }
+
+    fn execute_result<P: Params>(&self, sql: &str, params: P) -> Result<Box<dyn QueryResult>> {
         let _span = tracing::info_span!("db.execute", sql = %Self::truncate_sql(sql)).entered();
         let start = std::time::Instant::now();
         let executor = self
@@ -476,7 +659,7 @@ impl Database {
             tracing::warn!("Slow query detected ({}ms): {}", elapsed.as_millis(), sql);
         }
 
-        Ok(result.rows_affected())
+        Ok(result)
     }
 
     /// Execute a query that returns rows
@@ -849,7 +1032,12 @@ impl Database {
         rows.map(|r| r.and_then(|row| T::from_row(&row))).collect()
     }
 
-    /// Begin a new transaction with default isolation level
+    /// Begin a new transaction with the default isolation level
+    ///
+    /// The default is READ COMMITTED unless changed with
+    /// [`set_default_isolation_level`](Self::set_default_isolation_level).
+    /// A `SET TRANSACTION ISOLATION LEVEL` run outside a transaction applies
+    /// to the next transaction only.
     ///
     /// # Examples
     ///
@@ -859,7 +1047,13 @@ impl Database {
     /// tx.commit()?;
     /// ```
     pub fn begin(&self) -> Result<Transaction> {
-        self.begin_with_isolation(IsolationLevel::ReadCommitted)
+        let level = self
+            .inner
+            .executor
+            .lock()
+            .map_err(|_| Error::LockAcquisitionFailed("executor".to_string()))?
+            .take_next_isolation_level();
+        self.begin_with_isolation(level)
     }
 
     /// Begin a new transaction with a specific isolation level
@@ -869,7 +1063,7 @@ impl Database {
     /// ```ignore
     /// use oxibase::IsolationLevel;
     ///
-    /// let tx = db.begin_with_isolation(IsolationLevel::Snapshot)?;
+    /// let mut tx = db.begin_with_isolation(IsolationLevel::SnapshotIsolation)?;
     /// // All reads in this transaction see a consistent snapshot
     /// tx.execute("UPDATE users SET balance = balance - 100 WHERE id = $1", (1,))?;
     /// tx.commit()?;
@@ -882,13 +1076,56 @@ impl Database {
             .map_err(|_| Error::LockAcquisitionFailed("executor".to_string()))?;
 
         let tx = executor.begin_transaction_with_isolation(isolation)?;
-        Ok(Transaction::new(tx))
+        Ok(Transaction::new(
+            tx,
+            Arc::clone(executor.engine()),
+            Arc::clone(&self.inner.executor),
+        ))
+    }
+
+    /// Begin a transaction for loading many rows
+    ///
+    /// Inserts skip the per-row unique index checks, and each index is
+    /// updated once at commit with all the rows written. Uniqueness is then
+    /// checked across the whole load: a duplicate key makes `commit()` fail
+    /// with an error naming the offending row, and the transaction is rolled
+    /// back.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let mut tx = db.begin_bulk()?;
+    /// for (id, email) in rows {
+    ///     tx.execute("INSERT INTO users VALUES ($1, $2)", (id, email))?;
+    /// }
+    /// tx.commit()?;
+    /// ```
+    pub fn begin_bulk(&self) -> Result<Transaction> {
+        let executor = self
+            .inner
+            .executor
+            .lock()
+            .map_err(|_| Error::LockAcquisitionFailed("executor".to_string()))?;
+
+        let mut tx = executor.begin_transaction_with_isolation(IsolationLevel::ReadCommitted)?;
+        tx.set_bulk_load(true)?;
+        Ok(Transaction::new(
+            tx,
+            Arc::clone(executor.engine()),
+            Arc::clone(&self.inner.executor),
+        ))
     }
 
     /// Get the underlying storage engine
     ///
     /// This is primarily for advanced use cases and testing.
-    pub fn engine(&self) -> &Arc<MVCCEngine> {
+    ///
+    /// Since custom engines can be registered, this returns the engine as
+    /// `Arc<dyn Engine>` rather than `Arc<MVCCEngine>`. Code that used the
+    /// inherent `MVCCEngine` methods can get the concrete engine back with
+    /// `db.engine().as_mvcc()`, which returns `None` for custom engines that
+    /// do not expose one.
+    pub fn engine(&self) -> &Arc<dyn Engine> {
         &self.inner.engine
     }
 
@@ -911,9 +1148,7 @@ impl Database {
 
         // Close the engine immediately to release the file lock
         // This is idempotent - calling close_engine() multiple times is safe
-        self.inner.engine.close_engine()?;
-
-        Ok(())
+        close_engine(&self.inner.engine)
     }
 
     /// Check if a table exists
@@ -930,7 +1165,7 @@ impl Database {
 
     /// Set the default isolation level for new transactions
     pub fn set_default_isolation_level(&self, level: IsolationLevel) -> Result<()> {
-        let mut executor = self
+        let executor = self
             .inner
             .executor
             .lock()
@@ -939,6 +1174,16 @@ impl Database {
         Ok(())
     }
 
+    /// Get the default isolation level for new transactions
+    pub fn default_isolation_level(&self) -> Result<IsolationLevel> {
+        let executor = self
+            .inner
+            .executor
+            .lock()
+            .map_err(|_| Error::LockAcquisitionFailed("executor".to_string()))?;
+        Ok(executor.default_isolation_level())
+    }
+
     /// Create a point-in-time snapshot of the database
     ///
     /// This creates snapshot files for each table that can be used to speed up
@@ -947,10 +1192,94 @@ impl Database {
     ///
     /// Note: This is a no-op for in-memory databases.
     pub fn create_snapshot(&self) -> Result<()> {
-        use crate::storage::Engine;
         self.inner.engine.create_snapshot()
     }
 
+    /// Dump the schema as a list of DDL statements
+    ///
+    /// Includes schemas, tables, indexes, views, functions, procedures and
+    /// triggers, but no table data. Replaying the statements in order on an
+    /// empty database recreates the same schema.
+    pub fn dump_schema(&self) -> Result<Vec<String>> {
+        let executor = self
+            .inner
+            .executor
+            .lock()
+            .map_err(|_| Error::LockAcquisitionFailed("executor".to_string()))?;
+        executor.dump_schema()
+    }
+
+    /// Describe every table, view and stored routine in one call
+    ///
+    /// Tables come with their columns, indexes and constraints. This reads
+    /// the same metadata as the `information_schema` tables without running
+    /// a query per table.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let catalog = db.catalog()?;
+    /// for table in &catalog.tables {
+    ///     println!("{}.{} ({} columns)", table.schema, table.name, table.columns.len());
+    /// }
+    /// ```
+    pub fn catalog(&self) -> Result<crate::executor::Catalog> {
+        let executor = self
+            .inner
+            .executor
+            .lock()
+            .map_err(|_| Error::LockAcquisitionFailed("executor".to_string()))?;
+        executor.catalog()
+    }
+
+    /// Create a new in-memory database with the same schema but no data
+    ///
+    /// Useful for test fixtures: set up the schema once and clone it for
+    /// each test.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let template = Database::open_in_memory()?;
+    /// template.execute("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)", ())?;
+    ///
+    /// let db = template.clone_schema()?;
+    /// assert!(db.table_exists("users")?);
+    /// ```
+    pub fn clone_schema(&self) -> Result<Database> {
+        let statements = self.dump_schema()?;
+        let db = Database::open_in_memory()?;
+        for sql in &statements {
+            db.execute(sql, ())?;
+        }
+        Ok(db)
+    }
+
+    /// Back up a live database to a new directory
+    ///
+    /// Writes a consistent point-in-time copy without blocking writers:
+    /// changes committed while the backup runs are not part of it. The copy
+    /// holds every table, index and view, and the sequences, functions,
+    /// procedures and triggers kept in the `system` tables. Schema changes
+    /// are not versioned, so DDL that runs during the backup is left out.
+    /// Works for in-memory and file databases alike; open the result with
+    /// `file://<path>`.
+    ///
+    /// `path` must be empty or not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// db.export_snapshot("/backups/nightly")?;
+    /// let restored = Database::open("file:///backups/nightly")?;
+    /// ```
+    pub fn export_snapshot(&self, path: impl AsRef<std::path::Path>) -> Result<()> {
+        let mvcc = self.inner.engine.as_mvcc().ok_or_else(|| {
+            Error::NotSupportedMessage("export_snapshot requires the MVCC engine".to_string())
+        })?;
+        mvcc.export_snapshot(path.as_ref())
+    }
+
     /// Get the internal executor (for Statement use)
     pub(crate) fn executor(&self) -> &Mutex<Executor> {
         &self.inner.executor
@@ -979,6 +1308,18 @@ impl Database {
         Ok(executor.semantic_cache_stats())
     }
 
+    /// Get statistics of the parsed statement (plan) cache
+    ///
+    /// Its size can be changed with `SET plan_cache_size = n`; 0 disables it.
+    pub fn plan_cache_stats(&self) -> Result<crate::executor::CacheStats> {
+        let executor = self
+            .inner
+            .executor
+            .lock()
+            .map_err(|_| Error::LockAcquisitionFailed("executor".to_string()))?;
+        Ok(executor.cache_stats())
+    }
+
     /// Clear the semantic cache
     ///
     /// This clears all cached query results. Useful for testing or when
@@ -993,6 +1334,25 @@ impl Database {
         Ok(())
     }
 
+    /// Warnings raised by the last statement (what `SHOW WARNINGS` returns)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// db.execute("DROP TABLE IF EXISTS missing", ())?;
+    /// for warning in db.warnings()? {
+    ///     println!("{}", warning);
+    /// }
+    /// ```
+    pub fn warnings(&self) -> Result<Vec<crate::executor::Warning>> {
+        let executor = self
+            .inner
+            .executor
+            .lock()
+            .map_err(|_| Error::LockAcquisitionFailed("executor".to_string()))?;
+        Ok(executor.warnings())
+    }
+
     /// Statically analyze a procedural script to detect referenced database objects.
     pub fn analyze_script(&self, script: &str, backend: &str) -> Result<Vec<RelatedObject>> {
         crate::functions::analyzer::analyze_script(script, backend)
@@ -1007,6 +1367,12 @@ pub struct RelatedObject {
 }
 
 /// Trait for converting from Value to a Rust type
+///
+/// Implemented for the common Rust types. Implement it for your own types to
+/// read them with `ResultRow::get` and `query_one`; the
+/// [`impl_value_newtype!`](crate::impl_value_newtype) and
+/// [`impl_value_via_string!`](crate::impl_value_via_string) macros cover
+/// newtypes and types stored as TEXT.
 pub trait FromValue: Sized {
     /// Convert a Value to Self
     fn from_value(value: &Value) -> Result<Self>;
@@ -1038,6 +1404,25 @@ impl FromValue for i32 {
     }
 }
 
+// Narrower integers reject values that do not fit
+macro_rules! impl_from_value_for_int {
+    ($($T:ty),+) => {
+        $(
+            impl FromValue for $T {
+                fn from_value(value: &Value) -> Result<Self> {
+                    let wide = i64::from_value(value)?;
+                    <$T>::try_from(wide).map_err(|_| Error::TypeConversion {
+                        from: format!("{:?}", value),
+                        to: stringify!($T).to_string(),
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_from_value_for_int!(i16, i8, u32, u16, u8, usize);
+
 impl FromValue for f64 {
     fn from_value(value: &Value) -> Result<Self> {
         match value {
@@ -1051,6 +1436,12 @@ impl FromValue for f64 {
     }
 }
 
+impl FromValue for f32 {
+    fn from_value(value: &Value) -> Result<Self> {
+        f64::from_value(value).map(|f| f as f32)
+    }
+}
+
 impl FromValue for String {
     fn from_value(value: &Value) -> Result<Self> {
         match value {
@@ -1065,6 +1456,8 @@ impl FromValue for String {
                 "false".to_string()
             }),
             Value::Timestamp(ts) => Ok(ts.format("%Y-%m-%dT%H:%M:%SZ").to_string()),
+            Value::Interval(micros) => Ok(crate::core::format_interval(*micros)),
+            Value::Blob(bytes) => Ok(crate::core::format_blob(bytes)),
             Value::Null(_) => Ok(String::new()),
         }
     }
@@ -1083,6 +1476,53 @@ impl FromValue for bool {
     }
 }
 
+impl FromValue for Vec<u8> {
+    fn from_value(value: &Value) -> Result<Self> {
+        match value {
+            Value::Blob(b) => Ok(b.to_vec()),
+            Value::Text(s) => Ok(s.as_bytes().to_vec()),
+            _ => Err(Error::TypeConversion {
+                from: format!("{:?}", value),
+                to: "Blob".to_string(),
+            }),
+        }
+    }
+}
+
+impl FromValue for Arc<str> {
+    fn from_value(value: &Value) -> Result<Self> {
+        match value {
+            Value::Text(s) | Value::Json(s) => Ok(Arc::clone(s)),
+            _ => String::from_value(value).map(Arc::from),
+        }
+    }
+}
+
+impl FromValue for chrono::DateTime<chrono::Utc> {
+    fn from_value(value: &Value) -> Result<Self> {
+        match value {
+            Value::Timestamp(ts) => Ok(*ts),
+            Value::Text(s) => crate::core::parse_timestamp(s),
+            _ => Err(Error::TypeConversion {
+                from: format!("{:?}", value),
+                to: "Timestamp".to_string(),
+            }),
+        }
+    }
+}
+
+impl FromValue for serde_json::Value {
+    fn from_value(value: &Value) -> Result<Self> {
+        match value {
+            Value::Json(s) => serde_json::from_str(s).map_err(|_| Error::TypeConversion {
+                from: format!("{:?}", value),
+                to: "JSON".to_string(),
+            }),
+            _ => Ok(crate::api::output::value_to_json(value)),
+        }
+    }
+}
+
 impl FromValue for Value {
     fn from_value(value: &Value) -> Result<Self> {
         Ok(value.clone())
@@ -81,14 +81,24 @@
 //! }
 //! ```
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
 pub mod database;
+pub mod output;
 pub mod params;
+pub mod query_builder;
 pub mod rows;
 pub mod statement;
 pub mod transaction;
 
-pub use database::{Database, FromValue, RelatedObject};
+#[cfg(feature = "arrow")]
+pub use arrow::{ArrowBatches, DEFAULT_ARROW_BATCH_SIZE};
+pub use database::{Database, EngineFactory, FromValue, RelatedObject};
+pub use output::{ExportFormat, OutputFormat};
 pub use params::{NamedParams, Params, ToParam};
-pub use rows::{FromRow, ResultRow, Rows};
+pub use query_builder::{
+    quote_identifier, DeleteBuilder, InsertBuilder, SelectBuilder, UpdateBuilder,
+};
+pub use rows::{FromRow, ResultRow, RowCounts, Rows};
 pub use statement::Statement;
 pub use transaction::Transaction;
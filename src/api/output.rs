@@ -0,0 +1,288 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Result rendering for the CLI and server
+//!
+//! Query results can be printed as a table, CSV or JSON, or streamed to any
+//! writer as CSV or newline-delimited JSON. NULL is an empty, unquoted CSV
+//! field (an empty string is `""`) and `null` in JSON.
+
+use std::fmt;
+use std::io::Write;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+
+use crate::api::{Database, Params};
+use crate::core::{Error, Result, Value};
+
+/// Output format for query results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Boxed table for people to read
+    #[default]
+    Table,
+    /// Comma-separated values with a header row
+    Csv,
+    /// A `{"columns", "rows", "count"}` object
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "table" => Ok(OutputFormat::Table),
+            "csv" => Ok(OutputFormat::Csv),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(Error::invalid_argument(format!(
+                "unknown output format '{}' (expected table, csv or json)",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputFormat::Table => write!(f, "table"),
+            OutputFormat::Csv => write!(f, "csv"),
+            OutputFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+/// Format for streaming a result with [`Database::query_to_writer`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma-separated values with a header row
+    Csv,
+    /// Newline-delimited JSON: one object per row, keyed by column name
+    Ndjson,
+}
+
+impl FromStr for ExportFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "csv" => Ok(ExportFormat::Csv),
+            "ndjson" | "jsonl" => Ok(ExportFormat::Ndjson),
+            _ => Err(Error::invalid_argument(format!(
+                "unknown export format '{}' (expected csv or ndjson)",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportFormat::Csv => write!(f, "csv"),
+            ExportFormat::Ndjson => write!(f, "ndjson"),
+        }
+    }
+}
+
+impl Database {
+    /// Execute a query and stream its rows to `writer` as CSV or NDJSON
+    ///
+    /// Each row is written as soon as the query produces it, so the result
+    /// is never held in memory and a slow writer slows the query down with
+    /// it. Returns the number of rows written.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let file = std::fs::File::create("events.ndjson")?;
+    /// let mut out = std::io::BufWriter::new(file);
+    /// let n = db.query_to_writer("SELECT * FROM events", (), ExportFormat::Ndjson, &mut out)?;
+    /// ```
+    pub fn query_to_writer<P: Params, W: Write>(
+        &self,
+        sql: &str,
+        params: P,
+        format: ExportFormat,
+        writer: &mut W,
+    ) -> Result<usize> {
+        let rows = self.query(sql, params)?;
+        let columns = rows.columns().to_vec();
+        if format == ExportFormat::Csv {
+            write_csv_header(writer, &columns)?;
+        }
+
+        let mut count = 0;
+        for row in rows {
+            let row = row?;
+            match format {
+                ExportFormat::Csv => write_csv_row(writer, row.as_row().as_slice())?,
+                ExportFormat::Ndjson => {
+                    write_ndjson_row(writer, &columns, row.as_row().as_slice())?
+                }
+            }
+            count += 1;
+        }
+        writer
+            .flush()
+            .map_err(|e| Error::internal(format!("failed to flush output: {}", e)))?;
+        Ok(count)
+    }
+}
+
+/// Convert a value to JSON; NaN and infinities, which JSON cannot hold, become text
+pub fn value_to_json(value: &Value) -> JsonValue {
+    match value {
+        Value::Null(_) => JsonValue::Null,
+        Value::Integer(i) => serde_json::json!(i),
+        Value::Float(f) if !f.is_finite() => serde_json::json!(value.to_string()),
+        Value::Float(f) => serde_json::json!(f),
+        Value::Text(s) => serde_json::json!(s.as_ref()),
+        Value::Boolean(b) => serde_json::json!(b),
+        Value::Timestamp(ts) => serde_json::json!(ts.format("%Y-%m-%dT%H:%M:%SZ").to_string()),
+        Value::Json(s) => serde_json::json!(s.as_ref()),
+        Value::Interval(_) | Value::Blob(_) => serde_json::json!(value.to_string()),
+    }
+}
+
+/// Render a result set as a JSON object with positional rows
+pub fn rows_to_json(columns: &[String], rows: &[Vec<Value>]) -> JsonValue {
+    let json_rows: Vec<Vec<JsonValue>> = rows
+        .iter()
+        .map(|row| row.iter().map(value_to_json).collect())
+        .collect();
+
+    serde_json::json!({
+        "columns": columns,
+        "rows": json_rows,
+        "count": rows.len()
+    })
+}
+
+/// Write a result set as CSV with a header row, quoting fields as in RFC 4180
+pub fn write_csv<W: Write>(out: &mut W, columns: &[String], rows: &[Vec<Value>]) -> Result<()> {
+    write_csv_header(out, columns)?;
+    for row in rows {
+        write_csv_row(out, row)?;
+    }
+    Ok(())
+}
+
+/// Write the CSV header row
+pub fn write_csv_header<W: Write>(out: &mut W, columns: &[String]) -> Result<()> {
+    let header: Vec<String> = columns.iter().map(|c| csv_quote(c)).collect();
+    write_csv_line(out, &header)
+}
+
+/// Write one row of values as a CSV line
+pub fn write_csv_row<W: Write>(out: &mut W, row: &[Value]) -> Result<()> {
+    let fields: Vec<String> = row.iter().map(csv_field).collect();
+    write_csv_line(out, &fields)
+}
+
+/// Write one row as a JSON object on its own line, keys in column order
+pub fn write_ndjson_row<W: Write>(out: &mut W, columns: &[String], row: &[Value]) -> Result<()> {
+    let fields: Vec<String> = columns
+        .iter()
+        .zip(row)
+        .map(|(column, value)| {
+            format!(
+                "{}:{}",
+                JsonValue::from(column.as_str()),
+                value_to_json(value)
+            )
+        })
+        .collect();
+    writeln!(out, "{{{}}}", fields.join(","))
+        .map_err(|e| Error::internal(format!("failed to write JSON: {}", e)))
+}
+
+fn write_csv_line<W: Write>(out: &mut W, fields: &[String]) -> Result<()> {
+    writeln!(out, "{}", fields.join(","))
+        .map_err(|e| Error::internal(format!("failed to write CSV: {}", e)))
+}
+
+fn csv_field(value: &Value) -> String {
+    match value {
+        Value::Null(_) => String::new(),
+        Value::Timestamp(ts) => ts.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+        Value::Text(s) => csv_quote(s),
+        _ => csv_quote(&value.to_string()),
+    }
+}
+
+/// Quote a field if it is empty or holds a delimiter, quote or line break,
+/// so an empty string stays distinguishable from NULL
+fn csv_quote(s: &str) -> String {
+    if s.is_empty() || s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_quoting() {
+        let columns = vec!["id".to_string(), "note".to_string()];
+        let rows = vec![
+            vec![Value::Integer(1), Value::text("plain")],
+            vec![Value::Integer(2), Value::text("a, \"b\"\nc")],
+            vec![Value::Integer(3), Value::text("")],
+            vec![Value::Integer(4), Value::null_unknown()],
+        ];
+        let mut out = Vec::new();
+        write_csv(&mut out, &columns, &rows).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "id,note\n1,plain\n2,\"a, \"\"b\"\"\nc\"\n3,\"\"\n4,\n"
+        );
+    }
+
+    #[test]
+    fn test_ndjson_row() {
+        let columns = vec!["id".to_string(), "note".to_string()];
+        let mut out = Vec::new();
+        write_ndjson_row(
+            &mut out,
+            &columns,
+            &[Value::Integer(1), Value::null_unknown()],
+        )
+        .unwrap();
+        // Keys keep the column order
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "{\"id\":1,\"note\":null}\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("CSV".parse::<OutputFormat>().unwrap(), OutputFormat::Csv);
+        assert_eq!("json".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
+        assert_eq!(OutputFormat::default(), OutputFormat::Table);
+        assert!("xml".parse::<OutputFormat>().is_err());
+        assert_eq!(
+            "jsonl".parse::<ExportFormat>().unwrap(),
+            ExportFormat::Ndjson
+        );
+    }
+}
@@ -47,7 +47,10 @@ use crate::core::Value;
 /// Trait for types that can be converted to SQL parameters
 ///
 /// This trait is automatically implemented for common Rust types.
-/// It enables the `params!` macro and tuple parameter syntax.
+/// It enables the `params!` macro and tuple parameter syntax. Implement it
+/// for your own types to bind them directly, or use
+/// [`impl_value_newtype!`](crate::impl_value_newtype) and
+/// [`impl_value_via_string!`](crate::impl_value_via_string).
 pub trait ToParam {
     /// Convert self into a Value for SQL parameter binding
     fn to_param(&self) -> Value;
@@ -139,6 +142,18 @@ impl ToParam for Arc<str> {
     }
 }
 
+impl ToParam for Vec<u8> {
+    fn to_param(&self) -> Value {
+        Value::blob(self.as_slice())
+    }
+}
+
+impl ToParam for &[u8] {
+    fn to_param(&self) -> Value {
+        Value::blob(*self)
+    }
+}
+
 impl ToParam for DateTime<Utc> {
     fn to_param(&self) -> Value {
         Value::Timestamp(*self)
@@ -151,6 +166,42 @@ impl ToParam for Value {
     }
 }
 
+impl ToParam for serde_json::Value {
+    fn to_param(&self) -> Value {
+        Value::json(self.to_string())
+    }
+}
+
+/// Bind a list as a JSON array, for matching with `= ANY($1)`
+fn array_param<T: ToParam>(items: &[T]) -> Value {
+    let elements: Vec<serde_json::Value> = items
+        .iter()
+        .map(|item| crate::api::output::value_to_json(&item.to_param()))
+        .collect();
+    Value::json(serde_json::Value::Array(elements).to_string())
+}
+
+macro_rules! impl_to_param_for_array {
+    ($($T:ty),+) => {
+        $(
+            impl ToParam for Vec<$T> {
+                fn to_param(&self) -> Value {
+                    array_param(self)
+                }
+            }
+
+            impl ToParam for &[$T] {
+                fn to_param(&self) -> Value {
+                    array_param(self)
+                }
+            }
+        )+
+    };
+}
+
+// Vec<u8> and &[u8] bind as BLOBs
+impl_to_param_for_array!(i64, i32, f64, bool, String, &str);
+
 impl<T: ToParam> ToParam for Option<T> {
     fn to_param(&self) -> Value {
         match self {
@@ -267,6 +318,89 @@ macro_rules! params {
     };
 }
 
+/// Implement [`ToParam`] and [`FromValue`](crate::FromValue) for newtypes
+///
+/// Each type must be a tuple struct wrapping one field whose type already
+/// implements both traits. The newtype binds and reads exactly like that
+/// field.
+///
+/// # Examples
+///
+/// ```ignore
+/// use oxibase::{impl_value_newtype, Database};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct UserId(i64);
+///
+/// impl_value_newtype!(UserId(i64));
+///
+/// db.execute("INSERT INTO users VALUES ($1, $2)", (UserId(7), "Alice"))?;
+/// let id: UserId = db.query_one("SELECT id FROM users WHERE name = 'Alice'", ())?;
+/// ```
+#[macro_export]
+macro_rules! impl_value_newtype {
+    ($($name:ident($inner:ty)),+ $(,)?) => {
+        $(
+            impl $crate::ToParam for $name {
+                fn to_param(&self) -> $crate::Value {
+                    $crate::ToParam::to_param(&self.0)
+                }
+            }
+
+            impl $crate::FromValue for $name {
+                fn from_value(value: &$crate::Value) -> $crate::Result<Self> {
+                    <$inner as $crate::FromValue>::from_value(value).map($name)
+                }
+            }
+        )+
+    };
+}
+
+/// Implement [`ToParam`] and [`FromValue`](crate::FromValue) for types stored
+/// as TEXT
+///
+/// Values bind as the type's `Display` output and are read back with its
+/// `FromStr` implementation, which suits enums with a fixed set of names.
+///
+/// # Examples
+///
+/// ```ignore
+/// use oxibase::{impl_value_via_string, Database};
+///
+/// enum Status { Active, Disabled }
+///
+/// impl std::fmt::Display for Status { /* "active" / "disabled" */ }
+/// impl std::str::FromStr for Status { /* the reverse */ }
+///
+/// impl_value_via_string!(Status);
+///
+/// db.execute("UPDATE users SET status = $1 WHERE id = 1", (Status::Disabled,))?;
+/// let status: Status = db.query_one("SELECT status FROM users WHERE id = 1", ())?;
+/// ```
+#[macro_export]
+macro_rules! impl_value_via_string {
+    ($($name:ty),+ $(,)?) => {
+        $(
+            impl $crate::ToParam for $name {
+                fn to_param(&self) -> $crate::Value {
+                    $crate::Value::text(self.to_string())
+                }
+            }
+
+            impl $crate::FromValue for $name {
+                fn from_value(value: &$crate::Value) -> $crate::Result<Self> {
+                    let text = <String as $crate::FromValue>::from_value(value)?;
+                    text.parse::<$name>()
+                        .map_err(|_| $crate::Error::TypeConversion {
+                            from: format!("{:?}", value),
+                            to: stringify!($name).to_string(),
+                        })
+                }
+            }
+        )+
+    };
+}
+
 /// Named parameters for SQL queries
 ///
 /// This struct holds named parameter bindings that can be used with
@@ -413,6 +547,20 @@ mod tests {
         assert!(Option::<i64>::None.to_param().is_null());
     }
 
+    #[test]
+    fn test_to_param_arrays() {
+        assert_eq!(vec![1i64, 2, 3].to_param(), Value::json("[1,2,3]"));
+        assert_eq!(
+            ["a", "b\""].as_slice().to_param(),
+            Value::json(r#"["a","b\""]"#)
+        );
+        assert_eq!(Vec::<i64>::new().to_param(), Value::json("[]"));
+        assert_eq!(
+            serde_json::json!({"k": [1]}).to_param(),
+            Value::json(r#"{"k":[1]}"#)
+        );
+    }
+
     #[test]
     fn test_params_empty() {
         let params: Vec<Value> = ().into_params();
@@ -0,0 +1,411 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fluent query builder
+//!
+//! Builds basic SELECT, INSERT, UPDATE and DELETE statements without writing
+//! SQL strings. Identifiers are quoted and values are bound as parameters,
+//! so neither can change the shape of the statement.
+//!
+//! ```ignore
+//! let rows = db
+//!     .select(&["id", "name"])
+//!     .from("users")
+//!     .where_eq("id", 5)
+//!     .limit(10)
+//!     .query()?;
+//!
+//! db.insert_into("users").value("id", 6).value("name", "Eve").execute()?;
+//! db.update("users").set("name", "Bob").where_eq("id", 6).execute()?;
+//! db.delete_from("users").where_eq("id", 6).execute()?;
+//! ```
+
+use crate::api::{Database, FromValue, Rows, ToParam};
+use crate::core::{Error, Operator, Result, Value};
+
+/// Quote an identifier for use in SQL
+///
+/// Qualified names (`schema.table`, `table.column`) are quoted part by part
+/// and a bare `*` is left as is.
+pub fn quote_identifier(name: &str) -> String {
+    name.split('.')
+        .map(|part| {
+            if part == "*" {
+                part.to_string()
+            } else {
+                format!("\"{}\"", part.replace('"', "\"\""))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// WHERE conditions, combined with AND
+#[derive(Debug, Clone, Default)]
+struct Conditions {
+    clauses: Vec<String>,
+    params: Vec<Value>,
+}
+
+impl Conditions {
+    fn push(&mut self, column: &str, op: Operator, value: Value) {
+        let column = quote_identifier(column);
+        match op {
+            Operator::IsNull | Operator::IsNotNull => {
+                self.clauses.push(format!("{} {}", column, op));
+            }
+            Operator::In | Operator::NotIn => {
+                self.clauses.push(format!("{} {} (?)", column, op));
+                self.params.push(value);
+            }
+            _ => {
+                self.clauses.push(format!("{} {} ?", column, op));
+                self.params.push(value);
+            }
+        }
+    }
+
+    fn push_in(&mut self, column: &str, values: Vec<Value>) {
+        if values.is_empty() {
+            // Nothing is in an empty list
+            self.clauses.push("FALSE".to_string());
+            return;
+        }
+        let placeholders = vec!["?"; values.len()].join(", ");
+        self.clauses.push(format!(
+            "{} IN ({})",
+            quote_identifier(column),
+            placeholders
+        ));
+        self.params.extend(values);
+    }
+
+    fn write(&self, sql: &mut String) {
+        if !self.clauses.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&self.clauses.join(" AND "));
+        }
+    }
+}
+
+/// WHERE clause methods shared by the SELECT, UPDATE and DELETE builders
+macro_rules! where_methods {
+    () => {
+        /// Add `column = value` to the WHERE clause
+        ///
+        /// A NULL value never matches; use `where_null` instead.
+        pub fn where_eq(self, column: &str, value: impl ToParam) -> Self {
+            self.where_op(column, Operator::Eq, value)
+        }
+
+        /// Add `column <op> value` to the WHERE clause
+        ///
+        /// The value is ignored for `IsNull` and `IsNotNull`; `In` and
+        /// `NotIn` compare against the single value.
+        pub fn where_op(mut self, column: &str, op: Operator, value: impl ToParam) -> Self {
+            self.conditions.push(column, op, value.to_param());
+            self
+        }
+
+        /// Add `column IN (values...)` to the WHERE clause
+        pub fn where_in<T: ToParam>(
+            mut self,
+            column: &str,
+            values: impl IntoIterator<Item = T>,
+        ) -> Self {
+            let values = values.into_iter().map(|v| v.to_param()).collect();
+            self.conditions.push_in(column, values);
+            self
+        }
+
+        /// Add `column IS NULL` to the WHERE clause
+        pub fn where_null(mut self, column: &str) -> Self {
+            self.conditions
+                .push(column, Operator::IsNull, Value::null_unknown());
+            self
+        }
+
+        /// Add `column IS NOT NULL` to the WHERE clause
+        pub fn where_not_null(mut self, column: &str) -> Self {
+            self.conditions
+                .push(column, Operator::IsNotNull, Value::null_unknown());
+            self
+        }
+    };
+}
+
+impl Database {
+    /// Start a SELECT of the given columns
+    ///
+    /// An empty list selects `*`.
+    pub fn select(&self, columns: &[&str]) -> SelectBuilder<'_> {
+        SelectBuilder {
+            db: self,
+            columns: columns.iter().map(|c| c.to_string()).collect(),
+            table: None,
+            conditions: Conditions::default(),
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+        }
+    }
+
+    /// Start an INSERT into a table
+    pub fn insert_into(&self, table: &str) -> InsertBuilder<'_> {
+        InsertBuilder {
+            db: self,
+            table: table.to_string(),
+            columns: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Start an UPDATE of a table
+    pub fn update(&self, table: &str) -> UpdateBuilder<'_> {
+        UpdateBuilder {
+            db: self,
+            table: table.to_string(),
+            assignments: Vec::new(),
+            conditions: Conditions::default(),
+        }
+    }
+
+    /// Start a DELETE from a table
+    pub fn delete_from(&self, table: &str) -> DeleteBuilder<'_> {
+        DeleteBuilder {
+            db: self,
+            table: table.to_string(),
+            conditions: Conditions::default(),
+        }
+    }
+}
+
+/// SELECT statement builder, created by [`Database::select`]
+#[derive(Clone)]
+pub struct SelectBuilder<'a> {
+    db: &'a Database,
+    columns: Vec<String>,
+    table: Option<String>,
+    conditions: Conditions,
+    order_by: Vec<(String, bool)>,
+    limit: Option<u64>,
+    offset: Option<u64>,
+}
+
+impl SelectBuilder<'_> {
+    /// Set the table to select from
+    pub fn from(mut self, table: &str) -> Self {
+        self.table = Some(table.to_string());
+        self
+    }
+
+    where_methods!();
+
+    /// Sort ascending by a column
+    pub fn order_by(mut self, column: &str) -> Self {
+        self.order_by.push((column.to_string(), false));
+        self
+    }
+
+    /// Sort descending by a column
+    pub fn order_by_desc(mut self, column: &str) -> Self {
+        self.order_by.push((column.to_string(), true));
+        self
+    }
+
+    /// Return at most `n` rows
+    pub fn limit(mut self, n: u64) -> Self {
+        self.limit = Some(n);
+        self
+    }
+
+    /// Skip the first `n` rows
+    pub fn offset(mut self, n: u64) -> Self {
+        self.offset = Some(n);
+        self
+    }
+
+    /// The SQL text and parameters this builder executes
+    pub fn to_sql(&self) -> (String, Vec<Value>) {
+        let columns = if self.columns.is_empty() {
+            "*".to_string()
+        } else {
+            self.columns
+                .iter()
+                .map(|c| quote_identifier(c))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut sql = format!("SELECT {}", columns);
+        if let Some(table) = &self.table {
+            sql.push_str(" FROM ");
+            sql.push_str(&quote_identifier(table));
+        }
+        self.conditions.write(&mut sql);
+        if !self.order_by.is_empty() {
+            let order = self
+                .order_by
+                .iter()
+                .map(|(c, desc)| {
+                    format!(
+                        "{} {}",
+                        quote_identifier(c),
+                        if *desc { "DESC" } else { "ASC" }
+                    )
+                })
+                .collect::<Vec<_>>();
+            sql.push_str(" ORDER BY ");
+            sql.push_str(&order.join(", "));
+        }
+        if let Some(limit) = self.limit {
+            sql.push_str(&format!(" LIMIT {}", limit));
+        }
+        if let Some(offset) = self.offset {
+            sql.push_str(&format!(" OFFSET {}", offset));
+        }
+        (sql, self.conditions.params.clone())
+    }
+
+    /// Execute the query and return the rows
+    pub fn query(&self) -> Result<Rows> {
+        let (sql, params) = self.to_sql();
+        self.db.query(&sql, params)
+    }
+
+    /// Execute the query and return the first column of the first row
+    ///
+    /// Returns an error if the query returns no rows.
+    pub fn query_one<T: FromValue>(&self) -> Result<T> {
+        let (sql, params) = self.to_sql();
+        self.db.query_one(&sql, params)
+    }
+}
+
+/// INSERT statement builder, created by [`Database::insert_into`]
+#[derive(Clone)]
+pub struct InsertBuilder<'a> {
+    db: &'a Database,
+    table: String,
+    columns: Vec<String>,
+    values: Vec<Value>,
+}
+
+impl InsertBuilder<'_> {
+    /// Set the value of a column in the new row
+    pub fn value(mut self, column: &str, value: impl ToParam) -> Self {
+        self.columns.push(column.to_string());
+        self.values.push(value.to_param());
+        self
+    }
+
+    /// The SQL text and parameters this builder executes
+    pub fn to_sql(&self) -> (String, Vec<Value>) {
+        let columns = self
+            .columns
+            .iter()
+            .map(|c| quote_identifier(c))
+            .collect::<Vec<_>>();
+        let sql = format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            quote_identifier(&self.table),
+            columns.join(", "),
+            vec!["?"; columns.len()].join(", ")
+        );
+        (sql, self.values.clone())
+    }
+
+    /// Execute the insert and return the number of rows inserted
+    pub fn execute(&self) -> Result<i64> {
+        if self.columns.is_empty() {
+            return Err(Error::invalid_argument(
+                "INSERT requires at least one value",
+            ));
+        }
+        let (sql, params) = self.to_sql();
+        self.db.execute(&sql, params)
+    }
+}
+
+/// UPDATE statement builder, created by [`Database::update`]
+#[derive(Clone)]
+pub struct UpdateBuilder<'a> {
+    db: &'a Database,
+    table: String,
+    assignments: Vec<(String, Value)>,
+    conditions: Conditions,
+}
+
+impl UpdateBuilder<'_> {
+    /// Set a column to a new value
+    pub fn set(mut self, column: &str, value: impl ToParam) -> Self {
+        self.assignments
+            .push((column.to_string(), value.to_param()));
+        self
+    }
+
+    where_methods!();
+
+    /// The SQL text and parameters this builder executes
+    pub fn to_sql(&self) -> (String, Vec<Value>) {
+        let assignments = self
+            .assignments
+            .iter()
+            .map(|(c, _)| format!("{} = ?", quote_identifier(c)))
+            .collect::<Vec<_>>();
+        let mut sql = format!(
+            "UPDATE {} SET {}",
+            quote_identifier(&self.table),
+            assignments.join(", ")
+        );
+        self.conditions.write(&mut sql);
+
+        let mut params: Vec<Value> = self.assignments.iter().map(|(_, v)| v.clone()).collect();
+        params.extend(self.conditions.params.iter().cloned());
+        (sql, params)
+    }
+
+    /// Execute the update and return the number of rows changed
+    pub fn execute(&self) -> Result<i64> {
+        if self.assignments.is_empty() {
+            return Err(Error::invalid_argument("UPDATE requires at least one SET"));
+        }
+        let (sql, params) = self.to_sql();
+        self.db.execute(&sql, params)
+    }
+}
+
+/// DELETE statement builder, created by [`Database::delete_from`]
+#[derive(Clone)]
+pub struct DeleteBuilder<'a> {
+    db: &'a Database,
+    table: String,
+    conditions: Conditions,
+}
+
+impl DeleteBuilder<'_> {
+    where_methods!();
+
+    /// The SQL text and parameters this builder executes
+    pub fn to_sql(&self) -> (String, Vec<Value>) {
+        let mut sql = format!("DELETE FROM {}", quote_identifier(&self.table));
+        self.conditions.write(&mut sql);
+        (sql, self.conditions.params.clone())
+    }
+
+    /// Execute the delete and return the number of rows deleted
+    pub fn execute(&self) -> Result<i64> {
+        let (sql, params) = self.to_sql();
+        self.db.execute(&sql, params)
+    }
+}
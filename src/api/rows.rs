@@ -45,8 +45,9 @@
 //! let users: Vec<User> = db.query_as("SELECT id, name FROM users", ())?;
 //! ```
 
-use crate::core::{Error, Result, Row, Value};
-use crate::storage::traits::QueryResult;
+use crate::core::{DataType, Error, Result, Row, Value};
+use crate::executor::RowsAffectedResult;
+use crate::storage::traits::{ColumnMeta, QueryResult};
 
 use super::database::FromValue;
 
@@ -226,16 +227,43 @@ pub struct Rows {
     result: Box<dyn QueryResult>,
     /// Shared column names (Arc to avoid cloning per row)
     columns: std::sync::Arc<Vec<String>>,
+    /// Column types and nullability
+    columns_meta: Vec<ColumnMeta>,
     closed: bool,
 }
 
+/// Row counts of a DML statement, returned by
+/// [`Database::execute_counts`](crate::Database::execute_counts)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RowCounts {
+    /// Rows affected; for UPDATE, every row matched by the WHERE clause
+    pub rows_affected: i64,
+    /// Rows changed; for UPDATE, only the rows whose values changed
+    pub rows_changed: i64,
+}
+
 impl Rows {
     /// Create a new Rows iterator from a QueryResult
+    ///
+    /// A statement without result columns reads as one `rows_affected` row.
     pub(crate) fn new(result: Box<dyn QueryResult>) -> Self {
+        let result = RowsAffectedResult::wrap(result);
         let columns = std::sync::Arc::new(result.columns().to_vec());
+        let columns_meta = match result.columns_meta() {
+            Some(meta) => meta.to_vec(),
+            None => columns
+                .iter()
+                .map(|name| ColumnMeta {
+                    name: name.clone(),
+                    data_type: DataType::Null,
+                    nullable: true,
+                })
+                .collect(),
+        };
         Self {
             result,
             columns,
+            columns_meta,
             closed: false,
         }
     }
@@ -245,16 +273,72 @@ impl Rows {
         &self.columns
     }
 
+    /// Get the name, type and nullability of each column
+    ///
+    /// Columns read directly from a table report the declared type and NOT
+    /// NULL constraint. Computed columns are nullable and typed from the
+    /// first row (TEXT when there are no rows). Results that carry no type
+    /// information report `DataType::Null`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let rows = db.query("SELECT id, name FROM users", ())?;
+    /// for col in rows.columns_meta() {
+    ///     println!("{} {:?} nullable={}", col.name, col.data_type, col.nullable);
+    /// }
+    /// ```
+    pub fn columns_meta(&self) -> &[ColumnMeta] {
+        &self.columns_meta
+    }
+
     /// Get the number of columns
     pub fn column_count(&self) -> usize {
         self.columns.len()
     }
 
     /// Get the number of rows affected (for DML statements)
+    ///
+    /// For UPDATE this counts every row matched by the WHERE clause.
     pub fn rows_affected(&self) -> i64 {
         self.result.rows_affected()
     }
 
+    /// Get the number of rows changed (for DML statements)
+    ///
+    /// For UPDATE this leaves out rows set to the values they already had.
+    /// Other statements report the same count as
+    /// [`rows_affected`](Self::rows_affected).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let rows = db.query("UPDATE users SET name = name WHERE id = 1", ())?;
+    /// assert_eq!(rows.rows_affected(), 1);
+    /// assert_eq!(rows.rows_changed(), 0);
+    /// ```
+    pub fn rows_changed(&self) -> i64 {
+        self.result.rows_changed()
+    }
+
+    /// Whether a DDL statement changed nothing
+    ///
+    /// True when IF NOT EXISTS found the object already there, or IF EXISTS
+    /// found nothing to drop. Statements that created, altered or dropped
+    /// something, and all other statements, report false.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let rows = db.query("CREATE TABLE IF NOT EXISTS users (id INTEGER PRIMARY KEY)", ())?;
+    /// if rows.is_no_op() {
+    ///     println!("users already exists");
+    /// }
+    /// ```
+    pub fn is_no_op(&self) -> bool {
+        self.result.is_no_op()
+    }
+
     /// Collect all rows into a Vec
     ///
     /// # Example
@@ -296,6 +380,11 @@ impl Iterator for Rows {
                 row,
                 std::sync::Arc::clone(&self.columns),
             )))
+        } else if let Some(err) = self.result.err() {
+            // Reported once; the rows end after it
+            let err = err.clone();
+            self.close();
+            Some(Err(err))
         } else {
             None
         }
@@ -40,6 +40,7 @@
 //! ```
 
 use crate::core::{Error, Result};
+use crate::executor::SchemaToken;
 
 use super::database::{Database, FromValue};
 use super::params::Params;
@@ -56,20 +57,29 @@ use super::rows::Rows;
 /// Statement holds a reference to the Database and can be used from
 /// multiple threads, but each execution is serialized through the
 /// database's executor lock.
+///
+/// # Schema Changes
+///
+/// A statement remembers the tables and views it refers to. Once one of
+/// them is altered, renamed, replaced or dropped, executing the statement
+/// fails with [`Error::PlanStale`]; prepare it again to pick up the new
+/// schema.
 #[derive(Clone)]
 pub struct Statement {
     db: Database,
     sql: String,
+    schema_token: SchemaToken,
 }
 
 impl Statement {
     /// Create a new prepared statement
     ///
-    /// This validates the SQL syntax by pre-warming the query cache.
+    /// This validates the SQL syntax by pre-warming the query cache, and
+    /// records the schema versions of the objects the statement refers to.
     pub(crate) fn new(db: Database, sql: String) -> Result<Self> {
         // Pre-warm the cache by executing with empty params check
         // This validates the SQL syntax
-        {
+        let schema_token = {
             let executor = db
                 .executor()
                 .lock()
@@ -77,9 +87,24 @@ impl Statement {
 
             // Just touch the cache to pre-parse
             let _ = executor.query_cache().get(&sql);
-        }
+            executor.schema_token(&sql)
+        };
+
+        Ok(Self {
+            db,
+            sql,
+            schema_token,
+        })
+    }
 
-        Ok(Self { db, sql })
+    /// Fail with [`Error::PlanStale`] if a table or view this statement
+    /// refers to changed since it was prepared
+    fn check_schema(&self) -> Result<()> {
+        self.db
+            .executor()
+            .lock()
+            .map_err(|_| Error::LockAcquisitionFailed("executor".to_string()))?
+            .check_schema_token(&self.schema_token)
     }
 
     /// Execute the prepared statement
@@ -94,6 +119,7 @@ impl Statement {
     /// stmt.execute((2, "Bob"))?;
     /// ```
     pub fn execute<P: Params>(&self, params: P) -> Result<i64> {
+        self.check_schema()?;
         self.db.execute(&self.sql, params)
     }
 
@@ -112,6 +138,7 @@ impl Statement {
     /// }
     /// ```
     pub fn query<P: Params>(&self, params: P) -> Result<Rows> {
+        self.check_schema()?;
         self.db.query(&self.sql, params)
     }
 
@@ -124,6 +151,7 @@ impl Statement {
     /// let name: String = stmt.query_one((1,))?;
     /// ```
     pub fn query_one<T: FromValue, P: Params>(&self, params: P) -> Result<T> {
+        self.check_schema()?;
         self.db.query_one(&self.sql, params)
     }
 
@@ -136,6 +164,7 @@ impl Statement {
     /// let name: Option<String> = stmt.query_opt((999,))?;
     /// ```
     pub fn query_opt<T: FromValue, P: Params>(&self, params: P) -> Result<Option<T>> {
+        self.check_schema()?;
         self.db.query_opt(&self.sql, params)
     }
 
@@ -33,18 +33,22 @@
 //! tx.commit()?;
 //! ```
 
-use crate::core::{Error, Result, Row, Value};
+use std::sync::{Arc, Mutex};
+
+use crate::core::{Error, IsolationLevel, Result, Row, Value};
+use crate::executor::column_defaults::{supplied_columns, target_column_indices, ColumnDefaults};
 use crate::executor::context::ExecutionContext;
 use crate::executor::expression::ExpressionEval;
 use crate::executor::result::ExecutorMemoryResult;
+use crate::executor::{isolation_level_from_sql, isolation_level_result, Executor};
 use crate::parser::ast::{Expression, Statement};
 use crate::parser::Parser;
 use crate::storage::expression::Expression as StorageExpression;
-use crate::storage::traits::{QueryResult, Transaction as StorageTransaction};
+use crate::storage::traits::{Engine, QueryResult, Transaction as StorageTransaction};
 
 use super::database::FromValue;
 use super::params::Params;
-use super::rows::Rows;
+use super::rows::{RowCounts, Rows};
 
 /// Transaction represents a database transaction
 ///
@@ -52,17 +56,31 @@ use super::rows::Rows;
 /// Must be explicitly committed or rolled back.
 pub struct Transaction {
     tx: Option<Box<dyn StorageTransaction>>,
+    /// Engine the transaction runs on, for TXID_STATUS and sequences
+    engine: Arc<dyn Engine>,
+    /// Executor of the database, for statements run through its implementation
+    executor: Arc<Mutex<Executor>>,
     committed: bool,
     rolled_back: bool,
+    /// Whether a statement other than SET/SHOW ran, after which the
+    /// isolation level can no longer change
+    ran_query: bool,
 }
 
 impl Transaction {
     /// Create a new transaction wrapper
-    pub(crate) fn new(tx: Box<dyn StorageTransaction>) -> Self {
+    pub(crate) fn new(
+        tx: Box<dyn StorageTransaction>,
+        engine: Arc<dyn Engine>,
+        executor: Arc<Mutex<Executor>>,
+    ) -> Self {
         Self {
             tx: Some(tx),
+            engine,
+            executor,
             committed: false,
             rolled_back: false,
+            ran_query: false,
         }
     }
 
@@ -85,6 +103,11 @@ impl Transaction {
         self.tx.as_ref().map(|tx| tx.id()).unwrap_or(-1)
     }
 
+    /// Get the isolation level of the transaction
+    pub fn isolation_level(&self) -> Option<IsolationLevel> {
+        self.tx.as_ref().map(|tx| tx.isolation_level())
+    }
+
     /// Execute a SQL statement within the transaction
     ///
     /// # Parameters
@@ -110,6 +133,21 @@ impl Transaction {
         Ok(result.rows_affected())
     }
 
+    /// Execute a SQL statement within the transaction, returning both its
+    /// affected and changed row counts
+    ///
+    /// See [`Database::execute_counts`](crate::Database::execute_counts).
+    pub fn execute_counts<P: Params>(&mut self, sql: &str, params: P) -> Result<RowCounts> {
+        self.check_active()?;
+
+        let param_values = params.into_params();
+        let result = self.execute_sql(sql, &param_values)?;
+        Ok(RowCounts {
+            rows_affected: result.rows_affected(),
+            rows_changed: result.rows_changed(),
+        })
+    }
+
     /// Execute a query within the transaction
     ///
     /// # Examples
@@ -169,8 +207,13 @@ impl Transaction {
 
     /// Internal SQL execution
     fn execute_sql(&mut self, sql: &str, params: &[Value]) -> Result<Box<dyn QueryResult>> {
-        // Parse the SQL
-        let mut parser = Parser::new(sql);
+        // Parse the SQL under the session's identifier quoting
+        let identifier_quote = self
+            .executor
+            .lock()
+            .map_err(|_| Error::LockAcquisitionFailed("executor".to_string()))?
+            .identifier_quote();
+        let mut parser = Parser::with_identifier_quote(sql, identifier_quote);
         let program = parser
             .parse_program()
             .map_err(|e| Error::parse(e.to_string()))?;
@@ -181,6 +224,11 @@ impl Transaction {
         } else {
             ExecutionContext::with_params(params.to_vec())
         };
+        ctx.validate_param_count(parser.max_parameter_index())?;
+        // TXID_CURRENT() returns this transaction's ID
+        let ctx = ctx.with_transaction_id(self.id() as u64);
+        crate::executor::context::set_current_engine(Arc::clone(&self.engine));
+        crate::functions::context::set_identifier_quote(identifier_quote);
 
         // Execute each statement
         let mut last_result: Option<Box<dyn QueryResult>> = None;
@@ -202,35 +250,54 @@ impl Transaction {
 
         let tx = self.tx.as_mut().ok_or(Error::TransactionNotStarted)?;
 
+        // As in PostgreSQL, SET and SHOW don't count as queries
+        if !matches!(
+            statement,
+            Statement::SetTransaction(_) | Statement::ShowIsolationLevel(_)
+        ) {
+            self.ran_query = true;
+        }
+
         match statement {
             Statement::Insert(stmt) => {
                 let table_name = &stmt.table_name.value();
                 let mut table = tx.get_table(table_name)?;
                 let schema = table.schema().clone();
 
+                // Columns the INSERT leaves out take their DEFAULT, or NULL without one
+                let column_indices = target_column_indices(&schema, &stmt.columns)?;
+                let supplied = supplied_columns(schema.columns.len(), &column_indices);
+                let mut defaults = ColumnDefaults::new(&schema)?;
+                // Values are converted to the column types as in INSERT outside
+                // a transaction, failing on values that don't convert
+                let executor = self
+                    .executor
+                    .lock()
+                    .map_err(|_| Error::LockAcquisitionFailed("executor".to_string()))?;
+
                 let mut total_inserted = 0i64;
 
                 for row_values in &stmt.values {
-                    if row_values.len() > schema.columns.len() {
+                    if row_values.len() != column_indices.len() {
                         return Err(Error::InvalidArgumentMessage(format!(
                             "INSERT has {} columns but {} values",
-                            schema.columns.len(),
+                            column_indices.len(),
                             row_values.len()
                         )));
                     }
 
-                    let mut values = Vec::with_capacity(schema.columns.len());
-                    for (i, expr) in row_values.iter().enumerate() {
+                    let mut values = defaults.row(&supplied)?;
+                    for (expr, &idx) in row_values.iter().zip(&column_indices) {
+                        if matches!(expr, Expression::Default(_)) {
+                            values[idx] = defaults.value(idx)?;
+                            continue;
+                        }
                         // Use ExpressionEval for value expression evaluation
                         let mut eval = ExpressionEval::compile(expr, &[])?.with_context(ctx);
                         let val = eval.eval_slice(&[])?;
-                        let target_type = schema.columns[i].data_type;
-                        values.push(val.coerce_to_type(target_type));
-                    }
-
-                    // Pad missing columns
-                    while values.len() < schema.columns.len() {
-                        values.push(Value::null_unknown());
+                        let column = &schema.columns[idx];
+                        values[idx] =
+                            executor.coerce_column_value(val, column.data_type, &column.name)?;
                     }
 
                     // Validate foreign keys
@@ -269,83 +336,14 @@ impl Transaction {
 
                 Ok(Box::new(ExecResult::with_rows_affected(total_inserted)))
             }
-            Statement::Update(stmt) => {
-                use crate::executor::expression::{
-                    compile_expression, ExecuteContext, ExprVM, RowFilter, SharedProgram,
-                };
-
-                let table_name = &stmt.table_name.value();
-                let mut table = tx.get_table(table_name)?;
-                // Get column names owned to avoid borrow conflict with table.update()
-                let columns: Vec<String> = table.schema().column_names_owned().to_vec();
-
-                // Build the setter function that applies updates
-                let updates = stmt.updates.clone();
-
-                // OPTIMIZATION: Pre-compile WHERE clause (if present) using RowFilter
-                // CRITICAL: Must include context for parameter support
-                let where_filter: Option<RowFilter> = stmt
-                    .where_clause
-                    .as_ref()
-                    .map(|expr| RowFilter::new(expr, &columns).map(|f| f.with_context(ctx)))
-                    .transpose()?;
-
-                // OPTIMIZATION: Pre-compile update expressions and compute column indices
-                // CRITICAL: Return errors instead of silently skipping failed compilations
-                let compiled_updates: Vec<(usize, SharedProgram)> = updates
-                    .iter()
-                    .map(|(col_name, expr)| {
-                        let idx = columns
-                            .iter()
-                            .position(|c| c.eq_ignore_ascii_case(col_name))
-                            .ok_or_else(|| Error::ColumnNotFoundNamed(col_name.clone()))?;
-                        let program = compile_expression(expr, &columns)?;
-                        Ok((idx, program))
-                    })
-                    .collect::<Result<Vec<_>>>()?;
-
-                // Create VM for expression execution (reused for all rows)
-                let mut vm = ExprVM::new();
-
-                let mut setter = |row: Row| -> Result<(Row, bool)> {
-                    // Check WHERE clause if present (uses thread-local VM internally)
-                    if let Some(ref filter) = where_filter {
-                        if !filter.matches(&row) {
-                            return Ok((row, false));
-                        }
-                    }
-
-                    // Evaluate all expressions first (while we can still borrow row)
-                    let row_data = row.as_slice();
-                    let exec_ctx = ExecuteContext::new(row_data);
-
-                    // CRITICAL: Collect evaluated values, capturing any errors
-                    let mut updates_to_apply: Vec<(usize, Value)> =
-                        Vec::with_capacity(compiled_updates.len());
-                    for (idx, program) in compiled_updates.iter() {
-                        match vm.execute(program, &exec_ctx) {
-                            Ok(v) => updates_to_apply.push((*idx, v)),
-                            Err(e) => {
-                                return Err(e);
-                            }
-                        }
-                    }
-
-                    // Now apply updates - take ownership of row
-                    let mut new_values = row.into_values();
-                    for (idx, value) in updates_to_apply {
-                        new_values[idx] = value;
-                    }
-
-                    Ok((Row::from_values(new_values), true))
-                };
-
-                // Use None for where_expr since we handle WHERE in the setter
-                let updated_count = table.update(None, &mut setter)?;
-
-                Ok(Box::new(ExecResult::with_rows_affected(
-                    updated_count as i64,
-                )))
+            Statement::Update(_) => {
+                // UPDATE ... FROM, target aliases and type checks live in the
+                // executor, which runs the statement inside this transaction
+                let executor = self
+                    .executor
+                    .lock()
+                    .map_err(|_| Error::LockAcquisitionFailed("executor".to_string()))?;
+                executor.execute_in_transaction(statement, &mut self.tx, ctx)
             }
             Statement::Delete(stmt) => {
                 let table_name = &stmt.table_name.value();
@@ -415,6 +413,11 @@ impl Transaction {
                     .map(|expr| self.convert_to_storage_expression(expr, ctx))
                     .transpose()?;
 
+                // FOR UPDATE / FOR SHARE: lock the matching rows until commit
+                if let Some(mode) = stmt.lock {
+                    table.lock_rows(where_expr.as_deref(), mode)?;
+                }
+
                 // Scan table
                 let mut scanner = table.scan(&column_indices, where_expr.as_deref())?;
                 let mut rows = Vec::new();
@@ -444,8 +447,32 @@ impl Transaction {
                     result_rows,
                 )))
             }
+            Statement::SetTransaction(stmt) => {
+                if self.ran_query {
+                    return Err(Error::internal(
+                        "SET TRANSACTION ISOLATION LEVEL must be called before any query",
+                    ));
+                }
+                tx.set_isolation_level(isolation_level_from_sql(&stmt.isolation_level)?)?;
+                Ok(Box::new(ExecResult::empty()))
+            }
+            Statement::ShowIsolationLevel(_) => Ok(isolation_level_result(tx.isolation_level())),
+            Statement::Savepoint(stmt) => {
+                tx.create_savepoint(&stmt.savepoint_name.value)?;
+                Ok(Box::new(ExecResult::empty()))
+            }
+            Statement::Rollback(stmt) if stmt.savepoint_name.is_some() => {
+                if let Some(name) = &stmt.savepoint_name {
+                    tx.rollback_to_savepoint(&name.value)?;
+                }
+                Ok(Box::new(ExecResult::empty()))
+            }
+            Statement::ReleaseSavepoint(stmt) => {
+                tx.release_savepoint(&stmt.savepoint_name.value)?;
+                Ok(Box::new(ExecResult::empty()))
+            }
             _ => Err(Error::NotSupportedMessage(
-                "Only DML statements are supported in transactions".to_string(),
+                "Only DML and savepoint statements are supported in transactions".to_string(),
             )),
         }
     }
@@ -636,6 +663,45 @@ impl Transaction {
 
         Ok(())
     }
+
+    /// Create a savepoint
+    ///
+    /// Changes made after it can be undone with [`rollback_to`](Self::rollback_to)
+    /// while keeping the earlier work of the transaction. A savepoint with
+    /// the same name replaces the old one.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let mut tx = db.begin()?;
+    /// tx.execute("INSERT INTO users VALUES (1, 'Alice')", ())?;
+    /// tx.savepoint("sp1")?;
+    /// tx.execute("INSERT INTO users VALUES (2, 'Bob')", ())?;
+    /// tx.rollback_to("sp1")?; // only Alice remains
+    /// tx.commit()?;
+    /// ```
+    pub fn savepoint(&mut self, name: &str) -> Result<()> {
+        self.check_active()?;
+        let tx = self.tx.as_mut().ok_or(Error::TransactionNotStarted)?;
+        tx.create_savepoint(name)
+    }
+
+    /// Undo the changes made since a savepoint
+    ///
+    /// The savepoint stays and can be rolled back to again; savepoints
+    /// created after it are removed. The transaction stays open.
+    pub fn rollback_to(&mut self, name: &str) -> Result<()> {
+        self.check_active()?;
+        let tx = self.tx.as_mut().ok_or(Error::TransactionNotStarted)?;
+        tx.rollback_to_savepoint(name)
+    }
+
+    /// Remove a savepoint, keeping the changes made since it
+    pub fn release(&mut self, name: &str) -> Result<()> {
+        self.check_active()?;
+        let tx = self.tx.as_mut().ok_or(Error::TransactionNotStarted)?;
+        tx.release_savepoint(name)
+    }
 }
 
 impl Drop for Transaction {
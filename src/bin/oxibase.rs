@@ -31,7 +31,8 @@ use rustyline::history::DefaultHistory;
 use rustyline::validate::Validator;
 use rustyline::{Config, Context, EditMode, Editor, Helper};
 
-use oxibase::api::{Database, Transaction as ApiTransaction};
+use oxibase::api::output::{rows_to_json, write_csv};
+use oxibase::api::{Database, OutputFormat, Transaction as ApiTransaction};
 use oxibase::common::version::version;
 use oxibase::Value;
 
@@ -62,6 +63,7 @@ PERSISTENCE DSN PARAMETERS:\n\
   snapshot_interval=SECS      Snapshot interval in seconds (default: 300)\n\
   keep_snapshots=COUNT        Number of snapshots to keep (default: 5)\n\
   wal_max_size=BYTES          Max WAL file size before rotation (default: 67108864)\n\
+  checkpoint_wal_size=BYTES   WAL size that triggers a checkpoint (default: 268435456)\n\
   wal_buffer_size=BYTES       WAL buffer size (default: 65536)\n\
   wal_flush_trigger=BYTES     Buffer size to trigger flush (default: 32768)\n\
   commit_batch_size=COUNT     Commits to batch before sync (default: 100)\n\
@@ -82,10 +84,14 @@ struct Args {
     #[command(subcommand)]
     command: Option<Commands>,
 
-    /// Output results in JSON format (applies to repl and default modes)
+    /// Output results in JSON format (same as --format json)
     #[arg(short = 'j', long = "json", default_value = "false")]
     json_output: bool,
 
+    /// Output format for query results (table, csv or json)
+    #[arg(long = "format", value_name = "FORMAT", default_value = "table")]
+    format: OutputFormat,
+
     /// Suppress connection messages
     #[arg(short = 'q', long = "quiet", default_value = "false")]
     quiet: bool,
@@ -161,6 +167,14 @@ enum Commands {
         /// Host to bind to
         #[arg(long = "host", default_value = "127.0.0.1")]
         host: String,
+
+        /// Maximum number of open connections (0 = unlimited)
+        #[arg(long = "max-connections", default_value = "512")]
+        max_connections: usize,
+
+        /// Default result format of /api/sql (json or csv)
+        #[arg(long = "format", value_name = "FORMAT", default_value = "json")]
+        format: OutputFormat,
     },
 
     /// Install the Workspace GUI app templates and routes into the database
@@ -334,7 +348,7 @@ struct Cli {
     db: Database,
     tx: Option<ApiTransaction>,
     in_transaction: bool,
-    json_output: bool,
+    format: OutputFormat,
     limit: usize,
     #[allow(dead_code)]
     quiet: bool,
@@ -347,7 +361,7 @@ struct Cli {
 impl Cli {
     fn new(
         db: Database,
-        json_output: bool,
+        format: OutputFormat,
         limit: usize,
         quiet: bool,
         timeout_ms: u64,
@@ -371,7 +385,7 @@ impl Cli {
             db,
             tx: None,
             in_transaction: false,
-            json_output,
+            format,
             limit,
             quiet,
             timeout_ms,
@@ -398,8 +412,11 @@ impl Cli {
     fn run(&mut self) -> io::Result<()> {
         println!("Enter SQL commands, 'help' for assistance, or 'exit' to quit.");
         println!("Use Up/Down arrows for history, Ctrl+R to search history.");
-        if self.json_output {
-            println!("JSON output mode enabled.");
+        if self.format != OutputFormat::Table {
+            println!(
+                "{} output mode enabled.",
+                self.format.to_string().to_uppercase()
+            );
         }
         println!();
 
@@ -621,10 +638,10 @@ impl Cli {
 
         let row_count = all_rows.len();
 
-        if self.json_output {
-            self.output_json(&columns, &all_rows, row_count)?;
-        } else {
-            self.output_table(&columns, &all_rows, row_count)?;
+        match self.format {
+            OutputFormat::Json => output_json(&columns, &all_rows)?,
+            OutputFormat::Csv => output_csv(&columns, &all_rows)?,
+            OutputFormat::Table => self.output_table(&columns, &all_rows, row_count)?,
         }
 
         Ok(())
@@ -645,40 +662,18 @@ impl Cli {
             self.db.execute(query, ()).map_err(|e| e.to_string())?
         };
 
-        if self.json_output {
-            println!(r#"{{"rows_affected":{}}}"#, rows_affected);
-        } else {
-            let row_text = if rows_affected == 1 { "row" } else { "rows" };
-            println!("\x1b[1;32m{} {} affected\x1b[0m", rows_affected, row_text);
+        match self.format {
+            OutputFormat::Json => println!(r#"{{"rows_affected":{}}}"#, rows_affected),
+            OutputFormat::Csv => println!("rows_affected\n{}", rows_affected),
+            OutputFormat::Table => {
+                let row_text = if rows_affected == 1 { "row" } else { "rows" };
+                println!("\x1b[1;32m{} {} affected\x1b[0m", rows_affected, row_text);
+            }
         }
 
         Ok(())
     }
 
-    fn output_json(
-        &self,
-        columns: &[String],
-        rows: &[Vec<Value>],
-        row_count: usize,
-    ) -> Result<(), String> {
-        let json_rows: Vec<Vec<serde_json::Value>> = rows
-            .iter()
-            .map(|row| row.iter().map(value_to_json).collect())
-            .collect();
-
-        let result = serde_json::json!({
-            "columns": columns,
-            "rows": json_rows,
-            "count": row_count
-        });
-
-        println!(
-            "{}",
-            serde_json::to_string(&result).map_err(|e| e.to_string())?
-        );
-        Ok(())
-    }
-
     fn output_table(
         &self,
         columns: &[String],
@@ -959,20 +954,20 @@ fn main() {
     }
 
     // Determine the active command and db_path
-    let (db_path, is_serve) = match &args.command {
-        Some(Commands::Repl { db_path }) => (db_path.clone(), false),
+    let db_path = match &args.command {
+        Some(Commands::Repl { db_path }) => db_path.clone(),
         #[cfg(feature = "server")]
-        Some(Commands::Serve { db_path, .. }) => (db_path.clone(), true),
+        Some(Commands::Serve { db_path, .. }) => db_path.clone(),
         #[cfg(feature = "cli")]
-        Some(Commands::InstallWorkspace { db_path }) => (db_path.clone(), false),
-        None => ("memory://".to_string(), false), // Default to repl if no command is provided
+        Some(Commands::InstallWorkspace { db_path }) => db_path.clone(),
+        None => "memory://".to_string(), // Default to repl if no command is provided
     };
 
     // Build the DSN with optional query parameters
     let db_path = build_dsn(&db_path, &args);
 
     // Print version info
-    if !is_serve || !args.quiet {
+    if !args.quiet {
         println!("Oxibase v{}", version());
     }
 
@@ -1038,9 +1033,25 @@ fn main() {
         }
     }
 
+    let format = if args.json_output {
+        OutputFormat::Json
+    } else {
+        args.format
+    };
+
     match args.command {
         #[cfg(feature = "server")]
-        Some(Commands::Serve { port, host, .. }) => {
+        Some(Commands::Serve {
+            port,
+            host,
+            max_connections,
+            format: api_format,
+            ..
+        }) => {
+            if api_format == OutputFormat::Table {
+                eprintln!("Error: the server supports --format json or csv");
+                std::process::exit(1);
+            }
             println!("Server starting on {}:{}...", host, port);
 
             // Build a tokio runtime
@@ -1050,13 +1061,21 @@ fn main() {
                 .expect("Failed to build tokio runtime");
 
             rt.block_on(async {
-                let app = oxibase::server::create_router(db);
+                let app = oxibase::server::create_router_with_format(db, api_format);
                 let addr = format!("{}:{}", host, port);
                 let listener = tokio::net::TcpListener::bind(&addr)
                     .await
                     .expect("Failed to bind to port");
                 println!("Listening on {}", addr);
-                axum::serve(listener, app).await.expect("Server failed");
+                oxibase::server::serve(
+                    listener,
+                    app,
+                    max_connections,
+                    oxibase::server::shutdown_signal(),
+                )
+                .await
+                .expect("Server failed");
+                println!("Server stopped");
             });
             return;
         }
@@ -1072,14 +1091,9 @@ fn main() {
 
     // Handle execute flag - run single query and exit
     if let Some(ref sql) = args.execute {
-        if let Err(e) = execute_query_with_options(
-            &db,
-            sql,
-            args.json_output,
-            args.quiet,
-            args.limit,
-            args.timeout_ms,
-        ) {
+        if let Err(e) =
+            execute_query_with_options(&db, sql, format, args.quiet, args.limit, args.timeout_ms)
+        {
             eprintln!("Error: {}", e);
             std::process::exit(1);
         }
@@ -1091,7 +1105,7 @@ fn main() {
         if let Err(e) = execute_from_file(
             &db,
             filename,
-            args.json_output,
+            format,
             args.quiet,
             args.limit,
             args.timeout_ms,
@@ -1106,13 +1120,7 @@ fn main() {
     let is_pipe = !std::io::stdin().is_terminal();
 
     if is_pipe {
-        if let Err(e) = execute_piped_input(
-            &db,
-            args.json_output,
-            args.quiet,
-            args.limit,
-            args.timeout_ms,
-        ) {
+        if let Err(e) = execute_piped_input(&db, format, args.quiet, args.limit, args.timeout_ms) {
             eprintln!("Error: {}", e);
             std::process::exit(1);
         }
@@ -1120,13 +1128,7 @@ fn main() {
     }
 
     // Interactive mode
-    let mut cli = match Cli::new(
-        db,
-        args.json_output,
-        args.limit,
-        args.quiet,
-        args.timeout_ms,
-    ) {
+    let mut cli = match Cli::new(db, format, args.limit, args.quiet, args.timeout_ms) {
         Ok(cli) => cli,
         Err(e) => {
             eprintln!("Error initializing CLI: {}", e);
@@ -1143,7 +1145,7 @@ fn main() {
 fn execute_from_file(
     db: &Database,
     filename: &str,
-    json_output: bool,
+    format: OutputFormat,
     quiet: bool,
     row_limit: usize,
     timeout_ms: u64,
@@ -1180,14 +1182,9 @@ fn execute_from_file(
                         continue;
                     }
 
-                    if let Err(e) = execute_query_with_options(
-                        db,
-                        stmt,
-                        json_output,
-                        quiet,
-                        row_limit,
-                        timeout_ms,
-                    ) {
+                    if let Err(e) =
+                        execute_query_with_options(db, stmt, format, quiet, row_limit, timeout_ms)
+                    {
                         eprintln!("Error: {}", e);
                     }
                 }
@@ -1210,7 +1207,7 @@ fn execute_from_file(
                 }
 
                 if let Err(e) =
-                    execute_query_with_options(db, stmt, json_output, quiet, row_limit, timeout_ms)
+                    execute_query_with_options(db, stmt, format, quiet, row_limit, timeout_ms)
                 {
                     eprintln!("Error: {}", e);
                 }
@@ -1223,7 +1220,7 @@ fn execute_from_file(
 
 fn execute_piped_input(
     db: &Database,
-    json_output: bool,
+    format: OutputFormat,
     quiet: bool,
     row_limit: usize,
     timeout_ms: u64,
@@ -1260,16 +1257,11 @@ fn execute_piped_input(
                     }
 
                     let start = Instant::now();
-                    if let Err(e) = execute_query_with_options(
-                        db,
-                        stmt,
-                        json_output,
-                        quiet,
-                        row_limit,
-                        timeout_ms,
-                    ) {
+                    if let Err(e) =
+                        execute_query_with_options(db, stmt, format, quiet, row_limit, timeout_ms)
+                    {
                         eprintln!("Error: {}", e);
-                    } else if !json_output && !quiet {
+                    } else if format == OutputFormat::Table && !quiet {
                         println!("Query executed in {:?}", start.elapsed());
                     }
                 }
@@ -1293,10 +1285,10 @@ fn execute_piped_input(
 
                 let start = Instant::now();
                 if let Err(e) =
-                    execute_query_with_options(db, stmt, json_output, quiet, row_limit, timeout_ms)
+                    execute_query_with_options(db, stmt, format, quiet, row_limit, timeout_ms)
                 {
                     eprintln!("Error: {}", e);
-                } else if !json_output && !quiet {
+                } else if format == OutputFormat::Table && !quiet {
                     println!("Query executed in {:?}", start.elapsed());
                 }
             }
@@ -1309,7 +1301,7 @@ fn execute_piped_input(
 fn execute_query_with_options(
     db: &Database,
     query: &str,
-    json_output: bool,
+    format: OutputFormat,
     quiet: bool,
     row_limit: usize,
     timeout_ms: u64,
@@ -1366,10 +1358,10 @@ fn execute_query_with_options(
 
         let row_count = all_rows.len();
 
-        if json_output {
-            output_json(&columns, &all_rows, row_count)?;
-        } else {
-            output_table(&columns, &all_rows, row_count, row_limit, quiet)?;
+        match format {
+            OutputFormat::Json => output_json(&columns, &all_rows)?,
+            OutputFormat::Csv => output_csv(&columns, &all_rows)?,
+            OutputFormat::Table => output_table(&columns, &all_rows, row_count, row_limit, quiet)?,
         }
     } else {
         // Execute a non-query statement
@@ -1380,10 +1372,11 @@ fn execute_query_with_options(
             db.execute(&sql, params).map_err(|e| e.to_string())?
         };
 
-        if json_output {
-            println!(r#"{{"rows_affected":{}}}"#, rows_affected);
-        } else if !quiet {
-            println!("{} rows affected", rows_affected);
+        match format {
+            OutputFormat::Json => println!(r#"{{"rows_affected":{}}}"#, rows_affected),
+            OutputFormat::Csv => println!("rows_affected\n{}", rows_affected),
+            OutputFormat::Table if !quiet => println!("{} rows affected", rows_affected),
+            OutputFormat::Table => {}
         }
     }
 
@@ -1440,25 +1433,18 @@ fn convert_param_value(value: &str) -> Value {
     Value::text(value)
 }
 
-fn output_json(columns: &[String], rows: &[Vec<Value>], row_count: usize) -> Result<(), String> {
-    let json_rows: Vec<Vec<serde_json::Value>> = rows
-        .iter()
-        .map(|row| row.iter().map(value_to_json).collect())
-        .collect();
-
-    let result = serde_json::json!({
-        "columns": columns,
-        "rows": json_rows,
-        "count": row_count
-    });
-
+fn output_json(columns: &[String], rows: &[Vec<Value>]) -> Result<(), String> {
     println!(
         "{}",
-        serde_json::to_string(&result).map_err(|e| e.to_string())?
+        serde_json::to_string(&rows_to_json(columns, rows)).map_err(|e| e.to_string())?
     );
     Ok(())
 }
 
+fn output_csv(columns: &[String], rows: &[Vec<Value>]) -> Result<(), String> {
+    write_csv(&mut io::stdout().lock(), columns, rows).map_err(|e| e.to_string())
+}
+
 fn output_table(
     columns: &[String],
     rows: &[Vec<Value>],
@@ -1545,32 +1531,13 @@ fn format_value(value: &Value) -> String {
     match value {
         Value::Null(_) => "NULL".to_string(),
         Value::Integer(i) => i.to_string(),
-        Value::Float(f) => {
-            if *f == f.trunc() {
-                format!("{:.1}", f)
-            } else {
-                format!("{:.4}", f)
-                    .trim_end_matches('0')
-                    .trim_end_matches('.')
-                    .to_string()
-            }
-        }
         Value::Text(s) => s.to_string(),
         Value::Boolean(b) => if *b { "true" } else { "false" }.to_string(),
         Value::Timestamp(ts) => ts.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
         Value::Json(s) => s.to_string(),
-    }
-}
-
-fn value_to_json(value: &Value) -> serde_json::Value {
-    match value {
-        Value::Null(_) => serde_json::Value::Null,
-        Value::Integer(i) => serde_json::json!(i),
-        Value::Float(f) => serde_json::json!(f),
-        Value::Text(s) => serde_json::json!(s.as_ref()),
-        Value::Boolean(b) => serde_json::json!(b),
-        Value::Timestamp(ts) => serde_json::json!(ts.format("%Y-%m-%dT%H:%M:%SZ").to_string()),
-        Value::Json(s) => serde_json::json!(s.as_ref()),
+        // Floats render as in CSV and JSON output: NaN and Infinity by name,
+        // digits as the session's float_display_precision left them
+        Value::Float(_) | Value::Interval(_) | Value::Blob(_) => value.to_string(),
     }
 }
 
@@ -1583,6 +1550,8 @@ fn split_sql_statements(input: &str) -> Vec<String> {
     let mut in_double_quotes = false;
     let mut in_line_comment = false;
     let mut in_block_comment = false;
+    // Optimizer hints (/*+ ... */) are comments the parser reads, so they are kept
+    let mut in_hint_comment = false;
 
     let chars: Vec<char> = input.chars().collect();
     let mut i = 0;
@@ -1633,10 +1602,17 @@ fn split_sql_statements(input: &str) -> Vec<String> {
         // Handle end of block comment
         if in_block_comment {
             if char == '*' && i + 1 < chars.len() && chars[i + 1] == '/' {
+                if in_hint_comment {
+                    current_statement.push_str("*/");
+                }
                 in_block_comment = false;
+                in_hint_comment = false;
                 i += 2;
                 continue;
             }
+            if in_hint_comment {
+                current_statement.push(char);
+            }
             i += 1;
             continue;
         }
@@ -1649,6 +1625,10 @@ fn split_sql_statements(input: &str) -> Vec<String> {
             && chars[i + 1] == '*'
         {
             in_block_comment = true;
+            in_hint_comment = i + 2 < chars.len() && chars[i + 2] == '+';
+            if in_hint_comment {
+                current_statement.push_str("/*");
+            }
             i += 2;
             continue;
         }
@@ -1803,4 +1783,41 @@ mod tests {
             .iter()
             .any(|c| c.replacement == "public.my_awesome_table "));
     }
+
+    #[test]
+    fn test_split_sql_statements_keeps_hints() {
+        let statements = split_sql_statements(
+            "SELECT /*+ INDEX(users idx_email) */ * FROM users; /* note; */ SELECT 1",
+        );
+        assert_eq!(
+            statements,
+            vec![
+                "SELECT /*+ INDEX(users idx_email) */ * FROM users".to_string(),
+                "  SELECT 1".to_string(),
+            ]
+        );
+
+        // The hint reaches the planner when run through the CLI
+        let db = Database::open_in_memory().unwrap();
+        db.execute(
+            "CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT)",
+            (),
+        )
+        .unwrap();
+        db.execute("CREATE INDEX idx_email ON users (email)", ())
+            .unwrap();
+        let plan = |sql: &str| -> String {
+            let statement = &split_sql_statements(sql)[0];
+            db.query(statement, ())
+                .unwrap()
+                .map(|row| row.unwrap().get::<String>(0).unwrap())
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        let default_plan = plan("EXPLAIN SELECT * FROM users WHERE email = 'a';");
+        assert!(default_plan.contains("idx_email"), "{}", default_plan);
+        let hinted_plan =
+            plan("EXPLAIN SELECT /*+ NO_INDEX(users) */ * FROM users WHERE email = 'a';");
+        assert!(!hinted_plan.contains("idx_email"), "{}", hinted_plan);
+    }
 }
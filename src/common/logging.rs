@@ -27,7 +27,6 @@ use tracing_subscriber::layer::Context;
 use tracing_subscriber::Layer;
 
 use crate::core::Value;
-use crate::storage::mvcc::engine::MVCCEngine;
 use crate::storage::traits::Engine;
 
 thread_local! {
@@ -182,7 +181,7 @@ impl tracing::field::Visit for LogVisitor {
 
 /// Start the background flusher thread.
 pub fn start_log_flusher(
-    engine: Arc<MVCCEngine>,
+    engine: Arc<dyn Engine>,
     receiver: Receiver<LogEntry>,
 ) -> (
     Arc<std::sync::atomic::AtomicBool>,
@@ -229,7 +228,7 @@ pub fn start_log_flusher(
                 }
 
                 // Insert into database
-                if let Err(e) = insert_log_batch(&engine, &entries) {
+                if let Err(e) = insert_log_batch(engine.as_ref(), &entries) {
                     // We can't use tracing::error! here because it would loop if IS_LOG_FLUSHER wasn't set.
                     // Even though it is set, printing to stderr is safer.
                     eprintln!("Failed to flush internal logs: {}", e);
@@ -241,7 +240,7 @@ pub fn start_log_flusher(
     (shutdown_flag, handle)
 }
 
-fn insert_log_batch(engine: &MVCCEngine, entries: &[LogEntry]) -> crate::core::Result<()> {
+fn insert_log_batch(engine: &dyn Engine, entries: &[LogEntry]) -> crate::core::Result<()> {
     let mut tx = engine.begin_transaction()?;
 
     // Get the system.logs table
@@ -27,7 +27,6 @@ use tracing_subscriber::layer::Context;
 use tracing_subscriber::Layer;
 
 use crate::core::Value;
-use crate::storage::mvcc::engine::MVCCEngine;
 use crate::storage::traits::Engine;
 
 thread_local! {
@@ -200,7 +199,7 @@ impl tracing::field::Visit for MetricVisitor {
 
 /// Start the background flusher thread.
 pub fn start_metrics_flusher(
-    engine: Arc<MVCCEngine>,
+    engine: Arc<dyn Engine>,
     receiver: Receiver<MetricEvent>,
 ) -> (
     Arc<std::sync::atomic::AtomicBool>,
@@ -299,7 +298,7 @@ pub fn start_metrics_flusher(
                 }
 
                 // Insert into database
-                if let Err(e) = insert_metric_batch(&engine, &entries) {
+                if let Err(e) = insert_metric_batch(engine.as_ref(), &entries) {
                     eprintln!("Failed to flush internal metrics: {}", e);
                 }
             }
@@ -309,7 +308,7 @@ pub fn start_metrics_flusher(
     (shutdown_flag, handle)
 }
 
-fn insert_metric_batch(engine: &MVCCEngine, entries: &[MetricEvent]) -> crate::core::Result<()> {
+fn insert_metric_batch(engine: &dyn Engine, entries: &[MetricEvent]) -> crate::core::Result<()> {
     let mut tx = engine.begin_transaction()?;
 
     // Get the system.metrics table
@@ -20,12 +20,15 @@
 //! - [`version`] - Version information and constants
 //! - [`buffer_pool`] - Self-tuning buffer pool for efficient memory reuse
 //! - [`int64_map`] - Fast hash maps for integer keys
+//! - `password` - Password hashing (requires the `password` feature)
 
 pub mod buffer_pool;
 pub mod debug;
 pub mod int64_map;
 pub mod logging;
 pub mod metrics;
+#[cfg(feature = "password")]
+pub mod password;
 pub mod tracing;
 pub mod version;
 
@@ -0,0 +1,68 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Password hashing
+//!
+//! Passwords are hashed with Argon2id and a random salt. The result is a PHC
+//! string (`$argon2id$v=19$m=...,t=...,p=...$salt$hash`) that carries its own
+//! parameters, so hashes stay verifiable if the defaults change later.
+
+use argon2::password_hash::phc::PasswordHash;
+use argon2::password_hash::{Error as HashError, PasswordHasher, PasswordVerifier};
+use argon2::Argon2;
+
+use crate::core::{Error, Result};
+
+/// Hashes a password into a PHC string suitable for storing
+pub fn hash_password(password: &str) -> Result<String> {
+    Argon2::default()
+        .hash_password(password.as_bytes())
+        .map(|hash| hash.to_string())
+        .map_err(|e| Error::internal(format!("failed to hash password: {}", e)))
+}
+
+/// Checks a password against a hash produced by [`hash_password`]
+///
+/// Returns `Ok(false)` for a wrong password and an error if `hash` is not a
+/// valid PHC string.
+pub fn verify_password(password: &str, hash: &str) -> Result<bool> {
+    let parsed = PasswordHash::new(hash)
+        .map_err(|e| Error::invalid_argument(format!("invalid password hash: {}", e)))?;
+    match Argon2::default().verify_password(password.as_bytes(), &parsed) {
+        Ok(()) => Ok(true),
+        Err(HashError::PasswordInvalid) => Ok(false),
+        Err(e) => Err(Error::invalid_argument(format!(
+            "invalid password hash: {}",
+            e
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_and_verify() {
+        let hash = hash_password("hunter2").unwrap();
+        assert!(hash.starts_with("$argon2id$"));
+        assert!(!hash.contains("hunter2"));
+        assert!(verify_password("hunter2", &hash).unwrap());
+        assert!(!verify_password("hunter3", &hash).unwrap());
+
+        // Salted: the same password never hashes the same way twice
+        assert_ne!(hash, hash_password("hunter2").unwrap());
+        assert!(verify_password("hunter2", "plaintext").is_err());
+    }
+}
@@ -28,7 +28,6 @@ use tracing_subscriber::layer::Context;
 use tracing_subscriber::Layer;
 
 use crate::core::Value;
-use crate::storage::mvcc::engine::MVCCEngine;
 use crate::storage::traits::Engine;
 
 thread_local! {
@@ -256,7 +255,7 @@ impl tracing::field::Visit for AttributeVisitor {
 
 /// Start the background flusher thread.
 pub fn start_trace_flusher(
-    engine: Arc<MVCCEngine>,
+    engine: Arc<dyn Engine>,
     receiver: Receiver<SpanEvent>,
 ) -> (
     Arc<std::sync::atomic::AtomicBool>,
@@ -302,7 +301,7 @@ pub fn start_trace_flusher(
                 }
 
                 // Insert into database
-                if let Err(e) = insert_trace_batch(&engine, &entries) {
+                if let Err(e) = insert_trace_batch(engine.as_ref(), &entries) {
                     eprintln!("Failed to flush internal traces: {}", e);
                 }
             }
@@ -312,7 +311,7 @@ pub fn start_trace_flusher(
     (shutdown_flag, handle)
 }
 
-fn insert_trace_batch(engine: &MVCCEngine, entries: &[SpanEvent]) -> crate::core::Result<()> {
+fn insert_trace_batch(engine: &dyn Engine, entries: &[SpanEvent]) -> crate::core::Result<()> {
     let mut tx = engine.begin_transaction()?;
 
     // Get the system.traces table
@@ -0,0 +1,180 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversions between Oxibase values and Apache Arrow arrays
+//!
+//! | Oxibase     | Arrow                                         |
+//! |-------------|-----------------------------------------------|
+//! | `INTEGER`   | `Int64`                                       |
+//! | `FLOAT`     | `Float64`                                     |
+//! | `TEXT`      | `Utf8`                                        |
+//! | `BOOLEAN`   | `Boolean`                                     |
+//! | `TIMESTAMP` | `Timestamp(Microsecond, "UTC")`               |
+//! | `JSON`      | `Utf8` tagged with the `arrow.json` extension |
+//! | `INTERVAL`  | `Duration(Microsecond)`                       |
+//! | `BLOB`      | `Binary`                                      |
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::builder::{
+    BinaryBuilder, BooleanBuilder, DurationMicrosecondBuilder, Float64Builder, Int64Builder,
+    StringBuilder, TimestampMicrosecondBuilder,
+};
+use arrow_array::ArrayRef;
+use arrow_schema::{ArrowError, DataType as ArrowType, Field, TimeUnit};
+
+use super::{DataType, Value};
+
+/// Field metadata key for Arrow extension types
+pub(crate) const EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+
+/// Canonical Arrow extension type name for JSON stored as UTF-8
+pub(crate) const JSON_EXTENSION_NAME: &str = "arrow.json";
+
+/// Arrow type used for a column of the given Oxibase type
+pub(crate) fn arrow_type(data_type: DataType) -> ArrowType {
+    match data_type {
+        DataType::Integer => ArrowType::Int64,
+        DataType::Float => ArrowType::Float64,
+        DataType::Boolean => ArrowType::Boolean,
+        DataType::Timestamp => ArrowType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+        DataType::Interval => ArrowType::Duration(TimeUnit::Microsecond),
+        DataType::Blob => ArrowType::Binary,
+        DataType::Text | DataType::Json | DataType::Null => ArrowType::Utf8,
+    }
+}
+
+/// Nullable Arrow field for a column of the given Oxibase type
+pub(crate) fn arrow_field(name: &str, data_type: DataType) -> Field {
+    let field = Field::new(name, arrow_type(data_type), true);
+    if data_type == DataType::Json {
+        field.with_metadata(HashMap::from([(
+            EXTENSION_NAME_KEY.to_string(),
+            JSON_EXTENSION_NAME.to_string(),
+        )]))
+    } else {
+        field
+    }
+}
+
+fn type_mismatch(field: &Field, value: &Value) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "column '{}': cannot convert {} value to Arrow {}",
+        field.name(),
+        value.data_type(),
+        field.data_type()
+    ))
+}
+
+/// Build an Arrow array for `field` from a column of values
+///
+/// Missing values (`None`) and NULLs become Arrow nulls. Integers are
+/// widened into `Float64` columns and any value is accepted by a `Utf8`
+/// column in its display form; other mismatches are errors.
+pub(crate) fn values_to_array<'a>(
+    field: &Field,
+    len: usize,
+    values: impl Iterator<Item = Option<&'a Value>>,
+) -> std::result::Result<ArrayRef, ArrowError> {
+    let array: ArrayRef = match field.data_type() {
+        ArrowType::Int64 => {
+            let mut builder = Int64Builder::with_capacity(len);
+            for value in values {
+                match value {
+                    Some(Value::Integer(v)) => builder.append_value(*v),
+                    None | Some(Value::Null(_)) => builder.append_null(),
+                    Some(other) => return Err(type_mismatch(field, other)),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        ArrowType::Float64 => {
+            let mut builder = Float64Builder::with_capacity(len);
+            for value in values {
+                match value {
+                    Some(Value::Float(v)) => builder.append_value(*v),
+                    Some(Value::Integer(v)) => builder.append_value(*v as f64),
+                    None | Some(Value::Null(_)) => builder.append_null(),
+                    Some(other) => return Err(type_mismatch(field, other)),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        ArrowType::Boolean => {
+            let mut builder = BooleanBuilder::with_capacity(len);
+            for value in values {
+                match value {
+                    Some(Value::Boolean(v)) => builder.append_value(*v),
+                    None | Some(Value::Null(_)) => builder.append_null(),
+                    Some(other) => return Err(type_mismatch(field, other)),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        ArrowType::Timestamp(TimeUnit::Microsecond, tz) => {
+            let mut builder = TimestampMicrosecondBuilder::with_capacity(len);
+            for value in values {
+                match value {
+                    Some(Value::Timestamp(ts)) => builder.append_value(ts.timestamp_micros()),
+                    None | Some(Value::Null(_)) => builder.append_null(),
+                    Some(other) => return Err(type_mismatch(field, other)),
+                }
+            }
+            Arc::new(builder.finish().with_timezone_opt(tz.clone()))
+        }
+        ArrowType::Duration(TimeUnit::Microsecond) => {
+            let mut builder = DurationMicrosecondBuilder::with_capacity(len);
+            for value in values {
+                match value {
+                    Some(Value::Interval(v)) => builder.append_value(*v),
+                    None | Some(Value::Null(_)) => builder.append_null(),
+                    Some(other) => return Err(type_mismatch(field, other)),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        ArrowType::Binary => {
+            let mut builder = BinaryBuilder::with_capacity(len, len * 16);
+            for value in values {
+                match value {
+                    Some(Value::Blob(b)) => builder.append_value(b),
+                    Some(Value::Text(s)) => builder.append_value(s.as_bytes()),
+                    None | Some(Value::Null(_)) => builder.append_null(),
+                    Some(other) => return Err(type_mismatch(field, other)),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        ArrowType::Utf8 => {
+            // Text and JSON as-is, anything else in its display form
+            let mut builder = StringBuilder::with_capacity(len, len * 16);
+            for value in values {
+                match value {
+                    Some(Value::Text(s)) | Some(Value::Json(s)) => builder.append_value(s),
+                    None | Some(Value::Null(_)) => builder.append_null(),
+                    Some(other) => builder.append_value(other.to_string()),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "export to Arrow {}",
+                other
+            )))
+        }
+    };
+    Ok(array)
+}
@@ -111,6 +111,12 @@ pub enum Error {
         got: usize,
     },
 
+    /// Bytes stored into a TEXT column are not valid UTF-8
+    #[error(
+        "invalid UTF-8 byte sequence for TEXT column {column}, use a BLOB column for binary data"
+    )]
+    InvalidUtf8 { column: String },
+
     // =========================================================================
     // Constraint errors
     // =========================================================================
@@ -134,8 +140,15 @@ pub enum Error {
     },
 
     /// CHECK constraint violation
-    #[error("CHECK constraint failed for column {column}: {expression}")]
-    CheckConstraintViolation { column: String, expression: String },
+    #[error(
+        "CHECK constraint failed for column {column}: {expression}{}",
+        .constraint.as_ref().map(|name| format!(" (constraint {})", name)).unwrap_or_default()
+    )]
+    CheckConstraintViolation {
+        column: String,
+        expression: String,
+        constraint: Option<String>,
+    },
 
     /// Referential integrity violation (foreign key)
     #[error("referential integrity violation: {message}")]
@@ -230,6 +243,12 @@ pub enum Error {
     #[error("view '{0}' not found")]
     ViewNotFound(String),
 
+    /// DROP ... RESTRICT refused because other objects depend on the target
+    #[error(
+        "cannot drop {object} because other objects depend on it: {dependents} (use CASCADE to drop them too)"
+    )]
+    DependentObjectsExist { object: String, dependents: String },
+
     // =========================================================================
     // Function errors
     // =========================================================================
@@ -241,6 +260,10 @@ pub enum Error {
     #[error("function '{0}' not found")]
     FunctionNotFound(String),
 
+    /// An unqualified DROP FUNCTION matched several overloads
+    #[error("function name '{name}' is not unique, specify the argument types: {candidates}")]
+    AmbiguousFunction { name: String, candidates: String },
+
     // =========================================================================
     // Lock errors
     // =========================================================================
@@ -263,6 +286,15 @@ pub enum Error {
     #[error("column index {index} out of bounds")]
     ColumnIndexOutOfBounds { index: usize },
 
+    /// Number of bound parameters does not match the query's placeholders
+    #[error("parameter count mismatch: query expects {expected} parameters, got {got}")]
+    ParameterCountMismatch { expected: usize, got: usize },
+
+    /// A prepared statement refers to a table or view that was altered,
+    /// renamed or dropped after it was prepared; prepare it again
+    #[error("prepared statement is stale: '{table}' changed since it was prepared")]
+    PlanStale { table: String },
+
     // =========================================================================
     // WAL errors
     // =========================================================================
@@ -371,6 +403,14 @@ pub enum Error {
     #[error("query cancelled")]
     QueryCancelled,
 
+    /// Query exceeded its timeout (milliseconds)
+    #[error("query timed out after {0}ms")]
+    QueryTimeout(u64),
+
+    /// A write would push estimated memory usage past `max_memory` (bytes)
+    #[error("out of memory: write needs {needed} bytes, {used} of {limit} bytes in use")]
+    OutOfMemory { limit: u64, used: u64, needed: u64 },
+
     /// Operation not supported (with message)
     #[error("not supported: {0}")]
     NotSupportedMessage(String),
@@ -382,6 +422,13 @@ pub enum Error {
     /// Invalid argument (string variant)
     #[error("invalid argument: {0}")]
     InvalidArgumentMessage(String),
+
+    /// An error with the statement context added under
+    /// `SET error_verbosity = 'verbose'`; `context` holds the extra lines
+    ///
+    /// Match on [`Error::root`] to check the kind of error in either mode.
+    #[error("{error}{context}")]
+    Detailed { error: Box<Error>, context: String },
 }
 
 impl Error {
@@ -390,6 +437,11 @@ impl Error {
         Error::TableColumnsNotMatch { expected, got }
     }
 
+    /// Create a new ParameterCountMismatch error
+    pub fn parameter_count_mismatch(expected: usize, got: usize) -> Self {
+        Error::ParameterCountMismatch { expected, got }
+    }
+
     /// Create a new ValueTooLong error
     pub fn value_too_long(column: impl Into<String>, max: usize, got: usize) -> Self {
         Error::ValueTooLong {
@@ -399,6 +451,13 @@ impl Error {
         }
     }
 
+    /// Create a new InvalidUtf8 error
+    pub fn invalid_utf8(column: impl Into<String>) -> Self {
+        Error::InvalidUtf8 {
+            column: column.into(),
+        }
+    }
+
     /// Create a new NotNullConstraint error
     pub fn not_null_constraint(column: impl Into<String>) -> Self {
         Error::NotNullConstraint {
@@ -484,10 +543,30 @@ impl Error {
         }
     }
 
+    /// Create a new PlanStale error
+    pub fn plan_stale(table: impl Into<String>) -> Self {
+        Error::PlanStale {
+            table: table.into(),
+        }
+    }
+
+    /// The underlying error, without any verbose context
+    ///
+    /// Under `SET error_verbosity = 'verbose'` statement errors come wrapped
+    /// in [`Error::Detailed`], so `matches!(err, Error::QueryTimeout(_))`
+    /// only holds in terse mode while
+    /// `matches!(err.root(), Error::QueryTimeout(_))` holds in both.
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Detailed { error, .. } => error.root(),
+            other => other,
+        }
+    }
+
     /// Check if this is a "not found" type error
     pub fn is_not_found(&self) -> bool {
         matches!(
-            self,
+            self.root(),
             Error::TableNotFound
                 | Error::ColumnNotFound
                 | Error::ColumnNotFoundByName { .. }
@@ -502,7 +581,7 @@ impl Error {
     /// Check if this is a constraint violation error
     pub fn is_constraint_violation(&self) -> bool {
         matches!(
-            self,
+            self.root(),
             Error::NotNullConstraint { .. }
                 | Error::PrimaryKeyConstraint { .. }
                 | Error::UniqueConstraint { .. }
@@ -514,7 +593,7 @@ impl Error {
     /// Check if this is a transaction-related error
     pub fn is_transaction_error(&self) -> bool {
         matches!(
-            self,
+            self.root(),
             Error::TransactionNotStarted
                 | Error::TransactionAlreadyStarted
                 | Error::TransactionEnded
@@ -588,6 +667,12 @@ mod tests {
             err.to_string(),
             "unique constraint failed for index idx_email on column email with value test@example.com"
         );
+
+        let err = Error::parameter_count_mismatch(2, 1);
+        assert_eq!(
+            err.to_string(),
+            "parameter count mismatch: query expects 2 parameters, got 1"
+        );
     }
 
     #[test]
@@ -25,20 +25,26 @@
 //! - [`SchemaColumn`] - Column definition
 //! - [`Error`] - Error types for all storage operations
 
+#[cfg(feature = "arrow")]
+pub(crate) mod arrow;
 pub mod error;
 pub mod row;
 pub mod schema;
 pub mod sequence;
 pub mod types;
 pub mod value;
+pub mod view;
 
 // Re-export main types for convenience
 pub use error::{Error, Result};
 pub use row::Row;
-pub use schema::{Schema, SchemaBuilder, SchemaColumn};
-pub use sequence::{SequenceOptions, SequenceState};
-pub use types::{DataType, IndexEntry, IndexType, IsolationLevel, Operator};
-pub use value::{parse_timestamp, Value};
+pub use schema::{CheckConstraint, IndexedPrimaryKey, Schema, SchemaBuilder, SchemaColumn};
+pub use sequence::{SequenceOptions, SequenceState, SEQUENCE_RESERVE_BLOCK};
+pub use types::{
+    DataType, IndexEntry, IndexType, IsolationLevel, Operator, RowLockMode, TransactionStatus,
+};
+pub use value::{format_blob, format_interval, parse_blob, parse_interval, parse_timestamp, Value};
+pub use view::ViewDefinition;
 
 #[cfg(test)]
 mod integration_tests {
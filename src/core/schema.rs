@@ -37,6 +37,54 @@ pub struct ForeignKeyMetadata {
     pub on_update: ReferentialAction,
 }
 
+/// A PRIMARY KEY enforced by a unique index instead of the row id: a
+/// composite key, or a single key column that is not INTEGER
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexedPrimaryKey {
+    /// Name of the unique index enforcing the key
+    pub index_name: String,
+
+    /// Key columns, in key order
+    pub columns: Vec<String>,
+}
+
+/// A CHECK constraint on a column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckConstraint {
+    /// Constraint name (CONSTRAINT name CHECK ...), None if unnamed
+    pub name: Option<String>,
+
+    /// Constraint expression as a string (to be parsed and evaluated on write)
+    pub expr: String,
+}
+
+impl CheckConstraint {
+    /// Create an unnamed CHECK constraint
+    pub fn new(expr: impl Into<String>) -> Self {
+        Self {
+            name: None,
+            expr: expr.into(),
+        }
+    }
+
+    /// Create a named CHECK constraint
+    pub fn named(name: impl Into<String>, expr: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            expr: expr.into(),
+        }
+    }
+}
+
+impl fmt::Display for CheckConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ref name) = self.name {
+            write!(f, "CONSTRAINT {} ", name)?;
+        }
+        write!(f, "CHECK ({})", self.expr)
+    }
+}
+
 /// A column definition in a table schema
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SchemaColumn {
@@ -64,8 +112,14 @@ pub struct SchemaColumn {
     /// Pre-computed default value for schema evolution (used when adding column to existing rows)
     pub default_value: Option<super::Value>,
 
-    /// CHECK constraint expression as a string (to be parsed and evaluated during INSERT)
-    pub check_expr: Option<String>,
+    /// CHECK constraints, each evaluated on write
+    pub checks: Vec<CheckConstraint>,
+
+    /// Generation expression for a GENERATED ALWAYS AS column, computed from the row on write
+    pub generated_expr: Option<String>,
+
+    /// Values are stored LZ4-compressed and decompressed on read (COMPRESSED)
+    pub compressed: bool,
 }
 
 impl SchemaColumn {
@@ -86,7 +140,9 @@ impl SchemaColumn {
             auto_increment: false,
             default_expr: None,
             default_value: None,
-            check_expr: None,
+            checks: Vec::new(),
+            generated_expr: None,
+            compressed: false,
         }
     }
 
@@ -100,7 +156,7 @@ impl SchemaColumn {
         primary_key: bool,
         auto_increment: bool,
         default_expr: Option<String>,
-        check_expr: Option<String>,
+        checks: Vec<CheckConstraint>,
     ) -> Self {
         Self {
             id,
@@ -111,7 +167,9 @@ impl SchemaColumn {
             auto_increment,
             default_expr,
             default_value: None,
-            check_expr,
+            checks,
+            generated_expr: None,
+            compressed: false,
         }
     }
 
@@ -126,7 +184,7 @@ impl SchemaColumn {
         auto_increment: bool,
         default_expr: Option<String>,
         default_value: Option<super::Value>,
-        check_expr: Option<String>,
+        checks: Vec<CheckConstraint>,
     ) -> Self {
         Self {
             id,
@@ -137,7 +195,9 @@ impl SchemaColumn {
             auto_increment,
             default_expr,
             default_value,
-            check_expr,
+            checks,
+            generated_expr: None,
+            compressed: false,
         }
     }
 
@@ -196,6 +256,10 @@ pub struct Schema {
     /// List of tables that have foreign keys referencing this table
     pub referenced_by: Vec<String>,
 
+    /// PRIMARY KEY enforced by a unique index, if the key is not a single
+    /// INTEGER column flagged with `primary_key`
+    pub indexed_primary_key: Option<IndexedPrimaryKey>,
+
     /// Creation timestamp
     pub created_at: DateTime<Utc>,
 
@@ -223,6 +287,7 @@ impl Clone for Schema {
             columns: self.columns.clone(),
             foreign_keys: self.foreign_keys.clone(),
             referenced_by: self.referenced_by.clone(),
+            indexed_primary_key: self.indexed_primary_key.clone(),
             created_at: self.created_at,
             updated_at: self.updated_at,
             column_names_cache: OnceLock::new(), // Don't clone cache, it's recomputed lazily
@@ -239,6 +304,7 @@ impl PartialEq for Schema {
             && self.columns == other.columns
             && self.foreign_keys == other.foreign_keys
             && self.referenced_by == other.referenced_by
+            && self.indexed_primary_key == other.indexed_primary_key
             && self.created_at == other.created_at
             && self.updated_at == other.updated_at
     }
@@ -273,6 +339,7 @@ impl Schema {
             columns,
             foreign_keys: Vec::new(),
             referenced_by: Vec::new(),
+            indexed_primary_key: None,
             created_at: now,
             updated_at: now,
             column_names_cache: OnceLock::new(),
@@ -321,6 +388,7 @@ impl Schema {
             columns,
             foreign_keys: Vec::new(),
             referenced_by: Vec::new(),
+            indexed_primary_key: None,
             created_at,
             updated_at,
             column_names_cache: OnceLock::new(),
@@ -412,6 +480,33 @@ impl Schema {
         self.columns.iter().any(|c| c.primary_key)
     }
 
+    /// Names of the PRIMARY KEY columns, in key order, however the key is enforced
+    pub fn primary_key_column_names(&self) -> Vec<&str> {
+        match &self.indexed_primary_key {
+            Some(key) => key.columns.iter().map(|c| c.as_str()).collect(),
+            None => self
+                .columns
+                .iter()
+                .filter(|c| c.primary_key)
+                .map(|c| c.name.as_str())
+                .collect(),
+        }
+    }
+
+    /// Check if a column is part of the PRIMARY KEY, however the key is enforced
+    pub fn is_primary_key_column(&self, name: &str) -> bool {
+        self.primary_key_column_names()
+            .iter()
+            .any(|c| c.eq_ignore_ascii_case(name))
+    }
+
+    /// Check if an index enforces the PRIMARY KEY
+    pub fn is_primary_key_index(&self, index_name: &str) -> bool {
+        self.indexed_primary_key
+            .as_ref()
+            .is_some_and(|key| key.index_name.eq_ignore_ascii_case(index_name))
+    }
+
     /// Get the primary key column indices
     pub fn primary_key_indices(&self) -> Vec<usize> {
         self.columns
@@ -488,6 +583,13 @@ impl Schema {
             .get_column_index(old_name)
             .ok_or(Error::ColumnNotFound)?;
 
+        if let Some(key) = &mut self.indexed_primary_key {
+            for key_col in &mut key.columns {
+                if key_col.eq_ignore_ascii_case(old_name) {
+                    *key_col = new_name.clone();
+                }
+            }
+        }
         self.columns[idx].name = new_name;
         self.mark_updated();
         Ok(())
@@ -500,7 +602,7 @@ impl Schema {
         data_type: Option<DataType>,
         nullable: Option<bool>,
         auto_increment: Option<bool>,
-        check_expr: Option<Option<String>>,
+        checks: Option<Vec<CheckConstraint>>,
     ) -> Result<()> {
         let idx = self.get_column_index(name).ok_or(Error::ColumnNotFound)?;
 
@@ -513,8 +615,8 @@ impl Schema {
         if let Some(ai) = auto_increment {
             self.columns[idx].auto_increment = ai;
         }
-        if let Some(ce) = check_expr {
-            self.columns[idx].check_expr = ce;
+        if let Some(checks) = checks {
+            self.columns[idx].checks = checks;
         }
 
         self.mark_updated();
@@ -613,7 +715,7 @@ impl SchemaBuilder {
         primary_key: bool,
         auto_increment: bool,
         default_expr: Option<String>,
-        check_expr: Option<String>,
+        checks: Vec<CheckConstraint>,
     ) -> Self {
         let id = self.columns.len();
         self.columns.push(SchemaColumn::with_constraints(
@@ -624,7 +726,7 @@ impl SchemaBuilder {
             primary_key,
             auto_increment,
             default_expr,
-            check_expr,
+            checks,
         ));
         self
     }
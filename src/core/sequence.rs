@@ -16,6 +16,13 @@
 
 use crate::core::{Error, Result};
 use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Mutex;
+
+/// NEXTVAL values covered by each save of a sequence's value
+///
+/// A save records the last value of the next block, so after a crash
+/// numbering resumes past anything handed out, at the cost of a gap.
+pub const SEQUENCE_RESERVE_BLOCK: i64 = 32;
 
 /// Configuration options for a Sequence
 #[derive(Debug, Clone, PartialEq)]
@@ -44,6 +51,9 @@ impl Default for SequenceOptions {
 pub struct SequenceState {
     current_value: AtomicI64,
     is_called: std::sync::atomic::AtomicBool,
+    /// NEXTVAL calls the saved value still covers; held while a value is
+    /// handed out so no caller gets ahead of the save
+    reserved: Mutex<i64>,
     pub options: SequenceOptions,
 }
 
@@ -52,6 +62,7 @@ impl SequenceState {
         Self {
             current_value: AtomicI64::new(options.start_with),
             is_called: std::sync::atomic::AtomicBool::new(false),
+            reserved: Mutex::new(0),
             options,
         }
     }
@@ -124,7 +135,79 @@ impl SequenceState {
         Ok(value)
     }
 
+    /// Like [`nextval`](Self::nextval), but calls `save` with the last value
+    /// of a new block before handing out a value the saved one doesn't cover
+    ///
+    /// A failed save is returned and the value is skipped.
+    pub fn nextval_saved(&self, save: impl FnOnce(i64) -> Result<()>) -> Result<i64> {
+        let mut reserved = self
+            .reserved
+            .lock()
+            .map_err(|_| Error::LockAcquisitionFailed("sequence".to_string()))?;
+        let value = self.nextval()?;
+        if *reserved > 0 {
+            *reserved -= 1;
+            return Ok(value);
+        }
+
+        // A block ends at the bound, so a CYCLE wrap always saves again
+        let increment = i128::from(self.options.increment_by);
+        let room = if increment > 0 {
+            i128::from(self.options.max_value) - i128::from(value)
+        } else {
+            i128::from(value) - i128::from(self.options.min_value)
+        };
+        let steps = (room / increment.abs()).min(i128::from(SEQUENCE_RESERVE_BLOCK - 1)) as i64;
+        save(value + steps * self.options.increment_by)?;
+        *reserved = steps;
+        Ok(value)
+    }
+
+    /// Like [`setval`](Self::setval), but calls `save` with the new
+    /// [`saved_value`](Self::saved_value) before it takes effect
+    pub fn setval_saved(
+        &self,
+        value: i64,
+        is_called: bool,
+        save: impl FnOnce(Option<i64>) -> Result<()>,
+    ) -> Result<i64> {
+        let mut reserved = self
+            .reserved
+            .lock()
+            .map_err(|_| Error::LockAcquisitionFailed("sequence".to_string()))?;
+        let value = self.setval(value, is_called)?;
+        *reserved = 0;
+        save(self.saved_value())?;
+        Ok(value)
+    }
+
     pub fn current_value(&self) -> i64 {
         self.current_value.load(Ordering::SeqCst)
     }
+
+    /// The last value NEXTVAL returned, or None if it has not run yet
+    pub fn last_value(&self) -> Option<i64> {
+        self.is_called
+            .load(Ordering::SeqCst)
+            .then(|| self.current_value())
+    }
+
+    /// The value to save so that a restored sequence hands out what this one
+    /// would next: the last value NEXTVAL returned, or None if it has not run
+    ///
+    /// After SETVAL with is_called false this is the value before the one
+    /// set, or the one set (skipping it) when that would leave the bounds.
+    pub fn saved_value(&self) -> Option<i64> {
+        if let Some(value) = self.last_value() {
+            return Some(value);
+        }
+        let current = self.current_value();
+        if current == self.options.start_with {
+            return None;
+        }
+        current
+            .checked_sub(self.options.increment_by)
+            .filter(|v| (self.options.min_value..=self.options.max_value).contains(v))
+            .or(Some(current))
+    }
 }
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -49,6 +50,12 @@ pub enum DataType {
 
     /// JSON document
     Json = 6,
+
+    /// Time interval (duration)
+    Interval = 7,
+
+    /// Raw binary data
+    Blob = 8,
 }
 
 impl DataType {
@@ -77,6 +84,8 @@ impl DataType {
             4 => Some(DataType::Boolean),
             5 => Some(DataType::Timestamp),
             6 => Some(DataType::Json),
+            7 => Some(DataType::Interval),
+            8 => Some(DataType::Blob),
             _ => None,
         }
     }
@@ -92,6 +101,8 @@ impl fmt::Display for DataType {
             DataType::Boolean => write!(f, "BOOLEAN"),
             DataType::Timestamp => write!(f, "TIMESTAMP"),
             DataType::Json => write!(f, "JSON"),
+            DataType::Interval => write!(f, "INTERVAL"),
+            DataType::Blob => write!(f, "BLOB"),
         }
     }
 }
@@ -108,6 +119,8 @@ impl FromStr for DataType {
             "BOOLEAN" | "BOOL" => Ok(DataType::Boolean),
             "TIMESTAMP" | "DATETIME" | "DATE" | "TIME" => Ok(DataType::Timestamp),
             "JSON" | "JSONB" => Ok(DataType::Json),
+            "INTERVAL" => Ok(DataType::Interval),
+            "BLOB" | "BYTEA" | "BINARY" | "VARBINARY" => Ok(DataType::Blob),
             _ => Err(Error::InvalidColumnType),
         }
     }
@@ -340,6 +353,48 @@ impl FromStr for IsolationLevel {
     }
 }
 
+/// Row lock taken by SELECT ... FOR UPDATE / FOR SHARE
+///
+/// Locks are held until the transaction ends. A shared lock blocks writers
+/// and FOR UPDATE from other transactions but not other shared locks; an
+/// update lock blocks every other lock and writer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RowLockMode {
+    /// FOR SHARE
+    Share,
+    /// FOR UPDATE
+    Update,
+}
+
+impl fmt::Display for RowLockMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RowLockMode::Share => write!(f, "FOR SHARE"),
+            RowLockMode::Update => write!(f, "FOR UPDATE"),
+        }
+    }
+}
+
+/// Outcome of a transaction, as reported by TXID_STATUS
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransactionStatus {
+    /// Still running or between the two commit phases
+    InProgress,
+    Committed,
+    /// Rolled back, or ended without committing
+    Aborted,
+}
+
+impl fmt::Display for TransactionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransactionStatus::InProgress => write!(f, "in progress"),
+            TransactionStatus::Committed => write!(f, "committed"),
+            TransactionStatus::Aborted => write!(f, "aborted"),
+        }
+    }
+}
+
 /// Index entry representing a result from an index lookup
 ///
 
@@ -375,6 +430,8 @@ mod tests {
         assert_eq!(DataType::Boolean.to_string(), "BOOLEAN");
         assert_eq!(DataType::Timestamp.to_string(), "TIMESTAMP");
         assert_eq!(DataType::Json.to_string(), "JSON");
+        assert_eq!(DataType::Interval.to_string(), "INTERVAL");
+        assert_eq!(DataType::Blob.to_string(), "BLOB");
     }
 
     #[test]
@@ -392,6 +449,8 @@ mod tests {
             DataType::Timestamp
         );
         assert_eq!("JSON".parse::<DataType>().unwrap(), DataType::Json);
+        assert_eq!("interval".parse::<DataType>().unwrap(), DataType::Interval);
+        assert_eq!("bytea".parse::<DataType>().unwrap(), DataType::Blob);
         assert!("UNKNOWN".parse::<DataType>().is_err());
     }
 
@@ -414,6 +473,8 @@ mod tests {
         assert!(DataType::Boolean.is_orderable());
         assert!(DataType::Timestamp.is_orderable());
         assert!(!DataType::Json.is_orderable());
+        assert!(DataType::Interval.is_orderable());
+        assert!(DataType::Blob.is_orderable());
     }
 
     #[test]
@@ -426,6 +487,8 @@ mod tests {
             DataType::Boolean,
             DataType::Timestamp,
             DataType::Json,
+            DataType::Interval,
+            DataType::Blob,
         ]
         .iter()
         .enumerate()
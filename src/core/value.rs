@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -33,9 +34,13 @@ const TIMESTAMP_FORMATS: &[&str] = &[
     "%Y-%m-%dT%H:%M:%S%.f%:z", // RFC3339 with fractional seconds
     "%Y-%m-%dT%H:%M:%S%:z",    // RFC3339
     "%Y-%m-%dT%H:%M:%SZ",      // RFC3339 UTC
+    "%Y-%m-%dT%H:%M:%S%.f",    // ISO with fractional seconds
     "%Y-%m-%dT%H:%M:%S",       // ISO without timezone
+    "%Y-%m-%dT%H:%M",          // ISO without seconds
+    "%Y-%m-%d %H:%M:%S%.f%#z", // SQL-style with offset
     "%Y-%m-%d %H:%M:%S%.f",    // SQL-style with fractional seconds
     "%Y-%m-%d %H:%M:%S",       // SQL-style
+    "%Y-%m-%d %H:%M",          // SQL-style without seconds
     "%Y-%m-%d",                // Date only
     "%Y/%m/%d %H:%M:%S",       // Alternative with slashes
     "%Y/%m/%d",                // Alternative date only
@@ -49,6 +54,11 @@ const TIME_FORMATS: &[&str] = &[
     "%H:%M",       // Hours and minutes only
 ];
 
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const MICROS_PER_MINUTE: i64 = 60 * MICROS_PER_SECOND;
+const MICROS_PER_HOUR: i64 = 60 * MICROS_PER_MINUTE;
+const MICROS_PER_DAY: i64 = 24 * MICROS_PER_HOUR;
+
 /// A runtime value with type information
 ///
 /// Each variant carries its data directly, avoiding the need for interface
@@ -78,6 +88,12 @@ pub enum Value {
 
     /// JSON document (Arc for cheap cloning)
     Json(Arc<str>),
+
+    /// Interval, stored as a signed number of microseconds
+    Interval(i64),
+
+    /// Raw bytes (Arc for cheap cloning)
+    Blob(Arc<[u8]>),
 }
 
 impl Value {
@@ -135,6 +151,16 @@ impl Value {
         Value::Json(value)
     }
 
+    /// Create an interval value from microseconds
+    pub fn interval(micros: i64) -> Self {
+        Value::Interval(micros)
+    }
+
+    /// Create a blob value from raw bytes
+    pub fn blob(bytes: impl Into<Arc<[u8]>>) -> Self {
+        Value::Blob(bytes.into())
+    }
+
     // =========================================================================
     // Type accessors
     // =========================================================================
@@ -149,6 +175,8 @@ impl Value {
             Value::Boolean(_) => DataType::Boolean,
             Value::Timestamp(_) => DataType::Timestamp,
             Value::Json(_) => DataType::Json,
+            Value::Interval(_) => DataType::Interval,
+            Value::Blob(_) => DataType::Blob,
         }
     }
 
@@ -178,6 +206,8 @@ impl Value {
             Value::Boolean(b) => Some(if *b { 1 } else { 0 }),
             Value::Timestamp(t) => Some(t.timestamp_nanos_opt().unwrap_or(0)),
             Value::Json(_) => None,
+            Value::Interval(micros) => Some(*micros),
+            Value::Blob(_) => None,
         }
     }
 
@@ -191,6 +221,8 @@ impl Value {
             Value::Boolean(b) => Some(if *b { 1.0 } else { 0.0 }),
             Value::Timestamp(_) => None,
             Value::Json(_) => None,
+            Value::Interval(_) => None,
+            Value::Blob(_) => None,
         }
     }
 
@@ -225,6 +257,8 @@ impl Value {
             Value::Boolean(b) => Some(*b),
             Value::Timestamp(_) => None,
             Value::Json(_) => None,
+            Value::Interval(_) => None,
+            Value::Blob(_) => None,
         }
     }
 
@@ -238,6 +272,8 @@ impl Value {
             Value::Boolean(b) => Some(if *b { "true" } else { "false" }.to_string()),
             Value::Timestamp(t) => Some(t.to_rfc3339()),
             Value::Json(s) => Some(s.to_string()),
+            Value::Interval(micros) => Some(format_interval(*micros)),
+            Value::Blob(bytes) => Some(format_blob(bytes)),
         }
     }
 
@@ -271,6 +307,23 @@ impl Value {
         }
     }
 
+    /// Extract as raw bytes
+    pub fn as_blob(&self) -> Option<&[u8]> {
+        match self {
+            Value::Blob(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// Extract as interval microseconds
+    pub fn as_interval(&self) -> Option<i64> {
+        match self {
+            Value::Interval(micros) => Some(*micros),
+            Value::Text(s) => parse_interval(s).ok(),
+            _ => None,
+        }
+    }
+
     /// Extract as JSON string
     pub fn as_json(&self) -> Option<&str> {
         match self {
@@ -312,12 +365,52 @@ impl Value {
             return Ok(compare_floats(v1, v2));
         }
 
+        // Number vs text (e.g. int_col = '5'): the text must be a number
+        match (self, other) {
+            (Value::Text(s), number) if number.data_type().is_numeric() => {
+                return Ok(number.compare(&numeric_text(s, number)?)?.reverse());
+            }
+            (number, Value::Text(s)) if number.data_type().is_numeric() => {
+                return number.compare(&numeric_text(s, number)?);
+            }
+            _ => {}
+        }
+
+        // Interval vs interval text (e.g. duration > '2 hours')
+        if let (Some(a), Some(b)) = (self.interval_operand(other), other.interval_operand(self)) {
+            return Ok(a.cmp(&b));
+        }
+
         // Fall back to string comparison for mixed types
         let s1 = self.as_string().unwrap_or_default();
         let s2 = other.as_string().unwrap_or_default();
         Ok(s1.cmp(&s2))
     }
 
+    /// SQL `=` between two non-NULL values
+    ///
+    /// Unlike `==`, a number equals text holding the same number, and text
+    /// that is not a number is a type conversion error.
+    pub fn sql_equals(&self, other: &Value) -> Result<bool> {
+        match (self, other) {
+            (Value::Text(_), number) | (number, Value::Text(_))
+                if number.data_type().is_numeric() =>
+            {
+                Ok(self.compare(other)? == Ordering::Equal)
+            }
+            _ => Ok(self == other),
+        }
+    }
+
+    /// Interval microseconds of this value when compared against an interval
+    fn interval_operand(&self, other: &Value) -> Option<i64> {
+        match (self, other) {
+            (Value::Interval(micros), _) => Some(*micros),
+            (Value::Text(s), Value::Interval(_)) => parse_interval(s).ok(),
+            _ => None,
+        }
+    }
+
     /// Compare values of the same type
     fn compare_same_type(&self, other: &Value) -> Result<Ordering> {
         match (self, other) {
@@ -326,6 +419,8 @@ impl Value {
             (Value::Text(a), Value::Text(b)) => Ok(a.cmp(b)),
             (Value::Boolean(a), Value::Boolean(b)) => Ok(a.cmp(b)),
             (Value::Timestamp(a), Value::Timestamp(b)) => Ok(a.cmp(b)),
+            (Value::Interval(a), Value::Interval(b)) => Ok(a.cmp(b)),
+            (Value::Blob(a), Value::Blob(b)) => Ok(a.cmp(b)),
             (Value::Json(a), Value::Json(b)) => {
                 // JSON can only test equality, not ordering
                 if a == b {
@@ -416,6 +511,26 @@ impl Value {
                             Value::Null(data_type)
                         }
                     }
+                    DataType::Interval => {
+                        if let Some(&i) = v.downcast_ref::<i64>() {
+                            Value::Interval(i)
+                        } else if let Some(s) = v.downcast_ref::<String>() {
+                            parse_interval(s)
+                                .map(Value::Interval)
+                                .unwrap_or(Value::Null(data_type))
+                        } else {
+                            Value::Null(data_type)
+                        }
+                    }
+                    DataType::Blob => {
+                        if let Some(b) = v.downcast_ref::<Vec<u8>>() {
+                            Value::Blob(Arc::from(b.as_slice()))
+                        } else if let Some(s) = v.downcast_ref::<String>() {
+                            Value::Blob(parse_blob(s))
+                        } else {
+                            Value::Null(data_type)
+                        }
+                    }
                     DataType::Null => Value::Null(DataType::Null),
                 }
             }
@@ -431,7 +546,8 @@ impl Value {
     /// Type coercion rules:
     /// - Integer column receiving Float → converts to Integer
     /// - Float column receiving Integer → converts to Float
-    /// - Text column receiving any type → converts to Text
+    /// - Text column receiving any type → converts to Text (Blob only if valid UTF-8)
+    /// - Blob column receiving Text → `\\x` hex is decoded, other text stores its bytes
     /// - Timestamp column receiving String → parses timestamp
     /// - JSON column receiving valid JSON string → stores as JSON
     /// - Boolean column receiving Integer/String → converts to Boolean
@@ -484,6 +600,12 @@ impl Value {
                     Value::Boolean(b) => Value::Text(Arc::from(if *b { "true" } else { "false" })),
                     Value::Timestamp(t) => Value::Text(Arc::from(t.to_rfc3339().as_str())),
                     Value::Json(s) => Value::Text(Arc::clone(s)),
+                    Value::Interval(micros) => {
+                        Value::Text(Arc::from(format_interval(*micros).as_str()))
+                    }
+                    Value::Blob(bytes) => std::str::from_utf8(bytes)
+                        .map(Value::text)
+                        .unwrap_or(Value::Null(target_type)),
                     Value::Null(_) => Value::Null(target_type),
                 }
             }
@@ -555,6 +677,16 @@ impl Value {
                     _ => Value::Null(target_type),
                 }
             }
+            DataType::Interval => match self {
+                Value::Text(s) => parse_interval(s)
+                    .map(Value::Interval)
+                    .unwrap_or(Value::Null(target_type)),
+                _ => Value::Null(target_type),
+            },
+            DataType::Blob => match self {
+                Value::Text(s) => Value::Blob(parse_blob(s)),
+                _ => Value::Null(target_type),
+            },
             DataType::Null => Value::Null(DataType::Null),
         }
     }
@@ -601,6 +733,10 @@ impl Value {
                 Value::Boolean(b) => Value::Text(Arc::from(if b { "true" } else { "false" })),
                 Value::Timestamp(t) => Value::Text(Arc::from(t.to_rfc3339().as_str())),
                 Value::Json(s) => Value::Text(s),
+                Value::Interval(micros) => Value::Text(Arc::from(format_interval(micros).as_str())),
+                Value::Blob(bytes) => std::str::from_utf8(&bytes)
+                    .map(Value::text)
+                    .unwrap_or(Value::Null(target_type)),
                 Value::Null(_) => Value::Null(target_type),
             },
             DataType::Boolean => match &self {
@@ -656,6 +792,16 @@ impl Value {
                 Value::Boolean(b) => Value::Json(Arc::from(if b { "true" } else { "false" })),
                 _ => Value::Null(target_type),
             },
+            DataType::Interval => match self {
+                Value::Text(s) => parse_interval(&s)
+                    .map(Value::Interval)
+                    .unwrap_or(Value::Null(target_type)),
+                _ => Value::Null(target_type),
+            },
+            DataType::Blob => match self {
+                Value::Text(s) => Value::Blob(parse_blob(&s)),
+                _ => Value::Null(target_type),
+            },
             DataType::Null => Value::Null(DataType::Null),
         }
     }
@@ -681,6 +827,8 @@ impl fmt::Display for Value {
             Value::Boolean(b) => write!(f, "{}", if *b { "true" } else { "false" }),
             Value::Timestamp(t) => write!(f, "{}", t.to_rfc3339()),
             Value::Json(s) => write!(f, "{}", s),
+            Value::Interval(micros) => write!(f, "{}", format_interval(*micros)),
+            Value::Blob(bytes) => write!(f, "{}", format_blob(bytes)),
         }
     }
 }
@@ -715,6 +863,8 @@ impl PartialEq for Value {
             (Value::Boolean(a), Value::Boolean(b)) => a == b,
             (Value::Timestamp(a), Value::Timestamp(b)) => a == b,
             (Value::Json(a), Value::Json(b)) => a == b,
+            (Value::Interval(a), Value::Interval(b)) => a == b,
+            (Value::Blob(a), Value::Blob(b)) => a == b,
             _ => false,
         }
     }
@@ -728,9 +878,9 @@ impl Hash for Value {
         // Since Integer(5) == Float(5.0), they must hash the same.
         // We achieve this by hashing numeric types as their f64 bit representation.
         match self {
-            Value::Null(dt) => {
+            Value::Null(_) => {
+                // All NULLs are equal whatever their type, so the type is not hashed
                 0u8.hash(state); // discriminant for Null
-                dt.hash(state);
             }
             Value::Integer(v) => {
                 // Hash as f64 bits so Integer(5) and Float(5.0) hash the same
@@ -758,6 +908,14 @@ impl Hash for Value {
                 5u8.hash(state);
                 s.hash(state);
             }
+            Value::Interval(micros) => {
+                6u8.hash(state);
+                micros.hash(state);
+            }
+            Value::Blob(bytes) => {
+                7u8.hash(state);
+                bytes.hash(state);
+            }
         }
     }
 }
@@ -833,6 +991,8 @@ impl Ord for Value {
                 Value::Text(_) => 3,
                 Value::Timestamp(_) => 4,
                 Value::Json(_) => 5,
+                Value::Interval(_) => 6,
+                Value::Blob(_) => 7,
             }
         }
 
@@ -859,6 +1019,8 @@ impl Ord for Value {
             (Value::Text(a), Value::Text(b)) => a.cmp(b),
             (Value::Boolean(a), Value::Boolean(b)) => a.cmp(b),
             (Value::Timestamp(a), Value::Timestamp(b)) => a.cmp(b),
+            (Value::Interval(a), Value::Interval(b)) => a.cmp(b),
+            (Value::Blob(a), Value::Blob(b)) => a.cmp(b),
             (Value::Json(a), Value::Json(b)) => a.cmp(b), // Lexicographic for JSON
             _ => Ordering::Equal,                         // Should not reach here
         }
@@ -970,6 +1132,11 @@ impl<T: Into<Value>> From<Option<T>> for Value {
 pub fn parse_timestamp(s: &str) -> Result<DateTime<Utc>> {
     let s = s.trim();
 
+    // RFC 3339 in full: any fraction precision, `Z` or offset, either case
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+
     // Try each timestamp format
     for format in TIMESTAMP_FORMATS {
         if let Ok(dt) = DateTime::parse_from_str(s, format) {
@@ -987,6 +1154,11 @@ pub fn parse_timestamp(s: &str) -> Result<DateTime<Utc>> {
         return Ok(Utc.from_utc_datetime(&datetime));
     }
 
+    // RFC 2822, as used in mail and HTTP headers
+    if let Ok(dt) = DateTime::parse_from_rfc2822(s) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+
     // Try time-only formats (use today's date)
     for format in TIME_FORMATS {
         if let Ok(time) = NaiveTime::parse_from_str(s, format) {
@@ -999,9 +1171,166 @@ pub fn parse_timestamp(s: &str) -> Result<DateTime<Utc>> {
     Err(Error::parse(format!("invalid timestamp format: {}", s)))
 }
 
+/// Format bytes in the `\\x` hex form, e.g. `\\x48690a`
+pub fn format_blob(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(2 + bytes.len() * 2);
+    s.push_str("\\x");
+    for b in bytes {
+        s.push_str(&format!("{:02x}", b));
+    }
+    s
+}
+
+/// Parse text into blob bytes
+///
+/// Text in the `\\x` hex form (as produced by [`format_blob`]) is decoded;
+/// any other text is stored as its UTF-8 bytes.
+pub fn parse_blob(s: &str) -> Arc<[u8]> {
+    if let Some(hex) = s.strip_prefix("\\x") {
+        if hex.len() % 2 == 0 {
+            let decoded: Option<Vec<u8>> = (0..hex.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+                .collect();
+            if let Some(bytes) = decoded {
+                return Arc::from(bytes);
+            }
+        }
+    }
+    Arc::from(s.as_bytes())
+}
+
+/// Parse an interval string into microseconds
+///
+/// Accepts unit pairs (`2 hours 30 minutes`), a clock part (`02:30:00`) or
+/// both (`1 day 2:30:00`); every part may carry its own sign. Months count as
+/// 30 days and years as 365 days. A bare number is a number of days.
+pub fn parse_interval(s: &str) -> Result<i64> {
+    let invalid = || Error::parse(format!("invalid interval format: {}", s));
+    let parts: Vec<&str> = s.split_whitespace().collect();
+
+    if let [days] = parts.as_slice() {
+        if let Ok(days) = days.parse::<i64>() {
+            return days.checked_mul(MICROS_PER_DAY).ok_or_else(invalid);
+        }
+    }
+    if parts.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut total: i64 = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let micros = if parts[i].contains(':') {
+            i += 1;
+            parse_interval_clock(parts[i - 1]).ok_or_else(invalid)?
+        } else {
+            let unit = parts.get(i + 1).ok_or_else(invalid)?;
+            let unit_micros = interval_unit_micros(unit).ok_or_else(invalid)?;
+            let quantity = parts[i];
+            i += 2;
+            if let Ok(q) = quantity.parse::<i64>() {
+                q.checked_mul(unit_micros).ok_or_else(invalid)?
+            } else {
+                let q: f64 = quantity.parse().map_err(|_| invalid())?;
+                (q * unit_micros as f64).round() as i64
+            }
+        };
+        total = total.checked_add(micros).ok_or_else(invalid)?;
+    }
+
+    Ok(total)
+}
+
+/// Format interval microseconds as `[N day[s]] [-]HH:MM:SS[.ffffff]`
+///
+/// The output is accepted by [`parse_interval`].
+pub fn format_interval(micros: i64) -> String {
+    let sign = if micros < 0 { "-" } else { "" };
+    let abs = micros.unsigned_abs();
+    let days = abs / MICROS_PER_DAY as u64;
+    let rest = abs % MICROS_PER_DAY as u64;
+
+    let mut parts = Vec::with_capacity(2);
+    if days > 0 {
+        let unit = if days == 1 { "day" } else { "days" };
+        parts.push(format!("{}{} {}", sign, days, unit));
+    }
+    if days == 0 || rest > 0 {
+        let hours = rest / MICROS_PER_HOUR as u64;
+        let minutes = rest % MICROS_PER_HOUR as u64 / MICROS_PER_MINUTE as u64;
+        let seconds = rest % MICROS_PER_MINUTE as u64 / MICROS_PER_SECOND as u64;
+        let fraction = rest % MICROS_PER_SECOND as u64;
+        let mut clock = format!("{}{:02}:{:02}:{:02}", sign, hours, minutes, seconds);
+        if fraction > 0 {
+            clock.push_str(format!(".{:06}", fraction).trim_end_matches('0'));
+        }
+        parts.push(clock);
+    }
+    parts.join(" ")
+}
+
+/// Microseconds in one interval unit
+fn interval_unit_micros(unit: &str) -> Option<i64> {
+    match unit.to_lowercase().as_str() {
+        "microsecond" | "microseconds" | "us" => Some(1),
+        "millisecond" | "milliseconds" | "ms" => Some(1_000),
+        "second" | "seconds" | "sec" | "secs" | "s" => Some(MICROS_PER_SECOND),
+        "minute" | "minutes" | "min" | "mins" | "m" => Some(MICROS_PER_MINUTE),
+        "hour" | "hours" | "hr" | "hrs" | "h" => Some(MICROS_PER_HOUR),
+        "day" | "days" | "d" => Some(MICROS_PER_DAY),
+        "week" | "weeks" | "w" => Some(7 * MICROS_PER_DAY),
+        "month" | "months" | "mon" | "mons" => Some(30 * MICROS_PER_DAY),
+        "year" | "years" | "yr" | "yrs" | "y" => Some(365 * MICROS_PER_DAY),
+        _ => None,
+    }
+}
+
+/// Parse a `[-]H:MM[:SS[.ffffff]]` clock part into microseconds
+fn parse_interval_clock(part: &str) -> Option<i64> {
+    let (negative, body) = match part.strip_prefix('-') {
+        Some(body) => (true, body),
+        None => (false, part.strip_prefix('+').unwrap_or(part)),
+    };
+    let fields: Vec<&str> = body.split(':').collect();
+    if !(2..=3).contains(&fields.len()) {
+        return None;
+    }
+
+    let hours: u32 = fields[0].parse().ok()?;
+    let minutes: u32 = fields[1].parse().ok()?;
+    let (seconds, fraction) = match fields.get(2) {
+        Some(field) => field.split_once('.').unwrap_or((field, "")),
+        None => ("0", ""),
+    };
+    let seconds: u32 = seconds.parse().ok()?;
+    if minutes >= 60
+        || seconds >= 60
+        || fraction.len() > 6
+        || !fraction.chars().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let fraction: i64 = if fraction.is_empty() {
+        0
+    } else {
+        format!("{:0<6}", fraction).parse().ok()?
+    };
+
+    let micros = hours as i64 * MICROS_PER_HOUR
+        + minutes as i64 * MICROS_PER_MINUTE
+        + seconds as i64 * MICROS_PER_SECOND
+        + fraction;
+    Some(if negative { -micros } else { micros })
+}
+
 /// Format a float value consistently
 fn format_float(v: f64) -> String {
-    if v.fract() == 0.0 && v.abs() < 1e15 {
+    if v.is_nan() {
+        "NaN".to_string()
+    } else if v.is_infinite() {
+        if v > 0.0 { "Infinity" } else { "-Infinity" }.to_string()
+    } else if v.fract() == 0.0 && v.abs() < 1e15 {
         // Integer-like float, format without decimal
         format!("{:.0}", v)
     } else {
@@ -1027,6 +1356,24 @@ fn compare_floats(a: f64, b: f64) -> Ordering {
     }
 }
 
+/// Text compared against `number`, read as a number
+///
+/// Accepts integer and decimal notation with surrounding whitespace. Anything
+/// else cannot be compared with a number and is a type conversion error.
+fn numeric_text(text: &str, number: &Value) -> Result<Value> {
+    let trimmed = text.trim();
+    if let Ok(i) = trimmed.parse::<i64>() {
+        return Ok(Value::Integer(i));
+    }
+    match trimmed.parse::<f64>() {
+        Ok(f) if f.is_finite() => Ok(Value::Float(f)),
+        _ => Err(Error::type_conversion(
+            format!("'{}'", text),
+            number.data_type().to_string(),
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1283,6 +1630,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_compare_number_with_text() {
+        // Numeric text compares as a number, not as a string
+        assert_eq!(
+            Value::integer(10).compare(&Value::text("9")).unwrap(),
+            Ordering::Greater
+        );
+        assert_eq!(
+            Value::text(" 5.0 ").compare(&Value::integer(5)).unwrap(),
+            Ordering::Equal
+        );
+        assert_eq!(
+            Value::text("2.5").compare(&Value::float(3.0)).unwrap(),
+            Ordering::Less
+        );
+        assert!(Value::integer(5).sql_equals(&Value::text("5")).unwrap());
+        assert!(!Value::float(5.5).sql_equals(&Value::text("5")).unwrap());
+
+        // Other text is an error rather than a string comparison
+        let err = Value::integer(5).compare(&Value::text("abc")).unwrap_err();
+        assert!(matches!(err, Error::TypeConversion { .. }), "{}", err);
+        assert!(Value::text("NaN").sql_equals(&Value::float(1.0)).is_err());
+
+        // Same-type equality is unchanged
+        assert!(!Value::text("5").sql_equals(&Value::text("5.0")).unwrap());
+    }
+
     #[test]
     fn test_compare_strings() {
         assert_eq!(
@@ -1388,4 +1762,53 @@ mod tests {
         assert!(set.contains(&Value::integer(42)));
         assert!(set.contains(&Value::integer(43)));
     }
+
+    // =========================================================================
+    // Interval tests
+    // =========================================================================
+
+    #[test]
+    fn test_parse_interval() {
+        assert_eq!(parse_interval("2 hours").unwrap(), 2 * MICROS_PER_HOUR);
+        assert_eq!(
+            parse_interval("1 day 2:30:00").unwrap(),
+            MICROS_PER_DAY + 2 * MICROS_PER_HOUR + 30 * MICROS_PER_MINUTE
+        );
+        assert_eq!(parse_interval("1 week").unwrap(), 7 * MICROS_PER_DAY);
+        assert_eq!(parse_interval("3").unwrap(), 3 * MICROS_PER_DAY);
+        assert_eq!(
+            parse_interval("-1 day -02:30:00").unwrap(),
+            -(MICROS_PER_DAY + 2 * MICROS_PER_HOUR + 30 * MICROS_PER_MINUTE)
+        );
+        assert!(parse_interval("").is_err());
+        assert!(parse_interval("soon").is_err());
+        assert!(parse_interval("2 fortnights").is_err());
+    }
+
+    #[test]
+    fn test_format_interval() {
+        for (micros, text) in [
+            (0, "00:00:00"),
+            (2 * MICROS_PER_DAY, "2 days"),
+            (
+                MICROS_PER_DAY + 2 * MICROS_PER_HOUR + 1_500_000,
+                "1 day 02:00:01.5",
+            ),
+            (-(MICROS_PER_DAY + 2 * MICROS_PER_HOUR), "-1 day -02:00:00"),
+        ] {
+            assert_eq!(format_interval(micros), text);
+            assert_eq!(parse_interval(text).unwrap(), micros);
+        }
+    }
+
+    #[test]
+    fn test_interval_compare_with_text() {
+        let v = Value::interval(2 * MICROS_PER_HOUR);
+        assert_eq!(v.data_type(), DataType::Interval);
+        assert_eq!(
+            v.compare(&Value::text("1 hour")).unwrap(),
+            Ordering::Greater
+        );
+        assert_eq!(v, Value::text("2 hours").coerce_to_type(DataType::Interval));
+    }
 }
@@ -0,0 +1,92 @@
+// Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! View Definitions
+
+/// View definition storing the query that defines the view
+#[derive(Debug, Clone)]
+pub struct ViewDefinition {
+    /// View name (lowercase for case-insensitive lookup)
+    pub name: String,
+    /// Original view name (preserves case)
+    pub original_name: String,
+    /// The SQL query string that defines the view
+    pub query: String,
+}
+
+impl ViewDefinition {
+    /// Create a new view definition
+    pub fn new(name: &str, query: String) -> Self {
+        Self {
+            name: name.to_lowercase(),
+            original_name: name.to_string(),
+            query,
+        }
+    }
+
+    /// Serialize view definition to binary format for WAL
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+
+        // Original name (length-prefixed)
+        buf.extend_from_slice(&(self.original_name.len() as u16).to_le_bytes());
+        buf.extend_from_slice(self.original_name.as_bytes());
+
+        // Query (length-prefixed, using u32 for longer queries)
+        buf.extend_from_slice(&(self.query.len() as u32).to_le_bytes());
+        buf.extend_from_slice(self.query.as_bytes());
+
+        buf
+    }
+
+    /// Deserialize view definition from binary format
+    pub fn deserialize(data: &[u8]) -> crate::core::Result<Self> {
+        let mut pos = 0;
+
+        // Original name
+        if pos + 2 > data.len() {
+            return Err(crate::core::Error::internal(
+                "invalid view: missing name length",
+            ));
+        }
+        let name_len = u16::from_le_bytes([data[pos], data[pos + 1]]) as usize;
+        pos += 2;
+
+        if pos + name_len > data.len() {
+            return Err(crate::core::Error::internal("invalid view: missing name"));
+        }
+        let original_name = String::from_utf8(data[pos..pos + name_len].to_vec())
+            .map_err(|e| crate::core::Error::internal(format!("invalid view name: {}", e)))?;
+        pos += name_len;
+
+        // Query
+        if pos + 4 > data.len() {
+            return Err(crate::core::Error::internal(
+                "invalid view: missing query length",
+            ));
+        }
+        let query_len =
+            u32::from_le_bytes([data[pos], data[pos + 1], data[pos + 2], data[pos + 3]]) as usize;
+        pos += 4;
+
+        if pos + query_len > data.len() {
+            return Err(crate::core::Error::internal("invalid view: missing query"));
+        }
+        let query = String::from_utf8(data[pos..pos + query_len].to_vec())
+            .map_err(|e| crate::core::Error::internal(format!("invalid view query: {}", e)))?;
+
+        Ok(Self::new(&original_name, query))
+    }
+}
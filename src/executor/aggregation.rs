@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -231,8 +232,12 @@ impl Executor {
         base_rows: Vec<Row>,
         base_columns: &[String],
     ) -> Result<Box<dyn QueryResult>> {
+        // The input may have been cut short by the timeout
+        ctx.check_cancelled()?;
+
         // Parse aggregations and group by columns
-        let (aggregations, _non_agg_columns) = self.parse_aggregations(stmt)?;
+        let (mut aggregations, _non_agg_columns) = self.parse_aggregations(stmt)?;
+        Self::expand_json_agg_rows(&mut aggregations, base_columns);
         let group_by_columns = self.parse_group_by(stmt, base_columns)?;
 
         // Create column index map for fast lookup (FxHashMap for speed)
@@ -1102,7 +1107,7 @@ impl Executor {
                     }
 
                     let (column, distinct, extra_args, expression) =
-                        self.extract_agg_column(&func.arguments)?;
+                        self.extract_agg_arguments(func)?;
                     let column_lower = column.to_lowercase();
                     aggregations.push(SqlAggregateFunction {
                         name: func.function.clone(),
@@ -1186,7 +1191,7 @@ impl Executor {
                     }
 
                     let (column, distinct, extra_args, expression) =
-                        self.extract_agg_column(&func.arguments)?;
+                        self.extract_agg_arguments(func)?;
                     let column_lower = column.to_lowercase();
                     aggregations.push(SqlAggregateFunction {
                         name: func.function.clone(),
@@ -1241,6 +1246,98 @@ impl Executor {
         Ok(())
     }
 
+    /// Rewrite `JSON_AGG(*)` and `JSON_AGG(alias.*)` to aggregate each row as a
+    /// JSON object of its columns
+    fn expand_json_agg_rows(aggregations: &mut [SqlAggregateFunction], base_columns: &[String]) {
+        for agg in aggregations.iter_mut() {
+            if !agg.name.eq_ignore_ascii_case("JSON_AGG") {
+                continue;
+            }
+
+            let (token, columns): (_, Vec<&String>) = match &agg.expression {
+                Some(Expression::QualifiedStar(qs)) => {
+                    let prefix = format!("{}.", qs.qualifier.to_lowercase());
+                    let mut columns: Vec<&String> = base_columns
+                        .iter()
+                        .filter(|c| c.to_lowercase().starts_with(&prefix))
+                        .collect();
+                    // Single-table queries use unqualified column names
+                    if columns.is_empty() && base_columns.iter().all(|c| !c.contains('.')) {
+                        columns = base_columns.iter().collect();
+                    }
+                    (qs.token.clone(), columns)
+                }
+                None if agg.column == "*" => {
+                    let token = crate::parser::token::Token::new(
+                        crate::parser::token::TokenType::Punctuator,
+                        "*",
+                        crate::parser::token::Position::default(),
+                    );
+                    (token, base_columns.iter().collect())
+                }
+                _ => continue,
+            };
+
+            let mut arguments = Vec::with_capacity(columns.len() * 2);
+            for column in columns {
+                let name = column.rsplit('.').next().unwrap_or(column);
+                arguments.push(Expression::StringLiteral(
+                    crate::parser::ast::StringLiteral {
+                        token: token.clone(),
+                        value: name.to_string(),
+                        type_hint: None,
+                    },
+                ));
+                arguments.push(Expression::Identifier(crate::parser::ast::Identifier::new(
+                    token.clone(),
+                    column.clone(),
+                )));
+            }
+            agg.expression = Some(Expression::FunctionCall(crate::parser::ast::FunctionCall {
+                token,
+                function: "JSON_OBJECT".to_string(),
+                arguments,
+                is_distinct: false,
+                order_by: Vec::new(),
+                filter: None,
+            }));
+        }
+    }
+
+    /// Extract the arguments of an aggregate function call
+    ///
+    /// JSON_OBJECT_AGG takes a key and a value per row, but aggregates accumulate a
+    /// single value, so the pair is packed into one `JSON_ARRAY(key, value)` expression.
+    fn extract_agg_arguments(
+        &self,
+        func: &crate::parser::ast::FunctionCall,
+    ) -> Result<(String, bool, Vec<Value>, Option<Expression>)> {
+        if !func.function.eq_ignore_ascii_case("JSON_OBJECT_AGG") {
+            return self.extract_agg_column(&func.arguments);
+        }
+
+        if func.arguments.len() != 2 {
+            return Err(crate::core::Error::InvalidArgumentMessage(
+                "JSON_OBJECT_AGG requires exactly 2 arguments (key, value)".to_string(),
+            ));
+        }
+        let column = func
+            .arguments
+            .iter()
+            .map(|a| self.expression_to_string(a))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let pair = Expression::FunctionCall(crate::parser::ast::FunctionCall {
+            token: func.token.clone(),
+            function: "JSON_ARRAY".to_string(),
+            arguments: func.arguments.clone(),
+            is_distinct: false,
+            order_by: Vec::new(),
+            filter: None,
+        });
+        Ok((column, false, Vec::new(), Some(pair)))
+    }
+
     /// Extract column name, expression, and extra arguments from aggregate function arguments
     ///
     /// Returns: (column_name, distinct, extra_args, expression)
@@ -1731,7 +1828,10 @@ impl Executor {
                 let count_star_value = Value::Integer(1);
 
                 // Hot loop with compiled aggregates - zero virtual dispatch
-                for row in rows {
+                for (row_idx, row) in rows.iter().enumerate() {
+                    if row_idx.is_multiple_of(100) {
+                        ctx.check_cancelled()?;
+                    }
                     for i in 0..compiled_aggs.len() {
                         let value = if let Some(col_idx) = agg_col_indices[i] {
                             row.get(col_idx)
@@ -1783,13 +1883,17 @@ impl Executor {
                 // Buffer for evaluated expression values (to avoid repeated allocation)
                 let mut expr_values: Vec<Value> = vec![Value::null_unknown(); aggregations.len()];
 
-                for row in rows {
+                for (row_idx, row) in rows.iter().enumerate() {
+                    if row_idx.is_multiple_of(100) {
+                        ctx.check_cancelled()?;
+                    }
                     // Create execution context for this row
                     // CRITICAL: Include params for parameterized queries
                     let row_data = row.as_slice();
                     let exec_ctx = ExecuteContext::new(row_data)
                         .with_params(ctx.params())
-                        .with_named_params(ctx.named_params());
+                        .with_named_params(ctx.named_params())
+                        .with_div_by_zero_null(ctx.div_by_zero_null());
 
                     for (i, agg) in aggregations.iter().enumerate() {
                         if let Some(ref mut func) = agg_funcs[i] {
@@ -1818,16 +1922,7 @@ impl Executor {
                                             expr_values[i] = val;
                                             Some(&expr_values[i])
                                         }
-                                        Err(e) => {
-                                            // Expression evaluation failed - skip row
-                                            #[cfg(debug_assertions)]
-                                            eprintln!(
-                                                "Warning: aggregate expression evaluation failed: {}",
-                                                e
-                                            );
-                                            let _ = e;
-                                            None
-                                        }
+                                        Err(e) => return Err(e),
                                     }
                                 } else {
                                     None
@@ -1924,6 +2019,16 @@ impl Executor {
     fn merge_single_aggregate(&self, func_name: &str, partials: Vec<&Value>) -> Value {
         // OPTIMIZATION: func_name comes from SqlAggregateFunction.name which is already uppercase
         match func_name {
+            "SUM" if partials.iter().any(|v| matches!(v, Value::Interval(_))) => {
+                let total = partials
+                    .iter()
+                    .filter_map(|v| match v {
+                        Value::Interval(micros) => Some(*micros),
+                        _ => None,
+                    })
+                    .fold(0i64, |acc, micros| acc.saturating_add(micros));
+                Value::Interval(total)
+            }
             "COUNT" | "SUM" => {
                 // Sum all partial results
                 let mut total: i64 = 0;
@@ -1962,6 +2067,7 @@ impl Executor {
                 // For now, just average the partial averages (less accurate for uneven chunks)
                 let mut sum: f64 = 0.0;
                 let mut count = 0;
+                let mut interval = false;
 
                 for val in &partials {
                     match val {
@@ -1973,11 +2079,18 @@ impl Executor {
                             sum += *n as f64;
                             count += 1;
                         }
+                        Value::Interval(micros) => {
+                            sum += *micros as f64;
+                            count += 1;
+                            interval = true;
+                        }
                         _ => {}
                     }
                 }
 
-                if count > 0 {
+                if count > 0 && interval {
+                    Value::Interval((sum / count as f64).round() as i64)
+                } else if count > 0 {
                     Value::Float(sum / count as f64)
                 } else {
                     Value::null_unknown()
@@ -2019,6 +2132,23 @@ impl Executor {
 
                 max_val.unwrap_or_else(Value::null_unknown)
             }
+            "JSON_AGG" => {
+                // Concatenate the partial arrays in chunk order
+                let mut elements = Vec::new();
+                for val in partials {
+                    if let Value::Json(json) = val {
+                        if let Ok(serde_json::Value::Array(items)) = serde_json::from_str(json) {
+                            elements.extend(items);
+                        }
+                    }
+                }
+
+                if elements.is_empty() {
+                    Value::null_unknown()
+                } else {
+                    Value::json(serde_json::Value::Array(elements).to_string())
+                }
+            }
             _ => {
                 // For unknown functions, just take the first non-null
                 partials
@@ -2105,6 +2235,14 @@ impl Executor {
 
         let simple_aggs: Vec<SimpleAgg> = simple_aggs.into_iter().map(|a| a.unwrap()).collect();
 
+        // Running sums are f64, so intervals take the regular path
+        if simple_aggs
+            .iter()
+            .any(|agg| matches!(agg, SimpleAgg::Sum(idx) if Self::is_interval_column(rows, *idx)))
+        {
+            return Ok(None);
+        }
+
         // Fast path: single-pass streaming aggregation
         // Store aggregate state directly in hash map instead of row indices
         struct FastGroupState {
@@ -2395,6 +2533,9 @@ impl Executor {
                     FxHashMap::default();
 
                 for (row_idx, row) in rows.iter().enumerate() {
+                    if row_idx.is_multiple_of(100) {
+                        ctx.check_cancelled()?;
+                    }
                     let key_value = row
                         .get(col_idx)
                         .cloned()
@@ -2425,6 +2566,9 @@ impl Executor {
                 }
             } else {
                 for (row_idx, row) in rows.iter().enumerate() {
+                    if row_idx.is_multiple_of(100) {
+                        ctx.check_cancelled()?;
+                    }
                     // Build key values for this row
                     key_buffer.clear();
                     for &idx in &column_indices {
@@ -2484,6 +2628,9 @@ impl Executor {
         } else {
             // Slow path: need to evaluate expressions, use buffer
             for (row_idx, row) in rows.iter().enumerate() {
+                if row_idx.is_multiple_of(100) {
+                    ctx.check_cancelled()?;
+                }
                 key_buffer.clear();
 
                 // Create execution context for this row
@@ -2491,7 +2638,8 @@ impl Executor {
                 let row_data = row.as_slice();
                 let exec_ctx = ExecuteContext::new(row_data)
                     .with_params(ctx.params())
-                    .with_named_params(ctx.named_params());
+                    .with_named_params(ctx.named_params())
+                    .with_div_by_zero_null(ctx.div_by_zero_null());
 
                 for (i, item) in precomputed_group_by.iter().enumerate() {
                     let value = match item {
@@ -2727,6 +2875,7 @@ impl Executor {
 
             let mut result_rows_seq = Vec::with_capacity(groups_vec.len());
             for group in groups_vec {
+                ctx.check_cancelled()?;
                 // Reset aggregate functions for this group
                 for f in agg_funcs.iter_mut().flatten() {
                     f.reset();
@@ -2743,7 +2892,8 @@ impl Executor {
                     let row_data = row.as_slice();
                     let exec_ctx = ExecuteContext::new(row_data)
                         .with_params(ctx.params())
-                        .with_named_params(ctx.named_params());
+                        .with_named_params(ctx.named_params())
+                        .with_div_by_zero_null(ctx.div_by_zero_null());
 
                     for (i, agg) in aggregations.iter().enumerate() {
                         if let Some(ref mut func) = agg_funcs[i] {
@@ -2772,16 +2922,7 @@ impl Executor {
                                             expr_values[i] = val;
                                             Some(&expr_values[i])
                                         }
-                                        Err(e) => {
-                                            // Expression evaluation failed - see comment above
-                                            #[cfg(debug_assertions)]
-                                            eprintln!(
-                                                "Warning: aggregate expression evaluation failed: {}",
-                                                e
-                                            );
-                                            let _ = e;
-                                            None
-                                        }
+                                        Err(e) => return Err(e),
                                     }
                                 } else {
                                     None
@@ -2899,7 +3040,7 @@ impl Executor {
         // Check if any aggregation has an expression (e.g., SUM(val * 2)) or ORDER BY
         let has_agg_expression = aggregations
             .iter()
-            .any(|a| a.expression.is_some() || !a.order_by.is_empty());
+            .any(|a| a.expression.is_some() || !a.order_by.is_empty() || a.filter.is_some());
 
         // Pre-compute aggregate column indices
         let agg_col_indices: Vec<Option<usize>> = aggregations
@@ -2954,6 +3095,22 @@ impl Executor {
             })
             .collect::<Result<Vec<_>>>()?;
 
+        // Pre-compile aggregate FILTER clauses
+        // CRITICAL: Propagate errors instead of silently ignoring compilation failures
+        let compiled_agg_filters: Vec<Option<SharedProgram>> = if has_agg_expression {
+            aggregations
+                .iter()
+                .map(|agg| {
+                    agg.filter
+                        .as_ref()
+                        .map(|f| compile_expression(f, columns))
+                        .transpose()
+                })
+                .collect::<Result<Vec<_>>>()?
+        } else {
+            vec![None; aggregations.len()]
+        };
+
         // Pre-compile aggregate expressions
         // CRITICAL: Propagate errors instead of silently ignoring compilation failures
         let compiled_agg_expressions: Vec<Option<SharedProgram>> = if has_agg_expression {
@@ -3002,16 +3159,33 @@ impl Executor {
                 let count_star_value = Value::Integer(1);
                 let mut expr_values: Vec<Value> = vec![Value::null_unknown(); aggregations.len()];
 
-                for row in rows {
+                for (row_idx, row) in rows.iter().enumerate() {
+                    if row_idx.is_multiple_of(100) {
+                        ctx.check_cancelled()?;
+                    }
                     // Create execution context for this row
                     // CRITICAL: Include params for parameterized queries
                     let row_data = row.as_slice();
                     let exec_ctx = ExecuteContext::new(row_data)
                         .with_params(ctx.params())
-                        .with_named_params(ctx.named_params());
+                        .with_named_params(ctx.named_params())
+                        .with_div_by_zero_null(ctx.div_by_zero_null());
 
                     for (i, agg) in aggregations.iter().enumerate() {
                         if let Some(ref mut func) = agg_funcs[i] {
+                            // Check FILTER clause first - skip row if filter is false
+                            if let Some(ref filter_program) = compiled_agg_filters[i] {
+                                if let Some(ref mut vm) = expr_vm {
+                                    if !matches!(
+                                        vm.execute(filter_program, &exec_ctx),
+                                        Ok(Value::Boolean(true))
+                                    ) {
+                                        continue;
+                                    }
+                                } else {
+                                    continue;
+                                }
+                            }
                             if let Some(ref expr_program) = compiled_agg_expressions[i] {
                                 if let Some(ref mut vm) = expr_vm {
                                     if let Ok(val) = vm.execute(expr_program, &exec_ctx) {
@@ -3071,7 +3245,8 @@ impl Executor {
                     let row_data = row.as_slice();
                     let exec_ctx = ExecuteContext::new(row_data)
                         .with_params(ctx.params())
-                        .with_named_params(ctx.named_params());
+                        .with_named_params(ctx.named_params())
+                        .with_div_by_zero_null(ctx.div_by_zero_null());
 
                     // Only include active columns in the key
                     for (col_idx, &is_active) in grouping_set.active_columns.iter().enumerate() {
@@ -3166,10 +3341,24 @@ impl Executor {
                             let row_data = row.as_slice();
                             let exec_ctx = ExecuteContext::new(row_data)
                                 .with_params(ctx.params())
-                                .with_named_params(ctx.named_params());
+                                .with_named_params(ctx.named_params())
+                                .with_div_by_zero_null(ctx.div_by_zero_null());
 
                             for (i, agg) in aggregations.iter().enumerate() {
                                 if let Some(ref mut func) = agg_funcs[i] {
+                                    // Check FILTER clause first - skip row if filter is false
+                                    if let Some(ref filter_program) = compiled_agg_filters[i] {
+                                        if let Some(ref mut vm) = expr_vm {
+                                            if !matches!(
+                                                vm.execute(filter_program, &exec_ctx),
+                                                Ok(Value::Boolean(true))
+                                            ) {
+                                                continue;
+                                            }
+                                        } else {
+                                            continue;
+                                        }
+                                    }
                                     if let Some(ref expr_program) = compiled_agg_expressions[i] {
                                         if let Some(ref mut vm) = expr_vm {
                                             if let Ok(val) = vm.execute(expr_program, &exec_ctx) {
@@ -3449,6 +3638,18 @@ impl Executor {
             return Value::null_unknown();
         }
 
+        // Interval columns sum to an interval
+        if Self::is_interval_column(rows, col_idx) {
+            let sum = rows
+                .iter()
+                .filter_map(|row| match row.get(col_idx) {
+                    Some(Value::Interval(micros)) => Some(*micros),
+                    _ => None,
+                })
+                .fold(0i64, |acc, micros| acc.saturating_add(micros));
+            return Value::Interval(sum);
+        }
+
         // Use parallel processing for large datasets
         if rows.len() >= 10_000 {
             return self.fast_sum_column_parallel(rows, col_idx);
@@ -3523,6 +3724,14 @@ impl Executor {
         }
     }
 
+    /// Check whether the first non-NULL value of a column is an interval
+    fn is_interval_column(rows: &[Row], col_idx: usize) -> bool {
+        rows.iter()
+            .filter_map(|row| row.get(col_idx))
+            .find(|v| !v.is_null())
+            .is_some_and(|v| matches!(v, Value::Interval(_)))
+    }
+
     /// Parallel SUM implementation using Rayon
     #[inline]
     fn fast_sum_column_parallel(&self, rows: &[Row], col_idx: usize) -> Value {
@@ -3608,6 +3817,20 @@ impl Executor {
             return Value::null_unknown();
         }
 
+        // Interval columns average to an interval
+        if Self::is_interval_column(rows, col_idx) {
+            let (sum, count) = rows
+                .iter()
+                .filter_map(|row| match row.get(col_idx) {
+                    Some(Value::Interval(micros)) => Some(*micros as f64),
+                    _ => None,
+                })
+                .fold((0.0, 0i64), |(sum, count), micros| {
+                    (sum + micros, count + 1)
+                });
+            return Value::Interval((sum / count as f64).round() as i64);
+        }
+
         // Use parallel processing for large datasets
         if rows.len() >= 10_000 {
             return self.fast_avg_column_parallel(rows, col_idx);
@@ -0,0 +1,109 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Attached databases (ATTACH / DETACH DATABASE)
+//!
+//! An attached database is opened by DSN and its tables become readable as
+//! `alias.table`. Attachments are read-only and live for the session. Each
+//! scan of an attached table reads through its own transaction on the
+//! attached engine, so those reads see that database's committed data and
+//! are not part of the session's transaction.
+
+use std::sync::Arc;
+
+use crate::api::Database;
+use crate::core::{Error, Result};
+use crate::parser::ast::{AttachDatabaseStatement, DetachDatabaseStatement, TableName};
+use crate::storage::traits::{Engine, QueryResult};
+
+use super::result::ExecResult;
+use super::Executor;
+
+impl Executor {
+    /// Execute ATTACH DATABASE
+    pub(crate) fn execute_attach_database(
+        &self,
+        stmt: &AttachDatabaseStatement,
+    ) -> Result<Box<dyn QueryResult>> {
+        let alias = stmt.alias.value_lower.clone();
+        if self.attached.lock().unwrap().contains_key(&alias) {
+            return Err(Error::InvalidArgumentMessage(format!(
+                "database \"{}\" is already attached",
+                stmt.alias.value
+            )));
+        }
+        if self.engine.schema_exists(&alias)? {
+            return Err(Error::InvalidArgumentMessage(format!(
+                "cannot attach database as \"{}\": a schema with that name exists",
+                stmt.alias.value
+            )));
+        }
+
+        let database = Database::open(&stmt.dsn)?;
+        // Attaching the session's own database would keep it alive forever
+        if Arc::ptr_eq(database.engine(), &self.engine) {
+            return Err(Error::InvalidArgumentMessage(format!(
+                "cannot attach the current database ({})",
+                stmt.dsn
+            )));
+        }
+
+        self.attached.lock().unwrap().insert(alias, database);
+        Ok(Box::new(ExecResult::empty()))
+    }
+
+    /// Execute DETACH DATABASE
+    pub(crate) fn execute_detach_database(
+        &self,
+        stmt: &DetachDatabaseStatement,
+    ) -> Result<Box<dyn QueryResult>> {
+        let removed = self
+            .attached
+            .lock()
+            .unwrap()
+            .remove(stmt.alias.value_lower.as_str());
+        match removed {
+            Some(_) => Ok(Box::new(ExecResult::empty())),
+            None => Err(Error::InvalidArgumentMessage(format!(
+                "database \"{}\" is not attached",
+                stmt.alias.value
+            ))),
+        }
+    }
+
+    /// The engine of the attached database a qualified table name points
+    /// into, if its qualifier is an attachment alias
+    pub(crate) fn attached_engine(&self, table_name: &TableName) -> Option<Arc<dyn Engine>> {
+        let TableName::Qualified(qi) = table_name else {
+            return None;
+        };
+        let attached = self.attached.lock().unwrap();
+        attached
+            .get(qi.qualifier.value_lower.as_str())
+            .map(|database| Arc::clone(database.engine()))
+    }
+
+    /// Reject a write to a table of an attached database
+    pub(crate) fn reject_attached_write(&self, table_name: &TableName) -> Result<()> {
+        match table_name {
+            TableName::Qualified(qi) if self.attached_engine(table_name).is_some() => {
+                Err(Error::NotSupportedMessage(format!(
+                    "attached database \"{}\" is read-only",
+                    qi.qualifier.value
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
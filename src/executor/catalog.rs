@@ -0,0 +1,365 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Structured schema catalog
+//!
+//! Collects every user table (with its columns, indexes and constraints),
+//! view and stored routine in one value, read from the same sources as the
+//! information_schema tables and the schema dump. System tables are not
+//! included.
+
+use crate::core::{DataType, IndexType, Result, Schema, Value};
+use crate::parser::ast::ReferentialAction;
+use crate::storage::functions::{StoredParameter, SYS_FUNCTIONS};
+use crate::storage::procedures::{StoredProcedureParameter, SYS_PROCEDURES};
+
+use super::Executor;
+
+/// Every user-visible object in the database
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+    /// Base tables, ordered by schema and name
+    pub tables: Vec<CatalogTable>,
+    /// Views, ordered by schema and name
+    pub views: Vec<CatalogView>,
+    /// Stored functions and procedures, ordered by schema and name
+    pub routines: Vec<CatalogRoutine>,
+}
+
+impl Catalog {
+    /// Find a table by name, optionally qualified with its schema
+    pub fn table(&self, name: &str) -> Option<&CatalogTable> {
+        let (schema, name) = split_qualified(name);
+        self.tables
+            .iter()
+            .find(|t| t.schema.eq_ignore_ascii_case(schema) && t.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Find a view by name, optionally qualified with its schema
+    pub fn view(&self, name: &str) -> Option<&CatalogView> {
+        let (schema, name) = split_qualified(name);
+        self.views
+            .iter()
+            .find(|v| v.schema.eq_ignore_ascii_case(schema) && v.name.eq_ignore_ascii_case(name))
+    }
+}
+
+/// A base table
+#[derive(Debug, Clone)]
+pub struct CatalogTable {
+    pub schema: String,
+    pub name: String,
+    /// Columns in table order
+    pub columns: Vec<CatalogColumn>,
+    /// Indexes, including the ones backing UNIQUE constraints
+    pub indexes: Vec<CatalogIndex>,
+    pub constraints: Vec<CatalogConstraint>,
+}
+
+impl CatalogTable {
+    /// Find a column by name
+    pub fn column(&self, name: &str) -> Option<&CatalogColumn> {
+        self.columns
+            .iter()
+            .find(|c| c.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Find an index by name
+    pub fn index(&self, name: &str) -> Option<&CatalogIndex> {
+        self.indexes
+            .iter()
+            .find(|i| i.name.eq_ignore_ascii_case(name))
+    }
+}
+
+/// A table column
+#[derive(Debug, Clone)]
+pub struct CatalogColumn {
+    pub name: String,
+    pub data_type: DataType,
+    pub nullable: bool,
+    pub primary_key: bool,
+    pub auto_increment: bool,
+    /// DEFAULT expression as written
+    pub default: Option<String>,
+    /// GENERATED ALWAYS AS expression
+    pub generated: Option<String>,
+}
+
+/// An index on a table
+#[derive(Debug, Clone)]
+pub struct CatalogIndex {
+    pub name: String,
+    pub columns: Vec<String>,
+    pub unique: bool,
+    pub index_type: IndexType,
+}
+
+/// A table constraint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CatalogConstraint {
+    PrimaryKey {
+        columns: Vec<String>,
+    },
+    /// Enforced by the unique index `index`
+    Unique {
+        index: String,
+        columns: Vec<String>,
+    },
+    ForeignKey {
+        column: String,
+        referenced_table: String,
+        referenced_column: String,
+        on_delete: ReferentialAction,
+        on_update: ReferentialAction,
+    },
+    Check {
+        name: Option<String>,
+        column: String,
+        expression: String,
+    },
+}
+
+/// A view
+#[derive(Debug, Clone)]
+pub struct CatalogView {
+    pub schema: String,
+    pub name: String,
+    /// The view's SELECT statement
+    pub definition: String,
+}
+
+/// Kind of a stored routine
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoutineKind {
+    Function,
+    Procedure,
+}
+
+/// A stored function or procedure
+#[derive(Debug, Clone)]
+pub struct CatalogRoutine {
+    pub schema: String,
+    pub name: String,
+    pub kind: RoutineKind,
+    pub parameters: Vec<CatalogParameter>,
+    /// Return type of a function; None for procedures
+    pub return_type: Option<String>,
+    pub language: String,
+}
+
+/// A routine parameter
+#[derive(Debug, Clone)]
+pub struct CatalogParameter {
+    pub name: String,
+    pub data_type: String,
+    /// IN, OUT or INOUT for procedures; None for functions
+    pub mode: Option<String>,
+}
+
+impl Executor {
+    /// Collect the tables, views and routines of the database
+    pub fn catalog(&self) -> Result<Catalog> {
+        let mut catalog = Catalog::default();
+        let tx = self.engine.begin_transaction()?;
+        let table_names = tx.list_tables()?;
+
+        for table_name in &table_names {
+            if Schema::is_reserved_namespace(table_name) {
+                continue;
+            }
+            let table = tx.get_table(table_name)?;
+            let (schema_name, name) = split_qualified(table_name);
+
+            // Version stores are keyed by the unqualified table name
+            let mut index_names: Vec<String> =
+                self.engine.list_table_indexes(name)?.into_keys().collect();
+            index_names.sort();
+            let indexes: Vec<CatalogIndex> = index_names
+                .into_iter()
+                .filter_map(|index_name| {
+                    table.get_index(&index_name).map(|index| CatalogIndex {
+                        columns: index.column_names().to_vec(),
+                        unique: index.is_unique(),
+                        index_type: index.index_type(),
+                        name: index_name,
+                    })
+                })
+                .collect();
+
+            let table_schema = table.schema();
+            let columns = table_schema
+                .columns
+                .iter()
+                .map(|col| CatalogColumn {
+                    name: col.name.clone(),
+                    data_type: col.data_type,
+                    nullable: col.nullable,
+                    primary_key: table_schema.is_primary_key_column(&col.name),
+                    auto_increment: col.auto_increment,
+                    default: col.default_expr.clone(),
+                    generated: col.generated_expr.clone(),
+                })
+                .collect();
+
+            let mut constraints = Vec::new();
+            let pk_columns: Vec<String> = table_schema
+                .primary_key_column_names()
+                .into_iter()
+                .map(String::from)
+                .collect();
+            if !pk_columns.is_empty() {
+                constraints.push(CatalogConstraint::PrimaryKey {
+                    columns: pk_columns,
+                });
+            }
+            // The index enforcing a primary key is reported as the key only
+            constraints.extend(
+                indexes
+                    .iter()
+                    .filter(|i| i.unique && !table_schema.is_primary_key_index(&i.name))
+                    .map(|i| CatalogConstraint::Unique {
+                        index: i.name.clone(),
+                        columns: i.columns.clone(),
+                    }),
+            );
+            constraints.extend(table_schema.foreign_keys.iter().map(|fk| {
+                CatalogConstraint::ForeignKey {
+                    column: table_schema.columns[fk.column_id].name.clone(),
+                    referenced_table: fk.referenced_table.clone(),
+                    referenced_column: fk.referenced_column_name.clone(),
+                    on_delete: fk.on_delete,
+                    on_update: fk.on_update,
+                }
+            }));
+            for col in &table_schema.columns {
+                constraints.extend(col.checks.iter().map(|check| CatalogConstraint::Check {
+                    name: check.name.clone(),
+                    column: col.name.clone(),
+                    expression: check.expr.clone(),
+                }));
+            }
+
+            catalog.tables.push(CatalogTable {
+                schema: schema_name.to_string(),
+                name: name.to_string(),
+                columns,
+                indexes,
+                constraints,
+            });
+        }
+        catalog
+            .tables
+            .sort_by(|a, b| (&a.schema, &a.name).cmp(&(&b.schema, &b.name)));
+
+        for (schema_name, view_name) in self.engine.list_views()? {
+            if let Some(view_def) = self.engine.get_view(&schema_name, &view_name)? {
+                catalog.views.push(CatalogView {
+                    schema: schema_name,
+                    name: view_def.original_name.clone(),
+                    definition: view_def.query.clone(),
+                });
+            }
+        }
+        catalog
+            .views
+            .sort_by(|a, b| (&a.schema, &a.name).cmp(&(&b.schema, &b.name)));
+
+        // Stored routines live in system tables
+        let has_table = |name: &str| table_names.iter().any(|t| t.eq_ignore_ascii_case(name));
+
+        if has_table(SYS_FUNCTIONS) {
+            let mut scanner = tx.get_table(SYS_FUNCTIONS)?.scan(&[], None)?;
+            while scanner.next() {
+                let row = scanner.row();
+                // Schema: id(0), schema(1), name(2), parameters(3), return_type(4), language(5), code(6)
+                if let (
+                    Some(Value::Text(name)),
+                    Some(Value::Text(params_json)),
+                    Some(Value::Text(return_type)),
+                    Some(Value::Text(language)),
+                ) = (row.get(2), row.get(3), row.get(4), row.get(5))
+                {
+                    let params: Vec<StoredParameter> =
+                        serde_json::from_str(params_json).unwrap_or_default();
+                    catalog.routines.push(CatalogRoutine {
+                        schema: routine_schema(row.get(1)),
+                        name: name.to_string(),
+                        kind: RoutineKind::Function,
+                        parameters: params
+                            .into_iter()
+                            .map(|p| CatalogParameter {
+                                name: p.name,
+                                data_type: p.data_type,
+                                mode: None,
+                            })
+                            .collect(),
+                        return_type: Some(return_type.to_string()),
+                        language: language.to_string(),
+                    });
+                }
+            }
+        }
+
+        if has_table(SYS_PROCEDURES) {
+            let mut scanner = tx.get_table(SYS_PROCEDURES)?.scan(&[], None)?;
+            while scanner.next() {
+                let row = scanner.row();
+                // Schema: id(0), schema(1), name(2), parameters(3), language(4), code(5)
+                if let (
+                    Some(Value::Text(name)),
+                    Some(Value::Text(params_json)),
+                    Some(Value::Text(language)),
+                ) = (row.get(2), row.get(3), row.get(4))
+                {
+                    let params: Vec<StoredProcedureParameter> =
+                        serde_json::from_str(params_json).unwrap_or_default();
+                    catalog.routines.push(CatalogRoutine {
+                        schema: routine_schema(row.get(1)),
+                        name: name.to_string(),
+                        kind: RoutineKind::Procedure,
+                        parameters: params
+                            .into_iter()
+                            .map(|p| CatalogParameter {
+                                name: p.name,
+                                data_type: p.data_type,
+                                mode: Some(p.mode),
+                            })
+                            .collect(),
+                        return_type: None,
+                        language: language.to_string(),
+                    });
+                }
+            }
+        }
+        catalog
+            .routines
+            .sort_by(|a, b| (&a.schema, &a.name).cmp(&(&b.schema, &b.name)));
+
+        Ok(catalog)
+    }
+}
+
+/// Split `schema.name`; unqualified names belong to `public`
+fn split_qualified(name: &str) -> (&str, &str) {
+    name.split_once('.').unwrap_or(("public", name))
+}
+
+/// A routine's schema column; NULL means `public`
+fn routine_schema(value: Option<&Value>) -> String {
+    match value {
+        Some(Value::Text(schema)) => schema.to_string(),
+        _ => "public".to_string(),
+    }
+}
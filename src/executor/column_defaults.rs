@@ -0,0 +1,115 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Target columns and DEFAULT values of statements that add rows
+//!
+//! INSERT, COPY FROM and INSERT through the transaction API all map their
+//! column list onto the schema and fill the columns they leave out from here,
+//! so a row gets the same values whichever way it is written.
+
+use crate::core::{DataType, Error, Result, Schema, Value};
+use crate::parser::ast::{Expression, Identifier, Statement};
+
+use super::expression::ExpressionEval;
+
+/// Parse an expression stored as SQL text in the schema (a DEFAULT or a
+/// generation expression)
+pub(crate) fn parse_column_expression(expr_str: &str) -> Result<Expression> {
+    let sql = format!("SELECT {}", expr_str);
+    let stmts = crate::parser::parse_sql(&sql)
+        .map_err(|e| Error::internal(format!("invalid column expression '{}': {}", expr_str, e)))?;
+    if let Some(Statement::Select(select)) = stmts.into_iter().next() {
+        if let Some(expr) = select.columns.into_iter().next() {
+            return Ok(expr);
+        }
+    }
+    Err(Error::internal(format!(
+        "invalid column expression '{}'",
+        expr_str
+    )))
+}
+
+/// Schema positions of the columns a statement names, or of every column in
+/// order when it names none
+pub(crate) fn target_column_indices(schema: &Schema, columns: &[Identifier]) -> Result<Vec<usize>> {
+    if columns.is_empty() {
+        return Ok((0..schema.columns.len()).collect());
+    }
+    columns
+        .iter()
+        .map(|id| {
+            schema
+                .columns
+                .iter()
+                .position(|c| c.name.eq_ignore_ascii_case(&id.value_lower))
+                .ok_or_else(|| Error::ColumnNotFoundNamed(id.value.clone()))
+        })
+        .collect()
+}
+
+/// The DEFAULTs of a table that rows are added to
+///
+/// Each DEFAULT is compiled once per statement and evaluated again for every
+/// row, so volatile defaults (sequences, RANDOM(), NOW()) differ between rows.
+pub(crate) struct ColumnDefaults {
+    columns: Vec<Option<(ExpressionEval, DataType)>>,
+}
+
+impl ColumnDefaults {
+    pub(crate) fn new(schema: &Schema) -> Result<Self> {
+        let columns = schema
+            .columns
+            .iter()
+            .map(|col| match &col.default_expr {
+                Some(expr_str) => {
+                    let eval = ExpressionEval::compile(&parse_column_expression(expr_str)?, &[])?;
+                    Ok(Some((eval, col.data_type)))
+                }
+                None => Ok(None),
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { columns })
+    }
+
+    /// The DEFAULT of column `idx`, or NULL when it has none
+    pub(crate) fn value(&mut self, idx: usize) -> Result<Value> {
+        match &mut self.columns[idx] {
+            Some((eval, data_type)) => Ok(eval.eval_slice(&[])?.into_coerce_to_type(*data_type)),
+            None => Ok(Value::null_unknown()),
+        }
+    }
+
+    /// A new row holding the DEFAULT of every column the statement does not
+    /// supply; supplied columns are left NULL for the caller to fill in
+    pub(crate) fn row(&mut self, supplied: &[bool]) -> Result<Vec<Value>> {
+        (0..self.columns.len())
+            .map(|idx| {
+                if supplied[idx] {
+                    Ok(Value::null_unknown())
+                } else {
+                    self.value(idx)
+                }
+            })
+            .collect()
+    }
+}
+
+/// Marks the schema columns in `indices` as supplied by the statement
+pub(crate) fn supplied_columns(column_count: usize, indices: &[usize]) -> Vec<bool> {
+    let mut supplied = vec![false; column_count];
+    for &idx in indices {
+        supplied[idx] = true;
+    }
+    supplied
+}
@@ -0,0 +1,213 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Result column metadata
+//!
+//! Every top-level result with columns is wrapped in a
+//! [`TypedResult`](super::result::TypedResult) that
+//! reports the type and nullability of each column. For a SELECT, columns
+//! read straight from a table (`col`, `t.col`, `*`, `t.*`) take the type and
+//! NOT NULL constraint declared by the table, and COUNT is a non-NULL
+//! INTEGER. Every other column is nullable and typed from its first value,
+//! the same way CREATE TABLE ... AS SELECT infers a schema.
+
+use crate::core::{DataType, Row, Schema};
+use crate::parser::ast::{Expression, GroupByModifier, SelectStatement};
+use crate::storage::traits::ColumnMeta;
+
+use super::Executor;
+
+/// Declared type and nullability of one column (None = infer from the rows)
+pub(crate) type DeclaredColumn = Option<(DataType, bool)>;
+
+/// A table in the FROM clause
+struct SourceTable {
+    /// Lowercase alias, or the table name when there is none
+    alias: Option<String>,
+    /// None for sources without a declared schema (views, subqueries, ...)
+    schema: Option<Schema>,
+    /// On the optional side of an outer join
+    outer: bool,
+}
+
+impl Executor {
+    /// Declared type and nullability of each output column of a SELECT
+    ///
+    /// Returns `output_len` entries; any that cannot be worked out from the
+    /// statement alone are None.
+    pub(crate) fn declared_column_types(
+        &self,
+        stmt: &SelectStatement,
+        output_len: usize,
+    ) -> Vec<DeclaredColumn> {
+        let unknown = vec![None; output_len];
+        // CTEs can shadow table names, and set operations combine types
+        if stmt.with.is_some() || !stmt.set_operations.is_empty() {
+            return unknown;
+        }
+
+        let mut tables = Vec::new();
+        if let Some(table_expr) = stmt.table_expr.as_deref() {
+            self.collect_source_tables(table_expr, false, &mut tables);
+        }
+        // ROLLUP, CUBE and GROUPING SETS emit NULL for rolled-up columns
+        let grouped_nulls = !matches!(stmt.group_by.modifier, GroupByModifier::None);
+
+        let mut declared = Vec::with_capacity(output_len);
+        for column in &stmt.columns {
+            match column {
+                Expression::Star(_) => {
+                    for table in &tables {
+                        let Some(schema) = &table.schema else {
+                            return unknown;
+                        };
+                        declared.extend(schema_columns(schema, table.outer));
+                    }
+                }
+                Expression::QualifiedStar(star) => {
+                    let qualifier = star.qualifier.to_lowercase();
+                    let table = tables
+                        .iter()
+                        .find(|t| t.alias.as_deref() == Some(qualifier.as_str()));
+                    let Some((schema, outer)) =
+                        table.and_then(|t| t.schema.as_ref().map(|schema| (schema, t.outer)))
+                    else {
+                        return unknown;
+                    };
+                    declared.extend(schema_columns(schema, outer));
+                }
+                expr => declared.push(
+                    declared_expr_type(expr, &tables)
+                        .map(|(data_type, nullable)| (data_type, nullable || grouped_nulls)),
+                ),
+            }
+        }
+
+        // USING and NATURAL joins merge columns; don't guess which ones
+        if declared.len() != output_len {
+            return unknown;
+        }
+        declared
+    }
+
+    fn collect_source_tables(&self, expr: &Expression, outer: bool, out: &mut Vec<SourceTable>) {
+        match expr {
+            Expression::TableSource(source) => {
+                let alias = source
+                    .alias
+                    .as_ref()
+                    .map(|alias| alias.value_lower())
+                    .unwrap_or_else(|| source.name.table().to_lowercase());
+                out.push(SourceTable {
+                    alias: Some(alias),
+                    schema: self
+                        .engine
+                        .get_table_schema(&source.name.value_lower())
+                        .ok(),
+                    outer,
+                });
+            }
+            Expression::JoinSource(join) => {
+                let join_type = join.join_type.to_uppercase();
+                let full = join_type.contains("FULL");
+                self.collect_source_tables(
+                    &join.left,
+                    outer || full || join_type.contains("RIGHT"),
+                    out,
+                );
+                self.collect_source_tables(
+                    &join.right,
+                    outer || full || join_type.contains("LEFT"),
+                    out,
+                );
+            }
+            _ => out.push(SourceTable {
+                alias: None,
+                schema: None,
+                outer,
+            }),
+        }
+    }
+}
+
+/// Declared columns of a table, in order
+fn schema_columns(schema: &Schema, outer: bool) -> impl Iterator<Item = DeclaredColumn> + '_ {
+    schema
+        .columns
+        .iter()
+        .map(move |col| Some((col.data_type, col.nullable || outer)))
+}
+
+fn declared_expr_type(expr: &Expression, tables: &[SourceTable]) -> DeclaredColumn {
+    let lookup = |table: &SourceTable, name: &str| {
+        let schema = table.schema.as_ref()?;
+        let col = schema
+            .columns
+            .iter()
+            .find(|col| col.name.eq_ignore_ascii_case(name))?;
+        Some((col.data_type, col.nullable || table.outer))
+    };
+
+    match expr {
+        Expression::Aliased(aliased) => declared_expr_type(&aliased.expression, tables),
+        Expression::QualifiedIdentifier(qid) => {
+            let qualifier = qid.qualifier.value_lower();
+            let table = tables
+                .iter()
+                .find(|t| t.alias.as_deref() == Some(qualifier.as_str()))?;
+            lookup(table, &qid.name.value)
+        }
+        Expression::Identifier(id) => {
+            // An unqualified name is only certain if no other source could
+            // provide it
+            if tables.iter().any(|t| t.schema.is_none()) {
+                return None;
+            }
+            let mut matches = tables.iter().filter_map(|t| lookup(t, &id.value));
+            let found = matches.next()?;
+            matches.next().is_none().then_some(found)
+        }
+        Expression::FunctionCall(call) if call.function.eq_ignore_ascii_case("COUNT") => {
+            Some((DataType::Integer, false))
+        }
+        _ => None,
+    }
+}
+
+/// Fills in the undeclared columns from the first row
+pub(crate) fn build_columns_meta(
+    columns: &[String],
+    declared: &[DeclaredColumn],
+    first_row: Option<&Row>,
+) -> Vec<ColumnMeta> {
+    let rows = first_row.map(std::slice::from_ref).unwrap_or_default();
+    let inferred = Executor::infer_result_columns(columns, rows);
+    columns
+        .iter()
+        .zip(inferred)
+        .enumerate()
+        .map(|(i, (name, (_, inferred_type)))| {
+            let (data_type, nullable) = declared
+                .get(i)
+                .copied()
+                .flatten()
+                .unwrap_or((inferred_type, true));
+            ColumnMeta {
+                name: name.clone(),
+                data_type,
+                nullable,
+            }
+        })
+        .collect()
+}
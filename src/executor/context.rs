@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -27,7 +28,7 @@ use std::time::{Duration, Instant};
 /// Default schema name
 const DEFAULT_SCHEMA: &str = "public";
 
-use crate::core::{Result, Row, Schema, Value};
+use crate::core::{Error, Result, Row, Schema, Value};
 
 // Cache for scalar subquery results to avoid re-execution.
 // Thread-local to avoid synchronization overhead.
@@ -294,6 +295,11 @@ pub struct ExecutionContext {
     cte_data: Option<Arc<CteDataMap>>,
     /// Current transaction ID for CURRENT_TRANSACTION_ID() function
     transaction_id: Option<u64>,
+    /// Division and modulo by zero yield NULL instead of an error
+    /// (SET div_by_zero = 'null')
+    div_by_zero_null: bool,
+    /// Optimizer hints of the query block being executed (not inherited by subqueries)
+    hints: Option<Arc<HintPlan>>,
 }
 
 /// Type alias for CTE data map to reduce type complexity
@@ -301,6 +307,8 @@ type CteDataMap = FxHashMap<String, (Vec<String>, Vec<Row>)>;
 
 use crate::storage::traits::Engine;
 
+use super::hints::HintPlan;
+
 impl Default for ExecutionContext {
     fn default() -> Self {
         Self::new()
@@ -325,6 +333,8 @@ impl ExecutionContext {
             outer_columns: None,
             cte_data: None,
             transaction_id: None,
+            div_by_zero_null: false,
+            hints: None,
         }
     }
 
@@ -361,6 +371,17 @@ impl ExecutionContext {
         self.params.len()
     }
 
+    /// Validate the bound positional parameters against a query
+    ///
+    /// `expected` is the highest placeholder index ($N or ?) the query
+    /// references. Binding fewer or more parameters is an error.
+    pub fn validate_param_count(&self, expected: usize) -> Result<()> {
+        if self.params.len() != expected {
+            return Err(Error::parameter_count_mismatch(expected, self.params.len()));
+        }
+        Ok(())
+    }
+
     pub fn params(&self) -> &[Value] {
         &self.params
     }
@@ -417,6 +438,19 @@ impl ExecutionContext {
         self.timeout_ms
     }
 
+    pub fn set_timeout_ms(&mut self, timeout_ms: u64) {
+        self.timeout_ms = timeout_ms;
+    }
+
+    /// Whether division and modulo by zero yield NULL instead of an error
+    pub fn div_by_zero_null(&self) -> bool {
+        self.div_by_zero_null
+    }
+
+    pub fn set_div_by_zero_null(&mut self, null: bool) {
+        self.div_by_zero_null = null;
+    }
+
     pub fn view_depth(&self) -> usize {
         self.view_depth
     }
@@ -457,6 +491,8 @@ impl ExecutionContext {
             outer_columns: self.outer_columns.clone(),
             cte_data: self.cte_data.clone(),
             transaction_id: self.transaction_id,
+            div_by_zero_null: self.div_by_zero_null,
+            hints: None,
         }
     }
 
@@ -478,6 +514,8 @@ impl ExecutionContext {
             outer_columns: self.outer_columns.clone(),
             cte_data: self.cte_data.clone(),
             transaction_id: self.transaction_id,
+            div_by_zero_null: self.div_by_zero_null,
+            hints: None,
         }
     }
 
@@ -514,6 +552,8 @@ impl ExecutionContext {
             outer_columns: Some(outer_columns), // Arc clone = cheap
             cte_data: self.cte_data.clone(),    // Arc clone = cheap
             transaction_id: self.transaction_id,
+            div_by_zero_null: self.div_by_zero_null,
+            hints: None,
         }
     }
 
@@ -550,6 +590,8 @@ impl ExecutionContext {
             outer_columns: self.outer_columns.clone(),
             cte_data: Some(cte_data),
             transaction_id: self.transaction_id,
+            div_by_zero_null: self.div_by_zero_null,
+            hints: self.hints.clone(),
         }
     }
 
@@ -580,15 +622,48 @@ impl ExecutionContext {
             outer_columns: self.outer_columns.clone(),
             cte_data: self.cte_data.clone(),
             transaction_id: Some(txn_id),
+            div_by_zero_null: self.div_by_zero_null,
+            hints: self.hints.clone(),
+        }
+    }
+
+    /// Get the optimizer hints of the current query block
+    pub(crate) fn hints(&self) -> Option<&HintPlan> {
+        self.hints.as_deref()
+    }
+
+    /// Create a new context carrying the optimizer hints of a query block
+    pub(crate) fn with_hints(&self, hints: Option<Arc<HintPlan>>) -> Self {
+        Self {
+            params: self.params.clone(),
+            named_params: self.named_params.clone(),
+            auto_commit: self.auto_commit,
+            cancelled: self.cancelled.clone(),
+            current_schema: self.current_schema.clone(),
+            session_vars: self.session_vars.clone(),
+            timeout_ms: self.timeout_ms,
+            is_internal: self.is_internal,
+            view_depth: self.view_depth,
+            query_depth: self.query_depth,
+            outer_row: self.outer_row.clone(),
+            outer_columns: self.outer_columns.clone(),
+            cte_data: self.cte_data.clone(),
+            transaction_id: self.transaction_id,
+            div_by_zero_null: self.div_by_zero_null,
+            hints,
         }
     }
 
     /// Check for cancellation and return an error if cancelled
+    ///
+    /// Queries running with a timeout report `QueryTimeout` instead of `QueryCancelled`.
     pub fn check_cancelled(&self) -> Result<()> {
-        if self.is_cancelled() {
-            Err(crate::core::Error::QueryCancelled)
-        } else {
+        if !self.is_cancelled() {
             Ok(())
+        } else if self.timeout_ms > 0 {
+            Err(crate::core::Error::QueryTimeout(self.timeout_ms))
+        } else {
+            Err(crate::core::Error::QueryCancelled)
         }
     }
 }
@@ -13,12 +13,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! COPY FROM Statement Execution
+//! COPY FROM / COPY TO Statement Execution
 
-use crate::core::{DataType, Error, Result, Row, Value};
-use crate::parser::ast::{CopyFormat, CopyStatement};
-use crate::storage::traits::{Engine, QueryResult, Table};
+use crate::core::{CheckConstraint, DataType, Error, Result, Row, Value};
+use crate::parser::ast::{CopyDirection, CopyFormat, CopyStatement};
+use crate::storage::traits::{QueryResult, Table};
 
+use super::column_defaults::{supplied_columns, target_column_indices, ColumnDefaults};
 use super::context::ExecutionContext;
 use super::result::ExecResult;
 use super::Executor;
@@ -105,7 +106,7 @@ impl Executor {
             let active_tx = self.active_transaction.lock().unwrap();
             if active_tx.is_some() {
                 return Err(Error::InvalidArgument {
-                    message: "COPY FROM cannot be used inside an explicit transaction".to_string(),
+                    message: "COPY cannot be used inside an explicit transaction".to_string(),
                 });
             }
         }
@@ -122,55 +123,46 @@ impl Executor {
 
         let mut table = tx.get_table(table_name)?;
         let schema = table.schema();
-        let schema_column_count = schema.columns.len();
-
-        let column_indices: Vec<usize>;
-        let column_types: Vec<DataType>;
-        let column_names: Vec<String>;
         let all_column_types: Vec<DataType> = schema.columns.iter().map(|c| c.data_type).collect();
 
-        let default_exprs: Vec<Option<String>> = schema
-            .columns
+        let column_indices = target_column_indices(schema, &stmt.columns)?;
+        let column_types: Vec<DataType> = column_indices
+            .iter()
+            .map(|&idx| schema.columns[idx].data_type)
+            .collect();
+        let column_names: Vec<String> = column_indices
             .iter()
-            .map(|c| c.default_expr.clone())
+            .map(|&idx| schema.columns[idx].name.clone())
             .collect();
-        let check_exprs: Vec<(usize, String, String)> = schema
+
+        let mut defaults = ColumnDefaults::new(schema)?;
+        let check_exprs: Vec<(usize, String, CheckConstraint)> = schema
             .columns
             .iter()
             .enumerate()
-            .filter_map(|(idx, c)| {
-                c.check_expr
-                    .as_ref()
-                    .map(|expr| (idx, c.name.clone(), expr.clone()))
+            .flat_map(|(idx, c)| {
+                c.checks
+                    .iter()
+                    .map(move |check| (idx, c.name.clone(), check.clone()))
             })
             .collect();
 
-        if stmt.columns.is_empty() {
-            column_indices = (0..schema_column_count).collect();
-            column_types = all_column_types.clone();
-            column_names = schema.column_names_owned().to_vec();
-        } else {
-            let col_map = schema.column_index_map();
-            column_indices = stmt
-                .columns
-                .iter()
-                .map(|id| {
-                    col_map
-                        .get(id.value_lower.as_str())
-                        .copied()
-                        .ok_or_else(|| Error::ColumnNotFoundByName {
-                            name: id.value.to_string(),
-                        })
-                })
-                .collect::<Result<Vec<_>>>()?;
-            column_types = column_indices
-                .iter()
-                .map(|&idx| schema.columns[idx].data_type)
-                .collect();
-            column_names = column_indices
-                .iter()
-                .map(|&idx| schema.columns[idx].name.clone())
-                .collect();
+        if stmt.direction == CopyDirection::To {
+            let rows_written = self.copy_to(
+                stmt,
+                table.as_ref(),
+                &column_indices,
+                &column_names,
+                &column_types,
+            )?;
+            tx.commit()?;
+            return Ok(Box::new(ExecResult::with_rows_affected(rows_written)));
+        }
+
+        if schema.columns.iter().any(|c| c.generated_expr.is_some()) {
+            return Err(Error::NotSupportedMessage(
+                "COPY FROM into a table with generated columns is not supported".to_string(),
+            ));
         }
 
         let rows_affected = match stmt.format {
@@ -180,9 +172,8 @@ impl Executor {
                 &column_indices,
                 &column_names,
                 &all_column_types,
-                &default_exprs,
+                &mut defaults,
                 &check_exprs,
-                schema_column_count,
             )?,
             CopyFormat::Json => self.copy_from_json(
                 stmt,
@@ -191,10 +182,25 @@ impl Executor {
                 &column_types,
                 &column_names,
                 &all_column_types,
-                &default_exprs,
+                &mut defaults,
+                &check_exprs,
+            )?,
+            #[cfg(feature = "parquet")]
+            CopyFormat::Parquet => self.copy_from_parquet(
+                stmt,
+                &mut table,
+                &column_indices,
+                &column_names,
+                &all_column_types,
+                &mut defaults,
                 &check_exprs,
-                schema_column_count,
             )?,
+            #[cfg(not(feature = "parquet"))]
+            CopyFormat::Parquet => {
+                return Err(Error::NotSupportedMessage(
+                    "FORMAT PARQUET requires the 'parquet' feature".to_string(),
+                ))
+            }
         };
 
         if rows_affected > 0 {
@@ -205,6 +211,31 @@ impl Executor {
         Ok(Box::new(ExecResult::with_rows_affected(rows_affected)))
     }
 
+    #[cfg_attr(not(feature = "parquet"), allow(unused_variables))]
+    fn copy_to(
+        &self,
+        stmt: &CopyStatement,
+        table: &dyn Table,
+        column_indices: &[usize],
+        column_names: &[String],
+        column_types: &[DataType],
+    ) -> Result<i64> {
+        match stmt.format {
+            #[cfg(feature = "parquet")]
+            CopyFormat::Parquet => {
+                self.copy_to_parquet(stmt, table, column_indices, column_names, column_types)
+            }
+            #[cfg(not(feature = "parquet"))]
+            CopyFormat::Parquet => Err(Error::NotSupportedMessage(
+                "FORMAT PARQUET requires the 'parquet' feature".to_string(),
+            )),
+            _ => Err(Error::NotSupportedMessage(format!(
+                "COPY TO with FORMAT {}",
+                stmt.format
+            ))),
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn copy_from_csv(
         &self,
@@ -213,9 +244,8 @@ impl Executor {
         column_indices: &[usize],
         column_names: &[String],
         all_column_types: &[DataType],
-        default_exprs: &[Option<String>],
-        check_exprs: &[(usize, String, String)],
-        schema_column_count: usize,
+        defaults: &mut ColumnDefaults,
+        check_exprs: &[(usize, String, CheckConstraint)],
     ) -> Result<i64> {
         let file = std::fs::File::open(&stmt.file_path).map_err(|e| Error::InvalidArgument {
             message: format!("cannot open file '{}': {}", stmt.file_path, e),
@@ -252,14 +282,13 @@ impl Executor {
 
         let null_str = stmt.null_string.as_deref().unwrap_or("");
         let mut rows_affected = 0i64;
-        let default_row =
-            build_default_row(self, default_exprs, all_column_types, schema_column_count);
+        let effective_indices = field_to_col.as_deref().unwrap_or(column_indices);
+        let supplied = supplied_columns(all_column_types.len(), effective_indices);
 
         for result in reader.records() {
             let record = result.map_err(|e| Error::InvalidArgument {
                 message: format!("CSV parse error at row {}: {}", rows_affected + 1, e),
             })?;
-            let effective_indices = field_to_col.as_deref().unwrap_or(column_indices);
 
             if record.len() != effective_indices.len() {
                 return Err(Error::InvalidArgument {
@@ -272,7 +301,7 @@ impl Executor {
                 });
             }
 
-            let mut row_values = default_row.clone();
+            let mut row_values = defaults.row(&supplied)?;
 
             for (i, field) in record.iter().enumerate() {
                 let col_idx = effective_indices[i];
@@ -311,14 +340,11 @@ impl Executor {
         column_types: &[DataType],
         column_names: &[String],
         all_column_types: &[DataType],
-        default_exprs: &[Option<String>],
-        check_exprs: &[(usize, String, String)],
-        schema_column_count: usize,
+        defaults: &mut ColumnDefaults,
+        check_exprs: &[(usize, String, CheckConstraint)],
     ) -> Result<i64> {
         let null_str = stmt.null_string.as_deref();
         let use_columns = !stmt.columns.is_empty();
-        let default_row =
-            build_default_row(self, default_exprs, all_column_types, schema_column_count);
 
         let col_name_lower_map: Vec<(String, usize)> = if use_columns {
             stmt.columns
@@ -353,7 +379,7 @@ impl Executor {
             self.insert_json_row(
                 obj,
                 table,
-                &default_row,
+                defaults,
                 &col_name_lower_map,
                 use_columns,
                 column_types,
@@ -371,22 +397,24 @@ impl Executor {
         &self,
         obj: &serde_json::Map<String, serde_json::Value>,
         table: &mut Box<dyn Table>,
-        default_row: &[Value],
+        defaults: &mut ColumnDefaults,
         col_name_lower_map: &[(String, usize)],
         use_columns: bool,
         column_types: &[DataType],
         all_column_types: &[DataType],
         null_str: Option<&str>,
-        check_exprs: &[(usize, String, String)],
+        check_exprs: &[(usize, String, CheckConstraint)],
     ) -> Result<()> {
-        let mut row_values = default_row.to_vec();
-
+        // Keys missing from the object take the column's DEFAULT
+        let mut supplied_values = Vec::with_capacity(col_name_lower_map.len());
         if use_columns {
             for (i, (lower_name, col_idx)) in col_name_lower_map.iter().enumerate() {
                 let target_type = column_types[i];
                 if let Some(v) = find_json_key_ci(obj, lower_name) {
-                    row_values[*col_idx] =
-                        json_value_to_oxibase(v, target_type, lower_name, null_str)?;
+                    supplied_values.push((
+                        *col_idx,
+                        json_value_to_oxibase(v, target_type, lower_name, null_str)?,
+                    ));
                 }
             }
         } else {
@@ -397,12 +425,21 @@ impl Executor {
                     .find(|(name, _)| *name == lower_key)
                 {
                     let target_type = all_column_types[col_idx];
-                    row_values[col_idx] =
-                        json_value_to_oxibase(json_val, target_type, &lower_key, null_str)?;
+                    supplied_values.push((
+                        col_idx,
+                        json_value_to_oxibase(json_val, target_type, &lower_key, null_str)?,
+                    ));
                 }
             }
         }
 
+        let supplied_indices: Vec<usize> = supplied_values.iter().map(|(idx, _)| *idx).collect();
+        let mut row_values =
+            defaults.row(&supplied_columns(all_column_types.len(), &supplied_indices))?;
+        for (col_idx, value) in supplied_values {
+            row_values[col_idx] = value;
+        }
+
         for (col_idx, col_name, check_expr) in check_exprs {
             let col_type = all_column_types[*col_idx];
             self.validate_check_constraint(check_expr, col_name, &row_values[*col_idx], col_type)?;
@@ -414,27 +451,6 @@ impl Executor {
     }
 }
 
-fn build_default_row(
-    executor: &Executor,
-    default_exprs: &[Option<String>],
-    all_column_types: &[DataType],
-    schema_column_count: usize,
-) -> Vec<Value> {
-    let mut row = Vec::with_capacity(schema_column_count);
-    for i in 0..schema_column_count {
-        if let Some(ref default_expr) = default_exprs[i] {
-            let default_type = all_column_types[i];
-            match executor.evaluate_default_expr(default_expr, default_type) {
-                Ok(val) => row.push(val),
-                Err(_) => row.push(Value::null_unknown()),
-            }
-        } else {
-            row.push(Value::null_unknown());
-        }
-    }
-    row
-}
-
 #[inline]
 fn find_json_key_ci<'a>(
     obj: &'a serde_json::Map<String, serde_json::Value>,
@@ -0,0 +1,325 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! COPY ... (FORMAT PARQUET)
+//!
+//! Tables are written through Arrow record batches using the type mapping in
+//! `core::arrow`. The Arrow schema is embedded in the Parquet file, so JSON
+//! and INTERVAL columns come back with their original types on import.
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    Date32Type, Date64Type, DurationMicrosecondType, DurationMillisecondType,
+    DurationNanosecondType, DurationSecondType, Float16Type, Float32Type, Float64Type, Int16Type,
+    Int32Type, Int64Type, Int8Type, TimestampMicrosecondType, TimestampMillisecondType,
+    TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+};
+use arrow_array::{Array, RecordBatch};
+use arrow_schema::{DataType as ArrowType, Field, Schema, SchemaRef, TimeUnit};
+use chrono::{DateTime, Utc};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+
+use crate::core::arrow::{arrow_field, values_to_array, EXTENSION_NAME_KEY, JSON_EXTENSION_NAME};
+use crate::core::{CheckConstraint, DataType, Error, Result, Row, Value};
+use crate::parser::ast::CopyStatement;
+use crate::storage::traits::Table;
+
+use super::column_defaults::{supplied_columns, ColumnDefaults};
+use super::Executor;
+
+/// Rows per record batch / row group chunk
+const BATCH_SIZE: usize = 8192;
+
+fn parquet_error(path: &str, err: impl std::fmt::Display) -> Error {
+    Error::InvalidArgument {
+        message: format!("Parquet error in '{}': {}", path, err),
+    }
+}
+
+impl Executor {
+    /// Write the selected columns of `table` to a Parquet file
+    pub(crate) fn copy_to_parquet(
+        &self,
+        stmt: &CopyStatement,
+        table: &dyn Table,
+        column_indices: &[usize],
+        column_names: &[String],
+        column_types: &[DataType],
+    ) -> Result<i64> {
+        let path = stmt.file_path.as_str();
+        let schema: SchemaRef = Arc::new(Schema::new(
+            column_names
+                .iter()
+                .zip(column_types)
+                .map(|(name, data_type)| arrow_field(name, *data_type))
+                .collect::<Vec<_>>(),
+        ));
+
+        let file = std::fs::File::create(path).map_err(|e| Error::InvalidArgument {
+            message: format!("cannot create file '{}': {}", path, e),
+        })?;
+        let props = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+        let mut writer = ArrowWriter::try_new(file, Arc::clone(&schema), Some(props))
+            .map_err(|e| parquet_error(path, e))?;
+
+        let write_batch = |writer: &mut ArrowWriter<std::fs::File>, rows: &[Row]| -> Result<()> {
+            let columns = schema
+                .fields()
+                .iter()
+                .zip(column_indices)
+                .map(|(field, &idx)| {
+                    values_to_array(field, rows.len(), rows.iter().map(|row| row.get(idx)))
+                })
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(|e| parquet_error(path, e))?;
+            let batch = RecordBatch::try_new(Arc::clone(&schema), columns)
+                .map_err(|e| parquet_error(path, e))?;
+            writer.write(&batch).map_err(|e| parquet_error(path, e))
+        };
+
+        // Full rows: the scanner does not always apply the projection
+        let mut scanner = table.scan(&[], None)?;
+        let mut batch = Vec::with_capacity(BATCH_SIZE);
+        let mut rows_written = 0i64;
+        while scanner.next() {
+            batch.push(scanner.row().clone());
+            if batch.len() == BATCH_SIZE {
+                write_batch(&mut writer, &batch)?;
+                rows_written += batch.len() as i64;
+                batch.clear();
+            }
+        }
+        if let Some(err) = scanner.err() {
+            return Err(err.clone());
+        }
+        scanner.close()?;
+
+        if !batch.is_empty() {
+            write_batch(&mut writer, &batch)?;
+            rows_written += batch.len() as i64;
+        }
+        writer.close().map_err(|e| parquet_error(path, e))?;
+        Ok(rows_written)
+    }
+
+    /// Load a Parquet file into `table`, matching columns by name
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn copy_from_parquet(
+        &self,
+        stmt: &CopyStatement,
+        table: &mut Box<dyn Table>,
+        column_indices: &[usize],
+        column_names: &[String],
+        all_column_types: &[DataType],
+        defaults: &mut ColumnDefaults,
+        check_exprs: &[(usize, String, CheckConstraint)],
+    ) -> Result<i64> {
+        let path = stmt.file_path.as_str();
+        let file = std::fs::File::open(path).map_err(|e| Error::InvalidArgument {
+            message: format!("cannot open file '{}': {}", path, e),
+        })?;
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new(file).map_err(|e| parquet_error(path, e))?;
+
+        // Map each file column to a table column; with an explicit column
+        // list, only those columns are read and the rest keep their defaults
+        let file_schema = Arc::clone(builder.schema());
+        let mut mapping: Vec<(usize, usize)> = Vec::new();
+        if stmt.columns.is_empty() {
+            let col_map = table.schema().column_index_map().clone();
+            for (field_idx, field) in file_schema.fields().iter().enumerate() {
+                let col_idx = col_map
+                    .get(field.name().to_lowercase().as_str())
+                    .copied()
+                    .ok_or_else(|| Error::ColumnNotFoundByName {
+                        name: field.name().to_string(),
+                    })?;
+                mapping.push((field_idx, col_idx));
+            }
+        } else {
+            for (name, &col_idx) in column_names.iter().zip(column_indices) {
+                let field_idx = file_schema
+                    .fields()
+                    .iter()
+                    .position(|f| f.name().eq_ignore_ascii_case(name))
+                    .ok_or_else(|| Error::InvalidArgument {
+                        message: format!("column '{}' not found in '{}'", name, path),
+                    })?;
+                mapping.push((field_idx, col_idx));
+            }
+        }
+
+        let reader = builder
+            .with_batch_size(BATCH_SIZE)
+            .build()
+            .map_err(|e| parquet_error(path, e))?;
+        let mapped: Vec<usize> = mapping.iter().map(|&(_, col_idx)| col_idx).collect();
+        let supplied = supplied_columns(all_column_types.len(), &mapped);
+        let mut rows_affected = 0i64;
+
+        for batch in reader {
+            let batch = batch.map_err(|e| parquet_error(path, e))?;
+            for row in 0..batch.num_rows() {
+                let mut row_values = defaults.row(&supplied)?;
+                for &(field_idx, col_idx) in &mapping {
+                    let field = file_schema.field(field_idx);
+                    let value = array_value(field, batch.column(field_idx).as_ref(), row)?;
+                    let target_type = all_column_types[col_idx];
+                    let coerced = value.coerce_to_type(target_type);
+                    if !value.is_null() && coerced.is_null() {
+                        return Err(Error::Type(format!(
+                            "cannot convert value '{}' to {:?} for column '{}'",
+                            value,
+                            target_type,
+                            field.name()
+                        )));
+                    }
+                    row_values[col_idx] = coerced;
+                }
+
+                for (col_idx, col_name, check_expr) in check_exprs {
+                    let col_type = all_column_types[*col_idx];
+                    self.validate_check_constraint(
+                        check_expr,
+                        col_name,
+                        &row_values[*col_idx],
+                        col_type,
+                    )?;
+                }
+
+                table.insert(Row::from_values(row_values))?;
+                rows_affected += 1;
+            }
+        }
+        Ok(rows_affected)
+    }
+}
+
+fn timestamp_from_micros(field: &Field, micros: i64) -> Result<Value> {
+    DateTime::<Utc>::from_timestamp_micros(micros)
+        .map(Value::Timestamp)
+        .ok_or_else(|| {
+            Error::Type(format!(
+                "timestamp out of range in column '{}'",
+                field.name()
+            ))
+        })
+}
+
+/// Read the value at `row` of an Arrow array
+///
+/// Accepts the types written by `COPY TO` plus the narrower integer, float,
+/// string, date and time unit variants found in files from other tools.
+fn array_value(field: &Field, array: &dyn Array, row: usize) -> Result<Value> {
+    if array.is_null(row) {
+        return Ok(Value::null_unknown());
+    }
+    let value = match array.data_type() {
+        ArrowType::Int8 => Value::Integer(array.as_primitive::<Int8Type>().value(row).into()),
+        ArrowType::Int16 => Value::Integer(array.as_primitive::<Int16Type>().value(row).into()),
+        ArrowType::Int32 => Value::Integer(array.as_primitive::<Int32Type>().value(row).into()),
+        ArrowType::Int64 => Value::Integer(array.as_primitive::<Int64Type>().value(row)),
+        ArrowType::UInt8 => Value::Integer(array.as_primitive::<UInt8Type>().value(row).into()),
+        ArrowType::UInt16 => Value::Integer(array.as_primitive::<UInt16Type>().value(row).into()),
+        ArrowType::UInt32 => Value::Integer(array.as_primitive::<UInt32Type>().value(row).into()),
+        ArrowType::UInt64 => {
+            let v = array.as_primitive::<UInt64Type>().value(row);
+            Value::Integer(i64::try_from(v).map_err(|_| {
+                Error::Type(format!(
+                    "value {} out of INTEGER range in column '{}'",
+                    v,
+                    field.name()
+                ))
+            })?)
+        }
+        ArrowType::Float16 => Value::Float(array.as_primitive::<Float16Type>().value(row).into()),
+        ArrowType::Float32 => Value::Float(array.as_primitive::<Float32Type>().value(row).into()),
+        ArrowType::Float64 => Value::Float(array.as_primitive::<Float64Type>().value(row)),
+        ArrowType::Boolean => Value::Boolean(array.as_boolean().value(row)),
+        ArrowType::Utf8 | ArrowType::LargeUtf8 | ArrowType::Utf8View => {
+            let s = match array.data_type() {
+                ArrowType::Utf8 => array.as_string::<i32>().value(row),
+                ArrowType::LargeUtf8 => array.as_string::<i64>().value(row),
+                _ => array.as_string_view().value(row),
+            };
+            let is_json = field
+                .metadata()
+                .get(EXTENSION_NAME_KEY)
+                .is_some_and(|name| name == JSON_EXTENSION_NAME);
+            if is_json {
+                Value::json(s)
+            } else {
+                Value::text(s)
+            }
+        }
+        ArrowType::Binary => Value::blob(array.as_binary::<i32>().value(row)),
+        ArrowType::LargeBinary => Value::blob(array.as_binary::<i64>().value(row)),
+        ArrowType::Timestamp(unit, _) => {
+            let micros = match unit {
+                TimeUnit::Second => array
+                    .as_primitive::<TimestampSecondType>()
+                    .value(row)
+                    .saturating_mul(1_000_000),
+                TimeUnit::Millisecond => array
+                    .as_primitive::<TimestampMillisecondType>()
+                    .value(row)
+                    .saturating_mul(1_000),
+                TimeUnit::Microsecond => {
+                    array.as_primitive::<TimestampMicrosecondType>().value(row)
+                }
+                TimeUnit::Nanosecond => {
+                    array.as_primitive::<TimestampNanosecondType>().value(row) / 1_000
+                }
+            };
+            timestamp_from_micros(field, micros)?
+        }
+        ArrowType::Date32 => {
+            let days = i64::from(array.as_primitive::<Date32Type>().value(row));
+            timestamp_from_micros(field, days * 86_400_000_000)?
+        }
+        ArrowType::Date64 => {
+            let millis = array.as_primitive::<Date64Type>().value(row);
+            timestamp_from_micros(field, millis.saturating_mul(1_000))?
+        }
+        ArrowType::Duration(unit) => Value::Interval(match unit {
+            TimeUnit::Second => array
+                .as_primitive::<DurationSecondType>()
+                .value(row)
+                .saturating_mul(1_000_000),
+            TimeUnit::Millisecond => array
+                .as_primitive::<DurationMillisecondType>()
+                .value(row)
+                .saturating_mul(1_000),
+            TimeUnit::Microsecond => array.as_primitive::<DurationMicrosecondType>().value(row),
+            TimeUnit::Nanosecond => {
+                array.as_primitive::<DurationNanosecondType>().value(row) / 1_000
+            }
+        }),
+        other => {
+            return Err(Error::NotSupportedMessage(format!(
+                "Parquet column '{}' of type {}",
+                field.name(),
+                other
+            )))
+        }
+    };
+    Ok(value)
+}
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -292,6 +293,8 @@ impl Executor {
             limit: None,
             offset: None,
             set_operations: vec![],
+            hints: vec![],
+            lock: None,
         };
 
         let result = self.execute_select(&anchor_stmt, ctx)?;
@@ -431,6 +434,8 @@ impl Executor {
             limit: stmt.limit.clone(),
             offset: stmt.offset.clone(),
             set_operations: stmt.set_operations.clone(),
+            hints: stmt.hints.clone(),
+            lock: stmt.lock,
         };
 
         self.execute_select(&stmt_without_with, &ctx_with_ctes)
@@ -590,6 +595,7 @@ impl Executor {
                     &join_type,
                     left_qualified.len(),
                     right_qualified.len(),
+                    ctx,
                     join_limit,
                 )?;
 
@@ -1142,7 +1148,8 @@ impl Executor {
             // CRITICAL: Include params from context for parameterized queries
             let exec_ctx = ExecuteContext::new(row_data)
                 .with_params(ctx.params())
-                .with_named_params(ctx.named_params());
+                .with_named_params(ctx.named_params())
+                .with_div_by_zero_null(ctx.div_by_zero_null());
 
             for compiled in &compiled_columns {
                 match compiled {
@@ -1174,7 +1181,7 @@ impl Executor {
     }
 
     /// Apply ORDER BY to rows
-    fn apply_order_by_to_rows(
+    pub(crate) fn apply_order_by_to_rows(
         &self,
         mut rows: Vec<Row>,
         order_by: &[crate::parser::ast::OrderByExpression],
@@ -0,0 +1,176 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Server-side cursors (DECLARE / FETCH / CLOSE)
+//!
+//! A cursor holds the open `QueryResult` of its query, so clients can page
+//! through large results without buffering them. Cursors declared inside a
+//! transaction read from that transaction's snapshot and are closed when it
+//! ends; `WITH HOLD` cursors survive the commit by materializing their
+//! remaining rows.
+
+use std::sync::MutexGuard;
+
+use rustc_hash::FxHashMap;
+
+use crate::core::{Error, Result, Row};
+use crate::parser::ast::{CloseCursorStatement, DeclareCursorStatement, FetchStatement};
+use crate::storage::traits::QueryResult;
+
+use super::context::ExecutionContext;
+use super::result::{ExecResult, ExecutorMemoryResult};
+use super::Executor;
+
+/// An open cursor
+pub(crate) struct OpenCursor {
+    result: Box<dyn QueryResult>,
+    with_hold: bool,
+    /// Declared in the current explicit transaction
+    in_transaction: bool,
+}
+
+/// Drain the remaining rows of a result into memory
+fn materialize(mut result: Box<dyn QueryResult>) -> Box<dyn QueryResult> {
+    let columns = result.columns().to_vec();
+    let mut rows = Vec::new();
+    while result.next() {
+        rows.push(result.take_row());
+    }
+    Box::new(ExecutorMemoryResult::new(columns, rows))
+}
+
+impl Executor {
+    /// Execute DECLARE ... CURSOR
+    pub(crate) fn execute_declare_cursor(
+        &self,
+        stmt: &DeclareCursorStatement,
+        ctx: &ExecutionContext,
+    ) -> Result<Box<dyn QueryResult>> {
+        let name = stmt.name.value_lower.to_string();
+        if self.lock_cursors()?.contains_key(&name) {
+            return Err(Error::InvalidArgumentMessage(format!(
+                "cursor \"{}\" already exists",
+                stmt.name.value
+            )));
+        }
+
+        let in_transaction = self.has_active_transaction();
+        if !in_transaction && !stmt.with_hold {
+            return Err(Error::InvalidArgumentMessage(
+                "DECLARE CURSOR can only be used in transaction blocks".to_string(),
+            ));
+        }
+
+        let mut result = self.execute_select(&stmt.query, ctx)?;
+        if !in_transaction {
+            // The query's own transaction has already ended
+            result = materialize(result);
+            ctx.check_cancelled()?;
+        }
+
+        self.lock_cursors()?.insert(
+            name,
+            OpenCursor {
+                result,
+                with_hold: stmt.with_hold,
+                in_transaction,
+            },
+        );
+        Ok(Box::new(ExecResult::empty()))
+    }
+
+    /// Execute FETCH, returning up to `count` rows from the cursor
+    pub(crate) fn execute_fetch(&self, stmt: &FetchStatement) -> Result<Box<dyn QueryResult>> {
+        let mut cursors = self.lock_cursors()?;
+        let cursor = cursors
+            .get_mut(stmt.name.value_lower.as_str())
+            .ok_or_else(|| {
+                Error::InvalidArgumentMessage(format!(
+                    "cursor \"{}\" does not exist",
+                    stmt.name.value
+                ))
+            })?;
+
+        let columns = cursor.result.columns().to_vec();
+        let limit = stmt.count.unwrap_or(u64::MAX);
+        let mut rows: Vec<Row> = Vec::new();
+        while (rows.len() as u64) < limit && cursor.result.next() {
+            rows.push(cursor.result.take_row());
+        }
+        Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
+    }
+
+    /// Execute CLOSE name / CLOSE ALL
+    pub(crate) fn execute_close_cursor(
+        &self,
+        stmt: &CloseCursorStatement,
+    ) -> Result<Box<dyn QueryResult>> {
+        let mut cursors = self.lock_cursors()?;
+        match &stmt.name {
+            Some(name) => {
+                let mut cursor = cursors.remove(name.value_lower.as_str()).ok_or_else(|| {
+                    Error::InvalidArgumentMessage(format!(
+                        "cursor \"{}\" does not exist",
+                        name.value
+                    ))
+                })?;
+                let _ = cursor.result.close();
+            }
+            None => {
+                for (_, mut cursor) in cursors.drain() {
+                    let _ = cursor.result.close();
+                }
+            }
+        }
+        Ok(Box::new(ExecResult::empty()))
+    }
+
+    /// Close or detach the cursors of a transaction that is ending
+    ///
+    /// Must be called before the transaction commits or rolls back. On commit,
+    /// `WITH HOLD` cursors keep their remaining rows; everything else declared
+    /// in the transaction is closed.
+    pub(crate) fn end_transaction_cursors(&self, committed: bool) -> Result<()> {
+        let mut cursors = self.lock_cursors()?;
+        let names: Vec<String> = cursors
+            .iter()
+            .filter(|(_, c)| c.in_transaction)
+            .map(|(name, _)| name.clone())
+            .collect();
+        for name in names {
+            let Some(mut cursor) = cursors.remove(&name) else {
+                continue;
+            };
+            if committed && cursor.with_hold {
+                cursors.insert(
+                    name,
+                    OpenCursor {
+                        result: materialize(cursor.result),
+                        with_hold: true,
+                        in_transaction: false,
+                    },
+                );
+            } else {
+                let _ = cursor.result.close();
+            }
+        }
+        Ok(())
+    }
+
+    fn lock_cursors(&self) -> Result<MutexGuard<'_, FxHashMap<String, OpenCursor>>> {
+        self.cursors
+            .lock()
+            .map_err(|_| Error::LockAcquisitionFailed("cursors".to_string()))
+    }
+}
@@ -24,23 +24,31 @@
 //! - CREATE VIEW
 //! - DROP VIEW
 
-use crate::core::{DataType, Error, Result, Row, Schema, SchemaBuilder, Value};
+use crate::core::{
+    CheckConstraint, DataType, Error, IndexedPrimaryKey, Result, Row, Schema, SchemaBuilder, Value,
+};
+use crate::functions::user_defined::format_signature;
 use crate::functions::{FunctionDataType, FunctionSignature};
 use crate::parser::ast::*;
+use crate::parser::{parse_sql, DependencyExtractor, Visitor};
 use crate::storage::expression::Expression;
 use crate::storage::functions::{
     StoredFunction, StoredParameter, CREATE_FUNCTIONS_SQL, SYS_FUNCTIONS,
 };
+use crate::storage::mvcc::compression;
 use crate::storage::procedures::{CREATE_PROCEDURES_SQL, SYS_PROCEDURES};
-use crate::storage::traits::{result::EmptyResult, Engine, QueryResult};
-use rustc_hash::FxHashMap;
+use crate::storage::sequences::{find_sequence_row, CREATE_SEQUENCES_SQL, SYS_SEQUENCES};
+use crate::storage::traits::{result::EmptyResult, Index, QueryResult, Table};
+use rustc_hash::FxHashSet;
 
 use serde_json;
 use std::sync::Arc;
 
 use super::context::ExecutionContext;
-use super::expression::ExpressionEval;
-use super::result::ExecResult;
+use super::expression::{compile_expression, ExpressionEval};
+use super::result::{ExecResult, ExecutorMemoryResult};
+use super::utils::expression_contains_aggregate;
+use super::view_rewrite::rename_view_column;
 use super::Executor;
 
 impl Executor {
@@ -59,8 +67,7 @@ impl Executor {
 
         // Check if schema exists for qualified table names
         if let Some(schema_name) = stmt.table_name.schema() {
-            let schemas = self.engine.schemas.read().unwrap();
-            if !schemas.contains_key(&schema_name.to_lowercase()) {
+            if !self.engine.schema_exists(&schema_name)? {
                 return Err(Error::SchemaNotFound(schema_name));
             }
         }
@@ -68,7 +75,7 @@ impl Executor {
         // Check if table already exists
         if self.engine.table_exists(table_name)? {
             if stmt.if_not_exists {
-                return Ok(Box::new(ExecResult::empty()));
+                return Ok(Box::new(ExecResult::no_op()));
             }
             return Err(Error::TableExists(table_name.clone()));
         }
@@ -79,7 +86,7 @@ impl Executor {
             .schema()
             .unwrap_or_else(|| ctx.current_schema().unwrap_or("public").to_string())
             .to_lowercase();
-        if self.engine.view_exists(&schema_name, table_name)? {
+        if self.engine.get_view(&schema_name, table_name)?.is_some() {
             return Err(Error::ViewAlreadyExists(table_name.clone()));
         }
 
@@ -99,25 +106,65 @@ impl Executor {
         // Collect columns with UNIQUE constraints to create indexes after table creation
         let mut unique_columns: Vec<String> = Vec::new();
 
+        // Table-level PRIMARY KEY (a, b, ...). A single column is treated like
+        // a column-level PRIMARY KEY; several columns become NOT NULL columns
+        // enforced by the unique index recorded in the schema's
+        // indexed_primary_key, as is a single key column that isn't INTEGER.
+        let mut table_primary_keys = stmt.table_constraints.iter().filter_map(|c| match c {
+            TableConstraint::PrimaryKey(cols) => Some(cols),
+            _ => None,
+        });
+        let table_primary_key: Vec<String> = table_primary_keys
+            .next()
+            .map(|cols| cols.iter().map(|c| c.value.clone()).collect())
+            .unwrap_or_default();
+        let has_column_primary_key = stmt.columns.iter().any(|col_def| {
+            col_def
+                .constraints
+                .iter()
+                .any(|c| matches!(c, ColumnConstraint::PrimaryKey))
+        });
+        if table_primary_keys.next().is_some()
+            || (has_column_primary_key && !table_primary_key.is_empty())
+        {
+            return Err(Error::invalid_argument(format!(
+                "multiple primary keys for table '{}' are not allowed",
+                table_name
+            )));
+        }
+        for key_col in &table_primary_key {
+            if !stmt
+                .columns
+                .iter()
+                .any(|col_def| col_def.name.value.eq_ignore_ascii_case(key_col))
+            {
+                return Err(Error::column_not_found_by_name(key_col.clone()));
+            }
+        }
+        let is_composite_key = table_primary_key.len() > 1;
+        // Columns of a PRIMARY KEY enforced by a unique index, in key order
+        let mut indexed_key: Vec<String> = if is_composite_key {
+            table_primary_key.clone()
+        } else {
+            Vec::new()
+        };
+
         for col_def in &stmt.columns {
             let col_name = &col_def.name.value;
             let data_type = self.parse_data_type(&col_def.data_type)?;
+            let in_table_key = table_primary_key
+                .iter()
+                .any(|key_col| key_col.eq_ignore_ascii_case(col_name));
             let nullable = !col_def
                 .constraints
                 .iter()
-                .any(|c| matches!(c, ColumnConstraint::NotNull));
+                .any(|c| matches!(c, ColumnConstraint::NotNull))
+                && !in_table_key;
             let is_primary_key = col_def
                 .constraints
                 .iter()
-                .any(|c| matches!(c, ColumnConstraint::PrimaryKey));
-
-            // Validate PRIMARY KEY type - only INTEGER is supported
-            if is_primary_key && data_type != DataType::Integer {
-                return Err(Error::ParseError(format!(
-                    "PRIMARY KEY column '{}' must be INTEGER type, got {:?}. Only INTEGER PRIMARY KEY is supported.",
-                    col_name, data_type
-                )));
-            }
+                .any(|c| matches!(c, ColumnConstraint::PrimaryKey))
+                || (in_table_key && !is_composite_key);
 
             let is_unique = col_def
                 .constraints
@@ -129,6 +176,18 @@ impl Executor {
                 .iter()
                 .any(|c| matches!(c, ColumnConstraint::AutoIncrement));
 
+            // Only an INTEGER column can generate its own values
+            if is_auto_increment && data_type != DataType::Integer {
+                return Err(Error::ParseError(format!(
+                    "AUTO_INCREMENT column '{}' must be INTEGER type, got {:?}",
+                    col_name, data_type
+                )));
+            }
+
+            // Rows are keyed by an INTEGER primary key directly. Any other key
+            // type (TEXT, UUID, ...) is a NOT NULL column backed by a unique index.
+            let is_key_index = is_primary_key && data_type != DataType::Integer;
+
             // Extract DEFAULT expression
             let default_expr = col_def.constraints.iter().find_map(|c| {
                 if let ColumnConstraint::Default(expr) = c {
@@ -138,33 +197,59 @@ impl Executor {
                 }
             });
 
-            // Extract CHECK expression
-            let check_expr = col_def.constraints.iter().find_map(|c| {
-                if let ColumnConstraint::Check(expr) = c {
-                    Some(format!("{}", expr))
-                } else {
-                    None
-                }
-            });
+            // A generated column is always computed, so it cannot have its own value source
+            let is_generated = col_def
+                .constraints
+                .iter()
+                .any(|c| matches!(c, ColumnConstraint::Generated(_)));
+            if is_generated && (default_expr.is_some() || is_primary_key || is_auto_increment) {
+                return Err(Error::invalid_argument(format!(
+                    "generated column '{}' cannot have DEFAULT, PRIMARY KEY or AUTO_INCREMENT",
+                    col_name
+                )));
+            }
+
+            // JSON defaults are validated now rather than silently becoming NULL on insert
+            if let (DataType::Json, Some(expr_str)) = (data_type, default_expr.as_deref()) {
+                self.validate_json_default(col_name, expr_str)?;
+            }
+
+            // Extract every CHECK constraint
+            let checks = column_checks(&col_def.constraints);
 
             // Use add_with_constraints to include DEFAULT and CHECK
             schema_builder = schema_builder.add_with_constraints(
                 col_name,
                 data_type,
                 nullable && !is_primary_key,
-                is_primary_key,
+                is_primary_key && !is_key_index,
                 is_auto_increment,
                 default_expr,
-                check_expr,
+                checks,
             );
 
             // Track UNIQUE columns for index creation
             if is_unique && !is_primary_key {
                 unique_columns.push(col_name.clone());
             }
+            if is_key_index {
+                indexed_key.push(col_name.clone());
+            }
         }
 
         let mut schema = schema_builder.build();
+        self.attach_generated_columns(&stmt.columns, &mut schema)?;
+        Self::attach_compressed_columns(&stmt.columns, &mut schema)?;
+        if !indexed_key.is_empty() {
+            schema.indexed_primary_key = Some(IndexedPrimaryKey {
+                index_name: primary_key_index_name(table_name),
+                columns: indexed_key
+                    .iter()
+                    .filter_map(|name| schema.get_column_by_name(name))
+                    .map(|col| col.name.clone())
+                    .collect(),
+            });
+        }
 
         // Collect table-level UNIQUE constraints (multi-column unique indexes)
         let mut table_unique_constraints: Vec<Vec<String>> = Vec::new();
@@ -259,6 +344,8 @@ impl Executor {
                     let col_names: Vec<String> = cols.iter().map(|c| c.value.clone()).collect();
                     table_unique_constraints.push(col_names);
                 }
+                // Handled with the columns above
+                TableConstraint::PrimaryKey(_) => {}
                 TableConstraint::ForeignKey {
                     column,
                     foreign_table,
@@ -340,20 +427,75 @@ impl Executor {
                         schemas_to_update.push(ref_schema);
                     }
                 }
-                _ => {} // PrimaryKey and Check not fully supported at table level yet
+                _ => {} // Check not fully supported at table level yet
+            }
+        }
+
+        for col_names in table_unique_constraints
+            .iter()
+            .chain((!indexed_key.is_empty()).then_some(&indexed_key))
+        {
+            if let Some(col) = col_names
+                .iter()
+                .filter_map(|name| schema.get_column_by_name(name))
+                .find(|col| col.compressed)
+            {
+                return Err(Error::invalid_argument(format!(
+                    "COMPRESSED column '{}' cannot be part of a UNIQUE or PRIMARY KEY constraint",
+                    col.name
+                )));
             }
         }
 
+        // Unique indexes for UNIQUE columns and table-level UNIQUE constraints
+        let unique_indexes: Vec<(String, Vec<String>)> = unique_columns
+            .iter()
+            .map(|col_name| {
+                (
+                    format!("unique_{}_{}", table_name, col_name),
+                    vec![col_name.clone()],
+                )
+            })
+            .chain(
+                table_unique_constraints
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, col_names)| (format!("unique_{}_{}", table_name, i), col_names)),
+            )
+            .chain(
+                (!indexed_key.is_empty())
+                    .then(|| (primary_key_index_name(table_name), indexed_key.clone())),
+            )
+            .collect();
+
         // Check if there's an active transaction
         let mut active_tx = self.active_transaction.lock().unwrap();
 
-        if let Some(ref mut _tx_state) = *active_tx {
+        if let Some(ref mut tx_state) = *active_tx {
             // Transactional DDL: Create table immediately but log for undo
             tracing::info!(
                 "Executing CREATE TABLE for '{}' (in transaction)",
                 table_name
             );
             self.engine.create_table(schema.clone())?;
+            tx_state
+                .ddl_undo_log
+                .push(super::DeferredDdlOperation::CreateTable {
+                    name: table_name.clone(),
+                });
+
+            // The unique indexes belong to the same transaction, so ROLLBACK
+            // drops them (LIFO) before the table itself
+            let table = tx_state.transaction.get_table(table_name)?;
+            for (index_name, col_names) in &unique_indexes {
+                self.create_unique_index(&*table, table_name, index_name, col_names)?;
+                tx_state
+                    .ddl_undo_log
+                    .push(super::DeferredDdlOperation::CreateIndex {
+                        table: table_name.clone(),
+                        name: index_name.clone(),
+                    });
+            }
 
             // Update referenced schemas
             for ref_schema in schemas_to_update {
@@ -362,24 +504,26 @@ impl Executor {
                     ref_schema,
                 )?;
             }
-
-            if let Some(ref mut tx_state) = *active_tx {
-                tx_state
-                    .ddl_undo_log
-                    .push(super::DeferredDdlOperation::CreateTable {
-                        name: table_name.clone(),
-                    });
-            }
-
-            // Create indexes within the same transaction context if possible,
-            // but for now we'll do them separately or assume they are part of the table creation
-            // Note: Since we use engine.create_table directly, it's global.
-            // But we need to make sure indexes are also undone if we rollback.
         } else {
             // No active transaction - use direct engine call (auto-committed)
             tracing::info!("Executing CREATE TABLE for '{}'", table_name);
             self.engine.create_table(schema)?;
 
+            if !unique_indexes.is_empty() {
+                let mut tx = self.engine.begin_transaction()?;
+                let table = tx.get_table(table_name)?;
+                let created = unique_indexes
+                    .iter()
+                    .try_for_each(|(index_name, col_names)| {
+                        self.create_unique_index(&*table, table_name, index_name, col_names)
+                    });
+                // Don't leave a table behind without its constraints
+                if let Err(e) = created.and_then(|_| tx.commit()) {
+                    let _ = self.engine.drop_table(table_name);
+                    return Err(e);
+                }
+            }
+
             // Update referenced schemas
             for ref_schema in schemas_to_update {
                 self.engine.update_table_schema(
@@ -389,49 +533,42 @@ impl Executor {
             }
         }
 
-        // Create unique indexes for columns with UNIQUE constraint (shared logic)
-        let needs_indexes = !unique_columns.is_empty() || !table_unique_constraints.is_empty();
-        if needs_indexes {
-            let mut tx = self.engine.begin_transaction()?;
-            let table = tx.get_table(table_name)?;
-
-            for col_name in &unique_columns {
-                let index_name = format!("unique_{}_{}", table_name, col_name);
-                table.create_index(&index_name, &[col_name.as_str()], true)?;
-                // Get index type for WAL persistence
-                let idx_type = table
-                    .get_index(&index_name)
-                    .map(|idx| idx.index_type())
-                    .unwrap_or(crate::core::IndexType::BTree);
-                // Record index creation to WAL for persistence
-                self.engine.record_create_index(
-                    table_name,
-                    &index_name,
-                    std::slice::from_ref(col_name),
-                    true,
-                    idx_type,
-                );
-            }
-
-            // Create multi-column unique indexes from table-level constraints
-            for (i, col_names) in table_unique_constraints.iter().enumerate() {
-                let index_name = format!("unique_{}_{}", table_name, i);
-                let col_refs: Vec<&str> = col_names.iter().map(|s| s.as_str()).collect();
-                table.create_index(&index_name, &col_refs, true)?;
-                // Get index type for WAL persistence
-                let idx_type = table
-                    .get_index(&index_name)
-                    .map(|idx| idx.index_type())
-                    .unwrap_or(crate::core::IndexType::BTree);
-                // Record index creation to WAL for persistence
-                self.engine
-                    .record_create_index(table_name, &index_name, col_names, true, idx_type);
-            }
+        Ok(Box::new(ExecResult::empty()))
+    }
 
-            tx.commit()?;
-        }
+    /// Creates one of the unique indexes backing a table's UNIQUE constraints
+    /// and records it in the WAL
+    fn create_unique_index(
+        &self,
+        table: &dyn Table,
+        table_name: &str,
+        index_name: &str,
+        col_names: &[String],
+    ) -> Result<()> {
+        let col_refs: Vec<&str> = col_names.iter().map(|s| s.as_str()).collect();
+        table.create_index(index_name, &col_refs, true)?;
+        // Get index type for WAL persistence
+        let idx_type = table
+            .get_index(index_name)
+            .map(|idx| idx.index_type())
+            .unwrap_or(crate::core::IndexType::BTree);
+        // Record index creation to WAL for persistence
+        self.engine
+            .record_create_index(table_name, index_name, col_names, true, false, idx_type);
+        Ok(())
+    }
 
-        Ok(Box::new(ExecResult::empty()))
+    /// Drops an index created earlier in a transaction that is being rolled
+    /// back
+    pub(crate) fn undo_create_index(&self, table_name: &str, index_name: &str) {
+        let Ok(tx) = self.engine.begin_transaction() else {
+            return;
+        };
+        if let Ok(table) = tx.get_table(table_name) {
+            if table.drop_index(index_name).is_ok() {
+                self.engine.record_drop_index(table_name, index_name);
+            }
+        }
     }
 
     /// Execute CREATE TABLE ... AS SELECT ...
@@ -452,32 +589,16 @@ impl Executor {
         // Materialize the result to get the rows
         let mut rows: Vec<Row> = Vec::new();
         while result.next() {
+            ctx.check_cancelled()?;
             rows.push(result.take_row());
         }
+        // A streaming SELECT cut short by the timeout reads as finished
+        ctx.check_cancelled()?;
 
-        // Infer schema from the result columns and first row (if available)
+        // Infer schema from the result columns and rows
         let mut schema_builder = SchemaBuilder::new(table_name);
-
-        for (i, col_name) in columns.iter().enumerate() {
-            // Extract base column name (without table prefix)
-            let base_name = if let Some(pos) = col_name.rfind('.') {
-                &col_name[pos + 1..]
-            } else {
-                col_name.as_str()
-            };
-
-            // Infer data type from first row if available
-            let data_type = if let Some(first_row) = rows.first() {
-                if let Some(value) = first_row.get(i) {
-                    Self::infer_data_type(value)
-                } else {
-                    DataType::Text // Default to TEXT
-                }
-            } else {
-                DataType::Text // Default to TEXT for empty result
-            };
-
-            schema_builder = schema_builder.add_nullable(base_name, data_type);
+        for (name, data_type) in Self::infer_result_columns(&columns, &rows) {
+            schema_builder = schema_builder.add_nullable(name, data_type);
         }
 
         let schema = schema_builder.build();
@@ -488,20 +609,61 @@ impl Executor {
         // Insert the rows into the new table
         let rows_count = rows.len();
         if !rows.is_empty() {
-            let mut tx = self.engine.begin_transaction()?;
-            let mut table = tx.get_table(table_name)?;
+            let inserted = (|| -> Result<()> {
+                let mut tx = self.engine.begin_transaction()?;
+                let mut table = tx.get_table(table_name)?;
 
-            for row in rows {
-                let _ = table.insert(row)?;
-            }
+                for (i, row) in rows.into_iter().enumerate() {
+                    if i.is_multiple_of(100) {
+                        ctx.check_cancelled()?;
+                    }
+                    let _ = table.insert(row)?;
+                }
 
-            // Commit the transaction - it will commit all tables via commit_all_tables()
-            tx.commit()?;
+                // Commit the transaction - it will commit all tables via commit_all_tables()
+                tx.commit()
+            })();
+            // Don't leave a half-filled table behind
+            if let Err(e) = inserted {
+                let _ = self.engine.drop_table(table_name);
+                return Err(e);
+            }
         }
 
         Ok(Box::new(ExecResult::with_rows_affected(rows_count as i64)))
     }
 
+    /// Infer column names and types from the rows of a query result
+    ///
+    /// Table prefixes are stripped from the names. A column takes the type of
+    /// its first non-NULL value, falling back to the type carried by its NULLs
+    /// and to TEXT when there are no rows.
+    pub(crate) fn infer_result_columns(
+        columns: &[String],
+        rows: &[crate::core::Row],
+    ) -> Vec<(String, DataType)> {
+        columns
+            .iter()
+            .enumerate()
+            .map(|(i, col_name)| {
+                // Extract base column name (without table prefix)
+                let base_name = match col_name.rfind('.') {
+                    Some(pos) => &col_name[pos + 1..],
+                    None => col_name.as_str(),
+                };
+
+                let values = rows.iter().filter_map(|row| row.get(i));
+                let data_type = values
+                    .clone()
+                    .find(|value| !value.is_null())
+                    .or_else(|| values.clone().next())
+                    .map_or(DataType::Text, Self::infer_data_type);
+
+                (base_name.to_string(), data_type)
+            })
+            .collect()
+    }
+
     /// Infer data type from a Value
     fn infer_data_type(value: &crate::core::Value) -> DataType {
         match value {
@@ -511,7 +673,10 @@ impl Executor {
             crate::core::Value::Boolean(_) => DataType::Boolean,
             crate::core::Value::Timestamp(_) => DataType::Timestamp,
             crate::core::Value::Json(_) => DataType::Json,
-            crate::core::Value::Null(_) => DataType::Text, // Default nulls to TEXT
+            crate::core::Value::Interval(_) => DataType::Interval,
+            crate::core::Value::Blob(_) => DataType::Blob,
+            crate::core::Value::Null(DataType::Null) => DataType::Text, // Untyped nulls to TEXT
+            crate::core::Value::Null(data_type) => *data_type,
         }
     }
 
@@ -531,11 +696,38 @@ impl Executor {
         // Check if table exists
         if !self.engine.table_exists(table_name)? {
             if stmt.if_exists {
-                return Ok(Box::new(ExecResult::empty()));
+                self.note(format!("table \"{}\" does not exist, skipping", table_name));
+                return Ok(Box::new(ExecResult::no_op()));
             }
             return Err(Error::TableNotFoundByName(table_name.clone()));
         }
 
+        // Views reading the table and tables with foreign keys into it
+        let target = unqualified_public(table_name).to_lowercase();
+        let views = self.dependent_views(&target)?;
+        let children = self.referencing_tables(table_name, &target)?;
+        if !stmt.cascade && (!views.is_empty() || !children.is_empty()) {
+            let dependents = views
+                .iter()
+                .map(|(_, name)| format!("view {}", name))
+                .chain(children.iter().map(|t| format!("foreign key on {}", t)))
+                .collect::<Vec<_>>();
+            return Err(Error::DependentObjectsExist {
+                object: format!("table {}", table_name),
+                dependents: dependents.join(", "),
+            });
+        }
+        for (schema_name, view_name) in &views {
+            self.engine.drop_view(schema_name, view_name, true)?;
+        }
+        for child in &children {
+            let mut schema = self.engine.get_table_schema(child)?;
+            schema
+                .foreign_keys
+                .retain(|fk| unqualified_public(&fk.referenced_table).to_lowercase() != target);
+            self.engine.update_table_schema(child, schema)?;
+        }
+
         // Drop triggers BEFORE acquiring active_tx lock to avoid deadlock with start_transaction_for_dml
         if let Ok(true) = self
             .engine
@@ -552,7 +744,7 @@ impl Executor {
             // Transactional DDL: Get schema before dropping, then drop immediately
             let schema = self.engine.get_table_schema(table_name)?;
 
-            self.engine.drop_table_internal(table_name)?;
+            self.engine.drop_table(table_name)?;
 
             if let Some(ref mut tx_state) = *active_tx {
                 tx_state
@@ -563,14 +755,14 @@ impl Executor {
                     });
             }
 
-            eprintln!(
-                "Warning: DROP TABLE '{}' within transaction - data cannot be recovered on rollback",
+            self.warn(format!(
+                "DROP TABLE '{}' within transaction - data cannot be recovered on rollback",
                 table_name
-            );
+            ));
         } else {
             tracing::info!("Executing DROP TABLE for '{}'", table_name);
             // No active transaction - use engine method directly (auto-committed with WAL)
-            self.engine.drop_table_internal(table_name)?;
+            self.engine.drop_table(table_name)?;
         }
 
         Ok(Box::new(ExecResult::empty()))
@@ -584,8 +776,7 @@ impl Executor {
     ) -> Result<Box<dyn QueryResult>> {
         // Check if schema exists for qualified table names
         if let Some(schema) = stmt.table_name.schema() {
-            let schemas = self.engine.schemas.read().unwrap();
-            if !schemas.contains_key(&schema.to_lowercase()) {
+            if !self.engine.schema_exists(&schema)? {
                 return Err(Error::SchemaNotFound(schema));
             }
         }
@@ -601,7 +792,7 @@ impl Executor {
         // Check if index already exists
         if self.engine.index_exists(index_name, table_name)? {
             if stmt.if_not_exists {
-                return Ok(Box::new(ExecResult::empty()));
+                return Ok(Box::new(ExecResult::no_op()));
             }
             return Err(Error::internal(format!(
                 "index already exists: {}",
@@ -640,11 +831,11 @@ impl Executor {
         if stmt.if_not_exists {
             // Check by name
             if table.get_index(index_name).is_some() {
-                return Ok(Box::new(ExecResult::empty()));
+                return Ok(Box::new(ExecResult::no_op()));
             }
             // For single-column indexes, also check if column already has an index
             if column_names.len() == 1 && table.has_index_on_column(&column_names[0]) {
-                return Ok(Box::new(ExecResult::empty()));
+                return Ok(Box::new(ExecResult::no_op()));
             }
         }
 
@@ -656,8 +847,14 @@ impl Executor {
         });
 
         // Create the index (supports both single and multi-column)
-        // Use create_index_with_type to pass the optional explicit index type
-        table.create_index_with_type(index_name, &column_refs, is_unique, requested_index_type)?;
+        // with the optional explicit index type and NULL handling
+        table.create_index_with_nulls(
+            index_name,
+            &column_refs,
+            is_unique,
+            stmt.nulls_not_distinct,
+            requested_index_type,
+        )?;
 
         // Get the created index to determine its actual type for WAL persistence
         let index_type = table
@@ -671,6 +868,7 @@ impl Executor {
             index_name,
             &column_names,
             is_unique,
+            stmt.nulls_not_distinct,
             index_type,
         );
 
@@ -698,7 +896,7 @@ impl Executor {
         // Check if table exists
         if !self.engine.table_exists(&table_name)? {
             if stmt.if_exists {
-                return Ok(Box::new(ExecResult::empty()));
+                return Ok(Box::new(ExecResult::no_op()));
             }
             return Err(Error::TableNotFoundByName(table_name));
         }
@@ -706,7 +904,8 @@ impl Executor {
         // Check if index exists
         if !self.engine.index_exists(index_name, &table_name)? {
             if stmt.if_exists {
-                return Ok(Box::new(ExecResult::empty()));
+                self.note(format!("index \"{}\" does not exist, skipping", index_name));
+                return Ok(Box::new(ExecResult::no_op()));
             }
             return Err(Error::IndexNotFoundByName(index_name.to_string()));
         }
@@ -714,6 +913,12 @@ impl Executor {
         // Get the table and drop the index
         let tx = self.engine.begin_transaction()?;
         let table = tx.get_table(&table_name)?;
+        if table.schema().is_primary_key_index(index_name) {
+            return Err(Error::InvalidArgumentMessage(format!(
+                "cannot drop index '{}' because it enforces the PRIMARY KEY of table '{}'",
+                index_name, table_name
+            )));
+        }
         table.drop_index(index_name)?;
 
         // Record index drop to WAL for persistence
@@ -722,6 +927,78 @@ impl Executor {
         Ok(Box::new(ExecResult::empty()))
     }
 
+    /// Execute a REINDEX statement
+    ///
+    /// Rebuilds the indexes from the table data and swaps them in together.
+    /// The rebuild is logged to the WAL as a drop and re-create of each index.
+    pub(crate) fn execute_reindex(
+        &self,
+        stmt: &ReindexStatement,
+        _ctx: &ExecutionContext,
+    ) -> Result<Box<dyn QueryResult>> {
+        let tx = self.engine.begin_transaction()?;
+
+        let table_name = match (&stmt.index_name, &stmt.table_name) {
+            (_, Some(table)) => table.value.clone(),
+            (Some(index), None) => {
+                // Find the table that owns the index
+                let mut owners = Vec::new();
+                for table_name in tx.list_tables()? {
+                    if tx.get_table(&table_name)?.get_index(&index.value).is_some() {
+                        owners.push(table_name);
+                    }
+                }
+                match owners.len() {
+                    0 => return Err(Error::IndexNotFoundByName(index.value.clone())),
+                    1 => owners.remove(0),
+                    _ => {
+                        return Err(Error::InvalidArgumentMessage(format!(
+                            "index '{}' exists on several tables, use REINDEX INDEX {} ON <table>",
+                            index.value, index.value
+                        )))
+                    }
+                }
+            }
+            (None, None) => {
+                return Err(Error::InvalidArgumentMessage(
+                    "REINDEX requires a table or index name".to_string(),
+                ))
+            }
+        };
+
+        if !self.engine.table_exists(&table_name)? {
+            return Err(Error::TableNotFoundByName(table_name));
+        }
+
+        let table = tx.get_table(&table_name)?;
+        let indexes: Vec<_> = match &stmt.index_name {
+            Some(index) => vec![table
+                .get_index(&index.value)
+                .ok_or_else(|| Error::IndexNotFoundByName(index.value.clone()))?],
+            None => self.engine.get_all_indexes(&table_name)?,
+        };
+
+        let names: Vec<&str> = indexes.iter().map(|idx| idx.name()).collect();
+        table.rebuild_indexes(&names)?;
+
+        // Record the rebuild to WAL so recovery rebuilds the same indexes
+        for index in &indexes {
+            self.engine.record_drop_index(&table_name, index.name());
+            self.engine.record_create_index(
+                &table_name,
+                index.name(),
+                index.column_names(),
+                index.is_unique(),
+                index.nulls_not_distinct(),
+                index.index_type(),
+            );
+        }
+
+        let columns = vec!["indexes_rebuilt".to_string()];
+        let rows = vec![Row::from_values(vec![Value::Integer(indexes.len() as i64)])];
+        Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
+    }
+
     /// Execute an ALTER TABLE statement
     pub(crate) fn execute_alter_table(
         &self,
@@ -754,6 +1031,26 @@ impl Executor {
                         .iter()
                         .any(|c| matches!(c, ColumnConstraint::NotNull));
 
+                    // Existing rows would need backfilling from the expression
+                    if col_def
+                        .constraints
+                        .iter()
+                        .any(|c| matches!(c, ColumnConstraint::Generated(_)))
+                    {
+                        return Err(Error::NotSupportedMessage(
+                            "ADD COLUMN with GENERATED ALWAYS AS is not supported".to_string(),
+                        ));
+                    }
+                    if col_def
+                        .constraints
+                        .iter()
+                        .any(|c| matches!(c, ColumnConstraint::Compressed))
+                    {
+                        return Err(Error::NotSupportedMessage(
+                            "ADD COLUMN with COMPRESSED is not supported".to_string(),
+                        ));
+                    }
+
                     // Extract default expression if present
                     let default_expr = col_def.constraints.iter().find_map(|c| {
                         if let ColumnConstraint::Default(expr) = c {
@@ -763,6 +1060,10 @@ impl Executor {
                         }
                     });
 
+                    if let (DataType::Json, Some(expr_str)) = (data_type, default_expr.as_deref()) {
+                        self.validate_json_default(&col_def.name.value, expr_str)?;
+                    }
+
                     // Pre-compute the default value for schema evolution (backfilling existing rows)
                     // The default_expr string is also stored for new INSERTs
                     let default_value = if let Some(ref expr_str) = default_expr {
@@ -805,6 +1106,9 @@ impl Executor {
             }
             AlterTableOperation::DropColumn => {
                 if let Some(ref col_name) = stmt.column_name {
+                    if table.schema().is_primary_key_column(&col_name.value) {
+                        return Err(Error::CannotDropPrimaryKey);
+                    }
                     table.drop_column(&col_name.value)?;
 
                     // Force a global schema update
@@ -822,18 +1126,103 @@ impl Executor {
             }
             AlterTableOperation::RenameColumn => match (&stmt.column_name, &stmt.new_column_name) {
                 (Some(old_name), Some(new_name)) => {
-                    table.rename_column(&old_name.value, &new_name.value)?;
+                    let (old_name, new_name) = (&old_name.value, &new_name.value);
 
-                    // Force a global schema update
-                    let schema = table.schema().clone();
-                    self.engine.update_table_schema(table_name, schema)?;
+                    // Validate before touching indexes, so that a rename that
+                    // can't succeed leaves the table as it was
+                    let schema = self.engine.get_table_schema(table_name)?;
+                    if !schema.has_column(old_name) {
+                        return Err(Error::ColumnNotFound);
+                    }
+                    if schema.has_column(new_name) {
+                        return Err(Error::DuplicateColumn);
+                    }
+
+                    // Rewrite dependent views first so that a view we can't
+                    // rewrite fails the rename before anything is changed
+                    let views =
+                        self.rewrite_views_for_column_rename(table_name, old_name, new_name)?;
+
+                    // Indexes keep the names of their columns, so the ones
+                    // covering the renamed column are rebuilt
+                    let indexes: Vec<_> = self
+                        .engine
+                        .get_all_indexes(table_name)?
+                        .into_iter()
+                        .filter(|idx| {
+                            idx.column_names()
+                                .iter()
+                                .any(|c| c.eq_ignore_ascii_case(old_name))
+                        })
+                        .collect();
+                    let create_index = |table: &mut Box<dyn Table>,
+                                        index: &Arc<dyn Index>,
+                                        from: &str,
+                                        to: &str|
+                     -> Result<Vec<String>> {
+                        let column_names: Vec<String> = index
+                            .column_names()
+                            .iter()
+                            .map(|c| {
+                                if c.eq_ignore_ascii_case(from) {
+                                    to.to_string()
+                                } else {
+                                    c.clone()
+                                }
+                            })
+                            .collect();
+                        let column_refs: Vec<&str> =
+                            column_names.iter().map(|s| s.as_str()).collect();
+                        // Multi-column indexes pick their own type
+                        let index_type = match index.index_type() {
+                            crate::core::IndexType::MultiColumn => None,
+                            index_type => Some(index_type),
+                        };
+                        table.create_index_with_nulls(
+                            index.name(),
+                            &column_refs,
+                            index.is_unique(),
+                            index.nulls_not_distinct(),
+                            index_type,
+                        )?;
+                        Ok(column_names)
+                    };
+
+                    for index in &indexes {
+                        table.drop_index(index.name())?;
+                    }
+                    if let Err(e) = self.engine.rename_column(table_name, old_name, new_name) {
+                        // Put the dropped indexes back on the unchanged column
+                        for index in &indexes {
+                            create_index(&mut table, index, old_name, old_name)?;
+                        }
+                        return Err(e);
+                    }
 
                     // Record ALTER TABLE RENAME COLUMN to WAL for persistence
-                    self.engine.record_alter_table_rename_column(
-                        table_name,
-                        &old_name.value,
-                        &new_name.value,
-                    );
+                    for index in &indexes {
+                        self.engine.record_drop_index(table_name, index.name());
+                    }
+                    self.engine
+                        .record_alter_table_rename_column(table_name, old_name, new_name);
+
+                    for index in &indexes {
+                        let column_names = create_index(&mut table, index, old_name, new_name)?;
+                        self.engine.record_create_index(
+                            table_name,
+                            index.name(),
+                            &column_names,
+                            index.is_unique(),
+                            index.nulls_not_distinct(),
+                            index.index_type(),
+                        );
+                    }
+
+                    // Replacing keeps the old definition if a view can't be stored
+                    for (schema_name, view_name, query) in views {
+                        self.engine
+                            .create_or_replace_view(&schema_name, &view_name, query)?;
+                    }
                 }
                 _ => {
                     return Err(Error::InvalidArgumentMessage(
@@ -862,15 +1251,35 @@ impl Executor {
 
                     let auto_increment_opt = if auto_increment { Some(true) } else { None };
 
-                    let check_expr = col_def.constraints.iter().find_map(|c| {
-                        if let ColumnConstraint::Check(expr) = c {
-                            Some(expr.to_string())
-                        } else {
-                            None
-                        }
-                    });
+                    // Stored values stay encoded, so compression can't be
+                    // switched on here and the type must stay compressible
+                    let is_compressed = table
+                        .schema()
+                        .get_column_by_name(&col_def.name.value)
+                        .is_some_and(|col| col.compressed);
+                    if !is_compressed
+                        && col_def
+                            .constraints
+                            .iter()
+                            .any(|c| matches!(c, ColumnConstraint::Compressed))
+                    {
+                        return Err(Error::NotSupportedMessage(
+                            "MODIFY COLUMN cannot make an existing column COMPRESSED".to_string(),
+                        ));
+                    }
+                    if is_compressed && !compression::is_compressible_type(data_type) {
+                        return Err(Error::invalid_argument(format!(
+                            "COMPRESSED column '{}' must be TEXT, JSON or BLOB, got {:?}",
+                            col_def.name.value, data_type
+                        )));
+                    }
 
-                    let check_expr_opt = check_expr.map(Some);
+                    let checks = column_checks(&col_def.constraints);
+                    let checks_opt = if checks.is_empty() {
+                        None
+                    } else {
+                        Some(checks)
+                    };
 
                     self.engine.modify_column(
                         table_name,
@@ -878,7 +1287,7 @@ impl Executor {
                         data_type,
                         nullable,
                         auto_increment_opt,
-                        check_expr_opt.clone(),
+                        checks_opt.clone(),
                     )?;
 
                     let is_unique = col_def
@@ -903,7 +1312,7 @@ impl Executor {
                         data_type,
                         nullable,
                         auto_increment_opt,
-                        check_expr_opt,
+                        checks_opt,
                     );
                 } else {
                     return Err(Error::InvalidArgumentMessage(
@@ -1039,6 +1448,37 @@ impl Executor {
                     ));
                 }
             }
+            AlterTableOperation::SetAutoIncrement => {
+                let next_id = stmt.auto_increment.ok_or_else(|| {
+                    Error::InvalidArgumentMessage("AUTO_INCREMENT requires a value".to_string())
+                })?;
+
+                // The counter belongs to the table's AUTO_INCREMENT column
+                let schema = table.schema();
+                let column = schema.columns.iter().find(|c| c.auto_increment);
+                match (&stmt.column_name, column) {
+                    (Some(name), Some(col)) if col.name.eq_ignore_ascii_case(&name.value) => {}
+                    (Some(name), _) => {
+                        return Err(Error::InvalidArgumentMessage(format!(
+                            "column '{}' is not an AUTO_INCREMENT column",
+                            name.value
+                        )));
+                    }
+                    (None, Some(_)) => {}
+                    (None, None) => {
+                        return Err(Error::InvalidArgumentMessage(format!(
+                            "table '{}' has no AUTO_INCREMENT column",
+                            table_name
+                        )));
+                    }
+                }
+
+                self.engine.set_auto_increment(table_name, next_id)?;
+
+                // Record ALTER TABLE AUTO_INCREMENT to WAL for persistence
+                self.engine
+                    .record_alter_table_auto_increment(table_name, next_id);
+            }
         }
 
         tx.commit()?;
@@ -1059,11 +1499,9 @@ impl Executor {
             .to_lowercase();
 
         // Check if schema exists for qualified view names
-        let schemas = self.engine.schemas.read().unwrap();
-        if !schemas.contains_key(&schema_name) {
+        if !self.engine.schema_exists(&schema_name)? {
             return Err(Error::SchemaNotFound(schema_name));
         }
-        drop(schemas);
 
         let view_name = &stmt.view_name.table();
 
@@ -1075,9 +1513,18 @@ impl Executor {
         // Convert the query to SQL string
         let query_sql = stmt.query.to_string();
 
+        if stmt.if_not_exists && self.engine.get_view(&schema_name, view_name)?.is_some() {
+            return Ok(Box::new(ExecResult::no_op()));
+        }
+
         // Create the view (engine handles if_not_exists logic)
-        self.engine
-            .create_view(&schema_name, view_name, query_sql, stmt.if_not_exists)?;
+        if stmt.or_replace {
+            self.engine
+                .create_or_replace_view(&schema_name, view_name, query_sql)?;
+        } else {
+            self.engine
+                .create_view(&schema_name, view_name, query_sql, stmt.if_not_exists)?;
+        }
 
         Ok(Box::new(ExecResult::empty()))
     }
@@ -1091,13 +1538,168 @@ impl Executor {
         let schema_name = ctx.current_schema().unwrap_or("public").to_lowercase();
         let view_name = &stmt.view_name.value;
 
-        // Drop the view (engine handles if_exists logic)
+        if self.engine.get_view(&schema_name, view_name)?.is_none() {
+            if stmt.if_exists {
+                return Ok(Box::new(ExecResult::no_op()));
+            }
+            return Err(Error::ViewNotFound(view_name.to_string()));
+        }
+
+        let target = if schema_name == "public" {
+            view_name.to_lowercase()
+        } else {
+            format!("{}.{}", schema_name, view_name.to_lowercase())
+        };
+        let views = self.dependent_views(&target)?;
+        if !stmt.cascade && !views.is_empty() {
+            let dependents = views
+                .iter()
+                .map(|(_, name)| format!("view {}", name))
+                .collect::<Vec<_>>();
+            return Err(Error::DependentObjectsExist {
+                object: format!("view {}", view_name),
+                dependents: dependents.join(", "),
+            });
+        }
+        for (schema_name, view_name) in &views {
+            self.engine.drop_view(schema_name, view_name, true)?;
+        }
+
         self.engine
             .drop_view(&schema_name, view_name, stmt.if_exists)?;
 
         Ok(Box::new(ExecResult::empty()))
     }
 
+    /// Find the views that read a table or view, directly or through other views
+    ///
+    /// `target` is the lowercase name with any `public.` qualifier removed.
+    /// Returns (schema, view name) pairs in discovery order.
+    fn dependent_views(&self, target: &str) -> Result<Vec<(String, String)>> {
+        let mut views = Vec::new();
+        for (schema_name, view_name) in self.engine.list_views()? {
+            let Some(view_def) = self.engine.get_view(&schema_name, &view_name)? else {
+                continue;
+            };
+            let statements = parse_sql(&view_def.query)
+                .map_err(|e| Error::parse(format!("view '{}': {}", view_def.original_name, e)))?;
+            let mut extractor = DependencyExtractor::new();
+            for stmt in &statements {
+                extractor.visit_statement(stmt);
+            }
+            let tables: FxHashSet<String> = extractor
+                .tables
+                .iter()
+                .map(|t| unqualified_public(t).to_lowercase())
+                .collect();
+            let name = if schema_name == "public" {
+                view_name.to_lowercase()
+            } else {
+                format!("{}.{}", schema_name, view_name.to_lowercase())
+            };
+            views.push((schema_name, view_def.original_name.clone(), name, tables));
+        }
+
+        // Grow the set of dropped names until no further view reads one of them
+        let mut dropped = vec![target.to_string()];
+        let mut dependents = Vec::new();
+        let mut found = true;
+        while found {
+            found = false;
+            for (schema_name, view_name, name, tables) in &views {
+                if !dropped.contains(name) && dropped.iter().any(|d| tables.contains(d)) {
+                    dropped.push(name.clone());
+                    dependents.push((schema_name.clone(), view_name.clone()));
+                    found = true;
+                }
+            }
+        }
+        Ok(dependents)
+    }
+
+    /// Find the other tables whose foreign keys reference a table
+    fn referencing_tables(&self, table_name: &str, target: &str) -> Result<Vec<String>> {
+        let schema = self.engine.get_table_schema(table_name)?;
+        let mut children = Vec::new();
+        for child in &schema.referenced_by {
+            let child = unqualified_public(child).to_lowercase();
+            if child == target || children.contains(&child) || !self.engine.table_exists(&child)? {
+                continue;
+            }
+            let references = self
+                .engine
+                .get_table_schema(&child)?
+                .foreign_keys
+                .iter()
+                .any(|fk| unqualified_public(&fk.referenced_table).to_lowercase() == target);
+            if references {
+                children.push(child);
+            }
+        }
+        Ok(children)
+    }
+
+    /// Rewrite the views that depend on a column being renamed
+    ///
+    /// Returns (schema, view name, new query) for every view whose definition
+    /// changes. Fails when a view reads a column of that name that can't be
+    /// attributed to one table, see [`rename_view_column`].
+    fn rewrite_views_for_column_rename(
+        &self,
+        table_name: &str,
+        old_name: &str,
+        new_name: &str,
+    ) -> Result<Vec<(String, String, String)>> {
+        let table_lower = unqualified_public(table_name).to_lowercase();
+        let mut rewritten = Vec::new();
+
+        for (schema_name, view_name) in self.engine.list_views()? {
+            let Some(view_def) = self.engine.get_view(&schema_name, &view_name)? else {
+                continue;
+            };
+            let statements = parse_sql(&view_def.query)
+                .map_err(|e| Error::parse(format!("view '{}': {}", view_def.original_name, e)))?;
+            let mut extractor = DependencyExtractor::new();
+            for stmt in &statements {
+                extractor.visit_statement(stmt);
+            }
+            let tables: Vec<String> = extractor
+                .tables
+                .iter()
+                .map(|t| unqualified_public(t).to_lowercase())
+                .collect();
+            if !tables.contains(&table_lower) {
+                continue;
+            }
+
+            let table_columns = |name: &str| {
+                self.engine
+                    .get_table_schema(name)
+                    .ok()
+                    .map(|schema| schema.columns.iter().map(|c| c.name.clone()).collect())
+            };
+            for mut stmt in statements {
+                let Statement::Select(select) = &mut stmt else {
+                    continue;
+                };
+                let before = select.to_string();
+                rename_view_column(select, &table_lower, old_name, new_name, &table_columns)
+                    .map_err(|reason| {
+                        Error::NotSupportedMessage(format!(
+                            "cannot rename column '{}': view '{}' {}; drop the view first",
+                            old_name, view_def.original_name, reason
+                        ))
+                    })?;
+                let query = select.to_string();
+                if query != before {
+                    rewritten.push((schema_name.clone(), view_def.original_name.clone(), query));
+                }
+            }
+        }
+
+        Ok(rewritten)
+    }
+
     /// Execute a CREATE COLUMNAR INDEX statement
     ///
     /// DEPRECATED: The COLUMNAR INDEX syntax is deprecated.
@@ -1204,15 +1806,6 @@ impl Executor {
             }
         }
 
-        if let Some(id_value) = &function_id {
-            use crate::storage::expression::{ComparisonExpr, Expression as StorageExpr};
-            let mut id_expr =
-                ComparisonExpr::new("id", crate::core::Operator::Eq, id_value.clone());
-            let schema = table.schema();
-            id_expr.prepare_for_schema(schema);
-            table.delete(Some(&id_expr))?;
-        }
-
         let parameters_json = serde_json::to_string(&procedure.parameters).map_err(|e| {
             Error::internal(format!("Failed to serialize procedure parameters: {}", e))
         })?;
@@ -1222,9 +1815,11 @@ impl Executor {
             None => crate::core::value::Value::Null(crate::core::types::DataType::Text),
         };
 
-        let id_value = function_id.unwrap_or(crate::core::value::Value::Null(
-            crate::core::types::DataType::Integer,
-        ));
+        let id_value = function_id
+            .clone()
+            .unwrap_or(crate::core::value::Value::Null(
+                crate::core::types::DataType::Integer,
+            ));
 
         let new_row = crate::core::row::Row::from_values(vec![
             id_value,
@@ -1235,7 +1830,7 @@ impl Executor {
             crate::core::value::Value::text(procedure.code.clone()),
         ]);
 
-        table.insert(new_row)?;
+        replace_or_insert_row(table.as_mut(), function_id, new_row)?;
         tx.commit()?;
         Ok(())
     }
@@ -1280,10 +1875,11 @@ impl Executor {
         self.ensure_procedures_table_exists()?;
 
         let procedure_name_upper = stmt.procedure_name.function().to_uppercase();
+        let procedure_name = self.routine_display_name(&stmt.procedure_name.function());
         let exists = self.procedure_exists(&procedure_name_upper)?;
 
         if exists && !stmt.or_replace {
-            return Err(Error::FunctionAlreadyExists(procedure_name_upper.clone()));
+            return Err(Error::FunctionAlreadyExists(procedure_name.clone()));
         }
 
         let is_sql = stmt.language.eq_ignore_ascii_case("sql")
@@ -1314,7 +1910,7 @@ impl Executor {
                     .unwrap_or_else(|| ctx.current_schema().unwrap_or("public").to_string())
                     .to_uppercase(),
             ),
-            name: procedure_name_upper.clone(),
+            name: procedure_name,
             parameters: stored_parameters,
             language: stmt.language.clone(),
             code: stmt.body.clone(),
@@ -1344,7 +1940,7 @@ impl Executor {
         // Check if procedure exists
         if !self.procedure_exists(&procedure_name_upper)? {
             if stmt.if_exists {
-                return Ok(Box::new(EmptyResult::new()));
+                return Ok(Box::new(ExecResult::no_op()));
             }
             return Err(Error::FunctionNotFound(procedure_name.clone()));
         }
@@ -1368,13 +1964,28 @@ impl Executor {
         // Ensure the functions system table exists
         self.ensure_functions_table_exists()?;
 
-        // Check if function already exists
+        // Check if a function with the same argument types already exists;
+        // other argument types add an overload
         let function_name_upper = stmt.function_name.function().to_uppercase();
-        if self.function_exists(&function_name_upper)? {
+        let function_name = self.routine_display_name(&stmt.function_name.function());
+        let argument_types: Vec<FunctionDataType> = stmt
+            .parameters
+            .iter()
+            .map(|p| FunctionDataType::from_sql_type(&p.data_type))
+            .collect();
+        let existing_id = self
+            .function_overloads(&function_name_upper)?
+            .into_iter()
+            .find(|(_, types)| *types == argument_types)
+            .map(|(id, _)| id);
+        if existing_id.is_some() && !stmt.or_replace {
             if stmt.if_not_exists {
-                return Ok(Box::new(EmptyResult::new()));
+                return Ok(Box::new(ExecResult::no_op()));
             }
-            return Err(Error::FunctionAlreadyExists(function_name_upper.clone()));
+            return Err(Error::FunctionAlreadyExists(format_signature(
+                &function_name,
+                &argument_types,
+            )));
         }
 
         // Convert parameters to simplified format
@@ -1396,7 +2007,7 @@ impl Executor {
                     .unwrap_or_else(|| ctx.current_schema().unwrap_or("public").to_string())
                     .to_uppercase(),
             ),
-            name: function_name_upper.clone(),
+            name: function_name.clone(),
             parameters: stored_parameters,
             return_type: stmt.return_type.clone(),
             language: stmt.language.clone(),
@@ -1418,17 +2029,21 @@ impl Executor {
             )));
         }
 
+        // OR REPLACE: swap the system table row first; registering below
+        // overwrites the registry entry in place
+        if let Some(ref id) = existing_id {
+            self.update_function(id, &stored_function)?;
+        }
+
         // Register the function in the registry first
         self.function_registry.register_user_defined(
-            function_name_upper.clone(),
+            function_name,
             stmt.body.clone(),
             stmt.language.clone(),
             param_names,
             FunctionSignature::new(
-                // TODO: Map return_type string to FunctionDataType
-                FunctionDataType::Unknown,
-                // TODO: Map parameters to FunctionDataType
-                vec![],
+                FunctionDataType::from_sql_type(&stmt.return_type),
+                argument_types.clone(),
                 stmt.parameters.len(),
                 stmt.parameters.len(),
             ),
@@ -1436,11 +2051,14 @@ impl Executor {
 
         // Insert function into system table
         // If this fails, we need to unregister from the registry to maintain consistency
+        if existing_id.is_some() {
+            return Ok(Box::new(EmptyResult::new()));
+        }
         if let Err(e) = self.insert_function(&stored_function) {
             // Rollback registry registration on database insert failure
             let _ = self
                 .function_registry
-                .unregister_user_defined(&function_name_upper);
+                .unregister_user_defined(&function_name_upper, Some(&argument_types));
             return Err(e);
         }
 
@@ -1454,21 +2072,47 @@ impl Executor {
         _ctx: &ExecutionContext,
     ) -> Result<Box<dyn QueryResult>> {
         let function_name = stmt.function_name.function();
-
-        // Check if function exists
-        if !self.function_exists(&function_name)? {
+        let overloads = self.function_overloads(&function_name)?;
+
+        // Argument types pick one overload; a bare name must be unique
+        let target = match stmt.parameter_types {
+            Some(ref types) => {
+                let types: Vec<FunctionDataType> = types
+                    .iter()
+                    .map(|ty| FunctionDataType::from_sql_type(ty))
+                    .collect();
+                overloads
+                    .into_iter()
+                    .find(|(_, existing)| *existing == types)
+            }
+            None if overloads.len() > 1 => {
+                let candidates: Vec<String> = overloads
+                    .iter()
+                    .map(|(_, types)| format_signature(&function_name.to_uppercase(), types))
+                    .collect();
+                return Err(Error::AmbiguousFunction {
+                    name: function_name,
+                    candidates: candidates.join(", "),
+                });
+            }
+            None => overloads.into_iter().next(),
+        };
+        let Some((function_id, argument_types)) = target else {
             if stmt.if_exists {
-                return Ok(Box::new(EmptyResult::new()));
+                return Ok(Box::new(ExecResult::no_op()));
             }
-            return Err(Error::FunctionNotFound(function_name.clone()));
-        }
+            return Err(Error::FunctionNotFound(match stmt.parameter_types {
+                Some(ref types) => format!("{}({})", function_name, types.join(", ")),
+                None => function_name,
+            }));
+        };
 
         // Delete function from system table
-        self.delete_function(&function_name)?;
+        self.delete_function(function_id)?;
 
         // Unregister the function from the registry
         self.function_registry
-            .unregister_user_defined(&function_name)?;
+            .unregister_user_defined(&function_name, Some(&argument_types))?;
 
         Ok(Box::new(EmptyResult::new()))
     }
@@ -1484,31 +2128,63 @@ impl Executor {
             if let Err(e) = self.execute_internal_sql(CREATE_FUNCTIONS_SQL) {
                 tracing::error!("Failed to create functions table: {}", e);
             }
+            return Ok(());
+        }
+
+        // Tables created before overloading allowed one row per (schema, name);
+        // widen that unique index to include the parameters
+        let tx = self.engine.begin_transaction()?;
+        let table = tx.get_table(SYS_FUNCTIONS)?;
+        let legacy = self
+            .engine
+            .get_all_indexes(table.name())?
+            .into_iter()
+            .find(|index| index.is_unique() && index.column_names().len() == 2);
+        if let Some(index) = legacy {
+            let index_name = index.name().to_string();
+            table.drop_index(&index_name)?;
+            self.engine.record_drop_index(SYS_FUNCTIONS, &index_name);
+            let columns = ["schema", "name", "parameters"].map(String::from);
+            self.create_unique_index(&*table, SYS_FUNCTIONS, &index_name, &columns)?;
         }
 
         Ok(())
     }
 
-    /// Check if a function exists in the system table
-    fn function_exists(&self, function_name: &str) -> Result<bool> {
+    /// IDs and argument types of a function's overloads in the system table
+    fn function_overloads(
+        &self,
+        function_name: &str,
+    ) -> Result<Vec<(Value, Vec<FunctionDataType>)>> {
         let tx = self.engine.begin_transaction()?;
         let table = match tx.get_table(SYS_FUNCTIONS) {
             Ok(table) => table,
-            Err(_) => return Ok(false), // Table doesn't exist, so function doesn't exist
+            Err(_) => return Ok(Vec::new()), // Table doesn't exist, so function doesn't exist
         };
 
         // Query for function by name (name is now at index 2, schema at index 1)
+        let mut overloads = Vec::new();
         let mut scanner = table.scan(&[], None)?;
         while scanner.next() {
             let row = scanner.row();
-            if let Some(Value::Text(name)) = row.get(2) {
+            if let (Some(id), Some(Value::Text(name)), Some(Value::Text(parameters_json))) =
+                (row.get(0), row.get(2), row.get(3))
+            {
                 if name.eq_ignore_ascii_case(function_name) {
-                    return Ok(true);
+                    let parameters: Vec<StoredParameter> = serde_json::from_str(parameters_json)
+                        .map_err(|e| {
+                            Error::internal(format!("Failed to parse function parameters: {}", e))
+                        })?;
+                    let types = parameters
+                        .iter()
+                        .map(|p| FunctionDataType::from_sql_type(&p.data_type))
+                        .collect();
+                    overloads.push((id.clone(), types));
                 }
             }
         }
 
-        Ok(false)
+        Ok(overloads)
     }
 
     /// Insert a function into the system table
@@ -1544,33 +2220,50 @@ impl Executor {
         Ok(())
     }
 
-    /// Delete a function from the system table
-    fn delete_function(&self, function_name: &str) -> Result<()> {
+    /// Replace a function's row in the system table, keeping its ID
+    fn update_function(&self, function_id: &Value, function: &StoredFunction) -> Result<()> {
         let mut tx = self.engine.begin_transaction()?;
         let mut table = tx.get_table(SYS_FUNCTIONS)?;
 
-        // Find the function ID by name
-        let mut scanner = table.scan(&[], None)?;
-        let mut function_id: Option<Value> = None;
+        let parameters_json = serde_json::to_string(&function.parameters).map_err(|e| {
+            Error::internal(format!("Failed to serialize function parameters: {}", e))
+        })?;
 
-        while scanner.next() {
-            let row = scanner.row();
-            if let Some(Value::Text(name)) = row.get(2) {
-                if name.eq_ignore_ascii_case(function_name) {
-                    function_id = row.get(0).cloned(); // ID is at index 0
-                    break;
-                }
-            }
-        }
+        let schema_value = match &function.schema {
+            Some(schema) => Value::Text(Arc::from(schema.clone())),
+            None => Value::Null(DataType::Text),
+        };
 
-        if let Some(id_value) = function_id {
-            // Delete by ID using WHERE expression
-            use crate::storage::expression::{ComparisonExpr, Expression as StorageExpr};
-            let mut id_expr = ComparisonExpr::new("id", crate::core::Operator::Eq, id_value);
-            let schema = table.schema();
-            id_expr.prepare_for_schema(schema);
-            table.delete(Some(&id_expr))?;
-        }
+        let values = vec![
+            function_id.clone(),                                  // id
+            schema_value,                                         // schema
+            Value::Text(Arc::from(function.name.clone())),        // name
+            Value::Text(Arc::from(parameters_json)),              // parameters
+            Value::Text(Arc::from(function.return_type.clone())), // return_type
+            Value::Text(Arc::from(function.language.clone())),    // language
+            Value::Text(Arc::from(function.code.clone())),        // code
+        ];
+
+        replace_or_insert_row(
+            table.as_mut(),
+            Some(function_id.clone()),
+            Row::from_values(values),
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Delete a function overload from the system table by ID
+    fn delete_function(&self, function_id: Value) -> Result<()> {
+        let mut tx = self.engine.begin_transaction()?;
+        let mut table = tx.get_table(SYS_FUNCTIONS)?;
+
+        // Delete by ID using WHERE expression
+        use crate::storage::expression::{ComparisonExpr, Expression as StorageExpr};
+        let mut id_expr = ComparisonExpr::new("id", crate::core::Operator::Eq, function_id);
+        let schema = table.schema();
+        id_expr.prepare_for_schema(schema);
+        table.delete(Some(&id_expr))?;
 
         tx.commit()?;
         Ok(())
@@ -1598,13 +2291,14 @@ impl Executor {
         match base_type {
             "INTEGER" | "INT" | "BIGINT" | "SMALLINT" | "TINYINT" => Ok(DataType::Integer),
             "FLOAT" | "DOUBLE" | "REAL" | "DECIMAL" | "NUMERIC" => Ok(DataType::Float),
-            "TEXT" | "VARCHAR" | "CHAR" | "STRING" | "CLOB" => Ok(DataType::Text),
+            // UUIDs are stored as their text form
+            "TEXT" | "VARCHAR" | "CHAR" | "STRING" | "CLOB" | "UUID" => Ok(DataType::Text),
             "BOOLEAN" | "BOOL" => Ok(DataType::Boolean),
             // Date and time are all stored as Timestamp
             "TIMESTAMP" | "DATETIME" | "DATE" | "TIME" => Ok(DataType::Timestamp),
             "JSON" | "JSONB" => Ok(DataType::Json),
-            // Binary data stored as Text (base64 encoded)
-            "BLOB" | "BINARY" | "VARBINARY" => Ok(DataType::Text),
+            "INTERVAL" => Ok(DataType::Interval),
+            "BLOB" | "BYTEA" | "BINARY" | "VARBINARY" => Ok(DataType::Blob),
             _ => Err(Error::Type(format!("Unknown data type: {}", type_str))),
         }
     }
@@ -1615,28 +2309,137 @@ impl Executor {
         default_expr: &str,
         target_type: DataType,
     ) -> Result<Value> {
+        Ok(match self.evaluate_default_uncoerced(default_expr)? {
+            Some(value) => value.into_coerce_to_type(target_type),
+            None => Value::null(target_type),
+        })
+    }
+
+    /// Evaluate a default expression string without coercing it to the column type.
+    /// Returns None if the expression cannot be parsed.
+    fn evaluate_default_uncoerced(&self, default_expr: &str) -> Result<Option<Value>> {
         use crate::parser::parse_sql;
 
         // Parse the default expression as a SELECT expression
         let sql = format!("SELECT {}", default_expr);
         let stmts = match parse_sql(&sql) {
             Ok(s) => s,
-            Err(_) => return Ok(Value::null(target_type)),
+            Err(_) => return Ok(None),
         };
-        if stmts.is_empty() {
-            return Ok(Value::null(target_type));
-        }
 
         // Extract the expression from the SELECT statement
-        if let Statement::Select(select) = &stmts[0] {
+        if let Some(Statement::Select(select)) = stmts.first() {
             if let Some(expr) = select.columns.first() {
                 let mut eval = ExpressionEval::compile(expr, &[])?;
-                let value = eval.eval_slice(&[])?;
-                return Ok(value.into_coerce_to_type(target_type));
+                return Ok(Some(eval.eval_slice(&[])?));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Reject a DEFAULT on a JSON column that does not evaluate to valid JSON
+    fn validate_json_default(&self, col_name: &str, default_expr: &str) -> Result<()> {
+        if let Some(value) = self.evaluate_default_uncoerced(default_expr)? {
+            if !value.is_null() && value.clone().into_coerce_to_type(DataType::Json).is_null() {
+                return Err(Error::Type(format!(
+                    "invalid JSON in DEFAULT for column '{}': {}",
+                    col_name, default_expr
+                )));
             }
         }
+        Ok(())
+    }
 
-        Ok(Value::null(target_type))
+    /// Record the generation expressions of GENERATED columns in the schema
+    ///
+    /// The expression may only reference non-generated columns of the same
+    /// row, and cannot use aggregates or subqueries.
+    fn attach_generated_columns(
+        &self,
+        columns: &[ColumnDefinition],
+        schema: &mut Schema,
+    ) -> Result<()> {
+        let generated: Vec<(usize, &crate::parser::ast::Expression)> = columns
+            .iter()
+            .enumerate()
+            .filter_map(|(i, col_def)| {
+                col_def.constraints.iter().find_map(|c| match c {
+                    ColumnConstraint::Generated(expr) => Some((i, expr)),
+                    _ => None,
+                })
+            })
+            .collect();
+        if generated.is_empty() {
+            return Ok(());
+        }
+
+        // Generated columns are hidden from each other's expressions
+        let visible_columns: Vec<String> = schema
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, col)| {
+                if generated.iter().any(|(g, _)| *g == i) {
+                    String::new()
+                } else {
+                    col.name.clone()
+                }
+            })
+            .collect();
+
+        for (idx, expr) in generated {
+            let col_name = &schema.columns[idx].name;
+            if expression_contains_aggregate(expr) || Self::has_subqueries(expr) {
+                return Err(Error::invalid_argument(format!(
+                    "generation expression of column '{}' cannot contain aggregates or subqueries",
+                    col_name
+                )));
+            }
+            compile_expression(expr, &visible_columns).map_err(|e| {
+                Error::invalid_argument(format!(
+                    "invalid generation expression for column '{}': {}",
+                    col_name, e
+                ))
+            })?;
+            schema.columns[idx].generated_expr = Some(expr.to_string());
+        }
+        Ok(())
+    }
+
+    /// Mark the COMPRESSED columns in the schema
+    ///
+    /// Only TEXT, JSON and BLOB columns can be compressed. Key and UNIQUE
+    /// columns are rejected because their indexes need the plain values.
+    fn attach_compressed_columns(columns: &[ColumnDefinition], schema: &mut Schema) -> Result<()> {
+        for (idx, col_def) in columns.iter().enumerate() {
+            if !col_def
+                .constraints
+                .iter()
+                .any(|c| matches!(c, ColumnConstraint::Compressed))
+            {
+                continue;
+            }
+            let col = &mut schema.columns[idx];
+            if !compression::is_compressible_type(col.data_type) {
+                return Err(Error::invalid_argument(format!(
+                    "COMPRESSED column '{}' must be TEXT, JSON or BLOB, got {:?}",
+                    col.name, col.data_type
+                )));
+            }
+            if col_def
+                .constraints
+                .iter()
+                .any(|c| matches!(c, ColumnConstraint::PrimaryKey | ColumnConstraint::Unique))
+            {
+                return Err(Error::invalid_argument(format!(
+                    "COMPRESSED column '{}' cannot be PRIMARY KEY or UNIQUE",
+                    col.name
+                )));
+            }
+            col.compressed = true;
+        }
+        Ok(())
     }
 
     /// Execute a CREATE SEQUENCE statement
@@ -1654,7 +2457,7 @@ impl Executor {
 
         if self.engine.sequence_exists(&schema_name, &name)? {
             if stmt.if_not_exists {
-                return Ok(Box::new(crate::executor::result::ExecResult::new(0, 0)));
+                return Ok(Box::new(ExecResult::no_op()));
             }
             return Err(Error::SequenceAlreadyExists(name));
         }
@@ -1674,7 +2477,9 @@ impl Executor {
         }
         options.cycle = stmt.cycle;
 
-        self.engine.create_sequence(&schema_name, &name, options)?;
+        self.engine
+            .create_sequence(&schema_name, &name, options.clone())?;
+        self.save_sequence(&schema_name, &name, &options, None)?;
 
         Ok(Box::new(crate::executor::result::ExecResult::new(0, 0)))
     }
@@ -1693,7 +2498,7 @@ impl Executor {
 
         if !self.engine.sequence_exists(&schema_name, &name)? {
             if stmt.if_exists {
-                return Ok(Box::new(crate::executor::result::ExecResult::new(0, 0)));
+                return Ok(Box::new(ExecResult::no_op()));
             }
             return Err(Error::SequenceNotFound(name));
         }
@@ -1720,7 +2525,9 @@ impl Executor {
             options.cycle = v;
         }
 
-        self.engine.alter_sequence(&schema_name, &name, options)?;
+        self.engine
+            .alter_sequence(&schema_name, &name, options.clone())?;
+        self.save_sequence(&schema_name, &name, &options, None)?;
 
         Ok(Box::new(crate::executor::result::ExecResult::new(0, 0)))
     }
@@ -1739,16 +2546,84 @@ impl Executor {
 
         if !self.engine.sequence_exists(&schema_name, &name)? {
             if stmt.if_exists {
-                return Ok(Box::new(crate::executor::result::ExecResult::new(0, 0)));
+                return Ok(Box::new(ExecResult::no_op()));
             }
             return Err(Error::SequenceNotFound(name));
         }
 
         self.engine.drop_sequence(&schema_name, &name)?;
+        self.delete_sequence(&schema_name, &name)?;
 
         Ok(Box::new(crate::executor::result::ExecResult::new(0, 0)))
     }
 
+    pub(crate) fn ensure_sequences_table_exists(&self) -> Result<()> {
+        let tx = self.engine.begin_transaction()?;
+        let tables = tx.list_tables()?;
+        let has_sequences_table = tables.iter().any(|t| t.eq_ignore_ascii_case(SYS_SEQUENCES));
+        drop(tx);
+
+        if !has_sequences_table {
+            if let Err(e) = self.execute_internal_sql(CREATE_SEQUENCES_SQL) {
+                tracing::error!("Failed to create sequences table: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Insert or replace a sequence's row in the system table
+    fn save_sequence(
+        &self,
+        schema_name: &str,
+        name: &str,
+        options: &crate::core::SequenceOptions,
+        last_value: Option<i64>,
+    ) -> Result<()> {
+        self.ensure_sequences_table_exists()?;
+        let mut tx = self.engine.begin_transaction()?;
+        let mut table = tx.get_table(SYS_SEQUENCES)?;
+
+        let sequence_id = find_sequence_row(table.as_ref(), schema_name, name)?
+            .and_then(|row| row.get(0).cloned());
+        let new_row = Row::from_values(vec![
+            sequence_id
+                .clone()
+                .unwrap_or(Value::Null(DataType::Integer)),
+            Value::text(schema_name.to_lowercase()),
+            Value::text(name.to_lowercase()),
+            Value::integer(options.start_with),
+            Value::integer(options.increment_by),
+            Value::integer(options.min_value),
+            Value::integer(options.max_value),
+            Value::Boolean(options.cycle),
+            last_value.map_or(Value::Null(DataType::Integer), Value::integer),
+        ]);
+
+        replace_or_insert_row(table.as_mut(), sequence_id, new_row)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Delete a sequence's row from the system table
+    fn delete_sequence(&self, schema_name: &str, name: &str) -> Result<()> {
+        self.ensure_sequences_table_exists()?;
+        let mut tx = self.engine.begin_transaction()?;
+        let mut table = tx.get_table(SYS_SEQUENCES)?;
+
+        if let Some(id_value) = find_sequence_row(table.as_ref(), schema_name, name)?
+            .and_then(|row| row.get(0).cloned())
+        {
+            use crate::storage::expression::{ComparisonExpr, Expression as StorageExpr};
+            let mut id_expr = ComparisonExpr::new("id", crate::core::Operator::Eq, id_value);
+            id_expr.prepare_for_schema(table.schema());
+            table.delete(Some(&id_expr))?;
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+
     pub(crate) fn execute_create_schema(
         &self,
         stmt: &CreateSchemaStatement,
@@ -1757,14 +2632,11 @@ impl Executor {
         let schema_name = stmt.schema_name.value.to_lowercase();
 
         // Check if schema already exists
-        {
-            let schemas = self.engine.schemas.read().unwrap();
-            if schemas.contains_key(&schema_name) {
-                if stmt.if_not_exists {
-                    return Ok(Box::new(ExecResult::empty()));
-                }
-                return Err(Error::SchemaAlreadyExists);
+        if self.engine.schema_exists(&schema_name)? {
+            if stmt.if_not_exists {
+                return Ok(Box::new(ExecResult::no_op()));
             }
+            return Err(Error::SchemaAlreadyExists);
         }
 
         // Check active transaction
@@ -1772,10 +2644,7 @@ impl Executor {
 
         if let Some(ref mut tx_state) = *active_tx {
             // Add to schemas
-            {
-                let mut schemas = self.engine.schemas.write().unwrap();
-                schemas.insert(schema_name.clone(), FxHashMap::default());
-            }
+            self.engine.create_schema(&schema_name)?;
 
             // Add to undo log
             tx_state
@@ -1783,10 +2652,7 @@ impl Executor {
                 .push(super::DeferredDdlOperation::CreateSchema { name: schema_name });
         } else {
             // Add to schemas
-            {
-                let mut schemas = self.engine.schemas.write().unwrap();
-                schemas.insert(schema_name, FxHashMap::default());
-            }
+            self.engine.create_schema(&schema_name)?;
         }
 
         Ok(Box::new(ExecResult::empty()))
@@ -1823,14 +2689,11 @@ impl Executor {
         if let Some(ref mut tx_state) = *active_tx {
             // Drop tables
             for (table_name, _) in &tables_to_drop {
-                self.engine.drop_table_internal(table_name)?;
+                self.engine.drop_table(table_name)?;
             }
 
             // Drop schema
-            {
-                let mut schemas = self.engine.schemas.write().unwrap();
-                schemas.remove(&schema_name);
-            }
+            self.engine.drop_schema(&schema_name)?;
 
             // Add to undo log
             tx_state
@@ -1850,10 +2713,7 @@ impl Executor {
             }
 
             // Drop schema
-            {
-                let mut schemas = self.engine.schemas.write().unwrap();
-                schemas.remove(&schema_name);
-            }
+            self.engine.drop_schema(&schema_name)?;
         }
 
         Ok(Box::new(ExecResult::empty()))
@@ -1869,6 +2729,63 @@ impl Executor {
         Ok(Box::new(ExecResult::empty()))
     }
 
+    /// Resolve an unqualified table name along `SET search_path`
+    ///
+    /// Returns the name qualified with the first schema on the path that has
+    /// a table or view of that name. Returns None when the name is already
+    /// qualified, when nothing on the path matches, or when the match is in
+    /// `public`, which is where unqualified names point anyway.
+    pub(crate) fn resolve_search_path(&self, name: &TableName) -> Option<TableName> {
+        let TableName::Simple(ident) = name else {
+            return None;
+        };
+        let path = self.search_path.lock().unwrap();
+        if path.iter().all(|schema| schema == "public") {
+            return None;
+        }
+
+        for schema in path.iter() {
+            let has_table = matches!(
+                self.engine
+                    .table_exists(&format!("{}.{}", schema, ident.value_lower)),
+                Ok(true)
+            );
+            let has_view = matches!(
+                self.engine.get_view_lowercase(schema, &ident.value_lower),
+                Ok(Some(_))
+            );
+            if has_table || has_view {
+                if schema == "public" {
+                    return None;
+                }
+                return Some(TableName::Qualified(QualifiedIdentifier {
+                    token: ident.token.clone(),
+                    qualifier: Box::new(Identifier::new(ident.token.clone(), schema.clone())),
+                    name: Box::new(ident.clone()),
+                }));
+            }
+        }
+        None
+    }
+
+    /// `resolve_search_path` for a FROM-clause table; without an alias the
+    /// table still answers to the name it was written with (`t.col`)
+    pub(crate) fn resolve_search_path_source(
+        &self,
+        source: &SimpleTableSource,
+    ) -> Option<SimpleTableSource> {
+        let name = self.resolve_search_path(&source.name)?;
+        let written = match &source.name {
+            TableName::Simple(ident) => ident.clone(),
+            TableName::Qualified(qi) => (*qi.name).clone(),
+        };
+        Some(SimpleTableSource {
+            name,
+            alias: source.alias.clone().or(Some(written)),
+            ..source.clone()
+        })
+    }
+
     pub(crate) fn ensure_triggers_table_exists(&self) -> Result<()> {
         let tx = self.engine.begin_transaction()?;
         let tables = tx.list_tables()?;
@@ -2228,7 +3145,7 @@ impl Executor {
                 trigger_name_upper
             )));
         } else if exists && stmt.if_not_exists {
-            return Ok(Box::new(ExecResult::new(0, 0)));
+            return Ok(Box::new(ExecResult::no_op()));
         }
 
         if !self.function_registry.is_language_supported(&stmt.language) {
@@ -2277,7 +3194,63 @@ impl Executor {
             )));
         }
 
-        Ok(Box::new(ExecResult::new(if deleted { 1 } else { 0 }, 0)))
+        if !deleted {
+            return Ok(Box::new(ExecResult::no_op()));
+        }
+        Ok(Box::new(ExecResult::new(1, 0)))
+    }
+}
+
+/// Overwrite the system table row with the given ID, or insert the row when
+/// there is none
+///
+/// Updating in place keeps the primary key, which a delete followed by an
+/// insert of the same ID in one transaction would trip over.
+fn replace_or_insert_row(
+    table: &mut dyn crate::storage::traits::Table,
+    id: Option<Value>,
+    row: Row,
+) -> Result<()> {
+    let Some(id_value) = id else {
+        table.insert(row)?;
+        return Ok(());
+    };
+
+    use crate::storage::expression::{ComparisonExpr, Expression as StorageExpr};
+    let mut id_expr = ComparisonExpr::new("id", crate::core::Operator::Eq, id_value);
+    id_expr.prepare_for_schema(table.schema());
+    let mut new_row = Some(row);
+    table.update(Some(&id_expr), &mut |old| {
+        Ok(new_row.take().map_or((old, false), |row| (row, true)))
+    })?;
+    Ok(())
+}
+
+/// Collect every CHECK constraint declared on a column, in declaration order
+fn column_checks(constraints: &[ColumnConstraint]) -> Vec<CheckConstraint> {
+    constraints
+        .iter()
+        .filter_map(|c| match c {
+            ColumnConstraint::Check { name, expr } => Some(CheckConstraint {
+                name: name.clone(),
+                expr: expr.to_string(),
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Name of the unique index enforcing a PRIMARY KEY that rows aren't keyed
+/// by (a composite or non-INTEGER key)
+pub(crate) fn primary_key_index_name(table_name: &str) -> String {
+    format!("pk_{}", table_name)
+}
+
+/// Strip the implicit `public.` qualifier from a table name
+pub(crate) fn unqualified_public(name: &str) -> &str {
+    match name.split_once('.') {
+        Some((schema, table)) if schema.eq_ignore_ascii_case("public") => table,
+        _ => name,
     }
 }
 
@@ -2423,5 +3396,6 @@ mod tests {
             DataType::Timestamp
         );
         assert_eq!(executor.parse_data_type("JSON").unwrap(), DataType::Json);
+        assert_eq!(executor.parse_data_type("UUID").unwrap(), DataType::Text);
     }
 }
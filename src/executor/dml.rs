@@ -20,20 +20,49 @@
 //! - UPDATE
 //! - DELETE
 
-use crate::core::{DataType, Error, Result, Row, Schema, Value};
+use crate::core::{CheckConstraint, DataType, Error, Result, Row, Schema, Value};
 use crate::parser::ast::*;
-use crate::storage::expression::{ComparisonExpr, Expression as StorageExpr};
-use crate::storage::traits::{Engine, QueryResult, Table};
+use crate::storage::expression::{ComparisonExpr, Expression as StorageExpr, InListExpr};
+use crate::storage::traits::{QueryResult, Table};
 use ahash::AHashMap;
 use rustc_hash::FxHashMap;
 use std::sync::Arc;
 
+use super::column_defaults::{
+    parse_column_expression, supplied_columns, target_column_indices, ColumnDefaults,
+};
 use super::context::ExecutionContext;
-use super::expression::CompiledEvaluator;
+use super::expression::{CompiledEvaluator, ExpressionEval};
 use super::pushdown;
 use super::result::ExecResult;
 use super::Executor;
 
+/// Original row and its new column values, keyed by [`update_row_key`]
+type PrecomputedUpdates = AHashMap<Vec<Value>, (Row, Vec<(usize, Value)>)>;
+
+/// Columns that identify a row of `schema` while its updates are precomputed:
+/// the PRIMARY KEY in key order, or every column when the table has no key
+fn update_key_indices(schema: &Schema) -> Vec<usize> {
+    let key: Vec<usize> = schema
+        .primary_key_column_names()
+        .iter()
+        .filter_map(|name| schema.get_column_index(name))
+        .collect();
+    if key.is_empty() {
+        (0..schema.columns.len()).collect()
+    } else {
+        key
+    }
+}
+
+/// Values of the `key_indices` columns of `row`
+fn update_row_key(row: &Row, key_indices: &[usize]) -> Vec<Value> {
+    key_indices
+        .iter()
+        .map(|&idx| row.get(idx).cloned().unwrap_or(Value::null_unknown()))
+        .collect()
+}
+
 /// Validate type coercion didn't silently fail.
 /// Returns an error if a non-null value became null during coercion.
 fn validate_coercion(
@@ -72,7 +101,44 @@ fn try_extract_literal(expr: &Expression) -> Option<Value> {
     }
 }
 
+/// Error for an INSERT or UPDATE that supplies a value for a GENERATED column
+fn generated_column_write_error(column_name: &str) -> Error {
+    Error::InvalidArgumentMessage(format!(
+        "cannot write a value to generated column '{}' (only DEFAULT is allowed)",
+        column_name
+    ))
+}
+
 impl Executor {
+    /// Coerce a value for storage in a column of `target_type`
+    ///
+    /// A value that doesn't convert (such as 'garbage' for a TIMESTAMP) is an
+    /// error rather than NULL. Bytes written to a TEXT column must be valid
+    /// UTF-8: invalid sequences are rejected, or replaced with U+FFFD under
+    /// `SET invalid_utf8 = 'replace'`.
+    pub(crate) fn coerce_column_value(
+        &self,
+        value: Value,
+        target_type: DataType,
+        column_name: &str,
+    ) -> Result<Value> {
+        if let (Value::Blob(bytes), DataType::Text) = (&value, target_type) {
+            return match std::str::from_utf8(bytes) {
+                Ok(s) => Ok(Value::text(s)),
+                Err(_) if self.replace_invalid_utf8() => {
+                    Ok(Value::text(String::from_utf8_lossy(bytes)))
+                }
+                Err(_) => Err(Error::invalid_utf8(column_name)),
+            };
+        }
+        if value.is_null() || value.data_type() == target_type {
+            return Ok(value.into_coerce_to_type(target_type));
+        }
+        let coerced = value.coerce_to_type(target_type);
+        validate_coercion(&value, &coerced, column_name, target_type)?;
+        Ok(coerced)
+    }
+
     /// Execute an INSERT statement
     fn execute_row_triggers(
         &self,
@@ -103,13 +169,15 @@ impl Executor {
                 for trigger in triggers {
                     if let Some(backend) = self.function_registry.get_backend(&trigger.language) {
                         let mut args = vec![];
-                        if let Err(e) = backend.execute_procedure(
-                            &trigger.code,
-                            &mut args,
-                            &[],
-                            &[],
-                            Some(self),
-                        ) {
+                        if let Err(e) = crate::functions::backends::pool::with_script_worker(|| {
+                            backend.execute_procedure(
+                                &trigger.code,
+                                &mut args,
+                                &[],
+                                &[],
+                                Some(self),
+                            )
+                        }) {
                             return Err(crate::core::Error::internal(format!(
                                 "Trigger execution failed: {}",
                                 e
@@ -131,11 +199,21 @@ impl Executor {
         stmt: &InsertStatement,
         ctx: &ExecutionContext,
     ) -> Result<Box<dyn QueryResult>> {
+        // Unqualified names resolve along SET search_path
+        if let Some(table_name) = self.resolve_search_path(&stmt.table_name) {
+            let stmt = InsertStatement {
+                table_name,
+                ..stmt.clone()
+            };
+            return self.execute_insert(&stmt, ctx);
+        }
+
         // Prevent DML on reserved namespaces
         let table_name_raw = stmt.table_name.value();
         if Schema::is_reserved_namespace(&table_name_raw) && !ctx.is_internal() {
             return Err(Error::ReservedNamespaceModification(table_name_raw));
         }
+        self.reject_attached_write(&stmt.table_name)?;
 
         // OPTIMIZATION: Use pre-computed lowercase name to avoid allocation per query
         let table_name = &stmt.table_name.value_lower();
@@ -177,48 +255,26 @@ impl Executor {
         let column_names: Vec<String>;
         // Pre-compute ALL column types for default values and check constraints
         let all_column_types: Vec<crate::core::DataType>;
-        // Pre-compute default values and check expressions for all columns
-        let default_exprs: Vec<Option<String>>;
-        let check_exprs: Vec<(String, Option<String>)>; // (column_name, check_expr)
+        // Pre-compute check expressions for all columns
+        let check_exprs: Vec<(String, Vec<CheckConstraint>)>; // (column_name, checks)
         {
             let schema_ref = table.schema().clone();
             let schema = &schema_ref;
             schema_column_count = schema.columns.len();
 
-            // Extract default and check expressions from schema
-            default_exprs = schema
-                .columns
-                .iter()
-                .map(|c| c.default_expr.clone())
-                .collect();
+            // Extract check expressions from schema
             check_exprs = schema
                 .columns
                 .iter()
-                .map(|c| (c.name.clone(), c.check_expr.clone()))
+                .map(|c| (c.name.clone(), c.checks.clone()))
                 .collect();
             all_column_types = schema.columns.iter().map(|c| c.data_type).collect();
 
-            // OPTIMIZATION: When no columns specified, insert into all columns in order
-            // Skip all column name lookups - just use sequential indices
+            column_indices = target_column_indices(schema, &stmt.columns)?;
             if stmt.columns.is_empty() {
-                column_indices = (0..schema_column_count).collect();
                 column_types = all_column_types.clone();
                 column_names = schema.columns.iter().map(|c| c.name.clone()).collect();
             } else {
-                // Validate columns exist and pre-compute their indices
-                column_indices = stmt
-                    .columns
-                    .iter()
-                    .map(|id| {
-                        // Use pre-computed lowercase value from AST
-                        let col_lower = &id.value_lower;
-                        schema
-                            .columns
-                            .iter()
-                            .position(|c| c.name.eq_ignore_ascii_case(col_lower))
-                            .ok_or_else(|| Error::ColumnNotFoundNamed(id.value.clone()))
-                    })
-                    .collect::<Result<Vec<_>>>()?;
                 // Get column types for the specified columns
                 column_types = column_indices
                     .iter()
@@ -232,6 +288,18 @@ impl Executor {
             }
         }
 
+        // Every column the INSERT leaves out takes its DEFAULT, or NULL without one
+        let mut defaults = ColumnDefaults::new(&table.schema().clone())?;
+        let supplied = supplied_columns(schema_column_count, &column_indices);
+
+        // GENERATED columns are computed from the rest of the row and never written directly
+        let generated_schema = table.schema().clone();
+        let mut generated_columns = self.compile_generated_columns(&generated_schema, ctx)?;
+        let generated_targets: Vec<bool> = column_indices
+            .iter()
+            .map(|&idx| generated_schema.columns[idx].generated_expr.is_some())
+            .collect();
+
         // Create VM for constant expression evaluation (reused for all INSERT values)
         use super::expression::{compile_expression, ExecuteContext, ExprVM};
         let mut vm = ExprVM::new();
@@ -240,7 +308,8 @@ impl Executor {
         let empty_row: &[Value] = &[];
 
         // OPTIMIZATION: Pre-build ExecuteContext once (reused for all expressions)
-        let mut base_exec_ctx = ExecuteContext::new(empty_row);
+        let mut base_exec_ctx =
+            ExecuteContext::new(empty_row).with_div_by_zero_null(ctx.div_by_zero_null());
         if !params.is_empty() {
             base_exec_ctx = base_exec_ctx.with_params(params);
         }
@@ -253,6 +322,8 @@ impl Executor {
         // RETURNING clause support - collect inserted rows if RETURNING is specified
         let has_returning = !stmt.returning.is_empty();
         let mut returning_rows: Vec<Row> = Vec::new();
+        // Per RETURNING row: true if ON DUPLICATE KEY UPDATE updated it (xmax <> 0)
+        let mut returning_updated: Vec<bool> = Vec::new();
         let schema_column_names: Vec<String> = table.schema().column_names_owned().to_vec();
 
         let mut get_table_fn = |name: &str| -> Result<Box<dyn Table>> {
@@ -270,82 +341,99 @@ impl Executor {
 
         // Check if this is INSERT ... SELECT
         if let Some(ref select_stmt) = stmt.select {
-            // Execute the SELECT query
+            if let Some(pos) = generated_targets.iter().position(|&g| g) {
+                return Err(generated_column_write_error(&column_names[pos]));
+            }
+
+            // Rows are pulled from the SELECT one at a time and inserted as they
+            // arrive, so a streaming source is never buffered in full
             let mut select_result = self.execute_select(select_stmt, ctx)?;
+            let schema = table.schema().clone();
 
-            // Process each row from the SELECT result
-            while select_result.next() {
-                let select_row = select_result.row();
-                let select_values = select_row.as_slice();
+            // Within an explicit transaction a failing row must not leave the
+            // rows inserted before it behind
+            let statement_start = crate::storage::get_fast_timestamp();
 
-                if select_values.len() != column_indices.len() {
-                    return Err(Error::InvalidArgumentMessage(format!(
-                        "INSERT has {} columns but SELECT returns {} columns",
-                        column_indices.len(),
-                        select_values.len()
-                    )));
-                }
+            let mut insert_rows = || -> Result<()> {
+                while select_result.next() {
+                    ctx.check_cancelled()?;
+                    let select_row = select_result.take_row();
 
-                // Build row values - initialize with DEFAULT values for missing columns
-                // This matches the behavior of regular INSERT
-                let mut row_values = Vec::with_capacity(schema_column_count);
-                for i in 0..schema_column_count {
-                    if let Some(ref default_expr) = default_exprs[i] {
-                        let default_type = all_column_types[i];
-                        match self.evaluate_default_expr(default_expr, default_type) {
-                            Ok(val) => row_values.push(val),
-                            Err(_) => row_values.push(Value::null_unknown()),
-                        }
-                    } else {
-                        row_values.push(Value::null_unknown());
+                    if select_row.len() != column_indices.len() {
+                        return Err(Error::InvalidArgumentMessage(format!(
+                            "INSERT has {} columns but SELECT returns {} columns",
+                            column_indices.len(),
+                            select_row.len()
+                        )));
                     }
-                }
 
-                // Fill in values from SELECT using pre-computed indices with type coercion
-                for (i, value) in select_values.iter().enumerate() {
-                    // Coerce value to target column type
-                    let coerced = value.coerce_to_type(column_types[i]);
-                    // Validate coercion didn't silently fail
-                    validate_coercion(value, &coerced, &column_names[i], column_types[i])?;
-                    row_values[column_indices[i]] = coerced;
-                }
+                    let mut row_values = defaults.row(&supplied)?;
 
-                // Validate Foreign Keys
-                let schema = table.schema().clone();
-                if !schema.foreign_keys.is_empty() {
-                    self.validate_foreign_keys_for_row(&schema, &row_values, &mut get_table_fn)?;
-                }
+                    // Fill in values from SELECT using pre-computed indices with type coercion
+                    for (i, value) in select_row.into_iter().enumerate() {
+                        // Coerce value to target column type
+                        row_values[column_indices[i]] =
+                            self.coerce_column_value(value, column_types[i], &column_names[i])?;
+                    }
 
-                // Create row and insert (returns row with AUTO_INCREMENT applied)
-                let mut row = Row::from_values(row_values);
+                    if !generated_columns.is_empty() {
+                        self.apply_generated_columns(
+                            &mut generated_columns,
+                            &schema,
+                            &mut row_values,
+                        )?;
+                    }
 
-                // FIRE BEFORE INSERT TRIGGERS
-                self.execute_row_triggers(
-                    &table_name_raw,
-                    "BEFORE",
-                    "INSERT",
-                    Some(&mut row),
-                    None,
-                    &schema,
-                )?;
+                    // Validate Foreign Keys
+                    if !schema.foreign_keys.is_empty() {
+                        self.validate_foreign_keys_for_row(
+                            &schema,
+                            &row_values,
+                            &mut get_table_fn,
+                        )?;
+                    }
 
-                let mut inserted_row = table.insert(row)?;
-                rows_affected += 1;
+                    // Create row and insert (returns row with AUTO_INCREMENT applied)
+                    let mut row = Row::from_values(row_values);
 
-                // FIRE AFTER INSERT TRIGGERS
-                self.execute_row_triggers(
-                    &table_name_raw,
-                    "AFTER",
-                    "INSERT",
-                    Some(&mut inserted_row),
-                    None,
-                    &schema,
-                )?;
+                    // FIRE BEFORE INSERT TRIGGERS
+                    self.execute_row_triggers(
+                        &table_name_raw,
+                        "BEFORE",
+                        "INSERT",
+                        Some(&mut row),
+                        None,
+                        &schema,
+                    )?;
 
-                // Collect inserted row for RETURNING if specified
-                if has_returning {
-                    returning_rows.push(inserted_row);
+                    let mut inserted_row = table.insert(row)?;
+                    rows_affected += 1;
+
+                    // FIRE AFTER INSERT TRIGGERS
+                    self.execute_row_triggers(
+                        &table_name_raw,
+                        "AFTER",
+                        "INSERT",
+                        Some(&mut inserted_row),
+                        None,
+                        &schema,
+                    )?;
+
+                    // Collect inserted row for RETURNING if specified
+                    if has_returning {
+                        returning_rows.push(inserted_row);
+                    }
                 }
+                // A streaming SELECT cut short by the timeout reads as finished
+                ctx.check_cancelled()
+            };
+
+            if let Err(err) = insert_rows() {
+                // A standalone transaction rolls back when dropped
+                if !should_auto_commit {
+                    table.rollback_to_timestamp(statement_start);
+                }
+                return Err(err);
             }
 
             // Invalidate semantic cache for this table BEFORE commit
@@ -369,6 +457,7 @@ impl Executor {
                     &stmt.returning,
                     returning_rows,
                     &schema_column_names,
+                    Some(&returning_updated),
                     ctx,
                 );
             }
@@ -390,25 +479,18 @@ impl Executor {
                     )));
                 }
 
-                // Build row values - initialize with DEFAULT values for missing columns
-                let mut row_values = Vec::with_capacity(schema_column_count);
-                for i in 0..schema_column_count {
-                    if let Some(ref default_expr) = default_exprs[i] {
-                        let default_type = all_column_types[i];
-                        match self.evaluate_default_expr(default_expr, default_type) {
-                            Ok(val) => row_values.push(val),
-                            Err(_) => row_values.push(Value::null_unknown()),
-                        }
-                    } else {
-                        row_values.push(Value::null_unknown());
-                    }
-                }
+                // Build row values - DEFAULT values for the columns not listed
+                let mut row_values = defaults.row(&supplied)?;
                 // Fill in provided values using pre-computed indices with type coercion
                 for (i, expr) in value_row.iter().enumerate() {
-                    // Handle DEFAULT keyword - skip this column to use pre-initialized default
+                    // DEFAULT keyword - evaluate the column's default for this row
                     if matches!(expr, Expression::Default(_)) {
+                        row_values[column_indices[i]] = defaults.value(column_indices[i])?;
                         continue;
                     }
+                    if generated_targets[i] {
+                        return Err(generated_column_write_error(&column_names[i]));
+                    }
                     // OPTIMIZATION: Try to extract literal value directly without VM compilation
                     // This avoids ~1-2μs per expression for simple literals (INTEGER, TEXT, etc.)
                     let value = if let Some(lit_value) = try_extract_literal(expr) {
@@ -419,10 +501,12 @@ impl Executor {
                         vm.execute(&program, &base_exec_ctx)?
                     };
                     // Coerce to target type
-                    let coerced = value.coerce_to_type(column_types[i]);
-                    // Validate coercion didn't silently fail
-                    validate_coercion(&value, &coerced, &column_names[i], column_types[i])?;
-                    row_values[column_indices[i]] = coerced;
+                    row_values[column_indices[i]] =
+                        self.coerce_column_value(value, column_types[i], &column_names[i])?;
+                }
+
+                if !generated_columns.is_empty() {
+                    self.apply_generated_columns(&mut generated_columns, &schema, &mut row_values)?;
                 }
 
                 // Validate Foreign Keys
@@ -432,12 +516,10 @@ impl Executor {
 
                 // Need to clone for potential update
                 let row = Row::from_values(row_values.clone());
-                match table.insert(row) {
-                    Ok(_inserted_row) => {
-                        rows_affected += 1;
-                    }
+                let (returned_row, updated) = match table.insert(row) {
+                    Ok(inserted_row) => (Some(inserted_row), false),
                     Err(Error::PrimaryKeyConstraint { row_id }) => {
-                        self.apply_on_duplicate_update(
+                        let updated_row = self.apply_on_duplicate_update(
                             &mut table,
                             &schema,
                             row_id,
@@ -445,7 +527,7 @@ impl Executor {
                             stmt,
                             ctx,
                         )?;
-                        rows_affected += 1;
+                        (updated_row, true)
                     }
                     Err(Error::UniqueConstraint {
                         index,
@@ -459,7 +541,7 @@ impl Executor {
                             &column,
                             &row_values,
                         )? {
-                            self.apply_on_duplicate_update(
+                            let updated_row = self.apply_on_duplicate_update(
                                 &mut table,
                                 &schema,
                                 row_id,
@@ -467,7 +549,7 @@ impl Executor {
                                 stmt,
                                 ctx,
                             )?;
-                            rows_affected += 1;
+                            (updated_row, true)
                         } else {
                             return Err(Error::UniqueConstraint {
                                 index,
@@ -477,6 +559,15 @@ impl Executor {
                         }
                     }
                     Err(e) => return Err(e),
+                };
+                rows_affected += 1;
+
+                // Collect the inserted or updated row for RETURNING
+                if has_returning {
+                    if let Some(returned_row) = returned_row {
+                        returning_rows.push(returned_row);
+                        returning_updated.push(updated);
+                    }
                 }
             }
         } else {
@@ -490,27 +581,19 @@ impl Executor {
                     )));
                 }
 
-                // Build row values - initialize with DEFAULT values for missing columns
-                let mut row_values = Vec::with_capacity(schema_column_count);
-                for i in 0..schema_column_count {
-                    if let Some(ref default_expr) = default_exprs[i] {
-                        // Evaluate the default expression using the actual column type
-                        let default_type = all_column_types[i];
-                        match self.evaluate_default_expr(default_expr, default_type) {
-                            Ok(val) => row_values.push(val),
-                            Err(_) => row_values.push(Value::null_unknown()),
-                        }
-                    } else {
-                        row_values.push(Value::null_unknown());
-                    }
-                }
+                // Build row values - DEFAULT values for the columns not listed
+                let mut row_values = defaults.row(&supplied)?;
 
                 // Fill in provided values using pre-computed indices with type coercion
                 for (i, expr) in value_row.iter().enumerate() {
-                    // Handle DEFAULT keyword - skip this column to use pre-initialized default
+                    // DEFAULT keyword - evaluate the column's default for this row
                     if matches!(expr, Expression::Default(_)) {
+                        row_values[column_indices[i]] = defaults.value(column_indices[i])?;
                         continue;
                     }
+                    if generated_targets[i] {
+                        return Err(generated_column_write_error(&column_names[i]));
+                    }
                     // OPTIMIZATION: Try to extract literal value directly without VM compilation
                     // This avoids ~1-2μs per expression for simple literals (INTEGER, TEXT, etc.)
                     let value = if let Some(lit_value) = try_extract_literal(expr) {
@@ -521,10 +604,16 @@ impl Executor {
                         vm.execute(&program, &base_exec_ctx)?
                     };
                     // Coerce to target type
-                    let coerced = value.coerce_to_type(column_types[i]);
-                    // Validate coercion didn't silently fail
-                    validate_coercion(&value, &coerced, &column_names[i], column_types[i])?;
-                    row_values[column_indices[i]] = coerced;
+                    row_values[column_indices[i]] =
+                        self.coerce_column_value(value, column_types[i], &column_names[i])?;
+                }
+
+                if !generated_columns.is_empty() {
+                    self.apply_generated_columns(
+                        &mut generated_columns,
+                        &generated_schema,
+                        &mut row_values,
+                    )?;
                 }
 
                 // Validate Foreign Keys
@@ -534,11 +623,11 @@ impl Executor {
                 }
 
                 // Validate CHECK constraints
-                for (col_idx, (col_name, check_expr_opt)) in check_exprs.iter().enumerate() {
-                    if let Some(ref check_expr) = check_expr_opt {
+                for (col_idx, (col_name, checks)) in check_exprs.iter().enumerate() {
+                    for check in checks {
                         let col_type = all_column_types[col_idx];
                         self.validate_check_constraint(
-                            check_expr,
+                            check,
                             col_name,
                             &row_values[col_idx],
                             col_type,
@@ -596,6 +685,7 @@ impl Executor {
                 &stmt.returning,
                 returning_rows,
                 &schema_column_names,
+                Some(&returning_updated),
                 ctx,
             );
         }
@@ -609,11 +699,21 @@ impl Executor {
         stmt: &UpdateStatement,
         ctx: &ExecutionContext,
     ) -> Result<Box<dyn QueryResult>> {
+        // Unqualified names resolve along SET search_path
+        if let Some(table_name) = self.resolve_search_path(&stmt.table_name) {
+            let stmt = UpdateStatement {
+                table_name,
+                ..stmt.clone()
+            };
+            return self.execute_update(&stmt, ctx);
+        }
+
         // Prevent DML on reserved namespaces
         let table_name_raw = stmt.table_name.value();
         if Schema::is_reserved_namespace(&table_name_raw) && !ctx.is_internal() {
             return Err(Error::ReservedNamespaceModification(table_name_raw));
         }
+        self.reject_attached_write(&stmt.table_name)?;
 
         let table_name = &stmt.table_name.value_lower();
 
@@ -666,9 +766,10 @@ impl Executor {
             .iter()
             .any(|(_, expr)| Self::has_subqueries(expr) && Self::has_correlated_subqueries(expr));
 
-        // We must pre-compute updates if there are correlated subqueries OR if we need to validate foreign keys
+        // We must pre-compute updates if there are correlated subqueries, a FROM clause,
+        // OR if we need to validate foreign keys
         let has_foreign_keys = !schema.foreign_keys.is_empty() || !schema.referenced_by.is_empty();
-        let must_precompute = has_correlated_updates || has_foreign_keys;
+        let must_precompute = has_correlated_updates || has_foreign_keys || stmt.from.is_some();
 
         // Pre-process update expressions if they contain NON-correlated subqueries
         // Correlated subqueries must be processed per-row with outer row context
@@ -723,19 +824,35 @@ impl Executor {
                     .collect()
             };
 
+        // GENERATED columns cannot be assigned; they are recomputed from the updated row
+        if let Some((idx, ..)) = update_indices
+            .iter()
+            .find(|(idx, ..)| schema.columns[*idx].generated_expr.is_some())
+        {
+            return Err(generated_column_write_error(&schema.columns[*idx].name));
+        }
+        let mut generated_columns = self.compile_generated_columns(schema, ctx)?;
+
         // Build WHERE expression for storage layer
         // Try to convert to storage expression, fall back to in-memory filtering if not possible
         let (where_expr, needs_memory_filter, memory_where_clause): (
             Option<Box<dyn StorageExpr>>,
             bool,
             Option<Expression>,
-        ) = if let Some(ref where_clause) = stmt.where_clause {
+        ) = if stmt.from.is_some() {
+            // The WHERE clause references the source tables and is evaluated by the join
+            (None, false, None)
+        } else if let Some(ref where_clause) = stmt.where_clause {
             let processed_where = if Self::has_subqueries(where_clause) {
-                self.process_where_subqueries(where_clause, ctx)?
+                let processed = self.process_where_subqueries(where_clause, ctx)?;
+                ctx.check_cancelled()?;
+                processed
             } else {
                 (**where_clause).clone()
             };
 
+            pushdown::check_comparison_types(&processed_where, schema, Some(ctx))?;
+
             // Try to push down predicate to storage layer
             let (storage_expr, needs_mem) =
                 pushdown::try_pushdown(&processed_where, schema, Some(ctx));
@@ -760,9 +877,14 @@ impl Executor {
         let column_names_vec: Vec<String> = column_names.to_vec();
 
         // Use RefCell to collect updated rows for RETURNING clause
-        use std::cell::RefCell;
+        use std::cell::{Cell, RefCell};
         let returning_rows: RefCell<Vec<Row>> = RefCell::new(Vec::new());
 
+        // Rows matched by the WHERE clause; the setters report only the rows
+        // whose values changed, so rows updated to their current values
+        // aren't rewritten
+        let rows_matched = Cell::new(0i64);
+
         // Create a setter function that applies updates using pre-computed indices
         // If we need memory filtering, include the WHERE check in the setter
         // For correlated subqueries, we need special handling
@@ -772,19 +894,23 @@ impl Executor {
             // and can't be used inside the closure. Strategy:
             // 1. Scan table to find all rows (matching WHERE if applicable)
             // 2. For each row, build outer_row context and evaluate correlated expressions
-            // 3. Store computed values keyed by PK
+            // 3. Store computed values keyed by the row's key (see update_key_indices)
             // 4. Validate foreign keys and referential actions
             // 5. Call table.update with a setter that looks up pre-computed values
 
-            // Get primary key column index
+            // Get primary key column index (referenced by other tables' foreign keys)
             let pk_indices = schema.primary_key_indices();
             let pk_idx = pk_indices.first().copied().unwrap_or(0);
+            let key_indices = update_key_indices(schema);
 
             // Pre-compute values for all rows
-            // Map: pk_value -> (Row, Vec<(col_idx, new_value)>)
+            // Map: row key -> (Row, Vec<(col_idx, new_value)>)
             // OPTIMIZATION: Use AHashMap for Value keys (better hash distribution)
-            let mut precomputed: AHashMap<Value, (Row, Vec<(usize, Value)>)> =
-                AHashMap::with_capacity(64);
+            let mut precomputed: PrecomputedUpdates = if let Some(ref from) = stmt.from {
+                self.precompute_update_from(stmt, from, schema, &key_indices, &update_indices, ctx)?
+            } else {
+                AHashMap::with_capacity(64)
+            };
 
             // Build column indices for scanning (all columns)
             let all_col_indices: Vec<usize> = (0..column_names_vec.len()).collect();
@@ -804,72 +930,79 @@ impl Executor {
             let mut outer_row_map: FxHashMap<String, Value> =
                 FxHashMap::with_capacity_and_hasher(col_name_pairs.len() * 2, Default::default());
 
-            // Scan all rows (WHERE filtering happens in the setter)
-            let mut scanner = table.scan(&all_col_indices, None)?;
-            while scanner.next() {
-                let row = scanner.row();
+            // UPDATE ... FROM already computed its rows from the join
+            if stmt.from.is_none() {
+                // Scan all rows (WHERE filtering happens in the setter)
+                let mut scanner = table.scan(&all_col_indices, None)?;
+                while scanner.next() {
+                    ctx.check_cancelled()?;
+                    let row = scanner.row();
 
-                // Check WHERE condition if needed
-                evaluator.set_row_array(row);
-                if needs_memory_filter {
-                    if let Some(ref where_clause) = memory_where_clause {
-                        match evaluator.evaluate_bool(where_clause) {
-                            Ok(true) => {}
-                            _ => continue,
+                    // Check WHERE condition if needed
+                    evaluator.set_row_array(row);
+                    if needs_memory_filter {
+                        if let Some(ref where_clause) = memory_where_clause {
+                            match evaluator.evaluate_bool(where_clause) {
+                                Ok(true) => {}
+                                _ => continue,
+                            }
                         }
                     }
-                }
 
-                // Get PK value for this row
-                let pk_value = row.get(pk_idx).cloned().unwrap_or(Value::null_unknown());
+                    let key = update_row_key(row, &key_indices);
 
-                // Build outer row context from current row values using pre-computed names
-                outer_row_map.clear();
-                for (i, (col_lower, qualified)) in col_name_pairs.iter().enumerate() {
-                    if let Some(value) = row.get(i) {
-                        outer_row_map.insert(col_lower.clone(), value.clone());
-                        outer_row_map.insert(qualified.clone(), value.clone());
+                    // Build outer row context from current row values using pre-computed names
+                    outer_row_map.clear();
+                    for (i, (col_lower, qualified)) in col_name_pairs.iter().enumerate() {
+                        if let Some(value) = row.get(i) {
+                            outer_row_map.insert(col_lower.clone(), value.clone());
+                            outer_row_map.insert(qualified.clone(), value.clone());
+                        }
                     }
-                }
 
-                // Create context with outer row for correlated subquery evaluation
-                // Move map into context, we'll take it back after
-                let mut correlated_ctx = ctx
-                    .with_outer_row(std::mem::take(&mut outer_row_map), column_names_arc.clone());
-
-                // Evaluate all update expressions
-                let mut new_values: Vec<(usize, Value)> = Vec::with_capacity(update_indices.len());
-                for (idx, col_type, expr, is_correlated) in update_indices.iter() {
-                    let evaluated = if *is_correlated {
-                        // Process correlated expression - this executes the subquery
-                        match self.process_correlated_expression(expr, &correlated_ctx) {
-                            Ok(processed_expr) => {
-                                // Now evaluate the processed expression (subquery replaced with value)
-                                let mut eval = CompiledEvaluator::new(function_registry)
-                                    .with_context(&correlated_ctx);
-                                eval.init_columns(column_names);
-                                eval.set_row_array(row);
-                                eval.evaluate(&processed_expr).ok()
-                            }
-                            Err(_) => None,
-                        }
-                    } else {
-                        evaluator.evaluate(expr).ok()
-                    };
+                    // Create context with outer row for correlated subquery evaluation
+                    // Move map into context, we'll take it back after
+                    let mut correlated_ctx = ctx.with_outer_row(
+                        std::mem::take(&mut outer_row_map),
+                        column_names_arc.clone(),
+                    );
+
+                    // Evaluate all update expressions
+                    let mut new_values: Vec<(usize, Value)> =
+                        Vec::with_capacity(update_indices.len());
+                    for (idx, col_type, expr, is_correlated) in update_indices.iter() {
+                        let evaluated = if *is_correlated {
+                            // Process correlated expression - this executes the subquery
+                            let processed_expr =
+                                self.process_correlated_expression(expr, &correlated_ctx)?;
+                            // Now evaluate the processed expression (subquery replaced with value)
+                            let mut eval = CompiledEvaluator::new(function_registry)
+                                .with_context(&correlated_ctx);
+                            eval.init_columns(column_names);
+                            eval.set_row_array(row);
+                            eval.evaluate(&processed_expr)?
+                        } else {
+                            evaluator.evaluate(expr)?
+                        };
 
-                    if let Some(new_value) = evaluated {
-                        new_values.push((*idx, new_value.into_coerce_to_type(*col_type)));
+                        let column_name = &schema.columns[*idx].name;
+                        new_values.push((
+                            *idx,
+                            self.coerce_column_value(evaluated, *col_type, column_name)?,
+                        ));
                     }
-                }
 
-                // Take back the map for reuse (zero-copy transfer)
-                outer_row_map = correlated_ctx.outer_row.take().unwrap_or_default();
+                    // Take back the map for reuse (zero-copy transfer)
+                    outer_row_map = correlated_ctx.outer_row.take().unwrap_or_default();
 
-                if !new_values.is_empty() {
-                    precomputed.insert(pk_value, (row.clone(), new_values));
+                    if !new_values.is_empty() {
+                        precomputed.insert(key, (row.clone(), new_values));
+                    }
                 }
+                drop(scanner);
+                // A subquery cut short by the timeout reads as no match
+                ctx.check_cancelled()?;
             }
-            drop(scanner);
 
             // Validate foreign keys and handle referential actions before applying updates
             if has_foreign_keys {
@@ -886,7 +1019,7 @@ impl Executor {
                     }
                 };
 
-                for (pk_value, (original_row, updates)) in &precomputed {
+                for (original_row, updates) in precomputed.values() {
                     // Create the updated row to check constraints against
                     let mut updated_row = original_row.clone();
                     for (idx, new_value) in updates {
@@ -907,6 +1040,7 @@ impl Executor {
                         // Check if PK was actually updated
                         let pk_updated = updates.iter().any(|(idx, _)| *idx == pk_idx);
                         if pk_updated {
+                            let pk_value = original_row.get(pk_idx).unwrap();
                             let new_pk = updated_row.get(pk_idx).unwrap();
                             // If PK value changed
                             if pk_value != new_pk {
@@ -924,13 +1058,19 @@ impl Executor {
 
             // Now update using precomputed values
             let mut setter = |mut row: Row| -> Result<(Row, bool)> {
-                let pk_value = row.get(pk_idx).cloned().unwrap_or(Value::null_unknown());
-
-                if let Some((_, updates)) = precomputed.get(&pk_value) {
+                if let Some((_, updates)) = precomputed.get(&update_row_key(&row, &key_indices)) {
+                    rows_matched.set(rows_matched.get() + 1);
                     let old_row = row.clone();
                     for (idx, new_value) in updates {
                         let _ = row.set(*idx, new_value.clone());
                     }
+                    if !generated_columns.is_empty() {
+                        self.apply_generated_columns(
+                            &mut generated_columns,
+                            schema,
+                            row.as_mut_slice(),
+                        )?;
+                    }
 
                     self.execute_row_triggers(
                         &table_name_raw,
@@ -955,16 +1095,35 @@ impl Executor {
                         schema,
                     )?;
 
-                    Ok((row, true))
+                    let changed = row != old_row;
+                    Ok((row, changed))
                 } else {
                     Ok((row, false))
                 }
             };
 
-            table.update(where_expr.as_deref(), &mut setter)?
+            // UPDATE ... FROM only visits the matched rows; the setter checks
+            // the full key, so a composite key narrows the scan by its first column
+            let matched_expr: Option<Box<dyn StorageExpr>> = match &stmt.from {
+                Some(_) if !schema.primary_key_column_names().is_empty() => {
+                    let mut expr = InListExpr::new(
+                        schema.columns[key_indices[0]].name.clone(),
+                        precomputed.keys().map(|key| key[0].clone()).collect(),
+                    );
+                    expr.prepare_for_schema(schema);
+                    Some(Box::new(expr) as Box<dyn StorageExpr>)
+                }
+                _ => None,
+            };
+
+            table.update(
+                matched_expr.as_deref().or(where_expr.as_deref()),
+                &mut setter,
+            )?
         } else {
             // Optimized path for non-correlated subqueries
             let mut setter = |mut row: Row| -> Result<(Row, bool)> {
+                ctx.check_cancelled()?;
                 evaluator.set_row_array(&row);
 
                 // If we need in-memory WHERE filtering, check the condition first
@@ -976,48 +1135,58 @@ impl Executor {
                         }
                     }
                 }
+                rows_matched.set(rows_matched.get() + 1);
 
                 // Evaluate ALL expressions FIRST using original row values
                 let new_values: Vec<(usize, crate::core::Value)> = update_indices
                     .iter()
-                    .filter_map(|(idx, col_type, expr, _)| {
-                        evaluator
-                            .evaluate(expr)
-                            .ok()
-                            .map(|new_value| (*idx, new_value.into_coerce_to_type(*col_type)))
+                    .map(|(idx, col_type, expr, _)| {
+                        let new_value = evaluator.evaluate(expr)?;
+                        let column_name = &schema.columns[*idx].name;
+                        self.coerce_column_value(new_value, *col_type, column_name)
+                            .map(|v| (*idx, v))
                     })
-                    .collect();
+                    .collect::<Result<_>>()?;
 
-                let changed = !new_values.is_empty();
-                if changed {
-                    let old_row = row.clone();
-                    for (idx, new_value) in new_values {
-                        let _ = row.set(idx, new_value);
-                    }
+                if new_values.is_empty() {
+                    return Ok((row, false));
+                }
 
-                    self.execute_row_triggers(
-                        &table_name_raw,
-                        "BEFORE",
-                        "UPDATE",
-                        Some(&mut row),
-                        Some(&old_row),
+                let old_row = row.clone();
+                for (idx, new_value) in new_values {
+                    let _ = row.set(idx, new_value);
+                }
+                if !generated_columns.is_empty() {
+                    self.apply_generated_columns(
+                        &mut generated_columns,
                         schema,
+                        row.as_mut_slice(),
                     )?;
+                }
 
-                    if has_returning {
-                        returning_rows.borrow_mut().push(row.clone());
-                    }
+                self.execute_row_triggers(
+                    &table_name_raw,
+                    "BEFORE",
+                    "UPDATE",
+                    Some(&mut row),
+                    Some(&old_row),
+                    schema,
+                )?;
 
-                    self.execute_row_triggers(
-                        &table_name_raw,
-                        "AFTER",
-                        "UPDATE",
-                        Some(&mut row),
-                        Some(&old_row),
-                        schema,
-                    )?;
+                if has_returning {
+                    returning_rows.borrow_mut().push(row.clone());
                 }
 
+                self.execute_row_triggers(
+                    &table_name_raw,
+                    "AFTER",
+                    "UPDATE",
+                    Some(&mut row),
+                    Some(&old_row),
+                    schema,
+                )?;
+
+                let changed = row != old_row;
                 Ok((row, changed))
             };
 
@@ -1041,25 +1210,144 @@ impl Executor {
         // Handle RETURNING clause
         if has_returning {
             let rows = returning_rows.into_inner();
-            return self.build_returning_result(&stmt.returning, rows, &column_names_vec, ctx);
+            return self.build_returning_result(
+                &stmt.returning,
+                rows,
+                &column_names_vec,
+                None,
+                ctx,
+            );
         }
 
-        Ok(Box::new(ExecResult::with_rows_affected(
+        Ok(Box::new(ExecResult::with_rows_changed(
+            rows_matched.get(),
             rows_affected as i64,
         )))
     }
 
+    /// Compute the new values of an UPDATE ... FROM by joining the source tables
+    /// with the target table.
+    ///
+    /// Returns the original row and its new values keyed by `key_indices`. A target
+    /// row matched by more than one source row is an error, so the outcome never
+    /// depends on join order. A table without a key is keyed by the whole row, so
+    /// identical rows are indistinguishable and only conflicting values are an error.
+    fn precompute_update_from(
+        &self,
+        stmt: &UpdateStatement,
+        from: &Expression,
+        schema: &Schema,
+        key_indices: &[usize],
+        update_indices: &[(usize, DataType, Expression, bool)],
+        ctx: &ExecutionContext,
+    ) -> Result<PrecomputedUpdates> {
+        let token = stmt.token.clone();
+        let target = Expression::TableSource(SimpleTableSource {
+            token: token.clone(),
+            name: stmt.table_name.clone(),
+            alias: stmt.alias.clone(),
+            as_of: None,
+            index_hint: None,
+        });
+
+        // SELECT target.*, <set expressions> FROM <source> CROSS JOIN target WHERE ...
+        let mut columns = Vec::with_capacity(update_indices.len() + 1);
+        columns.push(Expression::QualifiedStar(QualifiedStarExpression {
+            token: token.clone(),
+            qualifier: stmt
+                .alias
+                .as_ref()
+                .map_or_else(|| stmt.table_name.table(), |alias| alias.value.clone()),
+        }));
+        columns.extend(update_indices.iter().map(|(_, _, expr, _)| expr.clone()));
+
+        let select = SelectStatement {
+            token: token.clone(),
+            distinct: false,
+            columns,
+            with: None,
+            table_expr: Some(Box::new(Expression::JoinSource(Box::new(
+                JoinTableSource {
+                    token,
+                    left: Box::new(from.clone()),
+                    join_type: "CROSS".to_string(),
+                    right: Box::new(target),
+                    condition: None,
+                    using_columns: vec![],
+                },
+            )))),
+            where_clause: stmt.where_clause.clone(),
+            group_by: GroupByClause::default(),
+            having: None,
+            window_defs: vec![],
+            order_by: vec![],
+            limit: None,
+            offset: None,
+            set_operations: vec![],
+            hints: vec![],
+            lock: None,
+        };
+
+        let num_columns = schema.columns.len();
+        let has_key = !schema.primary_key_column_names().is_empty();
+        let mut precomputed: PrecomputedUpdates = AHashMap::with_capacity(64);
+        let mut result = self.execute_select(&select, &ctx.with_incremented_query_depth())?;
+        while result.next() {
+            let values = result.row().as_slice();
+            let row = Row::from_values(values[..num_columns].to_vec());
+            let key = update_row_key(&row, key_indices);
+            let new_values: Vec<(usize, Value)> = update_indices
+                .iter()
+                .zip(&values[num_columns..])
+                .map(|((idx, col_type, _, _), value)| {
+                    let column_name = &schema.columns[*idx].name;
+                    self.coerce_column_value(value.clone(), *col_type, column_name)
+                        .map(|v| (*idx, v))
+                })
+                .collect::<Result<_>>()?;
+
+            let duplicate = match precomputed.get(&key) {
+                Some((_, existing)) => has_key || *existing != new_values,
+                None => false,
+            };
+            if duplicate {
+                return Err(Error::invalid_argument(format!(
+                    "UPDATE ... FROM matched more than one source row for the same row of table '{}'",
+                    stmt.table_name.value()
+                )));
+            }
+            precomputed.insert(key, (row, new_values));
+        }
+
+        Ok(precomputed)
+    }
+
     /// Execute a DELETE statement
     pub(crate) fn execute_delete(
         &self,
         stmt: &DeleteStatement,
         ctx: &ExecutionContext,
     ) -> Result<Box<dyn QueryResult>> {
+        // Unqualified names resolve along SET search_path
+        if let Some(table_name) = self.resolve_search_path(&stmt.table_name) {
+            let alias = stmt.alias.clone().or_else(|| match &stmt.table_name {
+                TableName::Simple(ident) => Some(ident.clone()),
+                TableName::Qualified(_) => None,
+            });
+            let stmt = DeleteStatement {
+                table_name,
+                alias,
+                ..stmt.clone()
+            };
+            return self.execute_delete(&stmt, ctx);
+        }
+
         // Prevent DML on reserved namespaces
         let table_name_raw = stmt.table_name.value();
         if Schema::is_reserved_namespace(&table_name_raw) && !ctx.is_internal() {
             return Err(Error::ReservedNamespaceModification(table_name_raw));
         }
+        self.reject_attached_write(&stmt.table_name)?;
 
         let table_name = &stmt.table_name.value_lower();
         // Use alias if provided, otherwise use table name
@@ -1122,11 +1410,15 @@ impl Executor {
                 (None, true, Some((**where_clause).clone()))
             } else {
                 let processed_where = if Self::has_subqueries(where_clause) {
-                    self.process_where_subqueries(where_clause, ctx)?
+                    let processed = self.process_where_subqueries(where_clause, ctx)?;
+                    ctx.check_cancelled()?;
+                    processed
                 } else {
                     (**where_clause).clone()
                 };
 
+                pushdown::check_comparison_types(&processed_where, &schema, Some(ctx))?;
+
                 // Try to push down predicate to storage layer
                 let (storage_expr, needs_mem) =
                     pushdown::try_pushdown(&processed_where, &schema, Some(ctx));
@@ -1215,6 +1507,7 @@ impl Executor {
                 FxHashMap::with_capacity_and_hasher(estimated_entries, Default::default());
 
             while scanner.next() {
+                ctx.check_cancelled()?;
                 let row = scanner.row();
 
                 // Check memory filter if needed
@@ -1289,6 +1582,8 @@ impl Executor {
             }
             // Drop scanner to release borrow
             drop(scanner);
+            // A subquery cut short by the timeout reads as no match
+            ctx.check_cancelled()?;
 
             // Handle referential actions before deleting
             if has_referential_actions {
@@ -1374,6 +1669,7 @@ impl Executor {
                 &stmt.returning,
                 returning_rows,
                 &column_names_owned,
+                None,
                 ctx,
             );
         }
@@ -1441,7 +1737,8 @@ impl Executor {
         )))
     }
 
-    /// Apply ON DUPLICATE KEY UPDATE to an existing row
+    /// Apply ON DUPLICATE KEY UPDATE to an existing row, returning the row
+    /// as it is after the update
     fn apply_on_duplicate_update(
         &self,
         table: &mut Box<dyn Table>,
@@ -1449,8 +1746,8 @@ impl Executor {
         row_id: i64,
         _insert_values: &[Value],
         stmt: &InsertStatement,
-        _ctx: &ExecutionContext,
-    ) -> Result<()> {
+        ctx: &ExecutionContext,
+    ) -> Result<Option<Row>> {
         // Build a WHERE clause to find the specific row by primary key
         let pk_col = schema
             .columns
@@ -1483,6 +1780,14 @@ impl Executor {
 
         let column_names: Vec<String> = schema.column_names_owned().to_vec();
 
+        if let Some((idx, _, _)) = update_specs
+            .iter()
+            .find(|(idx, _, _)| schema.columns[*idx].generated_expr.is_some())
+        {
+            return Err(generated_column_write_error(&schema.columns[*idx].name));
+        }
+        let mut generated_columns = self.compile_generated_columns(schema, ctx)?;
+
         // Pre-compile update expressions for efficient evaluation
         use super::expression::{compile_expression, ExecuteContext, ExprVM, SharedProgram};
         let compiled_updates: Vec<(usize, crate::core::DataType, SharedProgram)> = update_specs
@@ -1498,20 +1803,23 @@ impl Executor {
         let mut vm = ExprVM::new();
 
         // Create a setter function that applies the ON DUPLICATE KEY UPDATE
+        let mut updated_row = None;
         let mut setter = |mut row: Row| -> Result<(Row, bool)> {
             // Collect all updates first to avoid borrow conflicts
             let updates_to_apply: Vec<(usize, Value)> = {
                 let row_data = row.as_slice();
-                let exec_ctx = ExecuteContext::new(row_data);
+                let exec_ctx =
+                    ExecuteContext::new(row_data).with_div_by_zero_null(ctx.div_by_zero_null());
 
                 compiled_updates
                     .iter()
-                    .filter_map(|(idx, col_type, program)| {
-                        vm.execute(program, &exec_ctx)
-                            .ok()
-                            .map(|v| (*idx, v.into_coerce_to_type(*col_type)))
+                    .map(|(idx, col_type, program)| {
+                        let value = vm.execute(program, &exec_ctx)?;
+                        let column_name = &schema.columns[*idx].name;
+                        self.coerce_column_value(value, *col_type, column_name)
+                            .map(|v| (*idx, v))
                     })
-                    .collect()
+                    .collect::<Result<_>>()?
             };
 
             // Now apply updates
@@ -1519,14 +1827,18 @@ impl Executor {
             for (idx, new_value) in updates_to_apply {
                 let _ = row.set(idx, new_value);
             }
+            if changed && !generated_columns.is_empty() {
+                self.apply_generated_columns(&mut generated_columns, schema, row.as_mut_slice())?;
+            }
 
+            updated_row = Some(row.clone());
             Ok((row, changed))
         };
 
         // Update the row
         table.update(where_expr.as_deref(), &mut setter)?;
 
-        Ok(())
+        Ok(updated_row)
     }
 
     /// Find a row by unique index value
@@ -1585,42 +1897,48 @@ impl Executor {
         Ok(result)
     }
 
-    /// Evaluate a default expression string and return the resulting Value
-    pub(crate) fn evaluate_default_expr(
+    /// Compile the generation expressions of a table's GENERATED columns
+    /// against the full row, pairing each with its column index
+    pub(crate) fn compile_generated_columns(
         &self,
-        default_expr: &str,
-        target_type: crate::core::DataType,
-    ) -> Result<Value> {
-        use super::expression::ExpressionEval;
-        use crate::parser::parse_sql;
-
-        // Parse the default expression as a SELECT expression
-        let sql = format!("SELECT {}", default_expr);
-        let stmts = match parse_sql(&sql) {
-            Ok(s) => s,
-            Err(_) => return Ok(Value::null_unknown()),
-        };
-        if stmts.is_empty() {
-            return Ok(Value::null_unknown());
-        }
+        schema: &Schema,
+        ctx: &ExecutionContext,
+    ) -> Result<Vec<(usize, ExpressionEval)>> {
+        let column_names = schema.column_names_owned();
+        schema
+            .columns
+            .iter()
+            .enumerate()
+            .filter_map(|(i, col)| col.generated_expr.as_deref().map(|expr| (i, expr)))
+            .map(|(i, expr_str)| {
+                let expr = parse_column_expression(expr_str)?;
+                let eval = ExpressionEval::compile(&expr, column_names)?.with_context(ctx);
+                Ok((i, eval))
+            })
+            .collect()
+    }
 
-        // Extract the expression from the SELECT statement
-        if let crate::parser::ast::Statement::Select(select) = &stmts[0] {
-            if let Some(expr) = select.columns.first() {
-                // Constant expression - no row context needed
-                let value = ExpressionEval::compile(expr, &[])?.eval_slice(&[])?;
-                return Ok(value.into_coerce_to_type(target_type));
-            }
+    /// Overwrite the GENERATED columns of a row with values computed from the
+    /// rest of the row
+    pub(crate) fn apply_generated_columns(
+        &self,
+        generated: &mut [(usize, ExpressionEval)],
+        schema: &Schema,
+        row_values: &mut [Value],
+    ) -> Result<()> {
+        for (idx, eval) in generated.iter_mut() {
+            let value = eval.eval_slice(row_values)?;
+            let column = &schema.columns[*idx];
+            row_values[*idx] = self.coerce_column_value(value, column.data_type, &column.name)?;
         }
-
-        Ok(Value::null_unknown())
+        Ok(())
     }
 
     /// Validate a CHECK constraint against row values
     /// Returns Ok(()) if the constraint passes, Err if it fails
     pub(crate) fn validate_check_constraint(
         &self,
-        check_expr: &str,
+        check: &CheckConstraint,
         col_name: &str,
         col_value: &Value,
         _col_type: crate::core::DataType,
@@ -1633,6 +1951,7 @@ impl Executor {
         }
 
         // Parse the check expression
+        let check_expr = check.expr.as_str();
         let sql = format!("SELECT {}", check_expr);
         let stmts = match parse_sql(&sql) {
             Ok(s) => s,
@@ -1645,8 +1964,6 @@ impl Executor {
         // Create an evaluator with the column value in context
         if let crate::parser::ast::Statement::Select(select) = &stmts[0] {
             if let Some(expr) = select.columns.first() {
-                use super::expression::ExpressionEval;
-
                 // Create evaluator and evaluate with row context
                 let columns = vec![col_name.to_string()];
                 let row = crate::core::Row::from_values(vec![col_value.clone()]);
@@ -1660,6 +1977,7 @@ impl Executor {
                     Value::Boolean(false) => Err(Error::CheckConstraintViolation {
                         column: col_name.to_string(),
                         expression: check_expr.to_string(),
+                        constraint: check.name.clone(),
                     }),
                     Value::Null(_) => {
                         // NULL passes CHECK constraint (SQL standard)
@@ -1679,6 +1997,7 @@ impl Executor {
                             Err(Error::CheckConstraintViolation {
                                 column: col_name.to_string(),
                                 expression: check_expr.to_string(),
+                                constraint: check.name.clone(),
                             })
                         }
                     }
@@ -1694,12 +2013,16 @@ impl Executor {
     /// Build a result from RETURNING clause expressions
     ///
     /// Evaluates the RETURNING expressions for each affected row and returns
-    /// the results as a QueryResult.
+    /// the results as a QueryResult. For INSERT, `updated` flags the rows
+    /// ON DUPLICATE KEY UPDATE updated; RETURNING can read this as the
+    /// PostgreSQL-style `xmax` column, 0 for inserted rows and 1 for updated
+    /// ones, unless the table has its own `xmax` column.
     fn build_returning_result(
         &self,
         returning: &[Expression],
         source_rows: Vec<Row>,
         column_names: &[String],
+        updated: Option<&[bool]>,
         ctx: &ExecutionContext,
     ) -> Result<Box<dyn QueryResult>> {
         use super::result::ExecutorMemoryResult;
@@ -1743,10 +2066,20 @@ impl Executor {
 
         use super::expression::{compile_expression, ExecuteContext, ExprVM, SharedProgram};
 
+        let updated = updated.filter(|_| {
+            !column_names
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case("xmax"))
+        });
+        let mut scope: Vec<String> = column_names.to_vec();
+        if updated.is_some() {
+            scope.push("xmax".to_string());
+        }
+
         // Pre-compile all RETURNING expressions
         let compiled_exprs: Vec<SharedProgram> = expanded_exprs
             .iter()
-            .map(|expr| compile_expression(expr, column_names))
+            .map(|expr| compile_expression(expr, &scope))
             .collect::<Result<Vec<_>>>()?;
 
         // Create VM for execution (reused for all rows)
@@ -1754,8 +2087,18 @@ impl Executor {
 
         // Evaluate RETURNING expressions for each row
         let mut result_rows = Vec::with_capacity(source_rows.len());
-        for row in source_rows {
-            let row_data = row.as_slice();
+        let mut with_xmax: Vec<Value> = Vec::new();
+        for (i, row) in source_rows.iter().enumerate() {
+            let row_data = match updated {
+                Some(updated) => {
+                    let xmax = updated.get(i).copied().unwrap_or(false);
+                    with_xmax.clear();
+                    with_xmax.extend_from_slice(row.as_slice());
+                    with_xmax.push(Value::Integer(xmax as i64));
+                    with_xmax.as_slice()
+                }
+                None => row.as_slice(),
+            };
             // CRITICAL: Include params from context for parameterized queries
             let exec_ctx = ExecuteContext::new(row_data)
                 .with_params(ctx.params())
@@ -0,0 +1,270 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Schema-only dump
+//!
+//! Renders the catalog as replayable DDL statements without any table data:
+//! - CREATE SCHEMA for user schemas
+//! - CREATE TABLE with column constraints and foreign keys
+//! - CREATE INDEX for every index (including the ones backing UNIQUE)
+//! - CREATE VIEW
+//! - CREATE FUNCTION / PROCEDURE / TRIGGER for stored routines
+
+use rustc_hash::FxHashSet;
+
+use crate::core::{IndexType, Result, Schema, Value};
+use crate::storage::functions::{StoredParameter, SYS_FUNCTIONS};
+use crate::storage::procedures::{StoredProcedureParameter, SYS_PROCEDURES};
+use crate::storage::triggers::SYS_TRIGGERS;
+
+use super::ddl::unqualified_public;
+use super::show::create_table_sql;
+use super::Executor;
+
+/// Schemas that are managed by the engine and never dumped
+const INTERNAL_SCHEMAS: [&str; 3] = ["public", "system", "information_schema"];
+
+impl Executor {
+    /// Dump the database schema as a list of DDL statements
+    ///
+    /// Replaying the statements in order on an empty database recreates the
+    /// same tables, indexes, views and routines. Tables are ordered so that
+    /// referenced tables are created before the tables referencing them.
+    /// System tables and table data are not included.
+    pub fn dump_schema(&self) -> Result<Vec<String>> {
+        let mut statements = Vec::new();
+
+        // User schemas
+        let mut schema_names: Vec<String> = self
+            .engine
+            .list_schemas()?
+            .into_iter()
+            .filter(|name| !INTERNAL_SCHEMAS.contains(&name.as_str()))
+            .collect();
+        schema_names.sort();
+        for name in schema_names {
+            statements.push(format!("CREATE SCHEMA {}", name));
+        }
+
+        // Tables and their indexes
+        let tx = self.engine.begin_transaction()?;
+        let mut tables: Vec<(String, Schema)> = Vec::new();
+        for table_name in tx.list_tables()? {
+            if Schema::is_reserved_namespace(&table_name) {
+                continue;
+            }
+            let schema = tx.get_table(&table_name)?.schema().clone();
+            tables.push((table_name, schema));
+        }
+        tables.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (table_name, schema) in order_by_foreign_keys(tables) {
+            // UNIQUE columns come back through their CREATE UNIQUE INDEX
+            statements.push(create_table_sql(
+                &table_name,
+                &schema,
+                &FxHashSet::default(),
+            ));
+
+            let table = tx.get_table(&table_name)?;
+            let mut index_names: Vec<String> = self
+                .engine
+                .list_table_indexes(&table_name)?
+                .into_keys()
+                .collect();
+            index_names.sort();
+            for index_name in index_names {
+                if let Some(index) = table.get_index(&index_name) {
+                    let mut sql = String::from("CREATE ");
+                    if index.is_unique() {
+                        sql.push_str("UNIQUE ");
+                    }
+                    sql.push_str("INDEX ");
+                    // CREATE TABLE already made the PRIMARY KEY index, but a
+                    // table restored from snapshot files still needs it
+                    if schema.is_primary_key_index(&index_name) {
+                        sql.push_str("IF NOT EXISTS ");
+                    }
+                    sql.push_str(&format!(
+                        "{} ON {} ({})",
+                        index_name,
+                        table_name,
+                        index.column_names().join(", ")
+                    ));
+                    if index.nulls_not_distinct() {
+                        sql.push_str(" NULLS NOT DISTINCT");
+                    }
+                    // Multi-column indexes pick their own type
+                    if index.index_type() != IndexType::MultiColumn {
+                        sql.push_str(&format!(
+                            " USING {}",
+                            index.index_type().as_str().to_uppercase()
+                        ));
+                    }
+                    statements.push(sql);
+                }
+            }
+        }
+
+        // Views
+        let mut views = self.engine.list_views()?;
+        views.sort();
+        for (schema_name, view_name) in views {
+            if let Some(view_def) = self.engine.get_view(&schema_name, &view_name)? {
+                let qualified = if schema_name == "public" {
+                    view_def.original_name.clone()
+                } else {
+                    format!("{}.{}", schema_name, view_def.original_name)
+                };
+                statements.push(format!("CREATE VIEW {} AS {}", qualified, view_def.query));
+            }
+        }
+
+        // Stored routines live in system tables
+        let existing = tx.list_tables()?;
+        let has_table = |name: &str| existing.iter().any(|t| t.eq_ignore_ascii_case(name));
+
+        if has_table(SYS_FUNCTIONS) {
+            let mut scanner = tx.get_table(SYS_FUNCTIONS)?.scan(&[], None)?;
+            while scanner.next() {
+                let row = scanner.row();
+                // Schema: id(0), schema(1), name(2), parameters(3), return_type(4), language(5), code(6)
+                if let (
+                    Some(Value::Text(name)),
+                    Some(Value::Text(params_json)),
+                    Some(Value::Text(return_type)),
+                    Some(Value::Text(language)),
+                    Some(Value::Text(code)),
+                ) = (row.get(2), row.get(3), row.get(4), row.get(5), row.get(6))
+                {
+                    let params: Vec<StoredParameter> =
+                        serde_json::from_str(params_json).unwrap_or_default();
+                    let params: Vec<String> = params
+                        .iter()
+                        .map(|p| format!("{} {}", p.name, p.data_type))
+                        .collect();
+                    statements.push(format!(
+                        "CREATE FUNCTION {}({}) RETURNS {} LANGUAGE {} AS '{}'",
+                        name,
+                        params.join(", "),
+                        return_type,
+                        language,
+                        code.replace('\'', "''")
+                    ));
+                }
+            }
+        }
+
+        if has_table(SYS_PROCEDURES) {
+            let mut scanner = tx.get_table(SYS_PROCEDURES)?.scan(&[], None)?;
+            while scanner.next() {
+                let row = scanner.row();
+                // Schema: id(0), schema(1), name(2), parameters(3), language(4), code(5)
+                if let (
+                    Some(Value::Text(name)),
+                    Some(Value::Text(params_json)),
+                    Some(Value::Text(language)),
+                    Some(Value::Text(code)),
+                ) = (row.get(2), row.get(3), row.get(4), row.get(5))
+                {
+                    let params: Vec<StoredProcedureParameter> =
+                        serde_json::from_str(params_json).unwrap_or_default();
+                    let params: Vec<String> = params
+                        .iter()
+                        .map(|p| format!("{} {} {}", p.mode, p.name, p.data_type))
+                        .collect();
+                    statements.push(format!(
+                        "CREATE PROCEDURE {}({}) LANGUAGE {} AS '{}'",
+                        name,
+                        params.join(", "),
+                        language,
+                        code.replace('\'', "''")
+                    ));
+                }
+            }
+        }
+
+        if has_table(SYS_TRIGGERS) {
+            let mut scanner = tx.get_table(SYS_TRIGGERS)?.scan(&[], None)?;
+            while scanner.next() {
+                let row = scanner.row();
+                // Schema: id(0), schema(1), name(2), table_name(3), timing(4), event(5),
+                //         for_each_row(6), language(7), code(8)
+                if let (
+                    Some(Value::Text(name)),
+                    Some(Value::Text(table_name)),
+                    Some(Value::Text(timing)),
+                    Some(Value::Text(event)),
+                    Some(Value::Boolean(for_each_row)),
+                    Some(Value::Text(language)),
+                    Some(Value::Text(code)),
+                ) = (
+                    row.get(2),
+                    row.get(3),
+                    row.get(4),
+                    row.get(5),
+                    row.get(6),
+                    row.get(7),
+                    row.get(8),
+                ) {
+                    statements.push(format!(
+                        "CREATE TRIGGER {} {} {} ON {}{} LANGUAGE {} AS '{}'",
+                        name,
+                        timing,
+                        event,
+                        table_name,
+                        if *for_each_row { " FOR EACH ROW" } else { "" },
+                        language,
+                        code.replace('\'', "''")
+                    ));
+                }
+            }
+        }
+
+        Ok(statements)
+    }
+}
+
+/// Order tables so that every referenced table precedes its referencing tables
+///
+/// Tables that take part in a reference cycle keep their relative order and
+/// are appended last.
+fn order_by_foreign_keys(mut pending: Vec<(String, Schema)>) -> Vec<(String, Schema)> {
+    let mut ordered = Vec::with_capacity(pending.len());
+    let mut emitted: FxHashSet<String> = FxHashSet::default();
+
+    loop {
+        let before = pending.len();
+        let mut remaining = Vec::with_capacity(pending.len());
+        for (name, schema) in pending {
+            let ready = schema.foreign_keys.iter().all(|fk| {
+                let referenced = unqualified_public(&fk.referenced_table).to_lowercase();
+                referenced == name.to_lowercase() || emitted.contains(&referenced)
+            });
+            if ready {
+                emitted.insert(name.to_lowercase());
+                ordered.push((name, schema));
+            } else {
+                remaining.push((name, schema));
+            }
+        }
+        pending = remaining;
+        if pending.is_empty() || pending.len() == before {
+            break;
+        }
+    }
+
+    ordered.extend(pending);
+    ordered
+}
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -22,9 +23,10 @@ use std::sync::Arc;
 use crate::core::{Result, Row, Value};
 use crate::optimizer::feedback::{fingerprint_predicate, global_feedback_cache};
 use crate::parser::ast::*;
-use crate::storage::traits::{Engine, QueryResult, ScanPlan};
+use crate::storage::traits::{QueryResult, ScanPlan};
 
 use super::context::ExecutionContext;
+use super::hints::HintPlan;
 use super::parallel;
 use super::pushdown;
 use super::result::ExecutorMemoryResult;
@@ -37,6 +39,10 @@ impl Executor {
         stmt: &ExplainStatement,
         ctx: &ExecutionContext,
     ) -> Result<Box<dyn QueryResult>> {
+        if let Statement::Call(call) = &*stmt.statement {
+            return self.explain_call(call, stmt.analyze, ctx);
+        }
+
         let mut plan_lines: Vec<String> = Vec::new();
 
         if stmt.analyze {
@@ -105,6 +111,7 @@ impl Executor {
                 0,
                 row_count,
                 &time_str,
+                ctx,
             );
 
             // Return the plan as a result
@@ -117,7 +124,7 @@ impl Executor {
             Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
         } else {
             // Regular EXPLAIN: Just show the plan without executing
-            self.explain_statement(&stmt.statement, &mut plan_lines, 0);
+            self.explain_statement(&stmt.statement, &mut plan_lines, 0, ctx);
 
             // Return as a single-column result
             let columns = vec!["plan".to_string()];
@@ -138,6 +145,7 @@ impl Executor {
         indent: usize,
         row_count: usize,
         time_str: &str,
+        ctx: &ExecutionContext,
     ) {
         let prefix = "  ".repeat(indent);
 
@@ -151,13 +159,39 @@ impl Executor {
 
                 // FROM clause with access plan
                 if let Some(ref table_expr) = select.table_expr {
-                    self.explain_table_expr_with_where_and_stats(
-                        table_expr,
-                        select.where_clause.as_deref(),
-                        lines,
-                        indent + 1,
-                        row_count,
-                    );
+                    let hints = self.resolve_hints(select);
+                    if let Some(ref hints) = hints {
+                        for note in hints.notes() {
+                            lines.push(format!("{}  Hint: {}", prefix, note));
+                        }
+                    }
+                    if let Some((plan, alias)) =
+                        self.explain_single_table_scan(select, hints.as_deref(), ctx)
+                    {
+                        for (i, line) in plan.to_string().lines().enumerate() {
+                            if i == 0 {
+                                lines.push(format!(
+                                    "{}  -> {} (actual rows={})",
+                                    prefix, line, row_count
+                                ));
+                            } else {
+                                lines.push(format!("{}     {}", prefix, line));
+                            }
+                        }
+                        if let Some(alias) = alias {
+                            lines.push(format!("{}     Alias: {}", prefix, alias));
+                        }
+                    } else {
+                        self.explain_table_expr_with_where_and_stats(
+                            table_expr,
+                            select.where_clause.as_deref(),
+                            hints.as_deref(),
+                            lines,
+                            indent + 1,
+                            row_count,
+                            ctx,
+                        );
+                    }
                 }
 
                 // GROUP BY
@@ -204,7 +238,7 @@ impl Executor {
                 ));
                 if let Some(ref select) = insert.select {
                     lines.push(format!("{}  Source:", prefix));
-                    self.explain_select(select, lines, indent + 2);
+                    self.explain_select(select, lines, indent + 2, ctx);
                 } else {
                     lines.push(format!(
                         "{}  Values: {} row(s)",
@@ -212,6 +246,9 @@ impl Executor {
                         insert.values.len()
                     ));
                 }
+                if let Some(maintenance) = self.plan_write_maintenance(stmt) {
+                    maintenance.push_lines(lines, indent + 1);
+                }
             }
             Statement::Update(update) => {
                 lines.push(format!(
@@ -226,6 +263,9 @@ impl Executor {
                 if let Some(ref where_clause) = update.where_clause {
                     lines.push(format!("{}  Filter: {}", prefix, where_clause));
                 }
+                if let Some(maintenance) = self.plan_write_maintenance(stmt) {
+                    maintenance.push_lines(lines, indent + 1);
+                }
             }
             Statement::Delete(delete) => {
                 lines.push(format!(
@@ -235,6 +275,9 @@ impl Executor {
                 if let Some(ref where_clause) = delete.where_clause {
                     lines.push(format!("{}  Filter: {}", prefix, where_clause));
                 }
+                if let Some(maintenance) = self.plan_write_maintenance(stmt) {
+                    maintenance.push_lines(lines, indent + 1);
+                }
             }
             _ => {
                 lines.push(format!(
@@ -265,13 +308,16 @@ impl Executor {
     }
 
     /// Generate EXPLAIN output for a table expression with WHERE clause analysis and stats
+    #[allow(clippy::too_many_arguments)]
     fn explain_table_expr_with_where_and_stats(
         &self,
         expr: &Expression,
         where_clause: Option<&Expression>,
+        hints: Option<&HintPlan>,
         lines: &mut Vec<String>,
         indent: usize,
         row_count: usize,
+        ctx: &ExecutionContext,
     ) {
         let prefix = "  ".repeat(indent);
 
@@ -279,11 +325,15 @@ impl Executor {
             Expression::TableSource(simple) => {
                 // Try to get the table and analyze access plan
                 if let Ok(tx) = self.engine.begin_transaction() {
-                    if let Ok(table) = tx.get_table(&simple.name.value()) {
+                    if let Ok(mut table) = tx.get_table(&simple.name.value()) {
+                        if let Some(hints) = hints {
+                            table.set_index_hint(hints.index_hint(simple));
+                        }
+
                         // Build storage expression from WHERE clause for analysis
                         let storage_expr = if let Some(where_expr) = where_clause {
                             let schema = table.schema();
-                            let (expr, _) = pushdown::try_pushdown(where_expr, schema, None);
+                            let (expr, _) = pushdown::try_pushdown(where_expr, schema, Some(ctx));
                             expr
                         } else {
                             None
@@ -364,7 +414,7 @@ impl Executor {
                     sub_info.push_str(&format!(" AS {}", alias));
                 }
                 lines.push(sub_info);
-                self.explain_select(&subquery.subquery, lines, indent + 1);
+                self.explain_select(&subquery.subquery, lines, indent + 1, ctx);
             }
             Expression::JoinSource(join) => {
                 // Determine join algorithm based on condition
@@ -379,6 +429,7 @@ impl Executor {
                 } else {
                     "Hash Join" // USING clause implies equality
                 };
+                let join_algorithm = hinted_join_algorithm(join, join_algorithm, hints);
 
                 lines.push(format!(
                     "{}-> {} ({} Join) (actual rows={})",
@@ -393,9 +444,23 @@ impl Executor {
                     lines.push(format!("{}   Using: ({})", prefix, cols.join(", ")));
                 }
                 // Left side gets the WHERE clause for potential pushdown
-                self.explain_table_expr_with_where(&join.left, where_clause, lines, indent + 1);
+                self.explain_table_expr_with_where(
+                    &join.left,
+                    where_clause,
+                    hints,
+                    lines,
+                    indent + 1,
+                    ctx,
+                );
                 // Right side typically doesn't get the outer WHERE
-                self.explain_table_expr_with_where(&join.right, None, lines, indent + 1);
+                self.explain_table_expr_with_where(
+                    &join.right,
+                    None,
+                    hints,
+                    lines,
+                    indent + 1,
+                    ctx,
+                );
             }
             Expression::CteReference(cte_ref) => {
                 let mut cte_info = format!(
@@ -417,18 +482,30 @@ impl Executor {
     }
 
     /// Generate EXPLAIN output for a statement
-    fn explain_statement(&self, stmt: &Statement, lines: &mut Vec<String>, indent: usize) {
+    fn explain_statement(
+        &self,
+        stmt: &Statement,
+        lines: &mut Vec<String>,
+        indent: usize,
+        ctx: &ExecutionContext,
+    ) {
         let prefix = "  ".repeat(indent);
 
         match stmt {
             Statement::Select(select) => {
-                self.explain_select(select, lines, indent);
+                self.explain_select(select, lines, indent, ctx);
             }
             Statement::Insert(insert) => {
-                lines.push(format!("{}INSERT INTO {}", prefix, insert.table_name));
+                let maintenance = self.plan_write_maintenance(stmt);
+                lines.push(format!(
+                    "{}INSERT INTO {}{}",
+                    prefix,
+                    insert.table_name,
+                    WriteMaintenance::summary(maintenance.as_ref())
+                ));
                 if let Some(ref select) = insert.select {
                     lines.push(format!("{}  Source:", prefix));
-                    self.explain_select(select, lines, indent + 2);
+                    self.explain_select(select, lines, indent + 2, ctx);
                 } else {
                     lines.push(format!(
                         "{}  Values: {} row(s)",
@@ -436,9 +513,18 @@ impl Executor {
                         insert.values.len()
                     ));
                 }
+                if let Some(maintenance) = maintenance {
+                    maintenance.push_lines(lines, indent + 1);
+                }
             }
             Statement::Update(update) => {
-                lines.push(format!("{}UPDATE {}", prefix, update.table_name));
+                let maintenance = self.plan_write_maintenance(stmt);
+                lines.push(format!(
+                    "{}UPDATE {}{}",
+                    prefix,
+                    update.table_name,
+                    WriteMaintenance::summary(maintenance.as_ref())
+                ));
                 lines.push(format!(
                     "{}  Set: {} column(s)",
                     prefix,
@@ -447,12 +533,24 @@ impl Executor {
                 if let Some(ref where_clause) = update.where_clause {
                     lines.push(format!("{}  Filter: {}", prefix, where_clause));
                 }
+                if let Some(maintenance) = maintenance {
+                    maintenance.push_lines(lines, indent + 1);
+                }
             }
             Statement::Delete(delete) => {
-                lines.push(format!("{}DELETE FROM {}", prefix, delete.table_name));
+                let maintenance = self.plan_write_maintenance(stmt);
+                lines.push(format!(
+                    "{}DELETE FROM {}{}",
+                    prefix,
+                    delete.table_name,
+                    WriteMaintenance::summary(maintenance.as_ref())
+                ));
                 if let Some(ref where_clause) = delete.where_clause {
                     lines.push(format!("{}  Filter: {}", prefix, where_clause));
                 }
+                if let Some(maintenance) = maintenance {
+                    maintenance.push_lines(lines, indent + 1);
+                }
             }
             _ => {
                 lines.push(format!("{}Statement: {}", prefix, stmt));
@@ -461,7 +559,13 @@ impl Executor {
     }
 
     /// Generate EXPLAIN output for a SELECT statement
-    fn explain_select(&self, select: &SelectStatement, lines: &mut Vec<String>, indent: usize) {
+    fn explain_select(
+        &self,
+        select: &SelectStatement,
+        lines: &mut Vec<String>,
+        indent: usize,
+        ctx: &ExecutionContext,
+    ) {
         let prefix = "  ".repeat(indent);
 
         // CTE info
@@ -499,12 +603,35 @@ impl Executor {
 
         // FROM clause with access plan
         if let Some(ref table_expr) = select.table_expr {
-            self.explain_table_expr_with_where(
-                table_expr,
-                select.where_clause.as_deref(),
-                lines,
-                indent + 1,
-            );
+            let hints = self.resolve_hints(select);
+            if let Some(ref hints) = hints {
+                for note in hints.notes() {
+                    lines.push(format!("{}  Hint: {}", prefix, note));
+                }
+            }
+            if let Some((plan, alias)) =
+                self.explain_single_table_scan(select, hints.as_deref(), ctx)
+            {
+                for (i, line) in plan.to_string().lines().enumerate() {
+                    if i == 0 {
+                        lines.push(format!("{}  -> {}", prefix, line));
+                    } else {
+                        lines.push(format!("{}     {}", prefix, line));
+                    }
+                }
+                if let Some(alias) = alias {
+                    lines.push(format!("{}     Alias: {}", prefix, alias));
+                }
+            } else {
+                self.explain_table_expr_with_where(
+                    table_expr,
+                    select.where_clause.as_deref(),
+                    hints.as_deref(),
+                    lines,
+                    indent + 1,
+                    ctx,
+                );
+            }
         }
 
         // GROUP BY
@@ -541,18 +668,82 @@ impl Executor {
         if !select.set_operations.is_empty() {
             for set_op in &select.set_operations {
                 lines.push(format!("{}  {}", prefix, set_op.operation));
-                self.explain_select(&set_op.right, lines, indent + 2);
+                self.explain_select(&set_op.right, lines, indent + 2, ctx);
             }
         }
     }
 
+    /// Plan for a single-table SELECT served entirely by one index (an ordered
+    /// index scan or an index-only scan), with the table alias
+    fn explain_single_table_scan(
+        &self,
+        select: &SelectStatement,
+        hints: Option<&HintPlan>,
+        ctx: &ExecutionContext,
+    ) -> Option<(ScanPlan, Option<Identifier>)> {
+        let Some(Expression::TableSource(simple)) = select.table_expr.as_deref() else {
+            return None;
+        };
+        let tx = self.engine.begin_transaction().ok()?;
+        let mut table = tx.get_table(&simple.name.value()).ok()?;
+        if let Some(hints) = hints {
+            table.set_index_hint(hints.index_hint(simple));
+        }
+
+        if let Some(scan) = self.plan_ordered_index_scan(select, &*table) {
+            let order = scan
+                .columns
+                .iter()
+                .map(|column| {
+                    if scan.ascending {
+                        column.clone()
+                    } else {
+                        format!("{} DESC", column)
+                    }
+                })
+                .collect();
+            let plan = ScanPlan::OrderedIndexScan {
+                table: table.name().to_string(),
+                index_name: scan.index_name,
+                order,
+            };
+            return Some((plan, simple.alias.clone()));
+        }
+
+        let where_expr = select.where_clause.as_deref()?;
+        let all_columns = table.schema().column_names_owned().to_vec();
+        let scan = self.plan_index_only_scan(select, where_expr, &all_columns)?;
+        let (storage_expr, needs_memory_filter) =
+            pushdown::try_pushdown(where_expr, table.schema(), Some(ctx));
+        if needs_memory_filter {
+            return None;
+        }
+        let lookup = table.explain_scan(Some(storage_expr?.as_ref()));
+        if !matches!(
+            lookup,
+            ScanPlan::IndexScan { .. }
+                | ScanPlan::MultiIndexScan { .. }
+                | ScanPlan::CompositeIndexScan { .. }
+        ) {
+            return None;
+        }
+        let plan = ScanPlan::IndexOnlyScan {
+            table: table.name().to_string(),
+            index_name: table.covering_index_for(&scan.columns)?,
+            lookup: Box::new(lookup),
+        };
+        Some((plan, simple.alias.clone()))
+    }
+
     /// Generate EXPLAIN output for a table expression with WHERE clause analysis
     fn explain_table_expr_with_where(
         &self,
         expr: &Expression,
         where_clause: Option<&Expression>,
+        hints: Option<&HintPlan>,
         lines: &mut Vec<String>,
         indent: usize,
+        ctx: &ExecutionContext,
     ) {
         let prefix = "  ".repeat(indent);
 
@@ -560,11 +751,15 @@ impl Executor {
             Expression::TableSource(simple) => {
                 // Try to get the table and analyze access plan
                 if let Ok(tx) = self.engine.begin_transaction() {
-                    if let Ok(table) = tx.get_table(&simple.name.value()) {
+                    if let Ok(mut table) = tx.get_table(&simple.name.value()) {
+                        if let Some(hints) = hints {
+                            table.set_index_hint(hints.index_hint(simple));
+                        }
+
                         // Build storage expression from WHERE clause for analysis
                         let storage_expr = if let Some(where_expr) = where_clause {
                             let schema = table.schema();
-                            let (expr, _) = pushdown::try_pushdown(where_expr, schema, None);
+                            let (expr, _) = pushdown::try_pushdown(where_expr, schema, Some(ctx));
                             expr
                         } else {
                             None
@@ -619,7 +814,7 @@ impl Executor {
                     sub_info.push_str(&format!(" AS {}", alias));
                 }
                 lines.push(sub_info);
-                self.explain_select(&subquery.subquery, lines, indent + 1);
+                self.explain_select(&subquery.subquery, lines, indent + 1, ctx);
             }
             Expression::JoinSource(join) => {
                 // Determine join algorithm based on condition
@@ -638,6 +833,7 @@ impl Executor {
                     // USING clause implies equality join
                     "Hash Join"
                 };
+                let join_algorithm = hinted_join_algorithm(join, join_algorithm, hints);
 
                 // Get cost estimate from query planner
                 let planner = self.get_query_planner();
@@ -704,9 +900,23 @@ impl Executor {
                     lines.push(format!("{}   Using: ({})", prefix, cols.join(", ")));
                 }
                 // Left side gets the WHERE clause for potential pushdown
-                self.explain_table_expr_with_where(&join.left, where_clause, lines, indent + 1);
+                self.explain_table_expr_with_where(
+                    &join.left,
+                    where_clause,
+                    hints,
+                    lines,
+                    indent + 1,
+                    ctx,
+                );
                 // Right side typically doesn't get the outer WHERE
-                self.explain_table_expr_with_where(&join.right, None, lines, indent + 1);
+                self.explain_table_expr_with_where(
+                    &join.right,
+                    None,
+                    hints,
+                    lines,
+                    indent + 1,
+                    ctx,
+                );
             }
             Expression::CteReference(cte_ref) => {
                 let mut cte_info = format!("{}-> CTE Scan on {}", prefix, cte_ref.name);
@@ -726,6 +936,22 @@ impl Executor {
 // Helper Functions
 // ============================================================================
 
+/// Join algorithm label after applying a join method hint, if it can be honored
+fn hinted_join_algorithm(
+    join: &JoinTableSource,
+    default: &'static str,
+    hints: Option<&HintPlan>,
+) -> &'static str {
+    let has_equality = !join.using_columns.is_empty()
+        || join.condition.as_deref().is_some_and(is_equality_condition);
+    match hints.and_then(|h| h.join_method(&join.left, &join.right)) {
+        Some(JoinMethodHint::NestedLoop) => "Nested Loop",
+        Some(JoinMethodHint::Hash) if has_equality => "Hash Join",
+        Some(JoinMethodHint::Merge) if has_equality => "Merge Join",
+        _ => default,
+    }
+}
+
 /// Check if an expression is an equality condition (for EXPLAIN join algorithm display)
 pub(crate) fn is_equality_condition(expr: &Expression) -> bool {
     match expr {
@@ -753,6 +979,240 @@ pub(crate) fn is_equality_condition(expr: &Expression) -> bool {
     }
 }
 
+/// Fraction of rows assumed to match a WHERE clause that is not a key lookup
+const DEFAULT_WRITE_SELECTIVITY: f64 = 0.1;
+
+/// Constraint checks and index updates performed by a write, with
+/// estimated costs
+struct WriteMaintenance {
+    rows: u64,
+    constraints: Vec<(String, f64)>,
+    indexes: Vec<(String, f64)>,
+}
+
+impl WriteMaintenance {
+    fn cost(&self) -> f64 {
+        self.constraints
+            .iter()
+            .chain(&self.indexes)
+            .map(|(_, cost)| cost)
+            .sum()
+    }
+
+    /// Cost and row estimate for the statement header line
+    fn summary(maintenance: Option<&Self>) -> String {
+        match maintenance {
+            Some(m) => format!(" (cost={:.2} rows={})", m.cost(), m.rows),
+            None => String::new(),
+        }
+    }
+
+    fn push_lines(&self, lines: &mut Vec<String>, indent: usize) {
+        let prefix = "  ".repeat(indent);
+        for (title, items) in [
+            ("Constraint Checks", &self.constraints),
+            ("Index Maintenance", &self.indexes),
+        ] {
+            if items.is_empty() {
+                continue;
+            }
+            lines.push(format!("{}{}:", prefix, title));
+            for (item, cost) in items {
+                lines.push(format!("{}  -> {} (cost={:.2})", prefix, item, cost));
+            }
+        }
+    }
+}
+
+impl Executor {
+    /// Work out which constraints and indexes an INSERT, UPDATE or DELETE
+    /// maintains, using the same table metadata as the write path
+    fn plan_write_maintenance(&self, stmt: &Statement) -> Option<WriteMaintenance> {
+        let (table_name, where_clause, set_columns) = match stmt {
+            Statement::Insert(insert) => (insert.table_name.value(), None, None),
+            Statement::Update(update) => (
+                update.table_name.value(),
+                update.where_clause.as_deref(),
+                Some(
+                    update
+                        .updates
+                        .keys()
+                        .map(|c| c.to_lowercase())
+                        .collect::<Vec<_>>(),
+                ),
+            ),
+            Statement::Delete(delete) => (
+                delete.table_name.value(),
+                delete.where_clause.as_deref(),
+                None,
+            ),
+            _ => return None,
+        };
+        let is_delete = matches!(stmt, Statement::Delete(_));
+        // For UPDATE, only columns being assigned can change
+        let touches = |column: &str| {
+            set_columns
+                .as_ref()
+                .is_none_or(|cols| cols.iter().any(|c| c.eq_ignore_ascii_case(column)))
+        };
+
+        let tx = self.engine.begin_transaction().ok()?;
+        let table = tx.get_table(&table_name).ok()?;
+        let schema = table.schema();
+        let planner = self.get_query_planner();
+        let estimator = planner.cost_estimator();
+        let table_rows = planner.get_table_stats_with_fallback(&*table).row_count;
+
+        let rows = match stmt {
+            Statement::Insert(insert) => match insert.select {
+                Some(ref select) => select
+                    .table_expr
+                    .as_deref()
+                    .and_then(extract_table_name)
+                    .and_then(|name| planner.get_table_stats(&name))
+                    .map(|stats| stats.row_count)
+                    .unwrap_or(1),
+                None => insert.values.len() as u64,
+            },
+            _ => match where_clause {
+                None => table_rows,
+                Some(where_expr) => {
+                    let (expr, _) = pushdown::try_pushdown(where_expr, schema, None);
+                    match table.explain_scan(expr.as_deref()) {
+                        ScanPlan::PkLookup { .. } => 1,
+                        _ => ((table_rows as f64 * DEFAULT_WRITE_SELECTIVITY).ceil() as u64)
+                            .min(table_rows),
+                    }
+                }
+            },
+        };
+
+        let mut constraints = Vec::new();
+        if !is_delete {
+            let pk: Vec<&str> = schema
+                .primary_key_columns()
+                .iter()
+                .map(|c| c.name.as_str())
+                .collect();
+            if !pk.is_empty() && pk.iter().any(|c| touches(c)) {
+                constraints.push((
+                    format!("Primary Key ({})", pk.join(", ")),
+                    estimator.estimate_constraint_check(rows, true),
+                ));
+            }
+            for col in &schema.columns {
+                if !col.nullable && !col.primary_key && touches(&col.name) {
+                    constraints.push((
+                        format!("Not Null ({})", col.name),
+                        estimator.estimate_constraint_check(rows, false),
+                    ));
+                }
+            }
+            for col in &schema.columns {
+                if !touches(&col.name) {
+                    continue;
+                }
+                for check in &col.checks {
+                    let check = &check.expr;
+                    constraints.push((
+                        if check.starts_with('(') {
+                            format!("Check {}", check)
+                        } else {
+                            format!("Check ({})", check)
+                        },
+                        estimator.estimate_constraint_check(rows, false),
+                    ));
+                }
+            }
+            for fk in &schema.foreign_keys {
+                let Some(col) = schema.columns.get(fk.column_id) else {
+                    continue;
+                };
+                if touches(&col.name) {
+                    constraints.push((
+                        format!(
+                            "Foreign Key ({}) REFERENCES {}({})",
+                            col.name, fk.referenced_table, fk.referenced_column_name
+                        ),
+                        estimator.estimate_constraint_check(rows, true),
+                    ));
+                }
+            }
+        }
+        // Rows in other tables that reference the rows being changed
+        if !matches!(stmt, Statement::Insert(_)) {
+            for child_name in &schema.referenced_by {
+                let Ok(child) = tx.get_table(child_name) else {
+                    continue;
+                };
+                let child_schema = child.schema();
+                for fk in &child_schema.foreign_keys {
+                    let references_us =
+                        fk.referenced_table.eq_ignore_ascii_case(&schema.table_name)
+                            || fk.referenced_table.eq_ignore_ascii_case(&format!(
+                                "{}.{}",
+                                schema.schema_name, schema.table_name
+                            ));
+                    if !references_us || !touches(&fk.referenced_column_name) {
+                        continue;
+                    }
+                    let Some(col) = child_schema.columns.get(fk.column_id) else {
+                        continue;
+                    };
+                    let (event, action) = if is_delete {
+                        ("DELETE", &fk.on_delete)
+                    } else {
+                        ("UPDATE", &fk.on_update)
+                    };
+                    constraints.push((
+                        format!(
+                            "Referenced By {}({}) ON {} {}",
+                            child_name, col.name, event, action
+                        ),
+                        estimator.estimate_constraint_check(rows, true),
+                    ));
+                }
+            }
+        }
+
+        let mut index_names: Vec<String> = self
+            .engine
+            .list_table_indexes(&table_name)
+            .ok()?
+            .into_keys()
+            .collect();
+        index_names.sort();
+        let mut indexes = Vec::new();
+        for name in index_names {
+            let Some(index) = table.get_index(&name) else {
+                continue;
+            };
+            let columns = index.column_names();
+            if !columns.iter().any(|c| touches(c)) {
+                continue;
+            }
+            let index_type = index.index_type();
+            let is_unique = index.is_unique() && !is_delete;
+            indexes.push((
+                format!(
+                    "{} {}{} on ({})",
+                    name,
+                    index_type.as_str().to_uppercase(),
+                    if index.is_unique() { " UNIQUE" } else { "" },
+                    columns.join(", ")
+                ),
+                estimator.estimate_index_maintenance(index_type, is_unique, rows, table_rows),
+            ));
+        }
+
+        Some(WriteMaintenance {
+            rows,
+            constraints,
+            indexes,
+        })
+    }
+}
+
 /// Extract table name from a table expression (for statistics lookup)
 pub(crate) fn extract_table_name(expr: &Expression) -> Option<String> {
     match expr {
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -237,6 +238,15 @@ fn convert_between(between: &ast::BetweenExpression) -> Option<Box<dyn Expressio
     let column = extract_column_name(&between.expr)?;
     let low = extract_literal_value(&between.lower)?;
     let high = extract_literal_value(&between.upper)?;
+    // NULL bounds need three-valued logic the storage filter doesn't have
+    if low.is_null() || high.is_null() {
+        return None;
+    }
+    let (low, high) = if between.symmetric && low > high {
+        (high, low)
+    } else {
+        (low, high)
+    };
 
     let expr = if between.not {
         BetweenExpr::not_between(column, low, high)
@@ -258,6 +268,10 @@ fn convert_like(like: &ast::LikeExpression) -> Option<Box<dyn Expression>> {
 
     // Parse operator to determine case-sensitivity and negation
     let op_upper = like.operator.to_uppercase();
+    if !op_upper.ends_with("LIKE") || op_upper.contains("RLIKE") {
+        // GLOB, REGEXP, SIMILAR TO and ~ are not LIKE patterns
+        return None;
+    }
     let is_not = op_upper.contains("NOT");
     let is_ilike = op_upper.contains("ILIKE");
 
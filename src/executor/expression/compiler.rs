@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -487,11 +488,12 @@ impl<'a> ExprCompiler<'a> {
                 self.compile_expr(&between.expr, builder)?;
                 self.compile_expr(&between.lower, builder)?;
                 self.compile_expr(&between.upper, builder)?;
-                if between.not {
-                    builder.emit(Op::NotBetween);
-                } else {
-                    builder.emit(Op::Between);
-                }
+                builder.emit(match (between.not, between.symmetric) {
+                    (false, false) => Op::Between,
+                    (true, false) => Op::NotBetween,
+                    (false, true) => Op::BetweenSymmetric,
+                    (true, true) => Op::NotBetweenSymmetric,
+                });
             }
 
             // === LIKE EXPRESSION ===
@@ -551,8 +553,9 @@ impl<'a> ExprCompiler<'a> {
 
             // === INTERVAL ===
             Expression::IntervalLiteral(interval) => {
-                let s = format!("{} {}", interval.quantity, interval.unit);
-                builder.emit(Op::LoadConst(Value::Text(Arc::from(s.as_str()))));
+                let micros = crate::core::parse_interval(&interval.value)
+                    .map_err(|e| CompileError::InvalidExpression(e.to_string()))?;
+                builder.emit(Op::LoadConst(Value::Interval(micros)));
             }
 
             // === SUBQUERIES ===
@@ -1169,6 +1172,10 @@ impl<'a> ExprCompiler<'a> {
         let negated = op_upper.contains("NOT");
         let is_glob = op_upper.contains("GLOB");
         let is_regexp = op_upper.contains("REGEXP") || op_upper.contains("RLIKE");
+        let is_similar = op_upper.contains("SIMILAR");
+        // POSIX operators: ~ and ~* match, !~ and !~* negate, * ignores case
+        let posix = op_upper.contains('~');
+        let negated = negated || op_upper.starts_with('!');
 
         // Extract escape character if present
         let escape_char: Option<char> = if let Some(ref escape_expr) = like.escape {
@@ -1183,8 +1190,20 @@ impl<'a> ExprCompiler<'a> {
 
         // Try to compile pattern at compile time
         if let Expression::StringLiteral(lit) = &*like.pattern {
-            if is_regexp {
-                let regex = regex::Regex::new(&lit.value).map_err(|e| {
+            if is_regexp || posix || is_similar {
+                let pattern = if is_similar {
+                    // SIMILAR TO escapes with backslash unless ESCAPE says otherwise
+                    let escape = match like.escape {
+                        Some(_) => escape_char,
+                        None => Some('\\'),
+                    };
+                    similar_to_regex(&lit.value, escape)
+                } else if posix && op_upper.ends_with('*') {
+                    format!("(?i){}", lit.value)
+                } else {
+                    lit.value.clone()
+                };
+                let regex = regex::Regex::new(&pattern).map_err(|e| {
                     CompileError::InvalidExpression(format!("Invalid regex: {}", e))
                 })?;
                 builder.emit(Op::Regexp(Arc::new(regex)));
@@ -1347,12 +1366,18 @@ impl<'a> ExprCompiler<'a> {
                 return Ok(());
             }
 
-            "CURRENT_TRANSACTION_ID" => {
+            "CURRENT_TRANSACTION_ID" | "TXID_CURRENT" => {
                 // Context-dependent function - loads from ExecuteContext
                 builder.emit(Op::LoadTransactionId);
                 return Ok(());
             }
 
+            "TXID_STATUS" if func.arguments.len() == 1 => {
+                self.compile_expr(&func.arguments[0], builder)?;
+                builder.emit(Op::TxidStatus);
+                return Ok(());
+            }
+
             "COALESCE" => {
                 for arg in &func.arguments {
                     self.compile_expr(arg, builder)?;
@@ -1423,6 +1448,51 @@ fn try_eval_constant(expr: &Expression) -> Option<Value> {
     }
 }
 
+/// Translate a SQL-standard SIMILAR TO pattern into an anchored regex
+///
+/// `%` and `_` are the LIKE wildcards, `|`, `*`, `+`, `?`, `{m,n}`, `(...)`
+/// and `[...]` keep their regex meaning, and every other character, `.`
+/// included, matches itself. The whole string has to match.
+fn similar_to_regex(pattern: &str, escape: Option<char>) -> String {
+    let mut regex = String::with_capacity(pattern.len() + 12);
+    regex.push_str("(?s)^(?:");
+    let mut chars = pattern.chars();
+    let mut in_bracket = false;
+    while let Some(c) = chars.next() {
+        if Some(c) == escape {
+            match chars.next() {
+                Some(next) => regex.push_str(&regex::escape(&next.to_string())),
+                None => regex.push_str(&regex::escape(&c.to_string())),
+            }
+        } else if in_bracket {
+            match c {
+                ']' => {
+                    in_bracket = false;
+                    regex.push(c);
+                }
+                '[' | '\\' => {
+                    regex.push('\\');
+                    regex.push(c);
+                }
+                _ => regex.push(c),
+            }
+        } else {
+            match c {
+                '%' => regex.push_str(".*"),
+                '_' => regex.push('.'),
+                '[' => {
+                    in_bracket = true;
+                    regex.push(c);
+                }
+                '|' | '*' | '+' | '?' | '{' | '}' | '(' | ')' => regex.push(c),
+                _ => regex.push_str(&regex::escape(&c.to_string())),
+            }
+        }
+    }
+    regex.push_str(")$");
+    regex
+}
+
 // Note: string_to_datatype and expression_to_string are now imported from utils
 
 use chrono::Datelike;
@@ -1515,4 +1585,25 @@ mod tests {
         assert!(!program.is_empty());
         println!("{}", program.disassemble());
     }
+
+    #[test]
+    fn test_similar_to_regex() {
+        let matches = |pattern: &str, escape: Option<char>, s: &str| {
+            regex::Regex::new(&similar_to_regex(pattern, escape))
+                .unwrap()
+                .is_match(s)
+        };
+        assert!(matches("a%", Some('\\'), "abc"));
+        assert!(!matches("b%", Some('\\'), "abc"));
+        assert!(matches("(a|b)_c", Some('\\'), "bxc"));
+        assert!(matches("[0-9]+", Some('\\'), "2024"));
+        assert!(!matches("[0-9]+", Some('\\'), "20x24"));
+        // `.` and `^` are literal; the escape makes `%` literal
+        assert!(!matches("a.c", Some('\\'), "abc"));
+        assert!(matches("^a.c$", Some('\\'), "^a.c$"));
+        assert!(matches("100\\%", Some('\\'), "100%"));
+        assert!(!matches("100\\%", Some('\\'), "1000"));
+        assert!(matches("100#%", Some('#'), "100%"));
+        assert!(matches("a\\b", None, "a\\b"));
+    }
 }
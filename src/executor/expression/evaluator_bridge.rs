@@ -405,6 +405,8 @@ pub struct ExpressionEval {
     outer_row: Option<FxHashMap<Arc<str>, Value>>,
     /// Transaction ID
     transaction_id: Option<u64>,
+    /// Division by zero yields NULL instead of an error
+    div_by_zero_null: bool,
 }
 
 impl ExpressionEval {
@@ -418,6 +420,7 @@ impl ExpressionEval {
             named_params: FxHashMap::default(),
             outer_row: None,
             transaction_id: None,
+            div_by_zero_null: false,
         })
     }
 
@@ -489,6 +492,7 @@ impl ExpressionEval {
             named_params: FxHashMap::default(),
             outer_row: None,
             transaction_id: None,
+            div_by_zero_null: false,
         })
     }
 
@@ -501,6 +505,7 @@ impl ExpressionEval {
             named_params: FxHashMap::default(),
             outer_row: None,
             transaction_id: None,
+            div_by_zero_null: false,
         }
     }
 
@@ -532,6 +537,7 @@ impl ExpressionEval {
             self.outer_row = Some(arc_map);
         }
         self.transaction_id = ctx.transaction_id();
+        self.div_by_zero_null = ctx.div_by_zero_null();
         self
     }
 
@@ -570,7 +576,9 @@ impl ExpressionEval {
         if let Some(ref outer) = self.outer_row {
             ctx = ctx.with_outer_row(outer);
         }
-        ctx = ctx.with_transaction_id(self.transaction_id);
+        ctx = ctx
+            .with_transaction_id(self.transaction_id)
+            .with_div_by_zero_null(self.div_by_zero_null);
 
         self.vm.execute(&self.program, &ctx)
     }
@@ -590,7 +598,9 @@ impl ExpressionEval {
         if let Some(ref outer) = self.outer_row {
             ctx = ctx.with_outer_row(outer);
         }
-        ctx = ctx.with_transaction_id(self.transaction_id);
+        ctx = ctx
+            .with_transaction_id(self.transaction_id)
+            .with_div_by_zero_null(self.div_by_zero_null);
 
         self.vm.execute_bool(&self.program, &ctx)
     }
@@ -623,7 +633,9 @@ impl ExpressionEval {
         if let Some(ref outer) = self.outer_row {
             ctx = ctx.with_outer_row(outer);
         }
-        ctx = ctx.with_transaction_id(self.transaction_id);
+        ctx = ctx
+            .with_transaction_id(self.transaction_id)
+            .with_div_by_zero_null(self.div_by_zero_null);
 
         self.vm.execute(&self.program, &ctx)
     }
@@ -642,7 +654,9 @@ impl ExpressionEval {
         if let Some(ref outer) = self.outer_row {
             ctx = ctx.with_outer_row(outer);
         }
-        ctx = ctx.with_transaction_id(self.transaction_id);
+        ctx = ctx
+            .with_transaction_id(self.transaction_id)
+            .with_div_by_zero_null(self.div_by_zero_null);
 
         self.vm.execute_bool(&self.program, &ctx)
     }
@@ -671,6 +685,8 @@ pub struct MultiExpressionEval {
     named_params: FxHashMap<String, Value>,
     /// Transaction ID
     transaction_id: Option<u64>,
+    /// Division by zero yields NULL instead of an error
+    div_by_zero_null: bool,
 }
 
 impl MultiExpressionEval {
@@ -695,6 +711,7 @@ impl MultiExpressionEval {
             params: Vec::new(),
             named_params: FxHashMap::default(),
             transaction_id: None,
+            div_by_zero_null: false,
         })
     }
 
@@ -737,6 +754,7 @@ impl MultiExpressionEval {
             params: Vec::new(),
             named_params: FxHashMap::default(),
             transaction_id: None,
+            div_by_zero_null: false,
         })
     }
 
@@ -755,6 +773,7 @@ impl MultiExpressionEval {
             .map(|(k, v)| (k.clone(), v.clone()))
             .collect();
         self.transaction_id = ctx.transaction_id();
+        self.div_by_zero_null = ctx.div_by_zero_null();
         self
     }
 
@@ -770,7 +789,9 @@ impl MultiExpressionEval {
         if !self.named_params.is_empty() {
             ctx = ctx.with_named_params(&self.named_params);
         }
-        ctx = ctx.with_transaction_id(self.transaction_id);
+        ctx = ctx
+            .with_transaction_id(self.transaction_id)
+            .with_div_by_zero_null(self.div_by_zero_null);
 
         self.programs
             .iter()
@@ -790,7 +811,9 @@ impl MultiExpressionEval {
         if !self.named_params.is_empty() {
             ctx = ctx.with_named_params(&self.named_params);
         }
-        ctx = ctx.with_transaction_id(self.transaction_id);
+        ctx = ctx
+            .with_transaction_id(self.transaction_id)
+            .with_div_by_zero_null(self.div_by_zero_null);
 
         output.clear();
         for prog in &self.programs {
@@ -887,6 +910,9 @@ pub struct CompiledEvaluator<'a> {
     /// Current transaction ID
     transaction_id: Option<u64>,
 
+    /// Division by zero yields NULL instead of an error
+    div_by_zero_null: bool,
+
     /// Expression aliases for HAVING clause
     expression_aliases: FxHashMap<String, u16>,
 
@@ -922,6 +948,7 @@ impl<'a> CompiledEvaluator<'a> {
             named_params: FxHashMap::default(),
             outer_row: None,
             transaction_id: None,
+            div_by_zero_null: false,
             expression_aliases: FxHashMap::default(),
             column_aliases: FxHashMap::default(),
             vm: ExprVM::new(),
@@ -945,6 +972,7 @@ impl<'a> CompiledEvaluator<'a> {
         self.named_params.clear();
         self.outer_row = None;
         self.transaction_id = None;
+        self.div_by_zero_null = false;
         self.expression_aliases.clear();
         self.column_aliases.clear();
         self.local_cache.clear();
@@ -996,6 +1024,7 @@ impl<'a> CompiledEvaluator<'a> {
         }
 
         self.transaction_id = ctx.transaction_id();
+        self.div_by_zero_null = ctx.div_by_zero_null();
         self
     }
 
@@ -1452,7 +1481,9 @@ impl<'a> CompiledEvaluator<'a> {
         }
 
         // Add transaction ID
-        ctx = ctx.with_transaction_id(self.transaction_id);
+        ctx = ctx
+            .with_transaction_id(self.transaction_id)
+            .with_div_by_zero_null(self.div_by_zero_null);
 
         // Execute
         self.vm.execute(&program, &ctx)
@@ -1494,7 +1525,9 @@ impl<'a> CompiledEvaluator<'a> {
         }
 
         // Add transaction ID
-        ctx = ctx.with_transaction_id(self.transaction_id);
+        ctx = ctx
+            .with_transaction_id(self.transaction_id)
+            .with_div_by_zero_null(self.div_by_zero_null);
 
         // Execute and convert to bool
         Ok(self.vm.execute_bool(&program, &ctx))
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -536,6 +537,14 @@ pub enum Op {
     /// Stack: [value, low, high] -> [bool]
     NotBetween,
 
+    /// BETWEEN SYMMETRIC check: bounds in either order
+    /// Stack: [value, low, high] -> [bool]
+    BetweenSymmetric,
+
+    /// NOT BETWEEN SYMMETRIC check
+    /// Stack: [value, low, high] -> [bool]
+    NotBetweenSymmetric,
+
     /// Multi-column IN: (a, b) IN ((1, 2), (3, 4))
     /// Stack: [val1, val2, ...valN] -> [bool]
     /// The tuple_values contains pre-evaluated constant tuples
@@ -695,6 +704,9 @@ pub enum Op {
     NextVal,
     CurrVal,
     SetVal,
+    /// Status of the transaction whose ID is on the stack (TXID_STATUS)
+    /// Stack: [id] -> ['committed' | 'aborted' | 'in progress' | NULL]
+    TxidStatus,
 
     // =========================================================================
     // SPECIAL
@@ -837,6 +849,8 @@ impl std::fmt::Debug for Op {
             }
             Op::Between => write!(f, "Between"),
             Op::NotBetween => write!(f, "NotBetween"),
+            Op::BetweenSymmetric => write!(f, "BetweenSymmetric"),
+            Op::NotBetweenSymmetric => write!(f, "NotBetweenSymmetric"),
             Op::InTupleSet {
                 tuple_size,
                 values,
@@ -886,6 +900,7 @@ impl std::fmt::Debug for Op {
             Op::NextVal => write!(f, "NextVal"),
             Op::CurrVal => write!(f, "CurrVal"),
             Op::SetVal => write!(f, "SetVal"),
+            Op::TxidStatus => write!(f, "TxidStatus"),
             Op::Nop => write!(f, "Nop"),
             Op::Return => write!(f, "Return"),
             Op::ReturnTrue => write!(f, "ReturnTrue"),
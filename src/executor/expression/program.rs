@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -256,7 +257,10 @@ impl Program {
                 | Op::CaseCompare => -1,
 
                 // Pop 3, push 1 (-2)
-                Op::Between | Op::NotBetween => -2,
+                Op::Between
+                | Op::NotBetween
+                | Op::BetweenSymmetric
+                | Op::NotBetweenSymmetric => -2,
 
                 // JSON/Timestamp binary ops: Pop 2, push 1 (-1)
                 Op::JsonAccess
@@ -322,7 +326,7 @@ impl Program {
                 | Op::CaseThen(_)
                 | Op::CaseElse
                 | Op::CaseEnd => 0,
-                Op::NextVal | Op::CurrVal => 0,
+                Op::NextVal | Op::CurrVal | Op::TxidStatus => 0,
                 Op::SetVal => -2,
             };
 
@@ -75,6 +75,9 @@ pub struct ExecuteContext<'a> {
 
     /// Current transaction ID (for CURRENT_TRANSACTION_ID())
     pub transaction_id: Option<u64>,
+
+    /// Division and modulo by zero yield NULL instead of an error
+    pub div_by_zero_null: bool,
 }
 
 impl<'a> ExecuteContext<'a> {
@@ -88,6 +91,7 @@ impl<'a> ExecuteContext<'a> {
             named_params: None,
             subquery_executor: None,
             transaction_id: None,
+            div_by_zero_null: false,
         }
     }
 
@@ -101,6 +105,7 @@ impl<'a> ExecuteContext<'a> {
             named_params: None,
             subquery_executor: None,
             transaction_id: None,
+            div_by_zero_null: false,
         }
     }
 
@@ -110,6 +115,12 @@ impl<'a> ExecuteContext<'a> {
         self
     }
 
+    /// Make division and modulo by zero yield NULL instead of an error
+    pub fn with_div_by_zero_null(mut self, div_by_zero_null: bool) -> Self {
+        self.div_by_zero_null = div_by_zero_null;
+        self
+    }
+
     /// Add parameters
     pub fn with_params(mut self, params: &'a [Value]) -> Self {
         self.params = params;
@@ -329,7 +340,7 @@ impl ExprVM {
                                 crate::executor::context::cache_currval(seq_name, val);
                                 Value::Integer(val)
                             }
-                            Err(_) => Value::null_unknown(),
+                            Err(e) => return Err(e),
                         }
                     } else {
                         Value::null_unknown()
@@ -372,7 +383,7 @@ impl ExprVM {
                                     crate::executor::context::cache_currval(seq_name, val);
                                     Value::Integer(val)
                                 }
-                                Err(_) => Value::null_unknown(),
+                                Err(e) => return Err(e),
                             }
                         } else {
                             Value::null_unknown()
@@ -391,6 +402,20 @@ impl ExprVM {
                     pc += 1;
                 }
 
+                Op::TxidStatus => {
+                    let txn_id = self.stack.pop().unwrap().as_int64();
+                    let status = txn_id.and_then(|id| {
+                        crate::executor::context::get_current_engine()
+                            .and_then(|engine| engine.transaction_status(id))
+                    });
+                    let value = match status {
+                        Some(status) => Value::text(status.to_string()),
+                        None => Value::null_unknown(),
+                    };
+                    self.stack.push(value);
+                    pc += 1;
+                }
+
                 // =============================================================
                 // COMPARISON OPERATIONS
                 // =============================================================
@@ -400,7 +425,7 @@ impl ExprVM {
                     let result = if a.is_null() || b.is_null() {
                         Value::Null(DataType::Boolean)
                     } else {
-                        Value::Boolean(a == b)
+                        Value::Boolean(a.sql_equals(&b)?)
                     };
                     self.stack.push(result);
                     pc += 1;
@@ -412,7 +437,7 @@ impl ExprVM {
                     let result = if a.is_null() || b.is_null() {
                         Value::Null(DataType::Boolean)
                     } else {
-                        Value::Boolean(a != b)
+                        Value::Boolean(!a.sql_equals(&b)?)
                     };
                     self.stack.push(result);
                     pc += 1;
@@ -421,7 +446,7 @@ impl ExprVM {
                 Op::Lt => {
                     let b = self.stack.pop().unwrap_or_else(Value::null_unknown);
                     let a = self.stack.pop().unwrap_or_else(Value::null_unknown);
-                    let result = self.compare_values(&a, &b, std::cmp::Ordering::Less);
+                    let result = Self::compare_values(&a, &b, std::cmp::Ordering::Less)?;
                     self.stack.push(result);
                     pc += 1;
                 }
@@ -429,7 +454,7 @@ impl ExprVM {
                 Op::Le => {
                     let b = self.stack.pop().unwrap_or_else(Value::null_unknown);
                     let a = self.stack.pop().unwrap_or_else(Value::null_unknown);
-                    let result = match a.partial_cmp(&b) {
+                    let result = match Self::sql_ordering(&a, &b)? {
                         Some(std::cmp::Ordering::Less) | Some(std::cmp::Ordering::Equal) => {
                             Value::Boolean(true)
                         }
@@ -443,7 +468,7 @@ impl ExprVM {
                 Op::Gt => {
                     let b = self.stack.pop().unwrap_or_else(Value::null_unknown);
                     let a = self.stack.pop().unwrap_or_else(Value::null_unknown);
-                    let result = self.compare_values(&a, &b, std::cmp::Ordering::Greater);
+                    let result = Self::compare_values(&a, &b, std::cmp::Ordering::Greater)?;
                     self.stack.push(result);
                     pc += 1;
                 }
@@ -451,7 +476,7 @@ impl ExprVM {
                 Op::Ge => {
                     let b = self.stack.pop().unwrap_or_else(Value::null_unknown);
                     let a = self.stack.pop().unwrap_or_else(Value::null_unknown);
-                    let result = match a.partial_cmp(&b) {
+                    let result = match Self::sql_ordering(&a, &b)? {
                         Some(std::cmp::Ordering::Greater) | Some(std::cmp::Ordering::Equal) => {
                             Value::Boolean(true)
                         }
@@ -514,7 +539,7 @@ impl ExprVM {
                     let result = if col_val.is_null() || val.is_null() {
                         Value::Null(DataType::Boolean)
                     } else {
-                        Value::Boolean(col_val == val)
+                        Value::Boolean(col_val.sql_equals(val)?)
                     };
                     self.stack.push(result);
                     pc += 1;
@@ -528,7 +553,7 @@ impl ExprVM {
                     let result = if col_val.is_null() || val.is_null() {
                         Value::Null(DataType::Boolean)
                     } else {
-                        Value::Boolean(col_val != val)
+                        Value::Boolean(!col_val.sql_equals(val)?)
                     };
                     self.stack.push(result);
                     pc += 1;
@@ -539,7 +564,7 @@ impl ExprVM {
                         .row
                         .get(*idx as usize)
                         .unwrap_or(&Value::Null(DataType::Null));
-                    let result = self.compare_values(col_val, val, std::cmp::Ordering::Less);
+                    let result = Self::compare_values(col_val, val, std::cmp::Ordering::Less)?;
                     self.stack.push(result);
                     pc += 1;
                 }
@@ -549,7 +574,7 @@ impl ExprVM {
                         .row
                         .get(*idx as usize)
                         .unwrap_or(&Value::Null(DataType::Null));
-                    let result = match col_val.partial_cmp(val) {
+                    let result = match Self::sql_ordering(col_val, val)? {
                         Some(std::cmp::Ordering::Less) | Some(std::cmp::Ordering::Equal) => {
                             Value::Boolean(true)
                         }
@@ -565,7 +590,7 @@ impl ExprVM {
                         .row
                         .get(*idx as usize)
                         .unwrap_or(&Value::Null(DataType::Null));
-                    let result = self.compare_values(col_val, val, std::cmp::Ordering::Greater);
+                    let result = Self::compare_values(col_val, val, std::cmp::Ordering::Greater)?;
                     self.stack.push(result);
                     pc += 1;
                 }
@@ -575,7 +600,7 @@ impl ExprVM {
                         .row
                         .get(*idx as usize)
                         .unwrap_or(&Value::Null(DataType::Null));
-                    let result = match col_val.partial_cmp(val) {
+                    let result = match Self::sql_ordering(col_val, val)? {
                         Some(std::cmp::Ordering::Greater) | Some(std::cmp::Ordering::Equal) => {
                             Value::Boolean(true)
                         }
@@ -641,11 +666,7 @@ impl ExprVM {
                         .row
                         .get(*idx as usize)
                         .unwrap_or(&Value::Null(DataType::Null));
-                    let result = if col_val.is_null() || low.is_null() || high.is_null() {
-                        Value::Null(DataType::Boolean)
-                    } else {
-                        Value::Boolean(col_val >= low && col_val <= high)
-                    };
+                    let result = Self::tribool_value(Self::between(col_val, low, high));
                     self.stack.push(result);
                     pc += 1;
                 }
@@ -758,6 +779,20 @@ impl ExprVM {
                                 true,
                             )
                         }
+                        (Value::Timestamp(_), Value::Interval(_)) => {
+                            self.timestamp_add_interval(&a, &b, true)
+                        }
+                        (Value::Interval(_), Value::Timestamp(_)) => {
+                            self.timestamp_add_interval(&b, &a, true)
+                        }
+                        (Value::Interval(x), Value::Interval(y)) => match x.checked_add(*y) {
+                            Some(sum) => Value::Interval(sum),
+                            None => {
+                                return Err(crate::core::Error::Type(
+                                    "Interval overflow".to_string(),
+                                ))
+                            }
+                        },
                         _ => Self::arithmetic_op(&a, &b, ArithmeticOp::Add, |x, y| x + y)?,
                     };
                     self.stack.push(result);
@@ -787,6 +822,17 @@ impl ExprVM {
                                 false,
                             )
                         }
+                        (Value::Timestamp(_), Value::Interval(_)) => {
+                            self.timestamp_add_interval(&a, &b, false)
+                        }
+                        (Value::Interval(x), Value::Interval(y)) => match x.checked_sub(*y) {
+                            Some(diff) => Value::Interval(diff),
+                            None => {
+                                return Err(crate::core::Error::Type(
+                                    "Interval overflow".to_string(),
+                                ))
+                            }
+                        },
                         _ => Self::arithmetic_op(&a, &b, ArithmeticOp::Sub, |x, y| x - y)?,
                     };
                     self.stack.push(result);
@@ -804,7 +850,7 @@ impl ExprVM {
                 Op::Div => {
                     let b = self.stack.pop().unwrap_or_else(Value::null_unknown);
                     let a = self.stack.pop().unwrap_or_else(Value::null_unknown);
-                    let result = Self::div_op(&a, &b);
+                    let result = Self::div_op(&a, &b, ctx.div_by_zero_null)?;
                     self.stack.push(result);
                     pc += 1;
                 }
@@ -812,7 +858,7 @@ impl ExprVM {
                 Op::Mod => {
                     let b = self.stack.pop().unwrap_or_else(Value::null_unknown);
                     let a = self.stack.pop().unwrap_or_else(Value::null_unknown);
-                    let result = Self::mod_op(&a, &b);
+                    let result = Self::mod_op(&a, &b, ctx.div_by_zero_null)?;
                     self.stack.push(result);
                     pc += 1;
                 }
@@ -822,6 +868,7 @@ impl ExprVM {
                     let result = match v {
                         Value::Integer(i) => Value::Integer(-i),
                         Value::Float(f) => Value::Float(-f),
+                        Value::Interval(i) => Value::Interval(-i),
                         Value::Null(dt) => Value::Null(dt),
                         _ => Value::Null(DataType::Null),
                     };
@@ -1081,31 +1128,21 @@ impl ExprVM {
                     pc += 1;
                 }
 
-                Op::Between => {
+                op @ (Op::Between
+                | Op::NotBetween
+                | Op::BetweenSymmetric
+                | Op::NotBetweenSymmetric) => {
                     let high = self.stack.pop().unwrap_or_else(Value::null_unknown);
                     let low = self.stack.pop().unwrap_or_else(Value::null_unknown);
                     let val = self.stack.pop().unwrap_or_else(Value::null_unknown);
 
-                    let result = if val.is_null() || low.is_null() || high.is_null() {
-                        Value::Null(DataType::Boolean)
-                    } else {
-                        Value::Boolean(val >= low && val <= high)
+                    let result = match op {
+                        Op::Between => Self::between(&val, &low, &high),
+                        Op::NotBetween => Self::between(&val, &low, &high).map(|b| !b),
+                        Op::BetweenSymmetric => Self::between_symmetric(&val, &low, &high),
+                        _ => Self::between_symmetric(&val, &low, &high).map(|b| !b),
                     };
-                    self.stack.push(result);
-                    pc += 1;
-                }
-
-                Op::NotBetween => {
-                    let high = self.stack.pop().unwrap_or_else(Value::null_unknown);
-                    let low = self.stack.pop().unwrap_or_else(Value::null_unknown);
-                    let val = self.stack.pop().unwrap_or_else(Value::null_unknown);
-
-                    let result = if val.is_null() || low.is_null() || high.is_null() {
-                        Value::Null(DataType::Boolean)
-                    } else {
-                        Value::Boolean(val < low || val > high)
-                    };
-                    self.stack.push(result);
+                    self.stack.push(Self::tribool_value(result));
                     pc += 1;
                 }
 
@@ -1201,9 +1238,13 @@ impl ExprVM {
                     self.args_buffer.clear();
                     self.args_buffer.extend(self.stack.drain(start..));
 
-                    let result = func
-                        .evaluate(&self.args_buffer)
-                        .unwrap_or_else(|_| Value::null_unknown());
+                    // Other errors evaluate to NULL, but input that cannot be
+                    // converted (e.g. TO_TIMESTAMP('garbage')) is reported
+                    let result = match func.evaluate(&self.args_buffer) {
+                        Ok(value) => value,
+                        Err(e @ crate::core::Error::TypeConversion { .. }) => return Err(e),
+                        Err(_) => Value::null_unknown(),
+                    };
                     self.stack.push(result);
                     pc += 1;
                 }
@@ -1216,11 +1257,17 @@ impl ExprVM {
                     self.args_buffer.clear();
                     self.args_buffer.extend(self.stack.drain(start..));
 
-                    let result = self
-                        .args_buffer
-                        .drain(..)
-                        .find(|v| !v.is_null())
-                        .unwrap_or_else(Value::null_unknown);
+                    // All NULL: keep the type of the first typed NULL
+                    let mut result = Value::null_unknown();
+                    for v in self.args_buffer.drain(..) {
+                        if !v.is_null() {
+                            result = v;
+                            break;
+                        }
+                        if result.data_type() == DataType::Null {
+                            result = v;
+                        }
+                    }
                     self.stack.push(result);
                     pc += 1;
                 }
@@ -1228,7 +1275,11 @@ impl ExprVM {
                 Op::NullIf => {
                     let b = self.stack.pop().unwrap_or_else(Value::null_unknown);
                     let a = self.stack.pop().unwrap_or_else(Value::null_unknown);
-                    let result = if a == b { Value::null_unknown() } else { a };
+                    let result = if a == b {
+                        Value::Null(a.data_type())
+                    } else {
+                        a
+                    };
                     self.stack.push(result);
                     pc += 1;
                 }
@@ -1587,12 +1638,65 @@ impl ExprVM {
     }
 
     #[inline]
-    fn compare_values(&self, a: &Value, b: &Value, expected: std::cmp::Ordering) -> Value {
-        match a.partial_cmp(b) {
+    fn tribool_value(b: Option<bool>) -> Value {
+        match b {
+            Some(b) => Value::Boolean(b),
+            None => Value::Null(DataType::Boolean),
+        }
+    }
+
+    /// `val BETWEEN low AND high`, i.e. `val >= low AND val <= high` in
+    /// three-valued logic: a NULL bound only makes the result NULL when the
+    /// other bound does not already rule the value out
+    #[inline]
+    fn between(val: &Value, low: &Value, high: &Value) -> Option<bool> {
+        let cmp = |a: &Value, b: &Value| {
+            if a.is_null() || b.is_null() {
+                None
+            } else {
+                a.partial_cmp(b)
+            }
+        };
+        let above_low = cmp(val, low).map(|o| o != std::cmp::Ordering::Less);
+        let below_high = cmp(val, high).map(|o| o != std::cmp::Ordering::Greater);
+        match (above_low, below_high) {
+            (Some(false), _) | (_, Some(false)) => Some(false),
+            (Some(true), Some(true)) => Some(true),
+            _ => None,
+        }
+    }
+
+    /// `val BETWEEN SYMMETRIC low AND high`: BETWEEN with the bounds in
+    /// either order
+    #[inline]
+    fn between_symmetric(val: &Value, low: &Value, high: &Value) -> Option<bool> {
+        match (Self::between(val, low, high), Self::between(val, high, low)) {
+            (Some(true), _) | (_, Some(true)) => Some(true),
+            (Some(false), Some(false)) => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Ordering for a comparison operator, None when the result is NULL
+    ///
+    /// Like `partial_cmp`, but text that cannot be compared with a number is
+    /// reported instead of evaluating to NULL.
+    #[inline]
+    fn sql_ordering(a: &Value, b: &Value) -> Result<Option<std::cmp::Ordering>> {
+        match a.compare(b) {
+            Ok(ord) => Ok(Some(ord)),
+            Err(e @ crate::core::Error::TypeConversion { .. }) => Err(e),
+            Err(_) => Ok(None),
+        }
+    }
+
+    #[inline]
+    fn compare_values(a: &Value, b: &Value, expected: std::cmp::Ordering) -> Result<Value> {
+        Ok(match Self::sql_ordering(a, b)? {
             Some(ord) if ord == expected => Value::Boolean(true),
             Some(_) => Value::Boolean(false),
             None => Value::Null(DataType::Boolean),
-        }
+        })
     }
 
     #[inline]
@@ -1631,32 +1735,68 @@ impl ExprVM {
             (Value::Float(x), Value::Float(y)) => Ok(Value::Float(float_op(*x, *y))),
             (Value::Integer(x), Value::Float(y)) => Ok(Value::Float(float_op(*x as f64, *y))),
             (Value::Float(x), Value::Integer(y)) => Ok(Value::Float(float_op(*x, *y as f64))),
-            _ if a.is_null() || b.is_null() => Ok(Value::Null(DataType::Float)),
+            _ if a.is_null() || b.is_null() => Ok(Value::Null(Self::numeric_null_type(a, b))),
             _ => Ok(Value::Null(DataType::Null)),
         }
     }
 
+    /// Type of a NULL arithmetic result: INTEGER when both operands are
+    /// integers (or one is an untyped NULL), FLOAT otherwise
     #[inline]
-    fn div_op(a: &Value, b: &Value) -> Value {
-        match (a, b) {
-            (Value::Integer(x), Value::Integer(y)) if *y != 0 => Value::Integer(x / y),
+    fn numeric_null_type(a: &Value, b: &Value) -> DataType {
+        match (a.data_type(), b.data_type()) {
+            (DataType::Integer, DataType::Integer | DataType::Null)
+            | (DataType::Null, DataType::Integer) => DataType::Integer,
+            _ => DataType::Float,
+        }
+    }
+
+    /// Division by zero is an error, or NULL when `null_on_zero` is set
+    #[inline]
+    fn div_op(a: &Value, b: &Value, null_on_zero: bool) -> Result<Value> {
+        Ok(match (a, b) {
+            (Value::Integer(x), Value::Integer(y)) if *y != 0 => match x.checked_div(*y) {
+                Some(r) => Value::Integer(r),
+                None => {
+                    return Err(crate::core::Error::Type(format!(
+                        "Integer overflow in arithmetic operation: {} and {}",
+                        x, y
+                    )))
+                }
+            },
             (Value::Float(x), Value::Float(y)) if *y != 0.0 => Value::Float(x / y),
             (Value::Integer(x), Value::Float(y)) if *y != 0.0 => Value::Float(*x as f64 / y),
             (Value::Float(x), Value::Integer(y)) if *y != 0 => Value::Float(x / *y as f64),
-            _ if a.is_null() || b.is_null() => Value::Null(DataType::Float),
-            _ => Value::Null(DataType::Null), // Division by zero returns NULL
-        }
+            _ if a.is_null() || b.is_null() => Value::Null(Self::numeric_null_type(a, b)),
+            (Value::Integer(_) | Value::Float(_), Value::Integer(_) | Value::Float(_)) => {
+                return Self::division_by_zero(a, b, null_on_zero)
+            }
+            _ => Value::Null(DataType::Null),
+        })
     }
 
+    /// Modulo by zero is an error, or NULL when `null_on_zero` is set
     #[inline]
-    fn mod_op(a: &Value, b: &Value) -> Value {
-        match (a, b) {
-            (Value::Integer(x), Value::Integer(y)) if *y != 0 => Value::Integer(x % y),
+    fn mod_op(a: &Value, b: &Value, null_on_zero: bool) -> Result<Value> {
+        Ok(match (a, b) {
+            (Value::Integer(x), Value::Integer(y)) if *y != 0 => Value::Integer(x.wrapping_rem(*y)),
             (Value::Float(x), Value::Float(y)) if *y != 0.0 => Value::Float(x % y),
             (Value::Integer(x), Value::Float(y)) if *y != 0.0 => Value::Float(*x as f64 % y),
             (Value::Float(x), Value::Integer(y)) if *y != 0 => Value::Float(x % *y as f64),
-            _ if a.is_null() || b.is_null() => Value::Null(DataType::Float),
+            _ if a.is_null() || b.is_null() => Value::Null(Self::numeric_null_type(a, b)),
+            (Value::Integer(_) | Value::Float(_), Value::Integer(_) | Value::Float(_)) => {
+                return Self::division_by_zero(a, b, null_on_zero)
+            }
             _ => Value::Null(DataType::Null),
+        })
+    }
+
+    #[cold]
+    fn division_by_zero(a: &Value, b: &Value, null_on_zero: bool) -> Result<Value> {
+        if null_on_zero {
+            Ok(Value::Null(Self::numeric_null_type(a, b)))
+        } else {
+            Err(crate::core::Error::DivisionByZero)
         }
     }
 
@@ -1752,16 +1892,14 @@ impl ExprVM {
             _ => return Value::Null(DataType::Timestamp),
         };
 
-        let interval_str = match interval {
-            Value::Text(s) => s.as_ref(),
+        let duration = match interval {
+            Value::Interval(micros) => Some(chrono::Duration::microseconds(*micros)),
+            // Formats: "1 day", "2 hours", "30 minutes", "1 year", "1 month", etc.
+            Value::Text(s) => self.parse_interval(s),
             Value::Null(_) => return Value::Null(DataType::Timestamp),
             _ => return Value::Null(DataType::Timestamp),
         };
 
-        // Parse interval string
-        // Formats: "1 day", "2 hours", "30 minutes", "1 year", "1 month", etc.
-        let duration = self.parse_interval(interval_str);
-
         match duration {
             Some(d) => {
                 if add {
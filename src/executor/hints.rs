@@ -0,0 +1,228 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Optimizer hints
+//!
+//! Hints come from a `/*+ ... */` comment after SELECT (`INDEX`, `NO_INDEX`,
+//! `LEADING`, `HASH_JOIN`, `NL_JOIN`, `MERGE_JOIN`) or from `FORCE INDEX` /
+//! `IGNORE INDEX` after a table name. They are resolved once per query block
+//! against its FROM clause into a [`HintPlan`], which travels in the
+//! `ExecutionContext`. Hints that cannot be honored are ignored with a
+//! warning instead of failing the query.
+
+use std::sync::Arc;
+
+use rustc_hash::FxHashMap;
+
+use crate::parser::ast::{
+    Expression, JoinMethodHint, QueryHint, SelectStatement, SimpleTableSource,
+};
+use crate::storage::traits::IndexHint;
+
+use super::Executor;
+
+/// Hints of one query block, resolved against its FROM clause
+#[derive(Debug, Default)]
+pub(crate) struct HintPlan {
+    /// Index restrictions keyed by lowercase table alias or name
+    indexes: FxHashMap<String, IndexHint>,
+    /// Join methods with the tables they are limited to (empty = every join)
+    join_methods: Vec<(JoinMethodHint, Vec<String>)>,
+    /// LEADING table order (lowercase aliases or names)
+    leading: Vec<String>,
+    /// One line per hint: what was applied, or why it was ignored
+    notes: Vec<String>,
+}
+
+impl HintPlan {
+    /// Lines describing the hints, for EXPLAIN
+    pub(crate) fn notes(&self) -> &[String] {
+        &self.notes
+    }
+
+    /// Index restriction for a table of the query
+    pub(crate) fn index_hint(&self, ts: &SimpleTableSource) -> Option<IndexHint> {
+        table_keys(ts)
+            .iter()
+            .find_map(|key| self.indexes.get(key))
+            .cloned()
+    }
+
+    /// Join method requested for a join of `left` and `right`
+    pub(crate) fn join_method(
+        &self,
+        left: &Expression,
+        right: &Expression,
+    ) -> Option<JoinMethodHint> {
+        let mut joined = source_keys(left);
+        joined.extend(source_keys(right));
+        self.join_methods
+            .iter()
+            .rev()
+            .find(|(_, tables)| tables.is_empty() || tables.iter().any(|t| joined.contains(t)))
+            .map(|(method, _)| *method)
+    }
+
+    /// Which side LEADING wants to drive a join: Some(true) for the right
+    /// side, Some(false) for the left, None if it names neither
+    pub(crate) fn right_leads(&self, left: &Expression, right: &Expression) -> Option<bool> {
+        let first = self.leading.first()?;
+        if source_keys(left).contains(first) {
+            Some(false)
+        } else if source_keys(right).contains(first) {
+            Some(true)
+        } else {
+            None
+        }
+    }
+
+    fn ignore(&mut self, hint: impl std::fmt::Display, reason: impl std::fmt::Display) {
+        self.notes.push(format!("{} (ignored: {})", hint, reason));
+    }
+}
+
+/// Lowercase names a table can be referred to by in hints
+fn table_keys(ts: &SimpleTableSource) -> Vec<String> {
+    let mut keys = vec![ts.name.table().to_lowercase(), ts.name.value_lower()];
+    if let Some(ref alias) = ts.alias {
+        keys.insert(0, alias.value_lower.clone());
+    }
+    keys
+}
+
+/// Lowercase names of every table under a FROM item
+fn source_keys(expr: &Expression) -> Vec<String> {
+    let mut tables = Vec::new();
+    collect_tables(expr, &mut tables);
+    tables.iter().flat_map(|ts| table_keys(ts)).collect()
+}
+
+fn collect_tables<'a>(expr: &'a Expression, out: &mut Vec<&'a SimpleTableSource>) {
+    match expr {
+        Expression::TableSource(ts) => out.push(ts),
+        Expression::JoinSource(join) => {
+            collect_tables(&join.left, out);
+            collect_tables(&join.right, out);
+        }
+        _ => {}
+    }
+}
+
+impl Executor {
+    /// Resolve the hints of a query block, or None if it has none
+    ///
+    /// Ignored hints are logged as warnings.
+    pub(crate) fn resolve_hints(&self, stmt: &SelectStatement) -> Option<Arc<HintPlan>> {
+        let mut tables = Vec::new();
+        if let Some(ref table_expr) = stmt.table_expr {
+            collect_tables(table_expr, &mut tables);
+        }
+        let has_table_hints = tables.iter().any(|ts| ts.index_hint.is_some());
+        if stmt.hints.is_empty() && !has_table_hints {
+            return None;
+        }
+
+        let mut plan = HintPlan::default();
+        let find_table = |name: &str| {
+            let name = name.to_lowercase();
+            tables
+                .iter()
+                .find(|ts| table_keys(ts).contains(&name))
+                .copied()
+        };
+
+        // FORCE INDEX / IGNORE INDEX on the table itself
+        for ts in &tables {
+            if let Some(ref hint) = ts.index_hint {
+                let label = format!("{} on {}", hint, ts.name);
+                self.add_index_hint(&mut plan, label, ts, &hint.indexes, hint.ignore);
+            }
+        }
+
+        let has_join = matches!(stmt.table_expr.as_deref(), Some(Expression::JoinSource(_)));
+        for hint in &stmt.hints {
+            match hint {
+                QueryHint::Index { table, indexes } | QueryHint::NoIndex { table, indexes } => {
+                    let ignore = matches!(hint, QueryHint::NoIndex { .. });
+                    match find_table(table) {
+                        Some(ts) => self.add_index_hint(&mut plan, hint, ts, indexes, ignore),
+                        None => plan.ignore(hint, format!("no table {} in FROM", table)),
+                    }
+                }
+                QueryHint::JoinMethod {
+                    method,
+                    tables: names,
+                } => {
+                    if !has_join {
+                        plan.ignore(hint, "query has no join");
+                    } else if let Some(missing) = names.iter().find(|t| find_table(t).is_none()) {
+                        plan.ignore(hint, format!("no table {} in FROM", missing));
+                    } else {
+                        let names = names.iter().map(|t| t.to_lowercase()).collect();
+                        plan.join_methods.push((*method, names));
+                        plan.notes.push(hint.to_string());
+                    }
+                }
+                QueryHint::Leading(names) => {
+                    if !has_join {
+                        plan.ignore(hint, "query has no join");
+                    } else if let Some(missing) = names.iter().find(|t| find_table(t).is_none()) {
+                        plan.ignore(hint, format!("no table {} in FROM", missing));
+                    } else {
+                        plan.leading = names.iter().map(|t| t.to_lowercase()).collect();
+                        plan.notes.push(hint.to_string());
+                    }
+                }
+                QueryHint::Unknown(_) => plan.ignore(hint, "unknown hint"),
+            }
+        }
+
+        for note in plan.notes.iter().filter(|n| n.contains("(ignored: ")) {
+            self.warn(format!("optimizer hint {}", note));
+        }
+        Some(Arc::new(plan))
+    }
+
+    /// Record an index restriction after checking the indexes exist
+    fn add_index_hint(
+        &self,
+        plan: &mut HintPlan,
+        label: impl std::fmt::Display,
+        ts: &SimpleTableSource,
+        indexes: &[String],
+        ignore: bool,
+    ) {
+        let table_indexes = self
+            .engine
+            .list_table_indexes(&ts.name.value_lower())
+            .unwrap_or_default();
+        let exists = |name: &String| {
+            table_indexes
+                .keys()
+                .any(|existing| existing.eq_ignore_ascii_case(name))
+        };
+        if let Some(missing) = indexes.iter().find(|name| !exists(name)) {
+            plan.ignore(label, format!("no index {} on {}", missing, ts.name));
+            return;
+        }
+
+        let hint = if ignore {
+            IndexHint::Exclude(indexes.to_vec())
+        } else {
+            IndexHint::Only(indexes.to_vec())
+        };
+        plan.indexes.insert(table_keys(ts).remove(0), hint);
+        plan.notes.push(label.to_string());
+    }
+}
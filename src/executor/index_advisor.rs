@@ -0,0 +1,351 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Index suggestions from query history (SHOW INDEX SUGGESTIONS)
+//!
+//! Every single-table SELECT that filters or sorts on columns without an
+//! index, and whose scan plan falls back to a sequential scan, is recorded
+//! per (table, column). SHOW INDEX SUGGESTIONS ranks those columns by how
+//! many rows the full scans read. The suggestions are advisory only; nothing
+//! is created automatically.
+
+use std::sync::Mutex;
+
+use rustc_hash::FxHashMap;
+
+use crate::core::{Result, Row, Schema, Value};
+use crate::parser::ast::{
+    Expression, InfixOperator, SelectStatement, ShowIndexSuggestionsStatement,
+};
+use crate::storage::expression::Expression as StorageExpr;
+use crate::storage::traits::{QueryResult, ScanPlan, Table};
+
+use super::context::ExecutionContext;
+use super::result::ExecutorMemoryResult;
+use super::Executor;
+
+/// Maximum number of (table, column) pairs kept in the history
+const MAX_TRACKED_COLUMNS: usize = 1024;
+
+/// How a query used a column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColumnUsage {
+    /// ORDER BY column
+    Sort,
+    /// Range comparison, BETWEEN or prefix LIKE
+    Range,
+    /// Equality or IN list
+    Equality,
+}
+
+impl ColumnUsage {
+    /// Share of the scanned rows an index on the column is expected to skip
+    fn benefit_weight(self) -> f64 {
+        match self {
+            ColumnUsage::Equality => 1.0,
+            ColumnUsage::Range => 0.5,
+            ColumnUsage::Sort => 0.25,
+        }
+    }
+}
+
+/// Full scans recorded for one column
+#[derive(Debug, Default)]
+struct ColumnHistory {
+    full_scans: u64,
+    equality: bool,
+    range: bool,
+    sort: bool,
+    rows_scanned: u64,
+    benefit: f64,
+}
+
+impl ColumnHistory {
+    fn usage(&self) -> String {
+        let mut parts = Vec::new();
+        if self.equality {
+            parts.push("equality");
+        }
+        if self.range {
+            parts.push("range");
+        }
+        if self.sort {
+            parts.push("sort");
+        }
+        parts.join(", ")
+    }
+}
+
+/// Columns that full table scans filtered or sorted on
+#[derive(Debug, Default)]
+pub(crate) struct IndexAdvisor {
+    /// Keyed by lowercase (table, column)
+    history: Mutex<FxHashMap<(String, String), ColumnHistory>>,
+}
+
+impl IndexAdvisor {
+    /// Record one full scan; `columns` may name a column once per usage
+    fn record(&self, table: &str, columns: &[(String, ColumnUsage)], rows_scanned: u64) {
+        let mut history = self.history.lock().unwrap();
+        for (i, (column, _)) in columns.iter().enumerate() {
+            if columns[..i].iter().any(|(seen, _)| seen == column) {
+                continue;
+            }
+            let key = (table.to_string(), column.clone());
+            if history.len() >= MAX_TRACKED_COLUMNS && !history.contains_key(&key) {
+                continue;
+            }
+            let entry = history.entry(key).or_default();
+            let mut weight: f64 = 0.0;
+            for (_, usage) in columns.iter().filter(|(name, _)| name == column) {
+                match usage {
+                    ColumnUsage::Equality => entry.equality = true,
+                    ColumnUsage::Range => entry.range = true,
+                    ColumnUsage::Sort => entry.sort = true,
+                }
+                weight = weight.max(usage.benefit_weight());
+            }
+            entry.full_scans += 1;
+            entry.rows_scanned += rows_scanned;
+            entry.benefit += rows_scanned as f64 * weight;
+        }
+    }
+}
+
+impl Executor {
+    /// Record the filter and sort columns of a single-table SELECT when its
+    /// scan reads the whole table
+    ///
+    /// The engine's own queries and scans of system tables are left out;
+    /// their tables cannot be indexed by the user.
+    pub(crate) fn record_scan_for_index_advisor(
+        &self,
+        table_name: &str,
+        table: &dyn Table,
+        stmt: &SelectStatement,
+        where_expr: Option<&Expression>,
+        storage_expr: Option<&dyn StorageExpr>,
+        ctx: &ExecutionContext,
+    ) {
+        if ctx.is_internal() || Schema::is_reserved_namespace(table_name) {
+            return;
+        }
+        let schema = table.schema();
+        let mut columns: Vec<(String, ColumnUsage)> = Vec::new();
+        let mut add = |name: &str, usage: ColumnUsage| {
+            let Some((_, column)) = schema.find_column(name) else {
+                return;
+            };
+            if column.primary_key {
+                return;
+            }
+            let name = column.name.to_lowercase();
+            if !columns.contains(&(name.clone(), usage)) {
+                columns.push((name, usage));
+            }
+        };
+
+        if let Some(expr) = where_expr {
+            collect_predicate_columns(expr, &mut add);
+        }
+        for order in &stmt.order_by {
+            if let Some(name) = column_name(&order.expression) {
+                add(name, ColumnUsage::Sort);
+            }
+        }
+        if columns.is_empty() {
+            return;
+        }
+
+        if !matches!(
+            table.explain_scan(storage_expr),
+            ScanPlan::SeqScan { .. } | ScanPlan::ParallelSeqScan { .. }
+        ) {
+            return;
+        }
+        columns.retain(|(name, _)| {
+            !table.has_index_on_column(name) && table.get_multi_column_index(&[name]).is_none()
+        });
+        if columns.is_empty() {
+            return;
+        }
+
+        self.index_advisor
+            .record(table_name, &columns, table.row_count() as u64);
+    }
+
+    /// Execute SHOW INDEX SUGGESTIONS
+    ///
+    /// Columns that have since been indexed, or whose table or column no
+    /// longer exists, are left out.
+    pub(crate) fn execute_show_index_suggestions(
+        &self,
+        _stmt: &ShowIndexSuggestionsStatement,
+    ) -> Result<Box<dyn QueryResult>> {
+        let tx = self.engine.begin_transaction()?;
+        let history = self.index_advisor.history.lock().unwrap();
+
+        let mut suggestions = Vec::new();
+        for ((table_name, column), entry) in history.iter() {
+            let Ok(table) = tx.get_table(table_name) else {
+                continue;
+            };
+            let Some((_, schema_column)) = table.schema().find_column(column) else {
+                continue;
+            };
+            if table.has_index_on_column(column)
+                || table.get_multi_column_index(&[column]).is_some()
+            {
+                continue;
+            }
+            suggestions.push((table_name, schema_column.name.clone(), entry));
+        }
+        suggestions.sort_by(|a, b| {
+            b.2.benefit
+                .total_cmp(&a.2.benefit)
+                .then_with(|| a.0.cmp(b.0))
+                .then_with(|| a.1.cmp(&b.1))
+        });
+
+        let rows = suggestions
+            .into_iter()
+            .map(|(table_name, column, entry)| {
+                Row::from_values(vec![
+                    Value::text(table_name),
+                    Value::text(&column),
+                    Value::text(entry.usage()),
+                    Value::Integer(entry.full_scans as i64),
+                    Value::Integer(entry.rows_scanned as i64),
+                    Value::Float(entry.benefit),
+                    Value::text(format!(
+                        "CREATE INDEX {} ON {}({})",
+                        suggested_index_name(table_name, &column),
+                        table_name,
+                        column
+                    )),
+                ])
+            })
+            .collect();
+
+        let columns = vec![
+            "table_name".to_string(),
+            "column_name".to_string(),
+            "usage".to_string(),
+            "full_scans".to_string(),
+            "rows_scanned".to_string(),
+            "estimated_benefit".to_string(),
+            "suggestion".to_string(),
+        ];
+        Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
+    }
+}
+
+/// Index name for a suggestion: `idx_<table>_<column>`, with the schema of a
+/// qualified table name joined by `_` and anything but letters, digits and
+/// `_` replaced so the name needs no quoting
+fn suggested_index_name(table_name: &str, column: &str) -> String {
+    format!("idx_{}_{}", table_name, column)
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Columns compared against constants in the top-level AND conjuncts
+///
+/// A predicate under OR cannot use an index on its own, so it is skipped.
+fn collect_predicate_columns(expr: &Expression, add: &mut impl FnMut(&str, ColumnUsage)) {
+    match expr {
+        Expression::Infix(infix) if infix.op_type == InfixOperator::And => {
+            collect_predicate_columns(&infix.left, add);
+            collect_predicate_columns(&infix.right, add);
+        }
+        Expression::Infix(infix) => {
+            let usage = match infix.op_type {
+                InfixOperator::Equal => ColumnUsage::Equality,
+                InfixOperator::LessThan
+                | InfixOperator::LessEqual
+                | InfixOperator::GreaterThan
+                | InfixOperator::GreaterEqual => ColumnUsage::Range,
+                _ => return,
+            };
+            if let (Some(name), true) = (column_name(&infix.left), is_constant(&infix.right)) {
+                add(name, usage);
+            } else if let (Some(name), true) = (column_name(&infix.right), is_constant(&infix.left))
+            {
+                add(name, usage);
+            }
+        }
+        Expression::In(in_expr) if !in_expr.not => {
+            if let Some(name) = column_name(&in_expr.left) {
+                let all_constant = match in_expr.right.as_ref() {
+                    Expression::List(list) => list.elements.iter().all(is_constant),
+                    Expression::ExpressionList(list) => list.expressions.iter().all(is_constant),
+                    _ => false,
+                };
+                if all_constant {
+                    add(name, ColumnUsage::Equality);
+                }
+            }
+        }
+        Expression::Between(between) if !between.not => {
+            if let Some(name) = column_name(&between.expr) {
+                if is_constant(&between.lower) && is_constant(&between.upper) {
+                    add(name, ColumnUsage::Range);
+                }
+            }
+        }
+        Expression::Like(like) if like.operator.eq_ignore_ascii_case("LIKE") => {
+            // Only a pattern with a fixed prefix narrows an index range
+            if let (Some(name), Expression::StringLiteral(pattern)) =
+                (column_name(&like.left), like.pattern.as_ref())
+            {
+                if !pattern.value.is_empty()
+                    && !pattern.value.starts_with('%')
+                    && !pattern.value.starts_with('_')
+                {
+                    add(name, ColumnUsage::Range);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn column_name(expr: &Expression) -> Option<&str> {
+    match expr {
+        Expression::Identifier(ident) => Some(&ident.value),
+        Expression::QualifiedIdentifier(qualified) => Some(&qualified.name.value),
+        _ => None,
+    }
+}
+
+fn is_constant(expr: &Expression) -> bool {
+    match expr {
+        Expression::IntegerLiteral(_)
+        | Expression::FloatLiteral(_)
+        | Expression::StringLiteral(_)
+        | Expression::BooleanLiteral(_)
+        | Expression::Parameter(_) => true,
+        Expression::Prefix(prefix) => is_constant(&prefix.right),
+        Expression::Cast(cast) => is_constant(&cast.expr),
+        _ => false,
+    }
+}
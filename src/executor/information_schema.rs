@@ -26,7 +26,7 @@ use std::sync::Arc;
 
 use crate::core::{DataType, Error, Result, Row, Value};
 use crate::parser::{ast::*, Parser};
-use crate::storage::traits::{Engine, QueryResult};
+use crate::storage::traits::QueryResult;
 
 use super::context::ExecutionContext;
 use super::result::ExecutorMemoryResult;
@@ -111,7 +111,8 @@ impl Executor {
 
         // Columns: table_catalog, table_schema, table_name, column_name, ordinal_position,
         //          column_default, is_nullable, data_type, character_maximum_length,
-        //          character_octet_length, numeric_precision, numeric_scale, datetime_precision
+        //          character_octet_length, numeric_precision, numeric_scale, datetime_precision,
+        //          is_generated, generation_expression
         let columns = vec![
             "table_catalog".to_string(),
             "table_schema".to_string(),
@@ -126,6 +127,8 @@ impl Executor {
             "numeric_precision".to_string(),
             "numeric_scale".to_string(),
             "datetime_precision".to_string(),
+            "is_generated".to_string(),
+            "generation_expression".to_string(),
         ];
 
         let mut rows: Vec<Row> = Vec::new();
@@ -206,6 +209,15 @@ impl Executor {
                     num_precision.unwrap_or(Value::Null(DataType::Integer)),
                     num_scale.unwrap_or(Value::Null(DataType::Integer)),
                     datetime_precision.unwrap_or(Value::Null(DataType::Integer)),
+                    Value::Text(Arc::from(if col.generated_expr.is_some() {
+                        "ALWAYS"
+                    } else {
+                        "NEVER"
+                    })),
+                    match &col.generated_expr {
+                        Some(expr) => Value::Text(Arc::from(expr.as_str())),
+                        None => Value::Null(DataType::Text),
+                    },
                 ]));
             }
         }
@@ -261,6 +261,7 @@ impl Executor {
         join_type: &str,
         left_col_count: usize,
         right_col_count: usize,
+        ctx: &ExecutionContext,
         limit: Option<u64>,
     ) -> Result<Vec<Row>> {
         // Optimization: Build hash table on smaller side
@@ -278,9 +279,10 @@ impl Executor {
 
         if use_parallel {
             let parallel_join_type = parallel::JoinType::from_str(join_type);
+            let cancel = ctx.cancellation_handle();
 
             let result = if build_on_left {
-                parallel::parallel_hash_join(
+                parallel::parallel_hash_join_cancellable(
                     right_rows,
                     left_rows,
                     right_key_indices,
@@ -290,9 +292,10 @@ impl Executor {
                     left_col_count,
                     true, // swapped
                     &parallel_config,
+                    Some(&cancel),
                 )
             } else {
-                parallel::parallel_hash_join(
+                parallel::parallel_hash_join_cancellable(
                     left_rows,
                     right_rows,
                     left_key_indices,
@@ -302,8 +305,11 @@ impl Executor {
                     right_col_count,
                     false, // not swapped
                     &parallel_config,
+                    Some(&cancel),
                 )
             };
+            // A cancelled probe stops early with part of the rows
+            ctx.check_cancelled()?;
 
             return Ok(result.rows);
         }
@@ -319,6 +325,7 @@ impl Executor {
                 right_col_count,
                 left_col_count,
                 true,
+                ctx,
                 limit,
             )
         } else {
@@ -331,6 +338,7 @@ impl Executor {
                 left_col_count,
                 right_col_count,
                 false,
+                ctx,
                 limit,
             )
         }
@@ -348,6 +356,7 @@ impl Executor {
         probe_col_count: usize,
         build_col_count: usize,
         swapped: bool,
+        ctx: &ExecutionContext,
         limit: Option<u64>,
     ) -> Result<Vec<Row>> {
         use crate::optimizer::bloom::BloomEffectivenessTracker;
@@ -390,6 +399,7 @@ impl Executor {
 
         // Probe phase
         'probe: for probe_row in probe_rows {
+            ctx.check_cancelled()?;
             let mut matched = false;
             let hash = hash_composite_key(probe_row, probe_key_indices);
 
@@ -515,7 +525,7 @@ impl Executor {
         left_columns: &[String],
         right_columns: &[String],
         join_type: &str,
-        _ctx: &ExecutionContext,
+        ctx: &ExecutionContext,
         limit: Option<u64>,
     ) -> Result<Vec<Row>> {
         let is_inner_or_cross = !join_type.contains("LEFT")
@@ -542,6 +552,7 @@ impl Executor {
         let right_col_count = right_columns.len();
 
         'outer: for left_row in left_rows {
+            ctx.check_cancelled()?;
             let mut matched = false;
 
             for (right_idx, right_row) in right_rows.iter().enumerate() {
@@ -602,6 +613,7 @@ impl Executor {
         join_type: &str,
         left_col_count: usize,
         right_col_count: usize,
+        ctx: &ExecutionContext,
     ) -> Result<Vec<Row>> {
         let mut result_rows = Vec::new();
 
@@ -612,6 +624,7 @@ impl Executor {
         let mut right_idx = 0;
 
         while left_idx < left_rows.len() && right_idx < right_rows.len() {
+            ctx.check_cancelled()?;
             let left_row = &left_rows[left_idx];
             let right_row = &right_rows[right_idx];
 
@@ -722,6 +735,7 @@ impl Executor {
         join_type: &str,
         outer_col_count: usize,
         inner_col_count: usize,
+        ctx: &ExecutionContext,
         limit: Option<u64>,
     ) -> Result<Vec<Row>> {
         use crate::core::Value;
@@ -736,6 +750,7 @@ impl Executor {
         let true_expr = ConstBoolExpr::true_expr();
 
         'outer: for outer_row in outer_rows {
+            ctx.check_cancelled()?;
             let mut matched = false;
 
             // Get the join key value from the outer row
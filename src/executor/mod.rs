@@ -47,6 +47,8 @@
 
 pub mod context;
 pub mod copy;
+#[cfg(feature = "parquet")]
+mod copy_parquet;
 pub mod expression;
 pub mod parallel;
 pub mod pattern_cache;
@@ -58,29 +60,45 @@ pub mod semantic_cache;
 pub mod statistics;
 
 mod aggregation;
+mod attach;
+mod catalog;
+pub(crate) mod column_defaults;
+mod column_meta;
 mod cte;
+mod cursor;
 mod ddl;
 mod dml;
+mod dump;
 mod explain;
+mod hints;
+mod index_advisor;
 mod information_schema;
 mod join;
+mod pg_catalog;
+mod procedure_trace;
 pub mod pushdown;
 mod query;
+mod row_lock;
+mod schema_version;
 mod set_ops;
 mod show;
 mod subquery;
 pub mod system_schema;
 pub mod triggers;
+mod unnest;
 pub mod utils;
+mod view_rewrite;
+mod warnings;
 mod window;
 
 use rustc_hash::FxHashMap;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, AtomicU8, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 
-use crate::core::{Error, Result, Value};
+use crate::core::{Error, Result, Row, Value};
 use crate::functions::{global_registry, FunctionDataType, FunctionRegistry, FunctionSignature};
 use crate::parser::ast::{Program, Statement};
-use crate::parser::Parser;
+use crate::parser::{IdentifierQuote, Parser};
 use crate::storage::mvcc::engine::MVCCEngine;
 use crate::storage::traits::{Engine, QueryResult, Table, Transaction};
 
@@ -91,6 +109,10 @@ pub(crate) enum DeferredDdlOperation {
     CreateTable {
         name: String,
     }, // Undo by dropping
+    CreateIndex {
+        table: String,
+        name: String,
+    }, // Undo by dropping
     DropTable {
         name: String,
         schema: crate::core::Schema,
@@ -107,6 +129,10 @@ pub(crate) enum DeferredDdlOperation {
 use crate::storage::functions::StoredParameter;
 use serde_json;
 
+pub use catalog::{
+    Catalog, CatalogColumn, CatalogConstraint, CatalogIndex, CatalogParameter, CatalogRoutine,
+    CatalogTable, CatalogView, RoutineKind,
+};
 pub use context::{ExecutionContext, TimeoutGuard};
 pub use expression::{
     CompileContext, CompileError, CompiledEvaluator, ExecuteContext, ExprCompiler, ExprVM,
@@ -137,13 +163,22 @@ pub use planner::{
     AccessPlan, ColumnStatsCache, JoinPlan, QueryPlanner, RuntimeJoinAlgorithm,
     RuntimeJoinDecision, StatsHealth,
 };
-pub use query_cache::{CacheStats, CachedQueryPlan, QueryCache, DEFAULT_CACHE_SIZE};
+pub(crate) use query::isolation_level_from_sql;
+pub use query_cache::{
+    normalize_query, CacheStats, CachedQueryPlan, NormalizedQuery, QueryCache, DEFAULT_CACHE_SIZE,
+};
+pub(crate) use result::RowsAffectedResult;
+use result::{DeadlineResult, MappedResult, TypedResult};
 pub use result::{ExecResult, ExecutorMemoryResult};
+pub(crate) use schema_version::SchemaToken;
 pub use semantic_cache::{
     CacheLookupResult, CachedResult, QueryFingerprint, SemanticCache, SemanticCacheStats,
     SemanticCacheStatsSnapshot, SubsumptionResult, DEFAULT_CACHE_TTL_SECS, DEFAULT_MAX_CACHED_ROWS,
     DEFAULT_SEMANTIC_CACHE_SIZE,
 };
+pub(crate) use show::isolation_level_result;
+use warnings::StatementDepthGuard;
+pub use warnings::{Warning, WarningLevel};
 
 /// Active transaction state for explicit transaction control (BEGIN/COMMIT/ROLLBACK)
 pub(crate) struct ActiveTransaction {
@@ -155,6 +190,9 @@ pub(crate) struct ActiveTransaction {
     ddl_undo_log: Vec<DeferredDdlOperation>,
     /// Tracks if the transaction was started explicitly by the user (e.g. BEGIN;)
     is_explicit_tx: bool,
+    /// Whether a query ran in the transaction; its isolation level can only
+    /// be changed before that
+    ran_query: bool,
 }
 
 /// SQL Query Executor
@@ -163,11 +201,14 @@ pub(crate) struct ActiveTransaction {
 /// It coordinates between the parser, storage engine, and function registry.
 pub struct Executor {
     /// Storage engine
-    engine: Arc<MVCCEngine>,
+    engine: Arc<dyn Engine>,
     /// Function registry for scalar, aggregate, and window functions
     function_registry: Arc<FunctionRegistry>,
-    /// Default isolation level for transactions
-    default_isolation_level: crate::core::IsolationLevel,
+    /// Isolation level of transactions started without one
+    default_isolation_level: AtomicU8,
+    /// Isolation level of the next transaction only
+    /// (SET TRANSACTION ISOLATION LEVEL outside a transaction)
+    next_isolation_level: Mutex<Option<crate::core::IsolationLevel>>,
     /// Query cache for parsed statements
     query_cache: QueryCache,
     /// Semantic cache for query results with subsumption detection
@@ -178,20 +219,76 @@ pub struct Executor {
     query_planner: std::sync::OnceLock<QueryPlanner>,
     /// Trigger registry for caching active triggers
     trigger_registry: Arc<triggers::TriggerRegistry>,
+    /// Session statement timeout in milliseconds (SET statement_timeout), 0 = none
+    statement_timeout_ms: AtomicU64,
+    /// Digits after the decimal point for floats in results
+    /// (SET float_display_precision), -1 = shortest exact representation
+    float_display_precision: AtomicI64,
+    /// Store invalid UTF-8 bytes written to TEXT columns with U+FFFD
+    /// replacements instead of rejecting them (SET invalid_utf8 = 'replace')
+    replace_invalid_utf8: AtomicBool,
+    /// Read `"..."` as a string literal and only backticks as identifier
+    /// quotes (SET identifier_quote = 'backtick')
+    backtick_identifiers: AtomicBool,
+    /// Keep function and procedure names as written (the default) instead
+    /// of uppercasing them (SET routine_name_case = 'upper')
+    preserve_routine_name_case: AtomicBool,
+    /// Add the failing statement and its position to errors
+    /// (SET error_verbosity = 'verbose')
+    verbose_errors: AtomicBool,
+    /// Make division and modulo by zero yield NULL instead of an error
+    /// (SET div_by_zero = 'null')
+    div_by_zero_null: AtomicBool,
+    /// Sort the result of a SELECT without ORDER BY by primary key
+    /// (SET implicit_order = pk)
+    implicit_pk_order: AtomicBool,
+    /// Open cursors (DECLARE ... CURSOR), keyed by lowercase name
+    cursors: Mutex<FxHashMap<String, cursor::OpenCursor>>,
+    /// Warnings raised by the last top-level statement (SHOW WARNINGS)
+    warnings: Mutex<Vec<Warning>>,
+    /// Nesting depth of statements being executed, 0 when idle
+    statement_depth: AtomicUsize,
+    /// Databases attached with ATTACH DATABASE, keyed by lowercase alias
+    attached: Mutex<FxHashMap<String, crate::api::Database>>,
+    /// Schemas searched for unqualified table names (SET search_path)
+    search_path: Mutex<Vec<String>>,
+    /// Columns full table scans filtered or sorted on (SHOW INDEX SUGGESTIONS)
+    index_advisor: index_advisor::IndexAdvisor,
+    /// Version of each table and view changed by DDL, checked by prepared
+    /// statements; objects never changed are at version 0
+    schema_versions: Mutex<FxHashMap<String, u64>>,
 }
 
 impl Executor {
     /// Create a new executor with the given storage engine
-    pub fn new(engine: Arc<MVCCEngine>) -> Self {
+    pub fn new(engine: Arc<dyn Engine>) -> Self {
         let executor = Self {
             engine,
             function_registry: Arc::clone(global_registry()),
-            default_isolation_level: crate::core::IsolationLevel::ReadCommitted,
+            default_isolation_level: AtomicU8::new(
+                crate::core::IsolationLevel::ReadCommitted.as_u8(),
+            ),
+            next_isolation_level: Mutex::new(None),
             query_cache: QueryCache::default(),
             semantic_cache: SemanticCache::default(),
             active_transaction: Mutex::new(None),
             query_planner: std::sync::OnceLock::new(),
             trigger_registry: Arc::new(triggers::TriggerRegistry::new()),
+            statement_timeout_ms: AtomicU64::new(0),
+            float_display_precision: AtomicI64::new(-1),
+            replace_invalid_utf8: AtomicBool::new(false),
+            backtick_identifiers: AtomicBool::new(false),
+            preserve_routine_name_case: AtomicBool::new(true),
+            verbose_errors: AtomicBool::new(false),
+            div_by_zero_null: AtomicBool::new(false),
+            implicit_pk_order: AtomicBool::new(false),
+            cursors: Mutex::new(FxHashMap::default()),
+            warnings: Mutex::new(Vec::new()),
+            statement_depth: AtomicUsize::new(0),
+            attached: Mutex::new(FxHashMap::default()),
+            search_path: Mutex::new(vec!["public".to_string()]),
+            index_advisor: index_advisor::IndexAdvisor::default(),
+            schema_versions: Mutex::new(FxHashMap::default()),
         };
 
         // Initialize system schema and tables
@@ -203,6 +300,7 @@ impl Executor {
         let _ = executor.load_functions();
         let _ = executor.load_procedures();
         let _ = executor.load_triggers();
+        let _ = executor.load_sequences();
 
         executor
     }
@@ -210,18 +308,36 @@ impl Executor {
     /// Create a new executor with a custom function registry
     /// Create a new executor with a custom function registry
     pub fn with_function_registry(
-        engine: Arc<MVCCEngine>,
+        engine: Arc<dyn Engine>,
         function_registry: Arc<FunctionRegistry>,
     ) -> Self {
         let executor = Self {
             engine,
             function_registry,
-            default_isolation_level: crate::core::IsolationLevel::ReadCommitted,
+            default_isolation_level: AtomicU8::new(
+                crate::core::IsolationLevel::ReadCommitted.as_u8(),
+            ),
+            next_isolation_level: Mutex::new(None),
             query_cache: QueryCache::default(),
             semantic_cache: SemanticCache::default(),
             active_transaction: Mutex::new(None),
             query_planner: std::sync::OnceLock::new(),
             trigger_registry: Arc::new(triggers::TriggerRegistry::new()),
+            statement_timeout_ms: AtomicU64::new(0),
+            float_display_precision: AtomicI64::new(-1),
+            replace_invalid_utf8: AtomicBool::new(false),
+            backtick_identifiers: AtomicBool::new(false),
+            preserve_routine_name_case: AtomicBool::new(true),
+            verbose_errors: AtomicBool::new(false),
+            div_by_zero_null: AtomicBool::new(false),
+            implicit_pk_order: AtomicBool::new(false),
+            cursors: Mutex::new(FxHashMap::default()),
+            warnings: Mutex::new(Vec::new()),
+            statement_depth: AtomicUsize::new(0),
+            attached: Mutex::new(FxHashMap::default()),
+            search_path: Mutex::new(vec!["public".to_string()]),
+            index_advisor: index_advisor::IndexAdvisor::default(),
+            schema_versions: Mutex::new(FxHashMap::default()),
         };
 
         if let Err(e) = executor.ensure_system_schema_and_migrations() {
@@ -232,38 +348,75 @@ impl Executor {
         let _ = executor.load_functions();
         let _ = executor.load_procedures();
         let _ = executor.load_triggers();
+        let _ = executor.load_sequences();
 
         executor
     }
 
     /// Create an internal executor without running migrations (used by background threads)
     pub(crate) fn new_internal(
-        engine: Arc<MVCCEngine>,
+        engine: Arc<dyn Engine>,
         function_registry: Arc<FunctionRegistry>,
     ) -> Self {
         Self {
             engine,
             function_registry,
-            default_isolation_level: crate::core::IsolationLevel::ReadCommitted,
+            default_isolation_level: AtomicU8::new(
+                crate::core::IsolationLevel::ReadCommitted.as_u8(),
+            ),
+            next_isolation_level: Mutex::new(None),
             query_cache: QueryCache::default(),
             semantic_cache: SemanticCache::default(),
             active_transaction: Mutex::new(None),
             query_planner: std::sync::OnceLock::new(),
             trigger_registry: Arc::new(triggers::TriggerRegistry::new()),
+            statement_timeout_ms: AtomicU64::new(0),
+            float_display_precision: AtomicI64::new(-1),
+            replace_invalid_utf8: AtomicBool::new(false),
+            backtick_identifiers: AtomicBool::new(false),
+            preserve_routine_name_case: AtomicBool::new(true),
+            verbose_errors: AtomicBool::new(false),
+            div_by_zero_null: AtomicBool::new(false),
+            implicit_pk_order: AtomicBool::new(false),
+            cursors: Mutex::new(FxHashMap::default()),
+            warnings: Mutex::new(Vec::new()),
+            statement_depth: AtomicUsize::new(0),
+            attached: Mutex::new(FxHashMap::default()),
+            search_path: Mutex::new(vec!["public".to_string()]),
+            index_advisor: index_advisor::IndexAdvisor::default(),
+            schema_versions: Mutex::new(FxHashMap::default()),
         }
     }
 
     /// Create a new executor with a custom cache size
-    pub fn with_cache_size(engine: Arc<MVCCEngine>, cache_size: usize) -> Self {
+    pub fn with_cache_size(engine: Arc<dyn Engine>, cache_size: usize) -> Self {
         let executor = Self {
             engine,
             function_registry: Arc::clone(crate::functions::global_registry()),
-            default_isolation_level: crate::core::IsolationLevel::ReadCommitted,
+            default_isolation_level: AtomicU8::new(
+                crate::core::IsolationLevel::ReadCommitted.as_u8(),
+            ),
+            next_isolation_level: Mutex::new(None),
             query_cache: QueryCache::new(cache_size),
             semantic_cache: SemanticCache::default(),
             active_transaction: Mutex::new(None),
             query_planner: std::sync::OnceLock::new(),
             trigger_registry: Arc::new(triggers::TriggerRegistry::new()),
+            statement_timeout_ms: AtomicU64::new(0),
+            float_display_precision: AtomicI64::new(-1),
+            replace_invalid_utf8: AtomicBool::new(false),
+            backtick_identifiers: AtomicBool::new(false),
+            preserve_routine_name_case: AtomicBool::new(true),
+            verbose_errors: AtomicBool::new(false),
+            div_by_zero_null: AtomicBool::new(false),
+            implicit_pk_order: AtomicBool::new(false),
+            cursors: Mutex::new(FxHashMap::default()),
+            warnings: Mutex::new(Vec::new()),
+            statement_depth: AtomicUsize::new(0),
+            attached: Mutex::new(FxHashMap::default()),
+            search_path: Mutex::new(vec!["public".to_string()]),
+            index_advisor: index_advisor::IndexAdvisor::default(),
+            schema_versions: Mutex::new(FxHashMap::default()),
         };
 
         if let Err(e) = executor.ensure_system_schema_and_migrations() {
@@ -274,10 +427,104 @@ impl Executor {
         let _ = executor.load_functions();
         let _ = executor.load_procedures();
         let _ = executor.load_triggers();
+        let _ = executor.load_sequences();
 
         executor
     }
 
+    /// Get the session float display precision (digits after the decimal
+    /// point), or None for the shortest exact representation
+    pub fn float_display_precision(&self) -> Option<u32> {
+        u32::try_from(self.float_display_precision.load(Ordering::Relaxed)).ok()
+    }
+
+    /// Whether invalid UTF-8 written to TEXT columns is replaced rather
+    /// than rejected
+    pub fn replace_invalid_utf8(&self) -> bool {
+        self.replace_invalid_utf8.load(Ordering::Relaxed)
+    }
+
+    /// Whether division and modulo by zero yield NULL rather than an error
+    pub fn div_by_zero_null(&self) -> bool {
+        self.div_by_zero_null.load(Ordering::Relaxed)
+    }
+
+    /// Whether a SELECT without ORDER BY is sorted by primary key
+    pub fn implicit_pk_order(&self) -> bool {
+        self.implicit_pk_order.load(Ordering::Relaxed)
+    }
+
+    /// Which quote character delimits identifiers in this session
+    pub fn identifier_quote(&self) -> IdentifierQuote {
+        if self.backtick_identifiers.load(Ordering::Relaxed) {
+            IdentifierQuote::Backtick
+        } else {
+            IdentifierQuote::DoubleQuote
+        }
+    }
+
+    /// Whether errors carry the failing statement and its position
+    pub fn verbose_errors(&self) -> bool {
+        self.verbose_errors.load(Ordering::Relaxed)
+    }
+
+    /// The name a new function or procedure is stored and shown under;
+    /// lookups ignore case either way
+    pub(crate) fn routine_display_name(&self, name: &str) -> String {
+        if self.preserve_routine_name_case.load(Ordering::Relaxed) {
+            name.to_string()
+        } else {
+            name.to_uppercase()
+        }
+    }
+
+    /// Roll back a transaction taken out of `active_transaction`, including
+    /// its table changes and deferred DDL
+    pub(crate) fn rollback_transaction_state(
+        &self,
+        mut tx_state: ActiveTransaction,
+    ) -> crate::core::Result<()> {
+        // Rollback all tables first
+        for (_name, mut table) in tx_state.tables.drain() {
+            table.rollback();
+        }
+
+        tx_state.transaction.rollback()?;
+
+        // Undo DDL operations (LIFO order)
+        while let Some(op) = tx_state.ddl_undo_log.pop() {
+            match op {
+                DeferredDdlOperation::CreateTable { name } => {
+                    let _ = self.engine.drop_table(&name);
+                }
+                DeferredDdlOperation::CreateIndex { table, name } => {
+                    self.undo_create_index(&table, &name);
+                }
+                DeferredDdlOperation::DropTable { schema, .. } => {
+                    let _ = self.engine.create_table(schema);
+                }
+                DeferredDdlOperation::CreateSchema { name } => {
+                    let _ = self.engine.drop_schema(&name);
+                }
+                DeferredDdlOperation::DropSchema { name, tables: _ } => {
+                    let _ = self.engine.create_schema(&name);
+                    // Note: we can't call create_table directly from here,
+                    // but since DropSchema isn't fully transactional across everything,
+                    // the executor handles this more completely in query.rs.
+                    // For the standalone undo log, we just ensure the schema exists
+                    // and let query.rs recreate the tables if it's a true rollback.
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the session statement timeout in milliseconds (0 = no timeout)
+    pub fn statement_timeout_ms(&self) -> u64 {
+        self.statement_timeout_ms.load(Ordering::Relaxed)
+    }
+
     /// Check if there is an active explicit transaction
     pub fn has_active_transaction(&self) -> bool {
         self.active_transaction.lock().unwrap().is_some()
@@ -436,6 +683,7 @@ impl Executor {
         self.ensure_functions_table_exists()?;
         self.ensure_procedures_table_exists()?;
         self.ensure_triggers_table_exists()?;
+        self.ensure_sequences_table_exists()?;
         self.ensure_table_stats_table_exists()?;
         self.ensure_column_stats_table_exists()?;
 
@@ -597,22 +845,6 @@ impl Executor {
                     })
                     .collect();
 
-                // Helper function to convert string to FunctionDataType
-                fn string_to_function_data_type(s: &str) -> FunctionDataType {
-                    match s.to_uppercase().as_str() {
-                        "ANY" => FunctionDataType::Any,
-                        "INTEGER" => FunctionDataType::Integer,
-                        "FLOAT" => FunctionDataType::Float,
-                        "TEXT" => FunctionDataType::String,
-                        "BOOLEAN" => FunctionDataType::Boolean,
-                        "TIMESTAMP" => FunctionDataType::Timestamp,
-                        "DATE" => FunctionDataType::Date,
-                        "TIME" => FunctionDataType::Time,
-                        "JSON" => FunctionDataType::Json,
-                        _ => FunctionDataType::Unknown,
-                    }
-                }
-
                 // Register the function in the registry
                 // Use stored language if supported, otherwise default to Rhai for backward compatibility
                 let language = if self.function_registry.is_language_supported(_language) {
@@ -628,10 +860,10 @@ impl Executor {
                     language,
                     param_names,
                     FunctionSignature::new(
-                        string_to_function_data_type(_return_type),
+                        FunctionDataType::from_sql_type(_return_type),
                         parameters
                             .iter()
-                            .map(|p| string_to_function_data_type(&p.data_type))
+                            .map(|p| FunctionDataType::from_sql_type(&p.data_type))
                             .collect(),
                         parameters.len(),
                         parameters.len(),
@@ -697,6 +929,65 @@ impl Executor {
         Ok(())
     }
 
+    /// Load sequences from system table
+    ///
+    /// Sequences already known to the engine (from another executor on the
+    /// same engine) are kept as they are.
+    fn load_sequences(&self) -> Result<()> {
+        use crate::storage::sequences::SYS_SEQUENCES;
+
+        let tx = self.engine.begin_transaction()?;
+        let tables = tx.list_tables()?;
+        let has_sequences_table = tables.iter().any(|t| t.eq_ignore_ascii_case(SYS_SEQUENCES));
+
+        if !has_sequences_table {
+            return Ok(());
+        }
+
+        let table = tx.get_table(SYS_SEQUENCES)?;
+        let mut scanner = table.scan(&[], None)?;
+
+        while scanner.next() {
+            let row = scanner.row();
+            // Schema: id(0), schema(1), name(2), start_value(3), increment(4),
+            // min_value(5), max_value(6), cycle(7), last_value(8)
+            if let (
+                Some(Value::Text(schema)),
+                Some(Value::Text(name)),
+                Some(Value::Integer(start_with)),
+                Some(Value::Integer(increment_by)),
+                Some(Value::Integer(min_value)),
+                Some(Value::Integer(max_value)),
+                Some(Value::Boolean(cycle)),
+            ) = (
+                row.get(1),
+                row.get(2),
+                row.get(3),
+                row.get(4),
+                row.get(5),
+                row.get(6),
+                row.get(7),
+            ) {
+                if self.engine.sequence_exists(schema, name)? {
+                    continue;
+                }
+                let options = crate::core::SequenceOptions {
+                    increment_by: *increment_by,
+                    start_with: *start_with,
+                    min_value: *min_value,
+                    max_value: *max_value,
+                    cycle: *cycle,
+                };
+                self.engine.create_sequence(schema, name, options)?;
+                if let Some(Value::Integer(last_value)) = row.get(8) {
+                    self.engine.setval(schema, name, *last_value, true)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Get or create a table within the active transaction
     /// Returns (table, should_auto_commit) where should_auto_commit is false if there's an active transaction
     /// Load triggers from system table
@@ -817,15 +1108,56 @@ impl Executor {
     }
 
     /// Set the default isolation level for new transactions
-    pub fn set_default_isolation_level(&mut self, level: crate::core::IsolationLevel) {
-        self.default_isolation_level = level;
+    pub fn set_default_isolation_level(&self, level: crate::core::IsolationLevel) {
+        self.default_isolation_level
+            .store(level.as_u8(), Ordering::Relaxed);
+    }
+
+    /// Get the default isolation level for new transactions
+    pub fn default_isolation_level(&self) -> crate::core::IsolationLevel {
+        crate::core::IsolationLevel::from_u8(self.default_isolation_level.load(Ordering::Relaxed))
+            .unwrap_or_default()
+    }
+
+    /// Set the isolation level of the next transaction only, leaving the
+    /// default for later ones unchanged
+    pub(crate) fn set_next_isolation_level(&self, level: crate::core::IsolationLevel) {
+        *self.next_isolation_level.lock().unwrap() = Some(level);
+    }
+
+    /// The isolation level the next transaction will get
+    pub(crate) fn next_isolation_level(&self) -> crate::core::IsolationLevel {
+        self.next_isolation_level
+            .lock()
+            .unwrap()
+            .unwrap_or_else(|| self.default_isolation_level())
+    }
+
+    /// Isolation level for a transaction being started, using up a level
+    /// set for the next transaction
+    pub fn take_next_isolation_level(&self) -> crate::core::IsolationLevel {
+        self.next_isolation_level
+            .lock()
+            .unwrap()
+            .take()
+            .unwrap_or_else(|| self.default_isolation_level())
     }
 
     /// Get the storage engine
-    pub fn engine(&self) -> &Arc<MVCCEngine> {
+    pub fn engine(&self) -> &Arc<dyn Engine> {
         &self.engine
     }
 
+    /// Get the MVCC engine holding the catalog
+    ///
+    /// Returns `NotSupported` when the storage engine is not backed by
+    /// an `MVCCEngine` (see [`Engine::as_mvcc`]).
+    pub(crate) fn mvcc(&self) -> Result<&MVCCEngine> {
+        self.engine.as_mvcc().ok_or_else(|| {
+            Error::NotSupportedMessage("operation requires the MVCC storage engine".to_string())
+        })
+    }
+
     /// Get the function registry
     pub fn function_registry(&self) -> &Arc<FunctionRegistry> {
         &self.function_registry
@@ -837,8 +1169,6 @@ impl Executor {
     /// It parses the query and executes each statement in order.
     /// Uses the query cache to avoid re-parsing identical queries.
     pub fn execute(&self, sql: &str) -> Result<Box<dyn QueryResult>> {
-        crate::executor::context::set_current_engine(std::sync::Arc::clone(&self.engine)
-            as std::sync::Arc<dyn crate::storage::traits::Engine>);
         let ctx = ExecutionContext::new();
         self.execute_cached(sql, &ctx)
     }
@@ -881,6 +1211,9 @@ impl Executor {
     /// If found, it uses the cached AST. Otherwise, it parses the query
     /// and caches the result for future use.
     fn execute_cached(&self, sql: &str, ctx: &ExecutionContext) -> Result<Box<dyn QueryResult>> {
+        // Sequence and transaction functions reach the engine through this
+        context::set_current_engine(Arc::clone(&self.engine));
+
         // Increment the total queries metric
         tracing::info!(
             target: "oxibase::metrics",
@@ -892,40 +1225,43 @@ impl Executor {
         );
 
         // Try to get from cache
-        if let Some(cached) = self.query_cache.get(sql) {
-            // Validate parameter count if query has parameters
-            if cached.has_params {
-                let provided = ctx.params().len();
-                if provided < cached.param_count {
-                    return Err(Error::internal(format!(
-                        "Query requires {} parameters but only {} provided",
-                        cached.param_count, provided
-                    )));
-                }
-            }
+        let normalized = normalize_query(sql, self.identifier_quote());
+        if let Some(cached) = self.query_cache.get_normalized(&normalized) {
+            ctx.validate_param_count(cached.param_count)?;
 
             // Execute the cached statement
             return self.execute_statement(&cached.statement, ctx);
         }
 
         // Parse the query
-        let mut parser = Parser::new(sql);
-        let mut program = parser
-            .parse_program()
-            .map_err(|e| Error::parse(e.to_string()))?;
+        let mut parser = Parser::with_identifier_quote(sql, self.identifier_quote());
+        let mut program = parser.parse_program().map_err(|e| {
+            let error = Error::parse(e.to_string());
+            if self.verbose_errors() {
+                verbose_parse_error(error, sql, &e)
+            } else {
+                error
+            }
+        })?;
+        let param_count = parser.max_parameter_index();
 
         // Cache single-statement queries and execute directly from cache
         if program.statements.len() == 1 {
             // Take ownership of the statement to avoid clone
             let stmt = program.statements.pop().unwrap();
-            let (has_params, param_count) = count_parameters(&stmt);
             let stmt_arc = std::sync::Arc::new(stmt);
-            self.query_cache
-                .put(sql, stmt_arc.clone(), has_params, param_count);
+            self.query_cache.put_normalized(
+                &normalized,
+                stmt_arc.clone(),
+                param_count > 0,
+                param_count,
+            );
+            ctx.validate_param_count(param_count)?;
             // Execute directly from the Arc (no clone needed)
             return self.execute_statement(&stmt_arc, ctx);
         }
 
+        ctx.validate_param_count(param_count)?;
         self.execute_program_with_context(&program, ctx)
     }
 
@@ -999,9 +1335,20 @@ impl Executor {
     ) -> Result<Box<dyn QueryResult>> {
         // If there's an active transaction, inject the transaction ID into the context
         // This enables CURRENT_TRANSACTION_ID() function to return the correct value
-        let ctx = {
-            let active_tx = self.active_transaction.lock().unwrap();
-            if let Some(ref tx_state) = *active_tx {
+        let mut ctx = {
+            let mut active_tx = self.active_transaction.lock().unwrap();
+            if let Some(ref mut tx_state) = *active_tx {
+                // Settings and SHOW don't count as queries, as in PostgreSQL
+                if !matches!(
+                    statement,
+                    Statement::SetTransaction(_)
+                        | Statement::ShowIsolationLevel(_)
+                        | Statement::ShowSetting(_)
+                        | Statement::Set(_)
+                        | Statement::ShowWarnings(_)
+                ) {
+                    tx_state.ran_query = true;
+                }
                 let txn_id = tx_state.transaction.id();
                 ctx.with_transaction_id(txn_id as u64)
             } else {
@@ -1009,6 +1356,28 @@ impl Executor {
             }
         };
 
+        // The session statement timeout applies unless the caller set its own
+        let statement_timeout_ms = self.statement_timeout_ms();
+        if statement_timeout_ms > 0 && ctx.timeout_ms() == 0 {
+            ctx.set_timeout_ms(statement_timeout_ms);
+        }
+        ctx.set_div_by_zero_null(self.div_by_zero_null());
+        crate::functions::context::set_identifier_quote(self.identifier_quote());
+
+        // A new top-level statement replaces the previous one's warnings
+        let (_depth_guard, top_level) = StatementDepthGuard::enter(&self.statement_depth);
+        if top_level && !matches!(statement, Statement::ShowWarnings(_)) {
+            self.clear_warnings();
+        }
+
+        // The timeout covers the whole top-level statement, whatever its kind;
+        // nested statements (triggers, routines) run under the same guard
+        let timeout_guard = if top_level {
+            TimeoutGuard::new(&ctx)
+        } else {
+            None
+        };
+
         let _span = tracing::info_span!(
             "execute_statement",
             transaction_id = ctx.transaction_id(),
@@ -1016,7 +1385,7 @@ impl Executor {
         )
         .entered();
 
-        crate::functions::backends::with_sql_runner(
+        let result = crate::functions::backends::with_sql_runner(
             Some(self as &dyn crate::functions::backends::SqlRunner),
             || {
                 match statement {
@@ -1045,27 +1414,46 @@ impl Executor {
                     Statement::Truncate(stmt) => self.execute_truncate(stmt, &ctx),
 
                     // Query statements
-                    Statement::Select(stmt) => self.execute_select(stmt, &ctx),
+                    Statement::Select(stmt) => match self.with_implicit_order(stmt) {
+                        Some(ordered) => self.execute_select(&ordered, &ctx),
+                        None => self.execute_select(stmt, &ctx),
+                    },
 
                     // Transaction control
                     Statement::Begin(stmt) => self.execute_begin(stmt, &ctx),
                     Statement::Commit(stmt) => self.execute_commit_stmt(stmt, &ctx),
                     Statement::Rollback(stmt) => self.execute_rollback_stmt(stmt, &ctx),
                     Statement::Savepoint(stmt) => self.execute_savepoint(stmt, &ctx),
+                    Statement::ReleaseSavepoint(stmt) => self.execute_release_savepoint(stmt, &ctx),
+                    Statement::SetTransaction(stmt) => self.execute_set_transaction(stmt, &ctx),
 
                     // Utility statements
                     Statement::Set(stmt) => self.execute_set(stmt, &ctx),
                     Statement::ShowTables(stmt) => self.execute_show_tables(stmt, &ctx),
                     Statement::ShowViews(stmt) => self.execute_show_views(stmt, &ctx),
                     Statement::ShowFunctions(stmt) => self.execute_show_functions(stmt, &ctx),
+                    Statement::ShowProcedures(stmt) => self.execute_show_procedures(stmt, &ctx),
+                    Statement::ShowSequences(stmt) => self.execute_show_sequences(stmt),
+                    Statement::ShowIsolationLevel(stmt) => self.execute_show_isolation_level(stmt),
+                    Statement::ShowSetting(stmt) => self.execute_show_setting(stmt),
                     Statement::ShowCreateTable(stmt) => self.execute_show_create_table(stmt, &ctx),
                     Statement::ShowCreateView(stmt) => self.execute_show_create_view(stmt, &ctx),
                     Statement::ShowIndexes(stmt) => self.execute_show_indexes(stmt, &ctx),
+                    Statement::ShowWarnings(stmt) => self.execute_show_warnings(stmt),
+                    Statement::ShowEngineStatus(stmt) => self.execute_show_engine_status(stmt),
+                    Statement::ShowIndexSuggestions(stmt) => {
+                        self.execute_show_index_suggestions(stmt)
+                    }
+                    Statement::ShowRowVersions(stmt) => self.execute_show_row_versions(stmt, &ctx),
                     Statement::Describe(stmt) => self.execute_describe(stmt, &ctx),
                     Statement::Pragma(stmt) => self.execute_pragma(stmt, &ctx),
                     Statement::Expression(stmt) => self.execute_expression_stmt(stmt, &ctx),
                     Statement::Explain(stmt) => self.execute_explain(stmt, &ctx),
                     Statement::Analyze(stmt) => self.execute_analyze(stmt, &ctx),
+                    Statement::Reindex(stmt) => self.execute_reindex(stmt, &ctx),
+                    Statement::Checkpoint(stmt) => self.execute_checkpoint(stmt),
+                    Statement::AttachDatabase(stmt) => self.execute_attach_database(stmt),
+                    Statement::DetachDatabase(stmt) => self.execute_detach_database(stmt),
                     Statement::CreateFunction(stmt) => self.execute_create_function(stmt, &ctx),
                     Statement::DropFunction(stmt) => self.execute_drop_function(stmt, &ctx),
                     Statement::CreateProcedure(stmt) => self.execute_create_procedure(stmt, &ctx),
@@ -1080,9 +1468,64 @@ impl Executor {
                     Statement::AlterSchedule(stmt) => self.execute_alter_schedule(stmt, &ctx),
                     Statement::DropSchedule(stmt) => self.execute_drop_schedule(stmt, &ctx),
                     Statement::Copy(stmt) => self.execute_copy(stmt, &ctx),
+                    Statement::DeclareCursor(stmt) => self.execute_declare_cursor(stmt, &ctx),
+                    Statement::Fetch(stmt) => self.execute_fetch(stmt),
+                    Statement::CloseCursor(stmt) => self.execute_close_cursor(stmt),
                 }
             },
-        )
+        );
+        let result = match result {
+            Ok(result) => result,
+            Err(error) if self.verbose_errors() => {
+                return Err(verbose_statement_error(error, statement))
+            }
+            Err(error) => return Err(error),
+        };
+
+        // Cached plans may refer to objects this statement changed
+        if changes_schema(statement) {
+            self.query_cache.invalidate();
+            self.bump_schema_versions(statement);
+        }
+
+        if !top_level || result.columns().is_empty() {
+            return Ok(result);
+        }
+
+        // SET float_display_precision rounds the floats the client sees
+        let result: Box<dyn QueryResult> = match self.float_display_precision() {
+            Some(digits) => {
+                let columns = result.columns().to_vec();
+                Box::new(MappedResult::new(
+                    result,
+                    columns,
+                    Box::new(move |row: &Row| {
+                        Row::from_values(
+                            row.iter()
+                                .map(|value| match value {
+                                    Value::Float(v) => Value::Float(round_float(*v, digits)),
+                                    other => other.clone(),
+                                })
+                                .collect(),
+                        )
+                    }),
+                ))
+            }
+            None => result,
+        };
+
+        // Attach column types for drivers; only a SELECT declares any
+        let declared = match statement {
+            Statement::Select(stmt) => self.declared_column_types(stmt, result.columns().len()),
+            _ => Vec::new(),
+        };
+        let result: Box<dyn QueryResult> = Box::new(TypedResult::new(result, &declared));
+
+        // Rows read after the statement returns still count against its timeout
+        Ok(match timeout_guard {
+            Some(guard) => Box::new(DeadlineResult::new(result, ctx, guard)),
+            None => result,
+        })
     }
 
     /// Begin a new transaction
@@ -1099,162 +1542,38 @@ impl Executor {
         let _ = tx.set_isolation_level(isolation);
         Ok(tx)
     }
-}
-
-/// Count the number of parameter placeholders in a statement
-///
-/// Returns (has_params, max_param_index)
-fn count_parameters(stmt: &Statement) -> (bool, usize) {
-    use crate::parser::ast::*;
 
-    struct ParamCounter {
-        max_index: usize,
-        has_positional: bool,
-    }
-
-    impl ParamCounter {
-        fn new() -> Self {
-            Self {
-                max_index: 0,
-                has_positional: false,
-            }
-        }
-
-        fn visit_expr(&mut self, expr: &Expression) {
-            match expr {
-                Expression::Parameter(param) => {
-                    if param.index > 0 {
-                        self.max_index = self.max_index.max(param.index);
-                    } else {
-                        // Positional parameter (?)
-                        self.has_positional = true;
-                    }
-                }
-                Expression::Infix(infix) => {
-                    self.visit_expr(&infix.left);
-                    self.visit_expr(&infix.right);
-                }
-                Expression::Prefix(prefix) => {
-                    self.visit_expr(&prefix.right);
-                }
-                Expression::FunctionCall(func) => {
-                    for arg in &func.arguments {
-                        self.visit_expr(arg);
-                    }
-                }
-                Expression::Case(case) => {
-                    if let Some(val) = &case.value {
-                        self.visit_expr(val);
-                    }
-                    for when in &case.when_clauses {
-                        self.visit_expr(&when.condition);
-                        self.visit_expr(&when.then_result);
-                    }
-                    if let Some(el) = &case.else_value {
-                        self.visit_expr(el);
-                    }
-                }
-                Expression::In(in_expr) => {
-                    self.visit_expr(&in_expr.left);
-                    self.visit_expr(&in_expr.right);
-                }
-                Expression::Between(between) => {
-                    self.visit_expr(&between.expr);
-                    self.visit_expr(&between.lower);
-                    self.visit_expr(&between.upper);
-                }
-                Expression::Cast(cast) => {
-                    self.visit_expr(&cast.expr);
-                }
-                Expression::ScalarSubquery(subq) => {
-                    self.visit_select(&subq.subquery);
-                }
-                Expression::Exists(exists) => {
-                    self.visit_select(&exists.subquery);
-                }
-                Expression::List(list) => {
-                    for item in &list.elements {
-                        self.visit_expr(item);
-                    }
-                }
-                Expression::ExpressionList(list) => {
-                    for item in &list.expressions {
-                        self.visit_expr(item);
-                    }
-                }
-                Expression::Aliased(aliased) => {
-                    self.visit_expr(&aliased.expression);
-                }
-                Expression::Window(window) => {
-                    // WindowExpression.function is Box<FunctionCall>, visit its arguments
-                    for arg in &window.function.arguments {
-                        self.visit_expr(arg);
-                    }
-                }
-                _ => {}
-            }
-        }
-
-        fn visit_select(&mut self, select: &SelectStatement) {
-            // Visit columns
-            for col in &select.columns {
-                self.visit_expr(col);
-            }
-            // Visit table expression (may contain subqueries)
-            if let Some(table_expr) = &select.table_expr {
-                self.visit_expr(table_expr);
-            }
-            // Visit where clause
-            if let Some(where_clause) = &select.where_clause {
-                self.visit_expr(where_clause);
-            }
-            // Visit group by
-            for group in &select.group_by.columns {
-                self.visit_expr(group);
-            }
-            // Visit having
-            if let Some(having) = &select.having {
-                self.visit_expr(having);
-            }
-        }
-    }
+    /// Executes a statement inside a transaction begun through the API, as
+    /// if it were the transaction opened by BEGIN
+    ///
+    /// The transaction is taken out of `transaction` for the statement and put
+    /// back afterwards whether or not it succeeds, unless the statement ended
+    /// it; a transaction opened by BEGIN is set aside for the duration.
+    pub(crate) fn execute_in_transaction(
+        &self,
+        statement: &Statement,
+        transaction: &mut Option<Box<dyn Transaction>>,
+        ctx: &ExecutionContext,
+    ) -> Result<Box<dyn QueryResult>> {
+        let tx = transaction.take().ok_or(Error::TransactionNotStarted)?;
+        let outer = self
+            .active_transaction
+            .lock()
+            .unwrap()
+            .replace(ActiveTransaction {
+                transaction: tx,
+                tables: FxHashMap::default(),
+                ddl_undo_log: Vec::new(),
+                is_explicit_tx: true,
+                ran_query: true,
+            });
 
-    let mut counter = ParamCounter::new();
+        let result = self.execute_statement(statement, ctx);
 
-    match stmt {
-        Statement::Select(select) => counter.visit_select(select),
-        Statement::Insert(insert) => {
-            for row in &insert.values {
-                for expr in row {
-                    counter.visit_expr(expr);
-                }
-            }
-        }
-        Statement::Update(update) => {
-            for expr in update.updates.values() {
-                counter.visit_expr(expr);
-            }
-            if let Some(where_clause) = &update.where_clause {
-                counter.visit_expr(where_clause);
-            }
-        }
-        Statement::Delete(delete) => {
-            if let Some(where_clause) = &delete.where_clause {
-                counter.visit_expr(where_clause);
-            }
-        }
-        _ => {}
+        let mut active_tx = self.active_transaction.lock().unwrap();
+        *transaction = std::mem::replace(&mut *active_tx, outer).map(|state| state.transaction);
+        result
     }
-
-    let has_params = counter.max_index > 0 || counter.has_positional;
-    let param_count = if counter.has_positional {
-        // For positional params, we can't know the count statically
-        0
-    } else {
-        counter.max_index
-    };
-
-    (has_params, param_count)
 }
 
 impl crate::functions::backends::SqlRunner for Executor {
@@ -1300,6 +1619,7 @@ impl crate::functions::backends::SqlRunner for Executor {
                 tables: rustc_hash::FxHashMap::default(),
                 ddl_undo_log: Vec::new(),
                 is_explicit_tx: false,
+                ran_query: false,
             });
         }
 
@@ -1323,38 +1643,8 @@ impl crate::functions::backends::SqlRunner for Executor {
             ));
         }
 
-        if let Some(mut tx_state) = active_tx.take() {
-            // Rollback all tables first
-            for (_name, mut table) in tx_state.tables.drain() {
-                table.rollback();
-            }
-
-            tx_state.transaction.rollback()?;
-
-            // Undo DDL operations (LIFO order)
-            while let Some(op) = tx_state.ddl_undo_log.pop() {
-                match op {
-                    DeferredDdlOperation::CreateTable { name } => {
-                        let _ = self.engine.drop_table_internal(&name);
-                    }
-                    DeferredDdlOperation::DropTable { schema, .. } => {
-                        let _ = self.engine.create_table(schema);
-                    }
-                    DeferredDdlOperation::CreateSchema { name } => {
-                        let mut schemas = self.engine.schemas.write().unwrap();
-                        schemas.remove(&name);
-                    }
-                    DeferredDdlOperation::DropSchema { name, tables: _ } => {
-                        let mut schemas = self.engine.schemas.write().unwrap();
-                        schemas.entry(name.clone()).or_default();
-                        // Note: we can't call create_table directly from here,
-                        // but since DropSchema isn't fully transactional across everything,
-                        // the executor handles this more completely in query.rs.
-                        // For the standalone undo log, we just ensure the schema exists
-                        // and let query.rs recreate the tables if it's a true rollback.
-                    }
-                }
-            }
+        if let Some(tx_state) = active_tx.take() {
+            self.rollback_transaction_state(tx_state)?;
 
             // Immediately start a new implicit transaction for the rest of the procedure
             let new_tx = self.engine.begin_transaction()?;
@@ -1363,6 +1653,7 @@ impl crate::functions::backends::SqlRunner for Executor {
                 tables: rustc_hash::FxHashMap::default(),
                 ddl_undo_log: Vec::new(),
                 is_explicit_tx: false,
+                ran_query: false,
             });
         }
 
@@ -1375,6 +1666,99 @@ impl crate::functions::backends::SqlRunner for Executor {
     }
 }
 
+/// Whether a statement creates, drops or alters a schema object, or swaps
+/// out the objects behind the names cached plans refer to
+fn changes_schema(statement: &Statement) -> bool {
+    matches!(
+        statement,
+        Statement::CreateTable(_)
+            | Statement::DropTable(_)
+            | Statement::CreateIndex(_)
+            | Statement::DropIndex(_)
+            | Statement::AlterTable(_)
+            | Statement::CreateView(_)
+            | Statement::DropView(_)
+            | Statement::CreateColumnarIndex(_)
+            | Statement::DropColumnarIndex(_)
+            | Statement::CreateSchema(_)
+            | Statement::DropSchema(_)
+            | Statement::CreateFunction(_)
+            | Statement::DropFunction(_)
+            | Statement::CreateProcedure(_)
+            | Statement::DropProcedure(_)
+            | Statement::CreateTrigger(_)
+            | Statement::DropTrigger(_)
+            | Statement::CreateSequence(_)
+            | Statement::AlterSequence(_)
+            | Statement::DropSequence(_)
+            | Statement::Truncate(_)
+            | Statement::AttachDatabase(_)
+            | Statement::DetachDatabase(_)
+    )
+}
+
+/// Add the statement text and error position to a parse error
+fn verbose_parse_error(error: Error, sql: &str, errors: &crate::parser::ParseErrors) -> Error {
+    let mut context = format!("\n  statement: {}", sql.trim());
+    if let Some(first) = errors.errors.first() {
+        let position = first.position;
+        context.push_str(&format!(
+            "\n  position: line {}, column {}",
+            position.line, position.column
+        ));
+        if let Some(line) = sql.lines().nth(position.line.saturating_sub(1)) {
+            context.push_str(&format!(
+                "\n    {}\n    {}^",
+                line,
+                " ".repeat(position.column.saturating_sub(1))
+            ));
+        }
+    }
+    Error::Detailed {
+        error: Box::new(error),
+        context,
+    }
+}
+
+/// Add the failing statement to an error, or the enclosing statement when a
+/// nested statement (procedure body, trigger, SQL function) already did
+///
+/// Internal errors also get a backtrace of where the statement failed.
+fn verbose_statement_error(error: Error, statement: &Statement) -> Error {
+    match error {
+        Error::Detailed { error, mut context } => {
+            context.push_str(&format!("\n  called from: {}", statement));
+            Error::Detailed { error, context }
+        }
+        error => {
+            let mut context = format!("\n  statement: {}", statement);
+            if matches!(error, Error::Internal { .. }) {
+                context.push_str(&format!(
+                    "\n  backtrace:\n{}",
+                    std::backtrace::Backtrace::force_capture()
+                ));
+            }
+            Error::Detailed {
+                error: Box::new(error),
+                context,
+            }
+        }
+    }
+}
+
+/// Round a float to `digits` places after the decimal point
+///
+/// NaN and infinities are returned unchanged, as are values too large to
+/// have a fractional part at that precision.
+fn round_float(v: f64, digits: u32) -> f64 {
+    let scale = 10f64.powi(digits as i32);
+    let scaled = v * scale;
+    if !scaled.is_finite() || scaled.abs() >= 1e17 {
+        return v;
+    }
+    scaled.round() / scale
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
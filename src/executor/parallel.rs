@@ -45,6 +45,7 @@ use crate::core::{Result, Row, Value};
 use crate::functions::FunctionRegistry;
 use crate::parser::ast::Expression;
 
+use super::context::CancellationHandle;
 use super::expression::{ExpressionEval, RowFilter};
 use super::utils::{hash_composite_key, hash_row, rows_equal, verify_composite_key_equality};
 
@@ -732,6 +733,38 @@ pub fn parallel_hash_join(
     swapped: bool,
     config: &ParallelConfig,
 ) -> ParallelJoinResult {
+    parallel_hash_join_cancellable(
+        probe_rows,
+        build_rows,
+        probe_key_indices,
+        build_key_indices,
+        join_type,
+        probe_col_count,
+        build_col_count,
+        swapped,
+        config,
+        None,
+    )
+}
+
+/// Execute a parallel hash join that stops probing once `cancel` fires
+///
+/// The rows of a cancelled join are incomplete; the caller checks the
+/// statement for cancellation before using them.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn parallel_hash_join_cancellable(
+    probe_rows: &[Row],
+    build_rows: &[Row],
+    probe_key_indices: &[usize],
+    build_key_indices: &[usize],
+    join_type: JoinType,
+    probe_col_count: usize,
+    build_col_count: usize,
+    swapped: bool,
+    config: &ParallelConfig,
+    cancel: Option<&CancellationHandle>,
+) -> ParallelJoinResult {
+    let is_cancelled = || cancel.is_some_and(|c| c.is_cancelled());
     let probe_count = probe_rows.len();
     let build_count = build_rows.len();
 
@@ -758,6 +791,9 @@ pub fn parallel_hash_join(
             .flat_map(|chunk| {
                 let mut local_results = Vec::new();
                 for probe_row in chunk {
+                    if is_cancelled() {
+                        break;
+                    }
                     let hash = hash_row_by_keys(probe_row, probe_key_indices);
                     if let Some(build_indices) = hash_table.get(&hash) {
                         for build_idx in build_indices {
@@ -797,6 +833,9 @@ pub fn parallel_hash_join(
                 let mut unmatched_results = Vec::new();
 
                 for probe_row in chunk.iter() {
+                    if is_cancelled() {
+                        break;
+                    }
                     let mut matched = false;
                     let hash = hash_row_by_keys(probe_row, probe_key_indices);
 
@@ -878,6 +917,9 @@ pub fn parallel_hash_join(
         let needs_unmatched_probe = join_type.needs_unmatched_probe(swapped);
 
         for probe_row in probe_rows.iter() {
+            if is_cancelled() {
+                break;
+            }
             let hash = hash_row_by_keys(probe_row, probe_key_indices);
             let mut matched = false;
 
@@ -0,0 +1,295 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! PostgreSQL catalog compatibility tables
+//!
+//! Minimal read-only versions of the pg_catalog tables that Postgres drivers
+//! and tools query on connect, built from the same metadata as
+//! information_schema:
+//! - pg_catalog.pg_namespace
+//! - pg_catalog.pg_class
+//! - pg_catalog.pg_attribute
+//! - pg_catalog.pg_type
+//!
+//! Only the columns drivers commonly read are provided. Type OIDs match
+//! PostgreSQL's; namespace and relation OIDs are assigned per query.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use crate::core::{DataType, Error, Result, Row, Value};
+use crate::parser::ast::SimpleTableSource;
+use crate::storage::traits::QueryResult;
+
+use super::result::ExecutorMemoryResult;
+use super::Executor;
+
+/// OID of the pg_catalog namespace
+const PG_CATALOG_NAMESPACE: i64 = 11;
+/// OID of the public namespace
+const PUBLIC_NAMESPACE: i64 = 2200;
+/// OID of the information_schema namespace
+const INFORMATION_SCHEMA_NAMESPACE: i64 = 13000;
+/// First OID handed out to user objects (FirstNormalObjectId)
+const FIRST_USER_OID: i64 = 16384;
+/// OID of the bootstrap superuser that owns everything
+const OWNER_OID: i64 = 10;
+
+/// (type, oid, typname, typlen) for each Oxibase column type
+const PG_TYPES: &[(DataType, i64, &str, i64)] = &[
+    (DataType::Boolean, 16, "bool", 1),
+    (DataType::Integer, 20, "int8", 8),
+    (DataType::Text, 25, "text", -1),
+    (DataType::Json, 114, "json", -1),
+    (DataType::Float, 701, "float8", 8),
+    (DataType::Timestamp, 1184, "timestamptz", 8),
+    (DataType::Interval, 1186, "interval", 16),
+];
+
+/// Name of the pg_catalog table referenced by `source`, if any
+///
+/// Matches `pg_catalog.pg_class` style qualified names only, so user tables
+/// named like catalog tables are not shadowed.
+pub(crate) fn pg_catalog_table(source: &SimpleTableSource) -> Option<String> {
+    let schema = source.name.schema()?;
+    if !schema.eq_ignore_ascii_case("pg_catalog") {
+        return None;
+    }
+    let table = source.name.table().to_lowercase();
+    matches!(
+        table.as_str(),
+        "pg_namespace" | "pg_class" | "pg_attribute" | "pg_type"
+    )
+    .then_some(table)
+}
+
+/// A table or view as seen by pg_class
+struct Relation {
+    oid: i64,
+    namespace: i64,
+    name: String,
+    kind: &'static str,
+    /// (name, type, not null) for tables; views have no attributes here
+    columns: Vec<(String, DataType, bool)>,
+}
+
+/// Namespaces and relations visible to one catalog query
+struct Catalog {
+    namespaces: Vec<(i64, String)>,
+    relations: Vec<Relation>,
+}
+
+fn text(s: &str) -> Value {
+    Value::Text(Arc::from(s))
+}
+
+fn split_table_name(name: &str) -> (String, String) {
+    match name.split_once('.') {
+        Some((schema, table)) => (schema.to_string(), table.to_string()),
+        None => ("public".to_string(), name.to_string()),
+    }
+}
+
+impl Executor {
+    /// Execute queries against pg_catalog compatibility tables
+    pub(crate) fn execute_pg_catalog_table(&self, table: &str) -> Result<Box<dyn QueryResult>> {
+        match table {
+            "pg_namespace" => self.build_pg_namespace_result(),
+            "pg_class" => self.build_pg_class_result(),
+            "pg_attribute" => self.build_pg_attribute_result(),
+            "pg_type" => Ok(build_pg_type_result()),
+            _ => Err(Error::TableNotFoundByName(format!("pg_catalog.{}", table))),
+        }
+    }
+
+    fn load_pg_catalog(&self) -> Result<Catalog> {
+        let tx = self.engine.begin_transaction()?;
+
+        let mut tables = Vec::new();
+        for table_name in tx.list_tables()? {
+            let table = tx.get_table(&table_name)?;
+            let columns = table
+                .schema()
+                .columns
+                .iter()
+                .map(|c| (c.name.clone(), c.data_type, !c.nullable))
+                .collect();
+            let (schema, name) = split_table_name(&table_name);
+            tables.push((schema, name, "r", columns));
+        }
+        for (schema, view) in self.engine.list_views()? {
+            let name = match self.engine.get_view(&schema, &view) {
+                Ok(Some(def)) => def.original_name.clone(),
+                _ => view,
+            };
+            tables.push((schema, name, "v", Vec::new()));
+        }
+        tables.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+
+        // Built-in namespaces first, then user schemas in name order
+        let mut namespaces = vec![
+            (PG_CATALOG_NAMESPACE, "pg_catalog".to_string()),
+            (PUBLIC_NAMESPACE, "public".to_string()),
+            (
+                INFORMATION_SCHEMA_NAMESPACE,
+                "information_schema".to_string(),
+            ),
+        ];
+        let user_schemas: BTreeSet<&str> = tables
+            .iter()
+            .map(|t| t.0.as_str())
+            .filter(|s| !namespaces.iter().any(|(_, n)| n == s))
+            .collect();
+        let mut next_oid = FIRST_USER_OID;
+        for schema in user_schemas {
+            namespaces.push((next_oid, schema.to_string()));
+            next_oid += 1;
+        }
+
+        let relations = tables
+            .into_iter()
+            .map(|(schema, name, kind, columns)| {
+                let namespace = namespaces
+                    .iter()
+                    .find(|(_, n)| *n == schema)
+                    .map(|(oid, _)| *oid)
+                    .unwrap_or(PUBLIC_NAMESPACE);
+                let oid = next_oid;
+                next_oid += 1;
+                Relation {
+                    oid,
+                    namespace,
+                    name,
+                    kind,
+                    columns,
+                }
+            })
+            .collect();
+
+        Ok(Catalog {
+            namespaces,
+            relations,
+        })
+    }
+
+    /// Build pg_catalog.pg_namespace result
+    fn build_pg_namespace_result(&self) -> Result<Box<dyn QueryResult>> {
+        let catalog = self.load_pg_catalog()?;
+        let columns = vec![
+            "oid".to_string(),
+            "nspname".to_string(),
+            "nspowner".to_string(),
+        ];
+        let rows = catalog
+            .namespaces
+            .iter()
+            .map(|(oid, name)| {
+                Row::from_values(vec![
+                    Value::Integer(*oid),
+                    text(name),
+                    Value::Integer(OWNER_OID),
+                ])
+            })
+            .collect();
+        Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
+    }
+
+    /// Build pg_catalog.pg_class result (tables and views)
+    fn build_pg_class_result(&self) -> Result<Box<dyn QueryResult>> {
+        let catalog = self.load_pg_catalog()?;
+        let columns = vec![
+            "oid".to_string(),
+            "relname".to_string(),
+            "relnamespace".to_string(),
+            "relkind".to_string(),
+            "relowner".to_string(),
+            "relnatts".to_string(),
+        ];
+        let rows = catalog
+            .relations
+            .iter()
+            .map(|rel| {
+                Row::from_values(vec![
+                    Value::Integer(rel.oid),
+                    text(&rel.name),
+                    Value::Integer(rel.namespace),
+                    text(rel.kind),
+                    Value::Integer(OWNER_OID),
+                    Value::Integer(rel.columns.len() as i64),
+                ])
+            })
+            .collect();
+        Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
+    }
+
+    /// Build pg_catalog.pg_attribute result (table columns)
+    fn build_pg_attribute_result(&self) -> Result<Box<dyn QueryResult>> {
+        let catalog = self.load_pg_catalog()?;
+        let columns = vec![
+            "attrelid".to_string(),
+            "attname".to_string(),
+            "atttypid".to_string(),
+            "attnum".to_string(),
+            "attnotnull".to_string(),
+            "attisdropped".to_string(),
+        ];
+        let mut rows = Vec::new();
+        for rel in &catalog.relations {
+            for (pos, (name, data_type, not_null)) in rel.columns.iter().enumerate() {
+                rows.push(Row::from_values(vec![
+                    Value::Integer(rel.oid),
+                    text(name),
+                    Value::Integer(pg_type_oid(*data_type)),
+                    Value::Integer(pos as i64 + 1),
+                    Value::Boolean(*not_null),
+                    Value::Boolean(false),
+                ]));
+            }
+        }
+        Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
+    }
+}
+
+/// PostgreSQL type OID for an Oxibase type (unknown types map to text)
+fn pg_type_oid(data_type: DataType) -> i64 {
+    PG_TYPES
+        .iter()
+        .find(|(dt, ..)| *dt == data_type)
+        .map(|(_, oid, ..)| *oid)
+        .unwrap_or(25)
+}
+
+/// Build pg_catalog.pg_type result
+fn build_pg_type_result() -> Box<dyn QueryResult> {
+    let columns = vec![
+        "oid".to_string(),
+        "typname".to_string(),
+        "typnamespace".to_string(),
+        "typlen".to_string(),
+        "typtype".to_string(),
+    ];
+    let rows = PG_TYPES
+        .iter()
+        .map(|(_, oid, name, len)| {
+            Row::from_values(vec![
+                Value::Integer(*oid),
+                text(name),
+                Value::Integer(PG_CATALOG_NAMESPACE),
+                Value::Integer(*len),
+                text("b"),
+            ])
+        })
+        .collect();
+    Box::new(ExecutorMemoryResult::new(columns, rows))
+}
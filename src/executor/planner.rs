@@ -37,7 +37,6 @@ use crate::optimizer::{
     AccessMethod, BuildSide, CostEstimator, JoinAlgorithm, JoinStats, PlanCost,
 };
 use crate::parser::ast::Expression;
-use crate::storage::mvcc::engine::MVCCEngine;
 use crate::storage::mvcc::zonemap::{PruneStats, TableZoneMap};
 use crate::storage::statistics::{
     Histogram, HistogramOp, TableStats, SYS_COLUMN_STATS, SYS_TABLE_STATS,
@@ -47,7 +46,7 @@ use crate::storage::traits::{Engine, Table, Transaction};
 /// Query planner that integrates statistics-based optimization
 pub struct QueryPlanner {
     /// Reference to the storage engine for reading statistics
-    engine: Arc<MVCCEngine>,
+    engine: Arc<dyn Engine>,
     /// Cost estimator for evaluating access methods
     cost_estimator: CostEstimator,
     /// Cache of table statistics to avoid repeated lookups
@@ -133,7 +132,7 @@ pub struct JoinPlan {
 
 impl QueryPlanner {
     /// Create a new query planner
-    pub fn new(engine: Arc<MVCCEngine>) -> Self {
+    pub fn new(engine: Arc<dyn Engine>) -> Self {
         Self {
             engine,
             cost_estimator: CostEstimator::new(),
@@ -141,6 +140,11 @@ impl QueryPlanner {
         }
     }
 
+    /// Cost estimator used by this planner
+    pub fn cost_estimator(&self) -> &CostEstimator {
+        &self.cost_estimator
+    }
+
     /// Invalidate cached statistics for a table
     ///
     /// Call this after ANALYZE to ensure fresh statistics are used.
@@ -1214,7 +1218,8 @@ impl QueryPlanner {
             }
             // LIKE expression (standalone)
             Expression::Like(like_expr) => {
-                let is_negated = like_expr.operator.to_uppercase().contains("NOT");
+                let op_upper = like_expr.operator.to_uppercase();
+                let is_negated = op_upper.contains("NOT") || op_upper.starts_with('!');
                 let pattern_str = self.extract_string_value(&like_expr.pattern);
                 let base_sel = match pattern_str {
                     Some(p) if !p.starts_with('%') => 0.1,
@@ -1611,6 +1616,7 @@ impl QueryPlanner {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::storage::mvcc::engine::MVCCEngine;
 
     #[test]
     fn test_selectivity_estimation() {
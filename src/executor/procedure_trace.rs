@@ -0,0 +1,150 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Procedure traces (EXPLAIN CALL)
+//!
+//! A procedure reaches the database only through its [`SqlRunner`]: the
+//! `execute`/`query` host calls of script backends and the statements of
+//! PL/SQL bodies. `EXPLAIN CALL` runs the procedure with a runner that
+//! records each of those calls and its row count, then rolls the call back.
+//! `EXPLAIN ANALYZE CALL` records the same trace but keeps the changes.
+
+use std::sync::{Arc, Mutex};
+
+use crate::core::{Error, Result, Row, Value};
+use crate::functions::backends::SqlRunner;
+use crate::parser::ast::{CallStatement, Statement};
+use crate::storage::traits::QueryResult;
+
+use super::context::ExecutionContext;
+use super::result::ExecutorMemoryResult;
+use super::Executor;
+
+/// SQL runner that records what a procedure executes
+struct TracingRunner<'a> {
+    executor: &'a Executor,
+    /// Skip COMMIT so the whole call can be rolled back
+    dry_run: bool,
+    /// Statement text and row count (None for transaction control)
+    steps: Mutex<Vec<(String, Option<i64>)>>,
+}
+
+impl TracingRunner<'_> {
+    fn record(&self, statement: String, rows: Option<i64>) {
+        self.steps.lock().unwrap().push((statement, rows));
+    }
+
+    /// Record a statement result, buffering query rows so they can be
+    /// counted and still handed back to the procedure
+    fn record_result(
+        &self,
+        statement: String,
+        mut result: Box<dyn QueryResult>,
+    ) -> Result<Box<dyn QueryResult>> {
+        if result.columns().is_empty() {
+            self.record(statement, Some(result.rows_affected()));
+            return Ok(result);
+        }
+
+        let columns = result.columns().to_vec();
+        let mut rows = Vec::new();
+        while result.next() {
+            rows.push(result.take_row());
+        }
+        self.record(statement, Some(rows.len() as i64));
+        Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
+    }
+}
+
+impl SqlRunner for TracingRunner<'_> {
+    fn execute_query(&self, sql: &str) -> Result<Box<dyn QueryResult>> {
+        let result = self.executor.execute_query(sql)?;
+        self.record_result(sql.trim().to_string(), result)
+    }
+
+    fn execute_ast(&self, stmt: &Statement) -> Result<Box<dyn QueryResult>> {
+        let result = self.executor.execute_ast(stmt)?;
+        self.record_result(stmt.to_string(), result)
+    }
+
+    fn commit(&self) -> Result<()> {
+        self.record("COMMIT".to_string(), None);
+        if self.dry_run {
+            return Ok(());
+        }
+        self.executor.commit()
+    }
+
+    fn rollback(&self) -> Result<()> {
+        self.record("ROLLBACK".to_string(), None);
+        self.executor.rollback()
+    }
+
+    fn begin(&self) -> Result<()> {
+        self.record("BEGIN".to_string(), None);
+        self.executor.begin()
+    }
+}
+
+impl Executor {
+    /// Execute EXPLAIN [ANALYZE] CALL, returning one row per statement
+    /// the procedure ran
+    pub(crate) fn explain_call(
+        &self,
+        stmt: &CallStatement,
+        analyze: bool,
+        ctx: &ExecutionContext,
+    ) -> Result<Box<dyn QueryResult>> {
+        // A dry run can only undo the call if it owns the transaction
+        let in_explicit_tx = self
+            .active_transaction
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|tx| tx.is_explicit_tx);
+        if in_explicit_tx && !analyze {
+            return Err(Error::invalid_argument(
+                "EXPLAIN CALL cannot run inside an explicit transaction; use EXPLAIN ANALYZE CALL",
+            ));
+        }
+
+        let runner = TracingRunner {
+            executor: self,
+            dry_run: !analyze,
+            steps: Mutex::new(Vec::new()),
+        };
+        self.call_procedure(stmt, ctx, &runner, !analyze)?;
+
+        let columns = vec![
+            "step".to_string(),
+            "statement".to_string(),
+            "rows".to_string(),
+        ];
+        let rows = runner
+            .steps
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .enumerate()
+            .map(|(i, (statement, rows))| {
+                Row::from_values(vec![
+                    Value::Integer(i as i64 + 1),
+                    Value::Text(Arc::from(statement.as_str())),
+                    rows.map_or_else(Value::null_unknown, Value::Integer),
+                ])
+            })
+            .collect();
+        Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
+    }
+}
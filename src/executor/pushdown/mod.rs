@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -43,8 +44,9 @@
 
 mod rules;
 
-use crate::core::{Schema, Value};
+use crate::core::{DataType, Result, Schema, Value};
 use crate::executor::context::ExecutionContext;
+use crate::executor::utils::{extract_column_name, infix_to_operator};
 use crate::parser::ast::{self as ast};
 use crate::storage::expression::Expression as StorageExpr;
 
@@ -85,11 +87,38 @@ impl<'a> PushdownContext<'a> {
     }
 
     /// Coerce value to column type if known
-    pub fn coerce_to_column_type(&self, column: &str, value: Value) -> Value {
-        if let Some(col_type) = self.column_type(column) {
-            value.into_coerce_to_type(col_type)
-        } else {
-            value
+    ///
+    /// Returns None when the value has no exact equivalent in the column type
+    /// (e.g. 5.5 or 'abc' against an INTEGER column, or a number against a
+    /// TEXT column, which compares numerically). Such predicates are left to
+    /// the expression evaluator rather than pushed down with a lossy value.
+    pub fn coerce_to_column_type(&self, column: &str, value: Value) -> Option<Value> {
+        let col_type = match self.column_type(column) {
+            Some(t) => t,
+            None => return Some(value),
+        };
+        if value.is_null() || value.data_type() == col_type {
+            return Some(value.into_coerce_to_type(col_type));
+        }
+        match (col_type, &value) {
+            (DataType::Integer | DataType::Float, Value::Text(s)) => {
+                let number = s.trim().parse::<f64>().ok().filter(|f| f.is_finite())?;
+                let number = match s.trim().parse::<i64>() {
+                    Ok(i) => Value::Integer(i),
+                    Err(_) => Value::Float(number),
+                };
+                self.coerce_to_column_type(column, number)
+            }
+            (DataType::Integer, Value::Float(f)) => {
+                // i64::MAX as f64 rounds up to 2^63, which is out of range
+                let exact = f.fract() == 0.0 && *f >= i64::MIN as f64 && *f < i64::MAX as f64;
+                exact.then_some(Value::Integer(*f as i64))
+            }
+            (DataType::Text, Value::Integer(_) | Value::Float(_)) => None,
+            _ => {
+                let coerced = value.into_coerce_to_type(col_type);
+                (!coerced.is_null()).then_some(coerced)
+            }
         }
     }
 }
@@ -204,13 +233,18 @@ impl PushdownRegistry {
 
     /// Try to convert an expression, returning only the storage expression
     /// (for internal use in compound rules)
+    ///
+    /// Returns None unless the whole expression was pushed: OR, NOT and XOR
+    /// cannot keep a memory filter for part of one operand.
     pub(crate) fn convert_expr(
         &self,
         expr: &ast::Expression,
         ctx: &PushdownContext<'_>,
     ) -> Option<Box<dyn StorageExpr>> {
-        let (storage_expr, _) = self.try_pushdown_with_ctx(expr, ctx);
-        storage_expr
+        match self.try_pushdown_with_ctx(expr, ctx) {
+            (storage_expr, false) => storage_expr,
+            (_, true) => None,
+        }
     }
 }
 
@@ -233,6 +267,58 @@ pub fn try_pushdown(
     registry().try_pushdown(expr, schema, exec_ctx)
 }
 
+/// Reject comparisons between a numeric column and text that is not a number
+///
+/// The expression evaluator reports these per row, but a WHERE filter treats
+/// any evaluation error as "no match", so `WHERE int_col = 'abc'` is checked
+/// up front instead of silently returning nothing.
+pub fn check_comparison_types(
+    expr: &ast::Expression,
+    schema: &Schema,
+    exec_ctx: Option<&ExecutionContext>,
+) -> Result<()> {
+    let ctx = PushdownContext::new(schema, exec_ctx);
+    check_comparisons_with_ctx(expr, &ctx)
+}
+
+fn check_comparisons_with_ctx(expr: &ast::Expression, ctx: &PushdownContext<'_>) -> Result<()> {
+    let check_operand = |column: &ast::Expression, operand: &ast::Expression| -> Result<()> {
+        let col_type = match extract_column_name(column).and_then(|c| ctx.column_type(&c)) {
+            Some(t) if t.is_numeric() => t,
+            _ => return Ok(()),
+        };
+        match rules::extract_literal_with_ctx(operand, ctx) {
+            Some(text @ Value::Text(_)) => Value::Integer(0)
+                .into_coerce_to_type(col_type)
+                .compare(&text)
+                .map(|_| ()),
+            _ => Ok(()),
+        }
+    };
+
+    match expr {
+        ast::Expression::Infix(infix) => match infix.op_type {
+            ast::InfixOperator::And | ast::InfixOperator::Or | ast::InfixOperator::Xor => {
+                check_comparisons_with_ctx(&infix.left, ctx)?;
+                check_comparisons_with_ctx(&infix.right, ctx)
+            }
+            op if infix_to_operator(op).is_some() => {
+                check_operand(&infix.left, &infix.right)?;
+                check_operand(&infix.right, &infix.left)
+            }
+            _ => Ok(()),
+        },
+        ast::Expression::Prefix(prefix) if prefix.op_type == ast::PrefixOperator::Not => {
+            check_comparisons_with_ctx(&prefix.right, ctx)
+        }
+        ast::Expression::Between(between) => {
+            check_operand(&between.expr, &between.lower)?;
+            check_operand(&between.expr, &between.upper)
+        }
+        _ => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -377,6 +463,7 @@ mod tests {
             lower: Box::new(make_int(25)),
             upper: Box::new(make_int(35)),
             not: false,
+            symmetric: false,
         });
 
         let (storage_expr, needs_mem) = try_pushdown(&expr, &schema, None);
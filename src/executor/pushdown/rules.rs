@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -17,7 +18,7 @@
 //! Each rule handles a specific type of expression and converts it to
 //! a storage-layer expression when possible.
 
-use crate::core::Value;
+use crate::core::{DataType, Value};
 use crate::parser::ast::{self as ast, InfixOperator, PrefixOperator};
 use crate::storage::expression::{
     AndExpr, BetweenExpr, ComparisonExpr, ConstBoolExpr, Expression as StorageExpr, InListExpr,
@@ -34,7 +35,10 @@ use crate::executor::utils::{
 // =============================================================================
 
 /// Extract a literal value with context (handles parameters)
-fn extract_literal_with_ctx(expr: &ast::Expression, ctx: &PushdownContext<'_>) -> Option<Value> {
+pub(super) fn extract_literal_with_ctx(
+    expr: &ast::Expression,
+    ctx: &PushdownContext<'_>,
+) -> Option<Value> {
     // First try simple literal
     if let Some(value) = extract_literal_value(expr) {
         return Some(value);
@@ -69,7 +73,7 @@ fn extract_comparison_parts(
     // Try column on left, value on right
     if let Some(column) = extract_column_name(left) {
         if let Some(value) = extract_literal_with_ctx(right, ctx) {
-            let coerced = ctx.coerce_to_column_type(&column, value);
+            let coerced = ctx.coerce_to_column_type(&column, value)?;
             return Some((column, coerced, false)); // false = not flipped
         }
     }
@@ -77,7 +81,7 @@ fn extract_comparison_parts(
     // Try value on left, column on right (will need to flip operator)
     if let Some(column) = extract_column_name(right) {
         if let Some(value) = extract_literal_with_ctx(left, ctx) {
-            let coerced = ctx.coerce_to_column_type(&column, value);
+            let coerced = ctx.coerce_to_column_type(&column, value)?;
             return Some((column, coerced, true)); // true = flipped
         }
     }
@@ -322,14 +326,43 @@ impl PushdownRule for BetweenRule {
             None => return PushdownResult::CannotPush,
         };
 
-        let lower = match extract_literal_with_ctx(&between.lower, ctx) {
-            Some(v) => ctx.coerce_to_column_type(&column, v),
-            None => return PushdownResult::CannotPush,
+        let (lower, upper) = match (
+            extract_literal_with_ctx(&between.lower, ctx),
+            extract_literal_with_ctx(&between.upper, ctx),
+        ) {
+            (Some(l), Some(u)) => (l, u),
+            _ => return PushdownResult::CannotPush,
         };
 
-        let upper = match extract_literal_with_ctx(&between.upper, ctx) {
-            Some(v) => ctx.coerce_to_column_type(&column, v),
-            None => return PushdownResult::CannotPush,
+        // A NULL or NaN bound makes the predicate NULL or decided by the other
+        // bound alone (three-valued logic), which the storage filter can't express
+        let unordered = |v: &Value| v.is_null() || matches!(v, Value::Float(f) if f.is_nan());
+        if unordered(&lower) || unordered(&upper) {
+            return PushdownResult::CannotPush;
+        }
+
+        let (lower, upper) = if between.symmetric && lower > upper {
+            (upper, lower)
+        } else {
+            (lower, upper)
+        };
+
+        // Round fractional bounds inward before coercing them to an integer
+        // column, which would otherwise truncate them
+        let (lower, upper) = match (ctx.column_type(&column), &lower, &upper) {
+            (Some(DataType::Integer), Value::Float(l), Value::Float(u)) => {
+                (Value::Float(l.ceil()), Value::Float(u.floor()))
+            }
+            (Some(DataType::Integer), Value::Float(l), _) => (Value::Float(l.ceil()), upper),
+            (Some(DataType::Integer), _, Value::Float(u)) => (lower, Value::Float(u.floor())),
+            _ => (lower, upper),
+        };
+        let (lower, upper) = match (
+            ctx.coerce_to_column_type(&column, lower),
+            ctx.coerce_to_column_type(&column, upper),
+        ) {
+            (Some(l), Some(u)) => (l, u),
+            _ => return PushdownResult::CannotPush,
         };
 
         let mut expr = if between.not {
@@ -370,10 +403,14 @@ impl PushdownRule for InListRule {
         };
 
         // Coerce values to column type
-        let coerced_values: Vec<Value> = values
+        let coerced_values: Vec<Value> = match values
             .into_iter()
             .map(|v| ctx.coerce_to_column_type(&column, v))
-            .collect();
+            .collect()
+        {
+            Some(v) => v,
+            None => return PushdownResult::CannotPush,
+        };
 
         let mut expr = if in_expr.not {
             InListExpr::not_in(column, coerced_values)
@@ -415,9 +452,14 @@ impl LikeRule {
         // Parse operator to determine type
         let op_upper = like.operator.to_uppercase();
 
-        // GLOB and REGEXP use different pattern matching semantics than LIKE
+        // GLOB, REGEXP, SIMILAR TO and ~ use different pattern matching semantics than LIKE
         // They must be evaluated in memory by the expression VM
-        if op_upper.contains("GLOB") || op_upper.contains("REGEXP") || op_upper.contains("RLIKE") {
+        if op_upper.contains("GLOB")
+            || op_upper.contains("REGEXP")
+            || op_upper.contains("RLIKE")
+            || op_upper.contains("SIMILAR")
+            || op_upper.contains('~')
+        {
             return PushdownResult::CannotPush;
         }
 
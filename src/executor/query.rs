@@ -30,12 +30,12 @@ use std::sync::Arc;
 
 use rustc_hash::{FxHashMap, FxHashSet};
 
-use crate::core::{Error, Result, Row, Value};
+use crate::core::{Error, Result, Row, Value, ViewDefinition};
+use crate::functions::tvf::TableValuedFunction;
 use crate::optimizer::ExpressionSimplifier;
 use crate::parser::ast::*;
 use crate::parser::token::{Position, Token, TokenType};
-use crate::storage::mvcc::engine::ViewDefinition;
-use crate::storage::traits::{Engine, QueryResult};
+use crate::storage::traits::QueryResult;
 
 /// Maximum depth for nested views to prevent stack overflow
 const MAX_VIEW_DEPTH: usize = 32;
@@ -49,22 +49,47 @@ use super::context::{
 use super::expression::{CompiledEvaluator, ExpressionEval, JoinFilter, RowFilter};
 use super::join::{self, build_column_index_map};
 use super::parallel::{self, ParallelConfig};
+use super::pg_catalog::pg_catalog_table;
 use super::pushdown;
 use super::result::{
     DistinctResult, ExecResult, ExecutorMemoryResult, ExprFilteredResult, ExprMappedResult,
-    FilteredResult, LimitedResult, OrderedResult, ProjectedResult, RadixOrderSpec, ScannerResult,
-    StreamingProjectionResult, TopNResult,
+    FilteredResult, LimitedResult, OrderedResult, OrdinalResult, ProjectedResult, RadixOrderSpec,
+    ScannerResult, StreamingProjectionResult, TopNResult,
 };
+use super::unnest::has_select_unnest;
 use super::utils::{
-    add_table_qualifier, collect_table_qualifiers, combine_predicates_with_and, dummy_token,
-    expression_contains_aggregate, expression_has_parameters, extract_base_column_name,
-    flatten_and_predicates, get_table_alias_from_expr, strip_table_qualifier,
+    add_table_qualifier, collect_column_names, collect_table_qualifiers,
+    combine_predicates_with_and, dummy_token, expression_contains_aggregate,
+    expression_has_parameters, extract_base_column_name, flatten_and_predicates,
+    get_table_alias_from_expr, strip_table_qualifier, validate_aggregate_filters,
 };
 use super::window::{WindowPreGroupedState, WindowPreSortedState};
 use super::Executor;
 use crate::optimizer::aqe::{decide_join_algorithm, AqeJoinDecision, JoinAqeContext};
 use crate::optimizer::{BuildSide, JoinAlgorithm};
 
+/// An ORDER BY served by reading an index in order
+pub(crate) struct OrderedIndexScan {
+    /// Index providing the order
+    pub index_name: String,
+    /// Schema names of the ORDER BY columns, a prefix of the index columns
+    pub columns: Vec<String>,
+    /// Direction shared by all ORDER BY terms
+    pub ascending: bool,
+    /// Requested NULL placement of the first column
+    pub nulls_first: bool,
+}
+
+/// A single-table SELECT answered from a covering index
+pub(crate) struct IndexOnlyScan {
+    /// Schema positions of every column the query reads
+    pub columns: Vec<usize>,
+    /// Schema positions of the SELECT list
+    pub projection: Vec<usize>,
+    /// Output column names
+    pub output_columns: Vec<String>,
+}
+
 /// Pre-computed column name mappings for correlated subqueries.
 /// Avoids repeated string allocations per row in the inner loop.
 struct ColumnKeyMapping {
@@ -152,11 +177,25 @@ fn partition_where_for_join(
 }
 
 impl Executor {
-    /// Execute a SELECT statement
+    /// Execute a CALL statement
     pub(crate) fn execute_call(
         &self,
         stmt: &crate::parser::ast::CallStatement,
         ctx: &crate::executor::context::ExecutionContext,
+    ) -> crate::core::Result<Box<dyn crate::storage::traits::QueryResult>> {
+        self.call_procedure(stmt, ctx, self, false)
+    }
+
+    /// Run a procedure with `runner` serving its SQL and transaction calls
+    ///
+    /// With `dry_run` the implicit transaction the call runs in is rolled
+    /// back instead of committed.
+    pub(crate) fn call_procedure(
+        &self,
+        stmt: &crate::parser::ast::CallStatement,
+        ctx: &crate::executor::context::ExecutionContext,
+        runner: &dyn crate::functions::backends::SqlRunner,
+        dry_run: bool,
     ) -> crate::core::Result<Box<dyn crate::storage::traits::QueryResult>> {
         let procedure_name_upper = stmt.procedure_name.function().to_uppercase();
 
@@ -213,6 +252,7 @@ impl Executor {
                     tables: rustc_hash::FxHashMap::default(),
                     ddl_undo_log: Vec::new(),
                     is_explicit_tx: false,
+                    ran_query: false,
                 });
                 true
             } else {
@@ -222,28 +262,27 @@ impl Executor {
 
         crate::functions::context::set_current_procedure_name(Some(procedure_name_upper.clone()));
 
-        let result = crate::functions::backends::with_sql_runner(
-            Some(self as &dyn crate::functions::backends::SqlRunner),
-            || {
+        let result = crate::functions::backends::with_sql_runner(Some(runner), || {
+            crate::functions::backends::pool::with_script_worker(|| {
                 backend.execute_procedure(
                     &procedure.code,
                     &mut evaluated_args,
                     &param_names,
                     &modes,
-                    Some(self),
+                    Some(runner),
                 )
-            },
-        );
+            })
+        });
 
         crate::functions::context::set_current_procedure_name(None);
 
         if started_implicit_tx {
             let mut active_tx = self.active_transaction.lock().unwrap();
             if let Some(mut tx_state) = active_tx.take() {
-                if result.is_ok() {
+                if result.is_ok() && !dry_run {
                     let _ = tx_state.transaction.commit();
                 } else {
-                    let _ = tx_state.transaction.rollback();
+                    let _ = self.rollback_transaction_state(tx_state);
                 }
             }
         }
@@ -270,10 +309,80 @@ impl Executor {
         }
     }
 
+    /// Add `ORDER BY <primary key>` to a top-level SELECT that has no ORDER
+    /// BY, under `SET implicit_order = pk`
+    ///
+    /// Only single-table selects without DISTINCT, grouping, aggregates or
+    /// set operations are rewritten; None means the statement runs as is.
+    pub(crate) fn with_implicit_order(&self, stmt: &SelectStatement) -> Option<SelectStatement> {
+        if !self.implicit_pk_order()
+            || !stmt.order_by.is_empty()
+            || !stmt.set_operations.is_empty()
+            || stmt.with.is_some()
+            || stmt.distinct
+            || self.has_aggregation(stmt)
+        {
+            return None;
+        }
+        let Some(Expression::TableSource(source)) = stmt.table_expr.as_deref() else {
+            return None;
+        };
+
+        let name = self
+            .resolve_search_path(&source.name)
+            .unwrap_or_else(|| source.name.clone());
+        let schema = self.engine.get_table_schema(&name.value_lower()).ok()?;
+        let pk_columns = schema.primary_key_column_names();
+        if pk_columns.is_empty() {
+            return None;
+        }
+
+        let order_by = pk_columns
+            .iter()
+            .map(|col| OrderByExpression {
+                expression: Expression::Identifier(Identifier::new(
+                    source.token.clone(),
+                    col.to_string(),
+                )),
+                ascending: true,
+                nulls_first: None,
+            })
+            .collect();
+
+        Some(SelectStatement {
+            order_by,
+            ..stmt.clone()
+        })
+    }
+
     pub(crate) fn execute_select(
         &self,
         stmt: &SelectStatement,
         ctx: &ExecutionContext,
+    ) -> Result<Box<dyn QueryResult>> {
+        if let Some(mode) = stmt.lock {
+            self.lock_selected_rows(stmt, mode, ctx)?;
+        }
+        let result = self.execute_select_unnumbered(stmt, ctx)?;
+
+        // ORDINAL() numbers the rows as they leave the query, after
+        // ORDER BY and LIMIT/OFFSET
+        let positions = ordinal_positions(stmt, result.columns().len());
+        if positions.is_empty() {
+            Ok(result)
+        } else {
+            Ok(Box::new(OrdinalResult::new(result, positions)))
+        }
+    }
+
+    /// Execute a SELECT statement, leaving ORDINAL() columns unnumbered
+    ///
+    /// Used for the branches of a set operation, which are numbered once
+    /// the combined result is assembled.
+    pub(crate) fn execute_select_unnumbered(
+        &self,
+        stmt: &SelectStatement,
+        ctx: &ExecutionContext,
     ) -> Result<Box<dyn QueryResult>> {
         // Start timeout guard ONLY at the top level (query_depth == 0).
         // For nested queries (subqueries, views), the parent's TimeoutGuard handles timeout.
@@ -305,6 +414,19 @@ impl Executor {
             }
         }
 
+        // Validate: FILTER clauses only apply to aggregates and cannot nest aggregates
+        for col in &stmt.columns {
+            validate_aggregate_filters(col)?;
+        }
+        if let Some(ref having) = stmt.having {
+            validate_aggregate_filters(having)?;
+        }
+
+        // Set-returning UNNEST in the select list expands the rows of the query
+        if has_select_unnest(stmt) {
+            return self.execute_select_with_unnest(stmt, ctx);
+        }
+
         // Check for CTEs (WITH clause)
         if self.has_cte(stmt) {
             return self.execute_select_with_ctes(stmt, ctx);
@@ -382,6 +504,16 @@ impl Executor {
             0
         };
 
+        // Optimizer hints apply to this query block only
+        let hints = self.resolve_hints(stmt);
+        let hinted_ctx;
+        let ctx = if hints.is_some() || ctx.hints().is_some() {
+            hinted_ctx = ctx.with_hints(hints);
+            &hinted_ctx
+        } else {
+            ctx
+        };
+
         // Execute the main query
         // The third return value indicates if LIMIT/OFFSET was already applied (by storage-level pushdown)
         let (mut result, columns, limit_offset_applied) =
@@ -931,6 +1063,21 @@ impl Executor {
                     return self.execute_query_on_memory_result(stmt, ctx, columns, rows);
                 }
 
+                // Check if this is a pg_catalog compatibility table
+                if let Some(pg_table) = pg_catalog_table(table_source) {
+                    let mut result = self.execute_pg_catalog_table(&pg_table)?;
+                    let columns = result.columns().to_vec();
+                    let mut rows = Vec::new();
+                    while result.next() {
+                        rows.push(result.take_row());
+                    }
+                    return self.execute_query_on_memory_result(stmt, ctx, columns, rows);
+                }
+
+                // Unqualified names resolve along SET search_path
+                let resolved = self.resolve_search_path_source(table_source);
+                let table_source = resolved.as_ref().unwrap_or(table_source);
+
                 // Check if this is actually a view (single lookup, no double RwLock acquisition)
                 let schema_name = table_source
                     .name
@@ -960,7 +1107,7 @@ impl Executor {
         }
     }
 
-    /// Execute SELECT without FROM (expressions only)
+    /// Execute a SELECT whose FROM is a table-valued function
     fn execute_tvf_source(
         &self,
         tvf_source: &FunctionTableSource,
@@ -1006,6 +1153,7 @@ impl Executor {
 
         let (result_rows, columns) =
             Self::evaluate_tvf_with_range(tvf_source, ctx, limit_hint, range_hint)?;
+        ctx.check_cancelled()?;
 
         self.execute_query_on_memory_result(stmt, ctx, columns, result_rows)
     }
@@ -1105,21 +1253,49 @@ impl Executor {
             }
         }
 
-        let result_rows = tvf.generate(&arg_values, limit)?;
+        Self::tvf_output(tvf_source, tvf.as_ref(), &arg_values, limit)
+    }
 
-        let columns: Vec<String> = if !tvf_source.column_aliases.is_empty() {
+    /// Generate a table function's rows for evaluated arguments, with the
+    /// ordinality column and output column names
+    fn tvf_output(
+        tvf_source: &FunctionTableSource,
+        tvf: &dyn TableValuedFunction,
+        arg_values: &[Value],
+        limit: Option<usize>,
+    ) -> Result<(Vec<Row>, Vec<String>)> {
+        let mut result_rows = tvf.generate(arg_values, limit)?;
+
+        if tvf_source.with_ordinality {
+            for (i, row) in result_rows.iter_mut().enumerate() {
+                row.push(Value::Integer(i as i64 + 1));
+            }
+        }
+
+        Ok((result_rows, Self::tvf_columns(tvf_source, tvf)))
+    }
+
+    /// Output column names of a table function source
+    fn tvf_columns(tvf_source: &FunctionTableSource, tvf: &dyn TableValuedFunction) -> Vec<String> {
+        if !tvf_source.column_aliases.is_empty() {
             tvf_source
                 .column_aliases
                 .iter()
                 .map(|id| id.value.to_string())
                 .collect()
         } else {
-            tvf.column_names()
-        };
-
-        Ok((result_rows, columns))
+            let mut columns = tvf.column_names();
+            if tvf_source.with_ordinality {
+                columns.push("ordinality".to_string());
+            }
+            columns
+        }
     }
 
+    /// Execute SELECT without FROM (expressions only)
+    ///
+    /// Each select item is evaluated once into a single row; unaliased items
+    /// are named column1, column2, ...
     fn execute_expression_select(
         &self,
         stmt: &SelectStatement,
@@ -1306,12 +1482,33 @@ impl Executor {
         // OPTIMIZATION: Use pre-computed lowercase name to avoid allocation per query
         let table_name = &table_source.name.value_lower();
 
+        // Tables of an attached database are read through a transaction of
+        // their own engine, outside the session's transaction
+        let attached_engine = self.attached_engine(&table_source.name);
+
         // Check if there's an active explicit transaction
         let active_tx = self.active_transaction.lock().unwrap();
         let in_explicit_transaction = active_tx.is_some();
 
         // Get table from active transaction or create a new one
-        let (table, _standalone_tx) = if let Some(ref tx_state) = *active_tx {
+        let (table, _standalone_tx) = if let Some(engine) = attached_engine.as_ref() {
+            drop(active_tx);
+            let tx = engine.begin_transaction()?;
+            let local_name = table_source.name.table().to_lowercase();
+
+            if let Some(ref as_of) = table_source.as_of {
+                return self.execute_temporal_query(&local_name, as_of, stmt, ctx, &*tx);
+            }
+
+            let table = tx.get_table(&local_name).map_err(|e| {
+                if matches!(e, Error::TableNotFound) {
+                    Error::TableOrViewNotFound(table_name.to_string())
+                } else {
+                    e
+                }
+            })?;
+            (table, Some(tx))
+        } else if let Some(ref tx_state) = *active_tx {
             // Use the active transaction - this allows seeing uncommitted changes
             let table = tx_state.transaction.get_table(table_name).map_err(|e| {
                 if matches!(e, Error::TableNotFound) {
@@ -1341,6 +1538,10 @@ impl Executor {
             })?;
             (table, Some(tx))
         };
+        let mut table = table;
+        if let Some(hints) = ctx.hints() {
+            table.set_index_hint(hints.index_hint(table_source));
+        }
 
         // Build column list from schema (using cached version to avoid repeated clones)
         let all_columns: Vec<String> = table.schema().column_names_owned().to_vec();
@@ -1400,6 +1601,10 @@ impl Executor {
         // If we simplified, borrow from the simplified version
         let where_to_use: Option<&Expression> = where_to_use.or(simplified_where.as_ref());
 
+        if let Some(where_expr) = where_to_use {
+            pushdown::check_comparison_types(where_expr, table.schema(), Some(ctx))?;
+        }
+
         // Check if this query might reference outer columns (correlated)
         let has_outer_context = ctx.outer_row().is_some();
 
@@ -1441,6 +1646,7 @@ impl Executor {
             && stmt.order_by.is_empty()
             && !stmt.distinct
             && stmt.limit.is_none()
+            && attached_engine.is_none() // Writes to it never invalidate our cache
             && !in_explicit_transaction; // MVCC safety: no caching in transactions
 
         // Try cache lookup for eligible queries
@@ -1515,6 +1721,22 @@ impl Executor {
             (None, false)
         };
 
+        // Remember filter and sort columns that only a full scan could serve,
+        // for SHOW INDEX SUGGESTIONS
+        if attached_engine.is_none()
+            && !has_outer_context
+            && (where_to_use.is_some() || !stmt.order_by.is_empty())
+        {
+            self.record_scan_for_index_advisor(
+                table_name,
+                &*table,
+                stmt,
+                where_to_use,
+                storage_expr.as_deref(),
+                ctx,
+            );
+        }
+
         // ZONE MAP PRUNING: Short-circuit if zone maps indicate no rows can match
         // This checks min/max statistics per segment to skip entire scan when
         // the WHERE clause predicates are outside all segment ranges
@@ -1565,15 +1787,7 @@ impl Executor {
         // FAST PATH: ORDER BY + LIMIT optimization (TOP-N)
         // For queries like `SELECT * FROM table ORDER BY indexed_col LIMIT 10`,
         // use index to get rows in sorted order directly, avoiding full table sort
-        if stmt.limit.is_some()
-            && stmt.order_by.len() == 1
-            && stmt.group_by.columns.is_empty()
-            && !self.has_aggregation(stmt)
-            && !self.has_window_functions(stmt)
-            && !self.has_distinct(stmt)
-            && storage_expr.is_none()
-            && !needs_memory_filter
-        {
+        if storage_expr.is_none() && !needs_memory_filter {
             if let Some((result, columns)) =
                 self.try_order_by_index_optimization(stmt, &*table, &all_columns, ctx)?
             {
@@ -1582,6 +1796,35 @@ impl Executor {
             }
         }
 
+        // FAST PATH: Index-only scan
+        // For queries like `SELECT email FROM users WHERE email = $1` where one index
+        // stores every column the query reads, take the values from the index and
+        // skip fetching the base rows
+        if let (Some(filter), Some(where_expr)) = (storage_expr.as_deref(), where_to_use) {
+            if !needs_memory_filter {
+                if let Some(plan) = self.plan_index_only_scan(stmt, where_expr, &all_columns) {
+                    if let Some(rows) = table.collect_rows_index_only(filter, &plan.columns) {
+                        let projected_rows = rows
+                            .into_iter()
+                            .map(|row| {
+                                Row::from_values(
+                                    plan.projection
+                                        .iter()
+                                        .map(|&idx| {
+                                            row.get(idx).cloned().unwrap_or(Value::null_unknown())
+                                        })
+                                        .collect(),
+                                )
+                            })
+                            .collect();
+                        let result =
+                            ExecutorMemoryResult::new(plan.output_columns.clone(), projected_rows);
+                        return Ok((Box::new(result), plan.output_columns, false));
+                    }
+                }
+            }
+        }
+
         // FAST PATH: IN subquery index optimization
         // For queries like `SELECT * FROM table WHERE id IN (SELECT col FROM other_table WHERE ...)`
         // where 'id' has an index or is PRIMARY KEY, probe directly instead of scanning all rows
@@ -1740,9 +1983,11 @@ impl Executor {
                 let column_idx_vec: Vec<usize> = (0..all_columns.len()).collect();
                 let scanner = table.scan(&column_idx_vec, storage_expr.as_deref())?;
 
-                // Wrap scanner in ScannerResult
-                let mut result: Box<dyn QueryResult> =
-                    Box::new(ScannerResult::new(scanner, all_columns.clone()));
+                // Wrap scanner in ScannerResult; rows are read after the
+                // statement returns, so the scan itself watches for cancellation
+                let mut result: Box<dyn QueryResult> = Box::new(
+                    ScannerResult::new(scanner, all_columns.clone()).with_cancellation(ctx),
+                );
 
                 // If we need memory filtering (complex WHERE that couldn't be pushed down)
                 if needs_memory_filter {
@@ -2451,6 +2696,17 @@ impl Executor {
         stmt: &SelectStatement,
         ctx: &ExecutionContext,
     ) -> Result<(Box<dyn QueryResult>, Vec<String>, bool)> {
+        // A table function reading columns of the left side runs once per left row
+        if let Expression::FunctionTableSource(tvf_source) = join_source.right.as_ref() {
+            if tvf_source
+                .arguments
+                .iter()
+                .any(|arg| !collect_column_names(arg).is_empty())
+            {
+                return self.execute_lateral_tvf_join(join_source, tvf_source, stmt, ctx);
+            }
+        }
+
         // Get table aliases for filter pushdown
         let left_alias = get_table_alias_from_expr(&join_source.left);
         let right_alias = get_table_alias_from_expr(&join_source.right);
@@ -2458,6 +2714,14 @@ impl Executor {
         // Determine join type early for filter pushdown decisions
         let join_type = join_source.join_type.to_uppercase();
 
+        // Join method and LEADING hints for this join
+        let hinted_method = ctx
+            .hints()
+            .and_then(|h| h.join_method(&join_source.left, &join_source.right));
+        let right_leads = ctx
+            .hints()
+            .and_then(|h| h.right_leads(&join_source.left, &join_source.right));
+
         // Partition WHERE clause predicates for pushdown
         // Note: For OUTER JOINs, we must be careful:
         // - LEFT JOIN: Can push filters to left (preserved), but NOT to right (may have NULLs)
@@ -2583,7 +2847,15 @@ impl Executor {
                 // This optimization avoids materializing the right side entirely
                 // NOTE: Don't use Index NL for aggregation/window queries - they need full results
                 // and the current implementation falls through to standard path, causing double execution
-                let index_nl_info = if has_agg || has_window {
+                // A HASH_JOIN or MERGE_JOIN hint rules out Index Nested Loop
+                let index_nl_info = if has_agg
+                    || has_window
+                    || matches!(
+                        hinted_method,
+                        Some(JoinMethodHint::Hash | JoinMethodHint::Merge)
+                    )
+                    || right_leads == Some(true)
+                {
                     None
                 } else {
                     self.check_index_nested_loop_opportunity(
@@ -2592,6 +2864,7 @@ impl Executor {
                         &join_type,
                         left_alias.as_deref(),
                         right_alias.as_deref(),
+                        ctx,
                     )
                 };
 
@@ -2599,11 +2872,18 @@ impl Executor {
                 // When one side has a filter, prefer putting filtered side as outer (left)
                 // This reduces the number of probes into the inner table.
                 // Swap if: right has filter, left doesn't, and swapped order gives Index NL on PK
+                // A LEADING hint naming the right table also asks for the swap.
                 let (index_nl_info, nl_left_filter, nl_right_filter, swapped) = if !has_agg
-                        && !has_window
-                        && join_type == "INNER"
-                        && right_filter.is_some()  // Right side has a filter
-                        && left_filter.is_none()
+                    && !has_window
+                    && join_type == "INNER"
+                    && !matches!(
+                        hinted_method,
+                        Some(JoinMethodHint::Hash | JoinMethodHint::Merge)
+                    )
+                    && (right_leads == Some(true)
+                        || (right_leads.is_none()
+                            && right_filter.is_some() // Right side has a filter
+                            && left_filter.is_none()))
                 // Left side doesn't have a filter
                 {
                     // Check if swapping gives Index NL opportunity with PK lookup
@@ -2614,13 +2894,15 @@ impl Executor {
                         &join_type,
                         right_alias.as_deref(), // Swap aliases
                         left_alias.as_deref(),
+                        ctx,
                     );
 
-                    // Prefer swapped if it gives PK lookup (most efficient)
+                    // Prefer swapped if it gives PK lookup (most efficient),
+                    // or any index lookup when LEADING asked for it
                     let prefer_swap = matches!(
                         &swapped_info,
                         Some((_, join::IndexLookupStrategy::PrimaryKey, _, _))
-                    );
+                    ) || (right_leads == Some(true) && swapped_info.is_some());
 
                     if prefer_swap {
                         // Swap: right filter becomes outer filter
@@ -2739,20 +3021,27 @@ impl Executor {
                             (all, outer_cols.clone(), inner_cols.clone())
                         };
 
-                        // Create residual filter from nl_right_filter if present
+                        // Create residual filter from nl_right_filter and any ON terms
+                        // beyond the lookup key (e.g. ON t.id = u.id AND u.active)
                         // This allows early termination during Index Nested Loop
-                        let residual_filter = if let Some(ref rf) = nl_right_filter {
+                        let mut residual_preds = Vec::new();
+                        if let Some(ref rf) = nl_right_filter {
                             // Re-qualify the filter with inner table alias
-                            let qualified_rf = add_table_qualifier(rf, inner_alias);
-                            JoinFilter::new(
-                                &qualified_rf,
+                            residual_preds.push(add_table_qualifier(rf, inner_alias));
+                        }
+                        if let Some(Expression::Infix(on)) = join_source.condition.as_deref() {
+                            if on.op_type == InfixOperator::And {
+                                residual_preds.push(Expression::Infix(on.clone()));
+                            }
+                        }
+                        let residual_filter = match combine_predicates_with_and(residual_preds) {
+                            Some(pred) => Some(JoinFilter::new(
+                                &pred,
                                 &outer_cols,
                                 &inner_cols,
                                 &self.function_registry,
-                            )
-                            .ok()
-                        } else {
-                            None
+                            )?),
+                            None => None,
                         };
 
                         // Execute Index Nested Loop Join with residual filter
@@ -2765,6 +3054,7 @@ impl Executor {
                             &join_type,
                             outer_cols.len(),
                             inner_cols.len(),
+                            ctx,
                             join_limit,
                         )?;
 
@@ -3074,7 +3364,7 @@ impl Executor {
                 right_rows.len() as u64,
             );
 
-            let join_decision = self.get_query_planner().plan_runtime_join_with_sort_info(
+            let mut join_decision = self.get_query_planner().plan_runtime_join_with_sort_info(
                 left_rows.len(),
                 right_rows.len(),
                 has_equality_keys,
@@ -3082,6 +3372,46 @@ impl Executor {
                 right_sorted,
             );
 
+            // A join method hint overrides the planner when the join allows it
+            let mut method_hinted = false;
+            if let Some(method) = hinted_method {
+                if method != JoinMethodHint::NestedLoop && !has_equality_keys {
+                    self.warn(format!(
+                        "optimizer hint {} ignored: join has no equality condition",
+                        method
+                    ));
+                } else {
+                    join_decision.algorithm = match method {
+                        JoinMethodHint::Hash => RuntimeJoinAlgorithm::HashJoin,
+                        JoinMethodHint::Merge => RuntimeJoinAlgorithm::MergeJoin,
+                        JoinMethodHint::NestedLoop => RuntimeJoinAlgorithm::NestedLoop,
+                    };
+                    join_decision.explanation = format!("{} (hint)", method);
+                    method_hinted = true;
+                }
+            }
+            if right_leads == Some(true) {
+                self.warn(format!(
+                    "optimizer hint LEADING ignored: no index on the join key of {}",
+                    join_source.left
+                ));
+            }
+
+            // Merge join needs both inputs sorted on the join keys
+            let (left_rows, right_rows) =
+                if join_decision.use_merge_join() && !(left_sorted && right_sorted) {
+                    let sort_on = |mut rows: Vec<Row>, keys: &[usize]| {
+                        rows.sort_by(|a, b| join::compare_composite_keys(a, b, keys, keys));
+                        rows
+                    };
+                    (
+                        sort_on(left_rows, &left_key_indices),
+                        sort_on(right_rows, &right_key_indices),
+                    )
+                } else {
+                    (left_rows, right_rows)
+                };
+
             // Convert planner's runtime algorithm to optimizer's JoinAlgorithm for AQE
             use crate::executor::planner::RuntimeJoinAlgorithm;
             let planned_algorithm = match join_decision.algorithm {
@@ -3127,8 +3457,9 @@ impl Executor {
             // Let AQE decide if we should switch algorithms based on estimation error
             let aqe_decision = decide_join_algorithm(&aqe_context);
 
-            // Determine final algorithm based on AQE decision
+            // Determine final algorithm based on AQE decision (a hint is final)
             let final_algorithm = match aqe_decision {
+                _ if method_hinted => join_decision.algorithm,
                 AqeJoinDecision::KeepPlanned => join_decision.algorithm,
                 AqeJoinDecision::SwitchToHashJoin { build_side: _ } => {
                     // AQE recommends hash join (build side handled by execute_hash_join)
@@ -3188,6 +3519,7 @@ impl Executor {
                         &join_type,
                         left_columns.len(),
                         right_columns.len(),
+                        ctx,
                         join_limit,
                     )?;
 
@@ -3218,6 +3550,7 @@ impl Executor {
                         &join_type,
                         left_columns.len(),
                         right_columns.len(),
+                        ctx,
                     )?;
 
                     // Apply residual conditions
@@ -3288,9 +3621,6 @@ impl Executor {
             )?
         };
 
-        // Build alias map for alias substitution
-        let alias_map = Self::build_alias_map(&stmt.columns);
-
         // Apply remaining WHERE clause if present (after filter pushdown)
         // IMPORTANT: When predicates were pushed to left/right (left_filter or right_filter is Some),
         // we should ONLY apply cross_filter (predicates that reference both tables).
@@ -3305,6 +3635,36 @@ impl Executor {
             stmt.where_clause.as_ref().map(|wc| (**wc).clone())
         };
 
+        self.finish_join(
+            stmt,
+            ctx,
+            result_rows,
+            all_columns,
+            effective_where,
+            &excluded_column_indices,
+            &join_col_renames,
+        )
+    }
+
+    /// Filter joined rows by what is left of WHERE, then aggregate, window
+    /// and project them for the SELECT
+    ///
+    /// `excluded_column_indices` and `join_col_renames` drop and rename the
+    /// duplicate columns of NATURAL JOIN and JOIN USING under `SELECT *`.
+    #[allow(clippy::too_many_arguments)]
+    fn finish_join(
+        &self,
+        stmt: &SelectStatement,
+        ctx: &ExecutionContext,
+        result_rows: Vec<Row>,
+        all_columns: Vec<String>,
+        effective_where: Option<Expression>,
+        excluded_column_indices: &[usize],
+        join_col_renames: &[(usize, String)],
+    ) -> Result<(Box<dyn QueryResult>, Vec<String>, bool)> {
+        // Build alias map for alias substitution
+        let alias_map = Self::build_alias_map(&stmt.columns);
+
         let resolved_where_clause = if !alias_map.is_empty() {
             effective_where
                 .as_ref()
@@ -3432,6 +3792,98 @@ impl Executor {
         Ok((Box::new(result), output_columns, false))
     }
 
+    /// Join a table function whose arguments read columns of the left side,
+    /// as in `FROM orders o, JSON_TABLE(o.doc, ...) j`
+    ///
+    /// The function runs once per left row with that row's values. A LEFT
+    /// JOIN keeps left rows for which it returns no matching rows.
+    fn execute_lateral_tvf_join(
+        &self,
+        join_source: &JoinTableSource,
+        tvf_source: &FunctionTableSource,
+        stmt: &SelectStatement,
+        ctx: &ExecutionContext,
+    ) -> Result<(Box<dyn QueryResult>, Vec<String>, bool)> {
+        use crate::functions::global_registry;
+
+        let join_type = join_source.join_type.to_uppercase();
+        if join_type.contains("RIGHT") || join_type.contains("FULL") {
+            return Err(Error::NotSupportedMessage(format!(
+                "{} JOIN with a table function that reads columns of the left side",
+                join_type
+            )));
+        }
+        let keep_unmatched = join_type.contains("LEFT");
+
+        let func_name = &tvf_source.function.value;
+        let tvf = global_registry().get_tvf(func_name).ok_or_else(|| {
+            Error::NotSupportedMessage(format!("Unknown table-valued function: {}", func_name))
+        })?;
+
+        let (left_result, left_columns) = self.execute_table_expression(&join_source.left, ctx)?;
+        let left_rows = Self::materialize_result(left_result)?;
+
+        let mut arg_evals = tvf_source
+            .arguments
+            .iter()
+            .map(|arg| Ok(ExpressionEval::compile(arg, &left_columns)?.with_context(ctx)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let table_alias = tvf_source
+            .alias
+            .as_ref()
+            .map(|a| a.value.clone())
+            .unwrap_or_else(|| tvf_source.function.value.clone());
+
+        let right_columns = Self::tvf_columns(tvf_source, tvf.as_ref());
+        let right_width = right_columns.len();
+        let mut all_columns = left_columns.clone();
+        all_columns.extend(
+            right_columns
+                .iter()
+                .map(|col| format!("{}.{}", table_alias, col)),
+        );
+
+        let on_filter = match &join_source.condition {
+            Some(condition) => Some(RowFilter::new(condition, &all_columns)?.with_context(ctx)),
+            None => None,
+        };
+
+        let mut result_rows = Vec::new();
+        for left_row in &left_rows {
+            let args = arg_evals
+                .iter_mut()
+                .map(|eval| eval.eval(left_row))
+                .collect::<Result<Vec<Value>>>()?;
+            let (right_rows, _) = Self::tvf_output(tvf_source, tvf.as_ref(), &args, None)?;
+
+            let before = result_rows.len();
+            for right_row in right_rows {
+                let mut values = left_row.as_slice().to_vec();
+                values.extend(right_row.as_slice().iter().cloned());
+                let row = Row::from_values(values);
+                if on_filter.as_ref().is_none_or(|f| f.matches(&row)) {
+                    result_rows.push(row);
+                }
+            }
+            if keep_unmatched && result_rows.len() == before {
+                let mut values = left_row.as_slice().to_vec();
+                values.resize(values.len() + right_width, Value::null_unknown());
+                result_rows.push(Row::from_values(values));
+            }
+        }
+
+        self.finish_join(
+            stmt,
+            ctx,
+            result_rows,
+            all_columns,
+            stmt.where_clause.as_ref().map(|wc| (**wc).clone()),
+            &[],
+            &[],
+        )
+    }
+
     /// Execute a subquery source
     fn execute_subquery_source(
         &self,
@@ -3797,6 +4249,15 @@ impl Executor {
                 .map(|c| format!("{}.{}", table_alias, c))
                 .collect();
 
+            // Uncorrelated subqueries (IN, EXISTS, scalar) run once up front
+            let processed_where;
+            let where_clause = if Self::has_subqueries(where_clause) {
+                processed_where = self.process_where_subqueries(where_clause, ctx)?;
+                &processed_where
+            } else {
+                where_clause.as_ref()
+            };
+
             // Create filters for simple and qualified column names
             let filter = RowFilter::new(where_clause, &column_names)?.with_context(ctx);
             let qual_filter = RowFilter::new(where_clause, &qualified_cols)?.with_context(ctx);
@@ -4099,6 +4560,35 @@ impl Executor {
                     return Ok((Box::new(result), qualified_columns));
                 }
 
+                // pg_catalog compatibility tables (drivers join these on connect)
+                if let Some(pg_table) = pg_catalog_table(ts) {
+                    let mut result = self.execute_pg_catalog_table(&pg_table)?;
+                    let columns = result.columns().to_vec();
+                    let mut rows = Vec::new();
+                    while result.next() {
+                        rows.push(result.take_row());
+                    }
+                    if let Some(filter_expr) = filter {
+                        let row_filter = RowFilter::new(filter_expr, &columns)?;
+                        rows.retain(|row| row_filter.matches(row));
+                    }
+                    let table_alias = ts
+                        .alias
+                        .as_ref()
+                        .map(|a| a.value.clone())
+                        .unwrap_or_else(|| ts.name.table());
+                    let qualified_columns: Vec<String> = columns
+                        .iter()
+                        .map(|col| format!("{}.{}", table_alias, col))
+                        .collect();
+                    let result = super::result::ExecutorMemoryResult::new(columns, rows);
+                    return Ok((Box::new(result), qualified_columns));
+                }
+
+                // Unqualified names resolve along SET search_path
+                let resolved = self.resolve_search_path_source(ts);
+                let ts = resolved.as_ref().unwrap_or(ts);
+
                 // Check if this is actually a view (for JOINs that reference views)
                 let schema_name = ts
                     .name
@@ -4158,6 +4648,8 @@ impl Executor {
                     limit: None,
                     offset: None,
                     set_operations: vec![],
+                    hints: vec![],
+                    lock: None,
                 };
                 let (result, columns, _) = self.execute_simple_table_scan(ts, &select_all, ctx)?;
 
@@ -4193,6 +4685,8 @@ impl Executor {
                     limit: None,
                     offset: None,
                     set_operations: vec![],
+                    hints: vec![],
+                    lock: None,
                 };
                 let (result, columns, _) = self.execute_join_source(js, &select_all, ctx)?;
                 Ok((result, columns))
@@ -4200,9 +4694,21 @@ impl Executor {
             Expression::SubquerySource(ss) => {
                 // Execute subquery with incremented depth to avoid creating new TimeoutGuard
                 let subquery_ctx = ctx.with_incremented_query_depth();
-                let result = self.execute_select(&ss.subquery, &subquery_ctx)?;
+                let mut result = self.execute_select(&ss.subquery, &subquery_ctx)?;
                 let columns = result.columns().to_vec();
 
+                // The pushed-down filter applies to the subquery's output, so it
+                // runs after the subquery's own ORDER BY / LIMIT / OFFSET
+                if let Some(filter_expr) = filter {
+                    let row_filter = RowFilter::new(filter_expr, &columns)?;
+                    let mut rows = Self::materialize_result(result)?;
+                    rows.retain(|row| row_filter.matches(row));
+                    result = Box::new(super::result::ExecutorMemoryResult::new(
+                        columns.clone(),
+                        rows,
+                    ));
+                }
+
                 // Prefix column names with subquery alias (required for proper ON condition resolution)
                 // Without this, ON a.id = b.id cannot resolve qualified column names
                 if let Some(alias) = &ss.alias {
@@ -4233,8 +4739,21 @@ impl Executor {
                     limit: None,
                     offset: None,
                     set_operations: vec![],
+                    hints: vec![],
+                    lock: None,
                 };
                 let (result, columns, _) = self.execute_values_source(vs, &select_all, ctx)?;
+
+                // Prefix column names with the alias, as for subqueries, so the
+                // ON condition can resolve qualified names
+                if let Some(alias) = &vs.alias {
+                    let qualified_columns: Vec<String> = columns
+                        .iter()
+                        .map(|col| format!("{}.{}", alias.value, col))
+                        .collect();
+                    return Ok((result, qualified_columns));
+                }
+
                 Ok((result, columns))
             }
             Expression::FunctionTableSource(tvf_source) => {
@@ -5033,16 +5552,12 @@ impl Executor {
             return Ok(Box::new(ExecResult::empty()));
         }
 
-        // Determine isolation level
+        // Determine isolation level; a level set for the next transaction
+        // is used up either way
+        let next_level = self.take_next_isolation_level();
         let isolation_level = match stmt.isolation_level.as_deref() {
-            Some("SNAPSHOT") => crate::core::IsolationLevel::SnapshotIsolation,
-            Some("READ COMMITTED") => crate::core::IsolationLevel::ReadCommitted,
-            Some(_) => {
-                return Err(Error::InvalidArgumentMessage(
-                    "Unsupported isolation level. Supported: SNAPSHOT, READ COMMITTED".to_string(),
-                ))
-            }
-            None => self.default_isolation_level,
+            Some(level) => isolation_level_from_sql(level)?,
+            None => next_level,
         };
 
         // Start a new transaction
@@ -5054,6 +5569,7 @@ impl Executor {
             tables: FxHashMap::default(),
             ddl_undo_log: Vec::new(),
             is_explicit_tx: true, // This is execute_begin
+            ran_query: false,
         });
 
         Ok(Box::new(ExecResult::empty()))
@@ -5068,6 +5584,8 @@ impl Executor {
         let mut active_tx = self.active_transaction.lock().unwrap();
 
         if let Some(mut tx_state) = active_tx.take() {
+            self.end_transaction_cursors(true)?;
+
             // Commit the transaction - it will commit all tables via commit_all_tables()
             tx_state.transaction.commit()?;
 
@@ -5091,23 +5609,8 @@ impl Executor {
         // Check if this is a ROLLBACK TO SAVEPOINT
         if let Some(ref savepoint_name) = stmt.savepoint_name {
             if let Some(ref mut tx_state) = *active_tx {
-                // Get the savepoint timestamp first
-                let timestamp = tx_state
-                    .transaction
-                    .get_savepoint_timestamp(&savepoint_name.value)
-                    .ok_or_else(|| {
-                        Error::invalid_argument(format!(
-                            "savepoint '{}' does not exist",
-                            savepoint_name.value
-                        ))
-                    })?;
-
-                // Rollback each table's local changes that occurred after the savepoint
-                for table in tx_state.tables.values() {
-                    table.rollback_to_timestamp(timestamp);
-                }
-
-                // Rollback to savepoint in the transaction (removes the savepoint and any after it)
+                // Discards the changes made after the savepoint and removes
+                // the savepoints created after it
                 tx_state
                     .transaction
                     .rollback_to_savepoint(&savepoint_name.value)?;
@@ -5121,6 +5624,8 @@ impl Executor {
         } else {
             // Full ROLLBACK - ends the transaction
             if let Some(mut tx_state) = active_tx.take() {
+                let cursors = self.end_transaction_cursors(false);
+
                 // Rollback all tables first
                 for (_name, mut table) in tx_state.tables.drain() {
                     table.rollback();
@@ -5134,7 +5639,10 @@ impl Executor {
                     match op {
                         super::DeferredDdlOperation::CreateTable { name } => {
                             // Undo CreateTable by dropping it
-                            let _ = self.engine.drop_table_internal(&name);
+                            let _ = self.engine.drop_table(&name);
+                        }
+                        super::DeferredDdlOperation::CreateIndex { table, name } => {
+                            self.undo_create_index(&table, &name);
                         }
                         super::DeferredDdlOperation::DropTable {
                             name: _name,
@@ -5145,16 +5653,11 @@ impl Executor {
                         }
                         super::DeferredDdlOperation::CreateSchema { name } => {
                             // Undo CreateSchema by dropping it
-                            let mut schemas = self.engine.schemas.write().unwrap();
-                            schemas.remove(&name);
+                            let _ = self.engine.drop_schema(&name);
                         }
                         super::DeferredDdlOperation::DropSchema { name, tables } => {
                             // Undo DropSchema by recreating schema and tables
-                            {
-                                let mut schemas = self.engine.schemas.write().unwrap();
-                                // Just ensure the schema bucket exists so create_table works nicely
-                                schemas.entry(name.clone()).or_default();
-                            }
+                            let _ = self.engine.create_schema(&name);
                             for (_qualified, schema) in tables {
                                 let _ = self.engine.create_table(schema);
                             }
@@ -5162,6 +5665,7 @@ impl Executor {
                     }
                 }
 
+                cursors?;
                 Ok(Box::new(ExecResult::empty()))
             } else {
                 // No active transaction - this is a no-op
@@ -5171,11 +5675,216 @@ impl Executor {
     }
 
     /// Execute SET statement
+    ///
+    /// `statement_timeout`, `float_display_precision`, `invalid_utf8`, `identifier_quote`,
+    /// `routine_name_case`, `error_verbosity` and `div_by_zero` apply to this connection;
+    /// `plan_cache_size` resizes its parsed statement cache. The `autovacuum*` settings update the engine
+    /// configuration. Other settings are accepted and ignored.
     pub(crate) fn execute_set(
         &self,
-        _stmt: &SetStatement,
+        stmt: &SetStatement,
         _ctx: &ExecutionContext,
     ) -> Result<Box<dyn QueryResult>> {
+        let name = stmt.name.value.to_lowercase();
+        match name.as_str() {
+            "statement_timeout" => {
+                let timeout_ms = parse_duration_setting(&name, &stmt.value)?;
+                self.statement_timeout_ms
+                    .store(timeout_ms, std::sync::atomic::Ordering::Relaxed);
+            }
+            "float_display_precision" => {
+                let digits = parse_precision_setting(&name, &stmt.value)?;
+                self.float_display_precision
+                    .store(digits, std::sync::atomic::Ordering::Relaxed);
+            }
+            "invalid_utf8" => {
+                let mode = match &stmt.value {
+                    Expression::StringLiteral(lit) => lit.value.to_lowercase(),
+                    Expression::Identifier(ident) => ident.value_lower.clone(),
+                    _ => return Err(invalid_setting(&name, &stmt.value)),
+                };
+                let replace = match mode.as_str() {
+                    "error" => false,
+                    "replace" => true,
+                    _ => return Err(invalid_setting(&name, &stmt.value)),
+                };
+                self.replace_invalid_utf8
+                    .store(replace, std::sync::atomic::Ordering::Relaxed);
+            }
+            "identifier_quote" => {
+                let quote = match &stmt.value {
+                    Expression::StringLiteral(lit) => lit.value.to_lowercase(),
+                    Expression::Identifier(ident) => ident.value_lower.clone(),
+                    _ => return Err(invalid_setting(&name, &stmt.value)),
+                };
+                let backtick = match quote.as_str() {
+                    "double_quote" => false,
+                    "backtick" => true,
+                    _ => return Err(invalid_setting(&name, &stmt.value)),
+                };
+                // Cached statements were parsed under the previous quoting
+                if self
+                    .backtick_identifiers
+                    .swap(backtick, std::sync::atomic::Ordering::Relaxed)
+                    != backtick
+                {
+                    self.query_cache.clear();
+                }
+            }
+            "error_verbosity" => {
+                let mode = match &stmt.value {
+                    Expression::StringLiteral(lit) => lit.value.to_lowercase(),
+                    Expression::Identifier(ident) => ident.value_lower.clone(),
+                    _ => return Err(invalid_setting(&name, &stmt.value)),
+                };
+                let verbose = match mode.as_str() {
+                    "terse" | "default" => false,
+                    "verbose" => true,
+                    _ => return Err(invalid_setting(&name, &stmt.value)),
+                };
+                self.verbose_errors
+                    .store(verbose, std::sync::atomic::Ordering::Relaxed);
+            }
+            "div_by_zero" => {
+                let mode = match &stmt.value {
+                    Expression::StringLiteral(lit) => lit.value.to_lowercase(),
+                    Expression::Identifier(ident) => ident.value_lower.clone(),
+                    Expression::NullLiteral(_) => "null".to_string(),
+                    _ => return Err(invalid_setting(&name, &stmt.value)),
+                };
+                let null = match mode.as_str() {
+                    "error" => false,
+                    "null" => true,
+                    _ => return Err(invalid_setting(&name, &stmt.value)),
+                };
+                self.div_by_zero_null
+                    .store(null, std::sync::atomic::Ordering::Relaxed);
+            }
+            "implicit_order" => {
+                let mode = match &stmt.value {
+                    Expression::StringLiteral(lit) => lit.value.to_lowercase(),
+                    Expression::Identifier(ident) => ident.value_lower.clone(),
+                    _ => return Err(invalid_setting(&name, &stmt.value)),
+                };
+                let pk = match mode.as_str() {
+                    "none" => false,
+                    "pk" => true,
+                    _ => return Err(invalid_setting(&name, &stmt.value)),
+                };
+                self.implicit_pk_order
+                    .store(pk, std::sync::atomic::Ordering::Relaxed);
+            }
+            "routine_name_case" => {
+                let mode = match &stmt.value {
+                    Expression::StringLiteral(lit) => lit.value.to_lowercase(),
+                    Expression::Identifier(ident) => ident.value_lower.clone(),
+                    _ => return Err(invalid_setting(&name, &stmt.value)),
+                };
+                let preserve = match mode.as_str() {
+                    "upper" => false,
+                    "preserve" => true,
+                    _ => return Err(invalid_setting(&name, &stmt.value)),
+                };
+                self.preserve_routine_name_case
+                    .store(preserve, std::sync::atomic::Ordering::Relaxed);
+            }
+            "search_path" => {
+                let items = match &stmt.value {
+                    Expression::ExpressionList(list) => list.expressions.as_slice(),
+                    value => std::slice::from_ref(value),
+                };
+                let mut path = Vec::with_capacity(items.len());
+                for item in items {
+                    match item {
+                        // SET search_path = 'tenant1, public'
+                        Expression::StringLiteral(lit) => path.extend(
+                            lit.value
+                                .split(',')
+                                .map(|s| s.trim().to_lowercase())
+                                .filter(|s| !s.is_empty()),
+                        ),
+                        Expression::Identifier(ident) => path.push(ident.value_lower.clone()),
+                        _ => return Err(invalid_setting(&name, &stmt.value)),
+                    }
+                }
+                if path.is_empty() {
+                    return Err(invalid_setting(&name, &stmt.value));
+                }
+                *self.search_path.lock().unwrap() = path;
+            }
+            "plan_cache_size" => {
+                let size = match &stmt.value {
+                    Expression::IntegerLiteral(lit) => usize::try_from(lit.value)
+                        .map_err(|_| invalid_setting(&name, &stmt.value))?,
+                    _ => return Err(invalid_setting(&name, &stmt.value)),
+                };
+                self.query_cache.set_max_size(size);
+            }
+            "script_workers" => {
+                let workers = match &stmt.value {
+                    Expression::IntegerLiteral(lit) => usize::try_from(lit.value)
+                        .map_err(|_| invalid_setting(&name, &stmt.value))?,
+                    _ => return Err(invalid_setting(&name, &stmt.value)),
+                };
+                if !cfg!(feature = "runtime-pool") {
+                    return Err(Error::NotSupportedMessage(
+                        "SET script_workers requires the 'runtime-pool' feature".to_string(),
+                    ));
+                }
+                crate::functions::backends::pool::global_pool().set_limit(workers);
+            }
+            "autovacuum"
+            | "autovacuum_interval"
+            | "autovacuum_threshold"
+            | "autovacuum_min_dead_versions" => {
+                let mvcc = self.mvcc()?;
+                let mut config = mvcc.config();
+                match name.as_str() {
+                    "autovacuum" => {
+                        config.auto_vacuum.enabled = parse_bool_setting(&name, &stmt.value)?
+                    }
+                    "autovacuum_interval" => {
+                        config.auto_vacuum.interval_ms = parse_duration_setting(&name, &stmt.value)?
+                    }
+                    "autovacuum_threshold" => {
+                        config.auto_vacuum.threshold = parse_ratio_setting(&name, &stmt.value)?
+                    }
+                    _ => {
+                        config.auto_vacuum.min_dead_versions = match &stmt.value {
+                            Expression::IntegerLiteral(lit) => usize::try_from(lit.value)
+                                .map_err(|_| invalid_setting(&name, &stmt.value))?,
+                            _ => return Err(invalid_setting(&name, &stmt.value)),
+                        }
+                    }
+                }
+                mvcc.update_engine_config(config)?;
+            }
+            "max_memory" => {
+                let mvcc = self.mvcc()?;
+                let mut config = mvcc.config();
+                config.max_memory = parse_size_setting(&name, &stmt.value)?;
+                mvcc.update_engine_config(config)?;
+            }
+            "version_retention" => {
+                let mvcc = self.mvcc()?;
+                let mut config = mvcc.config();
+                config.version_retention_ms = parse_duration_setting(&name, &stmt.value)?;
+                mvcc.update_engine_config(config)?;
+            }
+            _ => {}
+        }
+        Ok(Box::new(ExecResult::empty()))
+    }
+
+    /// Execute CHECKPOINT statement
+    ///
+    /// Folds the WAL into a snapshot and removes the WAL segments it covers.
+    /// A no-op for in-memory databases.
+    pub(crate) fn execute_checkpoint(
+        &self,
+        _stmt: &CheckpointStatement,
+    ) -> Result<Box<dyn QueryResult>> {
+        self.engine.create_snapshot()?;
         Ok(Box::new(ExecResult::empty()))
     }
 
@@ -5219,7 +5928,7 @@ impl Executor {
                 Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
             }
             "SNAPSHOT_INTERVAL" => {
-                let config = self.engine.config();
+                let config = self.mvcc()?.config();
                 let columns = vec![pragma_name.to_lowercase()];
 
                 if let Some(ref value) = stmt.value {
@@ -5227,7 +5936,7 @@ impl Executor {
                     let new_value = self.extract_pragma_int_value(value)?;
                     let mut new_config = config.clone();
                     new_config.persistence.snapshot_interval = new_value as u32;
-                    self.engine.update_engine_config(new_config)?;
+                    self.mvcc()?.update_engine_config(new_config)?;
                     let rows = vec![Row::from_values(vec![Value::Integer(new_value)])];
                     Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
                 } else {
@@ -5239,14 +5948,14 @@ impl Executor {
                 }
             }
             "KEEP_SNAPSHOTS" => {
-                let config = self.engine.config();
+                let config = self.mvcc()?.config();
                 let columns = vec![pragma_name.to_lowercase()];
 
                 if let Some(ref value) = stmt.value {
                     let new_value = self.extract_pragma_int_value(value)?;
                     let mut new_config = config.clone();
                     new_config.persistence.keep_snapshots = new_value as u32;
-                    self.engine.update_engine_config(new_config)?;
+                    self.mvcc()?.update_engine_config(new_config)?;
                     let rows = vec![Row::from_values(vec![Value::Integer(new_value)])];
                     Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
                 } else {
@@ -5257,7 +5966,7 @@ impl Executor {
                 }
             }
             "SYNC_MODE" => {
-                let config = self.engine.config();
+                let config = self.mvcc()?.config();
                 let columns = vec![pragma_name.to_lowercase()];
 
                 if let Some(ref value) = stmt.value {
@@ -5273,7 +5982,7 @@ impl Executor {
                             ))
                         }
                     };
-                    self.engine.update_engine_config(new_config)?;
+                    self.mvcc()?.update_engine_config(new_config)?;
                     let rows = vec![Row::from_values(vec![Value::Integer(new_value)])];
                     Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
                 } else {
@@ -5284,14 +5993,14 @@ impl Executor {
                 }
             }
             "WAL_FLUSH_TRIGGER" => {
-                let config = self.engine.config();
+                let config = self.mvcc()?.config();
                 let columns = vec![pragma_name.to_lowercase()];
 
                 if let Some(ref value) = stmt.value {
                     let new_value = self.extract_pragma_int_value(value)?;
                     let mut new_config = config.clone();
                     new_config.persistence.wal_flush_trigger = new_value as usize;
-                    self.engine.update_engine_config(new_config)?;
+                    self.mvcc()?.update_engine_config(new_config)?;
                     let rows = vec![Row::from_values(vec![Value::Integer(new_value)])];
                     Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
                 } else {
@@ -5301,6 +6010,46 @@ impl Executor {
                     Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
                 }
             }
+            "AUTOVACUUM_STATS" => {
+                // Per-table dead-version counts and auto-vacuum history
+                if stmt.value.is_some() {
+                    return Err(Error::internal(
+                        "PRAGMA AUTOVACUUM_STATS does not accept values",
+                    ));
+                }
+
+                let columns = vec![
+                    "table_name".to_string(),
+                    "live_versions".to_string(),
+                    "dead_versions".to_string(),
+                    "dead_ratio".to_string(),
+                    "autovacuum_count".to_string(),
+                    "last_autovacuum".to_string(),
+                ];
+                let rows = self
+                    .mvcc()?
+                    .vacuum_stats()
+                    .into_iter()
+                    .map(|(table_name, stats)| {
+                        let last_autovacuum = if stats.last_autovacuum > 0 {
+                            Value::Timestamp(chrono::DateTime::from_timestamp_nanos(
+                                stats.last_autovacuum,
+                            ))
+                        } else {
+                            Value::Null(crate::core::DataType::Timestamp)
+                        };
+                        Row::from_values(vec![
+                            Value::text(table_name),
+                            Value::Integer(stats.live_versions as i64),
+                            Value::Integer(stats.dead_versions as i64),
+                            Value::Float(stats.dead_ratio()),
+                            Value::Integer(stats.autovacuum_count as i64),
+                            last_autovacuum,
+                        ])
+                    })
+                    .collect();
+                Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
+            }
             _ => {
                 // Unknown pragma - return empty result for compatibility
                 Ok(Box::new(ExecResult::empty()))
@@ -5339,6 +6088,52 @@ impl Executor {
         }
     }
 
+    /// Execute RELEASE SAVEPOINT statement
+    pub(crate) fn execute_release_savepoint(
+        &self,
+        stmt: &ReleaseSavepointStatement,
+        _ctx: &ExecutionContext,
+    ) -> Result<Box<dyn QueryResult>> {
+        let mut active_tx = self.active_transaction.lock().unwrap();
+
+        if let Some(ref mut tx_state) = *active_tx {
+            tx_state
+                .transaction
+                .release_savepoint(&stmt.savepoint_name.value)?;
+            Ok(Box::new(ExecResult::empty()))
+        } else {
+            Err(Error::internal(
+                "RELEASE SAVEPOINT can only be used within a transaction",
+            ))
+        }
+    }
+
+    /// Execute SET TRANSACTION ISOLATION LEVEL statement
+    ///
+    /// Inside a transaction this changes the level of that transaction, which
+    /// must not have run a query yet. Outside one it sets the level of the
+    /// next transaction only; the default stays as it is.
+    pub(crate) fn execute_set_transaction(
+        &self,
+        stmt: &SetTransactionStatement,
+        _ctx: &ExecutionContext,
+    ) -> Result<Box<dyn QueryResult>> {
+        let level = isolation_level_from_sql(&stmt.isolation_level)?;
+        let mut active_tx = self.active_transaction.lock().unwrap();
+
+        if let Some(ref mut tx_state) = *active_tx {
+            if tx_state.ran_query {
+                return Err(Error::internal(
+                    "SET TRANSACTION ISOLATION LEVEL must be called before any query",
+                ));
+            }
+            tx_state.transaction.set_isolation_level(level)?;
+        } else {
+            self.set_next_isolation_level(level);
+        }
+        Ok(Box::new(ExecResult::empty()))
+    }
+
     /// Execute an expression statement (SELECT 1+1)
     pub(crate) fn execute_expression_stmt(
         &self,
@@ -5493,6 +6288,115 @@ impl Executor {
         Ok(Some((result, columns)))
     }
 
+    /// Check whether a single-table SELECT can read its ORDER BY from an index
+    ///
+    /// The ORDER BY terms must be plain columns that match the leading columns
+    /// of an ordered index, all in one direction. NULL placement is only
+    /// adjusted for the first column, so later columns must either be NOT NULL
+    /// or ask for the index's natural NULL position.
+    pub(crate) fn plan_ordered_index_scan(
+        &self,
+        stmt: &SelectStatement,
+        table: &dyn crate::storage::traits::Table,
+    ) -> Option<OrderedIndexScan> {
+        if stmt.limit.is_none()
+            || stmt.order_by.is_empty()
+            || stmt.where_clause.is_some()
+            || stmt.having.is_some()
+            || !stmt.group_by.columns.is_empty()
+            || !stmt.set_operations.is_empty()
+            || self.has_aggregation(stmt)
+            || self.has_window_functions(stmt)
+            || self.has_distinct(stmt)
+        {
+            return None;
+        }
+
+        let ascending = stmt.order_by[0].ascending;
+        let schema = table.schema();
+        let mut columns = Vec::with_capacity(stmt.order_by.len());
+        for (i, order_by) in stmt.order_by.iter().enumerate() {
+            let name = match &order_by.expression {
+                Expression::Identifier(id) => &id.value,
+                Expression::QualifiedIdentifier(qid) => &qid.name.value,
+                _ => return None, // Can't optimize complex ORDER BY expressions
+            };
+            // A SELECT alias with the same name shadows the column
+            let shadowed = stmt.columns.iter().any(|col| match col {
+                Expression::Aliased(aliased) => {
+                    aliased.alias.value.eq_ignore_ascii_case(name)
+                        && !matches!(aliased.expression.as_ref(),
+                            Expression::Identifier(id) if id.value.eq_ignore_ascii_case(name))
+                }
+                _ => false,
+            });
+            if shadowed || order_by.ascending != ascending {
+                return None;
+            }
+            let column = schema
+                .columns
+                .iter()
+                .find(|c| c.name.eq_ignore_ascii_case(name))?;
+            // Index order puts NULLs first ascending and last descending
+            let nulls_first = order_by.nulls_first.unwrap_or(!ascending);
+            if i > 0 && nulls_first != ascending && column.nullable && !column.primary_key {
+                return None;
+            }
+            columns.push(column.name.clone());
+        }
+
+        let column_refs: Vec<&str> = columns.iter().map(String::as_str).collect();
+        let index_name = table.ordered_index_for(&column_refs)?;
+        Some(OrderedIndexScan {
+            index_name,
+            columns,
+            ascending,
+            nulls_first: stmt.order_by[0].nulls_first.unwrap_or(!ascending),
+        })
+    }
+
+    /// Check whether a filtered single-table SELECT reads nothing but plain
+    /// columns, so a covering index could answer it without the base rows
+    ///
+    /// Returns the columns the index must cover; the caller still has to
+    /// find such an index.
+    pub(crate) fn plan_index_only_scan(
+        &self,
+        stmt: &SelectStatement,
+        where_expr: &Expression,
+        all_columns: &[String],
+    ) -> Option<IndexOnlyScan> {
+        if !stmt.order_by.is_empty()
+            || stmt.having.is_some()
+            || !stmt.group_by.columns.is_empty()
+            || !stmt.set_operations.is_empty()
+            || self.has_aggregation(stmt)
+            || self.has_window_functions(stmt)
+            || self.has_distinct(stmt)
+            || Self::has_subqueries(where_expr)
+        {
+            return None;
+        }
+
+        let (projection, output_columns) =
+            self.get_simple_projection_indices(&stmt.columns, all_columns)?;
+        let mut columns = projection.clone();
+        for name in collect_column_names(where_expr) {
+            let idx = all_columns
+                .iter()
+                .position(|c| c.eq_ignore_ascii_case(&name))?;
+            columns.push(idx);
+        }
+        columns.sort_unstable();
+        columns.dedup();
+
+        Some(IndexOnlyScan {
+            columns,
+            projection,
+            output_columns,
+        })
+    }
+
     /// Try to optimize ORDER BY + LIMIT using index-ordered scan
     ///
     /// For queries like `SELECT * FROM table ORDER BY col LIMIT 10`,
@@ -5505,17 +6409,10 @@ impl Executor {
         all_columns: &[String],
         ctx: &ExecutionContext,
     ) -> Result<Option<(Box<dyn QueryResult>, Vec<String>)>> {
-        // Get the ORDER BY column name
-        let order_by = &stmt.order_by[0];
-        let column_name = match &order_by.expression {
-            Expression::Identifier(id) => id.value.clone(),
-            Expression::QualifiedIdentifier(qid) => qid.name.value.clone(),
-            _ => return Ok(None), // Can't optimize complex ORDER BY expressions
+        let Some(plan) = self.plan_ordered_index_scan(stmt, table) else {
+            return Ok(None);
         };
 
-        // Determine sort order
-        let ascending = order_by.ascending;
-
         // Evaluate limit and offset
         let limit = if let Some(ref limit_expr) = stmt.limit {
             match ExpressionEval::compile(limit_expr, &[])
@@ -5544,9 +6441,14 @@ impl Executor {
         };
 
         // Try to use index-ordered scan
-        if let Some(rows) =
-            table.collect_rows_ordered_by_index(&column_name, ascending, limit, offset)
-        {
+        let column_refs: Vec<&str> = plan.columns.iter().map(String::as_str).collect();
+        if let Some(rows) = table.collect_rows_in_index_order(
+            &column_refs,
+            plan.ascending,
+            plan.nulls_first,
+            limit,
+            offset,
+        ) {
             // Project rows according to SELECT expressions
             let projected_rows = self.project_rows(&stmt.columns, rows, all_columns, ctx)?;
 
@@ -6751,6 +7653,7 @@ impl Executor {
                 lower: Box::new(Self::substitute_aliases(&between.lower, alias_map)),
                 upper: Box::new(Self::substitute_aliases(&between.upper, alias_map)),
                 not: between.not,
+                symmetric: between.symmetric,
             }),
             Expression::In(in_expr) => Expression::In(InExpression {
                 token: in_expr.token.clone(),
@@ -6988,6 +7891,7 @@ impl Executor {
         join_type: &str,
         left_alias: Option<&str>,
         right_alias: Option<&str>,
+        ctx: &ExecutionContext,
     ) -> Option<(
         String,                    // table_name
         join::IndexLookupStrategy, // lookup strategy (index or PK)
@@ -7063,7 +7967,10 @@ impl Executor {
 
         // Try to get the table and check for index or PK
         let txn = self.engine.begin_transaction().ok()?;
-        let table = txn.get_table(table_name).ok()?;
+        let mut table = txn.get_table(table_name).ok()?;
+        if let Some(hints) = ctx.hints() {
+            table.set_index_hint(hints.index_hint(table_source));
+        }
         let schema = table.schema();
 
         // First check if inner column is the PRIMARY KEY (direct row_id lookup)
@@ -7199,6 +8106,152 @@ impl Executor {
     }
 }
 
+/// Output positions of the ORDINAL() items in a select list
+///
+/// Items before any `*` map to their own index; items after a `*` are
+/// counted back from the end of the `output_len` result columns.
+fn ordinal_positions(stmt: &SelectStatement, output_len: usize) -> Vec<usize> {
+    let is_ordinal = |expr: &Expression| {
+        let expr = match expr {
+            Expression::Aliased(aliased) => aliased.expression.as_ref(),
+            other => other,
+        };
+        matches!(expr, Expression::FunctionCall(func)
+            if func.function.eq_ignore_ascii_case("ORDINAL") && func.arguments.is_empty())
+    };
+
+    let mut positions = Vec::new();
+    let mut after_star = false;
+    for (i, column) in stmt.columns.iter().enumerate() {
+        if matches!(column, Expression::Star(_) | Expression::QualifiedStar(_)) {
+            after_star = true;
+        } else if is_ordinal(column) {
+            let pos = if after_star {
+                (output_len + i).checked_sub(stmt.columns.len())
+            } else {
+                Some(i)
+            };
+            positions.extend(pos.filter(|&p| p < output_len));
+        }
+    }
+    positions
+}
+
+fn invalid_setting(name: &str, value: &Expression) -> Error {
+    Error::invalid_argument(format!("invalid value for {}: {}", name, value))
+}
+
+/// Parse a duration setting such as `SET statement_timeout` into milliseconds.
+///
+/// Accepts a number of milliseconds (`5000`, `'5000'`), a number with a unit
+/// (`'500ms'`, `'5s'`, `'2min'`, `'1h'`, `'1d'`) or an interval (`'5 seconds'`).
+fn parse_duration_setting(name: &str, value: &Expression) -> Result<u64> {
+    let invalid = || {
+        Error::invalid_argument(format!(
+            "invalid {}: {} (expected milliseconds or a duration like '5s')",
+            name, value
+        ))
+    };
+
+    match value {
+        Expression::IntegerLiteral(lit) => u64::try_from(lit.value).map_err(|_| invalid()),
+        Expression::IntervalLiteral(lit) => crate::core::parse_interval(&lit.value)
+            .ok()
+            .and_then(|micros| u64::try_from(micros / 1000).ok())
+            .ok_or_else(invalid),
+        Expression::StringLiteral(lit) => {
+            crate::storage::parse_duration_ms(&lit.value).ok_or_else(invalid)
+        }
+        _ => Err(invalid()),
+    }
+}
+
+/// Parse a memory size setting such as `SET max_memory` into bytes.
+///
+/// Accepts a number of bytes (`1048576`) or a size with a unit (`'64KB'`,
+/// `'512MB'`, `'1GB'`).
+fn parse_size_setting(name: &str, value: &Expression) -> Result<u64> {
+    let bytes = match value {
+        Expression::IntegerLiteral(lit) => u64::try_from(lit.value).ok(),
+        Expression::StringLiteral(lit) => crate::storage::parse_byte_size(&lit.value),
+        _ => None,
+    };
+    bytes.ok_or_else(|| {
+        Error::invalid_argument(format!(
+            "invalid {}: {} (expected bytes or a size like '512MB')",
+            name, value
+        ))
+    })
+}
+
+/// Parse an on/off setting (`true`, `false`, `1`, `0`, `'on'`, `'off'`).
+fn parse_bool_setting(name: &str, value: &Expression) -> Result<bool> {
+    match value {
+        Expression::BooleanLiteral(lit) => Ok(lit.value),
+        Expression::IntegerLiteral(lit) if lit.value == 0 || lit.value == 1 => Ok(lit.value == 1),
+        Expression::StringLiteral(lit) => match lit.value.to_lowercase().as_str() {
+            "on" | "true" | "1" | "yes" => Ok(true),
+            "off" | "false" | "0" | "no" => Ok(false),
+            _ => Err(invalid_setting(name, value)),
+        },
+        _ => Err(invalid_setting(name, value)),
+    }
+}
+
+/// Parse a number of digits after the decimal point (0 to 15), or
+/// `'default'` (-1) for the shortest exact representation.
+fn parse_precision_setting(name: &str, value: &Expression) -> Result<i64> {
+    let digits = match value {
+        Expression::IntegerLiteral(lit) => lit.value,
+        Expression::StringLiteral(lit) if lit.value.eq_ignore_ascii_case("default") => -1,
+        Expression::StringLiteral(lit) => lit
+            .value
+            .trim()
+            .parse::<i64>()
+            .map_err(|_| invalid_setting(name, value))?,
+        Expression::Identifier(ident) if ident.value.eq_ignore_ascii_case("default") => -1,
+        _ => return Err(invalid_setting(name, value)),
+    };
+    if (-1..=15).contains(&digits) {
+        Ok(digits)
+    } else {
+        Err(invalid_setting(name, value))
+    }
+}
+
+/// Parse a fraction between 0.0 and 1.0.
+fn parse_ratio_setting(name: &str, value: &Expression) -> Result<f64> {
+    let ratio = match value {
+        Expression::FloatLiteral(lit) => lit.value,
+        Expression::IntegerLiteral(lit) => lit.value as f64,
+        Expression::StringLiteral(lit) => lit
+            .value
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| invalid_setting(name, value))?,
+        _ => return Err(invalid_setting(name, value)),
+    };
+    if (0.0..=1.0).contains(&ratio) {
+        Ok(ratio)
+    } else {
+        Err(invalid_setting(name, value))
+    }
+}
+
+/// Map an isolation level as parsed from SQL to a supported level.
+///
+/// REPEATABLE READ runs as SNAPSHOT, which gives the same guarantees.
+pub(crate) fn isolation_level_from_sql(level: &str) -> Result<crate::core::IsolationLevel> {
+    match level {
+        "SNAPSHOT" | "REPEATABLE READ" => Ok(crate::core::IsolationLevel::SnapshotIsolation),
+        "READ COMMITTED" => Ok(crate::core::IsolationLevel::ReadCommitted),
+        _ => Err(Error::InvalidArgumentMessage(
+            "Unsupported isolation level. Supported: SNAPSHOT, REPEATABLE READ, READ COMMITTED"
+                .to_string(),
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7233,4 +8286,25 @@ mod tests {
         }
         assert!(found);
     }
+
+    #[test]
+    fn test_parse_statement_timeout() {
+        let parse = |sql: &str| match crate::parser::parse_sql(sql).unwrap().remove(0) {
+            Statement::Set(stmt) => parse_duration_setting("statement_timeout", &stmt.value),
+            _ => panic!("expected SET statement"),
+        };
+
+        assert_eq!(parse("SET statement_timeout = 250").unwrap(), 250);
+        assert_eq!(parse("SET statement_timeout = '5000'").unwrap(), 5000);
+        assert_eq!(parse("SET statement_timeout = '5s'").unwrap(), 5000);
+        assert_eq!(parse("SET statement_timeout = '1.5 s'").unwrap(), 1500);
+        assert_eq!(parse("SET statement_timeout = '2min'").unwrap(), 120_000);
+        assert_eq!(
+            parse("SET statement_timeout = '10 seconds'").unwrap(),
+            10_000
+        );
+        assert_eq!(parse("SET statement_timeout TO 0").unwrap(), 0);
+        assert!(parse("SET statement_timeout = -1").is_err());
+        assert!(parse("SET statement_timeout = 'soon'").is_err());
+    }
 }
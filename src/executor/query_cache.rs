@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -18,6 +19,15 @@
 //! storing the parse tree to avoid the overhead of parsing the same
 //! query multiple times.
 //!
+//! The cache is keyed by the query's tokens with whitespace normalized and
+//! by the identifier quoting they were read with. Literals that are compared against or listed as values (`WHERE id = 5`,
+//! `IN (1, 2)`, `VALUES (1, 'a')`, `SET x = 5`) are replaced by typed
+//! placeholders in the key, so queries that differ only in those values
+//! share one entry, just like parameterized queries (`WHERE id = $1`). On a
+//! hit the query's own literals are put into a copy of the cached statement.
+//! When it is full, the least recently used entries are evicted. The
+//! executor invalidates it whenever a statement changes the schema.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -36,10 +46,16 @@
 //! let plan = cache.get("SELECT * FROM users").unwrap();
 //! ```
 
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, RwLock};
 use std::time::Instant;
 
-use crate::parser::ast::Statement;
+use crate::parser::ast::{Expression, FloatLiteral, IntegerLiteral, Statement, StringLiteral};
+use crate::parser::token::{Token, TokenType};
+use crate::parser::{IdentifierQuote, Lexer};
+
+/// Cache key: the identifier quoting and the normalized query text
+pub type PlanKey = (IdentifierQuote, String);
 
 /// Default cache size (number of cached plans)
 pub const DEFAULT_CACHE_SIZE: usize = 1000;
@@ -63,9 +79,9 @@ pub struct CachedQueryPlan {
     pub statement: Arc<Statement>,
     /// Original query text
     pub query_text: String,
-    /// Last time this plan was used (monotonic)
+    /// Time the plan was cached (monotonic)
     pub last_used: Instant,
-    /// Number of times this plan has been used
+    /// Number of times this plan has been used, as of when it was cached
     pub usage_count: u64,
     /// Whether this query has parameter placeholders
     pub has_params: bool,
@@ -73,6 +89,8 @@ pub struct CachedQueryPlan {
     pub param_count: usize,
     /// Normalized query text (cache key)
     pub normalized_query: String,
+    /// Offsets of the statement's literals the key has placeholders for
+    pub literal_offsets: Vec<usize>,
 }
 
 impl CachedQueryPlan {
@@ -92,21 +110,39 @@ impl CachedQueryPlan {
             has_params,
             param_count,
             normalized_query,
+            literal_offsets: Vec::new(),
         }
     }
 }
 
+/// A cached plan with its recency for LRU eviction
+struct CacheEntry {
+    plan: CachedQueryPlan,
+    /// Cache clock tick of the last use
+    last_used: AtomicU64,
+    /// Number of lookups that returned this plan
+    hits: AtomicU64,
+}
+
 /// Query cache for parsed SQL statements
 ///
 /// Provides thread-safe caching of parsed SQL queries to avoid
 /// the overhead of parsing the same query multiple times.
 pub struct QueryCache {
     /// Cached plans indexed by normalized query text
-    plans: RwLock<std::collections::HashMap<String, CachedQueryPlan>>,
-    /// Maximum number of cached plans
-    max_size: usize,
+    plans: RwLock<std::collections::HashMap<PlanKey, CacheEntry>>,
+    /// Maximum number of cached plans (0 disables caching)
+    max_size: AtomicUsize,
     /// Factor to determine how many plans to prune when cache is full (0.0-1.0)
     prune_factor: f64,
+    /// Logical clock ordering uses of cached plans
+    clock: AtomicU64,
+    /// Lookups that found a plan
+    hits: AtomicU64,
+    /// Lookups that found nothing
+    misses: AtomicU64,
+    /// Number of times the cache was invalidated by a schema change
+    invalidations: AtomicU64,
 }
 
 impl QueryCache {
@@ -114,8 +150,12 @@ impl QueryCache {
     pub fn new(max_size: usize) -> Self {
         Self {
             plans: RwLock::new(std::collections::HashMap::new()),
-            max_size,
+            max_size: AtomicUsize::new(max_size),
             prune_factor: 0.2, // Prune 20% of entries when cache is full
+            clock: AtomicU64::new(0),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            invalidations: AtomicU64::new(0),
         }
     }
 
@@ -128,21 +168,47 @@ impl QueryCache {
     ///
     /// Returns a cheap Arc clone of the cached statement and metadata.
     /// The Statement is immutable and shared via Arc.
-    ///
-    /// OPTIMIZATION: Only uses read lock for cache hits (no write lock for stats).
-    /// Stats are only updated on cache misses via put().
     pub fn get(&self, query: &str) -> Option<CachedPlanRef> {
-        let normalized = normalize_query(query);
+        self.get_normalized(&normalize_query(query, IdentifierQuote::default()))
+    }
 
-        // Only use read lock - skip stats update for performance
+    /// Get a cached plan for an already normalized query
+    ///
+    /// When the plan was cached for other literal values, the returned
+    /// statement is a copy holding the values of this query.
+    ///
+    /// OPTIMIZATION: Only takes the read lock; recency and hit counts are
+    /// atomics so concurrent lookups don't contend.
+    pub fn get_normalized(&self, query: &NormalizedQuery<'_>) -> Option<CachedPlanRef> {
         let plans = self.plans.read().ok()?;
-        let plan = plans.get(normalized.as_ref())?;
+        let entry = plans
+            .get(&query.key)
+            .filter(|entry| entry.plan.literal_offsets.len() == query.literals.len())
+            .or_else(|| plans.get(&query.exact_key));
+        let Some(entry) = entry else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return None;
+        };
+
+        entry.last_used.store(self.tick(), Ordering::Relaxed);
+        entry.hits.fetch_add(1, Ordering::Relaxed);
+        self.hits.fetch_add(1, Ordering::Relaxed);
+
+        let statement = if entry.plan.literal_offsets.is_empty() {
+            // Only clone the Arc (cheap)
+            entry.plan.statement.clone()
+        } else {
+            Arc::new(bind_literals(
+                &entry.plan.statement,
+                &entry.plan.literal_offsets,
+                &query.literals,
+            ))
+        };
 
-        // Only clone the Arc (cheap) and copy the small fields
         Some(CachedPlanRef {
-            statement: plan.statement.clone(),
-            has_params: plan.has_params,
-            param_count: plan.param_count,
+            statement,
+            has_params: entry.plan.has_params,
+            param_count: entry.plan.param_count,
         })
     }
 
@@ -156,25 +222,64 @@ impl QueryCache {
         has_params: bool,
         param_count: usize,
     ) -> CachedQueryPlan {
-        let normalized = normalize_query(query);
-        // Convert Cow to String for storage (only allocates if not already owned)
-        let normalized_key = normalized.into_owned();
+        let query = normalize_query(query, IdentifierQuote::default());
+        self.put_normalized(&query, statement, has_params, param_count)
+    }
+
+    /// Add the plan of an already normalized query to the cache
+    ///
+    /// The plan is shared with queries that differ only in the literals the
+    /// key has placeholders for, unless some of those literals can't be
+    /// found in the statement; then it is cached for this exact query.
+    pub fn put_normalized(
+        &self,
+        query: &NormalizedQuery<'_>,
+        statement: Arc<Statement>,
+        has_params: bool,
+        param_count: usize,
+    ) -> CachedQueryPlan {
+        let literal_offsets: Vec<usize> = query
+            .literals
+            .iter()
+            .map(|token| token.position.offset)
+            .collect();
+        let shared = !literal_offsets.is_empty() && has_literals(&statement, &query.literals);
+        let key = if shared {
+            query.key.clone()
+        } else {
+            query.exact_key.clone()
+        };
 
-        let plan = CachedQueryPlan::new(
+        let mut plan = CachedQueryPlan::new(
             statement,
-            query.to_string(),
+            query.query.to_string(),
             has_params,
             param_count,
-            normalized_key.clone(),
+            key.1.clone(),
         );
+        if shared {
+            plan.literal_offsets = literal_offsets;
+        }
+
+        let max_size = self.max_size();
+        if max_size == 0 {
+            return plan;
+        }
 
         if let Ok(mut plans) = self.plans.write() {
             // Check if we need to prune the cache
-            if plans.len() >= self.max_size {
-                self.prune_cache(&mut plans);
+            if plans.len() >= max_size && !plans.contains_key(&key) {
+                self.prune_cache(&mut plans, max_size);
             }
 
-            plans.insert(normalized_key, plan.clone());
+            plans.insert(
+                key,
+                CacheEntry {
+                    plan: plan.clone(),
+                    last_used: AtomicU64::new(self.tick()),
+                    hits: AtomicU64::new(0),
+                },
+            );
         }
 
         plan
@@ -187,6 +292,31 @@ impl QueryCache {
         }
     }
 
+    /// Drop every cached plan after a schema change
+    pub fn invalidate(&self) {
+        self.invalidations.fetch_add(1, Ordering::Relaxed);
+        self.clear();
+    }
+
+    /// Get the maximum number of cached plans
+    pub fn max_size(&self) -> usize {
+        self.max_size.load(Ordering::Relaxed)
+    }
+
+    /// Change the maximum number of cached plans, evicting the least
+    /// recently used ones if the cache is over the new size
+    pub fn set_max_size(&self, max_size: usize) {
+        self.max_size.store(max_size, Ordering::Relaxed);
+        if let Ok(mut plans) = self.plans.write() {
+            if max_size == 0 {
+                plans.clear();
+            } else if plans.len() > max_size {
+                let excess = plans.len() - max_size;
+                self.evict_lru(&mut plans, excess);
+            }
+        }
+    }
+
     /// Get the number of plans in the cache
     pub fn size(&self) -> usize {
         self.plans.read().map(|p| p.len()).unwrap_or(0)
@@ -194,20 +324,17 @@ impl QueryCache {
 
     /// Get cache statistics
     pub fn stats(&self) -> CacheStats {
-        let plans = match self.plans.read() {
-            Ok(p) => p,
-            Err(_) => {
-                return CacheStats {
-                    size: 0,
-                    max_size: self.max_size,
-                    total_usage: 0,
-                    avg_usage: 0.0,
-                }
-            }
+        let (size, total_usage) = match self.plans.read() {
+            Ok(plans) => (
+                plans.len(),
+                plans
+                    .values()
+                    .map(|e| e.plan.usage_count + e.hits.load(Ordering::Relaxed))
+                    .sum(),
+            ),
+            Err(_) => (0, 0),
         };
 
-        let size = plans.len();
-        let total_usage: u64 = plans.values().map(|p| p.usage_count).sum();
         let avg_usage = if size > 0 {
             total_usage as f64 / size as f64
         } else {
@@ -216,33 +343,42 @@ impl QueryCache {
 
         CacheStats {
             size,
-            max_size: self.max_size,
+            max_size: self.max_size(),
             total_usage,
             avg_usage,
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            invalidations: self.invalidations.load(Ordering::Relaxed),
         }
     }
 
+    /// Next tick of the cache clock
+    fn tick(&self) -> u64 {
+        self.clock.fetch_add(1, Ordering::Relaxed)
+    }
+
     /// Prune the least recently used entries when the cache is full
-    fn prune_cache(&self, plans: &mut std::collections::HashMap<String, CachedQueryPlan>) {
+    fn prune_cache(
+        &self,
+        plans: &mut std::collections::HashMap<PlanKey, CacheEntry>,
+        max_size: usize,
+    ) {
         // Calculate how many entries to remove
-        let num_to_remove = ((self.max_size as f64) * self.prune_factor).ceil() as usize;
-        let num_to_remove = num_to_remove.max(1);
+        let num_to_remove = ((max_size as f64) * self.prune_factor).ceil() as usize;
+        let num_to_remove = num_to_remove.max(1).max(plans.len() + 1 - max_size);
 
-        if plans.len() <= num_to_remove {
-            return;
-        }
+        self.evict_lru(plans, num_to_remove);
+    }
 
-        // Build a list of all plans sorted by last used time and usage count
-        let mut entries: Vec<(String, Instant, u64)> = plans
+    /// Remove the `count` least recently used entries
+    fn evict_lru(&self, plans: &mut std::collections::HashMap<PlanKey, CacheEntry>, count: usize) {
+        let mut entries: Vec<(u64, PlanKey)> = plans
             .iter()
-            .map(|(k, p)| (k.clone(), p.last_used, p.usage_count))
+            .map(|(k, e)| (e.last_used.load(Ordering::Relaxed), k.clone()))
             .collect();
+        entries.sort_unstable_by_key(|(tick, _)| *tick);
 
-        // Sort by last used (oldest first), then by usage count (least used first)
-        entries.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.2.cmp(&b.2)));
-
-        // Remove the oldest/least used entries
-        for (key, _, _) in entries.into_iter().take(num_to_remove) {
+        for (_, key) in entries.into_iter().take(count) {
             plans.remove(&key);
         }
     }
@@ -265,47 +401,362 @@ pub struct CacheStats {
     pub total_usage: u64,
     /// Average usage per cached plan
     pub avg_usage: f64,
+    /// Lookups that found a cached plan
+    pub hits: u64,
+    /// Lookups that had to parse
+    pub misses: u64,
+    /// Times the cache was cleared by a schema change
+    pub invalidations: u64,
+}
+
+/// A query with its cache keys
+#[derive(Debug, Clone)]
+pub struct NormalizedQuery<'a> {
+    /// The query text
+    pub query: &'a str,
+    /// Key with placeholders for the literals in `literals`
+    pub key: PlanKey,
+    /// Key with only whitespace normalized, used for plans whose literals
+    /// can't be replaced
+    pub exact_key: PlanKey,
+    /// The literal tokens the key has placeholders for, in query order
+    pub literals: Vec<Token>,
+}
+
+/// Clause a literal appears in, as far as the cache key is concerned
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum KeyClause {
+    Where,
+    Set,
+    Values,
+    Other,
+}
+
+/// A parenthesized part of the query
+struct KeyFrame {
+    /// Clause started inside the parentheses (None: the enclosing one)
+    clause: Option<KeyClause>,
+    /// Whether this is an IN list or a VALUES row
+    list: bool,
+    /// Whether the parentheses hold a subquery
+    subquery: bool,
 }
 
 /// Normalize a query for caching
 ///
-/// This handles irrelevant whitespace differences to improve cache hits.
-/// OPTIMIZATION: Returns Cow to avoid allocation when query is already normalized.
-fn normalize_query(query: &str) -> std::borrow::Cow<'_, str> {
-    use std::borrow::Cow;
-
-    let trimmed = query.trim();
-
-    // Fast path: check if already normalized (no consecutive whitespace)
-    let needs_normalization = trimmed
-        .as_bytes()
-        .windows(2)
-        .any(|w| w[0].is_ascii_whitespace() && w[1].is_ascii_whitespace())
-        || trimmed
-            .bytes()
-            .any(|b| b == b'\n' || b == b'\t' || b == b'\r');
-
-    if !needs_normalization {
-        return Cow::Borrowed(trimmed);
-    }
-
-    // Slow path: normalize whitespace
-    let mut result = String::with_capacity(trimmed.len());
-    let mut last_was_space = false;
-
-    for c in trimmed.chars() {
-        if c.is_whitespace() {
-            if !last_was_space {
-                result.push(' ');
-                last_was_space = true;
-            }
+/// Tokens are joined with single spaces, so whitespace differences between
+/// them don't matter; string literals and quoted identifiers keep theirs.
+/// In SELECT, INSERT, UPDATE and DELETE statements, literals in the
+/// outermost WHERE, SET and VALUES clauses that are compared against or are
+/// whole IN list or VALUES entries get a placeholder in `key`. The same
+/// text read with other identifier quoting gets other keys.
+pub fn normalize_query(query: &str, identifier_quote: IdentifierQuote) -> NormalizedQuery<'_> {
+    let chars: Vec<char> = query.chars().collect();
+    let mut lexer = Lexer::with_identifier_quote(query, identifier_quote);
+    let mut tokens = Vec::new();
+    loop {
+        let token = lexer.next_token();
+        if token.token_type == TokenType::Eof {
+            break;
+        }
+        tokens.push(token);
+    }
+
+    let literal_indices = parameterizable_literals(&tokens);
+
+    let mut key = String::with_capacity(query.len());
+    let mut exact_key = String::with_capacity(query.len());
+    let mut literals = Vec::with_capacity(literal_indices.len());
+    let mut next_literal = literal_indices.iter().peekable();
+    for (i, token) in tokens.iter().enumerate() {
+        let end = tokens
+            .get(i + 1)
+            .map_or(chars.len(), |next| next.position.offset);
+        let text: String = chars[token.position.offset..end].iter().collect();
+        let text = text.trim_end();
+
+        if !exact_key.is_empty() && !exact_key.ends_with('\n') {
+            exact_key.push(' ');
+            key.push(' ');
+        }
+        exact_key.push_str(text);
+        if next_literal.next_if(|&&index| index == i).is_some() {
+            key.push_str(match token.token_type {
+                TokenType::Integer => "$?int",
+                TokenType::Float => "$?float",
+                _ => "$?text",
+            });
+            literals.push(token.clone());
         } else {
-            result.push(c);
-            last_was_space = false;
+            key.push_str(text);
+        }
+
+        // A line comment ends at the line break
+        if token.token_type == TokenType::Comment && !text.starts_with("/*") {
+            exact_key.push('\n');
+            key.push('\n');
+        }
+    }
+
+    NormalizedQuery {
+        query,
+        key: (identifier_quote, key),
+        exact_key: (identifier_quote, exact_key),
+        literals,
+    }
+}
+
+/// Indices of the literal tokens a cache key can have placeholders for
+fn parameterizable_literals(tokens: &[Token]) -> Vec<usize> {
+    let significant: Vec<usize> = (0..tokens.len())
+        .filter(|&i| tokens[i].token_type != TokenType::Comment)
+        .collect();
+    let keyword = |i: usize| {
+        (tokens[i].token_type == TokenType::Keyword).then(|| tokens[i].literal.to_uppercase())
+    };
+    let is_punct = |i: usize, punct: &str| {
+        tokens[i].token_type == TokenType::Punctuator && tokens[i].literal == punct
+    };
+
+    let Some(statement) = significant.first().and_then(|&i| keyword(i)) else {
+        return Vec::new();
+    };
+    if !matches!(
+        statement.as_str(),
+        "SELECT" | "INSERT" | "UPDATE" | "DELETE"
+    ) {
+        return Vec::new();
+    }
+    // Only single statements are cached
+    if significant
+        .iter()
+        .rev()
+        .skip_while(|&&i| is_punct(i, ";"))
+        .any(|&i| is_punct(i, ";"))
+    {
+        return Vec::new();
+    }
+
+    let mut frames = vec![KeyFrame {
+        clause: Some(KeyClause::Other),
+        list: false,
+        subquery: false,
+    }];
+    let mut literals = Vec::new();
+    for (n, &i) in significant.iter().enumerate() {
+        let token = &tokens[i];
+        let prev = n.checked_sub(1).map(|p| significant[p]);
+        let next = significant.get(n + 1).copied();
+        let depth = frames.len() - 1;
+
+        match token.token_type {
+            TokenType::Punctuator if token.literal == "(" => {
+                let own_clause = frames[depth].clause;
+                let list = prev.is_some_and(|p| {
+                    matches!(keyword(p).as_deref(), Some("IN") | Some("VALUES"))
+                        || (is_punct(p, ",") && own_clause == Some(KeyClause::Values))
+                });
+                frames.push(KeyFrame {
+                    clause: None,
+                    list,
+                    subquery: false,
+                });
+            }
+            TokenType::Punctuator if token.literal == ")" && depth > 0 => {
+                frames.pop();
+            }
+            TokenType::Keyword => {
+                let clause = match keyword(i).as_deref() {
+                    Some("WHERE") => KeyClause::Where,
+                    Some("SET") if statement == "UPDATE" && depth == 0 => KeyClause::Set,
+                    Some("VALUES") if statement == "INSERT" && depth == 0 => KeyClause::Values,
+                    Some("SELECT") => {
+                        if depth > 0 {
+                            frames[depth].subquery = true;
+                        }
+                        KeyClause::Other
+                    }
+                    Some(
+                        "FROM" | "JOIN" | "ON" | "USING" | "GROUP" | "HAVING" | "ORDER" | "LIMIT"
+                        | "OFFSET" | "FETCH" | "RETURNING" | "UNION" | "INTERSECT" | "EXCEPT"
+                        | "WINDOW" | "INTO" | "SET" | "VALUES" | "UPDATE" | "CONFLICT"
+                        | "DUPLICATE" | "DO" | "FOR",
+                    ) => KeyClause::Other,
+                    _ => continue,
+                };
+                frames[depth].clause = Some(clause);
+            }
+            TokenType::Integer | TokenType::Float | TokenType::String => {
+                let parses = match token.token_type {
+                    TokenType::Integer => token.literal.parse::<i64>().is_ok(),
+                    TokenType::Float => token.literal.parse::<f64>().is_ok(),
+                    _ => true,
+                };
+                let clause = frames.iter().rev().find_map(|frame| frame.clause);
+                let compared = prev.is_some_and(|p| {
+                    tokens[p].token_type == TokenType::Operator
+                        && matches!(
+                            tokens[p].literal.as_str(),
+                            "=" | "<>" | "!=" | "<" | ">" | "<=" | ">="
+                        )
+                });
+                let listed = frames[depth].list
+                    && prev.is_some_and(|p| is_punct(p, "(") || is_punct(p, ","))
+                    && next.is_some_and(|n| is_punct(n, ",") || is_punct(n, ")"));
+                if parses
+                    && matches!(
+                        clause,
+                        Some(KeyClause::Where | KeyClause::Set | KeyClause::Values)
+                    )
+                    && !frames.iter().any(|frame| frame.subquery)
+                    && (compared || listed)
+                {
+                    literals.push(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    literals
+}
+
+/// Whether every literal token is a literal of the statement, at its offset
+fn has_literals(statement: &Statement, literals: &[Token]) -> bool {
+    let mut found = vec![false; literals.len()];
+    let mut statement = statement.clone();
+    visit_value_expressions(&mut statement, &mut |expr| {
+        let Some((offset, token_type)) = literal_token(expr) else {
+            return;
+        };
+        for (token, found) in literals.iter().zip(found.iter_mut()) {
+            if token.position.offset == offset && token.token_type == token_type {
+                *found = true;
+            }
+        }
+    });
+    found.into_iter().all(|found| found)
+}
+
+/// Copies a cached statement with the literals at `offsets` replaced
+fn bind_literals(statement: &Statement, offsets: &[usize], literals: &[Token]) -> Statement {
+    let mut statement = statement.clone();
+    visit_value_expressions(&mut statement, &mut |expr| {
+        let Some((offset, _)) = literal_token(expr) else {
+            return;
+        };
+        let Some(index) = offsets.iter().position(|&o| o == offset) else {
+            return;
+        };
+        let token = literals[index].clone();
+        *expr = match token.token_type {
+            TokenType::Integer => Expression::IntegerLiteral(IntegerLiteral {
+                value: token.literal.parse().unwrap_or_default(),
+                token,
+            }),
+            TokenType::Float => Expression::FloatLiteral(FloatLiteral {
+                value: token.literal.parse().unwrap_or_default(),
+                token,
+            }),
+            _ => Expression::StringLiteral(StringLiteral::from_token(token)),
+        };
+    });
+    statement
+}
+
+/// Offset and token type of a literal expression
+fn literal_token(expr: &Expression) -> Option<(usize, TokenType)> {
+    match expr {
+        Expression::IntegerLiteral(lit) => Some((lit.token.position.offset, TokenType::Integer)),
+        Expression::FloatLiteral(lit) => Some((lit.token.position.offset, TokenType::Float)),
+        Expression::StringLiteral(lit) if lit.token.token_type == TokenType::String => {
+            Some((lit.token.position.offset, TokenType::String))
+        }
+        _ => None,
+    }
+}
+
+/// Calls `f` on the expressions of the clauses a cache key can have
+/// literal placeholders in
+fn visit_value_expressions(statement: &mut Statement, f: &mut dyn FnMut(&mut Expression)) {
+    match statement {
+        Statement::Select(stmt) => {
+            if let Some(expr) = stmt.where_clause.as_deref_mut() {
+                visit_expression(expr, f);
+            }
+        }
+        Statement::Insert(stmt) => {
+            for expr in stmt.values.iter_mut().flatten() {
+                visit_expression(expr, f);
+            }
+            if let Some(expr) = stmt
+                .select
+                .as_deref_mut()
+                .and_then(|select| select.where_clause.as_deref_mut())
+            {
+                visit_expression(expr, f);
+            }
+        }
+        Statement::Update(stmt) => {
+            for expr in stmt.updates.values_mut() {
+                visit_expression(expr, f);
+            }
+            if let Some(expr) = stmt.where_clause.as_deref_mut() {
+                visit_expression(expr, f);
+            }
+        }
+        Statement::Delete(stmt) => {
+            if let Some(expr) = stmt.where_clause.as_deref_mut() {
+                visit_expression(expr, f);
+            }
         }
+        _ => {}
     }
+}
 
-    Cow::Owned(result)
+/// Calls `f` on an expression and the operands it is built from
+fn visit_expression(expr: &mut Expression, f: &mut dyn FnMut(&mut Expression)) {
+    f(expr);
+    match expr {
+        Expression::Prefix(e) => visit_expression(&mut e.right, f),
+        Expression::Infix(e) => {
+            visit_expression(&mut e.left, f);
+            visit_expression(&mut e.right, f);
+        }
+        Expression::List(e) => e.elements.iter_mut().for_each(|e| visit_expression(e, f)),
+        Expression::ExpressionList(e) => e
+            .expressions
+            .iter_mut()
+            .for_each(|e| visit_expression(e, f)),
+        Expression::In(e) => {
+            visit_expression(&mut e.left, f);
+            visit_expression(&mut e.right, f);
+        }
+        Expression::Between(e) => {
+            visit_expression(&mut e.expr, f);
+            visit_expression(&mut e.lower, f);
+            visit_expression(&mut e.upper, f);
+        }
+        Expression::Like(e) => {
+            visit_expression(&mut e.left, f);
+            visit_expression(&mut e.pattern, f);
+        }
+        Expression::Case(e) => {
+            if let Some(value) = e.value.as_deref_mut() {
+                visit_expression(value, f);
+            }
+            for when in &mut e.when_clauses {
+                visit_expression(&mut when.condition, f);
+                visit_expression(&mut when.then_result, f);
+            }
+            if let Some(value) = e.else_value.as_deref_mut() {
+                visit_expression(value, f);
+            }
+        }
+        Expression::Cast(e) => visit_expression(&mut e.expr, f),
+        Expression::FunctionCall(e) => e.arguments.iter_mut().for_each(|e| visit_expression(e, f)),
+        Expression::Aliased(e) => visit_expression(&mut e.expression, f),
+        _ => {}
+    }
 }
 
 #[cfg(test)]
@@ -339,6 +790,8 @@ mod tests {
             limit: None,
             offset: None,
             set_operations: vec![],
+            hints: vec![],
+            lock: None,
         }))
     }
 
@@ -375,15 +828,16 @@ mod tests {
 
         cache.put("SELECT * FROM users", stmt, false, 0);
 
-        // Get multiple times - OPTIMIZATION: get() no longer updates stats
-        // to avoid write lock contention
         for _ in 0..5 {
             cache.get("SELECT * FROM users");
         }
+        cache.get("SELECT * FROM orders");
 
-        // Usage count is only incremented on put(), not get() (for performance)
+        // The initial put plus 5 hits
         let stats = cache.stats();
-        assert_eq!(stats.total_usage, 1); // Only initial put = 1
+        assert_eq!(stats.total_usage, 6);
+        assert_eq!(stats.hits, 5);
+        assert_eq!(stats.misses, 1);
     }
 
     #[test]
@@ -413,20 +867,168 @@ mod tests {
         assert!(cache.size() <= 5);
     }
 
+    #[test]
+    fn test_cache_evicts_least_recently_used() {
+        let cache = QueryCache::new(5);
+        let stmt = create_test_statement();
+
+        for i in 0..5 {
+            cache.put(&format!("SELECT {}", i), stmt.clone(), false, 0);
+        }
+        // Using the oldest entry keeps it over the ones cached after it
+        assert!(cache.get("SELECT 0").is_some());
+
+        cache.put("SELECT 5", stmt.clone(), false, 0);
+        assert!(cache.size() <= 5);
+        assert!(cache.get("SELECT 0").is_some());
+        assert!(cache.get("SELECT 5").is_some());
+        assert!(cache.get("SELECT 1").is_none());
+    }
+
+    #[test]
+    fn test_cache_resize_and_invalidate() {
+        let cache = QueryCache::new(10);
+        let stmt = create_test_statement();
+
+        for i in 0..10 {
+            cache.put(&format!("SELECT {}", i), stmt.clone(), false, 0);
+        }
+        cache.get("SELECT 0");
+
+        cache.set_max_size(3);
+        assert_eq!(cache.size(), 3);
+        assert!(cache.get("SELECT 0").is_some());
+        assert!(cache.get("SELECT 9").is_some());
+
+        cache.invalidate();
+        assert_eq!(cache.size(), 0);
+        assert_eq!(cache.stats().invalidations, 1);
+
+        // A zero-sized cache stores nothing
+        cache.set_max_size(0);
+        cache.put("SELECT 1", stmt, false, 0);
+        assert_eq!(cache.size(), 0);
+    }
+
+    fn normalize(query: &str) -> NormalizedQuery<'_> {
+        normalize_query(query, IdentifierQuote::default())
+    }
+
     #[test]
     fn test_normalize_query() {
         assert_eq!(
-            normalize_query("  SELECT  *  FROM  users  "),
+            normalize("  SELECT  *  FROM  users  ").exact_key.1,
             "SELECT * FROM users"
         );
         assert_eq!(
-            normalize_query("SELECT\n*\nFROM\nusers"),
+            normalize("SELECT\n*\nFROM\nusers").exact_key.1,
             "SELECT * FROM users"
         );
         assert_eq!(
-            normalize_query("SELECT\t*\t\tFROM users"),
+            normalize("SELECT\t*\t\tFROM users").exact_key.1,
             "SELECT * FROM users"
         );
+        // Whitespace inside string literals is kept
+        assert_eq!(normalize("SELECT 'a  b'").exact_key.1, "SELECT 'a  b'");
+        // A line comment isn't joined with the next line
+        assert_ne!(
+            normalize("-- x\nSELECT 1").exact_key.1,
+            normalize("-- x SELECT 1").exact_key.1
+        );
+    }
+
+    #[test]
+    fn test_normalize_query_literals() {
+        let query = normalize("SELECT * FROM t WHERE id = 5 AND name = 'a b' AND x > 1.5");
+        assert_eq!(
+            query.key.1,
+            "SELECT * FROM t WHERE id = $?int AND name = $?text AND x > $?float"
+        );
+        assert_eq!(
+            query.exact_key.1,
+            "SELECT * FROM t WHERE id = 5 AND name = 'a b' AND x > 1.5"
+        );
+        assert_eq!(query.literals.len(), 3);
+
+        let query = normalize("INSERT INTO t (a, b) VALUES (1, 'x'), (2, f(3))");
+        assert_eq!(
+            query.key.1,
+            "INSERT INTO t ( a , b ) VALUES ( $?int , $?text ) , ( $?int , f ( 3 ) )"
+        );
+
+        let query = normalize("UPDATE t SET a = 1 WHERE b IN (2, 3)");
+        assert_eq!(
+            query.key.1,
+            "UPDATE t SET a = $?int WHERE b IN ( $?int , $?int )"
+        );
+
+        // Select lists, function arguments, subqueries, ORDER BY and LIMIT
+        // keep their literals
+        let sql = "SELECT 1 , x FROM t WHERE f ( x , 2 ) = 3 \
+                   AND y IN ( SELECT z FROM u WHERE w = 4 ) ORDER BY 1 LIMIT 5";
+        assert_eq!(
+            normalize(sql).key.1,
+            sql.replace("= 3", "= $?int")
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+        );
+
+        // Other statements aren't parameterized
+        assert!(normalize("SET x = 1").literals.is_empty());
+        assert!(normalize("SELECT * FROM t WHERE a = 1; SELECT 2")
+            .literals
+            .is_empty());
+    }
+
+    #[test]
+    fn test_plans_shared_across_literals() {
+        let cache = QueryCache::new(100);
+        let parse = |sql: &str| Arc::new(crate::parser::parse_sql(sql).unwrap().remove(0));
+
+        let sql = "SELECT * FROM t WHERE id = 5 AND name IN ('a', 'b')";
+        cache.put(sql, parse(sql), false, 0);
+        assert_eq!(cache.size(), 1);
+
+        let other = "SELECT  * FROM t WHERE id = 7 AND name IN ('c', 'd')";
+        let plan = cache.get(other).unwrap();
+        assert_eq!(plan.statement.to_string(), parse(other).to_string());
+        let plan = cache.get(sql).unwrap();
+        assert_eq!(plan.statement.to_string(), parse(sql).to_string());
+
+        // Different literal types or list lengths don't share a plan
+        assert!(cache
+            .get("SELECT * FROM t WHERE id = 'x' AND name IN ('a', 'b')")
+            .is_none());
+        assert!(cache
+            .get("SELECT * FROM t WHERE id = 5 AND name IN ('a')")
+            .is_none());
+    }
+
+    #[test]
+    fn test_key_includes_identifier_quote() {
+        let cache = QueryCache::new(100);
+        let stmt = create_test_statement();
+
+        let sql = "SELECT \"a\" FROM users";
+        cache.put(sql, stmt, false, 0);
+        assert!(cache
+            .get_normalized(&normalize_query(sql, IdentifierQuote::DoubleQuote))
+            .is_some());
+        assert!(cache
+            .get_normalized(&normalize_query(sql, IdentifierQuote::Backtick))
+            .is_none());
+    }
+
+    #[test]
+    fn test_plan_without_literals_cached_for_exact_query() {
+        let cache = QueryCache::new(100);
+        let stmt = create_test_statement();
+
+        // The statement doesn't hold the query's literal, so it can't be shared
+        cache.put("SELECT * FROM users WHERE id = 1", stmt, false, 0);
+        assert!(cache.get("SELECT * FROM users WHERE id = 1").is_some());
+        assert!(cache.get("SELECT * FROM users WHERE id = 2").is_none());
     }
 
     #[test]
@@ -462,7 +1064,7 @@ mod tests {
         cache.put("SELECT 1", stmt.clone(), false, 0);
         cache.put("SELECT 2", stmt.clone(), false, 0);
 
-        // Access first query more - get() no longer updates stats (OPTIMIZATION)
+        // Access first query more
         for _ in 0..5 {
             cache.get("SELECT 1");
         }
@@ -470,7 +1072,7 @@ mod tests {
         let stats = cache.stats();
         assert_eq!(stats.size, 2);
         assert_eq!(stats.max_size, 100);
-        assert_eq!(stats.total_usage, 2); // Only 2 puts (gets don't update stats)
+        assert_eq!(stats.total_usage, 7); // 2 puts + 5 hits
     }
 
     #[test]
@@ -16,11 +16,13 @@
 //!
 //! This module provides result types for SQL query execution.
 
-use crate::core::{Result, Row, Value};
+use crate::core::{DataType, Result, Row, Value};
 use crate::parser::ast::Expression;
-use crate::storage::traits::QueryResult;
+use crate::storage::traits::{ColumnMeta, QueryResult};
 use rustc_hash::{FxHashMap, FxHasher};
 
+use super::column_meta::{build_columns_meta, DeclaredColumn};
+use super::context::{CancellationHandle, ExecutionContext, TimeoutGuard};
 use super::expression::RowFilter;
 
 /// Execution result for DML operations (INSERT, UPDATE, DELETE)
@@ -30,8 +32,12 @@ use super::expression::RowFilter;
 pub struct ExecResult {
     /// Number of rows affected
     affected: i64,
+    /// Number of rows changed (UPDATE may match rows it leaves unchanged)
+    changed: i64,
     /// Last insert ID (for auto-increment)
     insert_id: i64,
+    /// DDL skipped by IF [NOT] EXISTS
+    no_op: bool,
     /// Column names (empty for DML)
     columns: Vec<String>,
     /// Empty row for row() method
@@ -43,7 +49,9 @@ impl ExecResult {
     pub fn new(rows_affected: i64, last_insert_id: i64) -> Self {
         Self {
             affected: rows_affected,
+            changed: rows_affected,
             insert_id: last_insert_id,
+            no_op: false,
             columns: Vec::new(),
             empty_row: Row::new(),
         }
@@ -54,6 +62,15 @@ impl ExecResult {
         Self::new(0, 0)
     }
 
+    /// Create the result of a DDL statement that IF [NOT] EXISTS turned
+    /// into a no-op
+    pub fn no_op() -> Self {
+        Self {
+            no_op: true,
+            ..Self::new(0, 0)
+        }
+    }
+
     /// Create a result with just rows affected
     pub fn with_rows_affected(rows_affected: i64) -> Self {
         Self::new(rows_affected, 0)
@@ -63,6 +80,14 @@ impl ExecResult {
     pub fn with_last_insert_id(rows_affected: i64, last_insert_id: i64) -> Self {
         Self::new(rows_affected, last_insert_id)
     }
+
+    /// Create an UPDATE result with rows matched and rows changed
+    pub fn with_rows_changed(rows_affected: i64, rows_changed: i64) -> Self {
+        Self {
+            changed: rows_changed,
+            ..Self::new(rows_affected, 0)
+        }
+    }
 }
 
 impl QueryResult for ExecResult {
@@ -91,6 +116,14 @@ impl QueryResult for ExecResult {
         self.affected
     }
 
+    fn rows_changed(&self) -> i64 {
+        self.changed
+    }
+
+    fn is_no_op(&self) -> bool {
+        self.no_op
+    }
+
     fn last_insert_id(&self) -> i64 {
         self.insert_id
     }
@@ -774,6 +807,329 @@ impl QueryResult for ExprMappedResult {
     }
 }
 
+/// Result that reports the type and nullability of its columns
+///
+/// The first row is read ahead to type the columns the query does not
+/// declare, and handed out again on the first `next()`.
+pub struct TypedResult {
+    /// Underlying result
+    inner: Box<dyn QueryResult>,
+    /// Metadata for each column
+    meta: Vec<ColumnMeta>,
+    /// First row, read ahead and not yet returned
+    pending: Option<Row>,
+    /// Current row when it is the read-ahead one
+    current: Option<Row>,
+}
+
+impl TypedResult {
+    /// Create a typed result; `declared` holds the known column types
+    pub(crate) fn new(mut inner: Box<dyn QueryResult>, declared: &[DeclaredColumn]) -> Self {
+        let pending = inner.next().then(|| inner.take_row());
+        let meta = build_columns_meta(inner.columns(), declared, pending.as_ref());
+        Self {
+            inner,
+            meta,
+            pending,
+            current: None,
+        }
+    }
+}
+
+impl QueryResult for TypedResult {
+    fn columns(&self) -> &[String] {
+        self.inner.columns()
+    }
+
+    fn next(&mut self) -> bool {
+        self.current = self.pending.take();
+        self.current.is_some() || self.inner.next()
+    }
+
+    fn scan(&self, dest: &mut [Value]) -> Result<()> {
+        let Some(row) = &self.current else {
+            return self.inner.scan(dest);
+        };
+        if dest.len() != row.len() {
+            return Err(crate::core::Error::internal(format!(
+                "scan destination has {} values but row has {} columns",
+                dest.len(),
+                row.len()
+            )));
+        }
+        dest.clone_from_slice(row.as_slice());
+        Ok(())
+    }
+
+    fn row(&self) -> &Row {
+        match &self.current {
+            Some(row) => row,
+            None => self.inner.row(),
+        }
+    }
+
+    fn take_row(&mut self) -> Row {
+        match &mut self.current {
+            Some(row) => std::mem::take(row),
+            None => self.inner.take_row(),
+        }
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.pending = None;
+        self.current = None;
+        self.inner.close()
+    }
+
+    fn rows_affected(&self) -> i64 {
+        self.inner.rows_affected()
+    }
+
+    fn rows_changed(&self) -> i64 {
+        self.inner.rows_changed()
+    }
+
+    fn is_no_op(&self) -> bool {
+        self.inner.is_no_op()
+    }
+
+    fn last_insert_id(&self) -> i64 {
+        self.inner.last_insert_id()
+    }
+
+    fn columns_meta(&self) -> Option<&[ColumnMeta]> {
+        Some(&self.meta)
+    }
+
+    fn with_aliases(self: Box<Self>, aliases: FxHashMap<String, String>) -> Box<dyn QueryResult> {
+        Box::new(AliasedResult::new(self, aliases))
+    }
+}
+
+/// Result that keeps its statement's timeout running while it is read
+///
+/// Rows produced lazily are read after the statement has returned, so the
+/// timeout guard lives as long as the result does. Once the statement is
+/// cancelled the result stops and reports the error through `err()`.
+pub struct DeadlineResult {
+    /// Underlying result
+    inner: Box<dyn QueryResult>,
+    /// Context of the statement, for its cancellation flag and timeout
+    ctx: ExecutionContext,
+    /// Timeout registration, cleared when the result is dropped
+    _guard: TimeoutGuard,
+    /// Why the rows ended early
+    error: Option<crate::core::Error>,
+}
+
+impl DeadlineResult {
+    /// Wrap `inner`, keeping `guard` alive until the result is dropped
+    pub(crate) fn new(
+        inner: Box<dyn QueryResult>,
+        ctx: ExecutionContext,
+        guard: TimeoutGuard,
+    ) -> Self {
+        Self {
+            inner,
+            ctx,
+            _guard: guard,
+            error: None,
+        }
+    }
+}
+
+impl QueryResult for DeadlineResult {
+    fn columns(&self) -> &[String] {
+        self.inner.columns()
+    }
+
+    fn next(&mut self) -> bool {
+        if self.error.is_some() {
+            return false;
+        }
+        // Inner results stop early when cancelled; tell that apart from the end
+        if let Err(err) = self.ctx.check_cancelled() {
+            self.error = Some(err);
+            return false;
+        }
+        if self.inner.next() {
+            return true;
+        }
+        self.error = self.ctx.check_cancelled().err();
+        false
+    }
+
+    fn scan(&self, dest: &mut [Value]) -> Result<()> {
+        self.inner.scan(dest)
+    }
+
+    fn row(&self) -> &Row {
+        self.inner.row()
+    }
+
+    fn take_row(&mut self) -> Row {
+        self.inner.take_row()
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.inner.close()
+    }
+
+    fn rows_affected(&self) -> i64 {
+        self.inner.rows_affected()
+    }
+
+    fn rows_changed(&self) -> i64 {
+        self.inner.rows_changed()
+    }
+
+    fn is_no_op(&self) -> bool {
+        self.inner.is_no_op()
+    }
+
+    fn last_insert_id(&self) -> i64 {
+        self.inner.last_insert_id()
+    }
+
+    fn err(&self) -> Option<&crate::core::Error> {
+        self.error.as_ref().or_else(|| self.inner.err())
+    }
+
+    fn columns_meta(&self) -> Option<&[ColumnMeta]> {
+        self.inner.columns_meta()
+    }
+
+    fn with_aliases(self: Box<Self>, aliases: FxHashMap<String, String>) -> Box<dyn QueryResult> {
+        Box::new(AliasedResult::new(self, aliases))
+    }
+}
+
+/// Result for a statement without result columns, read as a query
+///
+/// A statement that returns no rows (INSERT, UPDATE, DDL, SET, ...) reads as
+/// one row with a single INTEGER `rows_affected` column. Rows produced
+/// without column names get `column1`, `column2`, ... so a client never
+/// sees rows wider than the header.
+pub struct RowsAffectedResult {
+    /// Underlying result
+    inner: Box<dyn QueryResult>,
+    /// `rows_affected`, or generated names for unnamed rows
+    columns: Vec<String>,
+    /// Metadata for each column
+    meta: Vec<ColumnMeta>,
+    /// Row read ahead (or the rows_affected row) not yet returned
+    pending: Option<Row>,
+    /// Current row when it is the read-ahead one
+    current: Option<Row>,
+    /// Whether later rows come from the underlying result
+    passthrough: bool,
+}
+
+impl RowsAffectedResult {
+    /// Give `inner` a column shape if it has no columns; results that have
+    /// columns are returned unchanged
+    pub fn wrap(mut inner: Box<dyn QueryResult>) -> Box<dyn QueryResult> {
+        if !inner.columns().is_empty() {
+            return inner;
+        }
+        let first = inner.next().then(|| inner.take_row());
+        let (columns, meta, pending, passthrough) = match first {
+            Some(row) if !row.is_empty() => {
+                let columns: Vec<String> =
+                    (1..=row.len()).map(|i| format!("column{}", i)).collect();
+                let meta = build_columns_meta(&columns, &[], Some(&row));
+                (columns, meta, row, true)
+            }
+            _ => {
+                let columns = vec!["rows_affected".to_string()];
+                let meta = vec![ColumnMeta {
+                    name: columns[0].clone(),
+                    data_type: DataType::Integer,
+                    nullable: false,
+                }];
+                let row = Row::from_values(vec![Value::Integer(inner.rows_affected())]);
+                (columns, meta, row, false)
+            }
+        };
+        Box::new(Self {
+            inner,
+            columns,
+            meta,
+            pending: Some(pending),
+            current: None,
+            passthrough,
+        })
+    }
+}
+
+impl QueryResult for RowsAffectedResult {
+    fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    fn next(&mut self) -> bool {
+        self.current = self.pending.take();
+        self.current.is_some() || (self.passthrough && self.inner.next())
+    }
+
+    fn scan(&self, dest: &mut [Value]) -> Result<()> {
+        let row = self.row();
+        if dest.len() != row.len() {
+            return Err(crate::core::Error::internal(format!(
+                "scan destination has {} values but row has {} columns",
+                dest.len(),
+                row.len()
+            )));
+        }
+        dest.clone_from_slice(row.as_slice());
+        Ok(())
+    }
+
+    fn row(&self) -> &Row {
+        match &self.current {
+            Some(row) => row,
+            None => self.inner.row(),
+        }
+    }
+
+    fn take_row(&mut self) -> Row {
+        match &mut self.current {
+            Some(row) => std::mem::take(row),
+            None => self.inner.take_row(),
+        }
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.pending = None;
+        self.current = None;
+        self.inner.close()
+    }
+
+    fn rows_affected(&self) -> i64 {
+        self.inner.rows_affected()
+    }
+
+    fn rows_changed(&self) -> i64 {
+        self.inner.rows_changed()
+    }
+
+    fn is_no_op(&self) -> bool {
+        self.inner.is_no_op()
+    }
+
+    fn last_insert_id(&self) -> i64 {
+        self.inner.last_insert_id()
+    }
+
+    fn columns_meta(&self) -> Option<&[ColumnMeta]> {
+        Some(&self.meta)
+    }
+
+    fn with_aliases(self: Box<Self>, aliases: FxHashMap<String, String>) -> Box<dyn QueryResult> {
+        Box::new(AliasedResult::new(self, aliases))
+    }
+}
+
 /// Limited result that applies LIMIT and OFFSET to an underlying result
 pub struct LimitedResult {
     /// Underlying result
@@ -877,6 +1233,90 @@ impl QueryResult for LimitedResult {
     }
 }
 
+/// Result that fills ORDINAL() columns with the 1-based position of each
+/// row in the final output
+pub struct OrdinalResult {
+    /// Underlying result
+    inner: Box<dyn QueryResult>,
+    /// Column positions receiving the ordinal
+    positions: Vec<usize>,
+    /// Number of rows returned so far
+    count: i64,
+    /// Current row with ordinals filled in
+    current_row: Row,
+    /// Columns cached
+    columns: Vec<String>,
+}
+
+impl OrdinalResult {
+    /// Create a new ordinal result numbering the given column positions
+    pub fn new(inner: Box<dyn QueryResult>, positions: Vec<usize>) -> Self {
+        let columns = inner.columns().to_vec();
+        Self {
+            inner,
+            positions,
+            count: 0,
+            current_row: Row::new(),
+            columns,
+        }
+    }
+}
+
+impl QueryResult for OrdinalResult {
+    fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    fn next(&mut self) -> bool {
+        if !self.inner.next() {
+            return false;
+        }
+        self.count += 1;
+        self.current_row = self.inner.take_row();
+        for &pos in &self.positions {
+            if let Some(value) = self.current_row.get_mut(pos) {
+                *value = Value::Integer(self.count);
+            }
+        }
+        true
+    }
+
+    fn scan(&self, dest: &mut [Value]) -> Result<()> {
+        for (i, val) in dest.iter_mut().enumerate() {
+            *val = self
+                .current_row
+                .get(i)
+                .cloned()
+                .unwrap_or(Value::null_unknown());
+        }
+        Ok(())
+    }
+
+    fn row(&self) -> &Row {
+        &self.current_row
+    }
+
+    fn take_row(&mut self) -> Row {
+        std::mem::take(&mut self.current_row)
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.inner.close()
+    }
+
+    fn rows_affected(&self) -> i64 {
+        0
+    }
+
+    fn last_insert_id(&self) -> i64 {
+        0
+    }
+
+    fn with_aliases(self: Box<Self>, aliases: FxHashMap<String, String>) -> Box<dyn QueryResult> {
+        Box::new(AliasedResult::new(self, aliases))
+    }
+}
+
 /// Ordered result that sorts rows by ORDER BY expressions
 pub struct OrderedResult {
     /// Materialized and sorted rows
@@ -1527,6 +1967,8 @@ pub struct ScannerResult {
     current_row: Row,
     /// Whether we have a valid current row
     has_current: bool,
+    /// Stops the scan once the statement is cancelled
+    cancel: Option<CancellationHandle>,
 }
 
 impl ScannerResult {
@@ -1537,8 +1979,18 @@ impl ScannerResult {
             columns,
             current_row: Row::new(),
             has_current: false,
+            cancel: None,
         }
     }
+
+    /// End the scan early when the statement of `ctx` is cancelled
+    ///
+    /// Whoever reads the result checks the context to tell a cancelled scan
+    /// from a finished one.
+    pub fn with_cancellation(mut self, ctx: &ExecutionContext) -> Self {
+        self.cancel = Some(ctx.cancellation_handle());
+        self
+    }
 }
 
 impl QueryResult for ScannerResult {
@@ -1547,6 +1999,10 @@ impl QueryResult for ScannerResult {
     }
 
     fn next(&mut self) -> bool {
+        if self.cancel.as_ref().is_some_and(|c| c.is_cancelled()) {
+            self.has_current = false;
+            return false;
+        }
         if self.scanner.next() {
             self.current_row = self.scanner.take_row();
             self.has_current = true;
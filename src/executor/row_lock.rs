@@ -0,0 +1,111 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SELECT ... FOR UPDATE / FOR SHARE
+//!
+//! The rows matching the WHERE clause are locked in the storage layer before
+//! the query runs and stay locked until the transaction ends. A conflicting
+//! lock fails the statement immediately instead of waiting, the same way a
+//! write to a row another transaction has changed does. Outside an explicit
+//! transaction the locks are released straight away, so the statement only
+//! checks that no other transaction holds a conflicting lock.
+
+use crate::core::{Error, Result, RowLockMode, Schema};
+use crate::parser::ast::{Expression, SelectStatement};
+use crate::storage::expression::{EvalExpr, Expression as StorageExpr};
+
+use super::context::ExecutionContext;
+use super::expression::RowFilter;
+use super::pushdown;
+use super::Executor;
+
+impl Executor {
+    /// Locks the rows read by a SELECT ... FOR UPDATE / FOR SHARE
+    pub(crate) fn lock_selected_rows(
+        &self,
+        stmt: &SelectStatement,
+        mode: RowLockMode,
+        ctx: &ExecutionContext,
+    ) -> Result<()> {
+        let table_name = match stmt.table_expr.as_deref() {
+            Some(Expression::TableSource(source))
+                if source.as_of.is_none()
+                    && stmt.with.is_none()
+                    && stmt.set_operations.is_empty()
+                    && stmt.group_by.columns.is_empty()
+                    && !stmt.distinct =>
+            {
+                source.name.value_lower()
+            }
+            _ => {
+                return Err(Error::NotSupportedMessage(format!(
+                    "{} requires a single-table SELECT without DISTINCT, GROUP BY, \
+                     set operations or AS OF",
+                    mode
+                )))
+            }
+        };
+
+        let mut active_tx = self.active_transaction.lock().unwrap();
+        let (table, standalone_tx) = if let Some(ref mut tx_state) = *active_tx {
+            (tx_state.transaction.get_table(&table_name)?, None)
+        } else {
+            let tx = self.engine.begin_transaction()?;
+            (tx.get_table(&table_name)?, Some(tx))
+        };
+        drop(active_tx);
+
+        let where_expr = match stmt.where_clause.as_deref() {
+            Some(where_clause) => Some(self.lock_filter(where_clause, table.schema(), ctx)?),
+            None => None,
+        };
+        let locked = table.lock_rows(where_expr.as_deref(), mode);
+
+        // A standalone transaction has nothing to write; ending it drops the locks
+        if let Some(mut tx) = standalone_tx {
+            let _ = tx.rollback();
+        }
+        locked.map(|_| ())
+    }
+
+    /// Storage filter selecting the rows to lock
+    fn lock_filter(
+        &self,
+        where_clause: &Expression,
+        schema: &Schema,
+        ctx: &ExecutionContext,
+    ) -> Result<Box<dyn StorageExpr>> {
+        let where_clause = if Self::has_subqueries(where_clause) {
+            if Self::has_correlated_subqueries(where_clause) {
+                return Err(Error::NotSupportedMessage(
+                    "correlated subqueries are not supported with FOR UPDATE / FOR SHARE"
+                        .to_string(),
+                ));
+            }
+            self.process_where_subqueries(where_clause, ctx)?
+        } else {
+            where_clause.clone()
+        };
+
+        let (storage_expr, needs_memory_filter) =
+            pushdown::try_pushdown(&where_clause, schema, Some(ctx));
+        if let (Some(expr), false) = (storage_expr, needs_memory_filter) {
+            return Ok(expr);
+        }
+
+        // Not expressible as a storage predicate: evaluate it per row instead
+        let filter = RowFilter::new(&where_clause, schema.column_names_owned())?.with_context(ctx);
+        Ok(Box::new(EvalExpr::new(move |row| filter.matches(row))))
+    }
+}
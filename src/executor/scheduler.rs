@@ -23,7 +23,6 @@ use cron::Schedule;
 use crate::core::{DataType, Row, Value};
 use crate::executor::Executor;
 use crate::storage::jobs::{SYS_CRON, SYS_CRON_RUNS};
-use crate::storage::traits::Engine;
 
 /// Background worker for scheduling jobs using CRON expressions
 pub struct JobScheduler {
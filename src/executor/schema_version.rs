@@ -0,0 +1,106 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Schema versions for prepared statements
+//!
+//! Every table and view has a version, bumped when DDL alters, renames,
+//! replaces or drops it. A prepared statement records the versions of the
+//! objects it refers to when it is prepared, and executing it after one of
+//! them changed fails with [`Error::PlanStale`] instead of running against
+//! a schema it was not written for.
+
+use crate::core::{Error, Result};
+use crate::parser::ast::{AlterTableOperation, Statement};
+use crate::parser::{DependencyExtractor, Parser, Visitor};
+
+use super::Executor;
+
+/// Versions of the tables and views a statement refers to
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SchemaToken {
+    versions: Vec<(String, u64)>,
+}
+
+impl Executor {
+    /// Record the current versions of the tables and views `sql` refers to
+    ///
+    /// SQL that does not parse gets an empty token; executing it reports
+    /// the parse error.
+    pub(crate) fn schema_token(&self, sql: &str) -> SchemaToken {
+        let mut parser = Parser::with_identifier_quote(sql, self.identifier_quote());
+        let Ok(program) = parser.parse_program() else {
+            return SchemaToken::default();
+        };
+        let mut extractor = DependencyExtractor::new();
+        for statement in &program.statements {
+            extractor.visit_statement(statement);
+        }
+
+        let versions = self.schema_versions.lock().unwrap();
+        SchemaToken {
+            versions: extractor
+                .tables
+                .iter()
+                .map(|name| {
+                    let key = version_key(name);
+                    let version = versions.get(&key).copied().unwrap_or(0);
+                    (key, version)
+                })
+                .collect(),
+        }
+    }
+
+    /// Fail with [`Error::PlanStale`] if an object in `token` changed since
+    /// the token was taken
+    pub(crate) fn check_schema_token(&self, token: &SchemaToken) -> Result<()> {
+        let versions = self.schema_versions.lock().unwrap();
+        for (name, version) in &token.versions {
+            if versions.get(name).copied().unwrap_or(0) != *version {
+                return Err(Error::plan_stale(name.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Bump the versions of the objects a successful DDL statement changed
+    pub(crate) fn bump_schema_versions(&self, statement: &Statement) {
+        let changed = match statement {
+            Statement::AlterTable(stmt)
+                if stmt.operation != AlterTableOperation::SetAutoIncrement =>
+            {
+                let mut names = vec![stmt.table_name.value.clone()];
+                names.extend(stmt.new_table_name.as_ref().map(|n| n.value.clone()));
+                names
+            }
+            Statement::DropTable(stmt) => vec![stmt.table_name.value()],
+            Statement::CreateView(stmt) if stmt.or_replace => vec![stmt.view_name.value()],
+            Statement::DropView(stmt) => vec![stmt.view_name.value.clone()],
+            _ => return,
+        };
+
+        let mut versions = self.schema_versions.lock().unwrap();
+        for name in changed {
+            *versions.entry(version_key(&name)).or_insert(0) += 1;
+        }
+    }
+}
+
+/// Versions are keyed by lowercase name, without the default schema
+fn version_key(name: &str) -> String {
+    let name = name.to_lowercase();
+    match name.strip_prefix("public.") {
+        Some(unqualified) => unqualified.to_string(),
+        None => name,
+    }
+}
@@ -77,7 +77,7 @@ impl Executor {
 
             // Execute the right side query with incremented depth (part of same logical query)
             let set_ctx = ctx.with_incremented_query_depth();
-            let right_result = self.execute_select(&set_op.right, &set_ctx)?;
+            let right_result = self.execute_select_unnumbered(&set_op.right, &set_ctx)?;
 
             // Validate column count matches (SQL standard requirement)
             let right_col_count = right_result.columns().len();
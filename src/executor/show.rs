@@ -21,17 +21,26 @@
 //! - SHOW CREATE TABLE
 //! - SHOW CREATE VIEW
 //! - SHOW INDEXES
+//! - SHOW PROCEDURES
+//! - SHOW ENGINE STATUS
+//! - SHOW ROW VERSIONS
 //! - DESCRIBE
 
 use std::sync::Arc;
 
-use crate::core::{Error, Result, Row, Value};
+use rustc_hash::FxHashSet;
+
+use crate::core::{Error, Result, Row, Schema, Value};
 use crate::parser::{ast::*, Parser};
 use crate::storage::functions::StoredParameter;
+use crate::storage::mvcc::compression;
+use crate::storage::procedures::StoredProcedureParameter;
 
-use crate::storage::traits::{Engine, QueryResult};
+use crate::storage::traits::QueryResult;
 
 use super::context::ExecutionContext;
+use super::ddl::unqualified_public;
+use super::expression::ExpressionEval;
 use super::result::ExecutorMemoryResult;
 use super::Executor;
 
@@ -116,13 +125,15 @@ impl Executor {
         let table = tx.get_table(table_name)?;
         let schema = table.schema();
 
-        // Get unique column names from indexes
-        let mut unique_columns: std::collections::HashSet<String> =
-            std::collections::HashSet::new();
+        // Single-column unique indexes are shown as UNIQUE on the column
+        let mut unique_columns: FxHashSet<String> = FxHashSet::default();
         if let Ok(indexes) = self.engine.list_table_indexes(table_name) {
             for index_name in indexes.keys() {
+                // The PRIMARY KEY index is shown as the key itself
+                if schema.is_primary_key_index(index_name) {
+                    continue;
+                }
                 if let Some(index) = table.get_index(index_name) {
-                    // Only single-column unique indexes should be shown as UNIQUE constraint on column
                     let col_names = index.column_names();
                     if index.is_unique() && col_names.len() == 1 {
                         unique_columns.insert(col_names[0].to_lowercase());
@@ -130,41 +141,7 @@ impl Executor {
                 }
             }
         }
-
-        // Build CREATE TABLE statement
-        let mut create_sql = format!("CREATE TABLE {} (", table_name);
-        let col_defs: Vec<String> = schema
-            .columns
-            .iter()
-            .map(|col| {
-                let mut def = format!("{} {:?}", col.name, col.data_type);
-                if col.primary_key {
-                    def.push_str(" PRIMARY KEY");
-                    if col.auto_increment {
-                        def.push_str(" AUTO_INCREMENT");
-                    }
-                } else {
-                    // Check if this column has a UNIQUE constraint
-                    if unique_columns.contains(&col.name.to_lowercase()) {
-                        def.push_str(" UNIQUE");
-                    }
-                    if !col.nullable {
-                        def.push_str(" NOT NULL");
-                    }
-                }
-                // Add DEFAULT if present
-                if let Some(default_expr) = &col.default_expr {
-                    def.push_str(&format!(" DEFAULT {}", default_expr));
-                }
-                // Add CHECK constraint if present
-                if let Some(check) = &col.check_expr {
-                    def.push_str(&format!(" CHECK ({})", check));
-                }
-                def
-            })
-            .collect();
-        create_sql.push_str(&col_defs.join(", "));
-        create_sql.push(')');
+        let create_sql = create_table_sql(table_name, schema, &unique_columns);
 
         let columns = vec!["Table".to_string(), "Create Table".to_string()];
         let rows = vec![Row::from_values(vec![
@@ -262,13 +239,19 @@ impl Executor {
         Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
     }
 
-    /// Execute DESCRIBE statement - shows table structure
+    /// Execute DESCRIBE statement - shows table or view structure
     pub(crate) fn execute_describe(
         &self,
         stmt: &DescribeStatement,
-        _ctx: &ExecutionContext,
+        ctx: &ExecutionContext,
     ) -> Result<Box<dyn QueryResult>> {
         let table_name = &stmt.table_name.value();
+        if !self.engine.table_exists(table_name)? {
+            if let Some(result) = self.describe_view(table_name, ctx)? {
+                return Ok(result);
+            }
+        }
+
         let tx = self.engine.begin_transaction()?;
         let table = tx.get_table(table_name)?;
         let schema = table.schema();
@@ -292,7 +275,11 @@ impl Executor {
             let null_str = if col.nullable { "YES" } else { "NO" };
 
             // Determine key type
-            let key_str = if col.primary_key { "PRI" } else { "" };
+            let key_str = if schema.is_primary_key_column(&col.name) {
+                "PRI"
+            } else {
+                ""
+            };
 
             // Get default value if any
             let default_str = col
@@ -322,6 +309,66 @@ impl Executor {
         Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
     }
 
+    /// DESCRIBE a view: the columns its query projects, with inferred types
+    ///
+    /// Types are inferred as for CREATE TABLE ... AS SELECT, from the first
+    /// row of the view's query only. Returns None if there is no such view.
+    fn describe_view(
+        &self,
+        name: &str,
+        ctx: &ExecutionContext,
+    ) -> Result<Option<Box<dyn QueryResult>>> {
+        let schema_name = ctx.current_schema().unwrap_or("public").to_lowercase();
+        let Some(view_def) = self.engine.get_view(&schema_name, name)? else {
+            return Ok(None);
+        };
+
+        let mut parser = Parser::new(&view_def.query);
+        let program = parser.parse_program().map_err(|e| Error::Parse {
+            message: format!("view '{}': {}", view_def.original_name, e),
+        })?;
+        let Some(Statement::Select(select_stmt)) = program.statements.into_iter().next() else {
+            return Err(Error::internal(format!(
+                "view '{}' is not defined by a SELECT",
+                view_def.original_name
+            )));
+        };
+
+        let mut result = self.execute_select(&select_stmt, ctx)?;
+        let columns = result.columns().to_vec();
+        let mut rows = Vec::new();
+        if result.next() {
+            rows.push(result.take_row());
+        }
+
+        let columns_out = vec![
+            "Field".to_string(),
+            "Type".to_string(),
+            "Null".to_string(),
+            "Key".to_string(),
+            "Default".to_string(),
+            "Extra".to_string(),
+        ];
+        let rows_out = Self::infer_result_columns(&columns, &rows)
+            .into_iter()
+            .map(|(field, data_type)| {
+                Row::from_values(vec![
+                    Value::Text(Arc::from(field.as_str())),
+                    Value::Text(Arc::from(format!("{:?}", data_type).as_str())),
+                    Value::Text(Arc::from("YES")),
+                    Value::Text(Arc::from("")),
+                    Value::Text(Arc::from("")),
+                    Value::Text(Arc::from("")),
+                ])
+            })
+            .collect();
+
+        Ok(Some(Box::new(ExecutorMemoryResult::new(
+            columns_out,
+            rows_out,
+        ))))
+    }
+
     /// Execute SHOW FUNCTIONS statement
     pub(crate) fn execute_show_functions(
         &self,
@@ -429,4 +476,538 @@ impl Executor {
 
         Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
     }
+
+    /// Execute SHOW PROCEDURES statement
+    ///
+    /// Lists the stored procedures with their names as created.
+    pub(crate) fn execute_show_procedures(
+        &self,
+        _stmt: &ShowProceduresStatement,
+        ctx: &ExecutionContext,
+    ) -> Result<Box<dyn QueryResult>> {
+        let mut rows: Vec<Row> = Vec::new();
+        self.ensure_procedures_table_exists()?;
+
+        let sql = "SELECT name, parameters, language, code FROM system.procedures ORDER BY name";
+        let mut parser = Parser::new(sql);
+        let program = parser.parse_program().map_err(|e| Error::Parse {
+            message: format!("Failed to parse internal query: {}", e),
+        })?;
+        if let Some(Statement::Select(select_stmt)) = program.statements.into_iter().next() {
+            let mut result = self.execute_select(&select_stmt, ctx)?;
+            while result.next() {
+                let row = result.row();
+                if let (
+                    Some(Value::Text(name)),
+                    Some(Value::Text(params_json)),
+                    Some(Value::Text(language)),
+                    Some(Value::Text(code)),
+                ) = (row.get(0), row.get(1), row.get(2), row.get(3))
+                {
+                    let parameters: Vec<StoredProcedureParameter> =
+                        serde_json::from_str(params_json).map_err(|e| Error::Internal {
+                            message: format!("Failed to parse procedure parameters: {}", e),
+                        })?;
+
+                    // Format parameters as "(MODE name type, ...)"
+                    let args = format!(
+                        "({})",
+                        parameters
+                            .iter()
+                            .map(|p| format!("{} {} {}", p.mode, p.name, p.data_type))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    );
+
+                    rows.push(Row::from_values(vec![
+                        Value::Text(name.clone()),
+                        Value::Text(Arc::from(args)),
+                        Value::Text(language.clone()),
+                        Value::Text(code.clone()),
+                    ]));
+                }
+            }
+        }
+
+        let columns = vec![
+            "name".to_string(),
+            "args".to_string(),
+            "language".to_string(),
+            "body".to_string(),
+        ];
+        Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
+    }
+
+    /// Execute SHOW SEQUENCES statement
+    ///
+    /// Lists the sequences with their options and current values, ordered
+    /// by schema and name.
+    pub(crate) fn execute_show_sequences(
+        &self,
+        _stmt: &ShowSequencesStatement,
+    ) -> Result<Box<dyn QueryResult>> {
+        let mut sequences = self.engine.list_sequences()?;
+        sequences.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+
+        let rows = sequences
+            .into_iter()
+            .map(|(schema_name, name, options, current_value)| {
+                Row::from_values(vec![
+                    Value::text(schema_name),
+                    Value::text(name),
+                    Value::integer(options.start_with),
+                    Value::integer(options.increment_by),
+                    Value::integer(options.min_value),
+                    Value::integer(options.max_value),
+                    Value::Boolean(options.cycle),
+                    Value::integer(current_value),
+                ])
+            })
+            .collect();
+
+        let columns = vec![
+            "schema".to_string(),
+            "name".to_string(),
+            "start_value".to_string(),
+            "increment".to_string(),
+            "minimum_value".to_string(),
+            "maximum_value".to_string(),
+            "cycle".to_string(),
+            "current_value".to_string(),
+        ];
+        Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
+    }
+
+    /// Execute SHOW TRANSACTION ISOLATION LEVEL statement
+    ///
+    /// Returns the level of the open transaction, or the level the next
+    /// transaction gets when none is open.
+    pub(crate) fn execute_show_isolation_level(
+        &self,
+        _stmt: &ShowIsolationLevelStatement,
+    ) -> Result<Box<dyn QueryResult>> {
+        let level = match *self.active_transaction.lock().unwrap() {
+            Some(ref tx_state) => tx_state.transaction.isolation_level(),
+            None => self.next_isolation_level(),
+        };
+        Ok(isolation_level_result(level))
+    }
+
+    /// Execute SHOW <setting> statement
+    ///
+    /// Returns the connection's value of a setting in the form SET accepts.
+    pub(crate) fn execute_show_setting(
+        &self,
+        stmt: &ShowSettingStatement,
+    ) -> Result<Box<dyn QueryResult>> {
+        use std::sync::atomic::Ordering;
+
+        let name = stmt.name.value.to_lowercase();
+        let choose = |flag: bool, off: &str, on: &str| if flag { on } else { off }.to_string();
+        let value = match name.as_str() {
+            "statement_timeout" => self.statement_timeout_ms().to_string(),
+            "float_display_precision" => self
+                .float_display_precision()
+                .map_or_else(|| "default".to_string(), |digits| digits.to_string()),
+            "invalid_utf8" => choose(self.replace_invalid_utf8(), "error", "replace"),
+            "identifier_quote" => match self.identifier_quote() {
+                crate::parser::IdentifierQuote::DoubleQuote => "double_quote".to_string(),
+                crate::parser::IdentifierQuote::Backtick => "backtick".to_string(),
+            },
+            "routine_name_case" => choose(
+                self.preserve_routine_name_case.load(Ordering::Relaxed),
+                "upper",
+                "preserve",
+            ),
+            "error_verbosity" => choose(self.verbose_errors(), "default", "verbose"),
+            "div_by_zero" => choose(self.div_by_zero_null(), "error", "null"),
+            "implicit_order" => choose(self.implicit_pk_order(), "none", "pk"),
+            "search_path" => self.search_path.lock().unwrap().join(", "),
+            _ => {
+                return Err(Error::invalid_argument(format!(
+                    "unrecognized setting: {}",
+                    stmt.name.value
+                )))
+            }
+        };
+
+        let columns = vec![name];
+        let rows = vec![Row::from_values(vec![Value::text(value)])];
+        Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
+    }
+
+    /// Execute SHOW ENGINE STATUS statement
+    ///
+    /// Returns one row per metric as (section, object, metric, value):
+    /// engine-wide transaction state, the WAL, each active transaction and
+    /// each table. Rows claimed by a transaction stay locked against other
+    /// writers until it ends, so a transaction that holds claims and never
+    /// finishes shows up here as the source of "uncommitted changes" errors.
+    pub(crate) fn execute_show_engine_status(
+        &self,
+        _stmt: &ShowEngineStatusStatement,
+    ) -> Result<Box<dyn QueryResult>> {
+        let mvcc = self.mvcc()?;
+        let registry = mvcc.registry();
+        let active = registry.active_transactions();
+        let owners = mvcc.uncommitted_row_owners();
+
+        let mut rows = Vec::new();
+        let mut push = |section: &str, object: Option<String>, metric: &str, value: Value| {
+            rows.push(Row::from_values(vec![
+                Value::text(section),
+                object.map_or_else(|| Value::Null(crate::core::DataType::Text), Value::text),
+                Value::text(metric),
+                value,
+            ]));
+        };
+        let integer = |value: Option<i64>| value.map_or_else(Value::null_unknown, Value::Integer);
+
+        let oldest = active.first();
+        push(
+            "engine",
+            None,
+            "active_transactions",
+            Value::Integer(active.len() as i64),
+        );
+        push(
+            "engine",
+            None,
+            "committing_transactions",
+            Value::Integer(registry.committing_count() as i64),
+        );
+        push(
+            "engine",
+            None,
+            "current_sequence",
+            Value::Integer(registry.current_commit_sequence()),
+        );
+        push(
+            "engine",
+            None,
+            "oldest_active_transaction",
+            integer(oldest.map(|&(txn_id, _)| txn_id)),
+        );
+        push(
+            "engine",
+            None,
+            "oldest_active_snapshot",
+            integer(oldest.map(|&(_, begin_seq)| begin_seq)),
+        );
+
+        let wal = mvcc.wal_status();
+        push(
+            "wal",
+            None,
+            "size_bytes",
+            integer(wal.map(|(size, _)| size as i64)),
+        );
+        push("wal", None, "lsn", integer(wal.map(|(_, lsn)| lsn as i64)));
+
+        for &(txn_id, begin_seq) in &active {
+            let claimed = owners
+                .iter()
+                .flat_map(|(_, txns)| txns)
+                .filter(|&&owner| owner == txn_id)
+                .count();
+            push(
+                "transaction",
+                Some(txn_id.to_string()),
+                "begin_sequence",
+                Value::Integer(begin_seq),
+            );
+            push(
+                "transaction",
+                Some(txn_id.to_string()),
+                "uncommitted_rows",
+                Value::Integer(claimed as i64),
+            );
+        }
+
+        for (table, stats) in mvcc.vacuum_stats() {
+            let claimed = owners
+                .iter()
+                .find(|(name, _)| *name == table)
+                .map_or(0, |(_, txns)| txns.len());
+            push(
+                "table",
+                Some(table.clone()),
+                "live_versions",
+                Value::Integer(stats.live_versions as i64),
+            );
+            push(
+                "table",
+                Some(table.clone()),
+                "dead_versions",
+                Value::Integer(stats.dead_versions as i64),
+            );
+            push(
+                "table",
+                Some(table),
+                "uncommitted_rows",
+                Value::Integer(claimed as i64),
+            );
+        }
+
+        let columns = vec![
+            "section".to_string(),
+            "object".to_string(),
+            "metric".to_string(),
+            "value".to_string(),
+        ];
+        Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
+    }
+
+    /// Execute SHOW ROW VERSIONS statement
+    ///
+    /// Lists every stored version of the matching rows, newest first, with
+    /// the transaction that created it, the one that deleted it, its commit
+    /// status and whether the current transaction sees it, followed by the
+    /// row's columns. A row matches when any of its versions satisfies the
+    /// WHERE clause. The current transaction's own pending versions are
+    /// listed as `uncommitted`; a row claimed by another transaction gets an
+    /// `uncommitted` entry without data, since its changes are private to
+    /// that transaction.
+    pub(crate) fn execute_show_row_versions(
+        &self,
+        stmt: &ShowRowVersionsStatement,
+        ctx: &ExecutionContext,
+    ) -> Result<Box<dyn QueryResult>> {
+        let table_name = stmt.table_name.value.to_lowercase();
+        let mvcc = self.mvcc()?;
+        let store = mvcc.get_version_store(&table_name)?;
+        let registry = mvcc.registry();
+        let schema = store.schema();
+        let compressed = compression::compressed_columns(&schema);
+
+        // Visibility is judged for the current transaction, or for a new
+        // one outside an explicit transaction
+        let (viewer, pending) = {
+            let active_tx = self.active_transaction.lock().unwrap();
+            match active_tx.as_ref() {
+                Some(tx_state) => (
+                    tx_state.transaction.id(),
+                    tx_state
+                        .transaction
+                        .get_table(&table_name)?
+                        .get_pending_versions(),
+                ),
+                None => (self.engine.begin_transaction()?.id(), Vec::new()),
+            }
+        };
+
+        // Stored versions are padded or cut to the current columns, with
+        // COMPRESSED values decoded
+        let decode = |mut data: Row| {
+            let width = schema.columns.len();
+            if data.len() > width {
+                data.truncate(width);
+            }
+            while data.len() < width {
+                data.push(Value::null(schema.columns[data.len()].data_type));
+            }
+            compression::decompress_row(&mut data, &compressed, &schema);
+            data
+        };
+        let null_data = || {
+            Row::from_values(
+                schema
+                    .columns
+                    .iter()
+                    .map(|col| Value::null(col.data_type))
+                    .collect(),
+            )
+        };
+
+        let mut row_ids = store.get_all_row_ids();
+        let claims = store.row_claims();
+        row_ids.extend(claims.iter().map(|&(row_id, _)| row_id));
+        row_ids.extend(pending.iter().map(|(row_id, ..)| *row_id));
+        row_ids.sort_unstable();
+        row_ids.dedup();
+
+        let column_names: Vec<String> = schema.columns.iter().map(|c| c.name.clone()).collect();
+        let mut filter = match &stmt.where_clause {
+            Some(expr) => Some(ExpressionEval::compile(expr, &column_names)?.with_context(ctx)),
+            None => None,
+        };
+
+        let txn_value = |txn_id: i64| {
+            if txn_id == 0 {
+                Value::null(crate::core::DataType::Integer)
+            } else {
+                Value::Integer(txn_id)
+            }
+        };
+
+        let mut rows = Vec::new();
+        for row_id in row_ids {
+            // (created_by, deleted_by, status, visible, data)
+            let mut versions: Vec<(i64, i64, &str, bool, Row)> = Vec::new();
+
+            let own = pending.iter().find(|(id, ..)| *id == row_id);
+            if let Some((_, data, deleted, txn_id)) = own {
+                let deleted_by = if *deleted { *txn_id } else { 0 };
+                versions.push((
+                    *txn_id,
+                    deleted_by,
+                    "uncommitted",
+                    !deleted,
+                    decode(data.clone()),
+                ));
+            } else if let Some(&(_, owner)) = claims
+                .iter()
+                .find(|&&(id, owner)| id == row_id && owner != viewer)
+            {
+                versions.push((owner, 0, "uncommitted", false, null_data()));
+            }
+
+            // Only the newest version the viewer can see is visible, and
+            // none is when the viewer has its own pending version
+            let mut found_visible = own.is_some();
+            for version in store.version_chain(row_id) {
+                let status = if version.txn_id > 0
+                    && !registry.is_committed(version.txn_id)
+                    && registry.is_active(version.txn_id)
+                {
+                    "committing"
+                } else {
+                    "committed"
+                };
+                let mut visible = false;
+                if !found_visible && registry.is_visible(version.txn_id, viewer) {
+                    found_visible = true;
+                    visible = !(version.deleted_at_txn_id != 0
+                        && registry.is_visible(version.deleted_at_txn_id, viewer));
+                }
+                versions.push((
+                    version.txn_id,
+                    version.deleted_at_txn_id,
+                    status,
+                    visible,
+                    decode(version.data),
+                ));
+            }
+
+            if let Some(filter) = filter.as_mut() {
+                if !versions.iter().any(|(.., data)| filter.eval_bool(data)) {
+                    continue;
+                }
+            }
+
+            for (i, (created_by, deleted_by, status, visible, data)) in
+                versions.into_iter().enumerate()
+            {
+                let mut values = vec![
+                    Value::Integer(row_id),
+                    Value::Integer(i as i64 + 1),
+                    txn_value(created_by),
+                    txn_value(deleted_by),
+                    Value::text(status),
+                    Value::Boolean(visible),
+                ];
+                values.extend(data.into_values());
+                rows.push(Row::from_values(values));
+            }
+        }
+
+        let mut columns = vec![
+            "row_id".to_string(),
+            "version".to_string(),
+            "created_by".to_string(),
+            "deleted_by".to_string(),
+            "status".to_string(),
+            "visible".to_string(),
+        ];
+        columns.extend(column_names);
+        Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
+    }
+}
+
+/// Render the CREATE TABLE statement of a table, as SHOW CREATE TABLE
+/// and schema dumps show it
+///
+/// `unique_columns` (lowercase) are declared UNIQUE; a dump leaves them
+/// out and recreates their indexes instead.
+pub(crate) fn create_table_sql(
+    table_name: &str,
+    schema: &Schema,
+    unique_columns: &FxHashSet<String>,
+) -> String {
+    // Columns of a PRIMARY KEY enforced by an index, in key order
+    let indexed_key: Vec<&str> = match &schema.indexed_primary_key {
+        Some(key) => key.columns.iter().map(|c| c.as_str()).collect(),
+        None => Vec::new(),
+    };
+
+    let mut defs: Vec<String> = schema
+        .columns
+        .iter()
+        .map(|col| {
+            let mut def = format!("{} {:?}", col.name, col.data_type);
+            if col.compressed {
+                def.push_str(" COMPRESSED");
+            }
+            // Key columns are NOT NULL implicitly; a single key column
+            // enforced by an index is declared like an INTEGER key
+            let in_key = indexed_key
+                .iter()
+                .any(|key_col| key_col.eq_ignore_ascii_case(&col.name));
+            if col.primary_key || (in_key && indexed_key.len() == 1) {
+                def.push_str(" PRIMARY KEY");
+                if col.auto_increment {
+                    def.push_str(" AUTO_INCREMENT");
+                }
+            } else {
+                if unique_columns.contains(&col.name.to_lowercase()) {
+                    def.push_str(" UNIQUE");
+                }
+                if !col.nullable && !in_key {
+                    def.push_str(" NOT NULL");
+                }
+            }
+            if let Some(default_expr) = &col.default_expr {
+                def.push_str(&format!(" DEFAULT {}", default_expr));
+            }
+            if let Some(generated_expr) = &col.generated_expr {
+                def.push_str(&format!(" GENERATED ALWAYS AS ({}) STORED", generated_expr));
+            }
+            for check in &col.checks {
+                def.push_str(&format!(" {}", check));
+            }
+            def
+        })
+        .collect();
+
+    if indexed_key.len() > 1 {
+        defs.push(format!("PRIMARY KEY ({})", indexed_key.join(", ")));
+    }
+
+    for fk in &schema.foreign_keys {
+        if let Some(col) = schema.columns.get(fk.column_id) {
+            defs.push(format!(
+                "FOREIGN KEY ({}) REFERENCES {}({}) ON DELETE {} ON UPDATE {}",
+                col.name,
+                unqualified_public(&fk.referenced_table),
+                fk.referenced_column_name,
+                fk.on_delete,
+                fk.on_update
+            ));
+        }
+    }
+
+    format!("CREATE TABLE {} ({})", table_name, defs.join(", "))
+}
+
+/// Result of SHOW TRANSACTION ISOLATION LEVEL for `level`
+pub(crate) fn isolation_level_result(level: crate::core::IsolationLevel) -> Box<dyn QueryResult> {
+    // Named as SET TRANSACTION ISOLATION LEVEL accepts it
+    let name = match level {
+        crate::core::IsolationLevel::ReadCommitted => "READ COMMITTED",
+        crate::core::IsolationLevel::SnapshotIsolation => "SNAPSHOT",
+    };
+
+    let columns = vec!["transaction_isolation".to_string()];
+    let rows = vec![Row::from_values(vec![Value::text(name)])];
+    Box::new(ExecutorMemoryResult::new(columns, rows))
 }
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -29,7 +30,7 @@ use crate::storage::statistics::{
     is_stats_table, Histogram, DEFAULT_HISTOGRAM_BUCKETS, DEFAULT_SAMPLE_SIZE, SYS_COLUMN_STATS,
     SYS_TABLE_STATS,
 };
-use crate::storage::traits::{Engine, QueryResult, Transaction};
+use crate::storage::traits::{QueryResult, Transaction};
 
 use super::context::ExecutionContext;
 use super::result::ExecutorMemoryResult;
@@ -82,7 +83,7 @@ impl Executor {
                 Err(e) => {
                     let _ = tx.rollback();
                     // Log warning but continue with other tables
-                    eprintln!("Warning: Failed to analyze table '{}': {}", table_name, e);
+                    self.warn(format!("Failed to analyze table '{}': {}", table_name, e));
                     false
                 }
             };
@@ -275,6 +276,8 @@ impl Executor {
             Value::Text(s) => s.len() + 4, // string + length prefix
             Value::Timestamp(_) => 8,
             Value::Json(s) => s.len() + 4,
+            Value::Interval(_) => 8,
+            Value::Blob(b) => b.len() + 4,
         }
     }
 
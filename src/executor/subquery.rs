@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -26,7 +27,6 @@ use ahash::AHashSet;
 use crate::core::{Error, Result, Value};
 use crate::parser::ast::*;
 use crate::parser::token::TokenType;
-use crate::storage::traits::Engine;
 
 use super::context::{
     cache_exists_fetcher, cache_exists_index, cache_exists_pred_key, cache_exists_predicate,
@@ -225,6 +225,7 @@ impl Executor {
                     token: between.token.clone(),
                     expr: Box::new(processed_expr),
                     not: between.not,
+                    symmetric: between.symmetric,
                     lower: Box::new(processed_lower),
                     upper: Box::new(processed_upper),
                 }))
@@ -544,11 +545,31 @@ impl Executor {
         Ok(Some(false))
     }
 
+    /// Whether the subquery's LIMIT/OFFSET cannot change if it returns any row.
+    ///
+    /// A positive literal LIMIT without OFFSET keeps at least one row whenever
+    /// the unlimited query has one, so existence checks may ignore it. Anything
+    /// else (OFFSET, LIMIT 0, parameters) needs the subquery to run in full.
+    fn limit_keeps_existence(subquery: &SelectStatement) -> bool {
+        if subquery.offset.is_some() {
+            return false;
+        }
+        match subquery.limit.as_deref() {
+            None => true,
+            Some(Expression::IntegerLiteral(lit)) => lit.value > 0,
+            Some(_) => false,
+        }
+    }
+
     /// Extract index-nested-loop correlation info from a subquery.
     ///
     /// Looks for patterns like:
     /// SELECT 1 FROM orders WHERE orders.user_id = u.id [AND additional_predicates]
     fn extract_index_nested_loop_info(subquery: &SelectStatement) -> Option<IndexNestedLoopInfo> {
+        if !Self::limit_keeps_existence(subquery) {
+            return None;
+        }
+
         // Must have a simple table source
         let (inner_table, inner_alias) = match subquery.table_expr.as_ref().map(|b| b.as_ref()) {
             Some(Expression::TableSource(ts)) => {
@@ -1441,6 +1462,7 @@ impl Executor {
                     token: between.token.clone(),
                     expr: Box::new(processed_expr),
                     not: between.not,
+                    symmetric: between.symmetric,
                     lower: Box::new(processed_lower),
                     upper: Box::new(processed_upper),
                 }))
@@ -1726,6 +1748,7 @@ impl Executor {
                     token: between.token.clone(),
                     expr: Box::new(processed_expr),
                     not: between.not,
+                    symmetric: between.symmetric,
                     lower: Box::new(processed_lower),
                     upper: Box::new(processed_upper),
                 }))
@@ -1754,6 +1777,9 @@ impl Executor {
         outer_tables: &[String],
     ) -> Option<SemiJoinInfo> {
         let subquery = &exists.subquery;
+        if !Self::limit_keeps_existence(subquery) {
+            return None;
+        }
 
         // 1. Check for simple table source (not a join)
         let (inner_table, inner_alias) = match subquery.table_expr.as_ref().map(|b| b.as_ref()) {
@@ -2120,6 +2146,7 @@ impl Executor {
                 value_lower: a.to_lowercase(),
             }),
             as_of: None,
+            index_hint: None,
         });
 
         let select_stmt = SelectStatement {
@@ -2142,6 +2169,8 @@ impl Executor {
             limit: None,
             offset: None,
             set_operations: vec![],
+            hints: vec![],
+            lock: None,
         };
 
         // Execute the query with incremented depth to avoid creating new TimeoutGuard
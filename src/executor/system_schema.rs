@@ -50,8 +50,7 @@ impl Executor {
 
     /// Build system.tables result
     fn build_system_tables_result(&self) -> Result<Box<dyn QueryResult>> {
-        // Read directly from the in-memory engine catalog
-        let schemas_guard = self.engine.schemas.read().unwrap();
+        // Read directly from the engine catalog
 
         let columns = vec![
             "schema_name".to_string(),
@@ -62,8 +61,9 @@ impl Executor {
 
         let mut rows: Vec<Row> = Vec::new();
 
-        for (schema_name, table_map) in schemas_guard.iter() {
-            for (table_name, schema) in table_map.iter() {
+        for schema_name in self.engine.list_schemas()? {
+            for schema in self.engine.list_schema_tables(&schema_name)? {
+                let table_name = &schema.table_name_lower;
                 rows.push(Row::from_values(vec![
                     Value::Text(Arc::from(schema_name.as_str())),
                     Value::Text(Arc::from(table_name.as_str())),
@@ -78,8 +78,6 @@ impl Executor {
 
     /// Build system.columns result
     fn build_system_columns_result(&self) -> Result<Box<dyn QueryResult>> {
-        let schemas_guard = self.engine.schemas.read().unwrap();
-
         let columns = vec![
             "schema_name".to_string(),
             "table_name".to_string(),
@@ -93,8 +91,9 @@ impl Executor {
 
         let mut rows: Vec<Row> = Vec::new();
 
-        for (schema_name, table_map) in schemas_guard.iter() {
-            for (table_name, schema) in table_map.iter() {
+        for schema_name in self.engine.list_schemas()? {
+            for schema in self.engine.list_schema_tables(&schema_name)? {
+                let table_name = &schema.table_name_lower;
                 for (pos, col) in schema.columns.iter().enumerate() {
                     let ordinal = (pos + 1) as i64;
                     let data_type = format!("{:?}", col.data_type);
@@ -117,7 +116,7 @@ impl Executor {
                         Value::Integer(ordinal),
                         Value::Text(Arc::from(data_type.as_str())),
                         Value::Boolean(col.nullable),
-                        Value::Boolean(col.primary_key),
+                        Value::Boolean(schema.is_primary_key_column(&col.name)),
                         match default_value {
                             Some(s) => Value::Text(Arc::from(s.as_str())),
                             None => Value::Null(DataType::Text),
@@ -132,7 +131,7 @@ impl Executor {
 
     /// Build system.transactions result
     fn build_system_transactions_result(&self) -> Result<Box<dyn QueryResult>> {
-        let registry = self.engine.registry();
+        let registry = self.mvcc()?.registry();
 
         let columns = vec![
             "id".to_string(),
@@ -0,0 +1,199 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! UNNEST in the select list
+//!
+//! `SELECT id, UNNEST(tags) FROM t` returns one row per array element. The
+//! query runs with each `UNNEST(arr)` replaced by `arr`, then every result
+//! row is expanded. Several UNNEST calls in one select list are expanded side
+//! by side, padding the shorter arrays with NULLs. DISTINCT, LIMIT and OFFSET
+//! apply to the expanded rows, as does ORDER BY when it refers to an
+//! unnested column; other orderings are kept by the expansion.
+
+use crate::core::{Error, Result, Value};
+use crate::functions::tvf::unnest_values;
+use crate::parser::ast::{
+    AliasedExpression, Expression, FunctionCall, Identifier, OrderByExpression, SelectStatement,
+};
+use crate::storage::traits::QueryResult;
+
+use super::context::ExecutionContext;
+use super::expression::ExpressionEval;
+use super::result::{DistinctResult, ExecutorMemoryResult, LimitedResult};
+use super::Executor;
+
+/// The argument of a select-list `UNNEST(...)` call, if `expr` is one
+fn unnest_call(expr: &Expression) -> Option<&FunctionCall> {
+    let expr = match expr {
+        Expression::Aliased(aliased) => &aliased.expression,
+        other => other,
+    };
+    match expr {
+        Expression::FunctionCall(call) if call.function.eq_ignore_ascii_case("UNNEST") => {
+            Some(call)
+        }
+        _ => None,
+    }
+}
+
+/// Whether the select list of `stmt` calls UNNEST
+pub(crate) fn has_select_unnest(stmt: &SelectStatement) -> bool {
+    stmt.columns.iter().any(|c| unnest_call(c).is_some())
+}
+
+impl Executor {
+    /// Execute a SELECT whose select list calls UNNEST
+    pub(crate) fn execute_select_with_unnest(
+        &self,
+        stmt: &SelectStatement,
+        ctx: &ExecutionContext,
+    ) -> Result<Box<dyn QueryResult>> {
+        if !stmt.set_operations.is_empty() {
+            return Err(Error::NotSupportedMessage(
+                "UNNEST in the select list of a set operation".to_string(),
+            ));
+        }
+
+        // Replace each UNNEST(arr) by arr, keeping the output column name
+        let mut inner = stmt.clone();
+        let mut unnest_positions = Vec::new();
+        let mut unnest_names = Vec::new();
+        for (i, column) in inner.columns.iter_mut().enumerate() {
+            let Some(call) = unnest_call(column).cloned() else {
+                continue;
+            };
+            if call.arguments.len() != 1 {
+                return Err(Error::invalid_argument("UNNEST requires 1 argument"));
+            }
+            let alias = match column {
+                Expression::Aliased(aliased) => aliased.alias.clone(),
+                _ => Identifier::new(call.token.clone(), "unnest".to_string()),
+            };
+            unnest_positions.push(i);
+            unnest_names.push(alias.value_lower.clone());
+            *column = Expression::Aliased(AliasedExpression {
+                token: call.token.clone(),
+                expression: Box::new(call.arguments[0].clone()),
+                alias,
+            });
+        }
+
+        // Ordering by an unnested column has to wait for the expansion
+        let order_after = stmt
+            .order_by
+            .iter()
+            .any(|ob| orders_by_unnest(ob, &unnest_positions, &unnest_names));
+        let order_by = if order_after {
+            let mut order_by = std::mem::take(&mut inner.order_by);
+            // ORDER BY UNNEST(arr) sorts by the column it produced
+            for ob in &mut order_by {
+                if let Some(call) = unnest_call(&ob.expression) {
+                    let pos = stmt.columns.iter().position(|c| {
+                        unnest_call(c).is_some_and(|c| c.arguments == call.arguments)
+                    });
+                    if let Some(pos) = pos {
+                        let name = inner_column_name(&inner.columns[pos]);
+                        ob.expression =
+                            Expression::Identifier(Identifier::new(call.token.clone(), name));
+                    }
+                }
+            }
+            order_by
+        } else {
+            Vec::new()
+        };
+        inner.distinct = false;
+        inner.limit = None;
+        inner.offset = None;
+
+        let mut result = self.execute_select(&inner, ctx)?;
+        let columns = result.columns().to_vec();
+
+        let mut rows = Vec::new();
+        while result.next() {
+            let row = result.take_row();
+            let arrays = unnest_positions
+                .iter()
+                .map(|&pos| unnest_values(row.get(pos).unwrap_or(&Value::null_unknown())))
+                .collect::<Result<Vec<_>>>()?;
+            let len = arrays.iter().map(Vec::len).max().unwrap_or(0);
+            for element in 0..len {
+                let mut expanded = row.clone();
+                for (&pos, array) in unnest_positions.iter().zip(&arrays) {
+                    let value = array
+                        .get(element)
+                        .cloned()
+                        .unwrap_or_else(Value::null_unknown);
+                    expanded.set(pos, value)?;
+                }
+                rows.push(expanded);
+            }
+        }
+
+        if order_after {
+            rows = self.apply_order_by_to_rows(rows, &order_by, &columns)?;
+        }
+
+        let mut result: Box<dyn QueryResult> = Box::new(ExecutorMemoryResult::new(columns, rows));
+        if stmt.distinct {
+            result = Box::new(DistinctResult::new(result));
+        }
+        let limit = stmt
+            .limit
+            .as_ref()
+            .map(|l| eval_count(l, "LIMIT", ctx))
+            .transpose()?;
+        let offset = stmt
+            .offset
+            .as_ref()
+            .map(|o| eval_count(o, "OFFSET", ctx))
+            .transpose()?
+            .unwrap_or(0);
+        if limit.is_some() || offset > 0 {
+            result = Box::new(LimitedResult::new(result, limit, offset));
+        }
+        Ok(result)
+    }
+}
+
+/// Whether an ORDER BY key refers to an unnested output column
+fn orders_by_unnest(ob: &OrderByExpression, positions: &[usize], names: &[String]) -> bool {
+    match &ob.expression {
+        Expression::Identifier(id) => names.contains(&id.value_lower),
+        Expression::IntegerLiteral(lit) => positions.iter().any(|&p| p as i64 + 1 == lit.value),
+        expr => unnest_call(expr).is_some(),
+    }
+}
+
+/// Evaluate a LIMIT or OFFSET expression to a row count
+fn eval_count(expr: &Expression, clause: &str, ctx: &ExecutionContext) -> Result<usize> {
+    let value = ExpressionEval::compile(expr, &[])?
+        .with_context(ctx)
+        .eval_slice(&[])?;
+    match value.as_int64() {
+        Some(n) if n >= 0 => Ok(n as usize),
+        _ => Err(Error::ParseError(format!(
+            "{} must be a non-negative integer, got {}",
+            clause, value
+        ))),
+    }
+}
+
+/// Output name of a rewritten select-list UNNEST column
+fn inner_column_name(column: &Expression) -> String {
+    match column {
+        Expression::Aliased(aliased) => aliased.alias.value.clone(),
+        other => other.to_string(),
+    }
+}
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -26,13 +27,14 @@ use std::hash::{Hash, Hasher};
 
 use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 
-use crate::core::{DataType, Operator, Row, Value};
+use crate::core::{DataType, Error, Operator, Result, Row, Value};
 use crate::parser::ast::{
     BetweenExpression, BooleanLiteral, Expression, FloatLiteral, FunctionCall, Identifier,
     InExpression, InfixExpression, InfixOperator, IntegerLiteral, LikeExpression, ListExpression,
     NullLiteral, PrefixExpression, QualifiedIdentifier, StringLiteral,
 };
 use crate::parser::token::{Position, Token, TokenType};
+use crate::parser::{walk_expression, Visitor};
 
 // ============================================================================
 // Token Creation Utilities
@@ -183,6 +185,14 @@ pub fn hash_value_into<H: Hasher>(value: &Value, hasher: &mut H) {
             7u8.hash(hasher);
             j.hash(hasher);
         }
+        Value::Interval(micros) => {
+            8u8.hash(hasher);
+            micros.hash(hasher);
+        }
+        Value::Blob(bytes) => {
+            9u8.hash(hasher);
+            bytes.hash(hasher);
+        }
     }
 }
 
@@ -201,6 +211,8 @@ pub fn values_equal(a: &Value, b: &Value) -> bool {
         (Value::Null(_), Value::Null(_)) => false, // NULL != NULL in SQL
         (Value::Timestamp(x), Value::Timestamp(y)) => x == y,
         (Value::Json(x), Value::Json(y)) => x == y,
+        (Value::Interval(x), Value::Interval(y)) => x == y,
+        (Value::Blob(x), Value::Blob(y)) => x == y,
         // Cross-type comparisons - try numeric
         (Value::Integer(x), Value::Float(y)) | (Value::Float(y), Value::Integer(x)) => {
             (*x as f64 - y).abs() < f64::EPSILON
@@ -219,6 +231,8 @@ pub fn compare_values(a: &Value, b: &Value) -> Ordering {
         (Value::Null(_), Value::Null(_)) => Ordering::Equal,
         (Value::Timestamp(x), Value::Timestamp(y)) => x.cmp(y),
         (Value::Json(x), Value::Json(y)) => x.cmp(y),
+        (Value::Interval(x), Value::Interval(y)) => x.cmp(y),
+        (Value::Blob(x), Value::Blob(y)) => x.cmp(y),
         // Cross-type comparisons - try numeric
         (Value::Integer(x), Value::Float(y)) => {
             (*x as f64).partial_cmp(y).unwrap_or(Ordering::Equal)
@@ -240,6 +254,8 @@ pub fn compare_values(a: &Value, b: &Value) -> Ordering {
                     Value::Text(_) => 4,
                     Value::Timestamp(_) => 5,
                     Value::Json(_) => 6,
+                    Value::Interval(_) => 7,
+                    Value::Blob(_) => 8,
                 }
             }
             type_code(a).cmp(&type_code(b))
@@ -465,7 +481,9 @@ pub fn expressions_equivalent(a: &Expression, b: &Expression) -> bool {
             pa.operator == pb.operator && expressions_equivalent(&pa.right, &pb.right)
         }
         (Expression::Between(ba), Expression::Between(bb)) => {
-            expressions_equivalent(&ba.expr, &bb.expr)
+            ba.not == bb.not
+                && ba.symmetric == bb.symmetric
+                && expressions_equivalent(&ba.expr, &bb.expr)
                 && expressions_equivalent(&ba.lower, &bb.lower)
                 && expressions_equivalent(&ba.upper, &bb.upper)
         }
@@ -651,6 +669,7 @@ pub fn strip_table_qualifier(expr: &Expression, table_alias: &str) -> Expression
             lower: Box::new(strip_table_qualifier(&between.lower, table_alias)),
             upper: Box::new(strip_table_qualifier(&between.upper, table_alias)),
             not: between.not,
+            symmetric: between.symmetric,
         }),
         Expression::Like(like) => Expression::Like(LikeExpression {
             token: like.token.clone(),
@@ -735,6 +754,7 @@ pub fn add_table_qualifier(expr: &Expression, table_alias: &str) -> Expression {
             lower: Box::new(add_table_qualifier(&between.lower, table_alias)),
             upper: Box::new(add_table_qualifier(&between.upper, table_alias)),
             not: between.not,
+            symmetric: between.symmetric,
         }),
         Expression::Like(like) => Expression::Like(LikeExpression {
             token: like.token.clone(),
@@ -814,6 +834,73 @@ pub fn expression_contains_aggregate(expr: &Expression) -> bool {
     }
 }
 
+/// Validate FILTER clauses in an expression tree.
+/// FILTER is only meaningful on aggregate functions, and the filter predicate
+/// itself is evaluated per input row so it cannot contain aggregates.
+pub fn validate_aggregate_filters(expr: &Expression) -> Result<()> {
+    struct FilterValidator {
+        error: Option<Error>,
+    }
+
+    impl Visitor for FilterValidator {
+        fn visit_expression(&mut self, expr: &Expression) {
+            if self.error.is_some() {
+                return;
+            }
+            if let Expression::FunctionCall(func) = expr {
+                if let Some(ref filter) = func.filter {
+                    if !is_aggregate_function(&func.function) {
+                        self.error = Some(Error::invalid_argument(format!(
+                            "FILTER specified, but {} is not an aggregate function",
+                            func.function
+                        )));
+                        return;
+                    }
+                    if expression_contains_aggregate(filter) {
+                        self.error = Some(Error::invalid_argument(
+                            "aggregate functions are not allowed in FILTER",
+                        ));
+                        return;
+                    }
+                }
+            }
+            walk_expression(self, expr);
+        }
+    }
+
+    let mut validator = FilterValidator { error: None };
+    validator.visit_expression(expr);
+    validator.error.map_or(Ok(()), Err)
+}
+
+/// Collect the lowercased names of all columns an expression references.
+/// Qualifiers are dropped, so this is only meaningful for single-table expressions.
+pub fn collect_column_names(expr: &Expression) -> FxHashSet<String> {
+    struct ColumnCollector {
+        names: FxHashSet<String>,
+    }
+
+    impl Visitor for ColumnCollector {
+        fn visit_expression(&mut self, expr: &Expression) {
+            match expr {
+                Expression::Identifier(id) => {
+                    self.names.insert(id.value_lower.clone());
+                }
+                Expression::QualifiedIdentifier(qid) => {
+                    self.names.insert(qid.name.value_lower.clone());
+                }
+                _ => walk_expression(self, expr),
+            }
+        }
+    }
+
+    let mut collector = ColumnCollector {
+        names: FxHashSet::default(),
+    };
+    collector.visit_expression(expr);
+    collector.names
+}
+
 // ============================================================================
 // Column Index Utilities
 // ============================================================================
@@ -944,6 +1031,7 @@ pub fn string_to_datatype(type_str: &str) -> DataType {
         "BOOLEAN" | "BOOL" => DataType::Boolean,
         "TIMESTAMP" | "DATETIME" | "DATE" | "TIME" => DataType::Timestamp,
         "JSON" | "JSONB" => DataType::Json,
+        "BLOB" | "BYTEA" | "BINARY" | "VARBINARY" => DataType::Blob,
         _ => DataType::Text,
     }
 }
@@ -0,0 +1,667 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rewriting view queries for a renamed column
+//!
+//! A column reference is renamed only when it resolves to the renamed table:
+//! names are looked up through the FROM items of each query block, innermost
+//! block first, the way the executor resolves them. Derived tables, CTEs and
+//! select-list aliases shadow the table; correlated references from a
+//! subquery reach it through the enclosing blocks. A select item that was just
+//! the column keeps its output name with `AS <old name>`, so queries reading
+//! the view, or a derived table built on the query, are unaffected.
+//!
+//! When a reference can't be attributed to one FROM item, the rewrite fails
+//! instead of guessing.
+
+use crate::parser::ast::{
+    AliasedExpression, Expression, Identifier, JoinTableSource, OrderByExpression, SelectStatement,
+};
+
+/// Why a view query can't be rewritten
+pub(super) type RewriteError = String;
+
+/// Rename `table.old_name` to `new_name` in a view query
+///
+/// `table_columns` returns the columns of another table the query reads, or
+/// None if the name is not a table whose columns are known.
+pub(super) fn rename_view_column(
+    query: &mut SelectStatement,
+    table: &str,
+    old_name: &str,
+    new_name: &str,
+    table_columns: &dyn Fn(&str) -> Option<Vec<String>>,
+) -> Result<(), RewriteError> {
+    let mut renamer = ColumnRenamer {
+        table: table.to_lowercase(),
+        old_name: old_name.to_lowercase(),
+        new_name: new_name.to_string(),
+        table_columns,
+        scopes: Vec::new(),
+        ctes: Vec::new(),
+    };
+    renamer.select(query)
+}
+
+/// A FROM item of one query block, as far as name resolution is concerned
+enum Source {
+    /// The table whose column is renamed
+    Target { binding: String },
+    /// Any other relation, with its lowercase column names
+    Known {
+        binding: String,
+        name: String,
+        columns: Vec<String>,
+    },
+    /// A relation whose columns can't be determined
+    Unknown { binding: String },
+}
+
+impl Source {
+    fn binding(&self) -> &str {
+        match self {
+            Source::Target { binding }
+            | Source::Known { binding, .. }
+            | Source::Unknown { binding } => binding,
+        }
+    }
+}
+
+/// Where in a query block an expression sits
+#[derive(Clone, Copy, PartialEq)]
+enum Clause {
+    /// Select list, WHERE, join conditions, ...
+    Plain,
+    /// GROUP BY and HAVING, which see select-list aliases in some dialects
+    Grouping,
+    /// ORDER BY, where a select-list alias wins over a column
+    OrderBy,
+}
+
+struct ColumnRenamer<'a> {
+    table: String,
+    old_name: String,
+    new_name: String,
+    table_columns: &'a dyn Fn(&str) -> Option<Vec<String>>,
+    /// FROM items of the enclosing query blocks, innermost last
+    scopes: Vec<Vec<Source>>,
+    /// CTEs in scope: lowercase name and output columns (None if unknown)
+    ctes: Vec<Vec<(String, Option<Vec<String>>)>>,
+}
+
+impl ColumnRenamer<'_> {
+    fn select(&mut self, stmt: &mut SelectStatement) -> Result<(), RewriteError> {
+        let has_ctes = stmt.with.is_some();
+        if let Some(with) = &mut stmt.with {
+            // Output names survive the rewrite, so they are read up front
+            let frame = with
+                .ctes
+                .iter()
+                .map(|cte| {
+                    let columns = if cte.column_names.is_empty() {
+                        output_columns(&cte.query)
+                    } else {
+                        Some(
+                            cte.column_names
+                                .iter()
+                                .map(|c| c.value_lower.clone())
+                                .collect(),
+                        )
+                    };
+                    (cte.name.value_lower.clone(), columns)
+                })
+                .collect();
+            self.ctes.push(frame);
+            for cte in &mut with.ctes {
+                self.select(&mut cte.query)?;
+            }
+        }
+
+        let result = self.select_body(stmt);
+        if has_ctes {
+            self.ctes.pop();
+        }
+        result
+    }
+
+    fn select_body(&mut self, stmt: &mut SelectStatement) -> Result<(), RewriteError> {
+        // Derived tables only see the enclosing blocks, so they are rewritten
+        // before this block's scope is entered
+        let mut sources = Vec::new();
+        if let Some(table_expr) = &mut stmt.table_expr {
+            self.collect_sources(table_expr, &mut sources)?;
+        }
+        let has_target = sources.iter().any(|s| matches!(s, Source::Target { .. }));
+        self.scopes.push(sources);
+
+        let result = (|| {
+            if let Some(table_expr) = &mut stmt.table_expr {
+                self.join_expressions(table_expr, has_target)?;
+            }
+
+            let aliases: Vec<String> = stmt
+                .columns
+                .iter()
+                .filter_map(|c| match c {
+                    Expression::Aliased(a) => Some(a.alias.value_lower.clone()),
+                    _ => None,
+                })
+                .collect();
+
+            for column in &mut stmt.columns {
+                self.select_item(column)?;
+            }
+            if let Some(where_clause) = &mut stmt.where_clause {
+                self.expr(where_clause, Clause::Plain, &aliases)?;
+            }
+            for group in &mut stmt.group_by.columns {
+                self.expr(group, Clause::Grouping, &aliases)?;
+            }
+            if let Some(having) = &mut stmt.having {
+                self.expr(having, Clause::Grouping, &aliases)?;
+            }
+            for window in &mut stmt.window_defs {
+                for partition in &mut window.partition_by {
+                    self.expr(partition, Clause::Plain, &aliases)?;
+                }
+                self.order_by(&mut window.order_by, Clause::Plain, &aliases)?;
+            }
+            self.order_by(&mut stmt.order_by, Clause::OrderBy, &aliases)
+        })();
+        self.scopes.pop();
+        result?;
+
+        for set_op in &mut stmt.set_operations {
+            self.select(&mut set_op.right)?;
+        }
+        Ok(())
+    }
+
+    /// Record the FROM items of a block, rewriting derived tables on the way
+    fn collect_sources(
+        &mut self,
+        expr: &mut Expression,
+        sources: &mut Vec<Source>,
+    ) -> Result<(), RewriteError> {
+        match expr {
+            Expression::TableSource(source) => {
+                let name = source.name.value_lower();
+                let name = name.strip_prefix("public.").unwrap_or(&name).to_string();
+                let binding = source
+                    .alias
+                    .as_ref()
+                    .map(|a| a.value_lower.clone())
+                    .unwrap_or_else(|| source.name.table().to_lowercase());
+                sources.push(self.named_source(name, binding, source.name.schema().is_none()));
+            }
+            Expression::CteReference(cte) => {
+                let binding = cte.alias.as_ref().unwrap_or(&cte.name).value_lower.clone();
+                sources.push(self.named_source(cte.name.value_lower.clone(), binding, true));
+            }
+            Expression::JoinSource(join) => {
+                self.collect_sources(&mut join.left, sources)?;
+                self.collect_sources(&mut join.right, sources)?;
+            }
+            Expression::SubquerySource(subquery) => {
+                self.select(&mut subquery.subquery)?;
+                let binding = alias_of(subquery.alias.as_ref());
+                sources.push(match output_columns(&subquery.subquery) {
+                    Some(columns) => Source::Known {
+                        name: binding.clone(),
+                        binding,
+                        columns,
+                    },
+                    None => Source::Unknown { binding },
+                });
+            }
+            Expression::ValuesSource(values) => {
+                sources.push(aliased_source(
+                    alias_of(values.alias.as_ref()),
+                    &values.column_aliases,
+                ));
+            }
+            Expression::FunctionTableSource(func) => {
+                let binding = func
+                    .alias
+                    .as_ref()
+                    .map(|a| a.value_lower.clone())
+                    .unwrap_or_else(|| func.function.value_lower.clone());
+                sources.push(aliased_source(binding, &func.column_aliases));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Classify a FROM item given by name: a CTE, the renamed table or another table
+    fn named_source(&self, name: String, binding: String, may_be_cte: bool) -> Source {
+        if may_be_cte {
+            let cte = self
+                .ctes
+                .iter()
+                .rev()
+                .flat_map(|frame| frame.iter())
+                .find(|(cte_name, _)| *cte_name == name);
+            if let Some((_, columns)) = cte {
+                return match columns {
+                    Some(columns) => Source::Known {
+                        binding,
+                        name,
+                        columns: columns.clone(),
+                    },
+                    None => Source::Unknown { binding },
+                };
+            }
+        }
+        if name == self.table {
+            return Source::Target { binding };
+        }
+        match (self.table_columns)(&name) {
+            Some(columns) => Source::Known {
+                binding,
+                name,
+                columns: columns.iter().map(|c| c.to_lowercase()).collect(),
+            },
+            None => Source::Unknown { binding },
+        }
+    }
+
+    /// Rewrite the expressions of a FROM clause that see the block's own scope
+    fn join_expressions(
+        &mut self,
+        expr: &mut Expression,
+        has_target: bool,
+    ) -> Result<(), RewriteError> {
+        match expr {
+            Expression::JoinSource(join) => {
+                self.join_expressions(&mut join.left, has_target)?;
+                self.join_expressions(&mut join.right, has_target)?;
+                self.join(join, has_target)
+            }
+            Expression::FunctionTableSource(func) => {
+                for arg in &mut func.arguments {
+                    self.expr(arg, Clause::Plain, &[])?;
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn join(&mut self, join: &mut JoinTableSource, has_target: bool) -> Result<(), RewriteError> {
+        if has_target
+            && join
+                .using_columns
+                .iter()
+                .any(|c| c.value_lower == self.old_name)
+        {
+            return Err(format!("joins on it with USING ({})", self.old_name));
+        }
+        if let Some(condition) = &mut join.condition {
+            self.expr(condition, Clause::Plain, &[])?;
+        }
+        Ok(())
+    }
+
+    /// Rewrite a select-list item, keeping the output name of a bare column
+    fn select_item(&mut self, item: &mut Expression) -> Result<(), RewriteError> {
+        let old_output = match item {
+            Expression::Identifier(id) if id.value_lower == self.old_name => {
+                self.resolves_to_target(None)?.then(|| id.clone())
+            }
+            Expression::QualifiedIdentifier(qi) if qi.name.value_lower == self.old_name => self
+                .resolves_to_target(Some(&qi.qualifier.value_lower))?
+                .then(|| (*qi.name).clone()),
+            _ => None,
+        };
+        match old_output {
+            Some(alias) => {
+                let mut column = item.clone();
+                self.expr(&mut column, Clause::Plain, &[])?;
+                *item = Expression::Aliased(AliasedExpression {
+                    token: alias.token.clone(),
+                    expression: Box::new(column),
+                    alias,
+                });
+                Ok(())
+            }
+            None => self.expr(item, Clause::Plain, &[]),
+        }
+    }
+
+    fn order_by(
+        &mut self,
+        order_by: &mut [OrderByExpression],
+        clause: Clause,
+        aliases: &[String],
+    ) -> Result<(), RewriteError> {
+        for order in order_by {
+            self.expr(&mut order.expression, clause, aliases)?;
+        }
+        Ok(())
+    }
+
+    fn expr(
+        &mut self,
+        expr: &mut Expression,
+        clause: Clause,
+        aliases: &[String],
+    ) -> Result<(), RewriteError> {
+        match expr {
+            Expression::Identifier(id) => {
+                if id.value_lower != self.old_name {
+                    return Ok(());
+                }
+                let is_alias = aliases.contains(&id.value_lower);
+                if is_alias && clause == Clause::OrderBy {
+                    return Ok(());
+                }
+                if self.resolves_to_target(None)? {
+                    if is_alias {
+                        return Err(format!(
+                            "uses '{}' both as a column and as a select-list alias",
+                            self.old_name
+                        ));
+                    }
+                    self.rename(id);
+                }
+            }
+            Expression::QualifiedIdentifier(qi)
+                if qi.name.value_lower == self.old_name
+                    && self.resolves_to_target(Some(&qi.qualifier.value_lower))? =>
+            {
+                self.rename(&mut qi.name);
+            }
+            Expression::Prefix(prefix) => self.expr(&mut prefix.right, clause, aliases)?,
+            Expression::Infix(infix) => {
+                self.expr(&mut infix.left, clause, aliases)?;
+                self.expr(&mut infix.right, clause, aliases)?;
+            }
+            Expression::List(list) => {
+                for e in &mut list.elements {
+                    self.expr(e, clause, aliases)?;
+                }
+            }
+            Expression::Distinct(distinct) => self.expr(&mut distinct.expr, clause, aliases)?,
+            Expression::Exists(exists) => self.select(&mut exists.subquery)?,
+            Expression::AllAny(all_any) => {
+                self.expr(&mut all_any.left, clause, aliases)?;
+                self.select(&mut all_any.subquery)?;
+            }
+            Expression::In(in_expr) => {
+                self.expr(&mut in_expr.left, clause, aliases)?;
+                self.expr(&mut in_expr.right, clause, aliases)?;
+            }
+            Expression::InHashSet(in_hs) => self.expr(&mut in_hs.column, clause, aliases)?,
+            Expression::Between(between) => {
+                self.expr(&mut between.expr, clause, aliases)?;
+                self.expr(&mut between.lower, clause, aliases)?;
+                self.expr(&mut between.upper, clause, aliases)?;
+            }
+            Expression::Like(like) => {
+                self.expr(&mut like.left, clause, aliases)?;
+                self.expr(&mut like.pattern, clause, aliases)?;
+                if let Some(escape) = &mut like.escape {
+                    self.expr(escape, clause, aliases)?;
+                }
+            }
+            Expression::ScalarSubquery(subquery) => self.select(&mut subquery.subquery)?,
+            Expression::ExpressionList(list) => {
+                for e in &mut list.expressions {
+                    self.expr(e, clause, aliases)?;
+                }
+            }
+            Expression::Case(case) => {
+                if let Some(value) = &mut case.value {
+                    self.expr(value, clause, aliases)?;
+                }
+                for when in &mut case.when_clauses {
+                    self.expr(&mut when.condition, clause, aliases)?;
+                    self.expr(&mut when.then_result, clause, aliases)?;
+                }
+                if let Some(else_value) = &mut case.else_value {
+                    self.expr(else_value, clause, aliases)?;
+                }
+            }
+            Expression::Cast(cast) => self.expr(&mut cast.expr, clause, aliases)?,
+            Expression::FunctionCall(func) => {
+                for arg in &mut func.arguments {
+                    self.expr(arg, clause, aliases)?;
+                }
+                self.order_by(&mut func.order_by, Clause::Plain, aliases)?;
+                if let Some(filter) = &mut func.filter {
+                    self.expr(filter, clause, aliases)?;
+                }
+            }
+            Expression::Aliased(aliased) => self.expr(&mut aliased.expression, clause, aliases)?,
+            Expression::Window(window) => {
+                for arg in &mut window.function.arguments {
+                    self.expr(arg, clause, aliases)?;
+                }
+                if let Some(filter) = &mut window.function.filter {
+                    self.expr(filter, clause, aliases)?;
+                }
+                for partition in &mut window.partition_by {
+                    self.expr(partition, clause, aliases)?;
+                }
+                self.order_by(&mut window.order_by, clause, aliases)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn rename(&self, id: &mut Identifier) {
+        id.value = self.new_name.clone();
+        id.value_lower = self.new_name.to_lowercase();
+    }
+
+    /// Whether a reference to the old column name, optionally qualified,
+    /// resolves to the renamed table
+    fn resolves_to_target(&self, qualifier: Option<&str>) -> Result<bool, RewriteError> {
+        if let Some(qualifier) = qualifier {
+            let source = self
+                .scopes
+                .iter()
+                .rev()
+                .find_map(|scope| scope.iter().find(|s| s.binding() == qualifier));
+            return Ok(matches!(source, Some(Source::Target { .. })));
+        }
+
+        for (depth, scope) in self.scopes.iter().enumerate().rev() {
+            let mut target = false;
+            let mut other = None;
+            let mut unknown = false;
+            for source in scope {
+                match source {
+                    Source::Target { .. } => target = true,
+                    Source::Known { name, columns, .. } => {
+                        if columns.contains(&self.old_name) {
+                            other = Some(name);
+                        }
+                    }
+                    Source::Unknown { .. } => unknown = true,
+                }
+            }
+
+            match (target, other) {
+                (true, Some(other)) => {
+                    return Err(format!(
+                        "also reads a column '{}' from '{}'",
+                        self.old_name, other
+                    ))
+                }
+                (true, None) if unknown => {
+                    return Err(format!(
+                        "reads '{}' next to a source whose columns are unknown",
+                        self.old_name
+                    ))
+                }
+                (true, None) => return Ok(true),
+                (false, Some(_)) => return Ok(false),
+                (false, None) if unknown => {
+                    // Resolved here or further out; only a problem if further
+                    // out is the renamed table
+                    let outer_target = self.scopes[..depth]
+                        .iter()
+                        .flatten()
+                        .any(|s| matches!(s, Source::Target { .. }));
+                    if outer_target {
+                        return Err(format!(
+                            "reads '{}' in a subquery next to a source whose columns are unknown",
+                            self.old_name
+                        ));
+                    }
+                    return Ok(false);
+                }
+                (false, None) => {}
+            }
+        }
+        Ok(false)
+    }
+}
+
+/// Lowercase alias of a derived table, empty if it has none
+fn alias_of(alias: Option<&Identifier>) -> String {
+    alias.map(|a| a.value_lower.clone()).unwrap_or_default()
+}
+
+/// A VALUES list or table function: its columns are known only when aliased
+fn aliased_source(binding: String, column_aliases: &[Identifier]) -> Source {
+    if column_aliases.is_empty() {
+        Source::Unknown { binding }
+    } else {
+        Source::Known {
+            name: binding.clone(),
+            binding,
+            columns: column_aliases
+                .iter()
+                .map(|c| c.value_lower.clone())
+                .collect(),
+        }
+    }
+}
+
+/// Lowercase output column names of a query, None if a `*` hides them
+fn output_columns(stmt: &SelectStatement) -> Option<Vec<String>> {
+    let mut columns = Vec::with_capacity(stmt.columns.len());
+    for column in &stmt.columns {
+        match column {
+            Expression::Aliased(a) => columns.push(a.alias.value_lower.clone()),
+            Expression::Identifier(id) => columns.push(id.value_lower.clone()),
+            Expression::QualifiedIdentifier(qi) => columns.push(qi.name.value_lower.clone()),
+            Expression::Star(_) | Expression::QualifiedStar(_) => return None,
+            // Computed columns get generated names, which never match a column
+            _ => {}
+        }
+    }
+    Some(columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ast::Statement;
+    use crate::parser::parse_sql;
+
+    fn rename(sql: &str, table: &str, old: &str, new: &str) -> Result<String, RewriteError> {
+        let mut statements = parse_sql(sql).unwrap();
+        let Statement::Select(select) = &mut statements[0] else {
+            panic!("not a SELECT: {}", sql);
+        };
+        let columns = |name: &str| match name {
+            "teams" => Some(vec!["id".to_string(), "name".to_string()]),
+            "orders" => Some(vec!["id".to_string(), "user_id".to_string()]),
+            _ => None,
+        };
+        rename_view_column(select, table, old, new, &columns)?;
+        Ok(select.to_string())
+    }
+
+    #[test]
+    fn test_rename_view_column() {
+        let rewritten = rename(
+            "SELECT users.name, name AS n FROM users WHERE name <> 'name'",
+            "users",
+            "name",
+            "full_name",
+        )
+        .unwrap();
+        assert_eq!(
+            rewritten,
+            "SELECT users.full_name AS name, full_name AS n FROM users WHERE (full_name <> 'name')"
+        );
+
+        // Derived table columns and aliases are not the table's column
+        let sql = "SELECT name FROM (SELECT label AS name FROM users) AS d ORDER BY name ASC";
+        assert_eq!(rename(sql, "users", "name", "full_name").unwrap(), sql);
+        let rewritten = rename(
+            "SELECT d.name FROM (SELECT name FROM users) AS d",
+            "users",
+            "name",
+            "full_name",
+        )
+        .unwrap();
+        assert_eq!(
+            rewritten,
+            "SELECT d.name FROM (SELECT full_name AS name FROM users) AS d"
+        );
+
+        // Correlated references reach the table through the subquery
+        let rewritten = rename(
+            "SELECT id FROM users WHERE EXISTS (SELECT 1 FROM orders WHERE orders.user_id = id AND name = 'x')",
+            "users",
+            "name",
+            "full_name",
+        )
+        .unwrap();
+        assert!(rewritten.contains("full_name = 'x'"), "{}", rewritten);
+
+        // Qualifiers and table names are not column references
+        let sql = "SELECT name.id FROM name";
+        assert_eq!(rename(sql, "name", "name", "x").unwrap(), sql);
+    }
+
+    #[test]
+    fn test_rename_view_column_ambiguous() {
+        assert!(rename(
+            "SELECT name FROM users JOIN teams ON users.id = teams.id",
+            "users",
+            "name",
+            "full_name"
+        )
+        .is_err());
+        assert!(rename(
+            "SELECT name FROM users JOIN unknown_view ON users.id = unknown_view.id",
+            "users",
+            "name",
+            "full_name"
+        )
+        .is_err());
+
+        // Qualified references can be attributed
+        let rewritten = rename(
+            "SELECT users.name, teams.name AS team FROM users JOIN teams ON users.id = teams.id",
+            "users",
+            "name",
+            "full_name",
+        )
+        .unwrap();
+        assert!(
+            rewritten.starts_with("SELECT users.full_name AS name, teams.name AS team"),
+            "{}",
+            rewritten
+        );
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Session warnings (SHOW WARNINGS)
+//!
+//! Statements that succeed but have something to report (a DROP TABLE that
+//! cannot be undone, an ignored optimizer hint) record a warning on the
+//! session instead of failing. The buffer holds the warnings of the last
+//! top-level statement and is cleared when the next one starts, except by
+//! SHOW WARNINGS itself.
+
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::core::{Result, Row, Value};
+use crate::parser::ast::ShowWarningsStatement;
+use crate::storage::traits::QueryResult;
+
+use super::result::ExecutorMemoryResult;
+use super::Executor;
+
+/// Severity of a session warning
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarningLevel {
+    /// Informational, nothing went wrong
+    Note,
+    /// The statement did something other than what was asked
+    Warning,
+}
+
+impl fmt::Display for WarningLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WarningLevel::Note => write!(f, "Note"),
+            WarningLevel::Warning => write!(f, "Warning"),
+        }
+    }
+}
+
+/// A warning raised by the last statement of a session
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    pub level: WarningLevel,
+    pub message: String,
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.level, self.message)
+    }
+}
+
+/// Tracks statement nesting so only top-level statements reset the buffer
+pub(crate) struct StatementDepthGuard<'a> {
+    depth: &'a AtomicUsize,
+}
+
+impl<'a> StatementDepthGuard<'a> {
+    /// Enter a statement; returns the guard and whether it is top-level
+    pub(crate) fn enter(depth: &'a AtomicUsize) -> (Self, bool) {
+        let top_level = depth.fetch_add(1, Ordering::SeqCst) == 0;
+        (Self { depth }, top_level)
+    }
+}
+
+impl Drop for StatementDepthGuard<'_> {
+    fn drop(&mut self) {
+        self.depth.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl Executor {
+    /// Record a warning for the current statement
+    ///
+    /// Repeats of the same message (e.g. from a subquery run per row) are
+    /// recorded once.
+    pub(crate) fn warn(&self, message: impl Into<String>) {
+        self.push_warning(WarningLevel::Warning, message.into());
+    }
+
+    /// Record a note for the current statement
+    pub(crate) fn note(&self, message: impl Into<String>) {
+        self.push_warning(WarningLevel::Note, message.into());
+    }
+
+    fn push_warning(&self, level: WarningLevel, message: String) {
+        match level {
+            WarningLevel::Note => tracing::info!("{}", message),
+            WarningLevel::Warning => tracing::warn!("{}", message),
+        }
+        let mut warnings = self.warnings.lock().unwrap();
+        if !warnings
+            .iter()
+            .any(|w| w.level == level && w.message == message)
+        {
+            warnings.push(Warning { level, message });
+        }
+    }
+
+    /// Warnings raised by the last statement
+    pub fn warnings(&self) -> Vec<Warning> {
+        self.warnings.lock().unwrap().clone()
+    }
+
+    /// Discard the warnings of the previous statement
+    pub(crate) fn clear_warnings(&self) {
+        self.warnings.lock().unwrap().clear();
+    }
+
+    /// Execute SHOW WARNINGS
+    pub(crate) fn execute_show_warnings(
+        &self,
+        _stmt: &ShowWarningsStatement,
+    ) -> Result<Box<dyn QueryResult>> {
+        let columns = vec!["level".to_string(), "message".to_string()];
+        let rows = self
+            .warnings()
+            .into_iter()
+            .map(|w| {
+                Row::from_values(vec![
+                    Value::Text(Arc::from(w.level.to_string())),
+                    Value::Text(Arc::from(w.message)),
+                ])
+            })
+            .collect();
+        Ok(Box::new(ExecutorMemoryResult::new(columns, rows)))
+    }
+}
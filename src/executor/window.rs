@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -69,6 +70,8 @@ pub struct WindowFunctionInfo {
     pub column_index: usize,
     /// Whether DISTINCT was specified (for COUNT(DISTINCT col) OVER())
     pub is_distinct: bool,
+    /// FILTER clause for aggregate window functions (SUM(x) FILTER (WHERE ...) OVER ())
+    pub filter: Option<Expression>,
 }
 
 /// Information about a SELECT list item for window function processing
@@ -1206,6 +1209,7 @@ impl Executor {
             column_name,
             column_index: col_idx,
             is_distinct: func.is_distinct,
+            filter: func.filter.as_ref().map(|f| (**f).clone()),
         })
     }
 
@@ -1453,6 +1457,21 @@ impl Executor {
             None
         };
 
+        // Arguments that are not plain columns (e.g. LAG(price * qty)) are
+        // evaluated per row
+        let mut arg_eval = match wf_info.arguments.first() {
+            Some(arg)
+                if arg_col_idx.is_none()
+                    && matches!(
+                        wf_info.name.as_str(),
+                        "LEAD" | "LAG" | "FIRST_VALUE" | "LAST_VALUE" | "NTH_VALUE"
+                    ) =>
+            {
+                Some(ExpressionEval::compile(arg, columns)?.with_context(ctx))
+            }
+            _ => None,
+        };
+
         // Build partition values for the window function
         let partition_values: Vec<Value> = row_indices
             .iter()
@@ -1465,6 +1484,11 @@ impl Executor {
                         .cloned()
                         .unwrap_or_else(Value::null_unknown);
                 }
+                if let Some(ref mut eval) = arg_eval {
+                    return eval
+                        .eval(&all_rows[idx])
+                        .unwrap_or_else(|_| Value::null_unknown());
+                }
                 Value::null_unknown()
             })
             .collect();
@@ -1487,6 +1511,13 @@ impl Executor {
             vec![]
         };
 
+        // Peer groups (rows with equal ORDER BY values) for RANGE frames
+        let peer_groups = if order_by_values.is_empty() {
+            vec![]
+        } else {
+            Self::compute_peer_groups(&row_indices, order_by_values)
+        };
+
         // Compute window function for each row in the partition
         let mut results = Vec::with_capacity(row_indices.len());
         let partition_len = row_indices.len();
@@ -1506,8 +1537,17 @@ impl Executor {
                 "RANK" | "DENSE_RANK" => self.compute_rank(wf_info, &order_values, i)?,
                 "FIRST_VALUE" | "LAST_VALUE" | "NTH_VALUE" => {
                     // Compute frame bounds for navigation functions
-                    let (frame_start, frame_end) =
-                        self.compute_simple_frame_bounds(wf_info, i, partition_len);
+                    let (frame_start, frame_end) = self.compute_navigation_frame_bounds(
+                        wf_info,
+                        i,
+                        partition_len,
+                        &peer_groups,
+                        &order_values,
+                        order_by_values
+                            .get(row_idx)
+                            .and_then(|vals| vals.first())
+                            .is_none_or(|(_, ascending)| *ascending),
+                    );
 
                     // Get values within the frame
                     let frame_values: Vec<Value> =
@@ -1524,8 +1564,8 @@ impl Executor {
                         _ => unreachable!(),
                     }
                 }
-                "PERCENT_RANK" => self.compute_percent_rank(&order_values, i, row_indices.len())?,
-                "CUME_DIST" => self.compute_cume_dist(&order_values, i, row_indices.len())?,
+                "PERCENT_RANK" => self.compute_percent_rank(&peer_groups, i, row_indices.len())?,
+                "CUME_DIST" => self.compute_cume_dist(&peer_groups, i, row_indices.len())?,
                 _ => {
                     // ROW_NUMBER and other simple functions
                     window_func.process(&partition_values, &order_values, i)?
@@ -1547,16 +1587,18 @@ impl Executor {
         columns: &[String],
         ctx: &ExecutionContext,
     ) -> Result<Value> {
-        // Get offset (default 1)
+        // Get offset (default 1); a negative offset looks the other way
         let offset = if wf_info.arguments.len() > 1 {
             let mut eval = ExpressionEval::compile(&wf_info.arguments[1], &[])?.with_context(ctx);
             match eval.eval_slice(&[])? {
-                Value::Integer(n) => n as usize,
+                Value::Integer(n) => n,
                 _ => 1,
             }
         } else {
             1
         };
+        let forward = (wf_info.name == "LEAD") == (offset >= 0);
+        let offset = offset.unsigned_abs() as usize;
 
         // Get default value - use current row context for column references
         let default_value = if wf_info.arguments.len() > 2 {
@@ -1568,10 +1610,9 @@ impl Executor {
         };
 
         // Calculate target index
-        let target_idx = if wf_info.name == "LEAD" {
+        let target_idx = if forward {
             current_row.checked_add(offset)
         } else {
-            // LAG
             current_row.checked_sub(offset)
         };
 
@@ -1594,6 +1635,11 @@ impl Executor {
             let mut eval = ExpressionEval::compile(&wf_info.arguments[0], &[])?.with_context(ctx);
             match eval.eval_slice(&[])? {
                 Value::Integer(n) if n > 0 => n as usize,
+                Value::Integer(_) => {
+                    return Err(Error::invalid_argument(
+                        "argument of NTILE must be greater than zero",
+                    ))
+                }
                 _ => 1,
             }
         } else {
@@ -1739,156 +1785,176 @@ impl Executor {
         Ok(partition_values[index].clone())
     }
 
-    /// Compute simple ROWS-based frame bounds for navigation functions
-    /// Returns (start, end) where end is exclusive
-    fn compute_simple_frame_bounds(
+    /// Compute frame bounds for navigation functions (FIRST_VALUE, LAST_VALUE,
+    /// NTH_VALUE). Returns (start, end) where end is exclusive.
+    ///
+    /// ROWS frames count rows from the current one. RANGE frames extend
+    /// CURRENT ROW to all of its peers and compare `n PRECEDING/FOLLOWING`
+    /// offsets against the first ORDER BY value.
+    fn compute_navigation_frame_bounds(
         &self,
         wf_info: &WindowFunctionInfo,
         current_row: usize,
         partition_len: usize,
+        peer_groups: &[(usize, usize)],
+        order_values: &[Value],
+        ascending: bool,
     ) -> (usize, usize) {
-        if let Some(ref frame) = wf_info.frame {
-            // Only handle ROWS frames for now (not RANGE)
-            // Calculate start bound
-            let start = match &frame.start {
-                WindowFrameBound::UnboundedPreceding => 0,
-                WindowFrameBound::CurrentRow => current_row,
-                WindowFrameBound::Preceding(expr) => {
-                    if let Expression::IntegerLiteral(lit) = expr.as_ref() {
-                        current_row.saturating_sub(lit.value as usize)
-                    } else {
-                        0
-                    }
-                }
-                WindowFrameBound::Following(expr) => {
-                    if let Expression::IntegerLiteral(lit) = expr.as_ref() {
-                        (current_row + lit.value as usize).min(partition_len)
-                    } else {
-                        current_row
-                    }
-                }
-                WindowFrameBound::UnboundedFollowing => partition_len,
-            };
+        let peers = peer_groups
+            .get(current_row)
+            .copied()
+            .unwrap_or((current_row, current_row + 1));
 
-            // Calculate end bound (exclusive)
-            let end = match &frame.end {
-                Some(WindowFrameBound::UnboundedFollowing) => partition_len,
-                Some(WindowFrameBound::CurrentRow) => current_row + 1,
-                Some(WindowFrameBound::Following(expr)) => {
-                    if let Expression::IntegerLiteral(lit) = expr.as_ref() {
-                        (current_row + lit.value as usize + 1).min(partition_len)
-                    } else {
-                        partition_len
-                    }
-                }
-                Some(WindowFrameBound::Preceding(expr)) => {
-                    if let Expression::IntegerLiteral(lit) = expr.as_ref() {
-                        if lit.value as usize <= current_row {
-                            current_row - lit.value as usize + 1
-                        } else {
-                            0
-                        }
-                    } else {
-                        0
-                    }
-                }
-                Some(WindowFrameBound::UnboundedPreceding) => 0,
-                None => {
-                    // If no end is specified, default to CURRENT ROW
-                    current_row + 1
-                }
-            };
-
-            (start, end)
-        } else {
+        let Some(ref frame) = wf_info.frame else {
             // No explicit frame specified
             // SQL standard:
             // - With ORDER BY: default is RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW
             // - Without ORDER BY: default is the entire partition
             if wf_info.order_by.is_empty() {
-                // No ORDER BY - entire partition
-                (0, partition_len)
+                return (0, partition_len);
+            }
+            return (0, peers.1);
+        };
+        let is_range = matches!(frame.unit, WindowFrameUnit::Range);
+
+        let offset = |expr: &Expression| match expr {
+            Expression::IntegerLiteral(lit) => Some(lit.value),
+            _ => None,
+        };
+        // Index of the first row whose ORDER BY value is at or past `target`
+        // in sort order (RANGE offsets only)
+        let range_position = |delta: i64, inclusive_end: bool| -> Option<usize> {
+            let current = match order_values.get(current_row)? {
+                Value::Integer(v) => *v as f64,
+                Value::Float(v) => *v,
+                _ => return None,
+            };
+            let target = if ascending {
+                current + delta as f64
             } else {
-                // Has ORDER BY - default frame is UNBOUNDED PRECEDING to CURRENT ROW
-                (0, current_row + 1)
+                current - delta as f64
+            };
+            let before = |v: &Value| {
+                let v = match v {
+                    Value::Integer(v) => *v as f64,
+                    Value::Float(v) => *v,
+                    // NULLs and other values sort outside numeric ranges
+                    _ => return false,
+                };
+                let v_before = if ascending { v < target } else { v > target };
+                v_before || (inclusive_end && v == target)
+            };
+            Some(order_values.iter().take_while(|v| before(v)).count())
+        };
+
+        let start = match &frame.start {
+            WindowFrameBound::UnboundedPreceding => 0,
+            WindowFrameBound::CurrentRow if is_range => peers.0,
+            WindowFrameBound::CurrentRow => current_row,
+            WindowFrameBound::Preceding(expr) => match offset(expr) {
+                Some(n) if is_range => range_position(-n, false).unwrap_or(0),
+                Some(n) => current_row.saturating_sub(n as usize),
+                None => 0,
+            },
+            WindowFrameBound::Following(expr) => match offset(expr) {
+                Some(n) if is_range => range_position(n, false).unwrap_or(current_row),
+                Some(n) => (current_row + n as usize).min(partition_len),
+                None => current_row,
+            },
+            WindowFrameBound::UnboundedFollowing => partition_len,
+        };
+
+        // Calculate end bound (exclusive); no end means CURRENT ROW
+        let end = match frame.end.as_ref().unwrap_or(&WindowFrameBound::CurrentRow) {
+            WindowFrameBound::UnboundedFollowing => partition_len,
+            WindowFrameBound::CurrentRow if is_range => peers.1,
+            WindowFrameBound::CurrentRow => current_row + 1,
+            WindowFrameBound::Following(expr) => match offset(expr) {
+                Some(n) if is_range => range_position(n, true).unwrap_or(partition_len),
+                Some(n) => (current_row + n as usize + 1).min(partition_len),
+                None => partition_len,
+            },
+            WindowFrameBound::Preceding(expr) => match offset(expr) {
+                Some(n) if is_range => range_position(-n, true).unwrap_or(current_row + 1),
+                Some(n) => (current_row + 1).saturating_sub(n as usize),
+                None => 0,
+            },
+            WindowFrameBound::UnboundedPreceding => 0,
+        };
+
+        (start, end)
+    }
+
+    /// Precompute peer group boundaries for a sorted partition in O(n)
+    ///
+    /// Rows with the same ORDER BY values are peers; after sorting they are
+    /// adjacent. `peer_groups[i] = (start, end)` with `end` exclusive.
+    fn compute_peer_groups(
+        row_indices: &[usize],
+        order_by_values: &[Vec<(Value, bool)>],
+    ) -> Vec<(usize, usize)> {
+        let partition_len = row_indices.len();
+        let mut groups = Vec::with_capacity(partition_len);
+        if partition_len == 0 {
+            return groups;
+        }
+        let key = |i: usize| order_by_values[row_indices[i]].iter().map(|(v, _)| v);
+        let mut group_start = 0;
+        for i in 1..partition_len {
+            if !key(i).eq(key(group_start)) {
+                // New peer group starts - fill in previous group for all its members
+                groups.extend(std::iter::repeat_n((group_start, i), i - group_start));
+                group_start = i;
             }
         }
+        // Fill in the last group
+        groups.extend(std::iter::repeat_n(
+            (group_start, partition_len),
+            partition_len - group_start,
+        ));
+        groups
     }
 
     /// Compute PERCENT_RANK function
     /// PERCENT_RANK = (rank - 1) / (total_rows - 1)
-    /// The partition is already sorted by ORDER BY, so we calculate rank based on position
+    /// The partition is already sorted by ORDER BY, so the rank of a row is the
+    /// position of the first row in its peer group plus one
     fn compute_percent_rank(
         &self,
-        order_values: &[Value],
+        peer_groups: &[(usize, usize)],
         current_row: usize,
         partition_size: usize,
     ) -> Result<Value> {
-        // Use partition_size for the denominator when order_values is empty
-        let n = if order_values.is_empty() {
-            partition_size
-        } else {
-            order_values.len()
-        };
-
-        if n <= 1 {
+        if partition_size <= 1 {
             return Ok(Value::Float(0.0));
         }
 
-        // Calculate rank for current row
-        // Since partition is already sorted, rank is position + 1, with ties handled
-        let rank = if order_values.is_empty() || current_row == 0 {
-            1
-        } else {
-            let current_value = &order_values[current_row];
-            // Find the first row with the same value (this determines the rank for ties)
-            let mut first_same = current_row;
-            while first_same > 0 && order_values[first_same - 1] == *current_value {
-                first_same -= 1;
-            }
-            first_same + 1
-        };
+        // Without ORDER BY all rows are peers and share rank 1
+        let first_peer = peer_groups.get(current_row).map_or(0, |&(start, _)| start);
 
-        let percent_rank = (rank - 1) as f64 / (n - 1) as f64;
+        let percent_rank = first_peer as f64 / (partition_size - 1) as f64;
         Ok(Value::Float(percent_rank))
     }
 
     /// Compute CUME_DIST function
-    /// CUME_DIST = number of rows with value <= current / total rows
+    /// CUME_DIST = number of rows preceding or peer with current / total rows
     /// The partition is already sorted by ORDER BY
     fn compute_cume_dist(
         &self,
-        order_values: &[Value],
+        peer_groups: &[(usize, usize)],
         current_row: usize,
         partition_size: usize,
     ) -> Result<Value> {
-        // Use partition_size for the denominator when order_values is empty
-        let n = if order_values.is_empty() {
-            partition_size
-        } else {
-            order_values.len()
-        };
-
-        if n == 0 {
-            return Ok(Value::Float(1.0));
-        }
-
-        if order_values.is_empty() {
-            // No ordering - all rows are equal, so CUME_DIST is 1.0 for all
+        if partition_size == 0 {
             return Ok(Value::Float(1.0));
         }
 
-        let current_value = &order_values[current_row];
-
-        // Find the last row with value <= current value (including ties)
-        // Since sorted, find the last position with the same value
-        let mut last_same = current_row;
-        while last_same + 1 < order_values.len() && order_values[last_same + 1] == *current_value {
-            last_same += 1;
-        }
+        // Without ORDER BY all rows are peers, so CUME_DIST is 1.0 for all
+        let peers_end = peer_groups
+            .get(current_row)
+            .map_or(partition_size, |&(_, end)| end);
 
-        // CUME_DIST = (position of last row with same value + 1) / total rows
-        let cume_dist = (last_same + 1) as f64 / n as f64;
+        let cume_dist = peers_end as f64 / partition_size as f64;
         Ok(Value::Float(cume_dist))
     }
 
@@ -2146,6 +2212,16 @@ impl Executor {
             vec![]
         };
 
+        // Pre-compute FILTER clause results; rows failing the filter are not accumulated
+        let filter_mask: Vec<bool> = if let Some(ref filter) = wf_info.filter {
+            let mut eval = ExpressionEval::compile(filter, columns)?.with_context(ctx);
+            rows.iter()
+                .map(|row| matches!(eval.eval(row), Ok(Value::Boolean(true))))
+                .collect()
+        } else {
+            vec![]
+        };
+
         // Pre-compute partition column indices
         // OPTIMIZATION: Use SmallVec to avoid heap allocation for common cases
         // Try both qualified (e.g., "l.grp") and unqualified (e.g., "grp") names
@@ -2212,42 +2288,7 @@ impl Executor {
 
                 // OPTIMIZATION: Precompute peer group boundaries in O(n) instead of O(n²)
                 // For RANGE frames, rows with the same ORDER BY value are "peers"
-                // After sorting, peers are adjacent, so we can find boundaries in one pass
-                // peer_groups[i] = (start_idx, end_idx) where end_idx is exclusive
-                let peer_groups: Vec<(usize, usize)> = {
-                    let mut groups = Vec::with_capacity(partition_len);
-                    if partition_len == 0 {
-                        groups
-                    } else {
-                        let mut group_start = 0;
-                        // Use precomputed ORDER BY values (supports complex expressions)
-                        let mut prev_values: Vec<Value> = order_by_values[row_indices[0]]
-                            .iter()
-                            .map(|(v, _)| v.clone())
-                            .collect();
-
-                        for i in 1..partition_len {
-                            let current_values: Vec<Value> = order_by_values[row_indices[i]]
-                                .iter()
-                                .map(|(v, _)| v.clone())
-                                .collect();
-
-                            if current_values != prev_values {
-                                // New peer group starts - fill in previous group for all its members
-                                for _ in group_start..i {
-                                    groups.push((group_start, i));
-                                }
-                                group_start = i;
-                                prev_values = current_values;
-                            }
-                        }
-                        // Fill in the last group
-                        for _ in group_start..partition_len {
-                            groups.push((group_start, partition_len));
-                        }
-                        groups
-                    }
-                };
+                let peer_groups = Self::compute_peer_groups(&row_indices, &order_by_values);
 
                 for (i, &row_idx) in row_indices.iter().enumerate() {
                     let mut agg_func = self
@@ -2463,6 +2504,9 @@ impl Executor {
 
                     // Accumulate values within the frame
                     for &idx in &row_indices[frame_start..frame_end] {
+                        if !filter_mask.is_empty() && !filter_mask[idx] {
+                            continue;
+                        }
                         let value = if let Some(col_idx) = arg_col_idx {
                             rows[idx]
                                 .get(col_idx)
@@ -2493,6 +2537,9 @@ impl Executor {
 
                 // Accumulate all values in the partition
                 for &row_idx in &row_indices {
+                    if !filter_mask.is_empty() && !filter_mask[row_idx] {
+                        continue;
+                    }
                     let value = if let Some(col_idx) = arg_col_idx {
                         rows[row_idx]
                             .get(col_idx)
@@ -2760,6 +2807,7 @@ mod tests {
             column_name: "rn".to_string(),
             column_index: 0,
             is_distinct: false,
+            filter: None,
         };
 
         assert_eq!(info.name, "ROW_NUMBER");
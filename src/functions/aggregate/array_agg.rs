@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -165,6 +166,9 @@ impl AggregateFunction for ArrayAggFunction {
                 Value::Null(_) => "null".to_string(),
                 Value::Timestamp(t) => format!("\"{}\"", t),
                 Value::Json(j) => j.to_string(),
+                Value::Interval(_) => format!("\"{}\"", v),
+                // The \x prefix needs JSON escaping
+                Value::Blob(_) => serde_json::Value::String(v.to_string()).to_string(),
             })
             .collect();
         Value::text(format!("[{}]", json_elements.join(",")))
@@ -199,6 +203,7 @@ fn compare_values(a: &Value, b: &Value) -> std::cmp::Ordering {
         (Value::Text(a), Value::Text(b)) => a.cmp(b),
         (Value::Boolean(a), Value::Boolean(b)) => a.cmp(b),
         (Value::Timestamp(a), Value::Timestamp(b)) => a.cmp(b),
+        (Value::Interval(a), Value::Interval(b)) => a.cmp(b),
         _ => std::cmp::Ordering::Equal, // Different types compare equal
     }
 }
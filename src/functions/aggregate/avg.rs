@@ -14,7 +14,7 @@
 
 //! AVG aggregate function
 
-use crate::core::Value;
+use crate::core::{DataType, Value};
 use crate::functions::{
     AggregateFunction, FunctionDataType, FunctionInfo, FunctionSignature, FunctionType,
 };
@@ -23,12 +23,14 @@ use super::DistinctTracker;
 
 /// AVG aggregate function
 ///
-/// Returns the average of all non-NULL values in the specified column.
-/// Always returns a float64.
+/// Returns the average of all non-NULL values in the specified column:
+/// a float64 for numbers, an interval for intervals.
 #[derive(Default)]
 pub struct AvgFunction {
     sum: f64,
     count: i64,
+    /// The values are intervals, summed in microseconds
+    interval: bool,
     distinct_tracker: Option<DistinctTracker>,
 }
 
@@ -62,8 +64,13 @@ impl AggregateFunction for AvgFunction {
             }
         }
 
-        // Extract numeric value
+        // Extract numeric value; intervals don't mix with numbers
         let numeric_value = match value {
+            Value::Interval(micros) if self.count == 0 || self.interval => {
+                self.interval = true;
+                *micros as f64
+            }
+            _ if self.interval => return,
             Value::Integer(i) => *i as f64,
             Value::Float(f) => *f,
             _ => return, // Ignore non-numeric types
@@ -75,7 +82,9 @@ impl AggregateFunction for AvgFunction {
 
     fn result(&self) -> Value {
         if self.count == 0 {
-            Value::null_unknown() // Return NULL for empty sets
+            Value::Null(DataType::Float) // Return NULL for empty sets
+        } else if self.interval {
+            Value::Interval((self.sum / self.count as f64).round() as i64)
         } else {
             Value::Float(self.sum / self.count as f64)
         }
@@ -84,6 +93,7 @@ impl AggregateFunction for AvgFunction {
     fn reset(&mut self) {
         self.sum = 0.0;
         self.count = 0;
+        self.interval = false;
         self.distinct_tracker = None;
     }
 
@@ -142,6 +152,15 @@ mod tests {
         assert_eq!(avg.result(), Value::Float(2.0)); // (1 + 3) / 2
     }
 
+    #[test]
+    fn test_avg_intervals() {
+        let mut avg = AvgFunction::default();
+        avg.accumulate(&Value::Interval(1_000_000), false);
+        avg.accumulate(&Value::null_unknown(), false);
+        avg.accumulate(&Value::Interval(4_000_000), false);
+        assert_eq!(avg.result(), Value::Interval(2_500_000));
+    }
+
     #[test]
     fn test_avg_empty() {
         let avg = AvgFunction::default();
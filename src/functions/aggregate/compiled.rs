@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -35,18 +36,31 @@
 //!
 //! Expected speedup: 2-5x for aggregation-heavy queries.
 
-use crate::core::Value;
+use crate::core::{DataType, Value};
 use crate::functions::AggregateFunction;
 
 use super::DistinctTracker;
 
-/// Sum state for tracking integer vs float sums
+/// Sum state for tracking integer, float and interval sums
 #[derive(Debug, Clone, Default)]
 pub enum SumState {
     #[default]
     Empty,
+    /// Only NULLs of this type seen so far
+    Null(DataType),
     Integer(i64),
     Float(f64),
+    Interval(i64),
+}
+
+impl SumState {
+    /// Remember the type of a NULL input while nothing has been summed
+    #[inline]
+    fn accumulate_null(&mut self, value: &Value) {
+        if matches!(self, SumState::Empty) && value.data_type() != DataType::Null {
+            *self = SumState::Null(value.data_type());
+        }
+    }
 }
 
 /// Compiled aggregate function - enum-based specialization for zero virtual dispatch
@@ -73,13 +87,19 @@ pub enum CompiledAggregate {
         distinct_tracker: DistinctTracker,
     },
 
-    /// AVG(column) - average of numeric values
-    Avg { sum: f64, count: i64 },
+    /// AVG(column) - average of numeric or interval values
+    Avg {
+        sum: f64,
+        count: i64,
+        /// The values are intervals, so the average is one too
+        interval: bool,
+    },
 
-    /// AVG(DISTINCT column) - average of distinct numeric values
+    /// AVG(DISTINCT column) - average of distinct numeric or interval values
     AvgDistinct {
         sum: f64,
         count: i64,
+        interval: bool,
         distinct_tracker: DistinctTracker,
     },
 
@@ -129,19 +149,26 @@ impl std::fmt::Debug for CompiledAggregate {
                 .field("state", state)
                 .field("distinct_tracker", distinct_tracker)
                 .finish(),
-            CompiledAggregate::Avg { sum, count } => f
+            CompiledAggregate::Avg {
+                sum,
+                count,
+                interval,
+            } => f
                 .debug_struct("Avg")
                 .field("sum", sum)
                 .field("count", count)
+                .field("interval", interval)
                 .finish(),
             CompiledAggregate::AvgDistinct {
                 sum,
                 count,
+                interval,
                 distinct_tracker,
             } => f
                 .debug_struct("AvgDistinct")
                 .field("sum", sum)
                 .field("count", count)
+                .field("interval", interval)
                 .field("distinct_tracker", distinct_tracker)
                 .finish(),
             CompiledAggregate::Min { min_value } => {
@@ -210,10 +237,15 @@ impl CompiledAggregate {
             CompiledAggregate::AvgDistinct {
                 sum: 0.0,
                 count: 0,
+                interval: false,
                 distinct_tracker: DistinctTracker::default(),
             }
         } else {
-            CompiledAggregate::Avg { sum: 0.0, count: 0 }
+            CompiledAggregate::Avg {
+                sum: 0.0,
+                count: 0,
+                interval: false,
+            }
         }
     }
 
@@ -309,7 +341,9 @@ impl CompiledAggregate {
 
             // SUM(column) - add numeric values
             CompiledAggregate::Sum { state } => {
-                if !value.is_null() {
+                if value.is_null() {
+                    state.accumulate_null(value);
+                } else {
                     Self::accumulate_sum(state, value);
                 }
             }
@@ -319,60 +353,62 @@ impl CompiledAggregate {
                 state,
                 distinct_tracker,
             } => {
-                if !value.is_null() && distinct_tracker.check_and_add(value) {
+                if value.is_null() {
+                    state.accumulate_null(value);
+                } else if distinct_tracker.check_and_add(value) {
                     Self::accumulate_sum(state, value);
                 }
             }
 
             // AVG(column) - track sum and count
-            CompiledAggregate::Avg { sum, count } => {
-                if let Some(n) = Self::as_f64(value) {
-                    *sum += n;
-                    *count += 1;
-                }
-            }
+            CompiledAggregate::Avg {
+                sum,
+                count,
+                interval,
+            } => Self::accumulate_avg(sum, count, interval, value),
 
             // AVG(DISTINCT column) - track sum and count for distinct values
             CompiledAggregate::AvgDistinct {
                 sum,
                 count,
+                interval,
                 distinct_tracker,
             } => {
                 if !value.is_null() && distinct_tracker.check_and_add(value) {
-                    if let Some(n) = Self::as_f64(value) {
-                        *sum += n;
-                        *count += 1;
-                    }
+                    Self::accumulate_avg(sum, count, interval, value);
                 }
             }
 
             // MIN(column) - generic comparison
-            CompiledAggregate::Min { min_value } => {
-                if !value.is_null() {
-                    match min_value {
-                        None => *min_value = Some(value.clone()),
-                        Some(current) => {
-                            if Self::is_less_than(value, current) {
-                                *min_value = Some(value.clone());
-                            }
-                        }
+            // A NULL is kept only until the first value, to type an all-NULL result
+            CompiledAggregate::Min { min_value } => match min_value {
+                None => *min_value = Some(value.clone()),
+                Some(current) if current.is_null() => {
+                    if !value.is_null() {
+                        *min_value = Some(value.clone());
                     }
                 }
-            }
+                Some(current) => {
+                    if Self::is_less_than(value, current) {
+                        *min_value = Some(value.clone());
+                    }
+                }
+            },
 
             // MAX(column) - generic comparison
-            CompiledAggregate::Max { max_value } => {
-                if !value.is_null() {
-                    match max_value {
-                        None => *max_value = Some(value.clone()),
-                        Some(current) => {
-                            if Self::is_greater_than(value, current) {
-                                *max_value = Some(value.clone());
-                            }
-                        }
+            CompiledAggregate::Max { max_value } => match max_value {
+                None => *max_value = Some(value.clone()),
+                Some(current) if current.is_null() => {
+                    if !value.is_null() {
+                        *max_value = Some(value.clone());
                     }
                 }
-            }
+                Some(current) => {
+                    if Self::is_greater_than(value, current) {
+                        *max_value = Some(value.clone());
+                    }
+                }
+            },
 
             // MIN for integers (faster path)
             CompiledAggregate::MinInteger { min_value } => {
@@ -449,15 +485,28 @@ impl CompiledAggregate {
             CompiledAggregate::Sum { state } | CompiledAggregate::SumDistinct { state, .. } => {
                 match state {
                     SumState::Empty => Value::null_unknown(),
+                    SumState::Null(data_type) => Value::Null(*data_type),
                     SumState::Integer(sum) => Value::Integer(*sum),
                     SumState::Float(sum) => Value::Float(*sum),
+                    SumState::Interval(sum) => Value::Interval(*sum),
                 }
             }
 
-            CompiledAggregate::Avg { sum, count }
-            | CompiledAggregate::AvgDistinct { sum, count, .. } => {
+            CompiledAggregate::Avg {
+                sum,
+                count,
+                interval,
+            }
+            | CompiledAggregate::AvgDistinct {
+                sum,
+                count,
+                interval,
+                ..
+            } => {
                 if *count == 0 {
-                    Value::null_unknown()
+                    Value::Null(DataType::Float)
+                } else if *interval {
+                    Value::Interval((*sum / *count as f64).round() as i64)
                 } else {
                     Value::Float(*sum / *count as f64)
                 }
@@ -472,17 +521,17 @@ impl CompiledAggregate {
 
             CompiledAggregate::MinInteger { min_value } => min_value
                 .map(Value::Integer)
-                .unwrap_or_else(Value::null_unknown),
+                .unwrap_or(Value::Null(DataType::Integer)),
             CompiledAggregate::MaxInteger { max_value } => max_value
                 .map(Value::Integer)
-                .unwrap_or_else(Value::null_unknown),
+                .unwrap_or(Value::Null(DataType::Integer)),
 
             CompiledAggregate::MinFloat { min_value } => min_value
                 .map(Value::Float)
-                .unwrap_or_else(Value::null_unknown),
+                .unwrap_or(Value::Null(DataType::Float)),
             CompiledAggregate::MaxFloat { max_value } => max_value
                 .map(Value::Float)
-                .unwrap_or_else(Value::null_unknown),
+                .unwrap_or(Value::Null(DataType::Float)),
 
             CompiledAggregate::Dynamic(func) => func.result(),
         }
@@ -504,17 +553,24 @@ impl CompiledAggregate {
                 distinct_tracker.reset();
             }
 
-            CompiledAggregate::Avg { sum, count } => {
+            CompiledAggregate::Avg {
+                sum,
+                count,
+                interval,
+            } => {
                 *sum = 0.0;
                 *count = 0;
+                *interval = false;
             }
             CompiledAggregate::AvgDistinct {
                 sum,
                 count,
+                interval,
                 distinct_tracker,
             } => {
                 *sum = 0.0;
                 *count = 0;
+                *interval = false;
                 distinct_tracker.reset();
             }
 
@@ -536,21 +592,49 @@ impl CompiledAggregate {
     fn accumulate_sum(state: &mut SumState, value: &Value) {
         match value {
             Value::Integer(i) => match state {
-                SumState::Empty => *state = SumState::Integer(*i),
+                SumState::Empty | SumState::Null(_) => *state = SumState::Integer(*i),
                 SumState::Integer(sum) => *sum += i,
                 SumState::Float(sum) => *sum += *i as f64,
+                SumState::Interval(_) => {}
             },
             Value::Float(f) => match state {
-                SumState::Empty => *state = SumState::Float(*f),
+                SumState::Empty | SumState::Null(_) => *state = SumState::Float(*f),
                 SumState::Integer(sum) => {
                     *state = SumState::Float(*sum as f64 + f);
                 }
                 SumState::Float(sum) => *sum += f,
+                SumState::Interval(_) => {}
+            },
+            Value::Interval(micros) => match state {
+                SumState::Empty | SumState::Null(_) => *state = SumState::Interval(*micros),
+                SumState::Interval(sum) => *sum = sum.saturating_add(*micros),
+                _ => {} // Intervals don't mix with numbers
             },
             _ => {} // Ignore non-numeric types
         }
     }
 
+    /// Helper: accumulate into an AVG sum and count
+    ///
+    /// Intervals are summed in microseconds and don't mix with numbers.
+    #[inline(always)]
+    fn accumulate_avg(sum: &mut f64, count: &mut i64, interval: &mut bool, value: &Value) {
+        match value {
+            Value::Interval(micros) if *count == 0 || *interval => {
+                *sum += *micros as f64;
+                *count += 1;
+                *interval = true;
+            }
+            _ if *interval => {}
+            _ => {
+                if let Some(n) = Self::as_f64(value) {
+                    *sum += n;
+                    *count += 1;
+                }
+            }
+        }
+    }
+
     /// Helper: convert Value to f64
     #[inline(always)]
     fn as_f64(value: &Value) -> Option<f64> {
@@ -573,6 +657,7 @@ impl CompiledAggregate {
             (Value::Text(a), Value::Text(b)) => a < b,
             (Value::Boolean(a), Value::Boolean(b)) => !a && *b,
             (Value::Timestamp(a), Value::Timestamp(b)) => a < b,
+            (Value::Interval(a), Value::Interval(b)) => a < b,
             _ => false,
         }
     }
@@ -589,6 +674,7 @@ impl CompiledAggregate {
             (Value::Text(a), Value::Text(b)) => a > b,
             (Value::Boolean(a), Value::Boolean(b)) => *a && !b,
             (Value::Timestamp(a), Value::Timestamp(b)) => a > b,
+            (Value::Interval(a), Value::Interval(b)) => a > b,
             _ => false,
         }
     }
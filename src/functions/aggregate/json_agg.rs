@@ -0,0 +1,365 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! JSON_AGG and JSON_OBJECT_AGG aggregate functions
+
+use crate::core::Value;
+use crate::functions::scalar::value_to_json;
+use crate::functions::{
+    AggregateFunction, FunctionDataType, FunctionInfo, FunctionSignature, FunctionType,
+};
+
+use super::DistinctTracker;
+
+/// Entry for ordered aggregation - stores value with its sort keys
+#[derive(Clone)]
+struct OrderedEntry {
+    value: serde_json::Value,
+    sort_keys: Vec<Value>,
+}
+
+/// Values collected by a JSON aggregate, with optional ORDER BY support
+#[derive(Default)]
+struct JsonCollector {
+    /// Values collected (used when no ORDER BY)
+    values: Vec<serde_json::Value>,
+    /// Values with sort keys (used when ORDER BY is specified)
+    ordered_entries: Vec<OrderedEntry>,
+    /// Sort directions: true = ASC, false = DESC
+    order_directions: Vec<bool>,
+    /// Whether ORDER BY is active
+    has_order_by: bool,
+    distinct_tracker: Option<DistinctTracker>,
+}
+
+impl JsonCollector {
+    /// Returns false if the value is a duplicate under DISTINCT
+    fn check_distinct(&mut self, value: &Value, distinct: bool) -> bool {
+        if !distinct {
+            return true;
+        }
+        self.distinct_tracker
+            .get_or_insert_with(DistinctTracker::default)
+            .check_and_add(value)
+    }
+
+    /// Returns the collected values, sorted if ORDER BY is active
+    fn values(&self) -> Vec<&serde_json::Value> {
+        if self.has_order_by && !self.ordered_entries.is_empty() {
+            let mut entries: Vec<&OrderedEntry> = self.ordered_entries.iter().collect();
+            let directions = &self.order_directions;
+
+            entries.sort_by(|a, b| {
+                for (i, (key_a, key_b)) in a.sort_keys.iter().zip(b.sort_keys.iter()).enumerate() {
+                    let is_asc = directions.get(i).copied().unwrap_or(true);
+                    let cmp = compare_values(key_a, key_b);
+                    if cmp != std::cmp::Ordering::Equal {
+                        return if is_asc { cmp } else { cmp.reverse() };
+                    }
+                }
+                std::cmp::Ordering::Equal
+            });
+
+            entries.iter().map(|e| &e.value).collect()
+        } else if !self.values.is_empty() {
+            self.values.iter().collect()
+        } else {
+            // Has ordered entries but no ORDER BY configured - use insertion order
+            self.ordered_entries.iter().map(|e| &e.value).collect()
+        }
+    }
+
+    fn reset(&mut self) {
+        self.values.clear();
+        self.ordered_entries.clear();
+        self.distinct_tracker = None;
+        // Note: order_directions and has_order_by are kept as they're configuration
+    }
+}
+
+/// JSON_AGG aggregate function
+///
+/// Collects values into a JSON array, including NULLs as JSON `null`.
+/// Similar to PostgreSQL's JSON_AGG. A whole row can be aggregated as an
+/// object with `JSON_AGG(alias.*)` or `JSON_AGG(*)`.
+///
+/// Usage:
+///   JSON_AGG(column)
+///   JSON_AGG(JSON_OBJECT('id', id, 'title', title))
+///   JSON_AGG(p.*)
+///   JSON_AGG(column ORDER BY expr)
+#[derive(Default)]
+pub struct JsonAggFunction {
+    collector: JsonCollector,
+}
+
+impl AggregateFunction for JsonAggFunction {
+    fn name(&self) -> &str {
+        "JSON_AGG"
+    }
+
+    fn info(&self) -> FunctionInfo {
+        FunctionInfo::new(
+            "JSON_AGG",
+            FunctionType::Aggregate,
+            "Collects all values into a JSON array",
+            FunctionSignature::new(FunctionDataType::Json, vec![FunctionDataType::Any], 1, 1),
+        )
+    }
+
+    fn set_order_by(&mut self, directions: Vec<bool>) {
+        self.collector.order_directions = directions;
+        self.collector.has_order_by = true;
+    }
+
+    fn supports_order_by(&self) -> bool {
+        true
+    }
+
+    fn accumulate(&mut self, value: &Value, distinct: bool) {
+        if self.collector.check_distinct(value, distinct) {
+            self.collector.values.push(value_to_json(value));
+        }
+    }
+
+    fn accumulate_with_sort_key(&mut self, value: &Value, sort_keys: Vec<Value>, distinct: bool) {
+        if self.collector.check_distinct(value, distinct) {
+            self.collector.ordered_entries.push(OrderedEntry {
+                value: value_to_json(value),
+                sort_keys,
+            });
+        }
+    }
+
+    fn result(&self) -> Value {
+        let values = self.collector.values();
+        if values.is_empty() {
+            return Value::null_unknown();
+        }
+
+        let array = serde_json::Value::Array(values.into_iter().cloned().collect());
+        Value::json(array.to_string())
+    }
+
+    fn reset(&mut self) {
+        self.collector.reset();
+    }
+
+    fn clone_box(&self) -> Box<dyn AggregateFunction> {
+        Box::new(JsonAggFunction::default())
+    }
+}
+
+/// JSON_OBJECT_AGG aggregate function
+///
+/// Collects key/value pairs into a JSON object. Rows with a NULL key are
+/// skipped; for duplicate keys the last value wins. Keys keep the order in
+/// which they were first seen (or the ORDER BY order).
+///
+/// The executor passes each row's key and value packed as a two-element
+/// JSON array, since aggregates accumulate a single value per row.
+///
+/// Usage:
+///   JSON_OBJECT_AGG(key, value)
+///   JSON_OBJECT_AGG(key, value ORDER BY expr)
+#[derive(Default)]
+pub struct JsonObjectAggFunction {
+    collector: JsonCollector,
+}
+
+impl JsonObjectAggFunction {
+    /// Unpacks a `[key, value]` pair, returning None for NULL keys
+    fn unpack(pair: &Value) -> Option<serde_json::Value> {
+        match value_to_json(pair) {
+            serde_json::Value::Array(mut items) if items.len() == 2 => {
+                if items[0].is_null() {
+                    return None;
+                }
+                let value = items.pop()?;
+                let key = match items.pop()? {
+                    serde_json::Value::String(s) => s,
+                    other => other.to_string(),
+                };
+                Some(serde_json::Value::Array(vec![
+                    serde_json::Value::String(key),
+                    value,
+                ]))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl AggregateFunction for JsonObjectAggFunction {
+    fn name(&self) -> &str {
+        "JSON_OBJECT_AGG"
+    }
+
+    fn info(&self) -> FunctionInfo {
+        FunctionInfo::new(
+            "JSON_OBJECT_AGG",
+            FunctionType::Aggregate,
+            "Collects key/value pairs into a JSON object",
+            FunctionSignature::new(
+                FunctionDataType::Json,
+                vec![FunctionDataType::String, FunctionDataType::Any],
+                2,
+                2,
+            ),
+        )
+    }
+
+    fn set_order_by(&mut self, directions: Vec<bool>) {
+        self.collector.order_directions = directions;
+        self.collector.has_order_by = true;
+    }
+
+    fn supports_order_by(&self) -> bool {
+        true
+    }
+
+    fn accumulate(&mut self, value: &Value, distinct: bool) {
+        if let Some(pair) = Self::unpack(value) {
+            if self.collector.check_distinct(value, distinct) {
+                self.collector.values.push(pair);
+            }
+        }
+    }
+
+    fn accumulate_with_sort_key(&mut self, value: &Value, sort_keys: Vec<Value>, distinct: bool) {
+        if let Some(pair) = Self::unpack(value) {
+            if self.collector.check_distinct(value, distinct) {
+                self.collector.ordered_entries.push(OrderedEntry {
+                    value: pair,
+                    sort_keys,
+                });
+            }
+        }
+    }
+
+    fn result(&self) -> Value {
+        let pairs = self.collector.values();
+        if pairs.is_empty() {
+            return Value::null_unknown();
+        }
+
+        // Build the object by hand to keep key order without serde's preserve_order
+        let mut entries: Vec<(&str, &serde_json::Value)> = Vec::with_capacity(pairs.len());
+        for pair in pairs {
+            if let serde_json::Value::Array(items) = pair {
+                if let (Some(serde_json::Value::String(key)), Some(value)) =
+                    (items.first(), items.get(1))
+                {
+                    match entries.iter_mut().find(|(k, _)| *k == key.as_str()) {
+                        Some(entry) => entry.1 = value,
+                        None => entries.push((key.as_str(), value)),
+                    }
+                }
+            }
+        }
+
+        let members: Vec<String> = entries
+            .iter()
+            .map(|(key, value)| format!("{}:{}", serde_json::Value::from(*key), value))
+            .collect();
+        Value::json(format!("{{{}}}", members.join(",")))
+    }
+
+    fn reset(&mut self) {
+        self.collector.reset();
+    }
+
+    fn clone_box(&self) -> Box<dyn AggregateFunction> {
+        Box::new(JsonObjectAggFunction::default())
+    }
+}
+
+/// Compare two Values for sorting
+fn compare_values(a: &Value, b: &Value) -> std::cmp::Ordering {
+    match (a, b) {
+        (Value::Null(_), Value::Null(_)) => std::cmp::Ordering::Equal,
+        (Value::Null(_), _) => std::cmp::Ordering::Greater, // NULLs last
+        (_, Value::Null(_)) => std::cmp::Ordering::Less,
+        (Value::Integer(a), Value::Integer(b)) => a.cmp(b),
+        (Value::Float(a), Value::Float(b)) => a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal),
+        (Value::Integer(a), Value::Float(b)) => (*a as f64)
+            .partial_cmp(b)
+            .unwrap_or(std::cmp::Ordering::Equal),
+        (Value::Float(a), Value::Integer(b)) => a
+            .partial_cmp(&(*b as f64))
+            .unwrap_or(std::cmp::Ordering::Equal),
+        (Value::Text(a), Value::Text(b)) => a.cmp(b),
+        (Value::Boolean(a), Value::Boolean(b)) => a.cmp(b),
+        (Value::Timestamp(a), Value::Timestamp(b)) => a.cmp(b),
+        (Value::Interval(a), Value::Interval(b)) => a.cmp(b),
+        _ => std::cmp::Ordering::Equal, // Different types compare equal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pair(key: Value, value: Value) -> Value {
+        let array = serde_json::Value::Array(vec![value_to_json(&key), value_to_json(&value)]);
+        Value::json(array.to_string())
+    }
+
+    #[test]
+    fn test_json_agg_basic() {
+        let mut agg = JsonAggFunction::default();
+        agg.accumulate(&Value::Integer(1), false);
+        agg.accumulate(&Value::text("a\"b"), false);
+        agg.accumulate(&Value::null_unknown(), false);
+        agg.accumulate(&Value::json(r#"{"x":1}"#), false);
+        assert_eq!(agg.result(), Value::json(r#"[1,"a\"b",null,{"x":1}]"#));
+    }
+
+    #[test]
+    fn test_json_agg_ordered_and_distinct() {
+        let mut agg = JsonAggFunction::default();
+        agg.set_order_by(vec![false]);
+        agg.accumulate_with_sort_key(&Value::text("a"), vec![Value::Integer(1)], true);
+        agg.accumulate_with_sort_key(&Value::text("b"), vec![Value::Integer(2)], true);
+        agg.accumulate_with_sort_key(&Value::text("a"), vec![Value::Integer(3)], true);
+        assert_eq!(agg.result(), Value::json(r#"["b","a"]"#));
+    }
+
+    #[test]
+    fn test_json_agg_empty_and_reset() {
+        let mut agg = JsonAggFunction::default();
+        assert!(agg.result().is_null());
+        agg.accumulate(&Value::Integer(1), false);
+        agg.reset();
+        assert!(agg.result().is_null());
+    }
+
+    #[test]
+    fn test_json_object_agg() {
+        let mut agg = JsonObjectAggFunction::default();
+        agg.accumulate(&pair(Value::text("b"), Value::Integer(1)), false);
+        agg.accumulate(&pair(Value::text("a"), Value::text("x")), false);
+        agg.accumulate(&pair(Value::null_unknown(), Value::Integer(9)), false);
+        agg.accumulate(&pair(Value::Integer(7), Value::Boolean(true)), false);
+        agg.accumulate(&pair(Value::text("b"), Value::null_unknown()), false);
+        assert_eq!(agg.result(), Value::json(r#"{"b":null,"a":"x","7":true}"#));
+    }
+
+    #[test]
+    fn test_json_object_agg_empty() {
+        let mut agg = JsonObjectAggFunction::default();
+        agg.accumulate(&pair(Value::null_unknown(), Value::Integer(1)), false);
+        assert!(agg.result().is_null());
+    }
+}
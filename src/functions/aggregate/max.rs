@@ -48,13 +48,17 @@ impl AggregateFunction for MaxFunction {
     }
 
     fn accumulate(&mut self, value: &Value, _distinct: bool) {
-        // Handle NULL values - MAX ignores NULLs
+        // Handle NULL values - MAX ignores NULLs, but the first one is kept
+        // until a value arrives so an all-NULL result keeps its type
         if value.is_null() {
+            if self.max_value.is_none() {
+                self.max_value = Some(value.clone());
+            }
             return;
         }
 
         // If this is the first non-NULL value, set it as maximum
-        if self.max_value.is_none() {
+        if self.max_value.as_ref().is_none_or(Value::is_null) {
             self.max_value = Some(value.clone());
             return;
         }
@@ -89,6 +93,7 @@ fn is_greater_than(a: &Value, b: &Value) -> bool {
         (Value::Text(a), Value::Text(b)) => a > b,
         (Value::Boolean(a), Value::Boolean(b)) => *a && !b, // true > false
         (Value::Timestamp(a), Value::Timestamp(b)) => a > b,
+        (Value::Interval(a), Value::Interval(b)) => a > b,
         _ => false, // Different types are not comparable
     }
 }
@@ -124,6 +129,15 @@ mod tests {
         assert_eq!(max.result(), Value::text("cherry"));
     }
 
+    #[test]
+    fn test_max_intervals() {
+        let mut max = MaxFunction::default();
+        max.accumulate(&Value::Interval(86_400_000_000), false);
+        max.accumulate(&Value::Interval(7_200_000_000), false);
+        max.accumulate(&Value::Interval(259_200_000_000), false);
+        assert_eq!(max.result(), Value::Interval(259_200_000_000));
+    }
+
     #[test]
     fn test_max_ignores_null() {
         let mut max = MaxFunction::default();
@@ -48,13 +48,17 @@ impl AggregateFunction for MinFunction {
     }
 
     fn accumulate(&mut self, value: &Value, _distinct: bool) {
-        // Handle NULL values - MIN ignores NULLs
+        // Handle NULL values - MIN ignores NULLs, but the first one is kept
+        // until a value arrives so an all-NULL result keeps its type
         if value.is_null() {
+            if self.min_value.is_none() {
+                self.min_value = Some(value.clone());
+            }
             return;
         }
 
         // If this is the first non-NULL value, set it as minimum
-        if self.min_value.is_none() {
+        if self.min_value.as_ref().is_none_or(Value::is_null) {
             self.min_value = Some(value.clone());
             return;
         }
@@ -89,6 +93,7 @@ fn is_less_than(a: &Value, b: &Value) -> bool {
         (Value::Text(a), Value::Text(b)) => a < b,
         (Value::Boolean(a), Value::Boolean(b)) => !a && *b, // false < true
         (Value::Timestamp(a), Value::Timestamp(b)) => a < b,
+        (Value::Interval(a), Value::Interval(b)) => a < b,
         _ => false, // Different types are not comparable
     }
 }
@@ -124,6 +129,15 @@ mod tests {
         assert_eq!(min.result(), Value::text("apple"));
     }
 
+    #[test]
+    fn test_min_intervals() {
+        let mut min = MinFunction::default();
+        min.accumulate(&Value::Interval(86_400_000_000), false);
+        min.accumulate(&Value::Interval(7_200_000_000), false);
+        min.accumulate(&Value::Interval(259_200_000_000), false);
+        assert_eq!(min.result(), Value::Interval(7_200_000_000));
+    }
+
     #[test]
     fn test_min_ignores_null() {
         let mut min = MinFunction::default();
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -26,6 +27,8 @@
 //! - [`StringAggFunction`] - STRING_AGG(column, separator)
 //! - [`GroupConcatFunction`] - GROUP_CONCAT(column, separator)
 //! - [`ArrayAggFunction`] - ARRAY_AGG(column)
+//! - [`JsonAggFunction`] - JSON_AGG(value)
+//! - [`JsonObjectAggFunction`] - JSON_OBJECT_AGG(key, value)
 //! - [`StddevPopFunction`] - STDDEV_POP(column)
 //! - [`StddevFunction`] - STDDEV(column)
 //! - [`StddevSampFunction`] - STDDEV_SAMP(column)
@@ -39,6 +42,7 @@ mod avg;
 pub mod compiled;
 mod count;
 mod first;
+mod json_agg;
 mod last;
 mod max;
 mod min;
@@ -51,6 +55,7 @@ pub use avg::AvgFunction;
 pub use compiled::CompiledAggregate;
 pub use count::CountFunction;
 pub use first::FirstFunction;
+pub use json_agg::{JsonAggFunction, JsonObjectAggFunction};
 pub use last::LastFunction;
 pub use max::MaxFunction;
 pub use min::MinFunction;
@@ -78,6 +83,8 @@ fn value_to_distinct_key(value: &Value) -> Option<String> {
         Value::Boolean(b) => Some(format!("b:{}", b)),
         Value::Timestamp(t) => Some(format!("t:{}", t)),
         Value::Json(j) => Some(format!("j:{}", j)),
+        Value::Interval(i) => Some(format!("v:{}", i)),
+        Value::Blob(_) => Some(format!("x:{}", value)),
     }
 }
 
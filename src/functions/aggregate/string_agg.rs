@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -122,6 +123,7 @@ impl AggregateFunction for StringAggFunction {
             Value::Boolean(b) => b.to_string(),
             Value::Timestamp(t) => t.to_string(),
             Value::Json(j) => j.to_string(),
+            Value::Interval(_) | Value::Blob(_) => value.to_string(),
             Value::Null(_) => return,
         };
 
@@ -152,6 +154,7 @@ impl AggregateFunction for StringAggFunction {
             Value::Boolean(b) => b.to_string(),
             Value::Timestamp(t) => t.to_string(),
             Value::Json(j) => j.to_string(),
+            Value::Interval(_) | Value::Blob(_) => value.to_string(),
             Value::Null(_) => return,
         };
 
@@ -228,6 +231,7 @@ fn compare_values(a: &Value, b: &Value) -> std::cmp::Ordering {
         (Value::Text(a), Value::Text(b)) => a.cmp(b),
         (Value::Boolean(a), Value::Boolean(b)) => a.cmp(b),
         (Value::Timestamp(a), Value::Timestamp(b)) => a.cmp(b),
+        (Value::Interval(a), Value::Interval(b)) => a.cmp(b),
         _ => std::cmp::Ordering::Equal, // Different types compare equal
     }
 }
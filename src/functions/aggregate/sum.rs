@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -14,26 +15,30 @@
 
 //! SUM aggregate function
 
-use crate::core::Value;
+use crate::core::{DataType, Value};
 use crate::functions::{
     AggregateFunction, FunctionDataType, FunctionInfo, FunctionSignature, FunctionType,
 };
 
 use super::DistinctTracker;
 
-/// Sum state - tracks whether we have integers or floats
+/// Sum state - tracks whether we have integers, floats or intervals
 #[derive(Default)]
 enum SumState {
     #[default]
     Empty,
+    /// Only NULLs of this type seen so far
+    Null(DataType),
     Integer(i64),
     Float(f64),
+    Interval(i64),
 }
 
 /// SUM aggregate function
 ///
 /// Returns the sum of all non-NULL values in the specified column.
-/// Returns int64 for integer inputs, float64 for floating-point inputs.
+/// Returns int64 for integer inputs, float64 for floating-point inputs and
+/// an interval for interval inputs.
 #[derive(Default)]
 pub struct SumFunction {
     state: SumState,
@@ -60,8 +65,11 @@ impl AggregateFunction for SumFunction {
     }
 
     fn accumulate(&mut self, value: &Value, distinct: bool) {
-        // Handle NULL values - SUM ignores NULLs
+        // Handle NULL values - SUM ignores NULLs, keeping the first type seen
         if value.is_null() {
+            if matches!(self.state, SumState::Empty) && value.data_type() != DataType::Null {
+                self.state = SumState::Null(value.data_type());
+            }
             return;
         }
 
@@ -78,16 +86,23 @@ impl AggregateFunction for SumFunction {
         // Extract numeric value
         match value {
             Value::Integer(i) => match &mut self.state {
-                SumState::Empty => self.state = SumState::Integer(*i),
+                SumState::Empty | SumState::Null(_) => self.state = SumState::Integer(*i),
                 SumState::Integer(sum) => *sum += i,
                 SumState::Float(sum) => *sum += *i as f64,
+                SumState::Interval(_) => {}
             },
             Value::Float(f) => match &mut self.state {
-                SumState::Empty => self.state = SumState::Float(*f),
+                SumState::Empty | SumState::Null(_) => self.state = SumState::Float(*f),
                 SumState::Integer(sum) => {
                     self.state = SumState::Float(*sum as f64 + f);
                 }
                 SumState::Float(sum) => *sum += f,
+                SumState::Interval(_) => {}
+            },
+            Value::Interval(micros) => match &mut self.state {
+                SumState::Empty | SumState::Null(_) => self.state = SumState::Interval(*micros),
+                SumState::Interval(sum) => *sum = sum.saturating_add(*micros),
+                _ => {} // Intervals don't mix with numbers
             },
             _ => {} // Ignore non-numeric types
         }
@@ -96,8 +111,10 @@ impl AggregateFunction for SumFunction {
     fn result(&self) -> Value {
         match &self.state {
             SumState::Empty => Value::null_unknown(),
+            SumState::Null(data_type) => Value::Null(*data_type),
             SumState::Integer(sum) => Value::Integer(*sum),
             SumState::Float(sum) => Value::Float(*sum),
+            SumState::Interval(sum) => Value::Interval(*sum),
         }
     }
 
@@ -142,6 +159,14 @@ mod tests {
         assert_eq!(sum.result(), Value::Float(6.5));
     }
 
+    #[test]
+    fn test_sum_intervals() {
+        let mut sum = SumFunction::default();
+        sum.accumulate(&Value::Interval(1_000_000), false);
+        sum.accumulate(&Value::Interval(2_500_000), false);
+        assert_eq!(sum.result(), Value::Interval(3_500_000));
+    }
+
     #[test]
     fn test_sum_ignores_null() {
         let mut sum = SumFunction::default();
@@ -17,6 +17,7 @@
 //! This module provides pluggable scripting backends that allow user-defined
 //! functions to be written in different scripting languages.
 
+pub mod pool;
 pub mod python;
 pub mod rhai;
 pub mod triggers;
@@ -0,0 +1,190 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Worker pool for scripting backends
+//!
+//! Bounds how many scripting runtimes run at once. A script call takes a
+//! worker for its duration and waits while the pool is saturated. Nested
+//! calls on the same thread (a procedure that runs a query calling a UDF)
+//! reuse the caller's worker.
+
+use std::cell::Cell;
+use std::sync::{Condvar, Mutex, OnceLock};
+
+thread_local! {
+    /// Number of workers held by the current thread (nesting depth)
+    static HELD: Cell<usize> = const { Cell::new(0) };
+}
+
+static GLOBAL_POOL: OnceLock<ScriptWorkerPool> = OnceLock::new();
+
+/// Returns the process-wide pool shared by all scripting backends
+pub fn global_pool() -> &'static ScriptWorkerPool {
+    GLOBAL_POOL.get_or_init(|| ScriptWorkerPool::new(0))
+}
+
+#[derive(Debug)]
+struct PoolState {
+    /// Maximum concurrent workers (0 = unlimited)
+    limit: usize,
+    /// Workers currently running
+    active: usize,
+}
+
+/// Counting pool that caps concurrent script executions
+#[derive(Debug)]
+pub struct ScriptWorkerPool {
+    state: Mutex<PoolState>,
+    released: Condvar,
+}
+
+impl ScriptWorkerPool {
+    /// Create a pool with the given limit (0 = unlimited)
+    pub fn new(limit: usize) -> Self {
+        Self {
+            state: Mutex::new(PoolState { limit, active: 0 }),
+            released: Condvar::new(),
+        }
+    }
+
+    /// Current limit (0 = unlimited)
+    pub fn limit(&self) -> usize {
+        self.state.lock().unwrap().limit
+    }
+
+    /// Change the limit; waiting callers are re-checked against it
+    pub fn set_limit(&self, limit: usize) {
+        self.state.lock().unwrap().limit = limit;
+        self.released.notify_all();
+    }
+
+    /// Number of workers currently running
+    pub fn active(&self) -> usize {
+        self.state.lock().unwrap().active
+    }
+
+    /// Take a worker, blocking while the pool is saturated
+    pub fn acquire(&self) -> ScriptWorker<'_> {
+        let nested = HELD.with(|held| held.get()) > 0;
+        if !nested {
+            let mut state = self.state.lock().unwrap();
+            while state.limit > 0 && state.active >= state.limit {
+                state = self.released.wait(state).unwrap();
+            }
+            state.active += 1;
+        }
+        HELD.with(|held| held.set(held.get() + 1));
+        ScriptWorker { pool: self, nested }
+    }
+}
+
+/// A worker taken from a [`ScriptWorkerPool`], returned on drop
+pub struct ScriptWorker<'a> {
+    pool: &'a ScriptWorkerPool,
+    nested: bool,
+}
+
+impl Drop for ScriptWorker<'_> {
+    fn drop(&mut self) {
+        HELD.with(|held| held.set(held.get() - 1));
+        if !self.nested {
+            self.pool.state.lock().unwrap().active -= 1;
+            self.pool.released.notify_one();
+        }
+    }
+}
+
+/// Runs a script call on a worker from the global pool
+#[cfg(feature = "runtime-pool")]
+pub fn with_script_worker<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    let _worker = global_pool().acquire();
+    f()
+}
+
+/// Runs a script call directly; pooling needs the `runtime-pool` feature
+#[cfg(not(feature = "runtime-pool"))]
+pub fn with_script_worker<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_concurrency_is_capped() {
+        let pool = Arc::new(ScriptWorkerPool::new(2));
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let (pool, running, peak) = (pool.clone(), running.clone(), peak.clone());
+                thread::spawn(move || {
+                    let _worker = pool.acquire();
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    thread::sleep(Duration::from_millis(20));
+                    running.fetch_sub(1, Ordering::SeqCst);
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+        assert_eq!(pool.active(), 0);
+    }
+
+    #[test]
+    fn test_nested_acquire_reuses_worker() {
+        let pool = ScriptWorkerPool::new(1);
+        let outer = pool.acquire();
+        // Would deadlock if the nested call waited for a second worker
+        let inner = pool.acquire();
+        assert_eq!(pool.active(), 1);
+        drop(inner);
+        drop(outer);
+        assert_eq!(pool.active(), 0);
+    }
+
+    #[test]
+    fn test_raising_limit_wakes_waiters() {
+        let pool = Arc::new(ScriptWorkerPool::new(1));
+        let held = pool.acquire();
+
+        let waiter = {
+            let pool = pool.clone();
+            thread::spawn(move || {
+                let _worker = pool.acquire();
+            })
+        };
+        thread::sleep(Duration::from_millis(20));
+        pool.set_limit(0);
+        waiter.join().unwrap();
+        drop(held);
+        assert_eq!(pool.active(), 0);
+    }
+}
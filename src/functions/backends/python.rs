@@ -63,6 +63,10 @@ mod oxibase_py_module {
                             }
                             crate::core::Value::Null(_) => vm.ctx.none(),
                             crate::core::Value::Json(v) => vm.ctx.new_str(v.to_string()).into(),
+                            v @ crate::core::Value::Interval(_) => {
+                                vm.ctx.new_str(v.to_string()).into()
+                            }
+                            crate::core::Value::Blob(v) => vm.ctx.new_bytes(v.to_vec()).into(),
                         };
                         let _ = py_dict.set_item(col.as_str(), py_val, vm);
                     }
@@ -98,6 +102,20 @@ mod oxibase_py_module {
         }
     }
 
+    #[pyfunction]
+    fn quote_ident(name: PyStrRef) -> String {
+        crate::functions::scalar::quote_ident(name.as_ref())
+    }
+
+    #[pyfunction]
+    fn quote_literal(value: rustpython_vm::PyObjectRef, vm: &VirtualMachine) -> PyResult<String> {
+        if vm.is_none(&value) {
+            return Ok("NULL".to_string());
+        }
+        let text = value.str(vm)?;
+        Ok(crate::functions::scalar::quote_literal(text.as_ref()))
+    }
+
     #[pyfunction]
     fn log(level: PyStrRef, message: PyStrRef) {
         crate::common::logging::log_message(level.as_ref(), message.as_ref());
@@ -455,6 +473,8 @@ impl PythonBackend {
                     Ok(vm.ctx.new_str(j.as_ref()).into())
                 }
             }
+            Value::Interval(_) => Ok(value.to_string().to_pyobject(vm)),
+            Value::Blob(b) => Ok(vm.ctx.new_bytes(b.to_vec()).into()),
         }
     }
 
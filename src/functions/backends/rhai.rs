@@ -93,6 +93,8 @@ impl RhaiBackend {
                                     Value::Boolean(v) => rhai::Dynamic::from(v),
                                     Value::Timestamp(v) => rhai::Dynamic::from(RhaiDateTime(v)),
                                     Value::Null(_) => rhai::Dynamic::UNIT,
+                                    Value::Interval(v) => rhai::Dynamic::from(crate::core::format_interval(v)),
+                                    Value::Blob(v) => rhai::Dynamic::from_blob(v.to_vec()),
                                     Value::Json(v) => {
                                         if let Ok(json_val) = serde_json::from_str::<serde_json::Value>(v.as_ref()) {
                                             rhai::serde::to_dynamic(json_val).unwrap_or(rhai::Dynamic::UNIT)
@@ -174,6 +176,21 @@ impl RhaiBackend {
                 Ok(rand::rng().random::<f64>())
             },
         );
+        oxibase_module.set_native_fn(
+            "quote_ident",
+            |name: rhai::ImmutableString| -> std::result::Result<String, Box<rhai::EvalAltResult>> {
+                Ok(crate::functions::scalar::quote_ident(&name))
+            },
+        );
+        oxibase_module.set_native_fn(
+            "quote_literal",
+            |value: rhai::Dynamic| -> std::result::Result<String, Box<rhai::EvalAltResult>> {
+                if value.is_unit() {
+                    return Ok("NULL".to_string());
+                }
+                Ok(crate::functions::scalar::quote_literal(&value.to_string()))
+            },
+        );
         engine.register_static_module("oxibase", rhai::Shared::new(oxibase_module));
 
         engine.on_print(|x| {
@@ -287,6 +304,8 @@ impl ScriptingBackend for RhaiBackend {
                 Value::Boolean(b) => args_array.push(rhai::Dynamic::from(*b)),
                 Value::Timestamp(t) => args_array.push(rhai::Dynamic::from(RhaiDateTime(*t))),
                 Value::Null(_) => args_array.push(rhai::Dynamic::UNIT),
+                Value::Interval(_) => args_array.push(rhai::Dynamic::from(arg.to_string())),
+                Value::Blob(b) => args_array.push(rhai::Dynamic::from_blob(b.to_vec())),
                 Value::Json(s) => {
                     if let Ok(json_val) = serde_json::from_str::<serde_json::Value>(s.as_ref()) {
                         args_array
@@ -321,6 +340,12 @@ impl ScriptingBackend for RhaiBackend {
                 Value::Null(_) => {
                     scope.push(var_name, ());
                 }
+                Value::Interval(_) => {
+                    scope.push(var_name, arg.to_string());
+                }
+                Value::Blob(b) => {
+                    scope.push(var_name, b.to_vec());
+                }
                 Value::Json(s) => {
                     if let Ok(json_val) = serde_json::from_str::<serde_json::Value>(s.as_ref()) {
                         let _ = scope.push_dynamic(
@@ -428,6 +453,12 @@ impl ScriptingBackend for RhaiBackend {
                 Value::Null(_) => {
                     scope.push(var_name, ());
                 }
+                Value::Interval(_) => {
+                    scope.push(var_name, arg.to_string());
+                }
+                Value::Blob(b) => {
+                    scope.push(var_name, b.to_vec());
+                }
                 Value::Json(s) => {
                     if let Ok(json_val) = serde_json::from_str::<serde_json::Value>(s.as_ref()) {
                         let _ = scope.push_dynamic(
@@ -611,6 +642,8 @@ pub(crate) fn value_to_dynamic(val: &crate::core::Value) -> rhai::Dynamic {
         crate::core::Value::Text(s) => rhai::Dynamic::from(s.as_ref().to_string()),
         crate::core::Value::Boolean(b) => rhai::Dynamic::from(*b),
         crate::core::Value::Null(_) => rhai::Dynamic::UNIT,
+        crate::core::Value::Interval(_) => rhai::Dynamic::from(val.to_string()),
+        crate::core::Value::Blob(b) => rhai::Dynamic::from_blob(b.to_vec()),
         crate::core::Value::Json(s) => {
             if let Ok(json_val) = serde_json::from_str::<serde_json::Value>(s.as_ref()) {
                 rhai::serde::to_dynamic(json_val).unwrap_or(rhai::Dynamic::UNIT)
@@ -20,6 +20,8 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
 
+use crate::parser::IdentifierQuote;
+
 thread_local! {
     /// Thread-local storage for HTTP headers associated with the current execution.
     /// This is populated when a procedure is invoked via the HTTP API, allowing
@@ -40,6 +42,10 @@ thread_local! {
 
     /// Thread-local storage for whether stepping mode is active.
     pub static IS_STEPPING: RefCell<bool> = const { RefCell::new(false) };
+
+    /// Thread-local storage for the identifier quoting of the session running
+    /// the current statement (SET identifier_quote), used by QUOTE_IDENT.
+    pub static IDENTIFIER_QUOTE: RefCell<IdentifierQuote> = const { RefCell::new(IdentifierQuote::DoubleQuote) };
 }
 
 /// Executes a closure with HTTP headers and debug controller available in the thread-local context.
@@ -131,3 +137,13 @@ pub fn get_is_stepping() -> bool {
 pub fn set_is_stepping(stepping: bool) {
     IS_STEPPING.with(|c| *c.borrow_mut() = stepping);
 }
+
+/// Sets the identifier quoting of the current session
+pub fn set_identifier_quote(quote: IdentifierQuote) {
+    IDENTIFIER_QUOTE.with(|q| *q.borrow_mut() = quote);
+}
+
+/// Gets the identifier quoting of the current session
+pub fn get_identifier_quote() -> IdentifierQuote {
+    IDENTIFIER_QUOTE.with(|q| *q.borrow())
+}
@@ -73,6 +73,46 @@ pub enum FunctionDataType {
     Unknown,
 }
 
+impl FunctionDataType {
+    /// Map a SQL type name from a function definition, such as `INT` or
+    /// `VARCHAR(20)`
+    pub fn from_sql_type(type_name: &str) -> Self {
+        let upper = type_name.trim().to_uppercase();
+        match upper.split('(').next().unwrap_or(&upper).trim() {
+            "ANY" => FunctionDataType::Any,
+            "INTEGER" | "INT" | "BIGINT" | "SMALLINT" | "TINYINT" => FunctionDataType::Integer,
+            "FLOAT" | "DOUBLE" | "REAL" | "DECIMAL" | "NUMERIC" => FunctionDataType::Float,
+            "TEXT" | "VARCHAR" | "CHAR" | "STRING" | "CLOB" => FunctionDataType::String,
+            "BOOLEAN" | "BOOL" => FunctionDataType::Boolean,
+            "TIMESTAMP" => FunctionDataType::Timestamp,
+            "DATETIME" => FunctionDataType::DateTime,
+            "DATE" => FunctionDataType::Date,
+            "TIME" => FunctionDataType::Time,
+            "JSON" | "JSONB" => FunctionDataType::Json,
+            _ => FunctionDataType::Unknown,
+        }
+    }
+}
+
+impl std::fmt::Display for FunctionDataType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            FunctionDataType::Any => "ANY",
+            FunctionDataType::Integer => "INTEGER",
+            FunctionDataType::Float => "FLOAT",
+            FunctionDataType::String => "TEXT",
+            FunctionDataType::Boolean => "BOOLEAN",
+            FunctionDataType::Timestamp => "TIMESTAMP",
+            FunctionDataType::Date => "DATE",
+            FunctionDataType::Time => "TIME",
+            FunctionDataType::DateTime => "DATETIME",
+            FunctionDataType::Json => "JSON",
+            FunctionDataType::Unknown => "UNKNOWN",
+        };
+        f.write_str(name)
+    }
+}
+
 /// Function signature information
 #[derive(Debug, Clone)]
 pub struct FunctionSignature {
@@ -372,4 +412,44 @@ mod tests {
         assert!(registry.exists("add"));
         assert!(registry.get("add").is_some());
     }
+
+    #[test]
+    fn test_user_defined_function_overloads() {
+        use super::backends::create_backend_registry;
+        let backend_registry = std::sync::Arc::new(create_backend_registry());
+        let mut registry = user_defined::UserDefinedFunctionRegistry::new(backend_registry);
+
+        for (code, arg_type) in [
+            ("x * 2", FunctionDataType::Integer),
+            ("x + x", FunctionDataType::String),
+        ] {
+            registry
+                .register(
+                    "twice".to_string(),
+                    code.to_string(),
+                    "rhai".to_string(),
+                    vec!["x".to_string()],
+                    FunctionSignature::new(FunctionDataType::Unknown, vec![arg_type], 1, 1),
+                )
+                .unwrap();
+        }
+
+        let twice = registry.get("twice").unwrap();
+        assert_eq!(
+            twice.evaluate(&[Value::Integer(4)]).unwrap(),
+            Value::Integer(8)
+        );
+        assert_eq!(
+            twice.evaluate(&[Value::text("ab")]).unwrap(),
+            Value::text("abab")
+        );
+
+        assert!(registry.unregister("twice", None).is_err());
+        registry
+            .unregister("twice", Some(&[FunctionDataType::String]))
+            .unwrap();
+        assert!(registry.exists("twice"));
+        registry.unregister("twice", None).unwrap();
+        assert!(!registry.exists("twice"));
+    }
 }
@@ -44,30 +44,35 @@ pub fn global_registry() -> &'static Arc<FunctionRegistry> {
 }
 
 use super::aggregate::{
-    ArrayAggFunction, AvgFunction, CountFunction, FirstFunction, GroupConcatFunction, LastFunction,
-    MaxFunction, MedianFunction, MinFunction, StddevFunction, StddevPopFunction,
-    StddevSampFunction, StringAggFunction, SumFunction, VarPopFunction, VarSampFunction,
-    VarianceFunction,
+    ArrayAggFunction, AvgFunction, CountFunction, FirstFunction, GroupConcatFunction,
+    JsonAggFunction, JsonObjectAggFunction, LastFunction, MaxFunction, MedianFunction, MinFunction,
+    StddevFunction, StddevPopFunction, StddevSampFunction, StringAggFunction, SumFunction,
+    VarPopFunction, VarSampFunction, VarianceFunction,
 };
 use super::backends::create_backend_registry;
 use super::scalar::{
     AbsFunction, CastFunction, CeilFunction, CeilingFunction, CharFunction, CharLengthFunction,
     CoalesceFunction, CollateFunction, ConcatFunction, ConcatWsFunction, CosFunction,
     CurrentDateFunction, CurrentTimestampFunction, DateAddFunction, DateDiffAliasFunction,
-    DateDiffFunction, DateSubFunction, DateTruncFunction, DayFunction, ExpFunction,
-    ExtractFunction, FloorFunction, GreatestFunction, HourFunction, IfNullFunction, IifFunction,
-    InstrFunction, JsonArrayFunction, JsonArrayLengthFunction, JsonExtractFunction,
-    JsonKeysFunction, JsonObjectFunction, JsonTypeFunction, JsonTypeOfFunction, JsonValidFunction,
-    LeastFunction, LeftFunction, LengthFunction, LnFunction, LocateFunction, Log10Function,
-    Log2Function, LogFunction, LowerFunction, LpadFunction, LtrimFunction, MinuteFunction,
-    ModFunction, MonthFunction, NowFunction, NullIfFunction, PiFunction, PositionFunction,
-    PowFunction, PowerFunction, RandomFunction, RepeatFunction, ReplaceFunction, ReverseFunction,
-    RightFunction, RoundFunction, RpadFunction, RtrimFunction, SecondFunction, SignFunction,
-    SinFunction, SleepFunction, SplitPartFunction, SqrtFunction, StrposFunction, SubstrFunction,
-    SubstringFunction, TanFunction, TimeTruncFunction, ToCharFunction, TrimFunction, TruncFunction,
-    TruncateFunction, TypeOfFunction, UpperFunction, VersionFunction, YearFunction,
+    DateDiffFunction, DateSubFunction, DateTruncFunction, DayFunction, DayOfWeekFunction,
+    DayOfYearFunction, ExpFunction, ExtractFunction, FloorFunction, GreatestFunction, HourFunction,
+    IfNullFunction, IifFunction, InstrFunction, JsonArrayFunction, JsonArrayLengthFunction,
+    JsonExtractFunction, JsonKeysFunction, JsonObjectFunction, JsonTypeFunction,
+    JsonTypeOfFunction, JsonValidFunction, LeastFunction, LeftFunction, LengthFunction, LnFunction,
+    LocateFunction, Log10Function, Log2Function, LogFunction, LowerFunction, LpadFunction,
+    LtrimFunction, MinuteFunction, ModFunction, MonthFunction, NormalizeFunction, NowFunction,
+    NullIfFunction, OrdinalFunction, PiFunction, PositionFunction, PowFunction, PowerFunction,
+    QuarterFunction, QuoteIdentFunction, QuoteLiteralFunction, RandomFunction, RepeatFunction,
+    ReplaceFunction, ReverseFunction, RightFunction, RoundFunction, RpadFunction, RtrimFunction,
+    SecondFunction, SignFunction, SinFunction, SleepFunction, SplitPartFunction, SqrtFunction,
+    StrposFunction, SubstrFunction, SubstringFunction, TanFunction, TimeTruncFunction,
+    ToCharFunction, ToTimestampFunction, TrimFunction, TruncFunction, TruncateFunction,
+    TypeOfFunction, UnaccentFunction, UpperFunction, VersionFunction, WeekFunction, YearFunction,
+};
+use super::tvf::{
+    GenerateSeriesFunction, GenerateSeriesScalarFunction, JsonTableFunction, TableValuedFunction,
+    UnnestFunction,
 };
-use super::tvf::{GenerateSeriesFunction, GenerateSeriesScalarFunction, TableValuedFunction};
 use super::user_defined::UserDefinedFunctionRegistry;
 
 use super::window::{
@@ -75,8 +80,8 @@ use super::window::{
     LeadFunction, NthValueFunction, NtileFunction, PercentRankFunction, RankFunction,
     RowNumberFunction,
 };
-use super::FunctionSignature;
 use super::{AggregateFunction, FunctionInfo, ScalarFunction, WindowFunction};
+use super::{FunctionDataType, FunctionSignature};
 
 /// Type alias for aggregate function factory
 type AggregateFnFactory = Arc<dyn Fn() -> Box<dyn AggregateFunction> + Send + Sync>;
@@ -153,6 +158,8 @@ impl FunctionRegistry {
         registry.register_aggregate::<StringAggFunction>();
         registry.register_aggregate::<GroupConcatFunction>();
         registry.register_aggregate::<ArrayAggFunction>();
+        registry.register_aggregate::<JsonAggFunction>();
+        registry.register_aggregate::<JsonObjectAggFunction>();
         registry.register_aggregate::<StddevPopFunction>();
         registry.register_aggregate::<StddevFunction>();
         registry.register_aggregate::<StddevSampFunction>();
@@ -187,6 +194,10 @@ impl FunctionRegistry {
         registry.register_scalar::<LocateFunction>();
         registry.register_scalar::<LpadFunction>();
         registry.register_scalar::<RpadFunction>();
+        registry.register_scalar::<UnaccentFunction>();
+        registry.register_scalar::<NormalizeFunction>();
+        registry.register_scalar::<QuoteIdentFunction>();
+        registry.register_scalar::<QuoteLiteralFunction>();
 
         // Math functions
         registry.register_scalar::<AbsFunction>();
@@ -225,12 +236,17 @@ impl FunctionRegistry {
         registry.register_scalar::<HourFunction>();
         registry.register_scalar::<MinuteFunction>();
         registry.register_scalar::<SecondFunction>();
+        registry.register_scalar::<QuarterFunction>();
+        registry.register_scalar::<WeekFunction>();
+        registry.register_scalar::<DayOfWeekFunction>();
+        registry.register_scalar::<DayOfYearFunction>();
         registry.register_scalar::<DateAddFunction>();
         registry.register_scalar::<DateSubFunction>();
         registry.register_scalar::<DateDiffFunction>();
         registry.register_scalar::<DateDiffAliasFunction>(); // DATE_DIFF alias for DATEDIFF
         registry.register_scalar::<VersionFunction>();
         registry.register_scalar::<ToCharFunction>();
+        registry.register_scalar::<ToTimestampFunction>();
 
         // Utility functions
         registry.register_scalar::<CoalesceFunction>();
@@ -250,10 +266,15 @@ impl FunctionRegistry {
         registry.register_scalar::<JsonValidFunction>();
         registry.register_scalar::<JsonKeysFunction>();
         registry.register_scalar::<TypeOfFunction>();
+        registry.register_scalar::<OrdinalFunction>();
         registry.register_scalar::<SleepFunction>();
         registry.register_scalar::<crate::functions::scalar::GetHttpHeaderFunction>();
         registry.register_scalar::<crate::functions::scalar::QueryValueFunction>();
         registry.register_scalar::<crate::functions::scalar::QueryRowsFunction>();
+        #[cfg(feature = "password")]
+        registry.register_scalar::<crate::functions::scalar::HashPasswordFunction>();
+        #[cfg(feature = "password")]
+        registry.register_scalar::<crate::functions::scalar::VerifyPasswordFunction>();
 
         // Register built-in window functions
         // Register generate_series as scalar (returns JSON array for SELECT usage)
@@ -276,6 +297,8 @@ impl FunctionRegistry {
             "GENERATE_SERIES",
             Arc::new(|| Box::new(GenerateSeriesFunction)),
         );
+        registry.register_tvf("UNNEST", Arc::new(|| Box::new(UnnestFunction)));
+        registry.register_tvf("JSON_TABLE", Arc::new(|| Box::new(JsonTableFunction)));
 
         registry
     }
@@ -320,6 +343,8 @@ impl FunctionRegistry {
     }
 
     /// Register a user-defined function
+    ///
+    /// A signature with new argument types adds an overload of the name.
     pub fn register_user_defined(
         &self,
         name: String,
@@ -329,32 +354,31 @@ impl FunctionRegistry {
         signature: FunctionSignature,
     ) -> crate::core::Result<()> {
         let mut udf_registry = self.user_defined_functions.write().unwrap();
-        udf_registry.register(name.clone(), code, language, param_names, signature.clone())?;
-
-        // Add to function info cache
-        let info = FunctionInfo::new(
-            name.clone(),
-            super::FunctionType::Scalar,
-            "User-defined function".to_string(),
-            signature,
-        );
-
-        let mut infos = self.function_info.write().unwrap();
-        infos.insert(name.to_uppercase(), info);
-
+        udf_registry.register(name.clone(), code, language, param_names, signature)?;
+        self.refresh_user_defined_info(&udf_registry, &name);
         Ok(())
     }
 
-    /// Unregister a user-defined function
-    pub fn unregister_user_defined(&self, name: &str) -> crate::core::Result<()> {
+    /// Unregister a user-defined function, or one of its overloads when
+    /// `argument_types` is given
+    pub fn unregister_user_defined(
+        &self,
+        name: &str,
+        argument_types: Option<&[FunctionDataType]>,
+    ) -> crate::core::Result<()> {
         let mut udf_registry = self.user_defined_functions.write().unwrap();
-        udf_registry.unregister(name)?;
+        udf_registry.unregister(name, argument_types)?;
+        self.refresh_user_defined_info(&udf_registry, name);
+        Ok(())
+    }
 
-        // Remove from function info cache
+    /// Update the function info cache entry covering all overloads of a name
+    fn refresh_user_defined_info(&self, udf_registry: &UserDefinedFunctionRegistry, name: &str) {
         let mut infos = self.function_info.write().unwrap();
-        infos.remove(&name.to_uppercase());
-
-        Ok(())
+        match udf_registry.get(name) {
+            Some(udf) => infos.insert(name.to_uppercase(), udf.info()),
+            None => infos.remove(&name.to_uppercase()),
+        };
     }
 
     /// Get a new instance of an aggregate function by name
@@ -385,7 +409,7 @@ impl FunctionRegistry {
         // Check user-defined functions
         let udf_registry = self.user_defined_functions.read().unwrap();
         if let Some(udf) = udf_registry.get(&upper) {
-            return Some(udf.clone_box());
+            return Some(udf);
         }
 
         None
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -27,6 +28,8 @@ use crate::functions::{
 };
 use crate::validate_arg_count;
 
+use super::unaccent;
+
 /// CAST function for type conversion
 ///
 /// Converts a value from one type to another.
@@ -89,6 +92,8 @@ impl ScalarFunction for CastFunction {
             "BOOLEAN" | "BOOL" => cast_to_boolean(value),
             "TIMESTAMP" | "DATETIME" | "DATE" | "TIME" => cast_to_timestamp(value),
             "JSON" => cast_to_json(value),
+            "INTERVAL" => cast_to_interval(value),
+            "BLOB" | "BYTEA" | "BINARY" | "VARBINARY" => cast_to_blob(value),
             _ => Err(Error::invalid_argument(format!(
                 "Unsupported cast target type: {}",
                 target_type
@@ -123,6 +128,8 @@ fn cast_to_integer(value: &Value) -> Result<Value> {
             Ok(Value::Integer(0))
         }
         Value::Timestamp(t) => Ok(Value::Integer(t.timestamp())),
+        Value::Interval(micros) => Ok(Value::Integer(micros / 1_000_000)),
+        Value::Blob(_) => Err(Error::invalid_argument("Cannot convert BLOB to INTEGER")),
         Value::Json(_) => Ok(Value::Integer(0)),
         Value::Null(_) => Ok(Value::Integer(0)),
     }
@@ -147,7 +154,9 @@ fn cast_to_float(value: &Value) -> Result<Value> {
             }
         }
         Value::Timestamp(t) => Ok(Value::Float(t.timestamp() as f64)),
+        Value::Interval(micros) => Ok(Value::Float(*micros as f64 / 1_000_000.0)),
         Value::Json(_) => Err(Error::invalid_argument("Cannot convert JSON to FLOAT")),
+        Value::Blob(_) => Err(Error::invalid_argument("Cannot convert BLOB to FLOAT")),
         Value::Null(_) => Ok(Value::Float(0.0)),
     }
 }
@@ -164,6 +173,10 @@ fn cast_to_string(value: &Value) -> Result<Value> {
         Value::Boolean(b) => Ok(Value::Text(Arc::from(b.to_string().as_str()))),
         Value::Timestamp(t) => Ok(Value::Text(Arc::from(t.to_rfc3339().as_str()))),
         Value::Json(j) => Ok(Value::Text(j.clone())),
+        Value::Interval(_) => Ok(Value::Text(Arc::from(value.to_string().as_str()))),
+        Value::Blob(b) => std::str::from_utf8(b)
+            .map(Value::text)
+            .map_err(|_| Error::invalid_argument("Cannot convert BLOB to TEXT: invalid UTF-8")),
         Value::Null(_) => Ok(Value::Text(Arc::from(""))),
     }
 }
@@ -186,6 +199,10 @@ fn cast_to_boolean(value: &Value) -> Result<Value> {
             "Cannot convert TIMESTAMP to BOOLEAN",
         )),
         Value::Json(_) => Err(Error::invalid_argument("Cannot convert JSON to BOOLEAN")),
+        Value::Interval(_) => Err(Error::invalid_argument(
+            "Cannot convert INTERVAL to BOOLEAN",
+        )),
+        Value::Blob(_) => Err(Error::invalid_argument("Cannot convert BLOB to BOOLEAN")),
         Value::Null(_) => Ok(Value::Boolean(false)),
     }
 }
@@ -234,6 +251,41 @@ fn cast_to_json(value: &Value) -> Result<Value> {
         Value::Timestamp(t) => Ok(Value::Json(Arc::from(
             format!("\"{}\"", t.to_rfc3339()).as_str(),
         ))),
+        Value::Interval(_) => Ok(Value::Json(Arc::from(format!("\"{}\"", value).as_str()))),
+        Value::Blob(_) => Ok(Value::Json(Arc::from(
+            serde_json::Value::String(value.to_string())
+                .to_string()
+                .as_str(),
+        ))),
+    }
+}
+
+/// Cast a value to BLOB
+fn cast_to_blob(value: &Value) -> Result<Value> {
+    match value {
+        Value::Blob(b) => Ok(Value::Blob(b.clone())),
+        Value::Text(s) => Ok(Value::Blob(crate::core::parse_blob(s))),
+        _ => Err(Error::invalid_argument(format!(
+            "Cannot convert {:?} to BLOB",
+            value.data_type()
+        ))),
+    }
+}
+
+/// Cast a value to INTERVAL
+fn cast_to_interval(value: &Value) -> Result<Value> {
+    match value {
+        Value::Interval(micros) => Ok(Value::Interval(*micros)),
+        Value::Text(s) => crate::core::parse_interval(s)
+            .map(Value::Interval)
+            .map_err(|_| Error::invalid_argument(format!("Cannot parse '{}' as INTERVAL", s))),
+        // Interpret as a number of seconds
+        Value::Integer(i) => Ok(Value::Interval(i.saturating_mul(1_000_000))),
+        Value::Float(f) => Ok(Value::Interval((f * 1_000_000.0).round() as i64)),
+        _ => Err(Error::invalid_argument(format!(
+            "Cannot convert {:?} to INTERVAL",
+            value.data_type()
+        ))),
     }
 }
 
@@ -284,6 +336,7 @@ impl ScalarFunction for CollateFunction {
             Value::Boolean(b) => b.to_string(),
             Value::Timestamp(t) => t.to_rfc3339(),
             Value::Json(j) => j.to_string(),
+            Value::Interval(_) | Value::Blob(_) => args[0].to_string(),
             Value::Null(_) => return Ok(Value::null_unknown()),
         };
 
@@ -312,7 +365,7 @@ fn apply_collation(s: &str, collation: &str) -> Result<String> {
     match collation {
         "BINARY" => Ok(s.to_string()),
         "NOCASE" | "CASE_INSENSITIVE" => Ok(s.to_lowercase()),
-        "NOACCENT" | "ACCENT_INSENSITIVE" => Ok(remove_accents(s)),
+        "NOACCENT" | "ACCENT_INSENSITIVE" => Ok(unaccent(s)),
         "NUMERIC" => Ok(s.to_string()), // No transformation, comparison handles it
         _ => Err(Error::invalid_argument(format!(
             "Unsupported collation: {}",
@@ -321,43 +374,6 @@ fn apply_collation(s: &str, collation: &str) -> Result<String> {
     }
 }
 
-/// Remove accents from characters in a string
-fn remove_accents(s: &str) -> String {
-    s.chars()
-        .filter_map(|c| {
-            Some(match c {
-                // Latin letters with accents -> base letter
-                'À'..='Å' => 'A',
-                'à'..='å' => 'a',
-                'È'..='Ë' => 'E',
-                'è'..='ë' => 'e',
-                'Ì'..='Ï' => 'I',
-                'ì'..='ï' => 'i',
-                'Ò'..='Ö' => 'O',
-                'ò'..='ö' => 'o',
-                'Ù'..='Ü' => 'U',
-                'ù'..='ü' => 'u',
-                'Ç' => 'C',
-                'ç' => 'c',
-                'Ñ' => 'N',
-                'ñ' => 'n',
-                'Ÿ' => 'Y',
-                'ÿ' => 'y',
-                // Keep diacritical marks that are combining characters
-                _ if c.is_ascii() || !is_combining_mark(c) => c,
-                // Remove combining marks
-                _ => return None,
-            })
-        })
-        .collect()
-}
-
-/// Check if a character is a combining diacritical mark
-fn is_combining_mark(c: char) -> bool {
-    // Unicode combining diacritical marks range: U+0300 to U+036F
-    matches!(c, '\u{0300}'..='\u{036F}')
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -583,12 +599,12 @@ mod tests {
     }
 
     #[test]
-    fn test_remove_accents() {
-        assert_eq!(remove_accents("Café"), "Cafe");
-        assert_eq!(remove_accents("Naïve"), "Naive");
-        assert_eq!(remove_accents("Résumé"), "Resume");
-        assert_eq!(remove_accents("Élève"), "Eleve");
-        assert_eq!(remove_accents("Über"), "Uber");
-        assert_eq!(remove_accents("Español"), "Espanol");
+    fn test_unaccent() {
+        assert_eq!(unaccent("Café"), "Cafe");
+        assert_eq!(unaccent("Naïve"), "Naive");
+        assert_eq!(unaccent("Résumé"), "Resume");
+        assert_eq!(unaccent("Élève"), "Eleve");
+        assert_eq!(unaccent("Über"), "Uber");
+        assert_eq!(unaccent("Español"), "Espanol");
     }
 }
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -755,6 +756,190 @@ impl ScalarFunction for SecondFunction {
     }
 }
 
+// ============================================================================
+// QUARTER / WEEK / DAYOFWEEK / DAYOFYEAR
+// ============================================================================
+
+/// QUARTER function - extracts the quarter from a timestamp (1-4)
+#[derive(Default)]
+pub struct QuarterFunction;
+
+impl ScalarFunction for QuarterFunction {
+    fn name(&self) -> &str {
+        "QUARTER"
+    }
+
+    fn info(&self) -> FunctionInfo {
+        FunctionInfo::new(
+            "QUARTER",
+            FunctionType::Scalar,
+            "Extracts the quarter from a timestamp (1-4)",
+            FunctionSignature::new(FunctionDataType::Integer, vec![FunctionDataType::Any], 1, 1),
+        )
+    }
+
+    fn evaluate(&self, args: &[Value]) -> Result<Value> {
+        validate_arg_count!(args, "QUARTER", 1);
+
+        if args[0].is_null() {
+            return Ok(Value::null_unknown());
+        }
+
+        let ts = match &args[0] {
+            Value::Timestamp(t) => *t,
+            Value::Text(s) => parse_timestamp(s).map_err(|_| {
+                Error::invalid_argument(format!("QUARTER could not parse timestamp: {}", s))
+            })?,
+            _ => {
+                return Err(Error::invalid_argument(
+                    "QUARTER argument must be a timestamp or string",
+                ))
+            }
+        };
+
+        Ok(Value::Integer(((ts.month() - 1) / 3 + 1) as i64))
+    }
+
+    fn clone_box(&self) -> Box<dyn ScalarFunction> {
+        Box::new(QuarterFunction)
+    }
+}
+
+/// WEEK function - extracts the ISO week number from a timestamp (1-53)
+#[derive(Default)]
+pub struct WeekFunction;
+
+impl ScalarFunction for WeekFunction {
+    fn name(&self) -> &str {
+        "WEEK"
+    }
+
+    fn info(&self) -> FunctionInfo {
+        FunctionInfo::new(
+            "WEEK",
+            FunctionType::Scalar,
+            "Extracts the ISO week number from a timestamp (1-53)",
+            FunctionSignature::new(FunctionDataType::Integer, vec![FunctionDataType::Any], 1, 1),
+        )
+    }
+
+    fn evaluate(&self, args: &[Value]) -> Result<Value> {
+        validate_arg_count!(args, "WEEK", 1);
+
+        if args[0].is_null() {
+            return Ok(Value::null_unknown());
+        }
+
+        let ts = match &args[0] {
+            Value::Timestamp(t) => *t,
+            Value::Text(s) => parse_timestamp(s).map_err(|_| {
+                Error::invalid_argument(format!("WEEK could not parse timestamp: {}", s))
+            })?,
+            _ => {
+                return Err(Error::invalid_argument(
+                    "WEEK argument must be a timestamp or string",
+                ))
+            }
+        };
+
+        Ok(Value::Integer(ts.iso_week().week() as i64))
+    }
+
+    fn clone_box(&self) -> Box<dyn ScalarFunction> {
+        Box::new(WeekFunction)
+    }
+}
+
+/// DAYOFWEEK function - extracts the day of the week from a timestamp (0-6, Sunday=0)
+#[derive(Default)]
+pub struct DayOfWeekFunction;
+
+impl ScalarFunction for DayOfWeekFunction {
+    fn name(&self) -> &str {
+        "DAYOFWEEK"
+    }
+
+    fn info(&self) -> FunctionInfo {
+        FunctionInfo::new(
+            "DAYOFWEEK",
+            FunctionType::Scalar,
+            "Extracts the day of the week from a timestamp (0-6, Sunday=0)",
+            FunctionSignature::new(FunctionDataType::Integer, vec![FunctionDataType::Any], 1, 1),
+        )
+    }
+
+    fn evaluate(&self, args: &[Value]) -> Result<Value> {
+        validate_arg_count!(args, "DAYOFWEEK", 1);
+
+        if args[0].is_null() {
+            return Ok(Value::null_unknown());
+        }
+
+        let ts = match &args[0] {
+            Value::Timestamp(t) => *t,
+            Value::Text(s) => parse_timestamp(s).map_err(|_| {
+                Error::invalid_argument(format!("DAYOFWEEK could not parse timestamp: {}", s))
+            })?,
+            _ => {
+                return Err(Error::invalid_argument(
+                    "DAYOFWEEK argument must be a timestamp or string",
+                ))
+            }
+        };
+
+        Ok(Value::Integer(ts.weekday().num_days_from_sunday() as i64))
+    }
+
+    fn clone_box(&self) -> Box<dyn ScalarFunction> {
+        Box::new(DayOfWeekFunction)
+    }
+}
+
+/// DAYOFYEAR function - extracts the day of the year from a timestamp (1-366)
+#[derive(Default)]
+pub struct DayOfYearFunction;
+
+impl ScalarFunction for DayOfYearFunction {
+    fn name(&self) -> &str {
+        "DAYOFYEAR"
+    }
+
+    fn info(&self) -> FunctionInfo {
+        FunctionInfo::new(
+            "DAYOFYEAR",
+            FunctionType::Scalar,
+            "Extracts the day of the year from a timestamp (1-366)",
+            FunctionSignature::new(FunctionDataType::Integer, vec![FunctionDataType::Any], 1, 1),
+        )
+    }
+
+    fn evaluate(&self, args: &[Value]) -> Result<Value> {
+        validate_arg_count!(args, "DAYOFYEAR", 1);
+
+        if args[0].is_null() {
+            return Ok(Value::null_unknown());
+        }
+
+        let ts = match &args[0] {
+            Value::Timestamp(t) => *t,
+            Value::Text(s) => parse_timestamp(s).map_err(|_| {
+                Error::invalid_argument(format!("DAYOFYEAR could not parse timestamp: {}", s))
+            })?,
+            _ => {
+                return Err(Error::invalid_argument(
+                    "DAYOFYEAR argument must be a timestamp or string",
+                ))
+            }
+        };
+
+        Ok(Value::Integer(ts.ordinal() as i64))
+    }
+
+    fn clone_box(&self) -> Box<dyn ScalarFunction> {
+        Box::new(DayOfYearFunction)
+    }
+}
+
 // ============================================================================
 // DATE_ADD / DATE_SUB
 // ============================================================================
@@ -1431,6 +1616,178 @@ fn capitalize_first(s: &str) -> String {
     }
 }
 
+// ============================================================================
+// TO_TIMESTAMP
+// ============================================================================
+
+/// TO_TIMESTAMP function - parses text (or Unix seconds) into a timestamp
+///
+/// With one argument, text is parsed leniently (RFC 3339, RFC 2822,
+/// `YYYY-MM-DD HH:MM[:SS[.f]][+HH:MM]`, dates, ...) and numbers are taken as
+/// seconds since the Unix epoch. With a format, the text must match it
+/// exactly. The format uses the TO_CHAR patterns (YYYY, MM, DD, HH24, MI,
+/// SS, ...) or, if it contains `%`, strftime specifiers.
+///
+/// Unparseable text is an error rather than NULL.
+///
+/// # Examples
+/// ```sql
+/// TO_TIMESTAMP('2024-03-15T14:30:45.5Z')                   -- 2024-03-15 14:30:45.5
+/// TO_TIMESTAMP('15/03/2024 14:30', 'DD/MM/YYYY HH24:MI')   -- 2024-03-15 14:30:00
+/// TO_TIMESTAMP('Mar 15 2024', '%b %d %Y')                  -- 2024-03-15 00:00:00
+/// TO_TIMESTAMP(1710513045)                                 -- 2024-03-15 14:30:45
+/// ```
+#[derive(Default)]
+pub struct ToTimestampFunction;
+
+impl ScalarFunction for ToTimestampFunction {
+    fn name(&self) -> &str {
+        "TO_TIMESTAMP"
+    }
+
+    fn info(&self) -> FunctionInfo {
+        FunctionInfo::new(
+            "TO_TIMESTAMP",
+            FunctionType::Scalar,
+            "Parses text or Unix seconds into a timestamp, optionally with a format pattern",
+            FunctionSignature::new(
+                FunctionDataType::Timestamp,
+                vec![FunctionDataType::Any, FunctionDataType::String],
+                1,
+                2,
+            ),
+        )
+    }
+
+    fn evaluate(&self, args: &[Value]) -> Result<Value> {
+        validate_arg_count!(args, "TO_TIMESTAMP", 1, 2);
+
+        if args.iter().any(Value::is_null) {
+            return Ok(Value::Null(crate::core::DataType::Timestamp));
+        }
+
+        let unparseable = |input: &dyn std::fmt::Display| Error::TypeConversion {
+            from: format!("'{}'", input),
+            to: "TIMESTAMP".to_string(),
+        };
+
+        let ts = match (&args[0], args.get(1)) {
+            (Value::Timestamp(t), None) => *t,
+            (Value::Integer(secs), None) => Utc
+                .timestamp_opt(*secs, 0)
+                .single()
+                .ok_or_else(|| unparseable(secs))?,
+            (Value::Float(secs), None) => {
+                let micros = (secs * 1_000_000.0).round();
+                if !micros.is_finite() || micros.abs() >= i64::MAX as f64 {
+                    return Err(unparseable(secs));
+                }
+                DateTime::from_timestamp_micros(micros as i64).ok_or_else(|| unparseable(secs))?
+            }
+            (Value::Text(s), None) => parse_timestamp(s).map_err(|_| unparseable(s))?,
+            (Value::Text(s), Some(Value::Text(format))) => {
+                parse_timestamp_with_format(s, format).ok_or_else(|| unparseable(s))?
+            }
+            (_, Some(Value::Text(_))) => {
+                return Err(Error::invalid_argument(
+                    "TO_TIMESTAMP with a format requires a string to parse",
+                ))
+            }
+            (_, Some(_)) => {
+                return Err(Error::invalid_argument(
+                    "TO_TIMESTAMP format argument must be a string",
+                ))
+            }
+            (_, None) => {
+                return Err(Error::invalid_argument(
+                    "TO_TIMESTAMP argument must be a string or number",
+                ))
+            }
+        };
+
+        Ok(Value::Timestamp(ts))
+    }
+
+    fn clone_box(&self) -> Box<dyn ScalarFunction> {
+        Box::new(ToTimestampFunction)
+    }
+}
+
+/// Parse text that must match a TO_CHAR-style or strftime format exactly
+///
+/// Formats without a time part produce midnight UTC; formats without an
+/// offset are taken as UTC.
+fn parse_timestamp_with_format(s: &str, format: &str) -> Option<DateTime<Utc>> {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    let format = if format.contains('%') {
+        format.to_string()
+    } else {
+        to_strftime(format)
+    };
+    let s = s.trim();
+
+    if let Ok(dt) = DateTime::parse_from_str(s, &format) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    if let Ok(ndt) = NaiveDateTime::parse_from_str(s, &format) {
+        return Some(Utc.from_utc_datetime(&ndt));
+    }
+    NaiveDate::parse_from_str(s, &format)
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|ndt| Utc.from_utc_datetime(&ndt))
+}
+
+/// Translate TO_CHAR patterns into strftime specifiers
+///
+/// Patterns are matched case-insensitively, longest first; everything else
+/// is literal text.
+fn to_strftime(format: &str) -> String {
+    const PATTERNS: &[(&str, &str)] = &[
+        ("YYYY", "%Y"),
+        ("HH24", "%H"),
+        ("HH12", "%I"),
+        ("MONTH", "%B"),
+        ("DAY", "%A"),
+        ("MON", "%b"),
+        ("YY", "%y"),
+        ("MM", "%m"),
+        ("DD", "%d"),
+        ("DY", "%a"),
+        ("HH", "%I"),
+        ("MI", "%M"),
+        ("SS", "%S"),
+        ("MS", "%3f"),
+        ("US", "%6f"),
+        ("AM", "%p"),
+        ("PM", "%p"),
+        ("TZH:TZM", "%:z"),
+    ];
+
+    let mut out = String::with_capacity(format.len() * 2);
+    let mut rest = format;
+    'outer: while !rest.is_empty() {
+        for (pattern, spec) in PATTERNS {
+            if rest
+                .get(..pattern.len())
+                .is_some_and(|head| head.eq_ignore_ascii_case(pattern))
+            {
+                out.push_str(spec);
+                rest = &rest[pattern.len()..];
+                continue 'outer;
+            }
+        }
+        let c = rest.chars().next().unwrap();
+        if c == '%' {
+            out.push('%');
+        }
+        out.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1649,6 +2006,28 @@ mod tests {
             .is_null());
     }
 
+    #[test]
+    fn test_date_part_functions() {
+        // Friday, March 15, 2024
+        let args = [Value::Timestamp(
+            Utc.with_ymd_and_hms(2024, 3, 15, 10, 30, 45).unwrap(),
+        )];
+        assert_eq!(QuarterFunction.evaluate(&args).unwrap(), Value::Integer(1));
+        assert_eq!(WeekFunction.evaluate(&args).unwrap(), Value::Integer(11));
+        assert_eq!(
+            DayOfWeekFunction.evaluate(&args).unwrap(),
+            Value::Integer(5)
+        );
+        assert_eq!(
+            DayOfYearFunction.evaluate(&args).unwrap(),
+            Value::Integer(75)
+        );
+        assert!(DayOfWeekFunction
+            .evaluate(&[Value::null_unknown()])
+            .unwrap()
+            .is_null());
+    }
+
     #[test]
     fn test_version() {
         let f = VersionFunction;
@@ -1665,4 +2044,43 @@ mod tests {
         let f = VersionFunction;
         assert!(f.evaluate(&[Value::Integer(1)]).is_err());
     }
+
+    #[test]
+    fn test_to_strftime() {
+        assert_eq!(to_strftime("YYYY-MM-DD HH24:MI:SS"), "%Y-%m-%d %H:%M:%S");
+        assert_eq!(to_strftime("dd Mon yy"), "%d %b %y");
+        assert_eq!(to_strftime("HH12:MI AM"), "%I:%M %p");
+        assert_eq!(to_strftime("100% SS.MS"), "100%% %S.%3f");
+    }
+
+    #[test]
+    fn test_to_timestamp_with_format() {
+        let f = ToTimestampFunction;
+        let expected = Utc.with_ymd_and_hms(2024, 3, 15, 14, 30, 0).unwrap();
+        let result = f
+            .evaluate(&[
+                Value::text("15/03/2024 14:30"),
+                Value::text("DD/MM/YYYY HH24:MI"),
+            ])
+            .unwrap();
+        assert_eq!(result, Value::Timestamp(expected));
+
+        let result = f
+            .evaluate(&[Value::text("2024-03-15"), Value::text("%Y-%m-%d")])
+            .unwrap();
+        assert_eq!(
+            result,
+            Value::Timestamp(Utc.with_ymd_and_hms(2024, 3, 15, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_to_timestamp_invalid() {
+        let f = ToTimestampFunction;
+        assert!(f.evaluate(&[Value::text("garbage")]).is_err());
+        assert!(f
+            .evaluate(&[Value::text("2024-03-15"), Value::text("DD/MM/YYYY")])
+            .is_err());
+        assert!(f.evaluate(&[Value::null_unknown()]).unwrap().is_null());
+    }
 }
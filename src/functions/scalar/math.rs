@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -347,7 +348,12 @@ impl ScalarFunction for PowerFunction {
         let exp = value_to_f64(&args[1])
             .ok_or_else(|| Error::invalid_argument("POWER exponent must be a number"))?;
 
-        Ok(Value::Float(base.powf(exp)))
+        // A negative base with a fractional exponent has no real result
+        let result = base.powf(exp);
+        if result.is_nan() && !base.is_nan() && !exp.is_nan() {
+            return Ok(Value::null_unknown());
+        }
+        Ok(Value::Float(result))
     }
 
     fn clone_box(&self) -> Box<dyn ScalarFunction> {
@@ -23,6 +23,10 @@
 //! - [`LengthFunction`] - LENGTH(string)
 //! - [`ConcatFunction`] - CONCAT(string, ...)
 //! - [`SubstringFunction`] - SUBSTRING(string, start, length)
+//! - [`UnaccentFunction`] - UNACCENT(string)
+//! - [`NormalizeFunction`] - NORMALIZE(string, form)
+//! - [`QuoteIdentFunction`] - QUOTE_IDENT(name)
+//! - [`QuoteLiteralFunction`] - QUOTE_LITERAL(value)
 //!
 //! ## Math Functions
 //! - [`AbsFunction`] - ABS(number)
@@ -33,11 +37,16 @@
 //! ## Date/Time Functions
 //! - [`DateTruncFunction`] - DATE_TRUNC(unit, timestamp)
 //! - [`TimeTruncFunction`] - TIME_TRUNC(duration, timestamp)
+//! - [`ToTimestampFunction`] - TO_TIMESTAMP(text [, format])
 //!
 //! ## Conversion Functions
 //! - [`CastFunction`] - CAST(value AS type) - Type conversion
 //! - [`CollateFunction`] - COLLATE(string, collation) - Apply collation
 //!
+//! ## Password Functions (requires the `password` feature)
+//! - `HashPasswordFunction` - HASH_PASSWORD(password)
+//! - `VerifyPasswordFunction` - VERIFY_PASSWORD(password, hash)
+//!
 //! ## Utility Functions
 //! - [`CoalesceFunction`] - COALESCE(value, ...)
 //! - [`NowFunction`] - NOW()
@@ -46,15 +55,18 @@
 mod conversion;
 mod datetime;
 mod math;
+#[cfg(feature = "password")]
+mod password;
 mod string;
 mod utility;
 
 pub use conversion::{CastFunction, CollateFunction};
 pub use datetime::{
     CurrentDateFunction, CurrentTimestampFunction, DateAddFunction, DateDiffAliasFunction,
-    DateDiffFunction, DateSubFunction, DateTruncFunction, DayFunction, ExtractFunction,
-    HourFunction, MinuteFunction, MonthFunction, SecondFunction, TimeTruncFunction, ToCharFunction,
-    VersionFunction, YearFunction,
+    DateDiffFunction, DateSubFunction, DateTruncFunction, DayFunction, DayOfWeekFunction,
+    DayOfYearFunction, ExtractFunction, HourFunction, MinuteFunction, MonthFunction,
+    QuarterFunction, SecondFunction, TimeTruncFunction, ToCharFunction, ToTimestampFunction,
+    VersionFunction, WeekFunction, YearFunction,
 };
 pub use math::{
     AbsFunction, CeilFunction, CeilingFunction, CosFunction, ExpFunction, FloorFunction,
@@ -62,19 +74,25 @@ pub use math::{
     PowerFunction, RandomFunction, RoundFunction, SignFunction, SinFunction, SqrtFunction,
     TanFunction, TruncFunction, TruncateFunction,
 };
+#[cfg(feature = "password")]
+pub use password::{HashPasswordFunction, VerifyPasswordFunction};
+pub(crate) use string::unaccent;
+pub use string::{quote_ident, quote_literal};
 pub use string::{
     CharFunction, CharLengthFunction, ConcatFunction, ConcatWsFunction, InstrFunction,
     LeftFunction, LengthFunction, LocateFunction, LowerFunction, LpadFunction, LtrimFunction,
-    PositionFunction, RepeatFunction, ReplaceFunction, ReverseFunction, RightFunction,
-    RpadFunction, RtrimFunction, SplitPartFunction, StrposFunction, SubstrFunction,
-    SubstringFunction, TrimFunction, UpperFunction,
+    NormalizeFunction, PositionFunction, QuoteIdentFunction, QuoteLiteralFunction, RepeatFunction,
+    ReplaceFunction, ReverseFunction, RightFunction, RpadFunction, RtrimFunction,
+    SplitPartFunction, StrposFunction, SubstrFunction, SubstringFunction, TrimFunction,
+    UnaccentFunction, UpperFunction,
 };
+pub(crate) use utility::{extract_json_path, json_to_value, value_to_json};
 pub use utility::{
     CoalesceFunction, GetHttpHeaderFunction, GreatestFunction, IfNullFunction, IifFunction,
     JsonArrayFunction, JsonArrayLengthFunction, JsonExtractFunction, JsonKeysFunction,
     JsonObjectFunction, JsonTypeFunction, JsonTypeOfFunction, JsonValidFunction, LeastFunction,
-    NowFunction, NullIfFunction, QueryRowsFunction, QueryValueFunction, SleepFunction,
-    TypeOfFunction,
+    NowFunction, NullIfFunction, OrdinalFunction, QueryRowsFunction, QueryValueFunction,
+    SleepFunction, TypeOfFunction,
 };
 
 use crate::core::Value;
@@ -130,6 +148,7 @@ pub fn value_to_string(value: &Value) -> String {
         Value::Boolean(b) => b.to_string(),
         Value::Timestamp(t) => t.to_rfc3339(),
         Value::Json(j) => j.to_string(),
+        Value::Interval(_) | Value::Blob(_) => value.to_string(),
     }
 }
 
@@ -0,0 +1,108 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Password hashing scalar functions
+
+use std::sync::Arc;
+
+use crate::common::password::{hash_password, verify_password};
+use crate::core::{Result, Value};
+use crate::functions::{
+    FunctionDataType, FunctionInfo, FunctionSignature, FunctionType, ScalarFunction,
+};
+use crate::validate_arg_count;
+
+use super::value_to_string;
+
+// ============================================================================
+// HASH_PASSWORD
+// ============================================================================
+
+/// HASH_PASSWORD function - hashes a password with Argon2id and a random salt
+#[derive(Default)]
+pub struct HashPasswordFunction;
+
+impl ScalarFunction for HashPasswordFunction {
+    fn name(&self) -> &str {
+        "HASH_PASSWORD"
+    }
+
+    fn info(&self) -> FunctionInfo {
+        FunctionInfo::new(
+            "HASH_PASSWORD",
+            FunctionType::Scalar,
+            "Hashes a password with Argon2id, returning a PHC string",
+            FunctionSignature::new(FunctionDataType::String, vec![FunctionDataType::Any], 1, 1),
+        )
+    }
+
+    fn evaluate(&self, args: &[Value]) -> Result<Value> {
+        validate_arg_count!(args, "HASH_PASSWORD", 1);
+
+        if args[0].is_null() {
+            return Ok(Value::null_unknown());
+        }
+
+        let hash = hash_password(&value_to_string(&args[0]))?;
+        Ok(Value::Text(Arc::from(hash.as_str())))
+    }
+
+    fn clone_box(&self) -> Box<dyn ScalarFunction> {
+        Box::new(HashPasswordFunction)
+    }
+}
+
+// ============================================================================
+// VERIFY_PASSWORD
+// ============================================================================
+
+/// VERIFY_PASSWORD function - checks a password against a HASH_PASSWORD hash
+/// VERIFY_PASSWORD(password, hash)
+#[derive(Default)]
+pub struct VerifyPasswordFunction;
+
+impl ScalarFunction for VerifyPasswordFunction {
+    fn name(&self) -> &str {
+        "VERIFY_PASSWORD"
+    }
+
+    fn info(&self) -> FunctionInfo {
+        FunctionInfo::new(
+            "VERIFY_PASSWORD",
+            FunctionType::Scalar,
+            "Returns true if the password matches a hash produced by HASH_PASSWORD",
+            FunctionSignature::new(
+                FunctionDataType::Boolean,
+                vec![FunctionDataType::Any, FunctionDataType::String],
+                2,
+                2,
+            ),
+        )
+    }
+
+    fn evaluate(&self, args: &[Value]) -> Result<Value> {
+        validate_arg_count!(args, "VERIFY_PASSWORD", 2);
+
+        if args[0].is_null() || args[1].is_null() {
+            return Ok(Value::null_unknown());
+        }
+
+        let matches = verify_password(&value_to_string(&args[0]), &value_to_string(&args[1]))?;
+        Ok(Value::Boolean(matches))
+    }
+
+    fn clone_box(&self) -> Box<dyn ScalarFunction> {
+        Box::new(VerifyPasswordFunction)
+    }
+}
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -16,10 +17,14 @@
 
 use std::sync::Arc;
 
-use crate::core::{Error, Result, Value};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::core::{DataType, Error, Result, Value};
 use crate::functions::{
     FunctionDataType, FunctionInfo, FunctionSignature, FunctionType, ScalarFunction,
 };
+use crate::parser::IdentifierQuote;
 use crate::validate_arg_count;
 
 use super::{value_to_i64, value_to_string};
@@ -143,6 +148,9 @@ impl ScalarFunction for LengthFunction {
 // ============================================================================
 
 /// CONCAT function - concatenates multiple strings
+///
+/// NULL arguments are treated as empty strings, unlike the `||` operator
+/// which returns NULL if either side is NULL.
 #[derive(Default)]
 pub struct ConcatFunction;
 
@@ -302,7 +310,46 @@ impl ScalarFunction for SubstrFunction {
 // TRIM
 // ============================================================================
 
-/// TRIM function - removes leading and trailing whitespace from a string
+/// Evaluate a TRIM-family call: `(string [, characters])`
+///
+/// Without `characters` whitespace is removed. Otherwise every character
+/// of `characters` is removed, as a set, from the chosen end(s).
+fn evaluate_trim(name: &str, args: &[Value], leading: bool, trailing: bool) -> Result<Value> {
+    if args.is_empty() || args.len() > 2 {
+        return Err(Error::invalid_argument(format!(
+            "{} requires 1 or 2 arguments, got {}",
+            name,
+            args.len()
+        )));
+    }
+
+    if args.iter().any(|arg| arg.is_null()) {
+        return Ok(Value::null_unknown());
+    }
+
+    let s = value_to_string(&args[0]);
+    let trimmed = match args.get(1) {
+        None => match (leading, trailing) {
+            (true, true) => s.trim(),
+            (true, false) => s.trim_start(),
+            _ => s.trim_end(),
+        },
+        Some(chars) => {
+            let set: Vec<char> = value_to_string(chars).chars().collect();
+            let mut t = s.as_str();
+            if leading {
+                t = t.trim_start_matches(set.as_slice());
+            }
+            if trailing {
+                t = t.trim_end_matches(set.as_slice());
+            }
+            t
+        }
+    };
+    Ok(Value::Text(Arc::from(trimmed)))
+}
+
+/// TRIM function - removes leading and trailing whitespace (or the given characters) from a string
 #[derive(Default)]
 pub struct TrimFunction;
 
@@ -315,20 +362,18 @@ impl ScalarFunction for TrimFunction {
         FunctionInfo::new(
             "TRIM",
             FunctionType::Scalar,
-            "Removes leading and trailing whitespace from a string",
-            FunctionSignature::new(FunctionDataType::String, vec![FunctionDataType::Any], 1, 1),
+            "Removes leading and trailing whitespace, or the given characters, from a string",
+            FunctionSignature::new(
+                FunctionDataType::String,
+                vec![FunctionDataType::Any, FunctionDataType::String],
+                1,
+                2,
+            ),
         )
     }
 
     fn evaluate(&self, args: &[Value]) -> Result<Value> {
-        validate_arg_count!(args, "TRIM", 1);
-
-        if args[0].is_null() {
-            return Ok(Value::null_unknown());
-        }
-
-        let s = value_to_string(&args[0]);
-        Ok(Value::Text(Arc::from(s.trim())))
+        evaluate_trim("TRIM", args, true, true)
     }
 
     fn clone_box(&self) -> Box<dyn ScalarFunction> {
@@ -340,7 +385,7 @@ impl ScalarFunction for TrimFunction {
 // LTRIM
 // ============================================================================
 
-/// LTRIM function - removes leading whitespace from a string
+/// LTRIM function - removes leading whitespace (or the given characters) from a string
 #[derive(Default)]
 pub struct LtrimFunction;
 
@@ -353,20 +398,18 @@ impl ScalarFunction for LtrimFunction {
         FunctionInfo::new(
             "LTRIM",
             FunctionType::Scalar,
-            "Removes leading whitespace from a string",
-            FunctionSignature::new(FunctionDataType::String, vec![FunctionDataType::Any], 1, 1),
+            "Removes leading whitespace, or the given characters, from a string",
+            FunctionSignature::new(
+                FunctionDataType::String,
+                vec![FunctionDataType::Any, FunctionDataType::String],
+                1,
+                2,
+            ),
         )
     }
 
     fn evaluate(&self, args: &[Value]) -> Result<Value> {
-        validate_arg_count!(args, "LTRIM", 1);
-
-        if args[0].is_null() {
-            return Ok(Value::null_unknown());
-        }
-
-        let s = value_to_string(&args[0]);
-        Ok(Value::Text(Arc::from(s.trim_start())))
+        evaluate_trim("LTRIM", args, true, false)
     }
 
     fn clone_box(&self) -> Box<dyn ScalarFunction> {
@@ -378,7 +421,7 @@ impl ScalarFunction for LtrimFunction {
 // RTRIM
 // ============================================================================
 
-/// RTRIM function - removes trailing whitespace from a string
+/// RTRIM function - removes trailing whitespace (or the given characters) from a string
 #[derive(Default)]
 pub struct RtrimFunction;
 
@@ -391,20 +434,18 @@ impl ScalarFunction for RtrimFunction {
         FunctionInfo::new(
             "RTRIM",
             FunctionType::Scalar,
-            "Removes trailing whitespace from a string",
-            FunctionSignature::new(FunctionDataType::String, vec![FunctionDataType::Any], 1, 1),
+            "Removes trailing whitespace, or the given characters, from a string",
+            FunctionSignature::new(
+                FunctionDataType::String,
+                vec![FunctionDataType::Any, FunctionDataType::String],
+                1,
+                2,
+            ),
         )
     }
 
     fn evaluate(&self, args: &[Value]) -> Result<Value> {
-        validate_arg_count!(args, "RTRIM", 1);
-
-        if args[0].is_null() {
-            return Ok(Value::null_unknown());
-        }
-
-        let s = value_to_string(&args[0]);
-        Ok(Value::Text(Arc::from(s.trim_end())))
+        evaluate_trim("RTRIM", args, false, true)
     }
 
     fn clone_box(&self) -> Box<dyn ScalarFunction> {
@@ -1203,6 +1244,9 @@ impl ScalarFunction for CharLengthFunction {
 // ============================================================================
 
 /// CONCAT_WS function - concatenates strings with a separator
+///
+/// NULL arguments are skipped along with their separator; a NULL separator
+/// makes the result NULL.
 #[derive(Default)]
 pub struct ConcatWsFunction;
 
@@ -1229,7 +1273,7 @@ impl ScalarFunction for ConcatWsFunction {
 
         // First argument is the separator - if NULL, return NULL
         if args[0].is_null() {
-            return Ok(Value::null_unknown());
+            return Ok(Value::Null(DataType::Text));
         }
 
         let separator = value_to_string(&args[0]);
@@ -1303,10 +1347,254 @@ impl ScalarFunction for StrposFunction {
     }
 }
 
+// ============================================================================
+// UNACCENT
+// ============================================================================
+
+/// Strip diacritics from a string
+///
+/// Characters are decomposed (NFD), combining marks are dropped, and the
+/// result is recomposed. Letters that have no decomposition (ø, ß, æ, ...)
+/// are mapped to their closest ASCII spelling.
+pub(crate) fn unaccent(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.nfd().filter(|c| !is_combining_mark(*c)) {
+        match c {
+            'Ø' => out.push('O'),
+            'ø' => out.push('o'),
+            'Đ' | 'Ð' => out.push('D'),
+            'đ' | 'ð' => out.push('d'),
+            'Ł' => out.push('L'),
+            'ł' => out.push('l'),
+            'Ħ' => out.push('H'),
+            'ħ' => out.push('h'),
+            'ı' => out.push('i'),
+            'ß' => out.push_str("ss"),
+            'Æ' => out.push_str("AE"),
+            'æ' => out.push_str("ae"),
+            'Œ' => out.push_str("OE"),
+            'œ' => out.push_str("oe"),
+            'Þ' => out.push_str("TH"),
+            'þ' => out.push_str("th"),
+            _ => out.push(c),
+        }
+    }
+    out.nfc().collect()
+}
+
+/// UNACCENT function - removes diacritics from a string
+#[derive(Default)]
+pub struct UnaccentFunction;
+
+impl ScalarFunction for UnaccentFunction {
+    fn name(&self) -> &str {
+        "UNACCENT"
+    }
+
+    fn info(&self) -> FunctionInfo {
+        FunctionInfo::new(
+            "UNACCENT",
+            FunctionType::Scalar,
+            "Removes accents and other diacritics from a string",
+            FunctionSignature::new(FunctionDataType::String, vec![FunctionDataType::Any], 1, 1),
+        )
+    }
+
+    fn evaluate(&self, args: &[Value]) -> Result<Value> {
+        validate_arg_count!(args, "UNACCENT", 1);
+
+        if args[0].is_null() {
+            return Ok(Value::null_unknown());
+        }
+
+        let s = value_to_string(&args[0]);
+        Ok(Value::Text(Arc::from(unaccent(&s).as_str())))
+    }
+
+    fn clone_box(&self) -> Box<dyn ScalarFunction> {
+        Box::new(UnaccentFunction)
+    }
+}
+
+// ============================================================================
+// NORMALIZE
+// ============================================================================
+
+/// NORMALIZE function - converts a string to a Unicode normalization form
+/// NORMALIZE(string [, form]) where form is NFC (default), NFD, NFKC or NFKD
+#[derive(Default)]
+pub struct NormalizeFunction;
+
+impl ScalarFunction for NormalizeFunction {
+    fn name(&self) -> &str {
+        "NORMALIZE"
+    }
+
+    fn info(&self) -> FunctionInfo {
+        FunctionInfo::new(
+            "NORMALIZE",
+            FunctionType::Scalar,
+            "Converts a string to Unicode normalization form NFC, NFD, NFKC or NFKD",
+            FunctionSignature::new(
+                FunctionDataType::String,
+                vec![FunctionDataType::Any, FunctionDataType::String],
+                1,
+                2,
+            ),
+        )
+    }
+
+    fn evaluate(&self, args: &[Value]) -> Result<Value> {
+        validate_arg_count!(args, "NORMALIZE", 1, 2);
+
+        if args.iter().any(|arg| arg.is_null()) {
+            return Ok(Value::null_unknown());
+        }
+
+        let s = value_to_string(&args[0]);
+        let form = match args.get(1) {
+            Some(form) => value_to_string(form).to_uppercase(),
+            None => "NFC".to_string(),
+        };
+        let normalized: String = match form.as_str() {
+            "NFC" => s.nfc().collect(),
+            "NFD" => s.nfd().collect(),
+            "NFKC" => s.nfkc().collect(),
+            "NFKD" => s.nfkd().collect(),
+            _ => {
+                return Err(Error::invalid_argument(format!(
+                    "NORMALIZE form must be NFC, NFD, NFKC or NFKD, got '{}'",
+                    form
+                )))
+            }
+        };
+        Ok(Value::Text(Arc::from(normalized.as_str())))
+    }
+
+    fn clone_box(&self) -> Box<dyn ScalarFunction> {
+        Box::new(NormalizeFunction)
+    }
+}
+
+// ============================================================================
+// QUOTE_IDENT / QUOTE_LITERAL
+// ============================================================================
+
+/// Quote a string as a single SQL identifier
+///
+/// The whole string becomes one identifier, dots included, so it can never
+/// name a different object or end the identifier early. The quote character
+/// is the one identifiers take in the current session (SET identifier_quote).
+pub fn quote_ident(name: &str) -> String {
+    quote_ident_with(name, crate::functions::context::get_identifier_quote())
+}
+
+/// Quote a string as a single SQL identifier delimited by `quote`
+fn quote_ident_with(name: &str, quote: IdentifierQuote) -> String {
+    let q = quote.quote_char();
+    let doubled: String = [q, q].iter().collect();
+    format!("{q}{}{q}", name.replace(q, &doubled))
+}
+
+/// Quote a string as a SQL string literal
+///
+/// Quotes are doubled and backslashes escaped, since the lexer treats `\'`
+/// as an escaped quote.
+pub fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''"))
+}
+
+/// QUOTE_IDENT function - quotes a string for use as a table or column name
+#[derive(Default)]
+pub struct QuoteIdentFunction;
+
+impl ScalarFunction for QuoteIdentFunction {
+    fn name(&self) -> &str {
+        "QUOTE_IDENT"
+    }
+
+    fn info(&self) -> FunctionInfo {
+        FunctionInfo::new(
+            "QUOTE_IDENT",
+            FunctionType::Scalar,
+            "Quotes a string for use as an identifier in dynamic SQL",
+            FunctionSignature::new(FunctionDataType::String, vec![FunctionDataType::Any], 1, 1),
+        )
+    }
+
+    fn evaluate(&self, args: &[Value]) -> Result<Value> {
+        validate_arg_count!(args, "QUOTE_IDENT", 1);
+
+        if args[0].is_null() {
+            return Ok(Value::null_unknown());
+        }
+
+        let quoted = quote_ident(&value_to_string(&args[0]));
+        Ok(Value::Text(Arc::from(quoted.as_str())))
+    }
+
+    fn clone_box(&self) -> Box<dyn ScalarFunction> {
+        Box::new(QuoteIdentFunction)
+    }
+}
+
+/// QUOTE_LITERAL function - quotes a value as a string literal for dynamic SQL
+#[derive(Default)]
+pub struct QuoteLiteralFunction;
+
+impl ScalarFunction for QuoteLiteralFunction {
+    fn name(&self) -> &str {
+        "QUOTE_LITERAL"
+    }
+
+    fn info(&self) -> FunctionInfo {
+        FunctionInfo::new(
+            "QUOTE_LITERAL",
+            FunctionType::Scalar,
+            "Quotes a value as a string literal for use in dynamic SQL",
+            FunctionSignature::new(FunctionDataType::String, vec![FunctionDataType::Any], 1, 1),
+        )
+    }
+
+    fn evaluate(&self, args: &[Value]) -> Result<Value> {
+        validate_arg_count!(args, "QUOTE_LITERAL", 1);
+
+        if args[0].is_null() {
+            return Ok(Value::null_unknown());
+        }
+
+        let quoted = quote_literal(&value_to_string(&args[0]));
+        Ok(Value::Text(Arc::from(quoted.as_str())))
+    }
+
+    fn clone_box(&self) -> Box<dyn ScalarFunction> {
+        Box::new(QuoteLiteralFunction)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_quote_ident_and_literal() {
+        assert_eq!(quote_ident("users"), "\"users\"");
+        assert_eq!(quote_ident("a\"b.c"), "\"a\"\"b.c\"");
+        assert_eq!(quote_ident_with("a`b", IdentifierQuote::Backtick), "`a``b`");
+        assert_eq!(quote_literal("it's"), "'it''s'");
+        assert_eq!(quote_literal("a\\"), "'a\\\\'");
+        assert!(QuoteIdentFunction
+            .evaluate(&[Value::null_unknown()])
+            .unwrap()
+            .is_null());
+        assert_eq!(
+            QuoteLiteralFunction
+                .evaluate(&[Value::Integer(42)])
+                .unwrap(),
+            Value::text("'42'")
+        );
+    }
+
     #[test]
     fn test_upper() {
         let f = UpperFunction;
@@ -1418,4 +1706,56 @@ mod tests {
             .unwrap()
             .is_null());
     }
+
+    #[test]
+    fn test_unaccent() {
+        let f = UnaccentFunction;
+        assert_eq!(
+            f.evaluate(&[Value::text("Crème Brûlée à la façon")])
+                .unwrap(),
+            Value::text("Creme Brulee a la facon")
+        );
+        assert_eq!(
+            f.evaluate(&[Value::text("Ångström Łódź Straße Øre")])
+                .unwrap(),
+            Value::text("Angstrom Lodz Strasse Ore")
+        );
+        // Decomposed input and characters without accents
+        assert_eq!(
+            f.evaluate(&[Value::text("Cafe\u{301} 東京 ÿ")]).unwrap(),
+            Value::text("Cafe 東京 y")
+        );
+        // Hangul decomposes into jamo but must come back unchanged
+        assert_eq!(
+            f.evaluate(&[Value::text("한국어")]).unwrap(),
+            Value::text("한국어")
+        );
+        assert!(f.evaluate(&[Value::null_unknown()]).unwrap().is_null());
+    }
+
+    #[test]
+    fn test_normalize() {
+        let f = NormalizeFunction;
+        let composed = "caf\u{e9}";
+        let decomposed = "cafe\u{301}";
+        assert_eq!(
+            f.evaluate(&[Value::text(decomposed)]).unwrap(),
+            Value::text(composed)
+        );
+        assert_eq!(
+            f.evaluate(&[Value::text(composed), Value::text("nfd")])
+                .unwrap(),
+            Value::text(decomposed)
+        );
+        assert_eq!(
+            f.evaluate(&[Value::text("\u{fb01}le"), Value::text("NFKC")])
+                .unwrap(),
+            Value::text("file")
+        );
+        assert!(f.evaluate(&[Value::text("x"), Value::text("NFX")]).is_err());
+        assert!(f
+            .evaluate(&[Value::text("x"), Value::null_unknown()])
+            .unwrap()
+            .is_null());
+    }
 }
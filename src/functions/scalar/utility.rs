@@ -17,7 +17,7 @@
 
 use chrono::Utc;
 
-use crate::core::{Error, Result, Value};
+use crate::core::{DataType, Error, Result, Value};
 use crate::functions::{
     FunctionDataType, FunctionInfo, FunctionSignature, FunctionType, ScalarFunction,
 };
@@ -59,8 +59,12 @@ impl ScalarFunction for CoalesceFunction {
             }
         }
 
-        // If all arguments are null, return null
-        Ok(Value::null_unknown())
+        // If all arguments are null, return the first typed null
+        Ok(args
+            .iter()
+            .find(|arg| arg.data_type() != DataType::Null)
+            .cloned()
+            .unwrap_or_else(Value::null_unknown))
     }
 
     fn clone_box(&self) -> Box<dyn ScalarFunction> {
@@ -136,9 +140,9 @@ impl ScalarFunction for NullIfFunction {
     fn evaluate(&self, args: &[Value]) -> Result<Value> {
         validate_arg_count!(args, "NULLIF", 2);
 
-        // If both are equal, return NULL
+        // If both are equal, return NULL of the first argument's type
         if args[0] == args[1] {
-            return Ok(Value::null_unknown());
+            return Ok(Value::Null(args[0].data_type()));
         }
 
         // Otherwise return the first argument
@@ -431,7 +435,7 @@ impl ScalarFunction for JsonExtractFunction {
 }
 
 /// Extract a value from JSON using a path like "$.name" or "$.user.email" or "$.items[0]"
-fn extract_json_path<'a>(
+pub(crate) fn extract_json_path<'a>(
     value: &'a serde_json::Value,
     path: &str,
 ) -> Option<&'a serde_json::Value> {
@@ -476,7 +480,7 @@ fn extract_json_path<'a>(
 }
 
 /// Convert a serde_json::Value to a stoolap Value
-fn json_to_value(json: &serde_json::Value) -> Result<Value> {
+pub(crate) fn json_to_value(json: &serde_json::Value) -> Result<Value> {
     match json {
         serde_json::Value::Null => Ok(Value::null_unknown()),
         serde_json::Value::Bool(b) => Ok(Value::Boolean(*b)),
@@ -689,7 +693,7 @@ impl ScalarFunction for JsonObjectFunction {
 }
 
 /// Helper function to convert a Value to serde_json::Value
-fn value_to_json(v: &Value) -> serde_json::Value {
+pub(crate) fn value_to_json(v: &Value) -> serde_json::Value {
     match v {
         Value::Null(_) => serde_json::Value::Null,
         Value::Boolean(b) => serde_json::Value::Bool(*b),
@@ -703,6 +707,7 @@ fn value_to_json(v: &Value) -> serde_json::Value {
             serde_json::from_str(j).unwrap_or(serde_json::Value::String(j.to_string()))
         }
         Value::Timestamp(t) => serde_json::Value::String(t.to_rfc3339()),
+        Value::Interval(_) | Value::Blob(_) => serde_json::Value::String(v.to_string()),
     }
 }
 
@@ -1002,6 +1007,8 @@ impl ScalarFunction for TypeOfFunction {
             Value::Boolean(_) => "BOOLEAN",
             Value::Timestamp(_) => "TIMESTAMP",
             Value::Json(_) => "JSON",
+            Value::Interval(_) => "INTERVAL",
+            Value::Blob(_) => "BLOB",
         };
 
         Ok(Value::text(type_name))
@@ -1012,6 +1019,43 @@ impl ScalarFunction for TypeOfFunction {
     }
 }
 
+// ============================================================================
+// ORDINAL
+// ============================================================================
+
+/// ORDINAL function - the 1-based position of a row in the query output
+///
+/// The executor numbers ORDINAL() select-list items after ORDER BY and
+/// LIMIT/OFFSET have been applied; evaluated anywhere else it is NULL.
+#[derive(Default)]
+pub struct OrdinalFunction;
+
+impl ScalarFunction for OrdinalFunction {
+    fn name(&self) -> &str {
+        "ORDINAL"
+    }
+
+    fn info(&self) -> FunctionInfo {
+        FunctionInfo::new(
+            "ORDINAL",
+            FunctionType::Scalar,
+            "Returns the 1-based position of the row in the query output",
+            FunctionSignature::new(FunctionDataType::Integer, vec![], 0, 0),
+        )
+    }
+
+    fn evaluate(&self, args: &[Value]) -> Result<Value> {
+        validate_arg_count!(args, "ORDINAL", 0);
+
+        // Placeholder, filled in as the final rows are produced
+        Ok(Value::null(crate::core::DataType::Integer))
+    }
+
+    fn clone_box(&self) -> Box<dyn ScalarFunction> {
+        Box::new(OrdinalFunction)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -17,8 +17,9 @@
 //!
 //! Functions that return a set of rows, used in FROM clauses.
 
+use super::scalar::{extract_json_path, json_to_value};
 use super::{FunctionDataType, FunctionInfo, FunctionSignature, FunctionType, ScalarFunction};
-use crate::core::{parse_timestamp, Error, Result, Row, Value};
+use crate::core::{parse_timestamp, DataType, Error, Result, Row, Value};
 use chrono::{DateTime, Utc};
 
 /// Maximum number of rows a TVF can generate to prevent OOM
@@ -128,6 +129,7 @@ pub trait TableValuedFunction: Send + Sync {
 ///   generate_series(5, 1)       => 5, 4, 3, 2, 1 (auto-detect descending)
 ///   generate_series('2024-01-01', '2024-01-05', '1 day')
 ///   generate_series('2024-01-01 00:00:00', '2024-01-01 12:00:00', '1 hour')
+///   generate_series('2024-01-01', '2024-01-05', INTERVAL '1 day')
 pub struct GenerateSeriesFunction;
 
 impl GenerateSeriesFunction {
@@ -249,6 +251,7 @@ impl GenerateSeriesFunction {
         let step = if args.len() == 3 {
             match &args[2] {
                 Value::Text(s) => parse_interval(s)?,
+                Value::Interval(micros) => chrono::Duration::microseconds(*micros),
                 Value::Integer(n) => chrono::Duration::try_days(*n).ok_or_else(|| {
                     Error::invalid_argument(format!(
                         "GENERATE_SERIES timestamp step {} days overflows",
@@ -257,7 +260,7 @@ impl GenerateSeriesFunction {
                 })?,
                 _ => {
                     return Err(Error::invalid_argument(
-                        "GENERATE_SERIES timestamp step must be an interval string (e.g., '1 day', '2 hours')",
+                        "GENERATE_SERIES timestamp step must be an interval (e.g., INTERVAL '1 day', '2 hours')",
                     ));
                 }
             }
@@ -415,6 +418,165 @@ impl TableValuedFunction for GenerateSeriesFunction {
     }
 }
 
+/// UNNEST(array)
+///
+/// Expands an array into one row per element. Arrays are JSON arrays, as
+/// produced by ARRAY_AGG; a NULL array produces no rows.
+///
+/// Examples:
+///   unnest('[1, 2, 3]')         => 1, 2, 3
+///   unnest('["a", null]')       => 'a', NULL
+pub struct UnnestFunction;
+
+/// Split an array value into its elements
+pub(crate) fn unnest_values(array: &Value) -> Result<Vec<Value>> {
+    let text = match array {
+        Value::Null(_) => return Ok(Vec::new()),
+        Value::Json(j) => j.to_string(),
+        Value::Text(s) => s.to_string(),
+        _ => {
+            return Err(Error::invalid_argument(format!(
+                "UNNEST expects an array, got {}",
+                array
+            )))
+        }
+    };
+
+    match serde_json::from_str(&text) {
+        Ok(serde_json::Value::Array(elements)) => elements.iter().map(json_to_value).collect(),
+        _ => Err(Error::invalid_argument(format!(
+            "UNNEST expects an array, got {}",
+            array
+        ))),
+    }
+}
+
+impl TableValuedFunction for UnnestFunction {
+    fn name(&self) -> &str {
+        "UNNEST"
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        vec!["value".to_string()]
+    }
+
+    fn generate(&self, args: &[Value], limit: Option<usize>) -> Result<Vec<Row>> {
+        if args.len() != 1 {
+            return Err(Error::invalid_argument("UNNEST requires 1 argument"));
+        }
+
+        let max_rows = limit.unwrap_or(MAX_TVF_ROWS).min(MAX_TVF_ROWS);
+        Ok(unnest_values(&args[0])?
+            .into_iter()
+            .take(max_rows)
+            .map(|v| Row::from_values(vec![v]))
+            .collect())
+    }
+}
+
+/// JSON_TABLE(doc, row_path COLUMNS (name TYPE PATH 'path', ...))
+///
+/// Projects the JSON values selected by `row_path` into rows, one column per
+/// COLUMNS entry. A row path ending in `[*]` yields each element of that
+/// array; any other path yields its target, or each element if it is an
+/// array. Column paths are relative to the row value; a missing value is NULL.
+/// The parser passes each COLUMNS entry as three arguments: name, type, path.
+///
+/// Examples:
+///   json_table('{"items": [{"name": "a", "qty": 2}]}', '$.items[*]'
+///              COLUMNS (name TEXT PATH '$.name', qty INTEGER PATH '$.qty'))
+///       => ('a', 2)
+pub struct JsonTableFunction;
+
+impl JsonTableFunction {
+    /// The JSON values a row path selects
+    fn row_values<'a>(doc: &'a serde_json::Value, path: &str) -> Vec<&'a serde_json::Value> {
+        let (path, each) = match path.trim().strip_suffix("[*]") {
+            Some(prefix) => (prefix, true),
+            None => (path.trim(), false),
+        };
+        match extract_json_path(doc, path) {
+            Some(serde_json::Value::Array(elements)) => elements.iter().collect(),
+            Some(_) if each => Vec::new(),
+            Some(value) => vec![value],
+            None => Vec::new(),
+        }
+    }
+}
+
+impl TableValuedFunction for JsonTableFunction {
+    fn name(&self) -> &str {
+        "JSON_TABLE"
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        // Named by the COLUMNS clause
+        Vec::new()
+    }
+
+    fn generate(&self, args: &[Value], limit: Option<usize>) -> Result<Vec<Row>> {
+        if args.len() < 5 || !(args.len() - 2).is_multiple_of(3) {
+            return Err(Error::invalid_argument(
+                "JSON_TABLE requires a document, a row path and a COLUMNS clause",
+            ));
+        }
+
+        let text = match &args[0] {
+            Value::Null(_) => return Ok(Vec::new()),
+            Value::Json(j) => j.to_string(),
+            Value::Text(s) => s.to_string(),
+            other => {
+                return Err(Error::invalid_argument(format!(
+                    "JSON_TABLE expects a JSON document, got {}",
+                    other
+                )))
+            }
+        };
+        let doc: serde_json::Value = serde_json::from_str(&text).map_err(|e| {
+            Error::invalid_argument(format!("JSON_TABLE: invalid JSON document: {}", e))
+        })?;
+        let Value::Text(row_path) = &args[1] else {
+            return Err(Error::invalid_argument(
+                "JSON_TABLE row path must be a string",
+            ));
+        };
+
+        let mut columns = Vec::with_capacity((args.len() - 2) / 3);
+        for spec in args[2..].chunks(3) {
+            let (Value::Text(name), Value::Text(type_name), Value::Text(path)) =
+                (&spec[0], &spec[1], &spec[2])
+            else {
+                return Err(Error::invalid_argument(
+                    "JSON_TABLE: invalid COLUMNS clause",
+                ));
+            };
+            let data_type: DataType = type_name.parse().map_err(|_| {
+                Error::invalid_argument(format!(
+                    "JSON_TABLE: unknown type {} for column {}",
+                    type_name, name
+                ))
+            })?;
+            columns.push((data_type, path.to_string()));
+        }
+
+        let max_rows = limit.unwrap_or(MAX_TVF_ROWS).min(MAX_TVF_ROWS);
+        Self::row_values(&doc, row_path)
+            .into_iter()
+            .take(max_rows)
+            .map(|row| {
+                let values = columns
+                    .iter()
+                    .map(|(data_type, path)| match extract_json_path(row, path) {
+                        None | Some(serde_json::Value::Null) => Ok(Value::null(*data_type)),
+                        Some(json) => Ok(json_to_value(json)?.into_coerce_to_type(*data_type)),
+                    })
+                    .collect::<Result<Vec<Value>>>()?;
+                Ok(Row::from_values(values))
+            })
+            .collect()
+    }
+}
+
 /// Scalar version of GENERATE_SERIES that returns a JSON array.
 /// Used when called as SELECT generate_series(1, 5) (without FROM clause).
 /// Returns [1, 2, 3, 4, 5] as a text value, matching DuckDB behavior.
@@ -473,6 +635,47 @@ impl ScalarFunction for GenerateSeriesScalarFunction {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_unnest() {
+        let rows = UnnestFunction
+            .generate(&[Value::text(r#"[1, "a", null, [2]]"#)], None)
+            .unwrap();
+        let values: Vec<Value> = rows.iter().map(|r| r.get(0).unwrap().clone()).collect();
+        assert_eq!(values[0], Value::Integer(1));
+        assert_eq!(values[1], Value::text("a"));
+        assert!(values[2].is_null());
+        assert_eq!(values[3], Value::Json(std::sync::Arc::from("[2]")));
+
+        assert!(UnnestFunction
+            .generate(&[Value::null_unknown()], None)
+            .unwrap()
+            .is_empty());
+        assert!(UnnestFunction
+            .generate(&[Value::text("abc")], None)
+            .is_err());
+        assert!(UnnestFunction.generate(&[Value::Integer(1)], None).is_err());
+    }
+
+    #[test]
+    fn test_generate_series_interval_step() {
+        let gs = GenerateSeriesFunction;
+        let rows = gs
+            .generate(
+                &[
+                    Value::text("2024-01-01"),
+                    Value::text("2024-01-02"),
+                    Value::Interval(6 * 3_600_000_000),
+                ],
+                None,
+            )
+            .unwrap();
+        assert_eq!(rows.len(), 5); // 00:00, 06:00, 12:00, 18:00, 00:00
+        assert_eq!(
+            rows[4].get(0),
+            Some(&Value::Timestamp(parse_timestamp("2024-01-02").unwrap()))
+        );
+    }
+
     #[test]
     fn test_generate_series_basic() {
         let gs = GenerateSeriesFunction;
@@ -20,8 +20,9 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use super::backends::pool::with_script_worker;
 use super::backends::BackendRegistry;
-use super::{FunctionInfo, FunctionSignature, ScalarFunction};
+use super::{FunctionDataType, FunctionInfo, FunctionSignature, ScalarFunction};
 use crate::core::{Error, Result, Value};
 
 /// User-defined scalar function with pluggable scripting backends
@@ -82,15 +83,8 @@ impl ScalarFunction for UserDefinedScalarFunction {
             })?;
 
         // Execute using the backend with parameter names
-        backend.execute(
-            &self.code,
-            args,
-            &self
-                .param_names
-                .iter()
-                .map(|s| s.as_str())
-                .collect::<Vec<_>>(),
-        )
+        let param_names: Vec<&str> = self.param_names.iter().map(|s| s.as_str()).collect();
+        with_script_worker(|| backend.execute(&self.code, args, &param_names))
     }
 
     fn clone_box(&self) -> Box<dyn ScalarFunction> {
@@ -105,9 +99,113 @@ impl ScalarFunction for UserDefinedScalarFunction {
     }
 }
 
+/// A user-defined function name with several overloads
+///
+/// The overload is picked per call from the argument count and the types of
+/// the argument values.
+pub struct OverloadedUserFunction {
+    name: String,
+    overloads: Vec<Arc<UserDefinedScalarFunction>>,
+}
+
+impl OverloadedUserFunction {
+    fn resolve(&self, args: &[Value]) -> Result<&UserDefinedScalarFunction> {
+        let candidates: Vec<&UserDefinedScalarFunction> = self
+            .overloads
+            .iter()
+            .map(|udf| udf.as_ref())
+            .filter(|udf| udf.signature.argument_types.len() == args.len())
+            .collect();
+        if let [only] = candidates[..] {
+            return Ok(only);
+        }
+        // Prefer an exact type match, then one that only widens integers
+        for exact in [true, false] {
+            if let Some(udf) = candidates.iter().find(|udf| {
+                udf.signature
+                    .argument_types
+                    .iter()
+                    .zip(args)
+                    .all(|(ty, arg)| argument_matches(*ty, arg, exact))
+            }) {
+                return Ok(udf);
+            }
+        }
+        Err(Error::invalid_argument(format!(
+            "no overload of function '{}' matches {} argument(s) of the given types",
+            self.name,
+            args.len()
+        )))
+    }
+}
+
+/// Format a function name with its argument types, as in `ADD(INTEGER, TEXT)`
+pub fn format_signature(name: &str, argument_types: &[FunctionDataType]) -> String {
+    let types: Vec<String> = argument_types.iter().map(|ty| ty.to_string()).collect();
+    format!("{}({})", name, types.join(", "))
+}
+
+/// Whether an argument value can be passed for a parameter of the given type
+fn argument_matches(ty: FunctionDataType, arg: &Value, exact: bool) -> bool {
+    match (ty, arg) {
+        (FunctionDataType::Any | FunctionDataType::Unknown, _) | (_, Value::Null(_)) => true,
+        (FunctionDataType::Integer, Value::Integer(_))
+        | (FunctionDataType::Float, Value::Float(_))
+        | (FunctionDataType::String, Value::Text(_))
+        | (FunctionDataType::Boolean, Value::Boolean(_))
+        | (FunctionDataType::Json, Value::Json(_)) => true,
+        (
+            FunctionDataType::Timestamp
+            | FunctionDataType::Date
+            | FunctionDataType::Time
+            | FunctionDataType::DateTime,
+            Value::Timestamp(_),
+        ) => true,
+        (FunctionDataType::Float, Value::Integer(_)) => !exact,
+        _ => false,
+    }
+}
+
+impl ScalarFunction for OverloadedUserFunction {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn info(&self) -> FunctionInfo {
+        let arities = self
+            .overloads
+            .iter()
+            .map(|udf| udf.signature.argument_types.len());
+        FunctionInfo::new(
+            self.name.clone(),
+            super::FunctionType::Scalar,
+            "User-defined function".to_string(),
+            FunctionSignature::new(
+                FunctionDataType::Unknown,
+                vec![],
+                arities.clone().min().unwrap_or(0),
+                arities.max().unwrap_or(0),
+            ),
+        )
+    }
+
+    fn evaluate(&self, args: &[Value]) -> Result<Value> {
+        self.resolve(args)?.evaluate(args)
+    }
+
+    fn clone_box(&self) -> Box<dyn ScalarFunction> {
+        Box::new(Self {
+            name: self.name.clone(),
+            overloads: self.overloads.clone(),
+        })
+    }
+}
+
 /// Registry for user-defined functions
+///
+/// A name maps to one or more overloads with distinct argument types.
 pub struct UserDefinedFunctionRegistry {
-    functions: HashMap<String, Arc<UserDefinedScalarFunction>>,
+    functions: HashMap<String, Vec<Arc<UserDefinedScalarFunction>>>,
     backend_registry: Arc<BackendRegistry>,
 }
 
@@ -120,6 +218,8 @@ impl UserDefinedFunctionRegistry {
     }
 
     /// Register a user-defined function
+    ///
+    /// Replaces the overload with the same argument types, if there is one.
     pub fn register(
         &mut self,
         name: String,
@@ -144,13 +244,28 @@ impl UserDefinedFunctionRegistry {
             signature,
             self.backend_registry.clone(),
         ));
-        self.functions.insert(name.to_uppercase(), udf);
+        let overloads = self.functions.entry(name.to_uppercase()).or_default();
+        match overloads
+            .iter_mut()
+            .find(|existing| existing.signature.argument_types == udf.signature.argument_types)
+        {
+            Some(existing) => *existing = udf,
+            None => overloads.push(udf),
+        }
         Ok(())
     }
 
-    /// Get a user-defined function
-    pub fn get(&self, name: &str) -> Option<Arc<UserDefinedScalarFunction>> {
-        self.functions.get(&name.to_uppercase()).cloned()
+    /// Get a user-defined function, dispatching between its overloads if it
+    /// has several
+    pub fn get(&self, name: &str) -> Option<Box<dyn ScalarFunction>> {
+        match self.functions.get(&name.to_uppercase())?.as_slice() {
+            [] => None,
+            [udf] => Some(udf.clone_box()),
+            overloads => Some(Box::new(OverloadedUserFunction {
+                name: overloads[0].name.clone(),
+                overloads: overloads.to_vec(),
+            })),
+        }
     }
 
     /// Check if a function exists
@@ -173,10 +288,39 @@ impl UserDefinedFunctionRegistry {
     }
 
     /// Unregister a user-defined function
-    pub fn unregister(&mut self, name: &str) -> Result<()> {
+    ///
+    /// With `argument_types` only that overload is removed; without them the
+    /// name must have a single overload.
+    pub fn unregister(
+        &mut self,
+        name: &str,
+        argument_types: Option<&[FunctionDataType]>,
+    ) -> Result<()> {
         let key = name.to_uppercase();
-        if self.functions.remove(&key).is_none() {
-            return Err(Error::FunctionNotFound(name.to_string()));
+        let overloads = self
+            .functions
+            .get_mut(&key)
+            .ok_or_else(|| Error::FunctionNotFound(name.to_string()))?;
+        let position = match argument_types {
+            Some(types) => overloads
+                .iter()
+                .position(|udf| udf.signature.argument_types == types)
+                .ok_or_else(|| Error::FunctionNotFound(name.to_string()))?,
+            None if overloads.len() > 1 => {
+                let candidates: Vec<String> = overloads
+                    .iter()
+                    .map(|udf| format_signature(&key, &udf.signature.argument_types))
+                    .collect();
+                return Err(Error::AmbiguousFunction {
+                    name: name.to_string(),
+                    candidates: candidates.join(", "),
+                });
+            }
+            None => 0,
+        };
+        overloads.remove(position);
+        if overloads.is_empty() {
+            self.functions.remove(&key);
         }
         Ok(())
     }
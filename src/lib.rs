@@ -78,8 +78,8 @@ pub mod server;
 
 // Re-export main types for convenience
 pub use core::{
-    DataType, Error, IndexEntry, IndexType, IsolationLevel, Operator, Result, Row, Schema,
-    SchemaBuilder, SchemaColumn, Value,
+    DataType, Error, IndexEntry, IndexType, IsolationLevel, Operator, Result, Row, RowLockMode,
+    Schema, SchemaBuilder, SchemaColumn, TransactionStatus, Value,
 };
 
 // Re-export common utilities
@@ -98,12 +98,12 @@ pub use storage::{
 pub use storage::{BTree, Int64BTree};
 
 // Re-export config types
-pub use storage::{Config, PersistenceConfig, SyncMode};
+pub use storage::{AutoVacuumConfig, Config, PersistenceConfig, SyncMode};
 
 // Re-export storage traits
 pub use storage::{
-    EmptyResult, EmptyScanner, Engine, Index, MemoryResult, QueryResult, Scanner, Table,
-    TemporalType, Transaction, VecScanner,
+    ColumnMeta, EmptyResult, EmptyScanner, Engine, Index, MemoryResult, QueryResult, Scanner,
+    Table, TemporalType, Transaction, VecScanner,
 };
 
 // Re-export MVCC types
@@ -144,12 +144,20 @@ pub use functions::{
 
 // Re-export executor types
 pub use executor::{
-    AccessPlan, CacheStats, CachedQueryPlan, ColumnStatsCache, ExecResult, ExecutionContext,
-    Executor, ExecutorMemoryResult, JoinPlan, QueryCache, QueryPlanner, StatsHealth,
+    AccessPlan, CacheStats, CachedQueryPlan, Catalog, CatalogColumn, CatalogConstraint,
+    CatalogIndex, CatalogParameter, CatalogRoutine, CatalogTable, CatalogView, ColumnStatsCache,
+    ExecResult, ExecutionContext, Executor, ExecutorMemoryResult, JoinPlan, QueryCache,
+    QueryPlanner, RoutineKind, StatsHealth, Warning, WarningLevel,
 };
 
+// Re-export the SQL formatter
+pub use parser::format_sql;
+
 // Re-export API types
+#[cfg(feature = "arrow")]
+pub use api::{ArrowBatches, DEFAULT_ARROW_BATCH_SIZE};
 pub use api::{
-    Database, FromRow, FromValue, NamedParams, Params, RelatedObject, ResultRow, Rows, Statement,
-    ToParam, Transaction as ApiTransaction,
+    Database, DeleteBuilder, EngineFactory, ExportFormat, FromRow, FromValue, InsertBuilder,
+    NamedParams, OutputFormat, Params, RelatedObject, ResultRow, RowCounts, Rows, SelectBuilder,
+    Statement, ToParam, Transaction as ApiTransaction, UpdateBuilder,
 };
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -215,6 +216,8 @@ impl BloomFilter {
             Value::Text(s) => s.hash(&mut hasher),
             Value::Timestamp(t) => t.timestamp_nanos_opt().hash(&mut hasher),
             Value::Json(j) => j.hash(&mut hasher),
+            Value::Interval(i) => i.hash(&mut hasher),
+            Value::Blob(b) => b.hash(&mut hasher),
         }
         hasher.finish()
     }
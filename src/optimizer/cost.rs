@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -736,6 +737,47 @@ impl CostEstimator {
         )
     }
 
+    /// Estimate cost of keeping one index up to date for `rows` written rows
+    ///
+    /// B-tree inserts walk the tree, so their cost grows with the table size;
+    /// hash and bitmap inserts are constant time. Unique indexes pay an extra
+    /// lookup per row to check for duplicates.
+    pub fn estimate_index_maintenance(
+        &self,
+        index_type: IndexType,
+        is_unique: bool,
+        rows: u64,
+        table_rows: u64,
+    ) -> f64 {
+        let per_row = match index_type {
+            IndexType::BTree => {
+                self.constants.cpu_index_tuple_cost * (table_rows.max(2) as f64).log2()
+            }
+            IndexType::Hash | IndexType::MultiColumn => self.constants.cpu_index_tuple_cost * 2.0,
+            IndexType::Bitmap => self.constants.cpu_index_tuple_cost,
+        };
+        let unique_check = if is_unique {
+            self.constants.cpu_index_tuple_cost
+        } else {
+            0.0
+        };
+        rows as f64 * (per_row + unique_check)
+    }
+
+    /// Estimate cost of checking a constraint for `rows` written rows
+    ///
+    /// `needs_lookup` is set for constraints that probe another row by key
+    /// (primary key uniqueness, foreign keys); other constraints only
+    /// evaluate an expression on the row itself.
+    pub fn estimate_constraint_check(&self, rows: u64, needs_lookup: bool) -> f64 {
+        let per_row = if needs_lookup {
+            self.constants.pk_lookup_cost
+        } else {
+            self.constants.cpu_operator_cost
+        };
+        rows as f64 * per_row
+    }
+
     /// Estimate cost of an index scan
     pub fn estimate_index_scan(
         &self,
@@ -2878,4 +2920,30 @@ mod tests {
             "Parallel plan should indicate parallel execution"
         );
     }
+
+    #[test]
+    fn test_write_maintenance_cost() {
+        let estimator = CostEstimator::new();
+
+        // B-tree maintenance grows with the table, hash does not
+        let small = estimator.estimate_index_maintenance(IndexType::BTree, false, 100, 1_000);
+        let large = estimator.estimate_index_maintenance(IndexType::BTree, false, 100, 1_000_000);
+        assert!(large > small);
+        let hash_small = estimator.estimate_index_maintenance(IndexType::Hash, false, 100, 1_000);
+        let hash_large =
+            estimator.estimate_index_maintenance(IndexType::Hash, false, 100, 1_000_000);
+        assert_eq!(hash_small, hash_large);
+
+        // Unique indexes cost more than plain ones
+        assert!(
+            estimator.estimate_index_maintenance(IndexType::Hash, true, 100, 1_000) > hash_small
+        );
+
+        // Key lookups are more expensive than row-local checks
+        assert!(
+            estimator.estimate_constraint_check(10, true)
+                > estimator.estimate_constraint_check(10, false)
+        );
+        assert_eq!(estimator.estimate_constraint_check(0, true), 0.0);
+    }
 }
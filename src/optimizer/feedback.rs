@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -323,6 +324,7 @@ fn hash_expression_structure(expr: &Expression, hasher: &mut DefaultHasher) {
         Expression::Between(between) => {
             "BETWEEN".hash(hasher);
             between.not.hash(hasher);
+            between.symmetric.hash(hasher);
             hash_expression_structure(&between.expr, hasher);
             // Lower and upper structure matters
             hash_expression_structure(&between.lower, hasher);
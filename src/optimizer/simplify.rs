@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -79,6 +80,11 @@ impl ExpressionSimplifier {
                 ) = (&value, &lower, &upper)
                 {
                     self.simplified = true;
+                    let (l, h) = if between.symmetric && l.value > h.value {
+                        (h, l)
+                    } else {
+                        (l, h)
+                    };
                     let result = v.value >= l.value && v.value <= h.value;
                     return if between.not {
                         self.make_bool(!result)
@@ -93,6 +99,7 @@ impl ExpressionSimplifier {
                     lower: Box::new(lower),
                     upper: Box::new(upper),
                     not: between.not,
+                    symmetric: between.symmetric,
                 })
             }
             Expression::In(in_expr) => {
@@ -477,7 +484,7 @@ impl ExpressionSimplifier {
                 let name = func.function.to_uppercase();
                 if matches!(
                     name.as_str(),
-                    "RANDOM" | "NOW" | "CURRENT_TIMESTAMP" | "UUID"
+                    "RANDOM" | "NOW" | "CURRENT_TIMESTAMP" | "UUID" | "ORDINAL" | "HASH_PASSWORD"
                 ) {
                     return false;
                 }
@@ -24,7 +24,7 @@ use std::collections::HashMap;
 use std::fmt;
 use std::sync::Arc;
 
-use crate::core::Value;
+use crate::core::{RowLockMode, Value};
 
 // ============================================================================
 // Core Traits
@@ -384,6 +384,60 @@ pub struct StringLiteral {
     pub type_hint: Option<String>,
 }
 
+impl StringLiteral {
+    /// Builds the literal from a string token, removing the surrounding
+    /// quotes and decoding escape sequences
+    pub fn from_token(token: Token) -> Self {
+        let literal = &token.literal;
+        // Remove surrounding quotes
+        let value = if literal.len() >= 2 {
+            let inner = &literal[1..literal.len() - 1];
+            // Handle escape sequences
+            // Note: We don't convert \" to " because:
+            // 1. In SQL, double quotes don't need escaping inside single-quoted strings
+            // 2. Converting \" to " breaks JSON content like {"key":"value with \"quotes\""}
+            // Decoded in one pass so an escaped backslash is never read as the
+            // start of another escape (`\\n` is a backslash followed by `n`)
+            let mut value = String::with_capacity(inner.len());
+            let mut chars = inner.chars();
+            while let Some(c) = chars.next() {
+                if c != '\\' {
+                    value.push(c);
+                    continue;
+                }
+                match chars.next() {
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some('r') => value.push('\r'),
+                    Some('\'') => value.push('\''),
+                    Some('\\') => value.push('\\'),
+                    Some(other) => {
+                        value.push('\\');
+                        value.push(other);
+                    }
+                    None => value.push('\\'),
+                }
+            }
+            value
+        } else {
+            literal.clone()
+        };
+
+        // Check for JSON type hint
+        let type_hint = if value.starts_with('{') && value.ends_with('}') {
+            Some("JSON".to_string())
+        } else {
+            None
+        };
+
+        Self {
+            token,
+            value,
+            type_hint,
+        }
+    }
+}
+
 impl fmt::Display for StringLiteral {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "'{}'", self.value)
@@ -827,19 +881,21 @@ pub struct BetweenExpression {
     pub lower: Box<Expression>,
     pub upper: Box<Expression>,
     pub not: bool,
+    /// BETWEEN SYMMETRIC: the bounds may be given in either order
+    pub symmetric: bool,
 }
 
 impl fmt::Display for BetweenExpression {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.not {
-            write!(
-                f,
-                "{} NOT BETWEEN {} AND {}",
-                self.expr, self.lower, self.upper
-            )
-        } else {
-            write!(f, "{} BETWEEN {} AND {}", self.expr, self.lower, self.upper)
-        }
+        write!(
+            f,
+            "{} {}BETWEEN {}{} AND {}",
+            self.expr,
+            if self.not { "NOT " } else { "" },
+            if self.symmetric { "SYMMETRIC " } else { "" },
+            self.lower,
+            self.upper
+        )
     }
 }
 
@@ -1148,6 +1204,8 @@ pub struct SimpleTableSource {
     pub name: TableName,
     pub alias: Option<Identifier>,
     pub as_of: Option<AsOfClause>,
+    /// FORCE INDEX / USE INDEX / IGNORE INDEX after the table name
+    pub index_hint: Option<TableIndexHint>,
 }
 
 impl fmt::Display for SimpleTableSource {
@@ -1159,10 +1217,90 @@ impl fmt::Display for SimpleTableSource {
         if let Some(ref alias) = self.alias {
             result.push_str(&format!(" AS {}", alias));
         }
+        if let Some(ref hint) = self.index_hint {
+            result.push_str(&format!(" {}", hint));
+        }
         write!(f, "{}", result)
     }
 }
 
+/// Table-level index hint: `FORCE INDEX (a, b)` or `IGNORE INDEX (a)`
+///
+/// `USE INDEX` is accepted as a synonym for `FORCE INDEX`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableIndexHint {
+    /// True for IGNORE INDEX
+    pub ignore: bool,
+    pub indexes: Vec<String>,
+}
+
+impl fmt::Display for TableIndexHint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let keyword = if self.ignore { "IGNORE" } else { "FORCE" };
+        write!(f, "{} INDEX ({})", keyword, self.indexes.join(", "))
+    }
+}
+
+/// Join algorithm requested by a hint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinMethodHint {
+    Hash,
+    NestedLoop,
+    Merge,
+}
+
+impl fmt::Display for JoinMethodHint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JoinMethodHint::Hash => write!(f, "HASH_JOIN"),
+            JoinMethodHint::NestedLoop => write!(f, "NL_JOIN"),
+            JoinMethodHint::Merge => write!(f, "MERGE_JOIN"),
+        }
+    }
+}
+
+/// Optimizer hint from a `/*+ ... */` comment right after SELECT
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryHint {
+    /// INDEX(table index ...): scan the table with one of the listed indexes
+    Index { table: String, indexes: Vec<String> },
+    /// NO_INDEX(table [index ...]): do not use the listed indexes (any index if none listed)
+    NoIndex { table: String, indexes: Vec<String> },
+    /// LEADING(table ...): join order, first table drives the join
+    Leading(Vec<String>),
+    /// HASH_JOIN / NL_JOIN / MERGE_JOIN, optionally limited to joins of the listed tables
+    JoinMethod {
+        method: JoinMethodHint,
+        tables: Vec<String>,
+    },
+    /// Unrecognized hint, kept so it can be reported
+    Unknown(String),
+}
+
+impl fmt::Display for QueryHint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryHint::Index { table, indexes } => {
+                write!(f, "INDEX({} {})", table, indexes.join(" "))
+            }
+            QueryHint::NoIndex { table, indexes } if indexes.is_empty() => {
+                write!(f, "NO_INDEX({})", table)
+            }
+            QueryHint::NoIndex { table, indexes } => {
+                write!(f, "NO_INDEX({} {})", table, indexes.join(" "))
+            }
+            QueryHint::Leading(tables) => write!(f, "LEADING({})", tables.join(" ")),
+            QueryHint::JoinMethod { method, tables } if tables.is_empty() => {
+                write!(f, "{}", method)
+            }
+            QueryHint::JoinMethod { method, tables } => {
+                write!(f, "{}({})", method, tables.join(" "))
+            }
+            QueryHint::Unknown(text) => write!(f, "{}", text),
+        }
+    }
+}
+
 /// AS OF clause for temporal queries
 #[derive(Debug, Clone, PartialEq)]
 pub struct AsOfClause {
@@ -1351,18 +1489,35 @@ pub enum Statement {
     Commit(CommitStatement),
     Rollback(RollbackStatement),
     Savepoint(SavepointStatement),
+    ReleaseSavepoint(ReleaseSavepointStatement),
     Set(SetStatement),
+    SetTransaction(SetTransactionStatement),
     Pragma(PragmaStatement),
     ShowTables(ShowTablesStatement),
     ShowViews(ShowViewsStatement),
     ShowFunctions(ShowFunctionsStatement),
+    ShowProcedures(ShowProceduresStatement),
+    ShowSequences(ShowSequencesStatement),
+    ShowIsolationLevel(ShowIsolationLevelStatement),
+    ShowSetting(ShowSettingStatement),
     ShowCreateTable(ShowCreateTableStatement),
     ShowCreateView(ShowCreateViewStatement),
     ShowIndexes(ShowIndexesStatement),
+    ShowWarnings(ShowWarningsStatement),
+    ShowEngineStatus(ShowEngineStatusStatement),
+    ShowIndexSuggestions(ShowIndexSuggestionsStatement),
+    ShowRowVersions(ShowRowVersionsStatement),
     Describe(DescribeStatement),
     Expression(ExpressionStatement),
     Explain(ExplainStatement),
     Analyze(AnalyzeStatement),
+    Reindex(ReindexStatement),
+    Checkpoint(CheckpointStatement),
+    AttachDatabase(AttachDatabaseStatement),
+    DetachDatabase(DetachDatabaseStatement),
+    DeclareCursor(DeclareCursorStatement),
+    Fetch(FetchStatement),
+    CloseCursor(CloseCursorStatement),
     Copy(CopyStatement),
 }
 
@@ -1403,18 +1558,35 @@ impl fmt::Display for Statement {
             Statement::Commit(s) => write!(f, "{}", s),
             Statement::Rollback(s) => write!(f, "{}", s),
             Statement::Savepoint(s) => write!(f, "{}", s),
+            Statement::ReleaseSavepoint(s) => write!(f, "{}", s),
             Statement::Set(s) => write!(f, "{}", s),
+            Statement::SetTransaction(s) => write!(f, "{}", s),
             Statement::Pragma(s) => write!(f, "{}", s),
             Statement::ShowTables(s) => write!(f, "{}", s),
             Statement::ShowViews(s) => write!(f, "{}", s),
             Statement::ShowFunctions(s) => write!(f, "{}", s),
+            Statement::ShowProcedures(s) => write!(f, "{}", s),
+            Statement::ShowSequences(s) => write!(f, "{}", s),
+            Statement::ShowIsolationLevel(s) => write!(f, "{}", s),
+            Statement::ShowSetting(s) => write!(f, "{}", s),
             Statement::ShowCreateTable(s) => write!(f, "{}", s),
             Statement::ShowCreateView(s) => write!(f, "{}", s),
             Statement::ShowIndexes(s) => write!(f, "{}", s),
+            Statement::ShowWarnings(s) => write!(f, "{}", s),
+            Statement::ShowEngineStatus(s) => write!(f, "{}", s),
+            Statement::ShowIndexSuggestions(s) => write!(f, "{}", s),
+            Statement::ShowRowVersions(s) => write!(f, "{}", s),
             Statement::Describe(s) => write!(f, "{}", s),
             Statement::Expression(s) => write!(f, "{}", s),
             Statement::Explain(s) => write!(f, "{}", s),
             Statement::Analyze(s) => write!(f, "{}", s),
+            Statement::Reindex(s) => write!(f, "{}", s),
+            Statement::Checkpoint(s) => write!(f, "{}", s),
+            Statement::AttachDatabase(s) => write!(f, "{}", s),
+            Statement::DetachDatabase(s) => write!(f, "{}", s),
+            Statement::DeclareCursor(s) => write!(f, "{}", s),
+            Statement::Fetch(s) => write!(f, "{}", s),
+            Statement::CloseCursor(s) => write!(f, "{}", s),
             Statement::Copy(s) => write!(f, "{}", s),
         }
     }
@@ -1581,6 +1753,10 @@ pub struct SelectStatement {
     pub offset: Option<Box<Expression>>,
     /// Set operations (UNION, INTERSECT, EXCEPT)
     pub set_operations: Vec<SetOperation>,
+    /// Optimizer hints from a `/*+ ... */` comment after SELECT
+    pub hints: Vec<QueryHint>,
+    /// FOR UPDATE / FOR SHARE row lock on the selected rows
+    pub lock: Option<RowLockMode>,
 }
 
 impl fmt::Display for SelectStatement {
@@ -1590,6 +1766,10 @@ impl fmt::Display for SelectStatement {
             result.push_str(&format!("{} ", with));
         }
         result.push_str("SELECT ");
+        if !self.hints.is_empty() {
+            let hints: Vec<String> = self.hints.iter().map(|h| h.to_string()).collect();
+            result.push_str(&format!("/*+ {} */ ", hints.join(" ")));
+        }
         if self.distinct {
             result.push_str("DISTINCT ");
         }
@@ -1625,6 +1805,9 @@ impl fmt::Display for SelectStatement {
         for set_op in &self.set_operations {
             result.push_str(&format!(" {} {}", set_op.operation, set_op.right));
         }
+        if let Some(lock) = self.lock {
+            result.push_str(&format!(" {}", lock));
+        }
         write!(f, "{}", result)
     }
 }
@@ -1692,7 +1875,11 @@ impl fmt::Display for InsertStatement {
 pub struct UpdateStatement {
     pub token: Token,
     pub table_name: TableName,
+    /// Optional table alias (e.g., UPDATE users AS u SET ...)
+    pub alias: Option<Identifier>,
     pub updates: HashMap<String, Expression>,
+    /// Source tables joined against the target (UPDATE ... FROM)
+    pub from: Option<Box<Expression>>,
     pub where_clause: Option<Box<Expression>>,
     /// RETURNING clause expressions
     pub returning: Vec<Expression>,
@@ -1700,13 +1887,20 @@ pub struct UpdateStatement {
 
 impl fmt::Display for UpdateStatement {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut result = format!("UPDATE {} SET ", self.table_name);
+        let mut result = format!("UPDATE {}", self.table_name);
+        if let Some(ref alias) = self.alias {
+            result.push_str(&format!(" AS {}", alias));
+        }
+        result.push_str(" SET ");
         let updates: Vec<String> = self
             .updates
             .iter()
             .map(|(col, val)| format!("{} = {}", col, val))
             .collect();
         result.push_str(&updates.join(", "));
+        if let Some(ref from) = self.from {
+            result.push_str(&format!(" FROM {}", from));
+        }
         if let Some(ref where_clause) = self.where_clause {
             result.push_str(&format!(" WHERE {}", where_clause));
         }
@@ -1813,8 +2007,16 @@ pub enum ColumnConstraint {
     Unique,
     AutoIncrement,
     Default(Expression),
-    Check(Expression),
+    /// CHECK (expression), optionally named with CONSTRAINT name
+    Check {
+        name: Option<String>,
+        expr: Expression,
+    },
     References(TableName, Option<Identifier>),
+    /// GENERATED ALWAYS AS (expression) [STORED], computed from the row on write
+    Generated(Expression),
+    /// COMPRESSED: values are stored compressed and decompressed on read
+    Compressed,
 }
 
 impl fmt::Display for ColumnConstraint {
@@ -1824,8 +2026,17 @@ impl fmt::Display for ColumnConstraint {
             ColumnConstraint::PrimaryKey => write!(f, "PRIMARY KEY"),
             ColumnConstraint::Unique => write!(f, "UNIQUE"),
             ColumnConstraint::AutoIncrement => write!(f, "AUTO_INCREMENT"),
+            ColumnConstraint::Compressed => write!(f, "COMPRESSED"),
             ColumnConstraint::Default(expr) => write!(f, "DEFAULT {}", expr),
-            ColumnConstraint::Check(expr) => write!(f, "CHECK ({})", expr),
+            ColumnConstraint::Generated(expr) => {
+                write!(f, "GENERATED ALWAYS AS ({}) STORED", expr)
+            }
+            ColumnConstraint::Check { name, expr } => {
+                if let Some(name) = name {
+                    write!(f, "CONSTRAINT {} ", name)?;
+                }
+                write!(f, "CHECK ({})", expr)
+            }
             ColumnConstraint::References(table, col) => {
                 if let Some(col) = col {
                     write!(f, "REFERENCES {}({})", table, col)
@@ -1972,6 +2183,8 @@ pub struct DropTableStatement {
     pub token: Token,
     pub table_name: TableName,
     pub if_exists: bool,
+    /// CASCADE drops dependent views and foreign keys; RESTRICT (the default) refuses
+    pub cascade: bool,
 }
 
 impl fmt::Display for DropTableStatement {
@@ -1981,6 +2194,9 @@ impl fmt::Display for DropTableStatement {
             result.push_str("IF EXISTS ");
         }
         result.push_str(&self.table_name.to_string());
+        if self.cascade {
+            result.push_str(" CASCADE");
+        }
         write!(f, "{}", result)
     }
 }
@@ -2007,6 +2223,8 @@ pub enum AlterTableOperation {
     ModifyColumn,
     RenameTable,
     AddConstraint,
+    /// AUTO_INCREMENT = n, or ALTER COLUMN c RESTART WITH n
+    SetAutoIncrement,
 }
 
 /// ALTER TABLE statement
@@ -2021,6 +2239,8 @@ pub struct AlterTableStatement {
     pub new_column_name: Option<Identifier>,
     pub new_table_name: Option<Identifier>,
     pub constraint: Option<TableConstraint>,
+    /// Next id to generate for SetAutoIncrement
+    pub auto_increment: Option<i64>,
 }
 
 impl fmt::Display for AlterTableStatement {
@@ -2057,6 +2277,15 @@ impl fmt::Display for AlterTableStatement {
                     result.push_str(&format!("ADD {}", constraint));
                 }
             }
+            AlterTableOperation::SetAutoIncrement => {
+                if let Some(value) = self.auto_increment {
+                    match self.column_name {
+                        Some(ref name) => result
+                            .push_str(&format!("ALTER COLUMN {} RESTART WITH {}", name, value)),
+                        None => result.push_str(&format!("AUTO_INCREMENT = {}", value)),
+                    }
+                }
+            }
         }
         write!(f, "{}", result)
     }
@@ -2094,6 +2323,8 @@ pub struct CreateIndexStatement {
     pub if_not_exists: bool,
     /// Optional index type from USING clause (None = auto-select based on column type)
     pub index_method: Option<IndexMethod>,
+    /// NULLS NOT DISTINCT: a unique index allows only one NULL key
+    pub nulls_not_distinct: bool,
 }
 
 impl fmt::Display for CreateIndexStatement {
@@ -2113,6 +2344,9 @@ impl fmt::Display for CreateIndexStatement {
         if let Some(method) = &self.index_method {
             result.push_str(&format!(" USING {}", method));
         }
+        if self.nulls_not_distinct {
+            result.push_str(" NULLS NOT DISTINCT");
+        }
         write!(f, "{}", result)
     }
 }
@@ -2140,6 +2374,90 @@ impl fmt::Display for DropIndexStatement {
     }
 }
 
+/// REINDEX statement
+///
+/// `REINDEX TABLE table_name` rebuilds every index of a table,
+/// `REINDEX INDEX index_name [ON table_name]` rebuilds a single index.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReindexStatement {
+    pub token: Token,
+    /// Index to rebuild (None = all indexes of `table_name`)
+    pub index_name: Option<Identifier>,
+    pub table_name: Option<Identifier>,
+}
+
+impl fmt::Display for ReindexStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.index_name, &self.table_name) {
+            (Some(index), Some(table)) => write!(f, "REINDEX INDEX {} ON {}", index, table),
+            (Some(index), None) => write!(f, "REINDEX INDEX {}", index),
+            (None, Some(table)) => write!(f, "REINDEX TABLE {}", table),
+            (None, None) => write!(f, "REINDEX"),
+        }
+    }
+}
+
+/// DECLARE CURSOR statement
+///
+/// `DECLARE name [NO SCROLL] CURSOR [WITH HOLD | WITHOUT HOLD] FOR query`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeclareCursorStatement {
+    pub token: Token,
+    pub name: Identifier,
+    /// Keep the cursor open after the declaring transaction commits
+    pub with_hold: bool,
+    pub query: Box<SelectStatement>,
+}
+
+impl fmt::Display for DeclareCursorStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DECLARE {} CURSOR", self.name)?;
+        if self.with_hold {
+            write!(f, " WITH HOLD")?;
+        }
+        write!(f, " FOR {}", self.query)
+    }
+}
+
+/// FETCH statement
+///
+/// `FETCH [NEXT | count | ALL | FORWARD [count | ALL]] [FROM | IN] name`
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchStatement {
+    pub token: Token,
+    pub name: Identifier,
+    /// Number of rows to fetch (None = ALL)
+    pub count: Option<u64>,
+}
+
+impl fmt::Display for FetchStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.count {
+            Some(count) => write!(f, "FETCH {} FROM {}", count, self.name),
+            None => write!(f, "FETCH ALL FROM {}", self.name),
+        }
+    }
+}
+
+/// CLOSE statement
+///
+/// `CLOSE name | CLOSE ALL`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CloseCursorStatement {
+    pub token: Token,
+    /// Cursor to close (None = ALL)
+    pub name: Option<Identifier>,
+}
+
+impl fmt::Display for CloseCursorStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "CLOSE {}", name),
+            None => write!(f, "CLOSE ALL"),
+        }
+    }
+}
+
 /// CREATE COLUMNAR INDEX statement
 #[derive(Debug, Clone, PartialEq)]
 pub struct CreateColumnarIndexStatement {
@@ -2192,11 +2510,16 @@ pub struct CreateViewStatement {
     pub view_name: TableName,
     pub query: Box<SelectStatement>,
     pub if_not_exists: bool,
+    pub or_replace: bool,
 }
 
 impl fmt::Display for CreateViewStatement {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut result = String::from("CREATE VIEW ");
+        let mut result = String::from("CREATE ");
+        if self.or_replace {
+            result.push_str("OR REPLACE ");
+        }
+        result.push_str("VIEW ");
         if self.if_not_exists {
             result.push_str("IF NOT EXISTS ");
         }
@@ -2211,6 +2534,8 @@ pub struct DropViewStatement {
     pub token: Token,
     pub view_name: Identifier,
     pub if_exists: bool,
+    /// CASCADE drops dependent views; RESTRICT (the default) refuses
+    pub cascade: bool,
 }
 
 /// CREATE FUNCTION statement
@@ -2223,6 +2548,7 @@ pub struct CreateFunctionStatement {
     pub language: String,
     pub body: String,
     pub if_not_exists: bool,
+    pub or_replace: bool,
 }
 
 /// CREATE PROCEDURE statement
@@ -2279,7 +2605,11 @@ pub struct ProcedureParameter {
 
 impl fmt::Display for CreateFunctionStatement {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut result = String::from("CREATE FUNCTION ");
+        let mut result = String::from("CREATE ");
+        if self.or_replace {
+            result.push_str("OR REPLACE ");
+        }
+        result.push_str("FUNCTION ");
         if self.if_not_exists {
             result.push_str("IF NOT EXISTS ");
         }
@@ -2308,6 +2638,8 @@ pub struct DropFunctionStatement {
     pub token: Token,
     pub function_name: FunctionName,
     pub if_exists: bool,
+    /// Argument types selecting one overload, as in `DROP FUNCTION f(INTEGER)`
+    pub parameter_types: Option<Vec<String>>,
 }
 
 impl fmt::Display for DropFunctionStatement {
@@ -2317,6 +2649,9 @@ impl fmt::Display for DropFunctionStatement {
             result.push_str("IF EXISTS ");
         }
         result.push_str(&self.function_name.to_string());
+        if let Some(ref types) = self.parameter_types {
+            result.push_str(&format!("({})", types.join(", ")));
+        }
         write!(f, "{}", result)
     }
 }
@@ -2347,6 +2682,9 @@ impl fmt::Display for DropViewStatement {
             result.push_str("IF EXISTS ");
         }
         result.push_str(&self.view_name.to_string());
+        if self.cascade {
+            result.push_str(" CASCADE");
+        }
         write!(f, "{}", result)
     }
 }
@@ -2584,6 +2922,19 @@ impl fmt::Display for SavepointStatement {
     }
 }
 
+/// RELEASE SAVEPOINT statement
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReleaseSavepointStatement {
+    pub token: Token,
+    pub savepoint_name: Identifier,
+}
+
+impl fmt::Display for ReleaseSavepointStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RELEASE SAVEPOINT {}", self.savepoint_name)
+    }
+}
+
 /// SET statement
 #[derive(Debug, Clone, PartialEq)]
 pub struct SetStatement {
@@ -2594,7 +2945,31 @@ pub struct SetStatement {
 
 impl fmt::Display for SetStatement {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "SET {} = {}", self.name, self.value)
+        match &self.value {
+            Expression::ExpressionList(list) => {
+                let items: Vec<String> = list.expressions.iter().map(|e| e.to_string()).collect();
+                write!(f, "SET {} = {}", self.name, items.join(", "))
+            }
+            value => write!(f, "SET {} = {}", self.name, value),
+        }
+    }
+}
+
+/// SET TRANSACTION ISOLATION LEVEL statement
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetTransactionStatement {
+    pub token: Token,
+    /// Level as written, e.g. "SNAPSHOT" or "READ COMMITTED"
+    pub isolation_level: String,
+}
+
+impl fmt::Display for SetTransactionStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SET TRANSACTION ISOLATION LEVEL {}",
+            self.isolation_level
+        )
     }
 }
 
@@ -2657,6 +3032,55 @@ impl fmt::Display for ShowFunctionsStatement {
     }
 }
 
+/// SHOW PROCEDURES statement
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShowProceduresStatement {
+    pub token: Token,
+}
+
+impl fmt::Display for ShowProceduresStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SHOW PROCEDURES")
+    }
+}
+
+/// SHOW SEQUENCES statement
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShowSequencesStatement {
+    pub token: Token,
+}
+
+impl fmt::Display for ShowSequencesStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SHOW SEQUENCES")
+    }
+}
+
+/// SHOW TRANSACTION ISOLATION LEVEL statement
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShowIsolationLevelStatement {
+    pub token: Token,
+}
+
+impl fmt::Display for ShowIsolationLevelStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SHOW TRANSACTION ISOLATION LEVEL")
+    }
+}
+
+/// SHOW <setting> statement, for the settings SET changes per connection
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShowSettingStatement {
+    pub token: Token,
+    pub name: Identifier,
+}
+
+impl fmt::Display for ShowSettingStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SHOW {}", self.name)
+    }
+}
+
 /// SHOW CREATE TABLE statement
 #[derive(Debug, Clone, PartialEq)]
 pub struct ShowCreateTableStatement {
@@ -2696,6 +3120,108 @@ impl fmt::Display for ShowIndexesStatement {
     }
 }
 
+/// SHOW WARNINGS statement
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShowWarningsStatement {
+    pub token: Token,
+}
+
+impl fmt::Display for ShowWarningsStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SHOW WARNINGS")
+    }
+}
+
+/// SHOW ENGINE STATUS statement
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShowEngineStatusStatement {
+    pub token: Token,
+}
+
+impl fmt::Display for ShowEngineStatusStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SHOW ENGINE STATUS")
+    }
+}
+
+/// SHOW INDEX SUGGESTIONS statement
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShowIndexSuggestionsStatement {
+    pub token: Token,
+}
+
+impl fmt::Display for ShowIndexSuggestionsStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SHOW INDEX SUGGESTIONS")
+    }
+}
+
+/// SHOW ROW VERSIONS FROM table [WHERE condition] statement
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShowRowVersionsStatement {
+    pub token: Token,
+    pub table_name: Identifier,
+    pub where_clause: Option<Box<Expression>>,
+}
+
+impl fmt::Display for ShowRowVersionsStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SHOW ROW VERSIONS FROM {}", self.table_name)?;
+        if let Some(where_clause) = &self.where_clause {
+            write!(f, " WHERE {}", where_clause)?;
+        }
+        Ok(())
+    }
+}
+
+/// CHECKPOINT statement - folds the WAL into a snapshot
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckpointStatement {
+    pub token: Token,
+}
+
+impl fmt::Display for CheckpointStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CHECKPOINT")
+    }
+}
+
+/// ATTACH DATABASE statement - makes another database readable under an alias
+///
+/// `ATTACH [DATABASE] 'dsn' AS alias`
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttachDatabaseStatement {
+    pub token: Token,
+    pub dsn: String,
+    pub alias: Identifier,
+}
+
+impl fmt::Display for AttachDatabaseStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ATTACH DATABASE '{}' AS {}",
+            self.dsn.replace('\'', "''"),
+            self.alias
+        )
+    }
+}
+
+/// DETACH DATABASE statement
+///
+/// `DETACH [DATABASE] alias`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetachDatabaseStatement {
+    pub token: Token,
+    pub alias: Identifier,
+}
+
+impl fmt::Display for DetachDatabaseStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DETACH DATABASE {}", self.alias)
+    }
+}
+
 /// DESCRIBE statement - shows table structure
 #[derive(Debug, Clone, PartialEq)]
 pub struct DescribeStatement {
@@ -2892,6 +3418,7 @@ mod tests {
                 )),
                 alias: None,
                 as_of: None,
+                index_hint: None,
             }))),
             where_clause: None,
             group_by: GroupByClause::default(),
@@ -2901,6 +3428,8 @@ mod tests {
             limit: None,
             offset: None,
             set_operations: vec![],
+            hints: vec![],
+            lock: None,
         };
         assert_eq!(stmt.to_string(), "SELECT * FROM users");
     }
@@ -3096,13 +3625,14 @@ impl fmt::Display for DropTriggerStatement {
 }
 
 // ============================================================================
-// COPY FROM
+// COPY FROM / COPY TO
 // ============================================================================
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum CopyFormat {
     Csv,
     Json,
+    Parquet,
 }
 
 impl fmt::Display for CopyFormat {
@@ -3110,15 +3640,24 @@ impl fmt::Display for CopyFormat {
         match self {
             CopyFormat::Csv => write!(f, "CSV"),
             CopyFormat::Json => write!(f, "JSON"),
+            CopyFormat::Parquet => write!(f, "PARQUET"),
         }
     }
 }
 
+/// Whether COPY loads a file into the table or writes the table to a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyDirection {
+    From,
+    To,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct CopyStatement {
     pub token: Token,
     pub table_name: TableName,
     pub columns: Vec<Identifier>,
+    pub direction: CopyDirection,
     pub file_path: String,
     pub format: CopyFormat,
     pub header: bool,
@@ -3139,7 +3678,10 @@ impl fmt::Display for CopyStatement {
             }
             write!(f, ")")?;
         }
-        write!(f, " FROM '{}'", self.file_path)?;
+        match self.direction {
+            CopyDirection::From => write!(f, " FROM '{}'", self.file_path)?,
+            CopyDirection::To => write!(f, " TO '{}'", self.file_path)?,
+        }
 
         let mut options = Vec::new();
         options.push(format!("FORMAT {}", self.format));
@@ -3170,6 +3712,8 @@ pub struct FunctionTableSource {
     pub function: Identifier,
     /// Function arguments
     pub arguments: Vec<Expression>,
+    /// WITH ORDINALITY: append a 1-based row number column
+    pub with_ordinality: bool,
     /// Optional alias for the derived table
     pub alias: Option<Identifier>,
     /// Optional column aliases (e.g., gs(value))
@@ -3182,6 +3726,9 @@ impl fmt::Display for FunctionTableSource {
         let args: Vec<String> = self.arguments.iter().map(|a| a.to_string()).collect();
         result.push_str(&args.join(", "));
         result.push(')');
+        if self.with_ordinality {
+            result.push_str(" WITH ORDINALITY");
+        }
 
         if let Some(ref alias) = self.alias {
             result.push_str(&format!(" AS {}", alias));
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -55,6 +56,7 @@ impl Parser {
                         | "GLOB"
                         | "REGEXP"
                         | "RLIKE"
+                        | "SIMILAR"
                         | "IS"
                         | "IN"
                         | "BETWEEN"
@@ -153,36 +155,7 @@ impl Parser {
 
     /// Parse a string literal
     fn parse_string_literal(&self) -> Expression {
-        let literal = &self.cur_token.literal;
-        // Remove surrounding quotes
-        let value = if literal.len() >= 2 {
-            let inner = &literal[1..literal.len() - 1];
-            // Handle escape sequences
-            // Note: We don't convert \" to " because:
-            // 1. In SQL, double quotes don't need escaping inside single-quoted strings
-            // 2. Converting \" to " breaks JSON content like {"key":"value with \"quotes\""}
-            inner
-                .replace("\\n", "\n")
-                .replace("\\t", "\t")
-                .replace("\\r", "\r")
-                .replace("\\'", "'")
-                .replace("\\\\", "\\")
-        } else {
-            literal.clone()
-        };
-
-        // Check for JSON type hint
-        let type_hint = if value.starts_with('{') && value.ends_with('}') {
-            Some("JSON".to_string())
-        } else {
-            None
-        };
-
-        Expression::StringLiteral(StringLiteral {
-            token: self.cur_token.clone(),
-            value,
-            type_hint,
-        })
+        Expression::StringLiteral(StringLiteral::from_token(self.cur_token.clone()))
     }
 
     /// Parse a parameter ($1, ?, :name)
@@ -206,6 +179,8 @@ impl Parser {
             return None;
         };
 
+        self.record_parameter_index(index);
+
         Some(Expression::Parameter(Parameter {
             token: self.cur_token.clone(),
             name,
@@ -457,6 +432,15 @@ impl Parser {
         let operator = self.cur_token.literal.clone();
         let precedence = self.cur_precedence();
 
+        // POSIX regex operators share the LIKE expression node
+        if matches!(operator.as_str(), "~" | "~*" | "!~" | "!~*") {
+            return self.parse_like_expression(left, operator, false);
+        }
+
+        if operator == "::" {
+            return self.parse_double_colon_cast(left);
+        }
+
         self.next_token();
 
         // Check for ALL/ANY/SOME subquery comparison
@@ -507,16 +491,14 @@ impl Parser {
         // Move past (
         self.next_token();
 
-        // Parse subquery
-        if !self.cur_token_is_keyword("SELECT") {
-            self.add_error(format!(
-                "expected SELECT in {} subquery at {}",
-                all_any_type, self.cur_token.position
-            ));
-            return None;
-        }
-
-        let subquery = self.parse_select_statement()?;
+        // An array operand, e.g. `id = ANY($1)`, is read as the subquery
+        // `SELECT * FROM UNNEST(<array>)`
+        let subquery = if self.cur_token_is_keyword("SELECT") {
+            self.parse_select_statement()?
+        } else {
+            let array = self.parse_expression(Precedence::Lowest)?;
+            Self::unnest_subquery(token.clone(), array)
+        };
 
         // Expect closing parenthesis
         if !self.expect_peek(TokenType::Punctuator) || self.cur_token.literal != ")" {
@@ -536,6 +518,41 @@ impl Parser {
         }))
     }
 
+    /// Build `SELECT * FROM UNNEST(array)`
+    fn unnest_subquery(token: Token, array: Expression) -> SelectStatement {
+        use super::ast::{FunctionTableSource, GroupByClause, StarExpression};
+
+        let function = Identifier::new(token.clone(), "UNNEST".to_string());
+        SelectStatement {
+            token: token.clone(),
+            distinct: false,
+            columns: vec![Expression::Star(StarExpression {
+                token: token.clone(),
+            })],
+            with: None,
+            table_expr: Some(Box::new(Expression::FunctionTableSource(
+                FunctionTableSource {
+                    token,
+                    function,
+                    arguments: vec![array],
+                    with_ordinality: false,
+                    alias: None,
+                    column_aliases: Vec::new(),
+                },
+            ))),
+            where_clause: None,
+            group_by: GroupByClause::default(),
+            having: None,
+            window_defs: Vec::new(),
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+            set_operations: Vec::new(),
+            hints: Vec::new(),
+            lock: None,
+        }
+    }
+
     /// Parse a keyword infix expression (AND, OR, LIKE, IS, IN, BETWEEN, AS)
     fn parse_keyword_infix(&mut self, left: Expression) -> Option<Expression> {
         let keyword = self.cur_token.literal.to_uppercase();
@@ -558,12 +575,14 @@ impl Parser {
             "LIKE" | "ILIKE" | "GLOB" | "REGEXP" | "RLIKE" => {
                 self.parse_like_expression(left, keyword.clone(), false)
             }
+            "SIMILAR" => self.parse_similar_to_expression(left, false),
             "IS" => self.parse_is_expression(left),
             "IN" => self.parse_in_expression(left, false),
             "BETWEEN" => self.parse_between_expression(left, false),
             "AS" => self.parse_alias_expression(left),
             "NOT" => {
-                // Handle NOT IN, NOT BETWEEN, NOT LIKE, NOT ILIKE, NOT GLOB, NOT REGEXP, NOT RLIKE
+                // Handle NOT IN, NOT BETWEEN, NOT LIKE, NOT ILIKE, NOT GLOB, NOT REGEXP, NOT RLIKE,
+                // NOT SIMILAR TO
                 if self.peek_token_is_keyword("IN") {
                     self.next_token(); // consume IN
                     self.parse_in_expression(left, true)
@@ -585,6 +604,9 @@ impl Parser {
                 } else if self.peek_token_is_keyword("RLIKE") {
                     self.next_token(); // consume RLIKE
                     self.parse_like_expression(left, "RLIKE".to_string(), true)
+                } else if self.peek_token_is_keyword("SIMILAR") {
+                    self.next_token(); // consume SIMILAR
+                    self.parse_similar_to_expression(left, true)
                 } else {
                     let token = self.cur_token.clone();
                     self.next_token();
@@ -763,6 +785,11 @@ impl Parser {
 
         self.next_token();
 
+        // TRIM(LEADING | TRAILING | BOTH [characters] FROM string)
+        if call.function == "TRIM" && self.cur_token_is_trim_direction() {
+            return self.parse_trim_direction(call);
+        }
+
         // For POSITION(x IN y) syntax, parse first argument with higher precedence
         // to prevent IN from being consumed as an infix operator
         let first_arg_precedence = if call.function == "POSITION" {
@@ -794,6 +821,15 @@ impl Parser {
             }
         }
 
+        // TRIM(characters FROM string) is TRIM(string, characters)
+        if call.function == "TRIM" && self.peek_token_is_keyword("FROM") {
+            self.next_token(); // consume FROM
+            self.next_token(); // move to string expression
+
+            let source = self.parse_expression(Precedence::Lowest)?;
+            call.arguments.insert(0, source);
+        }
+
         // Parse additional arguments
         while self.peek_token_is_punctuator(",") {
             self.next_token(); // consume comma
@@ -871,6 +907,79 @@ impl Parser {
         Some(Box::new(condition))
     }
 
+    /// Check if the current token is a TRIM direction (LEADING, TRAILING or BOTH)
+    ///
+    /// The words are not reserved, so a following operator, comma or ')'
+    /// means the token is a column reference instead.
+    fn cur_token_is_trim_direction(&self) -> bool {
+        let is_word = matches!(
+            self.cur_token.token_type,
+            TokenType::Identifier | TokenType::Keyword
+        ) && ["LEADING", "TRAILING", "BOTH"]
+            .iter()
+            .any(|w| self.cur_token.literal.eq_ignore_ascii_case(w));
+        if !is_word {
+            return false;
+        }
+        match self.peek_token.token_type {
+            TokenType::Punctuator | TokenType::Operator => false,
+            TokenType::Keyword => {
+                let peek = self.peek_token.literal.to_uppercase();
+                !matches!(
+                    peek.as_str(),
+                    "AND" | "OR" | "IS" | "IN" | "NOT" | "LIKE" | "ILIKE" | "BETWEEN" | "AS"
+                )
+            }
+            _ => true,
+        }
+    }
+
+    /// Parse the rest of TRIM(LEADING | TRAILING | BOTH [characters] FROM string)
+    ///
+    /// The call is rewritten to LTRIM, RTRIM or TRIM with the string first and
+    /// the optional character set second.
+    fn parse_trim_direction(&mut self, mut call: FunctionCall) -> Option<Expression> {
+        call.function = match self.cur_token.literal.to_uppercase().as_str() {
+            "LEADING" => "LTRIM".to_string(),
+            "TRAILING" => "RTRIM".to_string(),
+            _ => "TRIM".to_string(),
+        };
+
+        let characters = if self.peek_token_is_keyword("FROM") {
+            None
+        } else {
+            self.next_token();
+            let chars = self.parse_expression(Precedence::Lowest)?;
+            if !self.peek_token_is_keyword("FROM") {
+                self.add_error(format!(
+                    "expected FROM in TRIM at {}",
+                    self.peek_token.position
+                ));
+                return None;
+            }
+            Some(chars)
+        };
+
+        self.next_token(); // consume FROM
+        self.next_token(); // move to string expression
+        let Some(source) = self.parse_expression(Precedence::Lowest) else {
+            self.add_error(format!(
+                "expected expression after FROM in TRIM at {}",
+                self.cur_token.position
+            ));
+            return None;
+        };
+        call.arguments.push(source);
+        call.arguments.extend(characters);
+
+        if !self.expect_peek(TokenType::Punctuator) || self.cur_token.literal != ")" {
+            self.add_error(format!("expected ')' at {}", self.cur_token.position));
+            return None;
+        }
+
+        Some(Expression::FunctionCall(call))
+    }
+
     /// Parse ORDER BY inside a function call
     fn parse_function_order_by(&mut self, call: &mut FunctionCall) {
         self.next_token(); // consume ORDER
@@ -1251,6 +1360,18 @@ impl Parser {
     fn parse_between_expression(&mut self, left: Expression, not: bool) -> Option<Expression> {
         let token = self.cur_token.clone();
 
+        // BETWEEN [SYMMETRIC | ASYMMETRIC]; ASYMMETRIC is the default
+        let mut symmetric = false;
+        for (word, is_symmetric) in [("SYMMETRIC", true), ("ASYMMETRIC", false)] {
+            if self.peek_token.token_type == TokenType::Identifier
+                && self.peek_token.literal.eq_ignore_ascii_case(word)
+            {
+                self.next_token();
+                symmetric = is_symmetric;
+                break;
+            }
+        }
+
         self.next_token();
         let lower = self.parse_expression(Precedence::Equals)?;
 
@@ -1267,6 +1388,7 @@ impl Parser {
             lower: Box::new(lower),
             upper: Box::new(upper),
             not,
+            symmetric,
         }))
     }
 
@@ -1278,8 +1400,20 @@ impl Parser {
         not: bool,
     ) -> Option<Expression> {
         let token = self.cur_token.clone();
-        let operator = if not { format!("NOT {}", op) } else { op };
         let precedence = self.cur_precedence();
+        self.parse_like_pattern(token, left, op, not, precedence)
+    }
+
+    /// Parse the pattern and optional ESCAPE clause of a LIKE-style expression
+    fn parse_like_pattern(
+        &mut self,
+        token: Token,
+        left: Expression,
+        op: String,
+        not: bool,
+        precedence: Precedence,
+    ) -> Option<Expression> {
+        let operator = if not { format!("NOT {}", op) } else { op };
 
         self.next_token();
         let pattern = self.parse_expression(precedence)?;
@@ -1288,7 +1422,7 @@ impl Parser {
         let escape = if self.peek_token_is_keyword("ESCAPE") {
             self.next_token(); // consume ESCAPE
             self.next_token(); // move to escape character
-            Some(Box::new(self.parse_expression(Precedence::Lowest)?))
+            Some(Box::new(self.parse_expression(precedence)?))
         } else {
             None
         };
@@ -1302,6 +1436,16 @@ impl Parser {
         }))
     }
 
+    /// Parse `[NOT] SIMILAR TO pattern [ESCAPE char]`, with SIMILAR as the current token
+    fn parse_similar_to_expression(&mut self, left: Expression, not: bool) -> Option<Expression> {
+        let token = self.cur_token.clone();
+        let precedence = self.cur_precedence();
+        if !self.expect_keyword("TO") {
+            return None;
+        }
+        self.parse_like_pattern(token, left, "SIMILAR TO".to_string(), not, precedence)
+    }
+
     /// Parse an alias expression (expr AS alias)
     fn parse_alias_expression(&mut self, left: Expression) -> Option<Expression> {
         let token = self.cur_token.clone();
@@ -1428,6 +1572,27 @@ impl Parser {
         }))
     }
 
+    /// Parse a PostgreSQL-style cast: expr::type
+    fn parse_double_colon_cast(&mut self, left: Expression) -> Option<Expression> {
+        let token = self.cur_token.clone();
+
+        if !self.peek_token_is(TokenType::Keyword) && !self.peek_token_is(TokenType::Identifier) {
+            self.add_error(format!(
+                "expected type name after :: at {}",
+                self.peek_token.position
+            ));
+            return None;
+        }
+        self.next_token();
+        let type_name = self.cur_token.literal.clone();
+
+        Some(Expression::Cast(CastExpression {
+            token,
+            expr: Box::new(left),
+            type_name,
+        }))
+    }
+
     /// Parse an EXTRACT expression: EXTRACT(field FROM source)
     /// Converts to equivalent function call: YEAR(source), MONTH(source), etc.
     fn parse_extract_expression(&mut self) -> Option<Expression> {
@@ -1656,9 +1821,8 @@ impl Parser {
                 literal.clone()
             };
 
-            // Parse the interval value
-            let parts: Vec<&str> = value.split_whitespace().collect();
-            if parts.len() < 2 {
+            // Validate the full interval ('2 hours', '1 day 2:30:00', ...)
+            if crate::core::parse_interval(&value).is_err() {
                 self.add_error(format!(
                     "invalid interval format: {} at {}",
                     value, self.cur_token.position
@@ -1666,19 +1830,16 @@ impl Parser {
                 return None;
             }
 
-            let quantity = match parts[0].parse::<i64>() {
-                Ok(q) => q,
-                Err(_) => {
-                    self.add_error(format!(
-                        "invalid interval quantity: {} at {}",
-                        parts[0], self.cur_token.position
-                    ));
-                    return None;
-                }
+            // Quantity and unit are only meaningful for the simple '<n> <unit>' form
+            let parts: Vec<&str> = value.split_whitespace().collect();
+            let (quantity, unit) = match parts.as_slice() {
+                [quantity, unit] => (
+                    quantity.parse::<i64>().unwrap_or(0),
+                    unit.to_lowercase().trim_end_matches('s').to_string(),
+                ),
+                _ => (0, String::new()),
             };
 
-            let unit = parts[1].to_lowercase().trim_end_matches('s').to_string();
-
             Some(Expression::IntervalLiteral(IntervalLiteral {
                 token,
                 value,
@@ -2027,7 +2188,64 @@ impl Parser {
 
     /// Parse an ORDER BY expression
     pub fn parse_order_by_expression(&mut self) -> Option<OrderByExpression> {
-        let expression = self.parse_expression(Precedence::Lowest)?;
+        let mut expression = self.parse_expression(Precedence::Lowest)?;
+
+        // `expr COLLATE name` sorts by COLLATE(expr, 'name')
+        if self.peek_token_is(TokenType::Identifier)
+            && self.peek_token.literal.eq_ignore_ascii_case("COLLATE")
+        {
+            self.next_token();
+            let token = self.cur_token.clone();
+            if matches!(expression, Expression::IntegerLiteral(_)) {
+                self.add_error(format!(
+                    "COLLATE cannot be applied to an ORDER BY position at {}; use the column name",
+                    token.position
+                ));
+                return None;
+            }
+            self.next_token();
+            let mut collation = match self.cur_token.token_type {
+                TokenType::Identifier | TokenType::Keyword => StringLiteral {
+                    token: self.cur_token.clone(),
+                    value: self.cur_token.literal.clone(),
+                    type_hint: None,
+                },
+                TokenType::String => StringLiteral::from_token(self.cur_token.clone()),
+                _ => {
+                    self.add_error(format!(
+                        "expected collation name after COLLATE at {}",
+                        self.cur_token.position
+                    ));
+                    return None;
+                }
+            };
+            collation.value = collation.value.to_uppercase();
+            let collations = [
+                "BINARY",
+                "NOCASE",
+                "CASE_INSENSITIVE",
+                "NOACCENT",
+                "ACCENT_INSENSITIVE",
+                "NUMERIC",
+            ];
+            if !collations.contains(&collation.value.as_str()) {
+                self.add_error(format!(
+                    "unsupported collation '{}' at {}. Valid collations: {}",
+                    collation.value,
+                    self.cur_token.position,
+                    collations.join(", ")
+                ));
+                return None;
+            }
+            expression = Expression::FunctionCall(FunctionCall {
+                token,
+                function: "COLLATE".to_string(),
+                arguments: vec![expression, Expression::StringLiteral(collation)],
+                is_distinct: false,
+                order_by: Vec::new(),
+                filter: None,
+            });
+        }
 
         let mut ascending = true;
         if self.peek_token_is_keyword("ASC") {
@@ -2092,6 +2310,20 @@ mod tests {
         parser.parse_expression(Precedence::Lowest)
     }
 
+    #[test]
+    fn test_parse_similar_to_and_posix_regex() {
+        let expr = parse_expr("name NOT SIMILAR TO 'a%' ESCAPE '#' AND id > 1").unwrap();
+        assert_eq!(
+            expr.to_string(),
+            "(name NOT SIMILAR TO 'a%' ESCAPE '#' AND (id > 1))"
+        );
+
+        for op in ["~", "~*", "!~", "!~*"] {
+            let expr = parse_expr(&format!("name {} '^a' OR x", op)).unwrap();
+            assert_eq!(expr.to_string(), format!("(name {} '^a' OR x)", op));
+        }
+    }
+
     #[test]
     fn test_parse_identifier() {
         let expr = parse_expr("users").unwrap();
@@ -2275,6 +2507,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_trim_standard_syntax() {
+        for (input, function, args) in [
+            ("TRIM(LEADING '0' FROM s)", "LTRIM", 2),
+            ("TRIM(TRAILING FROM s)", "RTRIM", 1),
+            ("TRIM(BOTH ' ' FROM s)", "TRIM", 2),
+            ("TRIM('x' FROM s)", "TRIM", 2),
+            ("TRIM(s)", "TRIM", 1),
+        ] {
+            match parse_expr(input).unwrap() {
+                Expression::FunctionCall(fc) => {
+                    assert_eq!(fc.function, function, "{}", input);
+                    assert_eq!(fc.arguments.len(), args, "{}", input);
+                    match &fc.arguments[0] {
+                        Expression::Identifier(id) => assert_eq!(id.value, "s"),
+                        other => panic!("Expected source first, got: {:?}", other),
+                    }
+                }
+                _ => panic!("expected FunctionCall"),
+            }
+        }
+
+        // Direction words are not reserved and still work as column names
+        match parse_expr("TRIM(both)").unwrap() {
+            Expression::FunctionCall(fc) => {
+                assert_eq!(fc.function, "TRIM");
+                assert_eq!(fc.arguments.len(), 1);
+            }
+            _ => panic!("expected FunctionCall"),
+        }
+        assert!(parse_expr("TRIM(LEADING 'x' s)").is_none());
+    }
+
     #[test]
     fn test_parse_is_null() {
         let expr = parse_expr("x IS NULL").unwrap();
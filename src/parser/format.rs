@@ -0,0 +1,171 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SQL pretty-printer
+//!
+//! [`format_sql`] parses SQL and re-emits it through the AST's `Display`
+//! impls, so keywords, parenthesization and spacing come out canonical.
+//! The single-line `Display` output is then laid out with every query
+//! clause on its own line and subqueries indented inside their parentheses.
+//! Only whitespace between tokens is changed, so formatting is idempotent.
+
+use super::lexer::Lexer;
+use super::parse_sql;
+use super::token::{Token, TokenType};
+
+/// Indentation for one level of subquery nesting
+const INDENT: &str = "    ";
+
+/// Keywords that start a new line when they open a query clause
+const CLAUSE_KEYWORDS: &[&str] = &[
+    "SELECT",
+    "FROM",
+    "WHERE",
+    "GROUP",
+    "HAVING",
+    "WINDOW",
+    "ORDER",
+    "LIMIT",
+    "OFFSET",
+    "UNION",
+    "INTERSECT",
+    "EXCEPT",
+    "VALUES",
+    "SET",
+    "RETURNING",
+];
+
+/// Keywords that may precede JOIN
+const JOIN_MODIFIERS: &[&str] = &[
+    "INNER", "LEFT", "RIGHT", "FULL", "CROSS", "NATURAL", "OUTER",
+];
+
+/// Parse SQL and re-emit it as canonical, indented SQL
+///
+/// Multiple statements are separated by `;` and a newline. Input that does
+/// not parse is returned unchanged.
+///
+/// # Example
+///
+/// ```
+/// let sql = oxibase::format_sql("select a from t where a > 1");
+/// assert_eq!(sql, "SELECT a\nFROM t\nWHERE (a > 1)");
+/// ```
+pub fn format_sql(sql: &str) -> String {
+    match parse_sql(sql) {
+        Ok(statements) => statements
+            .iter()
+            .map(|stmt| layout(&stmt.to_string()))
+            .collect::<Vec<_>>()
+            .join(";\n"),
+        Err(_) => sql.to_string(),
+    }
+}
+
+/// Lay out single-line SQL, one clause per line
+fn layout(sql: &str) -> String {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut lexer = Lexer::new(sql);
+    let mut tokens = Vec::new();
+    loop {
+        let token = lexer.next_token();
+        if token.token_type == TokenType::Eof {
+            break;
+        }
+        tokens.push(token);
+    }
+
+    let mut out = String::new();
+    // One entry per open parenthesis: true if it encloses a subquery
+    let mut parens: Vec<bool> = Vec::new();
+    let mut depth = 0;
+    let mut line_start = true;
+    let mut prev_end = 0;
+
+    for (i, token) in tokens.iter().enumerate() {
+        let start = token.position.offset;
+        let next_start = tokens.get(i + 1).map_or(chars.len(), |t| t.position.offset);
+        let text: String = chars[start..next_start].iter().collect();
+        let text = text.trim_end();
+
+        let at_clause_level = parens.last().is_none_or(|&subquery| subquery);
+        let prev = i.checked_sub(1).map(|j| &tokens[j]);
+        let next = tokens.get(i + 1);
+
+        if text == ")" && parens.pop() == Some(true) {
+            depth -= 1;
+            newline(&mut out, depth);
+        } else if !line_start && at_clause_level && starts_clause(prev, token, next) {
+            newline(&mut out, depth);
+        } else if !line_start {
+            out.extend(&chars[prev_end..start]);
+        }
+        out.push_str(text);
+        line_start = false;
+        prev_end = start + text.chars().count();
+
+        if text == "(" {
+            let subquery = next.is_some_and(|t| is_keyword(t, "SELECT") || is_keyword(t, "WITH"));
+            parens.push(subquery);
+            if subquery {
+                depth += 1;
+                newline(&mut out, depth);
+                line_start = true;
+            }
+        }
+    }
+
+    out
+}
+
+/// Whether `token` opens a clause that belongs on its own line
+fn starts_clause(prev: Option<&Token>, token: &Token, next: Option<&Token>) -> bool {
+    if token.token_type != TokenType::Keyword {
+        return false;
+    }
+    let word = token.literal.as_str();
+    let after = |words: &[&str]| prev.is_some_and(|p| words.iter().any(|w| is_keyword(p, w)));
+
+    match word {
+        // IS [NOT] DISTINCT FROM, DELETE FROM, WITHIN GROUP
+        "FROM" => !after(&["DISTINCT", "DELETE"]),
+        "GROUP" => !after(&["WITHIN"]),
+        "JOIN" => !after(JOIN_MODIFIERS),
+        "OUTER" => false,
+        _ if JOIN_MODIFIERS.contains(&word) => {
+            !after(JOIN_MODIFIERS)
+                && next.is_some_and(|t| {
+                    JOIN_MODIFIERS
+                        .iter()
+                        .chain(&["JOIN"])
+                        .any(|w| is_keyword(t, w))
+                })
+        }
+        _ => CLAUSE_KEYWORDS.contains(&word),
+    }
+}
+
+fn is_keyword(token: &Token, word: &str) -> bool {
+    token.token_type == TokenType::Keyword && token.literal == word
+}
+
+/// Start a new line at the given nesting depth
+fn newline(out: &mut String, depth: usize) {
+    let trimmed = out.trim_end_matches(' ').len();
+    out.truncate(trimmed);
+    out.push('\n');
+    for _ in 0..depth {
+        out.push_str(INDENT);
+    }
+}
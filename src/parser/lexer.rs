@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -20,6 +21,28 @@ use super::token::{
     is_keyword, is_operator, is_operator_char, is_punctuator, Position, Token, TokenType,
 };
 
+/// Which quote character delimits identifiers
+///
+/// With the default `DoubleQuote` (SQL standard), `"name"` and `` `name` ``
+/// are both identifiers. With `Backtick` (MySQL), `` `name` `` is an
+/// identifier and `"text"` is a string literal like `'text'`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum IdentifierQuote {
+    #[default]
+    DoubleQuote,
+    Backtick,
+}
+
+impl IdentifierQuote {
+    /// The quote character that delimits identifiers
+    pub fn quote_char(self) -> char {
+        match self {
+            IdentifierQuote::DoubleQuote => '"',
+            IdentifierQuote::Backtick => '`',
+        }
+    }
+}
+
 /// SQL Lexer for tokenizing input
 pub struct Lexer {
     /// Input string
@@ -34,11 +57,18 @@ pub struct Lexer {
     pos: Position,
     /// Last error encountered
     last_error: Option<String>,
+    /// Whether double quotes delimit identifiers or strings
+    identifier_quote: IdentifierQuote,
 }
 
 impl Lexer {
     /// Create a new lexer for the given input
     pub fn new(input: &str) -> Self {
+        Self::with_identifier_quote(input, IdentifierQuote::default())
+    }
+
+    /// Create a new lexer that reads double quotes according to `identifier_quote`
+    pub fn with_identifier_quote(input: &str, identifier_quote: IdentifierQuote) -> Self {
         let chars: Vec<char> = input.chars().collect();
         let mut lexer = Self {
             input: chars,
@@ -47,6 +77,7 @@ impl Lexer {
             ch: '\0',
             pos: Position::new(0, 1, 1),
             last_error: None,
+            identifier_quote,
         };
         lexer.read_char();
         lexer
@@ -124,6 +155,16 @@ impl Lexer {
                 }
             }
 
+            // Double-quoted string literal under backtick quoting
+            '"' if self.identifier_quote == IdentifierQuote::Backtick => {
+                let literal = self.read_string_literal();
+                if let Some(error_msg) = self.last_error.take() {
+                    Token::error(error_msg, literal, pos)
+                } else {
+                    Token::new(TokenType::String, literal, pos)
+                }
+            }
+
             // Double-quoted identifier
             '"' => {
                 let literal = self.read_quoted_identifier('"');
@@ -185,7 +226,11 @@ impl Lexer {
             // Multi-line comment
             '/' if self.peek_char() == '*' => {
                 let literal = self.read_block_comment();
-                Token::new(TokenType::Comment, literal, pos)
+                if let Some(error_msg) = self.last_error.take() {
+                    Token::error(error_msg, literal, pos)
+                } else {
+                    Token::new(TokenType::Comment, literal, pos)
+                }
             }
 
             // Parameter ($1, $2, etc.)
@@ -216,6 +261,13 @@ impl Lexer {
                 Token::new(TokenType::Parameter, literal, pos)
             }
 
+            // PostgreSQL-style cast (expr::type)
+            ':' if self.peek_char() == ':' => {
+                self.read_char();
+                self.read_char();
+                Token::new(TokenType::Operator, "::", pos)
+            }
+
             // Star is always an operator (SELECT * handled by parser)
             '*' => {
                 self.read_char();
@@ -742,12 +794,26 @@ mod tests {
         assert_eq!(token.literal, "column");
     }
 
+    #[test]
+    fn test_backtick_identifier_quote() {
+        let mut lexer =
+            Lexer::with_identifier_quote("`col` \"it\"\"s\"", IdentifierQuote::Backtick);
+
+        let token = lexer.next_token();
+        assert_eq!(token.token_type, TokenType::Identifier);
+        assert_eq!(token.literal, "col");
+
+        let token = lexer.next_token();
+        assert_eq!(token.token_type, TokenType::String);
+        assert_eq!(token.literal, "\"it\"s\"");
+    }
+
     #[test]
     fn test_operators() {
-        let mut lexer = Lexer::new("= <> >= <= != + - * / || -> ->>");
+        let mut lexer = Lexer::new("= <> >= <= != + - * / || -> ->> ::");
 
         let expected = vec![
-            "=", "<>", ">=", "<=", "!=", "+", "-", "*", "/", "||", "->", "->>",
+            "=", "<>", ">=", "<=", "!=", "+", "-", "*", "/", "||", "->", "->>", "::",
         ];
 
         for exp in expected {
@@ -791,6 +857,17 @@ mod tests {
         assert_eq!(token.literal, "1");
     }
 
+    #[test]
+    fn test_unterminated_block_comment() {
+        let mut lexer = Lexer::new("SELECT 1 /* never closed");
+        lexer.next_token();
+        lexer.next_token();
+
+        let token = lexer.next_token();
+        assert!(token.is_error());
+        assert_eq!(token.error.as_deref(), Some("unterminated block comment"));
+    }
+
     #[test]
     fn test_double_negation() {
         // --5 should tokenize as two minus operators followed by integer 5
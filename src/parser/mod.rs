@@ -22,6 +22,7 @@
 //! - [`ast`] - Abstract Syntax Tree types
 //! - [`token`] - Token types
 //! - [`error`] - Parser error types
+//! - [`format_sql`] - SQL pretty-printer built on the AST's `Display` impls
 //!
 //! # Example
 //!
@@ -46,6 +47,9 @@ pub mod visitor;
 mod expressions;
 mod statements;
 
+// SQL pretty-printer on top of the AST's Display impls
+mod format;
+
 // Re-export main types
 pub use ast::{
     // Expressions
@@ -62,6 +66,7 @@ pub use ast::{
     BooleanLiteral,
     CaseExpression,
     CastExpression,
+    CheckpointStatement,
     ColumnConstraint,
     ColumnDefinition,
     CommitStatement,
@@ -104,6 +109,7 @@ pub use ast::{
     PrefixExpression,
     Program,
     QualifiedIdentifier,
+    ReleaseSavepointStatement,
     RollbackStatement,
     SavepointStatement,
     ScalarSubquery,
@@ -111,10 +117,19 @@ pub use ast::{
     SetOperation,
     SetOperationType,
     SetStatement,
+    SetTransactionStatement,
     ShowCreateTableStatement,
+    ShowEngineStatusStatement,
     ShowFunctionsStatement,
+    ShowIndexSuggestionsStatement,
     ShowIndexesStatement,
+    ShowIsolationLevelStatement,
+    ShowProceduresStatement,
+    ShowRowVersionsStatement,
+    ShowSequencesStatement,
+    ShowSettingStatement,
     ShowTablesStatement,
+    ShowWarningsStatement,
     SimpleTableSource,
     StarExpression,
     // Statements
@@ -133,7 +148,8 @@ pub use ast::{
 };
 
 pub use error::{ParseError, ParseErrors};
-pub use lexer::Lexer;
+pub use format::format_sql;
+pub use lexer::{IdentifierQuote, Lexer};
 pub use parser::Parser;
 pub use precedence::Precedence;
 pub use token::{
@@ -20,7 +20,7 @@ use std::sync::LazyLock;
 
 use super::ast::*;
 use super::error::{ParseError, ParseErrors};
-use super::lexer::Lexer;
+use super::lexer::{IdentifierQuote, Lexer};
 use super::precedence::Precedence;
 use super::token::{Token, TokenType};
 
@@ -86,6 +86,7 @@ static RESERVED_KEYWORDS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
         "GLOB",
         "REGEXP",
         "RLIKE",
+        "SIMILAR",
         "IS",
         "ASC",
         "DESC",
@@ -120,14 +121,23 @@ pub struct Parser {
     current_statement_id: usize,
     /// Parameter counter within current statement
     parameter_counter: usize,
+    /// Highest positional parameter index referenced in the program
+    max_parameter_index: usize,
+    /// Text of an optimizer hint comment (`/*+ ... */`) right before the peek token
+    pub(crate) peek_hint: Option<String>,
 }
 
 impl Parser {
     /// Create a new parser for the given input
     pub fn new(input: &str) -> Self {
-        let mut lexer = Lexer::new(input);
-        let cur_token = lexer.next_token();
-        let peek_token = lexer.next_token();
+        Self::with_identifier_quote(input, IdentifierQuote::default())
+    }
+
+    /// Create a new parser that reads double quotes according to `identifier_quote`
+    pub fn with_identifier_quote(input: &str, identifier_quote: IdentifierQuote) -> Self {
+        let mut lexer = Lexer::with_identifier_quote(input, identifier_quote);
+        let (cur_token, _) = Self::read_token(&mut lexer);
+        let (peek_token, peek_hint) = Self::read_token(&mut lexer);
 
         Parser {
             lexer,
@@ -137,6 +147,8 @@ impl Parser {
             current_clause: String::new(),
             current_statement_id: 0,
             parameter_counter: 1,
+            max_parameter_index: 0,
+            peek_hint,
         }
     }
 
@@ -146,19 +158,35 @@ impl Parser {
         let mut statements = Vec::new();
 
         while !self.cur_token_is(TokenType::Eof) {
-            // Skip comments
-            if self.cur_token_is(TokenType::Comment) {
+            // Skip empty statements
+            if self.cur_token_is_punctuator(";") {
                 self.next_token();
                 continue;
             }
 
             if let Some(stmt) = self.parse_statement() {
                 statements.push(stmt);
-            }
 
-            // Skip optional semicolon
-            if self.peek_token_is_punctuator(";") {
-                self.next_token();
+                // Statements are separated by semicolons
+                if self.peek_token_is_punctuator(";") {
+                    self.next_token();
+                } else if !self.peek_token_is(TokenType::Eof) {
+                    // Lexer errors are reported when the token is reached
+                    if !self.peek_token.is_error() {
+                        self.errors.push(ParseError::new(
+                            format!(
+                                "expected ; or end of input, got {} at {}",
+                                self.peek_token.literal, self.peek_token.position
+                            ),
+                            self.peek_token.position,
+                        ));
+                    }
+                    self.skip_to_statement_end();
+                }
+            } else {
+                // Resume at the next statement so that one error doesn't
+                // cascade into the rest of the program
+                self.skip_to_statement_end();
             }
 
             self.next_token();
@@ -173,12 +201,43 @@ impl Parser {
         Ok(Program { statements })
     }
 
+    /// Advance until the current token ends a statement (`;` or EOF)
+    fn skip_to_statement_end(&mut self) {
+        while !self.cur_token_is_punctuator(";") && !self.cur_token_is(TokenType::Eof) {
+            self.next_token();
+        }
+    }
+
+    /// Read the next token from the lexer, skipping comments
+    ///
+    /// Also returns the body of the last optimizer hint comment (`/*+ ... */`)
+    /// skipped before the token.
+    fn read_token(lexer: &mut Lexer) -> (Token, Option<String>) {
+        let mut hint = None;
+        loop {
+            let token = lexer.next_token();
+            if token.token_type != TokenType::Comment {
+                return (token, hint);
+            }
+            if let Some(body) = token.literal.strip_prefix("/*+") {
+                hint = Some(body.strip_suffix("*/").unwrap_or(body).to_string());
+            }
+        }
+    }
+
     /// Advance to the next token
     pub(crate) fn next_token(&mut self) {
-        self.cur_token = std::mem::replace(&mut self.peek_token, self.lexer.next_token());
+        let (token, hint) = Self::read_token(&mut self.lexer);
+        self.peek_hint = hint;
+        self.cur_token = std::mem::replace(&mut self.peek_token, token);
         // Check if the current token is an error token
         if self.cur_token.is_error() {
-            self.add_error(self.cur_token.literal.clone());
+            let message = self
+                .cur_token
+                .error
+                .clone()
+                .unwrap_or_else(|| self.cur_token.literal.clone());
+            self.add_error(message);
         }
     }
 
@@ -346,6 +405,19 @@ impl Parser {
         idx
     }
 
+    /// Record a positional parameter reference ($N or ?)
+    pub(crate) fn record_parameter_index(&mut self, index: usize) {
+        self.max_parameter_index = self.max_parameter_index.max(index);
+    }
+
+    /// Get the highest positional parameter index referenced so far
+    ///
+    /// After parsing, this is the number of positional parameters the
+    /// program expects to be bound.
+    pub fn max_parameter_index(&self) -> usize {
+        self.max_parameter_index
+    }
+
     /// Get current statement ID
     #[allow(dead_code)]
     pub(crate) fn current_statement_id(&self) -> usize {
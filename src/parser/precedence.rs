@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -65,8 +66,8 @@ impl Precedence {
             "NOT" => Precedence::Not,
 
             // Comparison
-            "=" | "<>" | "!=" | "IS" | "LIKE" | "ILIKE" | "GLOB" | "REGEXP" | "RLIKE" | "IN"
-            | "BETWEEN" => Precedence::Equals,
+            "=" | "<>" | "!=" | "IS" | "LIKE" | "ILIKE" | "GLOB" | "REGEXP" | "RLIKE"
+            | "SIMILAR" | "IN" | "BETWEEN" | "~" | "~*" | "!~" | "!~*" => Precedence::Equals,
             // AS has lowest precedence to prevent it from being consumed by NOT/AND/OR etc.
             // e.g., "NOT TRUE AS alias" should parse as "(NOT TRUE) AS alias"
             "AS" => Precedence::Lowest,
@@ -90,6 +91,9 @@ impl Precedence {
             // JSON operators
             "->" | "->>" => Precedence::Index,
 
+            // PostgreSQL-style cast binds like an index access
+            "::" => Precedence::Index,
+
             _ => Precedence::Lowest,
         }
     }
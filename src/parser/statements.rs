@@ -17,7 +17,10 @@
 
 use std::collections::HashMap;
 
+use crate::core::RowLockMode;
+
 use super::ast::*;
+use super::lexer::Lexer;
 use super::parser::Parser;
 use super::precedence::Precedence;
 use super::token::{Token, TokenType};
@@ -25,11 +28,6 @@ use super::token::{Token, TokenType};
 impl Parser {
     /// Parse a statement
     pub fn parse_statement(&mut self) -> Option<Statement> {
-        // Skip comments
-        while self.cur_token_is(TokenType::Comment) {
-            self.next_token();
-        }
-
         if self.cur_token_is(TokenType::Eof) {
             return None;
         }
@@ -53,18 +51,56 @@ impl Parser {
                 "COMMIT" => self.parse_commit_statement().map(Statement::Commit),
                 "ROLLBACK" => self.parse_rollback_statement().map(Statement::Rollback),
                 "SAVEPOINT" => self.parse_savepoint_statement().map(Statement::Savepoint),
+                "SET" if self.peek_token_is_keyword("TRANSACTION") => self
+                    .parse_set_transaction_statement()
+                    .map(Statement::SetTransaction),
                 "SET" => self.parse_set_statement().map(Statement::Set),
                 "PRAGMA" => self.parse_pragma_statement().map(Statement::Pragma),
                 "SHOW" => self.parse_show_statement(),
                 "DESCRIBE" | "DESC" => self.parse_describe_statement().map(Statement::Describe),
                 "EXPLAIN" => self.parse_explain_statement().map(Statement::Explain),
                 "ANALYZE" => self.parse_analyze_statement().map(Statement::Analyze),
+                "REINDEX" => self.parse_reindex_statement().map(Statement::Reindex),
+                "DECLARE" => self
+                    .parse_declare_cursor_statement()
+                    .map(Statement::DeclareCursor),
+                "FETCH" => self.parse_fetch_statement().map(Statement::Fetch),
                 "COPY" => self.parse_copy_statement().map(Statement::Copy),
                 _ => {
                     // Try to parse as expression statement
                     self.parse_expression_statement().map(Statement::Expression)
                 }
             }
+        } else if self.cur_token_is_word("CHECKPOINT")
+            && (self.peek_token_is(TokenType::Eof) || self.peek_token_is_punctuator(";"))
+        {
+            // CHECKPOINT is not reserved either, only a statement on its own
+            Some(Statement::Checkpoint(CheckpointStatement {
+                token: self.cur_token.clone(),
+            }))
+        } else if self.cur_token_is_word("ATTACH")
+            && (self.peek_token_is(TokenType::String) || self.peek_token_is_word("DATABASE"))
+        {
+            self.parse_attach_database_statement()
+                .map(Statement::AttachDatabase)
+        } else if self.cur_token_is_word("DETACH")
+            && (self.peek_token_is(TokenType::Identifier) || self.peek_token_is_word("DATABASE"))
+        {
+            self.parse_detach_database_statement()
+                .map(Statement::DetachDatabase)
+        } else if self.cur_token_is_word("RELEASE")
+            && (self.peek_token_is(TokenType::Identifier)
+                || self.peek_token_is_keyword("SAVEPOINT"))
+        {
+            // RELEASE is not reserved either
+            self.parse_release_savepoint_statement()
+                .map(Statement::ReleaseSavepoint)
+        } else if self.cur_token_is_word("CLOSE")
+            && (self.peek_token_is(TokenType::Identifier) || self.peek_token_is_keyword("ALL"))
+        {
+            // CLOSE is not reserved, so columns named "close" keep working
+            self.parse_close_cursor_statement()
+                .map(Statement::CloseCursor)
         } else {
             // Try to parse as expression statement
             self.parse_expression_statement().map(Statement::Expression)
@@ -74,6 +110,8 @@ impl Parser {
     /// Parse a SELECT statement
     pub fn parse_select_statement(&mut self) -> Option<SelectStatement> {
         let token = self.cur_token.clone();
+        // A hint comment must directly follow SELECT
+        let hint_text = self.peek_hint.take();
 
         let mut stmt = SelectStatement {
             token,
@@ -89,8 +127,14 @@ impl Parser {
             limit: None,
             offset: None,
             set_operations: Vec::new(),
+            hints: Vec::new(),
+            lock: None,
         };
 
+        if let Some(text) = hint_text {
+            stmt.hints = Self::parse_query_hints(&text);
+        }
+
         // Check for DISTINCT
         if self.peek_token_is_keyword("DISTINCT") {
             self.next_token();
@@ -213,6 +257,25 @@ impl Parser {
             }
         }
 
+        // Parse FOR UPDATE / FOR SHARE row locking clause
+        if self.peek_token_is(TokenType::Identifier)
+            && self.peek_token.literal.eq_ignore_ascii_case("FOR")
+        {
+            self.next_token(); // consume FOR
+            self.next_token();
+            if self.cur_token_is_word("UPDATE") {
+                stmt.lock = Some(RowLockMode::Update);
+            } else if self.cur_token_is_word("SHARE") {
+                stmt.lock = Some(RowLockMode::Share);
+            } else {
+                self.add_error(format!(
+                    "expected UPDATE or SHARE after FOR at {}",
+                    self.cur_token.position
+                ));
+                return None;
+            }
+        }
+
         self.current_clause.clear();
         Some(stmt)
     }
@@ -235,6 +298,8 @@ impl Parser {
             limit: None,
             offset: None,
             set_operations: Vec::new(),
+            hints: Vec::new(),
+            lock: None,
         };
 
         // Parse FROM clause
@@ -251,6 +316,9 @@ impl Parser {
             match keyword.as_str() {
                 "SELECT" => {
                     self.next_token(); // consume SELECT
+                    if let Some(text) = self.peek_hint.take() {
+                        stmt.hints = Self::parse_query_hints(&text);
+                    }
 
                     // Check for DISTINCT
                     if self.peek_token_is_keyword("DISTINCT") {
@@ -420,6 +488,8 @@ impl Parser {
     /// Parse a simple SELECT (without set operations, used for right side of UNION etc)
     fn parse_simple_select(&mut self) -> Option<SelectStatement> {
         let token = self.cur_token.clone();
+        // A hint comment must directly follow SELECT
+        let hint_text = self.peek_hint.take();
 
         let mut stmt = SelectStatement {
             token,
@@ -435,8 +505,14 @@ impl Parser {
             limit: None,
             offset: None,
             set_operations: Vec::new(),
+            hints: Vec::new(),
+            lock: None,
         };
 
+        if let Some(text) = hint_text {
+            stmt.hints = Self::parse_query_hints(&text);
+        }
+
         // Check for DISTINCT
         if self.peek_token_is_keyword("DISTINCT") {
             self.next_token();
@@ -732,6 +808,9 @@ impl Parser {
                     | "UNION"
                     | "INTERSECT"
                     | "EXCEPT"
+                    | "FOR"
+                    | "FORCE"
+                    | "IGNORE"
             ) {
                 self.next_token();
                 alias = Some(Identifier::new(
@@ -741,14 +820,124 @@ impl Parser {
             }
         }
 
+        // Check for FORCE INDEX / USE INDEX / IGNORE INDEX (...)
+        let index_hint = if ["FORCE", "USE", "IGNORE"]
+            .iter()
+            .any(|kw| self.peek_token.literal.eq_ignore_ascii_case(kw))
+        {
+            self.next_token();
+            Some(self.parse_table_index_hint()?)
+        } else {
+            None
+        };
+
         Some(Expression::TableSource(SimpleTableSource {
             token,
             name: table_name,
             alias,
             as_of,
+            index_hint,
         }))
     }
 
+    /// Parse the rest of `FORCE INDEX (a, b)`, starting at FORCE/USE/IGNORE
+    fn parse_table_index_hint(&mut self) -> Option<TableIndexHint> {
+        let ignore = self.cur_token.literal.eq_ignore_ascii_case("IGNORE");
+        if !self.expect_keyword("INDEX") {
+            return None;
+        }
+        if !self.expect_peek(TokenType::Punctuator) || self.cur_token.literal != "(" {
+            self.add_error(format!(
+                "expected '(' after INDEX at {}",
+                self.cur_token.position
+            ));
+            return None;
+        }
+        let indexes: Vec<String> = self
+            .parse_identifier_list()
+            .into_iter()
+            .map(|ident| ident.value)
+            .collect();
+        if !self.expect_peek(TokenType::Punctuator) || self.cur_token.literal != ")" {
+            self.add_error(format!(
+                "expected ')' after index names at {}",
+                self.cur_token.position
+            ));
+            return None;
+        }
+        Some(TableIndexHint { ignore, indexes })
+    }
+
+    /// Parse the body of an optimizer hint comment, e.g.
+    /// `INDEX(users idx_email) HASH_JOIN LEADING(o u)`
+    ///
+    /// Hints are advisory, so malformed input never fails the query: anything
+    /// unrecognized becomes [`QueryHint::Unknown`].
+    pub(crate) fn parse_query_hints(text: &str) -> Vec<QueryHint> {
+        let mut lexer = Lexer::new(text);
+        let mut tokens = Vec::new();
+        loop {
+            let token = lexer.next_token();
+            match token.token_type {
+                TokenType::Eof => break,
+                TokenType::Comment => continue,
+                _ => tokens.push(token.literal),
+            }
+        }
+
+        let mut hints = Vec::new();
+        let mut i = 0;
+        while i < tokens.len() {
+            let name = tokens[i].clone();
+            i += 1;
+            if name == "," {
+                continue;
+            }
+
+            // Optional parenthesized arguments, separated by spaces or commas
+            let mut args = Vec::new();
+            let mut raw = name.clone();
+            if tokens.get(i).is_some_and(|t| t == "(") {
+                i += 1;
+                while i < tokens.len() && tokens[i] != ")" {
+                    if tokens[i] != "," {
+                        args.push(tokens[i].clone());
+                    }
+                    i += 1;
+                }
+                i += 1; // closing paren
+                raw = format!("{}({})", name, args.join(" "));
+            }
+
+            let hint = match name.to_uppercase().as_str() {
+                "HASH_JOIN" | "USE_HASH" => QueryHint::JoinMethod {
+                    method: JoinMethodHint::Hash,
+                    tables: args,
+                },
+                "NL_JOIN" | "USE_NL" => QueryHint::JoinMethod {
+                    method: JoinMethodHint::NestedLoop,
+                    tables: args,
+                },
+                "MERGE_JOIN" | "USE_MERGE" => QueryHint::JoinMethod {
+                    method: JoinMethodHint::Merge,
+                    tables: args,
+                },
+                "INDEX" | "FORCE_INDEX" if args.len() >= 2 => QueryHint::Index {
+                    table: args[0].clone(),
+                    indexes: args[1..].to_vec(),
+                },
+                "NO_INDEX" | "IGNORE_INDEX" if !args.is_empty() => QueryHint::NoIndex {
+                    table: args[0].clone(),
+                    indexes: args[1..].to_vec(),
+                },
+                "LEADING" if !args.is_empty() => QueryHint::Leading(args),
+                _ => QueryHint::Unknown(raw),
+            };
+            hints.push(hint);
+        }
+        hints
+    }
+
     /// Parse a JOIN table expression
     fn parse_join_table_expression(&mut self, mut left: Expression) -> Option<Expression> {
         loop {
@@ -981,17 +1170,29 @@ impl Parser {
             return None;
         }
 
-        // Parse the CTE query
+        // Parse the CTE query; a VALUES list reads as SELECT * FROM (VALUES ...)
         self.next_token();
-        if !self.cur_token_is_keyword("SELECT") {
+        let query = if self.cur_token_is_keyword("VALUES") {
+            let values_token = self.cur_token.clone();
+            let rows = self.parse_value_lists()?;
+            Self::values_select(
+                values_token.clone(),
+                ValuesTableSource {
+                    token: values_token,
+                    rows,
+                    alias: Some(name.clone()),
+                    column_aliases: column_names.clone(),
+                },
+            )
+        } else if self.cur_token_is_keyword("SELECT") {
+            self.parse_select_statement()?
+        } else {
             self.add_error(format!(
-                "expected SELECT in CTE at {}",
+                "expected SELECT or VALUES in CTE at {}",
                 self.cur_token.position
             ));
             return None;
-        }
-
-        let query = self.parse_select_statement()?;
+        };
 
         // Expect )
         if !self.expect_peek(TokenType::Punctuator) || self.cur_token.literal != ")" {
@@ -1161,9 +1362,10 @@ impl Parser {
         }
 
         self.next_token(); // consume RETURNING
+        self.next_token(); // move to first column
 
-        // Parse the expression list
-        self.parse_expression_list()
+        // Same column list as SELECT, so columns can be aliased
+        self.parse_select_columns()
     }
 
     /// Parse value lists for INSERT
@@ -1227,6 +1429,12 @@ impl Parser {
             self.next_token(); // move to '('
         }
 
+        // JSON_TABLE(doc, 'path' COLUMNS (...)) names its columns itself
+        let mut column_aliases = Vec::new();
+        if name.value.eq_ignore_ascii_case("JSON_TABLE") {
+            column_aliases = self.parse_json_table_columns(&mut arguments)?;
+        }
+
         if !self.expect_peek(TokenType::Punctuator) || self.cur_token.literal != ")" {
             self.add_error(format!(
                 "expected ')' after function arguments, got {:?} at {}",
@@ -1243,8 +1451,25 @@ impl Parser {
             return None;
         }
 
+        // WITH ORDINALITY can't be confused with a CTE here, WITH only starts one
+        // at the beginning of a statement
+        let mut with_ordinality = false;
+        if self.peek_token_is_keyword("WITH") {
+            self.next_token(); // consume WITH
+            if !self.peek_token_is(TokenType::Identifier)
+                || !self.peek_token.literal.eq_ignore_ascii_case("ORDINALITY")
+            {
+                self.add_error(format!(
+                    "expected ORDINALITY after WITH at {}",
+                    self.peek_token.position
+                ));
+                return None;
+            }
+            self.next_token(); // consume ORDINALITY
+            with_ordinality = true;
+        }
+
         let mut alias = None;
-        let mut column_aliases = Vec::new();
 
         if self.peek_token_is_keyword("AS") {
             self.next_token(); // consume AS
@@ -1264,6 +1489,7 @@ impl Parser {
         }
 
         if alias.is_some() && self.peek_token_is_punctuator("(") {
+            column_aliases.clear();
             self.next_token(); // consume '('
             self.next_token(); // move to first column alias
 
@@ -1291,11 +1517,109 @@ impl Parser {
             token,
             function: name,
             arguments,
+            with_ordinality,
             alias,
             column_aliases,
         }))
     }
 
+    /// Parse the COLUMNS clause of JSON_TABLE, after the row path
+    ///
+    /// Each `name TYPE PATH 'path'` is appended to `arguments` as three
+    /// string literals; the column names are returned as the default aliases.
+    fn parse_json_table_columns(
+        &mut self,
+        arguments: &mut Vec<Expression>,
+    ) -> Option<Vec<Identifier>> {
+        if !self.peek_token_is(TokenType::Identifier)
+            || !self.peek_token.literal.eq_ignore_ascii_case("COLUMNS")
+        {
+            self.add_error(format!(
+                "expected COLUMNS in JSON_TABLE at {}",
+                self.peek_token.position
+            ));
+            return None;
+        }
+        self.next_token(); // consume COLUMNS
+        if !self.expect_peek(TokenType::Punctuator) || self.cur_token.literal != "(" {
+            self.add_error(format!(
+                "expected '(' after COLUMNS at {}",
+                self.cur_token.position
+            ));
+            return None;
+        }
+
+        let mut names = Vec::new();
+        loop {
+            self.next_token(); // move to the column name
+            if !self.cur_token_is(TokenType::Identifier) && !self.cur_token_is(TokenType::Keyword) {
+                self.add_error(format!(
+                    "expected column name in JSON_TABLE COLUMNS at {}",
+                    self.cur_token.position
+                ));
+                return None;
+            }
+            let name = Identifier::new(self.cur_token.clone(), self.cur_token.literal.clone());
+            let data_type = self.parse_data_type()?;
+
+            if !self.peek_token.literal.eq_ignore_ascii_case("PATH") {
+                self.add_error(format!(
+                    "expected PATH after the type of JSON_TABLE column {} at {}",
+                    name.value, self.peek_token.position
+                ));
+                return None;
+            }
+            self.next_token(); // consume PATH
+            if !self.expect_peek(TokenType::String) {
+                return None;
+            }
+            let path = StringLiteral::from_token(self.cur_token.clone()).value;
+
+            for value in [name.value.clone(), data_type, path] {
+                arguments.push(Expression::StringLiteral(StringLiteral {
+                    token: name.token.clone(),
+                    value,
+                    type_hint: None,
+                }));
+            }
+            names.push(name);
+
+            if self.peek_token_is_punctuator(",") {
+                self.next_token(); // consume ','
+                continue;
+            }
+            if !self.expect_peek(TokenType::Punctuator) || self.cur_token.literal != ")" {
+                self.add_error(format!(
+                    "expected ',' or ')' in JSON_TABLE COLUMNS at {}",
+                    self.cur_token.position
+                ));
+                return None;
+            }
+            return Some(names);
+        }
+    }
+
+    /// Build `SELECT * FROM (VALUES ...)`
+    fn values_select(token: Token, source: ValuesTableSource) -> SelectStatement {
+        SelectStatement {
+            token: token.clone(),
+            distinct: false,
+            columns: vec![Expression::Star(StarExpression { token })],
+            with: None,
+            table_expr: Some(Box::new(Expression::ValuesSource(source))),
+            where_clause: None,
+            group_by: GroupByClause::default(),
+            having: None,
+            window_defs: Vec::new(),
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+            set_operations: Vec::new(),
+            hints: Vec::new(),
+            lock: None,
+        }
+    }
+
     fn parse_values_table_source(&mut self) -> Option<Expression> {
         let token = self.cur_token.clone(); // VALUES token
 
@@ -1378,6 +1702,27 @@ impl Parser {
         // Parse table name
         let table_name = self.parse_table_name()?;
 
+        // Parse optional alias (AS alias or just alias)
+        let alias = if self.peek_token_is_keyword("AS") {
+            self.next_token(); // consume AS
+            if !self.expect_peek(TokenType::Identifier) {
+                return None;
+            }
+            Some(Identifier::new(
+                self.cur_token.clone(),
+                self.cur_token.literal.clone(),
+            ))
+        } else if self.peek_token_is(TokenType::Identifier) && !self.peek_token_is_keyword("SET") {
+            // Alias without AS keyword (e.g., UPDATE users u SET ...)
+            self.next_token();
+            Some(Identifier::new(
+                self.cur_token.clone(),
+                self.cur_token.literal.clone(),
+            ))
+        } else {
+            None
+        };
+
         // Expect SET
         if !self.expect_keyword("SET") {
             return None;
@@ -1406,6 +1751,15 @@ impl Parser {
             self.next_token(); // consume comma
         }
 
+        // Parse optional FROM clause (UPDATE ... FROM source WHERE ...)
+        let from = if self.peek_token_is_keyword("FROM") {
+            self.next_token(); // consume FROM
+            self.next_token(); // move to table expression
+            Some(Box::new(self.parse_table_expression()?))
+        } else {
+            None
+        };
+
         // Parse WHERE clause
         let where_clause = if self.peek_token_is_keyword("WHERE") {
             self.next_token(); // consume WHERE
@@ -1424,7 +1778,9 @@ impl Parser {
         Some(UpdateStatement {
             token,
             table_name,
+            alias,
             updates,
+            from,
             where_clause,
             returning,
         })
@@ -1528,9 +1884,19 @@ impl Parser {
             return self
                 .parse_create_procedure_statement(or_replace)
                 .map(Statement::CreateProcedure);
+        } else if self.peek_token_is_keyword("VIEW") {
+            self.next_token();
+            return self
+                .parse_create_view_statement(or_replace)
+                .map(Statement::CreateView);
+        } else if self.peek_token_is_keyword("FUNCTION") {
+            self.next_token();
+            return self
+                .parse_create_function_statement(or_replace)
+                .map(Statement::CreateFunction);
         } else if or_replace {
             self.add_error(format!(
-                "OR REPLACE is only supported for PROCEDURE at {}",
+                "OR REPLACE is only supported for VIEW, FUNCTION and PROCEDURE at {}",
                 self.cur_token.position
             ));
             return None;
@@ -1574,14 +1940,6 @@ impl Parser {
             }
             self.parse_create_columnar_index_statement()
                 .map(Statement::CreateColumnarIndex)
-        } else if self.peek_token_is_keyword("VIEW") {
-            self.next_token();
-            self.parse_create_view_statement()
-                .map(Statement::CreateView)
-        } else if self.peek_token_is_keyword("FUNCTION") {
-            self.next_token();
-            self.parse_create_function_statement()
-                .map(Statement::CreateFunction)
         } else if self.peek_token_is_keyword("TRIGGER") {
             self.next_token();
             self.parse_create_trigger_statement()
@@ -1948,8 +2306,12 @@ impl Parser {
 
         let name = Identifier::new(self.cur_token.clone(), self.cur_token.literal.clone());
 
-        // Parse data type
-        if !self.expect_peek(TokenType::Keyword) {
+        // Parse data type; UUID is not a keyword since it also names a function
+        if self.peek_token_is(TokenType::Identifier)
+            && self.peek_token.literal.eq_ignore_ascii_case("UUID")
+        {
+            self.next_token();
+        } else if !self.expect_peek(TokenType::Keyword) {
             return None;
         }
         let data_type = self.cur_token.literal.to_uppercase();
@@ -1979,9 +2341,51 @@ impl Parser {
 
         // Parse constraints
         let mut constraints = Vec::new();
-        while self.peek_token_is(TokenType::Keyword) {
+        // GENERATED is not a keyword, so it is matched as a word
+        while self.peek_token_is(TokenType::Keyword)
+            || (self.peek_token_is(TokenType::Identifier)
+                && (self.peek_token.literal.eq_ignore_ascii_case("GENERATED")
+                    || self.peek_token.literal.eq_ignore_ascii_case("COMPRESSED")))
+        {
             let constraint_keyword = self.peek_token.literal.to_uppercase();
             match constraint_keyword.as_str() {
+                "GENERATED" => {
+                    self.next_token(); // consume GENERATED
+                    self.next_token();
+                    if !self.cur_token_is_word("ALWAYS") {
+                        self.add_error(format!(
+                            "expected ALWAYS after GENERATED at {}",
+                            self.cur_token.position
+                        ));
+                        return None;
+                    }
+                    if !self.expect_keyword("AS") {
+                        return None;
+                    }
+                    if !self.expect_peek(TokenType::Punctuator) || self.cur_token.literal != "(" {
+                        self.add_error(format!(
+                            "expected '(' after GENERATED ALWAYS AS at {}",
+                            self.cur_token.position
+                        ));
+                        return None;
+                    }
+                    self.next_token();
+                    let expr = self.parse_expression(Precedence::Lowest)?;
+                    if !self.expect_peek(TokenType::Punctuator) || self.cur_token.literal != ")" {
+                        return None;
+                    }
+                    // Generated columns are always stored; STORED is accepted for clarity
+                    if self.peek_token_is(TokenType::Identifier)
+                        && self.peek_token.literal.eq_ignore_ascii_case("STORED")
+                    {
+                        self.next_token();
+                    }
+                    constraints.push(ColumnConstraint::Generated(expr));
+                }
+                "COMPRESSED" => {
+                    self.next_token(); // consume COMPRESSED
+                    constraints.push(ColumnConstraint::Compressed);
+                }
                 "PRIMARY" => {
                     self.next_token(); // consume PRIMARY
                     if !self.expect_keyword("KEY") {
@@ -2006,7 +2410,23 @@ impl Parser {
                     let expr = self.parse_expression(Precedence::Lowest)?;
                     constraints.push(ColumnConstraint::Default(expr));
                 }
-                "CHECK" => {
+                "CONSTRAINT" | "CHECK" => {
+                    // Only CHECK constraints can be named at column level
+                    let mut name = None;
+                    if constraint_keyword == "CONSTRAINT" {
+                        self.next_token(); // consume CONSTRAINT
+                        if !self.expect_peek(TokenType::Identifier) {
+                            return None;
+                        }
+                        name = Some(self.cur_token.literal.clone());
+                        if !self.peek_token_is_keyword("CHECK") {
+                            self.add_error(format!(
+                                "expected CHECK after CONSTRAINT {} at {}",
+                                self.cur_token.literal, self.peek_token.position
+                            ));
+                            return None;
+                        }
+                    }
                     self.next_token(); // consume CHECK
                     if !self.expect_peek(TokenType::Punctuator) || self.cur_token.literal != "(" {
                         return None;
@@ -2016,7 +2436,7 @@ impl Parser {
                     if !self.expect_peek(TokenType::Punctuator) || self.cur_token.literal != ")" {
                         return None;
                     }
-                    constraints.push(ColumnConstraint::Check(expr));
+                    constraints.push(ColumnConstraint::Check { name, expr });
                 }
                 "REFERENCES" => {
                     self.next_token(); // consume REFERENCES
@@ -2100,6 +2520,9 @@ impl Parser {
             return None;
         }
 
+        // Parse optional NULLS [NOT] DISTINCT (before or after USING)
+        let mut nulls_not_distinct = self.parse_index_nulls_distinct()?;
+
         // Parse optional USING clause
         let index_method = if self.peek_token_is_keyword("USING") {
             self.next_token(); // consume USING
@@ -2121,6 +2544,15 @@ impl Parser {
         } else {
             None
         };
+        nulls_not_distinct |= self.parse_index_nulls_distinct()?;
+
+        if nulls_not_distinct && !is_unique {
+            self.add_error(format!(
+                "NULLS NOT DISTINCT is only allowed on a UNIQUE index at {}",
+                token.position
+            ));
+            return None;
+        }
 
         Some(CreateIndexStatement {
             token,
@@ -2130,9 +2562,31 @@ impl Parser {
             is_unique,
             if_not_exists,
             index_method,
+            nulls_not_distinct,
         })
     }
 
+    /// Parse an optional NULLS [NOT] DISTINCT clause of CREATE INDEX
+    ///
+    /// Returns Some(true) for NULLS NOT DISTINCT, Some(false) when the clause
+    /// is absent or NULLS DISTINCT, and None on a syntax error.
+    fn parse_index_nulls_distinct(&mut self) -> Option<bool> {
+        if !self.peek_token_is_keyword("NULLS") {
+            return Some(false);
+        }
+        self.next_token(); // consume NULLS
+        let not_distinct = if self.peek_token_is_keyword("NOT") {
+            self.next_token(); // consume NOT
+            true
+        } else {
+            false
+        };
+        if !self.expect_keyword("DISTINCT") {
+            return None;
+        }
+        Some(not_distinct)
+    }
+
     /// Parse a CREATE COLUMNAR INDEX statement
     fn parse_create_columnar_index_statement(&mut self) -> Option<CreateColumnarIndexStatement> {
         let token = self.cur_token.clone();
@@ -2190,7 +2644,7 @@ impl Parser {
     }
 
     /// Parse a CREATE VIEW statement
-    fn parse_create_view_statement(&mut self) -> Option<CreateViewStatement> {
+    fn parse_create_view_statement(&mut self, or_replace: bool) -> Option<CreateViewStatement> {
         let token = self.cur_token.clone();
 
         // Check for IF NOT EXISTS
@@ -2206,6 +2660,13 @@ impl Parser {
         } else {
             false
         };
+        if or_replace && if_not_exists {
+            self.add_error(format!(
+                "OR REPLACE cannot be combined with IF NOT EXISTS at {}",
+                self.cur_token.position
+            ));
+            return None;
+        }
 
         // Parse view name
         let view_name = self.parse_table_name()?;
@@ -2246,6 +2707,7 @@ impl Parser {
             view_name,
             query: Box::new(query),
             if_not_exists,
+            or_replace,
         })
     }
 
@@ -2282,7 +2744,10 @@ impl Parser {
     }
 
     /// Parse a CREATE FUNCTION statement
-    fn parse_create_function_statement(&mut self) -> Option<CreateFunctionStatement> {
+    fn parse_create_function_statement(
+        &mut self,
+        or_replace: bool,
+    ) -> Option<CreateFunctionStatement> {
         let token = self.cur_token.clone();
 
         // Optional IF NOT EXISTS
@@ -2298,6 +2763,13 @@ impl Parser {
         } else {
             false
         };
+        if or_replace && if_not_exists {
+            self.add_error(format!(
+                "OR REPLACE cannot be combined with IF NOT EXISTS at {}",
+                self.cur_token.position
+            ));
+            return None;
+        }
 
         // Function name
         let function_name = self.parse_function_name()?;
@@ -2381,6 +2853,7 @@ impl Parser {
             language,
             body,
             if_not_exists,
+            or_replace,
         })
     }
 
@@ -2596,13 +3069,30 @@ impl Parser {
         // Parse table name
         let table_name = self.parse_table_name()?;
 
+        let cascade = self.parse_drop_behavior();
+
         Some(DropTableStatement {
             token,
             table_name,
             if_exists,
+            cascade,
         })
     }
 
+    /// Parse an optional RESTRICT or CASCADE after a DROP target
+    /// Returns true for CASCADE; RESTRICT is the default
+    fn parse_drop_behavior(&mut self) -> bool {
+        if self.peek_token_is_keyword("CASCADE") {
+            self.next_token();
+            true
+        } else {
+            if self.peek_token_is_keyword("RESTRICT") {
+                self.next_token();
+            }
+            false
+        }
+    }
+
     /// Parse a function name (simple or qualified)
     fn parse_function_name(&mut self) -> Option<FunctionName> {
         if !self.expect_peek(TokenType::Identifier) {
@@ -2646,16 +3136,39 @@ impl Parser {
         // Parse function name (simple or qualified)
         let function_name = self.parse_function_name()?;
 
-        Some(DropFunctionStatement {
-            token,
-            function_name,
-            if_exists,
-        })
-    }
-
-    /// Parse a DROP PROCEDURE statement
-    fn parse_drop_procedure_statement(&mut self) -> Option<DropProcedureStatement> {
-        let token = self.cur_token.clone();
+        // Optional argument types selecting one overload
+        let parameter_types = if self.peek_token_is_punctuator("(") {
+            self.next_token();
+            let mut types = Vec::new();
+            if !self.peek_token_is_punctuator(")") {
+                loop {
+                    types.push(self.parse_data_type()?);
+                    if !self.peek_token_is_punctuator(",") {
+                        break;
+                    }
+                    self.next_token(); // consume comma
+                }
+            }
+            if !self.expect_peek(TokenType::Punctuator) || self.cur_token.literal != ")" {
+                self.add_error(format!("expected ')' at {}", self.cur_token.position));
+                return None;
+            }
+            Some(types)
+        } else {
+            None
+        };
+
+        Some(DropFunctionStatement {
+            token,
+            function_name,
+            if_exists,
+            parameter_types,
+        })
+    }
+
+    /// Parse a DROP PROCEDURE statement
+    fn parse_drop_procedure_statement(&mut self) -> Option<DropProcedureStatement> {
+        let token = self.cur_token.clone();
 
         // Check for IF EXISTS
         let if_exists = if self.peek_token_is_keyword("IF") {
@@ -2807,10 +3320,13 @@ impl Parser {
         }
         let view_name = Identifier::new(self.cur_token.clone(), self.cur_token.literal.clone());
 
+        let cascade = self.parse_drop_behavior();
+
         Some(DropViewStatement {
             token,
             view_name,
             if_exists,
+            cascade,
         })
     }
 
@@ -2970,6 +3486,7 @@ impl Parser {
 
         let operation_keyword = self.cur_token.literal.to_uppercase();
         let mut constraint = None;
+        let mut auto_increment = None;
         let (operation, column_def, column_name, new_column_name, new_table_name) =
             match operation_keyword.as_str() {
                 "ADD" => {
@@ -3084,9 +3601,48 @@ impl Parser {
                         None,
                     )
                 }
+                "AUTO_INCREMENT" => {
+                    // MySQL style: AUTO_INCREMENT [=] n
+                    if self.peek_token_is_operator("=") {
+                        self.next_token();
+                    }
+                    auto_increment = Some(self.parse_auto_increment_value()?);
+                    (
+                        AlterTableOperation::SetAutoIncrement,
+                        None,
+                        None,
+                        None,
+                        None,
+                    )
+                }
+                "ALTER" => {
+                    // Postgres style: ALTER [COLUMN] c RESTART [WITH] n
+                    if self.peek_token_is_keyword("COLUMN") {
+                        self.next_token();
+                    }
+                    if !self.expect_peek(TokenType::Identifier) {
+                        return None;
+                    }
+                    let col_name =
+                        Identifier::new(self.cur_token.clone(), self.cur_token.literal.clone());
+                    if !self.expect_keyword("RESTART") {
+                        return None;
+                    }
+                    if self.peek_token_is_keyword("WITH") {
+                        self.next_token();
+                    }
+                    auto_increment = Some(self.parse_auto_increment_value()?);
+                    (
+                        AlterTableOperation::SetAutoIncrement,
+                        None,
+                        Some(col_name),
+                        None,
+                        None,
+                    )
+                }
                 _ => {
                     self.add_error(format!(
-                        "expected ADD, DROP, RENAME, or MODIFY at {}",
+                        "expected ADD, DROP, RENAME, MODIFY, ALTER, or AUTO_INCREMENT at {}",
                         self.cur_token.position
                     ));
                     return None;
@@ -3102,9 +3658,27 @@ impl Parser {
             new_column_name,
             new_table_name,
             constraint,
+            auto_increment,
         })
     }
 
+    /// Parse the integer counter value of AUTO_INCREMENT / RESTART WITH
+    fn parse_auto_increment_value(&mut self) -> Option<i64> {
+        if !self.expect_peek(TokenType::Integer) {
+            return None;
+        }
+        match self.cur_token.literal.parse::<i64>() {
+            Ok(value) => Some(value),
+            Err(_) => {
+                self.add_error(format!(
+                    "invalid AUTO_INCREMENT value '{}' at {}",
+                    self.cur_token.literal, self.cur_token.position
+                ));
+                None
+            }
+        }
+    }
+
     /// Parse a BEGIN statement
     fn parse_begin_statement(&mut self) -> Option<BeginStatement> {
         let token = self.cur_token.clone();
@@ -3116,45 +3690,7 @@ impl Parser {
 
         // Check for ISOLATION LEVEL
         let isolation_level = if self.peek_token_is_keyword("ISOLATION") {
-            self.next_token();
-            if !self.expect_keyword("LEVEL") {
-                return None;
-            }
-            self.next_token();
-
-            let level = self.cur_token.literal.to_uppercase();
-            let isolation = match level.as_str() {
-                "SNAPSHOT" | "SERIALIZABLE" => level,
-                "REPEATABLE" => {
-                    if self.peek_token_is_keyword("READ") {
-                        self.next_token();
-                    }
-                    "REPEATABLE READ".to_string()
-                }
-                "READ" => {
-                    if self.peek_token_is_keyword("UNCOMMITTED") {
-                        self.next_token();
-                        "READ UNCOMMITTED".to_string()
-                    } else if self.peek_token_is_keyword("COMMITTED") {
-                        self.next_token();
-                        "READ COMMITTED".to_string()
-                    } else {
-                        self.add_error(format!(
-                            "expected UNCOMMITTED or COMMITTED after READ at {}",
-                            self.cur_token.position
-                        ));
-                        return None;
-                    }
-                }
-                _ => {
-                    self.add_error(format!(
-                        "invalid isolation level: {} at {}",
-                        level, self.cur_token.position
-                    ));
-                    return None;
-                }
-            };
-            Some(isolation)
+            Some(self.parse_isolation_level()?)
         } else {
             None
         };
@@ -3165,6 +3701,50 @@ impl Parser {
         })
     }
 
+    /// Parse `ISOLATION LEVEL <level>` with ISOLATION as the peek token
+    ///
+    /// Returns the level in upper case, e.g. "SNAPSHOT" or "READ COMMITTED".
+    fn parse_isolation_level(&mut self) -> Option<String> {
+        self.next_token();
+        if !self.expect_keyword("LEVEL") {
+            return None;
+        }
+        self.next_token();
+
+        let level = self.cur_token.literal.to_uppercase();
+        match level.as_str() {
+            "SNAPSHOT" | "SERIALIZABLE" => Some(level),
+            "REPEATABLE" => {
+                if self.peek_token_is_keyword("READ") {
+                    self.next_token();
+                }
+                Some("REPEATABLE READ".to_string())
+            }
+            "READ" => {
+                if self.peek_token_is_keyword("UNCOMMITTED") {
+                    self.next_token();
+                    Some("READ UNCOMMITTED".to_string())
+                } else if self.peek_token_is_keyword("COMMITTED") {
+                    self.next_token();
+                    Some("READ COMMITTED".to_string())
+                } else {
+                    self.add_error(format!(
+                        "expected UNCOMMITTED or COMMITTED after READ at {}",
+                        self.cur_token.position
+                    ));
+                    None
+                }
+            }
+            _ => {
+                self.add_error(format!(
+                    "invalid isolation level: {} at {}",
+                    level, self.cur_token.position
+                ));
+                None
+            }
+        }
+    }
+
     /// Parse a COMMIT statement
     fn parse_commit_statement(&mut self) -> Option<CommitStatement> {
         let token = self.cur_token.clone();
@@ -3226,6 +3806,46 @@ impl Parser {
         })
     }
 
+    /// Parse a RELEASE [SAVEPOINT] statement
+    fn parse_release_savepoint_statement(&mut self) -> Option<ReleaseSavepointStatement> {
+        let token = self.cur_token.clone();
+
+        if self.peek_token_is_keyword("SAVEPOINT") {
+            self.next_token();
+        }
+        if !self.expect_peek(TokenType::Identifier) {
+            return None;
+        }
+
+        let savepoint_name =
+            Identifier::new(self.cur_token.clone(), self.cur_token.literal.clone());
+
+        Some(ReleaseSavepointStatement {
+            token,
+            savepoint_name,
+        })
+    }
+
+    /// Parse a SET TRANSACTION ISOLATION LEVEL statement
+    fn parse_set_transaction_statement(&mut self) -> Option<SetTransactionStatement> {
+        let token = self.cur_token.clone();
+        self.next_token(); // TRANSACTION
+
+        if !self.peek_token_is_keyword("ISOLATION") {
+            self.add_error(format!(
+                "expected ISOLATION LEVEL after SET TRANSACTION at {}",
+                self.peek_token.position
+            ));
+            return None;
+        }
+        let isolation_level = self.parse_isolation_level()?;
+
+        Some(SetTransactionStatement {
+            token,
+            isolation_level,
+        })
+    }
+
     /// Parse a SET statement
     fn parse_set_statement(&mut self) -> Option<SetStatement> {
         let token = self.cur_token.clone();
@@ -3253,7 +3873,22 @@ impl Parser {
         }
 
         self.next_token();
-        let value = self.parse_expression(Precedence::Lowest)?;
+        let mut value = self.parse_expression(Precedence::Lowest)?;
+
+        // List-valued settings: SET search_path = a, b
+        if self.peek_token_is_punctuator(",") {
+            let list_token = self.cur_token.clone();
+            let mut expressions = vec![value];
+            while self.peek_token_is_punctuator(",") {
+                self.next_token(); // consume ','
+                self.next_token();
+                expressions.push(self.parse_expression(Precedence::Lowest)?);
+            }
+            value = Expression::ExpressionList(ExpressionList {
+                token: list_token,
+                expressions,
+            });
+        }
 
         Some(SetStatement { token, name, value })
     }
@@ -3326,6 +3961,12 @@ impl Parser {
             }
         } else if self.peek_token_is_keyword("INDEXES") || self.peek_token_is_keyword("INDEX") {
             self.next_token();
+            if self.peek_token.literal.eq_ignore_ascii_case("SUGGESTIONS") {
+                self.next_token();
+                return Some(Statement::ShowIndexSuggestions(
+                    ShowIndexSuggestionsStatement { token },
+                ));
+            }
             if !self.expect_keyword("FROM") {
                 return None;
             }
@@ -3346,6 +3987,72 @@ impl Parser {
                 token,
                 plural,
             }))
+        } else if self.peek_token.literal.eq_ignore_ascii_case("PROCEDURES") {
+            self.next_token();
+            Some(Statement::ShowProcedures(ShowProceduresStatement { token }))
+        } else if self.peek_token.literal.eq_ignore_ascii_case("SEQUENCES") {
+            self.next_token();
+            Some(Statement::ShowSequences(ShowSequencesStatement { token }))
+        } else if self.peek_token_is_keyword("TRANSACTION") {
+            self.next_token();
+            if !self.expect_keyword("ISOLATION") || !self.expect_keyword("LEVEL") {
+                return None;
+            }
+            Some(Statement::ShowIsolationLevel(ShowIsolationLevelStatement {
+                token,
+            }))
+        } else if self.peek_token.token_type == TokenType::Identifier
+            && self.peek_token.literal.eq_ignore_ascii_case("WARNINGS")
+        {
+            self.next_token();
+            Some(Statement::ShowWarnings(ShowWarningsStatement { token }))
+        } else if self.peek_token.literal.eq_ignore_ascii_case("ENGINE") {
+            self.next_token();
+            if !self.peek_token.literal.eq_ignore_ascii_case("STATUS") {
+                self.add_error(format!(
+                    "expected STATUS after SHOW ENGINE at {}",
+                    self.peek_token.position
+                ));
+                return None;
+            }
+            self.next_token();
+            Some(Statement::ShowEngineStatus(ShowEngineStatusStatement {
+                token,
+            }))
+        } else if self.peek_token.literal.eq_ignore_ascii_case("ROW") {
+            self.next_token();
+            if !self.peek_token.literal.eq_ignore_ascii_case("VERSIONS") {
+                self.add_error(format!(
+                    "expected VERSIONS after SHOW ROW at {}",
+                    self.peek_token.position
+                ));
+                return None;
+            }
+            self.next_token();
+            if !self.expect_keyword("FROM") {
+                return None;
+            }
+            if !self.expect_peek(TokenType::Identifier) {
+                return None;
+            }
+            let table_name =
+                Identifier::new(self.cur_token.clone(), self.cur_token.literal.clone());
+            let where_clause = if self.peek_token_is_keyword("WHERE") {
+                self.next_token(); // consume WHERE
+                self.next_token();
+                Some(Box::new(self.parse_expression(Precedence::Lowest)?))
+            } else {
+                None
+            };
+            Some(Statement::ShowRowVersions(ShowRowVersionsStatement {
+                token,
+                table_name,
+                where_clause,
+            }))
+        } else if self.peek_token.token_type == TokenType::Identifier {
+            self.next_token();
+            let name = Identifier::new(self.cur_token.clone(), self.cur_token.literal.clone());
+            Some(Statement::ShowSetting(ShowSettingStatement { token, name }))
         } else {
             self.add_error(format!(
                 "unsupported SHOW statement at {}",
@@ -3440,6 +4147,243 @@ impl Parser {
         Some(AnalyzeStatement { token, table_name })
     }
 
+    /// Parse a REINDEX statement
+    /// Syntax: REINDEX TABLE table_name | REINDEX INDEX index_name [ON table_name]
+    fn parse_reindex_statement(&mut self) -> Option<ReindexStatement> {
+        let token = self.cur_token.clone();
+
+        if self.peek_token_is_keyword("TABLE") {
+            self.next_token(); // consume TABLE
+            if !self.expect_peek(TokenType::Identifier) {
+                return None;
+            }
+            let table_name =
+                Identifier::new(self.cur_token.clone(), self.cur_token.literal.clone());
+            return Some(ReindexStatement {
+                token,
+                index_name: None,
+                table_name: Some(table_name),
+            });
+        }
+
+        if !self.expect_keyword("INDEX") {
+            return None;
+        }
+        if !self.expect_peek(TokenType::Identifier) {
+            return None;
+        }
+        let index_name = Identifier::new(self.cur_token.clone(), self.cur_token.literal.clone());
+
+        let table_name = if self.peek_token_is_keyword("ON") {
+            self.next_token();
+            if !self.expect_peek(TokenType::Identifier) {
+                return None;
+            }
+            Some(Identifier::new(
+                self.cur_token.clone(),
+                self.cur_token.literal.clone(),
+            ))
+        } else {
+            None
+        };
+
+        Some(ReindexStatement {
+            token,
+            index_name: Some(index_name),
+            table_name,
+        })
+    }
+
+    /// Check if the current token is a keyword or identifier spelled `word`
+    ///
+    /// Used for cursor syntax words (CURSOR, HOLD, ...) that are not reserved.
+    fn cur_token_is_word(&self, word: &str) -> bool {
+        (self.cur_token_is(TokenType::Keyword) || self.cur_token_is(TokenType::Identifier))
+            && self.cur_token.literal.eq_ignore_ascii_case(word)
+    }
+
+    /// Check if the peek token is a keyword or identifier spelled `word`
+    fn peek_token_is_word(&self, word: &str) -> bool {
+        (self.peek_token_is(TokenType::Keyword) || self.peek_token_is(TokenType::Identifier))
+            && self.peek_token.literal.eq_ignore_ascii_case(word)
+    }
+
+    /// Parse a DECLARE CURSOR statement
+    /// Syntax: DECLARE name [NO SCROLL] CURSOR [WITH HOLD | WITHOUT HOLD] FOR query
+    fn parse_declare_cursor_statement(&mut self) -> Option<DeclareCursorStatement> {
+        let token = self.cur_token.clone();
+
+        if !self.expect_peek(TokenType::Identifier) {
+            return None;
+        }
+        let name = Identifier::new(self.cur_token.clone(), self.cur_token.literal.clone());
+        self.next_token();
+
+        if self.cur_token_is_word("NO") {
+            self.next_token();
+            if !self.cur_token_is_word("SCROLL") {
+                self.add_error(format!(
+                    "expected SCROLL after NO, got {}",
+                    self.cur_token.literal
+                ));
+                return None;
+            }
+            self.next_token();
+        } else if self.cur_token_is_word("SCROLL") {
+            self.add_error("SCROLL cursors are not supported".to_string());
+            return None;
+        }
+
+        if !self.cur_token_is_word("CURSOR") {
+            self.add_error(format!("expected CURSOR, got {}", self.cur_token.literal));
+            return None;
+        }
+        self.next_token();
+
+        let mut with_hold = false;
+        if self.cur_token_is_word("WITH") || self.cur_token_is_word("WITHOUT") {
+            with_hold = self.cur_token_is_word("WITH");
+            self.next_token();
+            if !self.cur_token_is_word("HOLD") {
+                self.add_error(format!("expected HOLD, got {}", self.cur_token.literal));
+                return None;
+            }
+            self.next_token();
+        }
+
+        if !self.cur_token_is_word("FOR") {
+            self.add_error(format!("expected FOR, got {}", self.cur_token.literal));
+            return None;
+        }
+        self.next_token();
+
+        let query = match self.cur_token.literal.to_uppercase().as_str() {
+            "SELECT" => self.parse_select_statement()?,
+            "WITH" => match self.parse_with_statement()? {
+                Statement::Select(select) => select,
+                _ => {
+                    self.add_error("cursor query must be a SELECT".to_string());
+                    return None;
+                }
+            },
+            _ => {
+                self.add_error(format!(
+                    "cursor query must be a SELECT, got {}",
+                    self.cur_token.literal
+                ));
+                return None;
+            }
+        };
+
+        Some(DeclareCursorStatement {
+            token,
+            name,
+            with_hold,
+            query: Box::new(query),
+        })
+    }
+
+    /// Parse a FETCH statement
+    /// Syntax: FETCH [NEXT | count | ALL | FORWARD [count | ALL]] [FROM | IN] name
+    fn parse_fetch_statement(&mut self) -> Option<FetchStatement> {
+        let token = self.cur_token.clone();
+        self.next_token();
+
+        if self.cur_token_is_word("FORWARD") {
+            self.next_token();
+        }
+
+        let mut count = Some(1);
+        if self.cur_token_is_word("NEXT") {
+            self.next_token();
+        } else if self.cur_token_is_keyword("ALL") {
+            count = None;
+            self.next_token();
+        } else if self.cur_token_is(TokenType::Integer) {
+            match self.cur_token.literal.parse::<u64>() {
+                Ok(n) => count = Some(n),
+                Err(_) => {
+                    self.add_error(format!("invalid FETCH count {}", self.cur_token.literal));
+                    return None;
+                }
+            }
+            self.next_token();
+        }
+
+        if self.cur_token_is_keyword("FROM") || self.cur_token_is_keyword("IN") {
+            self.next_token();
+        }
+
+        if !self.cur_token_is(TokenType::Identifier) {
+            self.add_error(format!(
+                "expected cursor name, got {}",
+                self.cur_token.literal
+            ));
+            return None;
+        }
+        let name = Identifier::new(self.cur_token.clone(), self.cur_token.literal.clone());
+
+        Some(FetchStatement { token, name, count })
+    }
+
+    /// Parse a CLOSE statement
+    /// Syntax: CLOSE name | CLOSE ALL
+    fn parse_close_cursor_statement(&mut self) -> Option<CloseCursorStatement> {
+        let token = self.cur_token.clone();
+        self.next_token();
+
+        let name = if self.cur_token_is_keyword("ALL") {
+            None
+        } else {
+            Some(Identifier::new(
+                self.cur_token.clone(),
+                self.cur_token.literal.clone(),
+            ))
+        };
+
+        Some(CloseCursorStatement { token, name })
+    }
+
+    /// Parse ATTACH [DATABASE] 'dsn' AS alias
+    fn parse_attach_database_statement(&mut self) -> Option<AttachDatabaseStatement> {
+        let token = self.cur_token.clone();
+        if self.peek_token_is_word("DATABASE") {
+            self.next_token();
+        }
+
+        if !self.expect_peek(TokenType::String) {
+            return None;
+        }
+        let literal = &self.cur_token.literal;
+        let dsn = if literal.len() >= 2 && literal.starts_with('\'') && literal.ends_with('\'') {
+            literal[1..literal.len() - 1].replace("''", "'")
+        } else {
+            literal.clone()
+        };
+
+        if !self.expect_keyword("AS") || !self.expect_peek(TokenType::Identifier) {
+            return None;
+        }
+        let alias = Identifier::new(self.cur_token.clone(), self.cur_token.literal.clone());
+
+        Some(AttachDatabaseStatement { token, dsn, alias })
+    }
+
+    /// Parse DETACH [DATABASE] alias
+    fn parse_detach_database_statement(&mut self) -> Option<DetachDatabaseStatement> {
+        let token = self.cur_token.clone();
+        if self.peek_token_is_word("DATABASE") {
+            self.next_token();
+        }
+
+        if !self.expect_peek(TokenType::Identifier) {
+            return None;
+        }
+        let alias = Identifier::new(self.cur_token.clone(), self.cur_token.literal.clone());
+
+        Some(DetachDatabaseStatement { token, alias })
+    }
+
     /// Parse an expression statement
     fn parse_expression_statement(&mut self) -> Option<ExpressionStatement> {
         let token = self.cur_token.clone();
@@ -3925,7 +4869,7 @@ impl Parser {
         })
     }
 
-    /// Parse a COPY FROM statement
+    /// Parse a COPY FROM / COPY TO statement
     pub fn parse_copy_statement(&mut self) -> Option<CopyStatement> {
         let token = self.cur_token.clone();
 
@@ -3967,15 +4911,16 @@ impl Parser {
             }
         }
 
-        // FROM
-        if self.cur_token_is(TokenType::Keyword)
-            && self.cur_token.literal.eq_ignore_ascii_case("FROM")
-        {
-            self.next_token();
+        // FROM or TO
+        let direction = if self.cur_token_is_keyword("FROM") {
+            CopyDirection::From
+        } else if self.cur_token_is_keyword("TO") {
+            CopyDirection::To
         } else {
-            self.add_error("Expected FROM".to_string());
+            self.add_error("Expected FROM or TO".to_string());
             return None;
-        }
+        };
+        self.next_token();
 
         // file_path
         if !self.cur_token_is(TokenType::String) {
@@ -3997,11 +4942,12 @@ impl Parser {
         let mut delimiter = b',';
         let mut null_string = None;
 
-        // WITH (options)
-        if self.cur_token_is(TokenType::Keyword)
-            && self.cur_token.literal.eq_ignore_ascii_case("WITH")
-        {
-            self.next_token(); // consume WITH
+        // [WITH] (options)
+        let has_with = self.cur_token_is_keyword("WITH");
+        if has_with || (self.cur_token_is(TokenType::Punctuator) && self.cur_token.literal == "(") {
+            if has_with {
+                self.next_token(); // consume WITH
+            }
 
             if self.cur_token_is(TokenType::Punctuator) && self.cur_token.literal == "(" {
                 self.next_token();
@@ -4029,7 +4975,9 @@ impl Parser {
                         if !self.cur_token_is(TokenType::Keyword)
                             && !self.cur_token_is(TokenType::Identifier)
                         {
-                            self.add_error("Expected format name (CSV or JSON)".to_string());
+                            self.add_error(
+                                "Expected format name (CSV, JSON or PARQUET)".to_string(),
+                            );
                             return None;
                         }
                         let format_name = self.cur_token.literal.to_uppercase();
@@ -4037,6 +4985,8 @@ impl Parser {
                             format = CopyFormat::Csv;
                         } else if format_name == "JSON" {
                             format = CopyFormat::Json;
+                        } else if format_name == "PARQUET" {
+                            format = CopyFormat::Parquet;
                         } else {
                             self.add_error(format!("Unsupported copy format: {}", format_name));
                             return None;
@@ -4115,6 +5065,7 @@ impl Parser {
             token,
             table_name,
             columns,
+            direction,
             file_path,
             format,
             header,
@@ -4224,6 +5175,133 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_update_from() {
+        let stmt =
+            parse_stmt("UPDATE t SET x = s.x FROM source s WHERE t.id = s.id RETURNING t.id")
+                .unwrap();
+        match stmt {
+            Statement::Update(update) => {
+                assert_eq!(update.table_name.value(), "t");
+                assert!(matches!(
+                    update.from.as_deref(),
+                    Some(Expression::TableSource(_))
+                ));
+                assert!(update.where_clause.is_some());
+                assert_eq!(update.returning.len(), 1);
+                assert_eq!(
+                    update.to_string(),
+                    "UPDATE t SET x = s.x FROM source AS s WHERE (t.id = s.id) RETURNING t.id"
+                );
+            }
+            _ => panic!("expected UpdateStatement"),
+        }
+    }
+
+    #[test]
+    fn test_parse_checkpoint() {
+        let stmt = parse_stmt("CHECKPOINT").unwrap();
+        assert!(matches!(stmt, Statement::Checkpoint(_)));
+        assert_eq!(stmt.to_string(), "CHECKPOINT");
+
+        // Still an ordinary identifier anywhere else
+        let stmt = parse_stmt("SELECT checkpoint FROM runs").unwrap();
+        assert!(matches!(stmt, Statement::Select(_)));
+    }
+
+    #[test]
+    fn test_parse_set_list_value() {
+        let stmt = parse_stmt("SET search_path = tenant1, public").unwrap();
+        match &stmt {
+            Statement::Set(set) => match &set.value {
+                Expression::ExpressionList(list) => assert_eq!(list.expressions.len(), 2),
+                other => panic!("expected a list value, got {:?}", other),
+            },
+            _ => panic!("expected SetStatement"),
+        }
+        assert_eq!(stmt.to_string(), "SET search_path = tenant1, public");
+    }
+
+    #[test]
+    fn test_parse_attach_detach_database() {
+        let stmt = parse_stmt("ATTACH DATABASE 'file:///tmp/other.db' AS other").unwrap();
+        match stmt {
+            Statement::AttachDatabase(attach) => {
+                assert_eq!(attach.dsn, "file:///tmp/other.db");
+                assert_eq!(attach.alias.value_lower, "other");
+            }
+            _ => panic!("expected AttachDatabaseStatement"),
+        }
+        assert!(matches!(
+            parse_stmt("ATTACH 'memory://x' AS x").unwrap(),
+            Statement::AttachDatabase(_)
+        ));
+        assert!(matches!(
+            parse_stmt("DETACH DATABASE other").unwrap(),
+            Statement::DetachDatabase(_)
+        ));
+        assert!(parse_stmt("ATTACH DATABASE 'memory://x'").is_none());
+    }
+
+    #[test]
+    fn test_parse_reindex() {
+        let stmt = parse_stmt("REINDEX TABLE orders").unwrap();
+        match stmt {
+            Statement::Reindex(reindex) => {
+                assert!(reindex.index_name.is_none());
+                assert_eq!(reindex.table_name.unwrap().value, "orders");
+            }
+            _ => panic!("expected ReindexStatement"),
+        }
+
+        let stmt = parse_stmt("REINDEX INDEX idx_status ON orders").unwrap();
+        assert_eq!(stmt.to_string(), "REINDEX INDEX idx_status ON orders");
+
+        assert!(parse_stmt("REINDEX orders").is_none());
+    }
+
+    #[test]
+    fn test_parse_cursor_statements() {
+        let stmt =
+            parse_stmt("DECLARE big NO SCROLL CURSOR WITH HOLD FOR SELECT * FROM t").unwrap();
+        match stmt {
+            Statement::DeclareCursor(declare) => {
+                assert_eq!(declare.name.value, "big");
+                assert!(declare.with_hold);
+            }
+            _ => panic!("expected DeclareCursorStatement"),
+        }
+        let stmt = parse_stmt("DECLARE c CURSOR FOR SELECT id FROM t").unwrap();
+        assert_eq!(stmt.to_string(), "DECLARE c CURSOR FOR SELECT id FROM t");
+        assert!(parse_stmt("DECLARE c SCROLL CURSOR FOR SELECT 1").is_none());
+        assert!(parse_stmt("DECLARE c CURSOR FOR DELETE FROM t").is_none());
+
+        for (sql, count) in [
+            ("FETCH c", Some(1)),
+            ("FETCH NEXT FROM c", Some(1)),
+            ("FETCH 50 FROM c", Some(50)),
+            ("FETCH FORWARD 5 IN c", Some(5)),
+            ("FETCH ALL FROM c", None),
+            ("FETCH FORWARD ALL c", None),
+        ] {
+            match parse_stmt(sql).unwrap() {
+                Statement::Fetch(fetch) => {
+                    assert_eq!(fetch.name.value, "c", "{}", sql);
+                    assert_eq!(fetch.count, count, "{}", sql);
+                }
+                _ => panic!("expected FetchStatement for {}", sql),
+            }
+        }
+
+        assert_eq!(parse_stmt("CLOSE c").unwrap().to_string(), "CLOSE c");
+        assert_eq!(parse_stmt("close all").unwrap().to_string(), "CLOSE ALL");
+        // "close" is still usable as a column name
+        assert!(matches!(
+            parse_stmt("SELECT close FROM prices").unwrap(),
+            Statement::Select(_)
+        ));
+    }
+
     #[test]
     fn test_parse_delete() {
         let stmt = parse_stmt("DELETE FROM users WHERE id = 1").unwrap();
@@ -4268,6 +5346,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_alter_table_auto_increment() {
+        for (input, column, display) in [
+            (
+                "ALTER TABLE t AUTO_INCREMENT = 1000",
+                None,
+                "ALTER TABLE t AUTO_INCREMENT = 1000",
+            ),
+            (
+                "ALTER TABLE t AUTO_INCREMENT 1000",
+                None,
+                "ALTER TABLE t AUTO_INCREMENT = 1000",
+            ),
+            (
+                "ALTER TABLE t ALTER COLUMN id RESTART WITH 1000",
+                Some("id"),
+                "ALTER TABLE t ALTER COLUMN id RESTART WITH 1000",
+            ),
+        ] {
+            let stmt = match parse_stmt(input).unwrap() {
+                Statement::AlterTable(s) => s,
+                _ => panic!("Expected AlterTableStatement"),
+            };
+            assert_eq!(stmt.operation, AlterTableOperation::SetAutoIncrement);
+            assert_eq!(stmt.auto_increment, Some(1000));
+            assert_eq!(stmt.column_name.as_ref().map(|c| c.value.as_str()), column);
+            assert_eq!(stmt.to_string(), display);
+        }
+    }
+
+    #[test]
+    fn test_parse_select_locking_clause() {
+        for (input, lock, display) in [
+            (
+                "SELECT * FROM t WHERE id = 1 FOR UPDATE",
+                Some(RowLockMode::Update),
+                "SELECT * FROM t WHERE (id = 1) FOR UPDATE",
+            ),
+            (
+                "SELECT id FROM t ORDER BY id LIMIT 5 FOR SHARE",
+                Some(RowLockMode::Share),
+                "SELECT id FROM t ORDER BY id ASC LIMIT 5 FOR SHARE",
+            ),
+            ("SELECT * FROM t", None, "SELECT * FROM t"),
+        ] {
+            let stmt = match parse_stmt(input).unwrap() {
+                Statement::Select(s) => s,
+                _ => panic!("Expected SelectStatement"),
+            };
+            assert_eq!(stmt.lock, lock);
+            assert_eq!(stmt.table_expr.unwrap().to_string(), "t");
+            assert_eq!(parse_stmt(input).unwrap().to_string(), display);
+        }
+        assert!(parse_stmt("SELECT * FROM t FOR DELETE").is_none());
+    }
+
     #[test]
     fn test_parse_alter_table_add_foreign_key() {
         let input =
@@ -4315,6 +5449,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_create_table_generated_column() {
+        let stmt = parse_stmt(
+            "CREATE TABLE t (id INTEGER PRIMARY KEY, doc JSON GENERATED ALWAYS AS (JSON_OBJECT('id', id)) STORED NOT NULL)",
+        )
+        .unwrap();
+        match stmt {
+            Statement::CreateTable(create) => {
+                let constraints = &create.columns[1].constraints;
+                assert!(matches!(constraints[0], ColumnConstraint::Generated(_)));
+                assert!(matches!(constraints[1], ColumnConstraint::NotNull));
+            }
+            _ => panic!("expected CreateTableStatement"),
+        }
+        assert!(parse_stmt("CREATE TABLE t (a INTEGER GENERATED AS (1))").is_none());
+    }
+
+    #[test]
+    fn test_parse_create_table_compressed_column() {
+        let stmt =
+            parse_stmt("CREATE TABLE t (id INTEGER PRIMARY KEY, body TEXT COMPRESSED NOT NULL)")
+                .unwrap();
+        match stmt {
+            Statement::CreateTable(create) => {
+                let constraints = &create.columns[1].constraints;
+                assert!(matches!(constraints[0], ColumnConstraint::Compressed));
+                assert!(matches!(constraints[1], ColumnConstraint::NotNull));
+                assert_eq!(
+                    create.columns[1].to_string(),
+                    "body TEXT COMPRESSED NOT NULL"
+                );
+            }
+            _ => panic!("expected CreateTableStatement"),
+        }
+    }
+
     #[test]
     fn test_parse_drop_table() {
         let stmt = parse_stmt("DROP TABLE IF EXISTS users").unwrap();
@@ -4377,6 +5547,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_set_and_show_transaction_isolation() {
+        let stmt = parse_stmt("SET TRANSACTION ISOLATION LEVEL READ COMMITTED").unwrap();
+        match stmt {
+            Statement::SetTransaction(set) => {
+                assert_eq!(set.isolation_level, "READ COMMITTED");
+            }
+            _ => panic!("expected SetTransactionStatement"),
+        }
+
+        let stmt = parse_stmt("SHOW TRANSACTION ISOLATION LEVEL").unwrap();
+        assert_eq!(stmt.to_string(), "SHOW TRANSACTION ISOLATION LEVEL");
+
+        // Plain SET still parses as a setting
+        let stmt = parse_stmt("SET statement_timeout = 100").unwrap();
+        assert!(matches!(stmt, Statement::Set(_)));
+        assert!(parse_stmt("SET TRANSACTION READ ONLY").is_none());
+    }
+
     #[test]
     fn test_parse_with_cte() {
         let stmt = parse_stmt("WITH temp AS (SELECT * FROM users) SELECT * FROM temp").unwrap();
@@ -4521,6 +5710,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_copy_to_statement() {
+        let stmt = parse_stmt("COPY events TO 'events.parquet' (FORMAT parquet)").unwrap();
+        if let Statement::Copy(s) = stmt {
+            assert_eq!(s.table_name.table(), "events");
+            assert_eq!(s.direction, CopyDirection::To);
+            assert_eq!(s.file_path, "events.parquet");
+            assert_eq!(s.format, CopyFormat::Parquet);
+            assert_eq!(
+                s.to_string(),
+                "COPY events TO 'events.parquet' WITH (FORMAT PARQUET, HEADER true)"
+            );
+        } else {
+            panic!("Expected CopyStatement");
+        }
+
+        let stmt = parse_stmt("COPY events FROM 'events.parquet' WITH (FORMAT PARQUET)").unwrap();
+        if let Statement::Copy(s) = stmt {
+            assert_eq!(s.direction, CopyDirection::From);
+            assert_eq!(s.format, CopyFormat::Parquet);
+        } else {
+            panic!("Expected CopyStatement");
+        }
+
+        assert!(parse_stmt("COPY events INTO 'events.parquet'").is_none());
+    }
+
     #[test]
     fn test_parse_from_first_with_select() {
         let stmt1 = parse_stmt("FROM tbl SELECT a, b").unwrap();
@@ -291,6 +291,10 @@ pub static KEYWORDS: &[&str] = &[
     "DATE",
     "TIME",
     "JSON",
+    "BLOB",
+    "BYTEA",
+    "BINARY",
+    "VARBINARY",
     "CASE",
     "CAST",
     "EXTRACT",
@@ -307,6 +311,7 @@ pub static KEYWORDS: &[&str] = &[
     "GLOB",
     "REGEXP",
     "RLIKE",
+    "SIMILAR",
     "EXISTS",
     "ALL",
     "ANY",
@@ -386,6 +391,7 @@ pub static KEYWORDS: &[&str] = &[
     "RETURNING",
     "EXPLAIN",
     "ANALYZE",
+    "REINDEX",
     "FETCH",
     "NEXT",
     "ONLY",
@@ -444,7 +450,9 @@ pub static OPERATORS: &[&str] = &[
     "@>", "<@", // JSON contains
     "?", "?|", "?&", // JSON exists
     "&", "|", "^", "~", "<<", ">>", // Bitwise operators
-    ":=", // PL/SQL assignment operator
+    "~*", "!~", "!~*", // POSIX regex match (~ doubles as bitwise NOT)
+    ":=",  // PL/SQL assignment operator
+    "::",  // PostgreSQL-style cast
 ];
 
 /// Compiled operator set for O(1) lookups
@@ -73,6 +73,9 @@ pub fn walk_statement<V: Visitor + ?Sized>(visitor: &mut V, stmt: &Statement) {
             for val in update.updates.values() {
                 visitor.visit_expression(val);
             }
+            if let Some(from) = &update.from {
+                visitor.visit_expression(from);
+            }
             if let Some(where_clause) = &update.where_clause {
                 visitor.visit_expression(where_clause);
             }
@@ -14,6 +14,7 @@
 // This file handles the logic for the Axum routes.
 // We import required Axum and Oxibase types to be used across handlers.
 
+use crate::api::output::{write_csv, OutputFormat};
 use crate::api::Database;
 use crate::server::template::create_env;
 use crate::server::AppState;
@@ -53,6 +54,15 @@ pub struct GetQueryParams {
     pub filters: HashMap<String, String>,
 }
 
+/// Collect the `col=eq.val` filters of a request as (column, value) pairs
+fn eq_filters(params: &GetQueryParams) -> Vec<(&str, &str)> {
+    params
+        .filters
+        .iter()
+        .filter_map(|(key, val)| Some((key.as_str(), val.strip_prefix("eq.")?)))
+        .collect()
+}
+
 /// Helper function to check if a table exists in the information schema
 fn table_exists(db: &Database, full_table_name: &str) -> Result<bool, String> {
     let (schema_name, table_name) = if let Some(dot_pos) = full_table_name.find('.') {
@@ -78,18 +88,7 @@ fn table_exists(db: &Database, full_table_name: &str) -> Result<bool, String> {
     Ok(count > 0)
 }
 
-/// Convert an Oxibase Value to a Serde JSON Value
-pub fn value_to_json(value: &Value) -> JsonValue {
-    match value {
-        Value::Null(_) => JsonValue::Null,
-        Value::Integer(i) => serde_json::json!(i),
-        Value::Float(f) => serde_json::json!(f),
-        Value::Text(s) => serde_json::json!(s.as_ref()),
-        Value::Boolean(b) => serde_json::json!(b),
-        Value::Timestamp(ts) => serde_json::json!(ts.format("%Y-%m-%dT%H:%M:%SZ").to_string()),
-        Value::Json(s) => serde_json::json!(s.as_ref()),
-    }
-}
+pub use crate::api::output::value_to_json;
 
 pub async fn get_table(
     Path(table): Path<String>,
@@ -123,65 +122,40 @@ pub async fn get_table(
         }
     }
 
-    // Dynamic SELECT clause
-    let select_clause = params.select.unwrap_or_else(|| "*".to_string());
+    // Dynamic SELECT list (comma-separated column names)
+    let columns: Vec<&str> = params
+        .select
+        .as_deref()
+        .map(|select| select.split(',').map(str::trim).collect())
+        .unwrap_or_default();
+    let mut query = state.db.select(&columns).from(&table);
 
     // Dynamic WHERE clause (exact match eq. operator)
-    let mut where_clauses = Vec::new();
-    let mut query_args = Vec::new();
-
-    for (key, val) in &params.filters {
-        if val.starts_with("eq.") {
-            where_clauses.push(format!("{} = ?", key));
-            let actual_val = val.trim_start_matches("eq.");
-            query_args.push(Value::text(actual_val)); // Always binding as text for now
-        }
+    for (key, val) in eq_filters(&params) {
+        query = query.where_eq(key, Value::text(val)); // Always binding as text for now
     }
 
-    let where_clause = if where_clauses.is_empty() {
-        String::new()
-    } else {
-        format!("WHERE {}", where_clauses.join(" AND "))
-    };
-
     // Dynamic ORDER BY clause
-    let order_clause = if let Some(order) = params.order {
-        let mut order_parts = Vec::new();
+    if let Some(order) = &params.order {
         for part in order.split(',') {
-            if part.ends_with(".desc") {
-                order_parts.push(format!("{} DESC", part.trim_end_matches(".desc")));
-            } else if part.ends_with(".asc") {
-                order_parts.push(format!("{} ASC", part.trim_end_matches(".asc")));
+            if let Some(column) = part.strip_suffix(".desc") {
+                query = query.order_by_desc(column);
             } else {
-                order_parts.push(format!("{} ASC", part)); // Default to ASC
+                // Default to ASC
+                query = query.order_by(part.strip_suffix(".asc").unwrap_or(part));
             }
         }
-        format!("ORDER BY {}", order_parts.join(", "))
-    } else {
-        String::new()
-    };
+    }
 
     // Dynamic LIMIT and OFFSET clauses
-    let limit_clause = if let Some(limit) = params.limit {
-        format!("LIMIT {}", limit)
-    } else {
-        String::new()
-    };
-
-    let offset_clause = if let Some(offset) = params.offset {
-        format!("OFFSET {}", offset)
-    } else {
-        String::new()
-    };
-
-    // Construct final query
-    let query = format!(
-        "SELECT {} FROM {} {} {} {} {}",
-        select_clause, table, where_clause, order_clause, limit_clause, offset_clause
-    );
-    let query = query.trim().to_string();
+    if let Some(limit) = params.limit {
+        query = query.limit(limit as u64);
+    }
+    if let Some(offset) = params.offset {
+        query = query.offset(offset as u64);
+    }
 
-    let rows_result = match state.db.query(&query, query_args) {
+    let rows_result = match query.query() {
         Ok(r) => r,
         Err(e) => {
             return (
@@ -400,14 +374,9 @@ pub async fn insert_row(
             .into_response();
     }
 
-    let mut columns = Vec::new();
-    let mut placeholders = Vec::new();
-    let mut args = Vec::new();
+    let mut query = state.db.insert_into(&table);
 
     for (k, v) in payload.iter() {
-        columns.push(k.clone());
-        placeholders.push("?");
-
         let value = match v {
             JsonValue::Null => Value::null_unknown(),
             JsonValue::Bool(b) => Value::Boolean(*b),
@@ -423,17 +392,10 @@ pub async fn insert_row(
             JsonValue::String(s) => Value::text(s),
             _ => Value::text(v.to_string()), // arrays and objects as strings for now
         };
-        args.push(value);
+        query = query.value(k, value);
     }
 
-    let query = format!(
-        "INSERT INTO {} ({}) VALUES ({})",
-        table,
-        columns.join(", "),
-        placeholders.join(", ")
-    );
-
-    match state.db.execute(&query, args) {
+    match query.execute() {
         Ok(rows_affected) => (
             StatusCode::CREATED,
             Json(serde_json::json!({ "rows_affected": rows_affected })),
@@ -489,18 +451,8 @@ pub async fn update_row(
     }
 
     // Parse filters
-    let mut where_clauses = Vec::new();
-    let mut args = Vec::new();
-
-    for (key, val) in &params.filters {
-        if val.starts_with("eq.") {
-            where_clauses.push(format!("{} = ?", key));
-            let actual_val = val.trim_start_matches("eq.");
-            args.push(Value::text(actual_val));
-        }
-    }
-
-    if where_clauses.is_empty() {
+    let filters = eq_filters(&params);
+    if filters.is_empty() {
         return (
             StatusCode::BAD_REQUEST,
             Json(serde_json::json!({ "error": "Missing exact match filter (e.g. ?id=eq.1)" })),
@@ -508,12 +460,9 @@ pub async fn update_row(
             .into_response();
     }
 
-    let mut set_clauses = Vec::new();
-    let mut set_args = Vec::new();
+    let mut query = state.db.update(&table);
 
     for (k, v) in payload.iter() {
-        set_clauses.push(format!("{} = ?", k));
-
         let value = match v {
             JsonValue::Null => Value::null_unknown(),
             JsonValue::Bool(b) => Value::Boolean(*b),
@@ -529,21 +478,13 @@ pub async fn update_row(
             JsonValue::String(s) => Value::text(s),
             _ => Value::text(v.to_string()),
         };
-        set_args.push(value);
+        query = query.set(k, value);
+    }
+    for (key, val) in filters {
+        query = query.where_eq(key, Value::text(val));
     }
 
-    // Combine args: SET args first, then WHERE args
-    set_args.extend(args);
-    let final_args = set_args;
-
-    let query = format!(
-        "UPDATE {} SET {} WHERE {}",
-        table,
-        set_clauses.join(", "),
-        where_clauses.join(" AND ")
-    );
-
-    match state.db.execute(&query, final_args) {
+    match query.execute() {
         Ok(rows_affected) => (
             StatusCode::OK,
             Json(serde_json::json!({ "rows_affected": rows_affected })),
@@ -590,18 +531,8 @@ pub async fn delete_row(
     }
 
     // Parse filters
-    let mut where_clauses = Vec::new();
-    let mut args = Vec::new();
-
-    for (key, val) in &params.filters {
-        if val.starts_with("eq.") {
-            where_clauses.push(format!("{} = ?", key));
-            let actual_val = val.trim_start_matches("eq.");
-            args.push(Value::text(actual_val));
-        }
-    }
-
-    if where_clauses.is_empty() {
+    let filters = eq_filters(&params);
+    if filters.is_empty() {
         return (
             StatusCode::BAD_REQUEST,
             Json(serde_json::json!({ "error": "Missing exact match filter (e.g. ?id=eq.1)" })),
@@ -609,13 +540,12 @@ pub async fn delete_row(
             .into_response();
     }
 
-    let query = format!(
-        "DELETE FROM {} WHERE {}",
-        table,
-        where_clauses.join(" AND ")
-    );
+    let mut query = state.db.delete_from(&table);
+    for (key, val) in filters {
+        query = query.where_eq(key, Value::text(val));
+    }
 
-    match state.db.execute(&query, args) {
+    match query.execute() {
         Ok(rows_affected) => (
             StatusCode::OK,
             Json(serde_json::json!({ "rows_affected": rows_affected })),
@@ -937,6 +867,9 @@ fn json_to_value(json: &JsonValue) -> Value {
 #[derive(Deserialize)]
 pub struct SqlRequest {
     pub query: String,
+    /// `json` or `csv`; defaults to the server's `--format`
+    #[serde(default)]
+    pub format: Option<OutputFormat>,
 }
 
 pub async fn execute_sql(
@@ -952,6 +885,14 @@ pub async fn execute_sql(
     let _guard = span.enter();
 
     let sql = payload.query.trim();
+    let format = payload.format.unwrap_or(state.format);
+    if format == OutputFormat::Table {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": "Unsupported format 'table', use json or csv" })),
+        )
+            .into_response();
+    }
 
     // Check if it's a row-returning query (SELECT, SHOW, EXPLAIN, etc)
     let upper_sql = sql.to_uppercase();
@@ -965,19 +906,15 @@ pub async fn execute_sql(
         match state.db.query(sql, ()) {
             Ok(rows_result) => {
                 let columns = rows_result.columns().to_vec();
-                let mut all_rows = Vec::new();
+                let mut rows = Vec::new();
 
                 for row_res in rows_result {
                     match row_res {
-                        Ok(row) => {
-                            let mut json_row = serde_json::Map::new();
-                            for (i, col_name) in columns.iter().enumerate() {
-                                let val =
-                                    row.get_value(i).cloned().unwrap_or(Value::null_unknown());
-                                json_row.insert(col_name.clone(), value_to_json(&val));
-                            }
-                            all_rows.push(JsonValue::Object(json_row));
-                        }
+                        Ok(row) => rows.push(
+                            (0..columns.len())
+                                .map(|i| row.get_value(i).cloned().unwrap_or(Value::null_unknown()))
+                                .collect::<Vec<_>>(),
+                        ),
                         Err(e) => {
                             return (
                                 StatusCode::INTERNAL_SERVER_ERROR,
@@ -988,14 +925,28 @@ pub async fn execute_sql(
                     }
                 }
 
-                (
-                    StatusCode::OK,
-                    Json(serde_json::json!({
-                        "columns": columns,
-                        "rows": all_rows
-                    })),
-                )
-                    .into_response()
+                if format == OutputFormat::Csv {
+                    return csv_response(&columns, &rows);
+                }
+
+                let all_rows: Vec<JsonValue> = rows
+                    .iter()
+                    .map(|row| {
+                        let json_row = columns
+                            .iter()
+                            .zip(row)
+                            .map(|(col_name, val)| (col_name.clone(), value_to_json(val)))
+                            .collect();
+                        JsonValue::Object(json_row)
+                    })
+                    .collect();
+
+                let mut body = serde_json::json!({
+                    "columns": columns,
+                    "rows": all_rows
+                });
+                add_warnings(&state.db, &mut body);
+                (StatusCode::OK, Json(body)).into_response()
             }
             Err(e) => (
                 StatusCode::BAD_REQUEST,
@@ -1005,11 +956,17 @@ pub async fn execute_sql(
         }
     } else {
         match state.db.execute(sql, ()) {
-            Ok(rows_affected) => (
-                StatusCode::OK,
-                Json(serde_json::json!({ "rows_affected": rows_affected })),
-            )
-                .into_response(),
+            Ok(rows_affected) => {
+                if format == OutputFormat::Csv {
+                    return csv_response(
+                        &["rows_affected".to_string()],
+                        &[vec![Value::Integer(rows_affected)]],
+                    );
+                }
+                let mut body = serde_json::json!({ "rows_affected": rows_affected });
+                add_warnings(&state.db, &mut body);
+                (StatusCode::OK, Json(body)).into_response()
+            }
             Err(e) => (
                 StatusCode::BAD_REQUEST,
                 Json(serde_json::json!({ "error": format!("Execution error: {}", e) })),
@@ -1019,6 +976,37 @@ pub async fn execute_sql(
     }
 }
 
+/// A `text/csv` response holding a result set
+fn csv_response(columns: &[String], rows: &[Vec<Value>]) -> axum::response::Response {
+    let mut body = Vec::new();
+    if let Err(e) = write_csv(&mut body, columns, rows) {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({ "error": e.to_string() })),
+        )
+            .into_response();
+    }
+    (
+        StatusCode::OK,
+        [(axum::http::header::CONTENT_TYPE, "text/csv; charset=utf-8")],
+        body,
+    )
+        .into_response()
+}
+
+/// Attach the statement's warnings (SHOW WARNINGS) to a response body, if any
+fn add_warnings(db: &Database, body: &mut JsonValue) {
+    let warnings: Vec<JsonValue> = db
+        .warnings()
+        .unwrap_or_default()
+        .iter()
+        .map(|w| serde_json::json!({ "level": w.level.to_string(), "message": w.message }))
+        .collect();
+    if !warnings.is_empty() {
+        body["warnings"] = JsonValue::Array(warnings);
+    }
+}
+
 pub async fn workspace_execute_sql(
     headers: HeaderMap,
     State(state): State<AppState>,
@@ -0,0 +1,160 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Connection limiting for the HTTP server
+//!
+//! [`LimitedListener`] wraps a TCP listener and caps the number of open
+//! connections. Connections over the limit are answered with
+//! `503 Service Unavailable` and closed instead of being queued.
+
+use std::io;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use axum::serve::Listener;
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt, ReadBuf};
+use tokio::net::{TcpListener, TcpStream};
+
+/// A TCP listener that rejects connections over a limit
+pub struct LimitedListener {
+    inner: TcpListener,
+    max_connections: usize,
+    active: Arc<AtomicUsize>,
+}
+
+impl LimitedListener {
+    /// Wrap `inner`, allowing at most `max_connections` open connections
+    ///
+    /// A limit of 0 means unlimited.
+    pub fn new(inner: TcpListener, max_connections: usize) -> Self {
+        Self {
+            inner,
+            max_connections,
+            active: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Reserve a connection slot, returning false if the limit is reached
+    fn try_acquire(&self) -> bool {
+        if self.max_connections == 0 {
+            self.active.fetch_add(1, Ordering::AcqRel);
+            return true;
+        }
+        self.active
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| {
+                (n < self.max_connections).then_some(n + 1)
+            })
+            .is_ok()
+    }
+}
+
+impl Listener for LimitedListener {
+    type Io = TrackedStream;
+    type Addr = SocketAddr;
+
+    async fn accept(&mut self) -> (Self::Io, Self::Addr) {
+        loop {
+            let (stream, addr) = Listener::accept(&mut self.inner).await;
+            if self.try_acquire() {
+                let stream = TrackedStream {
+                    inner: stream,
+                    active: Arc::clone(&self.active),
+                };
+                return (stream, addr);
+            }
+            tracing::warn!(
+                "rejecting connection from {}: too many connections (limit {})",
+                addr,
+                self.max_connections
+            );
+            // Reply off the accept loop so a slow client cannot stall it
+            tokio::spawn(reject(stream, self.max_connections));
+        }
+    }
+
+    fn local_addr(&self) -> io::Result<Self::Addr> {
+        self.inner.local_addr()
+    }
+}
+
+/// Answer a connection over the limit with 503 and close it
+async fn reject(mut stream: TcpStream, max_connections: usize) {
+    let body = format!("too many connections (limit {})\n", max_connections);
+    let response = format!(
+        "HTTP/1.1 503 Service Unavailable\r\n\
+         Content-Type: text/plain; charset=utf-8\r\n\
+         Content-Length: {}\r\n\
+         Retry-After: 1\r\n\
+         Connection: close\r\n\r\n{}",
+        body.len(),
+        body
+    );
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+/// An accepted connection that frees its slot when dropped
+pub struct TrackedStream {
+    inner: TcpStream,
+    active: Arc<AtomicUsize>,
+}
+
+impl Drop for TrackedStream {
+    fn drop(&mut self) {
+        self.active.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+impl AsyncRead for TrackedStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for TrackedStream {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+}
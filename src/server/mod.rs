@@ -11,26 +11,39 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::api::Database;
+use crate::api::{Database, OutputFormat};
 use axum::{routing::get, Router};
+use std::future::Future;
 use std::sync::Arc;
+use tokio::net::TcpListener;
 use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
 
 pub mod dap;
 pub mod handlers;
+pub mod limit;
 pub mod meta;
 pub mod template;
 
+/// Password helpers for user tables and login handlers
+pub use crate::common::password::{hash_password, verify_password};
+
 /// The shared application state for the Axum server.
 #[derive(Clone)]
 pub struct AppState {
     pub db: Arc<Database>,
     pub debug_controller: Arc<crate::common::debug::DebugController>,
+    /// Result format of `/api/sql` when the request does not name one
+    pub format: OutputFormat,
 }
 
 /// Creates and configures the Axum router for the Auto-API layer.
 pub fn create_router(db: Database) -> Router {
+    create_router_with_format(db, OutputFormat::Json)
+}
+
+/// Like [`create_router`], with `format` as the default result format of `/api/sql`.
+pub fn create_router_with_format(db: Database, format: OutputFormat) -> Router {
     // Initialize system schemas and tables for template rendering. We ignore errors since they might already exist
     let _ = db.execute("CREATE SCHEMA interface", ());
 
@@ -50,6 +63,7 @@ pub fn create_router(db: Database) -> Router {
     let state = AppState {
         db: Arc::new(db),
         debug_controller: Arc::new(crate::common::debug::DebugController::new()),
+        format,
     };
 
     Router::new()
@@ -113,3 +127,48 @@ pub fn create_router(db: Database) -> Router {
         .layer(TraceLayer::new_for_http())
         .layer(CorsLayer::permissive())
 }
+
+/// Serves `app` on `listener` until `shutdown` completes.
+///
+/// At most `max_connections` connections are kept open (0 means unlimited);
+/// connections over the limit receive `503 Service Unavailable`. Once
+/// `shutdown` completes, no new connections are accepted and the server
+/// returns after in-flight requests have finished.
+pub async fn serve<F>(
+    listener: TcpListener,
+    app: Router,
+    max_connections: usize,
+    shutdown: F,
+) -> std::io::Result<()>
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    let listener = limit::LimitedListener::new(listener, max_connections);
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown)
+        .await
+}
+
+/// Completes on Ctrl-C, or on SIGTERM on Unix.
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(_) => std::future::pending::<()>().await,
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
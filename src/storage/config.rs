@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -54,7 +55,8 @@ pub struct PersistenceConfig {
     /// Default: Normal
     pub sync_mode: SyncMode,
 
-    /// Time between snapshots in seconds
+    /// Time between automatic checkpoints (snapshots) in seconds
+    /// (0 = only checkpoint on WAL size or on request)
     /// Default: 300 (5 minutes)
     pub snapshot_interval: u32,
 
@@ -70,10 +72,15 @@ pub struct PersistenceConfig {
     /// Default: 65536 (64KB)
     pub wal_buffer_size: usize,
 
-    /// Maximum size of a WAL file before rotation in bytes
+    /// Maximum size of a WAL segment before rotation in bytes
     /// Default: 67108864 (64MB)
     pub wal_max_size: usize,
 
+    /// Total WAL size in bytes that triggers an automatic checkpoint
+    /// (0 = only checkpoint on the snapshot interval)
+    /// Default: 268435456 (256MB)
+    pub checkpoint_wal_size: usize,
+
     /// Number of commits to batch before syncing in SyncNormal mode
     /// Default: 100
     pub commit_batch_size: u32,
@@ -100,16 +107,17 @@ impl Default for PersistenceConfig {
         Self {
             enabled: true,
             sync_mode: SyncMode::Normal,
-            snapshot_interval: 300,         // 5 minutes
-            keep_snapshots: 5,              // Keep 5 snapshots
-            wal_flush_trigger: 32 * 1024,   // 32KB
-            wal_buffer_size: 64 * 1024,     // 64KB
-            wal_max_size: 64 * 1024 * 1024, // 64MB
-            commit_batch_size: 100,         // Batch 100 commits
-            sync_interval_ms: 10,           // 10ms minimum interval
-            wal_compression: true,          // Enable WAL compression
-            snapshot_compression: true,     // Enable snapshot compression
-            compression_threshold: 64,      // Compress entries >= 64 bytes
+            snapshot_interval: 300,                 // 5 minutes
+            keep_snapshots: 5,                      // Keep 5 snapshots
+            wal_flush_trigger: 32 * 1024,           // 32KB
+            wal_buffer_size: 64 * 1024,             // 64KB
+            wal_max_size: 64 * 1024 * 1024,         // 64MB
+            checkpoint_wal_size: 256 * 1024 * 1024, // 256MB
+            commit_batch_size: 100,                 // Batch 100 commits
+            sync_interval_ms: 10,                   // 10ms minimum interval
+            wal_compression: true,                  // Enable WAL compression
+            snapshot_compression: true,             // Enable snapshot compression
+            compression_threshold: 64,              // Compress entries >= 64 bytes
         }
     }
 }
@@ -130,6 +138,7 @@ impl PersistenceConfig {
             wal_flush_trigger: 8 * 1024,    // 8KB - flush more often
             wal_buffer_size: 32 * 1024,     // 32KB
             wal_max_size: 32 * 1024 * 1024, // 32MB - smaller files
+            checkpoint_wal_size: 128 * 1024 * 1024, // 128MB
             commit_batch_size: 1,           // No batching
             sync_interval_ms: 0,            // Immediate sync
             wal_compression: true,
@@ -145,11 +154,12 @@ impl PersistenceConfig {
             sync_mode: SyncMode::None,
             snapshot_interval: 600, // 10 minutes
             keep_snapshots: 3,
-            wal_flush_trigger: 64 * 1024,    // 64KB
-            wal_buffer_size: 128 * 1024,     // 128KB
-            wal_max_size: 128 * 1024 * 1024, // 128MB
-            commit_batch_size: 500,          // Batch more commits
-            sync_interval_ms: 100,           // Less frequent sync
+            wal_flush_trigger: 64 * 1024,           // 64KB
+            wal_buffer_size: 128 * 1024,            // 128KB
+            wal_max_size: 128 * 1024 * 1024,        // 128MB
+            checkpoint_wal_size: 512 * 1024 * 1024, // 512MB
+            commit_batch_size: 500,                 // Batch more commits
+            sync_interval_ms: 100,                  // Less frequent sync
             wal_compression: true,
             snapshot_compression: true,
             compression_threshold: 64,
@@ -168,6 +178,18 @@ impl PersistenceConfig {
         self
     }
 
+    /// Builder method to set the WAL segment size in bytes
+    pub fn with_wal_max_size(mut self, bytes: usize) -> Self {
+        self.wal_max_size = bytes;
+        self
+    }
+
+    /// Builder method to set the WAL size that triggers a checkpoint
+    pub fn with_checkpoint_wal_size(mut self, bytes: usize) -> Self {
+        self.checkpoint_wal_size = bytes;
+        self
+    }
+
     /// Builder method to set number of snapshots to keep
     pub fn with_keep_snapshots(mut self, count: u32) -> Self {
         self.keep_snapshots = count;
@@ -200,6 +222,87 @@ impl PersistenceConfig {
     }
 }
 
+/// Configuration for the background auto-vacuum task
+#[derive(Debug, Clone)]
+pub struct AutoVacuumConfig {
+    /// Whether auto-vacuum is enabled
+    /// Default: false
+    pub enabled: bool,
+
+    /// Time between dead-version checks in milliseconds
+    /// Default: 60000 (1 minute)
+    pub interval_ms: u64,
+
+    /// Fraction of dead versions (0.0-1.0) that triggers a vacuum of a table
+    /// Default: 0.2
+    pub threshold: f64,
+
+    /// Minimum number of dead versions before a table is vacuumed
+    /// Default: 50
+    pub min_dead_versions: usize,
+}
+
+impl Default for AutoVacuumConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_ms: 60_000,   // 1 minute
+            threshold: 0.2,        // 20% dead versions
+            min_dead_versions: 50, // Skip tiny tables
+        }
+    }
+}
+
+/// Parse a byte size such as `4096`, `'64KB'`, `'512MB'` or `'1.5GB'`
+///
+/// Units are binary (1KB = 1024 bytes) and case-insensitive; a trailing `B`
+/// alone or no unit means bytes. Returns None for anything else.
+pub fn parse_byte_size(text: &str) -> Option<u64> {
+    let text = text.trim();
+    let split = text
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(text.len());
+    let (number, unit) = text.split_at(split);
+    let multiplier: u64 = match unit.trim().to_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" | "kib" => 1 << 10,
+        "m" | "mb" | "mib" => 1 << 20,
+        "g" | "gb" | "gib" => 1 << 30,
+        "t" | "tb" | "tib" => 1 << 40,
+        _ => return None,
+    };
+    let number: f64 = number.parse().ok()?;
+    let bytes = number * multiplier as f64;
+    (bytes.is_finite() && bytes >= 0.0 && bytes < u64::MAX as f64).then(|| bytes.round() as u64)
+}
+
+/// Parse a duration such as `5000`, `'500ms'`, `'5s'`, `'2min'`, `'1h'`,
+/// `'1d'` or `'5 seconds'` into milliseconds
+///
+/// A number without a unit means milliseconds. Returns None for anything else.
+pub fn parse_duration_ms(text: &str) -> Option<u64> {
+    let text = text.trim();
+    let split = text
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(text.len());
+    let (number, unit) = text.split_at(split);
+    let unit_ms = match unit.trim().to_lowercase().as_str() {
+        "" | "ms" => Some(1.0),
+        "s" => Some(1_000.0),
+        "min" => Some(60_000.0),
+        "h" => Some(3_600_000.0),
+        "d" => Some(86_400_000.0),
+        _ => None,
+    };
+    match (number.parse::<f64>(), unit_ms) {
+        (Ok(n), Some(unit_ms)) => Some((n * unit_ms).round() as u64),
+        // Fall back to interval syntax ('5 seconds', '1 minute 30 seconds')
+        _ => crate::core::parse_interval(text)
+            .ok()
+            .and_then(|micros| u64::try_from(micros / 1000).ok()),
+    }
+}
+
 /// Configuration for the storage engine
 #[derive(Debug, Clone, Default)]
 pub struct Config {
@@ -210,6 +313,19 @@ pub struct Config {
     /// Configuration options for disk persistence
     /// Only used if path is Some
     pub persistence: PersistenceConfig,
+
+    /// Background auto-vacuum settings
+    pub auto_vacuum: AutoVacuumConfig,
+
+    /// Limit on the estimated memory of stored rows and index entries, in bytes
+    /// Writes past the limit fail with `Error::OutOfMemory`
+    /// Default: 0 (unlimited)
+    pub max_memory: u64,
+
+    /// Minimum age in milliseconds before a deleted row or previous version
+    /// may be reclaimed, so AS OF queries can read that far back
+    /// Default: 0 (reclaim as soon as no transaction needs it)
+    pub version_retention_ms: u64,
 }
 
 impl Config {
@@ -221,6 +337,9 @@ impl Config {
                 enabled: false,
                 ..Default::default()
             },
+            auto_vacuum: AutoVacuumConfig::default(),
+            max_memory: 0,
+            version_retention_ms: 0,
         }
     }
 
@@ -229,6 +348,9 @@ impl Config {
         Self {
             path: Some(path.into()),
             persistence: PersistenceConfig::default(),
+            auto_vacuum: AutoVacuumConfig::default(),
+            max_memory: 0,
+            version_retention_ms: 0,
         }
     }
 
@@ -242,12 +364,59 @@ impl Config {
         self.persistence = config;
         self
     }
+
+    /// Builder method to set auto-vacuum config
+    pub fn with_auto_vacuum(mut self, config: AutoVacuumConfig) -> Self {
+        self.auto_vacuum = config;
+        self
+    }
+
+    /// Builder method to set the memory limit in bytes (0 = unlimited)
+    pub fn with_max_memory(mut self, bytes: u64) -> Self {
+        self.max_memory = bytes;
+        self
+    }
+
+    /// Builder method to set the version retention window in milliseconds
+    pub fn with_version_retention_ms(mut self, ms: u64) -> Self {
+        self.version_retention_ms = ms;
+        self
+    }
+
+    /// Returns the version retention window
+    pub fn version_retention(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.version_retention_ms)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_byte_size() {
+        assert_eq!(parse_byte_size("4096"), Some(4096));
+        assert_eq!(parse_byte_size("64KB"), Some(64 * 1024));
+        assert_eq!(parse_byte_size("512 mb"), Some(512 * 1024 * 1024));
+        assert_eq!(parse_byte_size("1.5GB"), Some(3 * 512 * 1024 * 1024));
+        assert_eq!(parse_byte_size("0"), Some(0));
+        assert_eq!(parse_byte_size("12 apples"), None);
+        assert_eq!(parse_byte_size("MB"), None);
+        assert_eq!(parse_byte_size("-1MB"), None);
+    }
+
+    #[test]
+    fn test_parse_duration_ms() {
+        assert_eq!(parse_duration_ms("3600000"), Some(3_600_000));
+        assert_eq!(parse_duration_ms("500ms"), Some(500));
+        assert_eq!(parse_duration_ms("1.5s"), Some(1_500));
+        assert_eq!(parse_duration_ms("2min"), Some(120_000));
+        assert_eq!(parse_duration_ms("1h"), Some(3_600_000));
+        assert_eq!(parse_duration_ms("1d"), Some(86_400_000));
+        assert_eq!(parse_duration_ms("5 seconds"), Some(5_000));
+        assert_eq!(parse_duration_ms("forever"), None);
+    }
+
     #[test]
     fn test_sync_mode_default() {
         assert_eq!(SyncMode::default(), SyncMode::Normal);
@@ -271,6 +440,7 @@ mod tests {
         assert_eq!(config.wal_flush_trigger, 32 * 1024);
         assert_eq!(config.wal_buffer_size, 64 * 1024);
         assert_eq!(config.wal_max_size, 64 * 1024 * 1024);
+        assert_eq!(config.checkpoint_wal_size, 256 * 1024 * 1024);
         assert_eq!(config.commit_batch_size, 100);
         assert_eq!(config.sync_interval_ms, 10);
         assert!(config.wal_compression);
@@ -348,4 +518,13 @@ mod tests {
         assert!(config.is_persistent());
         assert_eq!(config.persistence.sync_mode, SyncMode::Full);
     }
+
+    #[test]
+    fn test_auto_vacuum_config_default() {
+        let config = Config::in_memory();
+        assert!(!config.auto_vacuum.enabled);
+        assert_eq!(config.auto_vacuum.interval_ms, 60_000);
+        assert_eq!(config.auto_vacuum.threshold, 0.2);
+        assert_eq!(config.auto_vacuum.min_dead_versions, 50);
+    }
 }
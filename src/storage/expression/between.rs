@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -158,6 +159,18 @@ impl BetweenExpr {
     }
 }
 
+/// Convert a bound for comparison against an integer column.
+///
+/// A fractional float bound is rounded inward (`ceil` for the lower bound,
+/// `floor` for the upper one) so `v BETWEEN 1.5 AND 3` does not match 1.
+pub(crate) fn integer_bound(bound: &Value, round: fn(f64) -> f64) -> Option<i64> {
+    match bound {
+        Value::Float(f) if f.is_nan() => None,
+        Value::Float(f) => Some(round(*f) as i64),
+        other => other.as_int64(),
+    }
+}
+
 impl Expression for BetweenExpr {
     fn evaluate(&self, row: &Row) -> Result<bool> {
         let col_idx = match self.col_index {
@@ -177,10 +190,10 @@ impl Expression for BetweenExpr {
         let in_range =
             match col_value {
                 Value::Integer(val) => {
-                    let lower = self.lower_bound.as_int64().ok_or_else(|| {
+                    let lower = integer_bound(&self.lower_bound, f64::ceil).ok_or_else(|| {
                         crate::core::Error::type_conversion("lower bound", "integer")
                     })?;
-                    let upper = self.upper_bound.as_int64().ok_or_else(|| {
+                    let upper = integer_bound(&self.upper_bound, f64::floor).ok_or_else(|| {
                         crate::core::Error::type_conversion("upper bound", "integer")
                     })?;
                     self.check_integer(*val, lower, upper)
@@ -239,9 +252,10 @@ impl Expression for BetweenExpr {
 
         let in_range = match col_value {
             Value::Integer(val) => {
-                if let (Some(lower), Some(upper)) =
-                    (self.lower_bound.as_int64(), self.upper_bound.as_int64())
-                {
+                if let (Some(lower), Some(upper)) = (
+                    integer_bound(&self.lower_bound, f64::ceil),
+                    integer_bound(&self.upper_bound, f64::floor),
+                ) {
                     self.check_integer(*val, lower, upper)
                 } else {
                     return false;
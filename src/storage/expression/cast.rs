@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -72,6 +73,8 @@ impl CastExpr {
             DataType::Boolean => cast_to_boolean(value),
             DataType::Timestamp => cast_to_timestamp(value),
             DataType::Json => cast_to_json(value),
+            DataType::Interval => cast_to_interval(value),
+            DataType::Blob => Ok(value.coerce_to_type(DataType::Blob)),
             DataType::Null => Ok(Value::null(DataType::Null)),
         }
     }
@@ -442,6 +445,8 @@ fn cast_to_string(value: &Value) -> Result<Value> {
         Value::Boolean(b) => Ok(Value::Text(Arc::from(if *b { "true" } else { "false" }))),
         Value::Timestamp(t) => Ok(Value::Text(Arc::from(t.to_rfc3339().as_str()))),
         Value::Json(j) => Ok(Value::Text(j.clone())),
+        Value::Interval(_) => Ok(Value::Text(Arc::from(value.to_string().as_str()))),
+        Value::Blob(_) => Ok(value.coerce_to_type(DataType::Text)),
         Value::Null(_) => Ok(Value::null(DataType::Text)),
     }
 }
@@ -492,6 +497,19 @@ fn cast_to_json(value: &Value) -> Result<Value> {
     }
 }
 
+fn cast_to_interval(value: &Value) -> Result<Value> {
+    match value {
+        Value::Interval(v) => Ok(Value::Interval(*v)),
+        Value::Text(s) => Ok(crate::core::parse_interval(s)
+            .map(Value::Interval)
+            .unwrap_or(Value::null(DataType::Interval))),
+        // Numbers are seconds
+        Value::Integer(v) => Ok(Value::Interval(v.saturating_mul(1_000_000))),
+        Value::Float(v) => Ok(Value::Interval((v * 1_000_000.0).round() as i64)),
+        _ => Ok(Value::null(DataType::Interval)),
+    }
+}
+
 /// Compare two values
 /// Returns: -1 if a < b, 0 if a == b, 1 if a > b
 fn compare_values(a: &Value, b: &Value) -> i32 {
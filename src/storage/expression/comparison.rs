@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -39,6 +40,8 @@ pub enum ComparisonValue {
     Text(String),
     Boolean(bool),
     Timestamp(DateTime<Utc>),
+    Interval(i64),
+    Blob(Arc<[u8]>),
 }
 
 impl ComparisonValue {
@@ -52,6 +55,8 @@ impl ComparisonValue {
             Value::Boolean(b) => ComparisonValue::Boolean(*b),
             Value::Timestamp(t) => ComparisonValue::Timestamp(*t),
             Value::Json(j) => ComparisonValue::Text(j.to_string()),
+            Value::Interval(i) => ComparisonValue::Interval(*i),
+            Value::Blob(b) => ComparisonValue::Blob(Arc::clone(b)),
         }
     }
 
@@ -64,6 +69,8 @@ impl ComparisonValue {
             ComparisonValue::Text(_) => DataType::Text,
             ComparisonValue::Boolean(_) => DataType::Boolean,
             ComparisonValue::Timestamp(_) => DataType::Timestamp,
+            ComparisonValue::Interval(_) => DataType::Interval,
+            ComparisonValue::Blob(_) => DataType::Blob,
         }
     }
 
@@ -81,6 +88,8 @@ impl ComparisonValue {
             ComparisonValue::Text(s) => Value::Text(Arc::from(s.as_str())),
             ComparisonValue::Boolean(b) => Value::Boolean(*b),
             ComparisonValue::Timestamp(t) => Value::Timestamp(*t),
+            ComparisonValue::Interval(i) => Value::Interval(*i),
+            ComparisonValue::Blob(b) => Value::Blob(Arc::clone(b)),
         }
     }
 }
@@ -210,6 +219,12 @@ impl ComparisonExpr {
     /// Compare two strings with the configured operator
     #[inline]
     fn compare_strings(&self, col_val: &str, cmp_val: &str) -> bool {
+        self.compare_bytes(col_val.as_bytes(), cmp_val.as_bytes())
+    }
+
+    /// Compare two byte strings with the configured operator
+    #[inline]
+    fn compare_bytes(&self, col_val: &[u8], cmp_val: &[u8]) -> bool {
         match self.operator {
             Operator::Eq => col_val == cmp_val,
             Operator::Ne => col_val != cmp_val,
@@ -305,6 +320,16 @@ impl Expression for ComparisonExpr {
                 Ok(self.compare_timestamps(*col_val, *cmp_val))
             }
 
+            // Interval comparisons
+            (ComparisonValue::Interval(cmp_val), Value::Interval(col_val)) => {
+                Ok(self.compare_integers(*col_val, *cmp_val))
+            }
+
+            // Blob comparisons
+            (ComparisonValue::Blob(cmp_val), Value::Blob(col_val)) => {
+                Ok(self.compare_bytes(col_val, cmp_val))
+            }
+
             // Cross-type numeric comparisons (integer vs float)
             (ComparisonValue::Integer(cmp_val), Value::Float(col_val)) => {
                 Ok(self.compare_floats(*col_val, *cmp_val as f64))
@@ -362,6 +387,12 @@ impl Expression for ComparisonExpr {
             (ComparisonValue::Timestamp(cmp_val), Value::Timestamp(col_val)) => {
                 self.compare_timestamps(*col_val, *cmp_val)
             }
+            (ComparisonValue::Interval(cmp_val), Value::Interval(col_val)) => {
+                self.compare_integers(*col_val, *cmp_val)
+            }
+            (ComparisonValue::Blob(cmp_val), Value::Blob(col_val)) => {
+                self.compare_bytes(col_val, cmp_val)
+            }
             // Cross-type numeric
             (ComparisonValue::Integer(cmp_val), Value::Float(col_val)) => {
                 self.compare_floats(*col_val, *cmp_val as f64)
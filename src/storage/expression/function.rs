@@ -314,8 +314,8 @@ impl Expression for FunctionExpr {
 /// This is useful for testing or when you need a custom predicate
 /// that doesn't fit the standard expression types.
 pub struct EvalExpr {
-    /// The evaluation function
-    eval_fn: Box<dyn Fn(&Row) -> bool + Send + Sync>,
+    /// The evaluation function (shared between clones)
+    eval_fn: Arc<dyn Fn(&Row) -> bool + Send + Sync>,
 }
 
 impl EvalExpr {
@@ -325,7 +325,7 @@ impl EvalExpr {
         F: Fn(&Row) -> bool + Send + Sync + 'static,
     {
         Self {
-            eval_fn: Box::new(f),
+            eval_fn: Arc::new(f),
         }
     }
 }
@@ -359,7 +359,13 @@ impl Expression for EvalExpr {
     }
 
     fn clone_box(&self) -> Box<dyn Expression> {
-        panic!("EvalExpr cannot be cloned")
+        Box::new(EvalExpr {
+            eval_fn: Arc::clone(&self.eval_fn),
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
     }
 }
 
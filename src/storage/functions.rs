@@ -33,7 +33,7 @@ CREATE TABLE IF NOT EXISTS system.functions (
     return_type TEXT NOT NULL,
     language TEXT NOT NULL,
     code TEXT NOT NULL,
-    UNIQUE(schema, name)
+    UNIQUE(schema, name, parameters)
 );
 "#;
 
@@ -31,6 +31,7 @@ pub mod logs;
 pub mod metrics;
 pub mod mvcc;
 pub mod procedures;
+pub mod sequences;
 pub mod statistics;
 pub mod traces;
 pub mod traits;
@@ -46,12 +47,14 @@ pub use expression::{
 pub use index::{BTree, Int64BTree};
 
 // Re-export config types
-pub use config::{Config, PersistenceConfig, SyncMode};
+pub use config::{
+    parse_byte_size, parse_duration_ms, AutoVacuumConfig, Config, PersistenceConfig, SyncMode,
+};
 
 // Re-export trait types
 pub use traits::{
-    EmptyResult, EmptyScanner, Engine, Index, MemoryResult, QueryResult, Scanner, Table,
-    TemporalType, Transaction, VecScanner,
+    ColumnMeta, EmptyResult, EmptyScanner, Engine, Index, MemoryResult, QueryResult, Scanner,
+    Table, TemporalType, Transaction, VecScanner,
 };
 
 // Re-export MVCC types
@@ -59,7 +62,7 @@ pub use mvcc::{
     get_fast_timestamp, BTreeIndex, EmptyScanner as MvccEmptyScanner, MVCCEngine, MVCCScanner,
     MVCCTable, MvccError, MvccTransaction, RangeScanner, RowVersion, SingleRowScanner,
     TransactionEngineOperations, TransactionRegistry, TransactionState, TransactionVersionStore,
-    VersionStore, VisibilityChecker, WriteSetEntry, INVALID_TRANSACTION_ID,
+    VacuumStats, VersionStore, VisibilityChecker, WriteSetEntry, INVALID_TRANSACTION_ID,
     RECOVERY_TRANSACTION_ID,
 };
 
@@ -91,6 +94,7 @@ pub use statistics::{
 // Re-export Functions types
 pub use functions::{is_functions_table, StoredFunction, CREATE_FUNCTIONS_SQL, SYS_FUNCTIONS};
 pub use procedures::{is_procedures_table, StoredProcedure, CREATE_PROCEDURES_SQL, SYS_PROCEDURES};
+pub use sequences::{is_sequences_table, CREATE_SEQUENCES_SQL, SYS_SEQUENCES};
 
 // Re-export Logs types
 pub use logs::{is_logs_table, CREATE_LOGS_SQL, SYS_LOGS};
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -34,6 +35,11 @@
 //! - Range queries on numeric data (use BTree)
 //! - Unique columns (use Hash or BTree)
 //!
+//! ## NULL handling:
+//! NULL is kept as its own state next to the distinct values, so a nullable
+//! BOOLEAN column is indexed as true/false/NULL. `IS NULL` and `IS NOT NULL`
+//! are answered from the bitmaps, and `!=` never matches NULL rows.
+//!
 //! ## Key advantage:
 //! Multi-predicate queries (WHERE a = 1 AND b = 2 AND c = 3) can be
 //! answered with O(n/64) bitwise AND operations instead of O(n) scans.
@@ -83,10 +89,12 @@ pub struct BitmapIndex {
     column_ids: Vec<i32>,
     data_types: Vec<DataType>,
     is_unique: bool,
+    nulls_not_distinct: bool,
     closed: AtomicBool,
 
     /// One bitmap per distinct value
     /// Maps Value -> RoaringTreemap of row IDs (supports full u64 range)
+    /// NULL rows are stored under a single canonical NULL key
     bitmaps: RwLock<AHashMap<Value, RoaringTreemap>>,
 
     /// Reverse mapping: row_id -> value for efficient removal
@@ -104,6 +112,7 @@ impl std::fmt::Debug for BitmapIndex {
             .field("column_names", &self.column_names)
             .field("column_ids", &self.column_ids)
             .field("is_unique", &self.is_unique)
+            .field("nulls_not_distinct", &self.nulls_not_distinct)
             .field(
                 "distinct_count",
                 &self.distinct_count.load(AtomicOrdering::Relaxed),
@@ -130,6 +139,7 @@ impl BitmapIndex {
             column_ids,
             data_types,
             is_unique,
+            nulls_not_distinct: false,
             closed: AtomicBool::new(false),
             bitmaps: RwLock::new(AHashMap::new()),
             row_to_value: RwLock::new(AHashMap::new()),
@@ -137,6 +147,12 @@ impl BitmapIndex {
         }
     }
 
+    /// Makes a unique index treat NULL keys as equal (`NULLS NOT DISTINCT`)
+    pub fn with_nulls_not_distinct(mut self, nulls_not_distinct: bool) -> Self {
+        self.nulls_not_distinct = nulls_not_distinct;
+        self
+    }
+
     /// Get the current cardinality (number of distinct values)
     pub fn cardinality(&self) -> usize {
         self.distinct_count.load(AtomicOrdering::Relaxed)
@@ -191,22 +207,43 @@ impl BitmapIndex {
 
     /// Perform NOT operation on a value
     /// Returns row IDs that do NOT match the value
-    /// Note: Requires knowing all row IDs in the table
+    /// NULL rows never match, following SQL three-valued logic
     pub fn not_value(&self, value: &Value) -> RoaringTreemap {
-        let bitmaps = self.bitmaps.read().unwrap();
-
-        // Get all row IDs (union of all bitmaps)
-        let mut all_rows = RoaringTreemap::new();
-        for bitmap in bitmaps.values() {
-            all_rows |= bitmap;
-        }
+        let result = self.non_null_rows();
 
         // Subtract the matching bitmap
+        let bitmaps = self.bitmaps.read().unwrap();
         if let Some(bitmap) = bitmaps.get(value) {
-            all_rows - bitmap
+            result - bitmap
         } else {
-            all_rows
+            result
+        }
+    }
+
+    /// Returns the rows whose indexed value is NULL
+    pub fn null_rows(&self) -> RoaringTreemap {
+        let bitmaps = self.bitmaps.read().unwrap();
+        bitmaps.get(&Self::null_key()).cloned().unwrap_or_default()
+    }
+
+    /// Returns the rows whose indexed value is not NULL
+    pub fn non_null_rows(&self) -> RoaringTreemap {
+        let bitmaps = self.bitmaps.read().unwrap();
+        let mut result = RoaringTreemap::new();
+        for (value, bitmap) in bitmaps.iter() {
+            if !value.is_null() {
+                result |= bitmap;
+            }
         }
+        result
+    }
+
+    /// The key NULL rows are stored under
+    ///
+    /// NULLs of every type compare equal but hash by type, so they are
+    /// folded into one key.
+    fn null_key() -> Value {
+        Value::Null(DataType::Null)
     }
 
     /// Convert a single value to the key used for lookup
@@ -214,14 +251,24 @@ impl BitmapIndex {
     /// For multi-column indexes, we would need to combine values
     fn value_to_key(&self, values: &[Value]) -> Value {
         if values.len() == 1 {
-            values[0].clone()
+            if values[0].is_null() {
+                Self::null_key()
+            } else {
+                values[0].clone()
+            }
         } else {
             // For multi-column bitmap index, create a composite key
             // This is less common but supported
             Value::Text(
                 values
                     .iter()
-                    .map(|v| format!("{:?}", v))
+                    .map(|v| {
+                        if v.is_null() {
+                            "NULL".to_string()
+                        } else {
+                            format!("{:?}", v)
+                        }
+                    })
                     .collect::<Vec<_>>()
                     .join("||")
                     .into(),
@@ -274,9 +321,9 @@ impl Index for BitmapIndex {
 
         // Check uniqueness constraint
         if self.is_unique {
-            // NULL values don't violate uniqueness
+            // NULL values don't violate uniqueness unless NULLS NOT DISTINCT
             let has_null = values.iter().any(|v| v.is_null());
-            if !has_null {
+            if self.nulls_not_distinct || !has_null {
                 if let Some(bitmap) = bitmaps.get(&key) {
                     // Check if there's already a row with this value (excluding current row)
                     let existing_count = if bitmap.contains(row_id_u64) {
@@ -395,6 +442,10 @@ impl Index for BitmapIndex {
         self.is_unique
     }
 
+    fn nulls_not_distinct(&self) -> bool {
+        self.nulls_not_distinct
+    }
+
     fn find(&self, values: &[Value]) -> Result<Vec<IndexEntry>> {
         if self.closed.load(AtomicOrdering::Acquire) {
             return Err(Error::IndexClosed);
@@ -481,6 +532,23 @@ impl Index for BitmapIndex {
         vec![]
     }
 
+    fn supports_null_lookup(&self) -> bool {
+        // Composite keys of multi-column indexes don't keep NULL apart
+        self.column_ids.len() == 1
+    }
+
+    fn get_row_ids_null(&self, is_null: bool) -> Vec<i64> {
+        if !self.supports_null_lookup() {
+            return Vec::new();
+        }
+        let rows = if is_null {
+            self.null_rows()
+        } else {
+            self.non_null_rows()
+        };
+        rows.iter().map(|id| id as i64).collect()
+    }
+
     fn get_filtered_row_ids(&self, expr: &dyn Expression) -> Vec<i64> {
         // For complex expressions, return all row IDs and let caller filter
         let bitmaps = self.bitmaps.read().unwrap();
@@ -845,4 +913,37 @@ mod tests {
         let result = index.remove(&[Value::Text("pending".into())], -1, 0);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_bitmap_index_null_state() {
+        let index = BitmapIndex::new(
+            "idx_active".to_string(),
+            "users".to_string(),
+            vec!["active".to_string()],
+            vec![1],
+            vec![DataType::Boolean],
+            false,
+        );
+
+        index.add(&[Value::Boolean(true)], 1, 0).unwrap();
+        index.add(&[Value::Boolean(false)], 2, 0).unwrap();
+        index.add(&[Value::Null(DataType::Boolean)], 3, 0).unwrap();
+        index.add(&[Value::Null(DataType::Null)], 4, 0).unwrap();
+
+        // NULLs of any type share one state
+        assert!(index.supports_null_lookup());
+        assert_eq!(index.get_row_ids_null(true), vec![3, 4]);
+        assert_eq!(index.get_row_ids_null(false), vec![1, 2]);
+        assert_eq!(index.cardinality(), 3);
+
+        // != doesn't match NULL rows
+        let not_true: Vec<u64> = index.not_value(&Value::Boolean(true)).iter().collect();
+        assert_eq!(not_true, vec![2]);
+
+        // Rows move between states on update and leave on removal
+        index.add(&[Value::Boolean(true)], 3, 0).unwrap();
+        index.remove(&[Value::Null(DataType::Null)], 4, 0).unwrap();
+        assert!(index.get_row_ids_null(true).is_empty());
+        assert_eq!(index.get_row_ids_null(false), vec![1, 2, 3]);
+    }
 }
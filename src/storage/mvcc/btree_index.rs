@@ -84,6 +84,8 @@ pub struct BTreeIndex {
     data_type: DataType,
     /// Whether this is a unique index
     unique: bool,
+    /// Whether NULL keys conflict in a unique index
+    nulls_not_distinct: bool,
     /// Whether the index is closed
     closed: AtomicBool,
 
@@ -107,9 +109,6 @@ pub struct BTreeIndex {
 
     /// Whether the min/max cache is valid
     cache_valid: AtomicBool,
-
-    /// Reference ID counter
-    next_ref_id: RwLock<i64>,
 }
 
 impl BTreeIndex {
@@ -129,6 +128,7 @@ impl BTreeIndex {
             column_name,
             data_type,
             unique,
+            nulls_not_distinct: false,
             closed: AtomicBool::new(false),
             sorted_values: RwLock::new(BTreeMap::new()),
             value_to_rows: RwLock::new(AHashMap::default()),
@@ -136,7 +136,6 @@ impl BTreeIndex {
             cached_min: RwLock::new(None),
             cached_max: RwLock::new(None),
             cache_valid: AtomicBool::new(true),
-            next_ref_id: RwLock::new(0),
         }
     }
 
@@ -156,6 +155,12 @@ impl BTreeIndex {
         Self::new(name, table_name, column_id, column_name, data_type, unique)
     }
 
+    /// Makes a unique index treat NULL keys as equal (`NULLS NOT DISTINCT`)
+    pub fn with_nulls_not_distinct(mut self, nulls_not_distinct: bool) -> Self {
+        self.nulls_not_distinct = nulls_not_distinct;
+        self
+    }
+
     /// Check if the index is closed
     fn check_closed(&self) -> Result<()> {
         if self.closed.load(AtomicOrdering::Acquire) {
@@ -164,13 +169,6 @@ impl BTreeIndex {
         Ok(())
     }
 
-    /// Gets the next reference ID
-    fn next_ref(&self) -> i64 {
-        let mut ref_id = self.next_ref_id.write().unwrap();
-        *ref_id += 1;
-        *ref_id
-    }
-
     /// Returns the number of unique values in the index
     pub fn unique_value_count(&self) -> usize {
         let sorted_values = self.sorted_values.read().unwrap();
@@ -440,7 +438,7 @@ impl Index for BTreeIndex {
         }
 
         // Check uniqueness constraint
-        if self.unique && !value.is_null() {
+        if self.unique && (self.nulls_not_distinct || !value.is_null()) {
             let value_to_rows = self.value_to_rows.read().unwrap();
             if let Some(rows) = value_to_rows.get(&value) {
                 // Check if any OTHER row has this value (allow updating same row)
@@ -493,10 +491,70 @@ impl Index for BTreeIndex {
 
     fn add_batch(&self, entries: &HashMap<i64, Vec<Value>>) -> Result<()> {
         self.check_closed()?;
+        if entries.is_empty() {
+            return Ok(());
+        }
 
-        for (row_id, values) in entries {
-            self.add(values, *row_id, self.next_ref())?;
+        // Same steps as add(), with the locks taken once for the whole batch
+        // and the sorted map filled in key order
+        let mut batch: Vec<(&Value, i64)> = entries
+            .iter()
+            .filter_map(|(row_id, values)| values.first().map(|value| (value, *row_id)))
+            .collect();
+        batch.sort_unstable_by(|a, b| a.0.cmp(b.0).then(a.1.cmp(&b.1)));
+
+        let mut value_to_rows = self.value_to_rows.write().unwrap();
+        let mut sorted_values = self.sorted_values.write().unwrap();
+        let mut row_to_value = self.row_to_value.write().unwrap();
+
+        for (value, row_id) in batch {
+            if let Some(old_value) = row_to_value.get(&row_id) {
+                if old_value == value {
+                    continue;
+                }
+                let old_value = old_value.clone();
+                for rows in [
+                    value_to_rows.get_mut(&old_value),
+                    sorted_values.get_mut(&old_value),
+                ]
+                .into_iter()
+                .flatten()
+                {
+                    rows.retain(|id| *id != row_id);
+                }
+                if value_to_rows
+                    .get(&old_value)
+                    .is_some_and(|rows| rows.is_empty())
+                {
+                    value_to_rows.remove(&old_value);
+                }
+                if sorted_values
+                    .get(&old_value)
+                    .is_some_and(|rows| rows.is_empty())
+                {
+                    sorted_values.remove(&old_value);
+                }
+            }
+
+            if self.unique && (self.nulls_not_distinct || !value.is_null()) {
+                if let Some(rows) = value_to_rows.get(value) {
+                    if rows.iter().any(|id| *id != row_id) {
+                        self.invalidate_cache();
+                        return Err(Error::unique_constraint(
+                            &self.name,
+                            &self.column_name,
+                            format!("{:?}", value),
+                        ));
+                    }
+                }
+            }
+
+            value_to_rows.entry(value.clone()).or_default().push(row_id);
+            sorted_values.entry(value.clone()).or_default().push(row_id);
+            row_to_value.insert(row_id, value.clone());
         }
+
+        self.invalidate_cache();
         Ok(())
     }
 
@@ -579,6 +637,10 @@ impl Index for BTreeIndex {
         self.unique
     }
 
+    fn nulls_not_distinct(&self) -> bool {
+        self.nulls_not_distinct
+    }
+
     fn find(&self, values: &[Value]) -> Result<Vec<IndexEntry>> {
         self.check_closed()?;
 
@@ -863,6 +925,18 @@ impl Index for BTreeIndex {
         sorted_values.keys().cloned().collect()
     }
 
+    fn stores_values(&self) -> bool {
+        true
+    }
+
+    fn get_row_values(&self, row_id: i64) -> Option<Vec<Value>> {
+        if self.closed.load(AtomicOrdering::Acquire) {
+            return None;
+        }
+        let row_to_value = self.row_to_value.read().unwrap();
+        row_to_value.get(&row_id).map(|value| vec![value.clone()])
+    }
+
     fn get_row_ids_ordered(
         &self,
         ascending: bool,
@@ -0,0 +1,145 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-column value compression (COMPRESSED columns)
+//!
+//! Values of a TEXT, JSON or BLOB column declared `COMPRESSED` are kept in
+//! the version store, the WAL and snapshots as a [`Value::Blob`] holding a
+//! one-byte tag followed by either the LZ4 block of the original bytes or,
+//! when compression would not save space, the bytes themselves. The table
+//! layer decodes them back to the column type before rows reach a filter or
+//! the executor, so comparisons and sorts always see the original value.
+
+use crate::core::{DataType, Row, Schema, Value};
+
+/// Tag of a value stored as-is
+const TAG_RAW: u8 = 0;
+
+/// Tag of a value stored as an LZ4 block with its size prepended
+const TAG_LZ4: u8 = 1;
+
+/// Values shorter than this are not worth compressing
+const MIN_COMPRESS_LEN: usize = 64;
+
+/// Whether a column of this type can be declared COMPRESSED
+pub fn is_compressible_type(data_type: DataType) -> bool {
+    matches!(data_type, DataType::Text | DataType::Json | DataType::Blob)
+}
+
+/// Indices of the COMPRESSED columns of a schema
+pub fn compressed_columns(schema: &Schema) -> Vec<usize> {
+    schema
+        .columns
+        .iter()
+        .enumerate()
+        .filter(|(_, col)| col.compressed)
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// Encodes a value for storage; NULLs are kept as they are
+pub fn compress_value(value: &Value) -> Value {
+    let bytes: &[u8] = match value {
+        Value::Text(s) | Value::Json(s) => s.as_bytes(),
+        Value::Blob(b) => b,
+        _ => return value.clone(),
+    };
+
+    let mut encoded = Vec::with_capacity(bytes.len() / 2 + 5);
+    if bytes.len() >= MIN_COMPRESS_LEN {
+        let compressed = lz4_flex::compress_prepend_size(bytes);
+        if compressed.len() < bytes.len() {
+            encoded.push(TAG_LZ4);
+            encoded.extend_from_slice(&compressed);
+            return Value::Blob(encoded.into());
+        }
+    }
+    encoded.push(TAG_RAW);
+    encoded.extend_from_slice(bytes);
+    Value::Blob(encoded.into())
+}
+
+/// Decodes a stored value back to the column type
+///
+/// Anything that is not an encoded blob is returned unchanged, as is a
+/// blob that fails to decode, so a damaged value is still readable.
+pub fn decompress_value(value: Value, data_type: DataType) -> Value {
+    let Value::Blob(stored) = &value else {
+        return value;
+    };
+    let bytes = match stored.split_first() {
+        Some((&TAG_RAW, rest)) => rest.to_vec(),
+        Some((&TAG_LZ4, rest)) => match lz4_flex::decompress_size_prepended(rest) {
+            Ok(bytes) => bytes,
+            Err(_) => return value,
+        },
+        _ => return value,
+    };
+
+    match data_type {
+        DataType::Text | DataType::Json => match String::from_utf8(bytes) {
+            Ok(s) if data_type == DataType::Json => Value::Json(s.into()),
+            Ok(s) => Value::Text(s.into()),
+            Err(_) => value,
+        },
+        _ => Value::Blob(bytes.into()),
+    }
+}
+
+/// Encodes the compressed columns of a row in place
+pub fn compress_row(row: &mut Row, columns: &[usize]) {
+    for &idx in columns {
+        if let Some(value) = row.get_mut(idx) {
+            *value = compress_value(value);
+        }
+    }
+}
+
+/// Decodes the compressed columns of a row in place
+pub fn decompress_row(row: &mut Row, columns: &[usize], schema: &Schema) {
+    for &idx in columns {
+        if let (Some(value), Some(col)) = (row.get_mut(idx), schema.columns.get(idx)) {
+            if matches!(value, Value::Blob(_)) {
+                *value = decompress_value(std::mem::take(value), col.data_type);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let long = Value::text("lorem ipsum ".repeat(200));
+        let encoded = compress_value(&long);
+        match &encoded {
+            Value::Blob(b) => assert!(b.len() < 2400 / 4),
+            other => panic!("expected blob, got {:?}", other),
+        }
+        assert_eq!(decompress_value(encoded, DataType::Text), long);
+
+        let short = Value::text("tiny");
+        let encoded = compress_value(&short);
+        assert_eq!(decompress_value(encoded, DataType::Text), short);
+
+        let blob = Value::Blob(vec![7u8; 500].into());
+        let encoded = compress_value(&blob);
+        assert_eq!(decompress_value(encoded, DataType::Blob), blob);
+
+        let null = Value::null(DataType::Text);
+        assert_eq!(compress_value(&null), null);
+    }
+}
@@ -26,13 +26,23 @@ use std::path::Path;
 
 use super::file_lock::FileLock;
 
-use crate::core::{DataType, Error, IsolationLevel, Result, Schema};
+use crate::core::{
+    CheckConstraint, DataType, Error, IsolationLevel, Result, Schema, ViewDefinition,
+};
 use crate::storage::config::Config;
+use crate::storage::mvcc::persistence::{
+    deserialize_check_constraints, deserialize_column_checks, deserialize_column_compressed,
+    deserialize_column_generated, deserialize_foreign_keys, deserialize_indexed_primary_key,
+    serialize_check_constraints, serialize_column_checks, serialize_column_compressed,
+    serialize_column_generated, serialize_foreign_keys, serialize_indexed_primary_key,
+    IndexMetadata,
+};
+use crate::storage::mvcc::snapshot::SnapshotCatalog;
 use crate::storage::mvcc::wal_manager::WALOperationType;
 use crate::storage::mvcc::{
-    MVCCTable, MvccTransaction, PersistenceManager, RowVersion, TransactionEngineOperations,
-    TransactionRegistry, TransactionVersionStore, VersionStore, VisibilityChecker,
-    INVALID_TRANSACTION_ID,
+    MVCCTable, MemoryTracker, MvccTransaction, PersistenceManager, RowVersion,
+    TransactionEngineOperations, TransactionRegistry, TransactionVersionStore, VacuumStats,
+    VersionStore, VisibilityChecker, INVALID_TRANSACTION_ID,
 };
 use crate::storage::traits::{Engine, Index, Table, Transaction};
 
@@ -174,81 +184,6 @@ fn read_snapshot_lsn(snapshot_dir: &std::path::Path) -> u64 {
     0
 }
 
-/// View definition storing the query that defines the view
-#[derive(Debug, Clone)]
-pub struct ViewDefinition {
-    /// View name (lowercase for case-insensitive lookup)
-    pub name: String,
-    /// Original view name (preserves case)
-    pub original_name: String,
-    /// The SQL query string that defines the view
-    pub query: String,
-}
-
-impl ViewDefinition {
-    /// Create a new view definition
-    pub fn new(name: &str, query: String) -> Self {
-        Self {
-            name: name.to_lowercase(),
-            original_name: name.to_string(),
-            query,
-        }
-    }
-
-    /// Serialize view definition to binary format for WAL
-    pub fn serialize(&self) -> Vec<u8> {
-        let mut buf = Vec::new();
-
-        // Original name (length-prefixed)
-        buf.extend_from_slice(&(self.original_name.len() as u16).to_le_bytes());
-        buf.extend_from_slice(self.original_name.as_bytes());
-
-        // Query (length-prefixed, using u32 for longer queries)
-        buf.extend_from_slice(&(self.query.len() as u32).to_le_bytes());
-        buf.extend_from_slice(self.query.as_bytes());
-
-        buf
-    }
-
-    /// Deserialize view definition from binary format
-    pub fn deserialize(data: &[u8]) -> crate::core::Result<Self> {
-        let mut pos = 0;
-
-        // Original name
-        if pos + 2 > data.len() {
-            return Err(crate::core::Error::internal(
-                "invalid view: missing name length",
-            ));
-        }
-        let name_len = u16::from_le_bytes(data[pos..pos + 2].try_into().unwrap()) as usize;
-        pos += 2;
-
-        if pos + name_len > data.len() {
-            return Err(crate::core::Error::internal("invalid view: missing name"));
-        }
-        let original_name = String::from_utf8(data[pos..pos + name_len].to_vec())
-            .map_err(|e| crate::core::Error::internal(format!("invalid view name: {}", e)))?;
-        pos += name_len;
-
-        // Query
-        if pos + 4 > data.len() {
-            return Err(crate::core::Error::internal(
-                "invalid view: missing query length",
-            ));
-        }
-        let query_len = u32::from_le_bytes(data[pos..pos + 4].try_into().unwrap()) as usize;
-        pos += 4;
-
-        if pos + query_len > data.len() {
-            return Err(crate::core::Error::internal("invalid view: missing query"));
-        }
-        let query = String::from_utf8(data[pos..pos + query_len].to_vec())
-            .map_err(|e| crate::core::Error::internal(format!("invalid view query: {}", e)))?;
-
-        Ok(Self::new(&original_name, query))
-    }
-}
-
 /// Default schema name for backward compatibility
 const DEFAULT_SCHEMA: &str = "public";
 
@@ -258,8 +193,8 @@ const DEFAULT_SCHEMA: &str = "public";
 pub struct MVCCEngine {
     /// Database path (empty for in-memory)
     path: String,
-    /// Configuration
-    config: RwLock<Config>,
+    /// Configuration (Arc-wrapped so the auto-vacuum thread sees updates)
+    config: Arc<RwLock<Config>>,
     /// Table schemas organized by schema (Arc-wrapped for safe sharing with transactions)
     /// Outer map: schema_name -> Inner map: table_name -> Schema
     pub(crate) schemas: Arc<RwLock<FxHashMap<String, FxHashMap<String, Schema>>>>,
@@ -294,6 +229,14 @@ pub struct MVCCEngine {
     loading_from_disk: Arc<AtomicBool>,
     /// File lock to prevent multiple processes from accessing the same database
     file_lock: Mutex<Option<FileLock>>,
+    /// Background auto-vacuum thread (running only while enabled in the config)
+    auto_vacuum: Mutex<Option<CleanupHandle>>,
+    /// Background checkpoint thread (file-backed databases only)
+    auto_checkpoint: Mutex<Option<CleanupHandle>>,
+    /// Held while a checkpoint writes its snapshot batch
+    checkpoint_lock: Mutex<()>,
+    /// Estimated row memory across all tables, limited by `max_memory`
+    memory: Arc<MemoryTracker>,
 }
 
 impl MVCCEngine {
@@ -320,7 +263,8 @@ impl MVCCEngine {
             } else {
                 path
             },
-            config: RwLock::new(config),
+            memory: Arc::new(MemoryTracker::new(config.max_memory)),
+            config: Arc::new(RwLock::new(config)),
             schemas: Arc::new(RwLock::new(FxHashMap::default())),
             version_stores: Arc::new(RwLock::new(FxHashMap::default())),
             registry: Arc::new(TransactionRegistry::new()),
@@ -332,6 +276,9 @@ impl MVCCEngine {
             persistence: Arc::new(persistence),
             loading_from_disk: Arc::new(AtomicBool::new(false)),
             file_lock: Mutex::new(None),
+            auto_vacuum: Mutex::new(None),
+            auto_checkpoint: Mutex::new(None),
+            checkpoint_lock: Mutex::new(()),
         }
     }
 
@@ -376,6 +323,8 @@ impl MVCCEngine {
             }
         }
 
+        self.sync_auto_vacuum();
+
         Ok(())
     }
 
@@ -399,33 +348,76 @@ impl MVCCEngine {
         // Track max source_lsn from snapshot headers for validation/fallback
         let mut max_header_lsn: u64 = 0;
 
-        // Find and load table snapshots
-        let table_dirs = match std::fs::read_dir(&snapshot_dir) {
-            Ok(entries) => entries,
-            Err(_) => return Ok(0),
+        // The newest catalog names the tables of its batch; without one
+        // (older databases) the latest file of every table directory is used
+        let catalog = match SnapshotCatalog::find_latest(&snapshot_dir) {
+            Some((path, timestamp)) => match SnapshotCatalog::read(&path) {
+                Ok(catalog) => Some((catalog, timestamp)),
+                Err(e) => {
+                    eprintln!("Warning: Failed to read snapshot catalog {:?}: {}", path, e);
+                    None
+                }
+            },
+            None => None,
+        };
+        let snapshot_files: Vec<(String, std::path::PathBuf)> = match &catalog {
+            Some((catalog, timestamp)) => catalog
+                .tables
+                .iter()
+                .map(|dir_name| {
+                    let path = snapshot_dir
+                        .join(dir_name)
+                        .join(format!("snapshot-{}.bin", timestamp));
+                    (dir_name.clone(), path)
+                })
+                .collect(),
+            None => match std::fs::read_dir(&snapshot_dir) {
+                Ok(entries) => entries
+                    .flatten()
+                    .filter(|entry| entry.file_type().map(|ft| ft.is_dir()).unwrap_or(false))
+                    .filter_map(|entry| {
+                        let dir_name = entry.file_name().to_string_lossy().to_string();
+                        self.find_latest_snapshot(&entry.path())
+                            .map(|path| (dir_name, path))
+                    })
+                    .collect(),
+                Err(_) => return Ok(0),
+            },
         };
 
-        for entry in table_dirs.flatten() {
-            if !entry.file_type().map(|ft| ft.is_dir()).unwrap_or(false) {
-                continue;
+        for (table_name, snapshot_path) in snapshot_files {
+            match self.load_table_snapshot(&table_name, &snapshot_path) {
+                Ok(source_lsn) => {
+                    // Track max source_lsn from snapshot headers (v3+ format)
+                    if source_lsn > max_header_lsn {
+                        max_header_lsn = source_lsn;
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Warning: Failed to load snapshot for {}: {}", table_name, e);
+                }
             }
+        }
 
-            let table_name = entry.file_name().to_string_lossy().to_string();
-
-            // Find the most recent snapshot file in this directory
-            if let Some(snapshot_path) = self.find_latest_snapshot(&entry.path()) {
-                match self.load_table_snapshot(&table_name, &snapshot_path) {
-                    Ok(source_lsn) => {
-                        // Track max source_lsn from snapshot headers (v3+ format)
-                        if source_lsn > max_header_lsn {
-                            max_header_lsn = source_lsn;
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!("Warning: Failed to load snapshot for {}: {}", table_name, e);
+        // Indexes are populated in one pass once the WAL has been replayed
+        if let Some((catalog, _)) = catalog {
+            for (table_name, index_meta) in &catalog.indexes {
+                if let Ok(store) = self.get_version_store(table_name) {
+                    if let Err(e) = store.create_index_from_metadata(index_meta, true) {
+                        eprintln!(
+                            "Warning: Failed to restore index {} on {}: {}",
+                            index_meta.name, table_name, e
+                        );
                     }
                 }
             }
+            let mut views = self.views.write().unwrap();
+            for (schema_name, view_def) in catalog.views {
+                views
+                    .entry(schema_name)
+                    .or_default()
+                    .insert(view_def.name.clone(), Arc::new(view_def));
+            }
         }
 
         // Use the larger of metadata LSN and max header LSN
@@ -475,11 +467,14 @@ impl MVCCEngine {
         let table_name_lower = schema.table_name_lower.clone();
 
         // Create the version store
-        let version_store = Arc::new(VersionStore::with_visibility_checker(
-            schema.table_name.clone(),
-            schema.clone(),
-            Arc::clone(&self.registry) as Arc<dyn VisibilityChecker>,
-        ));
+        let version_store = Arc::new(
+            VersionStore::with_visibility_checker(
+                schema.table_name.clone(),
+                schema.clone(),
+                Arc::clone(&self.registry) as Arc<dyn VisibilityChecker>,
+            )
+            .with_memory_tracker(Arc::clone(&self.memory)),
+        );
 
         // Load all rows from the snapshot
         reader.for_each(|_row_id, mut version| {
@@ -494,8 +489,8 @@ impl MVCCEngine {
         // Store the schema and version store
         {
             let mut schemas = self.schemas.write().unwrap();
-            let default_schema = schemas.entry(DEFAULT_SCHEMA.to_string()).or_default();
-            default_schema.insert(table_name_lower.clone(), schema);
+            let tables = schemas.entry(schema.schema_name_lower.clone()).or_default();
+            tables.insert(table_name_lower.clone(), schema);
         }
         {
             let mut stores = self.version_stores.write().unwrap();
@@ -580,11 +575,14 @@ impl MVCCEngine {
                         ring_buffers.insert(table_name, buffer);
                     } else {
                         // Create the table (version store)
-                        let version_store = Arc::new(VersionStore::with_visibility_checker(
-                            schema.table_name.clone(),
-                            schema.clone(),
-                            Arc::clone(&self.registry) as Arc<dyn VisibilityChecker>,
-                        ));
+                        let version_store = Arc::new(
+                            VersionStore::with_visibility_checker(
+                                schema.table_name.clone(),
+                                schema.clone(),
+                                Arc::clone(&self.registry) as Arc<dyn VisibilityChecker>,
+                            )
+                            .with_memory_tracker(Arc::clone(&self.memory)),
+                        );
 
                         let mut stores = self.version_stores.write().unwrap();
                         stores.insert(table_name, version_store);
@@ -813,7 +811,8 @@ impl MVCCEngine {
                 None
             };
 
-            // Check expression (length-prefixed string) - optional for backwards compatibility
+            // Check expression (length-prefixed string) - optional for backwards compatibility.
+            // Only the first CHECK is stored here; the full list follows referenced_by.
             let check_expr = if pos + 2 <= data.len() {
                 let expr_len = u16::from_le_bytes(data[pos..pos + 2].try_into().unwrap()) as usize;
                 pos += 2;
@@ -837,91 +836,35 @@ impl MVCCEngine {
                 primary_key,
                 auto_increment,
                 default_expr,
-                check_expr,
+                check_expr.map(CheckConstraint::new).into_iter().collect(),
             ));
         }
 
         let mut foreign_keys = Vec::new();
         let mut referenced_by = Vec::new();
+        let mut indexed_primary_key = None;
 
         if pos + 2 <= data.len() {
-            let fk_count = u16::from_le_bytes(data[pos..pos + 2].try_into().unwrap()) as usize;
-            pos += 2;
-            for _ in 0..fk_count {
-                if pos + 2 > data.len() {
-                    break;
-                }
-                let column_id = u16::from_le_bytes(data[pos..pos + 2].try_into().unwrap()) as usize;
-                pos += 2;
-
-                if pos + 2 > data.len() {
-                    break;
-                }
-                let ref_tbl_len =
-                    u16::from_le_bytes(data[pos..pos + 2].try_into().unwrap()) as usize;
-                pos += 2;
-                if pos + ref_tbl_len > data.len() {
-                    break;
-                }
-                let referenced_table =
-                    String::from_utf8_lossy(&data[pos..pos + ref_tbl_len]).into_owned();
-                pos += ref_tbl_len;
-
-                if pos + 2 > data.len() {
-                    break;
-                }
-                let ref_col_len =
-                    u16::from_le_bytes(data[pos..pos + 2].try_into().unwrap()) as usize;
-                pos += 2;
-                if pos + ref_col_len > data.len() {
-                    break;
-                }
-                let referenced_column_name =
-                    String::from_utf8_lossy(&data[pos..pos + ref_col_len]).into_owned();
-                pos += ref_col_len;
+            (foreign_keys, referenced_by) = deserialize_foreign_keys(data, &mut pos)?;
 
-                if pos + 2 > data.len() {
-                    break;
-                }
-                let on_delete_val = data[pos];
-                pos += 1;
-                let on_update_val = data[pos];
-                pos += 1;
+            // Full list of named CHECK constraints - optional for backwards compatibility
+            if pos + 2 <= data.len() {
+                deserialize_column_checks(data, &mut pos, &mut columns)?;
+            }
 
-                let parse_action = |val: u8| match val {
-                    0 => crate::parser::ast::ReferentialAction::Restrict,
-                    1 => crate::parser::ast::ReferentialAction::Cascade,
-                    2 => crate::parser::ast::ReferentialAction::SetNull,
-                    _ => crate::parser::ast::ReferentialAction::NoAction,
-                };
+            // Generated column expressions - optional for backwards compatibility
+            if pos + 2 <= data.len() {
+                deserialize_column_generated(data, &mut pos, &mut columns)?;
+            }
 
-                foreign_keys.push(crate::core::schema::ForeignKeyMetadata {
-                    column_id,
-                    referenced_table,
-                    referenced_column_name,
-                    on_delete: parse_action(on_delete_val),
-                    on_update: parse_action(on_update_val),
-                });
+            // Compressed columns - optional for backwards compatibility
+            if pos + 2 <= data.len() {
+                deserialize_column_compressed(data, &mut pos, &mut columns)?;
             }
 
+            // Index-enforced primary key - optional for backwards compatibility
             if pos + 2 <= data.len() {
-                let ref_by_count =
-                    u16::from_le_bytes(data[pos..pos + 2].try_into().unwrap()) as usize;
-                pos += 2;
-                for _ in 0..ref_by_count {
-                    if pos + 2 > data.len() {
-                        break;
-                    }
-                    let ref_by_len =
-                        u16::from_le_bytes(data[pos..pos + 2].try_into().unwrap()) as usize;
-                    pos += 2;
-                    if pos + ref_by_len > data.len() {
-                        break;
-                    }
-                    let ref_by = String::from_utf8_lossy(&data[pos..pos + ref_by_len]).into_owned();
-                    pos += ref_by_len;
-                    referenced_by.push(ref_by);
-                }
+                indexed_primary_key = deserialize_indexed_primary_key(data, &mut pos)?;
             }
         }
 
@@ -930,11 +873,20 @@ impl MVCCEngine {
         schema.schema_name_lower = schema.schema_name.to_lowercase();
         schema.foreign_keys = foreign_keys;
         schema.referenced_by = referenced_by;
+        schema.indexed_primary_key = indexed_primary_key;
         Ok(schema)
     }
 
     /// Closes the engine (inherent method)
     pub fn close_engine(&self) -> Result<()> {
+        // Save exact sequence values while transactions can still run, so a
+        // clean restart carries on without skipping the reserved block
+        let sequences_saved = if self.is_open() {
+            self.save_all_sequence_values()
+        } else {
+            Ok(())
+        };
+
         // Use CAS to atomically check and set closed
         if self
             .open
@@ -944,6 +896,19 @@ impl MVCCEngine {
             return Ok(()); // Already closed
         }
 
+        // Stop the background threads before tearing down version stores
+        if let Some(mut handle) = self.auto_vacuum.lock().unwrap().take() {
+            handle.stop();
+        }
+        if let Some(mut handle) = self
+            .auto_checkpoint
+            .lock()
+            .map_err(|_| Error::LockAcquisitionFailed("auto_checkpoint".to_string()))?
+            .take()
+        {
+            handle.stop();
+        }
+
         // Stop accepting new transactions
         self.registry.stop_accepting_transactions();
 
@@ -969,6 +934,51 @@ impl MVCCEngine {
             *file_lock = None;
         }
 
+        sequences_saved
+    }
+
+    /// Write a sequence's last value to the sequences system table
+    ///
+    /// Sequences created without the system table (directly through the
+    /// engine) have nothing to save.
+    fn save_sequence_value(
+        &self,
+        schema_name: &str,
+        sequence_name: &str,
+        last_value: Option<i64>,
+    ) -> Result<()> {
+        use crate::storage::sequences::{save_last_value, SYS_SEQUENCES};
+
+        let mut tx = Engine::begin_transaction(self)?;
+        if !tx
+            .list_tables()?
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(SYS_SEQUENCES))
+        {
+            return Ok(());
+        }
+        let mut table = tx.get_table(SYS_SEQUENCES)?;
+        save_last_value(table.as_mut(), schema_name, sequence_name, last_value)?;
+        tx.commit()
+    }
+
+    /// Write the exact value of every sequence, replacing the end of its
+    /// reserved block
+    fn save_all_sequence_values(&self) -> Result<()> {
+        let sequences: Vec<(String, String, Arc<crate::core::SequenceState>)> = self
+            .sequences
+            .read()
+            .map_err(|_| Error::LockAcquisitionFailed("sequences".to_string()))?
+            .iter()
+            .flat_map(|(schema_name, schema_seqs)| {
+                schema_seqs
+                    .iter()
+                    .map(move |(name, seq)| (schema_name.clone(), name.clone(), Arc::clone(seq)))
+            })
+            .collect();
+        for (schema_name, name, sequence) in sequences {
+            self.save_sequence_value(&schema_name, &name, sequence.saved_value())?;
+        }
         Ok(())
     }
 
@@ -995,10 +1005,165 @@ impl MVCCEngine {
         }
         drop(current);
 
+        self.memory.set_limit(config.max_memory);
         *self.config.write().unwrap() = config;
+        self.sync_auto_vacuum();
         Ok(())
     }
 
+    /// Returns the tracker for estimated row memory
+    pub fn memory_tracker(&self) -> &Arc<MemoryTracker> {
+        &self.memory
+    }
+
+    /// Tables and catalog objects that make up a snapshot batch
+    ///
+    /// Returns each table with its snapshot directory name, and a catalog
+    /// with those names and the index and view definitions. Telemetry ring
+    /// buffers are not persisted and are left out.
+    #[allow(clippy::type_complexity)]
+    fn snapshot_contents(&self) -> (Vec<(String, Schema, Arc<VersionStore>)>, SnapshotCatalog) {
+        let schemas = self.schemas.read().unwrap();
+        let stores = self.version_stores.read().unwrap();
+
+        let mut tables = Vec::new();
+        let mut catalog = SnapshotCatalog::default();
+        for (schema_name, schema_tables) in schemas.iter() {
+            for (table_name, schema) in schema_tables {
+                if schema_name == "system"
+                    && matches!(table_name.as_str(), "logs" | "traces" | "metrics")
+                {
+                    continue;
+                }
+                let Some(store) = stores.get(table_name) else {
+                    continue;
+                };
+                let dir_name = if schema_name == DEFAULT_SCHEMA {
+                    table_name.clone()
+                } else {
+                    format!("{}.{}", schema_name, table_name)
+                };
+                for index in store.get_all_indexes() {
+                    catalog.indexes.push((
+                        table_name.clone(),
+                        IndexMetadata::from_index(index.as_ref()),
+                    ));
+                }
+                catalog.tables.push(dir_name.clone());
+                tables.push((dir_name, schema.clone(), Arc::clone(store)));
+            }
+        }
+
+        let views = self.views.read().unwrap();
+        for (schema_name, schema_views) in views.iter() {
+            for view_def in schema_views.values() {
+                catalog
+                    .views
+                    .push((schema_name.clone(), view_def.as_ref().clone()));
+            }
+        }
+
+        (tables, catalog)
+    }
+
+    /// Write a point-in-time copy of the database to a new snapshot directory
+    ///
+    /// The copy is read through a pinned MVCC read view: writers are never
+    /// blocked, transactions that commit while it is written are left out, and
+    /// vacuum keeps the versions the view needs until it is done. Every table
+    /// is written, including the `system` tables holding sequences, functions,
+    /// procedures and triggers, together with a catalog of the index and view
+    /// definitions. `dir` must be empty or not exist; afterwards it can be
+    /// opened as a database with `file://dir`.
+    ///
+    /// Schema changes are not versioned: tables, indexes and views are taken
+    /// as they are when the view is pinned, and DDL that runs while the copy
+    /// is written is not part of it.
+    pub fn export_snapshot(&self, dir: &Path) -> Result<()> {
+        if !self.is_open() {
+            return Err(Error::EngineNotOpen);
+        }
+        if std::fs::read_dir(dir).is_ok_and(|mut entries| entries.next().is_some()) {
+            return Err(Error::invalid_argument(format!(
+                "backup directory '{}' is not empty",
+                dir.display()
+            )));
+        }
+
+        // Pin a read view; its begin sequence is the snapshot point
+        let (pin_txn_id, snapshot_seq) = self.registry.begin_transaction();
+        if pin_txn_id == INVALID_TRANSACTION_ID {
+            return Err(Error::EngineNotOpen);
+        }
+        self.registry
+            .set_transaction_isolation_level(pin_txn_id, IsolationLevel::SnapshotIsolation);
+        self.registry.wait_for_commits_before(snapshot_seq);
+
+        // The catalog is taken at the same point as the data
+        let (tables, catalog) = self.snapshot_contents();
+        let result = self.write_snapshot_files(dir, &tables, &catalog, snapshot_seq);
+
+        self.registry.abort_transaction(pin_txn_id);
+        self.registry.remove_transaction_isolation_level(pin_txn_id);
+
+        if result.is_err() {
+            let _ = std::fs::remove_dir_all(dir.join("snapshots"));
+        }
+        result
+    }
+
+    /// Write one snapshot file per table and their catalog, as of a commit sequence
+    fn write_snapshot_files(
+        &self,
+        dir: &Path,
+        tables: &[(String, Schema, Arc<VersionStore>)],
+        catalog: &SnapshotCatalog,
+        commit_seq: i64,
+    ) -> Result<()> {
+        let snapshot_dir = dir.join("snapshots");
+        std::fs::create_dir_all(&snapshot_dir)
+            .map_err(|e| Error::internal(format!("failed to create snapshot directory: {}", e)))?;
+
+        let timestamp = chrono::Utc::now().format("%Y%m%d-%H%M%S%.3f").to_string();
+        for (dir_name, schema, store) in tables {
+            let table_dir = snapshot_dir.join(dir_name);
+            std::fs::create_dir_all(&table_dir).map_err(|e| {
+                Error::internal(format!(
+                    "failed to create snapshot directory for {}: {}",
+                    schema.table_name, e
+                ))
+            })?;
+
+            let path = table_dir.join(format!("snapshot-{}.bin", timestamp));
+            let mut writer = super::snapshot::SnapshotWriter::with_source_lsn(&path, 0)?;
+            writer.write_schema(schema)?;
+
+            let mut write_error = None;
+            store.for_each_committed_version_with_cutoff(
+                |_row_id, version| {
+                    let mut snapshot_version = version.clone();
+                    snapshot_version.txn_id = -1; // Mark as snapshot version
+                    match writer.append_row(&snapshot_version) {
+                        Ok(()) => true,
+                        Err(e) => {
+                            write_error = Some(e);
+                            false
+                        }
+                    }
+                },
+                commit_seq,
+            );
+            if let Some(e) = write_error {
+                writer.fail();
+                return Err(e);
+            }
+            writer.finalize()?;
+        }
+
+        catalog.write(&snapshot_dir.join(SnapshotCatalog::file_name(&timestamp)))?;
+        write_snapshot_metadata(&snapshot_dir.join("snapshot_meta.bin"), 0)
+    }
+
     /// Returns the transaction registry
     pub fn registry(&self) -> Arc<TransactionRegistry> {
         Arc::clone(&self.registry)
@@ -1211,13 +1376,14 @@ impl MVCCEngine {
                 }
 
                 // Read check_expr_present
-                let mut check_expr_opt = None;
+                let mut checks_opt = None;
                 if pos < data.len() {
                     let ce_present = data[pos] != 0;
                     pos += 1;
                     if ce_present && pos < data.len() {
                         let ce_is_some = data[pos] != 0;
                         pos += 1;
+                        let mut checks = Vec::new();
                         if ce_is_some {
                             if pos + 2 > data.len() {
                                 return Err(Error::internal(
@@ -1236,11 +1402,14 @@ impl MVCCEngine {
                                 .map_err(|e| {
                                     Error::internal(format!("invalid check_expr string: {}", e))
                                 })?;
-                            check_expr_opt = Some(Some(expr));
-                            let _ = ce_len; // pos increment would be unused
-                        } else {
-                            check_expr_opt = Some(None);
+                            pos += ce_len;
+                            checks.push(CheckConstraint::new(expr));
                         }
+                        // Newer records carry the full list of named constraints
+                        if pos < data.len() {
+                            checks = deserialize_check_constraints(data, &mut pos)?;
+                        }
+                        checks_opt = Some(checks);
                     }
                 }
 
@@ -1251,7 +1420,7 @@ impl MVCCEngine {
                     data_type,
                     nullable,
                     auto_increment_opt,
-                    check_expr_opt,
+                    checks_opt,
                 )?;
             }
             5 => {
@@ -1280,6 +1449,22 @@ impl MVCCEngine {
                 // Apply the RENAME TABLE using engine method
                 self.rename_table(&table_name, &new_table_name)?;
             }
+            6 => {
+                // SetAutoIncrement
+                if pos + 8 > data.len() {
+                    return Err(Error::internal(
+                        "invalid SetAutoIncrement data: missing value",
+                    ));
+                }
+                let next_id = i64::from_le_bytes(data[pos..pos + 8].try_into().unwrap());
+
+                // Rows replayed after this entry raise the counter again, so
+                // the value is applied without re-validating it
+                let stores = self.version_stores.read().unwrap();
+                if let Some(store) = stores.get(&table_name.to_lowercase()) {
+                    store.reset_auto_increment_counter(next_id - 1);
+                }
+            }
             _ => {
                 return Err(Error::internal(format!(
                     "unknown ALTER TABLE operation type: {}",
@@ -1352,53 +1537,28 @@ impl MVCCEngine {
             }
 
             // Check expression (length-prefixed string, 0 length if None)
-            if let Some(ref check_expr) = col.check_expr {
-                buf.extend_from_slice(&(check_expr.len() as u16).to_le_bytes());
-                buf.extend_from_slice(check_expr.as_bytes());
+            if let Some(check) = col.checks.first() {
+                buf.extend_from_slice(&(check.expr.len() as u16).to_le_bytes());
+                buf.extend_from_slice(check.expr.as_bytes());
             } else {
                 buf.extend_from_slice(&0u16.to_le_bytes());
             }
         }
 
-        // Foreign keys count
-        buf.extend_from_slice(&(schema.foreign_keys.len() as u16).to_le_bytes());
-
-        // Foreign keys
-        for fk in &schema.foreign_keys {
-            buf.extend_from_slice(&(fk.column_id as u16).to_le_bytes());
-
-            buf.extend_from_slice(&(fk.referenced_table.len() as u16).to_le_bytes());
-            buf.extend_from_slice(fk.referenced_table.as_bytes());
-
-            buf.extend_from_slice(&(fk.referenced_column_name.len() as u16).to_le_bytes());
-            buf.extend_from_slice(fk.referenced_column_name.as_bytes());
+        // Foreign keys and the tables referencing this one
+        serialize_foreign_keys(&mut buf, &schema.foreign_keys, &schema.referenced_by);
 
-            // Map ReferentialAction to u8
-            let on_delete_val = match fk.on_delete {
-                crate::parser::ast::ReferentialAction::Restrict => 0,
-                crate::parser::ast::ReferentialAction::Cascade => 1,
-                crate::parser::ast::ReferentialAction::SetNull => 2,
-                crate::parser::ast::ReferentialAction::NoAction => 3,
-            };
-            buf.push(on_delete_val);
+        // Named CHECK constraints (all of them, per column)
+        serialize_column_checks(&mut buf, &schema.columns);
 
-            let on_update_val = match fk.on_update {
-                crate::parser::ast::ReferentialAction::Restrict => 0,
-                crate::parser::ast::ReferentialAction::Cascade => 1,
-                crate::parser::ast::ReferentialAction::SetNull => 2,
-                crate::parser::ast::ReferentialAction::NoAction => 3,
-            };
-            buf.push(on_update_val);
-        }
+        // Generated column expressions
+        serialize_column_generated(&mut buf, &schema.columns);
 
-        // Referenced by count
-        buf.extend_from_slice(&(schema.referenced_by.len() as u16).to_le_bytes());
+        // Compressed columns
+        serialize_column_compressed(&mut buf, &schema.columns);
 
-        // Referenced by
-        for ref_by in &schema.referenced_by {
-            buf.extend_from_slice(&(ref_by.len() as u16).to_le_bytes());
-            buf.extend_from_slice(ref_by.as_bytes());
-        }
+        // Index-enforced primary key
+        serialize_indexed_primary_key(&mut buf, schema.indexed_primary_key.as_ref());
 
         buf
     }
@@ -1450,11 +1610,14 @@ impl MVCCEngine {
             }
         } else {
             // Create version store for this table
-            let version_store = Arc::new(VersionStore::with_visibility_checker(
-                schema.table_name.clone(),
-                schema.clone(),
-                Arc::clone(&self.registry) as Arc<dyn VisibilityChecker>,
-            ));
+            let version_store = Arc::new(
+                VersionStore::with_visibility_checker(
+                    schema.table_name.clone(),
+                    schema.clone(),
+                    Arc::clone(&self.registry) as Arc<dyn VisibilityChecker>,
+                )
+                .with_memory_tracker(Arc::clone(&self.memory)),
+            );
             let mut stores = self.version_stores.write().unwrap();
             stores.insert(table_name.clone(), version_store);
         }
@@ -1538,7 +1701,11 @@ impl MVCCEngine {
             return Err(Error::EngineNotOpen);
         }
 
-        let table_name = name.to_lowercase();
+        // Stores are keyed by the table name without its schema
+        let table_name = match name.find('.') {
+            Some(pos) => name[pos + 1..].to_lowercase(),
+            None => name.to_lowercase(),
+        };
 
         let stores = self.version_stores.read().unwrap();
         stores.get(&table_name).cloned().ok_or(Error::TableNotFound)
@@ -1676,7 +1843,7 @@ impl MVCCEngine {
 
         // Check if column is primary key
         if let Some((_, col)) = schema.find_column(column_name) {
-            if col.primary_key {
+            if col.primary_key || schema.is_primary_key_column(column_name) {
                 return Err(Error::CannotDropPrimaryKey);
             }
         } else {
@@ -1744,7 +1911,7 @@ impl MVCCEngine {
         data_type: DataType,
         nullable: bool,
         auto_increment: Option<bool>,
-        check_expr: Option<Option<String>>,
+        checks: Option<Vec<CheckConstraint>>,
     ) -> Result<()> {
         if !self.is_open() {
             return Err(Error::EngineNotOpen);
@@ -1772,7 +1939,7 @@ impl MVCCEngine {
             Some(data_type),
             Some(nullable),
             auto_increment,
-            check_expr.clone(),
+            checks.clone(),
         )?;
 
         // Also update version store schema
@@ -1784,13 +1951,48 @@ impl MVCCEngine {
                 Some(data_type),
                 Some(nullable),
                 auto_increment,
-                check_expr,
+                checks,
             )?;
         }
 
         Ok(())
     }
 
+    /// Sets the next ID generated for a table's AUTO_INCREMENT column
+    ///
+    /// `next_id` must be greater than every committed row ID of the table.
+    pub fn set_auto_increment(&self, table_name: &str, next_id: i64) -> Result<()> {
+        if !self.is_open() {
+            return Err(Error::EngineNotOpen);
+        }
+        if next_id < 1 {
+            return Err(Error::InvalidArgumentMessage(format!(
+                "AUTO_INCREMENT value must be positive, got {}",
+                next_id
+            )));
+        }
+
+        let stores = self.version_stores.read().unwrap();
+        let store = stores
+            .get(&table_name.to_lowercase())
+            .ok_or(Error::TableNotFound)?;
+
+        let mut max_id = 0;
+        store.for_each_committed_version(|row_id, _| {
+            max_id = max_id.max(row_id);
+            true
+        });
+        if next_id <= max_id {
+            return Err(Error::InvalidArgumentMessage(format!(
+                "AUTO_INCREMENT value {} must be greater than the largest existing id {}",
+                next_id, max_id
+            )));
+        }
+
+        store.reset_auto_increment_counter(next_id - 1);
+        Ok(())
+    }
+
     /// Renames a table
     pub fn rename_table(&self, old_name: &str, new_name: &str) -> Result<()> {
         if !self.is_open() {
@@ -1935,8 +2137,16 @@ impl MVCCEngine {
         Ok(())
     }
 
-    /// Drop a view
-    pub fn drop_view(&self, schema_name: &str, name: &str, if_exists: bool) -> Result<()> {
+    /// Create a view, replacing the definition of an existing view with the same name
+    ///
+    /// The old definition is swapped out under the views lock, so readers see
+    /// either the old or the new query, never a missing view.
+    pub fn create_or_replace_view(
+        &self,
+        schema_name: &str,
+        name: &str,
+        query: String,
+    ) -> Result<()> {
         use crate::storage::mvcc::wal_manager::WALOperationType;
 
         if !self.is_open() {
@@ -1945,31 +2155,79 @@ impl MVCCEngine {
 
         let schema_lower = schema_name.to_lowercase();
         let name_lower = name.to_lowercase();
-        let mut views = self.views.write().unwrap();
-
-        let removed = if let Some(schema_views) = views.get_mut(&schema_lower) {
-            schema_views.remove(&name_lower)
-        } else {
-            None
-        };
 
-        if removed.is_none() {
-            if if_exists {
-                return Ok(());
+        // Check if a table with the same name exists
+        {
+            let schemas = self.schemas.read().unwrap();
+            if schemas
+                .get(&schema_lower)
+                .is_some_and(|tables| tables.contains_key(&name_lower))
+            {
+                return Err(Error::internal(format!(
+                    "cannot create view '{}': a table with the same name exists in schema '{}'",
+                    name, schema_name
+                )));
             }
-            return Err(Error::ViewNotFound(name.to_string()));
         }
 
-        // Release the lock before recording to WAL
-        drop(views);
-
-        // Record to WAL for persistence
+        let view_def = Arc::new(ViewDefinition::new(name, query));
+        self.views
+            .write()
+            .unwrap()
+            .entry(schema_lower.clone())
+            .or_default()
+            .insert(name_lower.clone(), Arc::clone(&view_def));
+
+        // Replaying CreateView overwrites an existing definition
         let full_name = if schema_lower == DEFAULT_SCHEMA {
-            name_lower.clone()
+            name_lower
         } else {
             format!("{}.{}", schema_lower, name_lower)
         };
-        self.record_ddl(&full_name, WALOperationType::DropView, name.as_bytes());
+        self.record_ddl(
+            &full_name,
+            WALOperationType::CreateView,
+            &view_def.serialize(),
+        );
+
+        Ok(())
+    }
+
+    /// Drop a view
+    pub fn drop_view(&self, schema_name: &str, name: &str, if_exists: bool) -> Result<()> {
+        use crate::storage::mvcc::wal_manager::WALOperationType;
+
+        if !self.is_open() {
+            return Err(Error::EngineNotOpen);
+        }
+
+        let schema_lower = schema_name.to_lowercase();
+        let name_lower = name.to_lowercase();
+        let mut views = self.views.write().unwrap();
+
+        let removed = if let Some(schema_views) = views.get_mut(&schema_lower) {
+            schema_views.remove(&name_lower)
+        } else {
+            None
+        };
+
+        if removed.is_none() {
+            if if_exists {
+                return Ok(());
+            }
+            return Err(Error::ViewNotFound(name.to_string()));
+        }
+
+        // Release the lock before recording to WAL
+        drop(views);
+
+        // Record to WAL for persistence
+        let full_name = if schema_lower == DEFAULT_SCHEMA {
+            name_lower.clone()
+        } else {
+            format!("{}.{}", schema_lower, name_lower)
+        };
+        self.record_ddl(&full_name, WALOperationType::DropView, name.as_bytes());
 
         Ok(())
     }
@@ -2035,6 +2293,15 @@ impl MVCCEngine {
     }
 }
 
+impl dyn Engine {
+    /// Downcast to the MVCC engine through [`Engine::as_any`]
+    ///
+    /// Returns `None` for custom engines that do not expose an `MVCCEngine`.
+    pub fn as_mvcc(&self) -> Option<&MVCCEngine> {
+        self.as_any()?.downcast_ref()
+    }
+}
+
 impl Engine for MVCCEngine {
     fn open(&mut self) -> Result<()> {
         MVCCEngine::open_engine(self)
@@ -2044,6 +2311,10 @@ impl Engine for MVCCEngine {
         MVCCEngine::close_engine(self)
     }
 
+    fn close_engine(&self) -> Result<()> {
+        MVCCEngine::close_engine(self)
+    }
+
     fn begin_transaction(&self) -> Result<Box<dyn Transaction>> {
         self.begin_transaction_with_level(self.get_isolation_level())
     }
@@ -2275,6 +2546,12 @@ impl Engine for MVCCEngine {
             _ => return Ok(()), // No persistence, nothing to snapshot
         };
 
+        // One checkpoint at a time: each removes what the batch before it left
+        let _checkpoint = self
+            .checkpoint_lock
+            .lock()
+            .map_err(|_| Error::LockAcquisitionFailed("checkpoint".to_string()))?;
+
         // CRITICAL: Create checkpoint and capture the LSN atomically.
         // create_checkpoint() flushes and syncs all pending WAL entries, then returns
         // the LSN at the exact checkpoint point. This prevents the race condition where:
@@ -2301,15 +2578,15 @@ impl Engine for MVCCEngine {
             )));
         }
 
-        // Get all table schemas and version stores
-        let schemas = self.schemas.read().unwrap();
-        let stores = self.version_stores.read().unwrap();
+        // Get all tables, with the indexes and views that go with them
+        let (tables, catalog) = self.snapshot_contents();
 
         // ATOMIC SNAPSHOT STRATEGY:
-        // 1. Write all snapshots to .tmp files first
-        // 2. After ALL succeed, rename all .tmp files to final names
+        // 1. Write all snapshots and the catalog to .tmp files first
+        // 2. After ALL succeed, rename the table files, then the catalog
         // 3. If any fails, cleanup all .tmp files
-        // This ensures we never have a partially complete snapshot set
+        // This ensures we never have a partially complete snapshot set: the
+        // newest catalog only ever names a batch whose files all exist
 
         // Collect (temp_path, final_path, table_name) for atomic rename
         let mut pending_snapshots: Vec<(std::path::PathBuf, std::path::PathBuf, String)> =
@@ -2320,141 +2597,145 @@ impl Engine for MVCCEngine {
         let timestamp = chrono::Utc::now().format("%Y%m%d-%H%M%S%.3f").to_string();
 
         // Phase 1: Write all snapshots to temp files
-        if let Some(default_schema) = schemas.get(DEFAULT_SCHEMA) {
-            for (table_name, schema) in default_schema.iter() {
-                if let Some(store) = stores.get(table_name) {
-                    // Create table-specific snapshot directory
-                    let table_snapshot_dir = snapshot_dir.join(table_name);
-                    if let Err(e) = std::fs::create_dir_all(&table_snapshot_dir) {
+        for (table_name, schema, store) in &tables {
+            // Create table-specific snapshot directory
+            let table_snapshot_dir = snapshot_dir.join(table_name);
+            if let Err(e) = std::fs::create_dir_all(&table_snapshot_dir) {
+                eprintln!(
+                    "Warning: Failed to create snapshot directory for {}: {}",
+                    table_name, e
+                );
+                all_succeeded = false;
+                break;
+            }
+
+            // Write to .tmp file first, will rename after all succeed
+            let final_path = table_snapshot_dir.join(format!("snapshot-{}.bin", timestamp));
+            let temp_path = table_snapshot_dir.join(format!("snapshot-{}.bin.tmp", timestamp));
+
+            // Create snapshot writer for temp file with captured LSN
+            let mut writer =
+                match super::snapshot::SnapshotWriter::with_source_lsn(&temp_path, snapshot_lsn) {
+                    Ok(w) => w,
+                    Err(e) => {
                         eprintln!(
-                            "Warning: Failed to create snapshot directory for {}: {}",
+                            "Warning: Failed to create snapshot writer for {}: {}",
                             table_name, e
                         );
                         all_succeeded = false;
                         break;
                     }
+                };
 
-                    // Write to .tmp file first, will rename after all succeed
-                    let final_path = table_snapshot_dir.join(format!("snapshot-{}.bin", timestamp));
-                    let temp_path =
-                        table_snapshot_dir.join(format!("snapshot-{}.bin.tmp", timestamp));
-
-                    // Create snapshot writer for temp file with captured LSN
-                    let mut writer = match super::snapshot::SnapshotWriter::with_source_lsn(
-                        &temp_path,
-                        snapshot_lsn,
-                    ) {
-                        Ok(w) => w,
-                        Err(e) => {
-                            eprintln!(
-                                "Warning: Failed to create snapshot writer for {}: {}",
-                                table_name, e
-                            );
-                            all_succeeded = false;
-                            break;
-                        }
-                    };
+            // Write schema
+            if let Err(e) = writer.write_schema(schema) {
+                eprintln!("Warning: Failed to write schema for {}: {}", table_name, e);
+                writer.fail();
+                all_succeeded = false;
+                break;
+            }
 
-                    // Write schema
-                    if let Err(e) = writer.write_schema(schema) {
-                        eprintln!("Warning: Failed to write schema for {}: {}", table_name, e);
-                        writer.fail();
-                        all_succeeded = false;
-                        break;
+            // Write all committed versions using commit sequence cutoff for consistency.
+            // This ensures that transactions that commit after the checkpoint but before
+            // iteration completes are excluded from the snapshot, maintaining consistency
+            // between the WAL checkpoint and the snapshot contents.
+            let mut write_error = false;
+            store.for_each_committed_version_with_cutoff(
+                |_row_id, version| {
+                    // Clone version with snapshot TxnID marker
+                    let mut snapshot_version = version.clone();
+                    snapshot_version.txn_id = -1; // Mark as snapshot version
+
+                    if let Err(e) = writer.append_row(&snapshot_version) {
+                        eprintln!(
+                            "Warning: Failed to write row {} to snapshot: {}",
+                            _row_id, e
+                        );
+                        write_error = true;
+                        return false; // Stop iteration
                     }
+                    true
+                },
+                snapshot_commit_seq,
+            );
 
-                    // Write all committed versions using commit sequence cutoff for consistency.
-                    // This ensures that transactions that commit after the checkpoint but before
-                    // iteration completes are excluded from the snapshot, maintaining consistency
-                    // between the WAL checkpoint and the snapshot contents.
-                    let mut write_error = false;
-                    store.for_each_committed_version_with_cutoff(
-                        |_row_id, version| {
-                            // Clone version with snapshot TxnID marker
-                            let mut snapshot_version = version.clone();
-                            snapshot_version.txn_id = -1; // Mark as snapshot version
-
-                            if let Err(e) = writer.append_row(&snapshot_version) {
-                                eprintln!(
-                                    "Warning: Failed to write row {} to snapshot: {}",
-                                    _row_id, e
-                                );
-                                write_error = true;
-                                return false; // Stop iteration
-                            }
-                            true
-                        },
-                        snapshot_commit_seq,
-                    );
+            if write_error {
+                writer.fail();
+                all_succeeded = false;
+                break;
+            }
 
-                    if write_error {
-                        writer.fail();
-                        all_succeeded = false;
-                        break;
-                    }
+            // Finalize the snapshot (writes CRC32, syncs to disk)
+            if let Err(e) = writer.finalize() {
+                eprintln!(
+                    "Warning: Failed to finalize snapshot for {}: {}",
+                    table_name, e
+                );
+                writer.fail();
+                all_succeeded = false;
+                break;
+            }
 
-                    // Finalize the snapshot (writes CRC32, syncs to disk)
-                    if let Err(e) = writer.finalize() {
-                        eprintln!(
-                            "Warning: Failed to finalize snapshot for {}: {}",
-                            table_name, e
-                        );
-                        writer.fail();
-                        all_succeeded = false;
-                        break;
-                    }
+            // Track this temp file for later rename
+            pending_snapshots.push((temp_path, final_path, table_name.clone()));
+        }
 
-                    // Track this temp file for later rename
-                    pending_snapshots.push((temp_path, final_path, table_name.clone()));
-                }
+        // The catalog goes last, so it is only renamed after every table file
+        let catalog_path = snapshot_dir.join(SnapshotCatalog::file_name(&timestamp));
+        let catalog_temp_path = catalog_path.with_extension("bin.tmp");
+        if all_succeeded {
+            if let Err(e) = catalog.write(&catalog_temp_path) {
+                eprintln!("Warning: Failed to write snapshot catalog: {}", e);
+                all_succeeded = false;
+            } else {
+                pending_snapshots.push((catalog_temp_path, catalog_path, "catalog".to_string()));
             }
+        }
 
-            // Phase 2: If all snapshots succeeded, rename all temp files atomically
-            // Track successfully renamed files for rollback on failure
-            let mut renamed_successfully: Vec<(std::path::PathBuf, std::path::PathBuf)> =
-                Vec::new();
+        // Phase 2: If all snapshots succeeded, rename all temp files atomically
+        // Track successfully renamed files for rollback on failure
+        let mut renamed_successfully: Vec<(std::path::PathBuf, std::path::PathBuf)> = Vec::new();
 
-            if all_succeeded {
-                // Collect unique directories that need syncing
-                let mut dirs_to_sync: std::collections::HashSet<std::path::PathBuf> =
-                    std::collections::HashSet::new();
+        if all_succeeded {
+            // Collect unique directories that need syncing
+            let mut dirs_to_sync: std::collections::HashSet<std::path::PathBuf> =
+                std::collections::HashSet::new();
 
-                for (temp_path, final_path, table_name) in &pending_snapshots {
-                    if let Err(e) = std::fs::rename(temp_path, final_path) {
-                        eprintln!(
-                            "Warning: Failed to rename snapshot for {}: {}",
-                            table_name, e
-                        );
-                        // CRITICAL: Rollback all previously successful renames to maintain consistency
-                        // Without this, partial renames cause different tables to have snapshots
-                        // from different points in time, leading to data loss on recovery.
-                        for (orig_temp, renamed_final) in renamed_successfully.iter().rev() {
-                            if let Err(rollback_err) = std::fs::rename(renamed_final, orig_temp) {
-                                eprintln!(
-                                    "Critical: Failed to rollback snapshot rename {:?} -> {:?}: {}",
-                                    renamed_final, orig_temp, rollback_err
-                                );
-                            }
+            for (temp_path, final_path, table_name) in &pending_snapshots {
+                if let Err(e) = std::fs::rename(temp_path, final_path) {
+                    eprintln!(
+                        "Warning: Failed to rename snapshot for {}: {}",
+                        table_name, e
+                    );
+                    // CRITICAL: Rollback all previously successful renames to maintain consistency
+                    // Without this, partial renames cause different tables to have snapshots
+                    // from different points in time, leading to data loss on recovery.
+                    for (orig_temp, renamed_final) in renamed_successfully.iter().rev() {
+                        if let Err(rollback_err) = std::fs::rename(renamed_final, orig_temp) {
+                            eprintln!(
+                                "Critical: Failed to rollback snapshot rename {:?} -> {:?}: {}",
+                                renamed_final, orig_temp, rollback_err
+                            );
                         }
-                        all_succeeded = false;
-                        break;
-                    }
-                    // Track successful rename for potential rollback
-                    renamed_successfully.push((temp_path.clone(), final_path.clone()));
-                    // Track the directory for syncing
-                    if let Some(parent) = final_path.parent() {
-                        dirs_to_sync.insert(parent.to_path_buf());
                     }
+                    all_succeeded = false;
+                    break;
+                }
+                // Track successful rename for potential rollback
+                renamed_successfully.push((temp_path.clone(), final_path.clone()));
+                // Track the directory for syncing
+                if let Some(parent) = final_path.parent() {
+                    dirs_to_sync.insert(parent.to_path_buf());
                 }
+            }
 
-                // Sync directories to ensure renames are durable
-                // This is important on some file systems (e.g., ext4) where
-                // rename durability requires directory sync
-                if all_succeeded {
-                    for dir in &dirs_to_sync {
-                        if let Ok(dir_file) = std::fs::File::open(dir) {
-                            let _ = dir_file.sync_all();
-                        }
+            // Sync directories to ensure renames are durable
+            // This is important on some file systems (e.g., ext4) where
+            // rename durability requires directory sync
+            if all_succeeded {
+                for dir in &dirs_to_sync {
+                    if let Ok(dir_file) = std::fs::File::open(dir) {
+                        let _ = dir_file.sync_all();
                     }
                 }
             }
@@ -2493,12 +2774,13 @@ impl Engine for MVCCEngine {
 
         // Phase 5: Truncate WAL to remove entries up to the snapshot LSN
         // Safe because: metadata is durable, all data up to snapshot_lsn is in snapshot files
-        if snapshot_lsn > 0 {
-            if let Err(e) = pm.truncate_wal(snapshot_lsn) {
-                eprintln!("Warning: Failed to truncate WAL after snapshot: {}", e);
-                // Continue to cleanup even if truncation fails - data is safe in snapshots
-            }
-        }
+        // Cleanup continues even if truncation fails - data is safe in snapshots -
+        // and the failure is returned afterwards
+        let truncated = if snapshot_lsn > 0 {
+            pm.truncate_wal(snapshot_lsn)
+        } else {
+            Ok(())
+        };
 
         // Phase 6: Cleanup old snapshots LAST (only after metadata is durable)
         // This is safe because we now have:
@@ -2507,28 +2789,41 @@ impl Engine for MVCCEngine {
         // - WAL truncated (or still present if truncation failed)
         let keep_count = pm.keep_count();
         if keep_count > 0 {
-            for (_, _, table_name) in &pending_snapshots {
-                if let Some(default_schema) = schemas.get(DEFAULT_SCHEMA) {
-                    if let Some(schema) = default_schema.get(table_name) {
-                        let disk_store = super::snapshot::DiskVersionStore::new(
-                            &snapshot_dir,
-                            table_name,
-                            schema,
+            for (table_name, schema, _) in &tables {
+                let disk_store =
+                    super::snapshot::DiskVersionStore::new(&snapshot_dir, table_name, schema);
+                if let Ok(disk_store) = disk_store {
+                    if let Err(e) = disk_store.cleanup_old_snapshots(keep_count) {
+                        eprintln!(
+                            "Warning: Failed to cleanup old snapshots for {}: {}",
+                            table_name, e
                         );
-                        if let Ok(disk_store) = disk_store {
-                            if let Err(e) = disk_store.cleanup_old_snapshots(keep_count) {
-                                eprintln!(
-                                    "Warning: Failed to cleanup old snapshots for {}: {}",
-                                    table_name, e
-                                );
-                            }
-                        }
                     }
                 }
             }
+            if let Err(e) = SnapshotCatalog::cleanup_old(&snapshot_dir, keep_count) {
+                eprintln!("Warning: Failed to cleanup old snapshot catalogs: {}", e);
+            }
         }
 
-        Ok(())
+        // Directories of dropped tables are no longer part of any batch
+        if let Ok(entries) = std::fs::read_dir(&snapshot_dir) {
+            for entry in entries.flatten() {
+                let dir_name = entry.file_name().to_string_lossy().to_string();
+                if entry.file_type().is_ok_and(|ft| ft.is_dir())
+                    && !catalog.tables.contains(&dir_name)
+                {
+                    if let Err(e) = std::fs::remove_dir_all(entry.path()) {
+                        eprintln!(
+                            "Warning: Failed to remove snapshots of dropped table {}: {}",
+                            dir_name, e
+                        );
+                    }
+                }
+            }
+        }
+
+        truncated
     }
 
     fn record_create_index(
@@ -2537,6 +2832,7 @@ impl Engine for MVCCEngine {
         index_name: &str,
         column_names: &[String],
         is_unique: bool,
+        nulls_not_distinct: bool,
         index_type: crate::core::IndexType,
     ) {
         if self.should_skip_wal() {
@@ -2577,6 +2873,7 @@ impl Engine for MVCCEngine {
             column_ids,
             data_types,
             is_unique,
+            nulls_not_distinct,
             index_type,
         };
 
@@ -2697,7 +2994,7 @@ impl Engine for MVCCEngine {
         data_type: crate::core::DataType,
         nullable: bool,
         auto_increment: Option<bool>,
-        check_expr: Option<Option<String>>,
+        checks: Option<Vec<CheckConstraint>>,
     ) {
         if self.should_skip_wal() {
             return;
@@ -2706,7 +3003,8 @@ impl Engine for MVCCEngine {
         // Serialize: operation_type(1) + table_name_len(2) + table_name
         //          + column_name_len(2) + column_name + data_type(1) + nullable(1)
         //          + auto_increment_present(1) + [auto_increment(1)]
-        //          + check_expr_present(1) + [check_expr_is_some(1) + [check_expr_len(2) + check_expr]]
+        //          + check_expr_present(1) + [check_expr_is_some(1) + [check_expr_len(2) + check_expr]
+        //          + check_count(2) + checks]
         let mut data = Vec::new();
         data.push(4u8); // Operation type: ModifyColumn = 4
 
@@ -2732,16 +3030,18 @@ impl Engine for MVCCEngine {
             data.push(0); // not present
         }
 
-        // Check expression
-        if let Some(ce_opt) = check_expr {
+        // Check constraints: the first expression keeps the legacy layout,
+        // the full named list follows it
+        if let Some(checks) = checks {
             data.push(1); // present
-            if let Some(ce) = ce_opt {
+            if let Some(first) = checks.first() {
                 data.push(1); // is_some
-                data.extend_from_slice(&(ce.len() as u16).to_le_bytes());
-                data.extend_from_slice(ce.as_bytes());
+                data.extend_from_slice(&(first.expr.len() as u16).to_le_bytes());
+                data.extend_from_slice(first.expr.as_bytes());
             } else {
                 data.push(0); // is_none
             }
+            serialize_check_constraints(&mut data, &checks);
         } else {
             data.push(0); // not present
         }
@@ -2769,6 +3069,25 @@ impl Engine for MVCCEngine {
         self.record_ddl(old_table_name, WALOperationType::AlterTable, &data);
     }
 
+    fn record_alter_table_auto_increment(&self, table_name: &str, next_id: i64) {
+        if self.should_skip_wal() {
+            return;
+        }
+
+        // Serialize: operation_type(1) + table_name_len(2) + table_name + next_id(8)
+        let mut data = Vec::new();
+        data.push(6u8); // Operation type: SetAutoIncrement = 6
+
+        // Table name
+        data.extend_from_slice(&(table_name.len() as u16).to_le_bytes());
+        data.extend_from_slice(table_name.as_bytes());
+
+        // Next ID
+        data.extend_from_slice(&next_id.to_le_bytes());
+
+        self.record_ddl(table_name, WALOperationType::AlterTable, &data);
+    }
+
     fn fetch_rows_by_ids(
         &self,
         table_name: &str,
@@ -2902,7 +3221,9 @@ impl Engine for MVCCEngine {
                 ));
             }
         };
-        sequence.nextval()
+        sequence.nextval_saved(|last_value| {
+            self.save_sequence_value(schema_name, sequence_name, Some(last_value))
+        })
     }
 
     fn setval(
@@ -2927,7 +3248,9 @@ impl Engine for MVCCEngine {
                 ));
             }
         };
-        sequence.setval(value, is_called)
+        sequence.setval_saved(value, is_called, |last_value| {
+            self.save_sequence_value(schema_name, sequence_name, last_value)
+        })
     }
 
     fn list_sequences(&self) -> Result<Vec<(String, String, crate::core::SequenceOptions, i64)>> {
@@ -2945,6 +3268,134 @@ impl Engine for MVCCEngine {
         }
         Ok(result)
     }
+
+    fn transaction_status(&self, txn_id: i64) -> Option<crate::core::TransactionStatus> {
+        self.registry.transaction_status(txn_id)
+    }
+
+    fn create_table(&self, schema: Schema) -> Result<Schema> {
+        MVCCEngine::create_table(self, schema)
+    }
+
+    fn drop_table(&self, table_name: &str) -> Result<()> {
+        self.drop_table_internal(table_name)
+    }
+
+    fn rename_column(&self, table_name: &str, old_name: &str, new_name: &str) -> Result<()> {
+        MVCCEngine::rename_column(self, table_name, old_name, new_name)
+    }
+
+    fn modify_column(
+        &self,
+        table_name: &str,
+        column_name: &str,
+        data_type: DataType,
+        nullable: bool,
+        auto_increment: Option<bool>,
+        checks: Option<Vec<CheckConstraint>>,
+    ) -> Result<()> {
+        MVCCEngine::modify_column(
+            self,
+            table_name,
+            column_name,
+            data_type,
+            nullable,
+            auto_increment,
+            checks,
+        )
+    }
+
+    fn set_auto_increment(&self, table_name: &str, next_id: i64) -> Result<()> {
+        MVCCEngine::set_auto_increment(self, table_name, next_id)
+    }
+
+    fn schema_exists(&self, schema_name: &str) -> Result<bool> {
+        let schemas = self
+            .schemas
+            .read()
+            .map_err(|_| Error::LockAcquisitionFailed("schemas".to_string()))?;
+        Ok(schemas.contains_key(&schema_name.to_lowercase()))
+    }
+
+    fn create_schema(&self, schema_name: &str) -> Result<()> {
+        let mut schemas = self
+            .schemas
+            .write()
+            .map_err(|_| Error::LockAcquisitionFailed("schemas".to_string()))?;
+        schemas.entry(schema_name.to_lowercase()).or_default();
+        Ok(())
+    }
+
+    fn drop_schema(&self, schema_name: &str) -> Result<()> {
+        let mut schemas = self
+            .schemas
+            .write()
+            .map_err(|_| Error::LockAcquisitionFailed("schemas".to_string()))?;
+        schemas.remove(&schema_name.to_lowercase());
+        Ok(())
+    }
+
+    fn list_schemas(&self) -> Result<Vec<String>> {
+        let schemas = self
+            .schemas
+            .read()
+            .map_err(|_| Error::LockAcquisitionFailed("schemas".to_string()))?;
+        Ok(schemas.keys().cloned().collect())
+    }
+
+    fn list_schema_tables(&self, schema_name: &str) -> Result<Vec<Schema>> {
+        let schemas = self
+            .schemas
+            .read()
+            .map_err(|_| Error::LockAcquisitionFailed("schemas".to_string()))?;
+        Ok(schemas
+            .get(&schema_name.to_lowercase())
+            .map(|tables| tables.values().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    fn create_view(
+        &self,
+        schema_name: &str,
+        view_name: &str,
+        query: String,
+        if_not_exists: bool,
+    ) -> Result<()> {
+        MVCCEngine::create_view(self, schema_name, view_name, query, if_not_exists)
+    }
+
+    fn create_or_replace_view(
+        &self,
+        schema_name: &str,
+        view_name: &str,
+        query: String,
+    ) -> Result<()> {
+        MVCCEngine::create_or_replace_view(self, schema_name, view_name, query)
+    }
+
+    fn drop_view(&self, schema_name: &str, view_name: &str, if_exists: bool) -> Result<()> {
+        MVCCEngine::drop_view(self, schema_name, view_name, if_exists)
+    }
+
+    fn get_view(&self, schema_name: &str, view_name: &str) -> Result<Option<Arc<ViewDefinition>>> {
+        MVCCEngine::get_view(self, schema_name, view_name)
+    }
+
+    fn get_view_lowercase(
+        &self,
+        schema_lower: &str,
+        name_lower: &str,
+    ) -> Result<Option<Arc<ViewDefinition>>> {
+        MVCCEngine::get_view_lowercase(self, schema_lower, name_lower)
+    }
+
+    fn list_views(&self) -> Result<Vec<(String, String)>> {
+        MVCCEngine::list_views(self)
+    }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
 }
 
 // =============================================================================
@@ -2966,6 +3417,7 @@ impl MVCCEngine {
             return 0;
         }
 
+        let max_age = max_age.max(self.config.read().unwrap().version_retention());
         let stores = self.version_stores.read().unwrap();
         let mut total_removed = 0;
 
@@ -2982,16 +3434,202 @@ impl MVCCEngine {
             return 0;
         }
 
+        // Default 24-hour retention for historical versions, or longer if configured
+        let retention = std::time::Duration::from_secs(24 * 60 * 60)
+            .max(self.config.read().unwrap().version_retention());
         let stores = self.version_stores.read().unwrap();
         let mut total_cleaned = 0;
 
         for store in stores.values() {
-            total_cleaned += store.cleanup_old_previous_versions();
+            total_cleaned += store.cleanup_previous_versions(retention);
         }
 
         total_cleaned
     }
 
+    /// Runs one auto-vacuum pass over all tables
+    ///
+    /// Tables whose dead-version ratio exceeds the configured threshold are
+    /// vacuumed. Returns the number of versions reclaimed.
+    pub fn run_auto_vacuum(&self) -> i32 {
+        if !self.is_open() {
+            return 0;
+        }
+
+        let config = self.config.read().unwrap().clone();
+        auto_vacuum_pass(&self.version_stores, &config)
+    }
+
+    /// Returns dead-version counts and auto-vacuum history for every table
+    pub fn vacuum_stats(&self) -> Vec<(String, VacuumStats)> {
+        let stores = self.version_stores.read().unwrap();
+        let mut stats: Vec<(String, VacuumStats)> = stores
+            .iter()
+            .map(|(name, store)| (name.clone(), store.vacuum_stats()))
+            .collect();
+        stats.sort_by(|a, b| a.0.cmp(&b.0));
+        stats
+    }
+
+    /// Returns the owning transaction of every claimed row, per table
+    pub fn uncommitted_row_owners(&self) -> Vec<(String, Vec<i64>)> {
+        let stores = self.version_stores.read().unwrap();
+        let mut owners: Vec<(String, Vec<i64>)> = stores
+            .iter()
+            .map(|(name, store)| (name.clone(), store.uncommitted_row_owners()))
+            .collect();
+        owners.sort_by(|a, b| a.0.cmp(&b.0));
+        owners
+    }
+
+    /// Returns the current WAL file size in bytes and the WAL LSN, or None
+    /// without persistence
+    pub fn wal_status(&self) -> Option<(u64, u64)> {
+        self.persistence
+            .as_ref()
+            .as_ref()
+            .filter(|pm| pm.is_enabled())
+            .map(|pm| (pm.wal_size(), pm.current_lsn()))
+    }
+
+    /// Starts or stops the auto-vacuum thread to match the configuration
+    fn sync_auto_vacuum(&self) {
+        let enabled = self.is_open() && self.config.read().unwrap().auto_vacuum.enabled;
+        let mut handle = self.auto_vacuum.lock().unwrap();
+
+        if enabled && handle.is_none() {
+            *handle = Some(self.start_auto_vacuum());
+        } else if !enabled {
+            if let Some(mut running) = handle.take() {
+                running.stop();
+            }
+        }
+    }
+
+    /// Spawns the auto-vacuum thread
+    ///
+    /// The thread only holds the shared config and version stores, so it
+    /// picks up interval and threshold changes without a restart.
+    fn start_auto_vacuum(&self) -> CleanupHandle {
+        use std::thread;
+
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        let stop_flag_clone = Arc::clone(&stop_flag);
+        let config = Arc::clone(&self.config);
+        let version_stores = Arc::clone(&self.version_stores);
+
+        let handle = thread::spawn(move || {
+            while !stop_flag_clone.load(Ordering::Acquire) {
+                let interval = std::time::Duration::from_millis(
+                    config.read().unwrap().auto_vacuum.interval_ms.max(1),
+                );
+
+                // Sleep for the interval (check stop flag periodically)
+                let check_interval = interval.min(std::time::Duration::from_millis(100));
+                let mut elapsed = std::time::Duration::ZERO;
+                while elapsed < interval && !stop_flag_clone.load(Ordering::Acquire) {
+                    thread::sleep(check_interval);
+                    elapsed += check_interval;
+                }
+
+                if stop_flag_clone.load(Ordering::Acquire) {
+                    break;
+                }
+
+                let config = config.read().unwrap().clone();
+                auto_vacuum_pass(&version_stores, &config);
+            }
+        });
+
+        CleanupHandle {
+            stop_flag,
+            thread: Some(handle),
+        }
+    }
+
+    /// Starts the automatic checkpoint thread
+    ///
+    /// A checkpoint runs once `snapshot_interval` has passed with new WAL
+    /// entries, or as soon as the WAL grows past `checkpoint_wal_size`. The
+    /// thread holds a weak reference, so it never keeps the engine alive.
+    pub fn start_auto_checkpoint(self: &Arc<Self>) -> Result<()> {
+        use std::thread;
+
+        let persistent = matches!(self.persistence.as_ref(), Some(pm) if pm.is_enabled());
+        if !persistent || !self.is_open() {
+            return Ok(());
+        }
+
+        let mut auto_checkpoint = self
+            .auto_checkpoint
+            .lock()
+            .map_err(|_| Error::LockAcquisitionFailed("auto_checkpoint".to_string()))?;
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        let stop_flag_clone = Arc::clone(&stop_flag);
+        let engine = Arc::downgrade(self);
+        let started = now_nanos();
+
+        let handle = thread::spawn(move || {
+            let interval = std::time::Duration::from_millis(500);
+            while !stop_flag_clone.load(Ordering::Acquire) {
+                // Sleep for the interval (check stop flag periodically)
+                let check_interval = std::time::Duration::from_millis(100);
+                let mut elapsed = std::time::Duration::ZERO;
+                while elapsed < interval && !stop_flag_clone.load(Ordering::Acquire) {
+                    thread::sleep(check_interval);
+                    elapsed += check_interval;
+                }
+
+                if stop_flag_clone.load(Ordering::Acquire) {
+                    break;
+                }
+
+                let Some(engine) = engine.upgrade() else {
+                    break;
+                };
+                if engine.checkpoint_due(started) {
+                    if let Err(e) = engine.create_snapshot() {
+                        tracing::warn!("Automatic checkpoint failed: {}", e);
+                    }
+                }
+            }
+        });
+
+        *auto_checkpoint = Some(CleanupHandle {
+            stop_flag,
+            thread: Some(handle),
+        });
+        Ok(())
+    }
+
+    /// Returns whether the WAL should be folded into a snapshot now
+    ///
+    /// `since` is the earliest time (Unix nanoseconds) the snapshot interval
+    /// counts from when no snapshot has been taken yet.
+    fn checkpoint_due(&self, since: i64) -> bool {
+        let pm = match self.persistence.as_ref() {
+            Some(pm) if pm.is_enabled() && self.is_open() => pm,
+            _ => return false,
+        };
+
+        // Nothing has been written since the last checkpoint
+        if pm.current_lsn() <= pm.last_checkpoint_lsn() {
+            return false;
+        }
+
+        let Ok(config) = self.config.read() else {
+            return false;
+        };
+        let wal_limit = config.persistence.checkpoint_wal_size as u64;
+        if wal_limit > 0 && pm.wal_total_size() >= wal_limit {
+            return true;
+        }
+
+        let interval = config.persistence.snapshot_interval as i64 * 1_000_000_000;
+        let last = pm.last_snapshot_time().max(since);
+        interval > 0 && now_nanos() - last >= interval
+    }
+
     /// Start periodic cleanup of old transactions and deleted rows
     ///
     /// Returns a handle that can be used to stop the cleanup thread.
@@ -3043,6 +3681,39 @@ impl MVCCEngine {
     }
 }
 
+/// Vacuums every table whose dead-version ratio exceeds the threshold,
+/// keeping versions inside the retention window
+fn auto_vacuum_pass(
+    version_stores: &RwLock<FxHashMap<String, Arc<VersionStore>>>,
+    config: &Config,
+) -> i32 {
+    let retention = config.version_retention();
+    let config = &config.auto_vacuum;
+
+    // Snapshot the store list so DDL is not blocked while vacuuming
+    let stores: Vec<Arc<VersionStore>> = version_stores.read().unwrap().values().cloned().collect();
+
+    let mut reclaimed = 0;
+    for store in stores {
+        let stats = store.vacuum_stats();
+        if stats.dead_versions > 0
+            && stats.dead_versions >= config.min_dead_versions
+            && stats.dead_ratio() >= config.threshold
+        {
+            reclaimed += store.auto_vacuum(retention);
+        }
+    }
+    reclaimed
+}
+
+/// Current wall-clock time in Unix nanoseconds
+fn now_nanos() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as i64)
+        .unwrap_or(0)
+}
+
 /// Handle for stopping the cleanup thread
 pub struct CleanupHandle {
     stop_flag: Arc<AtomicBool>,
@@ -3092,6 +3763,8 @@ struct EngineOperations {
             >,
         >,
     >,
+    /// Shared reference to the row memory tracker
+    memory: Arc<MemoryTracker>,
 }
 
 // EngineOperations is Send + Sync because all fields are Arc-wrapped thread-safe types
@@ -3106,6 +3779,7 @@ impl EngineOperations {
             persistence: Arc::clone(&engine.persistence),
             loading_from_disk: Arc::clone(&engine.loading_from_disk),
             ring_buffers: Arc::clone(&engine.ring_buffers),
+            memory: Arc::clone(&engine.memory),
         }
     }
 
@@ -3215,10 +3889,9 @@ impl TransactionEngineOperations for EngineOperations {
             } else {
                 drop(cache);
                 // Create new transaction version store and cache it
-                let new_store = Arc::new(RwLock::new(TransactionVersionStore::new(
-                    Arc::clone(&version_store),
-                    txn_id,
-                )));
+                let mut store = TransactionVersionStore::new(Arc::clone(&version_store), txn_id);
+                store.set_bulk_load(self.registry.is_bulk_load(txn_id));
+                let new_store = Arc::new(RwLock::new(store));
                 let mut cache = self.txn_version_stores().write().unwrap();
                 cache.insert(cache_key, Arc::clone(&new_store));
                 new_store
@@ -3279,11 +3952,14 @@ impl TransactionEngineOperations for EngineOperations {
             )))
         } else {
             // Create version store for this table
-            let version_store = Arc::new(VersionStore::with_visibility_checker(
-                schema.table_name.clone(),
-                schema,
-                Arc::clone(&self.registry) as Arc<dyn VisibilityChecker>,
-            ));
+            let version_store = Arc::new(
+                VersionStore::with_visibility_checker(
+                    schema.table_name.clone(),
+                    schema,
+                    Arc::clone(&self.registry) as Arc<dyn VisibilityChecker>,
+                )
+                .with_memory_tracker(Arc::clone(&self.memory)),
+            );
 
             {
                 let mut stores = self.version_stores().write().unwrap();
@@ -3590,6 +4266,9 @@ impl TransactionEngineOperations for EngineOperations {
                         );
                         table.commit()?;
                     }
+                } else {
+                    // Nothing to write, but row locks end with the transaction
+                    txn_store.read().unwrap().release_all_claims();
                 }
             }
         }
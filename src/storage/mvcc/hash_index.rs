@@ -109,6 +109,7 @@ pub struct HashIndex {
     column_ids: Vec<i32>,
     data_types: Vec<DataType>,
     is_unique: bool,
+    nulls_not_distinct: bool,
     closed: AtomicBool,
 
     /// Hash -> row IDs mapping
@@ -134,6 +135,7 @@ impl std::fmt::Debug for HashIndex {
             .field("column_names", &self.column_names)
             .field("column_ids", &self.column_ids)
             .field("is_unique", &self.is_unique)
+            .field("nulls_not_distinct", &self.nulls_not_distinct)
             .field("closed", &self.closed.load(AtomicOrdering::Relaxed))
             .finish_non_exhaustive()
     }
@@ -156,6 +158,7 @@ impl HashIndex {
             column_ids,
             data_types,
             is_unique,
+            nulls_not_distinct: false,
             closed: AtomicBool::new(false),
             hash_to_rows: RwLock::new(AHashMap::new()),
             row_to_hash: RwLock::new(AHashMap::new()),
@@ -163,6 +166,12 @@ impl HashIndex {
         }
     }
 
+    /// Makes a unique index treat NULL keys as equal (`NULLS NOT DISTINCT`)
+    pub fn with_nulls_not_distinct(mut self, nulls_not_distinct: bool) -> Self {
+        self.nulls_not_distinct = nulls_not_distinct;
+        self
+    }
+
     /// Check uniqueness constraint
     #[allow(clippy::type_complexity)]
     fn check_unique_constraint(
@@ -176,11 +185,9 @@ impl HashIndex {
             return Ok(());
         }
 
-        // NULL values don't violate uniqueness
-        for v in values {
-            if v.is_null() {
-                return Ok(());
-            }
+        // NULL values don't violate uniqueness unless NULLS NOT DISTINCT
+        if !self.nulls_not_distinct && values.iter().any(|v| v.is_null()) {
+            return Ok(());
         }
 
         if let Some(entries) = hash_to_values.get(&hash) {
@@ -368,6 +375,10 @@ impl Index for HashIndex {
         self.is_unique
     }
 
+    fn nulls_not_distinct(&self) -> bool {
+        self.nulls_not_distinct
+    }
+
     fn find(&self, values: &[Value]) -> Result<Vec<IndexEntry>> {
         if self.closed.load(AtomicOrdering::Acquire) {
             return Err(Error::IndexClosed);
@@ -481,6 +492,24 @@ impl Index for HashIndex {
         results
     }
 
+    fn stores_values(&self) -> bool {
+        true
+    }
+
+    fn get_row_values(&self, row_id: i64) -> Option<Vec<Value>> {
+        if self.closed.load(AtomicOrdering::Acquire) {
+            return None;
+        }
+        // Copy the hash out first so the two locks are never held together
+        let hash = *self.row_to_hash.read().unwrap().get(&row_id)?;
+        let hash_to_values = self.hash_to_values.read().unwrap();
+        hash_to_values
+            .get(&hash)?
+            .iter()
+            .find(|(_, row_ids)| row_ids.contains(&row_id))
+            .map(|(values, _)| values.clone())
+    }
+
     fn close(&mut self) -> Result<()> {
         self.closed.store(true, AtomicOrdering::Release);
         Ok(())
@@ -533,6 +562,37 @@ mod tests {
         assert!(results.is_empty());
     }
 
+    #[test]
+    fn test_hash_index_row_values() {
+        let index = HashIndex::new(
+            "idx_email".to_string(),
+            "users".to_string(),
+            vec!["email".to_string()],
+            vec![1],
+            vec![DataType::Text],
+            false,
+        );
+
+        index
+            .add(&[Value::Text("alice@example.com".into())], 1, 0)
+            .unwrap();
+        index
+            .add(&[Value::Text("alice@example.com".into())], 2, 0)
+            .unwrap();
+
+        assert!(index.stores_values());
+        assert_eq!(
+            index.get_row_values(2),
+            Some(vec![Value::Text("alice@example.com".into())])
+        );
+        assert_eq!(index.get_row_values(3), None);
+
+        index
+            .remove(&[Value::Text("alice@example.com".into())], 2, 0)
+            .unwrap();
+        assert_eq!(index.get_row_values(2), None);
+    }
+
     #[test]
     fn test_hash_index_unique_constraint() {
         let index = HashIndex::new(
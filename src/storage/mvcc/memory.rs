@@ -0,0 +1,145 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Memory budget for stored rows
+//!
+//! [`MemoryTracker`] keeps an engine-wide estimate of the heap used by the
+//! current version of every row and its index entries. When a limit is set
+//! (`max_memory`), writes that would push the estimate past it fail with
+//! [`Error::OutOfMemory`] instead of growing the process until the OS kills
+//! it. Nothing is ever evicted: the limit is hard, and space is only given
+//! back by deleting rows or dropping tables.
+//!
+//! Older row versions kept for MVCC readers are not counted, so the real
+//! footprint can briefly exceed the estimate under heavy update load.
+
+use std::mem::size_of;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+
+use crate::core::{Error, Result, Row, Value};
+
+/// Fixed cost of one row: the version header and its map entry
+const ROW_OVERHEAD: u64 = 64;
+
+/// Fixed cost of one index entry per indexed row
+pub const INDEX_ENTRY_BYTES: u64 = 32;
+
+/// Engine-wide estimate of row memory, with an optional hard limit
+#[derive(Debug, Default)]
+pub struct MemoryTracker {
+    /// Limit in bytes (0 = unlimited)
+    limit: AtomicU64,
+    /// Estimated bytes in use
+    used: AtomicI64,
+}
+
+impl MemoryTracker {
+    /// Creates a tracker with the given limit (0 = unlimited)
+    pub fn new(limit: u64) -> Self {
+        Self {
+            limit: AtomicU64::new(limit),
+            used: AtomicI64::new(0),
+        }
+    }
+
+    /// Returns the limit in bytes (0 = unlimited)
+    pub fn limit(&self) -> u64 {
+        self.limit.load(Ordering::Relaxed)
+    }
+
+    /// Sets the limit in bytes (0 = unlimited)
+    ///
+    /// Lowering the limit below current usage does not free anything; it
+    /// only makes further growth fail.
+    pub fn set_limit(&self, limit: u64) {
+        self.limit.store(limit, Ordering::Relaxed);
+    }
+
+    /// Returns the estimated bytes in use
+    pub fn used(&self) -> u64 {
+        self.used.load(Ordering::Relaxed).max(0) as u64
+    }
+
+    /// Records a change in usage
+    #[inline]
+    pub fn record(&self, delta: i64) {
+        if delta != 0 {
+            self.used.fetch_add(delta, Ordering::Relaxed);
+        }
+    }
+
+    /// Checks that `pending` uncommitted bytes plus `needed` more still fit
+    #[inline]
+    pub fn check(&self, pending: u64, needed: u64) -> Result<()> {
+        let limit = self.limit();
+        if limit == 0 {
+            return Ok(());
+        }
+        let used = self.used().saturating_add(pending);
+        if used.saturating_add(needed) > limit {
+            return Err(Error::OutOfMemory {
+                limit,
+                used,
+                needed,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Estimated heap size of a stored row, not counting index entries
+pub fn estimate_row_size(row: &Row) -> u64 {
+    let values: usize = row
+        .iter()
+        .map(|value| {
+            size_of::<Value>()
+                + match value {
+                    Value::Text(s) | Value::Json(s) => s.len(),
+                    Value::Blob(b) => b.len(),
+                    _ => 0,
+                }
+        })
+        .sum();
+    ROW_OVERHEAD + values as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlimited_never_fails() {
+        let tracker = MemoryTracker::default();
+        tracker.record(1 << 40);
+        assert!(tracker.check(1 << 40, 1 << 40).is_ok());
+    }
+
+    #[test]
+    fn test_limit() {
+        let tracker = MemoryTracker::new(1000);
+        tracker.record(600);
+        assert!(tracker.check(0, 400).is_ok());
+        assert!(tracker.check(100, 400).is_err());
+        tracker.record(-600);
+        assert_eq!(tracker.used(), 0);
+        assert!(tracker.check(0, 1000).is_ok());
+    }
+
+    #[test]
+    fn test_row_size_counts_text() {
+        let short = Row::from_values(vec![Value::text("a")]);
+        let long = Row::from_values(vec![Value::text("a".repeat(1000))]);
+        assert_eq!(estimate_row_size(&long) - estimate_row_size(&short), 999);
+    }
+}
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -38,9 +39,11 @@
 pub mod arena;
 pub mod bitmap_index;
 pub mod btree_index;
+pub mod compression;
 pub mod engine;
 pub mod file_lock;
 pub mod hash_index;
+pub mod memory;
 pub mod multi_column_index;
 pub mod persistence;
 pub mod registry;
@@ -63,6 +66,7 @@ pub use btree_index::{
 };
 pub use engine::{CleanupHandle, MVCCEngine};
 pub use hash_index::HashIndex;
+pub use memory::MemoryTracker;
 pub use multi_column_index::{CompositeKey, MultiColumnIndex};
 pub use persistence::{
     deserialize_row_version, deserialize_value, serialize_row_version, serialize_value,
@@ -78,7 +82,8 @@ pub use table::MVCCTable;
 pub use timestamp::get_fast_timestamp;
 pub use transaction::{MvccTransaction, TransactionEngineOperations, TransactionState};
 pub use version_store::{
-    RowVersion, TransactionVersionStore, VersionStore, VisibilityChecker, WriteSetEntry,
+    RowVersion, TransactionVersionStore, VacuumStats, VersionStore, VisibilityChecker,
+    WriteSetEntry,
 };
 pub use wal_manager::{
     CheckpointMetadata, WALEntry, WALManager, WALOperationType, DEFAULT_WAL_BUFFER_SIZE,
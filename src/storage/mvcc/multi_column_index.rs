@@ -110,6 +110,7 @@ pub struct MultiColumnIndex {
     column_ids: Vec<i32>,
     data_types: Vec<DataType>,
     is_unique: bool,
+    nulls_not_distinct: bool,
     closed: AtomicBool,
 
     /// Main BTree index for range queries - LAZY built on first range query
@@ -136,6 +137,7 @@ impl std::fmt::Debug for MultiColumnIndex {
             .field("column_names", &self.column_names)
             .field("column_ids", &self.column_ids)
             .field("is_unique", &self.is_unique)
+            .field("nulls_not_distinct", &self.nulls_not_distinct)
             .field(
                 "btree_built",
                 &self.btree_built.load(AtomicOrdering::Relaxed),
@@ -169,6 +171,7 @@ impl MultiColumnIndex {
             column_ids,
             data_types,
             is_unique,
+            nulls_not_distinct: false,
             closed: AtomicBool::new(false),
             sorted_values: RwLock::new(BTreeMap::new()),
             btree_built: AtomicBool::new(false),
@@ -179,6 +182,12 @@ impl MultiColumnIndex {
         }
     }
 
+    /// Makes a unique index treat NULL keys as equal (`NULLS NOT DISTINCT`)
+    pub fn with_nulls_not_distinct(mut self, nulls_not_distinct: bool) -> Self {
+        self.nulls_not_distinct = nulls_not_distinct;
+        self
+    }
+
     /// Build BTree index lazily from hash index (on first range query)
     fn ensure_btree_built(&self) {
         if self.btree_built.load(AtomicOrdering::Acquire) {
@@ -248,11 +257,9 @@ impl MultiColumnIndex {
         if !self.is_unique {
             return Ok(());
         }
-        // NULL values don't violate uniqueness
-        for v in &key.0 {
-            if v.is_null() {
-                return Ok(());
-            }
+        // NULL values don't violate uniqueness unless NULLS NOT DISTINCT
+        if !self.nulls_not_distinct && key.0.iter().any(|v| v.is_null()) {
+            return Ok(());
         }
 
         if let Some(rows) = value_to_rows.get(key) {
@@ -473,6 +480,10 @@ impl Index for MultiColumnIndex {
         self.is_unique
     }
 
+    fn nulls_not_distinct(&self) -> bool {
+        self.nulls_not_distinct
+    }
+
     fn find(&self, values: &[Value]) -> Result<Vec<IndexEntry>> {
         if self.closed.load(AtomicOrdering::Acquire) {
             return Err(Error::IndexClosed);
@@ -630,6 +641,53 @@ impl Index for MultiColumnIndex {
         Vec::new()
     }
 
+    fn stores_values(&self) -> bool {
+        true
+    }
+
+    fn get_row_values(&self, row_id: i64) -> Option<Vec<Value>> {
+        if self.closed.load(AtomicOrdering::Acquire) {
+            return None;
+        }
+        let row_to_key = self.row_to_key.read().unwrap();
+        row_to_key.get(&row_id).map(|key| key.0.clone())
+    }
+
+    fn get_row_ids_ordered(
+        &self,
+        ascending: bool,
+        limit: usize,
+        offset: usize,
+    ) -> Option<Vec<i64>> {
+        if self.closed.load(AtomicOrdering::Acquire) {
+            return None;
+        }
+
+        // LAZY build BTree if needed
+        self.ensure_btree_built();
+
+        // Keys compare column by column, so this is also the order of
+        // every leading prefix of the index columns
+        let sorted_values = self.sorted_values.read().unwrap();
+        let row_ids: Vec<i64> = if ascending {
+            sorted_values
+                .values()
+                .flat_map(|ids| ids.iter().copied())
+                .skip(offset)
+                .take(limit)
+                .collect()
+        } else {
+            sorted_values
+                .values()
+                .rev()
+                .flat_map(|ids| ids.iter().copied())
+                .skip(offset)
+                .take(limit)
+                .collect()
+        };
+        Some(row_ids)
+    }
+
     fn close(&mut self) -> Result<()> {
         self.closed.store(true, AtomicOrdering::Release);
 
@@ -756,6 +814,32 @@ mod tests {
         assert_eq!(results.len(), 0);
     }
 
+    #[test]
+    fn test_multi_column_index_ordered_row_ids() {
+        let index = MultiColumnIndex::new(
+            "test_idx".to_string(),
+            "test_table".to_string(),
+            vec!["a".to_string(), "b".to_string()],
+            vec![0, 1],
+            vec![DataType::Integer, DataType::Integer],
+            false,
+        );
+
+        index
+            .add(&[Value::Integer(2), Value::Integer(1)], 1, 0)
+            .unwrap();
+        index
+            .add(&[Value::Integer(1), Value::Integer(9)], 2, 0)
+            .unwrap();
+        index
+            .add(&[Value::Integer(1), Value::Integer(3)], 3, 0)
+            .unwrap();
+
+        assert_eq!(index.get_row_ids_ordered(true, 10, 0), Some(vec![3, 2, 1]));
+        assert_eq!(index.get_row_ids_ordered(false, 2, 0), Some(vec![1, 2]));
+        assert_eq!(index.get_row_ids_ordered(true, 10, 1), Some(vec![2, 1]));
+    }
+
     #[test]
     fn test_composite_key_ordering() {
         let k1 = CompositeKey(vec![Value::Integer(1), Value::Integer(2)]);
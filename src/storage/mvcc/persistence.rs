@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -27,9 +28,15 @@ use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use crate::core::{DataType, Error, IndexType, Result, Row, Schema, Value};
+use crate::core::schema::ForeignKeyMetadata;
+use crate::core::{
+    CheckConstraint, DataType, Error, IndexType, IndexedPrimaryKey, Result, Row, Schema,
+    SchemaColumn, Value,
+};
+use crate::parser::ast::ReferentialAction;
 use crate::storage::mvcc::version_store::RowVersion;
 use crate::storage::mvcc::wal_manager::{WALEntry, WALManager, WALOperationType};
+use crate::storage::traits::Index;
 use crate::storage::PersistenceConfig;
 
 /// Default snapshot interval (5 minutes)
@@ -58,11 +65,27 @@ pub struct IndexMetadata {
     pub data_types: Vec<DataType>,
     /// Whether the index enforces uniqueness
     pub is_unique: bool,
+    /// Whether NULL keys conflict in a unique index (NULLS NOT DISTINCT)
+    pub nulls_not_distinct: bool,
     /// Type of index (BTree, Hash, Bitmap)
     pub index_type: IndexType,
 }
 
 impl IndexMetadata {
+    /// Metadata describing an existing index
+    pub fn from_index(index: &dyn Index) -> Self {
+        Self {
+            name: index.name().to_string(),
+            table_name: index.table_name().to_string(),
+            column_names: index.column_names().to_vec(),
+            column_ids: index.column_ids().to_vec(),
+            data_types: index.data_types().to_vec(),
+            is_unique: index.is_unique(),
+            nulls_not_distinct: index.nulls_not_distinct(),
+            index_type: index.index_type(),
+        }
+    }
+
     /// Serialize to binary format
     pub fn serialize(&self) -> Vec<u8> {
         let mut buf = Vec::new();
@@ -95,8 +118,8 @@ impl IndexMetadata {
             buf.push(dt.as_u8());
         }
 
-        // Unique flag
-        buf.push(if self.is_unique { 1 } else { 0 });
+        // Unique flags (bit 0: unique, bit 1: NULLS NOT DISTINCT)
+        buf.push(u8::from(self.is_unique) | (u8::from(self.nulls_not_distinct) << 1));
 
         // Index type (1 byte: 0=BTree, 1=Hash, 2=Bitmap, 3=MultiColumn)
         let index_type_byte = match self.index_type {
@@ -202,14 +225,16 @@ impl IndexMetadata {
             data_types.push(dt);
         }
 
-        // Unique flag
-        let is_unique = if pos < data.len() {
-            let val = data[pos] != 0;
+        // Unique flags (bit 0: unique, bit 1: NULLS NOT DISTINCT)
+        let flags = if pos < data.len() {
+            let val = data[pos];
             pos += 1;
             val
         } else {
-            false
+            0
         };
+        let is_unique = flags & 1 != 0;
+        let nulls_not_distinct = flags & 2 != 0;
 
         // Index type (1 byte: 0=BTree, 1=Hash, 2=Bitmap, 3=MultiColumn)
         let index_type = if pos < data.len() {
@@ -231,6 +256,7 @@ impl IndexMetadata {
             column_ids,
             data_types,
             is_unique,
+            nulls_not_distinct,
             index_type,
         })
     }
@@ -455,6 +481,9 @@ impl PersistenceManager {
         // Use commit_marker to set COMMIT_MARKER flag for two-phase recovery
         wal.write_commit_marker(txn_id)?;
 
+        // Start a new segment once the current one reaches wal_max_size
+        wal.maybe_rotate()?;
+
         Ok(())
     }
 
@@ -526,6 +555,32 @@ impl PersistenceManager {
         self.wal.as_ref().map(|w| w.current_lsn()).unwrap_or(0)
     }
 
+    /// Get the size of the current WAL file in bytes
+    pub fn wal_size(&self) -> u64 {
+        self.wal
+            .as_ref()
+            .map(|w| w.current_file_size())
+            .unwrap_or(0)
+    }
+
+    /// Get the size of all WAL segments in bytes
+    pub fn wal_total_size(&self) -> u64 {
+        self.wal.as_ref().map(|w| w.total_size()).unwrap_or(0)
+    }
+
+    /// Get the LSN of the last checkpoint
+    pub fn last_checkpoint_lsn(&self) -> u64 {
+        self.wal
+            .as_ref()
+            .map(|w| w.last_checkpoint_lsn())
+            .unwrap_or(0)
+    }
+
+    /// Get the time of the last snapshot (Unix nanoseconds)
+    pub fn last_snapshot_time(&self) -> i64 {
+        self.meta.last_snapshot_time.load(Ordering::Acquire)
+    }
+
     /// Truncate WAL to remove entries up to the given LSN
     ///
     /// This is used after a successful snapshot to reclaim disk space.
@@ -666,6 +721,349 @@ pub fn deserialize_row_version(data: &[u8]) -> Result<RowVersion> {
     })
 }
 
+/// Serialize a list of CHECK constraints
+///
+/// Format: count(2) + [name_len(2) + name + expr_len(2) + expr]...
+/// An unnamed constraint is written with a zero name length.
+pub fn serialize_check_constraints(buf: &mut Vec<u8>, checks: &[CheckConstraint]) {
+    buf.extend_from_slice(&(checks.len() as u16).to_le_bytes());
+    for check in checks {
+        let name = check.name.as_deref().unwrap_or("");
+        buf.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        buf.extend_from_slice(name.as_bytes());
+        buf.extend_from_slice(&(check.expr.len() as u16).to_le_bytes());
+        buf.extend_from_slice(check.expr.as_bytes());
+    }
+}
+
+/// Deserialize a list of CHECK constraints written by [`serialize_check_constraints`]
+pub fn deserialize_check_constraints(data: &[u8], pos: &mut usize) -> Result<Vec<CheckConstraint>> {
+    let read_str = |pos: &mut usize, what: &str| -> Result<String> {
+        if *pos + 2 > data.len() {
+            return Err(Error::internal(format!("missing check {} length", what)));
+        }
+        let len = u16::from_le_bytes(data[*pos..*pos + 2].try_into().unwrap()) as usize;
+        *pos += 2;
+        if *pos + len > data.len() {
+            return Err(Error::internal(format!("missing check {}", what)));
+        }
+        let s = String::from_utf8(data[*pos..*pos + len].to_vec())
+            .map_err(|e| Error::internal(format!("invalid check {}: {}", what, e)))?;
+        *pos += len;
+        Ok(s)
+    };
+
+    if *pos + 2 > data.len() {
+        return Err(Error::internal("missing check constraint count"));
+    }
+    let count = u16::from_le_bytes(data[*pos..*pos + 2].try_into().unwrap()) as usize;
+    *pos += 2;
+
+    let mut checks = Vec::with_capacity(count);
+    for _ in 0..count {
+        let name = read_str(pos, "name")?;
+        let expr = read_str(pos, "expression")?;
+        checks.push(CheckConstraint {
+            name: if name.is_empty() { None } else { Some(name) },
+            expr,
+        });
+    }
+    Ok(checks)
+}
+
+/// Serialize the CHECK constraints of every column that has any
+///
+/// Format: column_count(2) + [column_id(2) + check list]...
+pub fn serialize_column_checks(buf: &mut Vec<u8>, columns: &[SchemaColumn]) {
+    let with_checks: Vec<&SchemaColumn> = columns.iter().filter(|c| !c.checks.is_empty()).collect();
+    buf.extend_from_slice(&(with_checks.len() as u16).to_le_bytes());
+    for col in with_checks {
+        buf.extend_from_slice(&(col.id as u16).to_le_bytes());
+        serialize_check_constraints(buf, &col.checks);
+    }
+}
+
+/// Deserialize column CHECK constraints written by [`serialize_column_checks`],
+/// replacing the check list of each listed column
+pub fn deserialize_column_checks(
+    data: &[u8],
+    pos: &mut usize,
+    columns: &mut [SchemaColumn],
+) -> Result<()> {
+    if *pos + 2 > data.len() {
+        return Err(Error::internal("missing column check count"));
+    }
+    let count = u16::from_le_bytes(data[*pos..*pos + 2].try_into().unwrap()) as usize;
+    *pos += 2;
+
+    for _ in 0..count {
+        if *pos + 2 > data.len() {
+            return Err(Error::internal("missing check column id"));
+        }
+        let column_id = u16::from_le_bytes(data[*pos..*pos + 2].try_into().unwrap()) as usize;
+        *pos += 2;
+        let checks = deserialize_check_constraints(data, pos)?;
+        if let Some(col) = columns.get_mut(column_id) {
+            col.checks = checks;
+        }
+    }
+    Ok(())
+}
+
+/// Serialize the generation expression of every GENERATED column
+///
+/// Format: column_count(2) + [column_id(2) + expr_len(2) + expr]...
+pub fn serialize_column_generated(buf: &mut Vec<u8>, columns: &[SchemaColumn]) {
+    let generated: Vec<(usize, &str)> = columns
+        .iter()
+        .filter_map(|c| c.generated_expr.as_deref().map(|e| (c.id, e)))
+        .collect();
+    buf.extend_from_slice(&(generated.len() as u16).to_le_bytes());
+    for (column_id, expr) in generated {
+        buf.extend_from_slice(&(column_id as u16).to_le_bytes());
+        buf.extend_from_slice(&(expr.len() as u16).to_le_bytes());
+        buf.extend_from_slice(expr.as_bytes());
+    }
+}
+
+/// Deserialize generation expressions written by [`serialize_column_generated`]
+pub fn deserialize_column_generated(
+    data: &[u8],
+    pos: &mut usize,
+    columns: &mut [SchemaColumn],
+) -> Result<()> {
+    if *pos + 2 > data.len() {
+        return Err(Error::internal("missing generated column count"));
+    }
+    let count = u16::from_le_bytes(data[*pos..*pos + 2].try_into().unwrap()) as usize;
+    *pos += 2;
+
+    for _ in 0..count {
+        if *pos + 4 > data.len() {
+            return Err(Error::internal("missing generated column header"));
+        }
+        let column_id = u16::from_le_bytes(data[*pos..*pos + 2].try_into().unwrap()) as usize;
+        let len = u16::from_le_bytes(data[*pos + 2..*pos + 4].try_into().unwrap()) as usize;
+        *pos += 4;
+        if *pos + len > data.len() {
+            return Err(Error::internal("missing generated column expression"));
+        }
+        let expr = String::from_utf8(data[*pos..*pos + len].to_vec())
+            .map_err(|e| Error::internal(format!("invalid generated expression: {}", e)))?;
+        *pos += len;
+        if let Some(col) = columns.get_mut(column_id) {
+            col.generated_expr = Some(expr);
+        }
+    }
+    Ok(())
+}
+
+/// Serialize the ids of the COMPRESSED columns
+///
+/// Format: column_count(2) + [column_id(2)]...
+pub fn serialize_column_compressed(buf: &mut Vec<u8>, columns: &[SchemaColumn]) {
+    let compressed: Vec<usize> = columns
+        .iter()
+        .filter(|c| c.compressed)
+        .map(|c| c.id)
+        .collect();
+    buf.extend_from_slice(&(compressed.len() as u16).to_le_bytes());
+    for column_id in compressed {
+        buf.extend_from_slice(&(column_id as u16).to_le_bytes());
+    }
+}
+
+/// Deserialize compressed column ids written by [`serialize_column_compressed`]
+pub fn deserialize_column_compressed(
+    data: &[u8],
+    pos: &mut usize,
+    columns: &mut [SchemaColumn],
+) -> Result<()> {
+    if *pos + 2 > data.len() {
+        return Err(Error::internal("missing compressed column count"));
+    }
+    let count = u16::from_le_bytes(data[*pos..*pos + 2].try_into().unwrap()) as usize;
+    *pos += 2;
+
+    for _ in 0..count {
+        if *pos + 2 > data.len() {
+            return Err(Error::internal("missing compressed column id"));
+        }
+        let column_id = u16::from_le_bytes(data[*pos..*pos + 2].try_into().unwrap()) as usize;
+        *pos += 2;
+        if let Some(col) = columns.get_mut(column_id) {
+            col.compressed = true;
+        }
+    }
+    Ok(())
+}
+
+/// Serialize the PRIMARY KEY enforced by a unique index, if any
+///
+/// Format: index_name_len(2) + index_name + column_count(2) + [name_len(2) + name]...
+/// An empty index name means the table has no such key.
+pub fn serialize_indexed_primary_key(buf: &mut Vec<u8>, key: Option<&IndexedPrimaryKey>) {
+    let Some(key) = key else {
+        buf.extend_from_slice(&0u16.to_le_bytes());
+        return;
+    };
+    buf.extend_from_slice(&(key.index_name.len() as u16).to_le_bytes());
+    buf.extend_from_slice(key.index_name.as_bytes());
+    buf.extend_from_slice(&(key.columns.len() as u16).to_le_bytes());
+    for column in &key.columns {
+        buf.extend_from_slice(&(column.len() as u16).to_le_bytes());
+        buf.extend_from_slice(column.as_bytes());
+    }
+}
+
+/// Deserialize a key written by [`serialize_indexed_primary_key`]
+pub fn deserialize_indexed_primary_key(
+    data: &[u8],
+    pos: &mut usize,
+) -> Result<Option<IndexedPrimaryKey>> {
+    let read_str = |pos: &mut usize, what: &str| -> Result<String> {
+        if *pos + 2 > data.len() {
+            return Err(Error::internal(format!(
+                "missing primary key {} length",
+                what
+            )));
+        }
+        let len = u16::from_le_bytes(data[*pos..*pos + 2].try_into().unwrap()) as usize;
+        *pos += 2;
+        if *pos + len > data.len() {
+            return Err(Error::internal(format!("missing primary key {}", what)));
+        }
+        let s = String::from_utf8(data[*pos..*pos + len].to_vec())
+            .map_err(|e| Error::internal(format!("invalid primary key {}: {}", what, e)))?;
+        *pos += len;
+        Ok(s)
+    };
+
+    let index_name = read_str(pos, "index name")?;
+    if index_name.is_empty() {
+        return Ok(None);
+    }
+    if *pos + 2 > data.len() {
+        return Err(Error::internal("missing primary key column count"));
+    }
+    let count = u16::from_le_bytes(data[*pos..*pos + 2].try_into().unwrap()) as usize;
+    *pos += 2;
+    let mut columns = Vec::with_capacity(count);
+    for _ in 0..count {
+        columns.push(read_str(pos, "column")?);
+    }
+    Ok(Some(IndexedPrimaryKey {
+        index_name,
+        columns,
+    }))
+}
+
+/// Serialize foreign keys and the tables referencing this one
+///
+/// Format: fk_count(2) + [column_id(2) + table_len(2) + table + column_len(2)
+/// + column + on_delete(1) + on_update(1)]... + referenced_by_count(2)
+/// + [name_len(2) + name]...
+pub fn serialize_foreign_keys(
+    buf: &mut Vec<u8>,
+    foreign_keys: &[ForeignKeyMetadata],
+    referenced_by: &[String],
+) {
+    let action_byte = |action: &ReferentialAction| match action {
+        ReferentialAction::Restrict => 0u8,
+        ReferentialAction::Cascade => 1,
+        ReferentialAction::SetNull => 2,
+        ReferentialAction::NoAction => 3,
+    };
+
+    buf.extend_from_slice(&(foreign_keys.len() as u16).to_le_bytes());
+    for fk in foreign_keys {
+        buf.extend_from_slice(&(fk.column_id as u16).to_le_bytes());
+        buf.extend_from_slice(&(fk.referenced_table.len() as u16).to_le_bytes());
+        buf.extend_from_slice(fk.referenced_table.as_bytes());
+        buf.extend_from_slice(&(fk.referenced_column_name.len() as u16).to_le_bytes());
+        buf.extend_from_slice(fk.referenced_column_name.as_bytes());
+        buf.push(action_byte(&fk.on_delete));
+        buf.push(action_byte(&fk.on_update));
+    }
+
+    buf.extend_from_slice(&(referenced_by.len() as u16).to_le_bytes());
+    for name in referenced_by {
+        buf.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        buf.extend_from_slice(name.as_bytes());
+    }
+}
+
+/// Deserialize foreign keys written by [`serialize_foreign_keys`]
+///
+/// The referenced-by list is optional, for schemas written before it existed.
+pub fn deserialize_foreign_keys(
+    data: &[u8],
+    pos: &mut usize,
+) -> Result<(Vec<ForeignKeyMetadata>, Vec<String>)> {
+    let read_str = |pos: &mut usize, what: &str| -> Result<String> {
+        if *pos + 2 > data.len() {
+            return Err(Error::internal(format!(
+                "missing foreign key {} length",
+                what
+            )));
+        }
+        let len = u16::from_le_bytes(data[*pos..*pos + 2].try_into().unwrap()) as usize;
+        *pos += 2;
+        if *pos + len > data.len() {
+            return Err(Error::internal(format!("missing foreign key {}", what)));
+        }
+        let s = String::from_utf8_lossy(&data[*pos..*pos + len]).into_owned();
+        *pos += len;
+        Ok(s)
+    };
+    let parse_action = |val: u8| match val {
+        0 => ReferentialAction::Restrict,
+        1 => ReferentialAction::Cascade,
+        2 => ReferentialAction::SetNull,
+        _ => ReferentialAction::NoAction,
+    };
+
+    if *pos + 2 > data.len() {
+        return Err(Error::internal("missing foreign key count"));
+    }
+    let count = u16::from_le_bytes(data[*pos..*pos + 2].try_into().unwrap()) as usize;
+    *pos += 2;
+
+    let mut foreign_keys = Vec::with_capacity(count);
+    for _ in 0..count {
+        if *pos + 2 > data.len() {
+            return Err(Error::internal("missing foreign key column id"));
+        }
+        let column_id = u16::from_le_bytes(data[*pos..*pos + 2].try_into().unwrap()) as usize;
+        *pos += 2;
+        let referenced_table = read_str(pos, "table")?;
+        let referenced_column_name = read_str(pos, "column")?;
+        if *pos + 2 > data.len() {
+            return Err(Error::internal("missing foreign key actions"));
+        }
+        let on_delete = parse_action(data[*pos]);
+        let on_update = parse_action(data[*pos + 1]);
+        *pos += 2;
+        foreign_keys.push(ForeignKeyMetadata {
+            column_id,
+            referenced_table,
+            referenced_column_name,
+            on_delete,
+            on_update,
+        });
+    }
+
+    let mut referenced_by = Vec::new();
+    if *pos + 2 <= data.len() {
+        let count = u16::from_le_bytes(data[*pos..*pos + 2].try_into().unwrap()) as usize;
+        *pos += 2;
+        for _ in 0..count {
+            referenced_by.push(read_str(pos, "referencing table")?);
+        }
+    }
+    Ok((foreign_keys, referenced_by))
+}
+
 /// Serialize a Value to binary format
 pub fn serialize_value(value: &Value) -> Result<Vec<u8>> {
     let mut buf = Vec::new();
@@ -704,6 +1102,15 @@ pub fn serialize_value(value: &Value) -> Result<Vec<u8>> {
             buf.extend_from_slice(&(j.len() as u32).to_le_bytes());
             buf.extend_from_slice(j.as_bytes());
         }
+        Value::Interval(micros) => {
+            buf.push(9);
+            buf.extend_from_slice(&micros.to_le_bytes());
+        }
+        Value::Blob(b) => {
+            buf.push(10);
+            buf.extend_from_slice(&(b.len() as u32).to_le_bytes());
+            buf.extend_from_slice(b);
+        }
     }
 
     Ok(buf)
@@ -806,6 +1213,26 @@ pub fn deserialize_value(data: &[u8]) -> Result<Value> {
                 .map_err(|e| Error::internal(format!("invalid json: {}", e)))?;
             Ok(Value::Json(Arc::from(s.as_str())))
         }
+        9 => {
+            // Interval (microseconds)
+            if rest.len() < 8 {
+                return Err(Error::internal("missing interval value"));
+            }
+            Ok(Value::Interval(i64::from_le_bytes(
+                rest[..8].try_into().unwrap(),
+            )))
+        }
+        10 => {
+            // Blob (length-prefixed raw bytes)
+            if rest.len() < 4 {
+                return Err(Error::internal("missing blob length"));
+            }
+            let len = u32::from_le_bytes(rest[..4].try_into().unwrap()) as usize;
+            if rest.len() < 4 + len {
+                return Err(Error::internal("missing blob data"));
+            }
+            Ok(Value::Blob(Arc::from(&rest[4..4 + len])))
+        }
         _ => Err(Error::internal(format!(
             "unknown value type tag: {}",
             type_tag
@@ -829,6 +1256,7 @@ mod tests {
             column_ids: vec![0, 1],
             data_types: vec![DataType::Integer, DataType::Text],
             is_unique: true,
+            nulls_not_distinct: true,
             index_type: IndexType::Hash,
         };
 
@@ -840,6 +1268,7 @@ mod tests {
         assert_eq!(deserialized.column_names, vec!["col1", "col2"]);
         assert_eq!(deserialized.column_ids, vec![0, 1]);
         assert!(deserialized.is_unique);
+        assert!(deserialized.nulls_not_distinct);
         assert_eq!(deserialized.index_type, IndexType::Hash);
     }
 
@@ -859,6 +1288,7 @@ mod tests {
                 column_ids: vec![0],
                 data_types: vec![DataType::Integer],
                 is_unique: false,
+                nulls_not_distinct: false,
                 index_type,
             };
 
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -18,13 +19,13 @@
 //! Uses concurrent hash maps for high-performance thread-safe access.
 //!
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
 use std::sync::RwLock;
 use std::time::{Duration, Instant};
 
 use crate::common::{new_concurrent_int64_map, ConcurrentInt64Map};
-use crate::core::IsolationLevel;
+use crate::core::{IsolationLevel, TransactionStatus};
 use crate::storage::mvcc::VisibilityChecker;
 
 /// Invalid transaction ID returned when registry is not accepting new transactions
@@ -80,11 +81,18 @@ pub struct TransactionRegistry {
     /// Per-transaction isolation level overrides
     transaction_isolation_levels: RwLock<FxHashMap<i64, IsolationLevel>>,
 
+    /// Transactions that defer unique checks and index maintenance to commit
+    bulk_load_transactions: RwLock<FxHashSet<i64>>,
+
     /// Whether new transactions are being accepted
     accepting: AtomicBool,
 
     /// Monotonic sequence for both begin and commit ordering
     next_sequence: AtomicI64,
+
+    /// Highest transaction ID whose commit record was cleaned up; older
+    /// transactions missing from the maps may have committed
+    cleanup_horizon: AtomicI64,
 }
 
 impl TransactionRegistry {
@@ -100,8 +108,10 @@ impl TransactionRegistry {
                 100,
                 Default::default(),
             )),
+            bulk_load_transactions: RwLock::new(FxHashSet::default()),
             accepting: AtomicBool::new(true),
             next_sequence: AtomicI64::new(0),
+            cleanup_horizon: AtomicI64::new(0),
         }
     }
 
@@ -117,6 +127,24 @@ impl TransactionRegistry {
         levels.remove(&txn_id);
     }
 
+    /// Marks or unmarks a transaction as a bulk load
+    pub fn set_bulk_load(&self, txn_id: i64, enabled: bool) {
+        let mut bulk = self.bulk_load_transactions.write().unwrap();
+        if enabled {
+            bulk.insert(txn_id);
+        } else {
+            bulk.remove(&txn_id);
+        }
+    }
+
+    /// Whether a transaction defers unique checks and index maintenance to commit
+    pub fn is_bulk_load(&self, txn_id: i64) -> bool {
+        self.bulk_load_transactions
+            .read()
+            .unwrap()
+            .contains(&txn_id)
+    }
+
     /// Sets the global isolation level for new transactions
     pub fn set_global_isolation_level(&self, level: IsolationLevel) {
         let mut global = self.global_isolation_level.write().unwrap();
@@ -502,6 +530,7 @@ impl TransactionRegistry {
         let mut removed = 0;
         for txn_id in txns_to_remove {
             self.committed_transactions.remove(&txn_id);
+            self.cleanup_horizon.fetch_max(txn_id, Ordering::AcqRel);
             removed += 1;
         }
 
@@ -530,6 +559,21 @@ impl TransactionRegistry {
         self.active_transactions.len() as i32
     }
 
+    /// Waits until every commit sequenced before `seq` has completed
+    ///
+    /// A commit takes its sequence number before its changes become visible,
+    /// so a consistent read as of `seq` must wait for those in-flight commits
+    /// or it could see one half-applied across tables.
+    pub fn wait_for_commits_before(&self, seq: i64) {
+        while self
+            .committing_transactions
+            .iter()
+            .any(|entry| *entry.value() < seq)
+        {
+            std::thread::yield_now();
+        }
+    }
+
     /// Stops accepting new transactions
     pub fn stop_accepting_transactions(&self) {
         self.accepting.store(false, Ordering::Release);
@@ -560,6 +604,22 @@ impl TransactionRegistry {
         self.committed_transactions.len()
     }
 
+    /// Gets the count of transactions between the two commit phases
+    pub fn committing_count(&self) -> usize {
+        self.committing_transactions.len()
+    }
+
+    /// Returns the active transactions as (txn_id, begin_sequence), oldest first
+    pub fn active_transactions(&self) -> Vec<(i64, i64)> {
+        let mut active: Vec<(i64, i64)> = self
+            .active_transactions
+            .iter()
+            .map(|entry| (*entry.key(), *entry.value()))
+            .collect();
+        active.sort_by_key(|&(txn_id, begin_seq)| (begin_seq, txn_id));
+        active
+    }
+
     /// Checks if a transaction is active
     pub fn is_active(&self, txn_id: i64) -> bool {
         self.active_transactions.contains_key(&txn_id)
@@ -570,6 +630,29 @@ impl TransactionRegistry {
         self.committed_transactions.contains_key(&txn_id)
     }
 
+    /// Status of a transaction
+    ///
+    /// Aborted transactions are not recorded, so a transaction that was
+    /// started and is neither running nor committed is reported as aborted.
+    /// Returns None for IDs never handed out and for transactions whose
+    /// commit record was cleaned up.
+    pub fn transaction_status(&self, txn_id: i64) -> Option<TransactionStatus> {
+        if self.active_transactions.contains_key(&txn_id)
+            || self.committing_transactions.contains_key(&txn_id)
+        {
+            Some(TransactionStatus::InProgress)
+        } else if self.committed_transactions.contains_key(&txn_id) {
+            Some(TransactionStatus::Committed)
+        } else if txn_id <= 0
+            || txn_id > self.next_txn_id.load(Ordering::Acquire)
+            || txn_id <= self.cleanup_horizon.load(Ordering::Acquire)
+        {
+            None
+        } else {
+            Some(TransactionStatus::Aborted)
+        }
+    }
+
     /// Get the current commit sequence number
     ///
     /// This returns the sequence number that will be assigned to the NEXT commit.
@@ -638,6 +721,10 @@ impl VisibilityChecker for TransactionRegistry {
     fn is_committed_before(&self, txn_id: i64, cutoff_commit_seq: i64) -> bool {
         TransactionRegistry::is_committed_before(self, txn_id, cutoff_commit_seq)
     }
+
+    fn is_read_committed(&self, viewing_txn_id: i64) -> bool {
+        self.get_isolation_level(viewing_txn_id) == IsolationLevel::ReadCommitted
+    }
 }
 
 #[cfg(test)]
@@ -700,6 +787,38 @@ mod tests {
         assert!(!registry.is_committed(txn_id));
     }
 
+    #[test]
+    fn test_transaction_status() {
+        let registry = TransactionRegistry::new();
+
+        let (committed, _) = registry.begin_transaction();
+        let (aborted, _) = registry.begin_transaction();
+        let (running, _) = registry.begin_transaction();
+        registry.start_commit(committed);
+        assert_eq!(
+            registry.transaction_status(committed),
+            Some(TransactionStatus::InProgress)
+        );
+        registry.complete_commit(committed);
+        registry.abort_transaction(aborted);
+
+        assert_eq!(
+            registry.transaction_status(committed),
+            Some(TransactionStatus::Committed)
+        );
+        assert_eq!(
+            registry.transaction_status(aborted),
+            Some(TransactionStatus::Aborted)
+        );
+        assert_eq!(
+            registry.transaction_status(running),
+            Some(TransactionStatus::InProgress)
+        );
+        // Never handed out
+        assert_eq!(registry.transaction_status(running + 1), None);
+        assert_eq!(registry.transaction_status(0), None);
+    }
+
     #[test]
     fn test_visibility_own_writes() {
         let registry = TransactionRegistry::new();
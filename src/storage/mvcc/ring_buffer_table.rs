@@ -23,7 +23,7 @@ use std::collections::VecDeque;
 use std::sync::atomic::{AtomicI64, Ordering};
 use std::sync::Arc;
 
-use crate::core::{DataType, Error, Result, Row, Schema, Value};
+use crate::core::{CheckConstraint, DataType, Error, Result, Row, Schema, Value};
 use crate::storage::expression::Expression;
 use crate::storage::traits::{QueryResult, Scanner, Table};
 
@@ -306,7 +306,7 @@ impl Table for SystemRingBufferTable {
         _column_type: DataType,
         _nullable: bool,
         _auto_increment: Option<bool>,
-        _check_expr: Option<Option<String>>,
+        _checks: Option<Vec<CheckConstraint>>,
     ) -> Result<()> {
         Err(Error::NotSupportedMessage(
             "Schema changes are not supported on system ring buffer tables".to_string(),
@@ -25,8 +25,13 @@ use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
 use std::sync::RwLock;
 
-use crate::core::{DataType, Error, Result, Schema, SchemaColumn};
-use crate::storage::mvcc::persistence::{deserialize_row_version, serialize_row_version};
+use crate::core::{CheckConstraint, DataType, Error, Result, Schema, SchemaColumn, ViewDefinition};
+use crate::storage::mvcc::persistence::{
+    deserialize_column_checks, deserialize_column_compressed, deserialize_column_generated,
+    deserialize_foreign_keys, deserialize_indexed_primary_key, deserialize_row_version,
+    serialize_column_checks, serialize_column_compressed, serialize_column_generated,
+    serialize_foreign_keys, serialize_indexed_primary_key, serialize_row_version, IndexMetadata,
+};
 use crate::storage::mvcc::version_store::RowVersion;
 
 // ============================================================================
@@ -54,6 +59,15 @@ const FOOTER_SIZE: usize = 64;
 /// Default batch size for processing rows
 const DEFAULT_BATCH_SIZE: usize = 1000;
 
+/// Schema whose tables are snapshotted under their bare name
+const DEFAULT_SCHEMA: &str = "public";
+
+/// Magic number for snapshot catalog files ("SCAT")
+const CATALOG_MAGIC: u32 = 0x54414353;
+
+/// Snapshot catalog file format version
+const CATALOG_VERSION: u32 = 1;
+
 /// Default block size for buffered I/O
 const DEFAULT_BLOCK_SIZE: usize = 64 * 1024; // 64KB
 
@@ -353,9 +367,14 @@ impl Footer {
 fn serialize_snapshot_schema(schema: &Schema) -> Vec<u8> {
     let mut buf = Vec::new();
 
-    // Table name
-    buf.extend_from_slice(&(schema.table_name.len() as u16).to_le_bytes());
-    buf.extend_from_slice(schema.table_name.as_bytes());
+    // Table name, qualified outside the default schema
+    let table_name = if schema.schema_name_lower == DEFAULT_SCHEMA {
+        schema.table_name.clone()
+    } else {
+        format!("{}.{}", schema.schema_name, schema.table_name)
+    };
+    buf.extend_from_slice(&(table_name.len() as u16).to_le_bytes());
+    buf.extend_from_slice(table_name.as_bytes());
 
     // Column count
     buf.extend_from_slice(&(schema.columns.len() as u16).to_le_bytes());
@@ -386,10 +405,10 @@ fn serialize_snapshot_schema(schema: &Schema) -> Vec<u8> {
             buf.extend_from_slice(&0u16.to_le_bytes());
         }
 
-        // Check constraint (length-prefixed string, 0 length if None)
-        if let Some(ref check_expr) = col.check_expr {
-            buf.extend_from_slice(&(check_expr.len() as u16).to_le_bytes());
-            buf.extend_from_slice(check_expr.as_bytes());
+        // First check constraint (length-prefixed string, 0 length if None)
+        if let Some(check) = col.checks.first() {
+            buf.extend_from_slice(&(check.expr.len() as u16).to_le_bytes());
+            buf.extend_from_slice(check.expr.as_bytes());
         } else {
             buf.extend_from_slice(&0u16.to_le_bytes());
         }
@@ -403,6 +422,21 @@ fn serialize_snapshot_schema(schema: &Schema) -> Vec<u8> {
     let updated_nanos = schema.updated_at.timestamp_nanos_opt().unwrap_or(0);
     buf.extend_from_slice(&updated_nanos.to_le_bytes());
 
+    // Named check constraints (all of them, per column)
+    serialize_column_checks(&mut buf, &schema.columns);
+
+    // Generated column expressions
+    serialize_column_generated(&mut buf, &schema.columns);
+
+    // Compressed columns
+    serialize_column_compressed(&mut buf, &schema.columns);
+
+    // Index-enforced primary key
+    serialize_indexed_primary_key(&mut buf, schema.indexed_primary_key.as_ref());
+
+    // Foreign keys and the tables referencing this one
+    serialize_foreign_keys(&mut buf, &schema.foreign_keys, &schema.referenced_by);
+
     buf
 }
 
@@ -557,7 +591,9 @@ fn deserialize_snapshot_schema(data: &[u8]) -> Result<Schema> {
             auto_increment,
             default_expr,
             default_value: None,
-            check_expr,
+            checks: check_expr.map(CheckConstraint::new).into_iter().collect(),
+            generated_expr: None,
+            compressed: false,
         });
     }
 
@@ -576,6 +612,7 @@ fn deserialize_snapshot_schema(data: &[u8]) -> Result<Schema> {
 
     let updated_at = if pos + 8 <= data.len() {
         let nanos = i64::from_le_bytes(data[pos..pos + 8].try_into().unwrap());
+        pos += 8;
         if nanos > 0 {
             chrono::DateTime::from_timestamp_nanos(nanos)
         } else {
@@ -585,9 +622,34 @@ fn deserialize_snapshot_schema(data: &[u8]) -> Result<Schema> {
         chrono::Utc::now()
     };
 
-    Ok(Schema::with_timestamps(
-        table_name, columns, created_at, updated_at,
-    ))
+    // Named check constraints (optional for backward compatibility)
+    if pos + 2 <= data.len() {
+        deserialize_column_checks(data, &mut pos, &mut columns)?;
+    }
+
+    // Generated column expressions (optional for backward compatibility)
+    if pos + 2 <= data.len() {
+        deserialize_column_generated(data, &mut pos, &mut columns)?;
+    }
+
+    // Compressed columns (optional for backward compatibility)
+    if pos + 2 <= data.len() {
+        deserialize_column_compressed(data, &mut pos, &mut columns)?;
+    }
+
+    let mut schema = Schema::with_timestamps(table_name, columns, created_at, updated_at);
+
+    // Index-enforced primary key (optional for backward compatibility)
+    if pos + 2 <= data.len() {
+        schema.indexed_primary_key = deserialize_indexed_primary_key(data, &mut pos)?;
+    }
+
+    // Foreign keys (optional for backward compatibility)
+    if pos + 2 <= data.len() {
+        (schema.foreign_keys, schema.referenced_by) = deserialize_foreign_keys(data, &mut pos)?;
+    }
+
+    Ok(schema)
 }
 
 // ============================================================================
@@ -1260,6 +1322,189 @@ impl SnapshotReader {
     }
 }
 
+// ============================================================================
+// SnapshotCatalog - Catalog objects of one snapshot batch
+// ============================================================================
+
+/// Catalog objects of one snapshot batch
+///
+/// A table snapshot file only holds a schema and rows. The catalog lists the
+/// tables in the batch, so a dropped table is not loaded back, and holds the
+/// index and view definitions that would otherwise only be in the WAL. It is
+/// written as `catalog-<timestamp>.bin`, with the timestamp of the table
+/// snapshot files it belongs to.
+#[derive(Debug, Default)]
+pub struct SnapshotCatalog {
+    /// Snapshot directory names of the tables in the batch
+    pub tables: Vec<String>,
+    /// Index definitions, with the table they belong to
+    pub indexes: Vec<(String, IndexMetadata)>,
+    /// View definitions, with the schema they belong to
+    pub views: Vec<(String, ViewDefinition)>,
+}
+
+impl SnapshotCatalog {
+    /// File name of the catalog for a batch timestamp
+    pub fn file_name(timestamp: &str) -> String {
+        format!("catalog-{}.bin", timestamp)
+    }
+
+    /// Find the newest catalog in a snapshot directory
+    ///
+    /// Returns its path and batch timestamp.
+    pub fn find_latest(snapshot_dir: &Path) -> Option<(PathBuf, String)> {
+        let mut catalogs: Vec<String> = fs::read_dir(snapshot_dir)
+            .ok()?
+            .filter_map(|e| e.ok())
+            .filter_map(|e| e.file_name().to_str().map(str::to_string))
+            .filter(|n| n.starts_with("catalog-") && n.ends_with(".bin"))
+            .collect();
+        catalogs.sort();
+        let name = catalogs.pop()?;
+        let timestamp = name["catalog-".len()..name.len() - ".bin".len()].to_string();
+        Some((snapshot_dir.join(name), timestamp))
+    }
+
+    /// Delete all but the newest `keep_count` catalogs
+    pub fn cleanup_old(snapshot_dir: &Path, keep_count: usize) -> Result<()> {
+        let mut catalogs: Vec<PathBuf> = fs::read_dir(snapshot_dir)
+            .map_err(|e| Error::internal(format!("failed to read snapshot directory: {}", e)))?
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| {
+                p.file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.starts_with("catalog-") && n.ends_with(".bin"))
+            })
+            .collect();
+        catalogs.sort();
+        catalogs.reverse();
+        for path in catalogs.iter().skip(keep_count) {
+            if let Err(e) = fs::remove_file(path) {
+                eprintln!("Warning: failed to delete old catalog {:?}: {}", path, e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the catalog to `path` and sync it
+    ///
+    /// Format: magic(4) + version(4) + table_count(4) + [name_len(2) + name]...
+    /// + index_count(4) + [table_len(2) + table + meta_len(4) + meta]...
+    /// + view_count(4) + [schema_len(2) + schema + view_len(4) + view]... + crc32(4)
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let put_str = |buf: &mut Vec<u8>, s: &str| {
+            buf.extend_from_slice(&(s.len() as u16).to_le_bytes());
+            buf.extend_from_slice(s.as_bytes());
+        };
+        let put_bytes = |buf: &mut Vec<u8>, data: &[u8]| {
+            buf.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            buf.extend_from_slice(data);
+        };
+
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&CATALOG_MAGIC.to_le_bytes());
+        buf.extend_from_slice(&CATALOG_VERSION.to_le_bytes());
+
+        buf.extend_from_slice(&(self.tables.len() as u32).to_le_bytes());
+        for table in &self.tables {
+            put_str(&mut buf, table);
+        }
+        buf.extend_from_slice(&(self.indexes.len() as u32).to_le_bytes());
+        for (table, meta) in &self.indexes {
+            put_str(&mut buf, table);
+            put_bytes(&mut buf, &meta.serialize());
+        }
+        buf.extend_from_slice(&(self.views.len() as u32).to_le_bytes());
+        for (schema, view) in &self.views {
+            put_str(&mut buf, schema);
+            put_bytes(&mut buf, &view.serialize());
+        }
+
+        let crc = crc32fast::hash(&buf);
+        buf.extend_from_slice(&crc.to_le_bytes());
+
+        let mut file = File::create(path)
+            .map_err(|e| Error::internal(format!("failed to create snapshot catalog: {}", e)))?;
+        file.write_all(&buf)
+            .map_err(|e| Error::internal(format!("failed to write snapshot catalog: {}", e)))?;
+        file.sync_all()
+            .map_err(|e| Error::internal(format!("failed to sync snapshot catalog: {}", e)))
+    }
+
+    /// Read a catalog written by [`SnapshotCatalog::write`]
+    pub fn read(path: &Path) -> Result<Self> {
+        let data = fs::read(path)
+            .map_err(|e| Error::internal(format!("failed to read snapshot catalog: {}", e)))?;
+        if data.len() < 12 {
+            return Err(Error::internal("snapshot catalog too short"));
+        }
+        let (body, crc) = data.split_at(data.len() - 4);
+        if crc32fast::hash(body) != u32::from_le_bytes(crc.try_into().unwrap()) {
+            return Err(Error::internal("snapshot catalog checksum mismatch"));
+        }
+        if u32::from_le_bytes(body[0..4].try_into().unwrap()) != CATALOG_MAGIC {
+            return Err(Error::internal("invalid snapshot catalog magic"));
+        }
+        let version = u32::from_le_bytes(body[4..8].try_into().unwrap());
+        if version != CATALOG_VERSION {
+            return Err(Error::internal(format!(
+                "unsupported snapshot catalog version: {}",
+                version
+            )));
+        }
+
+        let mut pos = 8;
+        let read_u32 = |pos: &mut usize| -> Result<usize> {
+            if *pos + 4 > body.len() {
+                return Err(Error::internal("truncated snapshot catalog"));
+            }
+            let value = u32::from_le_bytes(body[*pos..*pos + 4].try_into().unwrap()) as usize;
+            *pos += 4;
+            Ok(value)
+        };
+        let read_str = |pos: &mut usize| -> Result<String> {
+            if *pos + 2 > body.len() {
+                return Err(Error::internal("truncated snapshot catalog"));
+            }
+            let len = u16::from_le_bytes(body[*pos..*pos + 2].try_into().unwrap()) as usize;
+            *pos += 2;
+            if *pos + len > body.len() {
+                return Err(Error::internal("truncated snapshot catalog"));
+            }
+            let s = String::from_utf8(body[*pos..*pos + len].to_vec())
+                .map_err(|e| Error::internal(format!("invalid snapshot catalog name: {}", e)))?;
+            *pos += len;
+            Ok(s)
+        };
+        let read_bytes = |pos: &mut usize| -> Result<&[u8]> {
+            let len = read_u32(pos)?;
+            if *pos + len > body.len() {
+                return Err(Error::internal("truncated snapshot catalog"));
+            }
+            let bytes = &body[*pos..*pos + len];
+            *pos += len;
+            Ok(bytes)
+        };
+
+        let mut catalog = Self::default();
+        for _ in 0..read_u32(&mut pos)? {
+            catalog.tables.push(read_str(&mut pos)?);
+        }
+        for _ in 0..read_u32(&mut pos)? {
+            let table = read_str(&mut pos)?;
+            let meta = IndexMetadata::deserialize(read_bytes(&mut pos)?)?;
+            catalog.indexes.push((table, meta));
+        }
+        for _ in 0..read_u32(&mut pos)? {
+            let schema = read_str(&mut pos)?;
+            let view = ViewDefinition::deserialize(read_bytes(&mut pos)?)?;
+            catalog.views.push((schema, view));
+        }
+        Ok(catalog)
+    }
+}
+
 // ============================================================================
 // DiskVersionStore - Manages on-disk snapshots for a table
 // ============================================================================
@@ -1577,7 +1822,7 @@ mod tests {
                     true,
                     true,
                     None,
-                    None,
+                    Vec::new(),
                 ),
                 SchemaColumn::with_constraints(
                     1,
@@ -1587,7 +1832,7 @@ mod tests {
                     false,
                     false,
                     Some("'unnamed'".to_string()),
-                    None,
+                    Vec::new(),
                 ),
                 SchemaColumn::with_constraints(
                     2,
@@ -1597,7 +1842,10 @@ mod tests {
                     false,
                     false,
                     None,
-                    Some("value > 0".to_string()),
+                    vec![
+                        CheckConstraint::new("value > 0"),
+                        CheckConstraint::named("value_cap", "value < 1000"),
+                    ],
                 ),
             ],
         )
@@ -1619,7 +1867,7 @@ mod tests {
             assert_eq!(orig.primary_key, deser.primary_key);
             assert_eq!(orig.auto_increment, deser.auto_increment);
             assert_eq!(orig.default_expr, deser.default_expr);
-            assert_eq!(orig.check_expr, deser.check_expr);
+            assert_eq!(orig.checks, deser.checks);
         }
     }
 
@@ -19,18 +19,23 @@
 //!
 
 use rustc_hash::FxHashMap;
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
 use crate::common::Int64Set;
-use crate::core::{DataType, Error, IndexType, Result, Row, Schema, SchemaColumn, Value};
+use crate::core::{
+    CheckConstraint, DataType, Error, IndexType, Result, Row, RowLockMode, Schema, SchemaColumn,
+    Value,
+};
 use crate::storage::expression::Expression;
 use crate::storage::mvcc::bitmap_index::BitmapIndex;
 use crate::storage::mvcc::btree_index::BTreeIndex;
 use crate::storage::mvcc::hash_index::HashIndex;
 use crate::storage::mvcc::multi_column_index::MultiColumnIndex;
 use crate::storage::mvcc::scanner::MVCCScanner;
-use crate::storage::mvcc::{TransactionVersionStore, VersionStore};
-use crate::storage::traits::{Index, QueryResult, ScanPlan, Scanner, Table};
+use crate::storage::mvcc::{compression, TransactionVersionStore, VersionStore};
+use crate::storage::traits::{Index, IndexHint, QueryResult, ScanPlan, Scanner, Table};
 use crate::storage::MemoryResult;
 
 /// MVCC Table wrapper that provides MVCC isolation for tables
@@ -43,6 +48,10 @@ pub struct MVCCTable {
     txn_versions: Arc<RwLock<TransactionVersionStore>>,
     /// Cached schema for returning references (cloned from version_store)
     cached_schema: Schema,
+    /// Indices of the COMPRESSED columns of the cached schema
+    compressed_columns: Vec<usize>,
+    /// Optimizer hint restricting which indexes reads may use
+    index_hint: Option<IndexHint>,
 }
 
 impl MVCCTable {
@@ -54,11 +63,14 @@ impl MVCCTable {
         txn_versions: TransactionVersionStore,
     ) -> Self {
         let cached_schema = version_store.schema();
+        let compressed_columns = compression::compressed_columns(&cached_schema);
         Self {
             txn_id,
             version_store,
             txn_versions: Arc::new(RwLock::new(txn_versions)),
             cached_schema,
+            compressed_columns,
+            index_hint: None,
         }
     }
 
@@ -70,11 +82,14 @@ impl MVCCTable {
         txn_versions: Arc<RwLock<TransactionVersionStore>>,
     ) -> Self {
         let cached_schema = version_store.schema();
+        let compressed_columns = compression::compressed_columns(&cached_schema);
         Self {
             txn_id,
             version_store,
             txn_versions,
             cached_schema,
+            compressed_columns,
+            index_hint: None,
         }
     }
 
@@ -88,6 +103,104 @@ impl MVCCTable {
         &self.version_store
     }
 
+    /// Single-column index on a column that the index hint allows reads to use
+    fn usable_index_by_column(&self, column_name: &str) -> Option<Arc<dyn Index>> {
+        match self.index_hint {
+            Some(ref hint) => self
+                .version_store
+                .find_index_by_column(column_name, |name| hint.allows(name)),
+            None => self.version_store.get_index_by_column(column_name),
+        }
+    }
+
+    /// Multi-column index for the predicate columns that the index hint allows
+    fn usable_multi_column_index(
+        &self,
+        predicate_columns: &[&str],
+    ) -> Option<(Arc<dyn Index>, usize)> {
+        match self.index_hint {
+            Some(ref hint) => self
+                .version_store
+                .find_multi_column_index(predicate_columns, |name| hint.allows(name)),
+            None => self.version_store.get_multi_column_index(predicate_columns),
+        }
+    }
+
+    /// Index supporting ordered iteration whose leading columns are `columns`
+    ///
+    /// A single-column B-tree index is preferred over a multi-column prefix.
+    fn usable_ordered_index(&self, columns: &[&str]) -> Option<Arc<dyn Index>> {
+        let allowed = |index: &Arc<dyn Index>| {
+            self.index_hint
+                .as_ref()
+                .is_none_or(|hint| hint.allows(index.name()))
+        };
+        let indexes = self.version_store.get_all_indexes();
+        if let [column] = columns {
+            let single = indexes.iter().find(|index| {
+                index.index_type() == IndexType::BTree
+                    && index.column_names() == [*column]
+                    && allowed(index)
+            });
+            if let Some(index) = single {
+                return Some(Arc::clone(index));
+            }
+        }
+        indexes
+            .into_iter()
+            .filter(|index| index.index_type() == IndexType::MultiColumn && allowed(index))
+            .find(|index| {
+                let names = index.column_names();
+                names.len() >= columns.len()
+                    && names
+                        .iter()
+                        .zip(columns)
+                        .all(|(name, column)| name == column)
+            })
+    }
+
+    /// Index storing values for all of `columns`, the narrowest one first
+    fn usable_covering_index(&self, columns: &[usize]) -> Option<Arc<dyn Index>> {
+        self.version_store
+            .get_all_indexes()
+            .into_iter()
+            .filter(|index| {
+                index.stores_values()
+                    && self
+                        .index_hint
+                        .as_ref()
+                        .is_none_or(|hint| hint.allows(index.name()))
+                    && columns
+                        .iter()
+                        .all(|&column| index.column_ids().contains(&(column as i32)))
+            })
+            .min_by_key(|index| index.column_ids().len())
+    }
+
+    /// Row ids of `index` in index order, fetched in growing batches
+    fn index_order_row_ids(
+        index: &Arc<dyn Index>,
+        ascending: bool,
+    ) -> impl Iterator<Item = i64> + '_ {
+        let mut batch = Vec::new().into_iter();
+        let mut fetched = 0;
+        let mut batch_size = 256;
+        let mut exhausted = false;
+        std::iter::from_fn(move || loop {
+            if let Some(row_id) = batch.next() {
+                return Some(row_id);
+            }
+            if exhausted {
+                return None;
+            }
+            let row_ids = index.get_row_ids_ordered(ascending, batch_size, fetched)?;
+            exhausted = row_ids.len() < batch_size;
+            fetched += row_ids.len();
+            batch_size *= 2;
+            batch = row_ids.into_iter();
+        })
+    }
+
     /// Returns a reference to the shared transaction version store
     pub fn txn_versions(&self) -> &Arc<RwLock<TransactionVersionStore>> {
         &self.txn_versions
@@ -96,7 +209,7 @@ impl MVCCTable {
     /// Auto-selects the optimal index type based on column data types
     ///
     /// # Type-Based Index Selection Rules:
-    /// - TEXT/JSON columns → Hash index (avoids O(strlen) comparisons per B-tree node)
+    /// - TEXT/JSON/BLOB columns → Hash index (avoids O(strlen) comparisons per B-tree node)
     /// - BOOLEAN columns → Bitmap index (only 2 values, fast AND/OR operations)
     /// - INTEGER/FLOAT/TIMESTAMP columns → BTree index (supports range queries)
     /// - Mixed types → BTree as safe default
@@ -116,20 +229,186 @@ impl MVCCTable {
 
         // Check the primary (first) column type
         match data_types[0] {
-            // TEXT/JSON - use Hash for O(1) lookups, avoid O(strlen) comparisons
-            DataType::Text | DataType::Json => IndexType::Hash,
+            // TEXT/JSON/BLOB - use Hash for O(1) lookups, avoid O(strlen) comparisons
+            DataType::Text | DataType::Json | DataType::Blob => IndexType::Hash,
 
             // BOOLEAN - use Bitmap for fast AND/OR/NOT operations
             DataType::Boolean => IndexType::Bitmap,
 
             // Numeric types - use BTree for range query support
-            DataType::Integer | DataType::Float | DataType::Timestamp => IndexType::BTree,
+            DataType::Integer | DataType::Float | DataType::Timestamp | DataType::Interval => {
+                IndexType::BTree
+            }
 
             // NULL type - use BTree as safe default
             DataType::Null => IndexType::BTree,
         }
     }
 
+    /// Creates an empty index of the given type
+    #[allow(clippy::too_many_arguments)]
+    fn new_index(
+        &self,
+        name: &str,
+        column_names: Vec<String>,
+        column_ids: Vec<i32>,
+        data_types: Vec<DataType>,
+        is_unique: bool,
+        nulls_not_distinct: bool,
+        index_type: IndexType,
+    ) -> Arc<dyn Index> {
+        let columns = column_names.len();
+        match index_type {
+            IndexType::Hash => Arc::new(
+                HashIndex::new(
+                    name.to_string(),
+                    self.name().to_string(),
+                    column_names,
+                    column_ids,
+                    data_types,
+                    is_unique,
+                )
+                .with_nulls_not_distinct(nulls_not_distinct),
+            ),
+            IndexType::Bitmap => Arc::new(
+                BitmapIndex::new(
+                    name.to_string(),
+                    self.name().to_string(),
+                    column_names,
+                    column_ids,
+                    data_types,
+                    is_unique,
+                )
+                .with_nulls_not_distinct(nulls_not_distinct),
+            ),
+            IndexType::BTree => {
+                // For single-column BTree, use BTreeIndex
+                // For multi-column, use MultiColumnIndex
+                if columns == 1 {
+                    Arc::new(
+                        BTreeIndex::new(
+                            name.to_string(),
+                            self.name().to_string(),
+                            column_ids[0],
+                            column_names[0].clone(),
+                            data_types[0],
+                            is_unique,
+                        )
+                        .with_nulls_not_distinct(nulls_not_distinct),
+                    )
+                } else {
+                    Arc::new(
+                        MultiColumnIndex::new(
+                            name.to_string(),
+                            self.name().to_string(),
+                            column_names,
+                            column_ids,
+                            data_types,
+                            is_unique,
+                        )
+                        .with_nulls_not_distinct(nulls_not_distinct),
+                    )
+                }
+            }
+            IndexType::MultiColumn => {
+                // MultiColumn always uses MultiColumnIndex
+                Arc::new(
+                    MultiColumnIndex::new(
+                        name.to_string(),
+                        self.name().to_string(),
+                        column_names,
+                        column_ids,
+                        data_types,
+                        is_unique,
+                    )
+                    .with_nulls_not_distinct(nulls_not_distinct),
+                )
+            }
+        }
+    }
+
+    /// Adds the rows visible to this table's transaction to an index
+    fn populate_index(&self, index: &dyn Index, col_indices: &[usize]) -> Result<()> {
+        // Populate the index with existing data
+        for row_id in self.version_store.get_all_visible_row_ids(self.txn_id) {
+            if let Some(version) = self.version_store.get_visible_version(row_id, self.txn_id) {
+                if !version.is_deleted() {
+                    let values: Vec<Value> = col_indices
+                        .iter()
+                        .map(|&idx| {
+                            version
+                                .data
+                                .get(idx)
+                                .cloned()
+                                .unwrap_or(Value::Null(DataType::Null))
+                        })
+                        .collect();
+                    index.add(&values, row_id, row_id)?;
+                }
+            }
+        }
+
+        // Also add any local uncommitted data
+        let txn_versions = self.txn_versions.read().unwrap();
+        for (row_id, version) in txn_versions.iter_local() {
+            if !version.is_deleted() && !self.version_store.quick_check_row_existence(row_id) {
+                let values: Vec<Value> = col_indices
+                    .iter()
+                    .map(|&idx| {
+                        version
+                            .data
+                            .get(idx)
+                            .cloned()
+                            .unwrap_or(Value::Null(DataType::Null))
+                    })
+                    .collect();
+                index.add(&values, row_id, row_id)?;
+            }
+        }
+        drop(txn_versions);
+
+        Ok(())
+    }
+
+    /// Checks that every non-NULL row visible to this table's transaction
+    /// can be found through an index
+    /// Populates a replacement index from the latest version of every row
+    ///
+    /// Live indexes hold every committed row, including rows committed after
+    /// this transaction's snapshot, so the replacement must too. Callers
+    /// hold the version store's rebuild lock so no commit lands meanwhile.
+    fn populate_rebuilt_index(&self, index: &dyn Index, col_indices: &[usize]) -> Result<()> {
+        self.version_store
+            .for_each_latest_version(|row_id, version| {
+                index.add(&row_key(&version.data, col_indices), row_id, row_id)
+            })?;
+
+        let txn_versions = self.txn_versions.read().unwrap();
+        for (row_id, version) in txn_versions.iter_local() {
+            if !version.is_deleted() && !self.version_store.quick_check_row_existence(row_id) {
+                index.add(&row_key(&version.data, col_indices), row_id, row_id)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_rebuilt_index(&self, index: &dyn Index, col_indices: &[usize]) -> Result<()> {
+        self.version_store
+            .for_each_latest_version(|row_id, version| {
+                let values = row_key(&version.data, col_indices);
+                if values.iter().any(Value::is_null)
+                    || index.get_row_ids_equal(&values).contains(&row_id)
+                {
+                    return Ok(());
+                }
+                Err(Error::internal(format!(
+                    "rebuilt index '{}' does not match the table data: row {} is missing",
+                    index.name(),
+                    row_id
+                )))
+            })
+    }
+
     /// Gets the current auto-increment value
     pub fn get_current_auto_increment_value(&self) -> i64 {
         self.version_store.get_auto_increment_counter()
@@ -160,6 +439,36 @@ impl MVCCTable {
             row.truncate(schema_cols);
         }
 
+        if !self.compressed_columns.is_empty() {
+            compression::decompress_row(&mut row, &self.compressed_columns, schema);
+        }
+
+        row
+    }
+
+    /// A stored row as filters see it: normalized and with COMPRESSED
+    /// columns decoded. Borrows the row when the table has none.
+    fn decoded_row<'a>(&self, row: &'a Row) -> Cow<'a, Row> {
+        if self.compressed_columns.is_empty() {
+            Cow::Borrowed(row)
+        } else {
+            Cow::Owned(self.normalize_row_to_schema(row.clone(), &self.cached_schema))
+        }
+    }
+
+    /// Decode the COMPRESSED columns of a stored row
+    fn decode_row(&self, mut row: Row) -> Row {
+        if !self.compressed_columns.is_empty() {
+            compression::decompress_row(&mut row, &self.compressed_columns, &self.cached_schema);
+        }
+        row
+    }
+
+    /// Encode the COMPRESSED columns of a row before it is stored
+    fn encode_row(&self, mut row: Row) -> Row {
+        if !self.compressed_columns.is_empty() {
+            compression::compress_row(&mut row, &self.compressed_columns);
+        }
         row
     }
 
@@ -209,11 +518,23 @@ impl MVCCTable {
                 return None;
             }
 
-            if let Some(index) = self.version_store.get_index_by_column(col_name) {
+            if let Some(index) = self.usable_index_by_column(col_name) {
                 return self.query_index_with_operator(&*index, operator, value);
             }
         }
 
+        // IS NULL / IS NOT NULL on an index that keeps NULL rows apart
+        if let Some(null_check) = expr
+            .as_any()
+            .downcast_ref::<crate::storage::expression::NullCheckExpr>()
+        {
+            let index = self.usable_index_by_column(null_check.get_column_name()?)?;
+            if index.supports_null_lookup() {
+                return Some(index.get_row_ids_null(null_check.is_null_check()));
+            }
+            return None;
+        }
+
         // OPTIMIZATION: Handle OR expressions with HYBRID index optimization
         // For (indexed_col = 'a' OR non_indexed_col = 'b'):
         // - Use index for indexed_col operands
@@ -281,7 +602,7 @@ impl MVCCTable {
             // Only handle positive IN (not NOT IN)
             if !in_list.is_not() {
                 if let Some(col_name) = in_list.get_column_name() {
-                    if let Some(index) = self.version_store.get_index_by_column(col_name) {
+                    if let Some(index) = self.usable_index_by_column(col_name) {
                         // Use the efficient get_row_ids_in method
                         let values = in_list.get_values();
                         let row_ids = index.get_row_ids_in(values);
@@ -299,7 +620,7 @@ impl MVCCTable {
                 return None;
             }
 
-            if let Some(index) = self.version_store.get_index_by_column(col_name) {
+            if let Some(index) = self.usable_index_by_column(col_name) {
                 // Create range from prefix to prefix + '\xff' (highest byte)
                 // This captures all strings starting with the prefix
                 let min_value = Value::text(&prefix);
@@ -351,9 +672,7 @@ impl MVCCTable {
 
         // Try to find a multi-column index that covers these equality columns
         if eq_columns.len() >= 2 {
-            if let Some((multi_idx, matched_count)) =
-                self.version_store.get_multi_column_index(&eq_columns)
-            {
+            if let Some((multi_idx, matched_count)) = self.usable_multi_column_index(&eq_columns) {
                 // Build the values array in index column order
                 let index_columns = multi_idx.column_names();
                 let mut values: Vec<Value> = Vec::with_capacity(matched_count);
@@ -405,9 +724,7 @@ impl MVCCTable {
 
                         // Check single-column indexes for uncovered columns
                         for col_name in uncovered_columns {
-                            if let Some(single_idx) =
-                                self.version_store.get_index_by_column(col_name)
-                            {
+                            if let Some(single_idx) = self.usable_index_by_column(col_name) {
                                 if let Some(ops) = column_comparisons.get(col_name) {
                                     // Try equality first
                                     if let Some((_, val)) =
@@ -447,7 +764,7 @@ impl MVCCTable {
         let mut all_row_ids: Vec<Vec<i64>> = Vec::new();
 
         for (col_name, ops) in &column_comparisons {
-            if let Some(index) = self.version_store.get_index_by_column(col_name) {
+            if let Some(index) = self.usable_index_by_column(col_name) {
                 // Check for range pattern: col >= min AND col <= max
                 let mut min_val: Option<(&Value, bool)> = None; // (value, inclusive)
                 let mut max_val: Option<(&Value, bool)> = None;
@@ -688,8 +1005,9 @@ impl MVCCTable {
                 }
 
                 // NULL values are allowed in unique indexes (multiple NULLs are distinct)
-                // For multi-column unique indexes, if ANY column is NULL, it's allowed
-                if values.iter().any(|v| v.is_null()) {
+                // For multi-column unique indexes, if ANY column is NULL, it's allowed,
+                // unless the index was created with NULLS NOT DISTINCT
+                if !index.nulls_not_distinct() && values.iter().any(|v| v.is_null()) {
                     return Ok(());
                 }
 
@@ -717,14 +1035,129 @@ impl MVCCTable {
             })
     }
 
+    /// Check the unique indexes against every row a bulk load wrote
+    ///
+    /// A row conflicts with committed rows the transaction does not delete or
+    /// re-key, and with other rows of the same transaction.
+    fn check_deferred_unique_constraints(&self) -> Result<()> {
+        let txn_versions = self.txn_versions.read().unwrap();
+
+        self.version_store
+            .for_each_unique_index(|index_name, index| {
+                let column_ids = index.column_ids();
+                if column_ids.is_empty() {
+                    return Ok(());
+                }
+
+                // Keys the transaction gives to rows, and the rows whose
+                // committed key in this index it removes
+                let mut rekeyed = Int64Set::default();
+                let mut keys: Vec<(i64, Vec<Value>, &Row)> = Vec::new();
+                for (row_id, new_version, old_row) in txn_versions.iter_local_with_old() {
+                    let old_key = old_row.map(|old| index_key(old, column_ids));
+                    if new_version.is_deleted() {
+                        if old_key.is_some() {
+                            rekeyed.insert(row_id);
+                        }
+                        continue;
+                    }
+                    let key = index_key(&new_version.data, column_ids);
+                    match old_key {
+                        Some(old_key) if old_key == key => continue,
+                        Some(_) => {
+                            rekeyed.insert(row_id);
+                        }
+                        None => {}
+                    }
+                    if index.nulls_not_distinct() || !key.iter().any(|v| v.is_null()) {
+                        keys.push((row_id, key, &new_version.data));
+                    }
+                }
+
+                let mut seen: FxHashMap<&[Value], i64> = FxHashMap::default();
+                for (row_id, key, row) in &keys {
+                    let taken = seen.insert(key, *row_id).is_some()
+                        || index
+                            .get_row_ids_equal(key)
+                            .iter()
+                            .any(|id| id != row_id && !rekeyed.contains(id));
+                    if taken {
+                        let col_names: Vec<&str> = column_ids
+                            .iter()
+                            .map(|&col_id| {
+                                self.cached_schema
+                                    .columns
+                                    .get(col_id as usize)
+                                    .map(|c| c.name.as_str())
+                                    .unwrap_or("unknown")
+                            })
+                            .collect();
+                        let row = self.decode_row((*row).clone());
+                        return Err(Error::unique_constraint(
+                            index_name,
+                            col_names.join(", "),
+                            format!("{:?} in row {:?}", key, row.as_slice()),
+                        ));
+                    }
+                }
+                Ok(())
+            })
+    }
+
+    /// Update each index once for all rows a bulk load wrote
+    ///
+    /// Entries of deleted and re-keyed rows are removed first, then the new
+    /// keys are added in a single batch per index.
+    fn apply_index_changes_in_batches(&self, index_names: &[String]) -> Result<()> {
+        let indexes: Vec<Arc<dyn Index>> = index_names
+            .iter()
+            .filter_map(|name| self.version_store.get_index(name))
+            .filter(|index| !index.column_ids().is_empty())
+            .collect();
+        let mut added: Vec<HashMap<i64, Vec<Value>>> = vec![HashMap::new(); indexes.len()];
+
+        let txn_versions = self.txn_versions.read().unwrap();
+        for (row_id, new_version, old_row) in txn_versions.iter_local_with_old() {
+            for (index, added) in indexes.iter().zip(added.iter_mut()) {
+                let column_ids = index.column_ids();
+                let old_values = old_row.map(|r| index_key(r, column_ids));
+                if new_version.is_deleted() {
+                    let old_values =
+                        old_values.unwrap_or_else(|| index_key(&new_version.data, column_ids));
+                    let _ = index.remove(&old_values, row_id, row_id);
+                    continue;
+                }
+                let new_values = index_key(&new_version.data, column_ids);
+                if old_values.as_ref() != Some(&new_values) {
+                    if let Some(old_values) = &old_values {
+                        let _ = index.remove(old_values, row_id, row_id);
+                    }
+                    added.insert(row_id, new_values);
+                }
+            }
+        }
+
+        for (index, added) in indexes.iter().zip(added.iter()) {
+            index.add_batch(added)?;
+        }
+        Ok(())
+    }
+
     /// Commits the transaction's local changes
     ///
     /// This method updates indexes before committing versions to the global store.
     pub fn commit(&mut self) -> Result<()> {
+        // Index updates and published versions must not straddle a REINDEX swap
+        let version_store = Arc::clone(&self.version_store);
+        let _commit = version_store.lock_for_commit();
+
         // Update indexes using already-cached old versions (no extra lookups needed)
         let index_names = self.version_store.list_indexes();
+        let bulk_load = self.txn_versions.read().unwrap().is_bulk_load();
 
-        if !index_names.is_empty() {
+        if bulk_load && !index_names.is_empty() {
+            self.apply_index_changes_in_batches(&index_names)?;
+        } else if !index_names.is_empty() {
             let txn_versions = self.txn_versions.read().unwrap();
             for (row_id, new_version, old_row) in txn_versions.iter_local_with_old() {
                 let is_deleted = new_version.is_deleted();
@@ -848,8 +1281,10 @@ impl MVCCTable {
         let txn_versions = self.txn_versions.read().unwrap();
         let schema = &self.cached_schema;
 
-        // Check if we have local versions (uncommitted changes in this transaction)
-        let has_local = txn_versions.has_local_changes();
+        // Check if we have local versions (uncommitted changes in this transaction).
+        // Stored COMPRESSED values can't be filtered in the version store, so
+        // those tables take the path that filters decoded rows.
+        let has_local = txn_versions.has_local_changes() || !self.compressed_columns.is_empty();
 
         if !has_local {
             // No local versions - use arena-based batch fetch for maximum performance
@@ -925,8 +1360,10 @@ impl MVCCTable {
         let txn_versions = self.txn_versions.read().unwrap();
         let schema = &self.cached_schema;
 
-        // Check if we have local versions (uncommitted changes in this transaction)
-        let has_local = txn_versions.has_local_changes();
+        // Check if we have local versions (uncommitted changes in this transaction).
+        // Stored COMPRESSED values can't be filtered in the version store, so
+        // those tables take the path that filters decoded rows.
+        let has_local = txn_versions.has_local_changes() || !self.compressed_columns.is_empty();
 
         if !has_local {
             // No local versions - use optimized path with true LIMIT pushdown
@@ -1020,8 +1457,10 @@ impl MVCCTable {
         let txn_versions = self.txn_versions.read().unwrap();
         let schema = &self.cached_schema;
 
-        // Check if we have local versions (uncommitted changes in this transaction)
-        let has_local = txn_versions.has_local_changes();
+        // Check if we have local versions (uncommitted changes in this transaction).
+        // Stored COMPRESSED values can't be filtered in the version store, so
+        // those tables take the path that filters decoded rows.
+        let has_local = txn_versions.has_local_changes() || !self.compressed_columns.is_empty();
 
         if !has_local {
             // No local versions - use optimized unordered path with true early termination
@@ -1141,13 +1580,14 @@ impl Table for MVCCTable {
             false, // auto_increment
             default_expr,
             default_value,
-            None, // check_expr
+            Vec::new(), // checks
         );
         {
             let mut schema = self.version_store.schema_mut();
             schema.add_column(column.clone())?;
         }
         self.cached_schema.add_column(column)?;
+        self.compressed_columns = compression::compressed_columns(&self.cached_schema);
         Ok(())
     }
 
@@ -1158,6 +1598,7 @@ impl Table for MVCCTable {
             schema.remove_column(name)?;
         }
         self.cached_schema.remove_column(name)?;
+        self.compressed_columns = compression::compressed_columns(&self.cached_schema);
         Ok(())
     }
 
@@ -1200,12 +1641,13 @@ impl Table for MVCCTable {
         let row_id = self.extract_row_pk(&row);
 
         // Check if row already exists in local versions
-        {
+        let bulk_load = {
             let txn_versions = self.txn_versions.read().unwrap();
             if txn_versions.has_locally_seen(row_id) && txn_versions.get(row_id).is_some() {
                 return Err(Error::primary_key_constraint(row_id));
             }
-        }
+            txn_versions.is_bulk_load()
+        };
 
         // Check if row exists in global store
         if self.version_store.quick_check_row_existence(row_id) {
@@ -1216,13 +1658,16 @@ impl Table for MVCCTable {
             }
         }
 
-        // Check unique index constraints
-        self.check_unique_constraints(&row, row_id)?;
+        // Check unique index constraints; a bulk load checks them at commit
+        if !bulk_load {
+            self.check_unique_constraints(&row, row_id)?;
+        }
 
         // Clone the row for returning (with AUTO_INCREMENT value applied)
         let inserted_row = row.clone();
 
         // Add to transaction's local version store
+        let row = self.encode_row(row);
         self.txn_versions.write().unwrap().put(row_id, row, false)?;
 
         Ok(inserted_row)
@@ -1277,7 +1722,11 @@ impl Table for MVCCTable {
                 if let Some(row) = row {
                     // Normalize row to match current schema (handles ALTER TABLE ADD/DROP COLUMN)
                     let row = self.normalize_row_to_schema(row, schema);
-                    let (updated_row, _) = setter(row)?;
+                    let (updated_row, changed) = setter(row)?;
+                    if !changed {
+                        return Ok(0);
+                    }
+                    let updated_row = self.encode_row(updated_row);
                     self.txn_versions
                         .write()
                         .unwrap()
@@ -1330,21 +1779,27 @@ impl Table for MVCCTable {
                     }
                 }
 
-                // Step 3: Apply setter to all rows
-                let update_count = local_rows_to_update.len() + rows_with_originals.len();
-
-                // OPTIMIZATION: Apply setter in-place, avoiding intermediate Vec allocation
+                // Step 3: Apply setter to all rows, keeping only the changed ones
                 // Update local rows (these already have write-set tracking)
-                for (_, row) in &mut local_rows_to_update {
-                    let (updated_row, _) = setter(std::mem::take(row))?;
-                    *row = updated_row;
+                let mut changed_local = Vec::with_capacity(local_rows_to_update.len());
+                for (row_id, row) in local_rows_to_update {
+                    let (updated_row, changed) = setter(row)?;
+                    if changed {
+                        changed_local.push((row_id, self.encode_row(updated_row)));
+                    }
                 }
+                let local_rows_to_update = changed_local;
 
                 // Update rows from version store with pre-fetched originals
-                for (_, row, _) in &mut rows_with_originals {
-                    let (updated_row, _) = setter(std::mem::take(row))?;
-                    *row = updated_row;
+                let mut changed_originals = Vec::with_capacity(rows_with_originals.len());
+                for (row_id, row, original) in rows_with_originals {
+                    let (updated_row, changed) = setter(row)?;
+                    if changed {
+                        changed_originals.push((row_id, self.encode_row(updated_row), original));
+                    }
                 }
+                let rows_with_originals = changed_originals;
+                let update_count = local_rows_to_update.len() + rows_with_originals.len();
 
                 // Batch put - first the local rows (use regular put)
                 {
@@ -1411,13 +1866,11 @@ impl Table for MVCCTable {
         // Apply setter to all rows
         let mut all_updated: Vec<(i64, Row)> =
             Vec::with_capacity(rows_to_update.len() + local_rows_to_update.len());
-        for (row_id, row) in rows_to_update {
-            let (updated_row, _) = setter(row)?;
-            all_updated.push((row_id, updated_row));
-        }
-        for (row_id, row) in local_rows_to_update {
-            let (updated_row, _) = setter(row)?;
-            all_updated.push((row_id, updated_row));
+        for (row_id, row) in rows_to_update.into_iter().chain(local_rows_to_update) {
+            let (updated_row, changed) = setter(row)?;
+            if changed {
+                all_updated.push((row_id, self.encode_row(updated_row)));
+            }
         }
 
         // Batch update all rows at once
@@ -1486,7 +1939,7 @@ impl Table for MVCCTable {
 
                     if let Some(row) = row {
                         // Re-apply filter (index may be partial match)
-                        if expr.evaluate(&row).unwrap_or(false) {
+                        if expr.evaluate(&self.decoded_row(&row)).unwrap_or(false) {
                             // Row is already owned from the above block, no extra clone needed
                             self.txn_versions.write().unwrap().put(row_id, row, true)?;
                             delete_count += 1;
@@ -1516,7 +1969,7 @@ impl Table for MVCCTable {
             if let Some(row) = local_row {
                 // Apply filter on local row (no clone needed - already have it)
                 if let Some(expr) = where_expr {
-                    match expr.evaluate(&row) {
+                    match expr.evaluate(&self.decoded_row(&row)) {
                         Ok(true) => {}
                         Ok(false) => continue,
                         Err(_) => continue,
@@ -1534,7 +1987,7 @@ impl Table for MVCCTable {
 
                 // Apply filter BEFORE cloning - evaluate on reference
                 if let Some(expr) = where_expr {
-                    match expr.evaluate(&version.data) {
+                    match expr.evaluate(&self.decoded_row(&version.data)) {
                         Ok(true) => {}
                         Ok(false) => continue, // Skip clone entirely!
                         Err(_) => continue,
@@ -1568,7 +2021,7 @@ impl Table for MVCCTable {
             if let Some(row) = row {
                 // Apply filter
                 if let Some(expr) = where_expr {
-                    match expr.evaluate(&row) {
+                    match expr.evaluate(&self.decoded_row(&row)) {
                         Ok(true) => {}
                         Ok(false) => continue,
                         Err(_) => continue,
@@ -1717,6 +2170,36 @@ impl Table for MVCCTable {
         self.txn_versions.read().unwrap().has_local_changes()
     }
 
+    fn check_deferred_constraints(&self) -> Result<()> {
+        if !self.txn_versions.read().unwrap().is_bulk_load() {
+            return Ok(());
+        }
+        self.check_deferred_unique_constraints()
+    }
+
+    fn lock_rows(&self, where_expr: Option<&dyn Expression>, mode: RowLockMode) -> Result<usize> {
+        // Rows written by this transaction are matched with evaluate_fast,
+        // which needs the filter resolved against the schema
+        let where_expr = where_expr.map(|expr| {
+            let mut expr = expr.clone_box();
+            if !expr.is_prepared() {
+                expr.prepare_for_schema(&self.cached_schema);
+            }
+            expr
+        });
+        let row_ids: Vec<i64> = self
+            .collect_visible_rows(where_expr.as_deref())
+            .into_iter()
+            .map(|(row_id, _)| row_id)
+            .collect();
+
+        let mut txn_versions = self.txn_versions.write().unwrap();
+        for &row_id in &row_ids {
+            txn_versions.lock_row(row_id, mode)?;
+        }
+        Ok(row_ids.len())
+    }
+
     fn get_pending_versions(&self) -> Vec<(i64, Row, bool, i64)> {
         let txn_versions = self.txn_versions.read().unwrap();
         txn_versions
@@ -1743,6 +2226,17 @@ impl Table for MVCCTable {
         columns: &[&str],
         is_unique: bool,
         index_type: Option<IndexType>,
+    ) -> Result<()> {
+        self.create_index_with_nulls(name, columns, is_unique, false, index_type)
+    }
+
+    fn create_index_with_nulls(
+        &self,
+        name: &str,
+        columns: &[&str],
+        is_unique: bool,
+        nulls_not_distinct: bool,
+        index_type: Option<IndexType>,
     ) -> Result<()> {
         if columns.is_empty() {
             return Err(Error::internal("index must have at least one column"));
@@ -1760,6 +2254,9 @@ impl Table for MVCCTable {
             let (col_idx, col) = schema
                 .find_column(col_name)
                 .ok_or(Error::ColumnNotFoundNamed(col_name.to_string()))?;
+            if col.compressed {
+                return Err(compressed_index_error(&col.name));
+            }
             column_names.push(col.name.clone());
             column_ids.push(col.id as i32);
             data_types.push(col.data_type);
@@ -1831,97 +2328,16 @@ impl Table for MVCCTable {
             }
         }
 
-        // Create the appropriate index type
-        let index: Arc<dyn Index> = match chosen_type {
-            IndexType::Hash => Arc::new(HashIndex::new(
-                name.to_string(),
-                self.name().to_string(),
-                column_names,
-                column_ids,
-                data_types,
-                is_unique,
-            )),
-            IndexType::Bitmap => Arc::new(BitmapIndex::new(
-                name.to_string(),
-                self.name().to_string(),
-                column_names,
-                column_ids,
-                data_types,
-                is_unique,
-            )),
-            IndexType::BTree => {
-                // For single-column BTree, use BTreeIndex
-                // For multi-column, use MultiColumnIndex
-                if columns.len() == 1 {
-                    Arc::new(BTreeIndex::new(
-                        name.to_string(),
-                        self.name().to_string(),
-                        column_ids[0],
-                        column_names[0].clone(),
-                        data_types[0],
-                        is_unique,
-                    ))
-                } else {
-                    Arc::new(MultiColumnIndex::new(
-                        name.to_string(),
-                        self.name().to_string(),
-                        column_names,
-                        column_ids,
-                        data_types,
-                        is_unique,
-                    ))
-                }
-            }
-            IndexType::MultiColumn => {
-                // MultiColumn always uses MultiColumnIndex
-                Arc::new(MultiColumnIndex::new(
-                    name.to_string(),
-                    self.name().to_string(),
-                    column_names,
-                    column_ids,
-                    data_types,
-                    is_unique,
-                ))
-            }
-        };
-
-        // Populate the index with existing data
-        for row_id in self.version_store.get_all_visible_row_ids(self.txn_id) {
-            if let Some(version) = self.version_store.get_visible_version(row_id, self.txn_id) {
-                if !version.is_deleted() {
-                    let values: Vec<Value> = col_indices
-                        .iter()
-                        .map(|&idx| {
-                            version
-                                .data
-                                .get(idx)
-                                .cloned()
-                                .unwrap_or(Value::Null(DataType::Null))
-                        })
-                        .collect();
-                    index.add(&values, row_id, row_id)?;
-                }
-            }
-        }
-
-        // Also add any local uncommitted data
-        let txn_versions = self.txn_versions.read().unwrap();
-        for (row_id, version) in txn_versions.iter_local() {
-            if !version.is_deleted() && !self.version_store.quick_check_row_existence(row_id) {
-                let values: Vec<Value> = col_indices
-                    .iter()
-                    .map(|&idx| {
-                        version
-                            .data
-                            .get(idx)
-                            .cloned()
-                            .unwrap_or(Value::Null(DataType::Null))
-                    })
-                    .collect();
-                index.add(&values, row_id, row_id)?;
-            }
-        }
-        drop(txn_versions);
+        let index = self.new_index(
+            name,
+            column_names,
+            column_ids,
+            data_types,
+            is_unique,
+            nulls_not_distinct,
+            chosen_type,
+        );
+        self.populate_index(&*index, &col_indices)?;
 
         // Add to version store
         self.version_store.add_index(name.to_string(), index);
@@ -1940,14 +2356,51 @@ impl Table for MVCCTable {
         Ok(())
     }
 
+    fn rebuild_indexes(&self, names: &[&str]) -> Result<()> {
+        // Commits update the live indexes; holding them off until the swap
+        // keeps their changes from being lost with the replaced indexes
+        let _rebuild = self.version_store.lock_for_index_rebuild();
+        let schema = self.version_store.schema();
+        let mut rebuilt = Vec::with_capacity(names.len());
+
+        for name in names {
+            let old = self
+                .version_store
+                .get_index(name)
+                .ok_or_else(|| Error::IndexNotFoundByName(name.to_string()))?;
+
+            let mut col_indices = Vec::with_capacity(old.column_names().len());
+            for col_name in old.column_names() {
+                let (col_idx, _) = schema
+                    .find_column(col_name)
+                    .ok_or_else(|| Error::ColumnNotFoundNamed(col_name.clone()))?;
+                col_indices.push(col_idx);
+            }
+
+            let index = self.new_index(
+                name,
+                old.column_names().to_vec(),
+                old.column_ids().to_vec(),
+                old.data_types().to_vec(),
+                old.is_unique(),
+                old.nulls_not_distinct(),
+                old.index_type(),
+            );
+            self.populate_rebuilt_index(&*index, &col_indices)?;
+            self.validate_rebuilt_index(&*index, &col_indices)?;
+            rebuilt.push((name.to_string(), index));
+        }
+
+        self.version_store.replace_indexes(rebuilt);
+        Ok(())
+    }
+
     fn has_index_on_column(&self, column_name: &str) -> bool {
-        self.version_store
-            .get_index_by_column(column_name)
-            .is_some()
+        self.usable_index_by_column(column_name).is_some()
     }
 
     fn get_index_on_column(&self, column_name: &str) -> Option<std::sync::Arc<dyn Index>> {
-        self.version_store.get_index_by_column(column_name)
+        self.usable_index_by_column(column_name)
     }
 
     fn get_index(&self, name: &str) -> Option<std::sync::Arc<dyn Index>> {
@@ -1958,7 +2411,7 @@ impl Table for MVCCTable {
         &self,
         predicate_columns: &[&str],
     ) -> Option<(std::sync::Arc<dyn Index>, usize)> {
-        self.version_store.get_multi_column_index(predicate_columns)
+        self.usable_multi_column_index(predicate_columns)
     }
 
     fn create_btree_index(
@@ -1972,6 +2425,9 @@ impl Table for MVCCTable {
         let (col_idx, col) = schema
             .find_column(column_name)
             .ok_or(Error::ColumnNotFound)?;
+        if col.compressed {
+            return Err(compressed_index_error(&col.name));
+        }
 
         // Generate index name
         let index_name = custom_name
@@ -2068,6 +2524,9 @@ impl Table for MVCCTable {
             let (col_idx, col) = schema
                 .find_column(col_name)
                 .ok_or(Error::ColumnNotFoundNamed(col_name.to_string()))?;
+            if col.compressed {
+                return Err(compressed_index_error(&col.name));
+            }
             column_names.push(col.name.clone());
             column_ids.push(col.id as i32);
             data_types.push(col.data_type);
@@ -2157,7 +2616,7 @@ impl Table for MVCCTable {
 
     fn get_index_min_value(&self, column_name: &str) -> Option<Value> {
         // Try to find an index on this column and get its minimum value
-        if let Some(index) = self.version_store.get_index_by_column(column_name) {
+        if let Some(index) = self.usable_index_by_column(column_name) {
             return index.get_min_value();
         }
         None
@@ -2165,7 +2624,7 @@ impl Table for MVCCTable {
 
     fn get_index_max_value(&self, column_name: &str) -> Option<Value> {
         // Try to find an index on this column and get its maximum value
-        if let Some(index) = self.version_store.get_index_by_column(column_name) {
+        if let Some(index) = self.usable_index_by_column(column_name) {
             return index.get_max_value();
         }
         None
@@ -2184,7 +2643,7 @@ impl Table for MVCCTable {
         offset: usize,
     ) -> Option<Vec<Row>> {
         // Check if column has an index
-        let index = self.version_store.get_index_by_column(column_name)?;
+        let index = self.usable_index_by_column(column_name)?;
 
         // Try using the efficient ordered iteration method (available in B-tree indexes)
         // We request more row IDs than needed to account for invisible rows
@@ -2208,7 +2667,7 @@ impl Table for MVCCTable {
                         continue;
                     }
 
-                    rows.push(version.data.clone());
+                    rows.push(self.decode_row(version.data.clone()));
 
                     // Check if we've reached the limit
                     if rows.len() >= limit {
@@ -2258,7 +2717,7 @@ impl Table for MVCCTable {
                         continue;
                     }
 
-                    rows.push(version.data.clone());
+                    rows.push(self.decode_row(version.data.clone()));
 
                     // Check if we've reached the limit
                     if rows.len() >= limit {
@@ -2271,12 +2730,120 @@ impl Table for MVCCTable {
         Some(rows)
     }
 
+    fn ordered_index_for(&self, columns: &[&str]) -> Option<String> {
+        self.usable_ordered_index(columns)
+            .map(|index| index.name().to_string())
+    }
+
+    fn collect_rows_in_index_order(
+        &self,
+        columns: &[&str],
+        ascending: bool,
+        nulls_first: bool,
+        limit: usize,
+        offset: usize,
+    ) -> Option<Vec<Row>> {
+        // The indexes only hold committed rows; this transaction's own
+        // changes need the regular scan
+        if self.txn_versions.read().unwrap().has_local_changes() {
+            return None;
+        }
+        let index = self.usable_ordered_index(columns)?;
+        let leading = *index.column_ids().first()? as usize;
+
+        let visible = |row_id: i64| {
+            self.version_store
+                .get_visible_version(row_id, self.txn_id)
+                .filter(|version| !version.is_deleted())
+                .map(|version| self.decode_row(version.data))
+        };
+        let leading_is_null = move |row: &Row| row.get(leading).is_none_or(|v| v.is_null());
+
+        // NULL keys sort first in the index, so they form the head of an
+        // ascending walk and the tail of a descending one
+        let nulls = Self::index_order_row_ids(&index, true)
+            .filter_map(visible)
+            .take_while(leading_is_null);
+        let values: Box<dyn Iterator<Item = Row>> = if ascending {
+            Box::new(
+                Self::index_order_row_ids(&index, true)
+                    .filter_map(visible)
+                    .skip_while(leading_is_null),
+            )
+        } else {
+            Box::new(
+                Self::index_order_row_ids(&index, false)
+                    .filter_map(visible)
+                    .take_while(move |row| !leading_is_null(row)),
+            )
+        };
+        let ordered: Box<dyn Iterator<Item = Row>> = if nulls_first {
+            Box::new(nulls.chain(values))
+        } else {
+            Box::new(values.chain(nulls))
+        };
+        Some(ordered.skip(offset).take(limit).collect())
+    }
+
+    fn covering_index_for(&self, columns: &[usize]) -> Option<String> {
+        self.usable_covering_index(columns)
+            .map(|index| index.name().to_string())
+    }
+
+    fn collect_rows_index_only(
+        &self,
+        where_expr: &dyn Expression,
+        columns: &[usize],
+    ) -> Option<Vec<Row>> {
+        // The indexes only hold committed rows; this transaction's own
+        // changes need the regular scan
+        if self.txn_versions.read().unwrap().has_local_changes() {
+            return None;
+        }
+        let index = self.usable_covering_index(columns)?;
+        let schema = &self.cached_schema;
+        let row_ids = self.try_index_lookup(where_expr, schema)?;
+
+        let mut rows = Vec::with_capacity(row_ids.len());
+        for row_id in row_ids {
+            let stored = if self
+                .version_store
+                .is_latest_version_visible(row_id, self.txn_id)
+            {
+                index.get_row_values(row_id)
+            } else {
+                None
+            };
+            let row = match stored {
+                Some(values) => {
+                    let mut row_values = vec![Value::null_unknown(); schema.columns.len()];
+                    for (&column_id, value) in index.column_ids().iter().zip(values) {
+                        row_values[column_id as usize] = value;
+                    }
+                    Row::from_values(row_values)
+                }
+                // The transaction sees an older version than the index
+                // holds; read that version instead
+                None => match self.version_store.get_visible_version(row_id, self.txn_id) {
+                    Some(version) if !version.is_deleted() => {
+                        self.normalize_row_to_schema(version.data, schema)
+                    }
+                    _ => continue,
+                },
+            };
+            if where_expr.evaluate_fast(&row) {
+                rows.push(row);
+            }
+        }
+        Some(rows)
+    }
+
     fn collect_rows_grouped_by_partition(
         &self,
         column_name: &str,
     ) -> Option<Vec<(Value, Vec<Row>)>> {
         // Check if column has an index
-        let index = self.version_store.get_index_by_column(column_name)?;
+        let index = self.usable_index_by_column(column_name)?;
 
         // Get all unique values from the index (partition keys)
         let all_values = index.get_all_values();
@@ -2296,7 +2863,7 @@ impl Table for MVCCTable {
             for row_id in row_ids {
                 if let Some(version) = self.version_store.get_visible_version(row_id, self.txn_id) {
                     if !version.is_deleted() {
-                        partition_rows.push(version.data.clone());
+                        partition_rows.push(self.decode_row(version.data.clone()));
                     }
                 }
             }
@@ -2311,7 +2878,7 @@ impl Table for MVCCTable {
 
     fn get_partition_values(&self, column_name: &str) -> Option<Vec<Value>> {
         // Get index for the column
-        let index = self.version_store.get_index_by_column(column_name)?;
+        let index = self.usable_index_by_column(column_name)?;
         // Return all distinct values from the index
         Some(index.get_all_values())
     }
@@ -2322,7 +2889,7 @@ impl Table for MVCCTable {
         partition_value: &Value,
     ) -> Option<Vec<Row>> {
         // Get index for the column
-        let index = self.version_store.get_index_by_column(column_name)?;
+        let index = self.usable_index_by_column(column_name)?;
 
         // Get row IDs for this partition value
         let row_ids = index.get_row_ids_equal(std::slice::from_ref(partition_value));
@@ -2332,7 +2899,7 @@ impl Table for MVCCTable {
         for row_id in row_ids {
             if let Some(version) = self.version_store.get_visible_version(row_id, self.txn_id) {
                 if !version.is_deleted() {
-                    rows.push(version.data.clone());
+                    rows.push(self.decode_row(version.data.clone()));
                 }
             }
         }
@@ -2355,7 +2922,7 @@ impl Table for MVCCTable {
         column_type: DataType,
         nullable: bool,
         _auto_increment: Option<bool>,
-        _check_expr: Option<Option<String>>,
+        _checks: Option<Vec<CheckConstraint>>,
     ) -> Result<()> {
         // This would need mutable access to schema
         // For now, return error - this operation should go through the engine
@@ -2419,6 +2986,29 @@ impl Table for MVCCTable {
             .filter_map(|name| self.cached_schema.find_column(name).map(|(idx, _)| idx))
             .collect();
 
+        // Versions before the history horizon may have been reclaimed, so
+        // an older point could silently read the wrong state
+        let (horizon_time, horizon_txn) = self.version_store.history_horizon();
+        let oldest = match temporal_type.to_uppercase().as_str() {
+            "TRANSACTION" if temporal_value < horizon_txn => {
+                Some(format!("transaction {}", horizon_txn))
+            }
+            "TIMESTAMP" if temporal_value < horizon_time => Some(
+                chrono::DateTime::from_timestamp_nanos(horizon_time)
+                    .format("%Y-%m-%d %H:%M:%S%.f")
+                    .to_string(),
+            ),
+            _ => None,
+        };
+        if let Some(oldest) = oldest {
+            return Err(Error::invalid_argument(format!(
+                "AS OF {} is older than the retained history of table '{}' (oldest readable point: {})",
+                temporal_type.to_uppercase(),
+                self.cached_schema.table_name,
+                oldest
+            )));
+        }
+
         // Get all row IDs
         let row_ids = self.version_store.get_all_row_ids();
 
@@ -2444,7 +3034,7 @@ impl Table for MVCCTable {
                 if !v.is_deleted() {
                     // Apply filter
                     if let Some(e) = expr {
-                        match e.evaluate(&v.data) {
+                        match e.evaluate(&self.decoded_row(&v.data)) {
                             Ok(true) => {}
                             Ok(false) => continue,
                             Err(_) => continue,
@@ -2452,9 +3042,10 @@ impl Table for MVCCTable {
                     }
 
                     // Project columns
+                    let data = self.decoded_row(&v.data);
                     let projected: Vec<Value> = column_indices
                         .iter()
-                        .map(|&idx| v.data.get(idx).cloned().unwrap_or(Value::null_unknown()))
+                        .map(|&idx| data.get(idx).cloned().unwrap_or(Value::null_unknown()))
                         .collect();
                     rows.push(Row::from_values(projected));
                 }
@@ -2468,6 +3059,10 @@ impl Table for MVCCTable {
         Ok(Box::new(result))
     }
 
+    fn set_index_hint(&mut self, hint: Option<IndexHint>) {
+        self.index_hint = hint;
+    }
+
     fn explain_scan(&self, where_expr: Option<&dyn Expression>) -> ScanPlan {
         use crate::core::Operator;
 
@@ -2505,7 +3100,7 @@ impl Table for MVCCTable {
             if !matches!(value, Value::Boolean(_))
                 || !matches!(operator, Operator::Eq | Operator::Ne)
             {
-                if let Some(index) = self.version_store.get_index_by_column(col_name) {
+                if let Some(index) = self.usable_index_by_column(col_name) {
                     let condition = format!("{} {}", operator_to_string(operator), value);
                     return ScanPlan::IndexScan {
                         table: table_name,
@@ -2517,10 +3112,34 @@ impl Table for MVCCTable {
             }
         }
 
+        // Check for IS NULL / IS NOT NULL on an index that keeps NULL rows apart
+        if let Some(null_check) = expr
+            .as_any()
+            .downcast_ref::<crate::storage::expression::NullCheckExpr>()
+        {
+            if let Some(col_name) = null_check.get_column_name() {
+                if let Some(index) = self.usable_index_by_column(col_name) {
+                    if index.supports_null_lookup() {
+                        let condition = if null_check.is_null_check() {
+                            "IS NULL"
+                        } else {
+                            "IS NOT NULL"
+                        };
+                        return ScanPlan::IndexScan {
+                            table: table_name,
+                            index_name: index.name().to_string(),
+                            column: col_name.to_string(),
+                            condition: condition.to_string(),
+                        };
+                    }
+                }
+            }
+        }
+
         // Check for LIKE prefix pattern
         if let Some((col_name, prefix, negated)) = expr.get_like_prefix_info() {
             if !negated {
-                if let Some(index) = self.version_store.get_index_by_column(col_name) {
+                if let Some(index) = self.usable_index_by_column(col_name) {
                     return ScanPlan::IndexScan {
                         table: table_name,
                         index_name: index.name().to_string(),
@@ -2538,7 +3157,7 @@ impl Table for MVCCTable {
 
             for operand in or_operands {
                 if let Some((col_name, operator, value)) = operand.get_comparison_info() {
-                    if let Some(index) = self.version_store.get_index_by_column(col_name) {
+                    if let Some(index) = self.usable_index_by_column(col_name) {
                         let condition = format!("{} {}", operator_to_string(operator), value);
                         indexed_info.push((
                             index.name().to_string(),
@@ -2599,7 +3218,7 @@ impl Table for MVCCTable {
             // Try multi-column index if we have 2+ equality predicates
             if eq_columns.len() >= 2 {
                 if let Some((multi_idx, matched_count)) =
-                    self.version_store.get_multi_column_index(&eq_columns)
+                    self.usable_multi_column_index(&eq_columns)
                 {
                     let index_columns = multi_idx.column_names();
                     let mut columns = Vec::new();
@@ -2646,7 +3265,7 @@ impl Table for MVCCTable {
             }
 
             for (col_name, ops) in &column_conditions {
-                if let Some(index) = self.version_store.get_index_by_column(col_name) {
+                if let Some(index) = self.usable_index_by_column(col_name) {
                     // Simplify to a single condition string
                     let condition = if ops.len() == 1 {
                         let (op, val) = ops[0];
@@ -2707,6 +3326,35 @@ impl Table for MVCCTable {
     }
 }
 
+/// Values at the given schema positions, NULL for columns the row lacks
+fn row_key(row: &Row, col_indices: &[usize]) -> Vec<Value> {
+    col_indices
+        .iter()
+        .map(|&idx| row.get(idx).cloned().unwrap_or(Value::Null(DataType::Null)))
+        .collect()
+}
+
+/// Values of a row's indexed columns, NULL for columns the row lacks
+fn index_key(row: &Row, column_ids: &[i32]) -> Vec<Value> {
+    column_ids
+        .iter()
+        .map(|&col_id| {
+            row.get(col_id as usize)
+                .cloned()
+                .unwrap_or(Value::Null(DataType::Null))
+        })
+        .collect()
+}
+
+/// COMPRESSED values are only decoded on read, so an index would hold the
+/// encoded bytes
+fn compressed_index_error(column: &str) -> Error {
+    Error::invalid_argument(format!(
+        "cannot create an index on COMPRESSED column '{}'",
+        column
+    ))
+}
+
 /// Helper function to convert Operator to string for display
 fn operator_to_string(op: crate::core::Operator) -> &'static str {
     use crate::core::Operator;
@@ -2866,12 +3514,16 @@ mod tests {
             .update(None, &mut |row| {
                 let mut new_row = row.clone();
                 let _ = new_row.set(1, Value::Integer(20));
-                Ok((new_row, false))
+                Ok((new_row, true))
             })
             .unwrap();
 
         assert_eq!(updated, 1);
 
+        // Rows the setter leaves unchanged are not written
+        let updated = table.update(None, &mut |row| Ok((row, false))).unwrap();
+        assert_eq!(updated, 0);
+
         // Verify update
         let mut scanner = table.scan(&[0, 1], None).unwrap();
         assert!(scanner.next());
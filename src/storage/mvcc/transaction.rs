@@ -200,6 +200,7 @@ impl MvccTransaction {
 
         // Remove transaction isolation level from registry
         self.registry.remove_transaction_isolation_level(self.id);
+        self.registry.set_bulk_load(self.id, false);
     }
 
     /// Check if this is a read-only transaction
@@ -246,7 +247,8 @@ impl MvccTransaction {
     /// Rolls back to a savepoint, discarding all changes made after it
     ///
     /// All local changes with timestamps after the savepoint are discarded.
-    /// The savepoint itself is also removed (SQL standard behavior).
+    /// The savepoint itself is kept, so it can be rolled back to again;
+    /// savepoints created after it are removed (SQL standard behavior).
     pub fn rollback_to_savepoint(&mut self, name: &str) -> Result<()> {
         self.check_active()?;
 
@@ -254,12 +256,17 @@ impl MvccTransaction {
             Error::invalid_argument(format!("savepoint '{}' does not exist", name))
         })?;
 
-        // Rollback each table's local changes that occurred after the savepoint
-        for table in self.tables.values() {
+        // Rollback each table's local changes that occurred after the savepoint.
+        // Tables are opened per statement, so the written ones are found
+        // through the engine rather than the local cache.
+        for table in self
+            .get_engine_ops()?
+            .get_tables_with_pending_changes(self.id)?
+        {
             table.rollback_to_timestamp(savepoint_ts);
         }
 
-        // Remove this savepoint and all savepoints created after it
+        // Remove all savepoints created after this one
         self.savepoints.retain(|_, &mut ts| ts <= savepoint_ts);
 
         Ok(())
@@ -301,6 +308,18 @@ impl Transaction for MvccTransaction {
             Vec::new()
         };
 
+        // A bulk load checks the uniqueness it deferred before anything is
+        // written, so a violation leaves no trace of the transaction
+        if self.registry.is_bulk_load(self.id) {
+            for table in tables_with_changes.iter() {
+                if let Err(e) = table.check_deferred_constraints() {
+                    self.state = TransactionState::Active;
+                    let _ = self.rollback();
+                    return Err(e);
+                }
+            }
+        }
+
         // Check if read-only: no DDL changes and no DML changes
         let is_read_only = self.created_tables.is_empty()
             && self.dropped_tables.is_empty()
@@ -345,8 +364,12 @@ impl Transaction for MvccTransaction {
                 let _ = ops.record_commit(self.id);
             }
         } else {
-            // Read-only transaction - just mark as committed in registry
-            self.registry.complete_commit(self.id);
+            // Read-only transaction - nothing to apply, but releasing its table
+            // stores ends any FOR UPDATE / FOR SHARE row locks it holds
+            if let Some(ops) = &self.engine_operations {
+                let _ = ops.commit_all_tables(self.id);
+            }
+            self.registry.commit_transaction(self.id);
         }
 
         // Mark as committed
@@ -466,6 +489,17 @@ impl Transaction for MvccTransaction {
         Ok(())
     }
 
+    fn isolation_level(&self) -> IsolationLevel {
+        // Delegate to the inherent method
+        MvccTransaction::get_isolation_level(self)
+    }
+
+    fn set_bulk_load(&mut self, enabled: bool) -> Result<()> {
+        self.check_active()?;
+        self.registry.set_bulk_load(self.id, enabled);
+        Ok(())
+    }
+
     fn create_table(&mut self, name: &str, schema: Schema) -> Result<Box<dyn Table>> {
         self.check_active()?;
 
@@ -647,7 +681,7 @@ impl Transaction for MvccTransaction {
             column.data_type,
             column.nullable,
             Some(column.auto_increment),
-            Some(column.check_expr.clone()),
+            Some(column.checks.clone()),
         )
     }
 
@@ -28,14 +28,15 @@
 //!
 
 use std::fmt;
-use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
-use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 use crate::common::{new_concurrent_int64_map, new_int64_map, ConcurrentInt64Map, Int64Map};
-use crate::core::{Error, Row, Schema};
+use crate::core::{Error, Row, RowLockMode, Schema};
 use crate::storage::expression::CompiledFilter;
 use crate::storage::mvcc::arena::RowArena;
 use crate::storage::mvcc::get_fast_timestamp;
+use crate::storage::mvcc::memory::{estimate_row_size, MemoryTracker, INDEX_ENTRY_BYTES};
 use crate::storage::mvcc::streaming_result::{StreamingResult, VisibleRowInfo};
 use crate::storage::Index;
 use radsort::sort_by_key;
@@ -153,6 +154,108 @@ pub trait VisibilityChecker: Send + Sync {
     fn is_committed_before(&self, _txn_id: i64, _cutoff_commit_seq: i64) -> bool {
         true // Default: no cutoff filtering
     }
+
+    /// Check if the viewing transaction reads with READ COMMITTED semantics.
+    ///
+    /// The maintained live-row counter is only exact for viewers that see every
+    /// committed version. Default implementation returns false, which makes
+    /// `count_visible_rows` fall back to a full visibility scan.
+    fn is_read_committed(&self, _viewing_txn_id: i64) -> bool {
+        false
+    }
+}
+
+/// Dead-version counts and auto-vacuum history for a table
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VacuumStats {
+    /// Latest versions of rows that are not deleted
+    pub live_versions: usize,
+    /// Deleted rows and superseded versions still held in memory
+    pub dead_versions: usize,
+    /// Number of auto-vacuum runs on this table
+    pub autovacuum_count: u64,
+    /// Time of the last auto-vacuum run in nanoseconds since the epoch (0 if never)
+    pub last_autovacuum: i64,
+}
+
+impl VacuumStats {
+    /// Returns the fraction of versions that are dead (0.0 for an empty table)
+    pub fn dead_ratio(&self) -> f64 {
+        let total = self.live_versions + self.dead_versions;
+        if total == 0 {
+            0.0
+        } else {
+            self.dead_versions as f64 / total as f64
+        }
+    }
+}
+
+/// Maintained count of live (non-deleted) rows for COUNT(*) without a predicate
+///
+/// Each batch of versions applied to the store contributes a net delta
+/// (inserts minus deletes). Deltas are kept per transaction until that
+/// transaction is observed as committed, then folded into `committed`.
+/// This keeps versions of committing or never-committed transactions out
+/// of the count, matching what a visibility scan would see.
+#[derive(Debug, Default)]
+struct LiveRowCounter {
+    /// Net live rows contributed by transactions known to be committed
+    committed: i64,
+    /// Net live-row deltas of transactions not yet observed as committed
+    pending: FxHashMap<i64, i64>,
+}
+
+impl LiveRowCounter {
+    /// Records a net live-row delta for a transaction
+    fn record(&mut self, txn_id: i64, delta: i64) {
+        if delta != 0 {
+            *self.pending.entry(txn_id).or_insert(0) += delta;
+        }
+    }
+
+    /// Returns the live-row count visible to a READ COMMITTED viewer
+    ///
+    /// Returns None if the viewer itself has pending deltas (its commit is in
+    /// progress), in which case the caller must scan.
+    fn visible_count(&mut self, checker: &dyn VisibilityChecker, txn_id: i64) -> Option<usize> {
+        if self.pending.contains_key(&txn_id) {
+            return None;
+        }
+
+        // Committed state is permanent under READ COMMITTED, so fold it in
+        let mut committed = self.committed;
+        self.pending.retain(|&version_txn_id, delta| {
+            if checker.is_visible(version_txn_id, txn_id) {
+                committed += *delta;
+                false
+            } else {
+                true
+            }
+        });
+        self.committed = committed;
+
+        Some(committed.max(0) as usize)
+    }
+}
+
+/// Returns the net live-row change of replacing `prev` with `next` as chain head
+#[inline]
+fn live_row_delta(prev: Option<&RowVersion>, next: &RowVersion) -> i64 {
+    let was_live = prev.is_some_and(|v| !v.is_deleted());
+    (!next.is_deleted()) as i64 - was_live as i64
+}
+
+/// Returns the net estimated-size change of replacing `prev` with `next` as chain head
+#[inline]
+fn row_bytes_delta(prev: Option<&RowVersion>, next: &RowVersion) -> i64 {
+    let size = |v: &RowVersion| {
+        if v.is_deleted() {
+            0
+        } else {
+            estimate_row_size(&v.data) as i64
+        }
+    };
+    size(next) - prev.map_or(0, size)
 }
 
 /// VersionStore tracks the latest committed version of each row for a table
@@ -176,12 +279,17 @@ pub struct VersionStore {
     schema: RwLock<Schema>,
     /// Indexes on this table (FxHashMap for fast string key lookups)
     indexes: RwLock<FxHashMap<String, Arc<dyn Index>>>,
+    /// Held shared by commits while they update indexes and publish versions,
+    /// and exclusively while indexes are rebuilt
+    index_maintenance: RwLock<()>,
     /// Whether this store has been closed
     closed: AtomicBool,
     /// Auto-increment counter for tables without explicit PK
     auto_increment_counter: AtomicI64,
     /// Track which transaction has uncommitted changes to each row
     uncommitted_writes: ConcurrentInt64Map<i64>,
+    /// Transactions holding a FOR SHARE lock on each row
+    shared_locks: ConcurrentInt64Map<Vec<i64>>,
     /// Visibility checker (registry reference)
     visibility_checker: Option<Arc<dyn VisibilityChecker>>,
     /// Arena-based storage for zero-copy full table scans
@@ -191,6 +299,23 @@ pub struct VersionStore {
     /// Zone maps for segment pruning (set by ANALYZE)
     /// Uses Arc to avoid cloning on every read - critical for high QPS workloads
     zone_maps: RwLock<Option<Arc<crate::storage::mvcc::zonemap::TableZoneMap>>>,
+    /// Maintained live-row count for COUNT(*) without a predicate
+    live_rows: Mutex<LiveRowCounter>,
+    /// Number of auto-vacuum runs on this table
+    autovacuum_count: AtomicU64,
+    /// Time of the last auto-vacuum run (0 if never)
+    last_autovacuum: AtomicI64,
+    /// Create time of the oldest state the version chains still fully hold
+    /// (0 until cleanup reclaims history)
+    history_horizon_time: AtomicI64,
+    /// Transaction ID counterpart of `history_horizon_time`
+    history_horizon_txn: AtomicI64,
+    /// Engine-wide memory budget this store reports its rows to
+    memory: Arc<MemoryTracker>,
+    /// Estimated bytes of the live rows in this store
+    memory_bytes: AtomicI64,
+    /// Live rows counted in `memory_bytes`
+    memory_rows: AtomicI64,
 }
 
 impl VersionStore {
@@ -202,13 +327,23 @@ impl VersionStore {
             table_name,
             schema: RwLock::new(schema),
             indexes: RwLock::new(FxHashMap::default()),
+            index_maintenance: RwLock::new(()),
             closed: AtomicBool::new(false),
             auto_increment_counter: AtomicI64::new(0),
             uncommitted_writes: new_concurrent_int64_map(),
+            shared_locks: new_concurrent_int64_map(),
             visibility_checker: None,
             arena: RowArena::new(cols),
             row_arena_index: RwLock::new(new_int64_map()),
             zone_maps: RwLock::new(None),
+            live_rows: Mutex::new(LiveRowCounter::default()),
+            autovacuum_count: AtomicU64::new(0),
+            last_autovacuum: AtomicI64::new(0),
+            history_horizon_time: AtomicI64::new(0),
+            history_horizon_txn: AtomicI64::new(0),
+            memory: Arc::new(MemoryTracker::default()),
+            memory_bytes: AtomicI64::new(0),
+            memory_rows: AtomicI64::new(0),
         }
     }
 
@@ -224,16 +359,53 @@ impl VersionStore {
             table_name,
             schema: RwLock::new(schema),
             indexes: RwLock::new(FxHashMap::default()),
+            index_maintenance: RwLock::new(()),
             closed: AtomicBool::new(false),
             auto_increment_counter: AtomicI64::new(0),
             uncommitted_writes: new_concurrent_int64_map(),
+            shared_locks: new_concurrent_int64_map(),
             visibility_checker: Some(checker),
             arena: RowArena::new(cols),
             row_arena_index: RwLock::new(new_int64_map()),
             zone_maps: RwLock::new(None),
+            live_rows: Mutex::new(LiveRowCounter::default()),
+            autovacuum_count: AtomicU64::new(0),
+            last_autovacuum: AtomicI64::new(0),
+            history_horizon_time: AtomicI64::new(0),
+            history_horizon_txn: AtomicI64::new(0),
+            memory: Arc::new(MemoryTracker::default()),
+            memory_bytes: AtomicI64::new(0),
+            memory_rows: AtomicI64::new(0),
         }
     }
 
+    /// Reports row memory to a shared (engine-wide) tracker
+    ///
+    /// Must be called before any rows are added.
+    pub fn with_memory_tracker(mut self, memory: Arc<MemoryTracker>) -> Self {
+        self.memory = memory;
+        self
+    }
+
+    /// Returns the memory tracker rows are reported to
+    pub fn memory_tracker(&self) -> &Arc<MemoryTracker> {
+        &self.memory
+    }
+
+    /// Records a change in live-row memory with the tracker
+    ///
+    /// Each live row is charged its estimated size plus one entry per index.
+    #[inline]
+    fn record_memory(&self, bytes: i64, rows: i64, index_count: usize) {
+        if bytes == 0 && rows == 0 {
+            return;
+        }
+        self.memory_bytes.fetch_add(bytes, Ordering::Relaxed);
+        self.memory_rows.fetch_add(rows, Ordering::Relaxed);
+        self.memory
+            .record(bytes + rows * index_count as i64 * INDEX_ENTRY_BYTES as i64);
+    }
+
     /// Sets the visibility checker
     pub fn set_visibility_checker(&mut self, checker: Arc<dyn VisibilityChecker>) {
         self.visibility_checker = Some(checker);
@@ -284,6 +456,13 @@ impl VersionStore {
         }
     }
 
+    /// Sets the auto-increment counter, lowering it if needed
+    ///
+    /// The next generated ID will be `value + 1`.
+    pub fn reset_auto_increment_counter(&self, value: i64) {
+        self.auto_increment_counter.store(value, Ordering::Release);
+    }
+
     /// Returns the current auto-increment value without incrementing
     pub fn get_current_auto_increment_value(&self) -> i64 {
         self.auto_increment_counter.load(Ordering::Acquire)
@@ -301,6 +480,12 @@ impl VersionStore {
             .get(&row_id)
             .map(|e| (e.version.clone(), e.prev.clone(), e.arena_idx));
 
+        let prev = existing_data.as_ref().map(|(v, _, _)| v);
+        let delta = live_row_delta(prev, &version);
+        self.live_rows.lock().unwrap().record(version.txn_id, delta);
+        let index_count = self.indexes.read().unwrap().len();
+        self.record_memory(row_bytes_delta(prev, &version), delta, index_count);
+
         let entry =
             if let Some((existing_version, existing_prev, existing_arena_idx)) = existing_data {
                 // Create new entry with previous version
@@ -378,6 +563,11 @@ impl VersionStore {
             return;
         }
 
+        // Accumulate live-row deltas locally to take the counter lock once per transaction
+        let mut deltas: Vec<(i64, i64)> = Vec::with_capacity(1);
+        let mut bytes = 0;
+        let mut rows = 0;
+
         for (row_id, version) in batch {
             // Get existing data first (if any)
             let existing_data = self
@@ -385,6 +575,15 @@ impl VersionStore {
                 .get(&row_id)
                 .map(|e| (e.version.clone(), e.prev.clone(), e.arena_idx));
 
+            let prev = existing_data.as_ref().map(|(v, _, _)| v);
+            let delta = live_row_delta(prev, &version);
+            bytes += row_bytes_delta(prev, &version);
+            rows += delta;
+            match deltas.last_mut() {
+                Some((txn_id, acc)) if *txn_id == version.txn_id => *acc += delta,
+                _ => deltas.push((version.txn_id, delta)),
+            }
+
             let entry = if let Some((existing_version, existing_prev, existing_arena_idx)) =
                 existing_data
             {
@@ -419,6 +618,15 @@ impl VersionStore {
 
             self.versions.insert(row_id, entry);
         }
+
+        let mut live_rows = self.live_rows.lock().unwrap();
+        for (txn_id, delta) in deltas {
+            live_rows.record(txn_id, delta);
+        }
+        drop(live_rows);
+
+        let index_count = self.indexes.read().unwrap().len();
+        self.record_memory(bytes, rows, index_count);
     }
 
     /// Quick check if a row might exist
@@ -465,6 +673,40 @@ impl VersionStore {
         result
     }
 
+    /// Returns every stored version of a row, newest first
+    pub fn version_chain(&self, row_id: i64) -> Vec<RowVersion> {
+        if self.closed.load(Ordering::Acquire) {
+            return Vec::new();
+        }
+
+        let mut chain = Vec::new();
+        if let Some(entry_ref) = self.versions.get(&row_id) {
+            let mut current: Option<&VersionChainEntry> = Some(&*entry_ref);
+            while let Some(e) = current {
+                chain.push(e.version.clone());
+                current = e.prev.as_ref().map(|b| b.as_ref());
+            }
+        }
+        chain
+    }
+
+    /// Checks whether the newest committed version of a row is visible and live
+    ///
+    /// Indexes follow the newest committed version, so when this holds the
+    /// values an index keeps for the row are the ones the transaction sees.
+    pub fn is_latest_version_visible(&self, row_id: i64, txn_id: i64) -> bool {
+        if self.closed.load(Ordering::Acquire) {
+            return false;
+        }
+        let Some(checker) = self.visibility_checker.as_ref() else {
+            return false;
+        };
+        let Some(entry) = self.versions.get(&row_id) else {
+            return false;
+        };
+        entry.version.deleted_at_txn_id == 0 && checker.is_visible(entry.version.txn_id, txn_id)
+    }
+
     /// Gets multiple visible versions in a single batch operation
     ///
     /// This is much faster than calling get_visible_version for each row_id
@@ -634,6 +876,27 @@ impl VersionStore {
         self.versions.iter().map(|e| *e.key()).collect()
     }
 
+    /// Calls `f` with the latest non-deleted version of every row
+    ///
+    /// Unlike the visibility-based scans this includes rows committed after
+    /// any particular snapshot, which is what the indexes hold.
+    pub fn for_each_latest_version<F>(&self, mut f: F) -> Result<(), Error>
+    where
+        F: FnMut(i64, &RowVersion) -> Result<(), Error>,
+    {
+        if self.closed.load(Ordering::Acquire) {
+            return Ok(());
+        }
+
+        for entry in self.versions.iter() {
+            let version = &entry.value().version;
+            if !version.is_deleted() {
+                f(*entry.key(), version)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Returns all row IDs that are visible to the given transaction
     pub fn get_all_visible_row_ids(&self, txn_id: i64) -> Vec<i64> {
         if self.closed.load(Ordering::Acquire) {
@@ -653,10 +916,11 @@ impl VersionStore {
         visible_row_ids
     }
 
-    /// Count visible non-deleted rows in a single pass (optimized for row_count)
+    /// Count visible non-deleted rows (optimized for row_count)
     ///
-    /// OPTIMIZATION: This method counts rows in O(1) lock acquisition instead of O(N)
-    /// by iterating through versions once without cloning any row data.
+    /// READ COMMITTED viewers are answered in O(1) from the maintained live-row
+    /// counter. Other viewers fall back to a single pass over all versions,
+    /// which takes O(1) lock acquisitions and doesn't clone any row data.
     pub fn count_visible_rows(&self, txn_id: i64) -> usize {
         if self.closed.load(Ordering::Acquire) {
             return 0;
@@ -667,6 +931,19 @@ impl VersionStore {
             None => return 0,
         };
 
+        // FAST PATH: READ COMMITTED viewers see every committed version, so the
+        // maintained live-row counter is exact for them
+        if checker.is_read_committed(txn_id) {
+            if let Some(count) = self
+                .live_rows
+                .lock()
+                .unwrap()
+                .visible_count(checker.as_ref(), txn_id)
+            {
+                return count;
+            }
+        }
+
         let mut count = 0;
 
         // Single pass through all versions - no per-row lock acquisition
@@ -1359,6 +1636,9 @@ impl VersionStore {
         self.versions.len()
     }
     /// Tries to claim a row for update (dirty write prevention)
+    ///
+    /// Also used for FOR UPDATE locks. Fails if another transaction holds
+    /// the claim or a FOR SHARE lock on the row.
     pub fn try_claim_row(&self, row_id: i64, txn_id: i64) -> Result<(), Error> {
         // Use DashMap's entry API for atomic check-and-insert
         use dashmap::mapref::entry::Entry;
@@ -1373,13 +1653,43 @@ impl VersionStore {
                     )));
                 }
                 // Same transaction already owns it
-                Ok(())
+                return Ok(());
             }
             Entry::Vacant(e) => {
                 e.insert(txn_id);
-                Ok(())
             }
         }
+
+        // The claim is published before checking for shared locks, and
+        // try_lock_row_shared does the reverse, so of two racing
+        // transactions at least one sees the other
+        if let Some(holder) = self.other_shared_lock_holder(row_id, txn_id) {
+            self.release_row_claim(row_id, txn_id);
+            return Err(Error::internal(format!(
+                "row {} is locked FOR SHARE by transaction {}",
+                row_id, holder
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the transaction holding each claimed row, one entry per row
+    ///
+    /// Claimed rows have uncommitted changes (or FOR UPDATE locks) that block
+    /// writes from every other transaction until their owner ends.
+    pub fn uncommitted_row_owners(&self) -> Vec<i64> {
+        self.uncommitted_writes
+            .iter()
+            .map(|entry| *entry.value())
+            .collect()
+    }
+
+    /// Returns every claimed row with the transaction that claimed it
+    pub fn row_claims(&self) -> Vec<(i64, i64)> {
+        self.uncommitted_writes
+            .iter()
+            .map(|entry| (*entry.key(), *entry.value()))
+            .collect()
     }
 
     /// Releases a row claim
@@ -1389,6 +1699,51 @@ impl VersionStore {
             .remove_if(&row_id, |_, &v| v == txn_id);
     }
 
+    /// Takes a FOR SHARE lock on a row
+    ///
+    /// Any number of transactions can share a row, but not while another
+    /// transaction has claimed it for a write or FOR UPDATE.
+    pub fn try_lock_row_shared(&self, row_id: i64, txn_id: i64) -> Result<(), Error> {
+        {
+            let mut holders = self.shared_locks.entry(row_id).or_default();
+            if !holders.contains(&txn_id) {
+                holders.push(txn_id);
+            }
+        }
+
+        let writer = self
+            .uncommitted_writes
+            .get(&row_id)
+            .map(|e| *e.value())
+            .filter(|&writer| writer != txn_id);
+        if let Some(writer) = writer {
+            self.release_shared_lock(row_id, txn_id);
+            return Err(Error::internal(format!(
+                "row {} is locked by transaction {}",
+                row_id, writer
+            )));
+        }
+        Ok(())
+    }
+
+    /// Releases a FOR SHARE lock
+    pub fn release_shared_lock(&self, row_id: i64, txn_id: i64) {
+        if let Some(mut holders) = self.shared_locks.get_mut(&row_id) {
+            holders.retain(|&holder| holder != txn_id);
+        }
+        self.shared_locks
+            .remove_if(&row_id, |_, holders| holders.is_empty());
+    }
+
+    /// A transaction other than `txn_id` holding a FOR SHARE lock on the row
+    fn other_shared_lock_holder(&self, row_id: i64, txn_id: i64) -> Option<i64> {
+        self.shared_locks
+            .get(&row_id)?
+            .iter()
+            .copied()
+            .find(|&holder| holder != txn_id)
+    }
+
     /// Check if an index exists
     pub fn index_exists(&self, index_name: &str) -> bool {
         let indexes = self.indexes.read().unwrap();
@@ -1419,13 +1774,48 @@ impl VersionStore {
     /// Add an index
     pub fn add_index(&self, name: String, index: Arc<dyn Index>) {
         let mut indexes = self.indexes.write().unwrap();
-        indexes.insert(name, index);
+        if indexes.insert(name, index).is_none() {
+            self.record_index_memory(1);
+        }
+    }
+
+    /// Charges (or refunds) one index entry per live row for added (or removed) indexes
+    fn record_index_memory(&self, indexes: i64) {
+        let rows = self.memory_rows.load(Ordering::Relaxed);
+        self.memory
+            .record(rows * indexes * INDEX_ENTRY_BYTES as i64);
+    }
+
+    /// Blocks index rebuilds while a commit updates indexes and publishes versions
+    pub fn lock_for_commit(&self) -> RwLockReadGuard<'_, ()> {
+        self.index_maintenance.read().unwrap()
+    }
+
+    /// Blocks commits to this table while its indexes are rebuilt
+    pub fn lock_for_index_rebuild(&self) -> RwLockWriteGuard<'_, ()> {
+        self.index_maintenance.write().unwrap()
+    }
+
+    /// Replace several indexes at once
+    ///
+    /// Readers see either all old or all new indexes.
+    pub fn replace_indexes(&self, replacements: Vec<(String, Arc<dyn Index>)>) {
+        let mut indexes = self.indexes.write().unwrap();
+        for (name, index) in replacements {
+            if indexes.insert(name, index).is_none() {
+                self.record_index_memory(1);
+            }
+        }
     }
 
     /// Remove an index
     pub fn remove_index(&self, name: &str) -> Option<Arc<dyn Index>> {
         let mut indexes = self.indexes.write().unwrap();
-        indexes.remove(name)
+        let removed = indexes.remove(name);
+        if removed.is_some() {
+            self.record_index_memory(-1);
+        }
+        removed
     }
 
     /// Get an index by name
@@ -1436,10 +1826,19 @@ impl VersionStore {
 
     /// Get an index by column name (single-column indexes only)
     pub fn get_index_by_column(&self, column_name: &str) -> Option<Arc<dyn Index>> {
+        self.find_index_by_column(column_name, |_| true)
+    }
+
+    /// Get a single-column index on `column_name` whose name passes `allow`
+    pub fn find_index_by_column(
+        &self,
+        column_name: &str,
+        allow: impl Fn(&str) -> bool,
+    ) -> Option<Arc<dyn Index>> {
         let indexes = self.indexes.read().unwrap();
         for index in indexes.values() {
             let column_names = index.column_names();
-            if column_names.len() == 1 && column_names[0] == column_name {
+            if column_names.len() == 1 && column_names[0] == column_name && allow(index.name()) {
                 return Some(index.clone());
             }
         }
@@ -1453,6 +1852,16 @@ impl VersionStore {
     pub fn get_multi_column_index(
         &self,
         predicate_columns: &[&str],
+    ) -> Option<(Arc<dyn Index>, usize)> {
+        self.find_multi_column_index(predicate_columns, |_| true)
+    }
+
+    /// Like [`get_multi_column_index`](Self::get_multi_column_index), considering
+    /// only indexes whose name passes `allow`
+    pub fn find_multi_column_index(
+        &self,
+        predicate_columns: &[&str],
+        allow: impl Fn(&str) -> bool,
     ) -> Option<(Arc<dyn Index>, usize)> {
         if predicate_columns.is_empty() {
             return None;
@@ -1466,8 +1875,8 @@ impl VersionStore {
 
         for index in indexes.values() {
             let index_columns = index.column_names();
-            if index_columns.len() < 2 {
-                continue; // Skip single-column indexes
+            if index_columns.len() < 2 || !allow(index.name()) {
+                continue; // Skip single-column and disallowed indexes
             }
 
             // Count how many of the leading index columns are in the predicate set.
@@ -1560,8 +1969,17 @@ impl VersionStore {
     }
 
     /// Close the version store
+    ///
+    /// The memory charged for its rows is given back to the tracker.
     pub fn close(&self) {
-        self.closed.store(true, Ordering::Release);
+        if self.closed.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        let index_count = self.indexes.read().unwrap().len();
+        let bytes = self.memory_bytes.swap(0, Ordering::Relaxed);
+        let rows = self.memory_rows.swap(0, Ordering::Relaxed);
+        self.memory
+            .record(-bytes - rows * index_count as i64 * INDEX_ENTRY_BYTES as i64);
     }
 
     /// Check if the version store is closed
@@ -1723,7 +2141,8 @@ impl VersionStore {
                         vec![column_id],
                         vec![data_type],
                         meta.is_unique,
-                    );
+                    )
+                    .with_nulls_not_distinct(meta.nulls_not_distinct);
                     Arc::new(idx)
                 }
                 IndexType::Bitmap => {
@@ -1734,7 +2153,8 @@ impl VersionStore {
                         vec![column_id],
                         vec![data_type],
                         meta.is_unique,
-                    );
+                    )
+                    .with_nulls_not_distinct(meta.nulls_not_distinct);
                     Arc::new(idx)
                 }
                 IndexType::BTree => {
@@ -1746,7 +2166,8 @@ impl VersionStore {
                         column_name.clone(),
                         data_type,
                         meta.is_unique,
-                    );
+                    )
+                    .with_nulls_not_distinct(meta.nulls_not_distinct);
                     Arc::new(idx)
                 }
                 IndexType::MultiColumn => {
@@ -1758,7 +2179,8 @@ impl VersionStore {
                         meta.column_ids.clone(),
                         meta.data_types.clone(),
                         meta.is_unique,
-                    );
+                    )
+                    .with_nulls_not_distinct(meta.nulls_not_distinct);
                     Arc::new(idx)
                 }
             };
@@ -1788,7 +2210,8 @@ impl VersionStore {
                 meta.column_ids.clone(),
                 meta.data_types.clone(),
                 meta.is_unique,
-            );
+            )
+            .with_nulls_not_distinct(meta.nulls_not_distinct);
 
             let index = Arc::new(index);
 
@@ -1953,7 +2376,9 @@ impl VersionStore {
             }
 
             // Now remove from version store
-            self.versions.remove(row_id);
+            if let Some((_, entry)) = self.versions.remove(row_id) {
+                self.advance_history_horizon(&entry.version);
+            }
         }
 
         rows_to_delete.len() as i32
@@ -1974,9 +2399,14 @@ impl VersionStore {
             return true;
         }
 
-        // Check if any active transaction can still see this version
+        // A deleted row must stay until every active transaction sees the delete;
+        // otherwise an older snapshot would lose a row it can still read
         for txn_id in active_txns {
-            if checker.is_visible(version.txn_id, txn_id) {
+            if version.is_deleted() {
+                if !checker.is_visible(version.deleted_at_txn_id, txn_id) {
+                    return false;
+                }
+            } else if checker.is_visible(version.txn_id, txn_id) {
                 return false; // Still visible to at least one transaction
             }
         }
@@ -1990,6 +2420,13 @@ impl VersionStore {
     /// 1. Needed by active transactions
     /// 2. Within the retention period (for AS OF TIMESTAMP queries)
     pub fn cleanup_old_previous_versions(&self) -> i32 {
+        // Default 24-hour retention for historical versions
+        self.cleanup_previous_versions(std::time::Duration::from_secs(24 * 60 * 60))
+    }
+
+    /// Cleanup previous versions older than the retention period that no
+    /// active transaction needs
+    pub fn cleanup_previous_versions(&self, retention_period: std::time::Duration) -> i32 {
         if self.closed.load(Ordering::Acquire) {
             return 0;
         }
@@ -1999,8 +2436,6 @@ impl VersionStore {
             None => return 0, // Need visibility checker for cleanup
         };
 
-        // Default 24-hour retention for historical versions
-        let retention_period = std::time::Duration::from_secs(24 * 60 * 60);
         let now = get_fast_timestamp();
         let retention_cutoff = now - retention_period.as_nanos() as i64;
 
@@ -2023,30 +2458,32 @@ impl VersionStore {
                 current = prev_entry.prev.as_ref();
             }
 
-            // Find cutoff point - first version we can discard
-            let mut keep_count = 0;
-            for prev_entry in &versions_to_check {
-                let mut keep = false;
-
-                // Rule 1: Keep if needed by any active transaction
-                for &txn_id in &active_txns {
-                    if checker.is_visible(prev_entry.version.txn_id, txn_id) {
-                        keep = true;
-                        break;
-                    }
-                }
-
-                // Rule 2: Keep if within retention period
-                if !keep && prev_entry.version.create_time >= retention_cutoff {
-                    keep = true;
-                }
-
-                if keep {
-                    keep_count += 1;
-                } else {
-                    // Found the cutoff point
-                    break;
+            // Rule 1: Keep every version that was still current at some point
+            // within the retention period, i.e. whose successor was created
+            // after the cutoff (the chain is ordered newest first, so these
+            // form a prefix)
+            let mut keep_count = std::iter::once(&chain_entry.version)
+                .chain(
+                    versions_to_check
+                        .iter()
+                        .map(|prev_entry| &prev_entry.version),
+                )
+                .take_while(|version| version.create_time >= retention_cutoff)
+                .count()
+                .min(versions_to_check.len());
+
+            // Rule 2: Keep everything up to the newest version each active
+            // transaction can see; anything older is unreachable for it
+            for &txn_id in &active_txns {
+                if checker.is_visible(chain_entry.version.txn_id, txn_id) {
+                    continue; // Reads the latest version
                 }
+                let needed = versions_to_check
+                    .iter()
+                    .position(|prev_entry| checker.is_visible(prev_entry.version.txn_id, txn_id))
+                    .map(|idx| idx + 1)
+                    .unwrap_or(versions_to_check.len());
+                keep_count = keep_count.max(needed);
             }
 
             // Prune versions beyond keep_count
@@ -2054,6 +2491,12 @@ impl VersionStore {
                 let to_remove = versions_to_check.len() - keep_count;
                 cleaned += to_remove as i32;
 
+                // States older than the oldest kept version are gone
+                match keep_count {
+                    0 => self.advance_history_horizon(&chain_entry.version),
+                    n => self.advance_history_horizon(&versions_to_check[n - 1].version),
+                }
+
                 // Disconnect the chain at the cutoff point
                 if keep_count == 0 {
                     // Remove all previous versions
@@ -2076,6 +2519,71 @@ impl VersionStore {
         cleaned
     }
 
+    /// Moves the history horizon up to a version whose predecessors were
+    /// reclaimed
+    fn advance_history_horizon(&self, version: &RowVersion) {
+        self.history_horizon_time
+            .fetch_max(version.create_time, Ordering::AcqRel);
+        self.history_horizon_txn
+            .fetch_max(version.txn_id, Ordering::AcqRel);
+    }
+
+    /// Returns the oldest (create time, transaction ID) an AS OF query can
+    /// read exactly; both are 0 while no history has been reclaimed
+    pub fn history_horizon(&self) -> (i64, i64) {
+        (
+            self.history_horizon_time.load(Ordering::Acquire),
+            self.history_horizon_txn.load(Ordering::Acquire),
+        )
+    }
+
+    /// Returns dead-version counts and auto-vacuum history for this table
+    ///
+    /// Walks every version chain, so the cost is proportional to the number
+    /// of versions held in memory.
+    pub fn vacuum_stats(&self) -> VacuumStats {
+        let mut stats = VacuumStats {
+            autovacuum_count: self.autovacuum_count.load(Ordering::Acquire),
+            last_autovacuum: self.last_autovacuum.load(Ordering::Acquire),
+            ..Default::default()
+        };
+
+        self.versions.iter().for_each(|entry| {
+            let chain_entry = entry.value();
+            if chain_entry.version.is_deleted() {
+                stats.dead_versions += 1;
+            } else {
+                stats.live_versions += 1;
+            }
+            let mut current = chain_entry.prev.as_ref();
+            while let Some(prev_entry) = current {
+                stats.dead_versions += 1;
+                current = prev_entry.prev.as_ref();
+            }
+        });
+
+        stats
+    }
+
+    /// Reclaims deleted rows and previous versions that no active transaction
+    /// can see, and records the run in the table's vacuum stats
+    ///
+    /// Versions younger than `retention` are kept even when no open
+    /// snapshot needs them, so AS OF queries can still read them.
+    pub fn auto_vacuum(&self, retention: std::time::Duration) -> i32 {
+        if self.closed.load(Ordering::Acquire) {
+            return 0;
+        }
+
+        let reclaimed =
+            self.cleanup_deleted_rows(retention) + self.cleanup_previous_versions(retention);
+
+        self.autovacuum_count.fetch_add(1, Ordering::AcqRel);
+        self.last_autovacuum
+            .store(get_fast_timestamp(), Ordering::Release);
+        reclaimed
+    }
+
     /// Iterate over all committed (non-deleted) versions for snapshot creation
     ///
     /// This method iterates over all rows that are visible to a snapshot transaction
@@ -2229,6 +2737,12 @@ pub struct TransactionVersionStore {
     txn_id: i64,
     /// Write set for conflict detection
     write_set: Int64Map<WriteSetEntry>,
+    /// Net estimated bytes this transaction will add to the parent store on commit
+    pending_bytes: i64,
+    /// Rows locked by SELECT ... FOR UPDATE / FOR SHARE
+    row_locks: Int64Map<RowLockMode>,
+    /// Unique checks and index updates wait for commit (bulk load)
+    bulk_load: bool,
 }
 
 impl TransactionVersionStore {
@@ -2239,6 +2753,9 @@ impl TransactionVersionStore {
             parent_store,
             txn_id,
             write_set: new_int64_map(),
+            pending_bytes: 0,
+            row_locks: new_int64_map(),
+            bulk_load: false,
         }
     }
 
@@ -2247,38 +2764,18 @@ impl TransactionVersionStore {
         self.txn_id
     }
 
+    /// Defer unique checks and index updates of this store's rows to commit
+    pub fn set_bulk_load(&mut self, enabled: bool) {
+        self.bulk_load = enabled;
+    }
+
+    /// Whether unique checks and index updates wait for commit
+    pub fn is_bulk_load(&self) -> bool {
+        self.bulk_load
+    }
+
     /// Put adds or updates a row in the transaction's local store
     pub fn put(&mut self, row_id: i64, data: Row, is_delete: bool) -> Result<(), Error> {
-        // Check if we already have a local version
-        if !self.local_versions.contains_key(&row_id) {
-            // Check if this row exists in parent store and track in write-set
-            // Single lookup instead of two separate calls
-            if !self.write_set.contains_key(&row_id) {
-                let read_version = self.parent_store.get_visible_version(row_id, self.txn_id);
-                let row_exists = read_version.is_some();
-
-                let read_version_seq = self
-                    .parent_store
-                    .visibility_checker
-                    .as_ref()
-                    .map(|c| c.get_current_sequence())
-                    .unwrap_or(0);
-
-                self.write_set.insert(
-                    row_id,
-                    WriteSetEntry {
-                        read_version,
-                        read_version_seq,
-                    },
-                );
-
-                // For existing rows, try to claim them
-                if row_exists {
-                    self.parent_store.try_claim_row(row_id, self.txn_id)?;
-                }
-            }
-        }
-
         // Create the row version
         let mut rv = RowVersion::new(self.txn_id, row_id, data);
 
@@ -2286,6 +2783,39 @@ impl TransactionVersionStore {
             rv.deleted_at_txn_id = self.txn_id;
         }
 
+        // Check if we already have a local version
+        if !self.local_versions.contains_key(&row_id) && !self.write_set.contains_key(&row_id) {
+            // Check if this row exists in parent store and track in write-set
+            // Single lookup instead of two separate calls
+            let read_version = self.parent_store.get_visible_version(row_id, self.txn_id);
+            let row_exists = read_version.is_some();
+
+            // Check the memory budget before claiming the row
+            self.reserve_memory(row_id, &rv, read_version.as_ref())?;
+
+            let read_version_seq = self
+                .parent_store
+                .visibility_checker
+                .as_ref()
+                .map(|c| c.get_current_sequence())
+                .unwrap_or(0);
+
+            self.write_set.insert(
+                row_id,
+                WriteSetEntry {
+                    read_version,
+                    read_version_seq,
+                },
+            );
+
+            // For existing rows, try to claim them
+            if row_exists {
+                self.parent_store.try_claim_row(row_id, self.txn_id)?;
+            }
+        } else {
+            self.reserve_memory(row_id, &rv, None)?;
+        }
+
         // Append to version history for this row (for savepoint support)
         if let Some(versions) = self.local_versions.get_mut(&row_id) {
             versions.push(rv);
@@ -2325,6 +2855,8 @@ impl TransactionVersionStore {
             let mut rv = RowVersion::new(self.txn_id, row_id, data);
             rv.create_time = now;
 
+            self.reserve_memory(row_id, &rv, Some(&original_version))?;
+
             // Check if already in local versions (already processed in this transaction)
             if let Some(versions) = self.local_versions.get_mut(&row_id) {
                 // Append new version to history
@@ -2351,6 +2883,37 @@ impl TransactionVersionStore {
         Ok(())
     }
 
+    /// Charges a new version of a row against the memory budget
+    ///
+    /// Only growth is checked, so updates that shrink a row and deletes
+    /// always succeed. `original` is the committed version the write replaces
+    /// when it is not yet in the write set.
+    fn reserve_memory(
+        &mut self,
+        row_id: i64,
+        version: &RowVersion,
+        original: Option<&RowVersion>,
+    ) -> Result<(), Error> {
+        let prev = match self.local_versions.get(&row_id).and_then(|v| v.last()) {
+            Some(local) => Some(local),
+            None => original.or_else(|| {
+                self.write_set
+                    .get(&row_id)
+                    .and_then(|entry| entry.read_version.as_ref())
+            }),
+        };
+        let index_count = self.parent_store.indexes.read().unwrap().len() as i64;
+        let delta = row_bytes_delta(prev, version)
+            + live_row_delta(prev, version) * index_count * INDEX_ENTRY_BYTES as i64;
+        if delta > 0 {
+            self.parent_store
+                .memory
+                .check(self.pending_bytes.max(0) as u64, delta as u64)?;
+        }
+        self.pending_bytes += delta;
+        Ok(())
+    }
+
     /// Check if we have local changes for a row
     pub fn has_locally_seen(&self, row_id: i64) -> bool {
         self.local_versions.contains_key(&row_id)
@@ -2513,19 +3076,55 @@ impl TransactionVersionStore {
             }
         }
 
-        // Remove rows with no remaining versions and release their claims
+        // Remove rows with no remaining versions and release their claims,
+        // unless the row is also locked FOR UPDATE
         for row_id in &rows_to_remove_completely {
             self.local_versions.remove(row_id);
-            self.parent_store.release_row_claim(*row_id, self.txn_id);
+            if self.row_locks.get(row_id) != Some(&RowLockMode::Update) {
+                self.parent_store.release_row_claim(*row_id, self.txn_id);
+            }
             self.write_set.remove(row_id);
         }
     }
 
-    /// Release all row claims held by this transaction
-    fn release_all_claims(&self) {
+    /// Locks a row until this transaction ends (SELECT ... FOR UPDATE / FOR SHARE)
+    ///
+    /// FOR UPDATE claims the row the same way a write does; FOR SHARE takes a
+    /// shared lock that only conflicts with claims from other transactions.
+    pub fn lock_row(&mut self, row_id: i64, mode: RowLockMode) -> Result<(), Error> {
+        match mode {
+            RowLockMode::Update => self.parent_store.try_claim_row(row_id, self.txn_id)?,
+            RowLockMode::Share => {
+                // Rows this transaction writes or locked FOR UPDATE are
+                // already held exclusively
+                if self.write_set.contains_key(&row_id)
+                    || self.row_locks.get(&row_id) == Some(&RowLockMode::Update)
+                {
+                    return Ok(());
+                }
+                self.parent_store.try_lock_row_shared(row_id, self.txn_id)?;
+            }
+        }
+
+        let held = self.row_locks.entry(row_id).or_insert(mode);
+        if mode == RowLockMode::Update {
+            *held = RowLockMode::Update;
+        }
+        Ok(())
+    }
+
+    /// Release all row claims and row locks held by this transaction
+    pub fn release_all_claims(&self) {
         for (row_id, _) in self.write_set.iter() {
             self.parent_store.release_row_claim(*row_id, self.txn_id);
         }
+        for (row_id, mode) in self.row_locks.iter() {
+            // A FOR UPDATE lock may have been upgraded from FOR SHARE
+            self.parent_store.release_shared_lock(*row_id, self.txn_id);
+            if *mode == RowLockMode::Update {
+                self.parent_store.release_row_claim(*row_id, self.txn_id);
+            }
+        }
     }
 }
 
@@ -2541,6 +3140,7 @@ impl fmt::Debug for TransactionVersionStore {
             .field("txn_id", &self.txn_id)
             .field("local_version_count", &self.local_versions.len())
             .field("write_set_count", &self.write_set.len())
+            .field("row_lock_count", &self.row_locks.len())
             .finish()
     }
 }
@@ -2794,4 +3394,68 @@ mod tests {
         let visible = store.get_visible_version(100, 2);
         assert!(visible.is_none());
     }
+
+    /// READ COMMITTED visibility checker backed by an explicit committed set
+    struct ReadCommittedChecker {
+        committed: RwLock<Vec<i64>>,
+    }
+
+    impl VisibilityChecker for ReadCommittedChecker {
+        fn is_visible(&self, version_txn_id: i64, viewing_txn_id: i64) -> bool {
+            version_txn_id == viewing_txn_id
+                || self.committed.read().unwrap().contains(&version_txn_id)
+        }
+
+        fn get_current_sequence(&self) -> i64 {
+            0
+        }
+
+        fn get_active_transaction_ids(&self) -> Vec<i64> {
+            Vec::new()
+        }
+
+        fn is_read_committed(&self, _viewing_txn_id: i64) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_live_row_counter_matches_scan() {
+        let checker = Arc::new(ReadCommittedChecker {
+            committed: RwLock::new(vec![1]),
+        });
+        let store = VersionStore::with_visibility_checker(
+            "test_table".to_string(),
+            test_schema(),
+            Arc::clone(&checker) as Arc<dyn VisibilityChecker>,
+        );
+
+        let row = Row::from(vec![Value::from(42)]);
+        store.add_versions_batch(
+            (1..=3)
+                .map(|id| (id, RowVersion::new(1, id, row.clone())))
+                .collect(),
+        );
+        assert_eq!(store.count_visible_rows(10), 3);
+
+        // Delete and insert by a transaction that hasn't completed its commit
+        store.add_versions_batch(vec![
+            (1, RowVersion::new_deleted(2, 1, row.clone())),
+            (4, RowVersion::new(2, 4, row.clone())),
+            (5, RowVersion::new(2, 5, row)),
+        ]);
+        assert_eq!(store.count_visible_rows(10), 3);
+        assert_eq!(
+            store.count_visible_rows(10),
+            store.get_all_visible_rows(10).len()
+        );
+
+        // Once committed the delta is folded into the maintained count
+        checker.committed.write().unwrap().push(2);
+        assert_eq!(store.count_visible_rows(10), 4);
+        assert_eq!(
+            store.count_visible_rows(10),
+            store.get_all_visible_rows(10).len()
+        );
+    }
 }
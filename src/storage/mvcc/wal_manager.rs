@@ -1090,32 +1090,15 @@ impl WALManager {
 
         // If no checkpoint or couldn't open WAL file, look for existing WAL files
         if wal_file.is_none() {
-            let mut wal_files: Vec<String> = Vec::new();
-
-            if let Ok(entries) = fs::read_dir(&path) {
-                for entry in entries.filter_map(|e| e.ok()) {
-                    let name = entry.file_name().to_string_lossy().to_string();
-                    if name.starts_with("wal-") && name.ends_with(".log") {
-                        wal_files.push(name);
-                    }
-                }
-            }
-
-            wal_files.sort();
+            let wal_files = list_wal_files(&path);
 
             if let Some(newest) = wal_files.last() {
                 wal_filename = newest.clone();
                 let wal_path = path.join(newest);
 
                 // Try to extract LSN from filename
-                if let Some(lsn_start) = wal_filename.find("lsn-") {
-                    if let Some(lsn_end) = wal_filename[lsn_start + 4..].find('.') {
-                        if let Ok(lsn) =
-                            wal_filename[lsn_start + 4..lsn_start + 4 + lsn_end].parse::<u64>()
-                        {
-                            initial_lsn = lsn;
-                        }
-                    }
+                if let Some(lsn) = lsn_from_filename(&wal_filename) {
+                    initial_lsn = lsn;
                 }
 
                 if let Ok(file) = OpenOptions::new().read(true).append(true).open(&wal_path) {
@@ -1205,11 +1188,12 @@ impl WALManager {
 
     /// Extract sequence number from WAL filename
     fn extract_sequence_from_filename(filename: &str) -> Option<u64> {
-        // Try new format: wal_00000001.log
-        if filename.starts_with("wal_") {
-            if let Some(dot_pos) = filename.find('.') {
-                return filename[4..dot_pos].parse().ok();
-            }
+        // Try new format: wal_00000001.log or wal_00000001-YYYYMMDD-HHMMSS-lsn-N.log
+        if let Some(rest) = filename.strip_prefix("wal_") {
+            let digits = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            return rest[..digits].parse().ok();
         }
         // Try old format: wal-YYYYMMDD-HHMMSS-lsn-N.log (sequence is implicit from LSN)
         if filename.starts_with("wal-") {
@@ -1468,6 +1452,15 @@ impl WALManager {
         self.current_file_position.load(Ordering::Relaxed)
     }
 
+    /// Get the size of all WAL segments on disk, including the current file
+    pub fn total_size(&self) -> u64 {
+        list_wal_files(&self.path)
+            .iter()
+            .filter_map(|name| fs::metadata(self.path.join(name)).ok())
+            .map(|meta| meta.len())
+            .sum()
+    }
+
     /// Get maximum WAL file size
     pub fn max_file_size(&self) -> u64 {
         self.max_wal_size
@@ -1576,20 +1569,11 @@ impl WALManager {
             }
         }
 
-        // Collect WAL files to replay
-        let mut wal_files: Vec<PathBuf> = Vec::new();
-
-        if let Ok(entries) = fs::read_dir(&self.path) {
-            for entry in entries.filter_map(|e| e.ok()) {
-                let name = entry.file_name().to_string_lossy().to_string();
-                if (name.starts_with("wal-") || name.starts_with("wal_")) && name.ends_with(".log")
-                {
-                    wal_files.push(entry.path());
-                }
-            }
-        }
-
-        wal_files.sort();
+        // Collect WAL files to replay, in LSN order
+        let wal_files: Vec<PathBuf> = list_wal_files(&self.path)
+            .into_iter()
+            .map(|name| self.path.join(name))
+            .collect();
 
         // =====================================================
         // Phase 1: Analysis - Identify transaction outcomes
@@ -2056,11 +2040,55 @@ impl WALManager {
         self.current_wal_file.lock().unwrap().clone()
     }
 
-    /// Truncate the WAL file to remove entries up to the given LSN
+    /// Truncate the WAL to remove entries up to the given LSN
     ///
     /// This is used after a successful checkpoint/snapshot to reclaim disk space.
-    /// Only entries with LSN > up_to_lsn are kept.
+    /// Only entries with LSN > up_to_lsn are kept: the current file is rewritten
+    /// and older segments holding nothing newer are deleted.
     pub fn truncate_wal(&self, up_to_lsn: u64) -> Result<()> {
+        self.truncate_current_file(up_to_lsn)?;
+        self.remove_applied_segments(up_to_lsn)
+    }
+
+    /// Delete rotated segments whose entries are all covered by up_to_lsn
+    ///
+    /// Every candidate is tried; the first failure is returned.
+    fn remove_applied_segments(&self, up_to_lsn: u64) -> Result<()> {
+        // Holding the name keeps a concurrent rotation from swapping files
+        let current = self
+            .current_wal_file
+            .lock()
+            .map_err(|_| Error::LockAcquisitionFailed("current_wal_file".to_string()))?;
+
+        let mut result = Ok(());
+        for name in list_wal_files(&self.path) {
+            // A segment is named after the LSN it starts from, so one created
+            // after the checkpoint is never a candidate
+            if name == *current || lsn_from_filename(&name).unwrap_or(0) >= up_to_lsn {
+                continue;
+            }
+            let path = self.path.join(&name);
+            if matches!(find_last_lsn(&path), Ok(last_lsn) if last_lsn <= up_to_lsn) {
+                if let Err(e) = fs::remove_file(&path) {
+                    if result.is_ok() {
+                        result = Err(Error::internal(format!(
+                            "failed to remove applied WAL segment {:?}: {}",
+                            path, e
+                        )));
+                    }
+                }
+            }
+        }
+
+        // Make the removals durable
+        File::open(&self.path)
+            .and_then(|dir_file| dir_file.sync_all())
+            .map_err(|e| Error::internal(format!("failed to sync WAL directory: {}", e)))?;
+        result
+    }
+
+    /// Rewrite the current WAL file, keeping only entries after up_to_lsn
+    fn truncate_current_file(&self, up_to_lsn: u64) -> Result<()> {
         // Skip if not running or if up_to_lsn is zero (no valid checkpoint)
         if !self.running.load(Ordering::Acquire) {
             return Err(Error::WalNotRunning);
@@ -2093,16 +2121,10 @@ impl WALManager {
 
         // Extract LSN from current WAL filename to check if truncation is needed
         // If upToLSN <= currentFileLSN, there's nothing to truncate
-        if let Some(lsn_start) = current_wal_name.find("lsn-") {
-            if let Some(lsn_end) = current_wal_name[lsn_start + 4..].find('.') {
-                if let Ok(current_file_lsn) =
-                    current_wal_name[lsn_start + 4..lsn_start + 4 + lsn_end].parse::<u64>()
-                {
-                    if up_to_lsn <= current_file_lsn {
-                        // All entries in this file are already newer than up_to_lsn
-                        return Ok(());
-                    }
-                }
+        if let Some(current_file_lsn) = lsn_from_filename(&current_wal_name) {
+            if up_to_lsn <= current_file_lsn {
+                // All entries in this file are already newer than up_to_lsn
+                return Ok(());
             }
         }
 
@@ -2390,6 +2412,41 @@ impl Drop for WALManager {
     }
 }
 
+/// Parse the LSN embedded in a WAL file name ("...-lsn-N.log")
+fn lsn_from_filename(name: &str) -> Option<u64> {
+    let lsn_start = name.find("lsn-")? + 4;
+    let lsn_end = name[lsn_start..].find('.')?;
+    name[lsn_start..lsn_start + lsn_end].parse().ok()
+}
+
+/// List the WAL segment files in a directory, oldest first
+///
+/// Truncated files ("wal-...") and rotated segments ("wal_NNNNNNNN-...")
+/// are ordered by the LSN in their name, since neither name sorts correctly
+/// as text against the other.
+fn list_wal_files(dir: &Path) -> Vec<String> {
+    let mut wal_files: Vec<String> = Vec::new();
+
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if (name.starts_with("wal-") || name.starts_with("wal_"))
+                && name.ends_with(".log")
+                && !name.starts_with("wal-temp-")
+            {
+                wal_files.push(name);
+            }
+        }
+    }
+
+    wal_files.sort_by(|a, b| {
+        let a_lsn = lsn_from_filename(a).unwrap_or(0);
+        let b_lsn = lsn_from_filename(b).unwrap_or(0);
+        a_lsn.cmp(&b_lsn).then_with(|| a.cmp(b))
+    });
+    wal_files
+}
+
 /// Find the last LSN in a WAL file (32-byte header format)
 fn find_last_lsn(path: &Path) -> Result<u64> {
     let mut file =
@@ -3612,4 +3669,86 @@ mod tests {
 
         wal.close().unwrap();
     }
+
+    #[test]
+    fn test_list_wal_files_orders_by_lsn() {
+        let dir = tempdir().unwrap();
+        for name in [
+            "wal_00000002-20260101-000000-lsn-100.log",
+            "wal-20260101-000000-lsn-20.log",
+            "wal_00000003-20260101-000000-lsn-9.log",
+            "wal-temp-123.log",
+            "checkpoint.meta",
+        ] {
+            File::create(dir.path().join(name)).unwrap();
+        }
+
+        assert_eq!(
+            list_wal_files(dir.path()),
+            vec![
+                "wal_00000003-20260101-000000-lsn-9.log",
+                "wal-20260101-000000-lsn-20.log",
+                "wal_00000002-20260101-000000-lsn-100.log",
+            ]
+        );
+        assert_eq!(
+            WALManager::extract_sequence_from_filename("wal_00000002-20260101-000000-lsn-100.log"),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_truncate_removes_applied_segments() {
+        let dir = tempdir().unwrap();
+        let wal_path = dir.path().join("wal");
+
+        let config = PersistenceConfig {
+            wal_max_size: 500,
+            ..Default::default()
+        };
+        let wal = WALManager::with_config(&wal_path, SyncMode::Full, Some(&config)).unwrap();
+
+        let write = |range: std::ops::RangeInclusive<i64>| {
+            for i in range {
+                let entry = WALEntry::new(
+                    i,
+                    "test_table".to_string(),
+                    i,
+                    WALOperationType::Insert,
+                    vec![0u8; 100],
+                );
+                wal.append_entry(entry).unwrap();
+                wal.write_commit_marker(i).unwrap();
+                wal.maybe_rotate().unwrap();
+            }
+        };
+
+        write(1..=20);
+        let segments_before = list_wal_files(&wal_path).len();
+        assert!(
+            segments_before > 2,
+            "expected rotation, got {}",
+            segments_before
+        );
+
+        let checkpoint_lsn = wal.create_checkpoint(vec![]).unwrap();
+        write(21..=22);
+        wal.truncate_wal(checkpoint_lsn).unwrap();
+
+        // Only the segment holding the two later entries is left
+        let remaining = list_wal_files(&wal_path);
+        assert_eq!(remaining, vec![wal.current_wal_file()]);
+
+        let mut replayed = Vec::new();
+        wal.replay_two_phase(0, |entry| {
+            if entry.operation == WALOperationType::Insert {
+                replayed.push(entry.row_id);
+            }
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(replayed, vec![21, 22]);
+
+        wal.close().unwrap();
+    }
 }
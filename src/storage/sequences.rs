@@ -0,0 +1,103 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sequences persistence
+//!
+//! This module provides storage for sequences in system tables.
+//! Sequences are stored in the `system.sequences` system table, with the
+//! last value handed out, and loaded into the engine during database startup.
+//! The engine saves the value ahead of NEXTVAL in blocks (see
+//! [`SEQUENCE_RESERVE_BLOCK`](crate::core::SEQUENCE_RESERVE_BLOCK)) and
+//! saves the exact value when it closes.
+
+use crate::core::{DataType, Operator, Result, Row, Value};
+use crate::storage::expression::{ComparisonExpr, Expression};
+use crate::storage::traits::Table;
+
+/// System table name for sequences
+pub const SYS_SEQUENCES: &str = "system.sequences";
+
+/// SQL to create the sequences system table
+///
+/// `last_value` is NULL until NEXTVAL first runs.
+pub const CREATE_SEQUENCES_SQL: &str = r#"
+CREATE TABLE IF NOT EXISTS system.sequences (
+    id INTEGER PRIMARY KEY AUTO_INCREMENT,
+    schema TEXT NOT NULL,
+    name TEXT NOT NULL,
+    start_value INTEGER NOT NULL,
+    increment INTEGER NOT NULL,
+    min_value INTEGER NOT NULL,
+    max_value INTEGER NOT NULL,
+    cycle BOOLEAN NOT NULL,
+    last_value INTEGER,
+    UNIQUE(schema, name)
+);
+"#;
+
+/// Check if a table name is the sequences system table
+pub fn is_sequences_table(_schema: &str, name: &str) -> bool {
+    name.eq_ignore_ascii_case(SYS_SEQUENCES)
+}
+
+/// Find a sequence's row in the sequences system table
+///
+/// Schema: id(0), schema(1), name(2), start_value(3), increment(4),
+/// min_value(5), max_value(6), cycle(7), last_value(8)
+pub(crate) fn find_sequence_row(
+    table: &dyn Table,
+    schema_name: &str,
+    name: &str,
+) -> Result<Option<Row>> {
+    let mut scanner = table.scan(&[], None)?;
+    while scanner.next() {
+        let row = scanner.row();
+        if let (Some(Value::Text(schema)), Some(Value::Text(seq_name))) = (row.get(1), row.get(2)) {
+            if schema.eq_ignore_ascii_case(schema_name) && seq_name.eq_ignore_ascii_case(name) {
+                return Ok(Some(row.clone()));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Write a sequence's last value to its row in the sequences system table
+///
+/// Does nothing when the sequence has no row or the value is unchanged.
+pub(crate) fn save_last_value(
+    table: &mut dyn Table,
+    schema_name: &str,
+    name: &str,
+    last_value: Option<i64>,
+) -> Result<()> {
+    let Some(mut row) = find_sequence_row(table, schema_name, name)? else {
+        return Ok(());
+    };
+    let last_value = last_value.map_or(Value::Null(DataType::Integer), Value::integer);
+    if row.get(8) == Some(&last_value) {
+        return Ok(());
+    }
+    let Some(id_value) = row.get(0).cloned() else {
+        return Ok(());
+    };
+    row.set(8, last_value)?;
+
+    let mut id_expr = ComparisonExpr::new("id", Operator::Eq, id_value);
+    id_expr.prepare_for_schema(table.schema());
+    let mut new_row = Some(row);
+    table.update(Some(&id_expr), &mut |old| {
+        Ok(new_row.take().map_or((old, false), |row| (row, true)))
+    })?;
+    Ok(())
+}
@@ -16,9 +16,14 @@
 //! Engine trait for the storage engine
 //!
 
+use std::any::Any;
+use std::sync::Arc;
+
 use rustc_hash::FxHashMap;
 
-use crate::core::{IsolationLevel, Result, Row, Schema};
+use crate::core::{
+    CheckConstraint, DataType, Error, IsolationLevel, Result, Row, Schema, ViewDefinition,
+};
 use crate::storage::config::Config;
 use crate::storage::traits::{Index, Transaction};
 
@@ -40,6 +45,37 @@ use crate::storage::traits::{Index, Transaction};
 ///
 /// engine.close()?;
 /// ```
+///
+/// # Custom engines
+///
+/// An embedder can back a database with its own implementation and register
+/// it for a DSN scheme with `Database::register_engine`. The SQL executor
+/// relies on this contract:
+///
+/// - Every statement runs inside a [`Transaction`] obtained from
+///   [`begin_transaction`](Engine::begin_transaction) (or
+///   [`begin_transaction_with_level`](Engine::begin_transaction_with_level)
+///   for explicit isolation levels). The executor commits or rolls it back.
+/// - Table access goes through [`Transaction::get_table`] and the returned
+///   [`Table`](crate::storage::traits::Table). Reads are consumed as
+///   [`Scanner`](crate::storage::traits::Scanner)s or
+///   [`QueryResult`](crate::storage::traits::QueryResult)s, whose rows must
+///   follow the column order of the table schema.
+/// - Metadata lookups ([`table_exists`](Engine::table_exists),
+///   [`get_table_schema`](Engine::get_table_schema), index listing) must
+///   reflect committed DDL.
+/// - DDL goes through the catalog methods ([`create_table`](Engine::create_table),
+///   [`create_view`](Engine::create_view), [`create_schema`](Engine::create_schema)
+///   and friends). Their default implementations fail with `NotSupported`,
+///   so an engine without a catalog can still serve queries on the tables
+///   it provides.
+/// - A few statements inspect MVCC internals (`SHOW ENGINE STATUS`,
+///   `system.transactions`, vacuum, memory and retention settings). They
+///   downcast [`as_any`](Engine::as_any) to `MVCCEngine` and fail with
+///   `NotSupported` otherwise.
+/// - The database closes the engine through
+///   [`close_engine`](Engine::close_engine) when it is closed or its last
+///   handle is dropped.
 pub trait Engine: Send + Sync {
     /// Opens the storage engine
     ///
@@ -53,6 +89,15 @@ pub trait Engine: Send + Sync {
     /// and releases all resources.
     fn close(&mut self) -> Result<()>;
 
+    /// Closes the storage engine through a shared reference
+    ///
+    /// The database calls this when it is closed and when its last handle
+    /// is dropped, so an engine that buffers writes flushes them here. It
+    /// may run more than once. The default does nothing.
+    fn close_engine(&self) -> Result<()> {
+        Ok(())
+    }
+
     /// Begins a new transaction
     ///
     /// The transaction will use the engine's default isolation level.
@@ -126,10 +171,18 @@ pub trait Engine: Send + Sync {
         index_name: &str,
         column_names: &[String],
         is_unique: bool,
+        nulls_not_distinct: bool,
         index_type: crate::core::IndexType,
     ) {
         // Default implementation does nothing (for in-memory engines)
-        let _ = (table_name, index_name, column_names, is_unique, index_type);
+        let _ = (
+            table_name,
+            index_name,
+            column_names,
+            is_unique,
+            nulls_not_distinct,
+            index_type,
+        );
     }
 
     /// Record an index drop operation to WAL for persistence
@@ -176,7 +229,7 @@ pub trait Engine: Send + Sync {
         data_type: crate::core::DataType,
         nullable: bool,
         auto_increment: Option<bool>,
-        check_expr: Option<Option<String>>,
+        checks: Option<Vec<crate::core::CheckConstraint>>,
     ) {
         // Default implementation does nothing
         let _ = (
@@ -185,7 +238,7 @@ pub trait Engine: Send + Sync {
             data_type,
             nullable,
             auto_increment,
-            check_expr,
+            checks,
         );
     }
 
@@ -195,6 +248,12 @@ pub trait Engine: Send + Sync {
         let _ = (old_table_name, new_table_name);
     }
 
+    /// Record ALTER TABLE AUTO_INCREMENT operation to WAL for persistence
+    fn record_alter_table_auto_increment(&self, table_name: &str, next_id: i64) {
+        // Default implementation does nothing
+        let _ = (table_name, next_id);
+    }
+
     // --- Sequences ---
 
     fn sequence_exists(&self, schema_name: &str, sequence_name: &str) -> Result<bool>;
@@ -227,6 +286,14 @@ pub trait Engine: Send + Sync {
 
     fn list_sequences(&self) -> Result<Vec<(String, String, crate::core::SequenceOptions, i64)>>;
 
+    /// Status of the transaction with the given ID (TXID_STATUS)
+    ///
+    /// Returns None when the engine does not know the transaction.
+    fn transaction_status(&self, txn_id: i64) -> Option<crate::core::TransactionStatus> {
+        let _ = txn_id;
+        None
+    }
+
     /// Fetch rows by IDs directly from storage without creating a full transaction.
     ///
     /// This is an optimization for EXISTS subquery evaluation where we only need
@@ -259,6 +326,151 @@ pub trait Engine: Send + Sync {
             "get_row_fetcher not supported by this engine",
         ))
     }
+
+    // --- Catalog ---
+
+    /// Creates a table and returns its stored schema
+    fn create_table(&self, schema: Schema) -> Result<Schema> {
+        let _ = schema;
+        Err(catalog_not_supported("CREATE TABLE"))
+    }
+
+    /// Drops a table directly from the catalog
+    ///
+    /// Used when the executor undoes DDL; regular drops go through
+    /// [`Transaction::drop_table`].
+    fn drop_table(&self, table_name: &str) -> Result<()> {
+        let _ = table_name;
+        Err(catalog_not_supported("DROP TABLE"))
+    }
+
+    /// Renames a column of a table
+    fn rename_column(&self, table_name: &str, old_name: &str, new_name: &str) -> Result<()> {
+        let _ = (table_name, old_name, new_name);
+        Err(catalog_not_supported("ALTER TABLE RENAME COLUMN"))
+    }
+
+    /// Changes the type, nullability, auto-increment flag or checks of a column
+    fn modify_column(
+        &self,
+        table_name: &str,
+        column_name: &str,
+        data_type: DataType,
+        nullable: bool,
+        auto_increment: Option<bool>,
+        checks: Option<Vec<CheckConstraint>>,
+    ) -> Result<()> {
+        let _ = (
+            table_name,
+            column_name,
+            data_type,
+            nullable,
+            auto_increment,
+            checks,
+        );
+        Err(catalog_not_supported("ALTER TABLE MODIFY COLUMN"))
+    }
+
+    /// Sets the next auto-increment value of a table
+    fn set_auto_increment(&self, table_name: &str, next_id: i64) -> Result<()> {
+        let _ = (table_name, next_id);
+        Err(catalog_not_supported("ALTER TABLE AUTO_INCREMENT"))
+    }
+
+    /// Checks if a schema (namespace) exists
+    fn schema_exists(&self, schema_name: &str) -> Result<bool> {
+        Ok(schema_name.eq_ignore_ascii_case("public"))
+    }
+
+    /// Creates an empty schema (namespace); creating an existing one is a no-op
+    fn create_schema(&self, schema_name: &str) -> Result<()> {
+        let _ = schema_name;
+        Err(catalog_not_supported("CREATE SCHEMA"))
+    }
+
+    /// Removes a schema (namespace) from the catalog
+    ///
+    /// The executor drops the tables of the schema before calling this.
+    fn drop_schema(&self, schema_name: &str) -> Result<()> {
+        let _ = schema_name;
+        Err(catalog_not_supported("DROP SCHEMA"))
+    }
+
+    /// Lists all schema (namespace) names, lowercase
+    fn list_schemas(&self) -> Result<Vec<String>> {
+        Ok(vec!["public".to_string()])
+    }
+
+    /// Lists the table schemas of a schema (namespace)
+    fn list_schema_tables(&self, schema_name: &str) -> Result<Vec<Schema>> {
+        let _ = schema_name;
+        Ok(Vec::new())
+    }
+
+    /// Creates a view
+    fn create_view(
+        &self,
+        schema_name: &str,
+        view_name: &str,
+        query: String,
+        if_not_exists: bool,
+    ) -> Result<()> {
+        let _ = (schema_name, view_name, query, if_not_exists);
+        Err(catalog_not_supported("CREATE VIEW"))
+    }
+
+    /// Creates a view, replacing the definition of an existing view
+    fn create_or_replace_view(
+        &self,
+        schema_name: &str,
+        view_name: &str,
+        query: String,
+    ) -> Result<()> {
+        let _ = (schema_name, view_name, query);
+        Err(catalog_not_supported("CREATE OR REPLACE VIEW"))
+    }
+
+    /// Drops a view
+    fn drop_view(&self, schema_name: &str, view_name: &str, if_exists: bool) -> Result<()> {
+        let _ = (schema_name, view_name, if_exists);
+        Err(catalog_not_supported("DROP VIEW"))
+    }
+
+    /// Gets a view definition
+    fn get_view(&self, schema_name: &str, view_name: &str) -> Result<Option<Arc<ViewDefinition>>> {
+        let _ = (schema_name, view_name);
+        Ok(None)
+    }
+
+    /// Gets a view definition by names that are already lowercase
+    ///
+    /// Called for every table reference in a query; engines can override
+    /// it to skip case folding.
+    fn get_view_lowercase(
+        &self,
+        schema_lower: &str,
+        name_lower: &str,
+    ) -> Result<Option<Arc<ViewDefinition>>> {
+        self.get_view(schema_lower, name_lower)
+    }
+
+    /// Lists all views as (schema, view name) pairs
+    fn list_views(&self) -> Result<Vec<(String, String)>> {
+        Ok(Vec::new())
+    }
+
+    /// Access the concrete engine for downcasting, if the engine allows it
+    ///
+    /// `MVCCEngine` returns itself. Engines that wrap an `MVCCEngine` can
+    /// return the inner engine to enable the statements that inspect MVCC
+    /// internals.
+    fn as_any(&self) -> Option<&dyn Any> {
+        None
+    }
+}
+
+fn catalog_not_supported(operation: &str) -> Error {
+    Error::NotSupportedMessage(format!("{} is not supported by this engine", operation))
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -87,6 +88,14 @@ pub trait Index: Send + Sync {
     /// Returns true if this is a unique index
     fn is_unique(&self) -> bool;
 
+    /// Returns true if a unique index treats NULL keys as equal
+    ///
+    /// By default NULLs are distinct, so any number of rows may share a NULL
+    /// key. With `NULLS NOT DISTINCT` they conflict like any other value.
+    fn nulls_not_distinct(&self) -> bool {
+        false
+    }
+
     /// Finds all entries where the columns equal the given values
     ///
     /// # Arguments
@@ -160,6 +169,19 @@ pub trait Index: Send + Sync {
         results
     }
 
+    /// Returns true if the index can answer IS NULL / IS NOT NULL lookups
+    fn supports_null_lookup(&self) -> bool {
+        false
+    }
+
+    /// Returns row IDs whose indexed value is NULL (`is_null`) or not NULL
+    ///
+    /// Only meaningful when `supports_null_lookup()` returns true.
+    fn get_row_ids_null(&self, is_null: bool) -> Vec<i64> {
+        let _ = is_null;
+        Vec::new()
+    }
+
     /// Returns row IDs that match the given expression
     ///
     /// # Arguments
@@ -192,6 +214,23 @@ pub trait Index: Send + Sync {
         Vec::new() // Default implementation - override in concrete indexes
     }
 
+    /// Whether the index keeps the indexed values of each row (covering index)
+    ///
+    /// When true, [`get_row_values`](Self::get_row_values) returns the values,
+    /// so queries touching only the indexed columns can skip the base row.
+    fn stores_values(&self) -> bool {
+        false // Default implementation - override in concrete indexes
+    }
+
+    /// Returns the indexed column values of a row (for index-only scans)
+    ///
+    /// The values are in index column order. Returns None if the row is not
+    /// in the index or the index does not store values.
+    fn get_row_values(&self, row_id: i64) -> Option<Vec<Value>> {
+        let _ = row_id;
+        None // Default implementation - override in concrete indexes
+    }
+
     /// Returns row IDs in sorted order by index value (for ORDER BY optimization)
     ///
     /// This enables efficient ORDER BY queries by iterating through the B-tree
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -34,7 +35,7 @@ pub mod transaction;
 // Re-export main traits
 pub use engine::Engine;
 pub use index_trait::Index;
-pub use result::{EmptyResult, MemoryResult, QueryResult};
+pub use result::{ColumnMeta, EmptyResult, MemoryResult, QueryResult};
 pub use scanner::{EmptyScanner, Scanner, VecScanner};
-pub use table::{ScanPlan, Table};
+pub use table::{IndexHint, ScanPlan, Table};
 pub use transaction::{TemporalType, Transaction};
@@ -17,7 +17,18 @@
 
 use rustc_hash::FxHashMap;
 
-use crate::core::{Result, Row, Value};
+use crate::core::{DataType, Error, Result, Row, Value};
+
+/// Name, type and nullability of one result column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnMeta {
+    /// Column name as returned by [`QueryResult::columns`]
+    pub name: String,
+    /// Declared type, or the type inferred from the first row
+    pub data_type: DataType,
+    /// Whether the column may hold NULL
+    pub nullable: bool,
+}
 
 /// QueryResult represents the result of a SQL query
 ///
@@ -71,11 +82,43 @@ pub trait QueryResult: Send {
     fn close(&mut self) -> Result<()>;
 
     /// Returns the number of rows affected by an INSERT, UPDATE, or DELETE
+    ///
+    /// For UPDATE this counts every row matched by the WHERE clause.
     fn rows_affected(&self) -> i64;
 
+    /// Returns the number of rows an UPDATE actually changed, leaving out
+    /// rows set to the values they already had
+    ///
+    /// Defaults to [`rows_affected`](Self::rows_affected) for other statements.
+    fn rows_changed(&self) -> i64 {
+        self.rows_affected()
+    }
+
+    /// Returns true when a DDL statement changed nothing because of its
+    /// IF EXISTS or IF NOT EXISTS clause
+    fn is_no_op(&self) -> bool {
+        false
+    }
+
     /// Returns the last inserted ID for an INSERT operation
     fn last_insert_id(&self) -> i64;
 
+    /// Returns the error that ended the rows early, if any
+    ///
+    /// Checked once `next()` returns `false`: a result still being read when
+    /// its statement times out stops and reports the timeout here.
+    fn err(&self) -> Option<&Error> {
+        None
+    }
+
+    /// Returns the type and nullability of each column, if known
+    ///
+    /// Only the executor's outermost result attaches this; wrappers do not
+    /// forward it.
+    fn columns_meta(&self) -> Option<&[ColumnMeta]> {
+        None
+    }
+
     /// Sets column aliases for this result
     ///
     /// The map keys are alias names, values are original column names.
@@ -19,10 +19,36 @@
 use rustc_hash::FxHashMap;
 use std::fmt;
 
-use crate::core::{DataType, Error, IndexType, Result, Row, Schema, Value};
+use crate::core::{
+    CheckConstraint, DataType, Error, IndexType, Result, Row, RowLockMode, Schema, Value,
+};
 use crate::storage::expression::Expression;
 use crate::storage::traits::{Index, QueryResult, Scanner};
 
+/// Restricts which indexes a table may use to answer reads
+///
+/// Set from optimizer hints (`/*+ INDEX(...) */`, `FORCE INDEX`, ...). It only
+/// affects index selection for scans, never index maintenance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IndexHint {
+    /// Only the named indexes may be used
+    Only(Vec<String>),
+    /// The named indexes may not be used; an empty list excludes every index
+    Exclude(Vec<String>),
+}
+
+impl IndexHint {
+    /// Check whether the index with the given name may be used
+    pub fn allows(&self, index_name: &str) -> bool {
+        match self {
+            IndexHint::Only(names) => names.iter().any(|n| n.eq_ignore_ascii_case(index_name)),
+            IndexHint::Exclude(names) => {
+                !names.is_empty() && !names.iter().any(|n| n.eq_ignore_ascii_case(index_name))
+            }
+        }
+    }
+}
+
 /// Describes the access method that will be used for a table scan
 ///
 /// This is used by EXPLAIN to show users how their queries will be executed.
@@ -65,6 +91,20 @@ pub enum ScanPlan {
         columns: Vec<String>,
         conditions: Vec<String>,
     },
+    /// Index-only scan - answers the query from a covering index's stored values
+    IndexOnlyScan {
+        table: String,
+        index_name: String,
+        /// How the matching rows are found
+        lookup: Box<ScanPlan>,
+    },
+    /// Ordered index scan - reads rows in index order so ORDER BY needs no sort
+    OrderedIndexScan {
+        table: String,
+        index_name: String,
+        /// ORDER BY terms served by the index, e.g. `a DESC`
+        order: Vec<String>,
+    },
 }
 
 impl fmt::Display for ScanPlan {
@@ -136,6 +176,31 @@ impl fmt::Display for ScanPlan {
                 }
                 Ok(())
             }
+            ScanPlan::IndexOnlyScan {
+                table,
+                index_name,
+                lookup,
+            } => {
+                write!(f, "Index Only Scan using {} on {}", index_name, table)?;
+                // Keep the lookup's conditions, dropping its own header line
+                for line in lookup.to_string().lines().skip(1) {
+                    write!(f, "\n{}", line)?;
+                }
+                Ok(())
+            }
+            ScanPlan::OrderedIndexScan {
+                table,
+                index_name,
+                order,
+            } => {
+                write!(
+                    f,
+                    "Index Scan using {} on {}\n  Index Order: {}, ordered index scan (no sort)",
+                    index_name,
+                    table,
+                    order.join(", ")
+                )
+            }
         }
     }
 }
@@ -248,7 +313,8 @@ pub trait Table: Send + Sync {
     /// * `setter` - Function that transforms a row in place, returns true if changed
     ///
     /// # Returns
-    /// The number of rows updated
+    /// The number of rows updated. Rows the setter reports as unchanged are
+    /// not written and not counted.
     fn update(
         &mut self,
         where_expr: Option<&dyn Expression>,
@@ -395,6 +461,25 @@ pub trait Table: Send + Sync {
     /// protocol needs to be executed.
     fn has_local_changes(&self) -> bool;
 
+    /// Checks the unique constraints a bulk load deferred to commit
+    ///
+    /// Fails on the first uncommitted row whose key another row, committed
+    /// or written by the same transaction, already holds.
+    fn check_deferred_constraints(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Locks the rows matching the expression until the transaction ends
+    ///
+    /// Used by SELECT ... FOR UPDATE / FOR SHARE. Returns the number of rows
+    /// locked, or an error if another transaction holds a conflicting lock
+    /// on one of them.
+    fn lock_rows(&self, _where_expr: Option<&dyn Expression>, _mode: RowLockMode) -> Result<usize> {
+        Err(Error::NotSupportedMessage(
+            "row locks are not supported on this table".to_string(),
+        ))
+    }
+
     /// Returns the pending versions to be committed for WAL logging
     ///
     /// Returns a list of (row_id, row_data, is_deleted, txn_id) tuples representing
@@ -441,12 +526,53 @@ pub trait Table: Send + Sync {
         self.create_index(name, columns, is_unique)
     }
 
+    /// Creates an index, choosing how a unique index treats NULL keys
+    ///
+    /// # Arguments
+    /// * `name` - The name of the index
+    /// * `columns` - The column names to include in the index
+    /// * `is_unique` - Whether this is a unique index
+    /// * `nulls_not_distinct` - Whether NULL keys conflict with each other
+    ///   (`NULLS NOT DISTINCT`) instead of each being distinct
+    /// * `index_type` - Optional index type, as for `create_index_with_type`
+    fn create_index_with_nulls(
+        &self,
+        name: &str,
+        columns: &[&str],
+        is_unique: bool,
+        nulls_not_distinct: bool,
+        index_type: Option<IndexType>,
+    ) -> Result<()> {
+        if nulls_not_distinct {
+            return Err(Error::NotSupportedMessage(
+                "NULLS NOT DISTINCT indexes are not supported by this table type".to_string(),
+            ));
+        }
+        self.create_index_with_type(name, columns, is_unique, index_type)
+    }
+
     /// Drops an index from the table
     ///
     /// # Arguments
     /// * `name` - The name of the index to drop
     fn drop_index(&self, name: &str) -> Result<()>;
 
+    /// Rebuilds indexes from the table data
+    ///
+    /// All new indexes are built and validated against the rows before any
+    /// of them replaces its predecessor, so on error the existing indexes
+    /// are left untouched. Like the indexes they replace, they cover every
+    /// committed row, not just those visible to this transaction.
+    ///
+    /// # Arguments
+    /// * `names` - The names of the indexes to rebuild
+    fn rebuild_indexes(&self, names: &[&str]) -> Result<()> {
+        let _ = names;
+        Err(Error::NotSupportedMessage(
+            "rebuilding indexes is not supported for this table".to_string(),
+        ))
+    }
+
     /// Creates a btree index on a column
     ///
     /// # Arguments
@@ -597,6 +723,62 @@ pub trait Table: Send + Sync {
         None // Default implementation - override in concrete tables
     }
 
+    /// Name of an index that can return rows ordered by `columns`
+    ///
+    /// Matches an index supporting ordered iteration whose leading columns
+    /// are exactly `columns`, in order.
+    fn ordered_index_for(&self, columns: &[&str]) -> Option<String> {
+        let _ = columns;
+        None // Default implementation - override in concrete tables
+    }
+
+    /// Collects rows in index order with limit (multi-column ORDER BY + LIMIT pushdown)
+    ///
+    /// Like [`collect_rows_ordered_by_index`](Self::collect_rows_ordered_by_index),
+    /// but for an index found by [`ordered_index_for`](Self::ordered_index_for).
+    /// All columns are read in one direction. NULLs in the leading column are
+    /// placed first or last as requested; the caller must make sure the other
+    /// columns need no such adjustment.
+    ///
+    /// # Returns
+    /// Some(Vec<Row>) if an ordered index covers `columns`, None otherwise
+    fn collect_rows_in_index_order(
+        &self,
+        columns: &[&str],
+        ascending: bool,
+        nulls_first: bool,
+        limit: usize,
+        offset: usize,
+    ) -> Option<Vec<Row>> {
+        let _ = (columns, ascending, nulls_first, limit, offset);
+        None // Default implementation - override in concrete tables
+    }
+
+    /// Name of an index that stores the values of all `columns` (covering index)
+    fn covering_index_for(&self, columns: &[usize]) -> Option<String> {
+        let _ = columns;
+        None // Default implementation - override in concrete tables
+    }
+
+    /// Collects rows matching `where_expr` from a covering index (index-only scan)
+    ///
+    /// Rows are found through the usual index lookup, but their values come
+    /// from an index storing all of `columns` instead of the base row. The
+    /// returned rows have the full schema width with only the covering
+    /// index's columns filled in, so `columns` must include every column the
+    /// filter and the query read.
+    ///
+    /// # Returns
+    /// Some(Vec<Row>) if a covering index and an index lookup apply, None otherwise
+    fn collect_rows_index_only(
+        &self,
+        where_expr: &dyn Expression,
+        columns: &[usize],
+    ) -> Option<Vec<Row>> {
+        let _ = (where_expr, columns);
+        None // Default implementation - override in concrete tables
+    }
+
     /// Collects rows grouped by an indexed partition column (PARTITION BY optimization)
     ///
     /// For window functions with `PARTITION BY col` where col is indexed, this uses the
@@ -680,14 +862,14 @@ pub trait Table: Send + Sync {
     /// * `column_type` - The new data type
     /// * `nullable` - Whether the column can contain NULL values
     /// * `auto_increment` - Optional new auto_increment setting
-    /// * `check_expr` - Optional new check_expr setting
+    /// * `checks` - Optional replacement list of CHECK constraints
     fn modify_column(
         &self,
         name: &str,
         column_type: DataType,
         nullable: bool,
         auto_increment: Option<bool>,
-        check_expr: Option<Option<String>>,
+        checks: Option<Vec<CheckConstraint>>,
     ) -> Result<()>;
 
     // ---- Query Operations ----
@@ -740,6 +922,14 @@ pub trait Table: Send + Sync {
         temporal_value: i64,
     ) -> Result<Box<dyn QueryResult>>;
 
+    /// Restricts index selection for reads through this table handle
+    ///
+    /// # Arguments
+    /// * `hint` - The restriction, or None to let the table pick freely
+    fn set_index_hint(&mut self, hint: Option<IndexHint>) {
+        let _ = hint; // Default implementation - override in concrete tables
+    }
+
     /// Explains what access method would be used for a scan
     ///
     /// This method analyzes the WHERE expression and returns a ScanPlan
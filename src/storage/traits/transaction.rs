@@ -68,7 +68,7 @@ pub trait Transaction: Send {
 
     /// Rolls back to a savepoint, discarding all changes made after it
     ///
-    /// The savepoint itself is also removed after rollback.
+    /// The savepoint itself is kept; savepoints created after it are removed.
     fn rollback_to_savepoint(&mut self, name: &str) -> Result<()>;
 
     /// Gets the timestamp associated with a savepoint
@@ -82,6 +82,21 @@ pub trait Transaction: Send {
     /// Sets the isolation level for this transaction
     fn set_isolation_level(&mut self, level: IsolationLevel) -> Result<()>;
 
+    /// Returns the isolation level of this transaction
+    ///
+    /// Transactions that can't change their level run at the default one.
+    fn isolation_level(&self) -> IsolationLevel {
+        IsolationLevel::default()
+    }
+
+    /// Switches this transaction to bulk loading
+    ///
+    /// Inserts skip the per-row unique index checks, and indexes are updated
+    /// in one batch per index at commit, where uniqueness is checked across
+    /// all the rows written. Must be set before the transaction touches a
+    /// table.
+    fn set_bulk_load(&mut self, enabled: bool) -> Result<()>;
+
     // ---- Table Operations ----
 
     /// Creates a new table with the given schema
@@ -330,3 +330,115 @@ fn test_sum_with_where() {
         "Expected sum of 3500 for Electronics"
     );
 }
+
+fn setup_nullable_dimensions(db: &Database) {
+    db.execute(
+        "CREATE TABLE orders_null_group (id INTEGER PRIMARY KEY, region TEXT, channel INTEGER, amount FLOAT)",
+        (),
+    )
+    .expect("Failed to create table");
+    db.execute(
+        "INSERT INTO orders_null_group VALUES \
+         (1, 'North', 1, 10.0), (2, NULL, NULL, 20.0), (3, 'North', NULL, 30.0), \
+         (4, NULL, 2, 40.0), (5, NULL, NULL, 50.0), (6, 'South', 1, 60.0)",
+        (),
+    )
+    .expect("Failed to insert data");
+}
+
+fn collect_groups(db: &Database, sql: &str) -> Vec<(Option<String>, i64, f64)> {
+    db.query(sql, ())
+        .expect("Failed to run query")
+        .map(|row| {
+            let row = row.unwrap();
+            (
+                row.get(0).unwrap(),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_group_by_puts_nulls_in_one_group() {
+    let db = Database::open("memory://aggregation_null_group").expect("Failed to create database");
+    setup_nullable_dimensions(&db);
+
+    let expected = vec![
+        (None, 3, 110.0),
+        (Some("North".to_string()), 2, 40.0),
+        (Some("South".to_string()), 1, 60.0),
+    ];
+    let sql = "SELECT region, COUNT(*), SUM(amount) FROM orders_null_group GROUP BY region ORDER BY region NULLS FIRST";
+    assert_eq!(collect_groups(&db, sql), expected);
+
+    let having = "SELECT region, COUNT(*), SUM(amount) FROM orders_null_group \
+                  GROUP BY region HAVING COUNT(*) > 2";
+    assert_eq!(collect_groups(&db, having), vec![expected[0].clone()]);
+
+    // Same result when the grouping column is indexed
+    db.execute(
+        "CREATE INDEX idx_null_group_region ON orders_null_group(region)",
+        (),
+    )
+    .unwrap();
+    assert_eq!(collect_groups(&db, sql), expected);
+
+    // Integer keys and expressions over NULL group the same way
+    let sql = "SELECT CAST(channel AS TEXT), COUNT(*), SUM(amount) FROM orders_null_group \
+               GROUP BY channel ORDER BY channel NULLS FIRST";
+    assert_eq!(
+        collect_groups(&db, sql),
+        vec![
+            (None, 3, 100.0),
+            (Some("1".to_string()), 2, 70.0),
+            (Some("2".to_string()), 1, 40.0),
+        ]
+    );
+    let sql = "SELECT UPPER(region), COUNT(*), SUM(amount) FROM orders_null_group \
+               GROUP BY UPPER(region) ORDER BY 1 NULLS FIRST";
+    assert_eq!(collect_groups(&db, sql)[0], (None, 3, 110.0));
+}
+
+#[test]
+fn test_group_by_multiple_columns_with_nulls() {
+    let db =
+        Database::open("memory://aggregation_null_group_multi").expect("Failed to create database");
+    setup_nullable_dimensions(&db);
+
+    let groups: Vec<(Option<String>, Option<i64>, i64)> = db
+        .query(
+            "SELECT region, channel, COUNT(*) FROM orders_null_group \
+             GROUP BY region, channel ORDER BY region NULLS FIRST, channel NULLS FIRST",
+            (),
+        )
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (
+                row.get(0).unwrap(),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        groups,
+        vec![
+            (None, None, 2),
+            (None, Some(2), 1),
+            (Some("North".to_string()), None, 1),
+            (Some("North".to_string()), Some(1), 1),
+            (Some("South".to_string()), Some(1), 1),
+        ]
+    );
+
+    let distinct: i64 = db
+        .query_one(
+            "SELECT COUNT(*) FROM (SELECT DISTINCT region FROM orders_null_group) AS d",
+            (),
+        )
+        .unwrap();
+    assert_eq!(distinct, 3);
+}
@@ -0,0 +1,114 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Array parameters matched with `= ANY($1)`
+//!
+//! A `Vec` or slice binds as one JSON array parameter, so a variable-length
+//! id list does not need a dynamically built `IN (...)`.
+
+use oxibase::Database;
+
+fn setup() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE items (id INTEGER PRIMARY KEY, name TEXT)", ())
+        .unwrap();
+    db.execute(
+        "INSERT INTO items VALUES (1, 'one'), (2, 'two'), (3, 'three'), (4, 'four'), (5, 'five')",
+        (),
+    )
+    .unwrap();
+    db
+}
+
+fn ids(db: &Database, sql: &str, param: impl oxibase::ToParam) -> Vec<i64> {
+    db.query(sql, (param,))
+        .unwrap()
+        .map(|row| row.unwrap().get(0).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_vec_param_matches_any() {
+    let db = setup();
+    let sql = "SELECT id FROM items WHERE id = ANY($1) ORDER BY id";
+
+    assert_eq!(ids(&db, sql, vec![4i64, 2, 9]), vec![2, 4]);
+    // The cached plan takes a different list on the next call
+    assert_eq!(ids(&db, sql, vec![1i64, 3, 5]), vec![1, 3, 5]);
+    assert_eq!(ids(&db, sql, [5i32, 1].as_slice()), vec![1, 5]);
+    assert!(ids(&db, sql, Vec::<i64>::new()).is_empty());
+
+    let names = vec!["two".to_string(), "five".to_string()];
+    assert_eq!(
+        ids(
+            &db,
+            "SELECT id FROM items WHERE name = ANY($1) ORDER BY id",
+            names
+        ),
+        vec![2, 5]
+    );
+
+    assert_eq!(
+        ids(
+            &db,
+            "SELECT id FROM items WHERE id <> ALL($1) ORDER BY id",
+            vec![1i64, 2, 3]
+        ),
+        vec![4, 5]
+    );
+    assert_eq!(
+        ids(
+            &db,
+            "SELECT id FROM items WHERE id <> ALL($1) ORDER BY id",
+            Vec::<i64>::new()
+        ),
+        vec![1, 2, 3, 4, 5]
+    );
+}
+
+#[test]
+fn test_json_and_prepared_array_params() {
+    let db = setup();
+
+    let filter = serde_json::json!([3, 1]);
+    assert_eq!(
+        ids(
+            &db,
+            "SELECT id FROM items WHERE id = ANY($1) ORDER BY id",
+            filter
+        ),
+        vec![1, 3]
+    );
+
+    let stmt = db
+        .prepare("SELECT COUNT(*) FROM items WHERE id = ANY($1)")
+        .unwrap();
+    for (list, expected) in [(vec![1i64], 1i64), (vec![1, 2, 3, 4], 4), (vec![7], 0)] {
+        let count: i64 = stmt
+            .query((list,))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .get(0)
+            .unwrap();
+        assert_eq!(count, expected);
+    }
+
+    // Array expressions other than parameters work too
+    let count: i64 = db
+        .query_one("SELECT COUNT(*) FROM items WHERE id = ANY('[2, 4]')", ())
+        .unwrap();
+    assert_eq!(count, 2);
+}
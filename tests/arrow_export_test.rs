@@ -0,0 +1,160 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Arrow Export Tests
+//!
+//! Tests converting query results to Arrow record batches
+
+#![cfg(feature = "arrow")]
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    DurationMicrosecondType, Float64Type, Int64Type, TimestampMicrosecondType,
+};
+use arrow_array::{Array, RecordBatchReader};
+use arrow_schema::{DataType, TimeUnit};
+use oxibase::{ArrowBatches, Database};
+
+fn setup(db: &Database) {
+    db.execute(
+        "CREATE TABLE events (id INTEGER PRIMARY KEY, name TEXT, score FLOAT, \
+         active BOOLEAN, at TIMESTAMP, payload JSON)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO events VALUES \
+         (1, 'login', 1.5, true, '2024-01-02 03:04:05', '{\"ip\": \"10.0.0.1\"}'), \
+         (2, NULL, NULL, false, NULL, NULL), \
+         (3, 'logout', 3.0, NULL, '2024-01-02 04:00:00', '[1, 2]')",
+        (),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_mixed_types_to_arrow() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let reader = db
+        .query_arrow(
+            "SELECT id, name, score, active, at, payload, INTERVAL '90 seconds' AS wait \
+             FROM events ORDER BY id",
+            (),
+        )
+        .unwrap();
+    let schema = reader.schema();
+    let types: Vec<DataType> = schema
+        .fields()
+        .iter()
+        .map(|f| f.data_type().clone())
+        .collect();
+    assert_eq!(
+        types,
+        vec![
+            DataType::Int64,
+            DataType::Utf8,
+            DataType::Float64,
+            DataType::Boolean,
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            DataType::Utf8,
+            DataType::Duration(TimeUnit::Microsecond),
+        ]
+    );
+    assert_eq!(
+        schema
+            .field(5)
+            .metadata()
+            .get("ARROW:extension:name")
+            .map(String::as_str),
+        Some("arrow.json")
+    );
+
+    let batches: Vec<_> = reader.map(|b| b.unwrap()).collect();
+    assert_eq!(batches.len(), 1);
+    let batch = &batches[0];
+    assert_eq!(batch.num_rows(), 3);
+
+    let ids = batch.column(0).as_primitive::<Int64Type>();
+    assert_eq!(ids.values().to_vec(), vec![1, 2, 3]);
+
+    let names = batch.column(1).as_string::<i32>();
+    assert_eq!(names.value(0), "login");
+    assert!(names.is_null(1));
+
+    let scores = batch.column(2).as_primitive::<Float64Type>();
+    assert_eq!(scores.value(2), 3.0);
+    assert!(scores.is_null(1));
+
+    let active = batch.column(3).as_boolean();
+    assert!(active.value(0));
+    assert!(!active.value(1));
+    assert!(active.is_null(2));
+
+    let at = batch.column(4).as_primitive::<TimestampMicrosecondType>();
+    assert_eq!(at.value(0), 1_704_164_645_000_000);
+    assert!(at.is_null(1));
+
+    let payload = batch.column(5).as_string::<i32>();
+    let json: serde_json::Value = serde_json::from_str(payload.value(0)).unwrap();
+    assert_eq!(json, serde_json::json!({"ip": "10.0.0.1"}));
+    assert!(payload.is_null(1));
+
+    let wait = batch.column(6).as_primitive::<DurationMicrosecondType>();
+    assert_eq!(wait.value(0), 90_000_000);
+}
+
+#[test]
+fn test_arrow_batches_stream() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE nums (n INTEGER, x FLOAT)", ())
+        .unwrap();
+    for n in 0..25 {
+        db.execute("INSERT INTO nums VALUES ($1, $2)", (n, n * 2))
+            .unwrap();
+    }
+
+    let rows = db.query("SELECT n, x FROM nums ORDER BY n", ()).unwrap();
+    let reader = ArrowBatches::new(rows, 10).unwrap();
+    let sizes: Vec<usize> = reader.map(|b| b.unwrap().num_rows()).collect();
+    assert_eq!(sizes, vec![10, 10, 5]);
+
+    // Empty results produce one empty batch with the column names
+    let reader = db
+        .query_arrow("SELECT n FROM nums WHERE n < 0", ())
+        .unwrap();
+    assert_eq!(reader.schema().field(0).name(), "n");
+    let batches: Vec<_> = reader.map(|b| b.unwrap()).collect();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].num_rows(), 0);
+}
+
+#[test]
+fn test_arrow_mixed_numeric_column() {
+    let db = Database::open_in_memory().unwrap();
+
+    let reader = db
+        .query_arrow(
+            "SELECT 1 AS v UNION ALL SELECT 2.5 UNION ALL SELECT NULL",
+            (),
+        )
+        .unwrap();
+    assert_eq!(reader.schema().field(0).data_type(), &DataType::Float64);
+    let batch = reader.into_iter().next().unwrap().unwrap();
+    let values = batch.column(0).as_primitive::<Float64Type>();
+    assert_eq!(values.value(0), 1.0);
+    assert_eq!(values.value(1), 2.5);
+    assert!(values.is_null(2));
+}
@@ -691,3 +691,110 @@ fn test_as_of_select_star() {
     let result = db.query("SELECT * FROM star_test AS OF TRANSACTION 2", ());
     assert!(result.is_ok(), "AS OF with SELECT * should execute");
 }
+
+// ============================================================================
+// Retention
+// ============================================================================
+
+fn utc_now() -> String {
+    chrono::Utc::now()
+        .format("%Y-%m-%d %H:%M:%S%.f")
+        .to_string()
+}
+
+#[test]
+fn test_as_of_timestamp_reads_value_before_update() {
+    let db = Database::open("memory://as_of_ret1").expect("Failed to create database");
+
+    db.execute(
+        "CREATE TABLE prices (id INTEGER PRIMARY KEY, price INTEGER)",
+        (),
+    )
+    .expect("Create failed");
+    db.execute("INSERT INTO prices VALUES (1, 100)", ())
+        .expect("Insert failed");
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    let before_update = utc_now();
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    db.execute("UPDATE prices SET price = 150 WHERE id = 1", ())
+        .expect("Update failed");
+
+    let price: i64 = db
+        .query_one(
+            &format!(
+                "SELECT price FROM prices AS OF TIMESTAMP '{}' WHERE id = 1",
+                before_update
+            ),
+            (),
+        )
+        .expect("AS OF query failed");
+    assert_eq!(price, 100);
+
+    let price: i64 = db
+        .query_one(
+            &format!(
+                "SELECT price FROM prices AS OF TIMESTAMP '{}' WHERE id = 1",
+                utc_now()
+            ),
+            (),
+        )
+        .expect("AS OF query failed");
+    assert_eq!(price, 150);
+}
+
+#[test]
+fn test_as_of_before_vacuumed_history_errors() {
+    let db = Database::open("memory://as_of_ret2").expect("Failed to create database");
+
+    db.execute(
+        "CREATE TABLE prices (id INTEGER PRIMARY KEY, price INTEGER)",
+        (),
+    )
+    .expect("Create failed");
+    db.execute("INSERT INTO prices VALUES (1, 100), (2, 200)", ())
+        .expect("Insert failed");
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    let before_update = utc_now();
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    db.execute("UPDATE prices SET price = price + 1", ())
+        .expect("Update failed");
+    db.execute("DELETE FROM prices WHERE id = 2", ())
+        .expect("Delete failed");
+
+    // Reclaim every old version
+    db.execute("SET autovacuum_interval = '10ms'", ()).unwrap();
+    db.execute("SET autovacuum_min_dead_versions = 1", ())
+        .unwrap();
+    db.execute("SET autovacuum = 'on'", ()).unwrap();
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+    let sql = format!(
+        "SELECT price FROM prices AS OF TIMESTAMP '{}'",
+        before_update
+    );
+    while db.query(&sql, ()).is_ok() {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "old versions were never reclaimed"
+        );
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    db.execute("SET autovacuum = 'off'", ()).unwrap();
+
+    let err = db.query(&sql, ()).err().unwrap().to_string();
+    assert!(err.contains("older than the retained history"), "{}", err);
+    assert!(db
+        .query("SELECT * FROM prices AS OF TRANSACTION 1", ())
+        .is_err());
+
+    // Points after the reclaimed versions still read normally
+    let price: i64 = db
+        .query_one(
+            &format!(
+                "SELECT price FROM prices AS OF TIMESTAMP '{}' WHERE id = 1",
+                utc_now()
+            ),
+            (),
+        )
+        .expect("AS OF query failed");
+    assert_eq!(price, 101);
+}
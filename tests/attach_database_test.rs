@@ -0,0 +1,149 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! ATTACH DATABASE Tests
+//!
+//! Tests read-only attached databases and cross-database queries
+
+use oxibase::Database;
+use tempfile::tempdir;
+
+#[test]
+fn test_attach_and_join_across_databases() {
+    let other = Database::open("memory://attach_other").expect("Failed to create database");
+    other
+        .execute(
+            "CREATE TABLE orders (id INTEGER PRIMARY KEY, user_id INTEGER, amount FLOAT)",
+            (),
+        )
+        .expect("Failed to create table");
+    other
+        .execute(
+            "INSERT INTO orders VALUES (1, 1, 10.0), (2, 1, 5.5), (3, 2, 7.0)",
+            (),
+        )
+        .expect("Failed to insert");
+
+    let db = Database::open("memory://attach_main").expect("Failed to create database");
+    db.execute("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)", ())
+        .expect("Failed to create table");
+    db.execute("INSERT INTO users VALUES (1, 'alice'), (2, 'bob')", ())
+        .expect("Failed to insert");
+
+    db.execute("ATTACH DATABASE 'memory://attach_other' AS other", ())
+        .expect("Failed to attach");
+
+    let count: i64 = db
+        .query_one("SELECT COUNT(*) FROM other.orders", ())
+        .expect("Failed to query attached table");
+    assert_eq!(count, 3);
+
+    let rows = db
+        .query(
+            "SELECT u.name, SUM(o.amount) FROM users u
+             JOIN other.orders o ON o.user_id = u.id
+             GROUP BY u.name ORDER BY u.name",
+            (),
+        )
+        .expect("Failed to join across databases");
+    let mut totals = Vec::new();
+    for row in rows {
+        let row = row.expect("Failed to read row");
+        totals.push((row.get::<String>(0).unwrap(), row.get::<f64>(1).unwrap()));
+    }
+    assert_eq!(
+        totals,
+        vec![("alice".to_string(), 15.5), ("bob".to_string(), 7.0)]
+    );
+
+    // Reads see the attached database's latest committed data
+    other
+        .execute("INSERT INTO orders VALUES (4, 2, 1.0)", ())
+        .expect("Failed to insert");
+    let count: i64 = db
+        .query_one("SELECT COUNT(*) FROM other.orders WHERE user_id = 2", ())
+        .expect("Failed to query attached table");
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn test_attached_database_is_read_only() {
+    let other = Database::open("memory://attach_ro_other").expect("Failed to create database");
+    other
+        .execute("CREATE TABLE t (id INTEGER PRIMARY KEY)", ())
+        .expect("Failed to create table");
+
+    let db = Database::open("memory://attach_ro_main").expect("Failed to create database");
+    db.execute("ATTACH 'memory://attach_ro_other' AS other", ())
+        .expect("Failed to attach");
+
+    for sql in [
+        "INSERT INTO other.t VALUES (1)",
+        "UPDATE other.t SET id = 2",
+        "DELETE FROM other.t",
+    ] {
+        let err = db.execute(sql, ()).expect_err("Write should be rejected");
+        assert!(err.to_string().contains("read-only"), "{}: {}", sql, err);
+    }
+}
+
+#[test]
+fn test_attach_errors_and_detach() {
+    let other = Database::open("memory://attach_detach_other").expect("Failed to create database");
+    other
+        .execute("CREATE TABLE t (id INTEGER PRIMARY KEY)", ())
+        .expect("Failed to create table");
+
+    let db = Database::open("memory://attach_detach_main").expect("Failed to create database");
+    db.execute("ATTACH 'memory://attach_detach_other' AS other", ())
+        .expect("Failed to attach");
+    assert!(db
+        .execute("ATTACH 'memory://attach_detach_other' AS other", ())
+        .is_err());
+    assert!(db
+        .execute("ATTACH 'memory://attach_detach_main' AS me", ())
+        .is_err());
+
+    db.execute("DETACH DATABASE other", ())
+        .expect("Failed to detach");
+    assert!(db.query("SELECT * FROM other.t", ()).is_err());
+    assert!(db.execute("DETACH other", ()).is_err());
+}
+
+#[test]
+fn test_attach_file_database() {
+    let dir = tempdir().expect("Failed to create temp dir");
+    let dsn = format!("file://{}", dir.path().join("other").display());
+    {
+        let other = Database::open(&dsn).expect("Failed to open database");
+        other
+            .execute(
+                "CREATE TABLE items (id INTEGER PRIMARY KEY, label TEXT)",
+                (),
+            )
+            .expect("Failed to create table");
+        other
+            .execute("INSERT INTO items VALUES (1, 'one')", ())
+            .expect("Failed to insert");
+        other.close().expect("Failed to close database");
+    }
+
+    let db = Database::open_in_memory().expect("Failed to create database");
+    db.execute(&format!("ATTACH DATABASE '{}' AS other", dsn), ())
+        .expect("Failed to attach");
+    let label: String = db
+        .query_one("SELECT label FROM other.items WHERE id = 1", ())
+        .expect("Failed to query attached table");
+    assert_eq!(label, "one");
+}
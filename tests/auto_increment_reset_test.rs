@@ -0,0 +1,108 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Resetting AUTO_INCREMENT counters
+//!
+//! `ALTER TABLE t AUTO_INCREMENT = n` and
+//! `ALTER TABLE t ALTER COLUMN c RESTART WITH n` set the next generated id.
+
+use oxibase::Database;
+use tempfile::tempdir;
+
+fn setup(db: &Database) {
+    db.execute(
+        "CREATE TABLE events (id INTEGER PRIMARY KEY AUTO_INCREMENT, name TEXT)",
+        (),
+    )
+    .unwrap();
+}
+
+fn insert(db: &Database, name: &str) -> i64 {
+    db.execute(
+        &format!("INSERT INTO events (name) VALUES ('{}')", name),
+        (),
+    )
+    .unwrap();
+    db.query_one("SELECT MAX(id) FROM events", ()).unwrap()
+}
+
+#[test]
+fn test_set_next_id() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+    assert_eq!(insert(&db, "a"), 1);
+
+    db.execute("ALTER TABLE events AUTO_INCREMENT = 1000", ())
+        .unwrap();
+    assert_eq!(insert(&db, "b"), 1000);
+    assert_eq!(insert(&db, "c"), 1001);
+
+    db.execute("ALTER TABLE events ALTER COLUMN id RESTART WITH 5000", ())
+        .unwrap();
+    assert_eq!(insert(&db, "d"), 5000);
+
+    // Lowering the counter is fine once the rows above it are gone
+    db.execute("DELETE FROM events WHERE id > 1", ()).unwrap();
+    db.execute("ALTER TABLE events AUTO_INCREMENT 2", ())
+        .unwrap();
+    assert_eq!(insert(&db, "e"), 2);
+}
+
+#[test]
+fn test_rejects_values_at_or_below_existing_ids() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+    db.execute("INSERT INTO events VALUES (50, 'x')", ())
+        .unwrap();
+
+    let err = db
+        .execute("ALTER TABLE events AUTO_INCREMENT = 50", ())
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("largest existing id 50"),
+        "{}",
+        err
+    );
+    assert!(db
+        .execute("ALTER TABLE events AUTO_INCREMENT = 0", ())
+        .is_err());
+
+    // The counter is unchanged after a rejected reset
+    assert_eq!(insert(&db, "y"), 51);
+
+    db.execute("CREATE TABLE plain (id INTEGER PRIMARY KEY, name TEXT)", ())
+        .unwrap();
+    assert!(db
+        .execute("ALTER TABLE plain AUTO_INCREMENT = 10", ())
+        .is_err());
+    assert!(db
+        .execute("ALTER TABLE events ALTER COLUMN name RESTART WITH 10", ())
+        .is_err());
+}
+
+#[test]
+fn test_reset_survives_reopen() {
+    let dir = tempdir().unwrap();
+    let dsn = format!("file://{}", dir.path().join("test.db").display());
+    {
+        let db = Database::open(&dsn).unwrap();
+        setup(&db);
+        assert_eq!(insert(&db, "a"), 1);
+        db.execute("ALTER TABLE events AUTO_INCREMENT = 1000", ())
+            .unwrap();
+    }
+
+    let db = Database::open(&dsn).unwrap();
+    assert_eq!(insert(&db, "b"), 1000);
+}
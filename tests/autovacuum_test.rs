@@ -0,0 +1,227 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Auto-Vacuum Tests
+//!
+//! Tests the background task that reclaims dead row versions
+
+use std::time::{Duration, Instant};
+
+use oxibase::{ApiTransaction, Database, IsolationLevel, Value};
+
+/// (live_versions, dead_versions, autovacuum_count, last_autovacuum) for a table
+fn vacuum_stats(db: &Database, table: &str) -> (i64, i64, i64, Value) {
+    for row in db.query("PRAGMA autovacuum_stats", ()).unwrap() {
+        let row = row.unwrap();
+        let name: String = row.get(0).unwrap();
+        if name == table {
+            return (
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+                row.get(4).unwrap(),
+                row.get_value(5).unwrap().clone(),
+            );
+        }
+    }
+    panic!("no autovacuum stats for table {}", table);
+}
+
+/// (row count, sum of val) as seen by a transaction
+fn read_counters(tx: &mut ApiTransaction) -> (usize, i64) {
+    let vals: Vec<i64> = tx
+        .query("SELECT val FROM counters", ())
+        .unwrap()
+        .map(|row| row.unwrap().get(0).unwrap())
+        .collect();
+    (vals.len(), vals.iter().sum())
+}
+
+fn setup(db: &Database) {
+    db.execute(
+        "CREATE TABLE counters (id INTEGER PRIMARY KEY, val INTEGER)",
+        (),
+    )
+    .unwrap();
+    for id in 1..=50 {
+        db.execute("INSERT INTO counters VALUES ($1, 0)", (id,))
+            .unwrap();
+    }
+}
+
+fn update_all(db: &Database, rounds: usize) {
+    for _ in 0..rounds {
+        db.execute("UPDATE counters SET val = val + 1", ()).unwrap();
+    }
+}
+
+fn wait_until(mut condition: impl FnMut() -> bool) -> bool {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while Instant::now() < deadline {
+        if condition() {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    false
+}
+
+#[test]
+fn test_heavy_updates_trigger_autovacuum() {
+    let db = Database::open(
+        "memory://?autovacuum=on&autovacuum_interval_ms=20&autovacuum_threshold=0.5&autovacuum_min_dead_versions=10",
+    )
+    .unwrap();
+    setup(&db);
+    update_all(&db, 10);
+
+    // A pass that overlaps the last UPDATE's commit only reclaims the rows
+    // committed so far; the rest stay behind once the table drops below the
+    // 50% dead ratio, so wait for that rather than for zero dead versions
+    assert!(
+        wait_until(|| {
+            let (live, dead, runs, _) = vacuum_stats(&db, "counters");
+            runs > 0 && dead < live
+        }),
+        "dead versions were not reclaimed: {:?}",
+        vacuum_stats(&db, "counters")
+    );
+
+    let (live, _, _, last_autovacuum) = vacuum_stats(&db, "counters");
+    assert_eq!(live, 50);
+    assert!(matches!(last_autovacuum, Value::Timestamp(_)));
+
+    // Data is unaffected
+    let total: i64 = db.query_one("SELECT SUM(val) FROM counters", ()).unwrap();
+    assert_eq!(total, 500);
+}
+
+#[test]
+fn test_autovacuum_keeps_versions_for_open_snapshot() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+    db.execute("SET autovacuum_interval = '20ms'", ()).unwrap();
+    db.execute("SET autovacuum_min_dead_versions = 1", ())
+        .unwrap();
+    db.execute("SET autovacuum = 'on'", ()).unwrap();
+
+    let mut snapshot = db
+        .begin_with_isolation(IsolationLevel::SnapshotIsolation)
+        .unwrap();
+    assert_eq!(read_counters(&mut snapshot), (50, 0));
+
+    update_all(&db, 5);
+    db.execute("DELETE FROM counters WHERE id > 40", ())
+        .unwrap();
+
+    // Let several vacuum passes run while the snapshot is open
+    assert!(wait_until(|| vacuum_stats(&db, "counters").2 > 2));
+    assert_eq!(read_counters(&mut snapshot), (50, 0));
+    snapshot.commit().unwrap();
+
+    // Once the snapshot ends, its versions are reclaimed
+    assert!(
+        wait_until(|| vacuum_stats(&db, "counters").1 == 0),
+        "dead versions were not reclaimed: {:?}",
+        vacuum_stats(&db, "counters")
+    );
+    let total: i64 = db.query_one("SELECT SUM(val) FROM counters", ()).unwrap();
+    assert_eq!(total, 200);
+}
+
+#[test]
+fn test_autovacuum_disabled_by_default() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+    update_all(&db, 3);
+
+    std::thread::sleep(Duration::from_millis(100));
+    let (live, dead, runs, last_autovacuum) = vacuum_stats(&db, "counters");
+    assert_eq!(live, 50);
+    assert!(dead > 0);
+    assert_eq!(runs, 0);
+    assert!(last_autovacuum.is_null());
+}
+
+/// SUM(val) as of a UTC timestamp string
+fn sum_as_of(db: &Database, timestamp: &str) -> oxibase::Result<i64> {
+    db.query_one(
+        &format!(
+            "SELECT SUM(val) FROM counters AS OF TIMESTAMP '{}'",
+            timestamp
+        ),
+        (),
+    )
+}
+
+#[test]
+fn test_version_retention_window() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+    update_all(&db, 1);
+    std::thread::sleep(Duration::from_millis(5));
+    let before_update = chrono::Utc::now()
+        .format("%Y-%m-%d %H:%M:%S%.f")
+        .to_string();
+    std::thread::sleep(Duration::from_millis(5));
+    update_all(&db, 1);
+
+    db.execute("SET version_retention = '1h'", ()).unwrap();
+    db.execute("SET autovacuum_interval = '20ms'", ()).unwrap();
+    db.execute("SET autovacuum_min_dead_versions = 1", ())
+        .unwrap();
+    db.execute("SET autovacuum = 'on'", ()).unwrap();
+
+    // Versions inside the window survive several vacuum passes
+    assert!(wait_until(|| vacuum_stats(&db, "counters").2 > 2));
+    assert_eq!(vacuum_stats(&db, "counters").1, 100);
+    assert_eq!(sum_as_of(&db, &before_update).unwrap(), 50);
+
+    // Once the window is shorter than their age they are reclaimed
+    db.execute("SET version_retention = '5ms'", ()).unwrap();
+    assert!(
+        wait_until(|| vacuum_stats(&db, "counters").1 == 0),
+        "dead versions were not reclaimed: {:?}",
+        vacuum_stats(&db, "counters")
+    );
+    let err = sum_as_of(&db, &before_update).unwrap_err().to_string();
+    assert!(err.contains("older than the retained history"), "{}", err);
+
+    let total: i64 = db.query_one("SELECT SUM(val) FROM counters", ()).unwrap();
+    assert_eq!(total, 100);
+}
+
+#[test]
+fn test_version_retention_from_dsn() {
+    let db = Database::open(
+        "memory://?version_retention=1h&autovacuum=on&autovacuum_interval=20ms&autovacuum_min_dead_versions=1",
+    )
+    .unwrap();
+    setup(&db);
+    update_all(&db, 1);
+
+    // Durations with units are accepted like in SET: the passes run every
+    // 20ms and the one-hour window keeps the old versions
+    assert!(wait_until(|| vacuum_stats(&db, "counters").2 > 2));
+    assert_eq!(vacuum_stats(&db, "counters").1, 50);
+}
+
+#[test]
+fn test_autovacuum_invalid_settings() {
+    let db = Database::open_in_memory().unwrap();
+
+    assert!(db.execute("SET autovacuum = 'maybe'", ()).is_err());
+    assert!(db.execute("SET autovacuum_threshold = 1.5", ()).is_err());
+    assert!(db.execute("SET autovacuum_interval = 'soon'", ()).is_err());
+    assert!(db.execute("SET version_retention = 'forever'", ()).is_err());
+}
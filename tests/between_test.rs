@@ -0,0 +1,142 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! BETWEEN / NOT BETWEEN / BETWEEN SYMMETRIC Tests
+//!
+//! Covers inclusive bounds, reversed bounds, fractional bounds on integer
+//! columns and three-valued logic with NULL operands, both for predicates
+//! pushed down to storage and for ones evaluated by the expression VM.
+
+use oxibase::Database;
+
+fn setup(db: &Database) {
+    db.execute(
+        "CREATE TABLE t (id INTEGER PRIMARY KEY, v INTEGER, f FLOAT)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO t VALUES (1, 1, 1.0), (2, 2, 2.0), (3, 3, 3.0), (4, NULL, NULL)",
+        (),
+    )
+    .unwrap();
+}
+
+fn ids(db: &Database, where_clause: &str) -> Vec<i64> {
+    let sql = format!("SELECT id FROM t WHERE {} ORDER BY id", where_clause);
+    db.query(&sql, ())
+        .unwrap()
+        .map(|row| row.unwrap().get::<i64>(0).unwrap())
+        .collect()
+}
+
+fn row_strings(db: &Database, sql: &str) -> Vec<String> {
+    let row = db.query(sql, ()).unwrap().next().unwrap().unwrap();
+    (0..row.len())
+        .map(|i| row.get_value(i).unwrap().to_string())
+        .collect()
+}
+
+/// Each predicate is checked on the bare column (pushed down to storage) and
+/// on `v + 0` (evaluated in memory); both paths must agree.
+fn assert_both_paths(db: &Database, predicate: &str, expected: &[i64]) {
+    assert_eq!(
+        ids(db, &predicate.replace("{v}", "v")),
+        expected,
+        "{}",
+        predicate
+    );
+    assert_eq!(
+        ids(db, &predicate.replace("{v}", "(v + 0)")),
+        expected,
+        "{} (in memory)",
+        predicate
+    );
+}
+
+#[test]
+fn test_between_bounds_are_inclusive() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    assert_both_paths(&db, "{v} BETWEEN 1 AND 3", &[1, 2, 3]);
+    assert_both_paths(&db, "{v} BETWEEN 2 AND 2", &[2]);
+    assert_both_paths(&db, "{v} NOT BETWEEN 2 AND 3", &[1]);
+    assert_eq!(ids(&db, "f BETWEEN 1 AND 2"), vec![1, 2]);
+
+    // A reversed range is empty, so NOT BETWEEN matches every non-NULL row
+    assert_both_paths(&db, "{v} BETWEEN 3 AND 1", &[]);
+    assert_both_paths(&db, "{v} NOT BETWEEN 3 AND 1", &[1, 2, 3]);
+
+    // Fractional bounds must not be truncated to the integer column type
+    assert_both_paths(&db, "{v} BETWEEN 1.5 AND 3", &[2, 3]);
+    assert_both_paths(&db, "{v} BETWEEN 1 AND 2.5", &[1, 2]);
+    assert_both_paths(&db, "{v} NOT BETWEEN 1.5 AND 2.5", &[1, 3]);
+    assert_eq!(ids(&db, "id BETWEEN 1.5 AND 3"), vec![2, 3]);
+}
+
+#[test]
+fn test_between_symmetric() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+    db.execute("CREATE INDEX idx_t_v ON t (v)", ()).unwrap();
+
+    assert_both_paths(&db, "{v} BETWEEN SYMMETRIC 3 AND 2", &[2, 3]);
+    assert_both_paths(&db, "{v} BETWEEN SYMMETRIC 2 AND 3", &[2, 3]);
+    assert_both_paths(&db, "{v} NOT BETWEEN SYMMETRIC 3 AND 2", &[1]);
+    assert_both_paths(&db, "{v} BETWEEN ASYMMETRIC 3 AND 2", &[]);
+    assert_eq!(ids(&db, "id BETWEEN SYMMETRIC 2.5 AND 1"), vec![1, 2]);
+
+    assert_eq!(
+        row_strings(
+            &db,
+            "SELECT 2 BETWEEN SYMMETRIC 3 AND 1, 2 NOT BETWEEN SYMMETRIC 3 AND 1, \
+             4 BETWEEN SYMMETRIC 3 AND 1"
+        ),
+        vec!["true", "false", "false"]
+    );
+}
+
+#[test]
+fn test_between_null_three_valued_logic() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    // A NULL operand yields NULL, so neither BETWEEN nor NOT BETWEEN matches
+    assert_both_paths(&db, "{v} BETWEEN 0 AND 10", &[1, 2, 3]);
+    assert_both_paths(&db, "{v} NOT BETWEEN 0 AND 10", &[]);
+
+    // A NULL bound is NULL unless the other bound already decides the result
+    assert_eq!(
+        row_strings(
+            &db,
+            "SELECT 5 BETWEEN NULL AND 3, 5 NOT BETWEEN NULL AND 3, \
+             2 BETWEEN NULL AND 3, 2 BETWEEN 3 AND NULL, NULL BETWEEN 1 AND 2"
+        ),
+        vec!["false", "true", "NULL", "false", "NULL"]
+    );
+    assert_both_paths(&db, "{v} BETWEEN 1 AND NULL", &[]);
+    assert_both_paths(&db, "{v} NOT BETWEEN 2 AND NULL", &[1]);
+    assert_both_paths(&db, "{v} NOT BETWEEN NULL AND 1", &[2, 3]);
+
+    // With SYMMETRIC either ordering may decide the result
+    assert_eq!(
+        row_strings(
+            &db,
+            "SELECT 2 BETWEEN SYMMETRIC NULL AND 1, 5 BETWEEN SYMMETRIC NULL AND 3"
+        ),
+        vec!["NULL", "NULL"]
+    );
+    assert_both_paths(&db, "{v} NOT BETWEEN SYMMETRIC NULL AND 2", &[]);
+}
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -378,3 +379,89 @@ fn test_bitmap_index_or_conditions() {
         .expect("Failed to count");
     assert_eq!(count, 1, "Expected 1 row with both false");
 }
+
+/// Test querying true, false and NULL through the bitmap index
+#[test]
+fn test_bitmap_index_three_states() {
+    let db = Database::open("memory://bitmap_idx_three_states").expect("Failed to create database");
+
+    db.execute(
+        "CREATE TABLE bitmap_state_test (
+            id INTEGER PRIMARY KEY,
+            active BOOLEAN
+        )",
+        (),
+    )
+    .expect("Failed to create table");
+    db.execute("CREATE INDEX idx_active ON bitmap_state_test (active)", ())
+        .expect("Failed to create index");
+
+    db.execute(
+        "INSERT INTO bitmap_state_test VALUES (1, true), (2, false), (3, NULL), (4, true), (5, NULL), (6, false)",
+        (),
+    )
+    .unwrap();
+    // Move rows between states so the index has to track them
+    db.execute(
+        "UPDATE bitmap_state_test SET active = NULL WHERE id = 1",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "UPDATE bitmap_state_test SET active = false WHERE id = 5",
+        (),
+    )
+    .unwrap();
+    db.execute("DELETE FROM bitmap_state_test WHERE id = 6", ())
+        .unwrap();
+
+    let ids = |sql: &str| -> Vec<i64> {
+        db.query(sql, ())
+            .unwrap()
+            .map(|row| row.unwrap().get(0).unwrap())
+            .collect()
+    };
+
+    assert_eq!(
+        ids("SELECT id FROM bitmap_state_test WHERE active = true ORDER BY id"),
+        vec![4]
+    );
+    assert_eq!(
+        ids("SELECT id FROM bitmap_state_test WHERE active = false ORDER BY id"),
+        vec![2, 5]
+    );
+    assert_eq!(
+        ids("SELECT id FROM bitmap_state_test WHERE active IS NULL ORDER BY id"),
+        vec![1, 3]
+    );
+    assert_eq!(
+        ids("SELECT id FROM bitmap_state_test WHERE active IS NOT NULL ORDER BY id"),
+        vec![2, 4, 5]
+    );
+    // NULL rows don't match inequality
+    assert_eq!(
+        ids("SELECT id FROM bitmap_state_test WHERE active <> true ORDER BY id"),
+        vec![2, 5]
+    );
+
+    // NULL checks are answered by the index
+    for predicate in ["IS NULL", "IS NOT NULL"] {
+        let plan: Vec<String> = db
+            .query(
+                &format!(
+                    "EXPLAIN SELECT * FROM bitmap_state_test WHERE active {}",
+                    predicate
+                ),
+                (),
+            )
+            .unwrap()
+            .map(|row| row.unwrap().get(0).unwrap())
+            .collect();
+        let plan = plan.join("\n");
+        assert!(
+            plan.contains("Index Scan") && plan.contains("idx_active"),
+            "Expected index scan, got:\n{}",
+            plan
+        );
+    }
+}
@@ -0,0 +1,160 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests for BLOB columns and UTF-8 validation of TEXT columns
+
+use oxibase::{Database, Error};
+
+const INVALID: &[u8] = &[0x66, 0x6f, 0xff, 0x6f];
+
+fn setup(db: &Database) {
+    db.execute(
+        "CREATE TABLE files (id INTEGER PRIMARY KEY, name TEXT, data BLOB)",
+        (),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_invalid_utf8_rejected_for_text() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let err = db
+        .execute("INSERT INTO files VALUES (1, $1, NULL)", (INVALID,))
+        .unwrap_err();
+    assert!(
+        matches!(&err, Error::InvalidUtf8 { column } if column == "name"),
+        "unexpected error: {err:?}"
+    );
+
+    db.execute("INSERT INTO files VALUES (1, 'ok', NULL)", ())
+        .unwrap();
+    let err = db
+        .execute("UPDATE files SET name = $1 WHERE id = 1", (INVALID,))
+        .unwrap_err();
+    assert!(matches!(err, Error::InvalidUtf8 { .. }));
+
+    let name: String = db
+        .query_one("SELECT name FROM files WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(name, "ok");
+}
+
+#[test]
+fn test_valid_utf8_bytes_stored_as_text() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    db.execute(
+        "INSERT INTO files VALUES (1, $1, NULL)",
+        ("héllo".as_bytes(),),
+    )
+    .unwrap();
+    let name: String = db
+        .query_one("SELECT name FROM files WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(name, "héllo");
+    let ty: String = db
+        .query_one("SELECT TYPEOF(name) FROM files WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(ty, "TEXT");
+}
+
+#[test]
+fn test_binary_data_round_trips_through_blob() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    db.execute("INSERT INTO files VALUES (1, 'a', $1)", (INVALID.to_vec(),))
+        .unwrap();
+    db.execute("INSERT INTO files VALUES (2, 'b', '\\x00ff10')", ())
+        .unwrap();
+
+    let data: Vec<u8> = db
+        .query_one("SELECT data FROM files WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(data, INVALID);
+
+    let data: Vec<u8> = db
+        .query_one("SELECT data FROM files WHERE id = 2", ())
+        .unwrap();
+    assert_eq!(data, vec![0x00, 0xff, 0x10]);
+
+    let id: i64 = db
+        .query_one("SELECT id FROM files WHERE data = $1", (INVALID,))
+        .unwrap();
+    assert_eq!(id, 1);
+
+    let ty: String = db
+        .query_one("SELECT TYPEOF(data) FROM files WHERE id = 2", ())
+        .unwrap();
+    assert_eq!(ty, "BLOB");
+    let text: Option<String> = db
+        .query_one("SELECT CAST(data AS TEXT) FROM files WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(text, None);
+}
+
+#[test]
+fn test_bytea_column_type() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, b BYTEA)", ())
+        .unwrap();
+    db.execute("INSERT INTO t VALUES (1, $1)", (INVALID,))
+        .unwrap();
+    let data: Vec<u8> = db.query_one("SELECT b FROM t", ()).unwrap();
+    assert_eq!(data, INVALID);
+}
+
+#[test]
+fn test_invalid_utf8_replace_mode() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    db.execute("SET invalid_utf8 = 'replace'", ()).unwrap();
+    db.execute("INSERT INTO files VALUES (1, $1, NULL)", (INVALID,))
+        .unwrap();
+    let name: String = db
+        .query_one("SELECT name FROM files WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(name, "fo\u{fffd}o");
+
+    db.execute("SET invalid_utf8 = 'error'", ()).unwrap();
+    assert!(db
+        .execute("INSERT INTO files VALUES (2, $1, NULL)", (INVALID,))
+        .is_err());
+    assert!(db.execute("SET invalid_utf8 = 'ignore'", ()).is_err());
+}
+
+#[test]
+fn test_blob_persistence_recovery() {
+    let dir = tempfile::tempdir().unwrap();
+    let dsn = format!("file://{}", dir.path().join("blob.db").display());
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        setup(&db);
+        db.execute("INSERT INTO files VALUES (1, 'a', $1)", (INVALID,))
+            .unwrap();
+    }
+
+    let db = Database::open(&dsn).unwrap();
+    let data: Vec<u8> = db
+        .query_one("SELECT data FROM files WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(data, INVALID);
+    let ty: String = db.query_one("SELECT TYPEOF(data) FROM files", ()).unwrap();
+    assert_eq!(ty, "BLOB");
+}
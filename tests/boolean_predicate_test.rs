@@ -0,0 +1,159 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bare BOOLEAN predicates
+//!
+//! `WHERE flag` and `WHERE NOT flag` work without `= true`. A NULL flag is
+//! unknown, so the row is dropped by both forms.
+
+use oxibase::Database;
+
+fn setup() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, is_active BOOLEAN, is_admin BOOLEAN)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO users VALUES (1, true, true), (2, true, false), (3, false, NULL), (4, NULL, true)",
+        (),
+    )
+    .unwrap();
+    db
+}
+
+fn ids(db: &Database, sql: &str) -> Vec<i64> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| row.unwrap().get::<i64>(0).unwrap())
+        .collect()
+}
+
+fn where_ids(db: &Database, predicate: &str) -> Vec<i64> {
+    ids(
+        db,
+        &format!("SELECT id FROM users WHERE {} ORDER BY id", predicate),
+    )
+}
+
+#[test]
+fn test_bare_column() {
+    let db = setup();
+    assert_eq!(where_ids(&db, "is_active"), vec![1, 2]);
+    assert_eq!(where_ids(&db, "NOT is_active"), vec![3]);
+    assert_eq!(where_ids(&db, "users.is_active"), vec![1, 2]);
+
+    // Same results once an index can serve the predicate
+    db.execute("CREATE INDEX idx_active ON users (is_active)", ())
+        .unwrap();
+    assert_eq!(where_ids(&db, "is_active"), vec![1, 2]);
+    assert_eq!(where_ids(&db, "NOT is_active"), vec![3]);
+}
+
+#[test]
+fn test_null_is_unknown() {
+    let db = setup();
+    assert_eq!(where_ids(&db, "is_admin"), vec![1, 4]);
+    assert_eq!(where_ids(&db, "NOT is_admin"), vec![2]);
+    assert_eq!(where_ids(&db, "NOT NOT is_admin"), vec![1, 4]);
+
+    // false AND NULL is false, true OR NULL is true
+    assert_eq!(where_ids(&db, "NOT (is_active AND is_admin)"), vec![2, 3]);
+    assert_eq!(where_ids(&db, "is_active OR is_admin"), vec![1, 2, 4]);
+    assert_eq!(where_ids(&db, "is_active AND NOT is_admin"), vec![2]);
+    assert_eq!(
+        where_ids(&db, "is_admin IS NULL OR is_active"),
+        vec![1, 2, 3]
+    );
+}
+
+#[test]
+fn test_boolean_expressions() {
+    let db = setup();
+    assert_eq!(where_ids(&db, "(id > 2)"), vec![3, 4]);
+    assert_eq!(where_ids(&db, "COALESCE(is_active, true)"), vec![1, 2, 4]);
+    assert_eq!(
+        where_ids(&db, "CASE WHEN id = 1 THEN is_admin ELSE NOT is_admin END"),
+        vec![1, 2]
+    );
+    assert_eq!(
+        where_ids(
+            &db,
+            "EXISTS (SELECT 1 FROM users u WHERE u.id = users.id AND u.is_admin)"
+        ),
+        vec![1, 4]
+    );
+}
+
+#[test]
+fn test_update_delete_and_having() {
+    let db = setup();
+    let count: i64 = db
+        .query_one(
+            "SELECT COUNT(*) FROM users GROUP BY is_active HAVING is_active",
+            (),
+        )
+        .unwrap();
+    assert_eq!(count, 2);
+
+    db.execute("UPDATE users SET is_admin = false WHERE NOT is_active", ())
+        .unwrap();
+    assert_eq!(where_ids(&db, "NOT is_admin"), vec![2, 3]);
+
+    db.execute("DELETE FROM users WHERE is_active", ()).unwrap();
+    assert_eq!(ids(&db, "SELECT id FROM users ORDER BY id"), vec![3, 4]);
+}
+
+#[test]
+fn test_join_on_bare_boolean() {
+    let db = setup();
+    db.execute(
+        "CREATE TABLE sessions (id INTEGER PRIMARY KEY, user_id INTEGER, open BOOLEAN)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO sessions VALUES (1, 1, true), (2, 2, false), (3, 3, NULL), (4, 4, true)",
+        (),
+    )
+    .unwrap();
+
+    // Extra ON terms next to an indexed key must still filter
+    assert_eq!(
+        ids(
+            &db,
+            "SELECT s.id FROM sessions s JOIN users u ON s.user_id = u.id AND u.is_active ORDER BY s.id"
+        ),
+        vec![1, 2]
+    );
+    assert_eq!(
+        ids(
+            &db,
+            "SELECT s.id FROM sessions s JOIN users u ON s.user_id = u.id AND NOT s.open ORDER BY s.id"
+        ),
+        vec![2]
+    );
+
+    // LEFT JOIN keeps every outer row, with NULLs where the ON clause fails
+    let matched: Vec<Option<i64>> = db
+        .query(
+            "SELECT s.id, u.id FROM sessions s LEFT JOIN users u ON s.user_id = u.id AND u.is_admin ORDER BY s.id",
+            (),
+        )
+        .unwrap()
+        .map(|row| row.unwrap().get::<Option<i64>>(1).unwrap())
+        .collect();
+    assert_eq!(matched, vec![Some(1), None, None, Some(4)]);
+}
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -4066,25 +4067,34 @@ fn test_bug_111_negative_offset() {
 
 // =============================================================================
 // Bug #112: TEXT PRIMARY KEY silently fails at CREATE, errors at INSERT
-// Description: CREATE TABLE should error for non-INTEGER PRIMARY KEY
+// Description: CREATE TABLE should error for AUTO_INCREMENT on a non-INTEGER
+// key; a plain TEXT PRIMARY KEY is supported
 // =============================================================================
 #[test]
 fn test_bug_112_text_primary_key() {
     let db = setup_db("bug112");
 
-    // TEXT PRIMARY KEY should fail at CREATE TABLE time
-    let result = db.execute("CREATE TABLE t112 (id TEXT PRIMARY KEY, val INTEGER)", ());
+    // TEXT PRIMARY KEY AUTO_INCREMENT should fail at CREATE TABLE time
+    let result = db.execute(
+        "CREATE TABLE t112 (id TEXT PRIMARY KEY AUTO_INCREMENT, val INTEGER)",
+        (),
+    );
     assert!(
         result.is_err(),
-        "TEXT PRIMARY KEY should fail at CREATE TABLE time"
+        "TEXT AUTO_INCREMENT should fail at CREATE TABLE time"
     );
 
     let err_msg = result.unwrap_err().to_string();
     assert!(
-        err_msg.contains("INTEGER") || err_msg.contains("PRIMARY KEY"),
-        "Error should mention PRIMARY KEY must be INTEGER: {}",
+        err_msg.contains("INTEGER") && err_msg.contains("AUTO_INCREMENT"),
+        "Error should mention AUTO_INCREMENT must be INTEGER: {}",
         err_msg
     );
+
+    db.execute("CREATE TABLE t112 (id TEXT PRIMARY KEY, val INTEGER)", ())
+        .expect("TEXT PRIMARY KEY should be supported");
+    db.execute("INSERT INTO t112 VALUES ('a', 1)", ())
+        .expect("Insert should work");
 }
 
 // =============================================================================
@@ -4787,3 +4797,42 @@ fn test_reserved_keyword_error_message() {
     let key_val: String = rows[0].as_ref().unwrap().get(0).unwrap();
     assert_eq!(key_val, "test");
 }
+
+// =============================================================================
+// Test for partially pushed predicates under OR
+// Description: `a GLOB 'x*' AND b > 1 OR b = 3` pushed only `b > 1` of the AND
+// branch into storage and dropped the GLOB, returning extra rows
+// =============================================================================
+#[test]
+fn test_or_with_partially_pushable_branch() {
+    let db = setup_db("or_partial_pushdown");
+
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, name TEXT)", ())
+        .expect("Failed to create table");
+    db.execute(
+        "INSERT INTO t VALUES (1, 'apple'), (2, 'banana'), (3, 'cherry'), (4, 'apricot')",
+        (),
+    )
+    .expect("Failed to insert");
+
+    for predicate in [
+        "name GLOB 'a*' AND id > 1 OR id = 3",
+        "name REGEXP '^a' AND id > 1 OR id = 3",
+        "NOT (name GLOB 'a*' AND id > 1)",
+    ] {
+        let ids: Vec<i64> = db
+            .query(
+                &format!("SELECT id FROM t WHERE {} ORDER BY id", predicate),
+                (),
+            )
+            .expect("Query should work")
+            .map(|row| row.unwrap().get(0).unwrap())
+            .collect();
+        let expected = if predicate.starts_with("NOT") {
+            vec![1, 2, 3]
+        } else {
+            vec![3, 4]
+        };
+        assert_eq!(ids, expected, "{}", predicate);
+    }
+}
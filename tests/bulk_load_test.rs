@@ -0,0 +1,188 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bulk load transactions
+//!
+//! `begin_bulk` defers unique checks and index maintenance to commit.
+
+use oxibase::{Database, Error};
+
+const SCHEMA: [&str; 4] = [
+    "CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT, age INTEGER, city TEXT)",
+    "CREATE UNIQUE INDEX idx_users_email ON users(email)",
+    "CREATE INDEX idx_users_age ON users(age)",
+    "CREATE UNIQUE INDEX idx_users_city_age ON users(city, age)",
+];
+
+fn setup(dsn: &str) -> Database {
+    let db = Database::open(dsn).unwrap();
+    for sql in SCHEMA {
+        db.execute(sql, ()).unwrap();
+    }
+    db
+}
+
+fn load(db: &Database, bulk: bool, ids: std::ops::Range<i64>) {
+    let mut tx = if bulk {
+        db.begin_bulk().unwrap()
+    } else {
+        db.begin().unwrap()
+    };
+    for id in ids {
+        tx.execute(
+            "INSERT INTO users VALUES ($1, $2, $3, $4)",
+            (
+                id,
+                format!("user{}@example.com", id),
+                id % 50,
+                format!("city{}", id / 50),
+            ),
+        )
+        .unwrap();
+    }
+    tx.commit().unwrap();
+}
+
+fn ids(db: &Database, sql: &str) -> Vec<i64> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| row.unwrap().get::<i64>(0).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_bulk_load_matches_per_row_maintenance() {
+    let per_row = setup("memory://bulk_load_per_row");
+    let bulk = setup("memory://bulk_load_bulk");
+    load(&per_row, false, 0..1000);
+    load(&bulk, true, 0..1000);
+    // A second load on top of committed rows
+    load(&per_row, false, 1000..1500);
+    load(&bulk, true, 1000..1500);
+
+    for sql in [
+        "SELECT id FROM users WHERE email = 'user1234@example.com'",
+        "SELECT id FROM users WHERE age = 7 ORDER BY id",
+        "SELECT id FROM users WHERE age BETWEEN 10 AND 12 ORDER BY id",
+        "SELECT id FROM users WHERE city = 'city3' AND age = 20",
+        "SELECT COUNT(*) FROM users",
+    ] {
+        assert_eq!(ids(&per_row, sql), ids(&bulk, sql), "{}", sql);
+    }
+    assert_eq!(
+        ids(
+            &bulk,
+            "SELECT id FROM users WHERE email = 'user1234@example.com'"
+        ),
+        vec![1234]
+    );
+
+    // The indexes built at commit still enforce uniqueness afterwards
+    assert!(bulk
+        .execute(
+            "INSERT INTO users VALUES (9999, 'user5@example.com', 1, 'x')",
+            ()
+        )
+        .is_err());
+}
+
+#[test]
+fn test_bulk_load_reports_duplicate_at_commit() {
+    let db = setup("memory://bulk_load_duplicate");
+    load(&db, true, 0..10);
+
+    // Duplicate within the load: accepted by INSERT, rejected by COMMIT
+    let mut tx = db.begin_bulk().unwrap();
+    tx.execute(
+        "INSERT INTO users VALUES (100, 'dup@example.com', 1, 'a')",
+        (),
+    )
+    .unwrap();
+    tx.execute(
+        "INSERT INTO users VALUES (101, 'dup@example.com', 2, 'a')",
+        (),
+    )
+    .unwrap();
+    let err = tx.commit().unwrap_err();
+    assert!(matches!(err, Error::UniqueConstraint { .. }), "{:?}", err);
+    assert!(err.to_string().contains("dup@example.com"), "{}", err);
+    assert!(err.to_string().contains("in row"), "{}", err);
+
+    // Duplicate of a committed row
+    let mut tx = db.begin_bulk().unwrap();
+    tx.execute(
+        "INSERT INTO users VALUES (102, 'user3@example.com', 3, 'a')",
+        (),
+    )
+    .unwrap();
+    assert!(tx.commit().is_err());
+
+    // Nothing of the failed loads was kept, and the indexes are intact
+    assert_eq!(ids(&db, "SELECT COUNT(*) FROM users"), vec![10]);
+    assert!(ids(&db, "SELECT id FROM users WHERE email = 'dup@example.com'").is_empty());
+    db.execute(
+        "INSERT INTO users VALUES (100, 'dup@example.com', 1, 'a')",
+        (),
+    )
+    .unwrap();
+    assert_eq!(
+        ids(&db, "SELECT id FROM users WHERE email = 'dup@example.com'"),
+        vec![100]
+    );
+}
+
+#[test]
+fn test_bulk_load_reuses_key_freed_in_same_transaction() {
+    let db = setup("memory://bulk_load_rekey");
+    load(&db, true, 0..5);
+
+    let mut tx = db.begin_bulk().unwrap();
+    tx.execute(
+        "UPDATE users SET email = 'moved@example.com' WHERE id = 1",
+        (),
+    )
+    .unwrap();
+    tx.execute(
+        "INSERT INTO users VALUES (50, 'user1@example.com', 40, 'b')",
+        (),
+    )
+    .unwrap();
+    tx.commit().unwrap();
+
+    assert_eq!(
+        ids(
+            &db,
+            "SELECT id FROM users WHERE email = 'user1@example.com'"
+        ),
+        vec![50]
+    );
+    assert_eq!(
+        ids(
+            &db,
+            "SELECT id FROM users WHERE email = 'moved@example.com'"
+        ),
+        vec![1]
+    );
+
+    // A key still held by an updated row is not free
+    let mut tx = db.begin_bulk().unwrap();
+    tx.execute("UPDATE users SET age = 41 WHERE id = 2", ())
+        .unwrap();
+    tx.execute(
+        "INSERT INTO users VALUES (51, 'user2@example.com', 42, 'b')",
+        (),
+    )
+    .unwrap();
+    assert!(tx.commit().is_err());
+}
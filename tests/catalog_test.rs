@@ -0,0 +1,134 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Catalog Tests
+//!
+//! Tests Database::catalog, which describes the whole schema in one call
+
+use oxibase::{CatalogConstraint, DataType, Database, IndexType, RoutineKind};
+
+#[test]
+fn test_catalog_reflects_schema() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE customers (id INTEGER PRIMARY KEY AUTO_INCREMENT, name TEXT NOT NULL, email TEXT UNIQUE)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE TABLE orders (id INTEGER PRIMARY KEY, customer_id INTEGER NOT NULL, amount FLOAT DEFAULT 0 CHECK (amount >= 0), FOREIGN KEY (customer_id) REFERENCES customers(id) ON DELETE CASCADE)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE INDEX idx_orders_customer ON orders (customer_id)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE VIEW big_orders AS SELECT * FROM orders WHERE amount > 100",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE OR REPLACE FUNCTION catalog_add_one(x INTEGER) RETURNS INTEGER LANGUAGE RHAI AS 'x + 1'",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE PROCEDURE catalog_touch(IN n INTEGER) LANGUAGE rhai AS $$ let y = n; $$",
+        (),
+    )
+    .unwrap();
+
+    let catalog = db.catalog().unwrap();
+    let names: Vec<&str> = catalog.tables.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, ["customers", "orders"]);
+
+    let orders = catalog.table("orders").unwrap();
+    assert_eq!(orders.schema, "public");
+    let columns: Vec<&str> = orders.columns.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(columns, ["id", "customer_id", "amount"]);
+    let amount = orders.column("amount").unwrap();
+    assert_eq!(amount.data_type, DataType::Float);
+    assert!(amount.nullable);
+    assert_eq!(amount.default.as_deref(), Some("0"));
+    assert!(orders.column("id").unwrap().primary_key);
+
+    let index = orders.index("idx_orders_customer").unwrap();
+    assert_eq!(index.columns, ["customer_id"]);
+    assert!(!index.unique);
+    assert_ne!(index.index_type, IndexType::MultiColumn);
+
+    assert!(orders.constraints.contains(&CatalogConstraint::PrimaryKey {
+        columns: vec!["id".to_string()]
+    }));
+    assert!(orders.constraints.iter().any(|c| matches!(
+        c,
+        CatalogConstraint::ForeignKey { column, referenced_column, .. }
+            if column == "customer_id" && referenced_column == "id"
+    )));
+    assert!(orders.constraints.iter().any(|c| matches!(
+        c,
+        CatalogConstraint::Check { column, .. } if column == "amount"
+    )));
+
+    // UNIQUE columns show up as an index and a constraint
+    let customers = catalog.table("public.customers").unwrap();
+    assert!(customers.column("id").unwrap().auto_increment);
+    assert!(customers.constraints.iter().any(|c| matches!(
+        c,
+        CatalogConstraint::Unique { columns, .. } if columns == &["email".to_string()]
+    )));
+
+    let view = catalog.view("big_orders").unwrap();
+    assert!(view.definition.to_uppercase().contains("AMOUNT > 100"));
+
+    let function = catalog
+        .routines
+        .iter()
+        .find(|r| r.name.eq_ignore_ascii_case("catalog_add_one"))
+        .unwrap();
+    assert_eq!(function.kind, RoutineKind::Function);
+    assert_eq!(function.parameters.len(), 1);
+    assert_eq!(function.parameters[0].name, "x");
+    assert!(function.return_type.is_some());
+    let procedure = catalog
+        .routines
+        .iter()
+        .find(|r| r.name.eq_ignore_ascii_case("catalog_touch"))
+        .unwrap();
+    assert_eq!(procedure.kind, RoutineKind::Procedure);
+    assert_eq!(procedure.return_type, None);
+}
+
+#[test]
+fn test_catalog_tracks_changes() {
+    let db = Database::open_in_memory().unwrap();
+    assert!(db.catalog().unwrap().tables.is_empty());
+
+    db.execute("CREATE SCHEMA sales", ()).unwrap();
+    db.execute("CREATE TABLE sales.leads (id INTEGER PRIMARY KEY)", ())
+        .unwrap();
+    db.execute("CREATE TABLE notes (body TEXT)", ()).unwrap();
+
+    let catalog = db.catalog().unwrap();
+    assert!(catalog.table("sales.leads").is_some());
+    assert!(catalog.table("leads").is_none());
+    // A table without a primary key has no PrimaryKey constraint
+    assert!(catalog.table("notes").unwrap().constraints.is_empty());
+
+    db.execute("DROP TABLE notes", ()).unwrap();
+    assert!(db.catalog().unwrap().table("notes").is_none());
+}
@@ -0,0 +1,145 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Multiple and named column CHECK constraints
+//!
+//! Every CHECK declared on a column is kept and evaluated on write.
+
+use oxibase::core::Error;
+use oxibase::Database;
+use tempfile::tempdir;
+
+const CREATE: &str = "CREATE TABLE items (
+    id INTEGER PRIMARY KEY,
+    qty INTEGER CHECK (qty >= 0) CONSTRAINT qty_max CHECK (qty <= 100)
+)";
+
+/// Name of the violated constraint, panicking if the error is not a CHECK violation
+fn violated(db: &Database, sql: &str) -> Option<String> {
+    match db.execute(sql, ()) {
+        Err(Error::CheckConstraintViolation { constraint, .. }) => constraint,
+        other => panic!("expected CHECK violation for {}, got {:?}", sql, other),
+    }
+}
+
+fn row_count(db: &Database) -> i64 {
+    db.query_one("SELECT COUNT(*) FROM items", ()).unwrap()
+}
+
+#[test]
+fn test_two_checks_on_one_column() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(CREATE, ()).unwrap();
+
+    db.execute("INSERT INTO items VALUES (1, 50)", ()).unwrap();
+    db.execute("INSERT INTO items VALUES (2, 0)", ()).unwrap();
+    db.execute("INSERT INTO items VALUES (3, 100)", ()).unwrap();
+
+    // Violating the first (unnamed) CHECK
+    assert_eq!(violated(&db, "INSERT INTO items VALUES (4, -1)"), None);
+    // Violating the second (named) CHECK
+    assert_eq!(
+        violated(&db, "INSERT INTO items VALUES (5, 101)"),
+        Some("qty_max".to_string())
+    );
+
+    assert_eq!(row_count(&db), 3);
+}
+
+#[test]
+fn test_named_check_in_error_message() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(CREATE, ()).unwrap();
+
+    let err = db
+        .execute("INSERT INTO items VALUES (1, 500)", ())
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "CHECK constraint failed for column qty: (qty <= 100) (constraint qty_max)"
+    );
+}
+
+#[test]
+fn test_show_create_table_lists_every_check() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(CREATE, ()).unwrap();
+
+    let mut rows = db.query("SHOW CREATE TABLE items", ()).unwrap();
+    let row = rows.next().unwrap().unwrap();
+    let sql: String = row.get(1).unwrap();
+    assert!(sql.contains("CHECK ((qty >= 0))"), "{}", sql);
+    assert!(
+        sql.contains("CONSTRAINT qty_max CHECK ((qty <= 100))"),
+        "{}",
+        sql
+    );
+}
+
+#[test]
+fn test_constraint_without_check_is_rejected() {
+    let db = Database::open_in_memory().unwrap();
+    assert!(db
+        .execute(
+            "CREATE TABLE bad (id INTEGER PRIMARY KEY, v INTEGER CONSTRAINT v_pos UNIQUE)",
+            (),
+        )
+        .is_err());
+}
+
+#[test]
+fn test_modify_column_replaces_checks() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(CREATE, ()).unwrap();
+
+    db.execute(
+        "ALTER TABLE items MODIFY COLUMN qty INTEGER CONSTRAINT qty_even CHECK (qty % 2 = 0) CHECK (qty < 10)",
+        (),
+    )
+    .unwrap();
+
+    db.execute("INSERT INTO items VALUES (1, 4)", ()).unwrap();
+    assert_eq!(
+        violated(&db, "INSERT INTO items VALUES (2, 3)"),
+        Some("qty_even".to_string())
+    );
+    assert_eq!(violated(&db, "INSERT INTO items VALUES (3, 12)"), None);
+    // The old upper bound no longer applies, the new one is stricter
+    assert_eq!(row_count(&db), 1);
+}
+
+#[test]
+fn test_checks_survive_restart() {
+    let dir = tempdir().unwrap();
+    let dsn = format!("file://{}", dir.path().join("test.db").display());
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        db.execute(CREATE, ()).unwrap();
+        db.execute("INSERT INTO items VALUES (1, 10)", ()).unwrap();
+        db.close().unwrap();
+    }
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        assert_eq!(violated(&db, "INSERT INTO items VALUES (2, -1)"), None);
+        assert_eq!(
+            violated(&db, "INSERT INTO items VALUES (3, 101)"),
+            Some("qty_max".to_string())
+        );
+        db.execute("INSERT INTO items VALUES (4, 100)", ()).unwrap();
+        assert_eq!(row_count(&db), 2);
+        db.close().unwrap();
+    }
+}
@@ -0,0 +1,325 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! WAL segments and checkpoints
+//!
+//! The WAL rotates into segments of `wal_max_size` bytes, and a checkpoint
+//! folds it into a snapshot and removes the segments it covers.
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use oxibase::Database;
+use tempfile::tempdir;
+
+/// Number of WAL segment files and their total size in bytes
+fn wal_files(db_path: &Path) -> (usize, u64) {
+    let mut count = 0;
+    let mut size = 0;
+    for entry in std::fs::read_dir(db_path.join("wal")).unwrap() {
+        let entry = entry.unwrap();
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with("wal") && name.ends_with(".log") {
+            count += 1;
+            size += entry.metadata().unwrap().len();
+        }
+    }
+    (count, size)
+}
+
+fn write_rows(db: &Database, from: i64, to: i64) {
+    for id in from..=to {
+        db.execute(
+            "INSERT INTO events VALUES ($1, $2, 'some payload text to grow the log')",
+            (id, id * 10),
+        )
+        .unwrap();
+    }
+}
+
+fn totals(db: &Database) -> (i64, i64) {
+    let count: i64 = db.query_one("SELECT COUNT(*) FROM events", ()).unwrap();
+    let sum: i64 = db.query_one("SELECT SUM(amount) FROM events", ()).unwrap();
+    (count, sum)
+}
+
+#[test]
+fn test_wal_rotates_into_segments_and_recovers() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db");
+    let dsn = format!(
+        "file://{}?wal_segment_size=8192&snapshot_interval=0",
+        db_path.display()
+    );
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        db.execute(
+            "CREATE TABLE events (id INTEGER PRIMARY KEY, amount INTEGER, note TEXT)",
+            (),
+        )
+        .unwrap();
+        write_rows(&db, 1, 1000);
+        db.execute("UPDATE events SET amount = amount + 1 WHERE id <= 100", ())
+            .unwrap();
+        db.execute("DELETE FROM events WHERE id > 900", ()).unwrap();
+    }
+
+    let (segments, _) = wal_files(&db_path);
+    assert!(
+        segments > 1,
+        "expected several WAL segments, got {}",
+        segments
+    );
+
+    let db = Database::open(&dsn).unwrap();
+    let expected_sum: i64 = (1..=900).map(|id| id * 10).sum::<i64>() + 100;
+    assert_eq!(totals(&db), (900, expected_sum));
+}
+
+#[test]
+fn test_checkpoint_shrinks_wal_and_recovers() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db");
+    let dsn = format!(
+        "file://{}?wal_segment_size=8192&snapshot_interval=0",
+        db_path.display()
+    );
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        db.execute(
+            "CREATE TABLE events (id INTEGER PRIMARY KEY, amount INTEGER, note TEXT)",
+            (),
+        )
+        .unwrap();
+        write_rows(&db, 1, 2000);
+        db.execute("UPDATE events SET amount = 0 WHERE id <= 10", ())
+            .unwrap();
+
+        let (segments_before, size_before) = wal_files(&db_path);
+        db.execute("CHECKPOINT", ()).unwrap();
+        let (segments_after, size_after) = wal_files(&db_path);
+
+        assert!(
+            segments_after < segments_before,
+            "segments: {} -> {}",
+            segments_before,
+            segments_after
+        );
+        assert!(
+            size_after < size_before / 10,
+            "WAL size: {} -> {}",
+            size_before,
+            size_after
+        );
+
+        // These only live in the WAL past the checkpoint
+        write_rows(&db, 2001, 2100);
+        db.execute("DELETE FROM events WHERE id <= 5", ()).unwrap();
+    }
+
+    let db = Database::open(&dsn).unwrap();
+    let expected_sum: i64 = (11..=2100).map(|id| id * 10).sum();
+    assert_eq!(totals(&db), (2095, expected_sum));
+
+    // A second checkpoint after recovery keeps everything readable
+    db.execute("CHECKPOINT", ()).unwrap();
+    drop(db);
+    let db = Database::open(&dsn).unwrap();
+    assert_eq!(totals(&db), (2095, expected_sum));
+}
+
+#[test]
+fn test_automatic_checkpoint_on_wal_size() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db");
+    let dsn = format!(
+        "file://{}?wal_segment_size=8192&checkpoint_wal_size=65536&snapshot_interval=0",
+        db_path.display()
+    );
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        db.execute(
+            "CREATE TABLE events (id INTEGER PRIMARY KEY, amount INTEGER, note TEXT)",
+            (),
+        )
+        .unwrap();
+        write_rows(&db, 1, 3000);
+
+        // The checkpoint runs in the background, so wait for the WAL to shrink
+        let deadline = Instant::now() + Duration::from_secs(10);
+        loop {
+            let (_, size) = wal_files(&db_path);
+            if size < 65536 {
+                break;
+            }
+            assert!(
+                Instant::now() < deadline,
+                "WAL never checkpointed: {}",
+                size
+            );
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        assert!(db_path.join("snapshots").exists());
+    }
+
+    let db = Database::open(&dsn).unwrap();
+    assert_eq!(totals(&db), (3000, (1..=3000).map(|id| id * 10).sum()));
+}
+
+#[test]
+fn test_checkpoint_statement_in_memory() {
+    // Nothing to fold, but the statement is still accepted
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CHECKPOINT", ()).unwrap();
+
+    // CHECKPOINT stays usable as a column name
+    db.execute("CREATE TABLE runs (checkpoint INTEGER)", ())
+        .unwrap();
+    db.execute("INSERT INTO runs (checkpoint) VALUES (1)", ())
+        .unwrap();
+    let value: i64 = db.query_one("SELECT checkpoint FROM runs", ()).unwrap();
+    assert_eq!(value, 1);
+}
+
+/// Catalog objects that only a snapshot holds once the WAL is truncated
+fn create_catalog_objects(db: &Database) {
+    db.execute(
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT UNIQUE, team INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute("CREATE INDEX idx_users_team ON users(team)", ())
+        .unwrap();
+    db.execute(
+        "CREATE TABLE memberships (user_id INTEGER, team INTEGER, PRIMARY KEY (user_id, team))",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE VIEW team_sizes AS SELECT team, COUNT(*) AS n FROM users GROUP BY team",
+        (),
+    )
+    .unwrap();
+    db.execute("CREATE SEQUENCE ticket_seq START 100 INCREMENT 5", ())
+        .unwrap();
+    db.execute("CREATE TABLE scratch (id INTEGER)", ()).unwrap();
+    db.execute("CREATE SCHEMA app", ()).unwrap();
+    db.execute(
+        "CREATE TABLE app.items (id INTEGER PRIMARY KEY, name TEXT)",
+        (),
+    )
+    .unwrap();
+    db.execute("INSERT INTO app.items VALUES (1, 'widget')", ())
+        .unwrap();
+
+    db.execute(
+        "INSERT INTO users VALUES (1, 'a@example.com', 1), (2, 'b@example.com', 1), (3, 'c@example.com', 2)",
+        (),
+    )
+    .unwrap();
+    db.execute("INSERT INTO memberships VALUES (1, 1), (1, 2)", ())
+        .unwrap();
+    let first: i64 = db.query_one("SELECT NEXTVAL('ticket_seq')", ()).unwrap();
+    assert_eq!(first, 100);
+}
+
+/// Assert the objects of [`create_catalog_objects`] came back
+fn assert_catalog_objects(db: &Database) {
+    let indexes: Vec<String> = db
+        .query("SHOW INDEXES FROM users", ())
+        .unwrap()
+        .map(|row| row.unwrap().get::<String>(1).unwrap())
+        .collect();
+    assert!(
+        indexes.iter().any(|name| name == "idx_users_team"),
+        "{:?}",
+        indexes
+    );
+    assert!(db
+        .execute("INSERT INTO users VALUES (4, 'a@example.com', 3)", ())
+        .is_err());
+    assert!(db
+        .execute("INSERT INTO memberships VALUES (1, 2)", ())
+        .is_err());
+    db.execute("INSERT INTO memberships VALUES (2, 2)", ())
+        .unwrap();
+
+    let teams: i64 = db.query_one("SELECT COUNT(*) FROM team_sizes", ()).unwrap();
+    assert_eq!(teams, 2);
+
+    let next: i64 = db.query_one("SELECT NEXTVAL('ticket_seq')", ()).unwrap();
+    assert!(next > 100 && (next - 100) % 5 == 0, "{}", next);
+
+    let item: String = db
+        .query_one("SELECT name FROM app.items WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(item, "widget");
+
+    assert!(db.query("SELECT * FROM scratch", ()).is_err());
+}
+
+#[test]
+fn test_checkpoint_keeps_catalog_objects() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db");
+    let dsn = format!("file://{}?snapshot_interval=0", db_path.display());
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        create_catalog_objects(&db);
+        db.execute("CHECKPOINT", ()).unwrap();
+        // A table dropped after one checkpoint stays dropped after the next
+        db.execute("DROP TABLE scratch", ()).unwrap();
+        db.execute("CHECKPOINT", ()).unwrap();
+    }
+
+    let db = Database::open(&dsn).unwrap();
+    assert_catalog_objects(&db);
+
+    // And once more through a checkpoint taken after recovery
+    db.execute("CHECKPOINT", ()).unwrap();
+    drop(db);
+    let db = Database::open(&dsn).unwrap();
+    let emails: i64 = db.query_one("SELECT COUNT(*) FROM users", ()).unwrap();
+    assert_eq!(emails, 3);
+    assert!(db
+        .execute("INSERT INTO users VALUES (9, 'b@example.com', 3)", ())
+        .is_err());
+}
+
+#[test]
+fn test_automatic_checkpoint_keeps_catalog_objects() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("db");
+    let dsn = format!("file://{}?snapshot_interval=1", db_path.display());
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        create_catalog_objects(&db);
+        db.execute("DROP TABLE scratch", ()).unwrap();
+
+        // Wait for the background checkpoint to fold the WAL
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while !db_path.join("snapshots").join("snapshot_meta.bin").exists() {
+            assert!(Instant::now() < deadline, "no automatic checkpoint");
+            std::thread::sleep(Duration::from_millis(100));
+        }
+    }
+
+    let db = Database::open(&dsn).unwrap();
+    assert_catalog_objects(&db);
+}
@@ -0,0 +1,230 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and limitations
+// under the License.
+
+//! Tests for schema-only dumps and Database::clone_schema
+
+use oxibase::api::Database;
+
+/// Collect every row of a query as strings for comparison
+fn query_strings(db: &Database, sql: &str) -> Vec<Vec<String>> {
+    let result = db.query(sql, ()).expect("Failed to query");
+    let columns = result.columns().len();
+    result
+        .map(|row| {
+            let row = row.expect("Failed to read row");
+            (0..columns)
+                .map(|i| {
+                    row.get::<Option<String>>(i)
+                        .expect("Failed to get value")
+                        .unwrap_or_else(|| "NULL".to_string())
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn setup_source() -> Database {
+    let db = Database::open_in_memory().expect("Failed to create database");
+
+    db.execute(
+        "CREATE TABLE customers (id INTEGER PRIMARY KEY AUTO_INCREMENT, name TEXT NOT NULL, email TEXT UNIQUE, active BOOLEAN DEFAULT true)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE TABLE orders (id INTEGER PRIMARY KEY, customer_id INTEGER NOT NULL, amount FLOAT CHECK (amount >= 0), FOREIGN KEY (customer_id) REFERENCES customers(id) ON DELETE CASCADE)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE INDEX idx_orders_customer ON orders (customer_id)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE VIEW big_orders AS SELECT * FROM orders WHERE amount > 100",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE FUNCTION add_one(x INTEGER) RETURNS INTEGER LANGUAGE RHAI AS 'x + 1'",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE PROCEDURE touch(IN n INTEGER) LANGUAGE rhai AS $$ let y = n; $$",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE TRIGGER orders_check BEFORE INSERT ON orders FOR EACH ROW LANGUAGE rhai AS 'let x = 1;'",
+        (),
+    )
+    .unwrap();
+
+    db.execute(
+        "INSERT INTO customers (name, email) VALUES ('Alice', 'alice@example.com')",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO orders (id, customer_id, amount) VALUES (1, 1, 250.0)",
+        (),
+    )
+    .unwrap();
+
+    db
+}
+
+#[test]
+fn test_clone_schema_matches_information_schema() {
+    let source = setup_source();
+    let clone = source.clone_schema().expect("Failed to clone schema");
+
+    let tables_sql = "SELECT * FROM information_schema.tables ORDER BY table_schema, table_name";
+    let columns_sql = "SELECT * FROM information_schema.columns ORDER BY table_schema, table_name, ordinal_position";
+
+    assert_eq!(
+        query_strings(&source, tables_sql),
+        query_strings(&clone, tables_sql)
+    );
+    assert_eq!(
+        query_strings(&source, columns_sql),
+        query_strings(&clone, columns_sql)
+    );
+}
+
+#[test]
+fn test_clone_schema_has_no_rows() {
+    let source = setup_source();
+    let clone = source.clone_schema().expect("Failed to clone schema");
+
+    for table in ["customers", "orders", "big_orders"] {
+        let count: i64 = clone
+            .query_one(&format!("SELECT COUNT(*) FROM {}", table), ())
+            .unwrap();
+        assert_eq!(count, 0, "{} should be empty in the clone", table);
+    }
+
+    let count: i64 = source.query_one("SELECT COUNT(*) FROM orders", ()).unwrap();
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn test_clone_schema_preserves_constraints_and_routines() {
+    let source = setup_source();
+    let clone = source.clone_schema().expect("Failed to clone schema");
+
+    // Auto-increment and defaults
+    clone
+        .execute(
+            "INSERT INTO customers (name, email) VALUES ('Bob', 'bob@example.com')",
+            (),
+        )
+        .unwrap();
+    let active: bool = clone
+        .query_one("SELECT active FROM customers WHERE name = 'Bob'", ())
+        .unwrap();
+    assert!(active);
+
+    // Unique constraint
+    assert!(clone
+        .execute(
+            "INSERT INTO customers (name, email) VALUES ('Bob2', 'bob@example.com')",
+            ()
+        )
+        .is_err());
+
+    // Check constraint
+    assert!(clone
+        .execute(
+            "INSERT INTO orders (id, customer_id, amount) VALUES (1, 1, -5.0)",
+            ()
+        )
+        .is_err());
+
+    // Foreign key
+    assert!(clone
+        .execute(
+            "INSERT INTO orders (id, customer_id, amount) VALUES (1, 99, 5.0)",
+            ()
+        )
+        .is_err());
+
+    // Function
+    let value: i64 = clone.query_one("SELECT add_one(41)", ()).unwrap();
+    assert_eq!(value, 42);
+
+    // Indexes keep their names
+    let index_names = |db: &Database| {
+        let mut names: Vec<String> = db
+            .engine()
+            .list_table_indexes("orders")
+            .unwrap()
+            .into_keys()
+            .collect();
+        names.sort();
+        names
+    };
+    assert_eq!(index_names(&source), index_names(&clone));
+}
+
+#[test]
+fn test_dump_schema_is_replayable() {
+    let source = setup_source();
+    let statements = source.dump_schema().expect("Failed to dump schema");
+
+    // Referenced tables come first
+    let customers = statements
+        .iter()
+        .position(|s| s.starts_with("CREATE TABLE customers"))
+        .unwrap();
+    let orders = statements
+        .iter()
+        .position(|s| s.starts_with("CREATE TABLE orders"))
+        .unwrap();
+    assert!(customers < orders);
+
+    // No data is dumped
+    assert!(statements.iter().all(|s| !s.starts_with("INSERT")));
+
+    // Dumping the clone yields the same statements
+    let clone = source.clone_schema().unwrap();
+    assert_eq!(statements, clone.dump_schema().unwrap());
+}
+
+#[test]
+fn test_dump_schema_quotes_procedure_bodies() {
+    let source = Database::open_in_memory().expect("Failed to create database");
+    source
+        .execute(
+            "CREATE PROCEDURE quoted() LANGUAGE rhai AS 'let s = \"it''s $$ and $$\"; let t = s;'",
+            (),
+        )
+        .unwrap();
+
+    let statements = source.dump_schema().unwrap();
+    let procedure = statements
+        .iter()
+        .find(|s| s.starts_with("CREATE PROCEDURE quoted"))
+        .expect("Procedure should be dumped");
+    assert_eq!(
+        procedure,
+        "CREATE PROCEDURE quoted() LANGUAGE rhai AS 'let s = \"it''s $$ and $$\"; let t = s;'"
+    );
+
+    let clone = source.clone_schema().expect("Failed to clone schema");
+    assert_eq!(statements, clone.dump_schema().unwrap());
+    clone.execute("CALL quoted()", ()).unwrap();
+}
@@ -235,3 +235,47 @@ fn test_collate_null_handling() {
 
     assert!(found, "Expected to find the NULL row");
 }
+
+/// Test a COLLATE clause on an ORDER BY item
+#[test]
+fn test_order_by_collate_clause() {
+    let db = Database::open("memory://collate_clause").expect("Failed to create database");
+    db.execute(
+        "CREATE TABLE fruits (id INTEGER PRIMARY KEY, name TEXT)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO fruits VALUES (1, 'banana'), (2, 'Apple'), (3, 'cherry'), (4, 'apricot'), (5, 'Blueberry')",
+        (),
+    )
+    .unwrap();
+
+    let names = |sql: &str| -> Vec<String> {
+        db.query(sql, ())
+            .expect("Failed to query with ORDER BY")
+            .map(|row| row.unwrap().get(0).unwrap())
+            .collect()
+    };
+
+    // Without the override uppercase sorts before lowercase
+    assert_eq!(
+        names("SELECT name FROM fruits ORDER BY name"),
+        vec!["Apple", "Blueberry", "apricot", "banana", "cherry"]
+    );
+    assert_eq!(
+        names("SELECT name FROM fruits ORDER BY name COLLATE NOCASE"),
+        vec!["Apple", "apricot", "banana", "Blueberry", "cherry"]
+    );
+    assert_eq!(
+        names("SELECT name AS n FROM fruits ORDER BY n COLLATE 'nocase' DESC LIMIT 2"),
+        vec!["cherry", "Blueberry"]
+    );
+
+    assert!(db
+        .query("SELECT name FROM fruits ORDER BY name COLLATE NOSUCH", ())
+        .is_err());
+    assert!(db
+        .query("SELECT name FROM fruits ORDER BY 1 COLLATE NOCASE", ())
+        .is_err());
+}
@@ -0,0 +1,195 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cross-type comparison rules
+//!
+//! INTEGER and FLOAT compare numerically. TEXT compared with a number is read
+//! as a number, and text that is not a number is a type conversion error.
+
+use oxibase::Database;
+
+fn setup(indexed: bool) -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE t (id INTEGER PRIMARY KEY, i INTEGER, f FLOAT, s TEXT)",
+        (),
+    )
+    .unwrap();
+    if indexed {
+        db.execute("CREATE INDEX idx_t_i ON t(i)", ()).unwrap();
+        db.execute("CREATE INDEX idx_t_f ON t(f)", ()).unwrap();
+    }
+    db.execute(
+        "INSERT INTO t VALUES
+            (1, 5, 5.0, '5'),
+            (2, 10, 2.5, '10'),
+            (3, NULL, NULL, 'abc'),
+            (4, 7, 7.5, ' 7 ')",
+        (),
+    )
+    .unwrap();
+    db
+}
+
+fn ids(db: &Database, predicate: &str) -> Vec<i64> {
+    let sql = format!("SELECT id FROM t WHERE {} ORDER BY id", predicate);
+    db.query(&sql, ())
+        .unwrap_or_else(|e| panic!("{}: {}", predicate, e))
+        .map(|row| row.unwrap().get::<i64>(0).unwrap())
+        .collect()
+}
+
+fn where_error(db: &Database, predicate: &str) -> String {
+    let sql = format!("SELECT id FROM t WHERE {}", predicate);
+    match db.query(&sql, ()) {
+        Ok(_) => panic!("{}: expected an error", predicate),
+        Err(e) => e.to_string(),
+    }
+}
+
+#[test]
+fn test_integer_and_float_compare_numerically() {
+    for indexed in [false, true] {
+        let db = setup(indexed);
+        assert_eq!(ids(&db, "i = 5.0"), vec![1]);
+        assert_eq!(ids(&db, "i = 5.5"), Vec::<i64>::new());
+        assert_eq!(ids(&db, "i <> 5.0"), vec![2, 4]);
+        // A fractional bound is not truncated to the column type
+        assert_eq!(ids(&db, "i < 5.5"), vec![1]);
+        assert_eq!(ids(&db, "i > 6.5"), vec![2, 4]);
+        assert_eq!(ids(&db, "i <= 7.0"), vec![1, 4]);
+        assert_eq!(ids(&db, "5.5 > i"), vec![1]);
+        assert_eq!(ids(&db, "i BETWEEN 4.5 AND 7.5"), vec![1, 4]);
+        assert_eq!(ids(&db, "i IN (5.0, 7.5)"), vec![1]);
+        assert_eq!(ids(&db, "id = 2.0"), vec![2]);
+        assert_eq!(ids(&db, "id = 2.5"), Vec::<i64>::new());
+
+        assert_eq!(ids(&db, "f = 5"), vec![1]);
+        assert_eq!(ids(&db, "f > 2"), vec![1, 2, 4]);
+        assert_eq!(ids(&db, "f < i"), vec![2]);
+    }
+}
+
+#[test]
+fn test_numeric_text_compares_as_number() {
+    for indexed in [false, true] {
+        let db = setup(indexed);
+        assert_eq!(ids(&db, "i = '5'"), vec![1]);
+        assert_eq!(ids(&db, "i = '5.0'"), vec![1]);
+        assert_eq!(ids(&db, "i = ' 7 '"), vec![4]);
+        assert_eq!(ids(&db, "i = '5.5'"), Vec::<i64>::new());
+        // 10 > 9 as numbers, although '10' < '9' as strings
+        assert_eq!(ids(&db, "i > '9'"), vec![2]);
+        assert_eq!(ids(&db, "'9' < i"), vec![2]);
+        assert_eq!(ids(&db, "i BETWEEN '6' AND '10'"), vec![2, 4]);
+        assert_eq!(ids(&db, "f = '7.5'"), vec![4]);
+        assert_eq!(ids(&db, "f >= '5'"), vec![1, 4]);
+    }
+
+    let db = setup(false);
+    let matched: Vec<i64> = db
+        .query("SELECT id FROM t WHERE i = $1", ("5",))
+        .unwrap()
+        .map(|row| row.unwrap().get::<i64>(0).unwrap())
+        .collect();
+    assert_eq!(matched, vec![1]);
+
+    let result: bool = db.query_one("SELECT 10 > '9'", ()).unwrap();
+    assert!(result);
+    let result: bool = db.query_one("SELECT 5 = '5.0'", ()).unwrap();
+    assert!(result);
+    // Text against text is still a string comparison
+    let result: bool = db.query_one("SELECT '10' > '9'", ()).unwrap();
+    assert!(!result);
+}
+
+#[test]
+fn test_text_column_against_number() {
+    let db = setup(false);
+    assert_eq!(ids(&db, "s = 10"), vec![2]);
+    assert_eq!(ids(&db, "s = 7.0"), vec![4]);
+
+    // The row holding 'abc' cannot be compared and is an error when evaluated
+    let err = db
+        .query("SELECT id, s = 10 FROM t ORDER BY id", ())
+        .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+        .unwrap_err();
+    assert!(err.to_string().contains("'abc'"), "{}", err);
+}
+
+#[test]
+fn test_non_numeric_text_is_an_error() {
+    for indexed in [false, true] {
+        let db = setup(indexed);
+        for predicate in [
+            "i = 'abc'",
+            "'abc' = i",
+            "i <> 'abc'",
+            "i < 'five'",
+            "f >= 'x'",
+            "id = 'one'",
+            "i BETWEEN 'a' AND 10",
+            "i = 5 OR i = 'abc'",
+            "NOT (f = '')",
+        ] {
+            let err = where_error(&db, predicate);
+            assert!(
+                err.contains("type conversion error"),
+                "{}: {}",
+                predicate,
+                err
+            );
+        }
+        let err = where_error(&db, "i = 'abc'");
+        assert!(err.contains("'abc'") && err.contains("INTEGER"), "{}", err);
+    }
+
+    let db = setup(false);
+    let err = db
+        .query("SELECT id FROM t WHERE i = $1", ("abc",))
+        .err()
+        .expect("parameter that is not a number");
+    assert!(err.to_string().contains("'abc'"), "{}", err);
+
+    for sql in [
+        "UPDATE t SET s = 'x' WHERE i = 'abc'",
+        "DELETE FROM t WHERE f < 'abc'",
+    ] {
+        let err = db.execute(sql, ()).unwrap_err();
+        assert!(err.to_string().contains("'abc'"), "{}: {}", sql, err);
+    }
+    let count: i64 = db.query_one("SELECT COUNT(*) FROM t", ()).unwrap();
+    assert_eq!(count, 4);
+
+    let err = db
+        .query("SELECT 5 = 'abc'", ())
+        .and_then(|mut rows| rows.next().transpose())
+        .unwrap_err();
+    assert!(err.to_string().contains("'abc'"), "{}", err);
+}
+
+#[test]
+fn test_null_comparisons_are_null() {
+    let db = setup(false);
+    assert_eq!(ids(&db, "i = NULL"), Vec::<i64>::new());
+    assert_eq!(ids(&db, "i IS NULL"), vec![3]);
+
+    // NULL is never converted, so it is not an error against any type
+    let result: Option<bool> = db.query_one("SELECT NULL = 'abc'", ()).unwrap();
+    assert_eq!(result, None);
+    let result: Option<bool> = db
+        .query_one("SELECT i < 'abc' FROM t WHERE id = 3", ())
+        .unwrap();
+    assert_eq!(result, None);
+}
@@ -0,0 +1,249 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Composite Primary Key Tests
+//!
+//! Tests table-level PRIMARY KEY (a, b) constraints in CREATE TABLE
+
+use oxibase::{CatalogConstraint, Database};
+use tempfile::tempdir;
+
+fn setup() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE order_items (order_id INTEGER, product_id INTEGER, qty INTEGER, PRIMARY KEY (order_id, product_id))",
+        (),
+    )
+    .unwrap();
+    db
+}
+
+fn show_create(db: &Database, table: &str) -> String {
+    let mut rows = db
+        .query(&format!("SHOW CREATE TABLE {}", table), ())
+        .unwrap();
+    rows.next().unwrap().unwrap().get::<String>(1).unwrap()
+}
+
+#[test]
+fn test_composite_key_enforces_uniqueness_of_the_pair() {
+    let db = setup();
+    db.execute(
+        "INSERT INTO order_items VALUES (1, 10, 2), (1, 11, 1), (2, 10, 5)",
+        (),
+    )
+    .unwrap();
+
+    // The same pair again is rejected
+    let err = db
+        .execute("INSERT INTO order_items VALUES (1, 10, 9)", ())
+        .unwrap_err();
+    assert!(err.is_constraint_violation(), "{}", err);
+    assert!(db
+        .execute(
+            "UPDATE order_items SET product_id = 10 WHERE product_id = 11",
+            ()
+        )
+        .is_err());
+
+    // Key columns cannot be NULL
+    assert!(db
+        .execute("INSERT INTO order_items VALUES (NULL, 12, 1)", ())
+        .is_err());
+
+    let count: i64 = db
+        .query_one("SELECT COUNT(*) FROM order_items", ())
+        .unwrap();
+    assert_eq!(count, 3);
+    let qty: i64 = db
+        .query_one(
+            "SELECT qty FROM order_items WHERE order_id = 2 AND product_id = 10",
+            (),
+        )
+        .unwrap();
+    assert_eq!(qty, 5);
+}
+
+#[test]
+fn test_composite_key_round_trips_through_show_create_table() {
+    let db = setup();
+    let sql = show_create(&db, "order_items");
+    assert!(
+        sql.ends_with(", PRIMARY KEY (order_id, product_id))"),
+        "{}",
+        sql
+    );
+    assert!(!sql.contains("NOT NULL"), "{}", sql);
+
+    // A dump declares the table the same way
+    let dump = db.dump_schema().unwrap();
+    assert!(dump.contains(&sql), "{:?}", dump);
+
+    // Replaying the statement recreates the same key
+    let copy = Database::open_in_memory().unwrap();
+    copy.execute(&sql, ()).unwrap();
+    assert_eq!(show_create(&copy, "order_items"), sql);
+    copy.execute("INSERT INTO order_items VALUES (1, 1, 1)", ())
+        .unwrap();
+    assert!(copy
+        .execute("INSERT INTO order_items VALUES (1, 1, 2)", ())
+        .is_err());
+
+    let catalog = db.catalog().unwrap();
+    let table = catalog.table("order_items").unwrap();
+    assert!(table.constraints.contains(&CatalogConstraint::PrimaryKey {
+        columns: vec!["order_id".to_string(), "product_id".to_string()]
+    }));
+    assert!(!table
+        .constraints
+        .iter()
+        .any(|c| matches!(c, CatalogConstraint::Unique { .. })));
+    assert!(table.column("product_id").unwrap().primary_key);
+    assert!(!table.column("qty").unwrap().primary_key);
+}
+
+#[test]
+fn test_table_level_primary_key_rules() {
+    let db = Database::open_in_memory().unwrap();
+
+    // A single column works like a column-level PRIMARY KEY
+    db.execute(
+        "CREATE TABLE single (id INTEGER, name TEXT, PRIMARY KEY (id))",
+        (),
+    )
+    .unwrap();
+    assert!(show_create(&db, "single").contains("id Integer PRIMARY KEY"));
+    db.execute("INSERT INTO single VALUES (1, 'a')", ())
+        .unwrap();
+    assert!(db
+        .execute("INSERT INTO single VALUES (1, 'b')", ())
+        .is_err());
+
+    // Only one primary key per table
+    assert!(db
+        .execute(
+            "CREATE TABLE twice (a INTEGER PRIMARY KEY, b INTEGER, PRIMARY KEY (a, b))",
+            (),
+        )
+        .is_err());
+    // Key columns must exist
+    assert!(db
+        .execute("CREATE TABLE missing (a INTEGER, PRIMARY KEY (a, b))", ())
+        .is_err());
+}
+
+#[test]
+fn test_composite_key_survives_table_rename() {
+    let db = setup();
+    db.execute("ALTER TABLE order_items RENAME TO line_items", ())
+        .unwrap();
+
+    let sql = show_create(&db, "line_items");
+    assert!(
+        sql.ends_with(", PRIMARY KEY (order_id, product_id))"),
+        "{}",
+        sql
+    );
+    db.execute("INSERT INTO line_items VALUES (1, 1, 1)", ())
+        .unwrap();
+    assert!(db
+        .execute("INSERT INTO line_items VALUES (1, 1, 2)", ())
+        .is_err());
+}
+
+#[test]
+fn test_composite_key_index_cannot_be_dropped() {
+    let db = setup();
+    let err = db
+        .execute("DROP INDEX pk_order_items ON order_items", ())
+        .unwrap_err();
+    assert!(err.to_string().contains("PRIMARY KEY"), "{}", err);
+
+    db.execute("INSERT INTO order_items VALUES (1, 1, 1)", ())
+        .unwrap();
+    assert!(db
+        .execute("INSERT INTO order_items VALUES (1, 1, 1)", ())
+        .is_err());
+}
+
+#[test]
+fn test_unique_index_named_like_a_key_is_not_a_primary_key() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE t (a INTEGER, b INTEGER)", ())
+        .unwrap();
+    db.execute("CREATE UNIQUE INDEX pk_t ON t (a, b)", ())
+        .unwrap();
+
+    let sql = show_create(&db, "t");
+    assert!(!sql.contains("PRIMARY KEY"), "{}", sql);
+    let catalog = db.catalog().unwrap();
+    let table = catalog.table("t").unwrap();
+    assert!(!table
+        .constraints
+        .iter()
+        .any(|c| matches!(c, CatalogConstraint::PrimaryKey { .. })));
+    assert!(!table.column("a").unwrap().primary_key);
+    db.execute("DROP INDEX pk_t ON t", ()).unwrap();
+}
+
+#[test]
+fn test_composite_key_renamed_column_and_restart() {
+    let dir = tempdir().unwrap();
+    let dsn = format!("file://{}", dir.path().join("test.db").display());
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        db.execute(
+            "CREATE TABLE order_items (order_id INTEGER, product_id INTEGER, qty INTEGER, PRIMARY KEY (order_id, product_id))",
+            (),
+        )
+        .unwrap();
+        db.execute(
+            "ALTER TABLE order_items RENAME COLUMN product_id TO sku",
+            (),
+        )
+        .unwrap();
+        db.execute("INSERT INTO order_items VALUES (1, 1, 1)", ())
+            .unwrap();
+        db.close().unwrap();
+    }
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        let sql = show_create(&db, "order_items");
+        assert!(sql.ends_with(", PRIMARY KEY (order_id, sku))"), "{}", sql);
+        assert!(db
+            .execute("INSERT INTO order_items VALUES (1, 1, 2)", ())
+            .is_err());
+        assert!(db
+            .execute("ALTER TABLE order_items DROP COLUMN sku", ())
+            .is_err());
+        db.close().unwrap();
+    }
+}
+
+#[test]
+fn test_composite_key_survives_clone_schema() {
+    let db = setup();
+    let copy = db.clone_schema().unwrap();
+    assert_eq!(
+        show_create(&copy, "order_items"),
+        show_create(&db, "order_items")
+    );
+    copy.execute("INSERT INTO order_items VALUES (1, 1, 1)", ())
+        .unwrap();
+    assert!(copy
+        .execute("INSERT INTO order_items VALUES (1, 1, 2)", ())
+        .is_err());
+}
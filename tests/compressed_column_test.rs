@@ -0,0 +1,219 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! COMPRESSED columns
+//!
+//! Large TEXT/JSON/BLOB values are stored compressed and read back unchanged.
+
+use oxibase::{Database, Error};
+use tempfile::tempdir;
+
+fn document(seed: i64) -> String {
+    format!(
+        "{} {}",
+        seed,
+        "the quick brown fox jumps over the lazy dog ".repeat(40)
+    )
+}
+
+/// Insert copies of a 1KB value until the memory limit refuses one
+fn rows_until_full(db: &Database, column_type: &str) -> i64 {
+    db.execute(
+        &format!(
+            "CREATE TABLE docs (id INTEGER PRIMARY KEY, body {})",
+            column_type
+        ),
+        (),
+    )
+    .unwrap();
+    let body = "x".repeat(1024);
+    for id in 1..=10_000 {
+        if let Err(err) = db.execute("INSERT INTO docs VALUES ($1, $2)", (id, body.as_str())) {
+            assert!(matches!(err, Error::OutOfMemory { .. }), "{:?}", err);
+            let stored: i64 = db.query_one("SELECT COUNT(*) FROM docs", ()).unwrap();
+            assert_eq!(stored, id - 1);
+            return stored;
+        }
+    }
+    panic!("limit never reached");
+}
+
+#[test]
+fn test_compressed_value_stores_smaller_and_reads_back() {
+    let plain = Database::open("memory://compressed_plain?max_memory=256KB").unwrap();
+    let compressed = Database::open("memory://compressed_lz4?max_memory=256KB").unwrap();
+
+    let plain_rows = rows_until_full(&plain, "TEXT");
+    let compressed_rows = rows_until_full(&compressed, "TEXT COMPRESSED");
+    assert!(
+        compressed_rows > plain_rows * 3,
+        "{} vs {}",
+        compressed_rows,
+        plain_rows
+    );
+
+    let body: String = compressed
+        .query_one("SELECT body FROM docs WHERE id = 7", ())
+        .unwrap();
+    assert_eq!(body, "x".repeat(1024));
+}
+
+#[test]
+fn test_compressed_column_filters_and_sorts() {
+    let db = Database::open("memory://compressed_filters").unwrap();
+    db.execute(
+        "CREATE TABLE docs (id INTEGER PRIMARY KEY, body TEXT COMPRESSED, note TEXT COMPRESSED)",
+        (),
+    )
+    .unwrap();
+    for id in [3, 1, 2] {
+        db.execute(
+            "INSERT INTO docs VALUES ($1, $2, 'short')",
+            (id, document(id).as_str()),
+        )
+        .unwrap();
+    }
+
+    // Equality on both a compressed and a too-short-to-compress value
+    let id: i64 = db
+        .query_one(
+            "SELECT id FROM docs WHERE body = $1",
+            (document(2).as_str(),),
+        )
+        .unwrap();
+    assert_eq!(id, 2);
+    let count: i64 = db
+        .query_one("SELECT COUNT(*) FROM docs WHERE note = 'short'", ())
+        .unwrap();
+    assert_eq!(count, 3);
+
+    // Range and ORDER BY compare the decompressed values
+    let ids: Vec<i64> = db
+        .query(
+            "SELECT id FROM docs WHERE body >= $1 ORDER BY body DESC",
+            (document(2).as_str(),),
+        )
+        .unwrap()
+        .map(|row| row.unwrap().get::<i64>(0).unwrap())
+        .collect();
+    assert_eq!(ids, vec![3, 2]);
+    let len: i64 = db
+        .query_one("SELECT LENGTH(body) FROM docs ORDER BY body LIMIT 1", ())
+        .unwrap();
+    assert_eq!(len as usize, document(1).len());
+
+    // Updates and deletes go through the same encoding
+    db.execute(
+        "UPDATE docs SET body = $1 WHERE id = 1",
+        (document(9).as_str(),),
+    )
+    .unwrap();
+    db.execute("DELETE FROM docs WHERE body = $1", (document(3).as_str(),))
+        .unwrap();
+    let ids: Vec<i64> = db
+        .query("SELECT id FROM docs ORDER BY body", ())
+        .unwrap()
+        .map(|row| row.unwrap().get::<i64>(0).unwrap())
+        .collect();
+    assert_eq!(ids, vec![2, 1]);
+
+    // Rows written in an open transaction read back decoded too
+    db.execute("BEGIN", ()).unwrap();
+    db.execute(
+        "INSERT INTO docs VALUES (4, $1, 'short')",
+        (document(4).as_str(),),
+    )
+    .unwrap();
+    let body: String = db
+        .query_one("SELECT body FROM docs WHERE id = 4", ())
+        .unwrap();
+    assert_eq!(body, document(4));
+    db.execute("ROLLBACK", ()).unwrap();
+}
+
+#[test]
+fn test_compressed_column_restrictions() {
+    let db = Database::open("memory://compressed_restrictions").unwrap();
+    assert!(db
+        .execute(
+            "CREATE TABLE bad (id INTEGER PRIMARY KEY, n INTEGER COMPRESSED)",
+            ()
+        )
+        .is_err());
+    assert!(db
+        .execute(
+            "CREATE TABLE bad (id INTEGER PRIMARY KEY, s TEXT COMPRESSED UNIQUE)",
+            ()
+        )
+        .is_err());
+
+    db.execute(
+        "CREATE TABLE docs (id INTEGER PRIMARY KEY, body TEXT COMPRESSED)",
+        (),
+    )
+    .unwrap();
+    assert!(db
+        .execute("CREATE INDEX idx_docs_body ON docs(body)", ())
+        .is_err());
+    assert!(db
+        .execute("ALTER TABLE docs ADD COLUMN extra TEXT COMPRESSED", ())
+        .is_err());
+
+    let ddl: String = db
+        .query("SHOW CREATE TABLE docs", ())
+        .unwrap()
+        .map(|row| row.unwrap().get::<String>(1).unwrap())
+        .next()
+        .unwrap();
+    assert!(ddl.contains("body Text COMPRESSED"), "{}", ddl);
+}
+
+#[test]
+fn test_compressed_column_survives_reopen() {
+    let dir = tempdir().expect("Failed to create temp dir");
+    let dsn = format!("file://{}", dir.path().display());
+
+    {
+        let db = Database::open(&dsn).expect("Failed to open database");
+        db.execute(
+            "CREATE TABLE docs (id INTEGER PRIMARY KEY, body TEXT COMPRESSED, data BLOB COMPRESSED)",
+            (),
+        )
+        .unwrap();
+        db.execute(
+            "INSERT INTO docs VALUES (1, $1, NULL)",
+            (document(1).as_str(),),
+        )
+        .unwrap();
+        db.execute("PRAGMA SNAPSHOT", ()).unwrap();
+        db.execute(
+            "INSERT INTO docs VALUES (2, $1, NULL)",
+            (document(2).as_str(),),
+        )
+        .unwrap();
+        db.close().expect("Failed to close database");
+    }
+
+    let db = Database::open(&dsn).expect("Failed to reopen database");
+    for id in [1, 2] {
+        let body: String = db
+            .query_one("SELECT body FROM docs WHERE id = $1", (id,))
+            .unwrap();
+        assert_eq!(body, document(id));
+    }
+    // The column is still compressed after reopening
+    assert!(db
+        .execute("CREATE INDEX idx_docs_body ON docs(body)", ())
+        .is_err());
+}
@@ -0,0 +1,91 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! NULL handling of string concatenation
+//!
+//! `||` propagates NULL, CONCAT treats NULL as an empty string and CONCAT_WS
+//! skips NULLs together with their separator.
+
+use oxibase::Database;
+
+fn setup() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE people (id INTEGER PRIMARY KEY, first TEXT, middle TEXT, last TEXT)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO people VALUES (1, 'Ada', NULL, 'Lovelace'), (2, 'Alan', 'M', 'Turing')",
+        (),
+    )
+    .unwrap();
+    db
+}
+
+fn texts(db: &Database, expr: &str) -> Vec<Option<String>> {
+    db.query(&format!("SELECT {} FROM people ORDER BY id", expr), ())
+        .unwrap()
+        .map(|row| row.unwrap().get::<Option<String>>(0).unwrap())
+        .collect()
+}
+
+fn some(s: &str) -> Option<String> {
+    Some(s.to_string())
+}
+
+#[test]
+fn test_operator_propagates_null() {
+    let db = setup();
+    assert_eq!(
+        texts(&db, "first || ' ' || middle || ' ' || last"),
+        vec![None, some("Alan M Turing")]
+    );
+    assert_eq!(
+        texts(&db, "first || ' ' || last"),
+        vec![some("Ada Lovelace"), some("Alan Turing")]
+    );
+    assert_eq!(
+        texts(&db, "COALESCE(first || middle, '-')"),
+        vec![some("-"), some("AlanM")]
+    );
+}
+
+#[test]
+fn test_concat_treats_null_as_empty() {
+    let db = setup();
+    assert_eq!(
+        texts(&db, "CONCAT(first, ' ', middle, ' ', last)"),
+        vec![some("Ada  Lovelace"), some("Alan M Turing")]
+    );
+    assert_eq!(texts(&db, "CONCAT(middle)"), vec![some(""), some("M")]);
+    assert_eq!(
+        texts(&db, "CONCAT('#', id, ': ', last)"),
+        vec![some("#1: Lovelace"), some("#2: Turing")]
+    );
+}
+
+#[test]
+fn test_concat_ws_skips_nulls() {
+    let db = setup();
+    assert_eq!(
+        texts(&db, "CONCAT_WS(' ', first, middle, last)"),
+        vec![some("Ada Lovelace"), some("Alan M Turing")]
+    );
+    assert_eq!(
+        texts(&db, "CONCAT_WS(' ', middle)"),
+        vec![some(""), some("M")]
+    );
+    assert_eq!(texts(&db, "CONCAT_WS(NULL, first, last)"), vec![None, None]);
+}
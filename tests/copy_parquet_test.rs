@@ -0,0 +1,161 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests for COPY TO / COPY FROM with FORMAT PARQUET
+
+#![cfg(feature = "parquet")]
+
+use oxibase::{Database, Value};
+
+const CREATE_EVENTS: &str = "CREATE TABLE events (id INTEGER PRIMARY KEY, name TEXT, \
+     score FLOAT, active BOOLEAN, at TIMESTAMP, payload JSON)";
+
+fn all_rows(db: &Database, sql: &str) -> Vec<Vec<Value>> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (0..row.len())
+                .map(|i| row.get_value(i).unwrap().clone())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_parquet_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("events.parquet");
+    let path = path.to_str().unwrap();
+
+    let src = Database::open("memory://parquet_src").unwrap();
+    src.execute(CREATE_EVENTS, ()).unwrap();
+    src.execute(
+        "INSERT INTO events VALUES \
+         (1, 'login', 1.5, true, '2024-01-02 03:04:05.123456', '{\"ip\": \"10.0.0.1\"}'), \
+         (2, NULL, NULL, NULL, NULL, NULL), \
+         (3, 'it''s', -0.25, false, '1999-12-31 23:59:59', '[1, 2, 3]')",
+        (),
+    )
+    .unwrap();
+    for id in 4..=20_000 {
+        src.execute(
+            "INSERT INTO events (id, name, score) VALUES ($1, 'bulk', $2)",
+            (id, id as f64 / 2.0),
+        )
+        .unwrap();
+    }
+
+    let written = src
+        .execute(&format!("COPY events TO '{}' (FORMAT parquet)", path), ())
+        .unwrap();
+    assert_eq!(written, 20_000);
+
+    let dst = Database::open("memory://parquet_dst").unwrap();
+    dst.execute(CREATE_EVENTS, ()).unwrap();
+    let loaded = dst
+        .execute(
+            &format!("COPY events FROM '{}' WITH (FORMAT PARQUET)", path),
+            (),
+        )
+        .unwrap();
+    assert_eq!(loaded, 20_000);
+
+    let query = "SELECT * FROM events ORDER BY id";
+    let expected = all_rows(&src, query);
+    let actual = all_rows(&dst, query);
+    assert_eq!(actual.len(), expected.len());
+    assert_eq!(actual, expected);
+
+    // Types survive the round trip
+    let types = all_rows(
+        &dst,
+        "SELECT TYPEOF(score), TYPEOF(at), TYPEOF(payload) FROM events WHERE id = 1",
+    );
+    assert_eq!(
+        types[0],
+        vec![
+            Value::text("FLOAT"),
+            Value::text("TIMESTAMP"),
+            Value::text("JSON")
+        ]
+    );
+}
+
+#[test]
+fn test_parquet_column_list() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("names.parquet");
+    let path = path.to_str().unwrap();
+
+    let db = Database::open("memory://parquet_columns").unwrap();
+    db.execute(CREATE_EVENTS, ()).unwrap();
+    db.execute(
+        "INSERT INTO events (id, name, score) VALUES (1, 'a', 1.0), (2, 'b', 2.0)",
+        (),
+    )
+    .unwrap();
+
+    // Export a subset of columns, then load it into a table with defaults
+    db.execute(
+        &format!("COPY events (id, name) TO '{}' (FORMAT PARQUET)", path),
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE TABLE names (id INTEGER PRIMARY KEY, name TEXT, source TEXT DEFAULT 'parquet')",
+        (),
+    )
+    .unwrap();
+    db.execute(&format!("COPY names FROM '{}' (FORMAT PARQUET)", path), ())
+        .unwrap();
+    assert_eq!(
+        all_rows(&db, "SELECT id, name, source FROM names ORDER BY id"),
+        vec![
+            vec![Value::Integer(1), Value::text("a"), Value::text("parquet")],
+            vec![Value::Integer(2), Value::text("b"), Value::text("parquet")],
+        ]
+    );
+
+    // File columns that do not exist in the target table are an error
+    db.execute("CREATE TABLE ids (id INTEGER PRIMARY KEY)", ())
+        .unwrap();
+    assert!(db
+        .execute(&format!("COPY ids FROM '{}' (FORMAT PARQUET)", path), ())
+        .is_err());
+    // ...unless the column list selects what to read
+    db.execute(
+        &format!("COPY ids (id) FROM '{}' (FORMAT PARQUET)", path),
+        (),
+    )
+    .unwrap();
+    let count: i64 = db.query_one("SELECT COUNT(*) FROM ids", ()).unwrap();
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn test_copy_to_unsupported_format() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("events.csv");
+
+    let db = Database::open("memory://parquet_unsupported").unwrap();
+    db.execute(CREATE_EVENTS, ()).unwrap();
+    let err = db
+        .execute(
+            &format!("COPY events TO '{}' (FORMAT CSV)", path.to_str().unwrap()),
+            (),
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("COPY TO"), "Got: {}", err);
+}
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -259,3 +260,135 @@ fn test_count_empty_table() {
 
     assert_eq!(count, 0, "COUNT(*) on empty table should return 0");
 }
+
+/// Counts rows through a predicate so the scan-based path is used
+fn scan_count(db: &Database, table: &str) -> i64 {
+    db.query_one(
+        &format!("SELECT COUNT(*) FROM {} WHERE id IS NOT NULL", table),
+        (),
+    )
+    .expect("Failed to execute scan-based COUNT(*)")
+}
+
+/// Test COUNT(*) fast path matches a scan after inserts, updates and deletes
+#[test]
+fn test_count_star_fast_path_matches_scan() {
+    let db = Database::open("memory://count_fast_path").expect("Failed to create database");
+    setup_count_test_table(&db);
+
+    db.execute("DELETE FROM test_count WHERE id <= 10", ())
+        .unwrap();
+    db.execute("UPDATE test_count SET value = 0 WHERE id > 90", ())
+        .unwrap();
+    db.execute("INSERT INTO test_count VALUES (101, 'x', 1.0, true)", ())
+        .unwrap();
+
+    let fast: i64 = db.query_one("SELECT COUNT(*) FROM test_count", ()).unwrap();
+    assert_eq!(fast, 91);
+    assert_eq!(fast, scan_count(&db, "test_count"));
+
+    // A rolled back transaction must not leak into the maintained count
+    let mut tx = db.begin().unwrap();
+    tx.execute("DELETE FROM test_count", ()).unwrap();
+    tx.rollback().unwrap();
+
+    let fast: i64 = db.query_one("SELECT COUNT(*) FROM test_count", ()).unwrap();
+    assert_eq!(fast, 91);
+    assert_eq!(fast, scan_count(&db, "test_count"));
+}
+
+/// Test COUNT(*) fast path inside a transaction with pending changes
+#[test]
+fn test_count_star_fast_path_in_transaction() {
+    let db = Database::open("memory://count_fast_path_txn").expect("Failed to create database");
+    setup_count_test_table(&db);
+
+    db.execute("BEGIN", ()).unwrap();
+    for i in 101..=105 {
+        db.execute(
+            &format!("INSERT INTO test_count VALUES ({}, 'p', 1.0, false)", i),
+            (),
+        )
+        .unwrap();
+    }
+    db.execute("DELETE FROM test_count WHERE id = 1", ())
+        .unwrap();
+
+    // The transaction sees its own pending inserts and deletes
+    let fast: i64 = db.query_one("SELECT COUNT(*) FROM test_count", ()).unwrap();
+    assert_eq!(fast, 104);
+    assert_eq!(fast, scan_count(&db, "test_count"));
+
+    db.execute("COMMIT", ()).unwrap();
+
+    let fast: i64 = db.query_one("SELECT COUNT(*) FROM test_count", ()).unwrap();
+    assert_eq!(fast, 104);
+    assert_eq!(fast, scan_count(&db, "test_count"));
+}
+
+/// Test COUNT(*) fast path doesn't see another transaction's pending inserts
+#[test]
+fn test_count_star_fast_path_uncommitted_not_visible() {
+    let db =
+        Database::open("memory://count_fast_path_uncommitted").expect("Failed to create database");
+    setup_count_test_table(&db);
+
+    let mut tx = db.begin().unwrap();
+    tx.execute("INSERT INTO test_count VALUES (101, 'p', 1.0, false)", ())
+        .unwrap();
+
+    let fast: i64 = db.query_one("SELECT COUNT(*) FROM test_count", ()).unwrap();
+    assert_eq!(fast, 100);
+    assert_eq!(fast, scan_count(&db, "test_count"));
+
+    tx.commit().unwrap();
+
+    let fast: i64 = db.query_one("SELECT COUNT(*) FROM test_count", ()).unwrap();
+    assert_eq!(fast, 101);
+    assert_eq!(fast, scan_count(&db, "test_count"));
+}
+
+/// Test COUNT(*) under snapshot isolation ignores rows committed after the snapshot
+#[test]
+fn test_count_star_snapshot_isolation() {
+    let db =
+        Database::open("memory://count_fast_path_snapshot").expect("Failed to create database");
+    setup_count_test_table(&db);
+
+    db.execute("BEGIN TRANSACTION ISOLATION LEVEL SNAPSHOT", ())
+        .unwrap();
+    let before: i64 = db.query_one("SELECT COUNT(*) FROM test_count", ()).unwrap();
+    assert_eq!(before, 100);
+
+    let mut tx = db.begin().unwrap();
+    tx.execute("INSERT INTO test_count VALUES (101, 'x', 1.0, true)", ())
+        .unwrap();
+    tx.commit().unwrap();
+
+    let after: i64 = db.query_one("SELECT COUNT(*) FROM test_count", ()).unwrap();
+    assert_eq!(after, 100);
+    db.execute("COMMIT", ()).unwrap();
+
+    let fast: i64 = db.query_one("SELECT COUNT(*) FROM test_count", ()).unwrap();
+    assert_eq!(fast, 101);
+}
+
+/// Test COUNT(*) fast path after recovering from the WAL
+#[test]
+fn test_count_star_after_recovery() {
+    let dir = tempfile::tempdir().unwrap();
+    let dsn = format!("file://{}", dir.path().join("count_db").display());
+
+    {
+        let db = Database::open(&dsn).expect("Failed to open database");
+        setup_count_test_table(&db);
+        db.execute("DELETE FROM test_count WHERE active = true", ())
+            .unwrap();
+        db.close().unwrap();
+    }
+
+    let db = Database::open(&dsn).expect("Failed to reopen database");
+    let fast: i64 = db.query_one("SELECT COUNT(*) FROM test_count", ()).unwrap();
+    assert_eq!(fast, 50);
+    assert_eq!(fast, scan_count(&db, "test_count"));
+}
@@ -0,0 +1,197 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! CREATE OR REPLACE for views, functions and procedures
+//!
+//! OR REPLACE swaps the definition of an existing object in place, without
+//! a separate DROP, and creates it when it does not exist yet.
+
+use oxibase::Database;
+use tempfile::tempdir;
+
+fn count(db: &Database, sql: &str) -> i64 {
+    db.query_one(sql, ()).unwrap()
+}
+
+#[test]
+fn test_or_replace_function_changes_behavior() {
+    let db = Database::open_in_memory().unwrap();
+
+    db.execute(
+        "CREATE OR REPLACE FUNCTION bump(x INTEGER) RETURNS INTEGER LANGUAGE RHAI AS 'x + 1'",
+        (),
+    )
+    .unwrap();
+    assert_eq!(count(&db, "SELECT bump(10)"), 11);
+
+    db.execute(
+        "CREATE OR REPLACE FUNCTION bump(x INTEGER) RETURNS INTEGER LANGUAGE RHAI AS 'x + 100'",
+        (),
+    )
+    .unwrap();
+    assert_eq!(count(&db, "SELECT bump(10)"), 110);
+
+    // Still a single function of that name
+    assert_eq!(
+        count(
+            &db,
+            "SELECT COUNT(*) FROM system.functions WHERE name = 'bump'"
+        ),
+        1
+    );
+    let code: String = db
+        .query_one("SELECT code FROM system.functions WHERE name = 'bump'", ())
+        .unwrap();
+    assert_eq!(code, "x + 100");
+}
+
+#[test]
+fn test_plain_create_function_still_rejects_duplicates() {
+    let db = Database::open_in_memory().unwrap();
+
+    db.execute(
+        "CREATE FUNCTION answer() RETURNS INTEGER LANGUAGE RHAI AS '42'",
+        (),
+    )
+    .unwrap();
+    assert!(db
+        .execute(
+            "CREATE FUNCTION answer() RETURNS INTEGER LANGUAGE RHAI AS '43'",
+            (),
+        )
+        .is_err());
+    assert_eq!(count(&db, "SELECT answer()"), 42);
+}
+
+#[test]
+fn test_or_replace_function_survives_restart() {
+    let dir = tempdir().unwrap();
+    let dsn = format!("file://{}", dir.path().join("test.db").display());
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        db.execute(
+            "CREATE FUNCTION scale(x INTEGER) RETURNS INTEGER LANGUAGE RHAI AS 'x * 2'",
+            (),
+        )
+        .unwrap();
+        db.execute(
+            "CREATE OR REPLACE FUNCTION scale(x INTEGER) RETURNS INTEGER LANGUAGE RHAI AS 'x * 3'",
+            (),
+        )
+        .unwrap();
+        db.close().unwrap();
+    }
+
+    let db = Database::open(&dsn).unwrap();
+    assert_eq!(count(&db, "SELECT scale(5)"), 15);
+}
+
+#[test]
+fn test_or_replace_view() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE nums (n INTEGER)", ()).unwrap();
+    db.execute("INSERT INTO nums VALUES (1), (2), (3), (4)", ())
+        .unwrap();
+
+    db.execute(
+        "CREATE OR REPLACE VIEW big AS SELECT n FROM nums WHERE n > 1",
+        (),
+    )
+    .unwrap();
+    assert_eq!(count(&db, "SELECT COUNT(*) FROM big"), 3);
+
+    db.execute(
+        "CREATE OR REPLACE VIEW big AS SELECT n FROM nums WHERE n > 3",
+        (),
+    )
+    .unwrap();
+    assert_eq!(count(&db, "SELECT COUNT(*) FROM big"), 1);
+
+    // Plain CREATE VIEW still refuses to overwrite
+    assert!(db
+        .execute("CREATE VIEW big AS SELECT n FROM nums", ())
+        .is_err());
+
+    // A table name cannot be replaced by a view
+    assert!(db
+        .execute("CREATE OR REPLACE VIEW nums AS SELECT 1 AS n", ())
+        .is_err());
+}
+
+#[test]
+fn test_or_replace_view_survives_restart() {
+    let dir = tempdir().unwrap();
+    let dsn = format!("file://{}", dir.path().join("test.db").display());
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        db.execute("CREATE TABLE nums (n INTEGER)", ()).unwrap();
+        db.execute("INSERT INTO nums VALUES (1), (2), (3)", ())
+            .unwrap();
+        db.execute("CREATE VIEW v AS SELECT n FROM nums", ())
+            .unwrap();
+        db.execute(
+            "CREATE OR REPLACE VIEW v AS SELECT n FROM nums WHERE n = 2",
+            (),
+        )
+        .unwrap();
+        db.close().unwrap();
+    }
+
+    let db = Database::open(&dsn).unwrap();
+    assert_eq!(count(&db, "SELECT COUNT(*) FROM v"), 1);
+}
+
+#[test]
+fn test_or_replace_procedure() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE log (v INTEGER)", ()).unwrap();
+
+    db.execute(
+        "CREATE OR REPLACE PROCEDURE note() LANGUAGE sql AS $$ INSERT INTO log VALUES (1); $$",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE OR REPLACE PROCEDURE note() LANGUAGE sql AS $$ INSERT INTO log VALUES (2); $$",
+        (),
+    )
+    .unwrap();
+    db.execute("CALL note()", ()).unwrap();
+
+    assert_eq!(count(&db, "SELECT SUM(v) FROM log"), 2);
+    assert_eq!(
+        count(
+            &db,
+            "SELECT COUNT(*) FROM system.procedures WHERE name = 'note'"
+        ),
+        1
+    );
+}
+
+#[test]
+fn test_or_replace_rejected_where_unsupported() {
+    let db = Database::open_in_memory().unwrap();
+
+    assert!(db
+        .execute("CREATE OR REPLACE TABLE t (id INTEGER)", ())
+        .is_err());
+    assert!(db
+        .execute(
+            "CREATE OR REPLACE VIEW IF NOT EXISTS v AS SELECT 1 AS one",
+            (),
+        )
+        .is_err());
+}
@@ -0,0 +1,165 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cursor Tests
+//!
+//! Tests DECLARE / FETCH / CLOSE for paging through large results
+
+use oxibase::Database;
+
+fn setup(db: &Database, rows: i64) {
+    db.execute(
+        "CREATE TABLE events (id INTEGER PRIMARY KEY, kind TEXT)",
+        (),
+    )
+    .unwrap();
+    for id in 1..=rows {
+        db.execute("INSERT INTO events VALUES ($1, 'click')", (id,))
+            .unwrap();
+    }
+}
+
+fn fetch_ids(db: &Database, sql: &str) -> Vec<i64> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| row.unwrap().get(0).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_fetch_in_chunks() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db, 1050);
+
+    db.execute("BEGIN", ()).unwrap();
+    db.execute(
+        "DECLARE page CURSOR FOR SELECT id, kind FROM events ORDER BY id",
+        (),
+    )
+    .unwrap();
+
+    let mut chunks = Vec::new();
+    let mut all = Vec::new();
+    loop {
+        let rows = db.query("FETCH 200 FROM page", ()).unwrap();
+        assert_eq!(rows.columns(), &["id".to_string(), "kind".to_string()]);
+        let ids: Vec<i64> = rows.map(|row| row.unwrap().get(0).unwrap()).collect();
+        if ids.is_empty() {
+            break;
+        }
+        chunks.push(ids.len());
+        all.extend(ids);
+    }
+    assert_eq!(chunks, vec![200, 200, 200, 200, 200, 50]);
+    assert_eq!(all, (1..=1050).collect::<Vec<_>>());
+
+    db.execute("CLOSE page", ()).unwrap();
+    assert!(db.query("FETCH 1 FROM page", ()).is_err());
+    db.execute("COMMIT", ()).unwrap();
+}
+
+#[test]
+fn test_fetch_forms() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db, 10);
+
+    db.execute("BEGIN", ()).unwrap();
+    db.execute(
+        "DECLARE c NO SCROLL CURSOR FOR SELECT id FROM events WHERE id > 3 ORDER BY id",
+        (),
+    )
+    .unwrap();
+    assert_eq!(fetch_ids(&db, "FETCH c"), vec![4]);
+    assert_eq!(fetch_ids(&db, "FETCH NEXT FROM c"), vec![5]);
+    assert_eq!(fetch_ids(&db, "FETCH FORWARD 2 IN c"), vec![6, 7]);
+    assert_eq!(fetch_ids(&db, "FETCH ALL FROM c"), vec![8, 9, 10]);
+    assert!(fetch_ids(&db, "FETCH ALL FROM c").is_empty());
+    db.execute("COMMIT", ()).unwrap();
+}
+
+#[test]
+fn test_cursor_uses_transaction_snapshot() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db, 100);
+
+    db.execute("BEGIN TRANSACTION ISOLATION LEVEL SNAPSHOT", ())
+        .unwrap();
+    db.execute("DECLARE c CURSOR FOR SELECT id FROM events ORDER BY id", ())
+        .unwrap();
+    assert_eq!(fetch_ids(&db, "FETCH 10 FROM c").len(), 10);
+
+    // Concurrent changes after DECLARE are not visible through the cursor
+    let mut writer = db.begin().unwrap();
+    writer
+        .execute("INSERT INTO events VALUES (1000, 'late')", ())
+        .unwrap();
+    writer
+        .execute("DELETE FROM events WHERE id = 100", ())
+        .unwrap();
+    writer.commit().unwrap();
+
+    let rest = fetch_ids(&db, "FETCH ALL FROM c");
+    assert_eq!(rest, (11..=100).collect::<Vec<_>>());
+    db.execute("COMMIT", ()).unwrap();
+
+    let count: i64 = db.query_one("SELECT COUNT(*) FROM events", ()).unwrap();
+    assert_eq!(count, 100);
+}
+
+#[test]
+fn test_cursor_lifetime() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db, 5);
+
+    // Plain cursors need a transaction block and close when it ends
+    assert!(db
+        .execute("DECLARE c CURSOR FOR SELECT id FROM events", ())
+        .is_err());
+    db.execute("BEGIN", ()).unwrap();
+    db.execute("DECLARE c CURSOR FOR SELECT id FROM events ORDER BY id", ())
+        .unwrap();
+    assert!(db
+        .execute("DECLARE c CURSOR FOR SELECT id FROM events", ())
+        .is_err());
+    db.execute(
+        "DECLARE held CURSOR WITH HOLD FOR SELECT id FROM events ORDER BY id",
+        (),
+    )
+    .unwrap();
+    assert_eq!(fetch_ids(&db, "FETCH 2 FROM held"), vec![1, 2]);
+    db.execute("COMMIT", ()).unwrap();
+
+    assert!(db.query("FETCH 1 FROM c", ()).is_err());
+    // WITH HOLD cursors keep their remaining rows after commit
+    db.execute("DELETE FROM events", ()).unwrap();
+    assert_eq!(fetch_ids(&db, "FETCH ALL FROM held"), vec![3, 4, 5]);
+    db.execute("CLOSE held", ()).unwrap();
+
+    // Rollback closes every cursor declared in the transaction
+    db.execute("BEGIN", ()).unwrap();
+    db.execute("DECLARE gone CURSOR WITH HOLD FOR SELECT 1", ())
+        .unwrap();
+    db.execute("ROLLBACK", ()).unwrap();
+    assert!(db.query("FETCH 1 FROM gone", ()).is_err());
+
+    // WITH HOLD outside a transaction, and CLOSE ALL
+    db.execute("DECLARE a CURSOR WITH HOLD FOR SELECT 1", ())
+        .unwrap();
+    db.execute("DECLARE b CURSOR WITH HOLD FOR SELECT 2", ())
+        .unwrap();
+    assert_eq!(fetch_ids(&db, "FETCH b"), vec![2]);
+    db.execute("CLOSE ALL", ()).unwrap();
+    assert!(db.query("FETCH a", ()).is_err());
+    assert!(db.execute("CLOSE a", ()).is_err());
+}
@@ -0,0 +1,437 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and limitations
+// under the License.
+
+//! Tests for registering custom storage engines with Database::open
+
+use std::any::Any;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use oxibase::core::{
+    CheckConstraint, DataType, IsolationLevel, Result, Row, Schema, SequenceOptions, ViewDefinition,
+};
+use oxibase::storage::traits::{Index, Transaction};
+use oxibase::{Config, Database, Engine, Error, MVCCEngine};
+use rustc_hash::FxHashMap;
+
+/// A trivial engine that keeps its data in memory and counts transactions
+struct CountingEngine {
+    inner: MVCCEngine,
+    transactions: Arc<AtomicUsize>,
+    closes: Arc<AtomicUsize>,
+    expose_mvcc: bool,
+}
+
+impl CountingEngine {
+    fn new(transactions: Arc<AtomicUsize>, expose_mvcc: bool) -> Result<Self> {
+        Self::wrap(MVCCEngine::in_memory(), transactions, expose_mvcc)
+    }
+
+    /// Wraps an engine that stores its data at `path`
+    fn on_disk(path: &str, transactions: Arc<AtomicUsize>) -> Result<Self> {
+        Self::wrap(MVCCEngine::new(Config::with_path(path)), transactions, true)
+    }
+
+    fn wrap(inner: MVCCEngine, transactions: Arc<AtomicUsize>, expose_mvcc: bool) -> Result<Self> {
+        inner.open_engine()?;
+        Ok(Self {
+            inner,
+            transactions,
+            closes: Arc::new(AtomicUsize::new(0)),
+            expose_mvcc,
+        })
+    }
+
+    /// Counts the calls to `close_engine` in `closes`
+    fn counting_closes(mut self, closes: Arc<AtomicUsize>) -> Self {
+        self.closes = closes;
+        self
+    }
+}
+
+impl Engine for CountingEngine {
+    fn open(&mut self) -> Result<()> {
+        self.inner.open()
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.inner.close()
+    }
+
+    fn close_engine(&self) -> Result<()> {
+        self.closes.fetch_add(1, Ordering::SeqCst);
+        Engine::close_engine(&self.inner)
+    }
+
+    fn begin_transaction(&self) -> Result<Box<dyn Transaction>> {
+        self.transactions.fetch_add(1, Ordering::SeqCst);
+        self.inner.begin_transaction()
+    }
+
+    fn begin_transaction_with_level(&self, level: IsolationLevel) -> Result<Box<dyn Transaction>> {
+        self.transactions.fetch_add(1, Ordering::SeqCst);
+        self.inner.begin_transaction_with_level(level)
+    }
+
+    fn path(&self) -> Option<&str> {
+        None
+    }
+
+    fn table_exists(&self, table_name: &str) -> Result<bool> {
+        self.inner.table_exists(table_name)
+    }
+
+    fn view_exists(&self, view_name: &str) -> Result<bool> {
+        Engine::view_exists(&self.inner, view_name)
+    }
+
+    fn index_exists(&self, index_name: &str, table_name: &str) -> Result<bool> {
+        self.inner.index_exists(index_name, table_name)
+    }
+
+    fn get_index(&self, table_name: &str, index_name: &str) -> Result<Box<dyn Index>> {
+        self.inner.get_index(table_name, index_name)
+    }
+
+    fn get_table_schema(&self, table_name: &str) -> Result<Schema> {
+        self.inner.get_table_schema(table_name)
+    }
+
+    fn update_table_schema(&self, table_name: &str, schema: Schema) -> Result<()> {
+        self.inner.update_table_schema(table_name, schema)
+    }
+
+    fn list_table_indexes(&self, table_name: &str) -> Result<FxHashMap<String, String>> {
+        self.inner.list_table_indexes(table_name)
+    }
+
+    fn get_all_indexes(&self, table_name: &str) -> Result<Vec<Arc<dyn Index>>> {
+        self.inner.get_all_indexes(table_name)
+    }
+
+    fn get_isolation_level(&self) -> IsolationLevel {
+        self.inner.get_isolation_level()
+    }
+
+    fn set_isolation_level(&mut self, level: IsolationLevel) -> Result<()> {
+        self.inner.set_isolation_level(level)
+    }
+
+    fn get_config(&self) -> Config {
+        self.inner.get_config()
+    }
+
+    fn update_config(&mut self, config: Config) -> Result<()> {
+        self.inner.update_config(config)
+    }
+
+    fn create_snapshot(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn sequence_exists(&self, schema_name: &str, sequence_name: &str) -> Result<bool> {
+        self.inner.sequence_exists(schema_name, sequence_name)
+    }
+
+    fn create_sequence(
+        &self,
+        schema_name: &str,
+        sequence_name: &str,
+        options: SequenceOptions,
+    ) -> Result<()> {
+        self.inner
+            .create_sequence(schema_name, sequence_name, options)
+    }
+
+    fn alter_sequence(
+        &self,
+        schema_name: &str,
+        sequence_name: &str,
+        options: SequenceOptions,
+    ) -> Result<()> {
+        self.inner
+            .alter_sequence(schema_name, sequence_name, options)
+    }
+
+    fn drop_sequence(&self, schema_name: &str, sequence_name: &str) -> Result<()> {
+        self.inner.drop_sequence(schema_name, sequence_name)
+    }
+
+    fn nextval(&self, schema_name: &str, sequence_name: &str) -> Result<i64> {
+        self.inner.nextval(schema_name, sequence_name)
+    }
+
+    fn setval(
+        &self,
+        schema_name: &str,
+        sequence_name: &str,
+        value: i64,
+        is_called: bool,
+    ) -> Result<i64> {
+        self.inner
+            .setval(schema_name, sequence_name, value, is_called)
+    }
+
+    fn list_sequences(&self) -> Result<Vec<(String, String, SequenceOptions, i64)>> {
+        self.inner.list_sequences()
+    }
+
+    fn fetch_rows_by_ids(&self, table_name: &str, row_ids: &[i64]) -> Result<Vec<(i64, Row)>> {
+        self.inner.fetch_rows_by_ids(table_name, row_ids)
+    }
+
+    fn create_table(&self, schema: Schema) -> Result<Schema> {
+        Engine::create_table(&self.inner, schema)
+    }
+
+    fn drop_table(&self, table_name: &str) -> Result<()> {
+        Engine::drop_table(&self.inner, table_name)
+    }
+
+    fn rename_column(&self, table_name: &str, old_name: &str, new_name: &str) -> Result<()> {
+        Engine::rename_column(&self.inner, table_name, old_name, new_name)
+    }
+
+    fn modify_column(
+        &self,
+        table_name: &str,
+        column_name: &str,
+        data_type: DataType,
+        nullable: bool,
+        auto_increment: Option<bool>,
+        checks: Option<Vec<CheckConstraint>>,
+    ) -> Result<()> {
+        Engine::modify_column(
+            &self.inner,
+            table_name,
+            column_name,
+            data_type,
+            nullable,
+            auto_increment,
+            checks,
+        )
+    }
+
+    fn schema_exists(&self, schema_name: &str) -> Result<bool> {
+        self.inner.schema_exists(schema_name)
+    }
+
+    fn create_schema(&self, schema_name: &str) -> Result<()> {
+        self.inner.create_schema(schema_name)
+    }
+
+    fn drop_schema(&self, schema_name: &str) -> Result<()> {
+        self.inner.drop_schema(schema_name)
+    }
+
+    fn list_schemas(&self) -> Result<Vec<String>> {
+        self.inner.list_schemas()
+    }
+
+    fn list_schema_tables(&self, schema_name: &str) -> Result<Vec<Schema>> {
+        self.inner.list_schema_tables(schema_name)
+    }
+
+    fn create_view(
+        &self,
+        schema_name: &str,
+        view_name: &str,
+        query: String,
+        if_not_exists: bool,
+    ) -> Result<()> {
+        Engine::create_view(&self.inner, schema_name, view_name, query, if_not_exists)
+    }
+
+    fn create_or_replace_view(
+        &self,
+        schema_name: &str,
+        view_name: &str,
+        query: String,
+    ) -> Result<()> {
+        Engine::create_or_replace_view(&self.inner, schema_name, view_name, query)
+    }
+
+    fn drop_view(&self, schema_name: &str, view_name: &str, if_exists: bool) -> Result<()> {
+        Engine::drop_view(&self.inner, schema_name, view_name, if_exists)
+    }
+
+    fn get_view(&self, schema_name: &str, view_name: &str) -> Result<Option<Arc<ViewDefinition>>> {
+        Engine::get_view(&self.inner, schema_name, view_name)
+    }
+
+    fn list_views(&self) -> Result<Vec<(String, String)>> {
+        Engine::list_views(&self.inner)
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        if self.expose_mvcc {
+            Some(&self.inner)
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn test_custom_engine_runs_sql() {
+    let transactions = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&transactions);
+    Database::register_engine("counting", move |_path| {
+        Ok(Arc::new(CountingEngine::new(Arc::clone(&counter), true)?) as Arc<dyn Engine>)
+    })
+    .expect("Failed to register engine");
+
+    let db = Database::open("counting://test_custom_engine_runs_sql")
+        .expect("Failed to open custom engine");
+
+    db.execute("CREATE TABLE items (id INTEGER PRIMARY KEY, name TEXT)", ())
+        .unwrap();
+    db.execute("INSERT INTO items VALUES (1, 'a'), (2, 'b')", ())
+        .unwrap();
+    db.execute("UPDATE items SET name = 'c' WHERE id = 2", ())
+        .unwrap();
+
+    let count: i64 = db.query_one("SELECT COUNT(*) FROM items", ()).unwrap();
+    assert_eq!(count, 2);
+    let name: String = db
+        .query_one("SELECT name FROM items WHERE id = 2", ())
+        .unwrap();
+    assert_eq!(name, "c");
+
+    assert!(
+        transactions.load(Ordering::SeqCst) > 0,
+        "Statements should run in transactions of the custom engine"
+    );
+
+    // Statements that inspect MVCC internals work through the downcast
+    assert!(db.engine().as_mvcc().is_some());
+    db.query("SHOW ENGINE STATUS", ()).unwrap();
+}
+
+#[test]
+fn test_custom_engine_ddl_without_mvcc() {
+    let transactions = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&transactions);
+    Database::register_engine("nomvcc", move |_path| {
+        Ok(Arc::new(CountingEngine::new(Arc::clone(&counter), false)?) as Arc<dyn Engine>)
+    })
+    .unwrap();
+
+    let db = Database::open("nomvcc://test_custom_engine_ddl_without_mvcc").unwrap();
+    assert!(db.engine().as_mvcc().is_none());
+
+    // DDL goes through the catalog methods of the Engine trait
+    db.execute("CREATE TABLE items (id INTEGER PRIMARY KEY, name TEXT)", ())
+        .unwrap();
+    db.execute("INSERT INTO items VALUES (1, 'a')", ()).unwrap();
+    db.execute("ALTER TABLE items RENAME COLUMN name TO label", ())
+        .unwrap();
+    db.execute("CREATE VIEW labels AS SELECT label FROM items", ())
+        .unwrap();
+    let label: String = db.query_one("SELECT label FROM labels", ()).unwrap();
+    assert_eq!(label, "a");
+
+    db.execute("CREATE SCHEMA shop", ()).unwrap();
+    db.execute("CREATE TABLE shop.orders (id INTEGER PRIMARY KEY)", ())
+        .unwrap();
+    let tables: i64 = db
+        .query_one(
+            "SELECT COUNT(*) FROM system.tables WHERE schema_name = 'shop'",
+            (),
+        )
+        .unwrap();
+    assert_eq!(tables, 1);
+
+    db.execute("DROP VIEW labels", ()).unwrap();
+    db.execute("DROP TABLE items", ()).unwrap();
+    db.execute("DROP SCHEMA shop", ()).unwrap();
+    assert!(!db.engine().schema_exists("shop").unwrap());
+
+    // Only statements that inspect MVCC internals need the MVCC engine
+    let Err(err) = db.query("SHOW ENGINE STATUS", ()) else {
+        panic!("Expected SHOW ENGINE STATUS to be unsupported");
+    };
+    assert!(matches!(err, Error::NotSupportedMessage(_)), "{:?}", err);
+}
+
+#[test]
+fn test_custom_engine_is_closed() {
+    let closes = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&closes);
+    Database::register_engine("closing", move |_path| {
+        let engine = CountingEngine::new(Arc::new(AtomicUsize::new(0)), false)?
+            .counting_closes(Arc::clone(&counter));
+        Ok(Arc::new(engine) as Arc<dyn Engine>)
+    })
+    .unwrap();
+
+    // Closing the database closes the engine
+    let db = Database::open("closing://test_custom_engine_is_closed").unwrap();
+    db.execute("CREATE TABLE t (id INTEGER)", ()).unwrap();
+    db.close().unwrap();
+    assert!(closes.load(Ordering::SeqCst) >= 1);
+    drop(db);
+
+    // So does dropping its last handle
+    let before = closes.load(Ordering::SeqCst);
+    let db = Database::open("closing://test_custom_engine_is_dropped").unwrap();
+    db.execute("CREATE TABLE t (id INTEGER)", ()).unwrap();
+    drop(db);
+    assert!(closes.load(Ordering::SeqCst) > before);
+}
+
+#[test]
+fn test_unregistered_scheme() {
+    let Err(err) = Database::open("unregistered://somewhere") else {
+        panic!("Expected unsupported scheme error");
+    };
+    assert!(err.to_string().contains("Unsupported scheme"), "{}", err);
+}
+
+#[test]
+fn test_builtin_scheme_cannot_be_overridden() {
+    let result = Database::register_engine("memory", |_path| {
+        Ok(Arc::new(MVCCEngine::in_memory()) as Arc<dyn Engine>)
+    });
+    assert!(result.is_err());
+
+    let db = Database::open("memory://test_builtin_scheme_cannot_be_overridden").unwrap();
+    db.execute("CREATE TABLE t (id INTEGER)", ()).unwrap();
+}
+
+#[test]
+fn test_custom_engine_persists_sequence_values() {
+    let transactions = Arc::new(AtomicUsize::new(0));
+    Database::register_engine("countingdisk", move |path| {
+        Ok(Arc::new(CountingEngine::on_disk(path, Arc::clone(&transactions))?) as Arc<dyn Engine>)
+    })
+    .expect("Failed to register engine");
+
+    let dir = tempfile::tempdir().unwrap();
+    let dsn = format!("countingdisk://{}", dir.path().join("seq.db").display());
+    {
+        let db = Database::open(&dsn).expect("Failed to open custom engine");
+        db.execute("CREATE SEQUENCE ids START 10", ()).unwrap();
+        for expected in [10, 11, 12] {
+            let value: i64 = db.query_one("SELECT NEXTVAL('ids')", ()).unwrap();
+            assert_eq!(value, expected);
+        }
+        db.close().unwrap();
+    }
+
+    // The wrapped engine saves the values, so numbering carries on
+    let db = Database::open(&dsn).expect("Failed to reopen custom engine");
+    let next: i64 = db.query_one("SELECT NEXTVAL('ids')", ()).unwrap();
+    assert_eq!(next, 13);
+}
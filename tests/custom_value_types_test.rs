@@ -0,0 +1,154 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Custom Value Type Tests
+//!
+//! Tests binding and reading embedder-defined types through ToParam and
+//! FromValue
+
+use std::fmt;
+use std::str::FromStr;
+
+use oxibase::{
+    impl_value_newtype, impl_value_via_string, Database, FromValue, Result, ToParam, Value,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct UserId(i64);
+
+impl_value_newtype!(UserId(i64));
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Status {
+    Active,
+    Disabled,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Status::Active => "active",
+            Status::Disabled => "disabled",
+        })
+    }
+}
+
+impl FromStr for Status {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "active" => Ok(Status::Active),
+            "disabled" => Ok(Status::Disabled),
+            other => Err(format!("unknown status {}", other)),
+        }
+    }
+}
+
+impl_value_via_string!(Status);
+
+/// Cents stored as INTEGER, implemented by hand
+#[derive(Debug, PartialEq)]
+struct Money {
+    cents: i64,
+}
+
+impl ToParam for Money {
+    fn to_param(&self) -> Value {
+        Value::Integer(self.cents)
+    }
+}
+
+impl FromValue for Money {
+    fn from_value(value: &Value) -> Result<Self> {
+        i64::from_value(value).map(|cents| Money { cents })
+    }
+}
+
+fn setup(name: &str) -> Database {
+    let db = Database::open(&format!("memory://{}", name)).expect("Failed to create database");
+    db.execute(
+        "CREATE TABLE accounts (id INTEGER PRIMARY KEY, status TEXT, balance INTEGER)",
+        (),
+    )
+    .expect("Failed to create table");
+    db
+}
+
+#[test]
+fn test_bind_and_read_newtype() {
+    let db = setup("custom_newtype");
+
+    db.execute(
+        "INSERT INTO accounts VALUES ($1, $2, $3), ($4, $5, $6)",
+        (
+            UserId(1),
+            Status::Active,
+            Money { cents: 1250 },
+            UserId(2),
+            Status::Disabled,
+            Money { cents: 0 },
+        ),
+    )
+    .expect("Failed to insert");
+
+    let id: UserId = db
+        .query_one(
+            "SELECT id FROM accounts WHERE status = $1",
+            (Status::Disabled,),
+        )
+        .expect("Failed to query");
+    assert_eq!(id, UserId(2));
+
+    let row = db
+        .query(
+            "SELECT status, balance FROM accounts WHERE id = $1",
+            (UserId(1),),
+        )
+        .expect("Failed to query")
+        .next()
+        .expect("Expected a row")
+        .expect("Failed to read row");
+    assert_eq!(row.get::<Status>(0).unwrap(), Status::Active);
+    assert_eq!(row.get::<Money>(1).unwrap(), Money { cents: 1250 });
+
+    // Option works with custom types too
+    let missing: Option<UserId> = db
+        .query_opt("SELECT id FROM accounts WHERE id = $1", (Some(UserId(9)),))
+        .expect("Failed to query");
+    assert_eq!(missing, None);
+}
+
+#[test]
+fn test_invalid_custom_value_is_an_error() {
+    let db = setup("custom_invalid");
+    db.execute("INSERT INTO accounts VALUES (1, 'frozen', 5)", ())
+        .expect("Failed to insert");
+
+    let err = db
+        .query_one::<Status, _>("SELECT status FROM accounts", ())
+        .expect_err("Unknown status should not convert");
+    assert!(err.to_string().contains("Status"), "{}", err);
+
+    // Narrow integers reject values that do not fit
+    db.execute("UPDATE accounts SET balance = -1", ())
+        .expect("Failed to update");
+    assert!(db
+        .query_one::<u32, _>("SELECT balance FROM accounts", ())
+        .is_err());
+    let balance: i16 = db
+        .query_one("SELECT balance FROM accounts", ())
+        .expect("Failed to query");
+    assert_eq!(balance, -1);
+}
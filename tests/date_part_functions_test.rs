@@ -0,0 +1,182 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and limitations
+// under the License.
+
+//! Tests for EXTRACT and the date-part convenience functions on TIMESTAMP columns
+
+use oxibase::api::Database;
+
+fn setup(name: &str) -> Database {
+    let db = Database::open(&format!("memory://{}", name)).expect("Failed to create database");
+    db.execute(
+        "CREATE TABLE posts (id INTEGER PRIMARY KEY, created_at TIMESTAMP)",
+        (),
+    )
+    .unwrap();
+    // Friday, March 15, 2024
+    db.execute(
+        "INSERT INTO posts VALUES (1, '2024-03-15 10:30:45'), (2, NULL)",
+        (),
+    )
+    .unwrap();
+    db
+}
+
+fn extract_int(db: &Database, expr: &str) -> i64 {
+    db.query_one(&format!("SELECT {} FROM posts WHERE id = 1", expr), ())
+        .unwrap_or_else(|e| panic!("{} failed: {}", expr, e))
+}
+
+fn extract_is_null(db: &Database, expr: &str) -> bool {
+    let value: Option<i64> = db
+        .query_one(&format!("SELECT {} FROM posts WHERE id = 2", expr), ())
+        .unwrap_or_else(|e| panic!("{} failed: {}", expr, e));
+    value.is_none()
+}
+
+#[test]
+fn test_extract_fields_from_timestamp_column() {
+    let db = setup("date_part_extract_fields");
+
+    let cases = [
+        ("YEAR", 2024),
+        ("MONTH", 3),
+        ("DAY", 15),
+        ("HOUR", 10),
+        ("MINUTE", 30),
+        ("SECOND", 45),
+        ("DOW", 5),
+        ("ISODOW", 5),
+        ("DOY", 75),
+        ("WEEK", 11),
+        ("QUARTER", 1),
+    ];
+    for (field, expected) in cases {
+        let expr = format!("EXTRACT({} FROM created_at)", field);
+        assert_eq!(extract_int(&db, &expr), expected, "{}", expr);
+    }
+}
+
+#[test]
+fn test_date_part_functions_on_timestamp_column() {
+    let db = setup("date_part_functions");
+
+    let cases = [
+        ("YEAR", 2024),
+        ("MONTH", 3),
+        ("DAY", 15),
+        ("HOUR", 10),
+        ("MINUTE", 30),
+        ("SECOND", 45),
+        ("QUARTER", 1),
+        ("WEEK", 11),
+        ("DAYOFWEEK", 5),
+        ("DAYOFYEAR", 75),
+    ];
+    for (func, expected) in cases {
+        let expr = format!("{}(created_at)", func);
+        assert_eq!(extract_int(&db, &expr), expected, "{}", expr);
+    }
+}
+
+#[test]
+fn test_day_of_week_boundaries() {
+    let db = Database::open("memory://date_part_dow").expect("Failed to create database");
+
+    // Sunday, March 17, 2024 and Saturday, March 23, 2024
+    let sunday: i64 = db
+        .query_one("SELECT DAYOFWEEK(CAST('2024-03-17' AS TIMESTAMP))", ())
+        .unwrap();
+    assert_eq!(sunday, 0);
+    let saturday: i64 = db
+        .query_one("SELECT DAYOFWEEK(CAST('2024-03-23' AS TIMESTAMP))", ())
+        .unwrap();
+    assert_eq!(saturday, 6);
+
+    let sunday: i64 = db
+        .query_one(
+            "SELECT EXTRACT(DOW FROM CAST('2024-03-17' AS TIMESTAMP))",
+            (),
+        )
+        .unwrap();
+    assert_eq!(sunday, 0);
+    let sunday: i64 = db
+        .query_one(
+            "SELECT EXTRACT(ISODOW FROM CAST('2024-03-17' AS TIMESTAMP))",
+            (),
+        )
+        .unwrap();
+    assert_eq!(sunday, 7);
+}
+
+#[test]
+fn test_date_part_null_input() {
+    let db = setup("date_part_null");
+
+    for field in [
+        "YEAR", "MONTH", "DAY", "HOUR", "MINUTE", "SECOND", "DOW", "DOY", "WEEK", "QUARTER",
+    ] {
+        let expr = format!("EXTRACT({} FROM created_at)", field);
+        assert!(extract_is_null(&db, &expr), "{} should be NULL", expr);
+    }
+    for func in [
+        "YEAR",
+        "MONTH",
+        "DAY",
+        "HOUR",
+        "MINUTE",
+        "SECOND",
+        "QUARTER",
+        "WEEK",
+        "DAYOFWEEK",
+        "DAYOFYEAR",
+    ] {
+        let expr = format!("{}(created_at)", func);
+        assert!(extract_is_null(&db, &expr), "{} should be NULL", expr);
+    }
+}
+
+#[test]
+fn test_group_posts_by_month() {
+    let db =
+        Database::open("memory://date_part_group_by_month").expect("Failed to create database");
+    db.execute(
+        "CREATE TABLE posts (id INTEGER PRIMARY KEY, created_at TIMESTAMP)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO posts VALUES
+            (1, '2024-01-05 09:00:00'),
+            (2, '2024-01-20 18:30:00'),
+            (3, '2024-02-11 12:00:00'),
+            (4, '2024-03-01 00:00:00'),
+            (5, '2024-03-31 23:59:59'),
+            (6, '2024-03-15 08:15:00')",
+        (),
+    )
+    .unwrap();
+
+    let rows = db
+        .query(
+            "SELECT EXTRACT(MONTH FROM created_at) AS m, COUNT(*) FROM posts GROUP BY EXTRACT(MONTH FROM created_at) ORDER BY m",
+            (),
+        )
+        .unwrap();
+    let mut counts = Vec::new();
+    for row in rows {
+        let row = row.unwrap();
+        counts.push((row.get::<i64>(0).unwrap(), row.get::<i64>(1).unwrap()));
+    }
+    assert_eq!(counts, vec![(1, 2), (2, 1), (3, 3)]);
+}
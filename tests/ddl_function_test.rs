@@ -265,4 +265,50 @@ mod ddl_function_tests {
         let pytext: String = db.query_one("SELECT test_func_python()", ()).unwrap();
         assert_eq!(pytext, "rhai rocks");
     }
+
+    #[test]
+    fn test_drop_function_overload_by_signature() {
+        let db = Database::open("memory://drop_overload_test").unwrap();
+
+        db.execute(
+            "CREATE FUNCTION describe_value(x INTEGER) RETURNS TEXT LANGUAGE RHAI AS '\"int \" + x'",
+            (),
+        )
+        .unwrap();
+        db.execute(
+            "CREATE FUNCTION describe_value(x TEXT) RETURNS TEXT LANGUAGE RHAI AS '\"text \" + x'",
+            (),
+        )
+        .unwrap();
+
+        // Calls pick the overload from the argument types
+        let text: String = db.query_one("SELECT describe_value(7)", ()).unwrap();
+        assert_eq!(text, "int 7");
+        let text: String = db.query_one("SELECT describe_value('a')", ()).unwrap();
+        assert_eq!(text, "text a");
+
+        // The bare name is ambiguous
+        let err = db.execute("DROP FUNCTION describe_value", ()).unwrap_err();
+        let err_msg = err.to_string();
+        assert!(err_msg.contains("not unique"), "{}", err_msg);
+        assert!(err_msg.contains("DESCRIBE_VALUE(INTEGER)"), "{}", err_msg);
+
+        // INT names the same type as INTEGER
+        db.execute("DROP FUNCTION describe_value(INT)", ()).unwrap();
+        let text: String = db.query_one("SELECT describe_value('b')", ()).unwrap();
+        assert_eq!(text, "text b");
+
+        let err = db
+            .execute("DROP FUNCTION describe_value(INTEGER)", ())
+            .unwrap_err();
+        assert!(err.to_string().contains("not found"));
+        db.execute("DROP FUNCTION IF EXISTS describe_value(BOOLEAN)", ())
+            .unwrap();
+
+        // The remaining overload can be dropped by name
+        db.execute("DROP FUNCTION describe_value", ()).unwrap();
+        assert!(db
+            .query_one::<String, _>("SELECT describe_value('c')", ())
+            .is_err());
+    }
 }
@@ -0,0 +1,80 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! DDL no-op reporting
+//!
+//! `is_no_op` tells a DDL statement skipped by IF [NOT] EXISTS apart from
+//! one that changed the schema.
+
+use oxibase::Database;
+
+fn no_op(db: &Database, sql: &str) -> bool {
+    db.query(sql, ()).unwrap().is_no_op()
+}
+
+#[test]
+fn test_if_not_exists_on_existing_table_reports_no_change() {
+    let db = Database::open("memory://ddl_no_op_table").unwrap();
+
+    assert!(!no_op(
+        &db,
+        "CREATE TABLE IF NOT EXISTS t (id INTEGER PRIMARY KEY, v INTEGER)"
+    ));
+    assert!(no_op(
+        &db,
+        "CREATE TABLE IF NOT EXISTS t (id INTEGER PRIMARY KEY, v INTEGER)"
+    ));
+
+    assert!(!no_op(&db, "CREATE INDEX IF NOT EXISTS idx_t_v ON t(v)"));
+    assert!(no_op(&db, "CREATE INDEX IF NOT EXISTS idx_t_v ON t(v)"));
+    assert!(!no_op(&db, "DROP INDEX IF EXISTS idx_t_v ON t"));
+    assert!(no_op(&db, "DROP INDEX IF EXISTS idx_t_v ON t"));
+
+    assert!(!no_op(&db, "DROP TABLE IF EXISTS t"));
+    assert!(no_op(&db, "DROP TABLE IF EXISTS t"));
+
+    // Statements other than skipped DDL never report a no-op
+    assert!(!no_op(&db, "CREATE TABLE u (id INTEGER PRIMARY KEY)"));
+    assert!(!no_op(&db, "SELECT * FROM u"));
+}
+
+#[test]
+fn test_if_exists_on_other_objects_reports_no_change() {
+    let db = Database::open("memory://ddl_no_op_objects").unwrap();
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY)", ())
+        .unwrap();
+
+    assert!(!no_op(
+        &db,
+        "CREATE VIEW IF NOT EXISTS v AS SELECT id FROM t"
+    ));
+    assert!(no_op(
+        &db,
+        "CREATE VIEW IF NOT EXISTS v AS SELECT id FROM t"
+    ));
+    assert!(!no_op(&db, "DROP VIEW IF EXISTS v"));
+    assert!(no_op(&db, "DROP VIEW IF EXISTS v"));
+
+    assert!(!no_op(&db, "CREATE SCHEMA IF NOT EXISTS s"));
+    assert!(no_op(&db, "CREATE SCHEMA IF NOT EXISTS s"));
+
+    assert!(!no_op(&db, "CREATE SEQUENCE IF NOT EXISTS seq"));
+    assert!(no_op(&db, "CREATE SEQUENCE IF NOT EXISTS seq"));
+    assert!(!no_op(&db, "DROP SEQUENCE IF EXISTS seq"));
+    assert!(no_op(&db, "DROP SEQUENCE IF EXISTS seq"));
+
+    assert!(no_op(&db, "DROP FUNCTION IF EXISTS missing_fn"));
+    assert!(no_op(&db, "DROP PROCEDURE IF EXISTS missing_proc"));
+    assert!(no_op(&db, "DROP TRIGGER IF EXISTS missing_trigger ON t"));
+}
@@ -77,6 +77,91 @@ fn test_create_table_commit() {
     assert_eq!(count, 1, "Should have 1 row after commit");
 }
 
+#[test]
+fn test_create_table_with_unique_rollback() {
+    let dir = tempfile::tempdir().unwrap();
+    let dsn = format!("file://{}", dir.path().join("test.db").display());
+    {
+        let db = Database::open(&dsn).expect("Failed to create database");
+        db.execute("BEGIN", ())
+            .expect("Failed to begin transaction");
+        db.execute(
+            "CREATE TABLE accounts (
+                id INTEGER PRIMARY KEY,
+                email TEXT UNIQUE,
+                a INTEGER,
+                b INTEGER,
+                UNIQUE (a, b)
+            )",
+            (),
+        )
+        .expect("Failed to create table");
+
+        db.execute("INSERT INTO accounts VALUES (1, 'x@y', 1, 1)", ())
+            .expect("Failed to insert");
+        assert_eq!(
+            db.query("SHOW INDEXES FROM accounts", ()).unwrap().count(),
+            2
+        );
+
+        db.execute("ROLLBACK", ())
+            .expect("Failed to rollback transaction");
+
+        assert!(db.query("SELECT * FROM accounts", ()).is_err());
+        assert!(db.query("SHOW INDEXES FROM accounts", ()).is_err());
+
+        // Recreating the table without the constraints must not pick up
+        // the rolled-back indexes
+        db.execute(
+            "CREATE TABLE accounts (id INTEGER PRIMARY KEY, email TEXT, a INTEGER, b INTEGER)",
+            (),
+        )
+        .expect("Failed to recreate table");
+        assert_eq!(
+            db.query("SHOW INDEXES FROM accounts", ()).unwrap().count(),
+            0
+        );
+        db.execute(
+            "INSERT INTO accounts VALUES (1, 'x@y', 1, 1), (2, 'x@y', 1, 1)",
+            (),
+        )
+        .expect("Duplicates should be allowed without the unique indexes");
+    }
+
+    // Nothing of the rolled-back DDL comes back after recovery
+    let db = Database::open(&dsn).expect("Failed to reopen database");
+    assert_eq!(
+        db.query("SHOW INDEXES FROM accounts", ()).unwrap().count(),
+        0
+    );
+    let count: i64 = db.query_one("SELECT COUNT(*) FROM accounts", ()).unwrap();
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn test_create_table_with_unique_commit() {
+    let db = Database::open("memory://ddl_unique_commit").expect("Failed to create database");
+    db.execute("BEGIN", ())
+        .expect("Failed to begin transaction");
+    db.execute(
+        "CREATE TABLE accounts (id INTEGER PRIMARY KEY, email TEXT UNIQUE)",
+        (),
+    )
+    .expect("Failed to create table");
+    db.execute("COMMIT", ())
+        .expect("Failed to commit transaction");
+
+    assert_eq!(
+        db.query("SHOW INDEXES FROM accounts", ()).unwrap().count(),
+        1
+    );
+    db.execute("INSERT INTO accounts VALUES (1, 'x@y')", ())
+        .expect("Failed to insert");
+    assert!(db
+        .execute("INSERT INTO accounts VALUES (2, 'x@y')", ())
+        .is_err());
+}
+
 #[test]
 fn test_drop_table_rollback() {
     let db = Database::open("memory://ddl_drop_rollback").expect("Failed to create database");
@@ -0,0 +1,145 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Division By Zero Tests
+//!
+//! Tests the division by zero error and `SET div_by_zero`
+
+use oxibase::{Database, Error, Value};
+
+fn setup() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE orders (id INTEGER PRIMARY KEY, price FLOAT, quantity INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO orders VALUES (1, 10.0, 4), (2, 7.5, 0), (3, 9.0, 3)",
+        (),
+    )
+    .unwrap();
+    db
+}
+
+fn query_error(db: &Database, sql: &str) -> Error {
+    let Err(err) = db.query(sql, ()) else {
+        panic!("Expected {} to fail", sql);
+    };
+    err
+}
+
+fn column_values(db: &Database, sql: &str) -> Vec<Value> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| row.unwrap().get_value(0).cloned().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_integer_division_by_zero_is_an_error() {
+    let db = setup();
+
+    for sql in [
+        "SELECT 1 / 0",
+        "SELECT 7 % 0",
+        "SELECT id / quantity FROM orders ORDER BY id",
+        "SELECT id % quantity FROM orders",
+        "SELECT SUM(id / quantity) FROM orders",
+    ] {
+        assert!(
+            matches!(query_error(&db, sql), Error::DivisionByZero),
+            "{}",
+            sql
+        );
+    }
+
+    let err = db
+        .execute("UPDATE orders SET quantity = id / quantity", ())
+        .unwrap_err();
+    assert!(matches!(err, Error::DivisionByZero), "{:?}", err);
+    // The failed statement changed nothing
+    assert_eq!(
+        column_values(&db, "SELECT quantity FROM orders ORDER BY id"),
+        vec![Value::Integer(4), Value::Integer(0), Value::Integer(3)]
+    );
+
+    // A non-zero divisor is unaffected
+    assert_eq!(
+        column_values(
+            &db,
+            "SELECT id / quantity FROM orders WHERE quantity <> 0 ORDER BY id"
+        ),
+        vec![Value::Integer(0), Value::Integer(1)]
+    );
+}
+
+#[test]
+fn test_float_division_by_zero_is_an_error() {
+    let db = setup();
+
+    for sql in [
+        "SELECT 1.0 / 0.0",
+        "SELECT 0.0 / 0.0",
+        "SELECT 5 / 0.0",
+        "SELECT 5.5 % 0",
+        "SELECT price / quantity FROM orders",
+    ] {
+        let err = query_error(&db, sql);
+        assert!(matches!(err, Error::DivisionByZero), "{}: {:?}", sql, err);
+        assert_eq!(err.to_string(), "division by zero");
+    }
+}
+
+#[test]
+fn test_division_by_zero_null_mode() {
+    let db = setup();
+    db.execute("SET div_by_zero = 'null'", ()).unwrap();
+
+    let row: Vec<Value> = db
+        .query("SELECT 1 / 0, 7 % 0, 1.0 / 0.0, 5 / 0.0, 5.5 % 0", ())
+        .unwrap()
+        .map(|row| row.unwrap())
+        .next()
+        .map(|row| {
+            (0..row.len())
+                .map(|i| row.get_value(i).cloned().unwrap())
+                .collect()
+        })
+        .unwrap();
+    assert!(row.iter().all(|v| v.is_null()), "{:?}", row);
+
+    assert_eq!(
+        column_values(&db, "SELECT price / quantity FROM orders ORDER BY id"),
+        vec![Value::Float(2.5), Value::null_unknown(), Value::Float(3.0)]
+    );
+    assert_eq!(
+        column_values(&db, "SELECT id / quantity FROM orders ORDER BY id"),
+        vec![Value::Integer(0), Value::null_unknown(), Value::Integer(1)]
+    );
+    // NULL is skipped by the aggregate
+    assert_eq!(
+        column_values(&db, "SELECT SUM(price / quantity) FROM orders"),
+        vec![Value::Float(5.5)]
+    );
+
+    // Back to the default
+    db.execute("SET div_by_zero = error", ()).unwrap();
+    assert!(matches!(
+        query_error(&db, "SELECT 1 / 0"),
+        Error::DivisionByZero
+    ));
+
+    assert!(db.execute("SET div_by_zero = 'maybe'", ()).is_err());
+}
@@ -0,0 +1,163 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! DROP TABLE / DROP VIEW with RESTRICT and CASCADE
+//!
+//! RESTRICT (the default) refuses to drop an object that views or foreign
+//! keys depend on; CASCADE drops the dependent views and foreign keys too.
+
+use oxibase::parser::parse_sql;
+use oxibase::{Database, Error};
+
+fn setup(db: &Database) {
+    db.execute("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)", ())
+        .unwrap();
+    db.execute("INSERT INTO users VALUES (1, 'a'), (2, 'b')", ())
+        .unwrap();
+    db.execute("CREATE VIEW user_names AS SELECT name FROM users", ())
+        .unwrap();
+    db.execute(
+        "CREATE VIEW first_names AS SELECT name FROM user_names WHERE name = 'a'",
+        (),
+    )
+    .unwrap();
+}
+
+fn view_exists(db: &Database, name: &str) -> bool {
+    db.query(&format!("SELECT * FROM {}", name), ()).is_ok()
+}
+
+#[test]
+fn test_drop_table_restrict_with_dependent_view() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    for sql in ["DROP TABLE users RESTRICT", "DROP TABLE users"] {
+        let err = db.execute(sql, ()).unwrap_err();
+        assert!(
+            matches!(err, Error::DependentObjectsExist { .. }),
+            "{}: {:?}",
+            sql,
+            err
+        );
+        assert!(err.to_string().contains("view user_names"), "{}", err);
+    }
+
+    let count: i64 = db.query_one("SELECT COUNT(*) FROM users", ()).unwrap();
+    assert_eq!(count, 2);
+    assert!(view_exists(&db, "first_names"));
+}
+
+#[test]
+fn test_drop_table_cascade_drops_views() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    db.execute("DROP TABLE users CASCADE", ()).unwrap();
+
+    assert!(db.query("SELECT * FROM users", ()).is_err());
+    assert!(!view_exists(&db, "user_names"));
+    assert!(!view_exists(&db, "first_names"));
+
+    // The names are free again
+    db.execute("CREATE TABLE users (id INTEGER PRIMARY KEY)", ())
+        .unwrap();
+    db.execute("CREATE VIEW user_names AS SELECT id FROM users", ())
+        .unwrap();
+}
+
+#[test]
+fn test_drop_table_with_foreign_key() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE users (id INTEGER PRIMARY KEY)", ())
+        .unwrap();
+    db.execute("CREATE TABLE orders (id INTEGER PRIMARY KEY, user_id INTEGER, FOREIGN KEY (user_id) REFERENCES users(id))", ()).unwrap();
+    db.execute("INSERT INTO users VALUES (1)", ()).unwrap();
+    db.execute("INSERT INTO orders VALUES (1, 1)", ()).unwrap();
+
+    let err = db.execute("DROP TABLE users RESTRICT", ()).unwrap_err();
+    assert!(err.to_string().contains("foreign key on orders"), "{}", err);
+
+    db.execute("DROP TABLE users CASCADE", ()).unwrap();
+
+    // The referencing table keeps its rows but no longer checks the key
+    db.execute("INSERT INTO orders VALUES (2, 99)", ()).unwrap();
+    let count: i64 = db.query_one("SELECT COUNT(*) FROM orders", ()).unwrap();
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn test_drop_table_without_dependents() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE a (id INTEGER PRIMARY KEY)", ())
+        .unwrap();
+    db.execute("CREATE TABLE b (id INTEGER PRIMARY KEY)", ())
+        .unwrap();
+    db.execute("CREATE TABLE c (id INTEGER PRIMARY KEY)", ())
+        .unwrap();
+
+    db.execute("DROP TABLE a RESTRICT", ()).unwrap();
+    db.execute("DROP TABLE b CASCADE", ()).unwrap();
+    db.execute("DROP TABLE c", ()).unwrap();
+    db.execute("DROP TABLE IF EXISTS c RESTRICT", ()).unwrap();
+    db.execute("DROP TABLE IF EXISTS c CASCADE", ()).unwrap();
+}
+
+#[test]
+fn test_drop_view_restrict_and_cascade() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let err = db.execute("DROP VIEW user_names", ()).unwrap_err();
+    assert!(err.to_string().contains("view first_names"), "{}", err);
+    let err = db.execute("DROP VIEW user_names RESTRICT", ()).unwrap_err();
+    assert!(
+        matches!(err, Error::DependentObjectsExist { .. }),
+        "{:?}",
+        err
+    );
+    assert!(view_exists(&db, "user_names"));
+
+    // A view nothing depends on drops under RESTRICT
+    db.execute("DROP VIEW first_names RESTRICT", ()).unwrap();
+    db.execute(
+        "CREATE VIEW first_names AS SELECT name FROM user_names WHERE name = 'a'",
+        (),
+    )
+    .unwrap();
+
+    db.execute("DROP VIEW user_names CASCADE", ()).unwrap();
+    assert!(!view_exists(&db, "user_names"));
+    assert!(!view_exists(&db, "first_names"));
+    db.execute("DROP VIEW IF EXISTS user_names CASCADE", ())
+        .unwrap();
+
+    // The table itself is untouched
+    let count: i64 = db.query_one("SELECT COUNT(*) FROM users", ()).unwrap();
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn test_drop_behavior_round_trips_through_display() {
+    for sql in [
+        "DROP TABLE t CASCADE",
+        "DROP TABLE IF EXISTS t CASCADE",
+        "DROP VIEW v CASCADE",
+    ] {
+        let stmts = parse_sql(sql).unwrap();
+        assert_eq!(stmts[0].to_string(), sql);
+    }
+    let stmts = parse_sql("DROP TABLE t RESTRICT").unwrap();
+    assert_eq!(stmts[0].to_string(), "DROP TABLE t");
+}
@@ -0,0 +1,120 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SHOW ENGINE STATUS
+//!
+//! Version-store and transaction diagnostics for tracking down stuck
+//! transactions.
+
+use oxibase::{Database, Value};
+use tempfile::tempdir;
+
+/// Value of one metric, None if the row is missing
+fn metric(db: &Database, section: &str, object: Option<&str>, name: &str) -> Option<Value> {
+    for row in db.query("SHOW ENGINE STATUS", ()).unwrap() {
+        let row = row.unwrap();
+        let row_section: String = row.get(0).unwrap();
+        let row_object: Option<String> = row.get(1).unwrap();
+        let row_metric: String = row.get(2).unwrap();
+        if row_section == section && row_object.as_deref() == object && row_metric == name {
+            return Some(row.get_value(3).unwrap().clone());
+        }
+    }
+    None
+}
+
+fn integer(db: &Database, section: &str, object: Option<&str>, name: &str) -> i64 {
+    match metric(db, section, object, name) {
+        Some(Value::Integer(value)) => value,
+        other => panic!("{} {:?} {}: {:?}", section, object, name, other),
+    }
+}
+
+#[test]
+fn test_engine_status_after_writes() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE items (id INTEGER PRIMARY KEY, qty INTEGER)",
+        (),
+    )
+    .unwrap();
+    for id in 1..=10 {
+        db.execute("INSERT INTO items VALUES ($1, 0)", (id,))
+            .unwrap();
+    }
+    db.execute("UPDATE items SET qty = 1 WHERE id <= 4", ())
+        .unwrap();
+
+    assert!(db.query("SHOW ENGINE STATUS", ()).unwrap().count() > 0);
+    assert_eq!(integer(&db, "table", Some("items"), "live_versions"), 10);
+    assert_eq!(integer(&db, "table", Some("items"), "dead_versions"), 4);
+    assert_eq!(integer(&db, "table", Some("items"), "uncommitted_rows"), 0);
+    assert!(integer(&db, "engine", None, "current_sequence") > 0);
+    // In-memory databases have no WAL
+    assert!(metric(&db, "wal", None, "size_bytes").unwrap().is_null());
+}
+
+#[test]
+fn test_engine_status_shows_open_transaction() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE items (id INTEGER PRIMARY KEY, qty INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute("INSERT INTO items VALUES (1, 0), (2, 0), (3, 0)", ())
+        .unwrap();
+
+    let mut tx = db.begin().unwrap();
+    tx.execute("UPDATE items SET qty = 5 WHERE id <= 2", ())
+        .unwrap();
+    let txn = tx.id().to_string();
+
+    // The open transaction is the oldest one and holds both rows
+    assert!(integer(&db, "engine", None, "active_transactions") >= 1);
+    assert_eq!(
+        integer(&db, "engine", None, "oldest_active_transaction"),
+        tx.id()
+    );
+    assert_eq!(
+        integer(&db, "transaction", Some(&txn), "uncommitted_rows"),
+        2
+    );
+    assert_eq!(integer(&db, "table", Some("items"), "uncommitted_rows"), 2);
+
+    // ...which is why another writer fails on them
+    assert!(db
+        .execute("UPDATE items SET qty = 9 WHERE id = 1", ())
+        .is_err());
+
+    tx.commit().unwrap();
+    assert_eq!(integer(&db, "table", Some("items"), "uncommitted_rows"), 0);
+    assert!(metric(&db, "transaction", Some(&txn), "uncommitted_rows").is_none());
+}
+
+#[test]
+fn test_engine_status_reports_wal() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&format!("file://{}", dir.path().join("db").display())).unwrap();
+    db.execute(
+        "CREATE TABLE items (id INTEGER PRIMARY KEY, qty INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute("INSERT INTO items VALUES (1, 0), (2, 0)", ())
+        .unwrap();
+
+    assert!(integer(&db, "wal", None, "size_bytes") > 0);
+    assert!(integer(&db, "wal", None, "lsn") > 0);
+}
@@ -0,0 +1,81 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Error Verbosity Tests
+//!
+//! Tests SET error_verbosity adding statement context to errors
+
+use oxibase::{Database, Error};
+
+#[test]
+fn test_verbose_errors_include_statement() {
+    let db = Database::open("memory://error_verbosity").expect("Failed to create database");
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY)", ())
+        .unwrap();
+    db.execute("INSERT INTO t VALUES (1)", ()).unwrap();
+
+    // Terse by default
+    let err = db.execute("INSERT INTO t VALUES (1)", ()).unwrap_err();
+    assert!(!err.to_string().contains("statement:"), "{}", err);
+
+    db.execute("SET error_verbosity = verbose", ()).unwrap();
+
+    let err = db.execute("INSERT INTO t VALUES (1)", ()).unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("statement: INSERT INTO t"), "{}", message);
+    assert!(err.is_constraint_violation());
+    assert!(matches!(err.root(), Error::PrimaryKeyConstraint { .. }));
+
+    // Parse errors point at the failing position
+    let err = db.execute("SELECT * FROMM t", ()).unwrap_err();
+    let message = err.to_string();
+    assert!(
+        message.contains("statement: SELECT * FROMM t"),
+        "{}",
+        message
+    );
+    assert!(
+        message.contains("position: line 1, column 10"),
+        "{}",
+        message
+    );
+    assert!(message.contains("\n             ^"), "{}", message);
+
+    db.execute("SET error_verbosity = 'terse'", ()).unwrap();
+    let err = db.query("SELECT * FROM missing", ()).err().unwrap();
+    assert!(!err.to_string().contains("statement:"), "{}", err);
+
+    assert!(db.execute("SET error_verbosity = 'loud'", ()).is_err());
+}
+
+#[test]
+fn test_verbose_errors_show_calling_statement() {
+    let db = Database::open("memory://error_verbosity_nested").expect("Failed to create database");
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY)", ())
+        .unwrap();
+    db.execute(
+        "CREATE PROCEDURE add_row(v INTEGER) LANGUAGE plsql AS '
+        BEGIN
+            INSERT INTO t VALUES (v);
+            INSERT INTO t VALUES (v);
+        END;'",
+        (),
+    )
+    .unwrap();
+    db.execute("SET error_verbosity = 'verbose'", ()).unwrap();
+
+    let message = db.execute("CALL add_row(1)", ()).unwrap_err().to_string();
+    assert!(message.contains("statement: INSERT INTO t"), "{}", message);
+    assert!(message.contains("called from: CALL"), "{}", message);
+}
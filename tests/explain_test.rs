@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -360,3 +361,190 @@ fn test_explain_join() {
     let plan = lines.join("\n");
     assert!(plan.contains("Join"), "Expected Join, got:\n{}", plan);
 }
+
+#[test]
+fn test_explain_insert_lists_indexes_and_constraints() {
+    let db = setup_test_db();
+    db.execute("CREATE UNIQUE INDEX idx_amount ON orders(amount)", ())
+        .expect("Failed to create amount index");
+
+    let lines = get_plan_output(
+        &db,
+        "EXPLAIN INSERT INTO orders VALUES (11, 1, 'new', 5), (12, 2, 'new', 6)",
+    );
+    let plan = lines.join("\n");
+
+    assert!(
+        lines[0].starts_with("INSERT INTO orders (cost="),
+        "Expected cost estimate, got:\n{}",
+        plan
+    );
+    assert!(
+        lines[0].ends_with("rows=2)"),
+        "Expected rows=2, got:\n{}",
+        plan
+    );
+    assert!(
+        plan.contains("Primary Key (id)"),
+        "Expected primary key check, got:\n{}",
+        plan
+    );
+    for index in ["idx_amount", "idx_status", "idx_user_id"] {
+        assert!(
+            lines
+                .iter()
+                .any(|l| l.trim_start().starts_with(&format!("-> {} ", index))),
+            "Expected {} in index maintenance, got:\n{}",
+            index,
+            plan
+        );
+    }
+    assert!(plan.contains("UNIQUE on (amount)"), "got:\n{}", plan);
+
+    // EXPLAIN does not run the insert
+    let count: i64 = db.query_one("SELECT COUNT(*) FROM orders", ()).unwrap();
+    assert_eq!(count, 10);
+}
+
+#[test]
+fn test_explain_update_delete_maintenance() {
+    let db = setup_test_db();
+
+    // UPDATE only maintains indexes on the assigned columns
+    let lines = get_plan_output(&db, "EXPLAIN UPDATE orders SET status = 'x' WHERE id = 1");
+    let plan = lines.join("\n");
+    assert!(lines[0].ends_with("rows=1)"), "got:\n{}", plan);
+    assert!(plan.contains("-> idx_status "), "got:\n{}", plan);
+    assert!(!plan.contains("idx_user_id"), "got:\n{}", plan);
+    assert!(!plan.contains("Primary Key"), "got:\n{}", plan);
+
+    // DELETE removes entries from every index
+    let lines = get_plan_output(&db, "EXPLAIN DELETE FROM orders");
+    let plan = lines.join("\n");
+    assert!(plan.contains("-> idx_status "), "got:\n{}", plan);
+    assert!(plan.contains("-> idx_user_id "), "got:\n{}", plan);
+    assert!(!plan.contains("Constraint Checks"), "got:\n{}", plan);
+}
+
+#[test]
+fn test_explain_index_hints() {
+    let db = setup_test_db();
+    let where_clause = "WHERE user_id = 1 AND status = 'active'";
+
+    // INDEX hint restricts the planner to the named index
+    let plan = get_plan_output(
+        &db,
+        &format!(
+            "EXPLAIN SELECT /*+ INDEX(orders idx_status) */ * FROM orders {}",
+            where_clause
+        ),
+    )
+    .join("\n");
+    assert!(
+        plan.contains("Index Scan using idx_status"),
+        "Expected idx_status, got:\n{}",
+        plan
+    );
+    assert!(
+        !plan.contains("idx_user_id"),
+        "Unexpected idx_user_id:\n{}",
+        plan
+    );
+    assert!(plan.contains("Hint: INDEX(orders idx_status)"), "{}", plan);
+
+    // FORCE INDEX on the table works the same way, also through an alias
+    let plan = get_plan_output(
+        &db,
+        &format!(
+            "EXPLAIN SELECT * FROM orders o FORCE INDEX (idx_user_id) {}",
+            where_clause
+        ),
+    )
+    .join("\n");
+    assert!(
+        plan.contains("Index Scan using idx_user_id"),
+        "Expected idx_user_id, got:\n{}",
+        plan
+    );
+
+    // NO_INDEX without index names falls back to a sequential scan
+    let plan = get_plan_output(
+        &db,
+        "EXPLAIN SELECT /*+ NO_INDEX(orders) */ * FROM orders WHERE user_id = 1",
+    )
+    .join("\n");
+    assert!(
+        plan.contains("Seq Scan on orders"),
+        "Expected Seq Scan:\n{}",
+        plan
+    );
+
+    // Hints naming unknown indexes or tables are ignored with a note
+    let plan = get_plan_output(
+        &db,
+        "EXPLAIN SELECT /*+ INDEX(orders idx_missing) LEADING(nope) FOO */ * FROM orders WHERE user_id = 1",
+    )
+    .join("\n");
+    assert!(
+        plan.contains("idx_user_id"),
+        "Expected default index:\n{}",
+        plan
+    );
+    assert!(
+        plan.contains("INDEX(orders idx_missing) (ignored: no index idx_missing on orders)"),
+        "{}",
+        plan
+    );
+    assert!(plan.contains("(ignored: unknown hint)"), "{}", plan);
+}
+
+#[test]
+fn test_join_method_hints() {
+    let db = setup_test_db();
+    db.execute("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)", ())
+        .unwrap();
+    db.execute(
+        "INSERT INTO users VALUES (1, 'ann'), (2, 'bob'), (3, 'cid')",
+        (),
+    )
+    .unwrap();
+
+    let query = |hint: &str| {
+        let sql = format!(
+            "SELECT {} u.name, o.id FROM orders o JOIN users u ON o.user_id = u.id ORDER BY o.id",
+            hint
+        );
+        let rows: Vec<(String, i64)> = db
+            .query(&sql, ())
+            .unwrap()
+            .map(|r| {
+                let r = r.unwrap();
+                (r.get(0).unwrap(), r.get(1).unwrap())
+            })
+            .collect();
+        rows
+    };
+
+    // Every method and join order gives the same answer
+    let expected = query("");
+    assert_eq!(expected.len(), 10);
+    for hint in [
+        "/*+ HASH_JOIN(o u) */",
+        "/*+ MERGE_JOIN */",
+        "/*+ NL_JOIN */",
+        "/*+ LEADING(u o) */",
+    ] {
+        assert_eq!(query(hint), expected, "hint {}", hint);
+    }
+
+    let plan = get_plan_output(
+        &db,
+        "EXPLAIN SELECT /*+ MERGE_JOIN */ * FROM orders o JOIN users u ON o.user_id = u.id",
+    )
+    .join("\n");
+    assert!(
+        plan.contains("Merge Join"),
+        "Expected Merge Join:\n{}",
+        plan
+    );
+}
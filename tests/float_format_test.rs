@@ -0,0 +1,133 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for NaN/Infinity floats and SET float_display_precision
+
+use oxibase::{Database, Value};
+
+fn query_values(db: &Database, sql: &str) -> Vec<Vec<Value>> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (0..row.len())
+                .map(|i| row.get_value(i).cloned().unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+fn query_strings(db: &Database, sql: &str) -> Vec<String> {
+    query_values(db, sql)
+        .into_iter()
+        .map(|row| row[0].to_string())
+        .collect()
+}
+
+#[test]
+fn test_float_division_by_zero() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("SET div_by_zero = 'null'", ()).unwrap();
+
+    // Division by zero is NULL for floats as it is for integers, 0.0 / 0.0 included
+    let row = &query_values(
+        &db,
+        "SELECT 1.0 / 0.0, 0.0 / 0.0, 5 / 0.0, 5.5 % 0.0, 1 / 0",
+    )[0];
+    assert!(row.iter().all(|v| v.is_null()), "{:?}", row);
+
+    // So are other results that are undefined for finite inputs
+    let row = &query_values(&db, "SELECT SQRT(-1), LN(0), POWER(-8, 0.5)")[0];
+    assert!(row.iter().all(|v| v.is_null()), "{:?}", row);
+}
+
+#[test]
+fn test_nan_and_infinity_storage() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, f FLOAT)", ())
+        .unwrap();
+    db.execute(
+        "INSERT INTO t VALUES (1, 'NaN'), (2, 'Infinity'), (3, '-Infinity'), (4, 1.5)",
+        (),
+    )
+    .unwrap();
+
+    // Stored as-is, rendered as NaN / Infinity / -Infinity; NaN sorts last
+    assert_eq!(
+        query_strings(&db, "SELECT f FROM t ORDER BY f"),
+        vec!["-Infinity", "1.5", "Infinity", "NaN"]
+    );
+    assert_eq!(
+        query_strings(&db, "SELECT CAST(f AS TEXT) FROM t WHERE id = 2"),
+        vec!["Infinity"]
+    );
+    let nan: f64 = db.query_one("SELECT f FROM t WHERE id = 1", ()).unwrap();
+    assert!(nan.is_nan());
+
+    // NaN equals itself, so it can be looked up
+    assert_eq!(
+        query_strings(&db, "SELECT id FROM t WHERE f = CAST('nan' AS FLOAT)"),
+        vec!["1"]
+    );
+
+    // Arithmetic on NaN / Infinity follows IEEE 754
+    assert_eq!(
+        query_strings(&db, "SELECT f + 1 FROM t WHERE id IN (1, 2, 3) ORDER BY id"),
+        vec!["NaN", "Infinity", "-Infinity"]
+    );
+    assert_eq!(
+        query_strings(
+            &db,
+            "SELECT a.f - b.f FROM t a, t b WHERE a.id = 2 AND b.id = 2"
+        ),
+        vec!["NaN"]
+    );
+}
+
+#[test]
+fn test_float_display_precision() {
+    let db = Database::open_in_memory().unwrap();
+    let sql = "SELECT 12.34567, 29.99, 1.0 / 3, 42, 'x'";
+
+    db.execute("SET float_display_precision = 2", ()).unwrap();
+    let row = &query_values(&db, sql)[0];
+    assert_eq!(row[0], Value::Float(12.35));
+    assert_eq!(row[1], Value::Float(29.99));
+    assert_eq!(row[2], Value::Float(0.33));
+    assert_eq!(row[3], Value::Integer(42));
+    assert_eq!(row[4], Value::text("x"));
+
+    db.execute("SET float_display_precision = 0", ()).unwrap();
+    assert_eq!(query_strings(&db, "SELECT 2.5 * 3"), vec!["8"]);
+
+    // Only results are rounded, not values computed or stored along the way
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, f FLOAT)", ())
+        .unwrap();
+    db.execute("INSERT INTO t SELECT 1, 1.0 / 3", ()).unwrap();
+    assert_eq!(
+        query_strings(&db, "SELECT id FROM t WHERE f * 3 > 0.9"),
+        vec!["1"]
+    );
+
+    db.execute("SET float_display_precision = 'default'", ())
+        .unwrap();
+    let f: f64 = db.query_one("SELECT f FROM t", ()).unwrap();
+    assert_eq!(f, 1.0 / 3.0);
+    assert_eq!(query_strings(&db, "SELECT 12.34567"), vec!["12.34567"]);
+
+    assert!(db.execute("SET float_display_precision = 16", ()).is_err());
+    assert!(db
+        .execute("SET float_display_precision = 'two'", ())
+        .is_err());
+}
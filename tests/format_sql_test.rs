@@ -0,0 +1,139 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SQL pretty-printer
+//!
+//! `format_sql` re-emits parsed SQL with one clause per line and indented
+//! subqueries, and formatting its own output changes nothing.
+
+use oxibase::format_sql;
+use oxibase::parser::parse_sql;
+
+/// Canonical single-line rendering of each parsed statement
+fn canonical(sql: &str) -> Vec<String> {
+    parse_sql(sql)
+        .unwrap()
+        .iter()
+        .map(|stmt| stmt.to_string())
+        .collect()
+}
+
+/// Format `sql`, checking the result is stable and parses to the same statements
+fn format_stable(sql: &str) -> String {
+    let formatted = format_sql(sql);
+    assert_eq!(format_sql(&formatted), formatted, "not idempotent");
+    assert_eq!(canonical(&formatted), canonical(sql));
+    formatted
+}
+
+#[test]
+fn test_format_select() {
+    assert_eq!(
+        format_stable(
+            "select id, name as n from users where age >= 18 and active \
+             group by id, name having count(*) > 1 order by name desc limit 10 offset 20"
+        ),
+        "SELECT id, name AS n\n\
+         FROM users\n\
+         WHERE ((age >= 18) AND active)\n\
+         GROUP BY id, name\n\
+         HAVING (COUNT(*) > 1)\n\
+         ORDER BY name DESC\n\
+         LIMIT 10\n\
+         OFFSET 20"
+    );
+}
+
+#[test]
+fn test_format_join() {
+    assert_eq!(
+        format_stable(
+            "SELECT o.id, c.name FROM orders o JOIN customers c ON o.customer_id = c.id \
+             LEFT OUTER JOIN regions r ON c.region_id = r.id CROSS JOIN flags"
+        ),
+        "SELECT o.id, c.name\n\
+         FROM orders AS o\n\
+         INNER JOIN customers AS c ON (o.customer_id = c.id)\n\
+         LEFT JOIN regions AS r ON (c.region_id = r.id)\n\
+         CROSS JOIN flags"
+    );
+}
+
+#[test]
+fn test_format_subquery() {
+    assert_eq!(
+        format_stable(
+            "select * from (select id from t where x in (select x from u)) as s \
+             where exists (select 1 from v where v.id = s.id)"
+        ),
+        "SELECT *\n\
+         FROM (\n    \
+             SELECT id\n    \
+             FROM t\n    \
+             WHERE x IN (\n        \
+                 SELECT x\n        \
+                 FROM u\n    \
+             )\n\
+         ) AS s\n\
+         WHERE EXISTS (\n    \
+             SELECT 1\n    \
+             FROM v\n    \
+             WHERE (v.id = s.id)\n\
+         )"
+    );
+}
+
+#[test]
+fn test_format_cte_and_set_operation() {
+    assert_eq!(
+        format_stable("with c as (select 1 as x) select x from c union all select 2"),
+        "WITH c AS (\n    \
+             SELECT 1 AS x\n\
+         )\n\
+         SELECT x\n\
+         FROM c\n\
+         UNION ALL\n\
+         SELECT 2"
+    );
+}
+
+#[test]
+fn test_format_keeps_function_keywords_inline() {
+    assert_eq!(
+        format_stable(
+            "select left(name, 2), a is distinct from b, \
+             sum(v) over (partition by g order by v) from t"
+        ),
+        "SELECT LEFT(name, 2), (a IS DISTINCT FROM b), \
+         SUM(v) OVER (PARTITION BY g ORDER BY v ASC)\n\
+         FROM t"
+    );
+}
+
+#[test]
+fn test_format_dml_and_multiple_statements() {
+    assert_eq!(
+        format_stable("update t set a = a + 1 where b = 'x  y'; delete from t where a > 5"),
+        "UPDATE t\n\
+         SET a = (a + 1)\n\
+         WHERE (b = 'x  y');\n\
+         DELETE FROM t\n\
+         WHERE (a > 5)"
+    );
+}
+
+#[test]
+fn test_format_invalid_sql_is_unchanged() {
+    assert_eq!(format_sql("SELEC nonsense FROM"), "SELEC nonsense FROM");
+}
@@ -109,6 +109,78 @@ fn test_generate_series_timestamp_hours() {
     assert_eq!(values.len(), 4); // 00:00, 02:00, 04:00, 06:00
 }
 
+#[test]
+fn test_generate_series_interval_step() {
+    let db = create_test_db("gs_interval");
+    let values = collect_string(
+        &db,
+        "SELECT * FROM generate_series(CAST('2024-01-01' AS TIMESTAMP), \
+         CAST('2024-01-02' AS TIMESTAMP), INTERVAL '12 hours')",
+    );
+    assert_eq!(
+        values,
+        vec![
+            "2024-01-01T00:00:00Z",
+            "2024-01-01T12:00:00Z",
+            "2024-01-02T00:00:00Z"
+        ]
+    );
+}
+
+#[test]
+fn test_generate_series_timestamp_descending() {
+    let db = create_test_db("gs_ts_desc");
+    let values = collect_string(
+        &db,
+        "SELECT * FROM generate_series('2024-01-10', '2024-01-01', INTERVAL '-3 days')",
+    );
+    assert_eq!(values.len(), 4); // 10th, 7th, 4th, 1st
+    assert!(values[0].starts_with("2024-01-10"));
+    assert!(values[3].starts_with("2024-01-01"));
+
+    // Step pointing away from stop yields no rows
+    let values = collect_string(
+        &db,
+        "SELECT * FROM generate_series('2024-01-10', '2024-01-01', INTERVAL '1 day')",
+    );
+    assert!(values.is_empty());
+}
+
+#[test]
+fn test_generate_series_calendar_left_join() {
+    let db = create_test_db("gs_calendar");
+    db.execute(
+        "CREATE TABLE sales (id INTEGER PRIMARY KEY, day TIMESTAMP, amount INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO sales VALUES (1, '2024-01-02', 10), (2, '2024-01-02', 5), (3, '2024-01-04', 7)",
+        (),
+    )
+    .unwrap();
+
+    // Days without sales still appear, with a zero total
+    let result = db
+        .query(
+            "SELECT g.d, COALESCE(SUM(s.amount), 0) \
+             FROM generate_series('2024-01-01', '2024-01-05', INTERVAL '1 day') AS g(d) \
+             LEFT JOIN sales s ON s.day = g.d GROUP BY g.d ORDER BY g.d",
+            (),
+        )
+        .unwrap();
+    let mut rows: Vec<(String, i64)> = Vec::new();
+    for row in result {
+        let row = row.unwrap();
+        rows.push((row.get(0).unwrap(), row.get(1).unwrap()));
+    }
+    assert_eq!(rows.len(), 5);
+    assert!(rows[0].0.starts_with("2024-01-01"));
+    assert!(rows[4].0.starts_with("2024-01-05"));
+    let totals: Vec<i64> = rows.iter().map(|(_, total)| *total).collect();
+    assert_eq!(totals, vec![0, 15, 0, 7, 0]);
+}
+
 // ============================================================================
 // Scalar (SELECT without FROM) Tests
 // ============================================================================
@@ -147,6 +219,15 @@ fn test_generate_series_auto_descending() {
     assert_eq!(values, vec![5, 4, 3, 2, 1]);
 }
 
+#[test]
+fn test_generate_series_descending_step_not_aligned() {
+    let db = create_test_db("gs_desc_unaligned");
+    let values = collect_i64(&db, "SELECT * FROM generate_series(10, 1, -3)");
+    assert_eq!(values, vec![10, 7, 4, 1]);
+    let values = collect_i64(&db, "SELECT * FROM generate_series(10, 2, -3)");
+    assert_eq!(values, vec![10, 7, 4]);
+}
+
 #[test]
 fn test_generate_series_zero_step_error() {
     let db = create_test_db("gs_err_zero");
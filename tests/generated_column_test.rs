@@ -0,0 +1,179 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! JSON Default and Generated Column Tests
+//!
+//! Tests validated JSON column defaults and GENERATED ALWAYS AS columns
+
+use oxibase::Database;
+use tempfile::tempdir;
+
+#[test]
+fn test_json_default_applied_on_insert() {
+    let db = Database::open("memory://json_default").expect("Failed to create database");
+
+    db.execute(
+        r#"CREATE TABLE settings (id INTEGER PRIMARY KEY, config JSON DEFAULT '{"enabled": true}')"#,
+        (),
+    )
+    .expect("Failed to create table");
+    db.execute("INSERT INTO settings (id) VALUES (1)", ())
+        .expect("Failed to insert");
+
+    let enabled: bool = db
+        .query_one(
+            "SELECT JSON_EXTRACT(config, '$.enabled') FROM settings WHERE id = 1",
+            (),
+        )
+        .expect("Failed to query");
+    assert!(enabled);
+}
+
+#[test]
+fn test_invalid_json_default_rejected() {
+    let db = Database::open("memory://json_default_invalid").expect("Failed to create database");
+
+    let err = db
+        .execute(
+            "CREATE TABLE settings (id INTEGER PRIMARY KEY, config JSON DEFAULT '{enabled: true')",
+            (),
+        )
+        .expect_err("Invalid JSON default should be rejected");
+    assert!(err.to_string().contains("invalid JSON in DEFAULT"));
+
+    db.execute("CREATE TABLE settings (id INTEGER PRIMARY KEY)", ())
+        .expect("Failed to create table");
+    let err = db
+        .execute(
+            "ALTER TABLE settings ADD COLUMN config JSON DEFAULT 'nope'",
+            (),
+        )
+        .expect_err("Invalid JSON default should be rejected on ADD COLUMN");
+    assert!(err.to_string().contains("invalid JSON in DEFAULT"));
+}
+
+#[test]
+fn test_generated_json_column() {
+    let db = Database::open("memory://generated_json").expect("Failed to create database");
+
+    db.execute(
+        "CREATE TABLE users (
+            id INTEGER PRIMARY KEY,
+            name TEXT,
+            doc JSON GENERATED ALWAYS AS (JSON_OBJECT('id', id, 'name', name)) STORED
+        )",
+        (),
+    )
+    .expect("Failed to create table");
+    db.execute("INSERT INTO users (id, name) VALUES (1, 'alice')", ())
+        .expect("Failed to insert");
+    db.execute("INSERT INTO users VALUES (2, 'bob', DEFAULT)", ())
+        .expect("Failed to insert with DEFAULT");
+
+    let name: String = db
+        .query_one(
+            "SELECT JSON_EXTRACT(doc, '$.name') FROM users WHERE id = 2",
+            (),
+        )
+        .expect("Failed to query");
+    assert_eq!(name, "bob");
+
+    // UPDATE recomputes the generated value from the new row
+    db.execute("UPDATE users SET name = 'alicia' WHERE id = 1", ())
+        .expect("Failed to update");
+    let name: String = db
+        .query_one(
+            "SELECT JSON_EXTRACT(doc, '$.name') FROM users WHERE id = 1",
+            (),
+        )
+        .expect("Failed to query");
+    assert_eq!(name, "alicia");
+
+    let is_generated: String = db
+        .query_one(
+            "SELECT is_generated FROM information_schema.columns
+             WHERE table_name = 'users' AND column_name = 'doc'",
+            (),
+        )
+        .expect("Failed to query information_schema");
+    assert_eq!(is_generated, "ALWAYS");
+}
+
+#[test]
+fn test_generated_column_write_rejected() {
+    let db = Database::open("memory://generated_write").expect("Failed to create database");
+
+    db.execute(
+        "CREATE TABLE t (a INTEGER, b INTEGER GENERATED ALWAYS AS (a * 2))",
+        (),
+    )
+    .expect("Failed to create table");
+
+    let err = db
+        .execute("INSERT INTO t (a, b) VALUES (1, 5)", ())
+        .expect_err("Writing a generated column should fail");
+    assert!(err.to_string().contains("generated column 'b'"));
+
+    db.execute("INSERT INTO t (a) VALUES (3)", ())
+        .expect("Failed to insert");
+    let err = db
+        .execute("UPDATE t SET b = 1", ())
+        .expect_err("Updating a generated column should fail");
+    assert!(err.to_string().contains("generated column 'b'"));
+
+    let b: i64 = db
+        .query_one("SELECT b FROM t", ())
+        .expect("Failed to query");
+    assert_eq!(b, 6);
+}
+
+#[test]
+fn test_invalid_generation_expressions_rejected() {
+    let db = Database::open("memory://generated_invalid").expect("Failed to create database");
+
+    for sql in [
+        "CREATE TABLE t1 (a INTEGER, b INTEGER GENERATED ALWAYS AS (missing + 1))",
+        "CREATE TABLE t2 (a INTEGER, b INTEGER GENERATED ALWAYS AS (a), c INTEGER GENERATED ALWAYS AS (b))",
+        "CREATE TABLE t3 (a INTEGER, b INTEGER GENERATED ALWAYS AS (SUM(a)))",
+        "CREATE TABLE t4 (a INTEGER, b INTEGER DEFAULT 1 GENERATED ALWAYS AS (a))",
+    ] {
+        assert!(db.execute(sql, ()).is_err(), "expected error for: {}", sql);
+    }
+}
+
+#[test]
+fn test_generated_column_survives_reopen() {
+    let dir = tempdir().expect("Failed to create temp dir");
+    let dsn = format!("file://{}", dir.path().display());
+
+    {
+        let db = Database::open(&dsn).expect("Failed to open database");
+        db.execute(
+            "CREATE TABLE t (a INTEGER, b JSON GENERATED ALWAYS AS (JSON_OBJECT('a', a)))",
+            (),
+        )
+        .expect("Failed to create table");
+        db.execute("INSERT INTO t (a) VALUES (1)", ())
+            .expect("Failed to insert");
+        db.close().expect("Failed to close database");
+    }
+
+    let db = Database::open(&dsn).expect("Failed to reopen database");
+    db.execute("INSERT INTO t (a) VALUES (2)", ())
+        .expect("Failed to insert after reopen");
+    let a: i64 = db
+        .query_one("SELECT JSON_EXTRACT(b, '$.a') FROM t WHERE a = 2", ())
+        .expect("Failed to query");
+    assert_eq!(a, 2);
+}
@@ -0,0 +1,108 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Double-quote and backtick identifier quoting
+//!
+//! Both quote styles name the same identifier by default. Under
+//! `SET identifier_quote = 'backtick'` double quotes delimit strings instead.
+
+use oxibase::parser::{parse_sql, IdentifierQuote, Parser};
+use oxibase::Database;
+
+fn setup() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE \"order items\" (id INTEGER PRIMARY KEY, \"unit price\" FLOAT)",
+        (),
+    )
+    .unwrap();
+    db.execute("INSERT INTO `order items` VALUES (1, 2.5), (2, 4.0)", ())
+        .unwrap();
+    db
+}
+
+#[test]
+fn test_same_identifier_parses_either_way() {
+    let double = parse_sql("SELECT \"unit price\" FROM \"order items\"").unwrap();
+    let backtick = parse_sql("SELECT `unit price` FROM `order items`").unwrap();
+    assert_eq!(double[0].to_string(), backtick[0].to_string());
+
+    let mut parser = Parser::with_identifier_quote(
+        "SELECT `unit price` FROM `order items`",
+        IdentifierQuote::Backtick,
+    );
+    let program = parser.parse_program().unwrap();
+    assert_eq!(program.statements[0].to_string(), double[0].to_string());
+}
+
+#[test]
+fn test_both_quotes_resolve_the_same_table() {
+    let db = setup();
+    for sql in [
+        "SELECT SUM(\"unit price\") FROM \"order items\"",
+        "SELECT SUM(`unit price`) FROM `order items`",
+        "SELECT SUM(`unit price`) FROM \"order items\"",
+    ] {
+        let total: f64 = db.query_one(sql, ()).unwrap();
+        assert_eq!(total, 6.5, "{}", sql);
+    }
+
+    let count: i64 = db
+        .query_one(
+            "SELECT COUNT(*) FROM \"information_schema\".\"tables\" WHERE table_name = 'order items'",
+            (),
+        )
+        .unwrap();
+    let backtick: i64 = db
+        .query_one(
+            "SELECT COUNT(*) FROM `information_schema`.`tables` WHERE table_name = 'order items'",
+            (),
+        )
+        .unwrap();
+    assert_eq!(count, 1);
+    assert_eq!(backtick, count);
+}
+
+#[test]
+fn test_backtick_dialect_reads_double_quotes_as_strings() {
+    let db = setup();
+
+    // Standard quoting: "id" is the column
+    let id: i64 = db
+        .query_one("SELECT \"id\" FROM `order items` WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(id, 1);
+
+    db.execute("SET identifier_quote = 'backtick'", ()).unwrap();
+    let text: String = db
+        .query_one("SELECT \"id\" FROM `order items` WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(text, "id");
+    let count: i64 = db
+        .query_one(
+            "SELECT COUNT(*) FROM `information_schema`.`tables` WHERE table_name = \"order items\"",
+            (),
+        )
+        .unwrap();
+    assert_eq!(count, 1);
+
+    db.execute("SET identifier_quote = 'double_quote'", ())
+        .unwrap();
+    let id: i64 = db
+        .query_one("SELECT \"id\" FROM `order items` WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(id, 1);
+
+    assert!(db.execute("SET identifier_quote = 'bracket'", ()).is_err());
+}
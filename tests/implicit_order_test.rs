@@ -0,0 +1,161 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implicit Order Tests
+//!
+//! Tests `SET implicit_order = pk`, which sorts SELECTs without ORDER BY
+//! by primary key
+
+use oxibase::Database;
+
+fn setup() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE items (id INTEGER PRIMARY KEY, name TEXT, price INTEGER)",
+        (),
+    )
+    .unwrap();
+    for id in [7, 3, 9, 1, 5, 8, 2, 6, 4] {
+        db.execute(
+            "INSERT INTO items VALUES ($1, $2, $3)",
+            (id, format!("item{}", id), 100 - id),
+        )
+        .unwrap();
+    }
+    // Rewrite some rows so storage order drifts further from key order
+    db.execute("UPDATE items SET price = price + 1 WHERE id % 2 = 0", ())
+        .unwrap();
+    db.execute("DELETE FROM items WHERE id = 3", ()).unwrap();
+    db.execute("INSERT INTO items VALUES (3, 'item3', 97)", ())
+        .unwrap();
+    db
+}
+
+fn first_column(db: &Database, sql: &str) -> Vec<i64> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| row.unwrap().get::<i64>(0).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_implicit_order_pk_is_deterministic() {
+    let db = setup();
+    db.execute("SET implicit_order = pk", ()).unwrap();
+
+    let first = first_column(&db, "SELECT id, name FROM items");
+    assert_eq!(first, (1..=9).collect::<Vec<i64>>());
+    for _ in 0..5 {
+        assert_eq!(first_column(&db, "SELECT id, name FROM items"), first);
+    }
+
+    // Filters and limits see the same order
+    assert_eq!(
+        first_column(&db, "SELECT id FROM items WHERE price < 97"),
+        vec![5, 6, 7, 8, 9]
+    );
+    assert_eq!(
+        first_column(&db, "SELECT id FROM items LIMIT 3 OFFSET 2"),
+        vec![3, 4, 5]
+    );
+
+    // The key need not be selected
+    assert_eq!(
+        first_column(&db, "SELECT price FROM items i WHERE i.id > 6"),
+        vec![93, 93, 91]
+    );
+
+    // An explicit ORDER BY still wins
+    assert_eq!(
+        first_column(&db, "SELECT id FROM items ORDER BY name DESC LIMIT 2"),
+        vec![9, 8]
+    );
+}
+
+#[test]
+fn test_implicit_order_setting_values() {
+    let db = setup();
+
+    let shown: String = db.query_one("SHOW implicit_order", ()).unwrap();
+    assert_eq!(shown, "none");
+
+    db.execute("SET implicit_order = 'pk'", ()).unwrap();
+    let shown: String = db.query_one("SHOW implicit_order", ()).unwrap();
+    assert_eq!(shown, "pk");
+    // Aggregates are left alone
+    assert_eq!(first_column(&db, "SELECT COUNT(*) FROM items"), vec![9]);
+
+    db.execute("SET implicit_order = none", ()).unwrap();
+    let mut ids = first_column(&db, "SELECT id FROM items");
+    ids.sort();
+    assert_eq!(ids, (1..=9).collect::<Vec<i64>>());
+
+    assert!(db.execute("SET implicit_order = 'random'", ()).is_err());
+}
+
+#[test]
+fn test_implicit_order_composite_key() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE oi (order_id INTEGER, product_id INTEGER, qty INTEGER, \
+         PRIMARY KEY (order_id, product_id))",
+        (),
+    )
+    .unwrap();
+    db.execute("INSERT INTO oi VALUES (2, 1, 1), (1, 2, 1), (1, 1, 1)", ())
+        .unwrap();
+    db.execute("SET implicit_order = pk", ()).unwrap();
+
+    let keys: Vec<(i64, i64)> = db
+        .query("SELECT * FROM oi", ())
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (row.get(0).unwrap(), row.get(1).unwrap())
+        })
+        .collect();
+    assert_eq!(keys, vec![(1, 1), (1, 2), (2, 1)]);
+}
+
+#[test]
+fn test_implicit_order_text_key() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE codes (code TEXT PRIMARY KEY, n INTEGER)", ())
+        .unwrap();
+    db.execute("INSERT INTO codes VALUES ('c', 1), ('a', 2), ('b', 3)", ())
+        .unwrap();
+    db.execute("SET implicit_order = pk", ()).unwrap();
+
+    let codes: Vec<String> = db
+        .query("SELECT code FROM codes", ())
+        .unwrap()
+        .map(|row| row.unwrap().get(0).unwrap())
+        .collect();
+    assert_eq!(codes, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn test_show_session_settings() {
+    let db = Database::open_in_memory().unwrap();
+    let shown = |name: &str| -> String { db.query_one(&format!("SHOW {}", name), ()).unwrap() };
+
+    assert_eq!(shown("search_path"), "public");
+    assert_eq!(shown("div_by_zero"), "error");
+    db.execute("SET div_by_zero = 'null'", ()).unwrap();
+    db.execute("SET statement_timeout = 5000", ()).unwrap();
+    assert_eq!(shown("div_by_zero"), "null");
+    assert_eq!(shown("statement_timeout"), "5000");
+
+    assert!(db.query("SHOW no_such_setting", ()).is_err());
+}
@@ -0,0 +1,175 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Index-Only Scan Tests
+//!
+//! Tests queries answered from the values a covering index stores
+
+use oxibase::Database;
+
+fn setup(name: &str) -> Database {
+    let db = Database::open(&format!("memory://{}", name)).expect("Failed to create database");
+    db.execute(
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT, name TEXT, region INTEGER, tier INTEGER)",
+        (),
+    )
+    .expect("Failed to create table");
+    db.execute("CREATE INDEX idx_email ON users(email)", ())
+        .expect("Failed to create index");
+    db.execute("CREATE INDEX idx_region_tier ON users(region, tier)", ())
+        .expect("Failed to create index");
+    db.execute(
+        "INSERT INTO users VALUES
+            (1, 'alice@example.com', 'Alice', 1, 3),
+            (2, 'bob@example.com', 'Bob', 1, 1),
+            (3, 'carol@example.com', 'Carol', 2, 2)",
+        (),
+    )
+    .expect("Failed to insert");
+    db
+}
+
+fn plan(db: &Database, sql: &str) -> String {
+    let rows = db.query(sql, ()).expect("Failed to explain");
+    let mut lines = Vec::new();
+    for row in rows {
+        let row = row.expect("Failed to read row");
+        lines.push(row.get::<String>(0).unwrap());
+    }
+    lines.join("\n")
+}
+
+#[test]
+fn test_explain_index_only_scan() {
+    let db = setup("index_only_explain");
+
+    let covered = plan(
+        &db,
+        "EXPLAIN SELECT email FROM users WHERE email = 'bob@example.com'",
+    );
+    assert!(
+        covered.contains("Index Only Scan using idx_email on users"),
+        "{}",
+        covered
+    );
+
+    let rows = db
+        .query(
+            "EXPLAIN SELECT email FROM users WHERE email = ?",
+            ("bob@example.com",),
+        )
+        .expect("Failed to explain");
+    let parameterized: Vec<String> = rows
+        .map(|row| row.expect("Failed to read row").get::<String>(0).unwrap())
+        .collect();
+    assert!(
+        parameterized[2].contains("Index Only Scan using idx_email on users"),
+        "{:?}",
+        parameterized
+    );
+
+    let composite = plan(
+        &db,
+        "EXPLAIN ANALYZE SELECT tier FROM users WHERE region = 1 AND tier = 3",
+    );
+    assert!(
+        composite.contains("Index Only Scan using idx_region_tier on users"),
+        "{}",
+        composite
+    );
+
+    // name is not stored in any index, so the base rows are needed
+    let uncovered = plan(
+        &db,
+        "EXPLAIN SELECT name FROM users WHERE email = 'bob@example.com'",
+    );
+    assert!(!uncovered.contains("Index Only Scan"), "{}", uncovered);
+    assert!(uncovered.contains("Index Scan"), "{}", uncovered);
+}
+
+#[test]
+fn test_index_only_scan_results() {
+    let db = setup("index_only_results");
+
+    let email: String = db
+        .query_one(
+            "SELECT email FROM users WHERE email = ?",
+            ("bob@example.com",),
+        )
+        .expect("Failed to query");
+    assert_eq!(email, "bob@example.com");
+
+    let mut tiers: Vec<i64> = db
+        .query("SELECT tier FROM users WHERE region = 1 AND tier > 0", ())
+        .expect("Failed to query")
+        .map(|row| row.expect("Failed to read row").get::<i64>(0).unwrap())
+        .collect();
+    tiers.sort_unstable();
+    assert_eq!(tiers, vec![1, 3]);
+
+    // Index values follow updates and deletes
+    db.execute(
+        "UPDATE users SET email = 'robert@example.com' WHERE id = 2",
+        (),
+    )
+    .expect("Failed to update");
+    let count = db
+        .query(
+            "SELECT email FROM users WHERE email = ?",
+            ("bob@example.com",),
+        )
+        .expect("Failed to query")
+        .count();
+    assert_eq!(count, 0);
+    let email: String = db
+        .query_one(
+            "SELECT email AS e FROM users WHERE email = ?",
+            ("robert@example.com",),
+        )
+        .expect("Failed to query");
+    assert_eq!(email, "robert@example.com");
+
+    db.execute("DELETE FROM users WHERE id = 2", ())
+        .expect("Failed to delete");
+    let count = db
+        .query(
+            "SELECT email FROM users WHERE email = ?",
+            ("robert@example.com",),
+        )
+        .expect("Failed to query")
+        .count();
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn test_index_only_scan_sees_own_writes() {
+    let db = setup("index_only_own_writes");
+
+    // Uncommitted rows are not in the index yet
+    db.execute("BEGIN", ())
+        .expect("Failed to begin transaction");
+    db.execute(
+        "INSERT INTO users VALUES (4, 'dave@example.com', 'Dave', 3, 1)",
+        (),
+    )
+    .expect("Failed to insert");
+    let email: String = db
+        .query_one(
+            "SELECT email FROM users WHERE email = ?",
+            ("dave@example.com",),
+        )
+        .expect("Failed to query");
+    assert_eq!(email, "dave@example.com");
+    db.execute("ROLLBACK", ()).expect("Failed to rollback");
+}
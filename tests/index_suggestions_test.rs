@@ -0,0 +1,172 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Index Suggestion Tests
+//!
+//! Tests SHOW INDEX SUGGESTIONS built from the full scans of earlier queries
+
+use oxibase::Database;
+
+fn setup(name: &str) -> Database {
+    let db = Database::open(&format!("memory://{}", name)).expect("Failed to create database");
+    db.execute(
+        "CREATE TABLE orders (id INTEGER PRIMARY KEY, customer INTEGER, status TEXT, total FLOAT)",
+        (),
+    )
+    .expect("Failed to create table");
+    db.execute("CREATE INDEX idx_status ON orders(status)", ())
+        .expect("Failed to create index");
+    db.execute(
+        "INSERT INTO orders SELECT value, value % 50, 'open', value * 1.5 FROM generate_series(1, 1000) AS g(value)",
+        (),
+    )
+    .expect("Failed to insert");
+    db
+}
+
+/// (table, column, usage, full_scans, suggestion) rows
+fn suggestions(db: &Database) -> Vec<(String, String, String, i64, String)> {
+    db.query("SHOW INDEX SUGGESTIONS", ())
+        .expect("Failed to show suggestions")
+        .map(|row| {
+            let row = row.expect("Failed to read row");
+            (
+                row.get::<String>(0).unwrap(),
+                row.get::<String>(1).unwrap(),
+                row.get::<String>(2).unwrap(),
+                row.get::<i64>(3).unwrap(),
+                row.get::<String>(6).unwrap(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_suggests_filtered_column_after_full_scans() {
+    let db = setup("suggest_filtered");
+    assert!(suggestions(&db).is_empty());
+
+    for customer in 0..5 {
+        db.query("SELECT * FROM orders WHERE customer = ?", (customer,))
+            .expect("Failed to query")
+            .count();
+    }
+    db.query("SELECT id FROM orders WHERE total > 100 ORDER BY total", ())
+        .expect("Failed to query")
+        .count();
+
+    let found = suggestions(&db);
+    assert_eq!(found[0].0, "orders");
+    assert_eq!(found[0].1, "customer");
+    assert_eq!(found[0].2, "equality");
+    assert_eq!(found[0].3, 5);
+    assert_eq!(
+        found[0].4,
+        "CREATE INDEX idx_orders_customer ON orders(customer)"
+    );
+    assert!(found
+        .iter()
+        .any(|s| s.1 == "total" && s.2 == "range, sort" && s.3 == 1));
+
+    // Every scan read all 1000 rows; equality counts in full, range by half
+    let benefits: Vec<f64> = db
+        .query("SHOW INDEX SUGGESTIONS", ())
+        .expect("Failed to show suggestions")
+        .map(|row| row.expect("Failed to read row").get::<f64>(5).unwrap())
+        .collect();
+    assert_eq!(benefits, vec![5000.0, 500.0]);
+}
+
+#[test]
+fn test_indexed_and_primary_key_columns_are_not_suggested() {
+    let db = setup("suggest_indexed");
+
+    // Served by an index or the primary key, no full scan
+    db.query("SELECT * FROM orders WHERE status = 'open'", ())
+        .expect("Failed to query")
+        .count();
+    db.query("SELECT * FROM orders WHERE id = 5", ())
+        .expect("Failed to query")
+        .count();
+    // Uses idx_status, so customer is filtered without a full scan
+    db.query(
+        "SELECT * FROM orders WHERE status = 'open' AND customer = 3",
+        (),
+    )
+    .expect("Failed to query")
+    .count();
+    assert!(suggestions(&db).is_empty(), "{:?}", suggestions(&db));
+
+    // A suggestion disappears once the index exists
+    db.query("SELECT * FROM orders WHERE customer = 3", ())
+        .expect("Failed to query")
+        .count();
+    assert_eq!(suggestions(&db).len(), 1);
+    db.execute("CREATE INDEX idx_customer ON orders(customer)", ())
+        .expect("Failed to create index");
+    assert!(suggestions(&db).is_empty());
+}
+
+#[test]
+fn test_system_tables_are_not_suggested() {
+    let db = setup("suggest_system");
+    db.execute(
+        "CREATE PROCEDURE touch(IN n INTEGER) LANGUAGE rhai AS $$ let y = n; $$",
+        (),
+    )
+    .expect("Failed to create procedure");
+
+    // SHOW commands read the system tables through internal queries
+    for sql in [
+        "SHOW PROCEDURES",
+        "SHOW FUNCTIONS",
+        "SHOW SEQUENCES",
+        "SELECT * FROM system.procedures WHERE name = 'TOUCH'",
+    ] {
+        db.query(sql, ()).expect("Failed to query").count();
+    }
+    assert!(suggestions(&db).is_empty(), "{:?}", suggestions(&db));
+}
+
+#[test]
+fn test_suggestion_for_table_in_schema() {
+    let db = setup("suggest_schema");
+    db.execute("CREATE SCHEMA app", ())
+        .expect("Failed to create schema");
+    db.execute(
+        "CREATE TABLE app.items (id INTEGER PRIMARY KEY, sku TEXT)",
+        (),
+    )
+    .expect("Failed to create table");
+    db.execute(
+        "INSERT INTO app.items SELECT value, 'sku' || value FROM generate_series(1, 100)",
+        (),
+    )
+    .expect("Failed to insert");
+    db.query("SELECT * FROM app.items WHERE sku = 'sku7'", ())
+        .expect("Failed to query")
+        .count();
+
+    let found = suggestions(&db);
+    assert_eq!(found.len(), 1, "{:?}", found);
+    assert_eq!(found[0].0, "app.items");
+    assert_eq!(
+        found[0].4,
+        "CREATE INDEX idx_app_items_sku ON app.items(sku)"
+    );
+
+    // The suggestion runs as given
+    db.execute(&found[0].4, ()).expect("Failed to create index");
+    assert!(suggestions(&db).is_empty());
+}
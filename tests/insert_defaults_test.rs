@@ -0,0 +1,214 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! INSERT Column Default Tests
+//!
+//! Tests that columns omitted from an INSERT take their DEFAULT or NULL on every path
+
+use oxibase::Database;
+
+fn setup(db: &Database) {
+    db.execute("CREATE SEQUENCE item_seq", ()).unwrap();
+    db.execute(
+        "CREATE TABLE items (
+            id INTEGER PRIMARY KEY,
+            name TEXT DEFAULT 'unnamed',
+            qty INTEGER DEFAULT 1 + 2,
+            price FLOAT DEFAULT 9.5,
+            active BOOLEAN DEFAULT true,
+            serial INTEGER DEFAULT NEXTVAL('item_seq'),
+            note TEXT
+        )",
+        (),
+    )
+    .unwrap();
+}
+
+/// id, name, qty, price, active, serial, note
+type ItemRow = (i64, String, i64, f64, bool, i64, Option<String>);
+
+fn rows(db: &Database) -> Vec<ItemRow> {
+    db.query(
+        "SELECT id, name, qty, price, active, serial, note FROM items ORDER BY id",
+        (),
+    )
+    .unwrap()
+    .map(|r| {
+        let r = r.unwrap();
+        (
+            r.get(0).unwrap(),
+            r.get(1).unwrap(),
+            r.get(2).unwrap(),
+            r.get(3).unwrap(),
+            r.get(4).unwrap(),
+            r.get(5).unwrap(),
+            r.get(6).unwrap(),
+        )
+    })
+    .collect()
+}
+
+#[test]
+fn test_partial_column_list_applies_all_defaults() {
+    let db = Database::open("memory://insert_defaults_values").unwrap();
+    setup(&db);
+
+    db.execute("INSERT INTO items (id) VALUES (1), (2)", ())
+        .unwrap();
+    db.execute("INSERT INTO items (id, name) VALUES (3, 'given')", ())
+        .unwrap();
+    db.execute(
+        "INSERT INTO items (id, qty, serial) VALUES (4, DEFAULT, DEFAULT)",
+        (),
+    )
+    .unwrap();
+
+    // Volatile defaults are evaluated for each row
+    assert_eq!(
+        rows(&db),
+        vec![
+            (1, "unnamed".into(), 3, 9.5, true, 1, None),
+            (2, "unnamed".into(), 3, 9.5, true, 2, None),
+            (3, "given".into(), 3, 9.5, true, 3, None),
+            (4, "unnamed".into(), 3, 9.5, true, 4, None),
+        ]
+    );
+
+    // A supplied value does not consume the sequence behind the default
+    db.execute("INSERT INTO items (id, serial) VALUES (5, 100)", ())
+        .unwrap();
+    db.execute("INSERT INTO items (id) VALUES (6)", ()).unwrap();
+    let serials: Vec<i64> = rows(&db).iter().map(|r| r.5).collect();
+    assert_eq!(serials, vec![1, 2, 3, 4, 100, 5]);
+}
+
+#[test]
+fn test_defaults_on_insert_select_and_upsert() {
+    let db = Database::open("memory://insert_defaults_select").unwrap();
+    setup(&db);
+
+    db.execute(
+        "INSERT INTO items (id, note) SELECT * FROM (VALUES (1, 'a'), (2, 'b')) AS v(id, note)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO items (id) VALUES (3) ON DUPLICATE KEY UPDATE note = 'dup'",
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(
+        rows(&db),
+        vec![
+            (1, "unnamed".into(), 3, 9.5, true, 1, Some("a".into())),
+            (2, "unnamed".into(), 3, 9.5, true, 2, Some("b".into())),
+            (3, "unnamed".into(), 3, 9.5, true, 3, None),
+        ]
+    );
+}
+
+#[test]
+fn test_defaults_on_table_created_as_select() {
+    let db = Database::open("memory://insert_defaults_ctas").unwrap();
+    db.execute("CREATE TABLE src (id INTEGER, name TEXT)", ())
+        .unwrap();
+    db.execute("INSERT INTO src VALUES (1, 'x')", ()).unwrap();
+    db.execute("CREATE TABLE snapshot AS SELECT id, name FROM src", ())
+        .unwrap();
+    db.execute(
+        "ALTER TABLE snapshot ADD COLUMN status TEXT DEFAULT 'new'",
+        (),
+    )
+    .unwrap();
+
+    db.execute("INSERT INTO snapshot (id) VALUES (2)", ())
+        .unwrap();
+
+    let row = db
+        .query("SELECT name, status FROM snapshot WHERE id = 2", ())
+        .unwrap()
+        .map(|r| r.unwrap())
+        .next()
+        .unwrap();
+    assert!(row.get::<Option<String>>(0).unwrap().is_none());
+    assert_eq!(row.get::<String>(1).unwrap(), "new");
+}
+
+#[test]
+fn test_defaults_in_explicit_transaction() {
+    let db = Database::open("memory://insert_defaults_tx").unwrap();
+    setup(&db);
+
+    let mut tx = db.begin().unwrap();
+    tx.execute("INSERT INTO items (id, qty) VALUES (1, 5)", ())
+        .unwrap();
+    tx.execute(
+        "INSERT INTO items (note, id, name) VALUES ('n', 2, DEFAULT)",
+        (),
+    )
+    .unwrap();
+    assert!(tx
+        .execute("INSERT INTO items (id, qty) VALUES (3)", ())
+        .is_err());
+    assert!(tx
+        .execute("INSERT INTO items (id, missing) VALUES (3, 1)", ())
+        .is_err());
+    tx.commit().unwrap();
+
+    assert_eq!(
+        rows(&db),
+        vec![
+            (1, "unnamed".into(), 5, 9.5, true, 1, None),
+            (2, "unnamed".into(), 3, 9.5, true, 2, Some("n".into())),
+        ]
+    );
+}
+
+#[test]
+fn test_defaults_on_copy_from() {
+    let db = Database::open("memory://insert_defaults_copy").unwrap();
+    setup(&db);
+
+    let dir = tempfile::tempdir().unwrap();
+    let csv = dir.path().join("items.csv");
+    std::fs::write(&csv, "1,a\n2,b\n").unwrap();
+    let json = dir.path().join("items.json");
+    std::fs::write(&json, "[{\"id\": 3}, {\"id\": 4, \"serial\": 50}]").unwrap();
+
+    db.execute(
+        &format!(
+            "COPY items (id, note) FROM '{}' WITH (FORMAT CSV, HEADER false)",
+            csv.display()
+        ),
+        (),
+    )
+    .unwrap();
+    db.execute(
+        &format!("COPY items FROM '{}' WITH (FORMAT JSON)", json.display()),
+        (),
+    )
+    .unwrap();
+
+    // Defaults are evaluated per row, and only for the values the file leaves out
+    assert_eq!(
+        rows(&db),
+        vec![
+            (1, "unnamed".into(), 3, 9.5, true, 1, Some("a".into())),
+            (2, "unnamed".into(), 3, 9.5, true, 2, Some("b".into())),
+            (3, "unnamed".into(), 3, 9.5, true, 3, None),
+            (4, "unnamed".into(), 3, 9.5, true, 50, None),
+        ]
+    );
+}
@@ -990,3 +990,100 @@ fn test_insert_select_default_consistency() {
     assert_eq!(rows[0], (1, 100, "default_tag".to_string()));
     assert_eq!(rows[1], (2, 200, "default_tag".to_string()));
 }
+
+// ============================================================================
+// Streaming and Atomicity
+// ============================================================================
+
+#[test]
+fn test_insert_select_streams_large_result_with_coercion() {
+    let db = create_test_db("insert_select_stream");
+
+    db.execute(
+        "CREATE TABLE source (id INTEGER PRIMARY KEY, amount INTEGER, label TEXT)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE TABLE target (id INTEGER PRIMARY KEY, amount FLOAT, label TEXT)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO source
+         SELECT value, value % 100, 'row ' || value FROM GENERATE_SERIES(1, 50000)",
+        (),
+    )
+    .unwrap();
+
+    let inserted = db
+        .execute("INSERT INTO target SELECT * FROM source", ())
+        .unwrap();
+    assert_eq!(inserted, 50000);
+
+    // INTEGER amounts were coerced to the FLOAT column
+    let amount: f64 = db
+        .query_one("SELECT amount FROM target WHERE id = 4242", ())
+        .unwrap();
+    assert_eq!(amount, 42.0);
+
+    let (count, total): (i64, f64) = db
+        .query("SELECT COUNT(*), SUM(amount) FROM target", ())
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (row.get(0).unwrap(), row.get(1).unwrap())
+        })
+        .next()
+        .unwrap();
+    assert_eq!(count, 50000);
+    assert_eq!(total, 2_475_000.0);
+
+    let mismatched: i64 = db
+        .query_one(
+            "SELECT COUNT(*) FROM source s JOIN target t ON s.id = t.id
+             WHERE s.label <> t.label OR s.amount <> t.amount",
+            (),
+        )
+        .unwrap();
+    assert_eq!(mismatched, 0);
+}
+
+#[test]
+fn test_insert_select_is_atomic() {
+    let db = create_test_db("insert_select_atomic");
+
+    db.execute("CREATE TABLE source (id INTEGER PRIMARY KEY)", ())
+        .unwrap();
+    db.execute("CREATE TABLE target (id INTEGER PRIMARY KEY)", ())
+        .unwrap();
+    for id in 1..=100 {
+        db.execute("INSERT INTO source VALUES ($1)", (id,)).unwrap();
+    }
+    // Conflicts with a row near the end of the source
+    db.execute("INSERT INTO target VALUES (90)", ()).unwrap();
+
+    let count = |db: &Database| -> i64 { db.query_one("SELECT COUNT(*) FROM target", ()).unwrap() };
+
+    // Auto-commit: the failed statement leaves nothing behind
+    assert!(db
+        .execute("INSERT INTO target SELECT id FROM source", ())
+        .is_err());
+    assert_eq!(count(&db), 1);
+
+    // Explicit transaction: earlier statements survive, the failed one is undone
+    db.execute("BEGIN", ()).unwrap();
+    db.execute("INSERT INTO target VALUES (500)", ()).unwrap();
+    assert!(db
+        .execute("INSERT INTO target SELECT id FROM source", ())
+        .is_err());
+    assert_eq!(count(&db), 2);
+
+    // The undone rows can be inserted again
+    let inserted = db
+        .execute("INSERT INTO target SELECT id FROM source WHERE id < 90", ())
+        .unwrap();
+    assert_eq!(inserted, 89);
+    db.execute("COMMIT", ()).unwrap();
+    assert_eq!(count(&db), 91);
+}
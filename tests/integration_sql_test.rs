@@ -1295,3 +1295,67 @@ fn test_schema_management() {
     db.execute("DROP SCHEMA marketing", ())
         .expect("Failed to drop marketing schema");
 }
+
+#[test]
+fn test_search_path_resolution() {
+    let db = Database::open("memory://int_search_path").expect("Failed to create database");
+
+    db.execute("CREATE SCHEMA tenant1", ())
+        .expect("Failed to create schema");
+    db.execute(
+        "CREATE TABLE tenant1.accounts (id INTEGER PRIMARY KEY, plan_id INTEGER, name TEXT)",
+        (),
+    )
+    .expect("Failed to create table in tenant1");
+    db.execute(
+        "CREATE TABLE plans (id INTEGER PRIMARY KEY, label TEXT)",
+        (),
+    )
+    .expect("Failed to create public table");
+    db.execute("INSERT INTO tenant1.accounts VALUES (1, 10, 'first')", ())
+        .expect("Failed to insert");
+    db.execute("INSERT INTO plans VALUES (10, 'basic')", ())
+        .expect("Failed to insert");
+
+    // Unqualified names only look in public by default
+    assert!(db.query("SELECT * FROM accounts", ()).is_err());
+
+    db.execute("SET search_path = tenant1, public", ())
+        .expect("Failed to set search_path");
+
+    // Found in tenant1 without qualification
+    let name: String = db
+        .query_one("SELECT name FROM accounts WHERE accounts.id = 1", ())
+        .expect("Failed to query");
+    assert_eq!(name, "first");
+
+    // Falls back to public when tenant1 has no such table
+    let label: String = db
+        .query_one(
+            "SELECT p.label FROM accounts a JOIN plans p ON p.id = a.plan_id",
+            (),
+        )
+        .expect("Failed to join");
+    assert_eq!(label, "basic");
+
+    // DML resolves the same way
+    db.execute("INSERT INTO accounts VALUES (2, 10, 'second')", ())
+        .expect("Failed to insert");
+    db.execute("UPDATE accounts SET name = 'renamed' WHERE id = 2", ())
+        .expect("Failed to update");
+    let name: String = db
+        .query_one("SELECT name FROM tenant1.accounts WHERE id = 2", ())
+        .expect("Failed to query");
+    assert_eq!(name, "renamed");
+    db.execute("DELETE FROM accounts WHERE accounts.id = 2", ())
+        .expect("Failed to delete");
+    let count: i64 = db
+        .query_one("SELECT COUNT(*) FROM tenant1.accounts", ())
+        .expect("Failed to query");
+    assert_eq!(count, 1);
+
+    // Back to the default path
+    db.execute("SET search_path = public", ())
+        .expect("Failed to reset search_path");
+    assert!(db.query("SELECT * FROM accounts", ()).is_err());
+}
@@ -0,0 +1,252 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for INTERVAL columns
+
+use oxibase::{Database, Value};
+use tempfile::tempdir;
+
+const HOUR: i64 = 3_600_000_000;
+const DAY: i64 = 24 * HOUR;
+
+fn setup(db: &Database) {
+    db.execute(
+        "CREATE TABLE tasks (id INTEGER PRIMARY KEY, name TEXT, duration INTERVAL)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO tasks VALUES \
+         (1, 'build', INTERVAL '1 day 2:30:00'), \
+         (2, 'test', '2 hours'), \
+         (3, 'deploy', INTERVAL '30 minutes'), \
+         (4, 'review', NULL)",
+        (),
+    )
+    .unwrap();
+}
+
+fn duration(db: &Database, id: i64) -> Value {
+    let mut rows = db
+        .query("SELECT duration FROM tasks WHERE id = $1", (id,))
+        .unwrap();
+    let row = rows.next().unwrap().unwrap();
+    row.get_value(0).unwrap().clone()
+}
+
+#[test]
+fn test_store_interval() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    assert_eq!(
+        duration(&db, 1),
+        Value::Interval(DAY + 2 * HOUR + 30 * 60_000_000)
+    );
+    assert_eq!(duration(&db, 2), Value::Interval(2 * HOUR));
+    assert!(duration(&db, 4).is_null());
+
+    let text: String = db
+        .query_one("SELECT duration FROM tasks WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(text, "1 day 02:30:00");
+
+    let type_name: String = db
+        .query_one("SELECT TYPEOF(duration) FROM tasks WHERE id = 3", ())
+        .unwrap();
+    assert_eq!(type_name, "INTERVAL");
+}
+
+#[test]
+fn test_compare_intervals() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let count: i64 = db
+        .query_one(
+            "SELECT COUNT(*) FROM tasks WHERE duration > INTERVAL '1 hour'",
+            (),
+        )
+        .unwrap();
+    assert_eq!(count, 2);
+
+    let id: i64 = db
+        .query_one("SELECT id FROM tasks WHERE duration = '2 hours'", ())
+        .unwrap();
+    assert_eq!(id, 2);
+
+    let rows = db
+        .query(
+            "SELECT id FROM tasks WHERE duration IS NOT NULL ORDER BY duration DESC",
+            (),
+        )
+        .unwrap();
+    let ids: Vec<i64> = rows.map(|row| row.unwrap().get(0).unwrap()).collect();
+    assert_eq!(ids, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_interval_arithmetic() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let mut rows = db.query("SELECT SUM(duration) FROM tasks", ()).unwrap();
+    let total = rows.next().unwrap().unwrap().get_value(0).unwrap().clone();
+    assert_eq!(total, Value::Interval(DAY + 5 * HOUR));
+
+    let end: String = db
+        .query_one(
+            "SELECT TIMESTAMP '2025-01-01 00:00:00' + duration FROM tasks WHERE id = 1",
+            (),
+        )
+        .unwrap();
+    assert!(end.starts_with("2025-01-02T02:30:00"), "{}", end);
+
+    let mut rows = db
+        .query(
+            "SELECT duration + INTERVAL '30 minutes', -duration FROM tasks WHERE id = 2",
+            (),
+        )
+        .unwrap();
+    let row = rows.next().unwrap().unwrap();
+    assert_eq!(
+        row.get_value(0).unwrap(),
+        &Value::Interval(2 * HOUR + 30 * 60_000_000)
+    );
+    assert_eq!(row.get_value(1).unwrap(), &Value::Interval(-2 * HOUR));
+}
+
+#[test]
+fn test_invalid_interval() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    assert!(db.query("SELECT INTERVAL 'soon'", ()).is_err());
+}
+
+#[test]
+fn test_interval_persists() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("test.db");
+    let dsn = format!("file://{}", db_path.display());
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        setup(&db);
+    }
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        assert_eq!(duration(&db, 2), Value::Interval(2 * HOUR));
+        let count: i64 = db
+            .query_one(
+                "SELECT COUNT(*) FROM tasks WHERE duration < INTERVAL '1 day'",
+                (),
+            )
+            .unwrap();
+        assert_eq!(count, 2);
+    }
+}
+
+#[test]
+fn test_interval_aggregates() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE spans (id INTEGER PRIMARY KEY, grp TEXT, d INTERVAL)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO spans VALUES \
+         (1, 'a', '1 day'), (2, 'a', '2 hours'), (3, 'b', '3 days'), (4, 'b', NULL)",
+        (),
+    )
+    .unwrap();
+
+    let mut rows = db
+        .query("SELECT MIN(d), MAX(d), SUM(d), AVG(d) FROM spans", ())
+        .unwrap();
+    let row = rows.next().unwrap().unwrap();
+    assert_eq!(row.get_value(0).unwrap(), &Value::Interval(2 * HOUR));
+    assert_eq!(row.get_value(1).unwrap(), &Value::Interval(3 * DAY));
+    assert_eq!(
+        row.get_value(2).unwrap(),
+        &Value::Interval(4 * DAY + 2 * HOUR)
+    );
+    // (1 day + 2 hours + 3 days) / 3, NULL skipped
+    assert_eq!(
+        row.get_value(3).unwrap(),
+        &Value::Interval((4 * DAY + 2 * HOUR) / 3)
+    );
+
+    // Grouped and DISTINCT forms agree
+    let rows = db
+        .query(
+            "SELECT grp, MIN(d), MAX(d), AVG(DISTINCT d) FROM spans GROUP BY grp ORDER BY grp",
+            (),
+        )
+        .unwrap();
+    let groups: Vec<(String, Value, Value, Value)> = rows
+        .map(|row| {
+            let row = row.unwrap();
+            (
+                row.get(0).unwrap(),
+                row.get_value(1).unwrap().clone(),
+                row.get_value(2).unwrap().clone(),
+                row.get_value(3).unwrap().clone(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        groups,
+        vec![
+            (
+                "a".to_string(),
+                Value::Interval(2 * HOUR),
+                Value::Interval(DAY),
+                Value::Interval((DAY + 2 * HOUR) / 2)
+            ),
+            (
+                "b".to_string(),
+                Value::Interval(3 * DAY),
+                Value::Interval(3 * DAY),
+                Value::Interval(3 * DAY)
+            ),
+        ]
+    );
+
+    let text: String = db.query_one("SELECT AVG(d) FROM spans", ()).unwrap();
+    assert_eq!(text, "1 day 08:40:00");
+
+    // Large inputs take the parallel paths
+    db.execute("CREATE TABLE many (id INTEGER PRIMARY KEY, d INTERVAL)", ())
+        .unwrap();
+    db.execute(
+        "INSERT INTO many SELECT value, value || ' seconds' FROM generate_series(1, 20000)",
+        (),
+    )
+    .unwrap();
+    let mut rows = db
+        .query("SELECT MIN(d), MAX(d), SUM(d), AVG(d) FROM many", ())
+        .unwrap();
+    let row = rows.next().unwrap().unwrap();
+    let second = 1_000_000;
+    assert_eq!(row.get_value(0).unwrap(), &Value::Interval(second));
+    assert_eq!(row.get_value(1).unwrap(), &Value::Interval(20_000 * second));
+    assert_eq!(
+        row.get_value(2).unwrap(),
+        &Value::Interval(200_010_000 * second)
+    );
+    assert_eq!(row.get_value(3).unwrap(), &Value::Interval(10_000_500_000));
+}
@@ -16,7 +16,7 @@
 //!
 //! Tests transaction isolation level settings
 
-use oxibase::Database;
+use oxibase::{Database, IsolationLevel};
 
 /// Test session-wide isolation level setting with SET ISOLATIONLEVEL
 #[test]
@@ -256,3 +256,158 @@ fn test_isolation_rollback() {
         .expect("Failed to read value");
     assert_eq!(value, 100, "Expected original value 100 after rollback");
 }
+
+fn show_isolation_level(db: &Database) -> String {
+    db.query_one("SHOW TRANSACTION ISOLATION LEVEL", ())
+        .expect("Failed to show isolation level")
+}
+
+/// Test SET TRANSACTION ISOLATION LEVEL inside and outside a transaction
+#[test]
+fn test_set_transaction_isolation_level() {
+    let db = Database::open_in_memory().expect("Failed to create database");
+    assert_eq!(show_isolation_level(&db), "READ COMMITTED");
+
+    // Outside a transaction it sets the level of the next transaction only
+    db.execute("SET TRANSACTION ISOLATION LEVEL SNAPSHOT", ())
+        .expect("Failed to set isolation level");
+    assert_eq!(show_isolation_level(&db), "SNAPSHOT");
+    db.execute("BEGIN", ()).expect("Failed to begin");
+    assert_eq!(show_isolation_level(&db), "SNAPSHOT");
+    db.execute("COMMIT", ()).expect("Failed to commit");
+    assert_eq!(show_isolation_level(&db), "READ COMMITTED");
+    db.execute("BEGIN", ()).expect("Failed to begin");
+    assert_eq!(show_isolation_level(&db), "READ COMMITTED");
+    db.execute("COMMIT", ()).expect("Failed to commit");
+    assert_eq!(
+        db.default_isolation_level().unwrap(),
+        IsolationLevel::ReadCommitted
+    );
+
+    // Inside a transaction it only changes that transaction
+    db.execute("SET TRANSACTION ISOLATION LEVEL READ COMMITTED", ())
+        .expect("Failed to set isolation level");
+    db.execute("BEGIN TRANSACTION ISOLATION LEVEL REPEATABLE READ", ())
+        .expect("Failed to begin");
+    assert_eq!(show_isolation_level(&db), "SNAPSHOT");
+    db.execute("SET TRANSACTION ISOLATION LEVEL READ COMMITTED", ())
+        .expect("Failed to set isolation level");
+    assert_eq!(show_isolation_level(&db), "READ COMMITTED");
+    db.execute("ROLLBACK", ()).expect("Failed to roll back");
+    assert_eq!(show_isolation_level(&db), "READ COMMITTED");
+
+    // Not once the transaction has run a query
+    db.execute("BEGIN", ()).expect("Failed to begin");
+    db.query("SELECT 1", ()).expect("Failed to query");
+    assert!(db
+        .execute("SET TRANSACTION ISOLATION LEVEL SNAPSHOT", ())
+        .is_err());
+    assert_eq!(show_isolation_level(&db), "READ COMMITTED");
+    db.execute("ROLLBACK", ()).expect("Failed to roll back");
+
+    assert!(db
+        .execute("SET TRANSACTION ISOLATION LEVEL SERIALIZABLE", ())
+        .is_err());
+    assert!(db
+        .execute("SET TRANSACTION ISOLATION LEVEL READ UNCOMMITTED", ())
+        .is_err());
+}
+
+/// Test that the chosen level decides what a transaction sees
+#[test]
+fn test_begin_with_isolation_visibility() {
+    let db = Database::open_in_memory().expect("Failed to create database");
+    db.execute(
+        "CREATE TABLE accounts (id INTEGER PRIMARY KEY, balance INTEGER)",
+        (),
+    )
+    .expect("Failed to create table");
+    db.execute("INSERT INTO accounts VALUES (1, 100)", ())
+        .expect("Failed to insert");
+
+    let mut read_committed = db
+        .begin_with_isolation(IsolationLevel::ReadCommitted)
+        .expect("Failed to begin");
+    let mut snapshot = db
+        .begin_with_isolation(IsolationLevel::SnapshotIsolation)
+        .expect("Failed to begin");
+    assert_eq!(
+        snapshot.isolation_level(),
+        Some(IsolationLevel::SnapshotIsolation)
+    );
+    let before: i64 = snapshot
+        .query_one("SELECT balance FROM accounts WHERE id = 1", ())
+        .expect("Failed to read");
+    assert_eq!(before, 100);
+
+    db.execute("UPDATE accounts SET balance = 50 WHERE id = 1", ())
+        .expect("Failed to update");
+
+    // READ COMMITTED sees the committed change, SNAPSHOT keeps its view
+    let seen: i64 = read_committed
+        .query_one("SELECT balance FROM accounts WHERE id = 1", ())
+        .expect("Failed to read");
+    assert_eq!(seen, 50);
+    let seen: i64 = snapshot
+        .query_one("SELECT balance FROM accounts WHERE id = 1", ())
+        .expect("Failed to read");
+    assert_eq!(seen, 100);
+    read_committed.commit().expect("Failed to commit");
+    snapshot.commit().expect("Failed to commit");
+
+    // db.begin() uses the default level, or the one SQL set for the next
+    // transaction
+    let tx = db.begin().expect("Failed to begin");
+    assert_eq!(tx.isolation_level(), Some(IsolationLevel::ReadCommitted));
+    drop(tx);
+    db.execute("SET TRANSACTION ISOLATION LEVEL SNAPSHOT", ())
+        .expect("Failed to set isolation level");
+    assert_eq!(
+        db.default_isolation_level().unwrap(),
+        IsolationLevel::ReadCommitted
+    );
+    let tx = db.begin().expect("Failed to begin");
+    assert_eq!(
+        tx.isolation_level(),
+        Some(IsolationLevel::SnapshotIsolation)
+    );
+    drop(tx);
+    let tx = db.begin().expect("Failed to begin");
+    assert_eq!(tx.isolation_level(), Some(IsolationLevel::ReadCommitted));
+}
+
+/// Test SET and SHOW TRANSACTION ISOLATION LEVEL on a db.begin() transaction
+#[test]
+fn test_set_transaction_in_api_transaction() {
+    let db = Database::open_in_memory().expect("Failed to create database");
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY)", ())
+        .expect("Failed to create table");
+
+    let mut tx = db.begin().expect("Failed to begin");
+    let level: String = tx
+        .query_one("SHOW TRANSACTION ISOLATION LEVEL", ())
+        .expect("Failed to show isolation level");
+    assert_eq!(level, "READ COMMITTED");
+
+    tx.execute("SET TRANSACTION ISOLATION LEVEL SNAPSHOT", ())
+        .expect("Failed to set isolation level");
+    assert_eq!(
+        tx.isolation_level(),
+        Some(IsolationLevel::SnapshotIsolation)
+    );
+    let level: String = tx
+        .query_one("SHOW TRANSACTION ISOLATION LEVEL", ())
+        .expect("Failed to show isolation level");
+    assert_eq!(level, "SNAPSHOT");
+
+    // Not once the transaction has run a query
+    tx.execute("INSERT INTO t VALUES (1)", ())
+        .expect("Failed to insert");
+    assert!(tx
+        .execute("SET TRANSACTION ISOLATION LEVEL READ COMMITTED", ())
+        .is_err());
+    tx.commit().expect("Failed to commit");
+
+    // The database default is unchanged
+    assert_eq!(show_isolation_level(&db), "READ COMMITTED");
+}
@@ -0,0 +1,179 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! JSON_AGG / JSON_OBJECT_AGG Tests
+//!
+//! Tests aggregating forum posts into JSON per thread
+
+use oxibase::{Database, Value};
+
+fn setup(db: &Database) {
+    db.execute(
+        "CREATE TABLE threads (id INTEGER PRIMARY KEY, title TEXT)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE TABLE posts (id INTEGER PRIMARY KEY, thread_id INTEGER, author TEXT, body TEXT)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO threads VALUES (1, 'Welcome'), (2, 'Rules'), (3, 'Empty')",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO posts VALUES \
+         (1, 1, 'alice', 'Hello \"everyone\"'), \
+         (2, 1, 'bob', 'Hi!'), \
+         (3, 2, 'carol', NULL), \
+         (4, 1, 'alice', 'Glad to be here')",
+        (),
+    )
+    .unwrap();
+}
+
+/// Runs a query returning (id, json) rows and parses the JSON column
+fn json_rows(db: &Database, sql: &str) -> Vec<(i64, serde_json::Value)> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            let json = match row.get_value(1).unwrap() {
+                Value::Json(json) => serde_json::from_str(json).unwrap(),
+                other => panic!("expected JSON value, got {:?}", other),
+            };
+            (row.get(0).unwrap(), json)
+        })
+        .collect()
+}
+
+#[test]
+fn test_json_agg_per_thread() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let rows = json_rows(
+        &db,
+        "SELECT thread_id, JSON_AGG(body ORDER BY id) FROM posts \
+         GROUP BY thread_id ORDER BY thread_id",
+    );
+    assert_eq!(
+        rows,
+        vec![
+            (
+                1,
+                serde_json::json!(["Hello \"everyone\"", "Hi!", "Glad to be here"])
+            ),
+            // NULLs are kept as JSON null
+            (2, serde_json::json!([null])),
+        ]
+    );
+}
+
+#[test]
+fn test_json_agg_rows() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let rows = json_rows(
+        &db,
+        "SELECT t.id, JSON_AGG(p.* ORDER BY p.id DESC) FROM threads t \
+         JOIN posts p ON p.thread_id = t.id GROUP BY t.id ORDER BY t.id",
+    );
+    assert_eq!(rows.len(), 2);
+    assert_eq!(
+        rows[0].1,
+        serde_json::json!([
+            {"id": 4, "thread_id": 1, "author": "alice", "body": "Glad to be here"},
+            {"id": 2, "thread_id": 1, "author": "bob", "body": "Hi!"},
+            {"id": 1, "thread_id": 1, "author": "alice", "body": "Hello \"everyone\""},
+        ])
+    );
+    assert_eq!(
+        rows[1].1,
+        serde_json::json!([{"id": 3, "thread_id": 2, "author": "carol", "body": null}])
+    );
+
+    // Nested response built with JSON_OBJECT
+    let rows = json_rows(
+        &db,
+        "SELECT thread_id, JSON_AGG(JSON_OBJECT('by', author, 'text', body)) FROM posts \
+         WHERE thread_id = 2 GROUP BY thread_id",
+    );
+    assert_eq!(
+        rows,
+        vec![(2, serde_json::json!([{"by": "carol", "text": null}]))]
+    );
+}
+
+#[test]
+fn test_json_object_agg() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let rows = json_rows(
+        &db,
+        "SELECT thread_id, JSON_OBJECT_AGG(id, author) FROM posts \
+         GROUP BY thread_id ORDER BY thread_id",
+    );
+    assert_eq!(
+        rows,
+        vec![
+            (
+                1,
+                serde_json::json!({"1": "alice", "2": "bob", "4": "alice"})
+            ),
+            (2, serde_json::json!({"3": "carol"})),
+        ]
+    );
+
+    // Duplicate keys: the last value in ORDER BY order wins
+    let by_author: String = db
+        .query_one(
+            "SELECT JSON_OBJECT_AGG(author, body ORDER BY id) FROM posts WHERE thread_id = 1",
+            (),
+        )
+        .unwrap();
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&by_author).unwrap(),
+        serde_json::json!({"alice": "Glad to be here", "bob": "Hi!"})
+    );
+
+    assert!(db
+        .query("SELECT JSON_OBJECT_AGG(author) FROM posts", ())
+        .is_err());
+}
+
+#[test]
+fn test_json_agg_empty_group() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let mut rows = db
+        .query(
+            "SELECT JSON_AGG(body), JSON_OBJECT_AGG(author, body) FROM posts WHERE thread_id = 3",
+            (),
+        )
+        .unwrap();
+    let row = rows.next().unwrap().unwrap();
+    assert!(row.get_value(0).unwrap().is_null());
+    assert!(row.get_value(1).unwrap().is_null());
+
+    let type_name: String = db
+        .query_one("SELECT TYPEOF(JSON_AGG(id)) FROM posts", ())
+        .unwrap();
+    assert_eq!(type_name, "JSON");
+}
@@ -0,0 +1,173 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! JSON_TABLE Tests
+//!
+//! Tests projecting JSON arrays into typed rows with JSON_TABLE
+
+use oxibase::{Database, Value};
+
+fn setup() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE orders (id INTEGER PRIMARY KEY, customer TEXT, doc JSON)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        r#"INSERT INTO orders VALUES
+            (1, 'ann', '{"items": [{"name": "pen", "qty": 2, "price": 1.5}, {"name": "ink", "qty": "3"}]}'),
+            (2, 'bob', '{"items": [{"name": "pad", "qty": 1, "price": 4.25}]}'),
+            (3, 'cy', '{"items": []}')"#,
+        (),
+    )
+    .unwrap();
+    db
+}
+
+fn collect(db: &Database, sql: &str) -> Vec<Vec<Value>> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (0..row.len())
+                .map(|i| row.get_value(i).cloned().unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_json_table_from_literal() {
+    let db = Database::open_in_memory().unwrap();
+    let mut rows = db
+        .query(
+            r#"SELECT * FROM JSON_TABLE(
+                '{"items": [{"name": "pen", "qty": 2}, {"name": "ink"}]}',
+                '$.items[*]'
+                COLUMNS (name TEXT PATH '$.name', qty INTEGER PATH '$.qty')
+            ) AS t"#,
+            (),
+        )
+        .unwrap();
+    assert_eq!(rows.columns(), ["name", "qty"]);
+
+    let first = rows.next().unwrap().unwrap();
+    assert_eq!(first.get::<String>(0).unwrap(), "pen");
+    assert_eq!(first.get::<i64>(1).unwrap(), 2);
+    // A missing value is NULL
+    let second = rows.next().unwrap().unwrap();
+    assert_eq!(second.get::<String>(0).unwrap(), "ink");
+    assert!(second.get_value(1).unwrap().is_null());
+    assert!(rows.next().is_none());
+}
+
+#[test]
+fn test_json_table_extracts_column_into_typed_rows() {
+    let db = setup();
+
+    let rows = collect(
+        &db,
+        "SELECT o.id, j.name, j.qty, j.price
+         FROM orders o, JSON_TABLE(o.doc, '$.items[*]' COLUMNS (
+             name TEXT PATH '$.name',
+             qty INTEGER PATH '$.qty',
+             price FLOAT PATH '$.price'
+         )) AS j
+         ORDER BY o.id, j.name",
+    );
+    assert_eq!(
+        rows,
+        vec![
+            vec![
+                Value::Integer(1),
+                Value::text("ink"),
+                // '3' is converted to the declared type
+                Value::Integer(3),
+                Value::null_unknown(),
+            ],
+            vec![
+                Value::Integer(1),
+                Value::text("pen"),
+                Value::Integer(2),
+                Value::Float(1.5),
+            ],
+            vec![
+                Value::Integer(2),
+                Value::text("pad"),
+                Value::Integer(1),
+                Value::Float(4.25),
+            ],
+        ]
+    );
+
+    // Columns resolve without qualifiers too
+    assert_eq!(
+        collect(
+            &db,
+            "SELECT name FROM orders, JSON_TABLE(doc, '$.items[*]'
+                 COLUMNS (name TEXT PATH '$.name')) j
+             WHERE id = 2",
+        ),
+        vec![vec![Value::text("pad")]]
+    );
+
+    // Filters and aggregates see the projected columns
+    assert_eq!(
+        collect(
+            &db,
+            "SELECT o.customer, SUM(j.qty) AS total
+             FROM orders o, JSON_TABLE(o.doc, '$.items[*]'
+                 COLUMNS (qty INTEGER PATH '$.qty')) j
+             WHERE j.qty > 1
+             GROUP BY o.customer",
+        ),
+        vec![vec![Value::text("ann"), Value::Integer(5)]]
+    );
+
+    // LEFT JOIN keeps orders without items
+    assert_eq!(
+        collect(
+            &db,
+            "SELECT o.id, j.name
+             FROM orders o LEFT JOIN JSON_TABLE(o.doc, '$.items[*]'
+                 COLUMNS (name TEXT PATH '$.name')) j ON true
+             WHERE o.id = 3",
+        ),
+        vec![vec![Value::Integer(3), Value::null_unknown()]]
+    );
+}
+
+#[test]
+fn test_json_table_errors() {
+    let db = Database::open_in_memory().unwrap();
+
+    // COLUMNS is required
+    assert!(db
+        .query("SELECT * FROM JSON_TABLE('[1]', '$[*]')", ())
+        .is_err());
+    // Each column needs a PATH
+    assert!(db
+        .query(
+            "SELECT * FROM JSON_TABLE('[1]', '$[*]' COLUMNS (a INTEGER))",
+            ()
+        )
+        .is_err());
+    assert!(db
+        .query(
+            "SELECT * FROM JSON_TABLE('not json', '$[*]' COLUMNS (a INTEGER PATH '$'))",
+            ()
+        )
+        .is_err());
+}
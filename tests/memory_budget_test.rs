@@ -0,0 +1,105 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! max_memory budget for in-memory databases
+//!
+//! Writes that would push the estimated row memory past the limit fail with
+//! `Error::OutOfMemory`; nothing is evicted.
+
+use oxibase::{Database, Error};
+
+/// Insert 1KB rows until one fails, returning the error and the rows stored
+fn fill(db: &Database) -> (Error, i64) {
+    db.execute("CREATE TABLE blobs (id INTEGER PRIMARY KEY, data TEXT)", ())
+        .unwrap();
+    let data = "x".repeat(1024);
+    for id in 1..=10_000 {
+        if let Err(err) = db.execute("INSERT INTO blobs VALUES ($1, $2)", (id, data.as_str())) {
+            return (err, id - 1);
+        }
+    }
+    panic!("limit never reached");
+}
+
+fn count(db: &Database) -> i64 {
+    db.query_one("SELECT COUNT(*) FROM blobs", ()).unwrap()
+}
+
+#[test]
+fn test_insert_until_limit() {
+    let db = Database::open("memory://?max_memory=256KB").unwrap();
+    let (err, stored) = fill(&db);
+
+    assert!(matches!(err, Error::OutOfMemory { .. }), "{:?}", err);
+    assert!(err.to_string().contains("out of memory"), "{}", err);
+    // Roughly 256 rows of 1KB fit; per-row overhead takes the rest
+    assert!((150..256).contains(&stored), "{}", stored);
+    assert_eq!(count(&db), stored);
+
+    // Deleting gives space back
+    db.execute("DELETE FROM blobs WHERE id <= 50", ()).unwrap();
+    for id in 1..=50 {
+        db.execute("INSERT INTO blobs VALUES ($1, 'again')", (id,))
+            .unwrap();
+    }
+}
+
+#[test]
+fn test_set_max_memory() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("SET max_memory = '128KB'", ()).unwrap();
+    let (err, stored) = fill(&db);
+    assert!(matches!(err, Error::OutOfMemory { .. }), "{:?}", err);
+
+    // Updates that grow a row are refused; shrinking ones still go through
+    let big = "y".repeat(64 * 1024);
+    let err = db
+        .execute("UPDATE blobs SET data = $1 WHERE id = 1", (big.as_str(),))
+        .unwrap_err();
+    assert!(matches!(err, Error::OutOfMemory { .. }), "{:?}", err);
+    db.execute("UPDATE blobs SET data = 'small'", ()).unwrap();
+
+    // Raising the limit (or removing it) allows more rows
+    db.execute("SET max_memory = 0", ()).unwrap();
+    db.execute("INSERT INTO blobs VALUES (100000, 'more')", ())
+        .unwrap();
+    assert_eq!(count(&db), stored + 1);
+
+    assert!(db.execute("SET max_memory = 'lots'", ()).is_err());
+}
+
+#[test]
+fn test_failed_transaction_leaves_data_unchanged() {
+    let db = Database::open("memory://?max_memory=64KB").unwrap();
+    db.execute("CREATE TABLE blobs (id INTEGER PRIMARY KEY, data TEXT)", ())
+        .unwrap();
+    db.execute("INSERT INTO blobs VALUES (1, 'keep')", ())
+        .unwrap();
+
+    // A single multi-row insert is checked as a whole
+    let rows = (2..200)
+        .map(|id| format!("({}, '{}')", id, "z".repeat(1024)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let err = db
+        .execute(&format!("INSERT INTO blobs VALUES {}", rows), ())
+        .unwrap_err();
+    assert!(matches!(err, Error::OutOfMemory { .. }), "{:?}", err);
+    assert_eq!(count(&db), 1);
+
+    // Dropping the table frees its rows
+    db.execute("DROP TABLE blobs", ()).unwrap();
+    let (_, stored) = fill(&db);
+    assert!(stored > 40, "{}", stored);
+}
@@ -242,13 +242,13 @@ mod multi_backend_integration_tests {
             .map(|row| row.unwrap().get::<String>(0).unwrap())
             .collect();
 
-        assert!(functions.contains(&"FUNC_RHAI".to_string()));
+        assert!(functions.contains(&"func_rhai".to_string()));
 
         #[cfg(any())]
-        assert!(functions.contains(&"FUNC_DENO".to_string()));
+        assert!(functions.contains(&"func_deno".to_string()));
 
         #[cfg(feature = "python")]
-        assert!(functions.contains(&"FUNC_PYTHON".to_string()));
+        assert!(functions.contains(&"func_python".to_string()));
     }
 
     #[test]
@@ -281,7 +281,7 @@ mod multi_backend_integration_tests {
                 r#"
             SELECT function_name, function_type, data_type
             FROM information_schema.functions
-            WHERE function_name LIKE 'INFO_%'
+            WHERE function_name LIKE 'info_%'
             ORDER BY function_name
         "#,
                 (),
@@ -299,9 +299,9 @@ mod multi_backend_integration_tests {
             .filter_map(|row| row.as_ref().ok().map(|r| r.get::<String>(0).unwrap()))
             .collect();
 
-        assert!(function_names.contains(&"INFO_RHAI".to_string()));
+        assert!(function_names.contains(&"info_rhai".to_string()));
 
         #[cfg(any())]
-        assert!(function_names.contains(&"INFO_DENO".to_string()));
+        assert!(function_names.contains(&"info_deno".to_string()));
     }
 }
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -208,3 +209,82 @@ UPDATE products SET price = 10.99 WHERE id = 1;
     let statements = result.unwrap();
     assert_eq!(statements.len(), 4, "Expected 4 statements");
 }
+
+/// Test comments inside and between statements
+#[test]
+fn test_comment_heavy_script() {
+    let sql = "
+-- Schema
+CREATE TABLE items ( -- items for sale
+    id INTEGER PRIMARY KEY, /* surrogate key */
+    name TEXT -- display name
+);
+/* Seed
+   data */
+INSERT INTO items (id, /* skip price */ name) VALUES (1, 'a'), -- first
+    (2, 'b');
+SELECT COUNT(/* all */ *) FROM items WHERE /* filter */ id > 0 -- trailing
+; -- done
+";
+
+    let statements = parse_sql(sql).unwrap();
+    assert_eq!(statements.len(), 3);
+    assert_eq!(
+        statements[1].to_string(),
+        "INSERT INTO items (id, name) VALUES (1, 'a'), (2, 'b')"
+    );
+
+    // Comment markers inside strings are kept
+    let statements = parse_sql("SELECT '-- not a comment', '/* nor this */'").unwrap();
+    assert_eq!(
+        statements[0].to_string(),
+        "SELECT '-- not a comment', '/* nor this */'"
+    );
+}
+
+/// Test empty statements between and around real ones
+#[test]
+fn test_empty_statements_between_semicolons() {
+    let statements = parse_sql(";; SELECT 1;;; /* empty */ ; SELECT 2;;").unwrap();
+    assert_eq!(statements.len(), 2);
+    assert_eq!(statements[1].to_string(), "SELECT 2");
+}
+
+/// Test statement separator errors
+#[test]
+fn test_statement_separator_errors() {
+    // Statements must be separated by semicolons
+    let err = parse_sql("SELECT 1 SELECT 2").unwrap_err();
+    assert!(err.to_string().contains("expected ;"), "{}", err);
+
+    let err = parse_sql("SELECT 1; SELECT 2 /* unterminated").unwrap_err();
+    assert!(
+        err.to_string().contains("unterminated block comment"),
+        "{}",
+        err
+    );
+
+    // Parsing resumes at the next statement after an error
+    let err = parse_sql("SELECT FROM; SELECT 1; SELECT 2 3").unwrap_err();
+    assert_eq!(err.errors.len(), 2, "{}", err);
+}
+
+/// Test executing a script with comments and empty statements
+#[test]
+fn test_execute_commented_script() {
+    let db = oxibase::Database::open_in_memory().unwrap();
+    db.execute(
+        "-- setup
+         CREATE TABLE t (id INTEGER /* pk */ PRIMARY KEY, v TEXT);;
+         INSERT INTO t VALUES (1, 'x'); -- one
+         /* two */ INSERT INTO t VALUES (2, 'y');
+         ;",
+        (),
+    )
+    .unwrap();
+
+    let count: i64 = db
+        .query_one("SELECT COUNT(*) -- rows\nFROM t /* all */", ())
+        .unwrap();
+    assert_eq!(count, 2);
+}
@@ -13,7 +13,8 @@
 // limitations under the License.
 
 //! Integration tests for new SQL functions and syntax
-//! Tests CONCAT_WS, STRPOS, POSITION(x IN y), and CURRENT_TRANSACTION_ID
+//! Tests CONCAT_WS, STRPOS, POSITION(x IN y), CURRENT_TRANSACTION_ID,
+//! TXID_CURRENT and TXID_STATUS
 
 use oxibase::Database;
 
@@ -295,3 +296,62 @@ fn test_current_transaction_id_after_rollback() {
     let row = result.into_iter().next().unwrap().unwrap();
     assert!(row.get::<Option<i64>>(0).unwrap().is_none());
 }
+
+// ============================================================================
+// TXID_CURRENT / TXID_STATUS Function Tests
+// ============================================================================
+
+#[test]
+fn test_txid_current_matches_api_transaction() {
+    let db = Database::open("memory://test_txid_current").expect("Failed to create database");
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY)", ())
+        .unwrap();
+
+    let mut tx = db.begin().unwrap();
+    let txid: i64 = tx.query_one("SELECT TXID_CURRENT()", ()).unwrap();
+    assert_eq!(txid, tx.id());
+    let status: String = tx.query_one("SELECT TXID_STATUS($1)", (tx.id(),)).unwrap();
+    assert_eq!(status, "in progress");
+    let id = tx.id();
+    tx.commit().unwrap();
+
+    let status: String = db.query_one("SELECT TXID_STATUS($1)", (id,)).unwrap();
+    assert_eq!(status, "committed");
+}
+
+#[test]
+fn test_txid_status() {
+    let db = Database::open("memory://test_txid_status").expect("Failed to create database");
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY)", ())
+        .unwrap();
+
+    // TXID_CURRENT is an alias of CURRENT_TRANSACTION_ID
+    db.execute("BEGIN", ()).unwrap();
+    let committed: i64 = db.query_one("SELECT TXID_CURRENT()", ()).unwrap();
+    let same: i64 = db.query_one("SELECT CURRENT_TRANSACTION_ID()", ()).unwrap();
+    assert_eq!(committed, same);
+    let status: String = db
+        .query_one("SELECT TXID_STATUS(TXID_CURRENT())", ())
+        .unwrap();
+    assert_eq!(status, "in progress");
+    db.execute("INSERT INTO t VALUES (1)", ()).unwrap();
+    db.execute("COMMIT", ()).unwrap();
+
+    db.execute("BEGIN", ()).unwrap();
+    let aborted: i64 = db.query_one("SELECT TXID_CURRENT()", ()).unwrap();
+    db.execute("INSERT INTO t VALUES (2)", ()).unwrap();
+    db.execute("ROLLBACK", ()).unwrap();
+
+    let status: String = db
+        .query_one("SELECT TXID_STATUS($1)", (committed,))
+        .unwrap();
+    assert_eq!(status, "committed");
+    let status: String = db.query_one("SELECT TXID_STATUS($1)", (aborted,)).unwrap();
+    assert_eq!(status, "aborted");
+
+    // Unknown IDs and NULL give NULL
+    let status: Option<String> = db.query_one("SELECT TXID_STATUS(1000000)", ()).unwrap();
+    assert!(status.is_none());
+    let status: Option<String> = db.query_one("SELECT TXID_STATUS(NULL)", ()).unwrap();
+    assert!(status.is_none());
+}
@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -417,3 +418,78 @@ fn test_functions_with_table_data() {
         .expect("Failed to execute EXTRACT on table");
     assert_eq!(result, 2);
 }
+
+// ============================================================================
+// UNACCENT / NORMALIZE Function Tests
+// ============================================================================
+
+#[test]
+fn test_unaccent_lookup() {
+    let db = Database::open("memory://unaccent_lookup").expect("Failed to create database");
+    db.execute(
+        "CREATE TABLE cities (id INTEGER PRIMARY KEY, name TEXT)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO cities VALUES (1, 'São Paulo'), (2, 'Zürich'), (3, 'Kraków'), (4, 'Sao Tome')",
+        (),
+    )
+    .unwrap();
+
+    let result: String = db.query_one("SELECT UNACCENT('Crème Brûlée')", ()).unwrap();
+    assert_eq!(result, "Creme Brulee");
+
+    // Accent- and case-insensitive search
+    let ids: Vec<i64> = db
+        .query(
+            "SELECT id FROM cities WHERE LOWER(UNACCENT(name)) LIKE 'sao%' ORDER BY id",
+            (),
+        )
+        .unwrap()
+        .map(|row| row.unwrap().get(0).unwrap())
+        .collect();
+    assert_eq!(ids, vec![1, 4]);
+
+    let id: i64 = db
+        .query_one(
+            "SELECT id FROM cities WHERE UNACCENT(name) = UNACCENT('Krakow')",
+            (),
+        )
+        .unwrap();
+    assert_eq!(id, 3);
+
+    // Multibyte characters count correctly after unaccenting
+    let len: i64 = db
+        .query_one("SELECT LENGTH(UNACCENT('Zürich'))", ())
+        .unwrap();
+    assert_eq!(len, 6);
+}
+
+#[test]
+fn test_normalize_forms() {
+    let db = Database::open("memory://normalize_forms").expect("Failed to create database");
+
+    // 'é' precomposed vs 'e' + combining acute accent
+    let equal: bool = db
+        .query_one(
+            "SELECT NORMALIZE('caf\u{e9}', 'NFC') = NORMALIZE('cafe\u{301}', 'NFC')",
+            (),
+        )
+        .unwrap();
+    assert!(equal);
+
+    let len: i64 = db
+        .query_one("SELECT LENGTH(NORMALIZE('caf\u{e9}', 'NFD'))", ())
+        .unwrap();
+    assert_eq!(len, 5);
+
+    let len: i64 = db
+        .query_one("SELECT LENGTH(NORMALIZE('cafe\u{301}'))", ())
+        .unwrap();
+    assert_eq!(len, 4);
+
+    // Unknown forms are an evaluation error, which yields NULL
+    let result: Option<String> = db.query_one("SELECT NORMALIZE('x', 'NFZ')", ()).unwrap();
+    assert_eq!(result, None);
+}
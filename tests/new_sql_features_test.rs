@@ -720,6 +720,156 @@ fn test_filter_no_match() {
     assert_eq!(result, 0, "COUNT(*) with no matches should be 0");
 }
 
+fn setup_filter_case_table(db: &Database) {
+    db.execute(
+        "CREATE TABLE items (id INTEGER PRIMARY KEY, grp TEXT, x INTEGER, active BOOLEAN)",
+        (),
+    )
+    .expect("Failed to create table");
+    db.execute(
+        "INSERT INTO items VALUES
+         (1, 'a', 1, true), (2, 'a', -1, false), (3, 'b', NULL, true),
+         (4, 'b', 5, NULL), (5, 'a', 1, true), (6, 'b', 0, false)",
+        (),
+    )
+    .expect("Failed to insert");
+}
+
+#[test]
+fn test_filter_matches_case_count() {
+    let db = Database::open("memory://filter_vs_case").expect("Failed to create database");
+    setup_filter_case_table(&db);
+
+    let result = db
+        .query(
+            "SELECT COUNT(*) FILTER (WHERE x > 0), COUNT(CASE WHEN x > 0 THEN 1 END) FROM items",
+            (),
+        )
+        .expect("Failed to query");
+    let row = result
+        .into_iter()
+        .next()
+        .expect("Expected one row")
+        .expect("Failed to get row");
+    let filtered: i64 = row.get(0).expect("Failed to get filtered count");
+    let case_count: i64 = row.get(1).expect("Failed to get case count");
+    assert_eq!(filtered, 3);
+    assert_eq!(filtered, case_count);
+
+    // NULL filter results exclude the row, like a false predicate
+    let active: i64 = db
+        .query_one("SELECT COUNT(*) FILTER (WHERE active) FROM items", ())
+        .expect("Failed to query");
+    assert_eq!(active, 3);
+}
+
+#[test]
+fn test_filter_matches_case_count_grouped() {
+    let db = Database::open("memory://filter_vs_case_grouped").expect("Failed to create database");
+    setup_filter_case_table(&db);
+
+    let result = db
+        .query(
+            "SELECT grp,
+                    COUNT(*) FILTER (WHERE x > 0),
+                    COUNT(CASE WHEN x > 0 THEN 1 END)
+             FROM items
+             GROUP BY grp
+             ORDER BY grp",
+            (),
+        )
+        .expect("Failed to query");
+
+    let mut rows: Vec<(String, i64, i64)> = Vec::new();
+    for row in result {
+        let row = row.expect("Failed to get row");
+        rows.push((
+            row.get(0).expect("Failed to get grp"),
+            row.get(1).expect("Failed to get filtered count"),
+            row.get(2).expect("Failed to get case count"),
+        ));
+    }
+
+    assert_eq!(rows, vec![("a".to_string(), 2, 2), ("b".to_string(), 1, 1)]);
+}
+
+#[test]
+fn test_filter_with_rollup() {
+    let db = Database::open("memory://filter_rollup").expect("Failed to create database");
+    setup_filter_case_table(&db);
+
+    let result = db
+        .query(
+            "SELECT grp, COUNT(*) FILTER (WHERE x > 0)
+             FROM items
+             GROUP BY ROLLUP(grp)
+             ORDER BY grp NULLS LAST",
+            (),
+        )
+        .expect("Failed to query");
+
+    let mut rows: Vec<(Option<String>, i64)> = Vec::new();
+    for row in result {
+        let row = row.expect("Failed to get row");
+        rows.push((
+            row.get(0).expect("Failed to get grp"),
+            row.get(1).expect("Failed to get count"),
+        ));
+    }
+
+    assert_eq!(
+        rows,
+        vec![
+            (Some("a".to_string()), 2),
+            (Some("b".to_string()), 1),
+            (None, 3)
+        ]
+    );
+}
+
+#[test]
+fn test_filter_window_aggregate() {
+    let db = Database::open("memory://filter_window").expect("Failed to create database");
+    setup_filter_case_table(&db);
+
+    let result = db
+        .query(
+            "SELECT id, SUM(x) FILTER (WHERE x > 0) OVER (PARTITION BY grp)
+             FROM items
+             ORDER BY id",
+            (),
+        )
+        .expect("Failed to query");
+
+    let mut sums: Vec<i64> = Vec::new();
+    for row in result {
+        let row = row.expect("Failed to get row");
+        sums.push(row.get(1).expect("Failed to get sum"));
+    }
+
+    assert_eq!(sums, vec![2, 2, 5, 5, 2, 5]);
+}
+
+#[test]
+fn test_filter_rejected_on_non_aggregate() {
+    let db = Database::open("memory://filter_invalid").expect("Failed to create database");
+    setup_filter_case_table(&db);
+
+    let err = db
+        .query("SELECT UPPER(grp) FILTER (WHERE active) FROM items", ())
+        .err()
+        .expect("FILTER on a scalar function should fail");
+    assert!(err.to_string().contains("not an aggregate function"));
+
+    let err = db
+        .query("SELECT COUNT(*) FILTER (WHERE COUNT(*) > 1) FROM items", ())
+        .err()
+        .expect("aggregate inside FILTER should fail");
+    assert!(err
+        .to_string()
+        .contains("aggregate functions are not allowed in FILTER"));
+}
+
 // ============================================================================
 // JSON_ARRAY_LENGTH Function
 // ============================================================================
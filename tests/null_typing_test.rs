@@ -0,0 +1,142 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typing of NULL values
+//!
+//! A NULL keeps the type of the column or expression it came from instead of
+//! being reported as TEXT.
+
+use oxibase::{DataType, Database, Value};
+
+fn setup(db: &Database) {
+    db.execute(
+        "CREATE TABLE t (id INTEGER PRIMARY KEY, a INTEGER, f FLOAT, ts TIMESTAMP)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO t VALUES (1, NULL, NULL, NULL), (2, NULL, NULL, NULL)",
+        (),
+    )
+    .unwrap();
+}
+
+/// Types of the values in the first result row
+fn types(db: &Database, sql: &str) -> Vec<DataType> {
+    let row = db.query(sql, ()).unwrap().next().unwrap().unwrap();
+    (0..row.len())
+        .map(|i| {
+            let value = row.get_value(i).unwrap();
+            assert!(value.is_null(), "{}: {:?}", sql, value);
+            value.data_type()
+        })
+        .collect()
+}
+
+#[test]
+fn test_cast_null() {
+    let db = Database::open_in_memory().unwrap();
+
+    assert_eq!(types(&db, "SELECT NULL::INTEGER"), vec![DataType::Integer]);
+    assert_eq!(
+        types(&db, "SELECT CAST(NULL AS FLOAT), NULL::TIMESTAMP"),
+        vec![DataType::Float, DataType::Timestamp]
+    );
+    assert_eq!(types(&db, "SELECT NULL"), vec![DataType::Null]);
+
+    let n: i64 = db.query_one("SELECT '12'::INTEGER + 1", ()).unwrap();
+    assert_eq!(n, 13);
+}
+
+#[test]
+fn test_all_null_columns() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    assert_eq!(
+        types(&db, "SELECT a, f, ts FROM t"),
+        vec![DataType::Integer, DataType::Float, DataType::Timestamp]
+    );
+    assert_eq!(
+        types(&db, "SELECT a + 1, a * 2.5, f - 1, a / 2 FROM t"),
+        vec![
+            DataType::Integer,
+            DataType::Float,
+            DataType::Float,
+            DataType::Integer
+        ]
+    );
+    assert_eq!(
+        types(&db, "SELECT COALESCE(a, NULL), NULLIF(f, f) FROM t"),
+        vec![DataType::Integer, DataType::Float]
+    );
+}
+
+#[test]
+fn test_aggregates_over_nulls() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    assert_eq!(
+        types(&db, "SELECT SUM(a), MIN(a), MAX(f), MIN(ts), AVG(a) FROM t"),
+        vec![
+            DataType::Integer,
+            DataType::Integer,
+            DataType::Float,
+            DataType::Timestamp,
+            DataType::Float
+        ]
+    );
+    assert_eq!(
+        types(&db, "SELECT SUM(f), MAX(a) FROM t GROUP BY id"),
+        vec![DataType::Float, DataType::Integer]
+    );
+
+    // A value after the NULLs still wins
+    db.execute("INSERT INTO t VALUES (3, 7, 1.5, NULL)", ())
+        .unwrap();
+    let row = db
+        .query("SELECT MIN(a), MAX(f), SUM(a) FROM t", ())
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+    assert_eq!(row.get_value(0), Some(&Value::Integer(7)));
+    assert_eq!(row.get_value(1), Some(&Value::Float(1.5)));
+    assert_eq!(row.get_value(2), Some(&Value::Integer(7)));
+}
+
+#[test]
+fn test_create_table_as_select_all_null_columns() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    db.execute(
+        "CREATE TABLE c AS SELECT a, f, ts, NULL::BOOLEAN AS b, NULL AS n FROM t",
+        (),
+    )
+    .unwrap();
+    let ddl: String = db
+        .query("SHOW CREATE TABLE c", ())
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .get(1)
+        .unwrap();
+    assert_eq!(
+        ddl,
+        "CREATE TABLE c (a Integer, f Float, ts Timestamp, b Boolean, n Text)"
+    );
+}
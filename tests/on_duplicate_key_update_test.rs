@@ -351,3 +351,70 @@ fn test_row_count_after_upserts() {
 
     assert_eq!(usernames.len(), 3, "Expected 3 distinct usernames");
 }
+
+/// Test RETURNING reports whether each row was inserted or updated
+#[test]
+fn test_upsert_returning_reports_insert_or_update() {
+    let db = Database::open("memory://upsert_returning").expect("Failed to create database");
+    setup_users_table(&db);
+
+    db.execute(
+        "INSERT INTO users (id, username, email, age) VALUES (1, 'alice', 'a@example.com', 30)",
+        (),
+    )
+    .expect("Failed to insert");
+
+    // id 1 conflicts on the primary key and 'alice' on the unique index
+    let result = db
+        .query(
+            "INSERT INTO users (id, username, email, age) VALUES \
+             (1, 'alice2', 'new@example.com', 31), \
+             (2, 'bob', 'b@example.com', 25), \
+             (3, 'alice', 'x@example.com', 99) \
+             ON DUPLICATE KEY UPDATE age = age + 1 \
+             RETURNING id, username, age, (xmax = 0) AS inserted",
+            (),
+        )
+        .expect("Failed to upsert");
+    assert_eq!(
+        result.columns(),
+        &["id", "username", "age", "inserted"].map(String::from)
+    );
+
+    let rows: Vec<(i64, String, i64, bool)> = result
+        .map(|row| {
+            let row = row.expect("Failed to get row");
+            (
+                row.get(0).unwrap(),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+                row.get(3).unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        rows,
+        vec![
+            (1, "alice".to_string(), 31, false),
+            (2, "bob".to_string(), 25, true),
+            (1, "alice".to_string(), 32, false),
+        ]
+    );
+
+    // RETURNING * lists only the table's columns
+    let result = db
+        .query(
+            "INSERT INTO users (id, username) VALUES (4, 'carol') \
+             ON DUPLICATE KEY UPDATE age = 1 RETURNING *, xmax",
+            (),
+        )
+        .expect("Failed to insert");
+    assert_eq!(
+        result.columns(),
+        &["id", "username", "email", "age", "xmax"].map(String::from)
+    );
+    for row in result {
+        let xmax: i64 = row.expect("Failed to get row").get(4).unwrap();
+        assert_eq!(xmax, 0);
+    }
+}
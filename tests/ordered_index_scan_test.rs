@@ -0,0 +1,174 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Ordered Index Scan Tests
+//!
+//! Tests ORDER BY ... LIMIT served by reading a B-tree or multi-column
+//! index in order instead of sorting
+
+use oxibase::Database;
+
+fn setup(name: &str) -> Database {
+    let db = Database::open(&format!("memory://{}", name)).expect("Failed to create database");
+    db.execute(
+        "CREATE TABLE events (id INTEGER PRIMARY KEY, score INTEGER, bucket INTEGER NOT NULL, seq INTEGER NOT NULL, note TEXT)",
+        (),
+    )
+    .expect("Failed to create table");
+    db.execute("CREATE INDEX idx_score ON events(score)", ())
+        .expect("Failed to create index");
+    db.execute("CREATE INDEX idx_bucket_seq ON events(bucket, seq)", ())
+        .expect("Failed to create index");
+    db.execute(
+        "INSERT INTO events VALUES
+            (1, 30, 2, 1, 'a'), (2, NULL, 1, 2, 'b'), (3, 10, 1, 1, 'c'),
+            (4, 20, 3, 5, 'd'), (5, NULL, 2, 0, 'e'), (6, 40, 1, 3, 'f')",
+        (),
+    )
+    .expect("Failed to insert");
+    db
+}
+
+fn plan(db: &Database, sql: &str) -> String {
+    let rows = db
+        .query(&format!("EXPLAIN {}", sql), ())
+        .expect("Failed to explain");
+    let mut lines = Vec::new();
+    for row in rows {
+        let row = row.expect("Failed to read row");
+        lines.push(row.get::<String>(0).unwrap());
+    }
+    lines.join("\n")
+}
+
+fn ids(db: &Database, sql: &str) -> Vec<i64> {
+    let rows = db.query(sql, ()).expect("Failed to query");
+    rows.map(|row| row.expect("Failed to read row").get::<i64>(0).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_explain_shows_ordered_index_scan() {
+    let db = setup("ordered_scan_explain");
+
+    let covered = plan(&db, "SELECT * FROM events ORDER BY score DESC LIMIT 3");
+    assert!(
+        covered.contains("Index Scan using idx_score on events"),
+        "{}",
+        covered
+    );
+    assert!(
+        covered.contains("ordered index scan (no sort)"),
+        "{}",
+        covered
+    );
+
+    let prefix = plan(&db, "SELECT id FROM events ORDER BY bucket, seq LIMIT 2");
+    assert!(prefix.contains("idx_bucket_seq"), "{}", prefix);
+    assert!(
+        prefix.contains("ordered index scan (no sort)"),
+        "{}",
+        prefix
+    );
+
+    // These need a sort: no index column, mixed directions, no LIMIT, a filter
+    for sql in [
+        "SELECT * FROM events ORDER BY note LIMIT 3",
+        "SELECT * FROM events ORDER BY bucket, seq DESC LIMIT 3",
+        "SELECT * FROM events ORDER BY seq LIMIT 3",
+        "SELECT * FROM events ORDER BY score",
+        "SELECT * FROM events WHERE id > 1 ORDER BY score LIMIT 3",
+    ] {
+        let sorted = plan(&db, sql);
+        assert!(
+            !sorted.contains("ordered index scan"),
+            "{}:\n{}",
+            sql,
+            sorted
+        );
+    }
+}
+
+#[test]
+fn test_ordered_index_scan_results() {
+    let db = setup("ordered_scan_results");
+
+    // NULLs sort last ascending and first descending, as with a sort
+    let ascending = ids(&db, "SELECT id FROM events ORDER BY score LIMIT 10");
+    assert_eq!(ascending[..4], [3, 4, 1, 6]);
+    assert!(ascending[4..] == [2, 5] || ascending[4..] == [5, 2]);
+    let descending = ids(&db, "SELECT id FROM events ORDER BY score DESC LIMIT 3");
+    assert_eq!(descending[2], 6);
+    let nulls_first = ids(
+        &db,
+        "SELECT id FROM events ORDER BY score NULLS FIRST LIMIT 3",
+    );
+    assert_eq!(nulls_first[2], 3);
+    assert_eq!(
+        ids(&db, "SELECT id FROM events ORDER BY score LIMIT 2 OFFSET 2"),
+        vec![1, 6]
+    );
+
+    // Multi-column index prefix
+    assert_eq!(
+        ids(&db, "SELECT id FROM events ORDER BY bucket, seq LIMIT 4"),
+        vec![3, 2, 6, 5]
+    );
+    assert_eq!(
+        ids(&db, "SELECT id FROM events ORDER BY bucket DESC LIMIT 3")[0],
+        4
+    );
+}
+
+#[test]
+fn test_ordered_index_scan_skips_deleted_rows() {
+    let db = Database::open("memory://ordered_scan_deleted").expect("Failed to create database");
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, v INTEGER)", ())
+        .expect("Failed to create table");
+    db.execute("CREATE INDEX idx_v ON t(v)", ())
+        .expect("Failed to create index");
+    db.execute(
+        "INSERT INTO t SELECT value, value FROM generate_series(1, 5000) AS g(value)",
+        (),
+    )
+    .expect("Failed to insert");
+    // Deleted rows ahead of the first live ones must not shorten the result
+    db.execute("DELETE FROM t WHERE v <= 4990", ())
+        .expect("Failed to delete");
+
+    assert_eq!(
+        ids(&db, "SELECT id FROM t ORDER BY v LIMIT 5"),
+        vec![4991, 4992, 4993, 4994, 4995]
+    );
+}
+
+#[test]
+fn test_ordered_index_scan_sees_own_writes() {
+    let db = setup("ordered_scan_own_writes");
+
+    // Uncommitted rows are not in the index yet
+    db.execute("BEGIN", ())
+        .expect("Failed to begin transaction");
+    db.execute("INSERT INTO events VALUES (7, 1, 9, 9, 'g')", ())
+        .expect("Failed to insert");
+    assert_eq!(
+        ids(&db, "SELECT id FROM events ORDER BY score LIMIT 1"),
+        vec![7]
+    );
+    db.execute("ROLLBACK", ()).expect("Failed to rollback");
+    assert_eq!(
+        ids(&db, "SELECT id FROM events ORDER BY score LIMIT 1"),
+        vec![3]
+    );
+}
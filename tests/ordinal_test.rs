@@ -0,0 +1,150 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! ORDINAL() Tests
+//!
+//! ORDINAL() numbers the rows of the final output, after ORDER BY and
+//! LIMIT/OFFSET, whichever execution path produced them.
+
+use oxibase::Database;
+
+fn setup(db: &Database) {
+    db.execute(
+        "CREATE TABLE items (id INTEGER PRIMARY KEY, name TEXT, price INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO items VALUES (1, 'pear', 30), (2, 'apple', 10), (3, 'fig', 50), \
+         (4, 'kiwi', 20), (5, 'lime', 40)",
+        (),
+    )
+    .unwrap();
+}
+
+/// (ordinal, id) pairs in output order
+fn numbered(db: &Database, sql: &str) -> Vec<(i64, i64)> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (row.get::<i64>(0).unwrap(), row.get::<i64>(1).unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn test_ordinal_follows_order_by() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    assert_eq!(
+        numbered(&db, "SELECT ORDINAL(), id FROM items ORDER BY price"),
+        vec![(1, 2), (2, 4), (3, 1), (4, 5), (5, 3)]
+    );
+    assert_eq!(
+        numbered(
+            &db,
+            "SELECT ORDINAL() AS n, id FROM items ORDER BY name DESC"
+        ),
+        vec![(1, 1), (2, 5), (3, 4), (4, 3), (5, 2)]
+    );
+}
+
+#[test]
+fn test_ordinal_with_limit_offset() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    // Top-N path
+    assert_eq!(
+        numbered(
+            &db,
+            "SELECT ORDINAL(), id FROM items ORDER BY price DESC LIMIT 2"
+        ),
+        vec![(1, 3), (2, 5)]
+    );
+    // Each page restarts at 1
+    assert_eq!(
+        numbered(
+            &db,
+            "SELECT ORDINAL(), id FROM items ORDER BY price LIMIT 2 OFFSET 2"
+        ),
+        vec![(1, 1), (2, 5)]
+    );
+}
+
+#[test]
+fn test_ordinal_after_star_and_in_filters() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let rows: Vec<(i64, i64)> = db
+        .query(
+            "SELECT *, ORDINAL() FROM items WHERE price > 15 ORDER BY price DESC",
+            (),
+        )
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (row.get::<i64>(0).unwrap(), row.get::<i64>(3).unwrap())
+        })
+        .collect();
+    assert_eq!(rows, vec![(3, 1), (5, 2), (1, 3), (4, 4)]);
+
+    // Outside the select list there is nothing to number yet
+    let count: i64 = db
+        .query_one("SELECT COUNT(*) FROM items WHERE ORDINAL() IS NULL", ())
+        .unwrap();
+    assert_eq!(count, 5);
+}
+
+#[test]
+fn test_ordinal_with_group_by_and_union() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    assert_eq!(
+        numbered(
+            &db,
+            "SELECT ORDINAL(), price / 20 AS bucket FROM items GROUP BY price / 20 \
+             ORDER BY bucket DESC"
+        ),
+        vec![(1, 2), (2, 1), (3, 0)]
+    );
+    assert_eq!(
+        numbered(
+            &db,
+            "SELECT ORDINAL(), id FROM items WHERE id <= 2 \
+             UNION SELECT ORDINAL(), id FROM items WHERE id >= 2 ORDER BY 2 DESC"
+        ),
+        vec![(1, 5), (2, 4), (3, 3), (4, 2), (5, 1)]
+    );
+}
+
+#[test]
+fn test_ordinal_in_derived_table() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    // The subquery's own output order is numbered, the outer query re-sorts
+    assert_eq!(
+        numbered(
+            &db,
+            "SELECT n, id FROM (SELECT ORDINAL() AS n, id FROM items ORDER BY price) AS s \
+             ORDER BY id"
+        ),
+        vec![(3, 1), (1, 2), (5, 3), (2, 4), (4, 5)]
+    );
+}
@@ -0,0 +1,147 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! CSV and JSON rendering of query results
+//!
+//! Covers the shared renderers in `oxibase::api::output` and the CLI's
+//! `--format` flag.
+
+use oxibase::api::output::{rows_to_json, write_csv};
+use oxibase::{Database, Value};
+
+const SAMPLE: &str = "SELECT id, name, score, active FROM people ORDER BY id";
+
+fn sample_rows() -> (Vec<String>, Vec<Vec<Value>>) {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE people (id INTEGER PRIMARY KEY, name TEXT, score FLOAT, active BOOLEAN)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO people VALUES (1, 'Ann', 9.5, true), (2, 'Smith, \"Bo\"', NULL, false), \
+         (3, '', 7.0, NULL)",
+        (),
+    )
+    .unwrap();
+
+    let rows = db.query(SAMPLE, ()).unwrap();
+    let columns = rows.columns().to_vec();
+    let values = rows
+        .map(|row| {
+            let row = row.unwrap();
+            (0..row.len())
+                .map(|i| row.get_value(i).unwrap().clone())
+                .collect()
+        })
+        .collect();
+    (columns, values)
+}
+
+#[test]
+fn test_csv_output() {
+    let (columns, rows) = sample_rows();
+    let mut out = Vec::new();
+    write_csv(&mut out, &columns, &rows).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "id,name,score,active\n\
+         1,Ann,9.5,true\n\
+         2,\"Smith, \"\"Bo\"\"\",,false\n\
+         3,\"\",7,\n"
+    );
+}
+
+#[test]
+fn test_json_output() {
+    let (columns, rows) = sample_rows();
+    assert_eq!(
+        rows_to_json(&columns, &rows),
+        serde_json::json!({
+            "columns": ["id", "name", "score", "active"],
+            "rows": [
+                [1, "Ann", 9.5, true],
+                [2, "Smith, \"Bo\"", null, false],
+                [3, "", 7.0, null]
+            ],
+            "count": 3
+        })
+    );
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_format_flag() {
+    use std::process::Command;
+
+    let run = |format: &str| {
+        let output = Command::new(env!("CARGO_BIN_EXE_oxibase"))
+            .args([
+                "-q",
+                "--format",
+                format,
+                "-e",
+                "SELECT 1 AS id, 'a,b' AS name, NULL AS note",
+            ])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    assert_eq!(run("csv"), "id,name,note\n1,\"a,b\",\n");
+    assert_eq!(
+        run("json"),
+        "{\"columns\":[\"id\",\"name\",\"note\"],\"count\":1,\"rows\":[[1,\"a,b\",null]]}\n"
+    );
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn test_cli_table_renders_floats_like_values() {
+    use std::process::Command;
+
+    let run = |sql: &str| {
+        let output = Command::new(env!("CARGO_BIN_EXE_oxibase"))
+            .args(["-q", "-e", sql])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    // Full precision, and NaN/Infinity by name, rather than four fixed digits
+    let out = run("SELECT 1.0 / 3 AS third, CAST('Infinity' AS FLOAT) AS inf, 7.0 AS whole");
+    assert!(out.contains("0.3333333333333333"), "{}", out);
+    assert!(out.contains("Infinity"), "{}", out);
+    let whole = out.lines().last().unwrap().rsplit('|').next().unwrap();
+    assert_eq!(whole.trim(), "7", "{}", out);
+
+    // SET float_display_precision decides the digits shown
+    let script = std::env::temp_dir().join(format!("float_precision_{}.sql", std::process::id()));
+    std::fs::write(
+        &script,
+        "SET float_display_precision = 6;\nSELECT 1.0 / 3 AS third;\n",
+    )
+    .unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_oxibase"))
+        .args(["-q", "-f", script.to_str().unwrap()])
+        .output()
+        .unwrap();
+    std::fs::remove_file(&script).unwrap();
+    assert!(output.status.success());
+    let out = String::from_utf8(output.stdout).unwrap();
+    assert!(out.contains("0.333333"), "{}", out);
+    assert!(!out.contains("0.3333333"), "{}", out);
+}
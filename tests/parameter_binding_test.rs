@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -17,7 +18,7 @@
 //! Tests SQL parameter binding with ? placeholders
 
 use oxibase::Database;
-use oxibase::Value;
+use oxibase::{Error, Value};
 
 /// Test integer parameter binding
 #[test]
@@ -275,3 +276,143 @@ fn test_limit_parameter() {
 
     assert_eq!(count, 5, "LIMIT ? should limit to 5 rows");
 }
+
+/// Test binding fewer parameters than the query references
+#[test]
+fn test_too_few_parameters() {
+    let db = Database::open("memory://param_too_few").expect("Failed to create database");
+
+    db.execute("CREATE TABLE test_params (id INTEGER, name TEXT)", ())
+        .expect("Failed to create table");
+
+    let err = db
+        .execute("INSERT INTO test_params (id, name) VALUES ($1, $2)", (1,))
+        .expect_err("Expected parameter count mismatch");
+    assert_eq!(
+        err,
+        Error::ParameterCountMismatch {
+            expected: 2,
+            got: 1
+        }
+    );
+
+    // Same query again is served from the query cache and must still fail
+    let err = db
+        .execute("INSERT INTO test_params (id, name) VALUES ($1, $2)", (1,))
+        .expect_err("Expected parameter count mismatch");
+    assert_eq!(
+        err,
+        Error::ParameterCountMismatch {
+            expected: 2,
+            got: 1
+        }
+    );
+
+    // No parameters at all
+    let Err(err) = db.query("SELECT * FROM test_params WHERE id = ?", ()) else {
+        panic!("Expected parameter count mismatch");
+    };
+    assert_eq!(
+        err,
+        Error::ParameterCountMismatch {
+            expected: 1,
+            got: 0
+        }
+    );
+
+    let count: i64 = db
+        .query_one("SELECT COUNT(*) FROM test_params", ())
+        .expect("Failed to query");
+    assert_eq!(count, 0, "Nothing should have been inserted");
+}
+
+/// Test binding more parameters than the query references
+#[test]
+fn test_too_many_parameters() {
+    let db = Database::open("memory://param_too_many").expect("Failed to create database");
+
+    db.execute("CREATE TABLE test_params (id INTEGER, name TEXT)", ())
+        .expect("Failed to create table");
+
+    let err = db
+        .execute(
+            "INSERT INTO test_params (id, name) VALUES ($1, $2)",
+            (1, "a", "extra"),
+        )
+        .expect_err("Expected parameter count mismatch");
+    assert_eq!(
+        err,
+        Error::ParameterCountMismatch {
+            expected: 2,
+            got: 3
+        }
+    );
+
+    // Placeholders outside WHERE still count
+    let Err(err) = db.query("SELECT * FROM test_params ORDER BY id LIMIT ?", (5, 6)) else {
+        panic!("Expected parameter count mismatch");
+    };
+    assert_eq!(
+        err,
+        Error::ParameterCountMismatch {
+            expected: 1,
+            got: 2
+        }
+    );
+
+    // The highest placeholder determines the count, even if some are reused
+    db.execute(
+        "INSERT INTO test_params (id, name) VALUES ($2, $1)",
+        ("b", 2),
+    )
+    .expect("Matching parameter count should succeed");
+    let name: String = db
+        .query_one(
+            "SELECT name FROM test_params WHERE id = $1 OR id = $1",
+            (2,),
+        )
+        .expect("Failed to query");
+    assert_eq!(name, "b");
+}
+
+/// Test parameter count validation inside an explicit transaction
+#[test]
+fn test_parameter_count_in_transaction() {
+    let db = Database::open("memory://param_count_tx").expect("Failed to create database");
+
+    db.execute("CREATE TABLE p (id INTEGER)", ())
+        .expect("Failed to create table");
+
+    let mut tx = db.begin().expect("Failed to begin transaction");
+
+    let Err(err) = tx.query("SELECT * FROM p WHERE id = ?", ()) else {
+        panic!("Expected parameter count mismatch");
+    };
+    assert_eq!(
+        err,
+        Error::ParameterCountMismatch {
+            expected: 1,
+            got: 0
+        }
+    );
+
+    let err = tx
+        .execute("INSERT INTO p VALUES (?)", (2, 3))
+        .expect_err("Expected parameter count mismatch");
+    assert_eq!(
+        err,
+        Error::ParameterCountMismatch {
+            expected: 1,
+            got: 2
+        }
+    );
+
+    tx.execute("INSERT INTO p VALUES (?)", (2,))
+        .expect("Matching parameter count should succeed");
+    tx.commit().expect("Failed to commit");
+
+    let count: i64 = db
+        .query_one("SELECT COUNT(*) FROM p", ())
+        .expect("Failed to query");
+    assert_eq!(count, 1, "Only the valid insert should be applied");
+}
@@ -0,0 +1,97 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Password hashing for user tables
+//!
+//! Users are created with `HASH_PASSWORD` and authenticated with
+//! `VERIFY_PASSWORD` in SQL or `oxibase::server::verify_password` in Rust.
+
+#![cfg(feature = "server")]
+
+use oxibase::server::{hash_password, verify_password};
+use oxibase::Database;
+
+fn setup() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, username TEXT, password_hash TEXT)",
+        (),
+    )
+    .unwrap();
+    db
+}
+
+fn authenticate(db: &Database, username: &str, password: &str) -> bool {
+    db.query_one(
+        "SELECT COUNT(*) FROM users WHERE username = $1 AND VERIFY_PASSWORD($2, password_hash)",
+        (username, password),
+    )
+    .map(|n: i64| n == 1)
+    .unwrap()
+}
+
+#[test]
+fn test_created_user_can_authenticate() {
+    let db = setup();
+    db.execute(
+        "INSERT INTO users VALUES (1, 'alice', HASH_PASSWORD('correct horse'))",
+        (),
+    )
+    .unwrap();
+
+    let stored: String = db
+        .query_one("SELECT password_hash FROM users WHERE id = 1", ())
+        .unwrap();
+    assert!(stored.starts_with("$argon2id$"), "{}", stored);
+    assert!(!stored.contains("correct horse"));
+
+    assert!(authenticate(&db, "alice", "correct horse"));
+    assert!(!authenticate(&db, "alice", "battery staple"));
+    assert!(!authenticate(&db, "bob", "correct horse"));
+
+    // The Rust helpers agree with the SQL functions
+    assert!(verify_password("correct horse", &stored).unwrap());
+    assert!(!verify_password("Correct horse", &stored).unwrap());
+}
+
+#[test]
+fn test_rust_hash_verifies_in_sql() {
+    let db = setup();
+    let hash = hash_password("s3cret").unwrap();
+    db.execute(
+        "INSERT INTO users VALUES (1, 'carol', $1)",
+        (hash.as_str(),),
+    )
+    .unwrap();
+
+    assert!(authenticate(&db, "carol", "s3cret"));
+    assert!(!authenticate(&db, "carol", "s3cret "));
+}
+
+#[test]
+fn test_null_and_malformed_hashes() {
+    let db = setup();
+    let result: Option<bool> = db
+        .query_one("SELECT VERIFY_PASSWORD('x', NULL)", ())
+        .unwrap();
+    assert_eq!(result, None);
+    let result: Option<String> = db.query_one("SELECT HASH_PASSWORD(NULL)", ()).unwrap();
+    assert_eq!(result, None);
+
+    // A plaintext value in the hash column never authenticates
+    db.execute("INSERT INTO users VALUES (1, 'dave', 'hunter2')", ())
+        .unwrap();
+    assert!(!authenticate(&db, "dave", "hunter2"));
+    assert!(verify_password("hunter2", "hunter2").is_err());
+}
@@ -2022,7 +2022,7 @@ fn test_default_constraint_persistence() {
 }
 
 /// Test that CHECK constraints ARE properly persisted after database restart.
-/// FIXED: serialize_schema() and deserialize_schema() now include check_expr.
+/// FIXED: serialize_schema() and deserialize_schema() now include CHECK constraints.
 #[test]
 fn test_check_constraint_persistence() {
     let dir = tempdir().unwrap();
@@ -0,0 +1,167 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! pg_catalog Compatibility Tests
+//!
+//! Tests the catalog queries Postgres drivers issue on connect
+
+use oxibase::Database;
+
+fn setup(db: &Database) {
+    db.execute(
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL, meta JSON)",
+        (),
+    )
+    .unwrap();
+    db.execute("CREATE TABLE orders (id INTEGER, total FLOAT)", ())
+        .unwrap();
+    db.execute(
+        "CREATE VIEW big_orders AS SELECT * FROM orders WHERE total > 100",
+        (),
+    )
+    .unwrap();
+}
+
+fn strings(db: &Database, sql: &str) -> Vec<String> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| row.unwrap().get(0).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_pg_class() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    assert_eq!(
+        strings(
+            &db,
+            "SELECT relname FROM pg_catalog.pg_class \
+             WHERE relkind = 'r' AND relnamespace = 2200 ORDER BY relname"
+        ),
+        vec!["orders", "users"]
+    );
+    assert_eq!(
+        strings(
+            &db,
+            "SELECT relname FROM pg_catalog.pg_class WHERE relkind = 'v'"
+        ),
+        vec!["big_orders"]
+    );
+
+    let natts: i64 = db
+        .query_one(
+            "SELECT relnatts FROM pg_catalog.pg_class WHERE relname = 'users'",
+            (),
+        )
+        .unwrap();
+    assert_eq!(natts, 3);
+}
+
+#[test]
+fn test_pg_class_join_namespace() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let rows: Vec<(String, String)> = db
+        .query(
+            "SELECT n.nspname, c.relname FROM pg_catalog.pg_class c \
+             JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace \
+             WHERE c.relkind IN ('r', 'v') AND n.nspname = 'public' ORDER BY c.relname",
+            (),
+        )
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (row.get(0).unwrap(), row.get(1).unwrap())
+        })
+        .collect();
+    assert_eq!(
+        rows,
+        vec![
+            ("public".to_string(), "big_orders".to_string()),
+            ("public".to_string(), "orders".to_string()),
+            ("public".to_string(), "users".to_string()),
+        ]
+    );
+
+    assert_eq!(
+        strings(
+            &db,
+            "SELECT nspname FROM pg_catalog.pg_namespace ORDER BY oid"
+        ),
+        vec!["pg_catalog", "public", "information_schema", "system"]
+    );
+}
+
+#[test]
+fn test_pg_attribute_and_type() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let rows: Vec<(String, String, bool)> = db
+        .query(
+            "SELECT a.attname, t.typname, a.attnotnull FROM pg_catalog.pg_attribute a \
+             JOIN pg_catalog.pg_class c ON c.oid = a.attrelid \
+             JOIN pg_catalog.pg_type t ON t.oid = a.atttypid \
+             WHERE c.relname = 'users' AND a.attnum > 0 AND NOT a.attisdropped \
+             ORDER BY a.attnum",
+            (),
+        )
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (
+                row.get(0).unwrap(),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        rows,
+        vec![
+            ("id".to_string(), "int8".to_string(), true),
+            ("name".to_string(), "text".to_string(), true),
+            ("meta".to_string(), "json".to_string(), false),
+        ]
+    );
+
+    let oid: i64 = db
+        .query_one(
+            "SELECT oid FROM pg_catalog.pg_type WHERE typname = 'timestamptz'",
+            (),
+        )
+        .unwrap();
+    assert_eq!(oid, 1184);
+}
+
+#[test]
+fn test_pg_catalog_does_not_shadow_user_tables() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE pg_class (id INTEGER)", ())
+        .unwrap();
+    db.execute("INSERT INTO pg_class VALUES (42)", ()).unwrap();
+
+    let id: i64 = db.query_one("SELECT id FROM pg_class", ()).unwrap();
+    assert_eq!(id, 42);
+    assert_eq!(
+        strings(
+            &db,
+            "SELECT relname FROM pg_catalog.pg_class WHERE relnamespace = 2200"
+        ),
+        vec!["pg_class"]
+    );
+}
@@ -0,0 +1,382 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests for the parsed statement (plan) cache
+
+use oxibase::Database;
+
+fn count(db: &Database, sql: &str) -> i64 {
+    db.query_one::<i64, _>(sql, ()).unwrap()
+}
+
+fn column<T: oxibase::FromValue>(db: &Database, sql: &str) -> Vec<T> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| row.unwrap().get::<T>(0).unwrap())
+        .collect()
+}
+
+/// A table with ids 1 to 10 and v = id * 10
+fn numbers() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, v INTEGER)", ())
+        .unwrap();
+    for i in 1..=10 {
+        db.execute(&format!("INSERT INTO t VALUES ({}, {})", i, i * 10), ())
+            .unwrap();
+    }
+    db
+}
+
+/// Cache misses and hits since `before`
+fn cache_delta(db: &Database, before: &oxibase::CacheStats) -> (u64, u64) {
+    let after = db.plan_cache_stats().unwrap();
+    (after.misses - before.misses, after.hits - before.hits)
+}
+
+#[test]
+fn test_plan_cache_reuses_repeated_queries() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, v INTEGER)", ())
+        .unwrap();
+    db.execute("INSERT INTO t VALUES (1, 10), (2, 20)", ())
+        .unwrap();
+
+    let before = db.plan_cache_stats().unwrap();
+    for _ in 0..5 {
+        assert_eq!(count(&db, "SELECT COUNT(*) FROM t WHERE v > 5"), 2);
+    }
+    // Whitespace differences share an entry
+    assert_eq!(count(&db, "SELECT  COUNT(*)\n FROM t   WHERE v > 5"), 2);
+
+    let after = db.plan_cache_stats().unwrap();
+    assert_eq!(after.misses - before.misses, 1);
+    assert_eq!(after.hits - before.hits, 5);
+}
+
+#[test]
+fn test_plan_cache_invalidated_on_ddl() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, v INTEGER)", ())
+        .unwrap();
+    db.execute("INSERT INTO t VALUES (1, 10), (2, 20)", ())
+        .unwrap();
+
+    assert_eq!(count(&db, "SELECT COUNT(*) FROM t"), 2);
+    assert!(db.plan_cache_stats().unwrap().size > 0);
+
+    let invalidations = db.plan_cache_stats().unwrap().invalidations;
+    db.execute("ALTER TABLE t ADD COLUMN w INTEGER DEFAULT 7", ())
+        .unwrap();
+    let stats = db.plan_cache_stats().unwrap();
+    assert_eq!(stats.size, 0);
+    assert_eq!(stats.invalidations, invalidations + 1);
+
+    // Cached `SELECT *` picks up the new column
+    let row = db.query("SELECT * FROM t WHERE id = 1", ()).unwrap();
+    assert_eq!(row.columns(), &["id", "v", "w"]);
+    assert_eq!(count(&db, "SELECT SUM(w) FROM t"), 14);
+
+    // Dropping and recreating a table with a different shape
+    db.execute("DROP TABLE t", ()).unwrap();
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, name TEXT)", ())
+        .unwrap();
+    db.execute("INSERT INTO t VALUES (1, 'a')", ()).unwrap();
+    assert_eq!(count(&db, "SELECT COUNT(*) FROM t"), 1);
+    let row = db.query("SELECT * FROM t WHERE id = 1", ()).unwrap();
+    assert_eq!(row.columns(), &["id", "name"]);
+
+    // Index DDL invalidates too
+    let invalidations = db.plan_cache_stats().unwrap().invalidations;
+    db.execute("CREATE INDEX idx_t_name ON t (name)", ())
+        .unwrap();
+    assert_eq!(
+        db.plan_cache_stats().unwrap().invalidations,
+        invalidations + 1
+    );
+
+    // DML does not
+    db.execute("INSERT INTO t VALUES (2, 'b')", ()).unwrap();
+    assert_eq!(
+        db.plan_cache_stats().unwrap().invalidations,
+        invalidations + 1
+    );
+}
+
+#[test]
+fn test_plan_cache_size_setting() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY)", ())
+        .unwrap();
+
+    db.execute("SET plan_cache_size = 0", ()).unwrap();
+    let stats = db.plan_cache_stats().unwrap();
+    assert_eq!((stats.size, stats.max_size), (0, 0));
+    assert_eq!(count(&db, "SELECT COUNT(*) FROM t"), 0);
+    assert_eq!(count(&db, "SELECT COUNT(*) FROM t"), 0);
+    let stats = db.plan_cache_stats().unwrap();
+    assert_eq!((stats.size, stats.hits), (0, 0));
+
+    // The least recently used statement is evicted once the cache is full
+    db.execute("SET plan_cache_size = 2", ()).unwrap();
+    count(&db, "SELECT 1");
+    count(&db, "SELECT 2");
+    count(&db, "SELECT 1");
+    count(&db, "SELECT 3");
+    let stats = db.plan_cache_stats().unwrap();
+    assert_eq!(stats.size, 2);
+    let hits = stats.hits;
+    count(&db, "SELECT 1");
+    assert_eq!(db.plan_cache_stats().unwrap().hits, hits + 1);
+    count(&db, "SELECT 2");
+    assert_eq!(db.plan_cache_stats().unwrap().hits, hits + 1);
+
+    assert!(db.execute("SET plan_cache_size = -1", ()).is_err());
+    assert!(db.execute("SET plan_cache_size = 'big'", ()).is_err());
+}
+
+#[test]
+fn test_plan_cache_shares_plans_across_literals() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE t (id INTEGER PRIMARY KEY, v INTEGER, name TEXT)",
+        (),
+    )
+    .unwrap();
+
+    let before = db.plan_cache_stats().unwrap();
+    for i in 1..=5 {
+        db.execute(
+            &format!("INSERT INTO t VALUES ({}, {}, 'n{}')", i, i * 10, i),
+            (),
+        )
+        .unwrap();
+    }
+    for i in 1..=5 {
+        assert_eq!(
+            count(&db, &format!("SELECT v FROM t WHERE id = {}", i)),
+            i * 10
+        );
+        assert_eq!(
+            count(&db, &format!("SELECT id FROM t WHERE name = 'n{}'", i)),
+            i
+        );
+    }
+    db.execute("UPDATE t SET v = 7 WHERE id IN (1, 2)", ())
+        .unwrap();
+    db.execute("UPDATE t SET v = 8 WHERE id IN (3, 4)", ())
+        .unwrap();
+    let after = db.plan_cache_stats().unwrap();
+    // One entry each for the INSERT, the two SELECTs and the UPDATE
+    assert_eq!(after.misses - before.misses, 4);
+    assert_eq!(after.hits - before.hits, 4 + 4 + 4 + 1);
+
+    assert_eq!(count(&db, "SELECT SUM(v) FROM t"), 7 + 7 + 8 + 8 + 50);
+
+    // Literals in the select list stay part of the key
+    let row = db
+        .query("SELECT v + 1 FROM t WHERE id = 5", ())
+        .unwrap()
+        .map(|row| row.unwrap())
+        .next()
+        .unwrap();
+    assert_eq!(row.get::<i64>(0).unwrap(), 51);
+    let row = db
+        .query("SELECT v + 2 FROM t WHERE id = 5", ())
+        .unwrap()
+        .map(|row| row.unwrap())
+        .next()
+        .unwrap();
+    assert_eq!(row.get::<i64>(0).unwrap(), 52);
+}
+
+#[test]
+fn test_plan_cache_rebinds_literals() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE t (id INTEGER PRIMARY KEY, price FLOAT, name TEXT)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO t VALUES (1, 1.5, 'it''s'), (2, 2.5, 'a\\b'), (3, 3.5, 'c')",
+        (),
+    )
+    .unwrap();
+
+    // Each query reuses the plan of the first one with its own values
+    assert_eq!(count(&db, "SELECT id FROM t WHERE name = 'it''s'"), 1);
+    assert_eq!(count(&db, "SELECT id FROM t WHERE name = 'a\\b'"), 2);
+    assert_eq!(count(&db, "SELECT id FROM t WHERE price > 3.0"), 3);
+    assert_eq!(count(&db, "SELECT id FROM t WHERE price > 3.4"), 3);
+    assert_eq!(
+        count(&db, "SELECT COUNT(*) FROM t WHERE price > 1.0 AND id <> 2"),
+        2
+    );
+    assert_eq!(
+        count(&db, "SELECT COUNT(*) FROM t WHERE price > 2.0 AND id <> 3"),
+        1
+    );
+
+    db.execute("DELETE FROM t WHERE id = 1", ()).unwrap();
+    db.execute("DELETE FROM t WHERE id = 3", ()).unwrap();
+    assert_eq!(count(&db, "SELECT COUNT(*) FROM t"), 1);
+    assert_eq!(count(&db, "SELECT id FROM t"), 2);
+}
+
+#[test]
+fn test_plan_cache_invalidated_on_truncate_and_attach() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY)", ())
+        .unwrap();
+    db.execute("INSERT INTO t VALUES (1)", ()).unwrap();
+    assert_eq!(count(&db, "SELECT COUNT(*) FROM t"), 1);
+
+    let invalidations = db.plan_cache_stats().unwrap().invalidations;
+    db.execute("TRUNCATE TABLE t", ()).unwrap();
+    db.execute("ATTACH DATABASE 'memory://plan_cache_other' AS other", ())
+        .unwrap();
+    db.execute("DETACH DATABASE other", ()).unwrap();
+    assert_eq!(
+        db.plan_cache_stats().unwrap().invalidations,
+        invalidations + 3
+    );
+    assert_eq!(count(&db, "SELECT COUNT(*) FROM t"), 0);
+}
+
+#[test]
+fn test_plan_cache_keeps_limit_and_offset_literals() {
+    let db = numbers();
+    let before = db.plan_cache_stats().unwrap();
+
+    let sql = "SELECT id FROM t WHERE v > 20 ORDER BY id LIMIT 2 OFFSET 1";
+    assert_eq!(column::<i64>(&db, sql), vec![4, 5]);
+    // Only the WHERE literal differs: the plan is shared
+    let sql = "SELECT id FROM t WHERE v > 50 ORDER BY id LIMIT 2 OFFSET 1";
+    assert_eq!(column::<i64>(&db, sql), vec![7, 8]);
+    // Other LIMIT and OFFSET values get their own plans
+    let sql = "SELECT id FROM t WHERE v > 50 ORDER BY id LIMIT 3 OFFSET 1";
+    assert_eq!(column::<i64>(&db, sql), vec![7, 8, 9]);
+    let sql = "SELECT id FROM t WHERE v > 50 ORDER BY id LIMIT 3 OFFSET 2";
+    assert_eq!(column::<i64>(&db, sql), vec![8, 9, 10]);
+
+    assert_eq!(cache_delta(&db, &before), (3, 1));
+}
+
+#[test]
+fn test_plan_cache_keeps_order_by_literals() {
+    let db = numbers();
+    let before = db.plan_cache_stats().unwrap();
+
+    let sql = "SELECT id, 100 - v FROM t WHERE id < 5 ORDER BY 2";
+    assert_eq!(column::<i64>(&db, sql), vec![4, 3, 2, 1]);
+    let sql = "SELECT id, 100 - v FROM t WHERE id < 4 ORDER BY 2";
+    assert_eq!(column::<i64>(&db, sql), vec![3, 2, 1]);
+    // An ordinal is a column reference, not a value
+    let sql = "SELECT id, 100 - v FROM t WHERE id < 4 ORDER BY 1";
+    assert_eq!(column::<i64>(&db, sql), vec![1, 2, 3]);
+    let sql = "SELECT id, v FROM t WHERE id < 4 ORDER BY v % 20, id";
+    assert_eq!(column::<i64>(&db, sql), vec![2, 1, 3]);
+    let sql = "SELECT id, v FROM t WHERE id < 4 ORDER BY v % 30, id";
+    assert_eq!(column::<i64>(&db, sql), vec![3, 1, 2]);
+
+    assert_eq!(cache_delta(&db, &before), (4, 1));
+}
+
+#[test]
+fn test_plan_cache_keeps_group_by_literals() {
+    let db = numbers();
+    let before = db.plan_cache_stats().unwrap();
+
+    let sql = "SELECT COUNT(*) FROM t WHERE id > 0 GROUP BY id % 2";
+    assert_eq!(column::<i64>(&db, sql).len(), 2);
+    let sql = "SELECT COUNT(*) FROM t WHERE id > 5 GROUP BY id % 2";
+    assert_eq!(column::<i64>(&db, sql).len(), 2);
+    let sql = "SELECT COUNT(*) FROM t WHERE id > 5 GROUP BY id % 5";
+    assert_eq!(column::<i64>(&db, sql).len(), 5);
+    // HAVING keeps its literals too
+    let sql = "SELECT id % 3 FROM t WHERE id > 0 GROUP BY id % 3 HAVING COUNT(*) > 3";
+    assert_eq!(column::<i64>(&db, sql), vec![1]);
+    let sql = "SELECT id % 3 FROM t WHERE id > 0 GROUP BY id % 3 HAVING COUNT(*) > 2";
+    assert_eq!(column::<i64>(&db, sql).len(), 3);
+
+    assert_eq!(cache_delta(&db, &before), (4, 1));
+}
+
+#[test]
+fn test_plan_cache_with_default_values() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE d (id INTEGER PRIMARY KEY, v INTEGER DEFAULT 42)",
+        (),
+    )
+    .unwrap();
+    let before = db.plan_cache_stats().unwrap();
+
+    db.execute("INSERT INTO d VALUES (1, DEFAULT)", ()).unwrap();
+    db.execute("INSERT INTO d VALUES (2, DEFAULT)", ()).unwrap();
+    // A value where the other query had DEFAULT is another plan
+    db.execute("INSERT INTO d VALUES (3, 5)", ()).unwrap();
+    db.execute("INSERT INTO d VALUES (4, 6)", ()).unwrap();
+    db.execute("INSERT INTO d (id) VALUES (5)", ()).unwrap();
+
+    assert_eq!(cache_delta(&db, &before), (3, 2));
+    assert_eq!(
+        column::<i64>(&db, "SELECT v FROM d ORDER BY id"),
+        vec![42, 42, 5, 6, 42]
+    );
+}
+
+#[test]
+fn test_plan_cache_rebinds_literals_in_case() {
+    let db = numbers();
+    let before = db.plan_cache_stats().unwrap();
+
+    // Compared literals inside a WHERE CASE are rebound, THEN and ELSE
+    // results stay part of the key
+    let sql = "SELECT COUNT(*) FROM t WHERE CASE WHEN v > 30 THEN 1 ELSE 0 END = 1";
+    assert_eq!(count(&db, sql), 7);
+    let sql = "SELECT COUNT(*) FROM t WHERE CASE WHEN v > 60 THEN 1 ELSE 0 END = 1";
+    assert_eq!(count(&db, sql), 4);
+    let sql = "SELECT COUNT(*) FROM t WHERE CASE WHEN v > 60 THEN 1 ELSE 0 END = 0";
+    assert_eq!(count(&db, sql), 6);
+    let sql = "SELECT COUNT(*) FROM t WHERE CASE WHEN v > 60 THEN 2 ELSE 0 END = 1";
+    assert_eq!(count(&db, sql), 0);
+
+    // A CASE in the select list keeps all its literals
+    let sql = "SELECT CASE WHEN v > 30 THEN 'big' ELSE 'small' END FROM t WHERE id = 4";
+    assert_eq!(column::<String>(&db, sql), vec!["big"]);
+    let sql = "SELECT CASE WHEN v > 30 THEN 'big' ELSE 'small' END FROM t WHERE id = 2";
+    assert_eq!(column::<String>(&db, sql), vec!["small"]);
+    let sql = "SELECT CASE WHEN v > 10 THEN 'big' ELSE 'small' END FROM t WHERE id = 2";
+    assert_eq!(column::<String>(&db, sql), vec!["big"]);
+
+    // And a CASE in UPDATE ... SET
+    db.execute(
+        "UPDATE t SET v = CASE WHEN id = 1 THEN 0 ELSE v END WHERE id < 3",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "UPDATE t SET v = CASE WHEN id = 9 THEN 0 ELSE v END WHERE id < 10",
+        (),
+    )
+    .unwrap();
+    assert_eq!(
+        column::<i64>(&db, "SELECT id FROM t WHERE v = 0 ORDER BY id"),
+        vec![1, 9]
+    );
+
+    assert_eq!(cache_delta(&db, &before), (6, 4));
+}
@@ -0,0 +1,133 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Stale Plan Tests
+//!
+//! Tests that prepared statements fail with Error::PlanStale once a table
+//! or view they refer to is changed by DDL
+
+use oxibase::{Database, Error};
+
+fn setup() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)", ())
+        .unwrap();
+    db.execute("CREATE TABLE logs (id INTEGER PRIMARY KEY, msg TEXT)", ())
+        .unwrap();
+    db.execute("INSERT INTO users VALUES (1, 'Alice'), (2, 'Bob')", ())
+        .unwrap();
+    db
+}
+
+fn stale_table(result: Result<i64, Error>) -> String {
+    match result {
+        Err(Error::PlanStale { table }) => table,
+        Err(e) => panic!("expected PlanStale, got {}", e),
+        Ok(_) => panic!("expected PlanStale, got success"),
+    }
+}
+
+#[test]
+fn test_alter_table_invalidates_prepared_statement() {
+    let db = setup();
+    let select = db.prepare("SELECT * FROM users WHERE id = $1").unwrap();
+    let insert = db.prepare("INSERT INTO users VALUES ($1, $2)").unwrap();
+    insert.execute((3, "Cy")).unwrap();
+
+    db.execute("ALTER TABLE users ADD COLUMN email TEXT", ())
+        .unwrap();
+
+    match select.query((1,)) {
+        Err(Error::PlanStale { table }) => assert_eq!(table, "users"),
+        Err(e) => panic!("expected PlanStale, got {}", e),
+        Ok(_) => panic!("expected PlanStale, got rows"),
+    }
+    assert_eq!(stale_table(insert.execute((4, "Dee"))), "users");
+    // The row was not inserted
+    let count: i64 = db.query_one("SELECT COUNT(*) FROM users", ()).unwrap();
+    assert_eq!(count, 3);
+
+    // Preparing again picks up the new column
+    let select = db.prepare(select.sql()).unwrap();
+    let mut rows = select.query((1,)).unwrap();
+    assert_eq!(rows.columns(), ["id", "name", "email"]);
+    assert!(rows.next().unwrap().is_ok());
+}
+
+#[test]
+fn test_unrelated_changes_keep_statement_valid() {
+    let db = setup();
+    let stmt = db.prepare("SELECT name FROM users WHERE id = $1").unwrap();
+
+    db.execute("ALTER TABLE logs ADD COLUMN level INTEGER", ())
+        .unwrap();
+    db.execute("CREATE INDEX idx_users_name ON users (name)", ())
+        .unwrap();
+    db.execute("INSERT INTO users VALUES (3, 'Cy')", ())
+        .unwrap();
+    db.execute("CREATE TABLE other (id INTEGER)", ()).unwrap();
+
+    let name: String = stmt.query_one((3,)).unwrap();
+    assert_eq!(name, "Cy");
+}
+
+#[test]
+fn test_drop_rename_and_views_invalidate_statements() {
+    let db = setup();
+
+    // Tables referenced anywhere in the statement count
+    let joined = db
+        .prepare(
+            "SELECT COUNT(*) FROM users WHERE id IN (SELECT id FROM logs)
+             OR EXISTS (SELECT 1 FROM logs)",
+        )
+        .unwrap();
+    db.execute("ALTER TABLE logs RENAME COLUMN msg TO message", ())
+        .unwrap();
+    assert!(matches!(
+        joined.query_one::<i64, _>(()),
+        Err(Error::PlanStale { ref table }) if table == "logs"
+    ));
+
+    // Dropping and recreating a table makes statements stale too
+    let count = db.prepare("SELECT COUNT(*) FROM logs").unwrap();
+    db.execute("DROP TABLE logs", ()).unwrap();
+    db.execute("CREATE TABLE logs (id INTEGER)", ()).unwrap();
+    assert!(matches!(
+        count.query_opt::<i64, _>(()),
+        Err(Error::PlanStale { .. })
+    ));
+
+    // So does renaming the table
+    let update = db
+        .prepare("UPDATE users SET name = $1 WHERE id = $2")
+        .unwrap();
+    db.execute("ALTER TABLE users RENAME TO members", ())
+        .unwrap();
+    assert_eq!(stale_table(update.execute(("Al", 1))), "users");
+
+    // And replacing a view
+    db.execute("CREATE VIEW names AS SELECT name FROM members", ())
+        .unwrap();
+    let view = db.prepare("SELECT * FROM names").unwrap();
+    db.execute(
+        "CREATE OR REPLACE VIEW names AS SELECT id, name FROM members",
+        (),
+    )
+    .unwrap();
+    assert!(matches!(
+        view.query(()),
+        Err(Error::PlanStale { ref table }) if table == "names"
+    ));
+}
@@ -0,0 +1,149 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! EXPLAIN CALL traces of stored procedures
+//!
+//! `EXPLAIN CALL` lists the statements a procedure runs without keeping
+//! their effects; `EXPLAIN ANALYZE CALL` lists them and commits.
+
+use oxibase::Database;
+
+fn setup() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE accounts (id INTEGER PRIMARY KEY, balance INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute("INSERT INTO accounts VALUES (1, 100), (2, 50)", ())
+        .unwrap();
+    db.execute(
+        r#"
+        CREATE PROCEDURE transfer(from_id INTEGER, to_id INTEGER, amount INTEGER)
+        LANGUAGE rhai
+        AS '
+            let rows = oxibase::query("SELECT balance FROM accounts WHERE id = " + from_id);
+            if rows[0].balance < amount {
+                throw "insufficient funds";
+            }
+            oxibase::execute("UPDATE accounts SET balance = balance - " + amount + " WHERE id = " + from_id);
+            oxibase::execute("UPDATE accounts SET balance = balance + " + amount + " WHERE id = " + to_id);
+            oxibase::commit();
+        ';
+        "#,
+        (),
+    )
+    .unwrap();
+    db
+}
+
+fn trace(db: &Database, sql: &str) -> Vec<(i64, String, Option<i64>)> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (
+                row.get(0).unwrap(),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+            )
+        })
+        .collect()
+}
+
+fn balances(db: &Database) -> Vec<i64> {
+    db.query("SELECT balance FROM accounts ORDER BY id", ())
+        .unwrap()
+        .map(|row| row.unwrap().get(0).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_explain_call_lists_transfer_updates_without_applying_them() {
+    let db = setup();
+
+    let steps = trace(&db, "EXPLAIN CALL transfer(1, 2, 30)");
+    assert_eq!(
+        steps,
+        vec![
+            (
+                1,
+                "SELECT balance FROM accounts WHERE id = 1".to_string(),
+                Some(1)
+            ),
+            (
+                2,
+                "UPDATE accounts SET balance = balance - 30 WHERE id = 1".to_string(),
+                Some(1)
+            ),
+            (
+                3,
+                "UPDATE accounts SET balance = balance + 30 WHERE id = 2".to_string(),
+                Some(1)
+            ),
+            (4, "COMMIT".to_string(), None),
+        ]
+    );
+
+    // The dry run skipped the procedure's COMMIT and rolled everything back
+    assert_eq!(balances(&db), vec![100, 50]);
+}
+
+#[test]
+fn test_explain_analyze_call_keeps_changes() {
+    let db = setup();
+
+    let steps = trace(&db, "EXPLAIN ANALYZE CALL transfer(1, 2, 30)");
+    assert_eq!(steps.len(), 4);
+    assert_eq!(balances(&db), vec![70, 80]);
+
+    // A failing procedure reports its error rather than a partial trace
+    assert!(db.query("EXPLAIN CALL transfer(2, 1, 500)", ()).is_err());
+    assert_eq!(balances(&db), vec![70, 80]);
+}
+
+#[test]
+fn test_explain_call_traces_plsql_statements() {
+    let db = setup();
+    db.execute(
+        r#"
+        CREATE PROCEDURE reset_balances()
+        LANGUAGE plsql
+        AS '
+        BEGIN
+            UPDATE accounts SET balance = 0 WHERE balance > 60;
+            DELETE FROM accounts WHERE id = 2;
+        END;
+        ';
+        "#,
+        (),
+    )
+    .unwrap();
+
+    let steps = trace(&db, "EXPLAIN CALL reset_balances()");
+    assert_eq!(steps.len(), 2);
+    assert!(steps[0].1.starts_with("UPDATE accounts"), "{:?}", steps);
+    assert_eq!(steps[0].2, Some(1));
+    assert!(
+        steps[1].1.starts_with("DELETE FROM accounts"),
+        "{:?}",
+        steps
+    );
+    assert_eq!(steps[1].2, Some(1));
+    assert_eq!(balances(&db), vec![100, 50]);
+
+    db.execute("BEGIN", ()).unwrap();
+    assert!(db.query("EXPLAIN CALL reset_balances()", ()).is_err());
+    db.execute("ROLLBACK", ()).unwrap();
+}
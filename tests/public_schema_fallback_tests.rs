@@ -26,7 +26,7 @@ fn test_public_schema_fallback() {
     .unwrap();
     let result = db
         .query(
-            "SELECT schema FROM system.procedures WHERE name = 'MY_PROC'",
+            "SELECT schema FROM system.procedures WHERE name = 'my_proc'",
             (),
         )
         .unwrap();
@@ -42,7 +42,7 @@ fn test_public_schema_fallback() {
     .unwrap();
     let result = db
         .query(
-            "SELECT schema FROM system.functions WHERE name = 'MY_FUNC'",
+            "SELECT schema FROM system.functions WHERE name = 'my_func'",
             (),
         )
         .unwrap();
@@ -0,0 +1,205 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fluent query builder
+//!
+//! Builder queries must return the same results as the equivalent raw SQL,
+//! with identifiers quoted and values bound as parameters.
+
+use oxibase::api::quote_identifier;
+use oxibase::{Database, Operator, Value};
+
+fn setup() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, age INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO users VALUES (1, 'Alice', 30), (2, 'Bob', 25), (3, 'Carol', NULL), (4, 'Dan', 41)",
+        (),
+    )
+    .unwrap();
+    db
+}
+
+/// All rows of a result, as values
+fn collect(rows: oxibase::Rows) -> Vec<Vec<Value>> {
+    rows.map(|row| {
+        let row = row.unwrap();
+        (0..row.len())
+            .map(|i| row.get_value(i).unwrap().clone())
+            .collect()
+    })
+    .collect()
+}
+
+#[test]
+fn test_select_matches_raw_sql() {
+    let db = setup();
+
+    let built = db
+        .select(&["id", "name"])
+        .from("users")
+        .where_eq("id", 2)
+        .limit(10);
+    assert_eq!(
+        built.to_sql().0,
+        "SELECT \"id\", \"name\" FROM \"users\" WHERE \"id\" = ? LIMIT 10"
+    );
+    assert_eq!(
+        collect(built.query().unwrap()),
+        collect(
+            db.query("SELECT id, name FROM users WHERE id = 2 LIMIT 10", ())
+                .unwrap()
+        )
+    );
+
+    let built = db
+        .select(&[])
+        .from("users")
+        .where_op("age", Operator::Gte, 25)
+        .where_not_null("age")
+        .order_by_desc("age")
+        .limit(2)
+        .offset(1);
+    assert_eq!(
+        collect(built.query().unwrap()),
+        collect(
+            db.query(
+                "SELECT * FROM users WHERE age >= 25 AND age IS NOT NULL ORDER BY age DESC LIMIT 2 OFFSET 1",
+                ()
+            )
+            .unwrap()
+        )
+    );
+
+    let ids = db
+        .select(&["id"])
+        .from("users")
+        .where_in("id", [1, 3, 9])
+        .order_by("id")
+        .query()
+        .unwrap();
+    assert_eq!(
+        collect(ids),
+        vec![vec![Value::Integer(1)], vec![Value::Integer(3)]]
+    );
+
+    let nobody = db
+        .select(&["id"])
+        .from("users")
+        .where_in("id", Vec::<i64>::new());
+    assert!(collect(nobody.query().unwrap()).is_empty());
+
+    let name: String = db
+        .select(&["name"])
+        .from("public.users")
+        .where_null("age")
+        .query_one()
+        .unwrap();
+    assert_eq!(name, "Carol");
+}
+
+#[test]
+fn test_insert_update_delete() {
+    let db = setup();
+
+    let inserted = db
+        .insert_into("users")
+        .value("id", 5)
+        .value("name", "Eve")
+        .value("age", None::<i64>)
+        .execute()
+        .unwrap();
+    assert_eq!(inserted, 1);
+    let name: String = db
+        .query_one("SELECT name FROM users WHERE id = 5 AND age IS NULL", ())
+        .unwrap();
+    assert_eq!(name, "Eve");
+
+    let updated = db
+        .update("users")
+        .set("age", 50)
+        .set("name", "Eve2")
+        .where_eq("id", 5)
+        .execute()
+        .unwrap();
+    assert_eq!(updated, 1);
+    let age: i64 = db
+        .query_one("SELECT age FROM users WHERE name = 'Eve2'", ())
+        .unwrap();
+    assert_eq!(age, 50);
+
+    let deleted = db
+        .delete_from("users")
+        .where_op("age", Operator::Gt, 35)
+        .execute()
+        .unwrap();
+    assert_eq!(deleted, 2);
+    let count: i64 = db.query_one("SELECT COUNT(*) FROM users", ()).unwrap();
+    assert_eq!(count, 3);
+
+    assert!(db.insert_into("users").execute().is_err());
+    assert!(db.update("users").where_eq("id", 1).execute().is_err());
+}
+
+#[test]
+fn test_values_and_identifiers_cannot_inject() {
+    let db = setup();
+
+    // A value is bound, never spliced into the SQL
+    let hostile = "x' OR '1'='1";
+    let rows = db
+        .select(&["id"])
+        .from("users")
+        .where_eq("name", hostile)
+        .query()
+        .unwrap();
+    assert!(collect(rows).is_empty());
+
+    db.insert_into("users")
+        .value("id", 6)
+        .value("name", hostile)
+        .execute()
+        .unwrap();
+    let name: String = db
+        .select(&["name"])
+        .from("users")
+        .where_eq("id", 6)
+        .query_one()
+        .unwrap();
+    assert_eq!(name, hostile);
+
+    // An identifier is quoted, so it can only name a column
+    let rows = db
+        .select(&["id"])
+        .from("users")
+        .where_eq("id = 1 OR 1", 1)
+        .query();
+    assert!(rows.map_or(true, |rows| collect(rows).is_empty()));
+    let deleted = db.delete_from("users; DROP TABLE users").execute();
+    assert!(deleted.is_err());
+    let count: i64 = db.query_one("SELECT COUNT(*) FROM users", ()).unwrap();
+    assert_eq!(count, 5);
+}
+
+#[test]
+fn test_quote_identifier() {
+    assert_eq!(quote_identifier("users"), "\"users\"");
+    assert_eq!(quote_identifier("public.users"), "\"public\".\"users\"");
+    assert_eq!(quote_identifier("u.*"), "\"u\".*");
+    assert_eq!(quote_identifier("we\"ird"), "\"we\"\"ird\"");
+}
@@ -0,0 +1,111 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Streaming Export Tests
+//!
+//! Tests Database::query_to_writer with the CSV and NDJSON formats
+
+use oxibase::{Database, ExportFormat};
+
+const ROWS: i64 = 20_000;
+
+fn setup_events(name: &str) -> Database {
+    let db = Database::open(&format!("memory://{}", name)).expect("Failed to create database");
+    db.execute(
+        "CREATE TABLE events (id INTEGER PRIMARY KEY, kind TEXT, note TEXT)",
+        (),
+    )
+    .expect("Failed to create table");
+    db.execute(
+        &format!(
+            "INSERT INTO events SELECT value, 'kind' || (value % 7), NULL FROM generate_series(1, {}) AS g(value)",
+            ROWS
+        ),
+        (),
+    )
+    .expect("Failed to insert");
+    db
+}
+
+#[test]
+fn test_stream_large_result_as_ndjson() {
+    let db = setup_events("export_ndjson");
+
+    let mut out = Vec::new();
+    let written = db
+        .query_to_writer(
+            "SELECT id, kind, note FROM events ORDER BY id",
+            (),
+            ExportFormat::Ndjson,
+            &mut out,
+        )
+        .expect("Failed to stream result");
+    assert_eq!(written, ROWS as usize);
+
+    let text = String::from_utf8(out).expect("Output should be UTF-8");
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), ROWS as usize);
+    assert_eq!(lines[0], r#"{"id":1,"kind":"kind1","note":null}"#);
+}
+
+#[test]
+fn test_stream_large_result_as_csv() {
+    let db = setup_events("export_csv");
+
+    let mut out = Vec::new();
+    let written = db
+        .query_to_writer(
+            "SELECT id, kind FROM events WHERE id > $1 ORDER BY id",
+            (10,),
+            ExportFormat::Csv,
+            &mut out,
+        )
+        .expect("Failed to stream result");
+    assert_eq!(written, (ROWS - 10) as usize);
+
+    let text = String::from_utf8(out).expect("Output should be UTF-8");
+    let mut lines = text.lines();
+    assert_eq!(lines.next(), Some("id,kind"));
+    assert_eq!(lines.next(), Some("11,kind4"));
+    // Header plus one line per row
+    assert_eq!(text.lines().count(), (ROWS - 10) as usize + 1);
+}
+
+#[test]
+fn test_writer_error_stops_the_export() {
+    struct FailingWriter {
+        budget: usize,
+    }
+
+    impl std::io::Write for FailingWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            if buf.len() > self.budget {
+                return Err(std::io::Error::other("disk full"));
+            }
+            self.budget -= buf.len();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let db = setup_events("export_fail");
+    let mut writer = FailingWriter { budget: 1024 };
+    let err = db
+        .query_to_writer("SELECT * FROM events", (), ExportFormat::Csv, &mut writer)
+        .expect_err("Writer failure should abort the export");
+    assert!(err.to_string().contains("disk full"));
+}
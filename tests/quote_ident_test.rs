@@ -0,0 +1,158 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! QUOTE_IDENT / QUOTE_LITERAL for building dynamic SQL
+//!
+//! Quoted names and values must come back out of the parser unchanged, so
+//! user input can never end the identifier or literal and inject SQL.
+
+use oxibase::Database;
+
+fn setup() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)", ())
+        .unwrap();
+    db.execute("INSERT INTO users VALUES (1, 'alice'), (2, 'bob')", ())
+        .unwrap();
+    db
+}
+
+fn quote(db: &Database, func: &str, arg: &str) -> String {
+    db.query_one(&format!("SELECT {}($1)", func), (arg,))
+        .unwrap()
+}
+
+#[test]
+fn test_quote_ident_neutralizes_malicious_table_name() {
+    let db = setup();
+    let malicious = "users\"; DROP TABLE users; --";
+    let ident = quote(&db, "QUOTE_IDENT", malicious);
+    assert_eq!(ident, "\"users\"\"; DROP TABLE users; --\"");
+
+    // The whole input names one (missing) table instead of running the DROP
+    let err = db
+        .query(&format!("SELECT * FROM {}", ident), ())
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("not found") || err.to_string().contains("not exist"));
+    let count: i64 = db.query_one("SELECT COUNT(*) FROM users", ()).unwrap();
+    assert_eq!(count, 2);
+
+    // A dotted name stays a single identifier
+    assert!(db
+        .query(
+            &format!("SELECT * FROM {}", quote(&db, "QUOTE_IDENT", "x.users")),
+            ()
+        )
+        .is_err());
+
+    // A legitimate name still works
+    let ident = quote(&db, "QUOTE_IDENT", "users");
+    let count: i64 = db
+        .query_one(&format!("SELECT COUNT(*) FROM {}", ident), ())
+        .unwrap();
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn test_quote_ident_follows_identifier_quote_setting() {
+    let db = setup();
+    db.execute("SET identifier_quote = 'backtick'", ()).unwrap();
+
+    let ident = quote(&db, "QUOTE_IDENT", "users");
+    assert_eq!(ident, "`users`");
+    let count: i64 = db
+        .query_one(&format!("SELECT COUNT(*) FROM {}", ident), ())
+        .unwrap();
+    assert_eq!(count, 2);
+
+    // Embedded backticks are doubled; double quotes need no escaping
+    let ident = quote(&db, "QUOTE_IDENT", "users`; DROP TABLE users; --");
+    assert_eq!(ident, "`users``; DROP TABLE users; --`");
+    assert!(db.query(&format!("SELECT * FROM {}", ident), ()).is_err());
+    let ident: String = db.query_one("SELECT QUOTE_IDENT('a\"b')", ()).unwrap();
+    assert_eq!(ident, "`a\"b`");
+
+    let mut tx = db.begin().unwrap();
+    let ident: String = tx.query_one("SELECT QUOTE_IDENT('users')", ()).unwrap();
+    assert_eq!(ident, "`users`");
+    tx.rollback().unwrap();
+
+    db.execute("SET identifier_quote = 'double_quote'", ())
+        .unwrap();
+    assert_eq!(quote(&db, "QUOTE_IDENT", "users"), "\"users\"");
+}
+
+#[test]
+fn test_quote_literal_round_trips() {
+    let db = setup();
+    for value in [
+        "plain",
+        "it's",
+        "x' OR '1'='1",
+        "ends with \\",
+        "\\' OR 1=1 --",
+        "back\\nslash",
+        "",
+    ] {
+        let literal = quote(&db, "QUOTE_LITERAL", value);
+        let back: String = db.query_one(&format!("SELECT {}", literal), ()).unwrap();
+        assert_eq!(back, value, "{}", literal);
+
+        let count: i64 = db
+            .query_one(
+                &format!("SELECT COUNT(*) FROM users WHERE name = {}", literal),
+                (),
+            )
+            .unwrap();
+        assert_eq!(count, 0, "{}", literal);
+    }
+
+    let literal: Option<String> = db.query_one("SELECT QUOTE_LITERAL(NULL)", ()).unwrap();
+    assert_eq!(literal, None);
+    let literal: String = db.query_one("SELECT QUOTE_LITERAL(42)", ()).unwrap();
+    assert_eq!(literal, "'42'");
+}
+
+#[test]
+fn test_rhai_procedure_builds_safe_dynamic_sql() {
+    let db = setup();
+    db.execute("CREATE TABLE audit (tbl TEXT, n INTEGER)", ())
+        .unwrap();
+    db.execute(
+        r#"
+        CREATE PROCEDURE count_rows(tbl TEXT)
+        LANGUAGE rhai
+        AS '
+            let rows = oxibase::query("SELECT COUNT(*) AS n FROM " + oxibase::quote_ident(tbl));
+            oxibase::execute("INSERT INTO audit VALUES (" + oxibase::quote_literal(tbl) + ", "
+                + rows[0].n + ")");
+        '
+        "#,
+        (),
+    )
+    .unwrap();
+
+    db.execute("CALL count_rows('users')", ()).unwrap();
+    assert!(db
+        .execute("CALL count_rows('users; DROP TABLE users')", ())
+        .is_err());
+
+    let count: i64 = db.query_one("SELECT COUNT(*) FROM users", ()).unwrap();
+    assert_eq!(count, 2);
+    let n: i64 = db
+        .query_one("SELECT n FROM audit WHERE tbl = 'users'", ())
+        .unwrap();
+    assert_eq!(n, 2);
+}
@@ -0,0 +1,248 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! REINDEX Tests
+//!
+//! Tests rebuilding indexes from table data
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use oxibase::{Database, IsolationLevel};
+use tempfile::tempdir;
+
+fn setup(db: &Database) {
+    db.execute(
+        "CREATE TABLE orders (id INTEGER PRIMARY KEY, customer INTEGER, status TEXT, code TEXT)",
+        (),
+    )
+    .unwrap();
+    db.execute("CREATE INDEX idx_customer ON orders (customer)", ())
+        .unwrap();
+    db.execute("CREATE INDEX idx_status ON orders (status)", ())
+        .unwrap();
+    db.execute("CREATE UNIQUE INDEX idx_code ON orders (code)", ())
+        .unwrap();
+
+    for i in 1..=200 {
+        db.execute(
+            "INSERT INTO orders VALUES ($1, $2, $3, $4)",
+            (
+                i,
+                i % 10,
+                if i % 3 == 0 { "open" } else { "closed" },
+                format!("c{}", i),
+            ),
+        )
+        .unwrap();
+    }
+
+    // Churn: move rows between keys and delete some
+    db.execute(
+        "UPDATE orders SET customer = customer + 1, status = 'open' WHERE id % 4 = 0",
+        (),
+    )
+    .unwrap();
+    db.execute("DELETE FROM orders WHERE id % 7 = 0", ())
+        .unwrap();
+}
+
+fn ids(db: &Database, sql: &str) -> Vec<i64> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| row.unwrap().get(0).unwrap())
+        .collect()
+}
+
+/// Compare index lookups against a scan that can't use the indexes
+fn assert_index_matches_scan(db: &Database) {
+    for customer in 0..=10 {
+        assert_eq!(
+            ids(
+                db,
+                &format!(
+                    "SELECT id FROM orders WHERE customer = {} ORDER BY id",
+                    customer
+                )
+            ),
+            ids(
+                db,
+                &format!(
+                    "SELECT id FROM orders WHERE customer + 0 = {} ORDER BY id",
+                    customer
+                )
+            ),
+        );
+    }
+    for status in ["open", "closed"] {
+        assert_eq!(
+            ids(
+                db,
+                &format!(
+                    "SELECT id FROM orders WHERE status = '{}' ORDER BY id",
+                    status
+                )
+            ),
+            ids(
+                db,
+                &format!(
+                    "SELECT id FROM orders WHERE status || '' = '{}' ORDER BY id",
+                    status
+                )
+            ),
+        );
+    }
+}
+
+#[test]
+fn test_reindex_table() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let rebuilt: i64 = db.query_one("REINDEX TABLE orders", ()).unwrap();
+    assert_eq!(rebuilt, 3);
+    assert_index_matches_scan(&db);
+
+    // Indexes keep being maintained after the rebuild
+    db.execute("UPDATE orders SET customer = 42 WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(
+        ids(&db, "SELECT id FROM orders WHERE customer = 42"),
+        vec![1]
+    );
+    assert_index_matches_scan(&db);
+}
+
+#[test]
+fn test_reindex_single_index() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let rebuilt: i64 = db
+        .query_one("REINDEX INDEX idx_status ON orders", ())
+        .unwrap();
+    assert_eq!(rebuilt, 1);
+
+    // The owning table is looked up when ON is omitted
+    let rebuilt: i64 = db.query_one("REINDEX INDEX idx_customer", ()).unwrap();
+    assert_eq!(rebuilt, 1);
+    assert_index_matches_scan(&db);
+
+    assert!(db.execute("REINDEX INDEX idx_missing", ()).is_err());
+    assert!(db.execute("REINDEX TABLE missing", ()).is_err());
+}
+
+#[test]
+fn test_reindex_keeps_unique_constraint() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    db.execute("REINDEX TABLE orders", ()).unwrap();
+
+    let result = db.execute("INSERT INTO orders VALUES (1000, 1, 'open', 'c1')", ());
+    assert!(result.is_err());
+    db.execute("INSERT INTO orders VALUES (1000, 1, 'open', 'c1000')", ())
+        .unwrap();
+}
+
+#[test]
+fn test_reindex_keeps_rows_committed_after_snapshot() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let mut tx = db
+        .engine()
+        .begin_transaction_with_level(IsolationLevel::SnapshotIsolation)
+        .unwrap();
+    let table = tx.get_table("orders").unwrap();
+
+    // Committed after the snapshot, so the rebuilding transaction can't see them
+    db.execute("INSERT INTO orders VALUES (1000, 55, 'open', 'c1000')", ())
+        .unwrap();
+    db.execute("UPDATE orders SET customer = 56 WHERE id = 1", ())
+        .unwrap();
+
+    table
+        .rebuild_indexes(&["idx_customer", "idx_status", "idx_code"])
+        .unwrap();
+    drop(table);
+    tx.commit().unwrap();
+
+    assert_eq!(
+        ids(&db, "SELECT id FROM orders WHERE customer = 55"),
+        vec![1000]
+    );
+    assert_eq!(
+        ids(&db, "SELECT id FROM orders WHERE customer = 56"),
+        vec![1]
+    );
+    assert_index_matches_scan(&db);
+}
+
+#[test]
+fn test_reindex_with_concurrent_writes() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let done = Arc::new(AtomicBool::new(false));
+    let writer = {
+        let db = db.clone();
+        let done = Arc::clone(&done);
+        thread::spawn(move || {
+            let mut id = 1000;
+            while !done.load(Ordering::Relaxed) {
+                db.execute(
+                    "INSERT INTO orders VALUES ($1, $2, 'open', $3)",
+                    (id, id % 10, format!("c{}", id)),
+                )
+                .unwrap();
+                db.execute(
+                    "UPDATE orders SET customer = customer + 1 WHERE id = $1",
+                    (id - 1000 + 1,),
+                )
+                .unwrap();
+                id += 1;
+            }
+        })
+    };
+
+    for _ in 0..20 {
+        db.execute("REINDEX TABLE orders", ()).unwrap();
+    }
+    done.store(true, Ordering::Relaxed);
+    writer.join().unwrap();
+
+    assert_index_matches_scan(&db);
+}
+
+#[test]
+fn test_reindex_persists() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("test.db");
+    let dsn = format!("file://{}", db_path.display());
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        setup(&db);
+        db.execute("REINDEX TABLE orders", ()).unwrap();
+    }
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        let count = db.query("SHOW INDEXES FROM orders", ()).unwrap().count();
+        assert_eq!(count, 3);
+        assert_index_matches_scan(&db);
+    }
+}
@@ -0,0 +1,295 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for ALTER TABLE ... RENAME COLUMN and its dependent views and indexes
+
+use oxibase::{Database, Error};
+use tempfile::tempdir;
+
+fn setup(db: &Database) {
+    db.execute(
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, email TEXT)",
+        (),
+    )
+    .unwrap();
+    db.execute("CREATE UNIQUE INDEX idx_users_email ON users (email)", ())
+        .unwrap();
+    db.execute(
+        "CREATE VIEW named_users AS SELECT id, name FROM users WHERE name <> 'name'",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO users VALUES (1, 'Alice', 'alice@example.com'), (2, 'name', 'bob@example.com')",
+        (),
+    )
+    .unwrap();
+}
+
+fn index_columns(db: &Database, table: &str, index: &str) -> Vec<String> {
+    db.engine()
+        .get_all_indexes(table)
+        .unwrap()
+        .into_iter()
+        .find(|idx| idx.name() == index)
+        .map(|idx| idx.column_names().to_vec())
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_rename_column() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    db.execute("ALTER TABLE users RENAME COLUMN name TO full_name", ())
+        .unwrap();
+
+    let name: String = db
+        .query_one("SELECT full_name FROM users WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(name, "Alice");
+    assert!(db.query("SELECT name FROM users", ()).is_err());
+}
+
+#[test]
+fn test_rename_column_updates_view() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    db.execute("ALTER TABLE users RENAME COLUMN name TO full_name", ())
+        .unwrap();
+
+    // The view reads the renamed column but keeps its output column name;
+    // the string literal is untouched
+    let name: String = db.query_one("SELECT name FROM named_users", ()).unwrap();
+    assert_eq!(name, "Alice");
+}
+
+#[test]
+fn test_rename_column_keeps_view_column_names() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+    db.execute("CREATE VIEW v1 AS SELECT id, name FROM users", ())
+        .unwrap();
+    db.execute(
+        "CREATE VIEW v2 AS SELECT id, UPPER(name) AS name FROM users",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE VIEW v3 AS SELECT users.name, name AS label, LOWER(name) FROM users ORDER BY name",
+        (),
+    )
+    .unwrap();
+
+    db.execute("ALTER TABLE users RENAME COLUMN name TO full_name", ())
+        .unwrap();
+
+    let name: String = db
+        .query_one("SELECT name FROM v1 WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(name, "Alice");
+    let name: String = db
+        .query_one("SELECT name FROM v2 WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(name, "ALICE");
+
+    let rows = db.query("SELECT * FROM v3", ()).unwrap();
+    assert_eq!(rows.columns()[..2], ["name", "label"].map(String::from));
+    let label: String = db
+        .query_one("SELECT label FROM v3 WHERE name = 'Alice'", ())
+        .unwrap();
+    assert_eq!(label, "Alice");
+}
+
+#[test]
+fn test_rename_column_invalid_changes_nothing() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let err = db
+        .execute("ALTER TABLE users RENAME COLUMN email TO name", ())
+        .unwrap_err();
+    assert_eq!(err, Error::DuplicateColumn);
+    let err = db
+        .execute("ALTER TABLE users RENAME COLUMN missing TO other", ())
+        .unwrap_err();
+    assert_eq!(err, Error::ColumnNotFound);
+
+    // The index on the column is still in place and enforced
+    assert_eq!(
+        index_columns(&db, "users", "idx_users_email"),
+        vec!["email"]
+    );
+    let result = db.execute(
+        "INSERT INTO users VALUES (3, 'Carol', 'alice@example.com')",
+        (),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_rename_column_updates_index() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    db.execute("ALTER TABLE users RENAME COLUMN email TO mail", ())
+        .unwrap();
+
+    assert_eq!(index_columns(&db, "users", "idx_users_email"), vec!["mail"]);
+
+    // The index still enforces uniqueness
+    let result = db.execute(
+        "INSERT INTO users VALUES (3, 'Carol', 'alice@example.com')",
+        (),
+    );
+    assert!(result.is_err());
+
+    let id: i64 = db
+        .query_one("SELECT id FROM users WHERE mail = 'bob@example.com'", ())
+        .unwrap();
+    assert_eq!(id, 2);
+}
+
+#[test]
+fn test_rename_column_ambiguous_view_fails() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+    db.execute("CREATE TABLE teams (id INTEGER PRIMARY KEY, name TEXT)", ())
+        .unwrap();
+    db.execute(
+        "CREATE VIEW user_teams AS SELECT name FROM users JOIN teams ON users.id = teams.id",
+        (),
+    )
+    .unwrap();
+
+    let err = db
+        .execute("ALTER TABLE users RENAME COLUMN name TO full_name", ())
+        .unwrap_err();
+    assert!(matches!(err, Error::NotSupportedMessage(_)), "{:?}", err);
+    assert!(err.to_string().contains("user_teams"), "{}", err);
+
+    // Nothing was changed
+    let name: String = db
+        .query_one("SELECT name FROM users WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(name, "Alice");
+    let name: String = db
+        .query_one("SELECT name FROM named_users WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(name, "Alice");
+}
+
+#[test]
+fn test_rename_column_persists() {
+    let dir = tempdir().unwrap();
+    let db_path = dir.path().join("test.db");
+    let dsn = format!("file://{}", db_path.display());
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        setup(&db);
+        db.execute("ALTER TABLE users RENAME COLUMN name TO full_name", ())
+            .unwrap();
+        db.execute("ALTER TABLE users RENAME COLUMN email TO mail", ())
+            .unwrap();
+    }
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        let name: String = db.query_one("SELECT name FROM named_users", ()).unwrap();
+        assert_eq!(name, "Alice");
+        assert_eq!(index_columns(&db, "users", "idx_users_email"), vec!["mail"]);
+    }
+}
+
+#[test]
+fn test_rename_column_leaves_derived_table_aliases_alone() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+    db.execute("ALTER TABLE users ADD COLUMN label TEXT", ())
+        .unwrap();
+    db.execute("UPDATE users SET label = 'L' || name", ())
+        .unwrap();
+    db.execute(
+        "CREATE VIEW labels AS SELECT name FROM (SELECT label AS name FROM users) d",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE VIEW derived_names AS SELECT d.name FROM (SELECT id, name FROM users) AS d WHERE d.id = 1",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE VIEW aliased AS SELECT u.name AS who, label AS name FROM users u ORDER BY name",
+        (),
+    )
+    .unwrap();
+
+    db.execute("ALTER TABLE users RENAME COLUMN name TO full_name", ())
+        .unwrap();
+
+    let label: String = db
+        .query_one("SELECT name FROM labels WHERE name = 'LAlice'", ())
+        .unwrap();
+    assert_eq!(label, "LAlice");
+    let name: String = db.query_one("SELECT name FROM derived_names", ()).unwrap();
+    assert_eq!(name, "Alice");
+    let rows = db.query("SELECT * FROM aliased", ()).unwrap();
+    assert_eq!(rows.columns(), ["who", "name"].map(String::from));
+    let who: String = db
+        .query_one("SELECT who FROM aliased WHERE name = 'LAlice'", ())
+        .unwrap();
+    assert_eq!(who, "Alice");
+}
+
+#[test]
+fn test_rename_column_resolves_subquery_scopes() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+    db.execute(
+        "CREATE TABLE orders (id INTEGER PRIMARY KEY, user_id INTEGER, name TEXT)",
+        (),
+    )
+    .unwrap();
+    db.execute("INSERT INTO orders VALUES (1, 1, 'book')", ())
+        .unwrap();
+    // The subquery's name is the order's; the outer one is the user's
+    db.execute(
+        "CREATE VIEW first_orders AS SELECT u.name, (SELECT o.name FROM orders o WHERE o.user_id = u.id) AS item FROM users u",
+        (),
+    )
+    .unwrap();
+    // A correlated reference reaches the renamed table from the subquery
+    db.execute(
+        "CREATE VIEW buyers AS SELECT id FROM users WHERE EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.id AND users.name <> 'x')",
+        (),
+    )
+    .unwrap();
+
+    db.execute("ALTER TABLE users RENAME COLUMN name TO full_name", ())
+        .unwrap();
+
+    let name: String = db
+        .query_one("SELECT name FROM first_orders WHERE item = 'book'", ())
+        .unwrap();
+    assert_eq!(name, "Alice");
+    let id: i64 = db.query_one("SELECT id FROM buyers", ()).unwrap();
+    assert_eq!(id, 1);
+
+    // The other table's column is untouched
+    let item: String = db.query_one("SELECT name FROM orders", ()).unwrap();
+    assert_eq!(item, "book");
+}
@@ -0,0 +1,165 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Result-set column metadata (Rows::columns_meta)
+
+use oxibase::{DataType, Database};
+
+fn setup() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE items (
+            id INTEGER PRIMARY KEY,
+            name TEXT NOT NULL,
+            price FLOAT,
+            in_stock BOOLEAN,
+            added TIMESTAMP
+        )",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE TABLE tags (item_id INTEGER NOT NULL, tag TEXT NOT NULL)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO items VALUES (1, 'pen', 1.5, true, '2026-01-01 00:00:00')",
+        (),
+    )
+    .unwrap();
+    db
+}
+
+/// (name, type, nullable) for each column, before any row is read
+fn meta(db: &Database, sql: &str) -> Vec<(String, DataType, bool)> {
+    db.query(sql, ())
+        .unwrap()
+        .columns_meta()
+        .iter()
+        .map(|col| (col.name.clone(), col.data_type, col.nullable))
+        .collect()
+}
+
+fn col(name: &str, data_type: DataType, nullable: bool) -> (String, DataType, bool) {
+    (name.to_string(), data_type, nullable)
+}
+
+#[test]
+fn test_mixed_type_select() {
+    let db = setup();
+    assert_eq!(
+        meta(
+            &db,
+            "SELECT id, name AS label, price, in_stock, added, price * 2 AS doubled, \
+             UPPER(name) AS upper_name, COUNT(*) OVER () AS n FROM items"
+        ),
+        vec![
+            col("id", DataType::Integer, false),
+            col("label", DataType::Text, false),
+            col("price", DataType::Float, true),
+            col("in_stock", DataType::Boolean, true),
+            col("added", DataType::Timestamp, true),
+            col("doubled", DataType::Float, true),
+            col("upper_name", DataType::Text, true),
+            col("n", DataType::Integer, true),
+        ]
+    );
+
+    // Rows are still all returned after the metadata read-ahead
+    let rows = db.query("SELECT * FROM items", ()).unwrap();
+    assert_eq!(rows.columns_meta().len(), 5);
+    assert_eq!(rows.count(), 1);
+}
+
+#[test]
+fn test_aggregates_and_empty_results() {
+    let db = setup();
+    assert_eq!(
+        meta(&db, "SELECT COUNT(*) AS n, MAX(price) AS top FROM items"),
+        vec![
+            col("n", DataType::Integer, false),
+            col("top", DataType::Float, true),
+        ]
+    );
+
+    // Declared columns keep their type with no rows; expressions fall back to TEXT
+    assert_eq!(
+        meta(&db, "SELECT price, price + 1 AS p FROM items WHERE id < 0"),
+        vec![
+            col("price", DataType::Float, true),
+            col("p", DataType::Text, true),
+        ]
+    );
+}
+
+#[test]
+fn test_outer_join_columns_are_nullable() {
+    let db = setup();
+    assert_eq!(
+        meta(
+            &db,
+            "SELECT i.id, t.tag FROM items i JOIN tags t ON t.item_id = i.id"
+        ),
+        vec![
+            col("id", DataType::Integer, false),
+            col("tag", DataType::Text, false),
+        ]
+    );
+    assert_eq!(
+        meta(
+            &db,
+            "SELECT * FROM items i LEFT JOIN tags t ON t.item_id = i.id"
+        )
+        .into_iter()
+        .map(|(_, _, nullable)| nullable)
+        .collect::<Vec<_>>(),
+        vec![false, false, true, true, true, true, true]
+    );
+}
+
+#[test]
+fn test_non_select_results() {
+    let db = setup();
+    let rows = db.query("SHOW TABLES", ()).unwrap();
+    assert_eq!(rows.columns_meta()[0].data_type, DataType::Text);
+
+    let rows = db
+        .query("INSERT INTO tags VALUES (1, 'office')", ())
+        .unwrap();
+    assert_eq!(
+        rows.columns_meta()
+            .iter()
+            .map(|col| (col.name.clone(), col.data_type, col.nullable))
+            .collect::<Vec<_>>(),
+        vec![col("rows_affected", DataType::Integer, false)]
+    );
+}
+
+#[test]
+fn test_statements_without_columns_read_as_rows_affected() {
+    let db = setup();
+    for (sql, affected) in [
+        ("INSERT INTO tags VALUES (1, 'a'), (1, 'b'), (1, 'c')", 3),
+        ("UPDATE tags SET tag = 'z' WHERE tag <> 'a'", 2),
+        ("DELETE FROM tags", 3),
+        ("CREATE TABLE empty_t (id INTEGER)", 0),
+    ] {
+        let rows = db.query(sql, ()).unwrap();
+        assert_eq!(rows.columns(), &["rows_affected".to_string()], "{}", sql);
+        assert_eq!(rows.rows_affected(), affected, "{}", sql);
+        let values: Vec<i64> = rows.map(|r| r.unwrap().get(0).unwrap()).collect();
+        assert_eq!(values, vec![affected], "{}", sql);
+    }
+}
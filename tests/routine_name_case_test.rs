@@ -0,0 +1,96 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Function and procedure name case
+//!
+//! Names keep the case they were created with unless routine_name_case is
+//! 'upper', and are matched case-insensitively either way.
+
+use oxibase::Database;
+
+fn names(db: &Database, sql: &str) -> Vec<String> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| row.unwrap().get::<String>(0).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_procedure_name_case_preserved() {
+    let db = Database::open("memory://routine_case_procedure").unwrap();
+    db.execute(
+        "CREATE PROCEDURE my_Proc(IN n INTEGER) LANGUAGE rhai AS $$ let y = n; $$",
+        (),
+    )
+    .unwrap();
+
+    db.execute("SET routine_name_case = 'upper'", ()).unwrap();
+    db.execute(
+        "CREATE PROCEDURE upper_proc() LANGUAGE rhai AS $$ let x = 1; $$",
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(
+        names(&db, "SHOW PROCEDURES"),
+        vec!["UPPER_PROC".to_string(), "my_Proc".to_string()]
+    );
+    let args: String = db
+        .query("SHOW PROCEDURES", ())
+        .unwrap()
+        .map(|row| row.unwrap().get::<String>(1).unwrap())
+        .nth(1)
+        .unwrap();
+    assert_eq!(args, "(IN n INTEGER)");
+    assert_eq!(
+        db.query_one::<String, _>("SHOW routine_name_case", ())
+            .unwrap(),
+        "upper"
+    );
+
+    // Lookups ignore case
+    db.execute("CALL MY_PROC(1)", ()).unwrap();
+    assert!(db
+        .execute(
+            "CREATE PROCEDURE My_Proc() LANGUAGE rhai AS $$ let x = 1; $$",
+            ()
+        )
+        .is_err());
+    db.execute("DROP PROCEDURE MY_PROC", ()).unwrap();
+    assert_eq!(
+        names(&db, "SHOW PROCEDURES"),
+        vec!["UPPER_PROC".to_string()]
+    );
+
+    assert!(db.execute("SET routine_name_case = 'lower'", ()).is_err());
+}
+
+#[test]
+fn test_function_name_case_preserved() {
+    let db = Database::open("memory://routine_case_function").unwrap();
+    assert_eq!(
+        db.query_one::<String, _>("SHOW routine_name_case", ())
+            .unwrap(),
+        "preserve"
+    );
+    db.execute(
+        "CREATE FUNCTION addOne(x INTEGER) RETURNS INTEGER LANGUAGE rhai AS 'x + 1'",
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(names(&db, "SHOW FUNCTIONS"), vec!["addOne".to_string()]);
+    let value: i64 = db.query_one("SELECT ADDONE(1)", ()).unwrap();
+    assert_eq!(value, 2);
+}
@@ -0,0 +1,192 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SELECT ... FOR UPDATE / FOR SHARE row locks
+//!
+//! FOR SHARE blocks writers and FOR UPDATE from other transactions but not
+//! other FOR SHARE readers. FOR UPDATE blocks every other lock and writer.
+//! Conflicts fail immediately rather than waiting.
+
+use oxibase::Database;
+
+fn setup(name: &str) -> Database {
+    let db = Database::open(&format!("memory://{}", name)).unwrap();
+    db.execute(
+        "CREATE TABLE accounts (id INTEGER PRIMARY KEY, balance INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO accounts VALUES (1, 100), (2, 200), (3, 300)",
+        (),
+    )
+    .unwrap();
+    db
+}
+
+#[test]
+fn test_for_share_blocks_writers_but_not_readers() {
+    let db = setup("row_lock_share");
+
+    let mut tx1 = db.begin().unwrap();
+    let locked = tx1
+        .query("SELECT * FROM accounts WHERE id = 1 FOR SHARE", ())
+        .unwrap()
+        .count();
+    assert_eq!(locked, 1);
+
+    // Another FOR SHARE on the same row is fine
+    let mut tx2 = db.begin().unwrap();
+    tx2.query("SELECT * FROM accounts WHERE id = 1 FOR SHARE", ())
+        .unwrap();
+
+    // Writers and FOR UPDATE are blocked
+    let err = db
+        .execute("UPDATE accounts SET balance = 0 WHERE id = 1", ())
+        .unwrap_err();
+    assert!(err.to_string().contains("locked FOR SHARE"), "{}", err);
+    assert!(db.execute("DELETE FROM accounts WHERE id = 1", ()).is_err());
+    let mut tx3 = db.begin().unwrap();
+    assert!(tx3
+        .query("SELECT * FROM accounts WHERE id = 1 FOR UPDATE", ())
+        .is_err());
+    tx3.rollback().unwrap();
+
+    // Rows that were not selected stay writable
+    db.execute("UPDATE accounts SET balance = 201 WHERE id = 2", ())
+        .unwrap();
+
+    // The row is free once every sharer has finished
+    tx1.commit().unwrap();
+    assert!(db
+        .execute("UPDATE accounts SET balance = 0 WHERE id = 1", ())
+        .is_err());
+    tx2.rollback().unwrap();
+    db.execute("UPDATE accounts SET balance = 0 WHERE id = 1", ())
+        .unwrap();
+}
+
+#[test]
+fn test_for_update_is_exclusive() {
+    let db = setup("row_lock_update");
+
+    db.execute("BEGIN", ()).unwrap();
+    let locked = db
+        .query(
+            "SELECT id FROM accounts WHERE balance + 0 >= 200 FOR UPDATE",
+            (),
+        )
+        .unwrap()
+        .count();
+    assert_eq!(locked, 2);
+
+    let mut other = db.begin().unwrap();
+    assert!(other
+        .query("SELECT * FROM accounts WHERE id = 2 FOR SHARE", ())
+        .is_err());
+    assert!(other
+        .query("SELECT * FROM accounts WHERE id = 3 FOR UPDATE", ())
+        .is_err());
+    assert!(other
+        .execute("UPDATE accounts SET balance = 0 WHERE id = 3", ())
+        .is_err());
+    other
+        .query("SELECT * FROM accounts WHERE id = 1 FOR UPDATE", ())
+        .unwrap();
+    other.rollback().unwrap();
+
+    // The lock holder can still write the rows it locked
+    db.execute("UPDATE accounts SET balance = 0 WHERE id = 3", ())
+        .unwrap();
+    db.execute("ROLLBACK", ()).unwrap();
+
+    let mut other = db.begin().unwrap();
+    other
+        .execute("UPDATE accounts SET balance = 0 WHERE id = 3", ())
+        .unwrap();
+    other.commit().unwrap();
+}
+
+#[test]
+fn test_for_share_then_update_in_same_transaction() {
+    let db = setup("row_lock_upgrade");
+
+    db.execute("BEGIN", ()).unwrap();
+    db.query("SELECT * FROM accounts WHERE id = 1 FOR SHARE", ())
+        .unwrap();
+    db.query("SELECT * FROM accounts WHERE id = 1 FOR UPDATE", ())
+        .unwrap();
+    db.execute("UPDATE accounts SET balance = 150 WHERE id = 1", ())
+        .unwrap();
+    db.execute("COMMIT", ()).unwrap();
+
+    let balance: i64 = db
+        .query_one("SELECT balance FROM accounts WHERE id = 1", ())
+        .unwrap();
+    assert_eq!(balance, 150);
+    db.execute("UPDATE accounts SET balance = 0 WHERE id = 1", ())
+        .unwrap();
+}
+
+#[test]
+fn test_unsupported_queries() {
+    let db = setup("row_lock_unsupported");
+    for sql in [
+        "SELECT COUNT(*) FROM accounts GROUP BY balance FOR UPDATE",
+        "SELECT DISTINCT balance FROM accounts FOR SHARE",
+        "SELECT * FROM accounts a JOIN accounts b ON a.id = b.id FOR UPDATE",
+    ] {
+        assert!(db.query(sql, ()).is_err(), "{}", sql);
+    }
+}
+
+#[test]
+fn test_lock_after_write_in_same_transaction() {
+    let db = setup("row_lock_after_write");
+
+    let mut tx = db.begin().unwrap();
+    tx.execute("UPDATE accounts SET balance = 150 WHERE id = 1", ())
+        .unwrap();
+    let locked = tx
+        .query("SELECT * FROM accounts WHERE id = 2 FOR SHARE", ())
+        .unwrap()
+        .count();
+    assert_eq!(locked, 1);
+
+    // The row locked after the write is held just like the written one
+    let err = db
+        .execute("UPDATE accounts SET balance = 0 WHERE id = 2", ())
+        .unwrap_err();
+    assert!(err.to_string().contains("locked FOR SHARE"), "{}", err);
+    assert!(db
+        .execute("UPDATE accounts SET balance = 0 WHERE id = 1", ())
+        .is_err());
+
+    // A row another transaction holds FOR UPDATE can't be locked either
+    let mut other = db.begin().unwrap();
+    other
+        .query("SELECT * FROM accounts WHERE id = 3 FOR UPDATE", ())
+        .unwrap();
+    assert!(tx
+        .query("SELECT * FROM accounts WHERE id = 3 FOR SHARE", ())
+        .is_err());
+    assert!(tx
+        .query("SELECT * FROM accounts WHERE id = 3 FOR UPDATE", ())
+        .is_err());
+    other.rollback().unwrap();
+
+    tx.commit().unwrap();
+    db.execute("UPDATE accounts SET balance = 0 WHERE id = 2", ())
+        .unwrap();
+}
@@ -0,0 +1,121 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SHOW ROW VERSIONS
+//!
+//! Lists the stored MVCC versions of rows with their transactions and status.
+
+use oxibase::Database;
+
+/// (version, created_by, deleted_by, status, visible, v)
+type VersionRow = (i64, Option<i64>, Option<i64>, String, bool, Option<i64>);
+
+fn versions(db: &Database, sql: &str) -> Vec<VersionRow> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+                row.get(3).unwrap(),
+                row.get(4).unwrap(),
+                row.get(5).unwrap(),
+                row.get(7).unwrap(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_show_row_versions_after_updates() {
+    let db = Database::open("memory://row_versions_updates").unwrap();
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, v INTEGER)", ())
+        .unwrap();
+    db.execute("INSERT INTO t VALUES (1, 10), (2, 5)", ())
+        .unwrap();
+    db.execute("UPDATE t SET v = 20 WHERE id = 1", ()).unwrap();
+    db.execute("UPDATE t SET v = 30 WHERE id = 1", ()).unwrap();
+
+    let listed = versions(&db, "SHOW ROW VERSIONS FROM t WHERE id = 1");
+    assert_eq!(listed.len(), 3, "{:?}", listed);
+    let values: Vec<Option<i64>> = listed.iter().map(|v| v.5).collect();
+    assert_eq!(values, vec![Some(30), Some(20), Some(10)]);
+    assert_eq!(
+        listed.iter().map(|v| v.0).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+    // Each update committed in its own transaction, newest first
+    assert!(listed[0].1 > listed[1].1 && listed[1].1 > listed[2].1);
+    assert!(listed.iter().all(|v| v.2.is_none() && v.3 == "committed"));
+    assert_eq!(
+        listed.iter().map(|v| v.4).collect::<Vec<_>>(),
+        vec![true, false, false]
+    );
+
+    // A WHERE on an old value still finds the row
+    assert_eq!(
+        versions(&db, "SHOW ROW VERSIONS FROM t WHERE v = 10").len(),
+        3
+    );
+    assert_eq!(versions(&db, "SHOW ROW VERSIONS FROM t").len(), 4);
+
+    // Deleting adds a version marked with the deleting transaction
+    db.execute("DELETE FROM t WHERE id = 1", ()).unwrap();
+    let listed = versions(&db, "SHOW ROW VERSIONS FROM t WHERE id = 1");
+    assert_eq!(listed.len(), 4);
+    assert!(listed[0].2.is_some());
+    assert!(listed.iter().all(|v| !v.4));
+}
+
+#[test]
+fn test_show_row_versions_uncommitted() {
+    let db = Database::open("memory://row_versions_uncommitted").unwrap();
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, v INTEGER)", ())
+        .unwrap();
+    db.execute("INSERT INTO t VALUES (1, 10)", ()).unwrap();
+
+    // Another transaction's change shows as a claim without data
+    let mut other = db.begin().unwrap();
+    other
+        .execute("UPDATE t SET v = 11 WHERE id = 1", ())
+        .unwrap();
+    let listed = versions(&db, "SHOW ROW VERSIONS FROM t WHERE id = 1");
+    assert_eq!(listed.len(), 2, "{:?}", listed);
+    assert_eq!(
+        listed[0],
+        (
+            1,
+            Some(other.id()),
+            None,
+            "uncommitted".to_string(),
+            false,
+            None
+        )
+    );
+    assert_eq!(listed[1].3, "committed");
+    assert!(listed[1].4);
+    other.rollback().unwrap();
+
+    // The current transaction's own change is listed with its data
+    db.execute("BEGIN", ()).unwrap();
+    db.execute("UPDATE t SET v = 12 WHERE id = 1", ()).unwrap();
+    let listed = versions(&db, "SHOW ROW VERSIONS FROM t WHERE id = 1");
+    assert_eq!(listed.len(), 2, "{:?}", listed);
+    assert_eq!(listed[0].3, "uncommitted");
+    assert!(listed[0].4);
+    assert_eq!(listed[0].5, Some(12));
+    assert!(!listed[1].4);
+    db.execute("ROLLBACK", ()).unwrap();
+}
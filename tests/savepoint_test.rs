@@ -0,0 +1,148 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Savepoint Tests
+//!
+//! Tests SAVEPOINT, ROLLBACK TO SAVEPOINT and RELEASE SAVEPOINT in SQL and
+//! on the Transaction API
+
+use oxibase::Database;
+
+fn setup() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE items (id INTEGER PRIMARY KEY, qty INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute("CREATE TABLE log (id INTEGER PRIMARY KEY, msg TEXT)", ())
+        .unwrap();
+    db.execute("INSERT INTO items VALUES (1, 10), (2, 20)", ())
+        .unwrap();
+    db
+}
+
+fn items(db: &Database) -> Vec<(i64, i64)> {
+    db.query("SELECT id, qty FROM items ORDER BY id", ())
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (row.get(0).unwrap(), row.get(1).unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn test_rollback_to_savepoint_in_sql() {
+    let db = setup();
+
+    db.execute("BEGIN", ()).unwrap();
+    db.execute("INSERT INTO items VALUES (3, 30)", ()).unwrap();
+    db.execute("SAVEPOINT before_changes", ()).unwrap();
+    db.execute("INSERT INTO items VALUES (4, 40)", ()).unwrap();
+    db.execute("UPDATE items SET qty = 0 WHERE id = 3", ())
+        .unwrap();
+    db.execute("DELETE FROM items WHERE id = 1", ()).unwrap();
+    db.execute("INSERT INTO log VALUES (1, 'changed')", ())
+        .unwrap();
+    db.execute("ROLLBACK TO SAVEPOINT before_changes", ())
+        .unwrap();
+
+    // Only the work before the savepoint is left, in every table
+    assert_eq!(items(&db), vec![(1, 10), (2, 20), (3, 30)]);
+    let logged: i64 = db.query_one("SELECT COUNT(*) FROM log", ()).unwrap();
+    assert_eq!(logged, 0);
+
+    // The transaction carries on and commits
+    db.execute("UPDATE items SET qty = 25 WHERE id = 2", ())
+        .unwrap();
+    db.execute("COMMIT", ()).unwrap();
+    assert_eq!(items(&db), vec![(1, 10), (2, 25), (3, 30)]);
+}
+
+#[test]
+fn test_nested_and_released_savepoints_in_sql() {
+    let db = setup();
+
+    db.execute("BEGIN", ()).unwrap();
+    db.execute("SAVEPOINT outer_sp", ()).unwrap();
+    db.execute("INSERT INTO items VALUES (3, 30)", ()).unwrap();
+    db.execute("SAVEPOINT inner_sp", ()).unwrap();
+    db.execute("INSERT INTO items VALUES (4, 40)", ()).unwrap();
+    db.execute("ROLLBACK TO inner_sp", ()).unwrap();
+    assert_eq!(items(&db), vec![(1, 10), (2, 20), (3, 30)]);
+
+    // Rolling back to outer_sp removes inner_sp, and releasing removes outer_sp
+    db.execute("INSERT INTO items VALUES (5, 50)", ()).unwrap();
+    db.execute("ROLLBACK TO SAVEPOINT inner_sp", ()).unwrap();
+    db.execute("ROLLBACK TO SAVEPOINT outer_sp", ()).unwrap();
+    assert!(db.execute("ROLLBACK TO SAVEPOINT inner_sp", ()).is_err());
+    db.execute("INSERT INTO items VALUES (3, 31)", ()).unwrap();
+    db.execute("RELEASE SAVEPOINT outer_sp", ()).unwrap();
+    assert!(db.execute("ROLLBACK TO SAVEPOINT outer_sp", ()).is_err());
+    assert!(db.execute("RELEASE outer_sp", ()).is_err());
+
+    db.execute("COMMIT", ()).unwrap();
+    assert_eq!(items(&db), vec![(1, 10), (2, 20), (3, 31)]);
+
+    // Outside a transaction there is nothing to release
+    assert!(db.execute("RELEASE SAVEPOINT outer_sp", ()).is_err());
+}
+
+#[test]
+fn test_transaction_api_savepoints() {
+    let db = setup();
+
+    let mut tx = db.begin().unwrap();
+    tx.execute("INSERT INTO items VALUES (3, 30)", ()).unwrap();
+    tx.savepoint("sp1").unwrap();
+    tx.execute("UPDATE items SET qty = 99 WHERE id = 1", ())
+        .unwrap();
+    tx.execute("DELETE FROM items WHERE id = 2", ()).unwrap();
+    tx.rollback_to("sp1").unwrap();
+
+    // sp1 stays and can be rolled back to again
+    tx.execute("DELETE FROM items WHERE id = 3", ()).unwrap();
+    tx.rollback_to("sp1").unwrap();
+    assert!(tx.rollback_to("missing").is_err());
+
+    // SQL savepoint statements work on the transaction too
+    tx.execute("SAVEPOINT sp2", ()).unwrap();
+    tx.execute("INSERT INTO items VALUES (4, 40)", ()).unwrap();
+    tx.execute("ROLLBACK TO SAVEPOINT sp2", ()).unwrap();
+    tx.savepoint("sp3").unwrap();
+    tx.execute("INSERT INTO items VALUES (5, 50)", ()).unwrap();
+    tx.execute("RELEASE SAVEPOINT sp3", ()).unwrap();
+    assert!(tx.release("sp3").is_err());
+    tx.commit().unwrap();
+
+    assert_eq!(items(&db), vec![(1, 10), (2, 20), (3, 30), (5, 50)]);
+
+    // A savepoint needs an open transaction
+    assert!(tx.savepoint("sp4").is_err());
+}
+
+#[test]
+fn test_transaction_api_rollback_after_savepoint() {
+    let db = setup();
+
+    let mut tx = db.begin().unwrap();
+    tx.execute("INSERT INTO items VALUES (3, 30)", ()).unwrap();
+    tx.savepoint("sp1").unwrap();
+    tx.execute("INSERT INTO items VALUES (4, 40)", ()).unwrap();
+    tx.release("sp1").unwrap();
+    tx.rollback().unwrap();
+
+    assert_eq!(items(&db), vec![(1, 10), (2, 20)]);
+}
@@ -229,6 +229,57 @@ fn test_rtrim_function() {
     assert_eq!(result, "  hello", "RTRIM function failed");
 }
 
+#[test]
+fn test_trim_standard_syntax() {
+    let db = Database::open("memory://scalar_trim_standard").expect("Failed to create database");
+
+    db.execute("CREATE TABLE trim_std (id INTEGER, val TEXT)", ())
+        .expect("Failed to create table");
+    db.execute(
+        "INSERT INTO trim_std VALUES (1, '  hello  '), (2, '00120.500'), (3, NULL)",
+        (),
+    )
+    .expect("Failed to insert");
+
+    let cases = [
+        ("TRIM(LEADING '0' FROM val)", 2, "120.500"),
+        ("TRIM(TRAILING '0' FROM val)", 2, "00120.5"),
+        ("TRIM(BOTH '0' FROM val)", 2, "120.5"),
+        ("TRIM('0' FROM val)", 2, "120.5"),
+        ("TRIM(LEADING FROM val)", 1, "hello  "),
+        ("TRIM(TRAILING FROM val)", 1, "  hello"),
+        ("TRIM(BOTH ' ' FROM val)", 1, "hello"),
+        // The characters form a set, not a prefix
+        ("TRIM(BOTH '05.' FROM val)", 2, "12"),
+        ("LTRIM(val, '01')", 2, "20.500"),
+        ("RTRIM(val, '0')", 2, "00120.5"),
+    ];
+    for (expr, id, expected) in cases {
+        let result: String = db
+            .query_one(
+                &format!("SELECT {} FROM trim_std WHERE id = {}", expr, id),
+                (),
+            )
+            .unwrap_or_else(|e| panic!("{} failed: {}", expr, e));
+        assert_eq!(result, expected, "{}", expr);
+    }
+
+    let null_source: Option<String> = db
+        .query_one(
+            "SELECT TRIM(LEADING 'x' FROM val) FROM trim_std WHERE id = 3",
+            (),
+        )
+        .expect("Failed to query");
+    assert_eq!(null_source, None);
+    let null_chars: Option<String> = db
+        .query_one(
+            "SELECT TRIM(BOTH NULL FROM val) FROM trim_std WHERE id = 1",
+            (),
+        )
+        .expect("Failed to query");
+    assert_eq!(null_chars, None);
+}
+
 #[test]
 fn test_substring_function() {
     let db = Database::open("memory://scalar_substr").expect("Failed to create database");
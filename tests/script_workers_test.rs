@@ -0,0 +1,90 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Script Worker Pool Tests
+//!
+//! `SET script_workers = n` caps how many scripting runtimes run at once.
+//! The pool is process-wide, so this file holds a single test.
+
+#![cfg(feature = "runtime-pool")]
+
+use oxibase::functions::backends::pool::global_pool;
+use oxibase::Database;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+/// Each thread needs its own database: statements on one database run one at a time
+fn open_with_spin() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE OR REPLACE FUNCTION spin(n INTEGER) RETURNS INTEGER LANGUAGE RHAI AS
+         'let total = 0; for i in 0..n { total += i % 7; } total'",
+        (),
+    )
+    .unwrap();
+    db
+}
+
+#[test]
+fn test_script_workers_caps_concurrent_udfs() {
+    let db = open_with_spin();
+
+    db.execute("SET script_workers = 2", ()).unwrap();
+    assert_eq!(global_pool().limit(), 2);
+
+    let done = Arc::new(AtomicBool::new(false));
+    let sampler = {
+        let done = done.clone();
+        thread::spawn(move || {
+            let mut peak = 0;
+            while !done.load(Ordering::SeqCst) {
+                peak = peak.max(global_pool().active());
+                thread::yield_now();
+            }
+            peak
+        })
+    };
+
+    let workers: Vec<_> = (0..6)
+        .map(|_| {
+            thread::spawn(|| {
+                let db = open_with_spin();
+                for _ in 0..5 {
+                    let total: i64 = db.query_one("SELECT spin(20000)", ()).unwrap();
+                    assert!(total > 0);
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    done.store(true, Ordering::SeqCst);
+
+    let peak = sampler.join().unwrap();
+    assert!((1..=2).contains(&peak), "peak {}", peak);
+    assert_eq!(global_pool().active(), 0);
+
+    // Invalid values are rejected and leave the limit unchanged
+    assert!(db.execute("SET script_workers = -1", ()).is_err());
+    assert!(db.execute("SET script_workers = 'many'", ()).is_err());
+    assert_eq!(global_pool().limit(), 2);
+
+    // 0 removes the cap
+    db.execute("SET script_workers = 0", ()).unwrap();
+    assert_eq!(global_pool().limit(), 0);
+    let total: i64 = db.query_one("SELECT spin(10)", ()).unwrap();
+    assert_eq!(total, 24);
+}
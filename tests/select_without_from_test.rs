@@ -0,0 +1,123 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SELECT Without FROM Tests
+//!
+//! Tests FROM-less SELECT as a general expression evaluator
+
+use oxibase::Database;
+
+#[test]
+fn test_multi_column_expressions_with_aliases() {
+    let db = Database::open("memory://select_no_from_multi").expect("Failed to create database");
+
+    let rows = db
+        .query(
+            "SELECT 1 + 1 AS x, UPPER('a') AS u, 7 * 6 - 2 AS calc, LENGTH('hello') AS len, \
+             COALESCE(NULL, 'fallback') AS c, CASE WHEN 2 > 1 THEN 'yes' ELSE 'no' END AS flag",
+            (),
+        )
+        .expect("Failed to run FROM-less select");
+    assert_eq!(
+        rows.columns(),
+        &["x", "u", "calc", "len", "c", "flag"].map(String::from)
+    );
+
+    let rows: Vec<_> = rows.map(|r| r.unwrap()).collect();
+    assert_eq!(rows.len(), 1);
+    let row = &rows[0];
+    assert_eq!(row.get::<i64>(0).unwrap(), 2);
+    assert_eq!(row.get::<String>(1).unwrap(), "A");
+    assert_eq!(row.get::<i64>(2).unwrap(), 40);
+    assert_eq!(row.get::<i64>(3).unwrap(), 5);
+    assert_eq!(row.get::<String>(4).unwrap(), "fallback");
+    assert_eq!(row.get::<String>(5).unwrap(), "yes");
+}
+
+#[test]
+fn test_unaliased_columns_and_nested_functions() {
+    let db =
+        Database::open("memory://select_no_from_unaliased").expect("Failed to create database");
+
+    let rows = db
+        .query(
+            "SELECT 2.5 * 4, LOWER(CONCAT('Ab', 'CD')), ABS(-3) + ROUND(1.6), NULL",
+            (),
+        )
+        .expect("Failed to run FROM-less select");
+    assert_eq!(
+        rows.columns(),
+        &["column1", "column2", "column3", "column4"].map(String::from)
+    );
+
+    let row = rows.map(|r| r.unwrap()).next().unwrap();
+    assert_eq!(row.get::<f64>(0).unwrap(), 10.0);
+    assert_eq!(row.get::<String>(1).unwrap(), "abcd");
+    assert_eq!(row.get::<f64>(2).unwrap(), 5.0);
+    assert!(row.get::<Option<i64>>(3).unwrap().is_none());
+}
+
+#[test]
+fn test_parameters_where_and_limit() {
+    let db = Database::open("memory://select_no_from_clauses").expect("Failed to create database");
+
+    let sum: i64 = db
+        .query_one("SELECT $1 + $2 AS total", (40, 2))
+        .expect("Failed to evaluate parameters");
+    assert_eq!(sum, 42);
+
+    let (a, b): (i64, String) = {
+        let row = db
+            .query("SELECT 10 % 3 AS m, 'x' || 'y' AS s WHERE 1 = 1", ())
+            .unwrap()
+            .map(|r| r.unwrap())
+            .next()
+            .unwrap();
+        (row.get(0).unwrap(), row.get(1).unwrap())
+    };
+    assert_eq!((a, b.as_str()), (1, "xy"));
+
+    let count = db
+        .query("SELECT 1 AS x, 2 AS y WHERE 1 = 0", ())
+        .unwrap()
+        .count();
+    assert_eq!(count, 0);
+
+    let rows = db.query("SELECT 1 AS x, 2 AS y LIMIT 0", ()).unwrap();
+    assert_eq!(rows.columns(), &["x", "y"].map(String::from));
+    assert_eq!(rows.count(), 0);
+
+    let count = db.query("SELECT 1 AS x OFFSET 1", ()).unwrap().count();
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn test_scalar_subquery_in_from_less_select() {
+    let db = Database::open("memory://select_no_from_subquery").expect("Failed to create database");
+    db.execute("CREATE TABLE nums (n INTEGER)", ()).unwrap();
+    db.execute("INSERT INTO nums VALUES (1), (2), (3)", ())
+        .unwrap();
+
+    let row = db
+        .query(
+            "SELECT (SELECT SUM(n) FROM nums) AS total, (SELECT MAX(n) FROM nums) * 10 AS scaled",
+            (),
+        )
+        .unwrap()
+        .map(|r| r.unwrap())
+        .next()
+        .unwrap();
+    assert_eq!(row.get::<i64>(0).unwrap(), 6);
+    assert_eq!(row.get::<i64>(1).unwrap(), 30);
+}
@@ -86,3 +86,191 @@ fn test_sequence_lifecycle() {
         .unwrap();
     assert!(rows2.next().is_none());
 }
+
+#[test]
+fn test_show_sequences() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE SEQUENCE seq_b START 100 INCREMENT 2", ())
+        .unwrap();
+    db.execute("CREATE SEQUENCE seq_a MAXVALUE 3 CYCLE", ())
+        .unwrap();
+
+    let first: i64 = db.query_one("SELECT NEXTVAL('seq_b')", ()).unwrap();
+    let second: i64 = db.query_one("SELECT NEXTVAL('seq_b')", ()).unwrap();
+    assert_eq!((first, second), (100, 102));
+
+    let rows: Vec<(String, i64, i64, i64, bool, i64)> = db
+        .query("SHOW SEQUENCES", ())
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+                row.get(3).unwrap(),
+                row.get(5).unwrap(),
+                row.get(6).unwrap(),
+                row.get(7).unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        rows,
+        vec![
+            ("seq_a".to_string(), 1, 1, 3, true, 1),
+            ("seq_b".to_string(), 100, 2, i64::MAX, false, 102),
+        ]
+    );
+
+    db.execute("DROP SEQUENCE seq_a", ()).unwrap();
+    let names: Vec<String> = db
+        .query("SHOW SEQUENCES", ())
+        .unwrap()
+        .map(|row| row.unwrap().get(1).unwrap())
+        .collect();
+    assert_eq!(names, vec!["seq_b"]);
+}
+
+#[test]
+fn test_sequences_persist_across_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    let dsn = format!("file://{}", dir.path().join("seq.db").display());
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        db.execute("CREATE SEQUENCE orders_seq START 100 INCREMENT 2", ())
+            .unwrap();
+        db.execute("CREATE SEQUENCE unused_seq MINVALUE 5 START 5", ())
+            .unwrap();
+        db.execute("CREATE SEQUENCE dropped_seq", ()).unwrap();
+        db.execute("DROP SEQUENCE dropped_seq", ()).unwrap();
+        for expected in [100, 102, 104] {
+            let value: i64 = db.query_one("SELECT NEXTVAL('orders_seq')", ()).unwrap();
+            assert_eq!(value, expected);
+        }
+        db.close().unwrap();
+    }
+
+    let db = Database::open(&dsn).unwrap();
+    let mut rows = db
+        .query(
+            "SELECT sequence_name, start_value, increment, minimum_value, current_value
+             FROM information_schema.sequences ORDER BY sequence_name",
+            (),
+        )
+        .unwrap();
+    let row = rows.next().unwrap().unwrap();
+    assert_eq!(row.get::<String>(0).unwrap(), "orders_seq");
+    assert_eq!(row.get::<i64>(1).unwrap(), 100);
+    assert_eq!(row.get::<i64>(2).unwrap(), 2);
+    assert_eq!(row.get::<i64>(4).unwrap(), 104);
+    let row = rows.next().unwrap().unwrap();
+    assert_eq!(row.get::<String>(0).unwrap(), "unused_seq");
+    assert_eq!(row.get::<i64>(3).unwrap(), 5);
+    assert!(rows.next().is_none());
+    drop(rows);
+
+    // Numbering carries on where it stopped; an unused sequence starts fresh
+    let next: i64 = db.query_one("SELECT NEXTVAL('orders_seq')", ()).unwrap();
+    assert_eq!(next, 106);
+    let first: i64 = db.query_one("SELECT NEXTVAL('unused_seq')", ()).unwrap();
+    assert_eq!(first, 5);
+    assert!(db
+        .query_one::<i64, _>("SELECT NEXTVAL('dropped_seq')", ())
+        .is_err());
+}
+
+#[test]
+fn test_sequences_survive_checkpoint_and_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    let dsn = format!(
+        "file://{}?snapshot_interval=0",
+        dir.path().join("seq.db").display()
+    );
+
+    {
+        let db = Database::open(&dsn).unwrap();
+        db.execute("CREATE SEQUENCE invoice_seq START 10 INCREMENT 10", ())
+            .unwrap();
+        for expected in [10, 20] {
+            let value: i64 = db.query_one("SELECT NEXTVAL('invoice_seq')", ()).unwrap();
+            assert_eq!(value, expected);
+        }
+        // The checkpoint truncates the WAL, so the sequence must be in the snapshot
+        db.execute("CHECKPOINT", ()).unwrap();
+        let value: i64 = db.query_one("SELECT NEXTVAL('invoice_seq')", ()).unwrap();
+        assert_eq!(value, 30);
+        db.close().unwrap();
+    }
+
+    let db = Database::open(&dsn).unwrap();
+    let next: i64 = db.query_one("SELECT NEXTVAL('invoice_seq')", ()).unwrap();
+    assert_eq!(next, 40);
+}
+
+#[test]
+fn test_nextval_on_unknown_sequence_is_an_error() {
+    let db = Database::open_in_memory().unwrap();
+    let err = db
+        .query("SELECT NEXTVAL('missing_seq')", ())
+        .and_then(|mut rows| rows.next().transpose())
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("sequence not found: missing_seq"),
+        "unexpected error: {}",
+        err
+    );
+    let err = db
+        .query("SELECT SETVAL('missing_seq', 5)", ())
+        .and_then(|mut rows| rows.next().transpose())
+        .unwrap_err();
+    assert!(err.to_string().contains("sequence not found"));
+}
+
+fn copy_dir(from: &std::path::Path, to: &std::path::Path) {
+    std::fs::create_dir_all(to).unwrap();
+    for entry in std::fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        let target = to.join(entry.file_name());
+        if entry.file_type().unwrap().is_dir() {
+            copy_dir(&entry.path(), &target);
+        } else {
+            std::fs::copy(entry.path(), target).unwrap();
+        }
+    }
+}
+
+#[test]
+fn test_sequence_values_not_reused_after_crash() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("seq.db");
+    let db = Database::open(&format!("file://{}", path.display())).unwrap();
+    db.execute("CREATE SEQUENCE crash_seq", ()).unwrap();
+
+    // Values handed out while a result is still being read count as well
+    let mut rows = db
+        .query(
+            "SELECT NEXTVAL('crash_seq') FROM generate_series(1, 40)",
+            (),
+        )
+        .unwrap();
+    let handed: Vec<i64> = rows
+        .by_ref()
+        .map(|row| row.unwrap().get(0).unwrap())
+        .collect();
+    assert_eq!(handed.last(), Some(&40));
+
+    // Recover from the files as they are while the database is still open
+    let crashed = dir.path().join("crashed.db");
+    copy_dir(&path, &crashed);
+    let _ = std::fs::remove_file(crashed.join("db.lock"));
+    let recovered = Database::open(&format!("file://{}", crashed.display())).unwrap();
+    let next: i64 = recovered
+        .query_one("SELECT NEXTVAL('crash_seq')", ())
+        .unwrap();
+    assert!(next > 40, "value {} handed out twice", next);
+
+    drop(rows);
+    recovered.close().unwrap();
+    db.close().unwrap();
+}
@@ -0,0 +1,113 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "server")]
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use oxibase::api::Database;
+use oxibase::server::{create_router, serve};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::oneshot;
+
+async fn start(dsn: &str, max_connections: usize) -> (SocketAddr, oneshot::Sender<()>) {
+    let db = Database::open(dsn).unwrap();
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, rx) = oneshot::channel::<()>();
+    tokio::spawn(serve(listener, create_router(db), max_connections, async {
+        let _ = rx.await;
+    }));
+    (addr, tx)
+}
+
+/// Send a keep-alive request and read the response head
+async fn request(stream: &mut TcpStream) -> String {
+    stream
+        .write_all(b"GET /api/meta/schemas HTTP/1.1\r\nHost: localhost\r\n\r\n")
+        .await
+        .unwrap();
+    let mut buf = vec![0u8; 4096];
+    let n = tokio::time::timeout(Duration::from_secs(5), stream.read(&mut buf))
+        .await
+        .expect("response timed out")
+        .unwrap();
+    String::from_utf8_lossy(&buf[..n]).to_string()
+}
+
+#[tokio::test]
+async fn test_connections_over_limit_are_rejected() {
+    let (addr, _shutdown) = start("memory://test_connections_over_limit", 2).await;
+
+    let mut first = TcpStream::connect(addr).await.unwrap();
+    let mut second = TcpStream::connect(addr).await.unwrap();
+    assert!(request(&mut first).await.starts_with("HTTP/1.1 200"));
+    assert!(request(&mut second).await.starts_with("HTTP/1.1 200"));
+
+    // Both connections are still open, so a third one is turned away
+    for _ in 0..3 {
+        let mut overflow = TcpStream::connect(addr).await.unwrap();
+        let mut response = String::new();
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            overflow.read_to_string(&mut response),
+        )
+        .await
+        .expect("rejection timed out")
+        .unwrap();
+        assert!(response.starts_with("HTTP/1.1 503"), "{}", response);
+        assert!(response.contains("too many connections (limit 2)"));
+    }
+
+    // Existing connections keep working
+    assert!(request(&mut first).await.starts_with("HTTP/1.1 200"));
+
+    // Closing one frees its slot
+    drop(second);
+    let mut accepted = false;
+    for _ in 0..50 {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        if request(&mut stream).await.starts_with("HTTP/1.1 200") {
+            accepted = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(accepted);
+}
+
+#[tokio::test]
+async fn test_graceful_shutdown_stops_accepting() {
+    let db = Database::open("memory://test_graceful_shutdown").unwrap();
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, rx) = oneshot::channel::<()>();
+    let server = tokio::spawn(serve(listener, create_router(db), 0, async {
+        let _ = rx.await;
+    }));
+
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+    assert!(request(&mut stream).await.starts_with("HTTP/1.1 200"));
+
+    tx.send(()).unwrap();
+    drop(stream);
+    tokio::time::timeout(Duration::from_secs(5), server)
+        .await
+        .expect("server did not shut down")
+        .unwrap()
+        .unwrap();
+    assert!(TcpStream::connect(addr).await.is_err());
+}
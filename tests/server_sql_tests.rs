@@ -18,8 +18,8 @@ use axum::{
     http::{Request, StatusCode},
 };
 use http_body_util::BodyExt;
-use oxibase::api::Database;
-use oxibase::server::create_router;
+use oxibase::api::{Database, OutputFormat};
+use oxibase::server::{create_router, create_router_with_format};
 use tower::ServiceExt;
 
 #[tokio::test]
@@ -95,3 +95,83 @@ async fn test_sql_endpoint_execute() {
     // Verify side effect
     assert!(db.table_exists("sql_exec_test").unwrap_or(false));
 }
+
+async fn post_sql(
+    app: axum::Router,
+    payload: serde_json::Value,
+) -> (StatusCode, Option<String>, String) {
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/sql")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&payload).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get("content-type")
+        .map(|v| v.to_str().unwrap().to_string());
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (
+        status,
+        content_type,
+        String::from_utf8(body.to_vec()).unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn test_sql_endpoint_csv_format() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE csv_test (id INTEGER, note TEXT)", ())
+        .unwrap();
+    db.execute("INSERT INTO csv_test VALUES (1, 'a, \"b\"'), (2, NULL)", ())
+        .unwrap();
+    let app = create_router(db);
+
+    let (status, content_type, body) = post_sql(
+        app.clone(),
+        serde_json::json!({ "query": "SELECT id, note FROM csv_test ORDER BY id", "format": "csv" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type.as_deref(), Some("text/csv; charset=utf-8"));
+    assert_eq!(body, "id,note\n1,\"a, \"\"b\"\"\"\n2,\n");
+
+    let (status, _, body) = post_sql(
+        app.clone(),
+        serde_json::json!({ "query": "DELETE FROM csv_test", "format": "csv" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "rows_affected\n2\n");
+
+    let (status, _, _) = post_sql(
+        app,
+        serde_json::json!({ "query": "SELECT 1", "format": "table" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_sql_endpoint_default_format() {
+    let db = Database::open_in_memory().unwrap();
+    let app = create_router_with_format(db, OutputFormat::Csv);
+
+    let (_, _, body) = post_sql(app.clone(), serde_json::json!({ "query": "SELECT 1 AS n" })).await;
+    assert_eq!(body, "n\n1\n");
+
+    // The request can still ask for JSON
+    let (_, _, body) = post_sql(
+        app,
+        serde_json::json!({ "query": "SELECT 1 AS n", "format": "json" }),
+    )
+    .await;
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["rows"][0]["n"], 1);
+}
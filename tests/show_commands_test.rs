@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -14,7 +15,7 @@
 
 //! SHOW and DESCRIBE Commands Tests
 //!
-//! Tests SHOW TABLES, SHOW CREATE TABLE, SHOW INDEXES, and DESCRIBE commands
+//! Tests SHOW TABLES, SHOW CREATE TABLE, SHOW INDEXES, SHOW WARNINGS, and DESCRIBE commands
 
 use oxibase::Database;
 
@@ -376,3 +377,115 @@ fn test_describe_column_headers() {
     );
     assert_eq!(columns[5], "Extra", "Sixth column header should be 'Extra'");
 }
+
+/// (Field, Type) pairs reported by DESCRIBE
+fn describe(db: &Database, name: &str) -> Vec<(String, String)> {
+    db.query(&format!("DESCRIBE {}", name), ())
+        .expect("Failed to execute DESCRIBE")
+        .map(|row| {
+            let row = row.expect("Failed to get row");
+            (row.get(0).unwrap(), row.get(1).unwrap())
+        })
+        .collect()
+}
+
+/// Test DESCRIBE on a view reports the projected columns with inferred types
+#[test]
+fn test_describe_view() {
+    let db = Database::open("memory://describe_view").expect("Failed to create database");
+
+    db.execute(
+        "CREATE TABLE orders (id INTEGER PRIMARY KEY, customer TEXT, amount FLOAT, note TEXT, placed TIMESTAMP)",
+        (),
+    )
+    .expect("Failed to create table");
+    db.execute(
+        "INSERT INTO orders VALUES (1, 'ann', NULL, NULL, '2024-01-01 00:00:00')",
+        (),
+    )
+    .expect("Failed to insert");
+    db.execute(
+        "CREATE VIEW order_summary AS SELECT o.id, customer, amount * 2 AS doubled, note, placed, id > 0 AS positive FROM orders o",
+        (),
+    )
+    .expect("Failed to create view");
+
+    let expected = vec![
+        ("id".to_string(), "Integer".to_string()),
+        ("customer".to_string(), "Text".to_string()),
+        ("doubled".to_string(), "Float".to_string()),
+        ("note".to_string(), "Text".to_string()),
+        ("placed".to_string(), "Timestamp".to_string()),
+        ("positive".to_string(), "Boolean".to_string()),
+    ];
+    // NULL amounts still report the column's type
+    assert_eq!(describe(&db, "order_summary"), expected);
+
+    let result = db
+        .query("DESCRIBE order_summary", ())
+        .expect("Failed to execute DESCRIBE");
+    assert_eq!(result.columns().len(), 6);
+    for row in result {
+        let row = row.expect("Failed to get row");
+        let nullable: String = row.get(2).unwrap();
+        assert_eq!(nullable, "YES");
+    }
+
+    assert!(db.query("DESCRIBE no_such_thing", ()).is_err());
+}
+
+fn show_warnings(db: &Database) -> Vec<(String, String)> {
+    db.query("SHOW WARNINGS", ())
+        .expect("Failed to execute SHOW WARNINGS")
+        .map(|row| {
+            let row = row.expect("Failed to get row");
+            (row.get(0).unwrap(), row.get(1).unwrap())
+        })
+        .collect()
+}
+
+/// Test SHOW WARNINGS returns the warnings of the last statement
+#[test]
+fn test_show_warnings() {
+    let db = Database::open("memory://show_warnings").expect("Failed to create database");
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY)", ())
+        .expect("Failed to create table");
+    assert!(show_warnings(&db).is_empty());
+
+    // DROP TABLE inside a transaction cannot be undone
+    db.execute("BEGIN", ()).unwrap();
+    db.execute("DROP TABLE t", ()).unwrap();
+    let warnings = show_warnings(&db);
+    assert_eq!(warnings.len(), 1, "{:?}", warnings);
+    assert_eq!(warnings[0].0, "Warning");
+    assert!(
+        warnings[0].1.contains("DROP TABLE 't' within transaction"),
+        "{:?}",
+        warnings
+    );
+    assert_eq!(db.warnings().unwrap().len(), 1);
+
+    // SHOW WARNINGS does not clear the buffer, the next statement does
+    assert_eq!(show_warnings(&db).len(), 1);
+    db.execute("COMMIT", ()).unwrap();
+    assert!(show_warnings(&db).is_empty());
+
+    // IF EXISTS on a missing object leaves a note
+    db.execute("DROP TABLE IF EXISTS t", ()).unwrap();
+    assert_eq!(
+        show_warnings(&db),
+        vec![(
+            "Note".to_string(),
+            "table \"t\" does not exist, skipping".to_string()
+        )]
+    );
+
+    // Ignored optimizer hints are reported too
+    db.execute("CREATE TABLE u (id INTEGER PRIMARY KEY, v INTEGER)", ())
+        .unwrap();
+    db.query("SELECT /*+ INDEX(u idx_missing) */ * FROM u", ())
+        .unwrap();
+    let warnings = show_warnings(&db);
+    assert_eq!(warnings.len(), 1, "{:?}", warnings);
+    assert!(warnings[0].1.contains("no index idx_missing on u"));
+}
@@ -0,0 +1,126 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! SIMILAR TO and POSIX Regex Operator Tests
+//!
+//! SIMILAR TO must match the whole string, treat `.` literally and honour
+//! ESCAPE; `~`, `~*`, `!~` and `!~*` search with a regex anywhere in the
+//! string, the `*` forms ignoring case.
+
+use oxibase::Database;
+
+fn setup(db: &Database) {
+    db.execute("CREATE TABLE files (id INTEGER PRIMARY KEY, name TEXT)", ())
+        .unwrap();
+    db.execute(
+        "INSERT INTO files VALUES (1, 'report.pdf'), (2, 'REPORT_2024.PDF'), \
+         (3, 'notes.txt'), (4, 'reportXpdf'), (5, '100%'), (6, NULL)",
+        (),
+    )
+    .unwrap();
+}
+
+fn ids(db: &Database, where_clause: &str) -> Vec<i64> {
+    let sql = format!("SELECT id FROM files WHERE {} ORDER BY id", where_clause);
+    db.query(&sql, ())
+        .unwrap()
+        .map(|row| row.unwrap().get::<i64>(0).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_similar_to() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    // The pattern must cover the whole string, and `.` is not a wildcard
+    assert_eq!(ids(&db, "name SIMILAR TO 'report.pdf'"), vec![1]);
+    assert_eq!(ids(&db, "name SIMILAR TO 'report'"), Vec::<i64>::new());
+    assert_eq!(ids(&db, "name LIKE 'report_pdf'"), vec![1, 4]);
+    assert_eq!(ids(&db, "name SIMILAR TO 'report_pdf'"), vec![1, 4]);
+
+    // Regex alternation, grouping, repetition and bracket expressions
+    assert_eq!(ids(&db, "name SIMILAR TO '%.(pdf|txt)'"), vec![1, 3]);
+    assert_eq!(ids(&db, "name SIMILAR TO '%[0-9]{4}%'"), vec![2]);
+    assert_eq!(ids(&db, "name SIMILAR TO '[a-z]+.[a-z]+'"), vec![1, 3]);
+
+    // NOT SIMILAR TO, with NULL neither matching nor not matching
+    assert_eq!(ids(&db, "name NOT SIMILAR TO '%.(pdf|txt)'"), vec![2, 4, 5]);
+
+    // Backslash escapes by default; ESCAPE picks another character
+    assert_eq!(ids(&db, r"name SIMILAR TO '%\%'"), vec![5]);
+    assert_eq!(ids(&db, "name SIMILAR TO '%#%' ESCAPE '#'"), vec![5]);
+
+    // Binds tighter than AND
+    assert_eq!(
+        ids(&db, "name SIMILAR TO 'report%' AND id > 1 OR id = 3"),
+        vec![3, 4]
+    );
+}
+
+#[test]
+fn test_posix_regex_operators() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    // Unanchored search, case-sensitive and case-insensitive
+    assert_eq!(ids(&db, r"name ~ '\.pdf$'"), vec![1]);
+    assert_eq!(ids(&db, r"name ~* '\.pdf$'"), vec![1, 2]);
+    assert_eq!(ids(&db, "name ~ 'port'"), vec![1, 4]);
+    assert_eq!(ids(&db, "name ~* '^report'"), vec![1, 2, 4]);
+
+    // Negated forms skip NULLs
+    assert_eq!(ids(&db, r"name !~ '\.pdf$'"), vec![2, 3, 4, 5]);
+    assert_eq!(ids(&db, r"name !~* '\.pdf$'"), vec![3, 4, 5]);
+
+    // Usable as a boolean expression in the select list
+    let row = db
+        .query(
+            "SELECT 'abc' ~ 'B', 'abc' ~* 'B', 'abc' !~ 'B', 'abc' !~* 'B', NULL ~ 'a'",
+            (),
+        )
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+    let values: Vec<String> = (0..row.len())
+        .map(|i| row.get_value(i).unwrap().to_string())
+        .collect();
+    assert_eq!(values, vec!["false", "true", "true", "false", "NULL"]);
+
+    // Prefix ~ is still bitwise NOT
+    assert_eq!(db.query_one::<i64, _>("SELECT ~5", ()).unwrap(), -6);
+
+    assert!(db.query("SELECT 'a' ~ '('", ()).is_err());
+}
+
+#[test]
+fn test_regex_operators_in_views() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    // The operators survive being stored and re-parsed as view text
+    db.execute(
+        r"CREATE VIEW docs AS SELECT id FROM files
+          WHERE name ~* '\.(pdf|txt)$' AND name NOT SIMILAR TO 'notes%'",
+        (),
+    )
+    .unwrap();
+    let ids: Vec<i64> = db
+        .query("SELECT id FROM docs ORDER BY id", ())
+        .unwrap()
+        .map(|row| row.unwrap().get::<i64>(0).unwrap())
+        .collect();
+    assert_eq!(ids, vec![1, 2]);
+}
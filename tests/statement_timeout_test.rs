@@ -0,0 +1,220 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for SET statement_timeout
+
+use oxibase::{Database, Error};
+
+/// Correlated subquery over every row, quadratic in the table size
+const SLOW_QUERY: &str =
+    "SELECT COUNT(*) FROM t a WHERE v > (SELECT AVG(v) FROM t b WHERE b.v = a.v AND b.id < a.id)";
+
+fn setup() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, v INTEGER)", ())
+        .unwrap();
+    db.execute(
+        "INSERT INTO t SELECT value, value % 100 FROM generate_series(1, 20000)",
+        (),
+    )
+    .unwrap();
+    db.execute("CREATE TABLE log (id INTEGER PRIMARY KEY, n INTEGER)", ())
+        .unwrap();
+    db
+}
+
+fn log_count(db: &Database) -> i64 {
+    db.query_one("SELECT COUNT(*) FROM log", ()).unwrap()
+}
+
+#[test]
+fn test_statement_timeout_cancels_slow_query() {
+    let db = setup();
+    db.execute("SET statement_timeout = '50ms'", ()).unwrap();
+
+    let Err(err) = db.query(SLOW_QUERY, ()) else {
+        panic!("Expected the query to time out");
+    };
+    assert!(matches!(err, Error::QueryTimeout(50)), "{:?}", err);
+
+    // Fast statements still run under the timeout
+    let count: i64 = db.query_one("SELECT COUNT(*) FROM t", ()).unwrap();
+    assert_eq!(count, 20000);
+}
+
+#[test]
+fn test_statement_timeout_with_verbose_errors() {
+    let db = setup();
+    db.execute("SET statement_timeout = '50ms'", ()).unwrap();
+    db.execute("SET error_verbosity = 'verbose'", ()).unwrap();
+
+    let Err(err) = db.query(SLOW_QUERY, ()) else {
+        panic!("Expected the query to time out");
+    };
+    // The context wraps the error; its kind is matched through root()
+    assert!(matches!(err, Error::Detailed { .. }), "{:?}", err);
+    assert!(matches!(err.root(), Error::QueryTimeout(50)), "{:?}", err);
+    assert!(err.to_string().contains("statement: SELECT"), "{}", err);
+}
+
+#[test]
+fn test_statement_timeout_rolls_back() {
+    let db = setup();
+    db.execute("SET statement_timeout TO 50", ()).unwrap();
+
+    // Auto-commit statement: nothing is written
+    let Err(err) = db.execute(&format!("INSERT INTO log SELECT 1, ({})", SLOW_QUERY), ()) else {
+        panic!("Expected the insert to time out");
+    };
+    assert!(matches!(err, Error::QueryTimeout(_)), "{:?}", err);
+    assert_eq!(log_count(&db), 0);
+
+    // Explicit transaction: the failed statement leaves the transaction usable
+    db.execute("BEGIN", ()).unwrap();
+    db.execute("INSERT INTO log VALUES (2, 2)", ()).unwrap();
+    assert!(db.query(SLOW_QUERY, ()).is_err());
+    db.execute("ROLLBACK", ()).unwrap();
+    assert_eq!(log_count(&db), 0);
+}
+
+#[test]
+fn test_statement_timeout_cancels_update_and_delete() {
+    let db = setup();
+    db.execute("SET statement_timeout = '50ms'", ()).unwrap();
+
+    let err = db
+        .execute(
+            "UPDATE t SET v = v + 1 WHERE id > (SELECT COUNT(*) FROM t b WHERE b.id < t.id AND b.v <> t.v)",
+            (),
+        )
+        .unwrap_err();
+    assert!(matches!(err, Error::QueryTimeout(50)), "{:?}", err);
+
+    let err = db
+        .execute(
+            "DELETE FROM t WHERE id > (SELECT COUNT(*) FROM t b WHERE b.id < t.id AND b.v <> t.v)",
+            (),
+        )
+        .unwrap_err();
+    assert!(matches!(err, Error::QueryTimeout(50)), "{:?}", err);
+
+    // Neither statement changed anything
+    db.execute("SET statement_timeout = 0", ()).unwrap();
+    let (count, sum): (i64, i64) = {
+        let row = db
+            .query("SELECT COUNT(*), SUM(v) FROM t", ())
+            .unwrap()
+            .map(|r| r.unwrap())
+            .next()
+            .unwrap();
+        (row.get(0).unwrap(), row.get(1).unwrap())
+    };
+    assert_eq!(count, 20000);
+    assert_eq!(sum, 990000);
+}
+
+/// Expect `sql` to fail with a 50ms timeout, reading every row it returns
+fn assert_times_out(db: &Database, sql: &str) {
+    let result = db
+        .query(sql, ())
+        .and_then(|rows| rows.collect::<oxibase::Result<Vec<_>>>());
+    match result {
+        Err(err) => assert!(matches!(err, Error::QueryTimeout(50)), "{}: {:?}", sql, err),
+        Ok(rows) => panic!("{} returned {} rows instead of timing out", sql, rows.len()),
+    }
+}
+
+#[test]
+fn test_statement_timeout_cancels_scans() {
+    let db = setup();
+    db.execute("SET statement_timeout = '50ms'", ()).unwrap();
+
+    assert_times_out(&db, "SELECT COUNT(*) FROM generate_series(1, 5000000)");
+    assert_times_out(
+        &db,
+        "SELECT * FROM t WHERE LENGTH(REPEAT(CAST(v AS TEXT), 50000)) < 0",
+    );
+
+    // Rows read after the statement returned still count against the timeout
+    let mut rows = db.query("SELECT id FROM t", ()).unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    let err = rows
+        .find_map(|row| row.err())
+        .expect("Expected reading the rows to time out");
+    assert!(matches!(err, Error::QueryTimeout(50)), "{:?}", err);
+    assert!(rows.next().is_none());
+}
+
+#[test]
+fn test_statement_timeout_cancels_joins() {
+    let db = setup();
+    db.execute("SET statement_timeout = '50ms'", ()).unwrap();
+
+    // Hash joins of 4 million rows, a nested loop join and an aggregate over a join
+    assert_times_out(&db, "SELECT COUNT(*) FROM t a JOIN t b ON a.v = b.v");
+    assert_times_out(&db, "SELECT a.id, b.id FROM t a JOIN t b ON a.v = b.v");
+    assert_times_out(
+        &db,
+        "SELECT COUNT(*) FROM t a, t b WHERE a.id + b.id < 0 AND a.id < 1000",
+    );
+    assert_times_out(
+        &db,
+        "SELECT a.v, COUNT(*) FROM t a JOIN t b ON a.v = b.v GROUP BY a.v",
+    );
+}
+
+#[test]
+fn test_statement_timeout_cancels_create_table_as() {
+    let db = setup();
+    db.execute("SET statement_timeout = '50ms'", ()).unwrap();
+
+    let err = db
+        .execute(
+            "CREATE TABLE pairs AS SELECT a.id, b.v FROM t a JOIN t b ON a.v = b.v",
+            (),
+        )
+        .unwrap_err();
+    assert!(matches!(err, Error::QueryTimeout(50)), "{:?}", err);
+    let err = db
+        .execute(
+            "INSERT INTO log SELECT a.id * 100000 + b.id, b.v FROM t a JOIN t b ON a.v = b.v",
+            (),
+        )
+        .unwrap_err();
+    assert!(matches!(err, Error::QueryTimeout(50)), "{:?}", err);
+
+    // Neither statement left anything behind
+    db.execute("SET statement_timeout = 0", ()).unwrap();
+    assert!(db.query("SELECT * FROM pairs", ()).is_err());
+    assert_eq!(log_count(&db), 0);
+}
+
+#[test]
+fn test_statement_timeout_disable() {
+    let db = setup();
+    db.execute("SET statement_timeout = '5s'", ()).unwrap();
+    db.execute("SET statement_timeout = 0", ()).unwrap();
+
+    let count: i64 = db
+        .query_one("SELECT COUNT(*) FROM t WHERE v = 7", ())
+        .unwrap();
+    assert_eq!(count, 200);
+}
+
+#[test]
+fn test_statement_timeout_invalid_value() {
+    let db = setup();
+    assert!(db.execute("SET statement_timeout = 'soon'", ()).is_err());
+    assert!(db.execute("SET statement_timeout = -5", ()).is_err());
+}
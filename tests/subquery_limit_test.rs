@@ -0,0 +1,192 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Subquery LIMIT/OFFSET Tests
+//!
+//! Tests that LIMIT and OFFSET inside derived tables and subqueries are
+//! applied before the outer query filters or joins their rows
+
+use oxibase::Database;
+
+fn setup() -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE players (id INTEGER PRIMARY KEY, name TEXT, score INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE TABLE games (id INTEGER PRIMARY KEY, player_id INTEGER, points INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO players VALUES (1, 'a', 50), (2, 'b', 90), (3, 'c', 70), (4, 'd', 10), (5, 'e', 80)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO games VALUES (1, 1, 5), (2, 2, 6), (3, 3, 7), (4, 5, 8), (5, 2, 9), (6, 4, 1)",
+        (),
+    )
+    .unwrap();
+    db
+}
+
+fn ints(db: &Database, sql: &str) -> Vec<i64> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| row.unwrap().get::<i64>(0).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_limited_derived_table_joined_to_table() {
+    let db = setup();
+
+    // Top two players by score are 2 and 5
+    assert_eq!(
+        ints(
+            &db,
+            "SELECT g.points FROM (SELECT id FROM players ORDER BY score DESC LIMIT 2) top
+             JOIN games g ON g.player_id = top.id
+             ORDER BY g.points",
+        ),
+        vec![6, 8, 9]
+    );
+    assert_eq!(
+        ints(
+            &db,
+            "SELECT g.points FROM games g
+             JOIN (SELECT id FROM players ORDER BY score DESC LIMIT 2 OFFSET 1) top
+               ON g.player_id = top.id
+             ORDER BY g.points",
+        ),
+        vec![7, 8]
+    );
+
+    // An outer filter sees only the limited rows
+    assert_eq!(
+        ints(
+            &db,
+            "SELECT g.points FROM games g
+             JOIN (SELECT id, score FROM players ORDER BY score DESC LIMIT 2) top
+               ON g.player_id = top.id
+             WHERE top.score < 85",
+        ),
+        vec![8]
+    );
+    assert_eq!(
+        ints(
+            &db,
+            "SELECT g.points FROM games g,
+                 (SELECT id, score FROM players ORDER BY score LIMIT 3) low
+             WHERE g.player_id = low.id AND low.score > 20
+             ORDER BY g.points",
+        ),
+        vec![5, 7]
+    );
+
+    // LEFT JOIN keeps games whose player fell outside the limit
+    let matched: i64 = db
+        .query_one(
+            "SELECT COUNT(top.id) FROM games g
+             LEFT JOIN (SELECT id FROM players ORDER BY id LIMIT 1) top
+               ON g.player_id = top.id",
+            (),
+        )
+        .unwrap();
+    assert_eq!(matched, 1);
+}
+
+#[test]
+fn test_limit_in_derived_tables() {
+    let db = setup();
+
+    assert_eq!(
+        ints(
+            &db,
+            "SELECT id FROM (SELECT id, score FROM players ORDER BY score DESC LIMIT 2 OFFSET 1) d",
+        ),
+        vec![5, 3]
+    );
+    assert_eq!(
+        ints(
+            &db,
+            "SELECT SUM(points) FROM (SELECT points FROM games ORDER BY points DESC LIMIT 3) d",
+        ),
+        vec![24]
+    );
+    // Nested limits apply innermost first
+    assert_eq!(
+        ints(
+            &db,
+            "SELECT id FROM (
+                 SELECT id FROM (SELECT id FROM players ORDER BY id LIMIT 4) a
+                 ORDER BY id DESC LIMIT 2
+             ) b",
+        ),
+        vec![4, 3]
+    );
+    assert_eq!(
+        ints(
+            &db,
+            "WITH top AS (SELECT id FROM players ORDER BY score DESC LIMIT 2)
+             SELECT g.points FROM games g JOIN top ON g.player_id = top.id
+             ORDER BY g.points",
+        ),
+        vec![6, 8, 9]
+    );
+}
+
+#[test]
+fn test_limit_in_correlated_subqueries() {
+    let db = setup();
+
+    // Only player 2 has a second game
+    assert_eq!(
+        ints(
+            &db,
+            "SELECT id FROM players p WHERE EXISTS (
+                 SELECT 1 FROM games g WHERE g.player_id = p.id ORDER BY points LIMIT 1 OFFSET 1
+             ) ORDER BY id",
+        ),
+        vec![2]
+    );
+    assert_eq!(
+        ints(
+            &db,
+            "SELECT id FROM players p WHERE NOT EXISTS (
+                 SELECT 1 FROM games g WHERE g.player_id = p.id ORDER BY points LIMIT 1 OFFSET 1
+             ) ORDER BY id",
+        ),
+        vec![1, 3, 4, 5]
+    );
+    assert!(ints(
+        &db,
+        "SELECT id FROM players p WHERE EXISTS (
+             SELECT 1 FROM games g WHERE g.player_id = p.id LIMIT 0
+         )",
+    )
+    .is_empty());
+    assert_eq!(
+        ints(
+            &db,
+            "SELECT id FROM players WHERE id IN (
+                 SELECT player_id FROM games ORDER BY points DESC LIMIT 2 OFFSET 1
+             ) ORDER BY id",
+        ),
+        vec![3, 5]
+    );
+}
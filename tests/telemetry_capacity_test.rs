@@ -14,7 +14,6 @@
 
 use oxibase::api::Database;
 use oxibase::core::{DataType, Row, Value};
-use oxibase::Engine;
 
 #[test]
 fn test_telemetry_capacity() {
@@ -0,0 +1,193 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Non-INTEGER Primary Key Tests
+//!
+//! Tests TEXT and UUID primary keys, which are enforced by a unique index
+
+use oxibase::Database;
+
+#[test]
+fn test_text_primary_key() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE users (email TEXT PRIMARY KEY, name TEXT)", ())
+        .unwrap();
+
+    db.execute("INSERT INTO users VALUES ('ann@example.com', 'Ann')", ())
+        .unwrap();
+    db.execute("INSERT INTO users VALUES ('bob@example.com', 'Bob')", ())
+        .unwrap();
+
+    // Duplicates and NULLs are rejected
+    assert!(db
+        .execute("INSERT INTO users VALUES ('ann@example.com', 'Other')", ())
+        .is_err());
+    assert!(db
+        .execute("INSERT INTO users VALUES (NULL, 'Nobody')", ())
+        .is_err());
+    assert!(db
+        .execute(
+            "UPDATE users SET email = 'ann@example.com' WHERE name = 'Bob'",
+            ()
+        )
+        .is_err());
+
+    let name: String = db
+        .query_one("SELECT name FROM users WHERE email = 'bob@example.com'", ())
+        .unwrap();
+    assert_eq!(name, "Bob");
+    let count: i64 = db.query_one("SELECT COUNT(*) FROM users", ()).unwrap();
+    assert_eq!(count, 2);
+
+    // A deleted key can be reused
+    db.execute("DELETE FROM users WHERE email = 'ann@example.com'", ())
+        .unwrap();
+    db.execute("INSERT INTO users VALUES ('ann@example.com', 'Ann 2')", ())
+        .unwrap();
+}
+
+#[test]
+fn test_uuid_primary_key() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE sessions (id UUID PRIMARY KEY, user_id INTEGER)",
+        (),
+    )
+    .unwrap();
+
+    db.execute(
+        "INSERT INTO sessions VALUES
+            ('0b7e6a54-3c1e-4d4a-9a8e-5f8d2c1b0a99', 1),
+            ('f47ac10b-58cc-4372-a567-0e02b2c3d479', 2)",
+        (),
+    )
+    .unwrap();
+    assert!(db
+        .execute(
+            "INSERT INTO sessions VALUES ('0b7e6a54-3c1e-4d4a-9a8e-5f8d2c1b0a99', 3)",
+            ()
+        )
+        .is_err());
+    // A failed multi-row insert leaves nothing behind
+    assert!(db
+        .execute(
+            "INSERT INTO sessions VALUES
+                ('6fa459ea-ee8a-3ca4-894e-db77e160355e', 4),
+                ('f47ac10b-58cc-4372-a567-0e02b2c3d479', 5)",
+            ()
+        )
+        .is_err());
+
+    let user: i64 = db
+        .query_one(
+            "SELECT user_id FROM sessions WHERE id = 'f47ac10b-58cc-4372-a567-0e02b2c3d479'",
+            (),
+        )
+        .unwrap();
+    assert_eq!(user, 2);
+    let count: i64 = db.query_one("SELECT COUNT(*) FROM sessions", ()).unwrap();
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn test_auto_increment_requires_integer() {
+    let db = Database::open_in_memory().unwrap();
+    let err = db
+        .execute(
+            "CREATE TABLE t (id TEXT PRIMARY KEY AUTO_INCREMENT, v INTEGER)",
+            (),
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("AUTO_INCREMENT"));
+
+    // INTEGER keys keep auto-increment
+    db.execute(
+        "CREATE TABLE t (id INTEGER PRIMARY KEY AUTO_INCREMENT, v INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute("INSERT INTO t (v) VALUES (10), (20)", ())
+        .unwrap();
+    let max: i64 = db.query_one("SELECT MAX(id) FROM t", ()).unwrap();
+    assert_eq!(max, 2);
+}
+
+#[test]
+fn test_text_primary_key_is_reported_as_primary_key() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE users (email TEXT PRIMARY KEY, name TEXT UNIQUE)",
+        (),
+    )
+    .unwrap();
+
+    // DESCRIBE marks the key column PRI, not a plain unique column
+    let keys: Vec<(String, String)> = db
+        .query("DESCRIBE users", ())
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (row.get(0).unwrap(), row.get(3).unwrap())
+        })
+        .collect();
+    assert_eq!(
+        keys,
+        vec![
+            ("email".to_string(), "PRI".to_string()),
+            ("name".to_string(), String::new()),
+        ]
+    );
+
+    let create: String = db
+        .query("SHOW CREATE TABLE users", ())
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .get(1)
+        .unwrap();
+    assert_eq!(
+        create,
+        "CREATE TABLE users (email Text PRIMARY KEY, name Text UNIQUE)"
+    );
+
+    // A dump declares the key like SHOW CREATE TABLE, leaving UNIQUE to its
+    // index, and restoring it keeps rejecting duplicates
+    let dump = db.dump_schema().unwrap();
+    assert!(
+        dump.iter()
+            .any(|stmt| stmt == "CREATE TABLE users (email Text PRIMARY KEY, name Text)"),
+        "{:?}",
+        dump
+    );
+    let restored = Database::open_in_memory().unwrap();
+    for stmt in &dump {
+        restored.execute(stmt, ()).unwrap();
+    }
+    restored
+        .execute("INSERT INTO users VALUES ('ann@example.com', 'Ann')", ())
+        .unwrap();
+    assert!(restored
+        .execute("INSERT INTO users VALUES ('ann@example.com', 'Other')", ())
+        .is_err());
+    let key: String = restored
+        .query("DESCRIBE users", ())
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .get(3)
+        .unwrap();
+    assert_eq!(key, "PRI");
+}
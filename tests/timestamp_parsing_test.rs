@@ -0,0 +1,157 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Timestamp parsing
+//!
+//! Text inserted into TIMESTAMP columns and passed to TO_TIMESTAMP accepts
+//! the common ISO 8601 / RFC 3339 forms; TO_TIMESTAMP also takes an explicit
+//! format.
+
+use chrono::{DateTime, TimeZone, Utc};
+use oxibase::{Database, Value};
+
+fn ts(y: i32, mo: u32, d: u32, h: u32, mi: u32, s: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, mo, d, h, mi, s).unwrap()
+}
+
+fn eval(db: &Database, expr: &str) -> Value {
+    db.query_one(&format!("SELECT {}", expr), ()).unwrap()
+}
+
+#[test]
+fn test_column_accepts_common_formats() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, ts TIMESTAMP)", ())
+        .unwrap();
+
+    let with_millis = ts(2024, 3, 15, 14, 30, 45) + chrono::Duration::milliseconds(500);
+    let cases = [
+        ("2024-03-15 14:30:45", ts(2024, 3, 15, 14, 30, 45)),
+        ("2024-03-15T14:30:45", ts(2024, 3, 15, 14, 30, 45)),
+        ("2024-03-15T14:30:45Z", ts(2024, 3, 15, 14, 30, 45)),
+        ("2024-03-15T14:30:45.5Z", with_millis),
+        ("2024-03-15T14:30:45.500", with_millis),
+        ("2024-03-15T16:30:45+02:00", ts(2024, 3, 15, 14, 30, 45)),
+        ("2024-03-15 16:30:45+02:00", ts(2024, 3, 15, 14, 30, 45)),
+        ("2024-03-15 14:30", ts(2024, 3, 15, 14, 30, 0)),
+        ("2024-03-15T14:30", ts(2024, 3, 15, 14, 30, 0)),
+        ("2024-03-15", ts(2024, 3, 15, 0, 0, 0)),
+        (
+            "Fri, 15 Mar 2024 14:30:45 +0000",
+            ts(2024, 3, 15, 14, 30, 45),
+        ),
+    ];
+    for (i, (text, expected)) in cases.iter().enumerate() {
+        db.execute(&format!("INSERT INTO t VALUES ({}, '{}')", i, text), ())
+            .unwrap_or_else(|e| panic!("{}: {}", text, e));
+        let stored: Value = db
+            .query_one(&format!("SELECT ts FROM t WHERE id = {}", i), ())
+            .unwrap();
+        assert_eq!(stored, Value::Timestamp(*expected), "{}", text);
+    }
+}
+
+#[test]
+fn test_to_timestamp() {
+    let db = Database::open_in_memory().unwrap();
+    assert_eq!(
+        eval(&db, "TO_TIMESTAMP('2024-03-15T14:30:45Z')"),
+        Value::Timestamp(ts(2024, 3, 15, 14, 30, 45))
+    );
+    assert_eq!(
+        eval(
+            &db,
+            "TO_TIMESTAMP('15/03/2024 14:30', 'DD/MM/YYYY HH24:MI')"
+        ),
+        Value::Timestamp(ts(2024, 3, 15, 14, 30, 0))
+    );
+    assert_eq!(
+        eval(
+            &db,
+            "TO_TIMESTAMP('03:30 PM 15 Mar 2024', 'HH12:MI AM DD MON YYYY')"
+        ),
+        Value::Timestamp(ts(2024, 3, 15, 15, 30, 0))
+    );
+    assert_eq!(
+        eval(&db, "TO_TIMESTAMP('Mar 15 2024', '%b %d %Y')"),
+        Value::Timestamp(ts(2024, 3, 15, 0, 0, 0))
+    );
+    assert_eq!(
+        eval(&db, "TO_TIMESTAMP(1710513045)"),
+        Value::Timestamp(ts(2024, 3, 15, 14, 30, 45))
+    );
+    assert!(eval(&db, "TO_TIMESTAMP(NULL)").is_null());
+}
+
+#[test]
+fn test_unparseable_input_is_an_error() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, ts TIMESTAMP)", ())
+        .unwrap();
+
+    let err = db
+        .execute("INSERT INTO t VALUES (1, 'garbage')", ())
+        .unwrap_err();
+    assert!(err.to_string().contains("garbage"), "{}", err);
+
+    for expr in [
+        "TO_TIMESTAMP('garbage')",
+        "TO_TIMESTAMP('2024-03-15', 'DD/MM/YYYY')",
+    ] {
+        let err = db
+            .query(&format!("SELECT {}", expr), ())
+            .and_then(|mut rows| rows.next().transpose())
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("to TIMESTAMP"),
+            "{}: {}",
+            expr,
+            err
+        );
+    }
+}
+
+#[test]
+fn test_unparseable_update_is_an_error() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, ts TIMESTAMP)", ())
+        .unwrap();
+    db.execute("INSERT INTO t VALUES (1, '2024-03-15 14:30:45')", ())
+        .unwrap();
+
+    let err = db
+        .execute("UPDATE t SET ts = 'garbage' WHERE id = 1", ())
+        .unwrap_err();
+    assert!(err.to_string().contains("cannot convert value"), "{}", err);
+
+    // Through an API transaction too, for UPDATE and INSERT alike
+    let mut tx = db.begin().unwrap();
+    let err = tx.execute("UPDATE t SET ts = 'garbage'", ()).unwrap_err();
+    assert!(err.to_string().contains("cannot convert value"), "{}", err);
+    let err = tx
+        .execute("INSERT INTO t VALUES (2, 'garbage')", ())
+        .unwrap_err();
+    assert!(err.to_string().contains("cannot convert value"), "{}", err);
+    tx.rollback().unwrap();
+
+    // The stored timestamp is untouched
+    let stored: Value = db.query_one("SELECT ts FROM t WHERE id = 1", ()).unwrap();
+    assert_eq!(stored, Value::Timestamp(ts(2024, 3, 15, 14, 30, 45)));
+
+    // Text that parses still converts
+    db.execute("UPDATE t SET ts = '2024-03-16' WHERE id = 1", ())
+        .unwrap();
+    let stored: Value = db.query_one("SELECT ts FROM t WHERE id = 1", ()).unwrap();
+    assert_eq!(stored, Value::Timestamp(ts(2024, 3, 16, 0, 0, 0)));
+}
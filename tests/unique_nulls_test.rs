@@ -0,0 +1,187 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! NULL handling in UNIQUE indexes
+//!
+//! By default NULLs are distinct, so a unique column may hold any number of
+//! them. `NULLS NOT DISTINCT` treats NULL as an ordinary key value.
+
+use oxibase::Database;
+use tempfile::tempdir;
+
+fn setup(index_sql: &str) -> Database {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE accounts (id INTEGER PRIMARY KEY, email TEXT, region INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute(index_sql, ()).unwrap();
+    db
+}
+
+fn count(db: &Database, sql: &str) -> i64 {
+    db.query_one(sql, ()).unwrap()
+}
+
+#[test]
+fn test_nulls_distinct_by_default() {
+    for sql in [
+        "CREATE UNIQUE INDEX idx_email ON accounts (email)",
+        "CREATE UNIQUE INDEX idx_email ON accounts (email) NULLS DISTINCT",
+        "CREATE UNIQUE INDEX idx_email ON accounts (email) USING HASH",
+    ] {
+        let db = setup(sql);
+        db.execute(
+            "INSERT INTO accounts VALUES (1, NULL, 1), (2, NULL, 1), (3, 'a@x', 1)",
+            (),
+        )
+        .unwrap();
+        db.execute("INSERT INTO accounts VALUES (4, NULL, 2)", ())
+            .unwrap();
+        db.execute("UPDATE accounts SET email = NULL WHERE id = 3", ())
+            .unwrap();
+        assert_eq!(
+            count(&db, "SELECT COUNT(*) FROM accounts WHERE email IS NULL"),
+            4
+        );
+
+        // Non-NULL duplicates are still rejected
+        db.execute("INSERT INTO accounts VALUES (5, 'b@x', 2)", ())
+            .unwrap();
+        assert!(db
+            .execute("INSERT INTO accounts VALUES (6, 'b@x', 2)", ())
+            .is_err());
+    }
+}
+
+#[test]
+fn test_nulls_not_distinct() {
+    for sql in [
+        "CREATE UNIQUE INDEX idx_email ON accounts (email) NULLS NOT DISTINCT",
+        "CREATE UNIQUE INDEX idx_email ON accounts (email) USING HASH NULLS NOT DISTINCT",
+        "CREATE UNIQUE INDEX idx_email ON accounts (email) NULLS NOT DISTINCT USING BTREE",
+    ] {
+        let db = setup(sql);
+        db.execute("INSERT INTO accounts VALUES (1, NULL, 1)", ())
+            .unwrap();
+        let err = db
+            .execute("INSERT INTO accounts VALUES (2, NULL, 1)", ())
+            .unwrap_err();
+        assert!(err.to_string().contains("idx_email"), "{}", err);
+
+        db.execute(
+            "INSERT INTO accounts VALUES (2, 'a@x', 1), (3, 'b@x', 1)",
+            (),
+        )
+        .unwrap();
+        assert!(db
+            .execute("UPDATE accounts SET email = NULL WHERE id = 3", ())
+            .is_err());
+
+        // Freeing the NULL lets another row take it
+        db.execute("UPDATE accounts SET email = 'c@x' WHERE id = 1", ())
+            .unwrap();
+        db.execute("UPDATE accounts SET email = NULL WHERE id = 2", ())
+            .unwrap();
+        assert_eq!(count(&db, "SELECT id FROM accounts WHERE email IS NULL"), 2);
+    }
+}
+
+#[test]
+fn test_nulls_not_distinct_in_transaction() {
+    let db = setup("CREATE UNIQUE INDEX idx_email ON accounts (email) NULLS NOT DISTINCT");
+    db.execute("BEGIN", ()).unwrap();
+    db.execute("INSERT INTO accounts VALUES (1, NULL, 1), (2, NULL, 1)", ())
+        .unwrap();
+    assert!(db.execute("COMMIT", ()).is_err());
+    assert_eq!(count(&db, "SELECT COUNT(*) FROM accounts"), 0);
+}
+
+#[test]
+fn test_multi_column_nulls() {
+    let db = setup("CREATE UNIQUE INDEX idx_pair ON accounts (email, region)");
+    db.execute("INSERT INTO accounts VALUES (1, NULL, 1), (2, NULL, 1)", ())
+        .unwrap();
+
+    let db = setup("CREATE UNIQUE INDEX idx_pair ON accounts (email, region) NULLS NOT DISTINCT");
+    db.execute(
+        "INSERT INTO accounts VALUES (1, NULL, 1), (2, NULL, 2), (3, 'a@x', NULL)",
+        (),
+    )
+    .unwrap();
+    assert!(db
+        .execute("INSERT INTO accounts VALUES (4, NULL, 1)", ())
+        .is_err());
+    assert!(db
+        .execute("INSERT INTO accounts VALUES (4, 'a@x', NULL)", ())
+        .is_err());
+}
+
+#[test]
+fn test_create_on_existing_nulls() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE accounts (id INTEGER PRIMARY KEY, email TEXT)",
+        (),
+    )
+    .unwrap();
+    db.execute("INSERT INTO accounts VALUES (1, NULL), (2, NULL)", ())
+        .unwrap();
+
+    db.execute("CREATE UNIQUE INDEX idx_a ON accounts (email)", ())
+        .unwrap();
+    assert!(db
+        .execute(
+            "CREATE UNIQUE INDEX idx_b ON accounts (email) NULLS NOT DISTINCT",
+            ()
+        )
+        .is_err());
+
+    // Only meaningful on a unique index
+    assert!(db
+        .execute(
+            "CREATE INDEX idx_c ON accounts (email) NULLS NOT DISTINCT",
+            ()
+        )
+        .is_err());
+}
+
+#[test]
+fn test_nulls_not_distinct_survives_reopen() {
+    let dir = tempdir().unwrap();
+    let dsn = format!("file://{}", dir.path().join("test.db").display());
+    {
+        let db = Database::open(&dsn).unwrap();
+        db.execute(
+            "CREATE TABLE accounts (id INTEGER PRIMARY KEY, email TEXT)",
+            (),
+        )
+        .unwrap();
+        db.execute(
+            "CREATE UNIQUE INDEX idx_email ON accounts (email) NULLS NOT DISTINCT",
+            (),
+        )
+        .unwrap();
+        db.execute("INSERT INTO accounts VALUES (1, NULL)", ())
+            .unwrap();
+    }
+
+    let db = Database::open(&dsn).unwrap();
+    assert!(db
+        .execute("INSERT INTO accounts VALUES (2, NULL)", ())
+        .is_err());
+    db.execute("INSERT INTO accounts VALUES (2, 'a@x')", ())
+        .unwrap();
+}
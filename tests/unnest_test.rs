@@ -0,0 +1,167 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests for UNNEST and WITH ORDINALITY
+
+use oxibase::Database;
+
+fn setup_posts(db: &Database) {
+    db.execute(
+        "CREATE TABLE posts (id INTEGER PRIMARY KEY, title TEXT, tags JSON)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        r#"INSERT INTO posts VALUES
+            (1, 'Intro', '["rust", "db"]'),
+            (2, 'Storage', '["db"]'),
+            (3, 'Draft', NULL),
+            (4, 'Empty', '[]')"#,
+        (),
+    )
+    .unwrap();
+}
+
+fn collect_rows(db: &Database, sql: &str) -> Vec<String> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (0..row.len())
+                .map(|i| row.get_value(i).unwrap().to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect()
+}
+
+#[test]
+fn test_unnest_select_list() {
+    let db = Database::open_in_memory().unwrap();
+    setup_posts(&db);
+
+    // NULL and empty arrays produce no rows
+    let result = db
+        .query("SELECT id, UNNEST(tags) FROM posts ORDER BY id", ())
+        .unwrap();
+    assert_eq!(result.columns(), &["id", "unnest"]);
+    assert_eq!(
+        collect_rows(&db, "SELECT id, UNNEST(tags) FROM posts ORDER BY id"),
+        vec!["1,rust", "1,db", "2,db"]
+    );
+
+    // ORDER BY, DISTINCT and LIMIT apply to the expanded rows
+    assert_eq!(
+        collect_rows(
+            &db,
+            "SELECT id, UNNEST(tags) AS tag FROM posts ORDER BY tag, id"
+        ),
+        vec!["1,db", "2,db", "1,rust"]
+    );
+    assert_eq!(
+        collect_rows(
+            &db,
+            "SELECT DISTINCT UNNEST(tags) AS tag FROM posts ORDER BY tag"
+        ),
+        vec!["db", "rust"]
+    );
+    assert_eq!(
+        collect_rows(
+            &db,
+            "SELECT id, UNNEST(tags) FROM posts ORDER BY id LIMIT 2 OFFSET 1"
+        ),
+        vec!["1,db", "2,db"]
+    );
+
+    // Side-by-side expansion pads the shorter array with NULLs
+    assert_eq!(
+        collect_rows(&db, r#"SELECT UNNEST('[1, 2, 3]'), UNNEST('["x"]')"#),
+        vec!["1,x", "2,NULL", "3,NULL"]
+    );
+
+    assert!(db.query("SELECT UNNEST(title) FROM posts", ()).is_err());
+}
+
+#[test]
+fn test_unnest_inverts_array_agg() {
+    let db = Database::open_in_memory().unwrap();
+    setup_posts(&db);
+
+    assert_eq!(
+        collect_rows(
+            &db,
+            "SELECT UNNEST(ARRAY_AGG(title ORDER BY id)) AS title FROM posts"
+        ),
+        vec!["Intro", "Storage", "Draft", "Empty"]
+    );
+}
+
+#[test]
+fn test_unnest_join_back() {
+    let db = Database::open_in_memory().unwrap();
+    setup_posts(&db);
+
+    // Post titles per tag, by joining the unnested tags back to their posts
+    assert_eq!(
+        collect_rows(
+            &db,
+            "SELECT t.tag, p.title FROM (SELECT id, UNNEST(tags) AS tag FROM posts) t \
+             JOIN posts p ON p.id = t.id ORDER BY t.tag, p.title"
+        ),
+        vec!["db,Intro", "db,Storage", "rust,Intro"]
+    );
+    assert_eq!(
+        collect_rows(
+            &db,
+            "SELECT tag, COUNT(*) FROM (SELECT UNNEST(tags) AS tag FROM posts) t \
+             GROUP BY tag ORDER BY tag"
+        ),
+        vec!["db,2", "rust,1"]
+    );
+}
+
+#[test]
+fn test_unnest_from_with_ordinality() {
+    let db = Database::open_in_memory().unwrap();
+
+    assert_eq!(
+        collect_rows(&db, r#"SELECT * FROM UNNEST('["a", "b", "c"]')"#),
+        vec!["a", "b", "c"]
+    );
+
+    let result = db
+        .query(
+            r#"SELECT * FROM UNNEST('["a", "b", "c"]') WITH ORDINALITY"#,
+            (),
+        )
+        .unwrap();
+    assert_eq!(result.columns(), &["value", "ordinality"]);
+    assert_eq!(
+        collect_rows(
+            &db,
+            r#"SELECT * FROM UNNEST('["a", "b", "c"]') WITH ORDINALITY"#
+        ),
+        vec!["a,1", "b,2", "c,3"]
+    );
+
+    // Works for any table-valued function, with column aliases
+    assert_eq!(
+        collect_rows(
+            &db,
+            "SELECT v, n FROM generate_series(10, 30, 10) WITH ORDINALITY AS g(v, n) \
+             ORDER BY n DESC"
+        ),
+        vec!["30,3", "20,2", "10,1"]
+    );
+}
@@ -0,0 +1,301 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! UPDATE ... FROM Tests
+//!
+//! Tests updating a table from rows of a joined source table
+
+use oxibase::Database;
+
+fn setup(db: &Database) {
+    db.execute(
+        "CREATE TABLE inventory (id INTEGER PRIMARY KEY, name TEXT, qty INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE TABLE counts (item_id INTEGER, counted INTEGER, location TEXT)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO inventory VALUES (1, 'bolt', 10), (2, 'nut', 20), (3, 'screw', 30)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO counts VALUES (1, 12, 'a'), (3, 25, 'a'), (4, 99, 'a')",
+        (),
+    )
+    .unwrap();
+}
+
+fn quantities(db: &Database) -> Vec<(i64, i64)> {
+    db.query("SELECT id, qty FROM inventory ORDER BY id", ())
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (row.get(0).unwrap(), row.get(1).unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn test_update_from_join() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let affected = db
+        .execute(
+            "UPDATE inventory SET qty = c.counted FROM counts c WHERE inventory.id = c.item_id",
+            (),
+        )
+        .unwrap();
+    assert_eq!(affected, 2);
+
+    // Rows without a match are untouched
+    assert_eq!(quantities(&db), vec![(1, 12), (2, 20), (3, 25)]);
+}
+
+#[test]
+fn test_update_from_expression_and_filter() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    db.execute(
+        "UPDATE inventory SET qty = qty + c.counted, name = name || '-' || c.location \
+         FROM counts AS c WHERE inventory.id = c.item_id AND c.counted > 20",
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(quantities(&db), vec![(1, 10), (2, 20), (3, 55)]);
+    let name: String = db
+        .query_one("SELECT name FROM inventory WHERE id = 3", ())
+        .unwrap();
+    assert_eq!(name, "screw-a");
+}
+
+#[test]
+fn test_update_from_returning() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let mut ids: Vec<i64> = db
+        .query(
+            "UPDATE inventory SET qty = c.counted FROM counts c \
+             WHERE inventory.id = c.item_id RETURNING id",
+            (),
+        )
+        .unwrap()
+        .map(|row| row.unwrap().get(0).unwrap())
+        .collect();
+    ids.sort();
+    assert_eq!(ids, vec![1, 3]);
+}
+
+#[test]
+fn test_update_from_multiple_matches_fails() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+    db.execute("INSERT INTO counts VALUES (1, 15, 'b')", ())
+        .unwrap();
+
+    let err = db
+        .execute(
+            "UPDATE inventory SET qty = c.counted FROM counts c WHERE inventory.id = c.item_id",
+            (),
+        )
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("more than one source row"),
+        "{}",
+        err
+    );
+
+    // Nothing was changed
+    assert_eq!(quantities(&db), vec![(1, 10), (2, 20), (3, 30)]);
+}
+
+#[test]
+fn test_update_from_in_transaction() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    db.execute("BEGIN", ()).unwrap();
+    db.execute("INSERT INTO counts VALUES (2, 21, 'a')", ())
+        .unwrap();
+    db.execute(
+        "UPDATE inventory SET qty = c.counted FROM counts c WHERE inventory.id = c.item_id",
+        (),
+    )
+    .unwrap();
+
+    // The join sees rows inserted earlier in the same transaction
+    assert_eq!(quantities(&db), vec![(1, 12), (2, 21), (3, 25)]);
+    db.execute("ROLLBACK", ()).unwrap();
+
+    assert_eq!(quantities(&db), vec![(1, 10), (2, 20), (3, 30)]);
+}
+
+#[test]
+fn test_update_from_table_without_key() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+    db.execute("INSERT INTO counts VALUES (1, 12, 'a')", ())
+        .unwrap();
+
+    // Identical rows of a table without a key get the same new values
+    let affected = db
+        .execute(
+            "UPDATE counts SET counted = i.qty FROM inventory i WHERE counts.item_id = i.id",
+            (),
+        )
+        .unwrap();
+    assert_eq!(affected, 3);
+
+    let counted: Vec<(i64, i64)> = db
+        .query(
+            "SELECT item_id, counted FROM counts ORDER BY item_id, counted",
+            (),
+        )
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (row.get(0).unwrap(), row.get(1).unwrap())
+        })
+        .collect();
+    assert_eq!(counted, vec![(1, 10), (1, 10), (3, 30), (4, 99)]);
+}
+
+#[test]
+fn test_update_from_text_primary_key() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE items (code TEXT PRIMARY KEY, qty INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute("CREATE TABLE deltas (code TEXT, delta INTEGER)", ())
+        .unwrap();
+    db.execute("INSERT INTO items VALUES ('a', 1), ('b', 2), ('c', 3)", ())
+        .unwrap();
+    db.execute("INSERT INTO deltas VALUES ('a', 10), ('c', 30)", ())
+        .unwrap();
+
+    let affected = db
+        .execute(
+            "UPDATE items SET qty = qty + d.delta FROM deltas d WHERE items.code = d.code",
+            (),
+        )
+        .unwrap();
+    assert_eq!(affected, 2);
+
+    let qty: Vec<(String, i64)> = db
+        .query("SELECT code, qty FROM items ORDER BY code", ())
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (row.get(0).unwrap(), row.get(1).unwrap())
+        })
+        .collect();
+    assert_eq!(
+        qty,
+        vec![
+            ("a".to_string(), 11),
+            ("b".to_string(), 2),
+            ("c".to_string(), 33)
+        ]
+    );
+}
+
+#[test]
+fn test_update_from_composite_primary_key() {
+    let db = Database::open_in_memory().unwrap();
+    db.execute(
+        "CREATE TABLE oi (order_id INTEGER, product_id INTEGER, qty INTEGER, \
+         PRIMARY KEY (order_id, product_id))",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "CREATE TABLE adj (order_id INTEGER, product_id INTEGER, qty INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute("INSERT INTO oi VALUES (1, 1, 5), (1, 2, 6), (2, 1, 7)", ())
+        .unwrap();
+    db.execute("INSERT INTO adj VALUES (1, 1, 50), (1, 2, 60)", ())
+        .unwrap();
+
+    // Rows sharing the first key column stay distinct
+    let affected = db
+        .execute(
+            "UPDATE oi SET qty = a.qty FROM adj a \
+             WHERE oi.order_id = a.order_id AND oi.product_id = a.product_id",
+            (),
+        )
+        .unwrap();
+    assert_eq!(affected, 2);
+
+    let qty: Vec<(i64, i64, i64)> = db
+        .query(
+            "SELECT order_id, product_id, qty FROM oi ORDER BY order_id, product_id",
+            (),
+        )
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (
+                row.get(0).unwrap(),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(qty, vec![(1, 1, 50), (1, 2, 60), (2, 1, 7)]);
+}
+
+#[test]
+fn test_update_from_with_target_alias_in_api_transaction() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    let mut tx = db.begin().unwrap();
+    tx.execute("INSERT INTO counts VALUES (2, 21, 'a')", ())
+        .unwrap();
+    let affected = tx
+        .execute(
+            "UPDATE inventory AS i SET qty = c.counted FROM counts c WHERE i.id = c.item_id",
+            (),
+        )
+        .unwrap();
+    assert_eq!(affected, 3);
+
+    // The join sees the row the transaction inserted; nothing shows outside it
+    assert_eq!(quantities(&db), vec![(1, 10), (2, 20), (3, 30)]);
+    tx.commit().unwrap();
+
+    assert_eq!(quantities(&db), vec![(1, 12), (2, 21), (3, 25)]);
+}
+
+#[test]
+fn test_update_with_target_alias() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    db.execute("UPDATE inventory i SET qty = i.qty + 1 WHERE i.id = 2", ())
+        .unwrap();
+    assert_eq!(quantities(&db), vec![(1, 10), (2, 21), (3, 30)]);
+}
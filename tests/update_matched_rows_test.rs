@@ -0,0 +1,117 @@
+// Copyright 2026 Oxibase Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! UPDATE matched vs changed row counts
+//!
+//! `rows_affected` counts every row the WHERE clause selects, `rows_changed`
+//! only the rows whose values changed.
+
+use oxibase::{Database, RowCounts};
+
+fn setup(db: &Database) {
+    db.execute(
+        "CREATE TABLE t (id INTEGER PRIMARY KEY, a INTEGER, b INTEGER)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO t VALUES (1, 1, 1), (2, 50, 60), (3, 2, 2), (4, 2, 9)",
+        (),
+    )
+    .unwrap();
+}
+
+/// (rows_affected, rows_changed) of an UPDATE
+fn counts(db: &Database, sql: &str) -> (i64, i64) {
+    let rows = db.query(sql, ()).unwrap();
+    (rows.rows_affected(), rows.rows_changed())
+}
+
+#[test]
+fn test_noop_update_matches_without_changing() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    // Primary key lookup
+    assert_eq!(counts(&db, "UPDATE t SET a = a WHERE id = 1"), (1, 0));
+    // Full scan
+    assert_eq!(counts(&db, "UPDATE t SET b = b"), (4, 0));
+    // Constant equal to the stored value
+    assert_eq!(counts(&db, "UPDATE t SET a = 50 WHERE id = 2"), (1, 0));
+
+    // execute() keeps reporting the matched rows; the changed count is opt-in
+    assert_eq!(
+        db.execute("UPDATE t SET a = a WHERE id = 1", ()).unwrap(),
+        1
+    );
+    assert_eq!(
+        db.execute_counts("UPDATE t SET a = a WHERE id <= 2", ())
+            .unwrap(),
+        RowCounts {
+            rows_affected: 2,
+            rows_changed: 0
+        }
+    );
+}
+
+#[test]
+fn test_partial_change_counts() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    // Rows 3 and 4 match, only row 4 has a different b
+    assert_eq!(counts(&db, "UPDATE t SET b = 2 WHERE a = 2"), (2, 1));
+    // Filter evaluated in memory
+    assert_eq!(counts(&db, "UPDATE t SET a = 0 WHERE a + b > 100"), (1, 1));
+    assert_eq!(counts(&db, "UPDATE t SET a = 0 WHERE a + b > 100"), (0, 0));
+
+    let b: i64 = db.query_one("SELECT b FROM t WHERE id = 4", ()).unwrap();
+    assert_eq!(b, 2);
+    let a: i64 = db.query_one("SELECT a FROM t WHERE id = 2", ()).unwrap();
+    assert_eq!(a, 0);
+}
+
+#[test]
+fn test_noop_update_inside_transaction() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    db.execute("BEGIN", ()).unwrap();
+    assert_eq!(counts(&db, "UPDATE t SET a = a + 0 WHERE id <= 2"), (2, 0));
+    assert_eq!(counts(&db, "UPDATE t SET a = a + 1 WHERE id <= 2"), (2, 2));
+    db.execute("COMMIT", ()).unwrap();
+
+    let sum: i64 = db.query_one("SELECT SUM(a) FROM t", ()).unwrap();
+    assert_eq!(sum, 1 + 50 + 2 + 2 + 2);
+
+    let mut tx = db.begin().unwrap();
+    assert_eq!(
+        tx.execute_counts("UPDATE t SET b = 2 WHERE a = 2", ())
+            .unwrap(),
+        RowCounts {
+            rows_affected: 3,
+            rows_changed: 2
+        }
+    );
+    tx.commit().unwrap();
+}
+
+#[test]
+fn test_other_statements_report_affected_as_changed() {
+    let db = Database::open_in_memory().unwrap();
+    setup(&db);
+
+    assert_eq!(counts(&db, "INSERT INTO t VALUES (5, 0, 0)"), (1, 1));
+    assert_eq!(counts(&db, "DELETE FROM t WHERE a = 2"), (2, 2));
+}
@@ -565,3 +565,136 @@ fn test_values_large_dataset() {
     assert_eq!(count, 100);
     assert_eq!(sum, 5050); // Sum of 1 to 100
 }
+
+// ============================================================================
+// VALUES in Joins and CTEs
+// ============================================================================
+
+fn create_orders_db(name: &str) -> Database {
+    let db = create_test_db(name);
+    db.execute(
+        "CREATE TABLE orders (id INTEGER PRIMARY KEY, status_code INTEGER, amount FLOAT)",
+        (),
+    )
+    .unwrap();
+    db.execute(
+        "INSERT INTO orders VALUES (1, 1, 10.0), (2, 2, 20.0), (3, 1, 5.0), (4, 3, 1.0)",
+        (),
+    )
+    .unwrap();
+    db
+}
+
+fn id_label_rows(db: &Database, sql: &str) -> Vec<(i64, Option<String>)> {
+    db.query(sql, ())
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (row.get(0).unwrap(), row.get(1).unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn test_values_joined_with_table() {
+    let db = create_orders_db("values_join");
+    let labelled = vec![
+        (1, Some("open".to_string())),
+        (2, Some("shipped".to_string())),
+        (3, Some("open".to_string())),
+    ];
+
+    // VALUES on either side of the join
+    assert_eq!(
+        id_label_rows(
+            &db,
+            "SELECT o.id, s.label FROM orders o \
+             JOIN (VALUES (1, 'open'), (2, 'shipped')) AS s(code, label) ON o.status_code = s.code \
+             ORDER BY o.id"
+        ),
+        labelled
+    );
+    assert_eq!(
+        id_label_rows(
+            &db,
+            "SELECT o.id, s.label FROM (VALUES (1, 'open'), (2, 'shipped')) AS s(code, label) \
+             JOIN orders o ON o.status_code = s.code ORDER BY o.id"
+        ),
+        labelled
+    );
+
+    let mut with_unmatched = labelled.clone();
+    with_unmatched.push((4, None));
+    assert_eq!(
+        id_label_rows(
+            &db,
+            "SELECT o.id, s.label FROM orders o \
+             LEFT JOIN (VALUES (1, 'open'), (2, 'shipped')) s(code, label) ON o.status_code = s.code \
+             ORDER BY o.id"
+        ),
+        with_unmatched
+    );
+
+    let totals: Vec<(String, f64)> = db
+        .query(
+            "SELECT s.label, SUM(o.amount) FROM orders o \
+             JOIN (VALUES (1, 'open'), (2, 'shipped')) s(code, label) ON o.status_code = s.code \
+             GROUP BY s.label ORDER BY s.label",
+            (),
+        )
+        .unwrap()
+        .map(|row| {
+            let row = row.unwrap();
+            (row.get(0).unwrap(), row.get(1).unwrap())
+        })
+        .collect();
+    assert_eq!(
+        totals,
+        vec![("open".to_string(), 15.0), ("shipped".to_string(), 20.0)]
+    );
+}
+
+#[test]
+fn test_values_as_cte() {
+    let db = create_orders_db("values_cte");
+
+    assert_eq!(
+        id_label_rows(
+            &db,
+            "WITH s(code, label) AS (VALUES (1, 'open'), (2, 'shipped')) \
+             SELECT o.id, s.label FROM orders o JOIN s ON o.status_code = s.code ORDER BY o.id"
+        ),
+        vec![
+            (1, Some("open".to_string())),
+            (2, Some("shipped".to_string())),
+            (3, Some("open".to_string())),
+        ]
+    );
+
+    // Without a column list the columns are column1, column2, ...
+    let rows = db
+        .query(
+            "WITH v AS (VALUES (1, 'a'), (2, 'b')) SELECT column2 FROM v ORDER BY column2 DESC",
+            (),
+        )
+        .unwrap();
+    assert_eq!(rows.columns(), &["column2".to_string()]);
+    let labels: Vec<String> = rows.map(|row| row.unwrap().get(0).unwrap()).collect();
+    assert_eq!(labels, vec!["b", "a"]);
+}
+
+#[test]
+fn test_values_with_in_subquery() {
+    let db = create_orders_db("values_in_subquery");
+
+    let ids: Vec<i64> = db
+        .query(
+            "SELECT id FROM (VALUES (1), (2), (5)) AS t(id) \
+             WHERE id IN (SELECT status_code FROM orders) ORDER BY id",
+            (),
+        )
+        .unwrap()
+        .map(|row| row.unwrap().get(0).unwrap())
+        .collect();
+    assert_eq!(ids, vec![1, 2]);
+}
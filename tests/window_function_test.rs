@@ -1,4 +1,5 @@
 // Copyright 2025 Stoolap Contributors
+// Copyright 2025 Oxibase Contributors
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -715,3 +716,305 @@ fn test_window_functions_with_partition() {
 
     assert_eq!(row_count, 7, "Expected 7 rows, got {}", row_count);
 }
+
+fn setup_sales_table(db: &Database) {
+    db.execute(
+        "CREATE TABLE sales (id INTEGER PRIMARY KEY, region TEXT, month INTEGER, amount INTEGER, qty INTEGER)",
+        (),
+    )
+    .expect("Failed to create table");
+    db.execute(
+        "INSERT INTO sales VALUES
+            (1, 'east', 1, 100, 1), (2, 'east', 2, 130, 2), (3, 'east', 3, 120, 1),
+            (4, 'west', 1, 200, 3), (5, 'west', 2, 180, 1), (6, 'west', 3, 260, 2)",
+        (),
+    )
+    .expect("Failed to insert data");
+}
+
+/// Collect (id, nullable integer) pairs from a two-column query
+fn collect_opt(db: &Database, sql: &str) -> Vec<(i64, Option<i64>)> {
+    db.query(sql, ())
+        .expect("Failed to query")
+        .map(|row| {
+            let row = row.expect("Failed to get row");
+            (row.get(0).unwrap(), row.get(1).ok())
+        })
+        .collect()
+}
+
+/// Test period-over-period differences with LAG over an ordered partition
+#[test]
+fn test_lag_period_over_period() {
+    let db = Database::open("memory://window_lag_pop").expect("Failed to create database");
+    setup_sales_table(&db);
+
+    let rows = collect_opt(
+        &db,
+        "SELECT id, amount - LAG(amount) OVER (PARTITION BY region ORDER BY month) AS diff
+         FROM sales ORDER BY id",
+    );
+    assert_eq!(
+        rows,
+        vec![
+            (1, None),
+            (2, Some(30)),
+            (3, Some(-10)),
+            (4, None),
+            (5, Some(-20)),
+            (6, Some(80)),
+        ]
+    );
+
+    // Offset and default
+    let rows = collect_opt(
+        &db,
+        "SELECT id, LAG(amount, 2, 0) OVER (PARTITION BY region ORDER BY month) FROM sales ORDER BY id",
+    );
+    assert_eq!(
+        rows,
+        vec![
+            (1, Some(0)),
+            (2, Some(0)),
+            (3, Some(100)),
+            (4, Some(0)),
+            (5, Some(0)),
+            (6, Some(200)),
+        ]
+    );
+
+    // A negative LEAD offset looks backwards like LAG
+    let rows = collect_opt(
+        &db,
+        "SELECT id, LEAD(amount, -1) OVER (PARTITION BY region ORDER BY month) FROM sales ORDER BY id",
+    );
+    assert_eq!(rows[1], (2, Some(100)));
+    assert_eq!(rows[0], (1, None));
+}
+
+/// Test navigation functions over expression arguments
+#[test]
+fn test_navigation_expression_argument() {
+    let db = Database::open("memory://window_nav_expr").expect("Failed to create database");
+    setup_sales_table(&db);
+
+    let rows = collect_opt(
+        &db,
+        "SELECT id, LEAD(amount * qty) OVER (PARTITION BY region ORDER BY month) FROM sales ORDER BY id",
+    );
+    assert_eq!(
+        rows,
+        vec![
+            (1, Some(260)),
+            (2, Some(120)),
+            (3, None),
+            (4, Some(180)),
+            (5, Some(520)),
+            (6, None),
+        ]
+    );
+}
+
+/// Test frame awareness of FIRST_VALUE / LAST_VALUE / NTH_VALUE
+#[test]
+fn test_navigation_frames() {
+    let db = Database::open("memory://window_nav_frames").expect("Failed to create database");
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, k INTEGER)", ())
+        .expect("Failed to create table");
+    db.execute(
+        "INSERT INTO t VALUES (1, 10), (2, 20), (3, 20), (4, 30), (5, 40)",
+        (),
+    )
+    .expect("Failed to insert data");
+
+    // Default frame with ORDER BY includes peers of the current row
+    let rows = collect_opt(
+        &db,
+        "SELECT id, LAST_VALUE(id) OVER (ORDER BY k) FROM t ORDER BY id",
+    );
+    assert_eq!(
+        rows,
+        vec![
+            (1, Some(1)),
+            (2, Some(3)),
+            (3, Some(3)),
+            (4, Some(4)),
+            (5, Some(5))
+        ]
+    );
+
+    // ROWS frame around the current row
+    let rows = collect_opt(
+        &db,
+        "SELECT id, FIRST_VALUE(k) OVER (ORDER BY id ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) FROM t ORDER BY id",
+    );
+    assert_eq!(
+        rows,
+        vec![
+            (1, Some(10)),
+            (2, Some(10)),
+            (3, Some(20)),
+            (4, Some(20)),
+            (5, Some(30))
+        ]
+    );
+
+    // NTH_VALUE is NULL until the frame holds n rows
+    let rows = collect_opt(
+        &db,
+        "SELECT id, NTH_VALUE(k, 2) OVER (ORDER BY id ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) FROM t ORDER BY id",
+    );
+    assert_eq!(
+        rows,
+        vec![
+            (1, None),
+            (2, Some(20)),
+            (3, Some(20)),
+            (4, Some(20)),
+            (5, Some(20))
+        ]
+    );
+
+    // Whole partition
+    let rows = collect_opt(
+        &db,
+        "SELECT id, LAST_VALUE(k) OVER (ORDER BY id ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING) FROM t ORDER BY id",
+    );
+    assert!(rows.iter().all(|(_, v)| *v == Some(40)));
+
+    // RANGE offsets compare ORDER BY values
+    let rows = collect_opt(
+        &db,
+        "SELECT id, FIRST_VALUE(id) OVER (ORDER BY k RANGE BETWEEN 10 PRECEDING AND CURRENT ROW) FROM t ORDER BY id",
+    );
+    assert_eq!(
+        rows,
+        vec![
+            (1, Some(1)),
+            (2, Some(1)),
+            (3, Some(1)),
+            (4, Some(2)),
+            (5, Some(4))
+        ]
+    );
+}
+
+/// Test NTILE bucket sizes, including uneven splits and more buckets than rows
+#[test]
+fn test_ntile_bucket_sizes() {
+    let db = Database::open("memory://window_ntile_sizes").expect("Failed to create database");
+    db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, grp TEXT)", ())
+        .expect("Failed to create table");
+    for i in 1..=10 {
+        let grp = if i <= 7 { "a" } else { "b" };
+        db.execute(&format!("INSERT INTO t VALUES ({}, '{}')", i, grp), ())
+            .expect("Failed to insert data");
+    }
+
+    let bucket_sizes = |sql: &str| -> Vec<(i64, i64)> {
+        db.query(sql, ())
+            .expect("Failed to query")
+            .map(|row| {
+                let row = row.expect("Failed to get row");
+                (row.get(0).unwrap(), row.get(1).unwrap())
+            })
+            .collect()
+    };
+
+    // 10 rows into 4 buckets: the first two buckets get the extra rows
+    assert_eq!(
+        bucket_sizes(
+            "SELECT b, COUNT(*) FROM (SELECT NTILE(4) OVER (ORDER BY id) AS b FROM t) s GROUP BY b ORDER BY b"
+        ),
+        vec![(1, 3), (2, 3), (3, 2), (4, 2)]
+    );
+
+    // Per partition: 7 rows into 3 buckets and 3 rows into 3 buckets
+    let rows: Vec<(i64, i64)> = db
+        .query(
+            "SELECT id, NTILE(3) OVER (PARTITION BY grp ORDER BY id) FROM t ORDER BY id",
+            (),
+        )
+        .expect("Failed to query")
+        .map(|row| {
+            let row = row.expect("Failed to get row");
+            (row.get(0).unwrap(), row.get(1).unwrap())
+        })
+        .collect();
+    let buckets: Vec<i64> = rows.iter().map(|(_, b)| *b).collect();
+    assert_eq!(buckets, vec![1, 1, 1, 2, 2, 3, 3, 1, 2, 3]);
+
+    // More buckets than rows: every row gets its own bucket
+    assert_eq!(
+        bucket_sizes(
+            "SELECT b, COUNT(*) FROM (SELECT NTILE(20) OVER (PARTITION BY grp ORDER BY id) AS b FROM t WHERE grp = 'b') s GROUP BY b ORDER BY b"
+        ),
+        vec![(1, 1), (2, 1), (3, 1)]
+    );
+
+    assert!(db
+        .query("SELECT NTILE(0) OVER (ORDER BY id) FROM t", ())
+        .is_err());
+}
+
+/// Test PERCENT_RANK and CUME_DIST distributions with ties across all ORDER BY keys
+#[test]
+fn test_rank_distributions() {
+    let db = Database::open("memory://window_rank_dist").expect("Failed to create database");
+    db.execute(
+        "CREATE TABLE scores (id INTEGER PRIMARY KEY, a INTEGER, b INTEGER)",
+        (),
+    )
+    .expect("Failed to create table");
+    db.execute(
+        "INSERT INTO scores VALUES (1, 1, 1), (2, 1, 2), (3, 1, 2), (4, 2, 1), (5, 3, 1)",
+        (),
+    )
+    .expect("Failed to insert data");
+
+    let rows: Vec<(i64, f64, f64)> = db
+        .query(
+            "SELECT id,
+                    PERCENT_RANK() OVER (ORDER BY a, b),
+                    CUME_DIST() OVER (ORDER BY a, b)
+             FROM scores ORDER BY id",
+            (),
+        )
+        .expect("Failed to query")
+        .map(|row| {
+            let row = row.expect("Failed to get row");
+            (
+                row.get(0).unwrap(),
+                row.get(1).unwrap(),
+                row.get(2).unwrap(),
+            )
+        })
+        .collect();
+
+    // Rows 2 and 3 tie on (a, b); row 1 shares only the first key
+    let expected = [
+        (1, 0.0, 0.2),
+        (2, 0.25, 0.6),
+        (3, 0.25, 0.6),
+        (4, 0.75, 0.8),
+        (5, 1.0, 1.0),
+    ];
+    for ((id, pr, cd), (eid, epr, ecd)) in rows.iter().zip(expected.iter()) {
+        assert_eq!(id, eid);
+        assert!((pr - epr).abs() < 1e-9, "PERCENT_RANK for {}: {}", id, pr);
+        assert!((cd - ecd).abs() < 1e-9, "CUME_DIST for {}: {}", id, cd);
+    }
+
+    // Listed in information_schema.functions
+    let names: Vec<String> = db
+        .query(
+            "SELECT function_name FROM information_schema.functions
+             WHERE function_type = 'WINDOW' AND function_name IN ('NTILE', 'CUME_DIST', 'PERCENT_RANK')
+             ORDER BY function_name",
+            (),
+        )
+        .expect("Failed to query")
+        .map(|row| row.expect("Failed to get row").get(0).unwrap())
+        .collect();
+    assert_eq!(names, vec!["CUME_DIST", "NTILE", "PERCENT_RANK"]);
+}